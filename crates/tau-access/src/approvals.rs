@@ -634,6 +634,7 @@ pub fn approval_paths_for_state_dir(state_dir: &Path) -> (PathBuf, PathBuf) {
     let tau_root = match state_name {
         Some("github")
         | Some("slack")
+        | Some("discord")
         | Some("events")
         | Some("channel-store")
         | Some("multi-channel") => state_dir
@@ -105,6 +105,7 @@ pub fn pairing_policy_for_state_dir(state_dir: &Path) -> PairingPolicyConfig {
     let tau_root = match state_name {
         Some("github")
         | Some("slack")
+        | Some("discord")
         | Some("events")
         | Some("channel-store")
         | Some("multi-channel") => state_dir
@@ -131,6 +131,7 @@ fn security_root_for_state_dir(state_dir: &Path) -> PathBuf {
     let tau_root = match state_name {
         Some("github")
         | Some("slack")
+        | Some("discord")
         | Some("events")
         | Some("channel-store")
         | Some("multi-channel") => state_dir
@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use arc_swap::ArcSwap;
-use tau_ai::{ChatRequest, LlmClient, Message, PromptCacheConfig};
+use tau_ai::{ChatRequest, LlmClient, Message, ModelTier, PromptCacheConfig};
 use tau_memory::runtime::{FileMemoryStore, RuntimeMemoryRecord};
 
 /// Pre-computed learning insights passed into the Cortex bulletin.
@@ -210,6 +210,8 @@ impl Cortex {
             max_tokens: Some(256),
             temperature: Some(0.0),
             prompt_cache: PromptCacheConfig::default(),
+            reasoning: Default::default(),
+            model_tier: Some(ModelTier::Economy),
         };
 
         let mut reason_code = "cortex_bulletin_llm_applied".to_string();
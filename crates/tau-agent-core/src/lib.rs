@@ -14,8 +14,10 @@ use async_trait::async_trait;
 use serde_json::{json, Value};
 use tau_ai::{
     promote_assistant_textual_tool_calls, ChatRequest, ChatUsage, LlmClient, Message, MessageRole,
-    StreamDeltaHandler, TauAiError, ToolCall, ToolChoice, ToolDefinition,
+    ModelTier, ReasoningConfig, StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError,
+    TokenizerFamily, ToolCall, ToolChoice, ToolDefinition,
 };
+use tau_core::{current_trace_context, with_trace_context};
 pub use tau_memory::runtime::{
     FileMemoryStore, MemoryLifecycleMaintenancePolicy, MemoryLifecycleMaintenanceResult,
 };
@@ -51,7 +53,10 @@ pub use cortex_runtime::{
     format_learning_bulletin, Cortex, CortexConfig, CortexRefreshReport, LearningInsight,
 };
 pub use failure_detector::{FailureDetector, FailureDetectorConfig, FailureSignal};
-pub use metrics::{AgentMetrics, AgentMetricsSnapshot, ToolHealthStats};
+pub use metrics::{
+    provider_model_labels, record_provider_request_metrics, record_tool_call_metrics,
+    record_usage_metrics, AgentMetrics, AgentMetricsSnapshot, ToolHealthStats,
+};
 pub use mission::{
     MissionAcceptanceCriterion, MissionArtifactRef, MissionCheckpoint, MissionCompletion,
     MissionCompletionBlocker, MissionCompletionStatus, MissionCuratorReviewStatus,
@@ -84,15 +89,17 @@ pub(crate) use runtime_startup::{
     cache_insert_with_limit, lock_or_recover, normalize_direct_message_content,
     sleep_with_cancellation, spawn_async_event_handler_worker,
 };
-pub(crate) use runtime_tool_bridge::execute_tool_call_inner;
+pub(crate) use runtime_tool_bridge::execute_tool_call_traced;
 #[cfg(test)]
 pub(crate) use runtime_turn_loop::extract_json_payload;
 pub(crate) use runtime_turn_loop::{
     bounded_messages, build_structured_output_retry_prompt, collapse_whitespace,
     compact_messages_for_tier, context_pressure_snapshot, estimate_chat_request_tokens,
-    estimate_usage_cost_usd, is_retryable_ai_error, normalize_cost_alert_thresholds,
-    parse_structured_output, role_label, stream_retry_buffer_on_delta, timeout_duration_from_ms,
-    truncate_chars, ContextCompactionConfig, ContextCompactionTier, StreamingRetryBufferState,
+    estimate_hedge_cost_usd, estimate_usage_cost_usd, is_retryable_ai_error,
+    normalize_cost_alert_thresholds, parse_structured_output, record_turn_span_response,
+    role_label, start_agent_span, start_turn_span, stream_retry_buffer_on_delta,
+    timeout_duration_from_ms, truncate_chars, ContextCompactionConfig, ContextCompactionTier,
+    StreamingRetryBufferState,
 };
 #[cfg(test)]
 pub(crate) use tau_memory::runtime::embed_text_vector;
@@ -128,6 +135,14 @@ reading code, making changes, and verifying results using your tools.
 - For complex output, use headers and structure.
 - Explain reasoning only for non-obvious decisions.";
 
+/// Per-million-token prices for one model route.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ModelCostRates {
+    pub input_cost_per_million: Option<f64>,
+    pub cached_input_cost_per_million: Option<f64>,
+    pub output_cost_per_million: Option<f64>,
+}
+
 /// Public struct `AgentConfig` used across Tau components.
 ///
 /// # Examples
@@ -151,6 +166,8 @@ pub struct AgentConfig {
     pub max_turns: usize,
     pub temperature: Option<f32>,
     pub max_tokens: Option<u32>,
+    /// Extended thinking/reasoning budget forwarded on every model request.
+    pub reasoning: ReasoningConfig,
     pub max_parallel_tool_calls: usize,
     pub max_context_messages: Option<usize>,
     pub request_max_retries: usize,
@@ -161,6 +178,8 @@ pub struct AgentConfig {
     pub tool_timeout_ms: Option<u64>,
     pub max_estimated_input_tokens: Option<u32>,
     pub max_estimated_total_tokens: Option<u32>,
+    /// Tokenizer for token budgets and compaction; `None` uses the character heuristic.
+    pub tokenizer: Option<TokenizerFamily>,
     pub context_compaction_warn_threshold_percent: u8,
     pub context_compaction_aggressive_threshold_percent: u8,
     pub context_compaction_emergency_threshold_percent: u8,
@@ -184,6 +203,11 @@ pub struct AgentConfig {
     pub model_input_cost_per_million: Option<f64>,
     pub model_cached_input_cost_per_million: Option<f64>,
     pub model_output_cost_per_million: Option<f64>,
+    /// Prices of fallback routes keyed by `provider/model`, used for spend on
+    /// cancelled hedged duplicates; unlisted routes use the `model_*` rates.
+    pub route_cost_rates: HashMap<String, ModelCostRates>,
+    /// Tier hint forwarded on turn requests for model-routing clients.
+    pub model_tier_hint: Option<ModelTier>,
     pub cost_budget_usd: Option<f64>,
     pub cost_alert_thresholds_percent: Vec<u8>,
     pub async_event_queue_capacity: usize,
@@ -232,6 +256,7 @@ impl Default for AgentConfig {
             max_turns: 8,
             temperature: Some(0.0),
             max_tokens: None,
+            reasoning: ReasoningConfig::default(),
             max_parallel_tool_calls: 4,
             max_context_messages: Some(256),
             request_max_retries: 3,
@@ -242,6 +267,7 @@ impl Default for AgentConfig {
             tool_timeout_ms: Some(120_000),
             max_estimated_input_tokens: Some(120_000),
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -265,6 +291,8 @@ impl Default for AgentConfig {
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
+            model_tier_hint: None,
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             async_event_queue_capacity: 128,
@@ -312,6 +340,14 @@ pub struct AgentCostSnapshot {
     pub input_tokens: u64,
     pub output_tokens: u64,
     pub total_tokens: u64,
+    /// Portion of `output_tokens` spent on provider reasoning.
+    pub reasoning_tokens: u64,
+    /// Estimated input tokens billed to cancelled hedged duplicate requests.
+    pub hedge_input_tokens: u64,
+    /// Output tokens cancelled hedged duplicates streamed before being dropped.
+    pub hedge_output_tokens: u64,
+    /// Portion of `estimated_cost_usd` spent on hedged duplicate requests.
+    pub hedge_cost_usd: f64,
     pub estimated_cost_usd: f64,
     pub budget_usd: Option<f64>,
     pub budget_utilization: Option<f64>,
@@ -346,6 +382,12 @@ impl AsyncEventDispatchMetricsInner {
             panicked: self.panicked.load(Ordering::Relaxed),
         }
     }
+
+    /// Bumps one outcome counter and its process-wide `/metrics` series.
+    fn record(&self, counter: &AtomicU64, outcome: &str) {
+        counter.fetch_add(1, Ordering::Relaxed);
+        metrics::record_async_event_dispatch_metrics(outcome);
+    }
 }
 
 /// Cooperative cancellation token shared across runtime components.
@@ -758,6 +800,109 @@ pub enum AgentEvent {
     GracefulTermination {
         summary: String,
     },
+    // Streaming events: emitted while a provider response is still in flight.
+    StreamTextDelta {
+        turn: usize,
+        delta: String,
+    },
+    StreamReasoningDelta {
+        turn: usize,
+        delta: String,
+    },
+    StreamToolCallStart {
+        turn: usize,
+        index: usize,
+        tool_call_id: String,
+        tool_name: String,
+    },
+    StreamToolCallArgumentsDelta {
+        turn: usize,
+        index: usize,
+        tool_call_id: String,
+        delta: String,
+    },
+    StreamToolCallEnd {
+        turn: usize,
+        index: usize,
+        tool_call_id: String,
+        tool_name: String,
+        arguments: Value,
+    },
+    StreamUsage {
+        turn: usize,
+        usage: ChatUsage,
+    },
+    StreamStop {
+        turn: usize,
+        finish_reason: Option<String>,
+    },
+    // Plan execution events: emitted by structured plan executors.
+    PlanStepStarted {
+        plan_id: String,
+        step_id: String,
+    },
+    PlanStepCompleted {
+        plan_id: String,
+        step_id: String,
+        new_messages: usize,
+    },
+    PlanStepFailed {
+        plan_id: String,
+        step_id: String,
+        reason: String,
+    },
+    PlanStepSkipped {
+        plan_id: String,
+        step_id: String,
+        reason: String,
+    },
+    PlanRevised {
+        plan_id: String,
+        revision: usize,
+        reason: String,
+    },
+}
+
+impl AgentEvent {
+    fn from_stream_event(turn: usize, event: StreamEvent) -> Self {
+        match event {
+            StreamEvent::TextDelta { text } => AgentEvent::StreamTextDelta { turn, delta: text },
+            StreamEvent::ReasoningDelta { text } => {
+                AgentEvent::StreamReasoningDelta { turn, delta: text }
+            }
+            StreamEvent::ToolCallStart { index, id, name } => AgentEvent::StreamToolCallStart {
+                turn,
+                index,
+                tool_call_id: id,
+                tool_name: name,
+            },
+            StreamEvent::ToolCallArgumentsDelta { index, id, delta } => {
+                AgentEvent::StreamToolCallArgumentsDelta {
+                    turn,
+                    index,
+                    tool_call_id: id,
+                    delta,
+                }
+            }
+            StreamEvent::ToolCallEnd {
+                index,
+                id,
+                name,
+                arguments,
+            } => AgentEvent::StreamToolCallEnd {
+                turn,
+                index,
+                tool_call_id: id,
+                tool_name: name,
+                arguments,
+            },
+            StreamEvent::Usage { usage } => AgentEvent::StreamUsage { turn, usage },
+            StreamEvent::Stop { finish_reason } => AgentEvent::StreamStop {
+                turn,
+                finish_reason,
+            },
+        }
+    }
 }
 
 /// Enumerates supported `AgentError` values.
@@ -905,6 +1050,62 @@ type EventHandler = Arc<dyn Fn(&AgentEvent) + Send + Sync>;
 type AsyncEventHandlerFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
 type AsyncEventHandler = Arc<dyn Fn(AgentEvent) -> AsyncEventHandlerFuture + Send + Sync>;
 type AsyncEventSender = std::sync::mpsc::SyncSender<AgentEvent>;
+
+/// Owned copy of an agent's subscribers, for emitting from `'static` stream callbacks.
+#[derive(Clone)]
+struct AgentEventDispatcher {
+    handlers: Vec<EventHandler>,
+    async_handlers: Vec<AsyncEventSender>,
+    async_event_metrics: Arc<AsyncEventDispatchMetricsInner>,
+    block_on_full: bool,
+}
+
+impl AgentEventDispatcher {
+    fn dispatch(&self, event: AgentEvent) {
+        dispatch_agent_event(
+            &self.handlers,
+            &self.async_handlers,
+            &self.async_event_metrics,
+            self.block_on_full,
+            event,
+        );
+    }
+}
+
+fn dispatch_agent_event(
+    handlers: &[EventHandler],
+    async_handlers: &[AsyncEventSender],
+    async_event_metrics: &AsyncEventDispatchMetricsInner,
+    block_on_full: bool,
+    event: AgentEvent,
+) {
+    for handler in handlers {
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handler(&event)));
+    }
+    for sender in async_handlers {
+        if block_on_full {
+            match sender.send(event.clone()) {
+                Ok(()) => {
+                    async_event_metrics.record(&async_event_metrics.enqueued, "enqueued");
+                }
+                Err(_) => {
+                    async_event_metrics.record(&async_event_metrics.dropped_full, "dropped_full");
+                }
+            }
+            continue;
+        }
+
+        match sender.try_send(event.clone()) {
+            Ok(()) => {
+                async_event_metrics.record(&async_event_metrics.enqueued, "enqueued");
+            }
+            Err(std::sync::mpsc::TrySendError::Full(_))
+            | Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
+                async_event_metrics.record(&async_event_metrics.dropped_full, "dropped_full");
+            }
+        }
+    }
+}
 const CONTEXT_SUMMARY_PREFIX: &str = "[Tau context summary]";
 const CONTEXT_SUMMARY_MAX_CHARS: usize = 1_200;
 const CONTEXT_SUMMARY_SNIPPET_MAX_CHARS: usize = 160;
@@ -1061,6 +1262,7 @@ pub struct Agent {
     leak_detector: Arc<dyn LeakDetector>,
     cumulative_usage: ChatUsage,
     cumulative_cost_usd: f64,
+    cumulative_hedge_cost_usd: f64,
     emitted_cost_alert_thresholds: HashSet<u8>,
     next_tool_choice: Option<ToolChoice>,
     skip_response_reason: Option<String>,
@@ -1102,6 +1304,7 @@ impl Agent {
             leak_detector: Arc::new(DefaultLeakDetector::new()),
             cumulative_usage: ChatUsage::default(),
             cumulative_cost_usd: 0.0,
+            cumulative_hedge_cost_usd: 0.0,
             emitted_cost_alert_thresholds: HashSet::new(),
             next_tool_choice: None,
             skip_response_reason: None,
@@ -1500,6 +1703,10 @@ impl Agent {
             input_tokens: self.cumulative_usage.input_tokens,
             output_tokens: self.cumulative_usage.output_tokens,
             total_tokens: self.cumulative_usage.total_tokens,
+            reasoning_tokens: self.cumulative_usage.reasoning_tokens,
+            hedge_input_tokens: self.cumulative_usage.hedge_input_tokens,
+            hedge_output_tokens: self.cumulative_usage.hedge_output_tokens,
+            hedge_cost_usd: self.cumulative_hedge_cost_usd,
             estimated_cost_usd: self.cumulative_cost_usd,
             budget_usd,
             budget_utilization,
@@ -1682,40 +1889,27 @@ impl Agent {
         result
     }
 
+    /// Publishes an externally produced event to this agent's subscribers.
+    pub fn publish_event(&self, event: AgentEvent) {
+        self.emit(event);
+    }
+
     fn emit(&self, event: AgentEvent) {
-        for handler in &self.handlers {
-            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handler(&event)));
-        }
-        for sender in &self.async_handlers {
-            if self.config.async_event_block_on_full {
-                match sender.send(event.clone()) {
-                    Ok(()) => {
-                        self.async_event_metrics
-                            .enqueued
-                            .fetch_add(1, Ordering::Relaxed);
-                    }
-                    Err(_) => {
-                        self.async_event_metrics
-                            .dropped_full
-                            .fetch_add(1, Ordering::Relaxed);
-                    }
-                }
-                continue;
-            }
+        dispatch_agent_event(
+            &self.handlers,
+            &self.async_handlers,
+            &self.async_event_metrics,
+            self.config.async_event_block_on_full,
+            event,
+        );
+    }
 
-            match sender.try_send(event.clone()) {
-                Ok(()) => {
-                    self.async_event_metrics
-                        .enqueued
-                        .fetch_add(1, Ordering::Relaxed);
-                }
-                Err(std::sync::mpsc::TrySendError::Full(_))
-                | Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
-                    self.async_event_metrics
-                        .dropped_full
-                        .fetch_add(1, Ordering::Relaxed);
-                }
-            }
+    fn event_dispatcher(&self) -> AgentEventDispatcher {
+        AgentEventDispatcher {
+            handlers: self.handlers.clone(),
+            async_handlers: self.async_handlers.clone(),
+            async_event_metrics: Arc::clone(&self.async_event_metrics),
+            block_on_full: self.config.async_event_block_on_full,
         }
     }
 
@@ -2463,6 +2657,21 @@ impl Agent {
         ToolExecutionResult::ok(Value::Object(payload))
     }
 
+    /// Rates of the route that served the cancelled hedge, falling back to the
+    /// agent's own model rates when the route is not priced.
+    fn hedge_cost_rates(&self, usage: &ChatUsage) -> ModelCostRates {
+        usage
+            .hedge_model
+            .as_ref()
+            .and_then(|model| self.config.route_cost_rates.get(model))
+            .copied()
+            .unwrap_or(ModelCostRates {
+                input_cost_per_million: self.config.model_input_cost_per_million,
+                cached_input_cost_per_million: self.config.model_cached_input_cost_per_million,
+                output_cost_per_million: self.config.model_output_cost_per_million,
+            })
+    }
+
     fn accumulate_usage_and_emit_cost_events(&mut self, turn: usize, usage: &ChatUsage) {
         self.cumulative_usage.input_tokens = self
             .cumulative_usage
@@ -2476,14 +2685,29 @@ impl Agent {
             .cumulative_usage
             .total_tokens
             .saturating_add(usage.total_tokens);
+        self.cumulative_usage.reasoning_tokens = self
+            .cumulative_usage
+            .reasoning_tokens
+            .saturating_add(usage.reasoning_tokens);
+        self.cumulative_usage.hedge_input_tokens = self
+            .cumulative_usage
+            .hedge_input_tokens
+            .saturating_add(usage.hedge_input_tokens);
+        self.cumulative_usage.hedge_output_tokens = self
+            .cumulative_usage
+            .hedge_output_tokens
+            .saturating_add(usage.hedge_output_tokens);
 
+        let hedge_cost_usd = estimate_hedge_cost_usd(usage, self.hedge_cost_rates(usage));
         let turn_cost_usd = estimate_usage_cost_usd(
             usage,
             self.config.model_input_cost_per_million,
             self.config.model_cached_input_cost_per_million,
             self.config.model_output_cost_per_million,
-        );
+        ) + hedge_cost_usd;
         self.cumulative_cost_usd += turn_cost_usd;
+        self.cumulative_hedge_cost_usd += hedge_cost_usd;
+        record_usage_metrics(&self.config.model, usage, turn_cost_usd);
 
         let budget_usd = self.config.cost_budget_usd.filter(|budget| *budget > 0.0);
         if self.config.model_input_cost_per_million.is_none()
@@ -2531,8 +2755,12 @@ impl Agent {
             .collect()
     }
 
+    fn tokenizer_family(&self) -> TokenizerFamily {
+        self.config.tokenizer.unwrap_or(TokenizerFamily::Heuristic)
+    }
+
     fn enforce_token_budget(&self, request: &ChatRequest) -> Result<(), AgentError> {
-        let estimate = estimate_chat_request_tokens(request);
+        let estimate = estimate_chat_request_tokens(request, self.tokenizer_family());
         let max_input_tokens = self.config.max_estimated_input_tokens.unwrap_or(u32::MAX);
         let max_total_tokens = self.config.max_estimated_total_tokens.unwrap_or(u32::MAX);
 
@@ -2577,6 +2805,25 @@ impl Agent {
         start_index: usize,
         on_delta: Option<StreamDeltaHandler>,
         json_mode: bool,
+    ) -> Result<Vec<Message>, AgentError> {
+        let mut agent_span = start_agent_span(&self.config.agent_id, &self.config.model);
+        let result = with_trace_context(
+            agent_span.context().clone(),
+            self.run_loop_turns(start_index, on_delta, json_mode),
+        )
+        .await;
+        match &result {
+            Ok(_) => agent_span.set_status_ok(),
+            Err(error) => agent_span.set_status_error(error.to_string()),
+        }
+        result
+    }
+
+    async fn run_loop_turns(
+        &mut self,
+        start_index: usize,
+        on_delta: Option<StreamDeltaHandler>,
+        json_mode: bool,
     ) -> Result<Vec<Message>, AgentError> {
         if self.is_cancelled() {
             return Err(AgentError::Cancelled);
@@ -2591,6 +2838,7 @@ impl Agent {
                 return Err(AgentError::Cancelled);
             }
             self.emit(AgentEvent::TurnStart { turn });
+            let mut turn_span = start_turn_span(turn, &self.config.model);
 
             let tools = self.tool_definitions();
             let next_tool_choice = self.next_tool_choice.take();
@@ -2612,6 +2860,8 @@ impl Agent {
                     retention: None,
                     google_cached_content: None,
                 },
+                reasoning: self.config.reasoning,
+                model_tier: self.config.model_tier_hint,
             };
             self.sanitize_outbound_http_request(&mut request)?;
             self.enforce_token_budget(&request)?;
@@ -2620,15 +2870,18 @@ impl Agent {
             let response = if let Some(cached) = self.lookup_response_cache(&request, &on_delta) {
                 cached
             } else {
-                let response = self
-                    .complete_with_retry(request.clone(), on_delta.clone())
-                    .await?;
+                let response = with_trace_context(
+                    turn_span.context().clone(),
+                    self.complete_with_retry(turn, request.clone(), on_delta.clone()),
+                )
+                .await?;
                 self.store_response_cache(&request, &on_delta, &response);
                 response
             };
             let request_duration_ms = request_started.elapsed().as_millis() as u64;
             let finish_reason = response.finish_reason.clone();
             let usage = response.usage.clone();
+            record_turn_span_response(&mut turn_span, finish_reason.as_deref(), &usage);
             let assistant = promote_assistant_textual_tool_calls(response.message)?;
             self.messages.push(assistant.clone());
             self.emit(AgentEvent::MessageAdded {
@@ -2679,7 +2932,12 @@ impl Agent {
                 return Ok(new_messages);
             }
 
-            let tool_stats = self.execute_tool_calls(tool_calls).await?;
+            turn_span.set_attribute("tau.turn.tool_calls", tool_calls.len());
+            let tool_stats = with_trace_context(
+                turn_span.context().clone(),
+                self.execute_tool_calls(tool_calls),
+            )
+            .await?;
             pending_replan_on_tool_failure =
                 tool_stats.total > 0 && tool_stats.errors == tool_stats.total;
 
@@ -2820,6 +3078,8 @@ impl Agent {
                 retention: None,
                 google_cached_content: None,
             },
+            reasoning: Default::default(),
+            model_tier: Some(ModelTier::Economy),
         };
 
         let response = if let Some(timeout) = timeout_duration_from_ms(request_timeout_ms) {
@@ -3003,21 +3263,26 @@ impl Agent {
             self.messages.clone()
         };
         let compaction_config = self.context_compaction_config();
-        let pressure_estimate = estimate_chat_request_tokens(&ChatRequest {
-            model: self.config.model.clone(),
-            messages: messages.clone(),
-            tool_choice: None,
-            json_mode: false,
-            tools: Vec::new(),
-            max_tokens: self.config.max_tokens,
-            temperature: self.config.temperature,
-            prompt_cache: tau_ai::PromptCacheConfig {
-                enabled: true,
-                cache_key: Some(self.config.agent_id.clone()),
-                retention: None,
-                google_cached_content: None,
+        let pressure_estimate = estimate_chat_request_tokens(
+            &ChatRequest {
+                model: self.config.model.clone(),
+                messages: messages.clone(),
+                tool_choice: None,
+                json_mode: false,
+                tools: Vec::new(),
+                max_tokens: self.config.max_tokens,
+                temperature: self.config.temperature,
+                prompt_cache: tau_ai::PromptCacheConfig {
+                    enabled: true,
+                    cache_key: Some(self.config.agent_id.clone()),
+                    retention: None,
+                    google_cached_content: None,
+                },
+                reasoning: Default::default(),
+                model_tier: self.config.model_tier_hint,
             },
-        });
+            self.tokenizer_family(),
+        );
         let pressure_snapshot =
             context_pressure_snapshot(pressure_estimate.input_tokens, compaction_config);
         match pressure_snapshot.tier {
@@ -3121,12 +3386,23 @@ impl Agent {
 
     async fn complete_with_retry(
         &self,
+        turn: usize,
         request: ChatRequest,
         on_delta: Option<StreamDeltaHandler>,
     ) -> Result<tau_ai::ChatResponse, AgentError> {
         if self.is_cancelled() {
             return Err(AgentError::Cancelled);
         }
+        // Streaming requests also surface typed provider events; text deltas are mirrored as
+        // `StreamTextDelta` after retry buffering so subscribers never see replayed prefixes.
+        let event_dispatcher = on_delta.as_ref().map(|_| self.event_dispatcher());
+        let on_delta = match (on_delta, event_dispatcher.clone()) {
+            (Some(handler), Some(dispatcher)) => Some(Arc::new(move |delta: String| {
+                handler(delta.clone());
+                dispatcher.dispatch(AgentEvent::StreamTextDelta { turn, delta });
+            }) as StreamDeltaHandler),
+            (on_delta, _) => on_delta,
+        };
         let max_retries = self.config.request_max_retries;
         let mut attempt = 0usize;
         let mut backoff_ms = self.config.request_retry_initial_backoff_ms.max(1);
@@ -3181,9 +3457,21 @@ impl Agent {
             } else {
                 on_delta.clone()
             };
+            let attempt_on_event = match (attempt_on_delta, event_dispatcher.clone()) {
+                (Some(delta_handler), Some(dispatcher)) => {
+                    Some(Arc::new(move |event: StreamEvent| match event {
+                        StreamEvent::TextDelta { text } => delta_handler(text),
+                        other => dispatcher.dispatch(AgentEvent::from_stream_event(turn, other)),
+                    }) as StreamEventHandler)
+                }
+                (attempt_on_delta, _) => {
+                    attempt_on_delta.map(tau_ai::stream_event_handler_from_deltas)
+                }
+            };
+            let attempt_started = std::time::Instant::now();
             let client_call = self
                 .client
-                .complete_with_stream(request_for_attempt, attempt_on_delta);
+                .complete_with_events(request_for_attempt, attempt_on_event);
             let response_result = if let Some(timeout) = request_timeout {
                 let timed = if let Some(token) = cancellation_token.clone() {
                     tokio::select! {
@@ -3197,6 +3485,11 @@ impl Agent {
                 match timed {
                     Ok(result) => result,
                     Err(_) => {
+                        record_provider_request_metrics(
+                            &request.model,
+                            "timeout",
+                            attempt_started.elapsed(),
+                        );
                         let can_retry =
                             attempt < max_retries && (on_delta.is_none() || stream_retry_enabled);
                         if !can_retry {
@@ -3224,6 +3517,12 @@ impl Agent {
                 client_call.await
             };
 
+            let outcome = if response_result.is_ok() {
+                "success"
+            } else {
+                "error"
+            };
+            record_provider_request_metrics(&request.model, outcome, attempt_started.elapsed());
             match response_result {
                 Ok(response) => return Ok(response),
                 Err(error) => {
@@ -3330,8 +3629,16 @@ impl Agent {
             .map(|tool| (tool.definition.clone(), Arc::clone(&tool.tool)));
         let tool_timeout = timeout_duration_from_ms(self.config.tool_timeout_ms);
         let cancellation_token = self.cancellation_token();
+        let trace_parent = current_trace_context();
         tokio::spawn(async move {
-            execute_tool_call_inner(call, registered, tool_timeout, cancellation_token).await
+            execute_tool_call_traced(
+                call,
+                registered,
+                tool_timeout,
+                cancellation_token,
+                trace_parent,
+            )
+            .await
         })
     }
 
@@ -3365,6 +3672,7 @@ impl Agent {
 
     fn record_tool_result(&mut self, call: ToolCall, result: ToolExecutionResult) -> bool {
         let result = self.sanitize_tool_result(result);
+        record_tool_call_metrics(&call.name, result.is_error);
         self.emit(AgentEvent::ToolExecutionEnd {
             tool_call_id: call.id.clone(),
             tool_name: call.name.clone(),
@@ -3414,8 +3722,8 @@ mod tests {
     use async_trait::async_trait;
     use httpmock::prelude::*;
     use tau_ai::{
-        ChatRequest, ChatResponse, ChatUsage, ContentBlock, Message, MessageRole, ToolChoice,
-        ToolDefinition,
+        ChatRequest, ChatResponse, ChatUsage, ContentBlock, Message, MessageRole, TokenizerFamily,
+        ToolChoice, ToolDefinition,
     };
     use tokio::sync::Mutex as AsyncMutex;
 
@@ -3425,10 +3733,10 @@ mod tests {
         estimate_usage_cost_usd, extract_json_payload, normalize_cost_alert_thresholds,
         retrieve_memory_matches, stream_retry_buffer_on_delta, truncate_chars, Agent, AgentConfig,
         AgentDirectMessageError, AgentDirectMessagePolicy, AgentError, AgentEvent, AgentTool,
-        AsyncEventDispatchMetrics, CooperativeCancellationToken, SafetyMode, SafetyPolicy,
-        SafetyStage, StreamingRetryBufferState, ToolExecutionResult, CONTEXT_SUMMARY_MAX_CHARS,
-        CONTEXT_SUMMARY_MAX_EXCERPTS, CONTEXT_SUMMARY_PREFIX, DIRECT_MESSAGE_PREFIX,
-        MEMORY_RECALL_PREFIX,
+        AsyncEventDispatchMetrics, CooperativeCancellationToken, ModelCostRates, SafetyMode,
+        SafetyPolicy, SafetyStage, StreamingRetryBufferState, ToolExecutionResult,
+        CONTEXT_SUMMARY_MAX_CHARS, CONTEXT_SUMMARY_MAX_EXCERPTS, CONTEXT_SUMMARY_PREFIX,
+        DIRECT_MESSAGE_PREFIX, MEMORY_RECALL_PREFIX,
     };
 
     struct MockClient {
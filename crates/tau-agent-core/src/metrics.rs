@@ -2,11 +2,17 @@
 //!
 //! Provides lock-free counters for LLM requests, tool executions, context
 //! compaction, and replanning events. Per-tool health statistics are tracked
-//! via a `Mutex<HashMap>` for infrequent reads.
+//! via a `Mutex<HashMap>` for infrequent reads. Process-wide series for the
+//! gateway `/metrics` endpoint are recorded into the shared
+//! [`tau_core::global_metrics_registry`].
 
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
+use std::time::Duration;
+
+use tau_ai::{ChatUsage, ModelRef};
+use tau_core::{global_metrics_registry, DEFAULT_LATENCY_BUCKETS_SECONDS};
 
 /// Aggregate session-level metrics for an agent run.
 #[derive(Debug, Default)]
@@ -168,6 +174,93 @@ impl AgentMetrics {
     }
 }
 
+/// Splits a configured model reference into `(provider, model)` metric labels.
+pub fn provider_model_labels(model: &str) -> (&'static str, String) {
+    match ModelRef::parse(model) {
+        Ok(parsed) => (parsed.provider.as_str(), parsed.model),
+        Err(_) => ("unknown", model.trim().to_string()),
+    }
+}
+
+/// Records one provider request attempt in the process-wide registry.
+pub fn record_provider_request_metrics(model: &str, outcome: &str, elapsed: Duration) {
+    let (provider, model) = provider_model_labels(model);
+    let labels = [
+        ("provider", provider),
+        ("model", model.as_str()),
+        ("outcome", outcome),
+    ];
+    let registry = global_metrics_registry();
+    registry.observe_histogram(
+        "tau_provider_request_duration_seconds",
+        "Latency of LLM provider request attempts.",
+        DEFAULT_LATENCY_BUCKETS_SECONDS,
+        &labels,
+        elapsed.as_secs_f64(),
+    );
+    registry.increment_counter(
+        "tau_provider_requests",
+        "LLM provider request attempts by outcome.",
+        &labels,
+        1.0,
+    );
+}
+
+/// Records token usage and estimated spend for one model response.
+pub fn record_usage_metrics(model: &str, usage: &ChatUsage, cost_usd: f64) {
+    let (provider, model) = provider_model_labels(model);
+    let registry = global_metrics_registry();
+    for (kind, tokens) in [
+        ("input", usage.input_tokens),
+        ("output", usage.output_tokens),
+        ("cached_input", usage.cached_input_tokens),
+        ("reasoning", usage.reasoning_tokens),
+    ] {
+        if tokens == 0 {
+            continue;
+        }
+        registry.increment_counter(
+            "tau_llm_tokens",
+            "LLM tokens consumed by kind.",
+            &[
+                ("provider", provider),
+                ("model", model.as_str()),
+                ("kind", kind),
+            ],
+            tokens as f64,
+        );
+    }
+    if cost_usd > 0.0 {
+        registry.increment_counter(
+            "tau_llm_cost_usd",
+            "Estimated LLM spend in US dollars.",
+            &[("provider", provider), ("model", model.as_str())],
+            cost_usd,
+        );
+    }
+}
+
+/// Records the outcome of one tool call in the process-wide registry.
+pub fn record_tool_call_metrics(tool_name: &str, is_error: bool) {
+    let outcome = if is_error { "error" } else { "success" };
+    global_metrics_registry().increment_counter(
+        "tau_tool_calls",
+        "Tool calls by outcome.",
+        &[("tool", tool_name), ("outcome", outcome)],
+        1.0,
+    );
+}
+
+/// Records one async event subscriber dispatch outcome in the process-wide registry.
+pub fn record_async_event_dispatch_metrics(outcome: &str) {
+    global_metrics_registry().increment_counter(
+        "tau_agent_async_events",
+        "Async agent event subscriber dispatches by outcome.",
+        &[("outcome", outcome)],
+        1.0,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +314,57 @@ mod tests {
         assert_eq!(s.compactions_total, 2);
         assert_eq!(s.compaction_messages_dropped, 15);
     }
+
+    #[test]
+    fn provider_model_labels_split_prefixed_and_unknown_models() {
+        assert_eq!(
+            provider_model_labels("anthropic/claude-sonnet-4"),
+            ("anthropic", "claude-sonnet-4".to_string())
+        );
+        assert_eq!(
+            provider_model_labels("unsupported/model-x"),
+            ("unknown", "unsupported/model-x".to_string())
+        );
+    }
+
+    #[test]
+    fn record_usage_metrics_accumulates_tokens_and_cost_in_global_registry() {
+        let usage = ChatUsage {
+            input_tokens: 120,
+            output_tokens: 30,
+            total_tokens: 150,
+            cached_input_tokens: 0,
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
+        };
+        record_usage_metrics("openai/metrics-usage-test", &usage, 0.25);
+        record_usage_metrics("openai/metrics-usage-test", &usage, 0.25);
+        let registry = global_metrics_registry();
+        let input_labels = [
+            ("provider", "openai"),
+            ("model", "metrics-usage-test"),
+            ("kind", "input"),
+        ];
+        assert_eq!(registry.value("tau_llm_tokens", &input_labels), Some(240.0));
+        assert_eq!(
+            registry.value(
+                "tau_llm_cost_usd",
+                &[("provider", "openai"), ("model", "metrics-usage-test")]
+            ),
+            Some(0.5)
+        );
+        assert_eq!(
+            registry.value(
+                "tau_llm_tokens",
+                &[
+                    ("provider", "openai"),
+                    ("model", "metrics-usage-test"),
+                    ("kind", "reasoning"),
+                ]
+            ),
+            None
+        );
+    }
 }
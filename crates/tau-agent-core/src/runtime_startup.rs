@@ -2,7 +2,7 @@
 
 use std::{
     collections::{HashMap, VecDeque},
-    sync::{atomic::Ordering, Arc, Mutex},
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
@@ -49,24 +49,24 @@ pub(crate) fn spawn_async_event_handler_worker(
                 if let Some(timeout) = timeout {
                     match tokio::time::timeout(timeout, &mut task).await {
                         Ok(Ok(())) => {
-                            metrics.completed.fetch_add(1, Ordering::Relaxed);
+                            metrics.record(&metrics.completed, "completed");
                         }
                         Ok(Err(_)) => {
-                            metrics.panicked.fetch_add(1, Ordering::Relaxed);
+                            metrics.record(&metrics.panicked, "panicked");
                         }
                         Err(_) => {
                             task.abort();
                             let _ = task.await;
-                            metrics.timed_out.fetch_add(1, Ordering::Relaxed);
+                            metrics.record(&metrics.timed_out, "timed_out");
                         }
                     }
                 } else {
                     match task.await {
                         Ok(()) => {
-                            metrics.completed.fetch_add(1, Ordering::Relaxed);
+                            metrics.record(&metrics.completed, "completed");
                         }
                         Err(_) => {
-                            metrics.panicked.fetch_add(1, Ordering::Relaxed);
+                            metrics.record(&metrics.panicked, "panicked");
                         }
                     }
                 }
@@ -5,10 +5,39 @@ use std::{sync::Arc, time::Duration};
 use jsonschema::validator_for;
 use serde_json::{json, Value};
 use tau_ai::{ToolCall, ToolDefinition};
+use tau_core::{with_trace_context, Span, SpanKind, TraceContext};
 
 use crate::{AgentTool, CooperativeCancellationToken, ToolExecutionResult};
 
-pub(crate) async fn execute_tool_call_inner(
+pub(crate) async fn execute_tool_call_traced(
+    call: ToolCall,
+    registered: Option<(ToolDefinition, Arc<dyn AgentTool>)>,
+    tool_timeout: Option<Duration>,
+    cancellation_token: Option<CooperativeCancellationToken>,
+    trace_parent: Option<TraceContext>,
+) -> ToolExecutionResult {
+    let mut span = Span::start(
+        format!("execute_tool {}", call.name),
+        SpanKind::Internal,
+        trace_parent.as_ref(),
+    );
+    span.set_attribute("gen_ai.operation.name", "execute_tool");
+    span.set_attribute("gen_ai.tool.name", call.name.as_str());
+    span.set_attribute("gen_ai.tool.call.id", call.id.as_str());
+    let result = with_trace_context(
+        span.context().clone(),
+        execute_tool_call_inner(call, registered, tool_timeout, cancellation_token),
+    )
+    .await;
+    if result.is_error {
+        span.set_status_error("tool returned an error result");
+    } else {
+        span.set_status_ok();
+    }
+    result
+}
+
+async fn execute_tool_call_inner(
     call: ToolCall,
     registered: Option<(ToolDefinition, Arc<dyn AgentTool>)>,
     tool_timeout: Option<Duration>,
@@ -4,119 +4,53 @@ use std::time::Duration;
 
 use jsonschema::validator_for;
 use serde_json::Value;
-use tau_ai::{ChatRequest, ChatUsage, Message, MessageRole, TauAiError, ToolDefinition};
+use tau_ai::{
+    ChatRequest, ChatRequestTokenCount, ChatUsage, Message, MessageRole, TauAiError,
+    TokenizerFamily,
+};
+use tau_core::{Span, SpanKind};
 
 use crate::{
-    AgentError, CONTEXT_SUMMARY_MAX_CHARS, CONTEXT_SUMMARY_MAX_EXCERPTS, CONTEXT_SUMMARY_PREFIX,
-    CONTEXT_SUMMARY_SNIPPET_MAX_CHARS,
+    AgentError, ModelCostRates, CONTEXT_SUMMARY_MAX_CHARS, CONTEXT_SUMMARY_MAX_EXCERPTS,
+    CONTEXT_SUMMARY_PREFIX, CONTEXT_SUMMARY_SNIPPET_MAX_CHARS,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct ChatRequestTokenEstimate {
-    pub(crate) input_tokens: u32,
-    pub(crate) total_tokens: u32,
-}
-
-pub(crate) fn estimate_chat_request_tokens(request: &ChatRequest) -> ChatRequestTokenEstimate {
-    let message_tokens = request.messages.iter().fold(0u32, |acc, message| {
-        acc.saturating_add(estimate_message_tokens(message))
-    });
-    let tool_tokens = request.tools.iter().fold(0u32, |acc, tool| {
-        acc.saturating_add(estimate_tool_definition_tokens(tool))
-    });
-    let input_tokens = message_tokens.saturating_add(tool_tokens).saturating_add(2);
-    let total_tokens = input_tokens.saturating_add(request.max_tokens.unwrap_or(0));
-
-    ChatRequestTokenEstimate {
-        input_tokens,
-        total_tokens,
-    }
-}
-
-fn estimate_message_tokens(message: &Message) -> u32 {
-    let mut total = 4u32;
-    for block in &message.content {
-        match block {
-            tau_ai::ContentBlock::Text { text } => {
-                total = total.saturating_add(estimate_text_tokens(text));
-            }
-            tau_ai::ContentBlock::ToolCall {
-                id,
-                name,
-                arguments,
-            } => {
-                total = total.saturating_add(estimate_text_tokens(id));
-                total = total.saturating_add(estimate_text_tokens(name));
-                total = total.saturating_add(estimate_json_tokens(arguments));
-                total = total.saturating_add(4);
-            }
-            tau_ai::ContentBlock::Image { source } => {
-                total = total.saturating_add(estimate_media_source_tokens(source));
-                total = total.saturating_add(8);
-            }
-            tau_ai::ContentBlock::Audio { source } => {
-                total = total.saturating_add(estimate_media_source_tokens(source));
-                total = total.saturating_add(8);
-            }
-        }
-    }
-    if let Some(tool_call_id) = &message.tool_call_id {
-        total = total.saturating_add(estimate_text_tokens(tool_call_id));
-    }
-    if let Some(tool_name) = &message.tool_name {
-        total = total.saturating_add(estimate_text_tokens(tool_name));
-    }
-    total
-}
-
-fn estimate_tool_definition_tokens(definition: &ToolDefinition) -> u32 {
-    let mut total = 12u32;
-    total = total.saturating_add(estimate_text_tokens(&definition.name));
-    total = total.saturating_add(estimate_text_tokens(&definition.description));
-    total = total.saturating_add(estimate_json_tokens(&definition.parameters));
-    total
+pub(crate) fn estimate_chat_request_tokens(
+    request: &ChatRequest,
+    tokenizer: TokenizerFamily,
+) -> ChatRequestTokenCount {
+    tokenizer.count_chat_request_tokens(request)
 }
 
-fn estimate_json_tokens(value: &Value) -> u32 {
-    let rendered = serde_json::to_string(value).unwrap_or_else(|_| value.to_string());
-    estimate_text_tokens(&rendered)
+pub(crate) fn start_agent_span(agent_id: &str, model: &str) -> Span {
+    let mut span = Span::start_in_current(format!("invoke_agent {agent_id}"), SpanKind::Internal);
+    span.set_attribute("gen_ai.operation.name", "invoke_agent");
+    span.set_attribute("gen_ai.agent.id", agent_id);
+    span.set_attribute("gen_ai.request.model", model);
+    span
 }
 
-fn estimate_media_source_tokens(source: &tau_ai::MediaSource) -> u32 {
-    match source {
-        tau_ai::MediaSource::Url { url } => estimate_text_tokens(url),
-        tau_ai::MediaSource::Base64 { mime_type, data } => estimate_text_tokens(mime_type)
-            .saturating_add((data.len() as u32).saturating_div(3))
-            .saturating_add(2),
-    }
+pub(crate) fn start_turn_span(turn: usize, model: &str) -> Span {
+    let mut span = Span::start_in_current("agent_turn", SpanKind::Internal);
+    span.set_attribute("tau.turn", turn);
+    span.set_attribute("gen_ai.request.model", model);
+    span
 }
 
-fn estimate_text_tokens(text: &str) -> u32 {
-    if text.is_empty() {
-        return 0;
+pub(crate) fn record_turn_span_response(
+    span: &mut Span,
+    finish_reason: Option<&str>,
+    usage: &ChatUsage,
+) {
+    if let Some(finish_reason) = finish_reason {
+        span.set_attribute(
+            "gen_ai.response.finish_reasons",
+            vec![finish_reason.to_string()],
+        );
     }
-    let len = text.len();
-    let ascii_count = text.bytes().filter(|b| b.is_ascii()).count();
-    let special_count = text
-        .bytes()
-        .filter(|b| !b.is_ascii_alphanumeric() && !b.is_ascii_whitespace())
-        .count();
-    let ascii_ratio = ascii_count as f64 / len.max(1) as f64;
-    let special_ratio = special_count as f64 / len.max(1) as f64;
-
-    let chars_per_token = if ascii_ratio > 0.9 {
-        if special_ratio > 0.2 {
-            3.5 // Code content
-        } else {
-            4.0 // Prose
-        }
-    } else if ascii_ratio > 0.5 {
-        3.0 // Mixed content
-    } else {
-        2.0 // Heavy Unicode
-    };
-
-    (len as f64 / chars_per_token).ceil() as u32
+    span.set_attribute("gen_ai.usage.input_tokens", usage.input_tokens);
+    span.set_attribute("gen_ai.usage.output_tokens", usage.output_tokens);
+    span.set_status_ok();
 }
 
 pub(crate) fn estimate_usage_cost_usd(
@@ -145,6 +79,24 @@ pub(crate) fn estimate_usage_cost_usd(
     input + cached_input + output
 }
 
+/// Prices cancelled hedged duplicates at the rates of the route that served them:
+/// input at the uncached rate plus any output streamed before cancellation.
+pub(crate) fn estimate_hedge_cost_usd(usage: &ChatUsage, rates: ModelCostRates) -> f64 {
+    let input = rates
+        .input_cost_per_million
+        .unwrap_or(0.0)
+        .max(0.0)
+        .mul_add(usage.hedge_input_tokens as f64, 0.0)
+        / 1_000_000.0;
+    let output = rates
+        .output_cost_per_million
+        .unwrap_or(0.0)
+        .max(0.0)
+        .mul_add(usage.hedge_output_tokens as f64, 0.0)
+        / 1_000_000.0;
+    input + output
+}
+
 pub(crate) fn normalize_cost_alert_thresholds(thresholds: &[u8]) -> Vec<u8> {
     let mut normalized = thresholds
         .iter()
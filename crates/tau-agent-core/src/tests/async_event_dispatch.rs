@@ -113,6 +113,16 @@ async fn regression_async_subscriber_timeout_and_panic_are_isolated() {
     let metrics = agent.async_event_metrics();
     assert!(metrics.timed_out > 0);
     assert!(metrics.panicked > 0);
+    let registry = tau_core::global_metrics_registry();
+    for outcome in ["enqueued", "timed_out", "panicked"] {
+        assert!(
+            registry
+                .value("tau_agent_async_events", &[("outcome", outcome)])
+                .unwrap_or_default()
+                >= 1.0,
+            "missing async event series for {outcome}"
+        );
+    }
 }
 
 #[tokio::test]
@@ -224,3 +234,102 @@ async fn regression_panicking_handler_does_not_break_subsequent_prompts() {
         "non-panicking handler should keep receiving events across runs"
     );
 }
+
+#[derive(Default)]
+struct CollectingSpanExporter {
+    spans: Mutex<Vec<tau_core::SpanRecord>>,
+}
+
+impl tau_core::SpanExporter for CollectingSpanExporter {
+    fn export(&self, span: tau_core::SpanRecord) {
+        self.spans.lock().expect("spans lock").push(span);
+    }
+}
+
+#[tokio::test]
+async fn integration_prompt_emits_nested_agent_turn_and_tool_spans_under_remote_parent() {
+    let exporter = Arc::new(CollectingSpanExporter::default());
+    tau_core::install_span_exporter(exporter.clone());
+    let first_assistant = Message::assistant_blocks(vec![ContentBlock::ToolCall {
+        id: "call_trace".to_string(),
+        name: "read".to_string(),
+        arguments: serde_json::json!({ "path": "README.md" }),
+    }]);
+    let client = Arc::new(MockClient {
+        responses: AsyncMutex::new(VecDeque::from([
+            ChatResponse {
+                message: first_assistant,
+                finish_reason: Some("tool_calls".to_string()),
+                usage: ChatUsage::default(),
+            },
+            ChatResponse {
+                message: Message::assistant_text("done"),
+                finish_reason: Some("stop".to_string()),
+                usage: ChatUsage {
+                    input_tokens: 7,
+                    output_tokens: 2,
+                    total_tokens: 9,
+                    cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
+                },
+            },
+        ])),
+    });
+    let mut agent = Agent::new(client, AgentConfig::default());
+    agent.register_tool(ReadTool);
+    let remote = tau_core::TraceContext::parse_traceparent(
+        "00-5af7651916cd43dd8448eb211c80319c-c7ad6b7169203331-01",
+    )
+    .expect("valid traceparent");
+
+    tau_core::with_trace_context(remote.clone(), agent.prompt("read"))
+        .await
+        .expect("prompt succeeds");
+
+    let spans = exporter
+        .spans
+        .lock()
+        .expect("spans lock")
+        .iter()
+        .filter(|span| span.trace_id == remote.trace_id)
+        .cloned()
+        .collect::<Vec<_>>();
+    let agent_span = spans
+        .iter()
+        .find(|span| span.name.starts_with("invoke_agent"))
+        .expect("agent span");
+    assert_eq!(
+        agent_span.parent_span_id.as_deref(),
+        Some(remote.span_id.as_str())
+    );
+    assert_eq!(agent_span.status, tau_core::SpanStatus::Ok);
+    let turns = spans
+        .iter()
+        .filter(|span| span.name == "agent_turn")
+        .collect::<Vec<_>>();
+    assert_eq!(turns.len(), 2);
+    assert!(turns
+        .iter()
+        .all(|turn| turn.parent_span_id.as_deref() == Some(agent_span.span_id.as_str())));
+    assert_eq!(
+        turns[1].attribute("gen_ai.usage.input_tokens"),
+        Some(&tau_core::SpanAttributeValue::Int(7))
+    );
+    let tool_span = spans
+        .iter()
+        .find(|span| span.name == "execute_tool read")
+        .expect("tool span");
+    assert_eq!(
+        tool_span.parent_span_id.as_deref(),
+        Some(turns[0].span_id.as_str())
+    );
+    assert_eq!(
+        tool_span.attribute("gen_ai.tool.call.id"),
+        Some(&tau_core::SpanAttributeValue::String(
+            "call_trace".to_string()
+        ))
+    );
+}
@@ -331,9 +331,11 @@ fn unit_estimate_chat_request_tokens_accounts_for_tools_and_max_tokens() {
         max_tokens: Some(64),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
-    let estimate = estimate_chat_request_tokens(&request);
+    let estimate = estimate_chat_request_tokens(&request, TokenizerFamily::Heuristic);
     assert!(estimate.input_tokens > 0);
     assert_eq!(
         estimate.total_tokens,
@@ -352,6 +354,8 @@ fn functional_estimate_chat_request_tokens_accounts_for_media_blocks() {
         max_tokens: Some(32),
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
     let with_media = ChatRequest {
         model: "openai/gpt-5.2".to_string(),
@@ -372,10 +376,12 @@ fn functional_estimate_chat_request_tokens_accounts_for_media_blocks() {
         max_tokens: Some(32),
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
-    let baseline_estimate = estimate_chat_request_tokens(&baseline);
-    let media_estimate = estimate_chat_request_tokens(&with_media);
+    let baseline_estimate = estimate_chat_request_tokens(&baseline, TokenizerFamily::Heuristic);
+    let media_estimate = estimate_chat_request_tokens(&with_media, TokenizerFamily::Heuristic);
     assert!(media_estimate.input_tokens > baseline_estimate.input_tokens);
     assert!(media_estimate.total_tokens > baseline_estimate.total_tokens);
 }
@@ -243,6 +243,85 @@ async fn integration_streaming_retry_with_buffering_continues_tool_turns() {
     assert_eq!(attempts.load(Ordering::Relaxed), 3);
 }
 
+#[tokio::test]
+async fn functional_prompt_with_stream_surfaces_typed_stream_events_before_tool_execution() {
+    let tool_turn = Message::assistant_blocks(vec![ContentBlock::ToolCall {
+        id: "call_1".to_string(),
+        name: "read".to_string(),
+        arguments: serde_json::json!({ "path": "README.md" }),
+    }]);
+    let client = Arc::new(RetryingStreamingClient {
+        outcomes: AsyncMutex::new(VecDeque::from([
+            RetryingStreamingOutcome {
+                deltas: vec!["Reading ".to_string()],
+                response: Ok(ChatResponse {
+                    message: tool_turn,
+                    finish_reason: Some("tool_calls".to_string()),
+                    usage: ChatUsage::default(),
+                }),
+            },
+            RetryingStreamingOutcome {
+                deltas: vec!["done".to_string()],
+                response: Ok(ChatResponse {
+                    message: Message::assistant_text("done"),
+                    finish_reason: Some("stop".to_string()),
+                    usage: ChatUsage::default(),
+                }),
+            },
+        ])),
+        attempts: Arc::new(AtomicUsize::new(0)),
+    });
+    let mut agent = Agent::new(client, AgentConfig::default());
+    agent.register_tool(ReadTool);
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink_events = events.clone();
+    agent.subscribe(move |event| {
+        let label = match event {
+            AgentEvent::StreamTextDelta { turn, delta } => format!("text:{turn}:{delta}"),
+            AgentEvent::StreamToolCallStart {
+                turn,
+                index,
+                tool_call_id,
+                tool_name,
+            } => format!("tool_start:{turn}:{index}:{tool_call_id}:{tool_name}"),
+            AgentEvent::StreamToolCallEnd {
+                tool_call_id,
+                arguments,
+                ..
+            } => format!("tool_end:{tool_call_id}:{arguments}"),
+            AgentEvent::StreamStop {
+                turn,
+                finish_reason,
+            } => format!("stop:{turn}:{}", finish_reason.as_deref().unwrap_or("none")),
+            AgentEvent::ToolExecutionStart { tool_call_id, .. } => {
+                format!("execute:{tool_call_id}")
+            }
+            _ => return,
+        };
+        sink_events.lock().expect("event lock").push(label);
+    });
+
+    let sink: tau_ai::StreamDeltaHandler = Arc::new(|_delta: String| {});
+    agent
+        .prompt_with_stream("read file", Some(sink))
+        .await
+        .expect("streaming prompt should succeed");
+
+    assert_eq!(
+        events.lock().expect("event lock").as_slice(),
+        [
+            "text:1:Reading ",
+            "tool_start:1:0:call_1:read",
+            r#"tool_end:call_1:{"path":"README.md"}"#,
+            "stop:1:tool_calls",
+            "execute:call_1",
+            "text:2:done",
+            "stop:2:stop",
+        ]
+    );
+}
+
 #[tokio::test]
 async fn regression_streaming_retry_disabled_fails_without_retrying_stream() {
     let attempts = Arc::new(AtomicUsize::new(0));
@@ -390,6 +469,42 @@ async fn integration_total_token_budget_enforces_max_tokens_headroom() {
     );
 }
 
+#[tokio::test]
+async fn functional_configured_tokenizer_replaces_heuristic_in_token_budget() {
+    async fn estimated_input_tokens(tokenizer: Option<TokenizerFamily>) -> u32 {
+        let client = Arc::new(CapturingMockClient {
+            responses: AsyncMutex::new(VecDeque::new()),
+            requests: AsyncMutex::new(Vec::new()),
+        });
+        let mut agent = Agent::new(
+            client,
+            AgentConfig {
+                system_prompt: String::new(),
+                max_estimated_input_tokens: Some(1),
+                tokenizer,
+                ..AgentConfig::default()
+            },
+        );
+        match agent
+            .prompt("日本語のテキストは英語よりも多くのトークンを使います。")
+            .await
+        {
+            Err(AgentError::TokenBudgetExceeded {
+                estimated_input_tokens,
+                ..
+            }) => estimated_input_tokens,
+            other => panic!("expected token budget failure, got {other:?}"),
+        }
+    }
+
+    let heuristic = estimated_input_tokens(None).await;
+    let o200k = estimated_input_tokens(Some(TokenizerFamily::O200kBase)).await;
+    assert!(
+        o200k < heuristic,
+        "o200k count {o200k} should be below byte heuristic {heuristic}"
+    );
+}
+
 #[test]
 fn unit_estimate_usage_cost_usd_applies_input_and_output_rates() {
     let usage = ChatUsage {
@@ -397,6 +512,10 @@ fn unit_estimate_usage_cost_usd_applies_input_and_output_rates() {
         output_tokens: 500,
         total_tokens: 2_500,
         cached_input_tokens: 0,
+        reasoning_tokens: 0,
+        hedge_input_tokens: 0,
+        hedge_output_tokens: 0,
+        hedge_model: None,
     };
     let cost = estimate_usage_cost_usd(&usage, Some(1.5), None, Some(6.0));
     let expected = (2_000.0 * 1.5 + 500.0 * 6.0) / 1_000_000.0;
@@ -410,6 +529,10 @@ fn spec_c07_estimate_usage_cost_uses_cached_input_rate_for_cached_tokens() {
         output_tokens: 500,
         total_tokens: 2_500,
         cached_input_tokens: 1_200,
+        reasoning_tokens: 0,
+        hedge_input_tokens: 0,
+        hedge_output_tokens: 0,
+        hedge_model: None,
     };
     let cost = estimate_usage_cost_usd(&usage, Some(2.0), Some(0.2), Some(6.0));
     let expected = ((800.0 * 2.0) + (1_200.0 * 0.2) + (500.0 * 6.0)) / 1_000_000.0;
@@ -436,6 +559,10 @@ async fn functional_prompt_emits_cost_update_event_when_model_pricing_present()
                 output_tokens: 100,
                 total_tokens: 300,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         }])),
     });
@@ -488,6 +615,138 @@ async fn functional_prompt_emits_cost_update_event_when_model_pricing_present()
     assert_eq!(events[0].3, None);
 }
 
+#[tokio::test]
+async fn functional_reasoning_budget_is_forwarded_and_reasoning_tokens_reach_cost_snapshot() {
+    let client = Arc::new(CapturingMockClient {
+        responses: AsyncMutex::new(VecDeque::from([ChatResponse {
+            message: Message::assistant_blocks(vec![
+                tau_ai::ContentBlock::thinking("anthropic", "weigh options", Some("sig".into())),
+                tau_ai::ContentBlock::text("done"),
+            ]),
+            finish_reason: Some("stop".to_string()),
+            usage: ChatUsage {
+                input_tokens: 20,
+                output_tokens: 50,
+                total_tokens: 70,
+                cached_input_tokens: 0,
+                reasoning_tokens: 35,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
+            },
+        }])),
+        requests: AsyncMutex::new(Vec::new()),
+    });
+    let reasoning = tau_ai::ReasoningConfig {
+        budget_tokens: Some(8_192),
+        effort: None,
+    };
+    let mut agent = Agent::new(
+        client.clone(),
+        AgentConfig {
+            reasoning,
+            ..AgentConfig::default()
+        },
+    );
+
+    let new_messages = agent.prompt("think it through").await.expect("prompt");
+
+    let requests = client.requests.lock().await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].reasoning, reasoning);
+    let snapshot = agent.cost_snapshot();
+    assert_eq!(snapshot.output_tokens, 50);
+    assert_eq!(snapshot.reasoning_tokens, 35);
+    let assistant = new_messages.last().expect("assistant message");
+    assert_eq!(assistant.text_content(), "done");
+    assert_eq!(assistant.thinking_content(), "weigh options");
+}
+
+#[tokio::test]
+async fn functional_hedged_duplicate_input_tokens_reach_cost_snapshot() {
+    let client = Arc::new(MockClient {
+        responses: AsyncMutex::new(VecDeque::from([ChatResponse {
+            message: Message::assistant_text("done"),
+            finish_reason: Some("stop".to_string()),
+            usage: ChatUsage {
+                input_tokens: 200,
+                output_tokens: 100,
+                total_tokens: 300,
+                cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 200,
+                hedge_output_tokens: 0,
+                hedge_model: None,
+            },
+        }])),
+    });
+    let mut agent = Agent::new(
+        client,
+        AgentConfig {
+            model_input_cost_per_million: Some(2.0),
+            model_output_cost_per_million: Some(4.0),
+            ..AgentConfig::default()
+        },
+    );
+
+    let _ = agent.prompt("hedge this run").await.expect("prompt");
+
+    let snapshot = agent.cost_snapshot();
+    let hedge_cost = 200.0 * 2.0 / 1_000_000.0;
+    let expected = (200.0 * 2.0 + 100.0 * 4.0) / 1_000_000.0 + hedge_cost;
+    assert_eq!(snapshot.input_tokens, 200);
+    assert_eq!(snapshot.total_tokens, 300);
+    assert_eq!(snapshot.hedge_input_tokens, 200);
+    assert!((snapshot.hedge_cost_usd - hedge_cost).abs() < 1e-12);
+    assert!((snapshot.estimated_cost_usd - expected).abs() < 1e-12);
+}
+
+#[tokio::test]
+async fn functional_cancelled_hedge_is_priced_with_its_route_rates() {
+    let client = Arc::new(MockClient {
+        responses: AsyncMutex::new(VecDeque::from([ChatResponse {
+            message: Message::assistant_text("done"),
+            finish_reason: Some("stop".to_string()),
+            usage: ChatUsage {
+                input_tokens: 200,
+                output_tokens: 100,
+                total_tokens: 300,
+                cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 200,
+                hedge_output_tokens: 40,
+                hedge_model: Some("openai/cheap-route".to_string()),
+            },
+        }])),
+    });
+    let mut agent = Agent::new(
+        client,
+        AgentConfig {
+            model_input_cost_per_million: Some(2.0),
+            model_output_cost_per_million: Some(4.0),
+            route_cost_rates: HashMap::from([(
+                "openai/cheap-route".to_string(),
+                ModelCostRates {
+                    input_cost_per_million: Some(0.5),
+                    cached_input_cost_per_million: None,
+                    output_cost_per_million: Some(1.5),
+                },
+            )]),
+            ..AgentConfig::default()
+        },
+    );
+
+    let _ = agent.prompt("hedge this run").await.expect("prompt");
+
+    let snapshot = agent.cost_snapshot();
+    let hedge_cost = (200.0 * 0.5 + 40.0 * 1.5) / 1_000_000.0;
+    let expected = (200.0 * 2.0 + 100.0 * 4.0) / 1_000_000.0 + hedge_cost;
+    assert_eq!(snapshot.hedge_input_tokens, 200);
+    assert_eq!(snapshot.hedge_output_tokens, 40);
+    assert!((snapshot.hedge_cost_usd - hedge_cost).abs() < 1e-12);
+    assert!((snapshot.estimated_cost_usd - expected).abs() < 1e-12);
+}
+
 #[tokio::test]
 async fn integration_budget_alerts_emit_once_per_threshold_across_multiple_prompts() {
     let client = Arc::new(MockClient {
@@ -500,6 +759,10 @@ async fn integration_budget_alerts_emit_once_per_threshold_across_multiple_promp
                     output_tokens: 0,
                     total_tokens: 80_000,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
             },
             ChatResponse {
@@ -510,6 +773,10 @@ async fn integration_budget_alerts_emit_once_per_threshold_across_multiple_promp
                     output_tokens: 0,
                     total_tokens: 40_000,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
             },
             ChatResponse {
@@ -520,6 +787,10 @@ async fn integration_budget_alerts_emit_once_per_threshold_across_multiple_promp
                     output_tokens: 0,
                     total_tokens: 40_000,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
             },
         ])),
@@ -575,6 +846,10 @@ async fn regression_cost_budget_alert_threshold_normalization_avoids_duplicates(
                 output_tokens: 0,
                 total_tokens: 150_000,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         }])),
     });
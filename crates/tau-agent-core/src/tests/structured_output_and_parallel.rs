@@ -39,6 +39,10 @@ async fn turn_end_events_include_usage_finish_reason_and_request_duration() {
         output_tokens: 2,
         total_tokens: 5,
         cached_input_tokens: 0,
+        reasoning_tokens: 0,
+        hedge_input_tokens: 0,
+        hedge_output_tokens: 0,
+        hedge_model: None,
     };
     let client = Arc::new(MockClient {
         responses: AsyncMutex::new(VecDeque::from([ChatResponse {
@@ -2140,21 +2144,26 @@ async fn spec_c05_pressure_path_compacts_before_budget_check_and_avoids_budget_e
 
     let mut unbounded_messages = agent.messages().to_vec();
     unbounded_messages.push(Message::user("latest"));
-    let estimate_without_compaction = estimate_chat_request_tokens(&ChatRequest {
-        model: agent.config.model.clone(),
-        messages: unbounded_messages,
-        tool_choice: None,
-        json_mode: false,
-        tools: Vec::new(),
-        max_tokens: agent.config.max_tokens,
-        temperature: agent.config.temperature,
-        prompt_cache: tau_ai::PromptCacheConfig {
-            enabled: true,
-            cache_key: Some(agent.config.agent_id.clone()),
-            retention: None,
-            google_cached_content: None,
+    let estimate_without_compaction = estimate_chat_request_tokens(
+        &ChatRequest {
+            model: agent.config.model.clone(),
+            messages: unbounded_messages,
+            tool_choice: None,
+            json_mode: false,
+            tools: Vec::new(),
+            max_tokens: agent.config.max_tokens,
+            temperature: agent.config.temperature,
+            prompt_cache: tau_ai::PromptCacheConfig {
+                enabled: true,
+                cache_key: Some(agent.config.agent_id.clone()),
+                retention: None,
+                google_cached_content: None,
+            },
+            reasoning: Default::default(),
+            model_tier: None,
         },
-    });
+        TokenizerFamily::Heuristic,
+    );
     assert!(
         estimate_without_compaction.input_tokens > 100,
         "test setup requires pre-compaction prompt to exceed token budget"
@@ -2164,7 +2173,8 @@ async fn spec_c05_pressure_path_compacts_before_budget_check_and_avoids_budget_e
 
     let requests = client.requests.lock().await;
     let first_request = requests.first().expect("request should be captured");
-    let compacted_estimate = estimate_chat_request_tokens(first_request);
+    let compacted_estimate =
+        estimate_chat_request_tokens(first_request, TokenizerFamily::Heuristic);
     assert!(
         compacted_estimate.input_tokens <= 100,
         "compacted request should fit inside configured token budget"
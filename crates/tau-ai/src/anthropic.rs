@@ -6,12 +6,19 @@ use serde_json::{json, Value};
 use tokio::time::sleep;
 
 use crate::{
+    genai_spans::{trace_propagation_headers, ChatCompletionSpan},
+    rate_limits::observe_rate_limit_headers,
     retry::{
         is_retryable_http_error, new_request_id, parse_retry_after_ms, provider_retry_delay_ms,
         retry_budget_allows_delay, should_retry_status,
     },
+    types::{
+        emit_stream_completion_events, emit_stream_events_for_response,
+        stream_event_handler_from_deltas,
+    },
     ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, MediaSource, Message,
-    MessageRole, StreamDeltaHandler, TauAiError, ToolChoice, ToolDefinition,
+    MessageRole, StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError, ToolChoice,
+    ToolDefinition,
 };
 
 #[derive(Debug, Clone)]
@@ -78,7 +85,16 @@ impl LlmClient for AnthropicClient {
         request: ChatRequest,
         on_delta: Option<StreamDeltaHandler>,
     ) -> Result<ChatResponse, TauAiError> {
-        self.complete_with_mode(request, on_delta).await
+        self.complete_with_mode(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, on_event).await
     }
 }
 
@@ -86,10 +102,20 @@ impl AnthropicClient {
     async fn complete_with_mode(
         &self,
         request: ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        ChatCompletionSpan::start("anthropic", &request)
+            .instrument(self.complete_untraced(request, on_event))
+            .await
+    }
+
+    async fn complete_untraced(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         let mut body = build_messages_request_body(&request);
-        let stream_mode = on_delta.is_some();
+        let stream_mode = on_event.is_some();
         if stream_mode {
             body["stream"] = json!(true);
         }
@@ -104,6 +130,7 @@ impl AnthropicClient {
                 .post(&url)
                 .header("x-tau-request-id", request_id)
                 .header("x-tau-retry-attempt", attempt.to_string())
+                .headers(trace_propagation_headers())
                 .json(&body)
                 .send()
                 .await;
@@ -111,8 +138,9 @@ impl AnthropicClient {
             match response {
                 Ok(response) => {
                     let status = response.status();
+                    observe_rate_limit_headers(status.as_u16(), response.headers());
                     if status.is_success() {
-                        if let Some(delta_handler) = on_delta.clone() {
+                        if let Some(event_handler) = on_event.clone() {
                             let is_event_stream = response
                                 .headers()
                                 .get(CONTENT_TYPE)
@@ -122,16 +150,13 @@ impl AnthropicClient {
                                 })
                                 .unwrap_or(false);
                             if is_event_stream {
-                                return parse_messages_stream_response(response, delta_handler)
+                                return parse_messages_stream_response(response, event_handler)
                                     .await;
                             }
 
                             let raw = response.text().await?;
                             let parsed = parse_messages_response(&raw)?;
-                            let text = parsed.message.text_content();
-                            if !text.is_empty() {
-                                delta_handler(text);
-                            }
+                            emit_stream_events_for_response(&event_handler, &parsed);
                             return Ok(parsed);
                         }
 
@@ -188,7 +213,7 @@ impl AnthropicClient {
     }
 }
 
-fn build_messages_request_body(request: &ChatRequest) -> Value {
+pub(crate) fn build_messages_request_body(request: &ChatRequest) -> Value {
     let system = extract_system_text(&request.messages);
     let messages = to_anthropic_messages(&request.messages);
 
@@ -217,6 +242,22 @@ fn build_messages_request_body(request: &ChatRequest) -> Value {
         set_anthropic_system_prompt(&mut body, system, &request.prompt_cache);
     }
 
+    let thinking_budget = request
+        .reasoning
+        .resolved_budget_tokens()
+        .map(|budget| budget.max(ANTHROPIC_MIN_THINKING_BUDGET_TOKENS));
+    if let Some(budget_tokens) = thinking_budget {
+        body["thinking"] = json!({
+            "type": "enabled",
+            "budget_tokens": budget_tokens,
+        });
+        let max_tokens = body["max_tokens"].as_u64().unwrap_or_default();
+        if max_tokens <= u64::from(budget_tokens) {
+            body["max_tokens"] =
+                json!(budget_tokens.saturating_add(ANTHROPIC_MIN_THINKING_BUDGET_TOKENS));
+        }
+    }
+
     if !request.tools.is_empty() {
         body["tools"] = to_anthropic_tools(&request.tools);
         if let Some(tool_choice) = request
@@ -224,17 +265,26 @@ fn build_messages_request_body(request: &ChatRequest) -> Value {
             .as_ref()
             .and_then(to_anthropic_tool_choice)
         {
-            body["tool_choice"] = tool_choice;
+            // Extended thinking only supports automatic tool selection.
+            body["tool_choice"] = if thinking_budget.is_some() {
+                json!({ "type": "auto" })
+            } else {
+                tool_choice
+            };
         }
     }
 
-    if let Some(temperature) = request.temperature {
+    // Anthropic rejects custom temperatures while extended thinking is enabled.
+    if let Some(temperature) = request.temperature.filter(|_| thinking_budget.is_none()) {
         body["temperature"] = json!(temperature);
     }
 
     body
 }
 
+const ANTHROPIC_PROVIDER: &str = "anthropic";
+const ANTHROPIC_MIN_THINKING_BUDGET_TOKENS: u32 = 1_024;
+
 fn set_anthropic_system_prompt(
     body: &mut Value,
     system_prompt: String,
@@ -413,12 +463,44 @@ fn to_anthropic_content_parts(message: &Message, allow_tool_calls: bool) -> Vec<
                     "text": format!("[tau-audio:{}]", media_source_descriptor(source)),
                 }));
             }
+            ContentBlock::Thinking {
+                text,
+                signature: Some(signature),
+                redacted,
+                ..
+            } if allow_tool_calls && block.is_thinking_from(ANTHROPIC_PROVIDER) => {
+                // Thinking blocks must be replayed verbatim, signature included, so that
+                // multi-turn tool use keeps the model's prior reasoning attached.
+                if *redacted {
+                    parts.push(json!({
+                        "type": "redacted_thinking",
+                        "data": signature,
+                    }));
+                } else {
+                    parts.push(json!({
+                        "type": "thinking",
+                        "thinking": text,
+                        "signature": signature,
+                    }));
+                }
+            }
+            ContentBlock::Thinking { .. } => {}
         }
     }
 
     parts
 }
 
+fn redacted_thinking_block(data: String) -> ContentBlock {
+    ContentBlock::Thinking {
+        text: String::new(),
+        signature: Some(data),
+        id: None,
+        redacted: true,
+        provider: Some(ANTHROPIC_PROVIDER.to_string()),
+    }
+}
+
 fn media_source_descriptor(source: &MediaSource) -> String {
     match source {
         MediaSource::Url { url } => format!("url:{url}"),
@@ -428,7 +510,7 @@ fn media_source_descriptor(source: &MediaSource) -> String {
     }
 }
 
-fn parse_messages_response(raw: &str) -> Result<ChatResponse, TauAiError> {
+pub(crate) fn parse_messages_response(raw: &str) -> Result<ChatResponse, TauAiError> {
     let parsed: AnthropicMessageResponse = serde_json::from_str(raw)?;
 
     let mut blocks = Vec::new();
@@ -494,6 +576,19 @@ fn parse_messages_response(raw: &str) -> Result<ChatResponse, TauAiError> {
             AnthropicContent::Audio {
                 source: AnthropicMediaSource::Other,
             } => {}
+            AnthropicContent::Thinking {
+                thinking,
+                signature,
+            } => {
+                blocks.push(ContentBlock::thinking(
+                    ANTHROPIC_PROVIDER,
+                    thinking,
+                    signature,
+                ));
+            }
+            AnthropicContent::RedactedThinking { data } => {
+                blocks.push(redacted_thinking_block(data));
+            }
             AnthropicContent::Other => {}
         }
     }
@@ -505,6 +600,10 @@ fn parse_messages_response(raw: &str) -> Result<ChatResponse, TauAiError> {
             output_tokens: usage.output_tokens,
             total_tokens: usage.input_tokens + usage.output_tokens,
             cached_input_tokens: usage.cache_read_input_tokens.unwrap_or_default(),
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
         })
         .unwrap_or_default();
 
@@ -515,9 +614,18 @@ fn parse_messages_response(raw: &str) -> Result<ChatResponse, TauAiError> {
     })
 }
 
-async fn parse_messages_stream_response(
+pub(crate) async fn parse_messages_stream_response(
     response: reqwest::Response,
-    on_delta: StreamDeltaHandler,
+    on_event: StreamEventHandler,
+) -> Result<ChatResponse, TauAiError> {
+    let parsed = collect_messages_stream_response(response, &on_event).await?;
+    emit_stream_completion_events(&on_event, &parsed);
+    Ok(parsed)
+}
+
+async fn collect_messages_stream_response(
+    response: reqwest::Response,
+    on_event: &StreamEventHandler,
 ) -> Result<ChatResponse, TauAiError> {
     let mut stream = response.bytes_stream();
     let mut line_buffer = String::new();
@@ -525,7 +633,7 @@ async fn parse_messages_stream_response(
     let mut current_data = String::new();
 
     let mut text = String::new();
-    let mut tool_calls: Vec<AnthropicToolUseAccumulator> = Vec::new();
+    let mut tool_calls: Vec<AnthropicContentBlockAccumulator> = Vec::new();
     let mut finish_reason = None;
     let mut usage = ChatUsage::default();
 
@@ -546,7 +654,7 @@ async fn parse_messages_stream_response(
                 apply_anthropic_stream_event(
                     current_event.take(),
                     current_data.trim(),
-                    &on_delta,
+                    on_event,
                     &mut text,
                     &mut tool_calls,
                     &mut finish_reason,
@@ -574,7 +682,7 @@ async fn parse_messages_stream_response(
         apply_anthropic_stream_event(
             current_event.take(),
             current_data.trim(),
-            &on_delta,
+            on_event,
             &mut text,
             &mut tool_calls,
             &mut finish_reason,
@@ -593,9 +701,9 @@ async fn parse_messages_stream_response(
 fn apply_anthropic_stream_event(
     event: Option<String>,
     data: &str,
-    on_delta: &StreamDeltaHandler,
+    on_event: &StreamEventHandler,
     text: &mut String,
-    tool_calls: &mut Vec<AnthropicToolUseAccumulator>,
+    tool_calls: &mut Vec<AnthropicContentBlockAccumulator>,
     finish_reason: &mut Option<String>,
     usage: &mut ChatUsage,
 ) -> Result<(), TauAiError> {
@@ -652,7 +760,7 @@ fn apply_anthropic_stream_event(
             };
             let index = index as usize;
             if tool_calls.len() <= index {
-                tool_calls.resize_with(index + 1, AnthropicToolUseAccumulator::default);
+                tool_calls.resize_with(index + 1, AnthropicContentBlockAccumulator::default);
             }
 
             let block = payload
@@ -660,7 +768,28 @@ fn apply_anthropic_stream_event(
                 .and_then(Value::as_object)
                 .cloned()
                 .unwrap_or_default();
-            if block.get("type").and_then(Value::as_str) == Some("tool_use") {
+            let block_type = block
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            tool_calls[index].kind = block_type.to_string();
+            match block_type {
+                "thinking" => {
+                    if let Some(thinking) = block.get("thinking").and_then(Value::as_str) {
+                        tool_calls[index].thinking.push_str(thinking);
+                    }
+                    if let Some(signature) = block.get("signature").and_then(Value::as_str) {
+                        tool_calls[index].signature.push_str(signature);
+                    }
+                }
+                "redacted_thinking" => {
+                    if let Some(data) = block.get("data").and_then(Value::as_str) {
+                        tool_calls[index].signature = data.to_string();
+                    }
+                }
+                _ => {}
+            }
+            if block_type == "tool_use" {
                 if let Some(id) = block.get("id").and_then(Value::as_str) {
                     tool_calls[index].id = id.to_string();
                 }
@@ -670,6 +799,11 @@ fn apply_anthropic_stream_event(
                 if let Some(input) = block.get("input") {
                     tool_calls[index].input = Some(input.clone());
                 }
+                on_event(StreamEvent::ToolCallStart {
+                    index: anthropic_tool_call_ordinal(tool_calls, index),
+                    id: tool_calls[index].id.clone(),
+                    name: tool_calls[index].name.clone(),
+                });
             }
         }
         "content_block_delta" => {
@@ -679,7 +813,7 @@ fn apply_anthropic_stream_event(
                 .map(|value| value as usize)
                 .unwrap_or(0);
             if tool_calls.len() <= index {
-                tool_calls.resize_with(index + 1, AnthropicToolUseAccumulator::default);
+                tool_calls.resize_with(index + 1, AnthropicContentBlockAccumulator::default);
             }
 
             let delta = payload
@@ -696,13 +830,37 @@ fn apply_anthropic_stream_event(
                     if let Some(delta_text) = delta.get("text").and_then(Value::as_str) {
                         if !delta_text.is_empty() {
                             text.push_str(delta_text);
-                            on_delta(delta_text.to_string());
+                            on_event(StreamEvent::TextDelta {
+                                text: delta_text.to_string(),
+                            });
                         }
                     }
                 }
                 "input_json_delta" => {
                     if let Some(partial_json) = delta.get("partial_json").and_then(Value::as_str) {
                         tool_calls[index].partial_json.push_str(partial_json);
+                        if !partial_json.is_empty() {
+                            on_event(StreamEvent::ToolCallArgumentsDelta {
+                                index: anthropic_tool_call_ordinal(tool_calls, index),
+                                id: tool_calls[index].id.clone(),
+                                delta: partial_json.to_string(),
+                            });
+                        }
+                    }
+                }
+                "thinking_delta" => {
+                    if let Some(thinking) = delta.get("thinking").and_then(Value::as_str) {
+                        tool_calls[index].thinking.push_str(thinking);
+                        if !thinking.is_empty() {
+                            on_event(StreamEvent::ReasoningDelta {
+                                text: thinking.to_string(),
+                            });
+                        }
+                    }
+                }
+                "signature_delta" => {
+                    if let Some(signature) = delta.get("signature").and_then(Value::as_str) {
+                        tool_calls[index].signature.push_str(signature);
                     }
                 }
                 _ => {}
@@ -742,13 +900,37 @@ fn apply_anthropic_stream_event(
     Ok(())
 }
 
+/// Maps an Anthropic content block index to the tool call's position within the turn.
+fn anthropic_tool_call_ordinal(blocks: &[AnthropicContentBlockAccumulator], index: usize) -> usize {
+    blocks[..index]
+        .iter()
+        .filter(|block| block.kind == "tool_use")
+        .count()
+}
+
 fn finalize_anthropic_stream_response(
     text: String,
-    tool_calls: Vec<AnthropicToolUseAccumulator>,
+    tool_calls: Vec<AnthropicContentBlockAccumulator>,
     finish_reason: Option<String>,
     usage: ChatUsage,
 ) -> ChatResponse {
     let mut blocks = Vec::new();
+    for block in &tool_calls {
+        match block.kind.as_str() {
+            "thinking" => {
+                let signature = (!block.signature.is_empty()).then(|| block.signature.clone());
+                blocks.push(ContentBlock::thinking(
+                    ANTHROPIC_PROVIDER,
+                    block.thinking.clone(),
+                    signature,
+                ));
+            }
+            "redacted_thinking" if !block.signature.is_empty() => {
+                blocks.push(redacted_thinking_block(block.signature.clone()));
+            }
+            _ => {}
+        }
+    }
     if !text.trim().is_empty() {
         blocks.push(ContentBlock::Text { text });
     }
@@ -810,6 +992,13 @@ enum AnthropicContent {
     Image { source: AnthropicMediaSource },
     #[serde(rename = "audio")]
     Audio { source: AnthropicMediaSource },
+    #[serde(rename = "thinking")]
+    Thinking {
+        thinking: String,
+        signature: Option<String>,
+    },
+    #[serde(rename = "redacted_thinking")]
+    RedactedThinking { data: String },
     #[serde(other)]
     Other,
 }
@@ -837,11 +1026,14 @@ struct AnthropicUsage {
 }
 
 #[derive(Debug, Default)]
-struct AnthropicToolUseAccumulator {
+struct AnthropicContentBlockAccumulator {
+    kind: String,
     id: String,
     name: String,
     input: Option<Value>,
     partial_json: String,
+    thinking: String,
+    signature: String,
 }
 
 #[cfg(test)]
@@ -854,8 +1046,8 @@ mod tests {
         finalize_anthropic_stream_response, parse_messages_response,
     };
     use crate::{
-        ChatRequest, ContentBlock, Message, MessageRole, PromptCacheConfig, ToolChoice,
-        ToolDefinition,
+        ChatRequest, ContentBlock, Message, MessageRole, PromptCacheConfig, StreamEvent,
+        ToolChoice, ToolDefinition,
     };
 
     #[test]
@@ -882,6 +1074,8 @@ mod tests {
             max_tokens: Some(512),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -913,6 +1107,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: PromptCacheConfig::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -939,6 +1135,8 @@ mod tests {
                 retention: Some("5m".to_string()),
                 google_cached_content: None,
             },
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -961,6 +1159,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -989,6 +1189,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -1006,6 +1208,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -1066,6 +1270,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_messages_request_body(&request);
@@ -1108,16 +1314,217 @@ mod tests {
             .any(|block| matches!(block, ContentBlock::Audio { .. })));
     }
 
+    #[test]
+    fn functional_serializes_thinking_budget_and_replays_signed_thinking_for_anthropic() {
+        let request = ChatRequest {
+            model: "claude-sonnet-4-20250514".to_string(),
+            messages: vec![
+                Message::user("Read file"),
+                Message::assistant_blocks(vec![
+                    ContentBlock::thinking("anthropic", "need to read", Some("sig-1".to_string())),
+                    ContentBlock::Thinking {
+                        text: String::new(),
+                        signature: Some("opaque".to_string()),
+                        id: None,
+                        redacted: true,
+                        provider: Some("anthropic".to_string()),
+                    },
+                    ContentBlock::thinking("openai", "foreign reasoning", None),
+                    ContentBlock::ToolCall {
+                        id: "toolu_1".to_string(),
+                        name: "read".to_string(),
+                        arguments: json!({ "path": "README.md" }),
+                    },
+                ]),
+                Message::tool_result("toolu_1", "read", "done", false),
+            ],
+            tools: vec![ToolDefinition {
+                name: "read".to_string(),
+                description: "Read file".to_string(),
+                parameters: json!({"type":"object"}),
+            }],
+            tool_choice: Some(ToolChoice::Required),
+            json_mode: false,
+            max_tokens: Some(1_000),
+            temperature: Some(0.2),
+            prompt_cache: Default::default(),
+            reasoning: crate::ReasoningConfig {
+                budget_tokens: Some(512),
+                effort: None,
+            },
+            model_tier: None,
+        };
+
+        let body = build_messages_request_body(&request);
+        assert_eq!(body["thinking"]["type"], "enabled");
+        assert_eq!(body["thinking"]["budget_tokens"], 1_024);
+        assert!(body["max_tokens"].as_u64().expect("max tokens") > 1_024);
+        assert_eq!(body["tool_choice"]["type"], "auto");
+        assert!(body.get("temperature").is_none());
+
+        let assistant = body["messages"][1]["content"]
+            .as_array()
+            .expect("assistant content");
+        assert_eq!(assistant.len(), 3);
+        assert_eq!(assistant[0]["type"], "thinking");
+        assert_eq!(assistant[0]["thinking"], "need to read");
+        assert_eq!(assistant[0]["signature"], "sig-1");
+        assert_eq!(assistant[1]["type"], "redacted_thinking");
+        assert_eq!(assistant[1]["data"], "opaque");
+        assert_eq!(assistant[2]["type"], "tool_use");
+    }
+
+    #[test]
+    fn functional_parses_thinking_and_redacted_thinking_response_for_anthropic() {
+        let raw = r#"{
+            "content": [
+                {"type":"thinking","thinking":"Let me check.","signature":"sig-1"},
+                {"type":"redacted_thinking","data":"opaque"},
+                {"type":"text","text":"Done."}
+            ],
+            "stop_reason":"end_turn",
+            "usage":{"input_tokens":10,"output_tokens":3}
+        }"#;
+
+        let response = parse_messages_response(raw).expect("response should parse");
+        assert_eq!(response.message.text_content(), "Done.");
+        assert_eq!(response.message.thinking_content(), "Let me check.");
+        assert!(matches!(
+            &response.message.content[0],
+            ContentBlock::Thinking { signature: Some(signature), redacted: false, .. }
+                if signature == "sig-1"
+        ));
+        assert!(matches!(
+            &response.message.content[1],
+            ContentBlock::Thinking { signature: Some(signature), redacted: true, .. }
+                if signature == "opaque"
+        ));
+    }
+
+    #[test]
+    fn functional_stream_event_parsing_accumulates_thinking_deltas() {
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        let mut finish_reason = None;
+        let mut usage = crate::ChatUsage::default();
+
+        for (event, data) in [
+            (
+                "content_block_start",
+                r#"{"type":"content_block_start","index":0,"content_block":{"type":"thinking","thinking":""}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"Plan "}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"ahead."}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":0,"delta":{"type":"signature_delta","signature":"sig-9"}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"Answer"}}"#,
+            ),
+        ] {
+            apply_anthropic_stream_event(
+                Some(event.to_string()),
+                data,
+                &sink,
+                &mut text,
+                &mut tool_calls,
+                &mut finish_reason,
+                &mut usage,
+            )
+            .expect("stream event parses");
+        }
+
+        let response = finalize_anthropic_stream_response(text, tool_calls, finish_reason, usage);
+        assert_eq!(response.message.text_content(), "Answer");
+        assert_eq!(response.message.thinking_content(), "Plan ahead.");
+        assert!(matches!(
+            &response.message.content[0],
+            ContentBlock::Thinking { signature: Some(signature), .. } if signature == "sig-9"
+        ));
+    }
+
+    #[test]
+    fn functional_stream_event_parsing_emits_typed_reasoning_and_tool_call_events() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let sink: crate::StreamEventHandler = Arc::new(move |event| {
+            sink_events.lock().expect("event lock").push(event);
+        });
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        let mut finish_reason = None;
+        let mut usage = crate::ChatUsage::default();
+
+        for (event, data) in [
+            (
+                "content_block_start",
+                r#"{"type":"content_block_start","index":0,"content_block":{"type":"thinking","thinking":""}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"Check file."}}"#,
+            ),
+            (
+                "content_block_start",
+                r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"read","input":{}}}"#,
+            ),
+            (
+                "content_block_delta",
+                r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"path\":\"README.md\"}"}}"#,
+            ),
+        ] {
+            apply_anthropic_stream_event(
+                Some(event.to_string()),
+                data,
+                &sink,
+                &mut text,
+                &mut tool_calls,
+                &mut finish_reason,
+                &mut usage,
+            )
+            .expect("stream event parses");
+        }
+
+        let events = events.lock().expect("event lock").clone();
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::ReasoningDelta {
+                    text: "Check file.".to_string(),
+                },
+                StreamEvent::ToolCallStart {
+                    index: 0,
+                    id: "toolu_1".to_string(),
+                    name: "read".to_string(),
+                },
+                StreamEvent::ToolCallArgumentsDelta {
+                    index: 0,
+                    id: "toolu_1".to_string(),
+                    delta: r#"{"path":"README.md"}"#.to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn functional_stream_event_parsing_emits_text_and_tool_payload() {
         let streamed = Arc::new(Mutex::new(String::new()));
         let sink_streamed = streamed.clone();
-        let sink: crate::StreamDeltaHandler = Arc::new(move |delta: String| {
+        let sink = crate::stream_event_handler_from_deltas(Arc::new(move |delta: String| {
             sink_streamed
                 .lock()
                 .expect("stream lock")
                 .push_str(delta.as_str());
-        });
+        }));
 
         let mut text = String::new();
         let mut tool_calls = Vec::new();
@@ -1181,7 +1588,7 @@ mod tests {
 
     #[test]
     fn regression_stream_event_parsing_surfaces_error_events() {
-        let sink: crate::StreamDeltaHandler = Arc::new(|_delta: String| {});
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
         let mut text = String::new();
         let mut tool_calls = Vec::new();
         let mut finish_reason = None;
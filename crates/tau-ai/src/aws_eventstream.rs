@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+
+use crate::TauAiError;
+
+// total length (4) + headers length (4) + prelude CRC (4).
+const PRELUDE_LEN: usize = 12;
+const MESSAGE_CRC_LEN: usize = 4;
+const MAX_MESSAGE_LEN: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One decoded `application/vnd.amazon.eventstream` message.
+pub(crate) struct EventStreamMessage {
+    pub headers: BTreeMap<String, String>,
+    pub payload: Vec<u8>,
+}
+
+impl EventStreamMessage {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Default)]
+/// Incremental decoder for AWS event-stream framing.
+///
+/// Bytes may arrive split at arbitrary boundaries; complete messages are
+/// returned as soon as their trailing CRC has been received and verified.
+pub(crate) struct EventStreamDecoder {
+    buffer: Vec<u8>,
+}
+
+impl EventStreamDecoder {
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<EventStreamMessage>, TauAiError> {
+        self.buffer.extend_from_slice(bytes);
+        let mut messages = Vec::new();
+        while let Some(message) = self.next_message()? {
+            messages.push(message);
+        }
+        Ok(messages)
+    }
+
+    pub fn has_pending_bytes(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    fn next_message(&mut self) -> Result<Option<EventStreamMessage>, TauAiError> {
+        if self.buffer.len() < PRELUDE_LEN {
+            return Ok(None);
+        }
+        let total_len = read_u32(&self.buffer[0..4]) as usize;
+        let headers_len = read_u32(&self.buffer[4..8]) as usize;
+        let prelude_crc = read_u32(&self.buffer[8..12]);
+        if crc32(&self.buffer[0..8]) != prelude_crc {
+            return Err(invalid_frame("prelude checksum mismatch"));
+        }
+        if total_len < PRELUDE_LEN + MESSAGE_CRC_LEN + headers_len || total_len > MAX_MESSAGE_LEN {
+            return Err(invalid_frame(&format!(
+                "invalid lengths total={total_len} headers={headers_len}"
+            )));
+        }
+        if self.buffer.len() < total_len {
+            return Ok(None);
+        }
+
+        let frame = self.buffer.drain(..total_len).collect::<Vec<_>>();
+        let message_crc = read_u32(&frame[total_len - MESSAGE_CRC_LEN..]);
+        if crc32(&frame[..total_len - MESSAGE_CRC_LEN]) != message_crc {
+            return Err(invalid_frame("message checksum mismatch"));
+        }
+        let headers = decode_headers(&frame[PRELUDE_LEN..PRELUDE_LEN + headers_len])?;
+        let payload = frame[PRELUDE_LEN + headers_len..total_len - MESSAGE_CRC_LEN].to_vec();
+        Ok(Some(EventStreamMessage { headers, payload }))
+    }
+}
+
+fn decode_headers(mut bytes: &[u8]) -> Result<BTreeMap<String, String>, TauAiError> {
+    let mut headers = BTreeMap::new();
+    while !bytes.is_empty() {
+        let name_len = usize::from(bytes[0]);
+        let name = take(&mut bytes, 1 + name_len)?;
+        let name = String::from_utf8_lossy(&name[1..]).to_string();
+        let value_type = take(&mut bytes, 1)?[0];
+        let value = match value_type {
+            0 => "true".to_string(),
+            1 => "false".to_string(),
+            2 => i8::from_be_bytes([take(&mut bytes, 1)?[0]]).to_string(),
+            3 => i16::from_be_bytes(fixed(take(&mut bytes, 2)?)).to_string(),
+            4 => i32::from_be_bytes(fixed(take(&mut bytes, 4)?)).to_string(),
+            5 | 8 => i64::from_be_bytes(fixed(take(&mut bytes, 8)?)).to_string(),
+            6 | 7 => {
+                let len = usize::from(u16::from_be_bytes(fixed(take(&mut bytes, 2)?)));
+                String::from_utf8_lossy(take(&mut bytes, len)?).to_string()
+            }
+            9 => take(&mut bytes, 16)?
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+            other => {
+                return Err(invalid_frame(&format!(
+                    "unsupported header value type {other} for '{name}'"
+                )))
+            }
+        };
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], TauAiError> {
+    if bytes.len() < len {
+        return Err(invalid_frame("truncated header block"));
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn fixed<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut out = [0u8; N];
+    out.copy_from_slice(bytes);
+    out
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes(fixed(bytes))
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+fn invalid_frame(reason: &str) -> TauAiError {
+    TauAiError::InvalidResponse(format!("invalid AWS event-stream frame: {reason}"))
+}
+
+#[cfg(test)]
+pub(crate) fn encode_event_stream_message(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+    let mut header_bytes = Vec::new();
+    for (name, value) in headers {
+        header_bytes.push(name.len() as u8);
+        header_bytes.extend_from_slice(name.as_bytes());
+        header_bytes.push(7);
+        header_bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        header_bytes.extend_from_slice(value.as_bytes());
+    }
+    let total_len = PRELUDE_LEN + header_bytes.len() + payload.len() + MESSAGE_CRC_LEN;
+    let mut frame = Vec::with_capacity(total_len);
+    frame.extend_from_slice(&(total_len as u32).to_be_bytes());
+    frame.extend_from_slice(&(header_bytes.len() as u32).to_be_bytes());
+    let prelude_crc = crc32(&frame);
+    frame.extend_from_slice(&prelude_crc.to_be_bytes());
+    frame.extend_from_slice(&header_bytes);
+    frame.extend_from_slice(payload);
+    let message_crc = crc32(&frame);
+    frame.extend_from_slice(&message_crc.to_be_bytes());
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_event_stream_message, EventStreamDecoder};
+
+    #[test]
+    fn unit_decoder_reassembles_messages_split_across_chunks() {
+        let mut bytes = encode_event_stream_message(
+            &[
+                (":event-type", "contentBlockDelta"),
+                (":message-type", "event"),
+            ],
+            br#"{"contentBlockIndex":0,"delta":{"text":"Hi"}}"#,
+        );
+        bytes.extend(encode_event_stream_message(
+            &[(":event-type", "messageStop"), (":message-type", "event")],
+            br#"{"stopReason":"end_turn"}"#,
+        ));
+
+        let mut decoder = EventStreamDecoder::default();
+        let mut messages = Vec::new();
+        for chunk in bytes.chunks(7) {
+            messages.extend(decoder.push(chunk).expect("decode chunk"));
+        }
+
+        assert!(!decoder.has_pending_bytes());
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].header(":event-type"), Some("contentBlockDelta"));
+        assert_eq!(
+            messages[1].payload,
+            br#"{"stopReason":"end_turn"}"#.to_vec()
+        );
+    }
+
+    #[test]
+    fn regression_decoder_rejects_corrupted_message_checksum() {
+        let mut bytes = encode_event_stream_message(&[(":event-type", "messageStop")], b"{}");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let error = EventStreamDecoder::default()
+            .push(&bytes)
+            .expect_err("corrupted frame must fail");
+        assert!(error.to_string().contains("message checksum mismatch"));
+    }
+}
@@ -0,0 +1,283 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+const SIGV4_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Static AWS credentials used to sign Bedrock requests.
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SigV4Scope<'a> {
+    pub region: &'a str,
+    pub service: &'a str,
+}
+
+/// Returns the headers (`x-amz-date`, optional `x-amz-security-token`, and
+/// `authorization`) that sign `method url` with `payload` at `now`.
+pub(crate) fn sign_request(
+    credentials: &AwsCredentials,
+    scope: &SigV4Scope<'_>,
+    method: &str,
+    url: &reqwest::Url,
+    payload: &[u8],
+    now: DateTime<Utc>,
+) -> Vec<(String, String)> {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let mut headers = vec![
+        ("host".to_string(), canonical_host(url)),
+        ("x-amz-date".to_string(), amz_date.clone()),
+    ];
+    if let Some(token) = credentials
+        .session_token
+        .as_deref()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    {
+        headers.push(("x-amz-security-token".to_string(), token.to_string()));
+    }
+    headers.sort_by(|left, right| left.0.cmp(&right.0));
+
+    let canonical_headers = headers
+        .iter()
+        .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+        .collect::<String>();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        method.to_ascii_uppercase(),
+        canonical_uri(url.path()),
+        canonical_query(url),
+        canonical_headers,
+        signed_headers,
+        hex_sha256(payload),
+    );
+
+    let credential_scope = format!("{date}/{}/{}/aws4_request", scope.region, scope.service);
+    let string_to_sign = format!(
+        "{SIGV4_ALGORITHM}\n{amz_date}\n{credential_scope}\n{}",
+        hex_sha256(canonical_request.as_bytes())
+    );
+
+    let signing_key = [scope.region, scope.service, "aws4_request"].iter().fold(
+        hmac_sha256(
+            format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+            date.as_bytes(),
+        ),
+        |key, part| hmac_sha256(&key, part.as_bytes()),
+    );
+    let signature = hex_encode(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let mut signed = headers
+        .into_iter()
+        .filter(|(name, _)| name != "host")
+        .collect::<Vec<_>>();
+    signed.push((
+        "authorization".to_string(),
+        format!(
+            "{SIGV4_ALGORITHM} Credential={}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            credentials.access_key_id.trim()
+        ),
+    ));
+    signed
+}
+
+/// Percent-encodes `value` with the RFC 3986 unreserved set used by SigV4.
+pub(crate) fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn canonical_host(url: &reqwest::Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
+// Non-S3 services sign the already-encoded path encoded a second time.
+fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    uri_encode(path, false)
+}
+
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut pairs = url
+        .query_pairs()
+        .map(|(key, value)| (uri_encode(&key, true), uri_encode(&value, true)))
+        .collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+    hex_encode(&Sha256::digest(data))
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_json::Value;
+
+    use super::{canonical_uri, sign_request, uri_encode, AwsCredentials, SigV4Scope};
+
+    const CONVERSE_FIXTURE: &str = include_str!("../testdata/bedrock/sigv4-converse.json");
+
+    fn example_credentials(session_token: Option<&str>) -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: session_token.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn conformance_sign_request_matches_aws_get_vanilla_vector() {
+        let url = reqwest::Url::parse("https://example.amazonaws.com/").expect("url");
+        let now = Utc
+            .with_ymd_and_hms(2015, 8, 30, 12, 36, 0)
+            .single()
+            .expect("timestamp");
+        let headers = sign_request(
+            &example_credentials(None),
+            &SigV4Scope {
+                region: "us-east-1",
+                service: "service",
+            },
+            "GET",
+            &url,
+            b"",
+            now,
+        );
+
+        assert_eq!(
+            headers,
+            vec![
+                ("x-amz-date".to_string(), "20150830T123600Z".to_string()),
+                (
+                    "authorization".to_string(),
+                    "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=host;x-amz-date, Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_sign_request_signs_session_token_and_double_encodes_model_path() {
+        let url = reqwest::Url::parse(
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-v2%3A1/converse",
+        )
+        .expect("url");
+        let now = Utc
+            .with_ymd_and_hms(2025, 1, 2, 3, 4, 5)
+            .single()
+            .expect("timestamp");
+        let headers = sign_request(
+            &example_credentials(Some("session-token")),
+            &SigV4Scope {
+                region: "us-east-1",
+                service: "bedrock",
+            },
+            "POST",
+            &url,
+            br#"{"messages":[]}"#,
+            now,
+        );
+
+        assert_eq!(
+            headers[0],
+            ("x-amz-date".to_string(), "20250102T030405Z".to_string())
+        );
+        assert_eq!(
+            headers[1],
+            (
+                "x-amz-security-token".to_string(),
+                "session-token".to_string()
+            )
+        );
+        assert!(headers[2].1.contains(
+            "Credential=AKIDEXAMPLE/20250102/us-east-1/bedrock/aws4_request, SignedHeaders=host;x-amz-date;x-amz-security-token, Signature="
+        ));
+        assert_eq!(
+            uri_encode(url.path(), false),
+            "/model/anthropic.claude-v2%253A1/converse"
+        );
+    }
+
+    #[test]
+    fn conformance_sign_request_matches_recorded_converse_fixture() {
+        let fixture: Value = serde_json::from_str(CONVERSE_FIXTURE).expect("fixture");
+        let text = |key: &str| fixture[key].as_str().expect(key).to_string();
+        let url = reqwest::Url::parse(&text("url")).expect("url");
+        let now = DateTime::parse_from_rfc3339(&text("timestamp"))
+            .expect("timestamp")
+            .with_timezone(&Utc);
+
+        let headers = sign_request(
+            &AwsCredentials {
+                access_key_id: text("access_key_id"),
+                secret_access_key: text("secret_access_key"),
+                session_token: Some(text("session_token")),
+            },
+            &SigV4Scope {
+                region: &text("region"),
+                service: &text("service"),
+            },
+            &text("method"),
+            &url,
+            text("body").as_bytes(),
+            now,
+        );
+
+        let expected = fixture["expected_headers"]
+            .as_array()
+            .expect("expected_headers")
+            .iter()
+            .map(|pair| {
+                (
+                    pair[0].as_str().expect("name").to_string(),
+                    pair[1].as_str().expect("value").to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(headers, expected);
+        assert_eq!(canonical_uri(url.path()), text("expected_canonical_uri"));
+    }
+}
@@ -0,0 +1,1109 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::header::CONTENT_TYPE;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::time::sleep;
+
+use crate::{
+    aws_eventstream::{EventStreamDecoder, EventStreamMessage},
+    aws_sigv4::{sign_request, uri_encode, AwsCredentials, SigV4Scope},
+    genai_spans::{trace_propagation_headers, ChatCompletionSpan},
+    retry::{
+        is_retryable_http_error, new_request_id, parse_retry_after_ms, provider_retry_delay_ms,
+        retry_budget_allows_delay, should_retry_status,
+    },
+    types::{
+        emit_stream_completion_events, emit_stream_events_for_response,
+        stream_event_handler_from_deltas,
+    },
+    ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, MediaSource, Message,
+    MessageRole, StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError, ToolChoice,
+    ToolDefinition,
+};
+
+const BEDROCK_PROVIDER: &str = "bedrock";
+const BEDROCK_SIGNING_SERVICE: &str = "bedrock";
+const BEDROCK_EVENT_STREAM_CONTENT_TYPE: &str = "application/vnd.amazon.eventstream";
+const BEDROCK_MIN_THINKING_BUDGET_TOKENS: u32 = 1_024;
+
+/// Returns the regional Bedrock runtime endpoint for `region`.
+pub fn default_bedrock_api_base(region: &str) -> String {
+    format!("https://bedrock-runtime.{}.amazonaws.com", region.trim())
+}
+
+#[derive(Debug, Clone)]
+/// Public struct `BedrockConfig` used across Tau components.
+pub struct BedrockConfig {
+    pub api_base: String,
+    pub region: String,
+    pub credentials: AwsCredentials,
+    pub request_timeout_ms: u64,
+    pub max_retries: usize,
+    pub retry_budget_ms: u64,
+    pub retry_jitter: bool,
+}
+
+#[derive(Debug, Clone)]
+/// Client for the AWS Bedrock Converse API, signed with SigV4.
+pub struct BedrockClient {
+    client: reqwest::Client,
+    config: BedrockConfig,
+}
+
+impl BedrockClient {
+    pub fn new(config: BedrockConfig) -> Result<Self, TauAiError> {
+        if config.credentials.access_key_id.trim().is_empty()
+            || config.credentials.secret_access_key.trim().is_empty()
+        {
+            return Err(TauAiError::MissingApiKey);
+        }
+        if config.region.trim().is_empty() {
+            return Err(TauAiError::InvalidResponse(
+                "bedrock region must not be empty".to_string(),
+            ));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_millis(
+                config.request_timeout_ms.max(1),
+            ))
+            .build()?;
+
+        Ok(Self { client, config })
+    }
+
+    fn converse_url(&self, model: &str, stream: bool) -> Result<reqwest::Url, TauAiError> {
+        let base = self.config.api_base.trim_end_matches('/');
+        let operation = if stream {
+            "converse-stream"
+        } else {
+            "converse"
+        };
+        let raw = format!("{base}/model/{}/{operation}", uri_encode(model, true));
+        reqwest::Url::parse(&raw).map_err(|error| {
+            TauAiError::InvalidResponse(format!("invalid Bedrock endpoint '{raw}': {error}"))
+        })
+    }
+}
+
+#[async_trait]
+impl LlmClient for BedrockClient {
+    async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, None).await
+    }
+
+    async fn complete_with_stream(
+        &self,
+        request: ChatRequest,
+        on_delta: Option<StreamDeltaHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, on_event).await
+    }
+}
+
+impl BedrockClient {
+    async fn complete_with_mode(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        ChatCompletionSpan::start("aws.bedrock", &request)
+            .instrument(self.complete_untraced(request, on_event))
+            .await
+    }
+
+    async fn complete_untraced(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        let body = serde_json::to_vec(&build_converse_request_body(&request))?;
+        let stream_mode = on_event.is_some();
+        let url = self.converse_url(&request.model, stream_mode)?;
+        let scope = SigV4Scope {
+            region: self.config.region.trim(),
+            service: BEDROCK_SIGNING_SERVICE,
+        };
+        let started = std::time::Instant::now();
+        let max_retries = self.config.max_retries;
+
+        for attempt in 0..=max_retries {
+            let request_id = new_request_id();
+            let mut request_builder = self
+                .client
+                .post(url.clone())
+                .header(CONTENT_TYPE, "application/json")
+                .header("x-tau-request-id", request_id)
+                .header("x-tau-retry-attempt", attempt.to_string())
+                .headers(trace_propagation_headers());
+            for (name, value) in sign_request(
+                &self.config.credentials,
+                &scope,
+                "POST",
+                &url,
+                &body,
+                chrono::Utc::now(),
+            ) {
+                request_builder = request_builder.header(name, value);
+            }
+            let response = request_builder.body(body.clone()).send().await;
+
+            match response {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        if let Some(event_handler) = on_event.clone() {
+                            let is_event_stream = response
+                                .headers()
+                                .get(CONTENT_TYPE)
+                                .and_then(|value| value.to_str().ok())
+                                .map(|value| {
+                                    value
+                                        .to_ascii_lowercase()
+                                        .contains(BEDROCK_EVENT_STREAM_CONTENT_TYPE)
+                                })
+                                .unwrap_or(false);
+                            if is_event_stream {
+                                return parse_converse_stream_response(response, event_handler)
+                                    .await;
+                            }
+
+                            let raw = response.text().await?;
+                            let parsed = parse_converse_response(&raw)?;
+                            emit_stream_events_for_response(&event_handler, &parsed);
+                            return Ok(parsed);
+                        }
+
+                        let raw = response.text().await?;
+                        return parse_converse_response(&raw);
+                    }
+
+                    let retry_after_ms = parse_retry_after_ms(response.headers());
+                    let raw = response.text().await?;
+                    if attempt < max_retries && should_retry_status(status.as_u16()) {
+                        let backoff_ms = provider_retry_delay_ms(
+                            attempt,
+                            self.config.retry_jitter,
+                            retry_after_ms,
+                        );
+                        let elapsed_ms = started.elapsed().as_millis() as u64;
+                        if retry_budget_allows_delay(
+                            elapsed_ms,
+                            backoff_ms,
+                            self.config.retry_budget_ms,
+                        ) {
+                            sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                            continue;
+                        }
+                    }
+
+                    return Err(TauAiError::HttpStatus {
+                        status: status.as_u16(),
+                        body: raw,
+                    });
+                }
+                Err(error) => {
+                    if attempt < max_retries && is_retryable_http_error(&error) {
+                        let backoff_ms =
+                            provider_retry_delay_ms(attempt, self.config.retry_jitter, None);
+                        let elapsed_ms = started.elapsed().as_millis() as u64;
+                        if retry_budget_allows_delay(
+                            elapsed_ms,
+                            backoff_ms,
+                            self.config.retry_budget_ms,
+                        ) {
+                            sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                            continue;
+                        }
+                    }
+                    return Err(TauAiError::Http(error));
+                }
+            }
+        }
+
+        Err(TauAiError::InvalidResponse(
+            "request retry loop terminated unexpectedly".to_string(),
+        ))
+    }
+}
+
+fn build_converse_request_body(request: &ChatRequest) -> Value {
+    let mut body = json!({
+        "messages": to_bedrock_messages(&request.messages),
+    });
+
+    let mut system_segments = Vec::new();
+    if request.json_mode {
+        system_segments.push(
+            "Respond with valid JSON only. Do not include markdown code fences or commentary."
+                .to_string(),
+        );
+    }
+    system_segments.extend(
+        request
+            .messages
+            .iter()
+            .filter(|message| message.role == MessageRole::System)
+            .map(Message::text_content)
+            .filter(|text| !text.trim().is_empty()),
+    );
+    if !system_segments.is_empty() {
+        body["system"] = json!([{ "text": system_segments.join("\n\n") }]);
+    }
+
+    // Extended thinking is only understood by Anthropic models hosted on Bedrock.
+    let thinking_budget = request
+        .reasoning
+        .resolved_budget_tokens()
+        .filter(|_| is_bedrock_anthropic_model(&request.model))
+        .map(|budget| budget.max(BEDROCK_MIN_THINKING_BUDGET_TOKENS));
+
+    let mut inference_config = serde_json::Map::new();
+    if let Some(max_tokens) = request.max_tokens {
+        inference_config.insert("maxTokens".to_string(), json!(max_tokens));
+    }
+    if let Some(temperature) = request.temperature.filter(|_| thinking_budget.is_none()) {
+        inference_config.insert("temperature".to_string(), json!(temperature));
+    }
+    if let Some(budget_tokens) = thinking_budget {
+        body["additionalModelRequestFields"] = json!({
+            "thinking": {
+                "type": "enabled",
+                "budget_tokens": budget_tokens,
+            }
+        });
+        let max_tokens = request.max_tokens.unwrap_or_default();
+        if max_tokens <= budget_tokens {
+            inference_config.insert(
+                "maxTokens".to_string(),
+                json!(budget_tokens.saturating_add(BEDROCK_MIN_THINKING_BUDGET_TOKENS)),
+            );
+        }
+    }
+    if !inference_config.is_empty() {
+        body["inferenceConfig"] = Value::Object(inference_config);
+    }
+
+    if !request.tools.is_empty() {
+        let mut tool_config = json!({
+            "tools": to_bedrock_tools(&request.tools),
+        });
+        if let Some(tool_choice) = request
+            .tool_choice
+            .as_ref()
+            .and_then(to_bedrock_tool_choice)
+        {
+            // Extended thinking only supports automatic tool selection.
+            tool_config["toolChoice"] = if thinking_budget.is_some() {
+                json!({ "auto": {} })
+            } else {
+                tool_choice
+            };
+        }
+        body["toolConfig"] = tool_config;
+    }
+
+    body
+}
+
+fn is_bedrock_anthropic_model(model: &str) -> bool {
+    model.to_ascii_lowercase().contains("anthropic.claude")
+}
+
+fn to_bedrock_tool_choice(tool_choice: &ToolChoice) -> Option<Value> {
+    match tool_choice {
+        ToolChoice::Auto => Some(json!({ "auto": {} })),
+        ToolChoice::None => None,
+        ToolChoice::Required => Some(json!({ "any": {} })),
+        ToolChoice::Tool { name } => Some(json!({ "tool": { "name": name } })),
+    }
+}
+
+fn to_bedrock_tools(tools: &[ToolDefinition]) -> Value {
+    Value::Array(
+        tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "toolSpec": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "inputSchema": { "json": tool.parameters },
+                    }
+                })
+            })
+            .collect(),
+    )
+}
+
+fn to_bedrock_messages(messages: &[Message]) -> Value {
+    // Converse requires strictly alternating roles, so consecutive user-side
+    // turns (for example several tool results) are folded into one message.
+    let mut converted: Vec<(&'static str, Vec<Value>)> = Vec::new();
+    for message in messages {
+        let (role, parts) = match message.role {
+            MessageRole::System => continue,
+            MessageRole::User => ("user", to_bedrock_content_parts(message, false)),
+            MessageRole::Assistant => ("assistant", to_bedrock_content_parts(message, true)),
+            MessageRole::Tool => ("user", vec![to_bedrock_tool_result(message)]),
+        };
+        if parts.is_empty() {
+            continue;
+        }
+        match converted.last_mut() {
+            Some((last_role, last_parts)) if *last_role == role => last_parts.extend(parts),
+            _ => converted.push((role, parts)),
+        }
+    }
+
+    Value::Array(
+        converted
+            .into_iter()
+            .map(|(role, content)| json!({ "role": role, "content": content }))
+            .collect(),
+    )
+}
+
+fn to_bedrock_tool_result(message: &Message) -> Value {
+    let Some(tool_call_id) = message.tool_call_id.as_deref() else {
+        return json!({ "text": "invalid tool result message: missing tool_call_id" });
+    };
+    json!({
+        "toolResult": {
+            "toolUseId": tool_call_id,
+            "content": [{ "text": message.text_content() }],
+            "status": if message.is_error { "error" } else { "success" },
+        }
+    })
+}
+
+fn to_bedrock_content_parts(message: &Message, allow_tool_calls: bool) -> Vec<Value> {
+    let mut parts = Vec::new();
+    for block in &message.content {
+        match block {
+            ContentBlock::Text { text } => {
+                if !text.trim().is_empty() {
+                    parts.push(json!({ "text": text }));
+                }
+            }
+            ContentBlock::ToolCall {
+                id,
+                name,
+                arguments,
+            } if allow_tool_calls => {
+                parts.push(json!({
+                    "toolUse": {
+                        "toolUseId": id,
+                        "name": name,
+                        "input": arguments,
+                    }
+                }));
+            }
+            ContentBlock::ToolCall { .. } => {}
+            ContentBlock::Image { source } => match source {
+                MediaSource::Base64 { mime_type, data } => {
+                    parts.push(json!({
+                        "image": {
+                            "format": bedrock_image_format(mime_type),
+                            "source": { "bytes": data },
+                        }
+                    }));
+                }
+                MediaSource::Url { url } => {
+                    parts.push(json!({ "text": format!("[tau-image:url:{url}]") }));
+                }
+            },
+            ContentBlock::Audio { source } => {
+                let descriptor = match source {
+                    MediaSource::Url { url } => format!("url:{url}"),
+                    MediaSource::Base64 { mime_type, data } => {
+                        format!("base64:{mime_type}:{}bytes", data.len())
+                    }
+                };
+                parts.push(json!({ "text": format!("[tau-audio:{descriptor}]") }));
+            }
+            ContentBlock::Thinking {
+                text,
+                signature: Some(signature),
+                redacted,
+                ..
+            } if allow_tool_calls && block.is_thinking_from(BEDROCK_PROVIDER) => {
+                // Reasoning must be replayed verbatim so multi-turn tool use keeps
+                // the model's prior thinking attached.
+                if *redacted {
+                    parts.push(json!({ "reasoningContent": { "redactedContent": signature } }));
+                } else {
+                    parts.push(json!({
+                        "reasoningContent": {
+                            "reasoningText": { "text": text, "signature": signature }
+                        }
+                    }));
+                }
+            }
+            ContentBlock::Thinking { .. } => {}
+        }
+    }
+    parts
+}
+
+fn bedrock_image_format(mime_type: &str) -> &str {
+    let subtype = mime_type
+        .rsplit_once('/')
+        .map(|(_, subtype)| subtype)
+        .unwrap_or(mime_type);
+    match subtype.to_ascii_lowercase().as_str() {
+        "jpg" | "jpeg" => "jpeg",
+        "gif" => "gif",
+        "webp" => "webp",
+        _ => "png",
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BedrockConverseResponse {
+    output: BedrockConverseOutput,
+    stop_reason: Option<String>,
+    usage: Option<BedrockUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BedrockConverseOutput {
+    message: Option<BedrockMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BedrockMessage {
+    #[serde(default)]
+    content: Vec<BedrockContent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BedrockContent {
+    text: Option<String>,
+    tool_use: Option<BedrockToolUse>,
+    reasoning_content: Option<BedrockReasoningContent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BedrockToolUse {
+    tool_use_id: String,
+    name: String,
+    #[serde(default)]
+    input: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BedrockReasoningContent {
+    reasoning_text: Option<BedrockReasoningText>,
+    redacted_content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BedrockReasoningText {
+    #[serde(default)]
+    text: String,
+    signature: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BedrockUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+    total_tokens: Option<u64>,
+    cache_read_input_tokens: Option<u64>,
+}
+
+impl BedrockUsage {
+    fn into_chat_usage(self) -> ChatUsage {
+        ChatUsage {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            total_tokens: self
+                .total_tokens
+                .unwrap_or(self.input_tokens + self.output_tokens),
+            cached_input_tokens: self.cache_read_input_tokens.unwrap_or_default(),
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
+        }
+    }
+}
+
+fn redacted_reasoning_block(data: String) -> ContentBlock {
+    ContentBlock::Thinking {
+        text: String::new(),
+        signature: Some(data),
+        id: None,
+        redacted: true,
+        provider: Some(BEDROCK_PROVIDER.to_string()),
+    }
+}
+
+fn parse_converse_response(raw: &str) -> Result<ChatResponse, TauAiError> {
+    let parsed: BedrockConverseResponse = serde_json::from_str(raw)?;
+
+    let mut reasoning = Vec::new();
+    let mut text_segments = Vec::new();
+    let mut tool_calls = Vec::new();
+    for part in parsed
+        .output
+        .message
+        .map(|message| message.content)
+        .unwrap_or_default()
+    {
+        if let Some(text) = part.text.filter(|text| !text.trim().is_empty()) {
+            text_segments.push(text);
+        }
+        if let Some(tool_use) = part.tool_use {
+            tool_calls.push(ContentBlock::ToolCall {
+                id: tool_use.tool_use_id,
+                name: tool_use.name,
+                arguments: if tool_use.input.is_null() {
+                    json!({})
+                } else {
+                    tool_use.input
+                },
+            });
+        }
+        if let Some(content) = part.reasoning_content {
+            if let Some(reasoning_text) = content.reasoning_text {
+                reasoning.push(ContentBlock::thinking(
+                    BEDROCK_PROVIDER,
+                    reasoning_text.text,
+                    reasoning_text.signature,
+                ));
+            } else if let Some(data) = content.redacted_content {
+                reasoning.push(redacted_reasoning_block(data));
+            }
+        }
+    }
+
+    let mut blocks = reasoning;
+    if !text_segments.is_empty() {
+        blocks.push(ContentBlock::Text {
+            text: text_segments.join("\n"),
+        });
+    }
+    blocks.extend(tool_calls);
+
+    Ok(ChatResponse {
+        message: Message::assistant_blocks(blocks),
+        finish_reason: parsed.stop_reason,
+        usage: parsed
+            .usage
+            .map(BedrockUsage::into_chat_usage)
+            .unwrap_or_default(),
+    })
+}
+
+async fn parse_converse_stream_response(
+    response: reqwest::Response,
+    on_event: StreamEventHandler,
+) -> Result<ChatResponse, TauAiError> {
+    let parsed = collect_converse_stream_response(response, &on_event).await?;
+    emit_stream_completion_events(&on_event, &parsed);
+    Ok(parsed)
+}
+
+async fn collect_converse_stream_response(
+    response: reqwest::Response,
+    on_event: &StreamEventHandler,
+) -> Result<ChatResponse, TauAiError> {
+    let mut stream = response.bytes_stream();
+    let mut decoder = EventStreamDecoder::default();
+    let mut state = BedrockStreamState::default();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        for message in decoder.push(chunk.as_ref())? {
+            state.apply(&message, on_event)?;
+        }
+    }
+    if decoder.has_pending_bytes() {
+        return Err(TauAiError::InvalidResponse(
+            "Bedrock event stream ended with a truncated frame".to_string(),
+        ));
+    }
+
+    Ok(state.finish())
+}
+
+#[derive(Debug)]
+enum BedrockBlockAccumulator {
+    Text(String),
+    ToolUse {
+        ordinal: usize,
+        id: String,
+        name: String,
+        input: String,
+    },
+    Reasoning {
+        text: String,
+        signature: String,
+        redacted: String,
+    },
+}
+
+#[derive(Debug, Default)]
+struct BedrockStreamState {
+    blocks: BTreeMap<u64, BedrockBlockAccumulator>,
+    tool_count: usize,
+    finish_reason: Option<String>,
+    usage: ChatUsage,
+}
+
+impl BedrockStreamState {
+    fn apply(
+        &mut self,
+        message: &EventStreamMessage,
+        on_event: &StreamEventHandler,
+    ) -> Result<(), TauAiError> {
+        let message_type = message.header(":message-type").unwrap_or("event");
+        if message_type != "event" {
+            let kind = message
+                .header(":exception-type")
+                .or_else(|| message.header(":error-code"))
+                .unwrap_or("unknown");
+            return Err(TauAiError::InvalidResponse(format!(
+                "Bedrock stream {message_type} '{kind}': {}",
+                String::from_utf8_lossy(&message.payload)
+            )));
+        }
+        if message.payload.is_empty() {
+            return Ok(());
+        }
+
+        let payload: Value = serde_json::from_slice(&message.payload).map_err(|error| {
+            TauAiError::InvalidResponse(format!("failed to parse Bedrock stream event: {error}"))
+        })?;
+        let index = payload["contentBlockIndex"].as_u64().unwrap_or_default();
+        match message.header(":event-type").unwrap_or_default() {
+            "contentBlockStart" => {
+                let tool_use = &payload["start"]["toolUse"];
+                if let Some(name) = tool_use["name"].as_str() {
+                    let id = tool_use["toolUseId"].as_str().unwrap_or_default();
+                    let ordinal = self.tool_count;
+                    self.tool_count += 1;
+                    on_event(StreamEvent::ToolCallStart {
+                        index: ordinal,
+                        id: id.to_string(),
+                        name: name.to_string(),
+                    });
+                    self.blocks.insert(
+                        index,
+                        BedrockBlockAccumulator::ToolUse {
+                            ordinal,
+                            id: id.to_string(),
+                            name: name.to_string(),
+                            input: String::new(),
+                        },
+                    );
+                }
+            }
+            "contentBlockDelta" => {
+                let delta = &payload["delta"];
+                if let Some(text) = delta["text"].as_str() {
+                    if !text.is_empty() {
+                        on_event(StreamEvent::TextDelta {
+                            text: text.to_string(),
+                        });
+                    }
+                    if let BedrockBlockAccumulator::Text(buffer) = self
+                        .blocks
+                        .entry(index)
+                        .or_insert_with(|| BedrockBlockAccumulator::Text(String::new()))
+                    {
+                        buffer.push_str(text);
+                    }
+                } else if let Some(input) = delta["toolUse"]["input"].as_str() {
+                    if let Some(BedrockBlockAccumulator::ToolUse {
+                        ordinal,
+                        id,
+                        input: buffer,
+                        ..
+                    }) = self.blocks.get_mut(&index)
+                    {
+                        buffer.push_str(input);
+                        on_event(StreamEvent::ToolCallArgumentsDelta {
+                            index: *ordinal,
+                            id: id.clone(),
+                            delta: input.to_string(),
+                        });
+                    }
+                } else if delta["reasoningContent"].is_object() {
+                    let reasoning = &delta["reasoningContent"];
+                    let entry = self.blocks.entry(index).or_insert_with(|| {
+                        BedrockBlockAccumulator::Reasoning {
+                            text: String::new(),
+                            signature: String::new(),
+                            redacted: String::new(),
+                        }
+                    });
+                    if let BedrockBlockAccumulator::Reasoning {
+                        text,
+                        signature,
+                        redacted,
+                    } = entry
+                    {
+                        if let Some(fragment) = reasoning["text"].as_str() {
+                            text.push_str(fragment);
+                            on_event(StreamEvent::ReasoningDelta {
+                                text: fragment.to_string(),
+                            });
+                        }
+                        if let Some(fragment) = reasoning["signature"].as_str() {
+                            signature.push_str(fragment);
+                        }
+                        if let Some(fragment) = reasoning["redactedContent"].as_str() {
+                            redacted.push_str(fragment);
+                        }
+                    }
+                }
+            }
+            "messageStop" => {
+                self.finish_reason = payload["stopReason"].as_str().map(str::to_string);
+            }
+            "metadata" => {
+                if let Ok(usage) = serde_json::from_value::<BedrockUsage>(payload["usage"].clone())
+                {
+                    self.usage = usage.into_chat_usage();
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> ChatResponse {
+        let mut reasoning = Vec::new();
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for block in self.blocks.into_values() {
+            match block {
+                BedrockBlockAccumulator::Text(fragment) => text.push_str(&fragment),
+                BedrockBlockAccumulator::ToolUse {
+                    ordinal,
+                    id,
+                    name,
+                    input,
+                } => {
+                    let arguments = if input.trim().is_empty() {
+                        json!({})
+                    } else {
+                        serde_json::from_str::<Value>(&input).unwrap_or(Value::String(input))
+                    };
+                    let id = if id.trim().is_empty() {
+                        format!("bedrock_tool_{}", ordinal + 1)
+                    } else {
+                        id
+                    };
+                    tool_calls.push(ContentBlock::ToolCall {
+                        id,
+                        name,
+                        arguments,
+                    });
+                }
+                BedrockBlockAccumulator::Reasoning {
+                    text: thinking,
+                    signature,
+                    redacted,
+                } => {
+                    if !redacted.is_empty() {
+                        reasoning.push(redacted_reasoning_block(redacted));
+                    } else {
+                        let signature = (!signature.is_empty()).then_some(signature);
+                        reasoning.push(ContentBlock::thinking(
+                            BEDROCK_PROVIDER,
+                            thinking,
+                            signature,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut blocks = reasoning;
+        if !text.trim().is_empty() {
+            blocks.push(ContentBlock::Text { text });
+        }
+        blocks.extend(tool_calls);
+
+        ChatResponse {
+            message: Message::assistant_blocks(blocks),
+            finish_reason: self.finish_reason,
+            usage: self.usage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use super::{
+        build_converse_request_body, parse_converse_response, BedrockStreamState, BEDROCK_PROVIDER,
+    };
+    use crate::{
+        aws_eventstream::{encode_event_stream_message, EventStreamDecoder},
+        ChatRequest, ContentBlock, Message, ReasoningConfig, StreamEvent, StreamEventHandler,
+        ToolCall, ToolChoice, ToolDefinition,
+    };
+
+    fn tool_request() -> ChatRequest {
+        ChatRequest {
+            model: "anthropic.claude-sonnet-4-20250514-v1:0".to_string(),
+            messages: vec![
+                Message::system("be brief"),
+                Message::user("list files"),
+                Message::assistant_blocks(vec![
+                    ContentBlock::thinking(BEDROCK_PROVIDER, "need ls", Some("sig-1".to_string())),
+                    ContentBlock::tool_call(ToolCall {
+                        id: "call_1".to_string(),
+                        name: "bash".to_string(),
+                        arguments: json!({"command": "ls"}),
+                    }),
+                    ContentBlock::tool_call(ToolCall {
+                        id: "call_2".to_string(),
+                        name: "bash".to_string(),
+                        arguments: json!({"command": "pwd"}),
+                    }),
+                ]),
+                Message::tool_result("call_1", "bash", "a.txt", false),
+                Message::tool_result("call_2", "bash", "denied", true),
+            ],
+            tools: vec![ToolDefinition {
+                name: "bash".to_string(),
+                description: "Run a command".to_string(),
+                parameters: json!({"type": "object"}),
+            }],
+            tool_choice: Some(ToolChoice::Required),
+            json_mode: false,
+            max_tokens: Some(512),
+            temperature: Some(0.2),
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        }
+    }
+
+    #[test]
+    fn unit_converse_body_merges_tool_results_and_maps_tool_config() {
+        let body = build_converse_request_body(&tool_request());
+
+        assert_eq!(body["system"], json!([{"text": "be brief"}]));
+        assert_eq!(body["inferenceConfig"]["maxTokens"], 512);
+        assert_eq!(body["toolConfig"]["toolChoice"], json!({"any": {}}));
+        assert_eq!(
+            body["toolConfig"]["tools"][0]["toolSpec"]["inputSchema"]["json"],
+            json!({"type": "object"})
+        );
+        let messages = body["messages"].as_array().expect("messages");
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages[1]["content"][0]["reasoningContent"]["reasoningText"]["signature"],
+            "sig-1"
+        );
+        assert_eq!(messages[1]["content"][1]["toolUse"]["toolUseId"], "call_1");
+        assert_eq!(messages[2]["role"], "user");
+        assert_eq!(messages[2]["content"][0]["toolResult"]["status"], "success");
+        assert_eq!(messages[2]["content"][1]["toolResult"]["status"], "error");
+    }
+
+    #[test]
+    fn functional_converse_body_enables_thinking_only_for_anthropic_models() {
+        let mut request = tool_request();
+        request.reasoning = ReasoningConfig {
+            budget_tokens: Some(2_048),
+            effort: None,
+        };
+        let body = build_converse_request_body(&request);
+        assert_eq!(
+            body["additionalModelRequestFields"]["thinking"]["budget_tokens"],
+            2_048
+        );
+        assert_eq!(body["inferenceConfig"]["maxTokens"], 3_072);
+        assert!(body["inferenceConfig"].get("temperature").is_none());
+        assert_eq!(body["toolConfig"]["toolChoice"], json!({"auto": {}}));
+
+        request.model = "amazon.nova-pro-v1:0".to_string();
+        let body = build_converse_request_body(&request);
+        assert!(body.get("additionalModelRequestFields").is_none());
+    }
+
+    #[test]
+    fn unit_parse_converse_response_maps_reasoning_text_and_tool_use() {
+        let response = parse_converse_response(
+            &json!({
+                "output": {"message": {"role": "assistant", "content": [
+                    {"reasoningContent": {"reasoningText": {"text": "think", "signature": "sig"}}},
+                    {"text": "Running ls"},
+                    {"toolUse": {"toolUseId": "tu_1", "name": "bash", "input": {"command": "ls"}}}
+                ]}},
+                "stopReason": "tool_use",
+                "usage": {"inputTokens": 10, "outputTokens": 4, "totalTokens": 14}
+            })
+            .to_string(),
+        )
+        .expect("parse converse response");
+
+        assert!(response.message.content[0].is_thinking_from(BEDROCK_PROVIDER));
+        assert_eq!(response.message.text_content(), "Running ls");
+        assert_eq!(response.message.tool_calls()[0].id, "tu_1");
+        assert_eq!(response.finish_reason.as_deref(), Some("tool_use"));
+        assert_eq!(response.usage.total_tokens, 14);
+    }
+
+    #[test]
+    fn functional_stream_frames_emit_typed_events_and_accumulate_response() {
+        let frames = [
+            ("messageStart", json!({"role": "assistant"})),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 0, "delta": {"reasoningContent": {"text": "plan"}}}),
+            ),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 0, "delta": {"reasoningContent": {"signature": "sig"}}}),
+            ),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 1, "delta": {"text": "Hel"}}),
+            ),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 1, "delta": {"text": "lo"}}),
+            ),
+            (
+                "contentBlockStart",
+                json!({"contentBlockIndex": 2, "start": {"toolUse": {"toolUseId": "tu_1", "name": "read"}}}),
+            ),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 2, "delta": {"toolUse": {"input": "{\"path\":"}}}),
+            ),
+            (
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 2, "delta": {"toolUse": {"input": "\"a.txt\"}"}}}),
+            ),
+            ("messageStop", json!({"stopReason": "tool_use"})),
+            (
+                "metadata",
+                json!({"usage": {"inputTokens": 3, "outputTokens": 5, "totalTokens": 8}}),
+            ),
+        ];
+        let bytes = frames
+            .iter()
+            .flat_map(|(event_type, payload)| {
+                encode_event_stream_message(
+                    &[
+                        (":event-type", event_type),
+                        (":content-type", "application/json"),
+                        (":message-type", "event"),
+                    ],
+                    payload.to_string().as_bytes(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let handler: StreamEventHandler = Arc::new(move |event| {
+            sink.lock().expect("events lock").push(event);
+        });
+        let mut decoder = EventStreamDecoder::default();
+        let mut state = BedrockStreamState::default();
+        for chunk in bytes.chunks(11) {
+            for message in decoder.push(chunk).expect("decode") {
+                state.apply(&message, &handler).expect("apply");
+            }
+        }
+        let response = state.finish();
+
+        let events = events.lock().expect("events lock").clone();
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::ReasoningDelta {
+                    text: "plan".to_string()
+                },
+                StreamEvent::TextDelta {
+                    text: "Hel".to_string()
+                },
+                StreamEvent::TextDelta {
+                    text: "lo".to_string()
+                },
+                StreamEvent::ToolCallStart {
+                    index: 0,
+                    id: "tu_1".to_string(),
+                    name: "read".to_string()
+                },
+                StreamEvent::ToolCallArgumentsDelta {
+                    index: 0,
+                    id: "tu_1".to_string(),
+                    delta: "{\"path\":".to_string()
+                },
+                StreamEvent::ToolCallArgumentsDelta {
+                    index: 0,
+                    id: "tu_1".to_string(),
+                    delta: "\"a.txt\"}".to_string()
+                },
+            ]
+        );
+        assert_eq!(response.message.text_content(), "Hello");
+        assert_eq!(
+            response.message.tool_calls()[0].arguments,
+            json!({"path": "a.txt"})
+        );
+        assert!(matches!(
+            &response.message.content[0],
+            ContentBlock::Thinking { signature: Some(signature), .. } if signature == "sig"
+        ));
+        assert_eq!(response.finish_reason.as_deref(), Some("tool_use"));
+        assert_eq!(response.usage.total_tokens, 8);
+    }
+
+    #[test]
+    fn regression_stream_exception_frame_surfaces_error() {
+        let bytes = encode_event_stream_message(
+            &[
+                (":message-type", "exception"),
+                (":exception-type", "throttlingException"),
+            ],
+            br#"{"message":"Too many requests"}"#,
+        );
+        let handler: StreamEventHandler = Arc::new(|_event| {});
+        let mut state = BedrockStreamState::default();
+        let message = EventStreamDecoder::default()
+            .push(&bytes)
+            .expect("decode")
+            .remove(0);
+
+        let error = state
+            .apply(&message, &handler)
+            .expect_err("exception frame must fail");
+        let rendered = error.to_string();
+        assert!(rendered.contains("throttlingException"), "{rendered}");
+    }
+}
@@ -0,0 +1,178 @@
+//! OpenTelemetry GenAI client spans for provider chat completions.
+//!
+//! Every provider client wraps its completion in a [`ChatCompletionSpan`] so the
+//! request shows up as a `chat {model}` client span carrying the GenAI semantic
+//! convention attributes, and outbound HTTP calls forward `traceparent`.
+
+use std::future::Future;
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use tau_core::{current_trace_context, with_trace_context, Span, SpanKind, TRACEPARENT_HEADER};
+
+use crate::{ChatRequest, ChatResponse, TauAiError};
+
+/// Client span covering one provider chat completion, including retries.
+pub(crate) struct ChatCompletionSpan {
+    span: Span,
+}
+
+impl ChatCompletionSpan {
+    /// Starts a span under the task's current trace context.
+    pub(crate) fn start(system: &str, request: &ChatRequest) -> Self {
+        let mut span = Span::start_in_current(format!("chat {}", request.model), SpanKind::Client);
+        span.set_attribute("gen_ai.operation.name", "chat");
+        span.set_attribute("gen_ai.system", system);
+        span.set_attribute("gen_ai.request.model", request.model.as_str());
+        if let Some(max_tokens) = request.max_tokens {
+            span.set_attribute("gen_ai.request.max_tokens", max_tokens);
+        }
+        if let Some(temperature) = request.temperature {
+            span.set_attribute("gen_ai.request.temperature", f64::from(temperature));
+        }
+        Self { span }
+    }
+
+    /// Runs `completion` inside the span and records the response attributes.
+    pub(crate) async fn instrument<F>(mut self, completion: F) -> Result<ChatResponse, TauAiError>
+    where
+        F: Future<Output = Result<ChatResponse, TauAiError>>,
+    {
+        let result = with_trace_context(self.span.context().clone(), completion).await;
+        match &result {
+            Ok(response) => {
+                if let Some(finish_reason) = response.finish_reason.as_ref() {
+                    self.span.set_attribute(
+                        "gen_ai.response.finish_reasons",
+                        vec![finish_reason.clone()],
+                    );
+                }
+                self.span
+                    .set_attribute("gen_ai.usage.input_tokens", response.usage.input_tokens);
+                self.span
+                    .set_attribute("gen_ai.usage.output_tokens", response.usage.output_tokens);
+                self.span.set_status_ok();
+            }
+            Err(error) => {
+                self.span
+                    .set_attribute("error.type", chat_error_type(error));
+                self.span.set_status_error(error.to_string());
+            }
+        }
+        self.span.end();
+        result
+    }
+}
+
+/// Returns `traceparent` propagation headers for the task's current span.
+pub(crate) fn trace_propagation_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(context) = current_trace_context() {
+        if let Ok(value) = HeaderValue::from_str(&context.to_traceparent()) {
+            headers.insert(TRACEPARENT_HEADER, value);
+        }
+    }
+    headers
+}
+
+fn chat_error_type(error: &TauAiError) -> &'static str {
+    match error {
+        TauAiError::MissingApiKey => "missing_api_key",
+        TauAiError::Http(_) => "http",
+        TauAiError::HttpStatus { .. } => "http_status",
+        TauAiError::Serde(_) => "serde",
+        TauAiError::InvalidResponse(_) => "invalid_response",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tau_core::{install_span_exporter, SpanAttributeValue, SpanExporter, SpanRecord};
+
+    use crate::{ChatUsage, Message, PromptCacheConfig, ReasoningConfig};
+
+    #[derive(Default)]
+    struct CollectingSpanExporter {
+        spans: Mutex<Vec<SpanRecord>>,
+    }
+
+    impl SpanExporter for CollectingSpanExporter {
+        fn export(&self, span: SpanRecord) {
+            self.spans.lock().expect("spans lock").push(span);
+        }
+    }
+
+    #[tokio::test]
+    async fn functional_chat_completion_span_records_genai_attributes_and_propagates_context() {
+        let exporter = Arc::new(CollectingSpanExporter::default());
+        install_span_exporter(exporter.clone());
+        let request = ChatRequest {
+            model: "gpt-genai-span-test".to_string(),
+            messages: vec![Message::user("hello")],
+            tools: Vec::new(),
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: Some(64),
+            temperature: None,
+            prompt_cache: PromptCacheConfig::default(),
+            reasoning: ReasoningConfig::default(),
+            model_tier: None,
+        };
+
+        let observed_header = Arc::new(Mutex::new(None));
+        let observed = observed_header.clone();
+        let result = ChatCompletionSpan::start("openai", &request)
+            .instrument(async move {
+                *observed.lock().expect("observed lock") = trace_propagation_headers()
+                    .get(TRACEPARENT_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string);
+                Ok(ChatResponse {
+                    message: Message::assistant_text("hi"),
+                    finish_reason: Some("stop".to_string()),
+                    usage: ChatUsage {
+                        input_tokens: 11,
+                        output_tokens: 3,
+                        total_tokens: 14,
+                        cached_input_tokens: 0,
+                        reasoning_tokens: 0,
+                        hedge_input_tokens: 0,
+                        hedge_output_tokens: 0,
+                        hedge_model: None,
+                    },
+                })
+            })
+            .await;
+        assert!(result.is_ok());
+
+        let span = exporter
+            .spans
+            .lock()
+            .expect("spans lock")
+            .iter()
+            .find(|span| span.name == "chat gpt-genai-span-test")
+            .cloned()
+            .expect("chat span exported");
+        assert_eq!(span.kind, SpanKind::Client);
+        assert_eq!(
+            span.attribute("gen_ai.system"),
+            Some(&SpanAttributeValue::String("openai".to_string()))
+        );
+        assert_eq!(
+            span.attribute("gen_ai.usage.input_tokens"),
+            Some(&SpanAttributeValue::Int(11))
+        );
+        assert_eq!(
+            span.attribute("gen_ai.response.finish_reasons"),
+            Some(&SpanAttributeValue::StringArray(vec!["stop".to_string()]))
+        );
+        let header = observed_header
+            .lock()
+            .expect("observed lock")
+            .clone()
+            .expect("traceparent header");
+        assert_eq!(header, format!("00-{}-{}-01", span.trace_id, span.span_id));
+        assert!(trace_propagation_headers().is_empty());
+    }
+}
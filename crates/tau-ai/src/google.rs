@@ -5,14 +5,23 @@ use serde_json::{json, Value};
 use tokio::time::sleep;
 
 use crate::{
+    genai_spans::{trace_propagation_headers, ChatCompletionSpan},
+    rate_limits::observe_rate_limit_headers,
     retry::{
         is_retryable_http_error, new_request_id, parse_retry_after_ms, provider_retry_delay_ms,
         retry_budget_allows_delay, should_retry_status,
     },
+    types::{
+        emit_stream_completion_events, emit_stream_events_for_response,
+        stream_event_handler_from_deltas,
+    },
     ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, MediaSource, Message,
-    MessageRole, StreamDeltaHandler, TauAiError, ToolChoice, ToolDefinition,
+    MessageRole, StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError, ToolChoice,
+    ToolDefinition,
 };
 
+const GOOGLE_PROVIDER: &str = "google";
+
 #[derive(Debug, Clone)]
 /// Public struct `GoogleConfig` used across Tau components.
 pub struct GoogleConfig {
@@ -82,7 +91,16 @@ impl LlmClient for GoogleClient {
         request: ChatRequest,
         on_delta: Option<StreamDeltaHandler>,
     ) -> Result<ChatResponse, TauAiError> {
-        self.complete_with_mode(request, on_delta).await
+        self.complete_with_mode(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, on_event).await
     }
 }
 
@@ -90,10 +108,20 @@ impl GoogleClient {
     async fn complete_with_mode(
         &self,
         request: ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        ChatCompletionSpan::start("google", &request)
+            .instrument(self.complete_untraced(request, on_event))
+            .await
+    }
+
+    async fn complete_untraced(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         let body = build_generate_content_body(&request);
-        let stream_mode = on_delta.is_some();
+        let stream_mode = on_event.is_some();
         let url = if stream_mode {
             self.stream_generate_content_url(&request.model)
         } else {
@@ -113,6 +141,7 @@ impl GoogleClient {
                 .post(&url)
                 .header("x-tau-request-id", request_id)
                 .header("x-tau-retry-attempt", attempt.to_string())
+                .headers(trace_propagation_headers())
                 .query(&query)
                 .json(&body)
                 .send()
@@ -121,8 +150,9 @@ impl GoogleClient {
             match response {
                 Ok(response) => {
                     let status = response.status();
+                    observe_rate_limit_headers(status.as_u16(), response.headers());
                     if status.is_success() {
-                        if let Some(delta_handler) = on_delta.clone() {
+                        if let Some(event_handler) = on_event.clone() {
                             let is_event_stream = response
                                 .headers()
                                 .get(reqwest::header::CONTENT_TYPE)
@@ -134,17 +164,14 @@ impl GoogleClient {
                             if is_event_stream {
                                 return parse_generate_content_stream_response(
                                     response,
-                                    delta_handler,
+                                    event_handler,
                                 )
                                 .await;
                             }
 
                             let raw = response.text().await?;
                             let parsed = parse_generate_content_response(&raw)?;
-                            let text = parsed.message.text_content();
-                            if !text.is_empty() {
-                                delta_handler(text);
-                            }
+                            emit_stream_events_for_response(&event_handler, &parsed);
                             return Ok(parsed);
                         }
 
@@ -201,7 +228,7 @@ impl GoogleClient {
     }
 }
 
-fn build_generate_content_body(request: &ChatRequest) -> Value {
+pub(crate) fn build_generate_content_body(request: &ChatRequest) -> Value {
     let system = extract_system_text(&request.messages);
     let contents = to_google_contents(&request.messages);
 
@@ -226,7 +253,12 @@ fn build_generate_content_body(request: &ChatRequest) -> Value {
         }
     }
 
-    if request.temperature.is_some() || request.max_tokens.is_some() || request.json_mode {
+    let thinking_budget = request.reasoning.resolved_budget_tokens();
+    if request.temperature.is_some()
+        || request.max_tokens.is_some()
+        || request.json_mode
+        || thinking_budget.is_some()
+    {
         let mut generation_config = json!({});
         if request.json_mode {
             generation_config["responseMimeType"] = json!("application/json");
@@ -237,6 +269,12 @@ fn build_generate_content_body(request: &ChatRequest) -> Value {
         if let Some(max_tokens) = request.max_tokens {
             generation_config["maxOutputTokens"] = json!(max_tokens);
         }
+        if let Some(thinking_budget) = thinking_budget {
+            generation_config["thinkingConfig"] = json!({
+                "thinkingBudget": thinking_budget,
+                "includeThoughts": true,
+            });
+        }
         body["generationConfig"] = generation_config;
     }
 
@@ -360,9 +398,20 @@ fn to_google_contents(messages: &[Message]) -> Value {
 }
 
 fn to_google_parts(message: &Message, allow_tool_calls: bool) -> Vec<Value> {
-    let mut parts = Vec::new();
+    let mut parts: Vec<Value> = Vec::new();
+    // Gemini attaches thought signatures to the part that follows the model's reasoning,
+    // so a replayed signature is carried forward onto the next emitted part.
+    let mut pending_signature = None;
     for block in &message.content {
+        let emitted_before = parts.len();
         match block {
+            ContentBlock::Thinking {
+                signature: Some(signature),
+                ..
+            } if allow_tool_calls && block.is_thinking_from(GOOGLE_PROVIDER) => {
+                pending_signature = Some(signature.clone());
+            }
+            ContentBlock::Thinking { .. } => {}
             ContentBlock::Text { text } => {
                 if !text.trim().is_empty() {
                     parts.push(json!({ "text": text }));
@@ -388,6 +437,16 @@ fn to_google_parts(message: &Message, allow_tool_calls: bool) -> Vec<Value> {
                 parts.push(to_google_media_part(source, "audio"));
             }
         }
+        if parts.len() > emitted_before {
+            if let Some(signature) = pending_signature.take() {
+                parts[emitted_before]["thoughtSignature"] = json!(signature);
+            }
+        }
+    }
+    if let (Some(signature), Some(last)) = (pending_signature, parts.last_mut()) {
+        if last.get("thoughtSignature").is_none() {
+            last["thoughtSignature"] = json!(signature);
+        }
     }
     parts
 }
@@ -418,7 +477,7 @@ fn flatten_message_with_media_markers(message: &Message) -> String {
                     parts.push(text.clone());
                 }
             }
-            ContentBlock::ToolCall { .. } => {}
+            ContentBlock::ToolCall { .. } | ContentBlock::Thinking { .. } => {}
             ContentBlock::Image { source } => {
                 parts.push(format!("[tau-image:{}]", media_source_descriptor(source)));
             }
@@ -447,7 +506,7 @@ fn default_mime_type(media_kind: &str) -> &'static str {
     }
 }
 
-fn parse_generate_content_response(raw: &str) -> Result<ChatResponse, TauAiError> {
+pub(crate) fn parse_generate_content_response(raw: &str) -> Result<ChatResponse, TauAiError> {
     let parsed: GenerateContentResponse = serde_json::from_str(raw)?;
     let candidate = parsed
         .candidates
@@ -463,6 +522,13 @@ fn parse_generate_content_response(raw: &str) -> Result<ChatResponse, TauAiError
     let mut blocks = Vec::new();
 
     for (index, part) in parts.into_iter().enumerate() {
+        if let Some(thinking) = google_thinking_block(&part) {
+            blocks.push(thinking);
+        }
+        if part.thought == Some(true) {
+            continue;
+        }
+
         if let Some(text) = part.text.as_ref() {
             if !text.trim().is_empty() {
                 blocks.push(ContentBlock::Text { text: text.clone() });
@@ -484,11 +550,19 @@ fn parse_generate_content_response(raw: &str) -> Result<ChatResponse, TauAiError
 
     let usage = parsed
         .usage_metadata
-        .map(|usage| ChatUsage {
-            input_tokens: usage.prompt_token_count.unwrap_or(0),
-            output_tokens: usage.candidates_token_count.unwrap_or(0),
-            total_tokens: usage.total_token_count.unwrap_or(0),
-            cached_input_tokens: usage.cached_content_token_count.unwrap_or(0),
+        .map(|usage| {
+            let reasoning_tokens = usage.thoughts_token_count.unwrap_or(0);
+            ChatUsage {
+                input_tokens: usage.prompt_token_count.unwrap_or(0),
+                // Gemini reports thought tokens separately from candidate tokens.
+                output_tokens: usage.candidates_token_count.unwrap_or(0) + reasoning_tokens,
+                total_tokens: usage.total_token_count.unwrap_or(0),
+                cached_input_tokens: usage.cached_content_token_count.unwrap_or(0),
+                reasoning_tokens,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
+            }
         })
         .unwrap_or_default();
 
@@ -499,9 +573,18 @@ fn parse_generate_content_response(raw: &str) -> Result<ChatResponse, TauAiError
     })
 }
 
-async fn parse_generate_content_stream_response(
+pub(crate) async fn parse_generate_content_stream_response(
+    response: reqwest::Response,
+    on_event: StreamEventHandler,
+) -> Result<ChatResponse, TauAiError> {
+    let parsed = collect_generate_content_stream_response(response, &on_event).await?;
+    emit_stream_completion_events(&on_event, &parsed);
+    Ok(parsed)
+}
+
+async fn collect_generate_content_stream_response(
     response: reqwest::Response,
-    on_delta: StreamDeltaHandler,
+    on_event: &StreamEventHandler,
 ) -> Result<ChatResponse, TauAiError> {
     let mut stream = response.bytes_stream();
     let mut buffer = String::new();
@@ -527,7 +610,7 @@ async fn parse_generate_content_stream_response(
                 let data = data.trim();
                 apply_google_stream_data(
                     data,
-                    &on_delta,
+                    on_event,
                     &mut text,
                     &mut tool_calls,
                     &mut finish_reason,
@@ -541,7 +624,7 @@ async fn parse_generate_content_stream_response(
     if let Some(data) = trailing.strip_prefix("data:") {
         apply_google_stream_data(
             data.trim(),
-            &on_delta,
+            on_event,
             &mut text,
             &mut tool_calls,
             &mut finish_reason,
@@ -559,7 +642,7 @@ async fn parse_generate_content_stream_response(
 
 fn apply_google_stream_data(
     data: &str,
-    on_delta: &StreamDeltaHandler,
+    on_event: &StreamEventHandler,
     text: &mut String,
     tool_calls: &mut Vec<ContentBlock>,
     finish_reason: &mut Option<String>,
@@ -574,8 +657,12 @@ fn apply_google_stream_data(
     })?;
     if let Some(chunk_usage) = chunk.usage_metadata {
         usage.input_tokens = chunk_usage.prompt_token_count.unwrap_or(usage.input_tokens);
+        usage.reasoning_tokens = chunk_usage
+            .thoughts_token_count
+            .unwrap_or(usage.reasoning_tokens);
         usage.output_tokens = chunk_usage
             .candidates_token_count
+            .map(|tokens| tokens + usage.reasoning_tokens)
             .unwrap_or(usage.output_tokens);
         usage.total_tokens = chunk_usage.total_token_count.unwrap_or(usage.total_tokens);
         usage.cached_input_tokens = chunk_usage
@@ -593,17 +680,47 @@ fn apply_google_stream_data(
                 continue;
             };
             for part in parts {
+                if let Some(thinking) = google_thinking_block(&part) {
+                    append_google_stream_thinking(tool_calls, thinking);
+                }
+                if part.thought == Some(true) {
+                    if let Some(thought_text) = part.text.as_ref().filter(|text| !text.is_empty()) {
+                        on_event(StreamEvent::ReasoningDelta {
+                            text: thought_text.clone(),
+                        });
+                    }
+                    continue;
+                }
                 if let Some(delta_text) = part.text.as_ref() {
                     if !delta_text.is_empty() {
                         text.push_str(delta_text);
-                        on_delta(delta_text.clone());
+                        on_event(StreamEvent::TextDelta {
+                            text: delta_text.clone(),
+                        });
                     }
                 }
                 if let Some(function_call) = part.function_call.as_ref() {
+                    // Gemini delivers function calls whole, so start and arguments arrive together.
+                    let index = tool_calls
+                        .iter()
+                        .filter(|block| matches!(block, ContentBlock::ToolCall { .. }))
+                        .count();
+                    let id = format!("google_stream_call_{}", tool_calls.len() + 1);
+                    let arguments = function_call.args.clone().unwrap_or_else(|| json!({}));
+                    on_event(StreamEvent::ToolCallStart {
+                        index,
+                        id: id.clone(),
+                        name: function_call.name.clone(),
+                    });
+                    on_event(StreamEvent::ToolCallArgumentsDelta {
+                        index,
+                        id: id.clone(),
+                        delta: arguments.to_string(),
+                    });
                     tool_calls.push(ContentBlock::ToolCall {
-                        id: format!("google_stream_call_{}", tool_calls.len() + 1),
+                        id,
                         name: function_call.name.clone(),
-                        arguments: function_call.args.clone().unwrap_or_else(|| json!({})),
+                        arguments,
                     });
                 }
                 if let Some(media_block) = parse_google_media_part(&part) {
@@ -622,11 +739,16 @@ fn finalize_google_stream_response(
     finish_reason: Option<String>,
     usage: ChatUsage,
 ) -> ChatResponse {
-    let mut blocks = Vec::new();
+    // Streamed thoughts precede the answer text; signature-only markers stay in place so
+    // they keep pointing at the function call they were attached to.
+    let (thoughts, rest): (Vec<_>, Vec<_>) = tool_calls.into_iter().partition(
+        |block| matches!(block, ContentBlock::Thinking { text, .. } if !text.is_empty()),
+    );
+    let mut blocks = thoughts;
     if !text.trim().is_empty() {
         blocks.push(ContentBlock::Text { text });
     }
-    blocks.extend(tool_calls);
+    blocks.extend(rest);
 
     ChatResponse {
         message: Message::assistant_blocks(blocks),
@@ -635,6 +757,41 @@ fn finalize_google_stream_response(
     }
 }
 
+fn google_thinking_block(part: &GenerateContentPart) -> Option<ContentBlock> {
+    if part.thought == Some(true) {
+        return Some(ContentBlock::thinking(
+            GOOGLE_PROVIDER,
+            part.text.clone().unwrap_or_default(),
+            part.thought_signature.clone(),
+        ));
+    }
+    part.thought_signature
+        .clone()
+        .map(|signature| ContentBlock::thinking(GOOGLE_PROVIDER, "", Some(signature)))
+}
+
+fn append_google_stream_thinking(blocks: &mut Vec<ContentBlock>, thinking: ContentBlock) {
+    if let (
+        Some(ContentBlock::Thinking {
+            text: existing_text,
+            signature: existing_signature,
+            ..
+        }),
+        ContentBlock::Thinking {
+            text, signature, ..
+        },
+    ) = (blocks.last_mut(), &thinking)
+    {
+        // Thought summaries arrive in fragments; merge them until a signature closes the block.
+        if existing_signature.is_none() && !existing_text.is_empty() && !text.is_empty() {
+            existing_text.push_str(text);
+            existing_signature.clone_from(signature);
+            return;
+        }
+    }
+    blocks.push(thinking);
+}
+
 fn parse_google_media_part(part: &GenerateContentPart) -> Option<ContentBlock> {
     if let Some(inline_data) = &part.inline_data {
         return Some(media_block_from_mime(
@@ -688,6 +845,9 @@ struct GenerateContentContent {
 #[derive(Debug, Deserialize)]
 struct GenerateContentPart {
     text: Option<String>,
+    thought: Option<bool>,
+    #[serde(rename = "thoughtSignature")]
+    thought_signature: Option<String>,
     #[serde(rename = "functionCall")]
     function_call: Option<GenerateContentFunctionCall>,
     #[serde(rename = "inlineData")]
@@ -727,6 +887,8 @@ struct GenerateContentUsage {
     total_token_count: Option<u64>,
     #[serde(rename = "cachedContentTokenCount")]
     cached_content_token_count: Option<u64>,
+    #[serde(rename = "thoughtsTokenCount")]
+    thoughts_token_count: Option<u64>,
 }
 
 #[cfg(test)]
@@ -769,6 +931,8 @@ mod tests {
             max_tokens: Some(256),
             temperature: Some(0.1),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -807,6 +971,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: PromptCacheConfig::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -831,6 +997,8 @@ mod tests {
                     "cachedContents/projects/demo/locations/us/cachedContents/abc123".to_string(),
                 ),
             },
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -851,6 +1019,8 @@ mod tests {
             max_tokens: Some(64),
             temperature: Some(0.2),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -894,6 +1064,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -975,6 +1147,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_generate_content_body(&request);
@@ -1027,16 +1201,111 @@ mod tests {
             .any(|block| matches!(block, ContentBlock::Audio { .. })));
     }
 
+    #[test]
+    fn functional_google_parses_thoughts_and_replays_thought_signatures() {
+        let raw = r#"{
+            "candidates": [{
+                "content": {
+                    "parts": [
+                        {"text": "Inspecting the repo.", "thought": true},
+                        {"functionCall": {"name": "read", "args": {"path": "README.md"}}, "thoughtSignature": "sig-g"}
+                    ]
+                },
+                "finishReason": "STOP"
+            }],
+            "usageMetadata": {
+                "promptTokenCount": 8,
+                "candidatesTokenCount": 4,
+                "thoughtsTokenCount": 6,
+                "totalTokenCount": 18
+            }
+        }"#;
+
+        let response = parse_generate_content_response(raw).expect("response must parse");
+        assert_eq!(response.message.text_content(), "");
+        assert_eq!(response.message.thinking_content(), "Inspecting the repo.");
+        assert_eq!(response.usage.reasoning_tokens, 6);
+        assert_eq!(response.usage.output_tokens, 10);
+        assert_eq!(response.message.tool_calls().len(), 1);
+
+        let request = ChatRequest {
+            model: "gemini-2.5-pro".to_string(),
+            messages: vec![Message::user("read"), response.message],
+            tools: vec![],
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: crate::ReasoningConfig {
+                budget_tokens: Some(2_048),
+                effort: None,
+            },
+            model_tier: None,
+        };
+        let body = build_generate_content_body(&request);
+        assert_eq!(
+            body["generationConfig"]["thinkingConfig"]["thinkingBudget"],
+            2_048
+        );
+        assert_eq!(
+            body["generationConfig"]["thinkingConfig"]["includeThoughts"],
+            true
+        );
+        let model_parts = body["contents"][1]["parts"]
+            .as_array()
+            .expect("model parts");
+        assert_eq!(model_parts.len(), 1);
+        assert_eq!(model_parts[0]["functionCall"]["name"], "read");
+        assert_eq!(model_parts[0]["thoughtSignature"], "sig-g");
+    }
+
+    #[test]
+    fn functional_google_stream_data_separates_thought_parts_from_text() {
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        let mut finish_reason = None;
+        let mut usage = crate::ChatUsage::default();
+
+        for data in [
+            r#"{"candidates":[{"content":{"parts":[{"text":"Thinking ","thought":true}]}}]}"#,
+            r#"{"candidates":[{"content":{"parts":[{"text":"hard.","thought":true}]}}]}"#,
+            r#"{"candidates":[{"content":{"parts":[{"text":"Answer"}]},"finishReason":"STOP"}],"usageMetadata":{"promptTokenCount":5,"candidatesTokenCount":1,"thoughtsTokenCount":3,"totalTokenCount":9}}"#,
+        ] {
+            apply_google_stream_data(
+                data,
+                &sink,
+                &mut text,
+                &mut tool_calls,
+                &mut finish_reason,
+                &mut usage,
+            )
+            .expect("stream chunk parses");
+        }
+
+        assert_eq!(text, "Answer");
+        assert_eq!(usage.reasoning_tokens, 3);
+        assert_eq!(usage.output_tokens, 4);
+        let response = finalize_google_stream_response(text, tool_calls, finish_reason, usage);
+        assert_eq!(response.message.text_content(), "Answer");
+        assert_eq!(response.message.thinking_content(), "Thinking hard.");
+        assert!(matches!(
+            response.message.content[0],
+            ContentBlock::Thinking { .. }
+        ));
+    }
+
     #[test]
     fn functional_google_stream_data_parses_text_and_function_calls() {
         let streamed = Arc::new(Mutex::new(String::new()));
         let sink_streamed = streamed.clone();
-        let sink: crate::StreamDeltaHandler = Arc::new(move |delta: String| {
+        let sink = crate::stream_event_handler_from_deltas(Arc::new(move |delta: String| {
             sink_streamed
                 .lock()
                 .expect("stream lock")
                 .push_str(delta.as_str());
-        });
+        }));
 
         let mut text = String::new();
         let mut tool_calls = Vec::new();
@@ -1079,7 +1348,7 @@ mod tests {
 
     #[test]
     fn regression_google_stream_data_parses_media_parts_without_error() {
-        let sink: crate::StreamDeltaHandler = Arc::new(|_delta: String| {});
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
         let mut text = String::new();
         let mut tool_calls = Vec::new();
         let mut finish_reason = None;
@@ -1105,7 +1374,7 @@ mod tests {
 
     #[test]
     fn regression_google_stream_data_surfaces_parse_errors() {
-        let sink: crate::StreamDeltaHandler = Arc::new(|_delta: String| {});
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
         let mut text = String::new();
         let mut tool_calls = Vec::new();
         let mut finish_reason = None;
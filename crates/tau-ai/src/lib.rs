@@ -1,23 +1,47 @@
 //! Provider clients and shared AI transport types for Tau.
 //!
 //! Defines request/response schemas, model/provider abstractions, and retry
-//! behavior used by OpenAI-, Anthropic-, and Google-compatible backends.
+//! behavior used by OpenAI-, Anthropic-, Google-compatible, Bedrock, Vertex AI, and local
+//! inference backends.
 
 mod anthropic;
+mod aws_eventstream;
+mod aws_sigv4;
+mod bedrock;
+mod genai_spans;
 mod google;
+mod local;
 mod openai;
 mod provider;
+mod rate_limits;
 mod retry;
 mod textual_tool_calls;
+mod tokenizer;
 mod types;
+mod vertex;
 
 pub use anthropic::{AnthropicClient, AnthropicConfig};
+pub use aws_sigv4::AwsCredentials;
+pub use bedrock::{default_bedrock_api_base, BedrockClient, BedrockConfig};
 pub use google::{GoogleClient, GoogleConfig};
+pub use local::{LocalClient, LocalConfig, DEFAULT_LOCAL_API_BASE};
 pub use openai::{OpenAiAuthScheme, OpenAiClient, OpenAiConfig};
 pub use provider::{ModelRef, ModelRefParseError, Provider};
+pub use rate_limits::{
+    observe_rate_limit_headers, parse_rate_limit_snapshot, provider_key_pool_health_snapshot,
+    register_provider_key_pool, with_rate_limit_observer, ProviderKeyHealth, ProviderKeyPoolHealth,
+    ProviderKeyPoolHealthSource, RateLimitObserver, RateLimitSnapshot,
+};
 pub use textual_tool_calls::promote_assistant_textual_tool_calls;
+pub use tokenizer::{ChatRequestTokenCount, TokenizerFamily};
 pub use types::{
-    ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, MediaSource, Message,
-    MessageRole, PromptCacheConfig, ProviderErrorKind, StreamDeltaHandler, TauAiError, ToolCall,
-    ToolChoice, ToolDefinition,
+    emit_stream_completion_events, emit_stream_events_for_response,
+    stream_delta_handler_from_events, stream_event_handler_from_deltas, ChatRequest, ChatResponse,
+    ChatUsage, ContentBlock, LlmClient, MediaSource, Message, MessageRole, ModelTier,
+    PromptCacheConfig, ProviderErrorKind, ReasoningConfig, ReasoningEffort, StreamDeltaHandler,
+    StreamEvent, StreamEventHandler, TauAiError, ToolCall, ToolChoice, ToolDefinition,
+};
+pub use vertex::{
+    default_vertex_api_base, VertexClient, VertexConfig, VertexCredentials,
+    VertexServiceAccountKey, DEFAULT_GOOGLE_OAUTH_TOKEN_URI,
 };
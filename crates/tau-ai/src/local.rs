@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+
+use crate::{
+    ChatRequest, ChatResponse, LlmClient, OpenAiAuthScheme, OpenAiClient, OpenAiConfig,
+    PromptCacheConfig, StreamDeltaHandler, StreamEventHandler, TauAiError, ToolChoice,
+};
+
+/// Default OpenAI-compatible base URL of a local Ollama daemon.
+pub const DEFAULT_LOCAL_API_BASE: &str = "http://127.0.0.1:11434/v1";
+
+// Local servers ignore bearer auth unless configured (e.g. `vllm serve --api-key`),
+// but the shared OpenAI transport requires a non-empty key.
+const LOCAL_PLACEHOLDER_API_KEY: &str = "local";
+
+#[derive(Debug, Clone)]
+/// Public struct `LocalConfig` used across Tau components.
+pub struct LocalConfig {
+    pub api_base: String,
+    pub api_key: Option<String>,
+    pub request_timeout_ms: u64,
+    pub max_retries: usize,
+    pub retry_budget_ms: u64,
+    pub retry_jitter: bool,
+}
+
+#[derive(Debug, Clone)]
+/// Client for local inference servers (Ollama, llama.cpp server, vLLM).
+///
+/// Requests go through the servers' OpenAI-compatible chat endpoint after the
+/// request is adapted to what these servers accept: `tool_choice` is not
+/// forwarded and prompt-cache hints are dropped. Textual tool calls emitted by
+/// models without native tool support are promoted by the shared transport.
+pub struct LocalClient {
+    inner: OpenAiClient,
+}
+
+impl LocalClient {
+    pub fn new(config: LocalConfig) -> Result<Self, TauAiError> {
+        let api_key = config
+            .api_key
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| LOCAL_PLACEHOLDER_API_KEY.to_string());
+        let inner = OpenAiClient::new(OpenAiConfig {
+            api_base: config.api_base,
+            api_key,
+            organization: None,
+            request_timeout_ms: config.request_timeout_ms,
+            max_retries: config.max_retries,
+            retry_budget_ms: config.retry_budget_ms,
+            retry_jitter: config.retry_jitter,
+            auth_scheme: OpenAiAuthScheme::Bearer,
+            api_version: None,
+        })?;
+        Ok(Self { inner })
+    }
+}
+
+#[async_trait]
+impl LlmClient for LocalClient {
+    async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        self.inner.complete(adapt_local_request(request)).await
+    }
+
+    async fn complete_with_stream(
+        &self,
+        request: ChatRequest,
+        on_delta: Option<StreamDeltaHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.inner
+            .complete_with_stream(adapt_local_request(request), on_delta)
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.inner
+            .complete_with_events(adapt_local_request(request), on_event)
+            .await
+    }
+}
+
+fn adapt_local_request(mut request: ChatRequest) -> ChatRequest {
+    // Local servers reject or silently ignore `tool_choice`; honor an explicit
+    // `none` by withholding the tools instead.
+    if matches!(request.tool_choice.take(), Some(ToolChoice::None)) {
+        request.tools.clear();
+    }
+    request.prompt_cache = PromptCacheConfig::default();
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::adapt_local_request;
+    use crate::{ChatRequest, Message, PromptCacheConfig, ToolChoice, ToolDefinition};
+
+    fn request_with_tool_choice(tool_choice: ToolChoice) -> ChatRequest {
+        ChatRequest {
+            model: "llama3.1:8b".to_string(),
+            messages: vec![Message::user("hello")],
+            tools: vec![ToolDefinition {
+                name: "read".to_string(),
+                description: "Read a file".to_string(),
+                parameters: json!({"type":"object"}),
+            }],
+            tool_choice: Some(tool_choice),
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: PromptCacheConfig {
+                enabled: true,
+                cache_key: Some("session-1".to_string()),
+                ..PromptCacheConfig::default()
+            },
+            reasoning: Default::default(),
+            model_tier: None,
+        }
+    }
+
+    #[test]
+    fn unit_adapt_local_request_drops_tool_choice_and_prompt_cache_hints() {
+        let adapted = adapt_local_request(request_with_tool_choice(ToolChoice::Required));
+        assert!(adapted.tool_choice.is_none());
+        assert_eq!(adapted.tools.len(), 1);
+        assert_eq!(adapted.prompt_cache, PromptCacheConfig::default());
+    }
+
+    #[test]
+    fn regression_adapt_local_request_withholds_tools_when_tool_choice_is_none() {
+        let adapted = adapt_local_request(request_with_tool_choice(ToolChoice::None));
+        assert!(adapted.tool_choice.is_none());
+        assert!(adapted.tools.is_empty());
+    }
+}
@@ -6,16 +6,24 @@ use serde_json::{json, Value};
 use tokio::time::sleep;
 
 use crate::{
+    genai_spans::{trace_propagation_headers, ChatCompletionSpan},
     promote_assistant_textual_tool_calls,
+    rate_limits::observe_rate_limit_headers,
     retry::{
         is_retryable_http_error, new_request_id, parse_retry_after_ms, provider_retry_delay_ms,
         retry_budget_allows_delay, should_retry_status,
     },
+    types::{
+        emit_stream_completion_events, emit_stream_events_for_response,
+        stream_event_handler_from_deltas,
+    },
     ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, MediaSource, Message,
-    MessageRole, StreamDeltaHandler, TauAiError, ToolChoice, ToolDefinition,
+    MessageRole, StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError, ToolChoice,
+    ToolDefinition,
 };
 
 const DEFAULT_OPENROUTER_X_TITLE: &str = "tau-rs";
+const OPENAI_PROVIDER: &str = "openai";
 
 fn non_empty_env_var(name: &str) -> Option<String> {
     std::env::var(name).ok().and_then(|value| {
@@ -173,7 +181,16 @@ impl LlmClient for OpenAiClient {
         request: ChatRequest,
         on_delta: Option<StreamDeltaHandler>,
     ) -> Result<ChatResponse, TauAiError> {
-        self.complete_with_mode(request, on_delta).await
+        self.complete_with_mode(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_with_mode(request, on_event).await
     }
 }
 
@@ -181,25 +198,35 @@ impl OpenAiClient {
     async fn complete_with_mode(
         &self,
         request: ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        ChatCompletionSpan::start("openai", &request)
+            .instrument(self.complete_untraced(request, on_event))
+            .await
+    }
+
+    async fn complete_untraced(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         if model_prefers_responses_api(&request.model) {
             match self
-                .complete_via_responses(&request, on_delta.clone())
+                .complete_via_responses(&request, on_event.clone())
                 .await
             {
                 Ok(response) => return Ok(response),
                 Err(error) if should_fallback_responses_error_to_chat(&error) => {
-                    return self.complete_via_chat(&request, on_delta).await;
+                    return self.complete_via_chat(&request, on_event).await;
                 }
                 Err(error) => return Err(error),
             }
         }
 
-        match self.complete_via_chat(&request, on_delta.clone()).await {
+        match self.complete_via_chat(&request, on_event.clone()).await {
             Ok(response) => Ok(response),
             Err(error) if should_fallback_chat_error_to_responses(&error) => {
-                self.complete_via_responses(&request, on_delta).await
+                self.complete_via_responses(&request, on_event).await
             }
             Err(error) => Err(error),
         }
@@ -208,10 +235,10 @@ impl OpenAiClient {
     async fn complete_via_chat(
         &self,
         request: &ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         let mut body = build_chat_request_body(request)?;
-        let stream_mode = on_delta.is_some();
+        let stream_mode = on_event.is_some();
         if stream_mode {
             body["stream"] = json!(true);
         }
@@ -225,7 +252,8 @@ impl OpenAiClient {
                 .client
                 .post(&url)
                 .header("x-tau-request-id", request_id)
-                .header("x-tau-retry-attempt", attempt.to_string());
+                .header("x-tau-retry-attempt", attempt.to_string())
+                .headers(trace_propagation_headers());
             if let Some(api_version) = self.config.api_version.as_deref() {
                 request_builder = request_builder.query(&[("api-version", api_version)]);
             }
@@ -234,8 +262,9 @@ impl OpenAiClient {
             match response {
                 Ok(response) => {
                     let status = response.status();
+                    observe_rate_limit_headers(status.as_u16(), response.headers());
                     if status.is_success() {
-                        if let Some(delta_handler) = on_delta.clone() {
+                        if let Some(event_handler) = on_event.clone() {
                             let is_event_stream = response
                                 .headers()
                                 .get(CONTENT_TYPE)
@@ -245,15 +274,12 @@ impl OpenAiClient {
                                 })
                                 .unwrap_or(false);
                             if is_event_stream {
-                                return parse_chat_stream_response(response, delta_handler).await;
+                                return parse_chat_stream_response(response, event_handler).await;
                             }
 
                             let raw = response.text().await?;
                             let parsed = parse_chat_response(&raw)?;
-                            let text = parsed.message.text_content();
-                            if !text.is_empty() {
-                                delta_handler(text);
-                            }
+                            emit_stream_events_for_response(&event_handler, &parsed);
                             return Ok(parsed);
                         }
                         let raw = response.text().await?;
@@ -311,7 +337,7 @@ impl OpenAiClient {
     async fn complete_via_responses(
         &self,
         request: &ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         let body = build_responses_request_body(request)?;
         let url = self.responses_url();
@@ -324,7 +350,8 @@ impl OpenAiClient {
                 .client
                 .post(&url)
                 .header("x-tau-request-id", request_id)
-                .header("x-tau-retry-attempt", attempt.to_string());
+                .header("x-tau-retry-attempt", attempt.to_string())
+                .headers(trace_propagation_headers());
             if let Some(api_version) = self.config.api_version.as_deref() {
                 request_builder = request_builder.query(&[("api-version", api_version)]);
             }
@@ -333,14 +360,12 @@ impl OpenAiClient {
             match response {
                 Ok(response) => {
                     let status = response.status();
+                    observe_rate_limit_headers(status.as_u16(), response.headers());
                     if status.is_success() {
                         let raw = response.text().await?;
                         let parsed = parse_responses_api_response(&raw)?;
-                        if let Some(delta_handler) = on_delta.clone() {
-                            let text = parsed.message.text_content();
-                            if !text.is_empty() {
-                                delta_handler(text);
-                            }
+                        if let Some(event_handler) = on_event.as_ref() {
+                            emit_stream_events_for_response(event_handler, &parsed);
                         }
                         return Ok(parsed);
                     }
@@ -421,6 +446,10 @@ fn build_chat_request_body(request: &ChatRequest) -> Result<Value, TauAiError> {
         body["max_tokens"] = json!(max_tokens);
     }
 
+    if let Some(effort) = request.reasoning.resolved_effort() {
+        body["reasoning_effort"] = json!(effort.as_str());
+    }
+
     apply_openai_prompt_cache_fields(&mut body, request);
 
     Ok(body)
@@ -455,6 +484,16 @@ fn build_responses_request_body(request: &ChatRequest) -> Result<Value, TauAiErr
         body["max_output_tokens"] = json!(max_tokens);
     }
 
+    if let Some(effort) = request.reasoning.resolved_effort() {
+        // Encrypted reasoning items are requested so they can be replayed on later turns
+        // without relying on server-side response storage.
+        body["reasoning"] = json!({
+            "effort": effort.as_str(),
+            "summary": "auto",
+        });
+        body["include"] = json!(["reasoning.encrypted_content"]);
+    }
+
     apply_openai_prompt_cache_fields(&mut body, request);
 
     Ok(body)
@@ -577,6 +616,15 @@ fn to_openai_responses_input(messages: &[Message]) -> Result<Value, TauAiError>
     for message in messages {
         match message.role {
             MessageRole::System | MessageRole::User | MessageRole::Assistant => {
+                if matches!(message.role, MessageRole::Assistant) {
+                    input_items.extend(
+                        message
+                            .content
+                            .iter()
+                            .filter_map(to_openai_responses_reasoning_item),
+                    );
+                }
+
                 let text = flatten_message_with_media_markers(message);
                 if !text.trim().is_empty() {
                     input_items.push(json!({
@@ -620,6 +668,35 @@ fn to_openai_responses_input(messages: &[Message]) -> Result<Value, TauAiError>
     Ok(Value::Array(input_items))
 }
 
+fn to_openai_responses_reasoning_item(block: &ContentBlock) -> Option<Value> {
+    if !block.is_thinking_from(OPENAI_PROVIDER) {
+        return None;
+    }
+    let ContentBlock::Thinking {
+        text,
+        signature,
+        id: Some(id),
+        ..
+    } = block
+    else {
+        return None;
+    };
+    let summary = if text.trim().is_empty() {
+        Vec::new()
+    } else {
+        vec![json!({ "type": "summary_text", "text": text })]
+    };
+    let mut item = json!({
+        "type": "reasoning",
+        "id": id,
+        "summary": summary,
+    });
+    if let Some(encrypted_content) = signature {
+        item["encrypted_content"] = json!(encrypted_content);
+    }
+    Some(item)
+}
+
 fn to_openai_messages(messages: &[Message]) -> Result<Vec<Value>, TauAiError> {
     let mut serialized = Vec::new();
 
@@ -695,10 +772,12 @@ fn to_openai_messages(messages: &[Message]) -> Result<Vec<Value>, TauAiError> {
 }
 
 fn to_openai_user_content(message: &Message) -> Value {
-    let has_non_text_block = message
-        .content
-        .iter()
-        .any(|block| !matches!(block, ContentBlock::Text { .. }));
+    let has_non_text_block = message.content.iter().any(|block| {
+        !matches!(
+            block,
+            ContentBlock::Text { .. } | ContentBlock::Thinking { .. }
+        )
+    });
     if !has_non_text_block {
         return Value::String(message.text_content());
     }
@@ -723,7 +802,7 @@ fn to_openai_user_content(message: &Message) -> Value {
                     "text": format!("[tau-audio:{}]", media_source_descriptor(source)),
                 }));
             }
-            ContentBlock::ToolCall { .. } => {}
+            ContentBlock::ToolCall { .. } | ContentBlock::Thinking { .. } => {}
         }
     }
 
@@ -775,7 +854,7 @@ fn flatten_message_with_media_markers(message: &Message) -> String {
                     parts.push(text.clone());
                 }
             }
-            ContentBlock::ToolCall { .. } => {}
+            ContentBlock::ToolCall { .. } | ContentBlock::Thinking { .. } => {}
             ContentBlock::Image { source } => {
                 parts.push(format!("[tau-image:{}]", media_source_descriptor(source)));
             }
@@ -803,7 +882,16 @@ fn parse_chat_response(raw: &str) -> Result<ChatResponse, TauAiError> {
             TauAiError::InvalidResponse("response contained no choices".to_string())
         })?;
 
-    let mut content = parse_openai_content_blocks(&choice.message.content);
+    let mut content = Vec::new();
+    if let Some(reasoning) = choice
+        .message
+        .reasoning_content
+        .or(choice.message.reasoning)
+        .filter(|reasoning| !reasoning.trim().is_empty())
+    {
+        content.push(ContentBlock::thinking(OPENAI_PROVIDER, reasoning, None));
+    }
+    content.extend(parse_openai_content_blocks(&choice.message.content));
 
     if let Some(tool_calls) = choice.message.tool_calls {
         for tool_call in tool_calls {
@@ -844,6 +932,14 @@ fn parse_chat_response(raw: &str) -> Result<ChatResponse, TauAiError> {
                 .as_ref()
                 .and_then(|details| details.cached_tokens)
                 .unwrap_or_default(),
+            reasoning_tokens: usage
+                .completion_tokens_details
+                .as_ref()
+                .and_then(|details| details.reasoning_tokens)
+                .unwrap_or_default(),
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
         })
         .unwrap_or_default();
 
@@ -864,6 +960,22 @@ fn parse_responses_api_response(raw: &str) -> Result<ChatResponse, TauAiError> {
                 Some("message") => {
                     content.extend(parse_openai_content_blocks(&output_item.content));
                 }
+                Some("reasoning") => {
+                    let text = output_item
+                        .summary
+                        .iter()
+                        .flatten()
+                        .filter_map(|part| part.get("text").and_then(Value::as_str))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    content.push(ContentBlock::Thinking {
+                        text,
+                        signature: output_item.encrypted_content,
+                        id: output_item.id,
+                        redacted: false,
+                        provider: Some(OPENAI_PROVIDER.to_string()),
+                    });
+                }
                 Some("function_call") => {
                     let Some(name) = output_item.name else {
                         continue;
@@ -890,7 +1002,11 @@ fn parse_responses_api_response(raw: &str) -> Result<ChatResponse, TauAiError> {
     {
         if let Some(output_text) = parsed.output_text {
             if !output_text.trim().is_empty() {
-                content.insert(0, ContentBlock::Text { text: output_text });
+                let insert_at = content
+                    .iter()
+                    .take_while(|block| matches!(block, ContentBlock::Thinking { .. }))
+                    .count();
+                content.insert(insert_at, ContentBlock::Text { text: output_text });
             }
         }
     }
@@ -916,6 +1032,14 @@ fn parse_responses_api_response(raw: &str) -> Result<ChatResponse, TauAiError> {
                     .as_ref()
                     .and_then(|details| details.cached_tokens)
                     .unwrap_or_default(),
+                reasoning_tokens: usage
+                    .output_tokens_details
+                    .as_ref()
+                    .and_then(|details| details.reasoning_tokens)
+                    .unwrap_or_default(),
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             }
         })
         .unwrap_or_default();
@@ -949,12 +1073,22 @@ fn parse_tool_call_arguments(arguments: Option<&str>) -> Value {
 
 async fn parse_chat_stream_response(
     response: reqwest::Response,
-    on_delta: StreamDeltaHandler,
+    on_event: StreamEventHandler,
+) -> Result<ChatResponse, TauAiError> {
+    let parsed = collect_chat_stream_response(response, &on_event).await?;
+    emit_stream_completion_events(&on_event, &parsed);
+    Ok(parsed)
+}
+
+async fn collect_chat_stream_response(
+    response: reqwest::Response,
+    on_event: &StreamEventHandler,
 ) -> Result<ChatResponse, TauAiError> {
     let mut stream = response.bytes_stream();
     let mut buffer = String::new();
     let mut finish_reason = None;
     let mut text = String::new();
+    let mut reasoning = String::new();
     let mut tool_calls: Vec<OpenAiToolCallAccumulator> = Vec::new();
     let mut usage = ChatUsage::default();
 
@@ -977,6 +1111,7 @@ async fn parse_chat_stream_response(
                 if data == "[DONE]" {
                     return Ok(finalize_stream_response(
                         text,
+                        reasoning,
                         tool_calls,
                         finish_reason,
                         usage,
@@ -985,8 +1120,9 @@ async fn parse_chat_stream_response(
 
                 apply_stream_data(
                     data,
-                    &on_delta,
+                    on_event,
                     &mut text,
+                    &mut reasoning,
                     &mut tool_calls,
                     &mut finish_reason,
                     &mut usage,
@@ -1002,8 +1138,9 @@ async fn parse_chat_stream_response(
             if data != "[DONE]" {
                 apply_stream_data(
                     data,
-                    &on_delta,
+                    on_event,
                     &mut text,
+                    &mut reasoning,
                     &mut tool_calls,
                     &mut finish_reason,
                     &mut usage,
@@ -1014,6 +1151,7 @@ async fn parse_chat_stream_response(
 
     Ok(finalize_stream_response(
         text,
+        reasoning,
         tool_calls,
         finish_reason,
         usage,
@@ -1022,8 +1160,9 @@ async fn parse_chat_stream_response(
 
 fn apply_stream_data(
     data: &str,
-    on_delta: &StreamDeltaHandler,
+    on_event: &StreamEventHandler,
     text: &mut String,
+    reasoning: &mut String,
     tool_calls: &mut Vec<OpenAiToolCallAccumulator>,
     finish_reason: &mut Option<String>,
     usage: &mut ChatUsage,
@@ -1041,6 +1180,11 @@ fn apply_stream_data(
             .as_ref()
             .and_then(|details| details.cached_tokens)
             .unwrap_or_default();
+        usage.reasoning_tokens = chunk_usage
+            .completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.reasoning_tokens)
+            .unwrap_or_default();
     }
 
     for choice in chunk.choices {
@@ -1052,10 +1196,19 @@ fn apply_stream_data(
             continue;
         };
 
+        if let Some(delta_reasoning) = delta.reasoning_content.or(delta.reasoning) {
+            if !delta_reasoning.is_empty() {
+                reasoning.push_str(&delta_reasoning);
+                on_event(StreamEvent::ReasoningDelta {
+                    text: delta_reasoning,
+                });
+            }
+        }
+
         if let Some(delta_text) = delta.content {
             if !delta_text.is_empty() {
                 text.push_str(&delta_text);
-                on_delta(delta_text);
+                on_event(StreamEvent::TextDelta { text: delta_text });
             }
         }
 
@@ -1078,8 +1231,23 @@ fn apply_stream_data(
                             current.name = name;
                         }
                     }
+                    if !current.started && !current.name.is_empty() {
+                        current.started = true;
+                        on_event(StreamEvent::ToolCallStart {
+                            index,
+                            id: current.id.clone(),
+                            name: current.name.clone(),
+                        });
+                    }
                     if let Some(arguments) = function.arguments {
                         current.arguments.push_str(&arguments);
+                        if !arguments.is_empty() {
+                            on_event(StreamEvent::ToolCallArgumentsDelta {
+                                index,
+                                id: current.id.clone(),
+                                delta: arguments,
+                            });
+                        }
                     }
                 }
             }
@@ -1091,11 +1259,15 @@ fn apply_stream_data(
 
 fn finalize_stream_response(
     text: String,
+    reasoning: String,
     tool_calls: Vec<OpenAiToolCallAccumulator>,
     finish_reason: Option<String>,
     usage: ChatUsage,
 ) -> ChatResponse {
     let mut content = Vec::new();
+    if !reasoning.trim().is_empty() {
+        content.push(ContentBlock::thinking(OPENAI_PROVIDER, reasoning, None));
+    }
     if !text.trim().is_empty() {
         content.push(ContentBlock::Text { text });
     }
@@ -1274,6 +1446,8 @@ struct OpenAiResponsesOutputItem {
     call_id: Option<String>,
     arguments: Option<String>,
     content: Option<Value>,
+    summary: Option<Vec<Value>>,
+    encrypted_content: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1284,6 +1458,7 @@ struct OpenAiResponsesUsage {
     prompt_tokens: Option<u64>,
     completion_tokens: Option<u64>,
     input_tokens_details: Option<OpenAiInputTokenDetails>,
+    output_tokens_details: Option<OpenAiOutputTokenDetails>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1301,6 +1476,8 @@ struct OpenAiChoice {
 #[derive(Debug, Deserialize)]
 struct OpenAiChoiceMessage {
     content: Option<Value>,
+    reasoning_content: Option<String>,
+    reasoning: Option<String>,
     tool_calls: Option<Vec<OpenAiToolCall>>,
 }
 
@@ -1324,6 +1501,7 @@ struct OpenAiUsage {
     completion_tokens: u64,
     total_tokens: u64,
     prompt_tokens_details: Option<OpenAiInputTokenDetails>,
+    completion_tokens_details: Option<OpenAiOutputTokenDetails>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1331,6 +1509,11 @@ struct OpenAiInputTokenDetails {
     cached_tokens: Option<u64>,
 }
 
+#[derive(Debug, Deserialize)]
+struct OpenAiOutputTokenDetails {
+    reasoning_tokens: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 struct OpenAiStreamChunk {
     choices: Vec<OpenAiStreamChoice>,
@@ -1346,6 +1529,8 @@ struct OpenAiStreamChoice {
 #[derive(Debug, Deserialize)]
 struct OpenAiStreamDelta {
     content: Option<String>,
+    reasoning_content: Option<String>,
+    reasoning: Option<String>,
     tool_calls: Option<Vec<OpenAiStreamToolCallDelta>>,
 }
 
@@ -1367,6 +1552,7 @@ struct OpenAiToolCallAccumulator {
     id: String,
     name: String,
     arguments: String,
+    started: bool,
 }
 
 #[cfg(test)]
@@ -1379,8 +1565,8 @@ mod tests {
         finalize_stream_response, parse_chat_response, parse_responses_api_response,
     };
     use crate::{
-        ChatRequest, ContentBlock, Message, MessageRole, PromptCacheConfig, ToolChoice,
-        ToolDefinition,
+        ChatRequest, ContentBlock, Message, MessageRole, PromptCacheConfig, StreamEvent,
+        ToolChoice, ToolDefinition,
     };
 
     #[test]
@@ -1413,6 +1599,8 @@ mod tests {
             max_tokens: Some(512),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_chat_request_body(&request).expect("request body must serialize");
@@ -1443,6 +1631,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: PromptCacheConfig::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_chat_request_body(&request).expect("request body must serialize");
@@ -1466,6 +1656,8 @@ mod tests {
                 retention: None,
                 google_cached_content: None,
             },
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_chat_request_body(&request).expect("request body must serialize");
@@ -1483,6 +1675,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_chat_request_body(&request).expect("request body must serialize");
@@ -1593,6 +1787,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body = build_chat_request_body(&request).expect("request body must serialize");
@@ -1649,10 +1845,11 @@ mod tests {
     fn functional_stream_chunk_parsing_appends_deltas_and_tool_calls() {
         let emitted = Arc::new(Mutex::new(String::new()));
         let sink_emitted = emitted.clone();
-        let sink: crate::StreamDeltaHandler = Arc::new(move |delta: String| {
+        let sink = crate::stream_event_handler_from_deltas(Arc::new(move |delta: String| {
             sink_emitted.lock().expect("delta lock").push_str(&delta);
-        });
+        }));
         let mut text = String::new();
+        let mut reasoning = String::new();
         let mut tool_calls = Vec::new();
         let mut finish_reason = None;
         let mut usage = crate::ChatUsage::default();
@@ -1661,6 +1858,7 @@ mod tests {
             r#"{"choices":[{"delta":{"content":"Hel"}}]}"#,
             &sink,
             &mut text,
+            &mut reasoning,
             &mut tool_calls,
             &mut finish_reason,
             &mut usage,
@@ -1670,6 +1868,7 @@ mod tests {
             r#"{"choices":[{"delta":{"content":"lo","tool_calls":[{"index":0,"id":"call_1","function":{"name":"read","arguments":"{\"path\":\"README"}}]}}]}"#,
             &sink,
             &mut text,
+            &mut reasoning,
             &mut tool_calls,
             &mut finish_reason,
             &mut usage,
@@ -1679,6 +1878,7 @@ mod tests {
             r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"function":{"arguments":".md\"}"}}]},"finish_reason":"tool_calls"}],"usage":{"prompt_tokens":4,"completion_tokens":3,"total_tokens":7}}"#,
             &sink,
             &mut text,
+            &mut reasoning,
             &mut tool_calls,
             &mut finish_reason,
             &mut usage,
@@ -1692,7 +1892,7 @@ mod tests {
         assert_eq!(tool_calls.len(), 1);
         assert_eq!(tool_calls[0].name, "read");
 
-        let response = finalize_stream_response(text, tool_calls, finish_reason, usage);
+        let response = finalize_stream_response(text, reasoning, tool_calls, finish_reason, usage);
         assert_eq!(response.message.tool_calls().len(), 1);
         assert_eq!(
             response.message.tool_calls()[0].arguments,
@@ -1700,10 +1900,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn functional_stream_chunk_parsing_emits_typed_tool_call_events_and_completion() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let sink: crate::StreamEventHandler = Arc::new(move |event| {
+            sink_events.lock().expect("event lock").push(event);
+        });
+        let mut text = String::new();
+        let mut reasoning = String::new();
+        let mut tool_calls = Vec::new();
+        let mut finish_reason = None;
+        let mut usage = crate::ChatUsage::default();
+
+        for data in [
+            r#"{"choices":[{"delta":{"reasoning_content":"Need file."}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_1","function":{"name":"read","arguments":"{\"path\":"}}]}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"function":{"arguments":"\"README.md\"}"}}]},"finish_reason":"tool_calls"}]}"#,
+        ] {
+            apply_stream_data(
+                data,
+                &sink,
+                &mut text,
+                &mut reasoning,
+                &mut tool_calls,
+                &mut finish_reason,
+                &mut usage,
+            )
+            .expect("stream chunk parse");
+        }
+        let response = finalize_stream_response(text, reasoning, tool_calls, finish_reason, usage);
+        crate::emit_stream_completion_events(&sink, &response);
+
+        let events = events.lock().expect("event lock").clone();
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::ReasoningDelta {
+                    text: "Need file.".to_string(),
+                },
+                StreamEvent::ToolCallStart {
+                    index: 0,
+                    id: "call_1".to_string(),
+                    name: "read".to_string(),
+                },
+                StreamEvent::ToolCallArgumentsDelta {
+                    index: 0,
+                    id: "call_1".to_string(),
+                    delta: r#"{"path":"#.to_string(),
+                },
+                StreamEvent::ToolCallArgumentsDelta {
+                    index: 0,
+                    id: "call_1".to_string(),
+                    delta: r#""README.md"}"#.to_string(),
+                },
+                StreamEvent::ToolCallEnd {
+                    index: 0,
+                    id: "call_1".to_string(),
+                    name: "read".to_string(),
+                    arguments: json!({"path":"README.md"}),
+                },
+                StreamEvent::Usage {
+                    usage: crate::ChatUsage::default(),
+                },
+                StreamEvent::Stop {
+                    finish_reason: Some("tool_calls".to_string()),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn regression_stream_chunk_parse_returns_actionable_error() {
-        let sink: crate::StreamDeltaHandler = Arc::new(|_delta: String| {});
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
         let mut text = String::new();
+        let mut reasoning = String::new();
         let mut tool_calls = Vec::new();
         let mut finish_reason = None;
         let mut usage = crate::ChatUsage::default();
@@ -1712,6 +1983,7 @@ mod tests {
             r#"{"choices":[{"delta":{"content":"hi"}}"#,
             &sink,
             &mut text,
+            &mut reasoning,
             &mut tool_calls,
             &mut finish_reason,
             &mut usage,
@@ -1811,6 +2083,8 @@ mod tests {
             max_tokens: Some(64),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let body =
@@ -1823,4 +2097,136 @@ mod tests {
         assert_eq!(body["input"][2]["call_id"], "call_1");
         assert_eq!(body["tools"][0]["name"], "read_file");
     }
+
+    #[test]
+    fn functional_parses_responses_reasoning_items_and_replays_encrypted_content() {
+        let raw = r#"{
+            "status": "completed",
+            "output": [
+                {
+                    "type": "reasoning",
+                    "id": "rs_1",
+                    "summary": [{"type":"summary_text","text":"Need the README."}],
+                    "encrypted_content": "enc-blob"
+                },
+                {
+                    "type": "function_call",
+                    "call_id": "call_1",
+                    "name": "read_file",
+                    "arguments": "{\"path\":\"README.md\"}"
+                }
+            ],
+            "usage": {
+                "input_tokens": 9,
+                "output_tokens": 40,
+                "total_tokens": 49,
+                "output_tokens_details": {"reasoning_tokens": 32}
+            }
+        }"#;
+
+        let response = parse_responses_api_response(raw).expect("responses payload should parse");
+        assert_eq!(response.message.thinking_content(), "Need the README.");
+        assert_eq!(response.usage.reasoning_tokens, 32);
+
+        let request = ChatRequest {
+            model: "gpt-5.2-codex".to_string(),
+            messages: vec![
+                Message::user("read it"),
+                response.message,
+                Message::tool_result("call_1", "read_file", "contents", false),
+            ],
+            tools: vec![],
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: crate::ReasoningConfig {
+                budget_tokens: None,
+                effort: Some(crate::ReasoningEffort::High),
+            },
+            model_tier: None,
+        };
+        let body =
+            build_responses_request_body(&request).expect("responses request body must serialize");
+        assert_eq!(body["reasoning"]["effort"], "high");
+        assert_eq!(body["include"][0], "reasoning.encrypted_content");
+        assert_eq!(body["input"][1]["type"], "reasoning");
+        assert_eq!(body["input"][1]["id"], "rs_1");
+        assert_eq!(body["input"][1]["encrypted_content"], "enc-blob");
+        assert_eq!(body["input"][1]["summary"][0]["text"], "Need the README.");
+        assert_eq!(body["input"][2]["type"], "function_call");
+    }
+
+    #[test]
+    fn functional_chat_reasoning_content_is_parsed_and_not_replayed() {
+        let raw = r#"{
+            "choices": [{
+                "message": {"content": "42", "reasoning_content": "Compute it."},
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 10,
+                "completion_tokens": 20,
+                "total_tokens": 30,
+                "completion_tokens_details": {"reasoning_tokens": 18}
+            }
+        }"#;
+
+        let response = parse_chat_response(raw).expect("response must parse");
+        assert_eq!(response.message.text_content(), "42");
+        assert_eq!(response.message.thinking_content(), "Compute it.");
+        assert_eq!(response.usage.reasoning_tokens, 18);
+
+        let request = ChatRequest {
+            model: "deepseek-reasoner".to_string(),
+            messages: vec![Message::user("answer"), response.message],
+            tools: vec![],
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: crate::ReasoningConfig {
+                budget_tokens: Some(4_096),
+                effort: None,
+            },
+            model_tier: None,
+        };
+        let body = build_chat_request_body(&request).expect("request body must serialize");
+        assert_eq!(body["reasoning_effort"], "low");
+        assert_eq!(body["messages"][1]["content"], "42");
+    }
+
+    #[test]
+    fn functional_stream_chunk_parsing_collects_reasoning_deltas() {
+        let sink: crate::StreamEventHandler = Arc::new(|_event| {});
+        let mut text = String::new();
+        let mut reasoning = String::new();
+        let mut tool_calls = Vec::new();
+        let mut finish_reason = None;
+        let mut usage = crate::ChatUsage::default();
+
+        for data in [
+            r#"{"choices":[{"delta":{"reasoning_content":"Think "}}]}"#,
+            r#"{"choices":[{"delta":{"reasoning_content":"first."}}]}"#,
+            r#"{"choices":[{"delta":{"content":"Done"},"finish_reason":"stop"}],"usage":{"prompt_tokens":3,"completion_tokens":5,"total_tokens":8,"completion_tokens_details":{"reasoning_tokens":4}}}"#,
+        ] {
+            apply_stream_data(
+                data,
+                &sink,
+                &mut text,
+                &mut reasoning,
+                &mut tool_calls,
+                &mut finish_reason,
+                &mut usage,
+            )
+            .expect("stream chunk parse");
+        }
+
+        assert_eq!(usage.reasoning_tokens, 4);
+        let response = finalize_stream_response(text, reasoning, tool_calls, finish_reason, usage);
+        assert_eq!(response.message.text_content(), "Done");
+        assert_eq!(response.message.thinking_content(), "Think first.");
+    }
 }
@@ -9,6 +9,9 @@ pub enum Provider {
     OpenRouter,
     Anthropic,
     Google,
+    Bedrock,
+    Vertex,
+    Local,
 }
 
 impl Provider {
@@ -18,6 +21,9 @@ impl Provider {
             Provider::OpenRouter => "openrouter",
             Provider::Anthropic => "anthropic",
             Provider::Google => "google",
+            Provider::Bedrock => "bedrock",
+            Provider::Vertex => "vertex",
+            Provider::Local => "local",
         }
     }
 }
@@ -33,7 +39,7 @@ impl fmt::Display for Provider {
 pub enum ModelRefParseError {
     #[error("missing model identifier")]
     MissingModel,
-    #[error("unsupported provider '{0}'. Supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google")]
+    #[error("unsupported provider '{0}'. Supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google, bedrock, aws-bedrock (alias), vertex, vertex-ai (alias), local, ollama (alias), llamacpp/llama.cpp (alias), vllm (alias)")]
     UnsupportedProvider(String),
 }
 
@@ -49,6 +55,11 @@ impl FromStr for Provider {
             "openrouter" => Ok(Provider::OpenRouter),
             "anthropic" => Ok(Provider::Anthropic),
             "google" | "gemini" => Ok(Provider::Google),
+            "bedrock" | "aws-bedrock" | "amazon-bedrock" => Ok(Provider::Bedrock),
+            "vertex" | "vertex-ai" | "vertexai" | "google-vertex" => Ok(Provider::Vertex),
+            "local" | "ollama" | "llamacpp" | "llama.cpp" | "llama-cpp" | "vllm" => {
+                Ok(Provider::Local)
+            }
             _ => Err(ModelRefParseError::UnsupportedProvider(value.to_string())),
         }
     }
@@ -147,6 +158,33 @@ mod tests {
         assert_eq!(parsed.model, "gpt-4o");
     }
 
+    #[test]
+    fn parses_local_inference_servers_as_first_class_provider() {
+        let parsed = ModelRef::parse("local/qwen2.5-coder:7b").expect("valid model ref");
+        assert_eq!(parsed.provider, Provider::Local);
+        assert_eq!(parsed.model, "qwen2.5-coder:7b");
+
+        for alias in ["ollama", "llamacpp", "llama.cpp", "vllm"] {
+            let parsed = ModelRef::parse(&format!("{alias}/meta-llama/Llama-3.1-8B-Instruct"))
+                .expect("valid model ref");
+            assert_eq!(parsed.provider, Provider::Local);
+            assert_eq!(parsed.model, "meta-llama/Llama-3.1-8B-Instruct");
+        }
+    }
+
+    #[test]
+    fn parses_bedrock_and_vertex_model_refs() {
+        let parsed = ModelRef::parse("bedrock/anthropic.claude-3-5-sonnet-20241022-v2:0")
+            .expect("valid model ref");
+        assert_eq!(parsed.provider, Provider::Bedrock);
+        assert_eq!(parsed.model, "anthropic.claude-3-5-sonnet-20241022-v2:0");
+
+        let parsed =
+            ModelRef::parse("vertex-ai/claude-sonnet-4@20250514").expect("valid model ref");
+        assert_eq!(parsed.provider, Provider::Vertex);
+        assert_eq!(parsed.model, "claude-sonnet-4@20250514");
+    }
+
     #[test]
     fn errors_on_unsupported_provider() {
         let error = ModelRef::parse("foo/model").expect_err("must reject unknown provider");
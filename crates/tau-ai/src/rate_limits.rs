@@ -0,0 +1,229 @@
+//! Provider rate-limit header tracking and credential pool health reporting.
+//!
+//! HTTP provider clients report every response's status and rate-limit headers
+//! to the task's [`RateLimitObserver`] (installed with
+//! [`with_rate_limit_observer`]), which is how credential pools learn each key's
+//! remaining quota. Pools publish their health through
+//! [`register_provider_key_pool`] so status surfaces can render it without
+//! depending on the pool implementation.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+
+use crate::retry::parse_retry_after_ms;
+
+/// Callback receiving the rate-limit snapshot of each provider HTTP response.
+pub type RateLimitObserver = Arc<dyn Fn(RateLimitSnapshot) + Send + Sync>;
+
+tokio::task_local! {
+    static CURRENT_RATE_LIMIT_OBSERVER: RateLimitObserver;
+}
+
+/// Status and quota headers from one provider HTTP response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitSnapshot {
+    pub status: u16,
+    pub limit_requests: Option<u64>,
+    pub remaining_requests: Option<u64>,
+    pub limit_tokens: Option<u64>,
+    pub remaining_tokens: Option<u64>,
+    pub retry_after_ms: Option<u64>,
+}
+
+/// Parses OpenAI (`x-ratelimit-*`) and Anthropic (`anthropic-ratelimit-*`) quota headers.
+pub fn parse_rate_limit_snapshot(status: u16, headers: &HeaderMap) -> RateLimitSnapshot {
+    RateLimitSnapshot {
+        status,
+        limit_requests: first_numeric_header(
+            headers,
+            &[
+                "x-ratelimit-limit-requests",
+                "anthropic-ratelimit-requests-limit",
+            ],
+        ),
+        remaining_requests: first_numeric_header(
+            headers,
+            &[
+                "x-ratelimit-remaining-requests",
+                "anthropic-ratelimit-requests-remaining",
+            ],
+        ),
+        limit_tokens: first_numeric_header(
+            headers,
+            &[
+                "x-ratelimit-limit-tokens",
+                "anthropic-ratelimit-tokens-limit",
+            ],
+        ),
+        remaining_tokens: first_numeric_header(
+            headers,
+            &[
+                "x-ratelimit-remaining-tokens",
+                "anthropic-ratelimit-tokens-remaining",
+            ],
+        ),
+        retry_after_ms: parse_retry_after_ms(headers),
+    }
+}
+
+fn first_numeric_header(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names.iter().find_map(|name| {
+        headers
+            .get(*name)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+    })
+}
+
+/// Runs `future` with `observer` receiving rate-limit snapshots of provider responses.
+pub async fn with_rate_limit_observer<F>(observer: RateLimitObserver, future: F) -> F::Output
+where
+    F: Future,
+{
+    CURRENT_RATE_LIMIT_OBSERVER.scope(observer, future).await
+}
+
+/// Reports a provider response's status and headers to the task's observer, if any.
+pub fn observe_rate_limit_headers(status: u16, headers: &HeaderMap) {
+    let _ = CURRENT_RATE_LIMIT_OBSERVER.try_with(|observer| {
+        observer(parse_rate_limit_snapshot(status, headers));
+    });
+}
+
+/// Health of one credential in a provider key pool.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ProviderKeyHealth {
+    pub label: String,
+    pub state: String,
+    pub quarantine_reason: Option<String>,
+    pub quarantined_until_unix_ms: Option<u64>,
+    pub in_flight: u64,
+    pub requests_total: u64,
+    pub failures_total: u64,
+    pub last_status: Option<u16>,
+    pub limit_requests: Option<u64>,
+    pub remaining_requests: Option<u64>,
+    pub limit_tokens: Option<u64>,
+    pub remaining_tokens: Option<u64>,
+}
+
+/// Health of one provider key pool.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ProviderKeyPoolHealth {
+    pub provider: String,
+    pub keys_total: usize,
+    pub healthy_keys: usize,
+    pub quarantined_keys: usize,
+    pub keys: Vec<ProviderKeyHealth>,
+}
+
+/// Source of key pool health registered by credential pool clients.
+pub trait ProviderKeyPoolHealthSource: Send + Sync {
+    fn key_pool_health(&self) -> ProviderKeyPoolHealth;
+}
+
+fn key_pool_registry() -> &'static Mutex<Vec<Weak<dyn ProviderKeyPoolHealthSource>>> {
+    static REGISTRY: OnceLock<Mutex<Vec<Weak<dyn ProviderKeyPoolHealthSource>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Registers a live key pool; dropped pools disappear from snapshots.
+pub fn register_provider_key_pool(source: Weak<dyn ProviderKeyPoolHealthSource>) {
+    let mut registry = match key_pool_registry().lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    registry.retain(|entry| entry.strong_count() > 0);
+    registry.push(source);
+}
+
+/// Returns health for every live key pool in registration order.
+pub fn provider_key_pool_health_snapshot() -> Vec<ProviderKeyPoolHealth> {
+    let sources = {
+        let mut registry = match key_pool_registry().lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        registry.retain(|entry| entry.strong_count() > 0);
+        registry
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>()
+    };
+    sources
+        .iter()
+        .map(|source| source.key_pool_health())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn unit_parse_rate_limit_snapshot_reads_openai_and_anthropic_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ratelimit-limit-requests",
+            HeaderValue::from_static("500"),
+        );
+        headers.insert(
+            "x-ratelimit-remaining-requests",
+            HeaderValue::from_static("499"),
+        );
+        headers.insert(
+            "x-ratelimit-remaining-tokens",
+            HeaderValue::from_static("149984"),
+        );
+        headers.insert("retry-after", HeaderValue::from_static("2"));
+        let snapshot = parse_rate_limit_snapshot(200, &headers);
+        assert_eq!(snapshot.limit_requests, Some(500));
+        assert_eq!(snapshot.remaining_requests, Some(499));
+        assert_eq!(snapshot.remaining_tokens, Some(149_984));
+        assert_eq!(snapshot.limit_tokens, None);
+        assert_eq!(snapshot.retry_after_ms, Some(2_000));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "anthropic-ratelimit-tokens-limit",
+            HeaderValue::from_static("80000"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-remaining",
+            HeaderValue::from_static("not-a-number"),
+        );
+        let snapshot = parse_rate_limit_snapshot(429, &headers);
+        assert_eq!(snapshot.status, 429);
+        assert_eq!(snapshot.limit_tokens, Some(80_000));
+        assert_eq!(snapshot.remaining_requests, None);
+    }
+
+    #[tokio::test]
+    async fn functional_rate_limit_observer_is_scoped_to_the_task() {
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let sink = observed.clone();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ratelimit-remaining-requests",
+            HeaderValue::from_static("7"),
+        );
+
+        with_rate_limit_observer(
+            Arc::new(move |snapshot| sink.lock().expect("observed lock").push(snapshot)),
+            async { observe_rate_limit_headers(200, &headers) },
+        )
+        .await;
+        observe_rate_limit_headers(200, &headers);
+
+        let observed = observed.lock().expect("observed lock");
+        assert_eq!(observed.len(), 1);
+        assert_eq!(observed[0].remaining_requests, Some(7));
+    }
+}
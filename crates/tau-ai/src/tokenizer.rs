@@ -0,0 +1,398 @@
+//! Offline token counting for context budgeting, compaction, and cost estimates.
+//!
+//! OpenAI model families are counted exactly with the o200k/cl100k BPE
+//! vocabularies embedded in `tiktoken-rs`, so no network access is needed.
+//! Anthropic and Gemini do not publish their tokenizers; their counts are
+//! calibrated from the closest BPE family. Unknown models fall back to a
+//! character-class heuristic.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tiktoken_rs::CoreBPE;
+
+use crate::{ChatRequest, ContentBlock, MediaSource, Message, ToolDefinition};
+
+/// Claude tokenizes roughly 15% more tokens than cl100k for the same text.
+const ANTHROPIC_CL100K_RATIO: f64 = 1.15;
+/// Gemini's SentencePiece vocabulary tracks o200k closely, slightly above it.
+const GEMINI_O200K_RATIO: f64 = 1.05;
+const MESSAGE_OVERHEAD_TOKENS: u32 = 4;
+const TOOL_CALL_OVERHEAD_TOKENS: u32 = 4;
+const TOOL_DEFINITION_OVERHEAD_TOKENS: u32 = 12;
+const MEDIA_OVERHEAD_TOKENS: u32 = 8;
+const REQUEST_PRIMING_TOKENS: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// Tokenizer used to count tokens for a model.
+pub enum TokenizerFamily {
+    /// OpenAI `o200k_base` (gpt-4o, gpt-4.1, gpt-5, o-series).
+    O200kBase,
+    /// OpenAI `cl100k_base` (gpt-4, gpt-3.5, text-embedding-3).
+    Cl100kBase,
+    /// Claude models, calibrated from cl100k counts.
+    Anthropic,
+    /// Gemini models, calibrated from o200k counts.
+    Gemini,
+    /// Character-class estimate for models without a known tokenizer.
+    Heuristic,
+}
+
+impl TokenizerFamily {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenizerFamily::O200kBase => "o200k_base",
+            TokenizerFamily::Cl100kBase => "cl100k_base",
+            TokenizerFamily::Anthropic => "anthropic",
+            TokenizerFamily::Gemini => "gemini",
+            TokenizerFamily::Heuristic => "heuristic",
+        }
+    }
+
+    /// Infers the tokenizer from a model name or `provider/model` reference.
+    pub fn for_model(model: &str) -> Self {
+        let normalized = model.trim().to_ascii_lowercase();
+        let (provider, name) = match normalized.split_once('/') {
+            Some((provider, name)) => (Some(provider), name),
+            None => (None, normalized.as_str()),
+        };
+        // OpenRouter-style refs carry the upstream vendor after the provider.
+        let name = name.rsplit('/').next().unwrap_or(name);
+        if name.contains("claude") {
+            return TokenizerFamily::Anthropic;
+        }
+        if name.contains("gemini") || name.contains("gemma") {
+            return TokenizerFamily::Gemini;
+        }
+        if name.starts_with("gpt-4o")
+            || name.starts_with("chatgpt-4o")
+            || name.starts_with("gpt-4.1")
+            || name.starts_with("gpt-4.5")
+            || name.starts_with("gpt-5")
+            || name.starts_with("gpt-oss")
+            || is_openai_reasoning_model(name)
+        {
+            return TokenizerFamily::O200kBase;
+        }
+        if name.starts_with("gpt-4")
+            || name.starts_with("gpt-3.5")
+            || name.starts_with("text-embedding-3")
+            || name.starts_with("text-embedding-ada")
+        {
+            return TokenizerFamily::Cl100kBase;
+        }
+        match provider {
+            Some("anthropic") => TokenizerFamily::Anthropic,
+            Some("google" | "gemini" | "vertex") => TokenizerFamily::Gemini,
+            _ => TokenizerFamily::Heuristic,
+        }
+    }
+
+    /// Counts tokens in one text span.
+    pub fn count_text_tokens(&self, text: &str) -> u32 {
+        if text.is_empty() {
+            return 0;
+        }
+        match self {
+            TokenizerFamily::O200kBase => {
+                bpe_token_count(o200k_bpe(), text).unwrap_or_else(|| heuristic_text_tokens(text))
+            }
+            TokenizerFamily::Cl100kBase => {
+                bpe_token_count(cl100k_bpe(), text).unwrap_or_else(|| heuristic_text_tokens(text))
+            }
+            TokenizerFamily::Anthropic => bpe_token_count(cl100k_bpe(), text)
+                .map(|count| scale_token_count(count, ANTHROPIC_CL100K_RATIO))
+                .unwrap_or_else(|| heuristic_text_tokens(text)),
+            TokenizerFamily::Gemini => bpe_token_count(o200k_bpe(), text)
+                .map(|count| scale_token_count(count, GEMINI_O200K_RATIO))
+                .unwrap_or_else(|| heuristic_text_tokens(text)),
+            TokenizerFamily::Heuristic => heuristic_text_tokens(text),
+        }
+    }
+
+    /// Counts input tokens for a full request, plus its output reservation.
+    pub fn count_chat_request_tokens(&self, request: &ChatRequest) -> ChatRequestTokenCount {
+        let message_tokens = request.messages.iter().fold(0u32, |acc, message| {
+            acc.saturating_add(self.count_message_tokens(message))
+        });
+        let tool_tokens = request.tools.iter().fold(0u32, |acc, tool| {
+            acc.saturating_add(self.count_tool_definition_tokens(tool))
+        });
+        let input_tokens = message_tokens
+            .saturating_add(tool_tokens)
+            .saturating_add(REQUEST_PRIMING_TOKENS);
+        let total_tokens = input_tokens.saturating_add(request.max_tokens.unwrap_or(0));
+
+        ChatRequestTokenCount {
+            input_tokens,
+            total_tokens,
+        }
+    }
+
+    /// Counts tokens for one message including role and block overhead.
+    pub fn count_message_tokens(&self, message: &Message) -> u32 {
+        let mut total = MESSAGE_OVERHEAD_TOKENS;
+        for block in &message.content {
+            let block_tokens = match block {
+                ContentBlock::Text { text } => self.count_text_tokens(text),
+                ContentBlock::ToolCall {
+                    id,
+                    name,
+                    arguments,
+                } => self
+                    .count_text_tokens(id)
+                    .saturating_add(self.count_text_tokens(name))
+                    .saturating_add(self.count_json_tokens(arguments))
+                    .saturating_add(TOOL_CALL_OVERHEAD_TOKENS),
+                ContentBlock::Image { source } | ContentBlock::Audio { source } => self
+                    .count_media_source_tokens(source)
+                    .saturating_add(MEDIA_OVERHEAD_TOKENS),
+                ContentBlock::Thinking {
+                    text, signature, ..
+                } => self.count_text_tokens(text).saturating_add(
+                    signature
+                        .as_deref()
+                        .map(|signature| self.count_text_tokens(signature))
+                        .unwrap_or(0),
+                ),
+            };
+            total = total.saturating_add(block_tokens);
+        }
+        if let Some(tool_call_id) = &message.tool_call_id {
+            total = total.saturating_add(self.count_text_tokens(tool_call_id));
+        }
+        if let Some(tool_name) = &message.tool_name {
+            total = total.saturating_add(self.count_text_tokens(tool_name));
+        }
+        total
+    }
+
+    fn count_tool_definition_tokens(&self, definition: &ToolDefinition) -> u32 {
+        TOOL_DEFINITION_OVERHEAD_TOKENS
+            .saturating_add(self.count_text_tokens(&definition.name))
+            .saturating_add(self.count_text_tokens(&definition.description))
+            .saturating_add(self.count_json_tokens(&definition.parameters))
+    }
+
+    fn count_json_tokens(&self, value: &Value) -> u32 {
+        let rendered = serde_json::to_string(value).unwrap_or_else(|_| value.to_string());
+        self.count_text_tokens(&rendered)
+    }
+
+    fn count_media_source_tokens(&self, source: &MediaSource) -> u32 {
+        match source {
+            MediaSource::Url { url } => self.count_text_tokens(url),
+            // Inline media is billed by the provider's vision/audio encoder,
+            // not the text vocabulary, so keep the size-based estimate.
+            MediaSource::Base64 { mime_type, data } => self
+                .count_text_tokens(mime_type)
+                .saturating_add((data.len() as u32).saturating_div(3))
+                .saturating_add(2),
+        }
+    }
+}
+
+impl fmt::Display for TokenizerFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TokenizerFamily {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "o200k_base" | "o200k" => Ok(TokenizerFamily::O200kBase),
+            "cl100k_base" | "cl100k" => Ok(TokenizerFamily::Cl100kBase),
+            "anthropic" | "claude" => Ok(TokenizerFamily::Anthropic),
+            "gemini" | "google" => Ok(TokenizerFamily::Gemini),
+            "heuristic" => Ok(TokenizerFamily::Heuristic),
+            other => Err(format!(
+                "unsupported tokenizer '{other}'; expected o200k_base, cl100k_base, anthropic, gemini, or heuristic"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Token counts for one chat request.
+pub struct ChatRequestTokenCount {
+    pub input_tokens: u32,
+    /// Input tokens plus the request's `max_tokens` output reservation.
+    pub total_tokens: u32,
+}
+
+fn is_openai_reasoning_model(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('o') && chars.next().is_some_and(|digit| digit.is_ascii_digit())
+}
+
+fn o200k_bpe() -> Option<&'static CoreBPE> {
+    static BPE: OnceLock<Option<CoreBPE>> = OnceLock::new();
+    BPE.get_or_init(|| tiktoken_rs::o200k_base().ok()).as_ref()
+}
+
+fn cl100k_bpe() -> Option<&'static CoreBPE> {
+    static BPE: OnceLock<Option<CoreBPE>> = OnceLock::new();
+    BPE.get_or_init(|| tiktoken_rs::cl100k_base().ok()).as_ref()
+}
+
+fn bpe_token_count(bpe: Option<&CoreBPE>, text: &str) -> Option<u32> {
+    let tokens = bpe?.encode_ordinary(text).len();
+    Some(u32::try_from(tokens).unwrap_or(u32::MAX))
+}
+
+fn scale_token_count(count: u32, ratio: f64) -> u32 {
+    (f64::from(count) * ratio).ceil() as u32
+}
+
+fn heuristic_text_tokens(text: &str) -> u32 {
+    let len = text.len();
+    let ascii_count = text.bytes().filter(|b| b.is_ascii()).count();
+    let special_count = text
+        .bytes()
+        .filter(|b| !b.is_ascii_alphanumeric() && !b.is_ascii_whitespace())
+        .count();
+    let ascii_ratio = ascii_count as f64 / len.max(1) as f64;
+    let special_ratio = special_count as f64 / len.max(1) as f64;
+
+    let chars_per_token = if ascii_ratio > 0.9 {
+        if special_ratio > 0.2 {
+            3.5 // Code content
+        } else {
+            4.0 // Prose
+        }
+    } else if ascii_ratio > 0.5 {
+        3.0 // Mixed content
+    } else {
+        2.0 // Heavy Unicode
+    };
+
+    (len as f64 / chars_per_token).ceil() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_tokenizer_family_for_model_maps_known_families() {
+        for (model, expected) in [
+            ("openai/gpt-4o-mini", TokenizerFamily::O200kBase),
+            ("gpt-5.2", TokenizerFamily::O200kBase),
+            ("openai/o3-mini", TokenizerFamily::O200kBase),
+            ("openai/gpt-4-turbo", TokenizerFamily::Cl100kBase),
+            ("gpt-3.5-turbo", TokenizerFamily::Cl100kBase),
+            ("anthropic/claude-sonnet-4-5", TokenizerFamily::Anthropic),
+            (
+                "openrouter/anthropic/claude-3.5-haiku",
+                TokenizerFamily::Anthropic,
+            ),
+            ("google/gemini-2.5-pro", TokenizerFamily::Gemini),
+            (
+                "bedrock/anthropic.claude-3-haiku",
+                TokenizerFamily::Anthropic,
+            ),
+            ("anthropic/custom-finetune", TokenizerFamily::Anthropic),
+            ("local/llama3.1", TokenizerFamily::Heuristic),
+            ("openai/omni-router", TokenizerFamily::Heuristic),
+        ] {
+            assert_eq!(TokenizerFamily::for_model(model), expected, "{model}");
+        }
+    }
+
+    #[test]
+    fn unit_tokenizer_family_parses_and_serializes_snake_case_names() {
+        for family in [
+            TokenizerFamily::O200kBase,
+            TokenizerFamily::Cl100kBase,
+            TokenizerFamily::Anthropic,
+            TokenizerFamily::Gemini,
+            TokenizerFamily::Heuristic,
+        ] {
+            assert_eq!(family.as_str().parse::<TokenizerFamily>(), Ok(family));
+            assert_eq!(
+                serde_json::to_value(family).expect("serialize"),
+                Value::String(family.as_str().to_string())
+            );
+        }
+        assert!("sentencepiece".parse::<TokenizerFamily>().is_err());
+    }
+
+    #[test]
+    fn functional_bpe_families_count_known_strings_exactly() {
+        assert_eq!(
+            TokenizerFamily::Cl100kBase.count_text_tokens("hello world"),
+            2
+        );
+        assert_eq!(
+            TokenizerFamily::O200kBase.count_text_tokens("hello world"),
+            2
+        );
+        assert_eq!(TokenizerFamily::O200kBase.count_text_tokens(""), 0);
+    }
+
+    #[test]
+    fn functional_calibrated_families_scale_reference_bpe_counts() {
+        let text = "fn main() { println!(\"calibrated token counts\"); }";
+        let cl100k = TokenizerFamily::Cl100kBase.count_text_tokens(text);
+        let o200k = TokenizerFamily::O200kBase.count_text_tokens(text);
+        assert_eq!(
+            TokenizerFamily::Anthropic.count_text_tokens(text),
+            scale_token_count(cl100k, ANTHROPIC_CL100K_RATIO)
+        );
+        assert_eq!(
+            TokenizerFamily::Gemini.count_text_tokens(text),
+            scale_token_count(o200k, GEMINI_O200K_RATIO)
+        );
+    }
+
+    #[test]
+    fn regression_bpe_counts_non_english_text_without_byte_inflation() {
+        // The byte-length heuristic charges every UTF-8 byte of CJK text,
+        // while BPE assigns at most about one token per character.
+        let text = "日本語のテキストは英語よりも多くのトークンを使います。";
+        let o200k = TokenizerFamily::O200kBase.count_text_tokens(text);
+        assert!(o200k > 0);
+        assert!(o200k <= text.chars().count() as u32);
+        assert!(o200k < TokenizerFamily::Heuristic.count_text_tokens(text));
+    }
+
+    #[test]
+    fn integration_count_chat_request_tokens_includes_overheads_and_reservation() {
+        let family = TokenizerFamily::O200kBase;
+        let request = ChatRequest {
+            model: "openai/gpt-4o".to_string(),
+            messages: vec![Message::system("be brief"), Message::user("hello world")],
+            tools: vec![ToolDefinition {
+                name: "read".to_string(),
+                description: "Read a file".to_string(),
+                parameters: serde_json::json!({"type": "object"}),
+            }],
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: Some(64),
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        };
+
+        let count = family.count_chat_request_tokens(&request);
+        let expected_input = family.count_message_tokens(&request.messages[0])
+            + family.count_message_tokens(&request.messages[1])
+            + family.count_tool_definition_tokens(&request.tools[0])
+            + REQUEST_PRIMING_TOKENS;
+        assert_eq!(count.input_tokens, expected_input);
+        assert_eq!(count.total_tokens, expected_input + 64);
+        assert_eq!(
+            family.count_message_tokens(&request.messages[1]),
+            MESSAGE_OVERHEAD_TOKENS + 2
+        );
+    }
+}
@@ -40,6 +40,22 @@ pub enum ContentBlock {
     Audio {
         source: MediaSource,
     },
+    /// Extended thinking / reasoning emitted by the model.
+    ///
+    /// `signature` carries the provider's opaque replay token (Anthropic thinking signature or
+    /// redacted payload, OpenAI encrypted reasoning content, Gemini thought signature). `provider`
+    /// records which backend produced the block so it is only replayed where it is understood.
+    Thinking {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        #[serde(default, skip_serializing_if = "is_false")]
+        redacted: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        provider: Option<String>,
+    },
 }
 
 impl ContentBlock {
@@ -84,6 +100,31 @@ impl ContentBlock {
             },
         }
     }
+
+    pub fn thinking(
+        provider: impl Into<String>,
+        text: impl Into<String>,
+        signature: Option<String>,
+    ) -> Self {
+        Self::Thinking {
+            text: text.into(),
+            signature,
+            id: None,
+            redacted: false,
+            provider: Some(provider.into()),
+        }
+    }
+
+    /// Returns true when this is a thinking block produced by `provider`.
+    pub fn is_thinking_from(&self, provider: &str) -> bool {
+        matches!(
+            self,
+            Self::Thinking {
+                provider: Some(source),
+                ..
+            } if source == provider
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -170,7 +211,22 @@ impl Message {
                 ContentBlock::Text { text } => Some(text.as_str()),
                 ContentBlock::ToolCall { .. }
                 | ContentBlock::Image { .. }
-                | ContentBlock::Audio { .. } => None,
+                | ContentBlock::Audio { .. }
+                | ContentBlock::Thinking { .. } => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns concatenated thinking/reasoning text from this message.
+    pub fn thinking_content(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::Thinking { text, .. } if !text.trim().is_empty() => {
+                    Some(text.as_str())
+                }
+                _ => None,
             })
             .collect::<Vec<_>>()
             .join("\n")
@@ -191,7 +247,8 @@ impl Message {
                 }),
                 ContentBlock::Text { .. }
                 | ContentBlock::Image { .. }
-                | ContentBlock::Audio { .. } => None,
+                | ContentBlock::Audio { .. }
+                | ContentBlock::Thinking { .. } => None,
             })
             .collect()
     }
@@ -229,6 +286,48 @@ pub struct ChatRequest {
     pub temperature: Option<f32>,
     #[serde(default)]
     pub prompt_cache: PromptCacheConfig,
+    #[serde(default, skip_serializing_if = "ReasoningConfig::is_disabled")]
+    pub reasoning: ReasoningConfig,
+    /// Tier hint for model-routing clients; provider clients ignore it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_tier: Option<ModelTier>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+/// Cost/capability tier a routing client should target for one request.
+pub enum ModelTier {
+    /// Cheap, small models for summarization, compaction and tool-result digestion.
+    Economy,
+    /// Default general-purpose models.
+    Standard,
+    /// Frontier models for long-context or tool-heavy reasoning turns.
+    Frontier,
+}
+
+impl ModelTier {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Economy => "economy",
+            Self::Standard => "standard",
+            Self::Frontier => "frontier",
+        }
+    }
+}
+
+impl std::str::FromStr for ModelTier {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "economy" => Ok(Self::Economy),
+            "standard" => Ok(Self::Standard),
+            "frontier" => Ok(Self::Frontier),
+            other => Err(format!(
+                "unsupported model tier '{other}' (expected economy, standard, or frontier)"
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -244,6 +343,88 @@ pub struct PromptCacheConfig {
     pub google_cached_content: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// Enumerates supported `ReasoningEffort` values.
+pub enum ReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
+impl ReasoningEffort {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReasoningEffort::Low => "low",
+            ReasoningEffort::Medium => "medium",
+            ReasoningEffort::High => "high",
+        }
+    }
+
+    /// Default thinking-token budget used when only an effort level is configured.
+    pub fn default_budget_tokens(self) -> u32 {
+        match self {
+            ReasoningEffort::Low => 2_048,
+            ReasoningEffort::Medium => 8_192,
+            ReasoningEffort::High => 24_576,
+        }
+    }
+
+    /// Maps a thinking-token budget onto the nearest effort level.
+    pub fn from_budget_tokens(budget_tokens: u32) -> Self {
+        if budget_tokens <= 4_096 {
+            ReasoningEffort::Low
+        } else if budget_tokens <= 16_384 {
+            ReasoningEffort::Medium
+        } else {
+            ReasoningEffort::High
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+/// Provider-agnostic extended thinking / reasoning controls.
+///
+/// Reasoning is requested when either `budget_tokens` or `effort` is set.
+pub struct ReasoningConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_tokens: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffort>,
+}
+
+impl ReasoningConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.budget_tokens.is_some_and(|budget| budget > 0) || self.effort.is_some()
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        !self.is_enabled()
+    }
+
+    /// Returns the effective thinking-token budget, deriving one from `effort` when needed.
+    pub fn resolved_budget_tokens(&self) -> Option<u32> {
+        if !self.is_enabled() {
+            return None;
+        }
+        self.budget_tokens
+            .filter(|budget| *budget > 0)
+            .or_else(|| self.effort.map(ReasoningEffort::default_budget_tokens))
+    }
+
+    /// Returns the effective effort level, deriving one from `budget_tokens` when needed.
+    pub fn resolved_effort(&self) -> Option<ReasoningEffort> {
+        if !self.is_enabled() {
+            return None;
+        }
+        self.effort.or_else(|| {
+            self.budget_tokens
+                .filter(|budget| *budget > 0)
+                .map(ReasoningEffort::from_budget_tokens)
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 /// Public struct `ChatUsage` used across Tau components.
 pub struct ChatUsage {
@@ -252,12 +433,30 @@ pub struct ChatUsage {
     pub total_tokens: u64,
     #[serde(default, skip_serializing_if = "is_zero_u64")]
     pub cached_input_tokens: u64,
+    /// Portion of `output_tokens` spent on thinking/reasoning.
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub reasoning_tokens: u64,
+    /// Estimated input tokens billed to a cancelled hedged duplicate request.
+    /// Not included in `input_tokens` or `total_tokens`.
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub hedge_input_tokens: u64,
+    /// Estimated output tokens the cancelled hedged duplicate streamed before it
+    /// was dropped. Not included in `output_tokens` or `total_tokens`.
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub hedge_output_tokens: u64,
+    /// `provider/model` route that served the cancelled hedged duplicate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedge_model: Option<String>,
 }
 
 fn is_zero_u64(value: &u64) -> bool {
     *value == 0
 }
 
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 /// Public struct `ChatResponse` used across Tau components.
 pub struct ChatResponse {
@@ -360,6 +559,103 @@ fn classify_http_status(status: u16) -> ProviderErrorKind {
 
 pub type StreamDeltaHandler = Arc<dyn Fn(String) + Send + Sync>;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// Typed incremental event emitted while a provider streams a completion.
+///
+/// `index` fields are the zero-based position of the tool call within the assistant turn.
+pub enum StreamEvent {
+    TextDelta {
+        text: String,
+    },
+    ReasoningDelta {
+        text: String,
+    },
+    ToolCallStart {
+        index: usize,
+        id: String,
+        name: String,
+    },
+    ToolCallArgumentsDelta {
+        index: usize,
+        id: String,
+        delta: String,
+    },
+    ToolCallEnd {
+        index: usize,
+        id: String,
+        name: String,
+        arguments: Value,
+    },
+    Usage {
+        usage: ChatUsage,
+    },
+    Stop {
+        finish_reason: Option<String>,
+    },
+}
+
+pub type StreamEventHandler = Arc<dyn Fn(StreamEvent) + Send + Sync>;
+
+/// Adapts a text-only delta handler into a typed event handler that forwards text deltas.
+pub fn stream_event_handler_from_deltas(on_delta: StreamDeltaHandler) -> StreamEventHandler {
+    Arc::new(move |event| {
+        if let StreamEvent::TextDelta { text } = event {
+            on_delta(text);
+        }
+    })
+}
+
+/// Adapts a typed event handler into a text delta handler that emits `TextDelta` events.
+pub fn stream_delta_handler_from_events(on_event: StreamEventHandler) -> StreamDeltaHandler {
+    Arc::new(move |text| on_event(StreamEvent::TextDelta { text }))
+}
+
+/// Emits the terminal events (tool-call ends, usage, stop) for a completed response.
+pub fn emit_stream_completion_events(on_event: &StreamEventHandler, response: &ChatResponse) {
+    for (index, tool_call) in response.message.tool_calls().into_iter().enumerate() {
+        on_event(StreamEvent::ToolCallEnd {
+            index,
+            id: tool_call.id,
+            name: tool_call.name,
+            arguments: tool_call.arguments,
+        });
+    }
+    on_event(StreamEvent::Usage {
+        usage: response.usage.clone(),
+    });
+    on_event(StreamEvent::Stop {
+        finish_reason: response.finish_reason.clone(),
+    });
+}
+
+/// Replays a fully buffered response as a stream event sequence.
+///
+/// Used by transports that only return complete payloads so event consumers still observe
+/// reasoning, text and tool calls in order.
+pub fn emit_stream_events_for_response(on_event: &StreamEventHandler, response: &ChatResponse) {
+    let reasoning = response.message.thinking_content();
+    if !reasoning.is_empty() {
+        on_event(StreamEvent::ReasoningDelta { text: reasoning });
+    }
+    let text = response.message.text_content();
+    if !text.is_empty() {
+        on_event(StreamEvent::TextDelta { text });
+    }
+    emit_tool_call_start_events(on_event, response);
+    emit_stream_completion_events(on_event, response);
+}
+
+fn emit_tool_call_start_events(on_event: &StreamEventHandler, response: &ChatResponse) {
+    for (index, tool_call) in response.message.tool_calls().into_iter().enumerate() {
+        on_event(StreamEvent::ToolCallStart {
+            index,
+            id: tool_call.id,
+            name: tool_call.name,
+        });
+    }
+}
+
 #[async_trait]
 /// Trait contract for `LlmClient` behavior.
 pub trait LlmClient: Send + Sync {
@@ -373,11 +669,39 @@ pub trait LlmClient: Send + Sync {
         let _ = on_delta;
         self.complete(request).await
     }
+
+    /// Completes a request while emitting typed [`StreamEvent`]s.
+    ///
+    /// The default bridges through `complete_with_stream`: text deltas stream live and the
+    /// tool-call, usage and stop events are emitted once the response is complete.
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        let Some(on_event) = on_event else {
+            return self.complete_with_stream(request, None).await;
+        };
+        let response = self
+            .complete_with_stream(
+                request,
+                Some(stream_delta_handler_from_events(on_event.clone())),
+            )
+            .await?;
+        emit_tool_call_start_events(&on_event, &response);
+        emit_stream_completion_events(&on_event, &response);
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ContentBlock, MediaSource, Message, MessageRole};
+    use std::sync::{Arc, Mutex};
+
+    use super::{
+        emit_stream_events_for_response, ChatResponse, ChatUsage, ContentBlock, MediaSource,
+        Message, MessageRole, ReasoningConfig, ReasoningEffort, StreamEvent, StreamEventHandler,
+    };
 
     #[test]
     fn collects_text_content() {
@@ -431,4 +755,97 @@ mod tests {
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "read");
     }
+
+    #[test]
+    fn unit_thinking_blocks_round_trip_and_stay_out_of_text_content() {
+        let message = Message::assistant_blocks(vec![
+            ContentBlock::thinking("anthropic", "plan first", Some("sig_1".to_string())),
+            ContentBlock::text("answer"),
+        ]);
+
+        assert_eq!(message.text_content(), "answer");
+        assert_eq!(message.thinking_content(), "plan first");
+        assert!(message.content[0].is_thinking_from("anthropic"));
+        assert!(!message.content[0].is_thinking_from("google"));
+
+        let encoded = serde_json::to_value(&message).expect("encode message");
+        assert_eq!(encoded["content"][0]["type"], "thinking");
+        assert_eq!(encoded["content"][0]["signature"], "sig_1");
+        assert!(encoded["content"][0].get("redacted").is_none());
+        let decoded: Message = serde_json::from_value(encoded).expect("decode message");
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn unit_reasoning_config_resolves_budget_and_effort() {
+        let disabled = ReasoningConfig::default();
+        assert!(disabled.is_disabled());
+        assert_eq!(disabled.resolved_budget_tokens(), None);
+
+        let budget_only = ReasoningConfig {
+            budget_tokens: Some(10_000),
+            effort: None,
+        };
+        assert_eq!(budget_only.resolved_budget_tokens(), Some(10_000));
+        assert_eq!(budget_only.resolved_effort(), Some(ReasoningEffort::Medium));
+
+        let effort_only = ReasoningConfig {
+            budget_tokens: None,
+            effort: Some(ReasoningEffort::High),
+        };
+        assert_eq!(effort_only.resolved_budget_tokens(), Some(24_576));
+        assert_eq!(effort_only.resolved_effort(), Some(ReasoningEffort::High));
+    }
+
+    #[test]
+    fn unit_buffered_response_replays_as_ordered_stream_events() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let sink: StreamEventHandler = Arc::new(move |event| {
+            sink_events.lock().expect("event lock").push(event);
+        });
+        let response = ChatResponse {
+            message: Message::assistant_blocks(vec![
+                ContentBlock::thinking("openai", "plan", None),
+                ContentBlock::text("reading"),
+                ContentBlock::tool_call(super::ToolCall {
+                    id: "call_1".to_string(),
+                    name: "read".to_string(),
+                    arguments: serde_json::json!({ "path": "README.md" }),
+                }),
+            ]),
+            finish_reason: Some("tool_calls".to_string()),
+            usage: ChatUsage::default(),
+        };
+
+        emit_stream_events_for_response(&sink, &response);
+
+        let kinds = events
+            .lock()
+            .expect("event lock")
+            .iter()
+            .map(|event| {
+                serde_json::to_value(event).expect("encode event")["type"]
+                    .as_str()
+                    .expect("event type")
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                "reasoning_delta",
+                "text_delta",
+                "tool_call_start",
+                "tool_call_end",
+                "usage",
+                "stop"
+            ]
+        );
+        assert!(matches!(
+            &events.lock().expect("event lock")[3],
+            StreamEvent::ToolCallEnd { id, arguments, .. }
+                if id == "call_1" && arguments["path"] == "README.md"
+        ));
+    }
 }
@@ -68,6 +68,8 @@ fn test_request(prompt: &str) -> ChatRequest {
         max_tokens: None,
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -63,6 +63,8 @@ fn tool_request(model: &str) -> ChatRequest {
         max_tokens: Some(128),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -76,6 +78,8 @@ fn prompt_request(model: &str) -> ChatRequest {
         max_tokens: None,
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -144,6 +148,10 @@ fn unit_normalize_tool_contract_extracts_stable_shape() {
             output_tokens: 6,
             total_tokens: 14,
             cached_input_tokens: 0,
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
         },
     };
 
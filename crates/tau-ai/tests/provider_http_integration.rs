@@ -1,10 +1,13 @@
+use base64::Engine as _;
 use httpmock::prelude::*;
 use serde_json::json;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 use tau_ai::{
-    AnthropicClient, AnthropicConfig, ChatRequest, GoogleClient, GoogleConfig, LlmClient, Message,
-    OpenAiAuthScheme, OpenAiClient, OpenAiConfig, TauAiError, ToolChoice, ToolDefinition,
+    AnthropicClient, AnthropicConfig, AwsCredentials, BedrockClient, BedrockConfig, ChatRequest,
+    GoogleClient, GoogleConfig, LlmClient, LocalClient, LocalConfig, Message, OpenAiAuthScheme,
+    OpenAiClient, OpenAiConfig, StreamEvent, StreamEventHandler, TauAiError, ToolChoice,
+    ToolDefinition, VertexClient, VertexConfig, VertexCredentials, VertexServiceAccountKey,
 };
 use tokio::sync::Mutex as AsyncMutex;
 
@@ -104,6 +107,8 @@ async fn openai_client_sends_expected_http_request() {
         max_tokens: Some(128),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     let response = client
@@ -172,6 +177,8 @@ async fn spec_c06_openrouter_route_applies_dedicated_headers_when_configured() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("openrouter completion should succeed");
@@ -247,6 +254,8 @@ async fn integration_openai_client_routes_codex_models_to_responses_endpoint() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("codex request should route to responses endpoint");
@@ -321,6 +330,8 @@ async fn integration_openai_client_falls_back_to_responses_when_chat_reports_end
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("chat endpoint mismatch should fallback to responses endpoint");
@@ -389,6 +400,8 @@ async fn regression_openai_client_falls_back_to_chat_when_responses_endpoint_is_
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("missing responses endpoint should fallback to chat endpoint");
@@ -459,6 +472,8 @@ async fn functional_openai_client_outbound_secret_fixture_matrix_preserves_usage
                 max_tokens: None,
                 temperature: None,
                 prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
             })
             .await
             .unwrap_or_else(|error| panic!("completion should succeed for {case_id}: {error}"));
@@ -524,6 +539,8 @@ async fn integration_openai_client_supports_azure_api_key_header_and_api_version
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("azure-compatible completion should succeed");
@@ -587,6 +604,8 @@ async fn anthropic_client_sends_expected_http_request() {
         max_tokens: Some(128),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     let response = client
@@ -660,6 +679,8 @@ async fn google_client_sends_expected_http_request() {
         max_tokens: Some(128),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     let response = client
@@ -673,6 +694,291 @@ async fn google_client_sends_expected_http_request() {
     assert_eq!(response.usage.total_tokens, 14);
 }
 
+#[tokio::test]
+async fn integration_local_client_omits_tool_choice_and_promotes_textual_tool_calls() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/chat/completions")
+            .header("authorization", "Bearer local")
+            .json_body_includes(
+                json!({
+                    "model": "qwen2.5-coder:7b",
+                    "tools": [{"type": "function"}]
+                })
+                .to_string(),
+            )
+            .body_excludes("tool_choice");
+        then.status(200).json_body(json!({
+            "choices": [{
+                "message": {
+                    "content": "{\"tool_calls\":[{\"id\":\"call_1\",\"name\":\"read\",\"arguments\":{\"path\":\"README.md\"}}]}"
+                },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 9,
+                "completion_tokens": 6,
+                "total_tokens": 15
+            }
+        }));
+    });
+
+    let client = LocalClient::new(LocalConfig {
+        api_base: format!("{}/v1", server.base_url()),
+        api_key: None,
+        request_timeout_ms: 5_000,
+        max_retries: 0,
+        retry_budget_ms: 0,
+        retry_jitter: false,
+    })
+    .expect("local client should be created");
+
+    let response = client
+        .complete(ChatRequest {
+            model: "qwen2.5-coder:7b".to_string(),
+            messages: vec![Message::user("read the readme")],
+            tools: vec![ToolDefinition {
+                name: "read".to_string(),
+                description: "Read a file".to_string(),
+                parameters: json!({"type":"object"}),
+            }],
+            tool_choice: Some(ToolChoice::Required),
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        })
+        .await
+        .expect("local completion should succeed");
+
+    mock.assert_calls(1);
+    let tool_calls = response.message.tool_calls();
+    assert_eq!(tool_calls.len(), 1);
+    assert_eq!(tool_calls[0].name, "read");
+    assert_eq!(tool_calls[0].arguments, json!({"path": "README.md"}));
+}
+
+#[tokio::test]
+async fn integration_bedrock_client_signs_request_and_decodes_recorded_event_stream() {
+    let fixture: serde_json::Value =
+        serde_json::from_str(include_str!("../testdata/bedrock/converse-stream.json"))
+            .expect("bedrock stream fixture");
+    assert_eq!(fixture["schema_version"], 1);
+    let body = base64::engine::general_purpose::STANDARD
+        .decode(fixture["body_base64"].as_str().expect("body_base64"))
+        .expect("decode fixture body");
+
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path_includes("/converse-stream")
+            .header_exists("x-amz-date")
+            .header("x-amz-security-token", "session-token")
+            .header_includes(
+                "authorization",
+                "SignedHeaders=host;x-amz-date;x-amz-security-token",
+            )
+            .json_body_includes(
+                json!({
+                    "toolConfig": {"toolChoice": {"auto": {}}}
+                })
+                .to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/vnd.amazon.eventstream")
+            .body(body.clone());
+    });
+
+    let client = BedrockClient::new(BedrockConfig {
+        api_base: server.base_url(),
+        region: "us-east-1".to_string(),
+        credentials: AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: Some("session-token".to_string()),
+        },
+        request_timeout_ms: 5_000,
+        max_retries: 0,
+        retry_budget_ms: 0,
+        retry_jitter: false,
+    })
+    .expect("bedrock client should be created");
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let on_event: StreamEventHandler = Arc::new(move |event| {
+        sink.lock().expect("events lock").push(event);
+    });
+    let response = client
+        .complete_with_events(
+            ChatRequest {
+                model: "anthropic.claude-3-5-haiku-20241022-v1:0".to_string(),
+                messages: vec![Message::user("read the readme")],
+                tools: vec![ToolDefinition {
+                    name: "read".to_string(),
+                    description: "Read a file".to_string(),
+                    parameters: json!({"type":"object"}),
+                }],
+                tool_choice: Some(ToolChoice::Auto),
+                json_mode: false,
+                max_tokens: Some(256),
+                temperature: None,
+                prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
+            },
+            Some(on_event),
+        )
+        .await
+        .expect("bedrock stream completion should succeed");
+
+    mock.assert_calls(1);
+    assert_eq!(
+        response.message.text_content(),
+        fixture["expected_text"].as_str().expect("expected_text")
+    );
+    let tool_calls = response.message.tool_calls();
+    assert_eq!(tool_calls.len(), 1);
+    assert_eq!(tool_calls[0].id, fixture["expected_tool_call"]["id"]);
+    assert_eq!(
+        tool_calls[0].arguments,
+        fixture["expected_tool_call"]["arguments"]
+    );
+    assert_eq!(
+        response.finish_reason.as_deref(),
+        fixture["expected_finish_reason"].as_str()
+    );
+    assert_eq!(
+        response.usage.total_tokens,
+        fixture["expected_total_tokens"].as_u64().expect("tokens")
+    );
+    let events = events.lock().expect("events lock").clone();
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, StreamEvent::ToolCallEnd { name, .. } if name == "read")));
+    assert!(matches!(events.last(), Some(StreamEvent::Stop { .. })));
+}
+
+#[tokio::test]
+async fn integration_vertex_client_exchanges_service_account_jwt_and_caches_token() {
+    let server = MockServer::start();
+    let token_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/token")
+            .body_includes("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer")
+            .body_includes("assertion=eyJ");
+        then.status(200).json_body(json!({
+            "access_token": "ya29.vertex-token",
+            "expires_in": 3600,
+            "token_type": "Bearer"
+        }));
+    });
+    let predict_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/projects/demo-project/locations/us-east5/publishers/anthropic/models/claude-sonnet-4@20250514:rawPredict")
+            .header("authorization", "Bearer ya29.vertex-token")
+            .json_body_includes(json!({"anthropic_version": "vertex-2023-10-16"}).to_string())
+            .body_excludes("\"model\"");
+        then.status(200).json_body(json!({
+            "content": [{"type": "text", "text": "vertex claude ok"}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 4, "output_tokens": 3}
+        }));
+    });
+
+    let mut key = VertexServiceAccountKey::from_json(include_str!(
+        "../testdata/vertex/service-account-key.json"
+    ))
+    .expect("service account fixture");
+    key.token_uri = format!("{}/token", server.base_url());
+    let client = VertexClient::new(VertexConfig {
+        api_base: server.base_url(),
+        project_id: "demo-project".to_string(),
+        location: "us-east5".to_string(),
+        credentials: VertexCredentials::ServiceAccount(key),
+        request_timeout_ms: 5_000,
+        max_retries: 0,
+        retry_budget_ms: 0,
+        retry_jitter: false,
+    })
+    .expect("vertex client should be created");
+
+    for _ in 0..2 {
+        let response = client
+            .complete(ChatRequest {
+                model: "claude-sonnet-4@20250514".to_string(),
+                messages: vec![Message::user("hello")],
+                tools: vec![],
+                tool_choice: None,
+                json_mode: false,
+                max_tokens: Some(64),
+                temperature: None,
+                prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
+            })
+            .await
+            .expect("vertex completion should succeed");
+        assert_eq!(response.message.text_content(), "vertex claude ok");
+    }
+
+    token_mock.assert_calls(1);
+    predict_mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn integration_vertex_client_routes_gemini_with_access_token() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/projects/demo-project/locations/global/publishers/google/models/gemini-2.5-flash:generateContent")
+            .header("authorization", "Bearer static-token")
+            .query_param_missing("key");
+        then.status(200).json_body(json!({
+            "candidates": [{
+                "content": {"parts": [{"text": "vertex gemini ok"}]},
+                "finishReason": "STOP"
+            }],
+            "usageMetadata": {"promptTokenCount": 2, "candidatesTokenCount": 3, "totalTokenCount": 5}
+        }));
+    });
+
+    let client = VertexClient::new(VertexConfig {
+        api_base: server.base_url(),
+        project_id: "demo-project".to_string(),
+        location: "global".to_string(),
+        credentials: VertexCredentials::AccessToken("static-token".to_string()),
+        request_timeout_ms: 5_000,
+        max_retries: 0,
+        retry_budget_ms: 0,
+        retry_jitter: false,
+    })
+    .expect("vertex client should be created");
+
+    let response = client
+        .complete(ChatRequest {
+            model: "gemini-2.5-flash".to_string(),
+            messages: vec![Message::user("hello")],
+            tools: vec![],
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        })
+        .await
+        .expect("vertex gemini completion should succeed");
+
+    mock.assert_calls(1);
+    assert_eq!(response.message.text_content(), "vertex gemini ok");
+    assert_eq!(response.usage.total_tokens, 5);
+}
+
 #[tokio::test]
 async fn openai_client_surfaces_http_status_error() {
     let server = MockServer::start();
@@ -703,6 +1009,8 @@ async fn openai_client_surfaces_http_status_error() {
         max_tokens: None,
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     let error = client
@@ -764,6 +1072,8 @@ async fn openai_client_retries_on_rate_limit_then_succeeds() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("retry should eventually succeed");
@@ -821,6 +1131,8 @@ async fn integration_openai_client_respects_retry_after_header_floor() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect("retry should eventually succeed");
@@ -880,6 +1192,8 @@ async fn openai_client_retry_budget_can_block_retries() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect_err("retry budget should block retry");
@@ -935,6 +1249,8 @@ async fn regression_openai_client_returns_timeout_error_when_server_is_slow() {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         })
         .await
         .expect_err("request should timeout");
@@ -998,6 +1314,8 @@ async fn integration_openai_client_streams_incremental_text_deltas() {
                 max_tokens: None,
                 temperature: None,
                 prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
             },
             Some(sink),
         )
@@ -1068,6 +1386,8 @@ async fn integration_anthropic_client_streams_incremental_text_deltas() {
                 max_tokens: None,
                 temperature: None,
                 prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
             },
             Some(sink),
         )
@@ -1125,6 +1445,8 @@ async fn integration_google_client_streams_incremental_text_deltas() {
                 max_tokens: None,
                 temperature: None,
                 prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
             },
             Some(sink),
         )
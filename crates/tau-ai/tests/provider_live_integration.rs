@@ -49,6 +49,8 @@ fn simple_prompt_request(model: &str) -> ChatRequest {
         max_tokens: Some(20),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -62,6 +64,8 @@ fn tool_call_request(model: &str) -> ChatRequest {
         max_tokens: Some(100),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -75,6 +79,8 @@ fn streaming_prompt_request(model: &str) -> ChatRequest {
         max_tokens: Some(20),
         temperature: Some(0.0),
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -347,6 +347,8 @@ async fn request_text_completion(
         max_tokens,
         temperature,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     let response = client.complete(request).await?;
@@ -4,7 +4,7 @@ use clap::{ArgAction, Parser};
 
 use crate::{
     CliCommandFileErrorMode, CliCredentialStoreEncryptionMode, CliOrchestratorMode,
-    CliPromptSanitizerMode, CliProviderAuthMode, CliShellCompletion,
+    CliPromptSanitizerMode, CliProviderAuthMode, CliReasoningEffort, CliShellCompletion,
 };
 
 mod execution_domain_flags;
@@ -27,6 +27,16 @@ fn parse_positive_usize(value: &str) -> Result<usize, String> {
     Ok(parsed)
 }
 
+fn parse_positive_u32(value: &str) -> Result<u32, String> {
+    let parsed = value
+        .parse::<u32>()
+        .map_err(|error| format!("failed to parse integer: {error}"))?;
+    if parsed == 0 {
+        return Err("value must be greater than 0".to_string());
+    }
+    Ok(parsed)
+}
+
 fn parse_positive_u64(value: &str) -> Result<u64, String> {
     let parsed = value
         .parse::<u64>()
@@ -76,7 +86,7 @@ pub struct Cli {
         long,
         env = "TAU_MODEL",
         default_value = "openai/gpt-5.2",
-        help = "Model in provider/model format. Supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google."
+        help = "Model in provider/model format. Supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google, bedrock, vertex, local/ollama/llamacpp/vllm."
     )]
     pub model: String,
 
@@ -88,6 +98,35 @@ pub struct Cli {
     )]
     pub fallback_model: Vec<String>,
 
+    #[arg(
+        long = "model-route",
+        env = "TAU_MODEL_ROUTE",
+        value_delimiter = ',',
+        help = "Optional per-request model routing table in tier=provider/model format (tiers: economy, standard, frontier). The primary --model joins as a standard route unless listed."
+    )]
+    pub model_route: Vec<String>,
+
+    #[arg(
+        long = "model-router-session-budget-usd",
+        env = "TAU_MODEL_ROUTER_SESSION_BUDGET_USD",
+        requires = "model_route",
+        help = "Optional per-session cost budget in USD; the model router downgrades to cheaper routes as spend approaches it"
+    )]
+    pub model_router_session_budget_usd: Option<f64>,
+
+    #[arg(
+        long = "model-router-budget-hard-limit",
+        env = "TAU_MODEL_ROUTER_BUDGET_HARD_LIMIT",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        requires = "model_router_session_budget_usd",
+        help = "Fail routed requests once the session budget is spent instead of using the cheapest route"
+    )]
+    pub model_router_budget_hard_limit: bool,
+
     #[arg(
         long,
         env = "TAU_API_BASE",
@@ -155,6 +194,66 @@ pub struct Cli {
     )]
     pub google_api_base: String,
 
+    #[arg(
+        long = "local-api-base",
+        env = "TAU_LOCAL_API_BASE",
+        default_value = "http://127.0.0.1:11434/v1",
+        help = "OpenAI-compatible base URL of the local inference server (Ollama, llama.cpp server, vLLM) used by local/<model>"
+    )]
+    pub local_api_base: String,
+
+    #[arg(
+        long = "local-api-key",
+        env = "TAU_LOCAL_API_KEY",
+        hide_env_values = true,
+        help = "Optional API key for local inference servers started with authentication (for example vllm serve --api-key)"
+    )]
+    pub local_api_key: Option<String>,
+
+    #[arg(
+        long = "bedrock-region",
+        env = "TAU_BEDROCK_REGION",
+        default_value = "us-east-1",
+        help = "AWS region used to sign and route bedrock/<model> requests"
+    )]
+    pub bedrock_region: String,
+
+    #[arg(
+        long = "bedrock-api-base",
+        env = "TAU_BEDROCK_API_BASE",
+        help = "Override for the Bedrock runtime endpoint (defaults to https://bedrock-runtime.<region>.amazonaws.com)"
+    )]
+    pub bedrock_api_base: Option<String>,
+
+    #[arg(
+        long = "vertex-project",
+        env = "TAU_VERTEX_PROJECT",
+        help = "Google Cloud project for vertex/<model> requests (defaults to the service account project or GOOGLE_CLOUD_PROJECT)"
+    )]
+    pub vertex_project: Option<String>,
+
+    #[arg(
+        long = "vertex-location",
+        env = "TAU_VERTEX_LOCATION",
+        default_value = "us-central1",
+        help = "Vertex AI location (region or global) for vertex/<model> requests"
+    )]
+    pub vertex_location: String,
+
+    #[arg(
+        long = "vertex-api-base",
+        env = "TAU_VERTEX_API_BASE",
+        help = "Override for the Vertex AI endpoint (defaults to https://<location>-aiplatform.googleapis.com)"
+    )]
+    pub vertex_api_base: Option<String>,
+
+    #[arg(
+        long = "vertex-service-account-file",
+        env = "GOOGLE_APPLICATION_CREDENTIALS",
+        help = "Service account key file exchanged for Vertex AI access tokens in adc auth mode"
+    )]
+    pub vertex_service_account_file: Option<PathBuf>,
+
     #[arg(
         long,
         env = "TAU_API_KEY",
@@ -316,6 +415,24 @@ pub struct Cli {
     )]
     pub google_auth_mode: CliProviderAuthMode,
 
+    #[arg(
+        long = "bedrock-auth-mode",
+        env = "TAU_BEDROCK_AUTH_MODE",
+        value_enum,
+        default_value_t = CliProviderAuthMode::ApiKey,
+        help = "Authentication mode preference for Bedrock provider (api-key: static IAM keys, session-token: temporary STS credentials)"
+    )]
+    pub bedrock_auth_mode: CliProviderAuthMode,
+
+    #[arg(
+        long = "vertex-auth-mode",
+        env = "TAU_VERTEX_AUTH_MODE",
+        value_enum,
+        default_value_t = CliProviderAuthMode::Adc,
+        help = "Authentication mode preference for Vertex AI provider (adc: service account key, oauth-token: access token)"
+    )]
+    pub vertex_auth_mode: CliProviderAuthMode,
+
     #[arg(
         long = "provider-subscription-strict",
         env = "TAU_PROVIDER_SUBSCRIPTION_STRICT",
@@ -587,6 +704,22 @@ pub struct Cli {
     )]
     pub agent_max_context_messages: Option<usize>,
 
+    #[arg(
+        long = "agent-reasoning-budget-tokens",
+        env = "TAU_AGENT_REASONING_BUDGET_TOKENS",
+        value_parser = parse_positive_u32,
+        help = "Optional extended thinking/reasoning token budget requested from reasoning-capable models"
+    )]
+    pub agent_reasoning_budget_tokens: Option<u32>,
+
+    #[arg(
+        long = "agent-reasoning-effort",
+        env = "TAU_AGENT_REASONING_EFFORT",
+        value_enum,
+        help = "Optional reasoning effort level for providers that accept effort instead of a token budget"
+    )]
+    pub agent_reasoning_effort: Option<CliReasoningEffort>,
+
     #[arg(
         long = "agent-request-max-retries",
         env = "TAU_AGENT_REQUEST_MAX_RETRIES",
@@ -740,6 +873,34 @@ pub struct Cli {
     )]
     pub provider_rate_limit_max_wait_ms: u64,
 
+    #[arg(
+        long = "provider-hedging",
+        env = "TAU_PROVIDER_HEDGING",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        help = "Send a duplicate request to the next --fallback-model route when the first route is slower than its hedge latency percentile"
+    )]
+    pub provider_hedging: bool,
+
+    #[arg(
+        long = "provider-hedge-percentile",
+        env = "TAU_PROVIDER_HEDGE_PERCENTILE",
+        default_value_t = 95.0,
+        help = "First-token latency percentile (0-100) after which a hedged duplicate request is sent"
+    )]
+    pub provider_hedge_percentile: f64,
+
+    #[arg(
+        long = "provider-hedge-initial-delay-ms",
+        env = "TAU_PROVIDER_HEDGE_INITIAL_DELAY_MS",
+        default_value_t = 2_000,
+        help = "Hedge delay in milliseconds used until a route has enough first-token latency samples"
+    )]
+    pub provider_hedge_initial_delay_ms: u64,
+
     #[arg(
         long,
         env = "TAU_TURN_TIMEOUT_MS",
@@ -395,6 +395,129 @@ pub struct CliGatewayDaemonFlags {
     )]
     pub slack_retry_base_delay_ms: u64,
 
+    #[arg(
+        long = "discord-bridge",
+        env = "TAU_DISCORD_BRIDGE",
+        default_value_t = false,
+        help = "Run as a Discord gateway conversational transport loop instead of interactive prompt mode"
+    )]
+    pub discord_bridge: bool,
+
+    #[arg(
+        long = "discord-bot-token",
+        env = "TAU_DISCORD_BRIDGE_BOT_TOKEN",
+        hide_env_values = true,
+        requires = "discord_bridge",
+        help = "Discord bot token used for the gateway connection and REST API"
+    )]
+    pub discord_bot_token: Option<String>,
+
+    #[arg(
+        long = "discord-bot-token-id",
+        env = "TAU_DISCORD_BRIDGE_BOT_TOKEN_ID",
+        requires = "discord_bridge",
+        help = "Credential-store integration id used to resolve Discord bot token"
+    )]
+    pub discord_bot_token_id: Option<String>,
+
+    #[arg(
+        long = "discord-application-id",
+        env = "TAU_DISCORD_APPLICATION_ID",
+        requires = "discord_bridge",
+        help = "Discord application id used to register native slash commands"
+    )]
+    pub discord_application_id: Option<u64>,
+
+    #[arg(
+        long = "discord-command-prefix",
+        env = "TAU_DISCORD_COMMAND_PREFIX",
+        default_value = "/tau",
+        requires = "discord_bridge",
+        help = "Text command prefix; its name (without '/') is also the native slash command name"
+    )]
+    pub discord_command_prefix: String,
+
+    #[arg(
+        long = "discord-command-guild-id",
+        env = "TAU_DISCORD_COMMAND_GUILD_IDS",
+        value_delimiter = ',',
+        requires = "discord_bridge",
+        help = "Register the slash command in these guilds only (repeatable); registers globally when omitted"
+    )]
+    pub discord_command_guild_ids: Vec<u64>,
+
+    #[arg(
+        long = "discord-guild-config",
+        env = "TAU_DISCORD_GUILD_CONFIG",
+        requires = "discord_bridge",
+        help = "Per-guild policy JSON (mention rules, allowed/forum channels, role-to-principal mapping); defaults to <discord-state-dir>/guilds.json"
+    )]
+    pub discord_guild_config: Option<PathBuf>,
+
+    #[arg(
+        long = "discord-state-dir",
+        env = "TAU_DISCORD_STATE_DIR",
+        default_value = ".tau/discord",
+        requires = "discord_bridge",
+        help = "Directory for discord bridge state/session/event logs"
+    )]
+    pub discord_state_dir: PathBuf,
+
+    #[arg(
+        long = "discord-embed-responses",
+        env = "TAU_DISCORD_EMBED_RESPONSES",
+        default_value_t = true,
+        action = ArgAction::Set,
+        requires = "discord_bridge",
+        help = "Render run replies as Discord embeds instead of plain message content"
+    )]
+    pub discord_embed_responses: bool,
+
+    #[arg(
+        long = "discord-artifact-retention-days",
+        env = "TAU_DISCORD_ARTIFACT_RETENTION_DAYS",
+        default_value_t = 30,
+        requires = "discord_bridge",
+        help = "Retention window for Discord bridge artifacts in days (0 disables expiration)"
+    )]
+    pub discord_artifact_retention_days: u64,
+
+    #[arg(
+        long = "discord-processed-event-cap",
+        env = "TAU_DISCORD_PROCESSED_EVENT_CAP",
+        default_value_t = 10_000,
+        requires = "discord_bridge",
+        help = "Maximum processed-event keys to retain for duplicate delivery protection"
+    )]
+    pub discord_processed_event_cap: usize,
+
+    #[arg(
+        long = "discord-max-event-age-seconds",
+        env = "TAU_DISCORD_MAX_EVENT_AGE_SECONDS",
+        default_value_t = 7_200,
+        requires = "discord_bridge",
+        help = "Ignore inbound Discord events older than this many seconds (0 disables age checks)"
+    )]
+    pub discord_max_event_age_seconds: u64,
+
+    #[arg(
+        long = "discord-coalescing-window-ms",
+        env = "TAU_DISCORD_COALESCING_WINDOW_MS",
+        default_value_t = 2_000,
+        requires = "discord_bridge",
+        help = "Coalescing window in milliseconds for batching same-user rapid messages in one session (0 disables coalescing)"
+    )]
+    pub discord_coalescing_window_ms: u64,
+
+    #[arg(
+        long = "discord-reconnect-delay-ms",
+        env = "TAU_DISCORD_RECONNECT_DELAY_MS",
+        default_value_t = 1_000,
+        requires = "discord_bridge",
+        help = "Delay before reconnecting after gateway errors"
+    )]
+    pub discord_reconnect_delay_ms: u64,
+
     #[arg(
         long,
         env = "TAU_SESSION",
@@ -645,12 +768,35 @@ pub struct CliGatewayDaemonFlags {
     )]
     pub otel_export_log: Option<PathBuf>,
 
+    #[arg(
+        long,
+        env = "TAU_OTEL_TRACES_ENDPOINT",
+        help = "Optional OTLP/HTTP collector URL for exporting agent, provider, and tool spans (defaults path to /v1/traces)"
+    )]
+    pub otel_traces_endpoint: Option<String>,
+
+    #[arg(
+        long = "otel-traces-header",
+        env = "TAU_OTEL_TRACES_HEADER",
+        value_delimiter = ',',
+        help = "Additional KEY=VALUE headers sent with OTLP trace exports (repeatable)"
+    )]
+    pub otel_traces_header: Vec<String>,
+
+    #[arg(
+        long,
+        env = "TAU_OTEL_SERVICE_NAME",
+        default_value = "tau",
+        help = "service.name resource attribute reported with exported spans"
+    )]
+    pub otel_service_name: String,
+
     #[arg(
         long,
         env = "TAU_OS_SANDBOX_MODE",
         value_enum,
         default_value = "off",
-        help = "OS sandbox mode for bash tool: off, auto, or force"
+        help = "OS sandbox mode for bash tool: off, auto, force, or native (in-process Landlock + seccomp, Linux only)"
     )]
     pub os_sandbox_mode: CliOsSandboxMode,
 
@@ -745,6 +891,89 @@ pub struct CliGatewayDaemonFlags {
     )]
     pub os_sandbox_docker_env: Vec<String>,
 
+    #[arg(
+        long = "os-sandbox-native-allow-network",
+        env = "TAU_OS_SANDBOX_NATIVE_ALLOW_NETWORK",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        help = "Skip the seccomp network filter when the native sandbox backend is active"
+    )]
+    pub os_sandbox_native_allow_network: bool,
+
+    #[arg(
+        long = "os-sandbox-native-memory-mb",
+        env = "TAU_OS_SANDBOX_NATIVE_MEMORY_MB",
+        default_value_t = 2048,
+        value_parser = parse_positive_u64,
+        help = "Native sandbox address-space limit (RLIMIT_AS) in megabytes"
+    )]
+    pub os_sandbox_native_memory_mb: u64,
+
+    #[arg(
+        long = "os-sandbox-native-max-processes",
+        env = "TAU_OS_SANDBOX_NATIVE_MAX_PROCESSES",
+        default_value_t = 0,
+        help = "Native sandbox RLIMIT_NPROC; counts every process of the user, not just the sandboxed command (0 disables)"
+    )]
+    pub os_sandbox_native_max_processes: u64,
+
+    #[arg(
+        long = "lsp-enabled",
+        env = "TAU_LSP_ENABLED",
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        help = "Enable the lsp_diagnostics, lsp_hover, lsp_definition and lsp_rename tools"
+    )]
+    pub lsp_enabled: bool,
+
+    #[arg(
+        long = "lsp-server",
+        env = "TAU_LSP_SERVER",
+        value_delimiter = ',',
+        value_name = "language=command",
+        help = "Override the language server for rust, python, go or typescript (e.g. 'rust=ra-multiplex'); an empty command disables that language"
+    )]
+    pub lsp_server: Vec<String>,
+
+    #[arg(
+        long = "lsp-request-timeout-ms",
+        env = "TAU_LSP_REQUEST_TIMEOUT_MS",
+        default_value_t = 10_000,
+        value_parser = parse_positive_u64,
+        help = "Timeout in milliseconds for individual language server requests"
+    )]
+    pub lsp_request_timeout_ms: u64,
+
+    #[arg(
+        long = "lsp-post-edit-diagnostics",
+        env = "TAU_LSP_POST_EDIT_DIAGNOSTICS",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        help = "Append language server diagnostics to write and edit tool results"
+    )]
+    pub lsp_post_edit_diagnostics: bool,
+
+    #[arg(
+        long = "git-session-worktrees",
+        env = "TAU_GIT_SESSION_WORKTREES",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        help = "Give each session branch created by the branch tool its own git worktree under .tau/git-worktrees"
+    )]
+    pub git_session_worktrees: bool,
+
     #[arg(
         long = "http-timeout-ms",
         env = "TAU_HTTP_TIMEOUT_MS",
@@ -5,8 +5,9 @@ use clap::{ArgAction, Args};
 use super::{parse_positive_u64, parse_positive_usize, CliGatewayDaemonFlags};
 use crate::{
     CliDeploymentWasmBrowserDidMethod, CliDeploymentWasmRuntimeProfile,
-    CliGatewayOpenResponsesAuthMode, CliGatewayRemoteProfile, CliMultiChannelLiveConnectorMode,
-    CliMultiChannelOutboundMode, CliMultiChannelTransport, CliWebhookSignatureAlgorithm,
+    CliGatewayOpenResponsesAuthMode, CliGatewayRemoteProfile, CliMultiChannelEmailTlsMode,
+    CliMultiChannelLiveConnectorMode, CliMultiChannelOutboundMode, CliMultiChannelTransport,
+    CliWebhookSignatureAlgorithm,
 };
 
 /// Tail runtime flags (gateway/daemon flatten + custom-command, voice, GitHub bridge).
@@ -378,6 +379,19 @@ pub struct CliRuntimeTailFlags {
     )]
     pub github_include_edited_comments: bool,
 
+    #[arg(
+        long = "github-pull-request-review",
+        env = "TAU_GITHUB_PULL_REQUEST_REVIEW",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        requires = "github_issues_bridge",
+        help = "Review open pull requests on each new head commit and answer /tau commands in review threads"
+    )]
+    pub github_pull_request_review: bool,
+
     #[arg(
         long = "github-processed-event-cap",
         env = "TAU_GITHUB_PROCESSED_EVENT_CAP",
@@ -676,6 +690,26 @@ pub struct CliRuntimeTailFlags {
     )]
     pub multi_channel_whatsapp_ingress_mode: CliMultiChannelLiveConnectorMode,
 
+    #[arg(
+        long = "multi-channel-matrix-ingress-mode",
+        env = "TAU_MULTI_CHANNEL_MATRIX_INGRESS_MODE",
+        value_enum,
+        default_value_t = CliMultiChannelLiveConnectorMode::Disabled,
+        requires = "multi_channel_live_connectors_runner",
+        help = "Matrix connector mode for live connectors runner (disabled, polling)"
+    )]
+    pub multi_channel_matrix_ingress_mode: CliMultiChannelLiveConnectorMode,
+
+    #[arg(
+        long = "multi-channel-email-ingress-mode",
+        env = "TAU_MULTI_CHANNEL_EMAIL_INGRESS_MODE",
+        value_enum,
+        default_value_t = CliMultiChannelLiveConnectorMode::Disabled,
+        requires = "multi_channel_live_connectors_runner",
+        help = "Email connector mode for live connectors runner (disabled, polling via IMAP)"
+    )]
+    pub multi_channel_email_ingress_mode: CliMultiChannelLiveConnectorMode,
+
     #[arg(
         long = "multi-channel-discord-ingress-channel-id",
         env = "TAU_MULTI_CHANNEL_DISCORD_INGRESS_CHANNEL_ID",
@@ -721,10 +755,48 @@ pub struct CliRuntimeTailFlags {
     )]
     pub multi_channel_whatsapp_webhook_app_secret: Option<String>,
 
+    #[arg(
+        long = "multi-channel-matrix-user-id",
+        env = "TAU_MATRIX_USER_ID",
+        requires = "multi_channel_live_connectors_runner",
+        help = "Matrix bot user id (for example @tau:example.org); resolved via whoami when omitted"
+    )]
+    pub multi_channel_matrix_user_id: Option<String>,
+
+    #[arg(
+        long = "multi-channel-matrix-auto-join-invites",
+        env = "TAU_MULTI_CHANNEL_MATRIX_AUTO_JOIN_INVITES",
+        default_value_t = false,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        requires = "multi_channel_live_connectors_runner",
+        help = "Join Matrix room invites allowed by the channel policy and decline the rest"
+    )]
+    pub multi_channel_matrix_auto_join_invites: bool,
+
+    #[arg(
+        long = "multi-channel-email-idle-timeout-ms",
+        env = "TAU_MULTI_CHANNEL_EMAIL_IDLE_TIMEOUT_MS",
+        default_value_t = 0,
+        requires = "multi_channel_live_connectors_runner",
+        help = "Wait up to this long in IMAP IDLE for new mail per poll cycle when the server supports it (0 disables IDLE)"
+    )]
+    pub multi_channel_email_idle_timeout_ms: u64,
+
+    #[arg(
+        long = "multi-channel-email-trusted-authserv-id",
+        env = "TAU_MULTI_CHANNEL_EMAIL_TRUSTED_AUTHSERV_ID",
+        requires = "multi_channel_live_connectors_runner",
+        help = "Authentication-Results authserv-id (for example mx.example.org) trusted for sender SPF/DKIM/DMARC verdicts"
+    )]
+    pub multi_channel_email_trusted_authserv_id: Option<String>,
+
     #[arg(
         long = "multi-channel-live-ingest-file",
         env = "TAU_MULTI_CHANNEL_LIVE_INGEST_FILE",
-        help = "One-shot provider payload ingestion: normalize a Telegram/Discord/WhatsApp payload file into live-ingress NDJSON and exit"
+        help = "One-shot provider payload ingestion: normalize a Telegram/Discord/WhatsApp/Matrix/email payload file into live-ingress NDJSON and exit"
     )]
     pub multi_channel_live_ingest_file: Option<PathBuf>,
 
@@ -733,7 +805,7 @@ pub struct CliRuntimeTailFlags {
         env = "TAU_MULTI_CHANNEL_LIVE_INGEST_TRANSPORT",
         value_enum,
         requires = "multi_channel_live_ingest_file",
-        help = "Transport for --multi-channel-live-ingest-file (telegram, discord, whatsapp)"
+        help = "Transport for --multi-channel-live-ingest-file (telegram, discord, whatsapp, matrix, email)"
     )]
     pub multi_channel_live_ingest_transport: Option<CliMultiChannelTransport>,
 
@@ -790,7 +862,7 @@ pub struct CliRuntimeTailFlags {
         conflicts_with = "multi_channel_channel_login",
         conflicts_with = "multi_channel_channel_logout",
         conflicts_with = "multi_channel_channel_probe",
-        help = "Inspect channel lifecycle/readiness status for one transport (telegram, discord, whatsapp) and exit"
+        help = "Inspect channel lifecycle/readiness status for one transport (telegram, discord, whatsapp, matrix, email) and exit"
     )]
     pub multi_channel_channel_status: Option<CliMultiChannelTransport>,
 
@@ -819,7 +891,7 @@ pub struct CliRuntimeTailFlags {
         conflicts_with = "multi_channel_channel_status",
         conflicts_with = "multi_channel_channel_logout",
         conflicts_with = "multi_channel_channel_probe",
-        help = "Initialize one channel lifecycle entry and ingress path for one transport (telegram, discord, whatsapp)"
+        help = "Initialize one channel lifecycle entry and ingress path for one transport (telegram, discord, whatsapp, matrix, email)"
     )]
     pub multi_channel_channel_login: Option<CliMultiChannelTransport>,
 
@@ -848,7 +920,7 @@ pub struct CliRuntimeTailFlags {
         conflicts_with = "multi_channel_channel_status",
         conflicts_with = "multi_channel_channel_login",
         conflicts_with = "multi_channel_channel_probe",
-        help = "Mark one channel lifecycle entry logged_out for one transport (telegram, discord, whatsapp)"
+        help = "Mark one channel lifecycle entry logged_out for one transport (telegram, discord, whatsapp, matrix, email)"
     )]
     pub multi_channel_channel_logout: Option<CliMultiChannelTransport>,
 
@@ -877,7 +949,7 @@ pub struct CliRuntimeTailFlags {
         conflicts_with = "multi_channel_channel_status",
         conflicts_with = "multi_channel_channel_login",
         conflicts_with = "multi_channel_channel_logout",
-        help = "Run readiness probe for one transport (telegram, discord, whatsapp) and persist lifecycle probe state"
+        help = "Run readiness probe for one transport (telegram, discord, whatsapp, matrix, email) and persist lifecycle probe state"
     )]
     pub multi_channel_channel_probe: Option<CliMultiChannelTransport>,
 
@@ -920,7 +992,7 @@ pub struct CliRuntimeTailFlags {
         conflicts_with = "multi_channel_channel_login",
         conflicts_with = "multi_channel_channel_logout",
         conflicts_with = "multi_channel_channel_probe",
-        help = "Send one outbound message for one transport (telegram, discord, whatsapp) and exit"
+        help = "Send one outbound message for one transport (telegram, discord, whatsapp, matrix, email) and exit"
     )]
     pub multi_channel_send: Option<CliMultiChannelTransport>,
 
@@ -1205,6 +1277,14 @@ pub struct CliRuntimeTailFlags {
     )]
     pub multi_channel_whatsapp_api_base: String,
 
+    #[arg(
+        long = "multi-channel-matrix-homeserver-url",
+        env = "TAU_MATRIX_HOMESERVER_URL",
+        default_value = "https://matrix.org",
+        help = "Matrix homeserver base URL for multi-channel sync ingress and outbound mode=provider"
+    )]
+    pub multi_channel_matrix_homeserver_url: String,
+
     #[arg(
         long = "multi-channel-telegram-bot-token",
         env = "TAU_TELEGRAM_BOT_TOKEN",
@@ -1236,6 +1316,94 @@ pub struct CliRuntimeTailFlags {
     )]
     pub multi_channel_whatsapp_phone_number_id: Option<String>,
 
+    #[arg(
+        long = "multi-channel-matrix-access-token",
+        env = "TAU_MATRIX_ACCESS_TOKEN",
+        hide_env_values = true,
+        help = "Matrix access token for multi-channel sync ingress and outbound mode=provider"
+    )]
+    pub multi_channel_matrix_access_token: Option<String>,
+
+    #[arg(
+        long = "multi-channel-email-address",
+        env = "TAU_EMAIL_ADDRESS",
+        help = "Mailbox address the agent reads and replies from for multi-channel email"
+    )]
+    pub multi_channel_email_address: Option<String>,
+
+    #[arg(
+        long = "multi-channel-email-username",
+        env = "TAU_EMAIL_USERNAME",
+        help = "IMAP/SMTP login name for multi-channel email (defaults to --multi-channel-email-address)"
+    )]
+    pub multi_channel_email_username: Option<String>,
+
+    #[arg(
+        long = "multi-channel-email-password",
+        env = "TAU_EMAIL_PASSWORD",
+        hide_env_values = true,
+        help = "IMAP/SMTP password or app password for multi-channel email ingress and outbound mode=provider"
+    )]
+    pub multi_channel_email_password: Option<String>,
+
+    #[arg(
+        long = "multi-channel-email-imap-host",
+        env = "TAU_EMAIL_IMAP_HOST",
+        default_value = "",
+        help = "IMAP server host for multi-channel email ingress"
+    )]
+    pub multi_channel_email_imap_host: String,
+
+    #[arg(
+        long = "multi-channel-email-imap-port",
+        env = "TAU_EMAIL_IMAP_PORT",
+        default_value_t = 993,
+        help = "IMAP server port for multi-channel email ingress"
+    )]
+    pub multi_channel_email_imap_port: u16,
+
+    #[arg(
+        long = "multi-channel-email-imap-tls",
+        env = "TAU_EMAIL_IMAP_TLS",
+        value_enum,
+        default_value_t = CliMultiChannelEmailTlsMode::Implicit,
+        help = "IMAP transport security (none, starttls, implicit); none is only allowed for loopback hosts"
+    )]
+    pub multi_channel_email_imap_tls: CliMultiChannelEmailTlsMode,
+
+    #[arg(
+        long = "multi-channel-email-imap-mailbox",
+        env = "TAU_EMAIL_IMAP_MAILBOX",
+        default_value = "INBOX",
+        help = "IMAP mailbox watched for inbound email"
+    )]
+    pub multi_channel_email_imap_mailbox: String,
+
+    #[arg(
+        long = "multi-channel-email-smtp-host",
+        env = "TAU_EMAIL_SMTP_HOST",
+        default_value = "",
+        help = "SMTP submission host for multi-channel email outbound mode=provider"
+    )]
+    pub multi_channel_email_smtp_host: String,
+
+    #[arg(
+        long = "multi-channel-email-smtp-port",
+        env = "TAU_EMAIL_SMTP_PORT",
+        default_value_t = 587,
+        help = "SMTP submission port for multi-channel email outbound mode=provider"
+    )]
+    pub multi_channel_email_smtp_port: u16,
+
+    #[arg(
+        long = "multi-channel-email-smtp-tls",
+        env = "TAU_EMAIL_SMTP_TLS",
+        value_enum,
+        default_value_t = CliMultiChannelEmailTlsMode::Starttls,
+        help = "SMTP transport security (none, starttls, implicit); none is only allowed for loopback hosts"
+    )]
+    pub multi_channel_email_smtp_tls: CliMultiChannelEmailTlsMode,
+
     #[arg(
         long = "multi-agent-contract-runner",
         env = "TAU_MULTI_AGENT_CONTRACT_RUNNER",
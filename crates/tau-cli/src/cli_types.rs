@@ -1,6 +1,7 @@
 use clap::ValueEnum;
 
 use tau_multi_channel::multi_channel_contract::MultiChannelTransport;
+use tau_multi_channel::multi_channel_email::MultiChannelEmailTlsMode;
 use tau_multi_channel::multi_channel_live_connectors::MultiChannelLiveConnectorMode;
 use tau_multi_channel::multi_channel_outbound::MultiChannelOutboundMode;
 use tau_session::SessionImportMode;
@@ -19,6 +20,7 @@ pub enum CliOsSandboxMode {
     Off,
     Auto,
     Force,
+    Native,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -83,6 +85,14 @@ pub enum CliToolPolicyPreset {
     Hardened,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// Enumerates supported `CliReasoningEffort` values.
+pub enum CliReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 /// Enumerates supported `CliPromptSanitizerMode` values.
 pub enum CliPromptSanitizerMode {
@@ -204,6 +214,8 @@ pub enum CliMultiChannelTransport {
     Telegram,
     Discord,
     Whatsapp,
+    Matrix,
+    Email,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -228,6 +240,14 @@ impl CliMultiChannelLiveConnectorMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// Enumerates supported `CliMultiChannelEmailTlsMode` values.
+pub enum CliMultiChannelEmailTlsMode {
+    None,
+    Starttls,
+    Implicit,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 /// Enumerates supported `CliMultiChannelOutboundMode` values.
 pub enum CliMultiChannelOutboundMode {
@@ -242,6 +262,8 @@ impl From<CliMultiChannelTransport> for MultiChannelTransport {
             CliMultiChannelTransport::Telegram => MultiChannelTransport::Telegram,
             CliMultiChannelTransport::Discord => MultiChannelTransport::Discord,
             CliMultiChannelTransport::Whatsapp => MultiChannelTransport::Whatsapp,
+            CliMultiChannelTransport::Matrix => MultiChannelTransport::Matrix,
+            CliMultiChannelTransport::Email => MultiChannelTransport::Email,
         }
     }
 }
@@ -256,6 +278,16 @@ impl From<CliMultiChannelLiveConnectorMode> for MultiChannelLiveConnectorMode {
     }
 }
 
+impl From<CliMultiChannelEmailTlsMode> for MultiChannelEmailTlsMode {
+    fn from(value: CliMultiChannelEmailTlsMode) -> Self {
+        match value {
+            CliMultiChannelEmailTlsMode::None => MultiChannelEmailTlsMode::None,
+            CliMultiChannelEmailTlsMode::Starttls => MultiChannelEmailTlsMode::Starttls,
+            CliMultiChannelEmailTlsMode::Implicit => MultiChannelEmailTlsMode::Implicit,
+        }
+    }
+}
+
 impl From<CliMultiChannelOutboundMode> for MultiChannelOutboundMode {
     fn from(value: CliMultiChannelOutboundMode) -> Self {
         match value {
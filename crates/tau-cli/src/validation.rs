@@ -127,6 +127,7 @@ pub fn validate_project_index_cli(cli: &Cli) -> Result<()> {
 
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -269,6 +270,45 @@ pub fn validate_slack_bridge_cli(cli: &Cli) -> Result<()> {
     Ok(())
 }
 
+pub fn validate_discord_bridge_cli(cli: &Cli) -> Result<()> {
+    if !cli.discord_bridge {
+        return Ok(());
+    }
+
+    if has_prompt_or_command_input(cli) {
+        bail!("--discord-bridge cannot be combined with --prompt, --prompt-file, --prompt-template-file, or --command-file");
+    }
+    if cli.no_session {
+        bail!("--discord-bridge cannot be used together with --no-session");
+    }
+    if cli.github_issues_bridge || cli.slack_bridge {
+        bail!("--discord-bridge cannot be combined with --github-issues-bridge or --slack-bridge");
+    }
+    let has_discord_bot_token =
+        resolve_non_empty_cli_value(cli.discord_bot_token.as_deref()).is_some();
+    let has_discord_bot_token_id =
+        resolve_non_empty_cli_value(cli.discord_bot_token_id.as_deref()).is_some();
+    if !has_discord_bot_token && !has_discord_bot_token_id {
+        bail!(
+            "--discord-bot-token (or --discord-bot-token-id) is required when --discord-bridge is set"
+        );
+    }
+    if cli.discord_application_id.unwrap_or(0) == 0 {
+        bail!("--discord-application-id is required when --discord-bridge is set");
+    }
+    if cli.discord_command_prefix.trim().is_empty() {
+        bail!("--discord-command-prefix cannot be empty");
+    }
+    if cli.discord_processed_event_cap == 0 {
+        bail!("--discord-processed-event-cap must be greater than 0");
+    }
+    if cli.discord_reconnect_delay_ms == 0 {
+        bail!("--discord-reconnect-delay-ms must be greater than 0");
+    }
+
+    Ok(())
+}
+
 pub fn validate_events_runner_cli(cli: &Cli) -> Result<()> {
     if !cli.events_runner {
         return Ok(());
@@ -280,9 +320,13 @@ pub fn validate_events_runner_cli(cli: &Cli) -> Result<()> {
     if cli.no_session {
         bail!("--events-runner cannot be used together with --no-session");
     }
-    if cli.github_issues_bridge || cli.slack_bridge || cli.memory_contract_runner {
+    if cli.github_issues_bridge
+        || cli.slack_bridge
+        || cli.discord_bridge
+        || cli.memory_contract_runner
+    {
         bail!(
-            "--events-runner cannot be combined with --github-issues-bridge, --slack-bridge, or --memory-contract-runner"
+            "--events-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, or --memory-contract-runner"
         );
     }
     if cli.events_poll_interval_ms == 0 {
@@ -312,10 +356,11 @@ pub fn validate_multi_channel_contract_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.memory_contract_runner
     {
-        bail!("--multi-channel-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner");
+        bail!("--multi-channel-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner");
     }
     if cli.multi_channel_queue_limit == 0 {
         bail!("--multi-channel-queue-limit must be greater than 0");
@@ -381,10 +426,11 @@ pub fn validate_multi_channel_live_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.memory_contract_runner
     {
-        bail!("--multi-channel-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner");
+        bail!("--multi-channel-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner");
     }
     if cli.multi_channel_queue_limit == 0 {
         bail!("--multi-channel-queue-limit must be greater than 0");
@@ -452,10 +498,11 @@ pub fn validate_multi_channel_live_connectors_runner_cli(cli: &Cli) -> Result<()
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.memory_contract_runner
     {
-        bail!("--multi-channel-live-connectors-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner");
+        bail!("--multi-channel-live-connectors-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner");
     }
     if cli.multi_channel_processed_event_cap == 0 {
         bail!("--multi-channel-processed-event-cap must be greater than 0");
@@ -481,9 +528,16 @@ pub fn validate_multi_channel_live_connectors_runner_cli(cli: &Cli) -> Result<()
     let telegram_mode = cli.multi_channel_telegram_ingress_mode;
     let discord_mode = cli.multi_channel_discord_ingress_mode;
     let whatsapp_mode = cli.multi_channel_whatsapp_ingress_mode;
-    if telegram_mode.is_disabled() && discord_mode.is_disabled() && whatsapp_mode.is_disabled() {
+    let matrix_mode = cli.multi_channel_matrix_ingress_mode;
+    let email_mode = cli.multi_channel_email_ingress_mode;
+    if telegram_mode.is_disabled()
+        && discord_mode.is_disabled()
+        && whatsapp_mode.is_disabled()
+        && matrix_mode.is_disabled()
+        && email_mode.is_disabled()
+    {
         bail!(
-            "at least one connector mode must be enabled: --multi-channel-telegram-ingress-mode, --multi-channel-discord-ingress-mode, or --multi-channel-whatsapp-ingress-mode"
+            "at least one connector mode must be enabled: --multi-channel-telegram-ingress-mode, --multi-channel-discord-ingress-mode, --multi-channel-whatsapp-ingress-mode, --multi-channel-matrix-ingress-mode, or --multi-channel-email-ingress-mode"
         );
     }
     if discord_mode.is_webhook() {
@@ -492,6 +546,44 @@ pub fn validate_multi_channel_live_connectors_runner_cli(cli: &Cli) -> Result<()
     if whatsapp_mode.is_polling() {
         bail!("--multi-channel-whatsapp-ingress-mode=polling is not supported; use webhook");
     }
+    if matrix_mode.is_webhook() {
+        bail!("--multi-channel-matrix-ingress-mode=webhook is not supported; use polling");
+    }
+    if matrix_mode.is_polling() && cli.multi_channel_matrix_homeserver_url.trim().is_empty() {
+        bail!(
+            "--multi-channel-matrix-homeserver-url cannot be empty when --multi-channel-matrix-ingress-mode=polling"
+        );
+    }
+    if cli.multi_channel_matrix_auto_join_invites && !matrix_mode.is_polling() {
+        bail!(
+            "--multi-channel-matrix-auto-join-invites requires --multi-channel-matrix-ingress-mode=polling"
+        );
+    }
+    if email_mode.is_webhook() {
+        bail!("--multi-channel-email-ingress-mode=webhook is not supported; use polling");
+    }
+    if email_mode.is_polling() {
+        if cli
+            .multi_channel_email_address
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or_default()
+            .is_empty()
+        {
+            bail!(
+                "--multi-channel-email-address is required when --multi-channel-email-ingress-mode=polling"
+            );
+        }
+        if cli.multi_channel_email_imap_host.trim().is_empty() {
+            bail!(
+                "--multi-channel-email-imap-host is required when --multi-channel-email-ingress-mode=polling"
+            );
+        }
+    } else if cli.multi_channel_email_idle_timeout_ms > 0 {
+        bail!(
+            "--multi-channel-email-idle-timeout-ms requires --multi-channel-email-ingress-mode=polling"
+        );
+    }
     if discord_mode.is_polling()
         && cli
             .multi_channel_discord_ingress_channel_ids
@@ -556,10 +648,11 @@ pub fn validate_multi_channel_live_ingest_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.memory_contract_runner
     {
-        bail!("--multi-channel-live-ingest-file cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner");
+        bail!("--multi-channel-live-ingest-file cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner");
     }
 
     let ingest_file = cli
@@ -626,6 +719,7 @@ pub fn validate_multi_channel_incident_timeline_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -725,6 +819,7 @@ pub fn validate_multi_channel_channel_lifecycle_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -815,6 +910,7 @@ pub fn validate_multi_channel_send_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -902,13 +998,14 @@ pub fn validate_multi_agent_contract_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
         || cli.memory_contract_runner
         || cli.dashboard_contract_runner
     {
-        bail!("--multi-agent-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --memory-contract-runner, or --dashboard-contract-runner");
+        bail!("--multi-agent-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --memory-contract-runner, or --dashboard-contract-runner");
     }
     if cli.multi_agent_queue_limit == 0 {
         bail!("--multi-agent-queue-limit must be greater than 0");
@@ -956,6 +1053,7 @@ pub fn validate_browser_automation_live_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -971,7 +1069,7 @@ pub fn validate_browser_automation_live_runner_cli(cli: &Cli) -> Result<()> {
         || cli.voice_live_runner
     {
         bail!(
-            "--browser-automation-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --browser-automation-contract-runner, --browser-automation-preflight, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --deployment-contract-runner, --custom-command-contract-runner, --voice-contract-runner, or --voice-live-runner"
+            "--browser-automation-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --browser-automation-contract-runner, --browser-automation-preflight, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --deployment-contract-runner, --custom-command-contract-runner, --voice-contract-runner, or --voice-live-runner"
         );
     }
     if cli.browser_automation_playwright_cli.trim().is_empty() {
@@ -1003,6 +1101,7 @@ pub fn validate_browser_automation_preflight_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1087,6 +1186,7 @@ pub fn validate_daemon_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1150,6 +1250,7 @@ pub fn validate_gateway_service_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1219,6 +1320,7 @@ pub fn validate_gateway_remote_profile_inspect_cli(cli: &Cli) -> Result<()> {
         || daemon_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1284,6 +1386,7 @@ pub fn validate_gateway_remote_plan_cli(cli: &Cli) -> Result<()> {
         || daemon_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1331,6 +1434,7 @@ pub fn validate_gateway_openresponses_server_cli(cli: &Cli) -> Result<()> {
         || daemon_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1425,6 +1529,7 @@ pub fn validate_gateway_contract_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1432,7 +1537,7 @@ pub fn validate_gateway_contract_runner_cli(cli: &Cli) -> Result<()> {
         || cli.memory_contract_runner
         || cli.dashboard_contract_runner
     {
-        bail!("--gateway-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, or --dashboard-contract-runner");
+        bail!("--gateway-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, or --dashboard-contract-runner");
     }
     if !cli.gateway_fixture.exists() {
         bail!(
@@ -1469,6 +1574,7 @@ pub fn validate_deployment_contract_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1480,7 +1586,7 @@ pub fn validate_deployment_contract_runner_cli(cli: &Cli) -> Result<()> {
         || cli.voice_contract_runner
         || cli.voice_live_runner
     {
-        bail!("--deployment-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner");
+        bail!("--deployment-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner");
     }
     if cli.deployment_queue_limit == 0 {
         bail!("--deployment-queue-limit must be greater than 0");
@@ -1520,6 +1626,7 @@ pub fn validate_deployment_wasm_package_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1585,6 +1692,7 @@ pub fn validate_deployment_wasm_inspect_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1637,6 +1745,7 @@ pub fn validate_deployment_wasm_browser_did_init_cli(cli: &Cli) -> Result<()> {
         || gateway_openresponses_mode_requested(cli)
         || cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1698,6 +1807,7 @@ pub fn validate_voice_contract_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1708,7 +1818,7 @@ pub fn validate_voice_contract_runner_cli(cli: &Cli) -> Result<()> {
         || cli.custom_command_contract_runner
         || cli.voice_live_runner
     {
-        bail!("--voice-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-live-runner");
+        bail!("--voice-contract-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-live-runner");
     }
     if cli.voice_queue_limit == 0 {
         bail!("--voice-queue-limit must be greater than 0");
@@ -1748,6 +1858,7 @@ pub fn validate_voice_live_runner_cli(cli: &Cli) -> Result<()> {
     }
     if cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1758,7 +1869,7 @@ pub fn validate_voice_live_runner_cli(cli: &Cli) -> Result<()> {
         || cli.custom_command_contract_runner
         || cli.voice_contract_runner
     {
-        bail!("--voice-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner");
+        bail!("--voice-live-runner cannot be combined with --github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner");
     }
     if cli.voice_live_wake_word.trim().is_empty() {
         bail!("--voice-live-wake-word cannot be empty");
@@ -0,0 +1,788 @@
+//! Tree-sitter extraction of definitions, references and imports for one file.
+//!
+//! Extraction walks the concrete syntax tree once in pre-order. Definition name
+//! nodes are remembered so the same identifier is not double-counted as a
+//! reference, and nesting (impl/trait/class bodies, functions, modules) is
+//! tracked so definitions carry their enclosing container.
+
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Node, Parser};
+
+use crate::CodeLanguage;
+
+const MAX_DEFINITIONS_PER_FILE: usize = 4_096;
+const MAX_REFERENCES_PER_FILE: usize = 16_384;
+const MAX_IMPORTS_PER_FILE: usize = 1_024;
+const MAX_SIGNATURE_CHARS: usize = 200;
+const MAX_TREE_DEPTH: usize = 512;
+
+/// Enumerates definition kinds recorded by the indexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Function,
+    Method,
+    Struct,
+    Enum,
+    Trait,
+    Interface,
+    Class,
+    Type,
+    Constant,
+    Variable,
+    Module,
+    Macro,
+}
+
+impl SymbolKind {
+    /// Returns the stable label used in index payloads and tool arguments.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Method => "method",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Trait => "trait",
+            Self::Interface => "interface",
+            Self::Class => "class",
+            Self::Type => "type",
+            Self::Constant => "constant",
+            Self::Variable => "variable",
+            Self::Module => "module",
+            Self::Macro => "macro",
+        }
+    }
+
+    /// Parses a label produced by [`SymbolKind::as_str`].
+    pub fn parse(value: &str) -> Option<Self> {
+        let kind = match value.trim().to_ascii_lowercase().as_str() {
+            "function" => Self::Function,
+            "method" => Self::Method,
+            "struct" => Self::Struct,
+            "enum" => Self::Enum,
+            "trait" => Self::Trait,
+            "interface" => Self::Interface,
+            "class" => Self::Class,
+            "type" => Self::Type,
+            "constant" => Self::Constant,
+            "variable" => Self::Variable,
+            "module" => Self::Module,
+            "macro" => Self::Macro,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// One named definition. Lines and columns are 1-based; columns count bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolDefinition {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub container: Option<String>,
+    pub line: u32,
+    pub column: u32,
+    pub end_line: u32,
+    pub signature: String,
+}
+
+/// One identifier occurrence that is not itself a definition name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolReference {
+    pub name: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// One import/use target as written in source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolImport {
+    pub path: String,
+    pub line: u32,
+}
+
+/// Extraction result for one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSymbols {
+    pub definitions: Vec<SymbolDefinition>,
+    pub references: Vec<SymbolReference>,
+    pub imports: Vec<SymbolImport>,
+    pub truncated: bool,
+}
+
+/// Parses `source` with the grammar for `language` and extracts its symbols.
+pub fn extract_file_symbols(language: CodeLanguage, source: &str) -> Result<FileSymbols> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&language.grammar())
+        .map_err(|error| anyhow!("failed to load {} grammar: {error}", language.as_str()))?;
+    let tree = parser
+        .parse(source, None)
+        .ok_or_else(|| anyhow!("{} parser returned no syntax tree", language.as_str()))?;
+
+    let mut extractor = Extractor {
+        language,
+        source: source.as_bytes(),
+        symbols: FileSymbols::default(),
+        definition_name_starts: BTreeSet::new(),
+    };
+    extractor.visit(tree.root_node(), None, 0);
+    Ok(extractor.symbols)
+}
+
+#[derive(Debug, Clone)]
+struct Scope {
+    name: String,
+    type_like: bool,
+}
+
+struct Extractor<'a> {
+    language: CodeLanguage,
+    source: &'a [u8],
+    symbols: FileSymbols,
+    definition_name_starts: BTreeSet<usize>,
+}
+
+impl<'a> Extractor<'a> {
+    fn visit(&mut self, node: Node<'a>, scope: Option<&Scope>, depth: usize) {
+        if depth > MAX_TREE_DEPTH {
+            self.symbols.truncated = true;
+            return;
+        }
+
+        let child_scope = match self.language {
+            CodeLanguage::Rust => self.visit_rust(node, scope),
+            CodeLanguage::TypeScript | CodeLanguage::Tsx => self.visit_typescript(node, scope),
+            CodeLanguage::Python => self.visit_python(node, scope),
+            CodeLanguage::Go => self.visit_go(node),
+        };
+        if self.is_reference_kind(node.kind())
+            && !self.definition_name_starts.contains(&node.start_byte())
+        {
+            self.push_reference(node);
+        }
+
+        let next_scope = child_scope.as_ref().or(scope);
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, next_scope, depth + 1);
+        }
+    }
+
+    fn visit_rust(&mut self, node: Node<'a>, scope: Option<&Scope>) -> Option<Scope> {
+        let in_type = scope.is_some_and(|scope| scope.type_like);
+        match node.kind() {
+            "function_item" | "function_signature_item" => {
+                let kind = if in_type {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                self.define_named(node, kind, scope, false)
+            }
+            "struct_item" | "union_item" => {
+                self.define_named(node, SymbolKind::Struct, scope, false)
+            }
+            "enum_item" => self.define_named(node, SymbolKind::Enum, scope, false),
+            "trait_item" => self.define_named(node, SymbolKind::Trait, scope, true),
+            "type_item" => self.define_named(node, SymbolKind::Type, scope, false),
+            "const_item" | "static_item" => {
+                self.define_named(node, SymbolKind::Constant, scope, false)
+            }
+            "mod_item" => self.define_named(node, SymbolKind::Module, scope, false),
+            "macro_definition" => self.define_named(node, SymbolKind::Macro, scope, false),
+            "impl_item" => node.child_by_field_name("type").map(|type_node| Scope {
+                name: simple_type_name(self.text(type_node)),
+                type_like: true,
+            }),
+            "use_declaration" => {
+                if let Some(argument) = node.child_by_field_name("argument") {
+                    self.push_import(self.text(argument), node);
+                }
+                None
+            }
+            "extern_crate_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.push_import(self.text(name), node);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_typescript(&mut self, node: Node<'a>, scope: Option<&Scope>) -> Option<Scope> {
+        match node.kind() {
+            "function_declaration" | "generator_function_declaration" | "function_signature" => {
+                self.define_named(node, SymbolKind::Function, scope, false)
+            }
+            "class_declaration" | "abstract_class_declaration" | "class" => {
+                self.define_named(node, SymbolKind::Class, scope, true)
+            }
+            "interface_declaration" => self.define_named(node, SymbolKind::Interface, scope, true),
+            "method_definition" | "method_signature" | "abstract_method_signature" => {
+                self.define_named(node, SymbolKind::Method, scope, false)
+            }
+            "type_alias_declaration" => self.define_named(node, SymbolKind::Type, scope, false),
+            "enum_declaration" => self.define_named(node, SymbolKind::Enum, scope, false),
+            "internal_module" | "module" => {
+                self.define_named(node, SymbolKind::Module, scope, false)
+            }
+            "variable_declarator" => {
+                let declaration = node.parent()?;
+                if !is_top_level_typescript_declaration(declaration) {
+                    return None;
+                }
+                let name = node.child_by_field_name("name")?;
+                if name.kind() != "identifier" {
+                    return None;
+                }
+                let is_function = node.child_by_field_name("value").is_some_and(|value| {
+                    matches!(
+                        value.kind(),
+                        "arrow_function" | "function_expression" | "function"
+                    )
+                });
+                let is_const = declaration
+                    .child(0)
+                    .is_some_and(|keyword| self.text(keyword) == "const");
+                let kind = if is_function {
+                    SymbolKind::Function
+                } else if is_const {
+                    SymbolKind::Constant
+                } else {
+                    SymbolKind::Variable
+                };
+                self.define(node, name, kind, scope, false)
+            }
+            "import_statement" | "export_statement" => {
+                if let Some(source) = node.child_by_field_name("source") {
+                    self.push_import(trim_quotes(self.text(source)), node);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_python(&mut self, node: Node<'a>, scope: Option<&Scope>) -> Option<Scope> {
+        let in_type = scope.is_some_and(|scope| scope.type_like);
+        match node.kind() {
+            "function_definition" => {
+                let kind = if in_type {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                self.define_named(node, kind, scope, false)
+            }
+            "class_definition" => self.define_named(node, SymbolKind::Class, scope, true),
+            "assignment" => {
+                let statement = node.parent()?;
+                if statement.kind() != "expression_statement"
+                    || statement.parent().map(|parent| parent.kind()) != Some("module")
+                {
+                    return None;
+                }
+                let name = node.child_by_field_name("left")?;
+                if name.kind() != "identifier" {
+                    return None;
+                }
+                let text = self.text(name);
+                let kind = if text.chars().any(|ch| ch.is_ascii_lowercase()) {
+                    SymbolKind::Variable
+                } else {
+                    SymbolKind::Constant
+                };
+                self.define(node, name, kind, scope, false)
+            }
+            "import_statement" => {
+                let mut cursor = node.walk();
+                let targets = node
+                    .children_by_field_name("name", &mut cursor)
+                    .collect::<Vec<_>>();
+                for target in targets {
+                    let module = if target.kind() == "aliased_import" {
+                        target.child_by_field_name("name").unwrap_or(target)
+                    } else {
+                        target
+                    };
+                    self.push_import(self.text(module), node);
+                }
+                None
+            }
+            "import_from_statement" => {
+                if let Some(module) = node.child_by_field_name("module_name") {
+                    self.push_import(self.text(module), node);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_go(&mut self, node: Node<'a>) -> Option<Scope> {
+        match node.kind() {
+            "function_declaration" => self.define_named(node, SymbolKind::Function, None, false),
+            "method_declaration" => {
+                let receiver = node
+                    .child_by_field_name("receiver")
+                    .and_then(|receiver| receiver.named_child(0))
+                    .and_then(|parameter| parameter.child_by_field_name("type"))
+                    .map(|receiver_type| Scope {
+                        name: simple_type_name(self.text(receiver_type)),
+                        type_like: true,
+                    });
+                self.define_named(node, SymbolKind::Method, receiver.as_ref(), false)
+            }
+            "type_spec" | "type_alias" => {
+                let kind = match node
+                    .child_by_field_name("type")
+                    .map(|type_node| type_node.kind())
+                {
+                    Some("struct_type") => SymbolKind::Struct,
+                    Some("interface_type") => SymbolKind::Interface,
+                    _ => SymbolKind::Type,
+                };
+                self.define_named(node, kind, None, kind == SymbolKind::Interface)
+            }
+            "method_spec" | "method_elem" => {
+                let interface = node
+                    .parent()
+                    .and_then(|parent| parent.parent())
+                    .filter(|type_spec| type_spec.kind() == "type_spec")
+                    .and_then(|type_spec| type_spec.child_by_field_name("name"))
+                    .map(|name| Scope {
+                        name: self.text(name).to_string(),
+                        type_like: true,
+                    });
+                self.define_named(node, SymbolKind::Method, interface.as_ref(), false)
+            }
+            "const_spec" | "var_spec" => {
+                if !is_top_level_go_spec(node) {
+                    return None;
+                }
+                let kind = if node.kind() == "const_spec" {
+                    SymbolKind::Constant
+                } else {
+                    SymbolKind::Variable
+                };
+                let mut cursor = node.walk();
+                let names = node
+                    .children_by_field_name("name", &mut cursor)
+                    .collect::<Vec<_>>();
+                for name in names {
+                    self.define(node, name, kind, None, false);
+                }
+                None
+            }
+            "import_spec" => {
+                if let Some(path) = node.child_by_field_name("path") {
+                    self.push_import(trim_quotes(self.text(path)), node);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn is_reference_kind(&self, kind: &str) -> bool {
+        match self.language {
+            CodeLanguage::Rust => {
+                matches!(kind, "identifier" | "type_identifier" | "field_identifier")
+            }
+            CodeLanguage::TypeScript | CodeLanguage::Tsx => matches!(
+                kind,
+                "identifier"
+                    | "type_identifier"
+                    | "property_identifier"
+                    | "shorthand_property_identifier"
+            ),
+            CodeLanguage::Python => kind == "identifier",
+            CodeLanguage::Go => matches!(
+                kind,
+                "identifier" | "type_identifier" | "field_identifier" | "package_identifier"
+            ),
+        }
+    }
+
+    fn define_named(
+        &mut self,
+        node: Node<'a>,
+        kind: SymbolKind,
+        scope: Option<&Scope>,
+        type_like: bool,
+    ) -> Option<Scope> {
+        let name = node.child_by_field_name("name")?;
+        self.define(node, name, kind, scope, type_like)
+    }
+
+    fn define(
+        &mut self,
+        node: Node<'a>,
+        name_node: Node<'a>,
+        kind: SymbolKind,
+        scope: Option<&Scope>,
+        type_like: bool,
+    ) -> Option<Scope> {
+        let name = trim_quotes(self.text(name_node)).to_string();
+        if name.is_empty() {
+            return None;
+        }
+        self.definition_name_starts.insert(name_node.start_byte());
+        if self.symbols.definitions.len() >= MAX_DEFINITIONS_PER_FILE {
+            self.symbols.truncated = true;
+        } else {
+            let start = node.start_position();
+            self.symbols.definitions.push(SymbolDefinition {
+                name: name.clone(),
+                kind,
+                container: scope.map(|scope| scope.name.clone()),
+                line: one_based(start.row),
+                column: one_based(start.column),
+                end_line: one_based(node.end_position().row),
+                signature: signature_line(self.text(node)),
+            });
+        }
+        Some(Scope { name, type_like })
+    }
+
+    fn push_reference(&mut self, node: Node<'a>) {
+        if self.symbols.references.len() >= MAX_REFERENCES_PER_FILE {
+            self.symbols.truncated = true;
+            return;
+        }
+        let name = self.text(node);
+        if name.is_empty() {
+            return;
+        }
+        let start = node.start_position();
+        self.symbols.references.push(SymbolReference {
+            name: name.to_string(),
+            line: one_based(start.row),
+            column: one_based(start.column),
+        });
+    }
+
+    fn push_import(&mut self, path: &str, node: Node<'a>) {
+        let path = path.trim();
+        if path.is_empty() {
+            return;
+        }
+        if self.symbols.imports.len() >= MAX_IMPORTS_PER_FILE {
+            self.symbols.truncated = true;
+            return;
+        }
+        self.symbols.imports.push(SymbolImport {
+            path: path.to_string(),
+            line: one_based(node.start_position().row),
+        });
+    }
+
+    fn text(&self, node: Node<'a>) -> &'a str {
+        node.utf8_text(self.source).unwrap_or_default()
+    }
+}
+
+fn is_top_level_typescript_declaration(declaration: Node<'_>) -> bool {
+    if !matches!(
+        declaration.kind(),
+        "lexical_declaration" | "variable_declaration"
+    ) {
+        return false;
+    }
+    match declaration.parent() {
+        Some(parent) if parent.kind() == "program" => true,
+        Some(parent) if parent.kind() == "export_statement" => parent
+            .parent()
+            .is_some_and(|grandparent| grandparent.kind() == "program"),
+        _ => false,
+    }
+}
+
+fn is_top_level_go_spec(spec: Node<'_>) -> bool {
+    let mut current = spec.parent();
+    while let Some(node) = current {
+        match node.kind() {
+            "const_declaration" | "var_declaration" | "var_spec_list" => current = node.parent(),
+            "source_file" => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn simple_type_name(raw: &str) -> String {
+    let without_generics = raw
+        .split(['<', '['])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_start_matches(['*', '&'])
+        .trim_start_matches("mut ")
+        .trim();
+    without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics)
+        .rsplit('.')
+        .next()
+        .unwrap_or(without_generics)
+        .to_string()
+}
+
+fn trim_quotes(raw: &str) -> &str {
+    raw.trim().trim_matches(|ch| matches!(ch, '"' | '\'' | '`'))
+}
+
+fn signature_line(text: &str) -> String {
+    text.lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .take(MAX_SIGNATURE_CHARS)
+        .collect()
+}
+
+fn one_based(value: usize) -> u32 {
+    u32::try_from(value.saturating_add(1)).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition<'a>(symbols: &'a FileSymbols, name: &str) -> &'a SymbolDefinition {
+        symbols
+            .definitions
+            .iter()
+            .find(|definition| definition.name == name)
+            .unwrap_or_else(|| panic!("missing definition {name}: {:?}", symbols.definitions))
+    }
+
+    #[test]
+    fn unit_extract_rust_definitions_containers_references_and_imports() {
+        let source = r#"use std::collections::BTreeMap;
+
+pub struct Index {
+    entries: BTreeMap<String, usize>,
+}
+
+impl Index {
+    pub fn insert(&mut self, key: String) -> usize {
+        helper(self.entries.len())
+    }
+}
+
+pub trait Store {
+    fn load(&self) -> Index;
+}
+
+fn helper(value: usize) -> usize {
+    value
+}
+
+const LIMIT: usize = 4;
+"#;
+        let symbols = extract_file_symbols(CodeLanguage::Rust, source).expect("extract");
+
+        let index = definition(&symbols, "Index");
+        assert_eq!(index.kind, SymbolKind::Struct);
+        assert_eq!(index.line, 3);
+        assert_eq!(index.end_line, 5);
+        assert_eq!(index.signature, "pub struct Index {");
+
+        let insert = definition(&symbols, "insert");
+        assert_eq!(insert.kind, SymbolKind::Method);
+        assert_eq!(insert.container.as_deref(), Some("Index"));
+
+        let load = definition(&symbols, "load");
+        assert_eq!(load.kind, SymbolKind::Method);
+        assert_eq!(load.container.as_deref(), Some("Store"));
+        assert_eq!(definition(&symbols, "Store").kind, SymbolKind::Trait);
+        assert_eq!(definition(&symbols, "helper").kind, SymbolKind::Function);
+        assert_eq!(definition(&symbols, "LIMIT").kind, SymbolKind::Constant);
+
+        let helper_references = symbols
+            .references
+            .iter()
+            .filter(|reference| reference.name == "helper")
+            .map(|reference| reference.line)
+            .collect::<Vec<_>>();
+        assert_eq!(helper_references, vec![9]);
+        assert!(symbols
+            .references
+            .iter()
+            .any(|reference| reference.name == "Index" && reference.line == 14));
+        assert_eq!(symbols.imports[0].path, "std::collections::BTreeMap");
+        assert!(!symbols.truncated);
+    }
+
+    #[test]
+    fn unit_extract_typescript_classes_interfaces_and_module_bindings() {
+        let source = r#"import { readFile } from "node:fs/promises";
+
+export interface Loader {
+  load(path: string): Promise<string>;
+}
+
+export class FileLoader implements Loader {
+  async load(path: string): Promise<string> {
+    return readFile(path, "utf8");
+  }
+}
+
+export const createLoader = () => new FileLoader();
+const MAX_RETRIES = 3;
+export type LoaderMap = Record<string, Loader>;
+"#;
+        let symbols = extract_file_symbols(CodeLanguage::TypeScript, source).expect("extract");
+
+        assert_eq!(definition(&symbols, "Loader").kind, SymbolKind::Interface);
+        assert_eq!(definition(&symbols, "FileLoader").kind, SymbolKind::Class);
+        let methods = symbols
+            .definitions
+            .iter()
+            .filter(|definition| definition.name == "load")
+            .map(|definition| (definition.kind, definition.container.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            methods,
+            vec![
+                (SymbolKind::Method, Some("Loader".to_string())),
+                (SymbolKind::Method, Some("FileLoader".to_string())),
+            ]
+        );
+        assert_eq!(
+            definition(&symbols, "createLoader").kind,
+            SymbolKind::Function
+        );
+        assert_eq!(
+            definition(&symbols, "MAX_RETRIES").kind,
+            SymbolKind::Constant
+        );
+        assert_eq!(definition(&symbols, "LoaderMap").kind, SymbolKind::Type);
+        assert_eq!(symbols.imports[0].path, "node:fs/promises");
+        assert!(symbols
+            .references
+            .iter()
+            .any(|reference| reference.name == "FileLoader" && reference.line == 13));
+    }
+
+    #[test]
+    fn unit_extract_python_classes_methods_and_imports() {
+        let source = r#"import os.path
+from typing import Optional as Opt
+
+DEFAULT_ROOT = "/srv"
+
+class Walker:
+    def walk(self, root):
+        def visit(entry):
+            return entry
+        return visit(os.path.join(root, DEFAULT_ROOT))
+"#;
+        let symbols = extract_file_symbols(CodeLanguage::Python, source).expect("extract");
+
+        assert_eq!(definition(&symbols, "Walker").kind, SymbolKind::Class);
+        let walk = definition(&symbols, "walk");
+        assert_eq!(walk.kind, SymbolKind::Method);
+        assert_eq!(walk.container.as_deref(), Some("Walker"));
+        let visit = definition(&symbols, "visit");
+        assert_eq!(visit.kind, SymbolKind::Function);
+        assert_eq!(visit.container.as_deref(), Some("walk"));
+        assert_eq!(
+            definition(&symbols, "DEFAULT_ROOT").kind,
+            SymbolKind::Constant
+        );
+        let imports = symbols
+            .imports
+            .iter()
+            .map(|import| import.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(imports, vec!["os.path", "typing"]);
+        assert!(symbols
+            .references
+            .iter()
+            .any(|reference| reference.name == "DEFAULT_ROOT" && reference.line == 10));
+    }
+
+    #[test]
+    fn unit_extract_go_types_methods_and_top_level_values() {
+        let source = r#"package store
+
+import (
+	"fmt"
+	"strings"
+)
+
+const Version = "1"
+
+type Store struct {
+	name string
+}
+
+type Reader interface {
+	Read() string
+}
+
+func (s *Store) Read() string {
+	local := strings.ToUpper(s.name)
+	return fmt.Sprint(local, Version)
+}
+
+func New() *Store { return &Store{} }
+"#;
+        let symbols = extract_file_symbols(CodeLanguage::Go, source).expect("extract");
+
+        assert_eq!(definition(&symbols, "Store").kind, SymbolKind::Struct);
+        assert_eq!(definition(&symbols, "Reader").kind, SymbolKind::Interface);
+        assert_eq!(definition(&symbols, "Version").kind, SymbolKind::Constant);
+        assert_eq!(definition(&symbols, "New").kind, SymbolKind::Function);
+        let read_containers = symbols
+            .definitions
+            .iter()
+            .filter(|definition| definition.name == "Read")
+            .map(|definition| definition.container.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            read_containers,
+            vec![Some("Reader".to_string()), Some("Store".to_string())]
+        );
+        assert!(!symbols
+            .definitions
+            .iter()
+            .any(|definition| definition.name == "local"));
+        let imports = symbols
+            .imports
+            .iter()
+            .map(|import| import.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(imports, vec!["fmt", "strings"]);
+    }
+
+    #[test]
+    fn regression_symbol_kind_labels_round_trip() {
+        for kind in [
+            SymbolKind::Function,
+            SymbolKind::Method,
+            SymbolKind::Struct,
+            SymbolKind::Enum,
+            SymbolKind::Trait,
+            SymbolKind::Interface,
+            SymbolKind::Class,
+            SymbolKind::Type,
+            SymbolKind::Constant,
+            SymbolKind::Variable,
+            SymbolKind::Module,
+            SymbolKind::Macro,
+        ] {
+            assert_eq!(SymbolKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(SymbolKind::parse("unknown"), None);
+    }
+}
@@ -0,0 +1,736 @@
+//! Incremental on-disk code index keyed by file content hash.
+//!
+//! Layout under the index directory:
+//! - `manifest.json` maps relative paths to their SHA-256, language, size and
+//!   modification time for the last refresh.
+//! - `objects/<sha256>-<language>.json` stores extracted symbols for one file
+//!   content, so unchanged or duplicated files are never re-parsed.
+//!
+//! Refresh trusts size + mtime first, then the content hash; objects no longer
+//! referenced by the manifest are garbage collected.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tau_core::{current_unix_timestamp_ms, write_text_atomic};
+
+use crate::{extract_file_symbols, CodeLanguage, FileSymbols, SymbolDefinition, SymbolKind};
+
+/// Schema version stored in the manifest and every object file.
+pub const CODE_INDEX_SCHEMA_VERSION: u32 = 1;
+const CODE_INDEX_MANIFEST_FILE_NAME: &str = "manifest.json";
+const CODE_INDEX_OBJECTS_DIR_NAME: &str = "objects";
+const MAX_CODE_INDEX_FILE_BYTES: u64 = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct CodeIndexManifest {
+    schema_version: u32,
+    generated_unix_ms: u64,
+    root: String,
+    files: BTreeMap<String, CodeIndexManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct CodeIndexManifestEntry {
+    sha256: String,
+    language: CodeLanguage,
+    bytes: u64,
+    modified_unix_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct CodeIndexObject {
+    schema_version: u32,
+    language: CodeLanguage,
+    symbols: FileSymbols,
+}
+
+/// Indexed symbols for one source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeIndexFile {
+    pub path: String,
+    pub language: CodeLanguage,
+    pub sha256: String,
+    pub symbols: FileSymbols,
+}
+
+/// In-memory view of the code index for one project root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeIndex {
+    pub root: PathBuf,
+    pub files: Vec<CodeIndexFile>,
+}
+
+/// Counters describing one [`refresh_code_index`] run.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CodeIndexRefreshReport {
+    pub root: String,
+    pub index_dir: String,
+    pub schema_version: u32,
+    pub generated_unix_ms: u64,
+    pub files_discovered: usize,
+    pub files_indexed: usize,
+    pub files_parsed: usize,
+    pub files_reused: usize,
+    pub files_removed: usize,
+    pub skipped_large: usize,
+    pub skipped_non_utf8: usize,
+    pub parse_failures: usize,
+    pub definitions: usize,
+    pub references: usize,
+    pub imports: usize,
+    pub recovered_from_corrupt_state: bool,
+}
+
+/// Refreshed index plus the report describing what changed.
+#[derive(Debug, Clone)]
+pub struct CodeIndexRefresh {
+    pub index: CodeIndex,
+    pub report: CodeIndexRefreshReport,
+}
+
+/// A definition match with the file it was found in.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct DefinitionMatch {
+    pub path: String,
+    pub language: CodeLanguage,
+    #[serde(flatten)]
+    pub definition: SymbolDefinition,
+}
+
+/// A reference match with the file it was found in.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReferenceMatch {
+    pub path: String,
+    pub name: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A ranked symbol search hit.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SymbolSearchMatch {
+    pub path: String,
+    pub language: CodeLanguage,
+    pub score: u32,
+    #[serde(flatten)]
+    pub definition: SymbolDefinition,
+}
+
+impl CodeIndex {
+    /// Returns the indexed file for a root-relative `/`-separated path.
+    pub fn file(&self, path: &str) -> Option<&CodeIndexFile> {
+        self.files
+            .binary_search_by(|file| file.path.as_str().cmp(path))
+            .ok()
+            .map(|position| &self.files[position])
+    }
+
+    /// Finds definitions named `symbol`.
+    ///
+    /// `Container::name` or `Container.name` additionally requires the
+    /// definition's enclosing impl/class/trait/module to match.
+    pub fn find_definitions(&self, symbol: &str, kind: Option<SymbolKind>) -> Vec<DefinitionMatch> {
+        let (container, name) = split_qualified_symbol(symbol);
+        let mut matches = Vec::new();
+        for file in &self.files {
+            for definition in &file.symbols.definitions {
+                if definition.name != name
+                    || kind.is_some_and(|kind| definition.kind != kind)
+                    || container
+                        .is_some_and(|container| definition.container.as_deref() != Some(container))
+                {
+                    continue;
+                }
+                matches.push(DefinitionMatch {
+                    path: file.path.clone(),
+                    language: file.language,
+                    definition: definition.clone(),
+                });
+            }
+        }
+        matches
+    }
+
+    /// Finds identifier occurrences of `symbol` (the last segment when qualified).
+    pub fn find_references(&self, symbol: &str) -> Vec<ReferenceMatch> {
+        let (_, name) = split_qualified_symbol(symbol);
+        let mut matches = Vec::new();
+        for file in &self.files {
+            for reference in &file.symbols.references {
+                if reference.name == name {
+                    matches.push(ReferenceMatch {
+                        path: file.path.clone(),
+                        name: reference.name.clone(),
+                        line: reference.line,
+                        column: reference.column,
+                    });
+                }
+            }
+        }
+        matches
+    }
+
+    /// Ranks definitions against `query` by exact, prefix, substring and
+    /// subsequence matches (case-insensitive), returning at most `limit` hits.
+    pub fn search_symbols(
+        &self,
+        query: &str,
+        kind: Option<SymbolKind>,
+        limit: usize,
+    ) -> Vec<SymbolSearchMatch> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut matches = Vec::new();
+        for file in &self.files {
+            for definition in &file.symbols.definitions {
+                if kind.is_some_and(|kind| definition.kind != kind) {
+                    continue;
+                }
+                let Some(score) = score_symbol_name(&definition.name, query) else {
+                    continue;
+                };
+                matches.push(SymbolSearchMatch {
+                    path: file.path.clone(),
+                    language: file.language,
+                    score,
+                    definition: definition.clone(),
+                });
+            }
+        }
+        matches.sort_by(|left, right| {
+            right
+                .score
+                .cmp(&left.score)
+                .then_with(|| left.definition.name.len().cmp(&right.definition.name.len()))
+                .then_with(|| left.path.cmp(&right.path))
+                .then_with(|| left.definition.line.cmp(&right.definition.line))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+/// Splits `Container::name` / `Container.name` into its container and name parts.
+pub fn split_qualified_symbol(symbol: &str) -> (Option<&str>, &str) {
+    let symbol = symbol.trim();
+    let split = symbol
+        .rsplit_once("::")
+        .or_else(|| symbol.rsplit_once('.'))
+        .filter(|(container, name)| !container.is_empty() && !name.is_empty());
+    match split {
+        Some((container, name)) => (
+            Some(container.rsplit("::").next().unwrap_or(container)),
+            name,
+        ),
+        None => (None, symbol),
+    }
+}
+
+fn score_symbol_name(name: &str, query: &str) -> Option<u32> {
+    if name == query {
+        return Some(100);
+    }
+    let name_lower = name.to_ascii_lowercase();
+    let query_lower = query.to_ascii_lowercase();
+    if name_lower == query_lower {
+        return Some(90);
+    }
+    if name.starts_with(query) {
+        return Some(75);
+    }
+    if name_lower.starts_with(&query_lower) {
+        return Some(70);
+    }
+    if name_lower.contains(&query_lower) {
+        return Some(50);
+    }
+    let mut remaining = query_lower.chars().peekable();
+    for character in name_lower.chars() {
+        if remaining.peek() == Some(&character) {
+            remaining.next();
+        }
+    }
+    if remaining.peek().is_none() {
+        return Some(20);
+    }
+    None
+}
+
+/// Loads the index written by the last [`refresh_code_index`] without touching sources.
+pub fn load_code_index(index_dir: &Path) -> Result<CodeIndex> {
+    let manifest = load_code_index_manifest(&code_index_manifest_path(index_dir))?;
+    let mut files = Vec::with_capacity(manifest.files.len());
+    for (path, entry) in manifest.files {
+        let object_path = code_index_object_path(index_dir, &entry.sha256, entry.language);
+        let object = load_code_index_object(&object_path)?;
+        files.push(CodeIndexFile {
+            path,
+            language: entry.language,
+            sha256: entry.sha256,
+            symbols: object.symbols,
+        });
+    }
+    Ok(CodeIndex {
+        root: PathBuf::from(manifest.root),
+        files,
+    })
+}
+
+/// Re-indexes `root` into `index_dir`, parsing only files whose content changed.
+pub fn refresh_code_index(root: &Path, index_dir: &Path) -> Result<CodeIndexRefresh> {
+    let objects_dir = index_dir.join(CODE_INDEX_OBJECTS_DIR_NAME);
+    fs::create_dir_all(&objects_dir).with_context(|| {
+        format!(
+            "failed creating code index directory '{}'",
+            objects_dir.display()
+        )
+    })?;
+    let manifest_path = code_index_manifest_path(index_dir);
+    let root_label = root.display().to_string();
+
+    let mut recovered_from_corrupt_state = false;
+    let previous_files = if manifest_path.exists() {
+        match load_code_index_manifest(&manifest_path) {
+            Ok(manifest) if manifest.root == root_label => manifest.files,
+            Ok(_) => BTreeMap::new(),
+            Err(_) => {
+                recovered_from_corrupt_state = true;
+                BTreeMap::new()
+            }
+        }
+    } else {
+        BTreeMap::new()
+    };
+
+    let candidate_files = collect_code_index_candidate_files(root)?;
+    let files_discovered = candidate_files.len();
+    let mut files_parsed = 0usize;
+    let mut files_reused = 0usize;
+    let mut skipped_large = 0usize;
+    let mut skipped_non_utf8 = 0usize;
+    let mut parse_failures = 0usize;
+    let mut manifest_files = BTreeMap::new();
+    let mut indexed_files = Vec::new();
+
+    for (file_path, language) in candidate_files {
+        let relative_path = normalize_relative_path(root, &file_path)?;
+        let metadata = fs::metadata(&file_path)
+            .with_context(|| format!("failed to inspect '{}'", file_path.display()))?;
+        if metadata.len() > MAX_CODE_INDEX_FILE_BYTES {
+            skipped_large = skipped_large.saturating_add(1);
+            continue;
+        }
+        let modified_unix_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+            .unwrap_or_default();
+
+        if let Some(previous) = previous_files.get(&relative_path) {
+            if previous.language == language
+                && previous.bytes == metadata.len()
+                && previous.modified_unix_ms == modified_unix_ms
+            {
+                let object_path = code_index_object_path(index_dir, &previous.sha256, language);
+                if let Ok(object) = load_code_index_object(&object_path) {
+                    files_reused = files_reused.saturating_add(1);
+                    manifest_files.insert(relative_path.clone(), previous.clone());
+                    indexed_files.push(CodeIndexFile {
+                        path: relative_path,
+                        language,
+                        sha256: previous.sha256.clone(),
+                        symbols: object.symbols,
+                    });
+                    continue;
+                }
+            }
+        }
+
+        let bytes = fs::read(&file_path)
+            .with_context(|| format!("failed reading '{}'", file_path.display()))?;
+        let Ok(text) = std::str::from_utf8(&bytes) else {
+            skipped_non_utf8 = skipped_non_utf8.saturating_add(1);
+            continue;
+        };
+        let sha256 = format!("{:x}", Sha256::digest(&bytes));
+        let object_path = code_index_object_path(index_dir, &sha256, language);
+        let symbols = match load_code_index_object(&object_path) {
+            Ok(object) => {
+                files_reused = files_reused.saturating_add(1);
+                object.symbols
+            }
+            Err(_) => {
+                let Ok(symbols) = extract_file_symbols(language, text) else {
+                    parse_failures = parse_failures.saturating_add(1);
+                    continue;
+                };
+                let object = CodeIndexObject {
+                    schema_version: CODE_INDEX_SCHEMA_VERSION,
+                    language,
+                    symbols,
+                };
+                write_text_atomic(
+                    &object_path,
+                    &serde_json::to_string(&object)
+                        .context("failed to serialize code index object")?,
+                )
+                .with_context(|| format!("failed writing '{}'", object_path.display()))?;
+                files_parsed = files_parsed.saturating_add(1);
+                object.symbols
+            }
+        };
+
+        manifest_files.insert(
+            relative_path.clone(),
+            CodeIndexManifestEntry {
+                sha256: sha256.clone(),
+                language,
+                bytes: metadata.len(),
+                modified_unix_ms,
+            },
+        );
+        indexed_files.push(CodeIndexFile {
+            path: relative_path,
+            language,
+            sha256,
+            symbols,
+        });
+    }
+
+    let files_removed = previous_files
+        .keys()
+        .filter(|path| !manifest_files.contains_key(*path))
+        .count();
+    let generated_unix_ms = current_unix_timestamp_ms();
+    let manifest = CodeIndexManifest {
+        schema_version: CODE_INDEX_SCHEMA_VERSION,
+        generated_unix_ms,
+        root: root_label.clone(),
+        files: manifest_files,
+    };
+    write_text_atomic(
+        &manifest_path,
+        &serde_json::to_string_pretty(&manifest)
+            .context("failed to serialize code index manifest")?,
+    )
+    .with_context(|| format!("failed writing '{}'", manifest_path.display()))?;
+    remove_unreferenced_code_index_objects(index_dir, &manifest)?;
+
+    let report = CodeIndexRefreshReport {
+        root: root_label,
+        index_dir: index_dir.display().to_string(),
+        schema_version: CODE_INDEX_SCHEMA_VERSION,
+        generated_unix_ms,
+        files_discovered,
+        files_indexed: indexed_files.len(),
+        files_parsed,
+        files_reused,
+        files_removed,
+        skipped_large,
+        skipped_non_utf8,
+        parse_failures,
+        definitions: indexed_files
+            .iter()
+            .map(|file| file.symbols.definitions.len())
+            .sum(),
+        references: indexed_files
+            .iter()
+            .map(|file| file.symbols.references.len())
+            .sum(),
+        imports: indexed_files
+            .iter()
+            .map(|file| file.symbols.imports.len())
+            .sum(),
+        recovered_from_corrupt_state,
+    };
+    Ok(CodeIndexRefresh {
+        index: CodeIndex {
+            root: root.to_path_buf(),
+            files: indexed_files,
+        },
+        report,
+    })
+}
+
+fn code_index_manifest_path(index_dir: &Path) -> PathBuf {
+    index_dir.join(CODE_INDEX_MANIFEST_FILE_NAME)
+}
+
+fn code_index_object_path(index_dir: &Path, sha256: &str, language: CodeLanguage) -> PathBuf {
+    index_dir
+        .join(CODE_INDEX_OBJECTS_DIR_NAME)
+        .join(format!("{sha256}-{}.json", language.as_str()))
+}
+
+fn load_code_index_manifest(path: &Path) -> Result<CodeIndexManifest> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("failed reading '{}'", path.display()))?;
+    let manifest: CodeIndexManifest = serde_json::from_str(&raw)
+        .with_context(|| format!("failed parsing '{}'", path.display()))?;
+    if manifest.schema_version != CODE_INDEX_SCHEMA_VERSION {
+        bail!(
+            "code index schema mismatch in '{}': expected {}, got {}",
+            path.display(),
+            CODE_INDEX_SCHEMA_VERSION,
+            manifest.schema_version
+        );
+    }
+    Ok(manifest)
+}
+
+fn load_code_index_object(path: &Path) -> Result<CodeIndexObject> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("failed reading '{}'", path.display()))?;
+    let object: CodeIndexObject = serde_json::from_str(&raw)
+        .with_context(|| format!("failed parsing '{}'", path.display()))?;
+    if object.schema_version != CODE_INDEX_SCHEMA_VERSION {
+        bail!("code index object schema mismatch in '{}'", path.display());
+    }
+    Ok(object)
+}
+
+fn remove_unreferenced_code_index_objects(
+    index_dir: &Path,
+    manifest: &CodeIndexManifest,
+) -> Result<()> {
+    let referenced = manifest
+        .files
+        .values()
+        .map(|entry| format!("{}-{}.json", entry.sha256, entry.language.as_str()))
+        .collect::<BTreeSet<_>>();
+    let objects_dir = index_dir.join(CODE_INDEX_OBJECTS_DIR_NAME);
+    for entry in fs::read_dir(&objects_dir)
+        .with_context(|| format!("failed to read directory '{}'", objects_dir.display()))?
+    {
+        let entry = entry
+            .with_context(|| format!("failed to list entries for '{}'", objects_dir.display()))?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !referenced.contains(&file_name) {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+    Ok(())
+}
+
+fn should_skip_directory(name: &str) -> bool {
+    name.starts_with('.')
+        || matches!(
+            name,
+            "target" | "node_modules" | "dist" | "build" | "venv" | "__pycache__"
+        )
+}
+
+fn collect_code_index_candidate_files(root: &Path) -> Result<Vec<(PathBuf, CodeLanguage)>> {
+    let mut files = Vec::new();
+    collect_code_index_candidate_files_recursive(root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_code_index_candidate_files_recursive(
+    dir: &Path,
+    files: &mut Vec<(PathBuf, CodeLanguage)>,
+) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to read directory '{}'", dir.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("failed to list directory entries for '{}'", dir.display()))?;
+
+    for entry in entries {
+        let path = entry.path();
+        let file_type = entry
+            .file_type()
+            .with_context(|| format!("failed to inspect '{}'", path.display()))?;
+        if file_type.is_dir() {
+            if should_skip_directory(&entry.file_name().to_string_lossy()) {
+                continue;
+            }
+            collect_code_index_candidate_files_recursive(&path, files)?;
+            continue;
+        }
+        if !file_type.is_file() {
+            continue;
+        }
+        if let Some(language) = CodeLanguage::from_path(&path) {
+            files.push((path, language));
+        }
+    }
+    Ok(())
+}
+
+fn normalize_relative_path(root: &Path, path: &Path) -> Result<String> {
+    let relative = path.strip_prefix(root).with_context(|| {
+        format!(
+            "failed to compute path relative to root '{}'",
+            root.display()
+        )
+    })?;
+    Ok(relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_fixture(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().expect("parent")).expect("create parent");
+        fs::write(path, content).expect("write fixture");
+    }
+
+    #[test]
+    fn functional_refresh_indexes_supported_languages_and_skips_ignored_dirs() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().join("workspace");
+        let index_dir = temp.path().join("index");
+        write_fixture(&root, "src/lib.rs", "pub fn parse_config() {}\n");
+        write_fixture(&root, "web/app.ts", "export function renderApp() {}\n");
+        write_fixture(&root, "tools/run.py", "def run_tool():\n    pass\n");
+        write_fixture(&root, "cmd/main.go", "package main\n\nfunc main() {}\n");
+        write_fixture(&root, "README.md", "# not indexed\n");
+        write_fixture(&root, "target/debug/gen.rs", "pub fn generated() {}\n");
+        write_fixture(&root, "node_modules/pkg/index.ts", "export const x = 1;\n");
+
+        let refresh = refresh_code_index(&root, &index_dir).expect("refresh");
+        assert_eq!(refresh.report.files_discovered, 4);
+        assert_eq!(refresh.report.files_indexed, 4);
+        assert_eq!(refresh.report.files_parsed, 4);
+        assert_eq!(refresh.report.files_reused, 0);
+        let paths = refresh
+            .index
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec!["cmd/main.go", "src/lib.rs", "tools/run.py", "web/app.ts"]
+        );
+
+        let loaded = load_code_index(&index_dir).expect("load");
+        assert_eq!(loaded, refresh.index);
+        assert_eq!(
+            loaded.find_definitions("renderApp", None)[0].path,
+            "web/app.ts"
+        );
+    }
+
+    #[test]
+    fn integration_refresh_reuses_unchanged_files_and_collects_stale_objects() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().join("workspace");
+        let index_dir = temp.path().join("index");
+        write_fixture(&root, "src/alpha.rs", "pub fn alpha() {}\n");
+        write_fixture(&root, "src/beta.rs", "pub fn beta() { alpha(); }\n");
+        write_fixture(&root, "src/gamma.rs", "pub fn gamma() {}\n");
+        refresh_code_index(&root, &index_dir).expect("first refresh");
+
+        write_fixture(
+            &root,
+            "src/beta.rs",
+            "pub fn beta_v2() { alpha(); alpha(); }\n",
+        );
+        fs::remove_file(root.join("src/gamma.rs")).expect("remove gamma");
+        let second = refresh_code_index(&root, &index_dir).expect("second refresh");
+        assert_eq!(second.report.files_indexed, 2);
+        assert_eq!(second.report.files_parsed, 1);
+        assert_eq!(second.report.files_reused, 1);
+        assert_eq!(second.report.files_removed, 1);
+
+        let object_count = fs::read_dir(index_dir.join(CODE_INDEX_OBJECTS_DIR_NAME))
+            .expect("objects dir")
+            .count();
+        assert_eq!(object_count, 2);
+
+        let references = second.index.find_references("alpha");
+        assert_eq!(references.len(), 2);
+        assert!(references
+            .iter()
+            .all(|reference| reference.path == "src/beta.rs"));
+        assert!(second.index.find_definitions("beta", None).is_empty());
+        assert_eq!(second.index.find_definitions("beta_v2", None).len(), 1);
+    }
+
+    #[test]
+    fn regression_refresh_recovers_from_corrupt_manifest() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().join("workspace");
+        let index_dir = temp.path().join("index");
+        write_fixture(&root, "src/lib.rs", "pub fn alpha() {}\n");
+        fs::create_dir_all(&index_dir).expect("create index dir");
+        fs::write(code_index_manifest_path(&index_dir), "{invalid").expect("write corrupt");
+
+        assert!(load_code_index(&index_dir).is_err());
+        let refresh = refresh_code_index(&root, &index_dir).expect("refresh should recover");
+        assert!(refresh.report.recovered_from_corrupt_state);
+        assert_eq!(refresh.report.files_indexed, 1);
+    }
+
+    #[test]
+    fn unit_qualified_definitions_and_symbol_search_ranking() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().join("workspace");
+        let index_dir = temp.path().join("index");
+        write_fixture(
+            &root,
+            "src/lib.rs",
+            "pub struct Parser;\nimpl Parser {\n    pub fn parse(&self) {}\n}\npub fn parse() {}\npub fn parse_all() {}\npub fn reparse_tree() {}\n",
+        );
+        let index = refresh_code_index(&root, &index_dir)
+            .expect("refresh")
+            .index;
+
+        assert_eq!(index.find_definitions("parse", None).len(), 2);
+        let qualified = index.find_definitions("Parser::parse", None);
+        assert_eq!(qualified.len(), 1);
+        assert_eq!(qualified[0].definition.kind, SymbolKind::Method);
+        assert_eq!(
+            index
+                .find_definitions("parse", Some(SymbolKind::Function))
+                .len(),
+            1
+        );
+
+        let names = index
+            .search_symbols("parse", None, 10)
+            .into_iter()
+            .map(|hit| (hit.definition.name, hit.score))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("parse".to_string(), 100),
+                ("parse".to_string(), 100),
+                ("parse_all".to_string(), 75),
+                ("Parser".to_string(), 70),
+                ("reparse_tree".to_string(), 50),
+            ]
+        );
+        assert_eq!(
+            index.search_symbols("rpt", None, 10)[0].definition.name,
+            "reparse_tree"
+        );
+        assert_eq!(split_qualified_symbol("a::b::c"), (Some("b"), "c"));
+        assert_eq!(
+            split_qualified_symbol("Widget.render"),
+            (Some("Widget"), "render")
+        );
+        assert_eq!(split_qualified_symbol("plain"), (None, "plain"));
+    }
+}
@@ -0,0 +1,14 @@
+//! Tree-sitter code-intelligence indexing for Rust, TypeScript, Python and Go.
+mod extract;
+mod index;
+mod language;
+
+pub use extract::{
+    extract_file_symbols, FileSymbols, SymbolDefinition, SymbolImport, SymbolKind, SymbolReference,
+};
+pub use index::{
+    load_code_index, refresh_code_index, split_qualified_symbol, CodeIndex, CodeIndexFile,
+    CodeIndexRefresh, CodeIndexRefreshReport, DefinitionMatch, ReferenceMatch, SymbolSearchMatch,
+    CODE_INDEX_SCHEMA_VERSION,
+};
+pub use language::CodeLanguage;
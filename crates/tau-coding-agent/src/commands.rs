@@ -73,6 +73,7 @@ use crate::macro_profile_commands::{
     default_macro_config_path, default_profile_store_path, execute_macro_command,
     execute_profile_command,
 };
+use crate::mcp_client::execute_mcp_client_command;
 use crate::qa_loop_commands::execute_qa_loop_cli_command;
 use crate::release_channel_commands::{
     default_release_channel_path, execute_release_channel_command,
@@ -198,6 +199,9 @@ pub(crate) fn handle_command(
         openai_auth_mode: ProviderAuthMethod::ApiKey,
         anthropic_auth_mode: ProviderAuthMethod::ApiKey,
         google_auth_mode: ProviderAuthMethod::ApiKey,
+        bedrock_auth_mode: ProviderAuthMethod::ApiKey,
+        vertex_auth_mode: ProviderAuthMethod::Adc,
+        vertex_service_account_file: None,
         provider_subscription_strict: false,
         openai_codex_backend: true,
         openai_codex_cli: "codex".to_string(),
@@ -220,6 +224,7 @@ pub(crate) fn handle_command(
             auth_command_config: &auth_command_config,
             model_catalog: &model_catalog,
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
 }
@@ -239,6 +244,7 @@ pub(crate) fn handle_command_with_session_import_mode(
         auth_command_config,
         model_catalog,
         extension_commands,
+        mcp_client_sessions,
     } = command_context;
 
     let skills_dir = skills_command_config.skills_dir.as_path();
@@ -708,6 +714,7 @@ pub(crate) fn handle_command_with_session_import_mode(
                     auth_command_config,
                     model_catalog,
                     extension_commands,
+                    mcp_client_sessions,
                 }
             )
         );
@@ -760,6 +767,14 @@ pub(crate) fn handle_command_with_session_import_mode(
         return Ok(CommandAction::Continue);
     }
 
+    if command_name == "/mcp" {
+        println!(
+            "{}",
+            execute_mcp_client_command(command_args, mcp_client_sessions, agent)
+        );
+        return Ok(CommandAction::Continue);
+    }
+
     if command_name == "/pair" {
         println!("{}", execute_pair_command(command_args, "local"));
         return Ok(CommandAction::Continue);
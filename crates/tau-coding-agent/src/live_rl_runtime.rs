@@ -1519,6 +1519,8 @@ impl PromptEvaluator for LiveApoPromptEvaluator {
             max_tokens: Some(64),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let llm_score = self
@@ -576,6 +576,19 @@ async fn dispatch_interactive_turn(
         return Ok(InteractiveLoopControl::Continue);
     }
 
+    if let Some(mcp_client_sessions) = config.command_context.mcp_client_sessions {
+        for diagnostic in mcp_client_sessions.apply_pending_tool_changes(agent) {
+            eprintln!(
+                "mcp client diagnostic: server={} phase={} status={} reason_code={} detail={}",
+                diagnostic.server,
+                diagnostic.phase,
+                diagnostic.status,
+                diagnostic.reason_code,
+                diagnostic.detail
+            );
+        }
+    }
+
     if trimmed.starts_with('/') {
         if handle_command_with_session_import_mode(
             trimmed,
@@ -1768,6 +1781,9 @@ mod tests {
                     openai_auth_mode: ProviderAuthMethod::ApiKey,
                     anthropic_auth_mode: ProviderAuthMethod::ApiKey,
                     google_auth_mode: ProviderAuthMethod::ApiKey,
+                    bedrock_auth_mode: ProviderAuthMethod::ApiKey,
+                    vertex_auth_mode: ProviderAuthMethod::Adc,
+                    vertex_service_account_file: None,
                     provider_subscription_strict: false,
                     openai_codex_backend: false,
                     openai_codex_cli: "codex".to_string(),
@@ -1811,6 +1827,7 @@ mod tests {
                     auth_command_config: &self.auth_command_config,
                     model_catalog: &self.model_catalog,
                     extension_commands: &[],
+                    mcp_client_sessions: None,
                 },
             }
         }
@@ -5,6 +5,7 @@
 
 #[allow(deprecated)]
 use crate::extension_manifest::ExtensionRegisteredCommand;
+use crate::mcp_client::McpClientSessionManager;
 pub(crate) use tau_onboarding::startup_config::ProfileDefaults;
 #[cfg(test)]
 pub(crate) use tau_onboarding::startup_config::{
@@ -31,4 +32,5 @@ pub(crate) struct CommandExecutionContext<'a> {
     pub(crate) auth_command_config: &'a AuthCommandConfig,
     pub(crate) model_catalog: &'a ModelCatalog,
     pub(crate) extension_commands: &'a [ExtensionRegisteredCommand],
+    pub(crate) mcp_client_sessions: Option<&'a McpClientSessionManager>,
 }
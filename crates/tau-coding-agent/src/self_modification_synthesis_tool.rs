@@ -268,6 +268,8 @@ impl AgentTool for SelfModificationSynthesizeTool {
             max_tokens: Some(2048),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         };
 
         let response = match self.client.complete(request).await {
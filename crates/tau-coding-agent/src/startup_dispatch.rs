@@ -3,6 +3,8 @@
 //! Builds resolved startup context (models, policy, prompt, runtime defaults)
 //! and routes execution into local or transport runtime paths.
 
+use std::sync::Arc;
+
 use anyhow::Result;
 use tau_ai::ModelRef;
 use tau_cli::render_shell_completion;
@@ -13,7 +15,11 @@ use tau_onboarding::startup_dispatch::{
 };
 use tau_onboarding::startup_model_resolution::{resolve_startup_models, StartupModelResolution};
 use tau_onboarding::startup_skills_bootstrap::run_startup_skills_bootstrap;
-use tau_provider::build_client_with_fallbacks;
+use tau_provider::{build_client_with_fallbacks, build_model_router_client_if_configured};
+use tau_runtime::{
+    install_otlp_trace_exporter, parse_otlp_export_headers, OtlpHttpSpanExporter,
+    OtlpTraceExporterConfig,
+};
 use tau_skills::execute_package_activate_on_startup;
 
 use crate::runtime_types::RenderOptions;
@@ -34,9 +40,20 @@ pub(crate) async fn run_cli(cli: Cli) -> Result<()> {
         return Ok(());
     }
     validate_removed_contract_runner_flags_cli(&cli)?;
+    let trace_exporter = install_otel_trace_exporter_if_configured(&cli)?;
 
+    let result = run_startup_runtime(&cli).await;
+    if let Some(exporter) = trace_exporter {
+        if let Err(error) = exporter.flush().await {
+            eprintln!("warning: failed to flush OpenTelemetry spans: {error:#}");
+        }
+    }
+    result
+}
+
+async fn run_startup_runtime(cli: &Cli) -> Result<()> {
     execute_startup_runtime_from_cli_with_modes(ExecuteStartupRuntimeFromCliWithModesRequest {
-        cli: &cli,
+        cli,
         resolve_models: Box::new(|cli| -> Result<(ModelRef, Vec<ModelRef>)> {
             let StartupModelResolution {
                 model_ref,
@@ -51,8 +68,9 @@ pub(crate) async fn run_cli(cli: Cli) -> Result<()> {
             },
         ),
         build_client_with_fallbacks: Box::new(
-            |cli, model_ref, fallback_model_refs: &Vec<ModelRef>| {
-                build_client_with_fallbacks(cli, model_ref, fallback_model_refs)
+            |cli, model_ref, fallback_model_refs: &Vec<ModelRef>, model_catalog| {
+                let client = build_client_with_fallbacks(cli, model_ref, fallback_model_refs)?;
+                build_model_router_client_if_configured(cli, model_ref, client, model_catalog)
             },
         ),
         run_skills_bootstrap: Box::new(|cli| Box::pin(run_startup_skills_bootstrap(cli))),
@@ -129,3 +147,15 @@ pub(crate) async fn run_cli(cli: Cli) -> Result<()> {
     })
     .await
 }
+
+fn install_otel_trace_exporter_if_configured(
+    cli: &Cli,
+) -> Result<Option<Arc<OtlpHttpSpanExporter>>> {
+    let Some(endpoint) = cli.otel_traces_endpoint.as_deref() else {
+        return Ok(None);
+    };
+    let mut config = OtlpTraceExporterConfig::new(endpoint);
+    config.headers = parse_otlp_export_headers(&cli.otel_traces_header)?;
+    config.service_name = cli.otel_service_name.clone();
+    install_otlp_trace_exporter(config).map(Some)
+}
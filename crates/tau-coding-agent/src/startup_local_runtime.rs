@@ -3,13 +3,13 @@
 //! This module builds local runtime dependencies and entry-mode execution paths
 //! after preflight/model/policy resolution completes.
 
-use std::{path::Path, sync::Arc};
+use std::{collections::HashMap, path::Path, sync::Arc};
 
 use anyhow::{Context, Result};
 use serde_json::Value;
 #[cfg(test)]
 use tau_agent_core::Agent;
-use tau_agent_core::{AgentConfig, AgentEvent, SafetyMode};
+use tau_agent_core::{AgentConfig, AgentEvent, ModelCostRates, SafetyMode};
 use tau_ai::{LlmClient, ModelRef};
 use tau_cli::{Cli, CliPromptSanitizerMode};
 use tau_runtime::start_runtime_heartbeat_scheduler;
@@ -25,7 +25,9 @@ use crate::extension_manifest::{
     ExtensionRuntimeRegistrationSummary,
 };
 use crate::live_rl_runtime::LiveRlRuntimeBridge;
-use crate::mcp_client::register_mcp_client_tools;
+use crate::mcp_client::{
+    start_mcp_client_sessions, McpClientSamplingBackend, McpClientSessionStartup,
+};
 use crate::model_catalog::ModelCatalog;
 use crate::multi_agent_router::load_multi_agent_route_table;
 use crate::observability_loggers::{PromptTelemetryLogger, ToolAuditLogger};
@@ -48,6 +50,7 @@ use tau_onboarding::startup_local_runtime::{
     register_runtime_event_reporter_if_configured as register_onboarding_runtime_event_reporter_if_configured,
     register_runtime_extension_pipeline as register_onboarding_runtime_extension_pipeline,
     register_runtime_observability_if_configured as register_onboarding_runtime_observability_if_configured,
+    resolve_local_runtime_reasoning_config,
     resolve_local_runtime_startup_from_cli as resolve_onboarding_local_runtime_startup_from_cli,
     resolve_session_runtime_from_cli as resolve_onboarding_session_runtime_from_cli,
     LocalRuntimeAgentSettings, LocalRuntimeCommandDefaults, LocalRuntimeEntryDispatch,
@@ -81,6 +84,28 @@ fn resolve_safety_mode(mode: CliPromptSanitizerMode) -> SafetyMode {
     }
 }
 
+/// Catalog rates of every route the client may send to, keyed by `provider/model`.
+fn resolve_route_cost_rates(
+    model_catalog: &ModelCatalog,
+    model_ref: &ModelRef,
+    fallback_model_refs: &[ModelRef],
+) -> HashMap<String, ModelCostRates> {
+    std::iter::once(model_ref)
+        .chain(fallback_model_refs)
+        .filter_map(|route| {
+            let entry = model_catalog.find_model_ref(route)?;
+            Some((
+                format!("{}/{}", route.provider, route.model),
+                ModelCostRates {
+                    input_cost_per_million: entry.input_cost_per_million,
+                    cached_input_cost_per_million: entry.cached_input_cost_per_million,
+                    output_cost_per_million: entry.output_cost_per_million,
+                },
+            ))
+        })
+        .collect()
+}
+
 #[allow(deprecated)]
 pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<()> {
     let LocalRuntimeConfig {
@@ -116,10 +141,12 @@ pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<
         LocalRuntimeAgentSettings {
             max_turns: cli.max_turns,
             max_tokens: model_max_output_tokens,
+            reasoning: resolve_local_runtime_reasoning_config(cli),
             max_parallel_tool_calls: cli.agent_max_parallel_tool_calls,
             max_context_messages: cli.agent_max_context_messages,
             max_estimated_input_tokens,
             max_estimated_total_tokens,
+            tokenizer: model_catalog_entry.map(|entry| entry.tokenizer_family()),
             context_compaction_warn_threshold_percent: profile_defaults_for_agent
                 .policy
                 .context_compaction_warn_threshold_percent,
@@ -149,6 +176,11 @@ pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<
                 .and_then(|entry| entry.cached_input_cost_per_million),
             model_output_cost_per_million: model_catalog_entry
                 .and_then(|entry| entry.output_cost_per_million),
+            route_cost_rates: resolve_route_cost_rates(
+                model_catalog,
+                model_ref,
+                fallback_model_refs,
+            ),
             cost_budget_usd: cli.agent_cost_budget_usd,
             cost_alert_thresholds_percent: cli.agent_cost_alert_threshold_percent.clone(),
             prompt_sanitizer_enabled: cli.prompt_sanitizer_enabled,
@@ -202,6 +234,10 @@ pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<
             |error: &str| eprintln!("otel export logger error: {error}"),
         )?;
     }
+    let mcp_sampling_backend = McpClientSamplingBackend {
+        client: client.clone(),
+        model: model_ref.model.clone(),
+    };
     if let Some(snapshot) = LiveRlRuntimeBridge::register_if_enabled(
         &mut agent,
         cli.prompt_optimization_store_sqlite.as_path(),
@@ -284,7 +320,10 @@ pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<
         |diagnostic| eprintln!("{diagnostic}"),
         |root, hook, payload| dispatch_extension_runtime_hook(root, hook, payload).diagnostics,
     );
-    let mcp_registration = register_mcp_client_tools(&mut agent, cli)?;
+    let McpClientSessionStartup {
+        report: mcp_registration,
+        sessions: mcp_client_sessions,
+    } = start_mcp_client_sessions(&mut agent, cli, Some(mcp_sampling_backend))?;
     if cli.mcp_client {
         eprintln!(
             "mcp client registration: config={} servers={} discovered_tools={} registered_tools={}",
@@ -336,6 +375,7 @@ pub(crate) async fn run_local_runtime(config: LocalRuntimeConfig<'_>) -> Result<
         auth_command_config: &auth_command_config,
         model_catalog,
         extension_commands: &extension_runtime_registrations.registered_commands,
+        mcp_client_sessions: mcp_client_sessions.as_ref(),
     };
     let orchestrator_worker_skill_prompt = {
         let skill_catalog_dirs = resolve_prompt_composition_skill_dirs(cli, skills_dir);
@@ -9,14 +9,19 @@ use anyhow::Result;
 use async_trait::async_trait;
 use tau_ai::{LlmClient, ModelRef};
 use tau_cli::Cli;
+use tau_discord_runtime::{
+    run_discord_bridge, DiscordBridgeRuntimeConfig, DiscordRuntimeBootstrapConfig,
+};
 use tau_github_issues_runtime::{run_github_issues_bridge, GithubIssuesBridgeRuntimeConfig};
 use tau_onboarding::startup_config::build_auth_command_config;
 use tau_onboarding::startup_transport_modes::{
     build_gateway_openresponses_server_config,
     build_transport_doctor_config as build_onboarding_transport_doctor_config,
+    resolve_discord_bridge_token_from_cli as resolve_onboarding_discord_bridge_token_from_cli,
     resolve_github_issues_bridge_repo_and_token_from_cli as resolve_onboarding_github_issues_bridge_repo_and_token_from_cli,
     resolve_slack_bridge_tokens_from_cli as resolve_onboarding_slack_bridge_tokens_from_cli,
     run_browser_automation_live_runner_if_requested, run_deployment_contract_runner_if_requested,
+    run_discord_bridge_with_runtime_defaults_if_requested as run_onboarding_discord_bridge_with_runtime_defaults_if_requested,
     run_events_runner_with_runtime_defaults_if_requested as run_onboarding_events_runner_with_runtime_defaults_if_requested,
     run_gateway_contract_runner_if_requested,
     run_github_issues_bridge_with_runtime_defaults_if_requested as run_onboarding_github_issues_bridge_with_runtime_defaults_if_requested,
@@ -111,6 +116,7 @@ impl TransportRuntimeExecutor for CodingAgentTransportRuntimeExecutor<'_> {
                     required_issue_numbers: config.required_issue_numbers,
                     include_issue_body: config.include_issue_body,
                     include_edited_comments: config.include_edited_comments,
+                    pull_request_review: config.pull_request_review,
                     processed_event_cap: config.processed_event_cap,
                     retry_max_attempts: config.retry_max_attempts,
                     retry_base_delay_ms: config.retry_base_delay_ms,
@@ -183,6 +189,58 @@ impl TransportRuntimeExecutor for CodingAgentTransportRuntimeExecutor<'_> {
         Ok(())
     }
 
+    async fn run_discord_bridge(&self) -> Result<()> {
+        run_onboarding_discord_bridge_with_runtime_defaults_if_requested(
+            self.cli,
+            self.model_ref,
+            self.system_prompt,
+            || {
+                resolve_onboarding_discord_bridge_token_from_cli(
+                    self.cli,
+                    |direct_secret, secret_id, secret_id_flag| {
+                        resolve_secret_from_cli_or_store_id(
+                            self.cli,
+                            direct_secret,
+                            secret_id,
+                            secret_id_flag,
+                        )
+                    },
+                )
+            },
+            |config, runtime_defaults| async move {
+                run_discord_bridge(DiscordBridgeRuntimeConfig {
+                    client: self.client.clone(),
+                    model: runtime_defaults.model,
+                    system_prompt: runtime_defaults.system_prompt,
+                    max_turns: runtime_defaults.max_turns,
+                    tool_policy: self.tool_policy.clone(),
+                    turn_timeout_ms: runtime_defaults.turn_timeout_ms,
+                    render_options: self.render_options,
+                    session_lock_wait_ms: runtime_defaults.session_lock_wait_ms,
+                    session_lock_stale_ms: runtime_defaults.session_lock_stale_ms,
+                    state_dir: config.state_dir,
+                    bot_token: config.bot_token,
+                    bootstrap: DiscordRuntimeBootstrapConfig {
+                        application_id: config.application_id,
+                        command_prefix: config.command_prefix,
+                        ..DiscordRuntimeBootstrapConfig::default()
+                    },
+                    command_guild_ids: config.command_guild_ids,
+                    guild_config_path: config.guild_config_path,
+                    embed_responses: config.embed_responses,
+                    processed_event_cap: config.processed_event_cap,
+                    max_event_age_seconds: config.max_event_age_seconds,
+                    coalescing_window_ms: config.coalescing_window_ms,
+                    reconnect_delay: Duration::from_millis(config.reconnect_delay_ms),
+                    artifact_retention_days: config.artifact_retention_days,
+                })
+                .await
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn run_events_runner(&self) -> Result<()> {
         run_onboarding_events_runner_with_runtime_defaults_if_requested(
             self.cli,
@@ -185,12 +185,12 @@ pub(crate) use tau_cli::validation::validate_gateway_remote_profile_inspect_cli;
 pub(crate) use tau_cli::validation::validate_multi_channel_live_connectors_runner_cli;
 pub(crate) use tau_cli::validation::{
     validate_custom_command_contract_runner_cli, validate_dashboard_contract_runner_cli,
-    validate_deployment_contract_runner_cli, validate_events_runner_cli,
-    validate_gateway_contract_runner_cli, validate_gateway_openresponses_server_cli,
-    validate_github_issues_bridge_cli, validate_memory_contract_runner_cli,
-    validate_multi_agent_contract_runner_cli, validate_multi_channel_contract_runner_cli,
-    validate_multi_channel_live_runner_cli, validate_slack_bridge_cli,
-    validate_voice_contract_runner_cli, validate_voice_live_runner_cli,
+    validate_deployment_contract_runner_cli, validate_discord_bridge_cli,
+    validate_events_runner_cli, validate_gateway_contract_runner_cli,
+    validate_gateway_openresponses_server_cli, validate_github_issues_bridge_cli,
+    validate_memory_contract_runner_cli, validate_multi_agent_contract_runner_cli,
+    validate_multi_channel_contract_runner_cli, validate_multi_channel_live_runner_cli,
+    validate_slack_bridge_cli, validate_voice_contract_runner_cli, validate_voice_live_runner_cli,
 };
 pub(crate) use tau_cli::validation::{
     validate_daemon_cli, validate_deployment_wasm_inspect_cli,
@@ -22,7 +22,7 @@ use tau_ai::{
     Provider, TauAiError,
 };
 use tau_cli::cli_args::{CliExecutionDomainFlags, CliGatewayDaemonFlags, CliRuntimeTailFlags};
-use tau_cli::CliPromptSanitizerMode;
+use tau_cli::{CliMultiChannelEmailTlsMode, CliPromptSanitizerMode};
 use tempfile::tempdir;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::time::sleep;
@@ -91,7 +91,7 @@ use super::{
     validate_custom_command_contract_runner_cli, validate_daemon_cli,
     validate_dashboard_contract_runner_cli, validate_deployment_contract_runner_cli,
     validate_deployment_wasm_inspect_cli, validate_deployment_wasm_package_cli,
-    validate_event_webhook_ingest_cli, validate_events_runner_cli,
+    validate_discord_bridge_cli, validate_event_webhook_ingest_cli, validate_events_runner_cli,
     validate_gateway_contract_runner_cli, validate_gateway_openresponses_server_cli,
     validate_gateway_remote_plan_cli, validate_gateway_remote_profile_inspect_cli,
     validate_gateway_service_cli, validate_github_issues_bridge_cli, validate_macro_command_entry,
@@ -339,6 +339,8 @@ fn test_chat_request() -> ChatRequest {
         max_tokens: None,
         temperature: None,
         prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     }
 }
 
@@ -347,6 +349,9 @@ pub(crate) fn test_cli() -> Cli {
         shell_completion: None,
         model: "openai/gpt-5.2".to_string(),
         fallback_model: vec![],
+        model_route: vec![],
+        model_router_session_budget_usd: None,
+        model_router_budget_hard_limit: false,
         api_base: "https://api.openai.com/v1".to_string(),
         azure_openai_api_version: "2024-10-21".to_string(),
         model_catalog_url: None,
@@ -355,6 +360,14 @@ pub(crate) fn test_cli() -> Cli {
         model_catalog_stale_after_hours: 24,
         anthropic_api_base: "https://api.anthropic.com/v1".to_string(),
         google_api_base: "https://generativelanguage.googleapis.com/v1beta".to_string(),
+        local_api_base: "http://127.0.0.1:11434/v1".to_string(),
+        local_api_key: None,
+        bedrock_region: "us-east-1".to_string(),
+        bedrock_api_base: None,
+        vertex_project: None,
+        vertex_location: "us-central1".to_string(),
+        vertex_api_base: None,
+        vertex_service_account_file: None,
         api_key: None,
         openai_api_key: None,
         anthropic_api_key: None,
@@ -373,6 +386,8 @@ pub(crate) fn test_cli() -> Cli {
         anthropic_claude_args: vec![],
         anthropic_claude_timeout_ms: 120_000,
         google_auth_mode: CliProviderAuthMode::ApiKey,
+        bedrock_auth_mode: CliProviderAuthMode::ApiKey,
+        vertex_auth_mode: CliProviderAuthMode::Adc,
         provider_subscription_strict: false,
         google_gemini_backend: true,
         google_gemini_cli: "gemini".to_string(),
@@ -407,6 +422,8 @@ pub(crate) fn test_cli() -> Cli {
         max_turns: 8,
         agent_max_parallel_tool_calls: 4,
         agent_max_context_messages: Some(256),
+        agent_reasoning_budget_tokens: None,
+        agent_reasoning_effort: None,
         agent_request_max_retries: 2,
         agent_request_retry_initial_backoff_ms: 200,
         agent_request_retry_max_backoff_ms: 2_000,
@@ -425,6 +442,9 @@ pub(crate) fn test_cli() -> Cli {
         provider_rate_limit_capacity: 0,
         provider_rate_limit_refill_per_second: 0,
         provider_rate_limit_max_wait_ms: 0,
+        provider_hedging: false,
+        provider_hedge_percentile: 95.0,
+        provider_hedge_initial_delay_ms: 2_000,
         turn_timeout_ms: 0,
         json_events: false,
         stream_output: true,
@@ -522,6 +542,20 @@ pub(crate) fn test_cli() -> Cli {
                 slack_reconnect_delay_ms: 1_000,
                 slack_retry_max_attempts: 4,
                 slack_retry_base_delay_ms: 500,
+                discord_bridge: false,
+                discord_bot_token: None,
+                discord_bot_token_id: None,
+                discord_application_id: None,
+                discord_command_prefix: "/tau".to_string(),
+                discord_command_guild_ids: vec![],
+                discord_guild_config: None,
+                discord_state_dir: PathBuf::from(".tau/discord"),
+                discord_embed_responses: true,
+                discord_artifact_retention_days: 30,
+                discord_processed_event_cap: 10_000,
+                discord_max_event_age_seconds: 7_200,
+                discord_coalescing_window_ms: 2_000,
+                discord_reconnect_delay_ms: 1_000,
                 session: PathBuf::from(".tau/sessions/default.sqlite"),
                 no_session: false,
                 session_validate: false,
@@ -553,6 +587,9 @@ pub(crate) fn test_cli() -> Cli {
                 tool_audit_log: None,
                 telemetry_log: None,
                 otel_export_log: None,
+                otel_traces_endpoint: None,
+                otel_traces_header: vec![],
+                otel_service_name: "tau".to_string(),
                 os_sandbox_mode: CliOsSandboxMode::Off,
                 os_sandbox_command: vec![],
                 os_sandbox_policy_mode: None,
@@ -564,6 +601,14 @@ pub(crate) fn test_cli() -> Cli {
                 os_sandbox_docker_pids_limit: 256,
                 os_sandbox_docker_read_only_rootfs: true,
                 os_sandbox_docker_env: vec![],
+                os_sandbox_native_allow_network: false,
+                os_sandbox_native_memory_mb: 2048,
+                os_sandbox_native_max_processes: 0,
+                lsp_enabled: true,
+                lsp_server: vec![],
+                lsp_request_timeout_ms: 10_000,
+                lsp_post_edit_diagnostics: false,
+                git_session_worktrees: false,
                 http_timeout_ms: 20_000,
                 http_max_response_bytes: 256_000,
                 http_max_redirects: 5,
@@ -615,6 +660,7 @@ pub(crate) fn test_cli() -> Cli {
             github_artifact_retention_days: 30,
             github_include_issue_body: false,
             github_include_edited_comments: true,
+            github_pull_request_review: false,
             github_processed_event_cap: 10_000,
             github_retry_max_attempts: 4,
             github_retry_base_delay_ms: 500,
@@ -649,11 +695,17 @@ pub(crate) fn test_cli() -> Cli {
             multi_channel_telegram_ingress_mode: CliMultiChannelLiveConnectorMode::Disabled,
             multi_channel_discord_ingress_mode: CliMultiChannelLiveConnectorMode::Disabled,
             multi_channel_whatsapp_ingress_mode: CliMultiChannelLiveConnectorMode::Disabled,
+            multi_channel_matrix_ingress_mode: CliMultiChannelLiveConnectorMode::Disabled,
+            multi_channel_email_ingress_mode: CliMultiChannelLiveConnectorMode::Disabled,
             multi_channel_discord_ingress_channel_ids: vec![],
             multi_channel_discord_ingress_guild_ids: vec![],
             multi_channel_telegram_webhook_secret: None,
             multi_channel_whatsapp_webhook_verify_token: None,
             multi_channel_whatsapp_webhook_app_secret: None,
+            multi_channel_matrix_user_id: None,
+            multi_channel_matrix_auto_join_invites: false,
+            multi_channel_email_idle_timeout_ms: 0,
+            multi_channel_email_trusted_authserv_id: None,
             multi_channel_live_ingest_file: None,
             multi_channel_live_ingest_transport: None,
             multi_channel_live_ingest_provider: "native-ingress".to_string(),
@@ -702,10 +754,22 @@ pub(crate) fn test_cli() -> Cli {
             multi_channel_telegram_api_base: "https://api.telegram.org".to_string(),
             multi_channel_discord_api_base: "https://discord.com/api/v10".to_string(),
             multi_channel_whatsapp_api_base: "https://graph.facebook.com/v20.0".to_string(),
+            multi_channel_matrix_homeserver_url: "https://matrix.org".to_string(),
             multi_channel_telegram_bot_token: None,
             multi_channel_discord_bot_token: None,
             multi_channel_whatsapp_access_token: None,
             multi_channel_whatsapp_phone_number_id: None,
+            multi_channel_matrix_access_token: None,
+            multi_channel_email_address: None,
+            multi_channel_email_username: None,
+            multi_channel_email_password: None,
+            multi_channel_email_imap_host: String::new(),
+            multi_channel_email_imap_port: 993,
+            multi_channel_email_imap_tls: CliMultiChannelEmailTlsMode::Implicit,
+            multi_channel_email_imap_mailbox: "INBOX".to_string(),
+            multi_channel_email_smtp_host: String::new(),
+            multi_channel_email_smtp_port: 587,
+            multi_channel_email_smtp_tls: CliMultiChannelEmailTlsMode::Starttls,
             multi_agent_contract_runner: false,
             multi_agent_fixture: PathBuf::from(
                 "crates/tau-coding-agent/testdata/multi-agent-contract/mixed-outcomes.json",
@@ -990,6 +1054,7 @@ fn write_test_provider_credential(
         encryption,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     store
         .providers
@@ -1008,6 +1073,7 @@ fn write_test_integration_credential(
         encryption,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     store
         .integrations
@@ -1081,6 +1147,7 @@ fn test_command_context<'a>(
         auth_command_config,
         model_catalog,
         extension_commands: &[],
+        mcp_client_sessions: None,
     }
 }
 
@@ -1093,6 +1160,9 @@ fn set_provider_auth_mode(
         Provider::OpenAi | Provider::OpenRouter => config.openai_auth_mode = mode,
         Provider::Anthropic => config.anthropic_auth_mode = mode,
         Provider::Google => config.google_auth_mode = mode,
+        Provider::Bedrock => config.bedrock_auth_mode = mode,
+        Provider::Vertex => config.vertex_auth_mode = mode,
+        Provider::Local => {}
     }
 }
 
@@ -1101,6 +1171,7 @@ fn set_provider_api_key(config: &mut AuthCommandConfig, provider: Provider, valu
         Provider::OpenAi | Provider::OpenRouter => config.openai_api_key = Some(value.to_string()),
         Provider::Anthropic => config.anthropic_api_key = Some(value.to_string()),
         Provider::Google => config.google_api_key = Some(value.to_string()),
+        Provider::Local | Provider::Bedrock | Provider::Vertex => {}
     }
 }
 
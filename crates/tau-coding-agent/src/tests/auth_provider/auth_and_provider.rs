@@ -2500,6 +2500,7 @@ fn functional_execute_auth_command_rotate_key_rotates_store_without_data_loss()
         encryption: CredentialStoreEncryptionMode::Keyed,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     store.providers.insert(
         Provider::OpenAi.as_str().to_string(),
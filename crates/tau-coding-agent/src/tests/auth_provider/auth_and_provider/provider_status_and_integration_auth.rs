@@ -750,6 +750,7 @@ fn regression_execute_integration_auth_command_status_handles_empty_store() {
         encryption: CredentialStoreEncryptionMode::None,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     save_credential_store(&config.credential_store, &store, None)
         .expect("save empty credential store");
@@ -36,7 +36,7 @@ use super::super::{
     validate_custom_command_contract_runner_cli, validate_daemon_cli,
     validate_dashboard_contract_runner_cli, validate_deployment_contract_runner_cli,
     validate_deployment_wasm_inspect_cli, validate_deployment_wasm_package_cli,
-    validate_event_webhook_ingest_cli, validate_events_runner_cli,
+    validate_discord_bridge_cli, validate_event_webhook_ingest_cli, validate_events_runner_cli,
     validate_gateway_contract_runner_cli, validate_gateway_openresponses_server_cli,
     validate_gateway_remote_plan_cli, validate_gateway_remote_profile_inspect_cli,
     validate_gateway_service_cli, validate_github_issues_bridge_cli, validate_macro_command_entry,
@@ -262,6 +262,7 @@ fn integration_execute_macro_command_save_show_run_delete_lifecycle() {
         auth_command_config: &auth_command_config,
         model_catalog: &model_catalog,
         extension_commands: &[],
+        mcp_client_sessions: None,
     };
 
     let save_output = execute_macro_command(
@@ -374,6 +375,7 @@ fn regression_execute_macro_command_reports_missing_commands_file() {
         auth_command_config: &auth_command_config,
         model_catalog: &model_catalog,
         extension_commands: &[],
+        mcp_client_sessions: None,
     };
     let mut session_runtime = None;
     let mut agent = Agent::new(Arc::new(NoopClient), AgentConfig::default());
@@ -416,6 +418,7 @@ fn regression_execute_macro_command_reports_corrupt_macro_file() {
         auth_command_config: &auth_command_config,
         model_catalog: &model_catalog,
         extension_commands: &[],
+        mcp_client_sessions: None,
     };
     let mut session_runtime = None;
     let mut agent = Agent::new(Arc::new(NoopClient), AgentConfig::default());
@@ -453,6 +456,7 @@ fn regression_execute_macro_command_rejects_unknown_macro_and_invalid_entries()
         auth_command_config: &auth_command_config,
         model_catalog: &model_catalog,
         extension_commands: &[],
+        mcp_client_sessions: None,
     };
     let mut session_runtime = None;
     let mut agent = Agent::new(Arc::new(NoopClient), AgentConfig::default());
@@ -1353,7 +1357,7 @@ fn functional_render_help_overview_lists_known_commands() {
     assert!(help.contains("/skills-lock-write [lockfile_path]"));
     assert!(help.contains("/skills-sync [lockfile_path]"));
     assert!(help.contains("/macro <save|run|list|show|delete> ..."));
-    assert!(help.contains("/auth <login|reauth|status|logout|matrix|rotate-key> ..."));
+    assert!(help.contains("/auth <login|reauth|status|logout|matrix|rotate-key|pool> ..."));
     assert!(help.contains("/canvas <create|update|show|export|import>"));
     assert!(help.contains("/rbac <check|whoami> ..."));
     assert!(help.contains("/approvals <list|approve|reject> [--json] [--status <pending|approved|rejected|expired|consumed>] [request_id] [reason]"));
@@ -1419,7 +1423,7 @@ fn functional_render_command_help_supports_integration_auth_topic_without_slash(
 fn functional_render_command_help_supports_auth_topic_without_slash() {
     let help = render_command_help("auth").expect("render help");
     assert!(help.contains("command: /auth"));
-    assert!(help.contains("usage: /auth <login|reauth|status|logout|matrix|rotate-key> ..."));
+    assert!(help.contains("usage: /auth <login|reauth|status|logout|matrix|rotate-key|pool> ..."));
     assert!(help.contains("rotate-key"));
 }
 
@@ -2708,6 +2712,7 @@ fn integration_skills_sync_command_preserves_session_runtime_on_drift() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills sync command should continue");
@@ -2763,6 +2768,7 @@ fn integration_skills_lock_write_command_preserves_session_runtime_on_error() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills lock write command should continue");
@@ -2817,6 +2823,7 @@ fn integration_skills_list_command_preserves_session_runtime() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills list command should continue");
@@ -2870,6 +2877,7 @@ fn integration_skills_show_command_preserves_session_runtime_on_unknown_skill()
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills show command should continue");
@@ -2923,6 +2931,7 @@ fn integration_skills_search_command_preserves_session_runtime_on_invalid_args()
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills search command should continue");
@@ -2976,6 +2985,7 @@ fn integration_skills_lock_diff_command_preserves_session_runtime_on_error() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills lock diff command should continue");
@@ -3029,6 +3039,7 @@ fn integration_skills_verify_command_preserves_session_runtime_on_error() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills verify command should continue");
@@ -3082,6 +3093,7 @@ fn integration_skills_prune_command_preserves_session_runtime_on_error() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills prune command should continue");
@@ -3138,6 +3150,7 @@ fn integration_skills_trust_list_command_preserves_session_runtime_on_error() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills trust list command should continue");
@@ -3194,6 +3207,7 @@ fn integration_skills_trust_mutation_commands_update_store_and_preserve_runtime(
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills trust add command should continue");
@@ -3211,6 +3225,7 @@ fn integration_skills_trust_mutation_commands_update_store_and_preserve_runtime(
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills trust revoke command should continue");
@@ -3228,6 +3243,7 @@ fn integration_skills_trust_mutation_commands_update_store_and_preserve_runtime(
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("skills trust rotate command should continue");
@@ -157,6 +157,49 @@ fn regression_validate_slack_bridge_cli_rejects_missing_tokens() {
         .contains("--slack-bot-token (or --slack-bot-token-id) is required"));
 }
 
+#[test]
+fn unit_validate_discord_bridge_cli_accepts_minimum_configuration() {
+    let mut cli = test_cli();
+    cli.discord_bridge = true;
+    cli.discord_bot_token = Some("discord-bot-token".to_string());
+    cli.discord_application_id = Some(42);
+
+    validate_discord_bridge_cli(&cli).expect("discord bridge config should validate");
+}
+
+#[test]
+fn functional_validate_discord_bridge_cli_rejects_slack_bridge_conflict() {
+    let mut cli = test_cli();
+    cli.discord_bridge = true;
+    cli.discord_bot_token_id = Some("discord-bot".to_string());
+    cli.discord_application_id = Some(42);
+    cli.slack_bridge = true;
+
+    let error = validate_discord_bridge_cli(&cli).expect_err("slack conflict");
+    assert!(error.to_string().contains(
+        "--discord-bridge cannot be combined with --github-issues-bridge or --slack-bridge"
+    ));
+}
+
+#[test]
+fn regression_validate_discord_bridge_cli_requires_token_and_application_id() {
+    let mut cli = test_cli();
+    cli.discord_bridge = true;
+    cli.discord_application_id = Some(42);
+
+    let error = validate_discord_bridge_cli(&cli).expect_err("missing discord bot token");
+    assert!(error
+        .to_string()
+        .contains("--discord-bot-token (or --discord-bot-token-id) is required"));
+
+    cli.discord_bot_token = Some("discord-bot-token".to_string());
+    cli.discord_application_id = None;
+    let error = validate_discord_bridge_cli(&cli).expect_err("missing application id");
+    assert!(error
+        .to_string()
+        .contains("--discord-application-id is required"));
+}
+
 #[test]
 fn unit_validate_events_runner_cli_accepts_minimum_configuration() {
     let mut cli = test_cli();
@@ -250,7 +293,7 @@ fn integration_validate_multi_channel_contract_runner_cli_rejects_transport_conf
 
     let error = validate_multi_channel_contract_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner"
     ));
 }
 
@@ -371,7 +414,7 @@ fn integration_validate_multi_channel_live_runner_cli_rejects_transport_conflict
 
     let error = validate_multi_channel_live_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner"
     ));
 }
 
@@ -480,7 +523,7 @@ fn integration_validate_multi_channel_live_connectors_runner_cli_rejects_transpo
     let error = validate_multi_channel_live_connectors_runner_cli(&cli)
         .expect_err("transport conflict should fail");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner"
     ));
 }
 
@@ -577,7 +620,7 @@ fn functional_validate_multi_channel_live_ingest_cli_rejects_transport_conflicts
     let error =
         validate_multi_channel_live_ingest_cli(&cli).expect_err("transport conflict should fail");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, or --memory-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, or --memory-contract-runner"
     ));
 }
 
@@ -854,7 +897,7 @@ fn integration_validate_multi_agent_contract_runner_cli_rejects_transport_confli
 
     let error = validate_multi_agent_contract_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --memory-contract-runner, or --dashboard-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --memory-contract-runner, or --dashboard-contract-runner"
     ));
 }
 
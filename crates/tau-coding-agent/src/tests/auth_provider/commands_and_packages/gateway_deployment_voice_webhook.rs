@@ -296,7 +296,7 @@ fn integration_validate_gateway_contract_runner_cli_rejects_transport_conflicts(
 
     let error = validate_gateway_contract_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, or --dashboard-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, or --dashboard-contract-runner"
     ));
 }
 
@@ -430,7 +430,7 @@ fn integration_validate_deployment_contract_runner_cli_rejects_transport_conflic
 
     let error = validate_deployment_contract_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-contract-runner"
     ));
 }
 
@@ -756,7 +756,7 @@ fn integration_validate_voice_contract_runner_cli_rejects_transport_conflicts()
 
     let error = validate_voice_contract_runner_cli(&cli).expect_err("transport conflict");
     assert!(error.to_string().contains(
-        "--github-issues-bridge, --slack-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-live-runner"
+        "--github-issues-bridge, --slack-bridge, --discord-bridge, --events-runner, --multi-channel-contract-runner, --multi-channel-live-runner, --multi-agent-contract-runner, --memory-contract-runner, --dashboard-contract-runner, --gateway-contract-runner, --custom-command-contract-runner, or --voice-live-runner"
     ));
 }
 
@@ -549,6 +549,10 @@ fn integration_prompt_telemetry_logger_persists_completed_record() {
                 output_tokens: 2,
                 total_tokens: 6,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
             finish_reason: Some("stop".to_string()),
         })
@@ -591,6 +595,10 @@ fn regression_prompt_telemetry_logger_marks_interrupted_runs() {
                 output_tokens: 1,
                 total_tokens: 2,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
             finish_reason: Some("length".to_string()),
         })
@@ -712,6 +720,10 @@ async fn functional_run_prompt_with_cancellation_records_session_usage_and_cost(
             output_tokens: 10,
             total_tokens: 40,
             cached_input_tokens: 0,
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
         },
     }]);
     let mut agent = Agent::new(
@@ -772,6 +784,10 @@ async fn integration_spec_c02_run_prompt_with_cancellation_accumulates_session_u
                 output_tokens: 10,
                 total_tokens: 40,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         },
         ChatResponse {
@@ -782,6 +798,10 @@ async fn integration_spec_c02_run_prompt_with_cancellation_accumulates_session_u
                 output_tokens: 5,
                 total_tokens: 25,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         },
     ]);
@@ -1420,6 +1440,7 @@ fn integration_handle_command_dispatches_extension_registered_command() {
             auth_command_config: &auth_command_config,
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &registrations.registered_commands,
+            mcp_client_sessions: None,
         },
     )
     .expect("command should execute");
@@ -1496,6 +1517,7 @@ fn regression_handle_command_extension_failure_is_fail_isolated() {
             auth_command_config: &auth_command_config,
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &registrations.registered_commands,
+            mcp_client_sessions: None,
         },
     )
     .expect("errors should be fail-isolated");
@@ -3367,6 +3389,7 @@ fn integration_session_import_command_replace_mode_overwrites_runtime_state() {
             auth_command_config: &test_auth_command_config(),
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("session replace import should succeed");
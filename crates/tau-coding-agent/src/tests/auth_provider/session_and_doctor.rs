@@ -1374,6 +1374,7 @@ fn integration_doctor_command_preserves_session_runtime() {
             auth_command_config: &auth_command_config,
             model_catalog: &ModelCatalog::built_in(),
             extension_commands: &[],
+            mcp_client_sessions: None,
         },
     )
     .expect("doctor command should continue");
@@ -1636,6 +1637,7 @@ fn functional_evaluate_multi_channel_live_readiness_uses_store_backed_secrets()
         encryption: CredentialStoreEncryptionMode::None,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     let timestamp = current_unix_timestamp();
     store.integrations.insert(
@@ -1706,6 +1708,7 @@ fn integration_evaluate_multi_channel_live_readiness_warns_on_unsafe_open_dm_whe
         encryption: CredentialStoreEncryptionMode::None,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     let timestamp = current_unix_timestamp();
     for (id, secret) in [
@@ -1789,6 +1792,7 @@ fn regression_evaluate_multi_channel_live_readiness_fails_on_unsafe_open_dm_when
         encryption: CredentialStoreEncryptionMode::None,
         providers: BTreeMap::new(),
         integrations: BTreeMap::new(),
+        provider_key_pools: BTreeMap::new(),
     };
     let timestamp = current_unix_timestamp();
     for (id, secret) in [
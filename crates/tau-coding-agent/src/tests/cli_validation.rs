@@ -7,7 +7,7 @@ use tau_cli::{
     CliCredentialStoreEncryptionMode, CliDaemonProfile, CliDeploymentWasmRuntimeProfile,
     CliGatewayOpenResponsesAuthMode, CliGatewayRemoteProfile, CliMultiChannelLiveConnectorMode,
     CliMultiChannelOutboundMode, CliMultiChannelTransport, CliOsSandboxDockerNetwork,
-    CliOsSandboxPolicyMode, CliPromptSanitizerMode, CliProviderAuthMode,
+    CliOsSandboxMode, CliOsSandboxPolicyMode, CliPromptSanitizerMode, CliProviderAuthMode,
 };
 
 use super::{normalize_startup_cli_args, parse_cli_with_stack, try_parse_cli_with_stack};
@@ -263,6 +263,75 @@ fn functional_cli_os_sandbox_docker_flags_accept_overrides() {
     );
 }
 
+#[test]
+fn unit_cli_os_sandbox_native_flags_default_values_are_stable() {
+    let cli = parse_cli_with_stack(["tau-rs"]);
+    assert!(!cli.os_sandbox_native_allow_network);
+    assert_eq!(cli.os_sandbox_native_memory_mb, 2048);
+    assert_eq!(cli.os_sandbox_native_max_processes, 0);
+}
+
+#[test]
+fn functional_cli_os_sandbox_native_flags_accept_overrides() {
+    let cli = parse_cli_with_stack([
+        "tau-rs",
+        "--os-sandbox-mode",
+        "native",
+        "--os-sandbox-native-allow-network=true",
+        "--os-sandbox-native-memory-mb",
+        "1024",
+        "--os-sandbox-native-max-processes",
+        "512",
+    ]);
+    assert_eq!(cli.os_sandbox_mode, CliOsSandboxMode::Native);
+    assert!(cli.os_sandbox_native_allow_network);
+    assert_eq!(cli.os_sandbox_native_memory_mb, 1024);
+    assert_eq!(cli.os_sandbox_native_max_processes, 512);
+}
+
+#[test]
+fn unit_cli_lsp_flags_default_values_are_stable() {
+    let cli = parse_cli_with_stack(["tau-rs"]);
+    assert!(cli.lsp_enabled);
+    assert!(cli.lsp_server.is_empty());
+    assert_eq!(cli.lsp_request_timeout_ms, 10_000);
+    assert!(!cli.lsp_post_edit_diagnostics);
+}
+
+#[test]
+fn functional_cli_lsp_flags_accept_overrides() {
+    let cli = parse_cli_with_stack([
+        "tau-rs",
+        "--lsp-enabled=false",
+        "--lsp-server",
+        "python=pylsp",
+        "--lsp-server",
+        "typescript=typescript-language-server --stdio",
+        "--lsp-request-timeout-ms",
+        "3000",
+        "--lsp-post-edit-diagnostics",
+    ]);
+    assert!(!cli.lsp_enabled);
+    assert_eq!(
+        cli.lsp_server,
+        vec![
+            "python=pylsp".to_string(),
+            "typescript=typescript-language-server --stdio".to_string()
+        ]
+    );
+    assert_eq!(cli.lsp_request_timeout_ms, 3_000);
+    assert!(cli.lsp_post_edit_diagnostics);
+}
+
+#[test]
+fn functional_cli_git_session_worktrees_flag_defaults_off_and_accepts_override() {
+    assert!(!parse_cli_with_stack(["tau-rs"]).git_session_worktrees);
+    assert!(parse_cli_with_stack(["tau-rs", "--git-session-worktrees"]).git_session_worktrees);
+    assert!(
+        !parse_cli_with_stack(["tau-rs", "--git-session-worktrees=false"]).git_session_worktrees
+    );
+}
+
 #[test]
 fn unit_cli_http_tool_policy_flags_default_values_are_stable() {
     let cli = parse_cli_with_stack(["tau-rs"]);
@@ -20,7 +20,8 @@ use tau_algorithm::{
 };
 use tau_cli::{Cli, CliPromptSanitizerMode};
 use tau_onboarding::startup_local_runtime::{
-    build_local_runtime_agent, derive_preflight_token_limits, LocalRuntimeAgentSettings,
+    build_local_runtime_agent, derive_preflight_token_limits,
+    resolve_local_runtime_reasoning_config, LocalRuntimeAgentSettings,
 };
 use tau_trainer::checkpoint_store::{
     load_policy_checkpoint, load_policy_checkpoint_with_rollback, CheckpointSource,
@@ -528,6 +529,7 @@ fn validate_prompt_optimization_control_cli_request(cli: &Cli) -> Result<()> {
 
     let has_other_runtime_mode = cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -989,6 +991,7 @@ fn validate_training_cli_request(cli: &Cli) -> Result<()> {
 
     let has_transport_mode = cli.github_issues_bridge
         || cli.slack_bridge
+        || cli.discord_bridge
         || cli.events_runner
         || cli.multi_channel_contract_runner
         || cli.multi_channel_live_runner
@@ -1429,10 +1432,12 @@ fn build_executor(
     let settings = LocalRuntimeAgentSettings {
         max_turns: cli.max_turns,
         max_tokens: model_max_output_tokens,
+        reasoning: resolve_local_runtime_reasoning_config(cli),
         max_parallel_tool_calls: cli.agent_max_parallel_tool_calls,
         max_context_messages: cli.agent_max_context_messages,
         max_estimated_input_tokens,
         max_estimated_total_tokens,
+        tokenizer: model_catalog_entry.map(|entry| entry.tokenizer_family()),
         context_compaction_warn_threshold_percent: agent_defaults
             .context_compaction_warn_threshold_percent,
         context_compaction_aggressive_threshold_percent: agent_defaults
@@ -1456,6 +1461,7 @@ fn build_executor(
             .and_then(|entry| entry.cached_input_cost_per_million),
         model_output_cost_per_million: model_catalog_entry
             .and_then(|entry| entry.output_cost_per_million),
+        route_cost_rates: HashMap::new(),
         cost_budget_usd: cli.agent_cost_budget_usd,
         cost_alert_thresholds_percent: cli.agent_cost_alert_threshold_percent.clone(),
         prompt_sanitizer_enabled: cli.prompt_sanitizer_enabled,
@@ -1794,6 +1800,7 @@ mod tests {
                     cached_input_cost_per_million: None,
                     input_cost_per_million: None,
                     output_cost_per_million: None,
+                    tokenizer: None,
                 }],
             },
             ModelCatalogSource::BuiltIn,
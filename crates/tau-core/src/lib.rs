@@ -1,21 +1,37 @@
 //! Foundational low-level utilities shared across Tau crates.
 //!
-//! Provides atomic file-write helpers and time utilities used by runtime state,
-//! cache persistence, and expiry calculations.
+//! Provides atomic file-write helpers, time utilities used by runtime state,
+//! cache persistence, and expiry calculations, the shared metrics registry
+//! exported by the gateway, and span recording with W3C trace-context
+//! propagation.
 
 /// Atomic file-write helpers for durable state updates.
 pub mod atomic_io;
 /// Size-based NDJSON log rotation helpers for operational runtime files.
 pub mod log_rotation;
+/// Process-wide metrics registry with OpenMetrics text rendering.
+pub mod metrics_registry;
 /// Unix timestamp utilities used across runtime policy/state logic.
 pub mod time_utils;
+/// Span recording, trace-context propagation, and exporter installation.
+pub mod trace_spans;
 
 /// Re-export of atomic text file write helper.
 pub use atomic_io::write_text_atomic;
 /// Re-exports for log append and rotation policy helpers.
 pub use log_rotation::{append_line_with_rotation, LogRotationPolicy};
+/// Re-exports for the shared metrics registry and OpenMetrics exposition helpers.
+pub use metrics_registry::{
+    global_metrics_registry, MetricKind, MetricsRegistry, DEFAULT_LATENCY_BUCKETS_SECONDS,
+    OPENMETRICS_CONTENT_TYPE,
+};
 /// Re-exports for Unix timestamp and expiry helper utilities.
 pub use time_utils::{current_unix_timestamp, current_unix_timestamp_ms, is_expired_unix};
+/// Re-exports for span recording and trace-context propagation.
+pub use trace_spans::{
+    current_trace_context, install_span_exporter, with_trace_context, Span, SpanAttributeValue,
+    SpanExporter, SpanKind, SpanRecord, SpanStatus, TraceContext, TRACEPARENT_HEADER,
+};
 
 #[cfg(test)]
 mod tests {
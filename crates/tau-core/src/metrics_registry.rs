@@ -0,0 +1,385 @@
+//! Process-wide metrics registry rendered in OpenMetrics text format.
+//!
+//! Runtime crates record counters, gauges, and histograms into the shared
+//! [`global_metrics_registry`]; the gateway serves the rendered exposition at
+//! `/metrics`. Recording never fails: updates that conflict with an existing
+//! family's kind are dropped so instrumentation cannot disturb callers.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Mutex, OnceLock};
+
+/// Content type for OpenMetrics text exposition responses.
+pub const OPENMETRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Default latency buckets (seconds) for request and tool duration histograms.
+pub const DEFAULT_LATENCY_BUCKETS_SECONDS: &[f64] =
+    &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0];
+
+/// Metric family kinds supported by the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+type MetricLabels = Vec<(String, String)>;
+
+#[derive(Debug, Clone)]
+enum MetricSeries {
+    Value(f64),
+    Histogram {
+        bucket_counts: Vec<u64>,
+        sum: f64,
+        count: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct MetricFamily {
+    kind: MetricKind,
+    help: String,
+    buckets: Vec<f64>,
+    series: BTreeMap<MetricLabels, MetricSeries>,
+}
+
+/// Thread-safe registry of labelled metric families.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    families: Mutex<BTreeMap<String, MetricFamily>>,
+}
+
+impl MetricsRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `delta` to a counter; `name` omits the `_total` suffix.
+    pub fn increment_counter(&self, name: &str, help: &str, labels: &[(&str, &str)], delta: f64) {
+        if !delta.is_finite() || delta < 0.0 {
+            return;
+        }
+        self.update(name, help, MetricKind::Counter, &[], labels, |series, _| {
+            if let MetricSeries::Value(value) = series {
+                *value += delta;
+            }
+        });
+    }
+
+    /// Sets a gauge to `value`.
+    pub fn set_gauge(&self, name: &str, help: &str, labels: &[(&str, &str)], value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        self.update(name, help, MetricKind::Gauge, &[], labels, |series, _| {
+            if let MetricSeries::Value(current) = series {
+                *current = value;
+            }
+        });
+    }
+
+    /// Records one histogram observation; `buckets` apply on first registration.
+    pub fn observe_histogram(
+        &self,
+        name: &str,
+        help: &str,
+        buckets: &[f64],
+        labels: &[(&str, &str)],
+        value: f64,
+    ) {
+        if !value.is_finite() {
+            return;
+        }
+        self.update(
+            name,
+            help,
+            MetricKind::Histogram,
+            buckets,
+            labels,
+            |series, bounds| {
+                if let MetricSeries::Histogram {
+                    bucket_counts,
+                    sum,
+                    count,
+                } = series
+                {
+                    *sum += value;
+                    *count = count.saturating_add(1);
+                    for (bucket_count, bound) in bucket_counts.iter_mut().zip(bounds) {
+                        if value <= *bound {
+                            *bucket_count = bucket_count.saturating_add(1);
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    /// Returns the current value of a counter or gauge series, if recorded.
+    pub fn value(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        let families = self.families.lock().ok()?;
+        match families.get(name)?.series.get(&normalize_labels(labels))? {
+            MetricSeries::Value(value) => Some(*value),
+            MetricSeries::Histogram { .. } => None,
+        }
+    }
+
+    /// Renders all families in OpenMetrics text format, terminated by `# EOF`.
+    pub fn render_openmetrics(&self) -> String {
+        let mut output = String::new();
+        let Ok(families) = self.families.lock() else {
+            output.push_str("# EOF\n");
+            return output;
+        };
+        for (name, family) in families.iter() {
+            let _ = writeln!(output, "# TYPE {name} {}", family.kind.as_str());
+            if !family.help.is_empty() {
+                let _ = writeln!(output, "# HELP {name} {}", escape_help(&family.help));
+            }
+            for (labels, series) in &family.series {
+                match series {
+                    MetricSeries::Value(value) => {
+                        let sample_name = match family.kind {
+                            MetricKind::Counter => format!("{name}_total"),
+                            _ => name.clone(),
+                        };
+                        let _ = writeln!(
+                            output,
+                            "{sample_name}{} {}",
+                            render_labels(labels, None),
+                            format_sample_value(*value)
+                        );
+                    }
+                    MetricSeries::Histogram {
+                        bucket_counts,
+                        sum,
+                        count,
+                    } => {
+                        for (bound, bucket_count) in family.buckets.iter().zip(bucket_counts) {
+                            let _ = writeln!(
+                                output,
+                                "{name}_bucket{} {bucket_count}",
+                                render_labels(labels, Some(format_bucket_bound(*bound).as_str()))
+                            );
+                        }
+                        let _ = writeln!(
+                            output,
+                            "{name}_bucket{} {count}",
+                            render_labels(labels, Some("+Inf"))
+                        );
+                        let _ = writeln!(
+                            output,
+                            "{name}_sum{} {}",
+                            render_labels(labels, None),
+                            format_sample_value(*sum)
+                        );
+                        let _ = writeln!(
+                            output,
+                            "{name}_count{} {count}",
+                            render_labels(labels, None)
+                        );
+                    }
+                }
+            }
+        }
+        output.push_str("# EOF\n");
+        output
+    }
+
+    fn update(
+        &self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        buckets: &[f64],
+        labels: &[(&str, &str)],
+        apply: impl FnOnce(&mut MetricSeries, &[f64]),
+    ) {
+        let Ok(mut families) = self.families.lock() else {
+            return;
+        };
+        let family = families
+            .entry(name.to_string())
+            .or_insert_with(|| MetricFamily {
+                kind,
+                help: help.to_string(),
+                buckets: normalize_buckets(buckets),
+                series: BTreeMap::new(),
+            });
+        if family.kind != kind {
+            return;
+        }
+        let MetricFamily {
+            buckets: bounds,
+            series,
+            ..
+        } = family;
+        let bucket_len = bounds.len();
+        let series = series
+            .entry(normalize_labels(labels))
+            .or_insert_with(|| match kind {
+                MetricKind::Histogram => MetricSeries::Histogram {
+                    bucket_counts: vec![0; bucket_len],
+                    sum: 0.0,
+                    count: 0,
+                },
+                MetricKind::Counter | MetricKind::Gauge => MetricSeries::Value(0.0),
+            });
+        apply(series, bounds);
+    }
+}
+
+/// Returns the process-wide registry shared by all Tau runtime crates.
+pub fn global_metrics_registry() -> &'static MetricsRegistry {
+    static REGISTRY: OnceLock<MetricsRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(MetricsRegistry::new)
+}
+
+fn normalize_labels(labels: &[(&str, &str)]) -> MetricLabels {
+    let mut normalized = labels
+        .iter()
+        .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+        .collect::<Vec<_>>();
+    normalized.sort();
+    normalized
+}
+
+fn normalize_buckets(buckets: &[f64]) -> Vec<f64> {
+    let mut normalized = buckets
+        .iter()
+        .copied()
+        .filter(|bound| bound.is_finite())
+        .collect::<Vec<_>>();
+    normalized.sort_by(f64::total_cmp);
+    normalized.dedup();
+    normalized
+}
+
+fn render_labels(labels: &MetricLabels, le: Option<&str>) -> String {
+    if labels.is_empty() && le.is_none() {
+        return String::new();
+    }
+    let mut parts = labels
+        .iter()
+        .map(|(name, value)| format!("{name}=\"{}\"", escape_label_value(value)))
+        .collect::<Vec<_>>();
+    if let Some(le) = le {
+        parts.push(format!("le=\"{le}\""));
+    }
+    format!("{{{}}}", parts.join(","))
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn format_sample_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value}")
+    }
+}
+
+fn format_bucket_bound(bound: f64) -> String {
+    if bound.fract() == 0.0 {
+        format!("{bound:.1}")
+    } else {
+        format!("{bound}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_counter_and_gauge_render_with_sorted_labels_and_eof() {
+        let registry = MetricsRegistry::new();
+        registry.increment_counter(
+            "tau_tool_calls",
+            "Tool calls by outcome.",
+            &[("tool", "bash"), ("outcome", "success")],
+            1.0,
+        );
+        registry.increment_counter(
+            "tau_tool_calls",
+            "Tool calls by outcome.",
+            &[("outcome", "success"), ("tool", "bash")],
+            2.0,
+        );
+        registry.set_gauge("tau_queue_depth", "Queue depth.", &[], 7.0);
+
+        let rendered = registry.render_openmetrics();
+        assert!(rendered.contains("# TYPE tau_tool_calls counter\n"));
+        assert!(rendered.contains("tau_tool_calls_total{outcome=\"success\",tool=\"bash\"} 3\n"));
+        assert!(rendered.contains("# TYPE tau_queue_depth gauge\n"));
+        assert!(rendered.contains("tau_queue_depth 7\n"));
+        assert!(rendered.ends_with("# EOF\n"));
+        assert_eq!(
+            registry.value(
+                "tau_tool_calls",
+                &[("tool", "bash"), ("outcome", "success")]
+            ),
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn unit_histogram_renders_cumulative_buckets_sum_and_count() {
+        let registry = MetricsRegistry::new();
+        for value in [0.2, 0.7, 3.0] {
+            registry.observe_histogram(
+                "tau_latency_seconds",
+                "Latency.",
+                &[0.5, 1.0],
+                &[("provider", "openai")],
+                value,
+            );
+        }
+
+        let rendered = registry.render_openmetrics();
+        assert!(rendered.contains("tau_latency_seconds_bucket{provider=\"openai\",le=\"0.5\"} 1\n"));
+        assert!(rendered.contains("tau_latency_seconds_bucket{provider=\"openai\",le=\"1.0\"} 2\n"));
+        assert!(
+            rendered.contains("tau_latency_seconds_bucket{provider=\"openai\",le=\"+Inf\"} 3\n")
+        );
+        assert!(rendered.contains("tau_latency_seconds_sum{provider=\"openai\"} 3.9\n"));
+        assert!(rendered.contains("tau_latency_seconds_count{provider=\"openai\"} 3\n"));
+    }
+
+    #[test]
+    fn regression_kind_conflicts_and_invalid_values_are_ignored() {
+        let registry = MetricsRegistry::new();
+        registry.increment_counter("tau_conflict", "", &[], 1.0);
+        registry.set_gauge("tau_conflict", "", &[], 42.0);
+        registry.increment_counter("tau_conflict", "", &[], -5.0);
+        registry.increment_counter("tau_conflict", "", &[], f64::NAN);
+        assert_eq!(registry.value("tau_conflict", &[]), Some(1.0));
+
+        registry.set_gauge("tau_label", "", &[("path", "a\"b\\c\nd")], 1.0);
+        assert!(registry
+            .render_openmetrics()
+            .contains("tau_label{path=\"a\\\"b\\\\c\\nd\"} 1\n"));
+    }
+}
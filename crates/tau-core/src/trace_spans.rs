@@ -0,0 +1,455 @@
+//! Lightweight span recording with W3C trace-context propagation.
+//!
+//! Runtime crates open [`Span`]s around gateway requests, agent turns, provider
+//! calls, and tool executions. The active context flows through async code via
+//! a Tokio task-local ([`with_trace_context`]); finished spans are handed to the
+//! process-wide [`SpanExporter`] (the OTLP/HTTP exporter in `tau-runtime`).
+//! Without an installed exporter spans are recorded and discarded.
+
+use std::collections::hash_map::RandomState;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// HTTP header carrying W3C trace context.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+tokio::task_local! {
+    static CURRENT_TRACE_CONTEXT: TraceContext;
+}
+
+/// W3C trace context identifying the active span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    pub trace_id: String,
+    pub span_id: String,
+    pub sampled: bool,
+}
+
+impl TraceContext {
+    /// Parses a `traceparent` header value; returns `None` for malformed input.
+    pub fn parse_traceparent(value: &str) -> Option<Self> {
+        let mut parts = value.trim().split('-');
+        let version = parts.next()?;
+        let trace_id = parts.next()?;
+        let span_id = parts.next()?;
+        let flags = parts.next()?;
+        if !is_lower_hex(version, 2) || version == "ff" {
+            return None;
+        }
+        if version == "00" && parts.next().is_some() {
+            return None;
+        }
+        if !is_lower_hex(trace_id, 32) || is_all_zero(trace_id) {
+            return None;
+        }
+        if !is_lower_hex(span_id, 16) || is_all_zero(span_id) {
+            return None;
+        }
+        if !is_lower_hex(flags, 2) {
+            return None;
+        }
+        let flags = u8::from_str_radix(flags, 16).ok()?;
+        Some(Self {
+            trace_id: trace_id.to_string(),
+            span_id: span_id.to_string(),
+            sampled: flags & 0x01 == 0x01,
+        })
+    }
+
+    /// Renders the context as a version `00` `traceparent` header value.
+    pub fn to_traceparent(&self) -> String {
+        format!(
+            "00-{}-{}-{}",
+            self.trace_id,
+            self.span_id,
+            if self.sampled { "01" } else { "00" }
+        )
+    }
+}
+
+/// Span kinds mirrored from the OpenTelemetry data model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    Internal,
+    Server,
+    Client,
+}
+
+/// Final span status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpanStatus {
+    Unset,
+    Ok,
+    Error(String),
+}
+
+/// Attribute values supported on spans.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpanAttributeValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    StringArray(Vec<String>),
+}
+
+impl From<&str> for SpanAttributeValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for SpanAttributeValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for SpanAttributeValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<u64> for SpanAttributeValue {
+    fn from(value: u64) -> Self {
+        Self::Int(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<u32> for SpanAttributeValue {
+    fn from(value: u32) -> Self {
+        Self::Int(i64::from(value))
+    }
+}
+
+impl From<usize> for SpanAttributeValue {
+    fn from(value: usize) -> Self {
+        Self::Int(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<f64> for SpanAttributeValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for SpanAttributeValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Vec<String>> for SpanAttributeValue {
+    fn from(value: Vec<String>) -> Self {
+        Self::StringArray(value)
+    }
+}
+
+/// Completed span handed to the installed exporter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanRecord {
+    pub trace_id: String,
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub name: String,
+    pub kind: SpanKind,
+    pub start_unix_nanos: u64,
+    pub end_unix_nanos: u64,
+    pub attributes: Vec<(String, SpanAttributeValue)>,
+    pub status: SpanStatus,
+}
+
+impl SpanRecord {
+    /// Returns the attribute value recorded under `key`, if any.
+    pub fn attribute(&self, key: &str) -> Option<&SpanAttributeValue> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Sink for finished spans; implementations must not block the caller.
+pub trait SpanExporter: Send + Sync {
+    fn export(&self, span: SpanRecord);
+}
+
+/// In-flight span; ends on [`Span::end`] or when dropped.
+#[derive(Debug)]
+pub struct Span {
+    context: TraceContext,
+    record: Option<SpanRecord>,
+}
+
+impl Span {
+    /// Starts a span under `parent`, or a new sampled trace when `parent` is `None`.
+    pub fn start(name: impl Into<String>, kind: SpanKind, parent: Option<&TraceContext>) -> Self {
+        let context = TraceContext {
+            trace_id: parent
+                .map(|parent| parent.trace_id.clone())
+                .unwrap_or_else(|| random_hex_id(16)),
+            span_id: random_hex_id(8),
+            sampled: parent.map(|parent| parent.sampled).unwrap_or(true),
+        };
+        let record = SpanRecord {
+            trace_id: context.trace_id.clone(),
+            span_id: context.span_id.clone(),
+            parent_span_id: parent.map(|parent| parent.span_id.clone()),
+            name: name.into(),
+            kind,
+            start_unix_nanos: current_unix_nanos(),
+            end_unix_nanos: 0,
+            attributes: Vec::new(),
+            status: SpanStatus::Unset,
+        };
+        Self {
+            context,
+            record: Some(record),
+        }
+    }
+
+    /// Starts a span under the task's current trace context.
+    pub fn start_in_current(name: impl Into<String>, kind: SpanKind) -> Self {
+        Self::start(name, kind, current_trace_context().as_ref())
+    }
+
+    /// Returns the context child spans and outbound requests should use.
+    pub fn context(&self) -> &TraceContext {
+        &self.context
+    }
+
+    /// Records or replaces an attribute.
+    pub fn set_attribute(&mut self, key: &str, value: impl Into<SpanAttributeValue>) {
+        let Some(record) = self.record.as_mut() else {
+            return;
+        };
+        let value = value.into();
+        if let Some(existing) = record.attributes.iter_mut().find(|(name, _)| name == key) {
+            existing.1 = value;
+        } else {
+            record.attributes.push((key.to_string(), value));
+        }
+    }
+
+    /// Marks the span as successful.
+    pub fn set_status_ok(&mut self) {
+        if let Some(record) = self.record.as_mut() {
+            record.status = SpanStatus::Ok;
+        }
+    }
+
+    /// Marks the span as failed with `message`.
+    pub fn set_status_error(&mut self, message: impl Into<String>) {
+        if let Some(record) = self.record.as_mut() {
+            record.status = SpanStatus::Error(message.into());
+        }
+    }
+
+    /// Ends the span and exports it.
+    pub fn end(mut self) {
+        self.finish();
+    }
+
+    fn finish(&mut self) {
+        let Some(mut record) = self.record.take() else {
+            return;
+        };
+        if !self.context.sampled {
+            return;
+        }
+        record.end_unix_nanos = current_unix_nanos().max(record.start_unix_nanos);
+        if let Some(exporter) = installed_span_exporter() {
+            exporter.export(record);
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+fn exporter_slot() -> &'static RwLock<Option<Arc<dyn SpanExporter>>> {
+    static EXPORTER: OnceLock<RwLock<Option<Arc<dyn SpanExporter>>>> = OnceLock::new();
+    EXPORTER.get_or_init(|| RwLock::new(None))
+}
+
+/// Installs the process-wide span exporter, returning the previous one.
+pub fn install_span_exporter(exporter: Arc<dyn SpanExporter>) -> Option<Arc<dyn SpanExporter>> {
+    match exporter_slot().write() {
+        Ok(mut slot) => slot.replace(exporter),
+        Err(poisoned) => poisoned.into_inner().replace(exporter),
+    }
+}
+
+fn installed_span_exporter() -> Option<Arc<dyn SpanExporter>> {
+    match exporter_slot().read() {
+        Ok(slot) => slot.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Returns the trace context of the enclosing [`with_trace_context`] scope.
+pub fn current_trace_context() -> Option<TraceContext> {
+    CURRENT_TRACE_CONTEXT.try_with(Clone::clone).ok()
+}
+
+/// Runs `future` with `context` as the task's current trace context.
+pub async fn with_trace_context<F: Future>(context: TraceContext, future: F) -> F::Output {
+    CURRENT_TRACE_CONTEXT.scope(context, future).await
+}
+
+fn random_hex_id(bytes: usize) -> String {
+    static STATE: OnceLock<RandomState> = OnceLock::new();
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let state = STATE.get_or_init(RandomState::new);
+    let width = bytes * 2;
+    let mut id = String::with_capacity(width + 16);
+    while id.len() < width {
+        let sequence = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let value = state.hash_one((sequence, current_unix_nanos()));
+        if value != 0 {
+            let _ = write!(id, "{value:016x}");
+        }
+    }
+    id.truncate(width);
+    id
+}
+
+fn current_unix_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
+fn is_lower_hex(value: &str, len: usize) -> bool {
+    value.len() == len
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+}
+
+fn is_all_zero(value: &str) -> bool {
+    value.bytes().all(|byte| byte == b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct CollectingSpanExporter {
+        spans: Mutex<Vec<SpanRecord>>,
+    }
+
+    impl SpanExporter for CollectingSpanExporter {
+        fn export(&self, span: SpanRecord) {
+            self.spans.lock().expect("spans lock").push(span);
+        }
+    }
+
+    #[test]
+    fn unit_traceparent_round_trips_and_rejects_malformed_values() {
+        let parsed = TraceContext::parse_traceparent(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        )
+        .expect("valid traceparent");
+        assert_eq!(parsed.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(parsed.span_id, "00f067aa0ba902b7");
+        assert!(parsed.sampled);
+        assert_eq!(
+            parsed.to_traceparent(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert!(
+                TraceContext::parse_traceparent(invalid).is_none(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn functional_spans_inherit_task_local_context_and_export_on_end() {
+        let exporter = Arc::new(CollectingSpanExporter::default());
+        install_span_exporter(exporter.clone());
+
+        let remote = TraceContext::parse_traceparent(
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        )
+        .expect("valid traceparent");
+        let mut server = Span::start("POST /v1/responses", SpanKind::Server, Some(&remote));
+        let server_context = server.context().clone();
+        with_trace_context(server_context.clone(), async {
+            let mut child = Span::start_in_current("chat gpt-5.2", SpanKind::Client);
+            child.set_attribute("gen_ai.usage.input_tokens", 42_u64);
+            child.set_status_error("boom");
+            child.end();
+        })
+        .await;
+        server.set_status_ok();
+        drop(server);
+        assert!(current_trace_context().is_none());
+
+        let spans = exporter
+            .spans
+            .lock()
+            .expect("spans lock")
+            .iter()
+            .filter(|span| span.trace_id == remote.trace_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(spans.len(), 2);
+        let child = &spans[0];
+        let server = &spans[1];
+        assert_eq!(server.parent_span_id.as_deref(), Some("b7ad6b7169203331"));
+        assert_eq!(server.kind, SpanKind::Server);
+        assert_eq!(server.status, SpanStatus::Ok);
+        assert_eq!(
+            child.parent_span_id.as_deref(),
+            Some(server_context.span_id.as_str())
+        );
+        assert_eq!(
+            child.attribute("gen_ai.usage.input_tokens"),
+            Some(&SpanAttributeValue::Int(42))
+        );
+        assert_eq!(child.status, SpanStatus::Error("boom".to_string()));
+        assert!(child.end_unix_nanos >= child.start_unix_nanos);
+        assert_eq!(server.span_id.len(), 16);
+    }
+
+    #[test]
+    fn regression_unsampled_parent_suppresses_export_and_root_ids_are_unique() {
+        let root_a = Span::start("root", SpanKind::Internal, None);
+        let root_b = Span::start("root", SpanKind::Internal, None);
+        assert_eq!(root_a.context().trace_id.len(), 32);
+        assert_ne!(root_a.context().trace_id, root_b.context().trace_id);
+        assert_ne!(root_a.context().span_id, root_b.context().span_id);
+
+        let unsampled = TraceContext::parse_traceparent(
+            "00-1af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-00",
+        )
+        .expect("valid traceparent");
+        let child = Span::start("child", SpanKind::Internal, Some(&unsampled));
+        assert!(!child.context().sampled);
+        assert!(child.context().to_traceparent().ends_with("-00"));
+    }
+}
@@ -535,6 +535,9 @@ pub fn provider_key_env_var(provider: Provider) -> &'static str {
         Provider::OpenRouter => "OPENROUTER_API_KEY",
         Provider::Anthropic => "ANTHROPIC_API_KEY",
         Provider::Google => "GEMINI_API_KEY",
+        Provider::Local => "TAU_LOCAL_API_KEY",
+        Provider::Bedrock => "AWS_ACCESS_KEY_ID",
+        Provider::Vertex => "GOOGLE_APPLICATION_CREDENTIALS",
     }
 }
 
@@ -556,6 +559,16 @@ pub fn provider_key_present(cli: &Cli, provider: Provider) -> bool {
         Provider::Google => {
             resolve_api_key(vec![cli.google_api_key.clone(), cli.api_key.clone()]).is_some()
         }
+        // Local inference servers run unauthenticated unless explicitly configured.
+        Provider::Local => true,
+        Provider::Bedrock => {
+            resolve_non_empty_env_var("AWS_ACCESS_KEY_ID").is_some()
+                && resolve_non_empty_env_var("AWS_SECRET_ACCESS_KEY").is_some()
+        }
+        Provider::Vertex => {
+            cli.vertex_service_account_file.is_some()
+                || resolve_non_empty_env_var("GOOGLE_ACCESS_TOKEN").is_some()
+        }
     }
 }
 
@@ -0,0 +1,1967 @@
+//! Discord gateway bridge runtime that receives serenity events and orchestrates agent responses.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serenity::all::{ApplicationId, Client};
+use tau_agent_core::{Agent, AgentConfig, AgentEvent};
+use tau_ai::LlmClient;
+use tokio::sync::{mpsc, watch};
+
+use crate::channel_store::{ChannelArtifactRecord, ChannelLogEntry, ChannelStore};
+use crate::tools::ToolPolicy;
+use crate::{
+    approval_paths_for_state_dir, current_unix_timestamp_ms, discord_principal,
+    evaluate_pairing_access, execute_approvals_command_with_paths_and_actor,
+    execute_canvas_command, pairing_policy_for_state_dir, rbac_policy_path_for_state_dir,
+    run_prompt_with_cancellation, write_text_atomic, CanvasCommandConfig, CanvasEventOrigin,
+    CanvasSessionLinkContext, DiscordRuntimeBootstrapConfig, PairingDecision, PromptRunStatus,
+    RbacDecision, RenderOptions, SessionRuntime, TransportHealthSnapshot,
+};
+use tau_session::SessionStore;
+
+const DISCORD_STATE_SCHEMA_VERSION: u32 = 1;
+const DISCORD_APPROVAL_CUSTOM_ID_PREFIX: &str = "tau:approval:";
+const DISCORD_MAX_APPROVAL_PROMPTS: usize = 5;
+
+#[derive(Clone)]
+/// Runtime configuration for the Discord gateway bridge transport loop.
+pub struct DiscordBridgeRuntimeConfig {
+    pub client: Arc<dyn LlmClient>,
+    pub model: String,
+    pub system_prompt: String,
+    pub max_turns: usize,
+    pub tool_policy: ToolPolicy,
+    pub turn_timeout_ms: u64,
+    pub render_options: RenderOptions,
+    pub session_lock_wait_ms: u64,
+    pub session_lock_stale_ms: u64,
+    pub state_dir: PathBuf,
+    pub bot_token: String,
+    pub bootstrap: DiscordRuntimeBootstrapConfig,
+    pub command_guild_ids: Vec<u64>,
+    pub guild_config_path: PathBuf,
+    pub embed_responses: bool,
+    pub processed_event_cap: usize,
+    pub max_event_age_seconds: u64,
+    pub coalescing_window_ms: u64,
+    pub reconnect_delay: Duration,
+    pub artifact_retention_days: u64,
+}
+
+mod discord_api_client;
+mod discord_command_helpers;
+mod discord_gateway_handler;
+mod discord_guild_config;
+mod discord_render_helpers;
+mod discord_state_store;
+
+use discord_api_client::{DiscordApiClient, DiscordPostedMessage};
+use discord_command_helpers::{
+    discord_command_usage, parse_approval_button_custom_id, parse_discord_command,
+    rbac_action_for_discord_command, render_discord_command_response, render_slash_invocation_text,
+};
+use discord_gateway_handler::DiscordGatewayHandler;
+use discord_guild_config::{authorize_discord_action, DiscordAdmission, DiscordGuildConfigFile};
+use discord_render_helpers::{
+    collect_assistant_reply, extract_pending_approvals, normalize_artifact_retention_days,
+    prompt_status_label, render_discord_artifact_markdown, render_discord_reply_chunks,
+    render_discord_run_error_chunks, render_event_prompt, DiscordOutboundChunk,
+};
+use discord_state_store::{DiscordBridgeStateStore, JsonlEventLog};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DiscordChannelScope {
+    DirectMessage,
+    GuildText,
+    Thread,
+    ForumPost,
+}
+
+impl DiscordChannelScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::DirectMessage => "direct_message",
+            Self::GuildText => "guild_text",
+            Self::Thread => "thread",
+            Self::ForumPost => "forum_post",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct DiscordAttachment {
+    id: String,
+    filename: String,
+    url: String,
+    #[serde(default)]
+    content_type: Option<String>,
+    #[serde(default)]
+    size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Gateway `MESSAGE_CREATE` payload reduced to the fields the bridge consumes.
+struct DiscordInboundMessage {
+    message_id: String,
+    channel_id: String,
+    #[serde(default)]
+    parent_channel_id: Option<String>,
+    #[serde(default)]
+    guild_id: Option<String>,
+    scope: DiscordChannelScope,
+    author_id: String,
+    author_name: String,
+    #[serde(default)]
+    author_is_bot: bool,
+    #[serde(default)]
+    member_role_ids: Vec<String>,
+    #[serde(default)]
+    mentioned_user_ids: Vec<String>,
+    content: String,
+    timestamp_unix_ms: u64,
+    #[serde(default)]
+    attachments: Vec<DiscordAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Native slash-command invocation acknowledged by the gateway handler.
+struct DiscordSlashInvocation {
+    interaction_id: String,
+    #[serde(skip)]
+    interaction_token: String,
+    #[serde(default)]
+    guild_id: Option<String>,
+    channel_id: String,
+    #[serde(default)]
+    parent_channel_id: Option<String>,
+    scope: DiscordChannelScope,
+    user_id: String,
+    user_name: String,
+    #[serde(default)]
+    member_role_ids: Vec<String>,
+    subcommand: String,
+    #[serde(default)]
+    args: String,
+    occurred_unix_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Message-component (button) interaction acknowledged by the gateway handler.
+struct DiscordComponentInvocation {
+    interaction_id: String,
+    #[serde(default)]
+    guild_id: Option<String>,
+    channel_id: String,
+    message_id: String,
+    user_id: String,
+    user_name: String,
+    #[serde(default)]
+    member_role_ids: Vec<String>,
+    custom_id: String,
+    occurred_unix_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+enum DiscordGatewayInput {
+    Ready {
+        bot_user_id: String,
+        bot_user_name: String,
+        guild_count: usize,
+    },
+    Message(DiscordInboundMessage),
+    SlashCommand(DiscordSlashInvocation),
+    Component(DiscordComponentInvocation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum DiscordBridgeEventKind {
+    Mention,
+    DirectMessage,
+    ThreadMessage,
+    ForumPost,
+    SlashCommand,
+}
+
+impl DiscordBridgeEventKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mention => "mention",
+            Self::DirectMessage => "direct_message",
+            Self::ThreadMessage => "thread_message",
+            Self::ForumPost => "forum_post",
+            Self::SlashCommand => "slash_command",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DiscordBridgeEvent {
+    key: String,
+    kind: DiscordBridgeEventKind,
+    scope: DiscordChannelScope,
+    occurred_unix_ms: u64,
+    guild_id: Option<String>,
+    channel_id: String,
+    parent_channel_id: Option<String>,
+    message_id: Option<String>,
+    #[serde(skip)]
+    interaction_token: Option<String>,
+    user_id: String,
+    user_name: String,
+    member_role_ids: Vec<String>,
+    text: String,
+    attachments: Vec<DiscordAttachment>,
+    raw_payload: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiscordCommand {
+    Help,
+    Status,
+    Health,
+    Stop,
+    Artifacts { purge: bool, run_id: Option<String> },
+    ArtifactShow { artifact_id: String },
+    Approvals { args: String },
+    Canvas { args: String },
+    Invalid { message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DiscordApprovalPrompt {
+    request_id: String,
+    rule_id: String,
+    action_kind: String,
+    summary: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct PromptUsageSummary {
+    input_tokens: u64,
+    output_tokens: u64,
+    total_tokens: u64,
+    request_duration_ms: u64,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PromptRunReport {
+    run_id: String,
+    model: String,
+    status: PromptRunStatus,
+    assistant_reply: String,
+    usage: PromptUsageSummary,
+    pending_approvals: Vec<DiscordApprovalPrompt>,
+    artifact: ChannelArtifactRecord,
+}
+
+#[derive(Debug)]
+struct ActiveChannelRun {
+    run_id: String,
+    event_key: String,
+    started_unix_ms: u64,
+    started: Instant,
+    cancel_tx: watch::Sender<bool>,
+    handle: tokio::task::JoinHandle<RunTaskResult>,
+}
+
+#[derive(Debug, Clone)]
+struct DiscordLatestRun {
+    run_id: String,
+    event_key: String,
+    status: String,
+    started_unix_ms: u64,
+    completed_unix_ms: u64,
+    duration_ms: u64,
+}
+
+#[derive(Debug)]
+struct RunTaskResult {
+    channel_id: String,
+    event_key: String,
+    run_id: String,
+    started_unix_ms: u64,
+    completed_unix_ms: u64,
+    duration_ms: u64,
+    status: String,
+    model: String,
+    usage: PromptUsageSummary,
+    delivered_messages: usize,
+    approval_prompts: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PollCycleReport {
+    pub discovered_events: usize,
+    pub queued_events: usize,
+    pub completed_runs: usize,
+    pub skipped_duplicate_events: usize,
+    pub skipped_stale_events: usize,
+    pub failed_events: usize,
+}
+
+/// Runs the Discord gateway bridge and processes incoming messages and interactions.
+pub async fn run_discord_bridge(config: DiscordBridgeRuntimeConfig) -> Result<()> {
+    let mut runtime = DiscordBridgeRuntime::new(config)?;
+    runtime.run().await
+}
+
+struct DiscordBridgeRuntime {
+    config: DiscordBridgeRuntimeConfig,
+    discord_client: DiscordApiClient,
+    state_store: DiscordBridgeStateStore,
+    inbound_log: JsonlEventLog,
+    outbound_log: JsonlEventLog,
+    bot_user_id: String,
+    state_dir: PathBuf,
+    active_runs: HashMap<String, ActiveChannelRun>,
+    latest_runs: HashMap<String, DiscordLatestRun>,
+    channel_queues: HashMap<String, VecDeque<DiscordBridgeEvent>>,
+}
+
+impl DiscordBridgeRuntime {
+    fn new(config: DiscordBridgeRuntimeConfig) -> Result<Self> {
+        config
+            .bootstrap
+            .validate()
+            .map_err(|error| anyhow!("invalid discord bootstrap config: {error}"))?;
+        if config.bot_token.trim().is_empty() {
+            return Err(anyhow!("discord bot token must not be empty"));
+        }
+        let state_dir = config.state_dir.clone();
+        std::fs::create_dir_all(&state_dir)
+            .with_context(|| format!("failed to create {}", state_dir.display()))?;
+
+        let discord_client =
+            DiscordApiClient::from_token(&config.bot_token, config.bootstrap.application_id);
+        let state_store = DiscordBridgeStateStore::load(
+            state_dir.join("state.json"),
+            config.processed_event_cap,
+        )?;
+        let inbound_log = JsonlEventLog::open(state_dir.join("inbound-events.jsonl"))?;
+        let outbound_log = JsonlEventLog::open(state_dir.join("outbound-events.jsonl"))?;
+
+        Ok(Self {
+            config,
+            discord_client,
+            state_store,
+            inbound_log,
+            outbound_log,
+            bot_user_id: String::new(),
+            state_dir,
+            active_runs: HashMap::new(),
+            latest_runs: HashMap::new(),
+            channel_queues: HashMap::new(),
+        })
+    }
+
+    async fn run(&mut self) -> Result<()> {
+        let mut failure_streak = self.state_store.transport_health().failure_streak;
+        loop {
+            let connect_started = Instant::now();
+            let (input_tx, input_rx) = mpsc::unbounded_channel();
+            let handler = DiscordGatewayHandler::new(
+                input_tx,
+                self.config.bootstrap.slash_command_name(),
+                self.config.command_guild_ids.clone(),
+            );
+            let client = match Client::builder(
+                &self.config.bot_token,
+                self.config.bootstrap.gateway_intents(),
+            )
+            .application_id(ApplicationId::new(self.config.bootstrap.application_id))
+            .event_handler(handler)
+            .await
+            {
+                Ok(client) => client,
+                Err(error) => {
+                    failure_streak = failure_streak.saturating_add(1);
+                    self.persist_transport_health(
+                        &PollCycleReport::default(),
+                        connect_started.elapsed().as_millis() as u64,
+                        failure_streak,
+                    )?;
+                    eprintln!("discord bridge failed to build gateway client: {error}");
+                    tokio::select! {
+                        _ = tokio::signal::ctrl_c() => {
+                            println!("discord bridge shutdown requested");
+                            return Ok(());
+                        }
+                        _ = tokio::time::sleep(self.config.reconnect_delay) => {}
+                    }
+                    continue;
+                }
+            };
+
+            self.discord_client = DiscordApiClient::new(client.http.clone());
+            println!("discord bridge gateway client started");
+            match self.run_gateway_session(client, input_rx).await {
+                Ok(true) => {
+                    println!("discord bridge shutdown requested");
+                    return Ok(());
+                }
+                Ok(false) => {
+                    failure_streak = 0;
+                }
+                Err(error) => {
+                    failure_streak = failure_streak.saturating_add(1);
+                    self.persist_transport_health(&PollCycleReport::default(), 0, failure_streak)?;
+                    eprintln!("discord bridge gateway session error: {error}");
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    println!("discord bridge shutdown requested");
+                    return Ok(());
+                }
+                _ = tokio::time::sleep(self.config.reconnect_delay) => {}
+            }
+        }
+    }
+
+    /// Drives one gateway connection; returns `Ok(true)` when shutdown was requested.
+    async fn run_gateway_session(
+        &mut self,
+        mut client: Client,
+        mut inputs: mpsc::UnboundedReceiver<DiscordGatewayInput>,
+    ) -> Result<bool> {
+        let shard_manager = client.shard_manager.clone();
+        let mut gateway_task = tokio::spawn(async move { client.start().await });
+        let outcome = self
+            .process_gateway_inputs(&mut gateway_task, &mut inputs)
+            .await;
+        shard_manager.shutdown_all().await;
+        if !gateway_task.is_finished() {
+            gateway_task.abort();
+        }
+        outcome
+    }
+
+    async fn process_gateway_inputs(
+        &mut self,
+        gateway_task: &mut tokio::task::JoinHandle<serenity::Result<()>>,
+        inputs: &mut mpsc::UnboundedReceiver<DiscordGatewayInput>,
+    ) -> Result<bool> {
+        loop {
+            let cycle_started = Instant::now();
+            let mut report = PollCycleReport::default();
+            self.drain_finished_runs(&mut report).await?;
+            self.try_start_queued_runs(&mut report).await?;
+
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    return Ok(true);
+                }
+                joined = &mut *gateway_task => {
+                    return match joined {
+                        Ok(Ok(())) => Ok(false),
+                        Ok(Err(error)) => Err(anyhow!("discord gateway client stopped: {error}")),
+                        Err(error) => Err(anyhow!("discord gateway task failed: {error}")),
+                    };
+                }
+                maybe_input = inputs.recv() => {
+                    if let Some(input) = maybe_input {
+                        self.handle_gateway_input(input, &mut report).await?;
+                    }
+                }
+                _ = tokio::time::sleep(Duration::from_millis(50)) => {
+                }
+            }
+
+            let cycle_duration_ms = cycle_started.elapsed().as_millis() as u64;
+            self.persist_transport_health(&report, cycle_duration_ms, 0)?;
+
+            if report.discovered_events > 0
+                || report.queued_events > 0
+                || report.completed_runs > 0
+                || report.skipped_duplicate_events > 0
+                || report.skipped_stale_events > 0
+                || report.failed_events > 0
+            {
+                println!(
+                    "discord bridge cycle: discovered={} queued={} completed={} duplicate_skips={} stale_skips={} failed={}",
+                    report.discovered_events,
+                    report.queued_events,
+                    report.completed_runs,
+                    report.skipped_duplicate_events,
+                    report.skipped_stale_events,
+                    report.failed_events,
+                );
+            }
+        }
+    }
+
+    fn persist_transport_health(
+        &mut self,
+        report: &PollCycleReport,
+        cycle_duration_ms: u64,
+        failure_streak: usize,
+    ) -> Result<()> {
+        let snapshot =
+            self.build_transport_health_snapshot(report, cycle_duration_ms, failure_streak);
+        if self.state_store.update_transport_health(snapshot) {
+            self.state_store.save()?;
+        }
+        Ok(())
+    }
+
+    fn build_transport_health_snapshot(
+        &self,
+        report: &PollCycleReport,
+        cycle_duration_ms: u64,
+        failure_streak: usize,
+    ) -> TransportHealthSnapshot {
+        let queue_depth = self
+            .channel_queues
+            .values()
+            .map(VecDeque::len)
+            .sum::<usize>();
+        let processed_events = report
+            .discovered_events
+            .saturating_sub(report.skipped_duplicate_events)
+            .saturating_sub(report.skipped_stale_events);
+        TransportHealthSnapshot {
+            updated_unix_ms: current_unix_timestamp_ms(),
+            cycle_duration_ms,
+            queue_depth,
+            active_runs: self.active_runs.len(),
+            failure_streak,
+            last_cycle_discovered: report.discovered_events,
+            last_cycle_processed: processed_events,
+            last_cycle_completed: report.completed_runs,
+            last_cycle_failed: report.failed_events,
+            last_cycle_duplicates: report.skipped_duplicate_events,
+        }
+    }
+
+    async fn handle_gateway_input(
+        &mut self,
+        input: DiscordGatewayInput,
+        report: &mut PollCycleReport,
+    ) -> Result<()> {
+        match input {
+            DiscordGatewayInput::Ready {
+                bot_user_id,
+                bot_user_name,
+                guild_count,
+            } => {
+                println!(
+                    "discord bridge ready: bot_user_id={} bot_user_name={} guilds={}",
+                    bot_user_id, bot_user_name, guild_count
+                );
+                self.bot_user_id = bot_user_id;
+                Ok(())
+            }
+            DiscordGatewayInput::Message(message) => {
+                report.discovered_events = report.discovered_events.saturating_add(1);
+                let Some((event, mentioned)) =
+                    normalize_inbound_message(&message, &self.bot_user_id)
+                else {
+                    return Ok(());
+                };
+                self.handle_event(event, mentioned, report).await
+            }
+            DiscordGatewayInput::SlashCommand(invocation) => {
+                report.discovered_events = report.discovered_events.saturating_add(1);
+                let event =
+                    normalize_slash_invocation(&invocation, &self.config.bootstrap.command_prefix);
+                self.handle_event(event, true, report).await
+            }
+            DiscordGatewayInput::Component(invocation) => {
+                report.discovered_events = report.discovered_events.saturating_add(1);
+                self.handle_component(invocation, report).await
+            }
+        }
+    }
+
+    async fn handle_event(
+        &mut self,
+        event: DiscordBridgeEvent,
+        mentioned: bool,
+        report: &mut PollCycleReport,
+    ) -> Result<()> {
+        let now_unix_ms = current_unix_timestamp_ms();
+
+        if self.state_store.contains(&event.key) {
+            report.skipped_duplicate_events = report.skipped_duplicate_events.saturating_add(1);
+            return Ok(());
+        }
+
+        if event_is_stale(&event, self.config.max_event_age_seconds, now_unix_ms) {
+            if self.state_store.mark_processed(&event.key) {
+                self.state_store.save()?;
+            }
+            report.skipped_stale_events = report.skipped_stale_events.saturating_add(1);
+            return Ok(());
+        }
+
+        let guild_config = match DiscordGuildConfigFile::load(&self.config.guild_config_path) {
+            Ok(guild_config) => guild_config,
+            Err(error) => {
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": now_unix_ms,
+                    "event_key": event.key,
+                    "channel": event.channel_id,
+                    "command": "guild-config",
+                    "status": "error",
+                    "reason_code": "guild_config_error",
+                    "error": error.to_string(),
+                }))?;
+                if self.state_store.mark_processed(&event.key) {
+                    self.state_store.save()?;
+                }
+                self.reject_interaction(&event, "Tau guild configuration is invalid.")
+                    .await;
+                report.failed_events = report.failed_events.saturating_add(1);
+                return Ok(());
+            }
+        };
+        let session_exists = ChannelStore::open(
+            &self.state_dir.join("channel-store"),
+            "discord",
+            &event.channel_id,
+        )?
+        .session_path()
+        .exists();
+        if let DiscordAdmission::Ignore { reason_code } =
+            guild_config.admit_event(&event, mentioned, session_exists)
+        {
+            if event.interaction_token.is_some() {
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": now_unix_ms,
+                    "event_key": event.key,
+                    "channel": event.channel_id,
+                    "command": "admission",
+                    "status": "ignored",
+                    "reason_code": reason_code,
+                    "actor_id": event.user_id,
+                }))?;
+                if self.state_store.mark_processed(&event.key) {
+                    self.state_store.save()?;
+                }
+                self.reject_interaction(
+                    &event,
+                    &format!("Tau is not enabled here (reason_code `{reason_code}`)."),
+                )
+                .await;
+            }
+            return Ok(());
+        }
+
+        let policy_channel = format!("discord:{}", event.channel_id);
+        let pairing_policy = pairing_policy_for_state_dir(&self.config.state_dir);
+        let pairing_decision = evaluate_pairing_access(
+            &pairing_policy,
+            &policy_channel,
+            &event.user_id,
+            now_unix_ms,
+        )?;
+        let pairing_status = if matches!(pairing_decision, PairingDecision::Allow { .. }) {
+            "allow"
+        } else {
+            "deny"
+        };
+        let pairing_reason_code = pairing_decision.reason_code().to_string();
+
+        self.inbound_log.append(&json!({
+            "timestamp_unix_ms": now_unix_ms,
+            "event_key": event.key,
+            "kind": event.kind.as_str(),
+            "scope": event.scope.as_str(),
+            "guild": event.guild_id,
+            "channel": event.channel_id,
+            "parent_channel": event.parent_channel_id,
+            "pairing": {
+                "decision": pairing_status,
+                "reason_code": pairing_reason_code,
+                "channel": policy_channel,
+                "actor_id": event.user_id,
+            },
+            "payload": event.raw_payload,
+        }))?;
+        ChannelStore::open(
+            &self.state_dir.join("channel-store"),
+            "discord",
+            &event.channel_id,
+        )?
+        .append_log_entry(&ChannelLogEntry {
+            timestamp_unix_ms: now_unix_ms,
+            direction: "inbound".to_string(),
+            event_key: Some(event.key.clone()),
+            source: "discord".to_string(),
+            payload: json!({
+                "kind": event.kind.as_str(),
+                "scope": event.scope.as_str(),
+                "message_id": event.message_id,
+                "user_id": event.user_id,
+                "text": event.text,
+                "pairing": {
+                    "decision": pairing_status,
+                    "reason_code": pairing_reason_code,
+                    "channel": policy_channel,
+                },
+            }),
+        })?;
+
+        if let PairingDecision::Deny { reason_code } = pairing_decision {
+            self.outbound_log.append(&json!({
+                "timestamp_unix_ms": now_unix_ms,
+                "event_key": event.key,
+                "channel": event.channel_id,
+                "command": "authorization",
+                "status": "denied",
+                "reason_code": reason_code,
+                "policy_channel": policy_channel,
+                "actor_id": event.user_id,
+            }))?;
+            if self.state_store.mark_processed(&event.key) {
+                self.state_store.save()?;
+            }
+            self.reject_interaction(
+                &event,
+                &format!("Tau denied this request (reason_code `{reason_code}`)."),
+            )
+            .await;
+            eprintln!(
+                "discord bridge event denied: channel={} key={} actor={} reason_code={}",
+                event.channel_id, event.key, event.user_id, reason_code
+            );
+            return Ok(());
+        }
+
+        let discord_command = parse_discord_command(
+            &event,
+            &self.bot_user_id,
+            &self.config.bootstrap.command_prefix,
+        );
+        let principal_candidates = guild_config.principal_candidates(
+            event.guild_id.as_deref(),
+            &event.user_id,
+            &event.member_role_ids,
+        );
+        let rbac_action = rbac_action_for_discord_command(discord_command.as_ref());
+        let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
+        let authorization = match authorize_discord_action(
+            &principal_candidates,
+            &rbac_action,
+            rbac_policy_path.as_path(),
+        ) {
+            Ok(authorization) => authorization,
+            Err(error) => {
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": now_unix_ms,
+                    "event_key": event.key,
+                    "channel": event.channel_id,
+                    "command": "rbac-authorization",
+                    "status": "error",
+                    "reason_code": "rbac_policy_error",
+                    "principals": principal_candidates,
+                    "action": rbac_action,
+                    "actor_id": event.user_id,
+                    "error": error.to_string(),
+                }))?;
+                if self.state_store.mark_processed(&event.key) {
+                    self.state_store.save()?;
+                }
+                self.reject_interaction(&event, "Tau could not evaluate the RBAC policy.")
+                    .await;
+                report.failed_events = report.failed_events.saturating_add(1);
+                return Ok(());
+            }
+        };
+        if let RbacDecision::Deny {
+            reason_code,
+            matched_role,
+            matched_pattern,
+        } = &authorization.decision
+        {
+            self.outbound_log.append(&json!({
+                "timestamp_unix_ms": now_unix_ms,
+                "event_key": event.key,
+                "channel": event.channel_id,
+                "command": "rbac-authorization",
+                "status": "denied",
+                "reason_code": reason_code,
+                "matched_role": matched_role,
+                "matched_pattern": matched_pattern,
+                "principal": authorization.principal,
+                "principals": authorization.candidates,
+                "action": rbac_action,
+                "actor_id": event.user_id,
+            }))?;
+            if self.state_store.mark_processed(&event.key) {
+                self.state_store.save()?;
+            }
+            self.reject_interaction(
+                &event,
+                &format!("Tau denied this request (reason_code `{reason_code}`)."),
+            )
+            .await;
+            return Ok(());
+        }
+
+        if self.state_store.mark_processed(&event.key) {
+            self.state_store.save()?;
+        }
+
+        if let Some(command) = discord_command {
+            self.handle_discord_command(&event, command, &authorization.principal, report)
+                .await?;
+            return Ok(());
+        }
+
+        self.channel_queues
+            .entry(event.channel_id.clone())
+            .or_default()
+            .push_back(event);
+        report.queued_events = report.queued_events.saturating_add(1);
+
+        Ok(())
+    }
+
+    async fn reject_interaction(&self, event: &DiscordBridgeEvent, message: &str) {
+        if event.interaction_token.is_none() {
+            return;
+        }
+        let chunk = DiscordOutboundChunk::text(message);
+        if let Err(error) = self.discord_client.respond(event, &chunk).await {
+            eprintln!(
+                "discord bridge failed to answer interaction: key={} error={error}",
+                event.key
+            );
+        }
+    }
+
+    async fn try_start_queued_runs(&mut self, report: &mut PollCycleReport) -> Result<()> {
+        let channels = self.channel_queues.keys().cloned().collect::<Vec<_>>();
+
+        for channel in channels {
+            if self.active_runs.contains_key(&channel) {
+                continue;
+            }
+            let now_unix_ms = current_unix_timestamp_ms();
+            let Some(event) = self.channel_queues.get_mut(&channel).and_then(|queue| {
+                dequeue_coalesced_event_for_run(
+                    queue,
+                    now_unix_ms,
+                    self.config.coalescing_window_ms,
+                )
+            }) else {
+                continue;
+            };
+
+            let guild_config =
+                DiscordGuildConfigFile::load(&self.config.guild_config_path).unwrap_or_default();
+            let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
+            let principal = authorize_discord_action(
+                &guild_config.principal_candidates(
+                    event.guild_id.as_deref(),
+                    &event.user_id,
+                    &event.member_role_ids,
+                ),
+                &rbac_action_for_discord_command(None),
+                rbac_policy_path.as_path(),
+            )
+            .ok()
+            .filter(|authorization| authorization.decision.is_allowed())
+            .map(|authorization| authorization.principal)
+            .unwrap_or_else(|| discord_principal(&event.user_id));
+
+            let run_id = format!(
+                "discord-{}-{}",
+                event.channel_id,
+                current_unix_timestamp_ms()
+            );
+            let working_message = self
+                .discord_client
+                .respond(
+                    &event,
+                    &DiscordOutboundChunk::text(&format!("Tau is working on run {run_id}...")),
+                )
+                .await?;
+
+            let (cancel_tx, cancel_rx) = watch::channel(false);
+            let started_unix_ms = current_unix_timestamp_ms();
+            let task_params = DiscordRunTaskParams {
+                discord_client: self.discord_client.clone(),
+                config: self.config.clone(),
+                state_dir: self.state_dir.clone(),
+                event: event.clone(),
+                run_id: run_id.clone(),
+                working_message,
+                cancel_rx,
+                bot_user_id: self.bot_user_id.clone(),
+                principal,
+                started_unix_ms,
+            };
+            let handle = tokio::spawn(async move { execute_channel_run_task(task_params).await });
+
+            self.active_runs.insert(
+                channel,
+                ActiveChannelRun {
+                    run_id: run_id.clone(),
+                    event_key: event.key.clone(),
+                    started_unix_ms,
+                    started: Instant::now(),
+                    cancel_tx,
+                    handle,
+                },
+            );
+            report.queued_events = report.queued_events.saturating_add(1);
+        }
+
+        Ok(())
+    }
+
+    async fn handle_discord_command(
+        &mut self,
+        event: &DiscordBridgeEvent,
+        command: DiscordCommand,
+        principal: &str,
+        report: &mut PollCycleReport,
+    ) -> Result<()> {
+        let now_unix_ms = current_unix_timestamp_ms();
+        let (message, command_name, status, extra) = match command {
+            DiscordCommand::Help => (
+                discord_command_usage(&self.config.bootstrap.command_prefix),
+                "help",
+                "reported",
+                None,
+            ),
+            DiscordCommand::Status => (
+                self.render_channel_status(&event.channel_id),
+                "status",
+                "reported",
+                None,
+            ),
+            DiscordCommand::Health => (
+                self.render_channel_health(&event.channel_id),
+                "health",
+                "reported",
+                None,
+            ),
+            DiscordCommand::Stop => {
+                if let Some(active) = self.active_runs.get(&event.channel_id) {
+                    if *active.cancel_tx.borrow() {
+                        (
+                            format!(
+                                "Stop has already been requested for run `{}`.",
+                                active.run_id
+                            ),
+                            "stop",
+                            "acknowledged",
+                            Some(json!({"run_id": active.run_id})),
+                        )
+                    } else {
+                        let _ = active.cancel_tx.send(true);
+                        (
+                            format!(
+                                "Cancellation requested for run `{}` (event `{}`).",
+                                active.run_id, active.event_key
+                            ),
+                            "stop",
+                            "acknowledged",
+                            Some(json!({"run_id": active.run_id, "event_key": active.event_key})),
+                        )
+                    }
+                } else {
+                    (
+                        "No active run for this session. Current state is idle.".to_string(),
+                        "stop",
+                        "acknowledged",
+                        None,
+                    )
+                }
+            }
+            DiscordCommand::Artifacts { purge, run_id } => {
+                if purge {
+                    (
+                        self.render_channel_artifact_purge(&event.channel_id)?,
+                        "artifacts-purge",
+                        "reported",
+                        None,
+                    )
+                } else {
+                    (
+                        self.render_channel_artifacts(&event.channel_id, run_id.as_deref())?,
+                        "artifacts",
+                        "reported",
+                        run_id.as_ref().map(|value| json!({"run_id": value})),
+                    )
+                }
+            }
+            DiscordCommand::ArtifactShow { artifact_id } => (
+                self.render_channel_artifact_show(&event.channel_id, &artifact_id)?,
+                "artifacts-show",
+                "reported",
+                Some(json!({"artifact_id": artifact_id})),
+            ),
+            DiscordCommand::Approvals { args } => {
+                let (policy_path, store_path) = approval_paths_for_state_dir(&self.state_dir);
+                let output = execute_approvals_command_with_paths_and_actor(
+                    &args,
+                    &policy_path,
+                    &store_path,
+                    Some(principal),
+                );
+                let status = if output.trim_start().starts_with("approvals error:") {
+                    "failed"
+                } else {
+                    "reported"
+                };
+                (
+                    output,
+                    "approvals",
+                    status,
+                    Some(json!({"approvals_args": args, "principal": principal})),
+                )
+            }
+            DiscordCommand::Canvas { args } => {
+                let channel_store = ChannelStore::open(
+                    &self.state_dir.join("channel-store"),
+                    "discord",
+                    &event.channel_id,
+                )?;
+                let session_path = channel_store.session_path();
+                let session_head_id = SessionStore::load(&session_path)
+                    .ok()
+                    .and_then(|store| store.head_id());
+                (
+                    execute_canvas_command(
+                        &args,
+                        &CanvasCommandConfig {
+                            canvas_root: self.state_dir.join("canvas"),
+                            channel_store_root: self.state_dir.join("channel-store"),
+                            principal: principal.to_string(),
+                            origin: CanvasEventOrigin {
+                                transport: "discord".to_string(),
+                                channel: Some(event.channel_id.clone()),
+                                source_event_key: Some(event.key.clone()),
+                                source_unix_ms: Some(event.occurred_unix_ms),
+                            },
+                            session_link: Some(CanvasSessionLinkContext {
+                                session_path,
+                                session_head_id,
+                            }),
+                        },
+                    ),
+                    "canvas",
+                    "reported",
+                    Some(json!({"canvas_args": args})),
+                )
+            }
+            DiscordCommand::Invalid { message } => (message, "invalid", "usage_reported", None),
+        };
+        let response_message =
+            render_discord_command_response(event, command_name, status, &message);
+
+        let posted = self
+            .discord_client
+            .respond(event, &DiscordOutboundChunk::text(&response_message))
+            .await?;
+        let mut payload = json!({
+            "timestamp_unix_ms": now_unix_ms,
+            "event_key": event.key,
+            "channel_id": event.channel_id,
+            "command": command_name,
+            "status": status,
+            "posted_message_id": posted.message_id.clone(),
+        });
+        if let Some(extra) = extra {
+            payload["details"] = extra;
+        }
+        self.outbound_log.append(&payload)?;
+        ChannelStore::open(
+            &self.state_dir.join("channel-store"),
+            "discord",
+            &event.channel_id,
+        )?
+        .append_log_entry(&ChannelLogEntry {
+            timestamp_unix_ms: now_unix_ms,
+            direction: "outbound".to_string(),
+            event_key: Some(event.key.clone()),
+            source: "discord".to_string(),
+            payload: json!({
+                "kind": "command_response",
+                "command": command_name,
+                "status": status,
+                "posted_message_id": posted.message_id,
+                "body": response_message,
+            }),
+        })?;
+        report.completed_runs = report.completed_runs.saturating_add(1);
+        Ok(())
+    }
+
+    async fn handle_component(
+        &mut self,
+        invocation: DiscordComponentInvocation,
+        report: &mut PollCycleReport,
+    ) -> Result<()> {
+        let now_unix_ms = current_unix_timestamp_ms();
+        let key = format!("interaction:{}", invocation.interaction_id);
+        if self.state_store.contains(&key) {
+            report.skipped_duplicate_events = report.skipped_duplicate_events.saturating_add(1);
+            return Ok(());
+        }
+        if self.state_store.mark_processed(&key) {
+            self.state_store.save()?;
+        }
+        let Some((decision, request_id)) = parse_approval_button_custom_id(&invocation.custom_id)
+        else {
+            return Ok(());
+        };
+
+        let guild_config =
+            DiscordGuildConfigFile::load(&self.config.guild_config_path).unwrap_or_default();
+        let principal_candidates = guild_config.principal_candidates(
+            invocation.guild_id.as_deref(),
+            &invocation.user_id,
+            &invocation.member_role_ids,
+        );
+        let rbac_action = rbac_action_for_discord_command(Some(&DiscordCommand::Approvals {
+            args: String::new(),
+        }));
+        let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
+        let authorization = authorize_discord_action(
+            &principal_candidates,
+            &rbac_action,
+            rbac_policy_path.as_path(),
+        );
+
+        let (status, reason_code, principal, body) = match authorization {
+            Ok(authorization) if authorization.decision.is_allowed() => {
+                let (policy_path, store_path) = approval_paths_for_state_dir(&self.state_dir);
+                let output = execute_approvals_command_with_paths_and_actor(
+                    &format!("{} {}", decision.as_str(), request_id),
+                    &policy_path,
+                    &store_path,
+                    Some(authorization.principal.as_str()),
+                );
+                let failed = output.trim_start().starts_with("approvals error:");
+                (
+                    if failed {
+                        "failed"
+                    } else {
+                        decision.status_label()
+                    },
+                    authorization.decision.reason_code().to_string(),
+                    authorization.principal,
+                    output,
+                )
+            }
+            Ok(authorization) => (
+                "denied",
+                authorization.decision.reason_code().to_string(),
+                authorization.principal,
+                format!(
+                    "<@{}> is not allowed to decide approval `{}`.",
+                    invocation.user_id, request_id
+                ),
+            ),
+            Err(error) => (
+                "failed",
+                "rbac_policy_error".to_string(),
+                discord_principal(&invocation.user_id),
+                format!("approvals error: {error}"),
+            ),
+        };
+
+        let resolved = matches!(status, "approved" | "rejected");
+        let content = format!(
+            "Approval `{}` {} by <@{}>.\n{}",
+            request_id, status, invocation.user_id, body
+        );
+        let delivery = if resolved {
+            self.discord_client
+                .resolve_approval_message(&invocation.channel_id, &invocation.message_id, &content)
+                .await
+        } else {
+            self.discord_client
+                .post_message(
+                    &invocation.channel_id,
+                    Some(&invocation.message_id),
+                    &DiscordOutboundChunk::text(&content),
+                    Vec::new(),
+                )
+                .await
+                .map(|_| ())
+        };
+        if let Err(error) = &delivery {
+            report.failed_events = report.failed_events.saturating_add(1);
+            eprintln!(
+                "discord bridge failed to publish approval decision: request_id={} error={error}",
+                request_id
+            );
+        }
+
+        self.outbound_log.append(&json!({
+            "timestamp_unix_ms": now_unix_ms,
+            "event_key": key,
+            "channel": invocation.channel_id,
+            "command": "approval-button",
+            "status": status,
+            "reason_code": reason_code,
+            "request_id": request_id,
+            "decision": decision.as_str(),
+            "principal": principal,
+            "principals": principal_candidates,
+            "actor_id": invocation.user_id,
+            "message_id": invocation.message_id,
+            "error": delivery.err().map(|error| error.to_string()),
+        }))?;
+        ChannelStore::open(
+            &self.state_dir.join("channel-store"),
+            "discord",
+            &invocation.channel_id,
+        )?
+        .append_log_entry(&ChannelLogEntry {
+            timestamp_unix_ms: now_unix_ms,
+            direction: "inbound".to_string(),
+            event_key: Some(key),
+            source: "discord".to_string(),
+            payload: json!({
+                "kind": "approval_button",
+                "request_id": request_id,
+                "decision": decision.as_str(),
+                "status": status,
+                "user_id": invocation.user_id,
+                "principal": principal,
+            }),
+        })?;
+        report.completed_runs = report.completed_runs.saturating_add(1);
+        Ok(())
+    }
+
+    fn render_channel_status(&self, channel_id: &str) -> String {
+        let active = self.active_runs.get(channel_id);
+        let latest = self.latest_runs.get(channel_id);
+        let state = if active.is_some() { "running" } else { "idle" };
+        let mut lines = vec![format!("Tau status for session {channel_id}: {state}")];
+        if let Some(active) = active {
+            lines.push(format!("active_run_id: {}", active.run_id));
+            lines.push(format!("active_event_key: {}", active.event_key));
+            lines.push(format!(
+                "active_elapsed_ms: {}",
+                active.started.elapsed().as_millis()
+            ));
+            lines.push(format!(
+                "active_started_unix_ms: {}",
+                active.started_unix_ms
+            ));
+            lines.push(format!(
+                "cancellation_requested: {}",
+                if *active.cancel_tx.borrow() {
+                    "true"
+                } else {
+                    "false"
+                }
+            ));
+        } else {
+            lines.push("active_run_id: none".to_string());
+        }
+
+        if let Some(latest) = latest {
+            lines.push(format!("latest_run_id: {}", latest.run_id));
+            lines.push(format!("latest_event_key: {}", latest.event_key));
+            lines.push(format!("latest_status: {}", latest.status));
+            lines.push(format!(
+                "latest_started_unix_ms: {}",
+                latest.started_unix_ms
+            ));
+            lines.push(format!(
+                "latest_completed_unix_ms: {}",
+                latest.completed_unix_ms
+            ));
+            lines.push(format!("latest_duration_ms: {}", latest.duration_ms));
+        } else {
+            lines.push("latest_run_id: none".to_string());
+        }
+
+        lines.extend(self.state_store.transport_health().status_lines());
+
+        lines.join("\n")
+    }
+
+    fn render_channel_health(&self, channel_id: &str) -> String {
+        let active = self.active_runs.get(channel_id);
+        let runtime_state = if active.is_some() { "running" } else { "idle" };
+        let health = self.state_store.transport_health();
+        let classification = health.classify();
+        let mut lines = vec![format!(
+            "Tau health for session {}: {}",
+            channel_id,
+            classification.state.as_str()
+        )];
+        lines.push(format!("runtime_state: {runtime_state}"));
+        if let Some(active) = active {
+            lines.push(format!("active_run_id: {}", active.run_id));
+            lines.push(format!("active_event_key: {}", active.event_key));
+            lines.push(format!(
+                "active_elapsed_ms: {}",
+                active.started.elapsed().as_millis()
+            ));
+        } else {
+            lines.push("active_run_id: none".to_string());
+        }
+        lines.extend(health.health_detail_lines());
+        lines.join("\n")
+    }
+
+    fn render_channel_artifacts(
+        &self,
+        channel_id: &str,
+        run_id_filter: Option<&str>,
+    ) -> Result<String> {
+        let store =
+            ChannelStore::open(&self.state_dir.join("channel-store"), "discord", channel_id)?;
+        let loaded = store.load_artifact_records_tolerant()?;
+        let mut active = store.list_active_artifacts(current_unix_timestamp_ms())?;
+        if let Some(run_id_filter) = run_id_filter {
+            active.retain(|artifact| artifact.run_id == run_id_filter);
+        }
+        active.sort_by(|left, right| {
+            right
+                .created_unix_ms
+                .cmp(&left.created_unix_ms)
+                .then_with(|| left.id.cmp(&right.id))
+        });
+
+        let mut lines = vec![if let Some(run_id_filter) = run_id_filter {
+            format!(
+                "Tau artifacts for session {} run_id `{}`: active={}",
+                channel_id,
+                run_id_filter,
+                active.len()
+            )
+        } else {
+            format!(
+                "Tau artifacts for session {}: active={}",
+                channel_id,
+                active.len()
+            )
+        }];
+        if active.is_empty() {
+            if let Some(run_id_filter) = run_id_filter {
+                lines.push(format!("none for run_id `{}`", run_id_filter));
+            } else {
+                lines.push("none".to_string());
+            }
+        } else {
+            let max_rows = 10_usize;
+            for artifact in active.iter().take(max_rows) {
+                lines.push(format!(
+                    "- id `{}` type `{}` bytes `{}` created_unix_ms `{}` expires_unix_ms `{}` path `{}`",
+                    artifact.id,
+                    artifact.artifact_type,
+                    artifact.bytes,
+                    artifact.created_unix_ms,
+                    artifact
+                        .expires_unix_ms
+                        .map(|value| value.to_string())
+                        .unwrap_or_else(|| "none".to_string()),
+                    artifact.relative_path,
+                ));
+            }
+            if active.len() > max_rows {
+                lines.push(format!(
+                    "... {} additional artifacts omitted",
+                    active.len() - max_rows
+                ));
+            }
+        }
+        if loaded.invalid_lines > 0 {
+            lines.push(format!(
+                "index_invalid_lines: {} (ignored)",
+                loaded.invalid_lines
+            ));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn render_channel_artifact_purge(&self, channel_id: &str) -> Result<String> {
+        let now_unix_ms = current_unix_timestamp_ms();
+        let store =
+            ChannelStore::open(&self.state_dir.join("channel-store"), "discord", channel_id)?;
+        let purge = store.purge_expired_artifacts(now_unix_ms)?;
+        let active = store.list_active_artifacts(now_unix_ms)?;
+        Ok(format!(
+            "Tau artifact purge for session {}: expired_removed={} invalid_removed={} active_remaining={}",
+            channel_id,
+            purge.expired_removed,
+            purge.invalid_removed,
+            active.len()
+        ))
+    }
+
+    fn render_channel_artifact_show(&self, channel_id: &str, artifact_id: &str) -> Result<String> {
+        let store =
+            ChannelStore::open(&self.state_dir.join("channel-store"), "discord", channel_id)?;
+        let loaded = store.load_artifact_records_tolerant()?;
+        let now_unix_ms = current_unix_timestamp_ms();
+        let artifact = loaded
+            .records
+            .iter()
+            .find(|record| record.id == artifact_id);
+        let mut lines = Vec::new();
+        match artifact {
+            Some(record) => {
+                let expired = record
+                    .expires_unix_ms
+                    .map(|expires_unix_ms| expires_unix_ms <= now_unix_ms)
+                    .unwrap_or(false);
+                lines.push(format!(
+                    "Tau artifact for session {} id `{}`: state={}",
+                    channel_id,
+                    artifact_id,
+                    if expired { "expired" } else { "active" }
+                ));
+                lines.push(format!("run_id: {}", record.run_id));
+                lines.push(format!("artifact_type: {}", record.artifact_type));
+                lines.push(format!("visibility: {}", record.visibility));
+                lines.push(format!("bytes: {}", record.bytes));
+                lines.push(format!("created_unix_ms: {}", record.created_unix_ms));
+                lines.push(format!(
+                    "expires_unix_ms: {}",
+                    record
+                        .expires_unix_ms
+                        .map(|value| value.to_string())
+                        .unwrap_or_else(|| "none".to_string())
+                ));
+                lines.push(format!("checksum: {}", record.checksum_sha256));
+                lines.push(format!("path: {}", record.relative_path));
+                if expired {
+                    lines.push(format!(
+                        "artifact is expired and may be removed by `{} artifacts purge`.",
+                        self.config.bootstrap.command_prefix.trim()
+                    ));
+                }
+            }
+            None => lines.push(format!(
+                "Tau artifact for session {} id `{}`: not found",
+                channel_id, artifact_id
+            )),
+        }
+        if loaded.invalid_lines > 0 {
+            lines.push(format!(
+                "index_invalid_lines: {} (ignored)",
+                loaded.invalid_lines
+            ));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    async fn drain_finished_runs(&mut self, report: &mut PollCycleReport) -> Result<()> {
+        let finished_channels = self
+            .active_runs
+            .iter()
+            .filter_map(|(channel, run)| run.handle.is_finished().then_some(channel.clone()))
+            .collect::<Vec<_>>();
+
+        for channel in finished_channels {
+            let Some(active) = self.active_runs.remove(&channel) else {
+                continue;
+            };
+            match active.handle.await {
+                Ok(result) => {
+                    self.latest_runs.insert(
+                        channel.clone(),
+                        DiscordLatestRun {
+                            run_id: result.run_id.clone(),
+                            event_key: result.event_key.clone(),
+                            status: result.status.clone(),
+                            started_unix_ms: result.started_unix_ms,
+                            completed_unix_ms: result.completed_unix_ms,
+                            duration_ms: result.duration_ms,
+                        },
+                    );
+                    self.outbound_log.append(&json!({
+                        "timestamp_unix_ms": current_unix_timestamp_ms(),
+                        "event_key": result.event_key,
+                        "channel": result.channel_id,
+                        "run_id": result.run_id,
+                        "status": result.status,
+                        "started_unix_ms": result.started_unix_ms,
+                        "completed_unix_ms": result.completed_unix_ms,
+                        "duration_ms": result.duration_ms,
+                        "model": result.model,
+                        "delivered_messages": result.delivered_messages,
+                        "approval_prompts": result.approval_prompts,
+                        "usage": {
+                            "input_tokens": result.usage.input_tokens,
+                            "output_tokens": result.usage.output_tokens,
+                            "total_tokens": result.usage.total_tokens,
+                            "request_duration_ms": result.usage.request_duration_ms,
+                            "finish_reason": result.usage.finish_reason,
+                        },
+                        "error": result.error,
+                    }))?;
+                    report.completed_runs = report.completed_runs.saturating_add(1);
+                }
+                Err(error) => {
+                    report.failed_events = report.failed_events.saturating_add(1);
+                    eprintln!("discord bridge run task join error: {error}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn should_coalesce_events(
+    previous: &DiscordBridgeEvent,
+    current: &DiscordBridgeEvent,
+    coalescing_window_ms: u64,
+) -> bool {
+    if coalescing_window_ms == 0 {
+        return false;
+    }
+    if previous.interaction_token.is_some() || current.interaction_token.is_some() {
+        return false;
+    }
+    if previous.user_id.trim() != current.user_id.trim() {
+        return false;
+    }
+    if current.occurred_unix_ms < previous.occurred_unix_ms {
+        return false;
+    }
+    current
+        .occurred_unix_ms
+        .saturating_sub(previous.occurred_unix_ms)
+        <= coalescing_window_ms
+}
+
+fn coalesced_batch_len(queue: &VecDeque<DiscordBridgeEvent>, coalescing_window_ms: u64) -> usize {
+    let Some(mut previous) = queue.front() else {
+        return 0;
+    };
+    if coalescing_window_ms == 0 {
+        return 1;
+    }
+    let mut len = 1;
+    while let Some(current) = queue.get(len) {
+        if !should_coalesce_events(previous, current, coalescing_window_ms) {
+            break;
+        }
+        len = len.saturating_add(1);
+        previous = current;
+    }
+    len
+}
+
+fn merge_coalesced_event(target: &mut DiscordBridgeEvent, source: DiscordBridgeEvent) {
+    if !source.text.is_empty() {
+        if !target.text.is_empty() {
+            target.text.push('\n');
+        }
+        target.text.push_str(&source.text);
+    }
+    target.attachments.extend(source.attachments);
+    target.occurred_unix_ms = source.occurred_unix_ms;
+    if source.message_id.is_some() {
+        target.message_id = source.message_id;
+    }
+}
+
+fn dequeue_coalesced_event_for_run(
+    queue: &mut VecDeque<DiscordBridgeEvent>,
+    now_unix_ms: u64,
+    coalescing_window_ms: u64,
+) -> Option<DiscordBridgeEvent> {
+    queue.front()?;
+
+    let batch_len = coalesced_batch_len(queue, coalescing_window_ms);
+    if batch_len == 0 {
+        return None;
+    }
+    let last = queue.get(batch_len.saturating_sub(1))?;
+    if last.interaction_token.is_none()
+        && now_unix_ms.saturating_sub(last.occurred_unix_ms) < coalescing_window_ms
+    {
+        return None;
+    }
+
+    let mut event = queue.pop_front()?;
+    for _ in 1..batch_len {
+        let Some(next) = queue.pop_front() else {
+            break;
+        };
+        merge_coalesced_event(&mut event, next);
+    }
+    Some(event)
+}
+
+struct DiscordRunTaskParams {
+    discord_client: DiscordApiClient,
+    config: DiscordBridgeRuntimeConfig,
+    state_dir: PathBuf,
+    event: DiscordBridgeEvent,
+    run_id: String,
+    working_message: DiscordPostedMessage,
+    cancel_rx: watch::Receiver<bool>,
+    bot_user_id: String,
+    principal: String,
+    started_unix_ms: u64,
+}
+
+async fn execute_channel_run_task(params: DiscordRunTaskParams) -> RunTaskResult {
+    let DiscordRunTaskParams {
+        discord_client,
+        config,
+        state_dir,
+        event,
+        run_id,
+        working_message,
+        cancel_rx,
+        bot_user_id,
+        principal,
+        started_unix_ms,
+    } = params;
+
+    let started = Instant::now();
+    let typing = discord_client.start_typing(&event.channel_id);
+    let run_result = run_prompt_for_event(
+        &config,
+        &state_dir,
+        &event,
+        &run_id,
+        cancel_rx,
+        &bot_user_id,
+        &principal,
+    )
+    .await;
+    if let Some(typing) = typing {
+        typing.stop();
+    }
+
+    let completed_unix_ms = current_unix_timestamp_ms();
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let (status, usage, chunks, approvals) = match run_result {
+        Ok(run) => (
+            prompt_status_label(run.status).to_string(),
+            run.usage.clone(),
+            render_discord_reply_chunks(&run, config.embed_responses),
+            run.pending_approvals.clone(),
+        ),
+        Err(error) => (
+            "failed".to_string(),
+            PromptUsageSummary::default(),
+            render_discord_run_error_chunks(&event, &run_id, &error, config.embed_responses),
+            Vec::new(),
+        ),
+    };
+
+    let delivery = discord_client
+        .deliver_reply(
+            &working_message,
+            &chunks,
+            &approvals,
+            config.embed_responses,
+        )
+        .await;
+    let (delivered_messages, approval_prompts, error) = match delivery {
+        Ok(report) => (report.messages, report.approval_prompts, None),
+        Err(error) => (0, 0, Some(error.to_string())),
+    };
+
+    RunTaskResult {
+        channel_id: event.channel_id,
+        event_key: event.key,
+        run_id,
+        started_unix_ms,
+        completed_unix_ms,
+        duration_ms,
+        status,
+        model: config.model,
+        usage,
+        delivered_messages,
+        approval_prompts,
+        error,
+    }
+}
+
+async fn run_prompt_for_event(
+    config: &DiscordBridgeRuntimeConfig,
+    state_dir: &Path,
+    event: &DiscordBridgeEvent,
+    run_id: &str,
+    mut cancel_rx: watch::Receiver<bool>,
+    bot_user_id: &str,
+    principal: &str,
+) -> Result<PromptRunReport> {
+    let channel_store = ChannelStore::open(
+        &state_dir.join("channel-store"),
+        "discord",
+        &event.channel_id,
+    )?;
+    let session_path = channel_store.session_path();
+
+    let mut agent = Agent::new(
+        config.client.clone(),
+        AgentConfig {
+            model: config.model.clone(),
+            system_prompt: config.system_prompt.clone(),
+            max_turns: config.max_turns,
+            temperature: Some(0.0),
+            max_tokens: None,
+            ..AgentConfig::default()
+        },
+    );
+    let mut tool_policy = config.tool_policy.clone();
+    tool_policy.rbac_principal = Some(principal.to_string());
+    tool_policy.rbac_policy_path = Some(rbac_policy_path_for_state_dir(&config.state_dir));
+    crate::tools::register_builtin_tools(&mut agent, tool_policy);
+
+    let usage = Arc::new(Mutex::new(PromptUsageSummary::default()));
+    agent.subscribe({
+        let usage = usage.clone();
+        move |event| {
+            if let AgentEvent::TurnEnd {
+                usage: turn_usage,
+                request_duration_ms,
+                finish_reason,
+                ..
+            } = event
+            {
+                if let Ok(mut guard) = usage.lock() {
+                    guard.input_tokens = guard.input_tokens.saturating_add(turn_usage.input_tokens);
+                    guard.output_tokens =
+                        guard.output_tokens.saturating_add(turn_usage.output_tokens);
+                    guard.total_tokens = guard.total_tokens.saturating_add(turn_usage.total_tokens);
+                    guard.request_duration_ms = guard
+                        .request_duration_ms
+                        .saturating_add(*request_duration_ms);
+                    guard.finish_reason = finish_reason.clone();
+                }
+            }
+        }
+    });
+
+    let mut session_runtime = Some(initialize_channel_session_runtime(
+        &session_path,
+        &config.system_prompt,
+        config.session_lock_wait_ms,
+        config.session_lock_stale_ms,
+        &mut agent,
+    )?);
+
+    let formatted_prompt = render_event_prompt(event, bot_user_id);
+    let start_index = agent.messages().len();
+    let cancellation_signal = async move {
+        loop {
+            if *cancel_rx.borrow() {
+                break;
+            }
+            if cancel_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    };
+
+    let status = run_prompt_with_cancellation(
+        &mut agent,
+        &mut session_runtime,
+        &formatted_prompt,
+        config.turn_timeout_ms,
+        cancellation_signal,
+        config.render_options,
+    )
+    .await?;
+    let (assistant_reply, pending_approvals) = match status {
+        PromptRunStatus::Cancelled => ("Run cancelled before completion.".to_string(), Vec::new()),
+        PromptRunStatus::TimedOut => ("Run timed out before completion.".to_string(), Vec::new()),
+        PromptRunStatus::Completed => {
+            let new_messages = &agent.messages()[start_index..];
+            (
+                collect_assistant_reply(new_messages),
+                extract_pending_approvals(new_messages),
+            )
+        }
+    };
+
+    let usage = usage
+        .lock()
+        .map_err(|_| anyhow!("prompt usage lock is poisoned"))?
+        .clone();
+    let artifact = channel_store.write_text_artifact(
+        run_id,
+        "discord-reply",
+        "private",
+        normalize_artifact_retention_days(config.artifact_retention_days),
+        "md",
+        &render_discord_artifact_markdown(event, run_id, status, &assistant_reply),
+    )?;
+    channel_store.sync_context_from_messages(agent.messages())?;
+    channel_store.append_log_entry(&ChannelLogEntry {
+        timestamp_unix_ms: current_unix_timestamp_ms(),
+        direction: "outbound".to_string(),
+        event_key: Some(event.key.clone()),
+        source: "discord".to_string(),
+        payload: json!({
+            "run_id": run_id,
+            "status": prompt_status_label(status),
+            "principal": principal,
+            "assistant_reply": assistant_reply.clone(),
+            "pending_approvals": pending_approvals
+                .iter()
+                .map(|approval| approval.request_id.clone())
+                .collect::<Vec<_>>(),
+            "tokens": {
+                "input": usage.input_tokens,
+                "output": usage.output_tokens,
+                "total": usage.total_tokens,
+            },
+            "artifact": {
+                "id": artifact.id,
+                "path": artifact.relative_path,
+                "checksum_sha256": artifact.checksum_sha256,
+                "bytes": artifact.bytes,
+                "expires_unix_ms": artifact.expires_unix_ms,
+            },
+        }),
+    })?;
+
+    Ok(PromptRunReport {
+        run_id: run_id.to_string(),
+        model: config.model.clone(),
+        status,
+        assistant_reply,
+        usage,
+        pending_approvals,
+        artifact,
+    })
+}
+
+fn initialize_channel_session_runtime(
+    session_path: &Path,
+    system_prompt: &str,
+    lock_wait_ms: u64,
+    lock_stale_ms: u64,
+    agent: &mut Agent,
+) -> Result<SessionRuntime> {
+    if let Some(parent) = session_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+    }
+    let mut store = SessionStore::load(session_path)?;
+    store.set_lock_policy(lock_wait_ms.max(1), lock_stale_ms);
+    let active_head = store.ensure_initialized(system_prompt)?;
+    let lineage = store.lineage_messages(active_head)?;
+    if !lineage.is_empty() {
+        agent.replace_messages(lineage);
+    }
+    Ok(SessionRuntime { store, active_head })
+}
+
+/// Maps one gateway message into a bridge event; the flag reports a direct bot mention.
+fn normalize_inbound_message(
+    message: &DiscordInboundMessage,
+    bot_user_id: &str,
+) -> Option<(DiscordBridgeEvent, bool)> {
+    if message.author_is_bot {
+        return None;
+    }
+    let user_id = message.author_id.trim();
+    if user_id.is_empty() || (!bot_user_id.is_empty() && user_id == bot_user_id) {
+        return None;
+    }
+    let channel_id = message.channel_id.trim();
+    let message_id = message.message_id.trim();
+    if channel_id.is_empty() || message_id.is_empty() {
+        return None;
+    }
+    if message.content.trim().is_empty() && message.attachments.is_empty() {
+        return None;
+    }
+
+    let mentioned = !bot_user_id.is_empty()
+        && message
+            .mentioned_user_ids
+            .iter()
+            .any(|mentioned| mentioned.trim() == bot_user_id);
+    let kind = match message.scope {
+        DiscordChannelScope::DirectMessage => DiscordBridgeEventKind::DirectMessage,
+        DiscordChannelScope::GuildText => DiscordBridgeEventKind::Mention,
+        DiscordChannelScope::Thread => DiscordBridgeEventKind::ThreadMessage,
+        DiscordChannelScope::ForumPost => DiscordBridgeEventKind::ForumPost,
+    };
+    let raw_payload = serde_json::to_value(message).unwrap_or(Value::Null);
+    Some((
+        DiscordBridgeEvent {
+            key: format!("message:{message_id}"),
+            kind,
+            scope: message.scope,
+            occurred_unix_ms: message.timestamp_unix_ms,
+            guild_id: message.guild_id.clone(),
+            channel_id: channel_id.to_string(),
+            parent_channel_id: message.parent_channel_id.clone(),
+            message_id: Some(message_id.to_string()),
+            interaction_token: None,
+            user_id: user_id.to_string(),
+            user_name: message.author_name.clone(),
+            member_role_ids: message.member_role_ids.clone(),
+            text: message.content.clone(),
+            attachments: message.attachments.clone(),
+            raw_payload,
+        },
+        mentioned,
+    ))
+}
+
+fn normalize_slash_invocation(
+    invocation: &DiscordSlashInvocation,
+    command_prefix: &str,
+) -> DiscordBridgeEvent {
+    let raw_payload = serde_json::to_value(invocation).unwrap_or(Value::Null);
+    DiscordBridgeEvent {
+        key: format!("interaction:{}", invocation.interaction_id),
+        kind: DiscordBridgeEventKind::SlashCommand,
+        scope: invocation.scope,
+        occurred_unix_ms: invocation.occurred_unix_ms,
+        guild_id: invocation.guild_id.clone(),
+        channel_id: invocation.channel_id.clone(),
+        parent_channel_id: invocation.parent_channel_id.clone(),
+        message_id: None,
+        interaction_token: Some(invocation.interaction_token.clone()),
+        user_id: invocation.user_id.clone(),
+        user_name: invocation.user_name.clone(),
+        member_role_ids: invocation.member_role_ids.clone(),
+        text: render_slash_invocation_text(
+            command_prefix,
+            &invocation.subcommand,
+            &invocation.args,
+        ),
+        attachments: Vec::new(),
+        raw_payload,
+    }
+}
+
+fn event_is_stale(
+    event: &DiscordBridgeEvent,
+    max_event_age_seconds: u64,
+    now_unix_ms: u64,
+) -> bool {
+    if max_event_age_seconds == 0 {
+        return false;
+    }
+    let max_age_ms = max_event_age_seconds.saturating_mul(1000);
+    now_unix_ms.saturating_sub(event.occurred_unix_ms) > max_age_ms
+}
+
+#[cfg(test)]
+mod tests;
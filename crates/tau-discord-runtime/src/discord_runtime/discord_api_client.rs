@@ -0,0 +1,291 @@
+//! Serenity HTTP wrapper used by the Discord bridge for replies, typing, and components.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use serenity::all::{
+    ApplicationId, ButtonStyle, ChannelId, CommandOptionType, CreateActionRow, CreateButton,
+    CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter, CreateMessage,
+    EditInteractionResponse, EditMessage, Http, MessageId, Typing,
+};
+
+use super::{
+    discord_command_helpers::{
+        approval_button_custom_id, discord_slash_subcommand_specs, DiscordApprovalDecision,
+    },
+    discord_render_helpers::{
+        render_discord_approval_prompt, DiscordEmbedPayload, DiscordOutboundChunk,
+    },
+    DiscordApprovalPrompt, DiscordBridgeEvent,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Location of a message the bridge posted, kept so it can be edited later.
+pub(super) struct DiscordPostedMessage {
+    pub(super) channel_id: String,
+    pub(super) message_id: String,
+    /// Set when the message is the original response of a deferred interaction.
+    pub(super) interaction_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct DiscordDeliveryReport {
+    pub(super) messages: usize,
+    pub(super) approval_prompts: usize,
+}
+
+#[derive(Clone)]
+pub(super) struct DiscordApiClient {
+    http: Arc<Http>,
+}
+
+impl DiscordApiClient {
+    pub(super) fn new(http: Arc<Http>) -> Self {
+        Self { http }
+    }
+
+    pub(super) fn from_token(bot_token: &str, application_id: u64) -> Self {
+        let http = Http::new(bot_token.trim());
+        http.set_application_id(ApplicationId::new(application_id.max(1)));
+        Self::new(Arc::new(http))
+    }
+
+    /// Starts the channel typing indicator; serenity refreshes it until stopped.
+    pub(super) fn start_typing(&self, channel_id: &str) -> Option<Typing> {
+        let channel_id = parse_snowflake(channel_id).ok()?;
+        Some(ChannelId::new(channel_id).start_typing(&self.http))
+    }
+
+    /// Answers an event: edits the deferred interaction response or replies to the message.
+    pub(super) async fn respond(
+        &self,
+        event: &DiscordBridgeEvent,
+        chunk: &DiscordOutboundChunk,
+    ) -> Result<DiscordPostedMessage> {
+        if let Some(token) = event.interaction_token.as_deref() {
+            let message = self
+                .http
+                .edit_original_interaction_response(
+                    token,
+                    &build_edit_interaction_response(chunk, Vec::new()),
+                    Vec::new(),
+                )
+                .await
+                .context("failed to edit discord interaction response")?;
+            return Ok(DiscordPostedMessage {
+                channel_id: message.channel_id.get().to_string(),
+                message_id: message.id.get().to_string(),
+                interaction_token: Some(token.to_string()),
+            });
+        }
+        self.post_message(
+            &event.channel_id,
+            event.message_id.as_deref(),
+            chunk,
+            Vec::new(),
+        )
+        .await
+    }
+
+    pub(super) async fn post_message(
+        &self,
+        channel_id: &str,
+        reply_to_message_id: Option<&str>,
+        chunk: &DiscordOutboundChunk,
+        components: Vec<CreateActionRow>,
+    ) -> Result<DiscordPostedMessage> {
+        let channel = ChannelId::new(parse_snowflake(channel_id)?);
+        let mut builder = CreateMessage::new().content(chunk.content.clone());
+        if let Some(embed) = &chunk.embed {
+            builder = builder.embed(build_embed(embed));
+        }
+        if !components.is_empty() {
+            builder = builder.components(components);
+        }
+        if let Some(reply_to) = reply_to_message_id {
+            builder =
+                builder.reference_message((channel, MessageId::new(parse_snowflake(reply_to)?)));
+        }
+        let message = channel
+            .send_message(&self.http, builder)
+            .await
+            .with_context(|| format!("failed to post discord message in channel {channel_id}"))?;
+        Ok(DiscordPostedMessage {
+            channel_id: channel_id.to_string(),
+            message_id: message.id.get().to_string(),
+            interaction_token: None,
+        })
+    }
+
+    pub(super) async fn update_message(
+        &self,
+        posted: &DiscordPostedMessage,
+        chunk: &DiscordOutboundChunk,
+        components: Vec<CreateActionRow>,
+    ) -> Result<()> {
+        if let Some(token) = posted.interaction_token.as_deref() {
+            self.http
+                .edit_original_interaction_response(
+                    token,
+                    &build_edit_interaction_response(chunk, components),
+                    Vec::new(),
+                )
+                .await
+                .context("failed to edit discord interaction response")?;
+            return Ok(());
+        }
+        let channel = ChannelId::new(parse_snowflake(&posted.channel_id)?);
+        let message_id = MessageId::new(parse_snowflake(&posted.message_id)?);
+        channel
+            .edit_message(
+                &self.http,
+                message_id,
+                build_edit_message(chunk, components),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "failed to edit discord message {} in channel {}",
+                    posted.message_id, posted.channel_id
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Delivers a rendered reply: the first chunk replaces the working placeholder,
+    /// remaining chunks follow as new messages, then one message per pending approval.
+    pub(super) async fn deliver_reply(
+        &self,
+        working_message: &DiscordPostedMessage,
+        chunks: &[DiscordOutboundChunk],
+        approvals: &[DiscordApprovalPrompt],
+        embed_responses: bool,
+    ) -> Result<DiscordDeliveryReport> {
+        let mut report = DiscordDeliveryReport::default();
+        for (index, chunk) in chunks.iter().enumerate() {
+            if index == 0 {
+                if let Err(error) = self
+                    .update_message(working_message, chunk, Vec::new())
+                    .await
+                {
+                    eprintln!("discord bridge failed to edit working message: {error}");
+                    self.post_message(&working_message.channel_id, None, chunk, Vec::new())
+                        .await?;
+                }
+            } else {
+                self.post_message(&working_message.channel_id, None, chunk, Vec::new())
+                    .await?;
+            }
+            report.messages = report.messages.saturating_add(1);
+        }
+        for approval in approvals {
+            self.post_message(
+                &working_message.channel_id,
+                None,
+                &render_discord_approval_prompt(approval, embed_responses),
+                approval_components(&approval.request_id),
+            )
+            .await?;
+            report.approval_prompts = report.approval_prompts.saturating_add(1);
+        }
+        Ok(report)
+    }
+
+    /// Replaces an approval prompt with its decision and removes the buttons.
+    pub(super) async fn resolve_approval_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        content: &str,
+    ) -> Result<()> {
+        self.update_message(
+            &DiscordPostedMessage {
+                channel_id: channel_id.to_string(),
+                message_id: message_id.to_string(),
+                interaction_token: None,
+            },
+            &DiscordOutboundChunk::text(content),
+            Vec::new(),
+        )
+        .await
+    }
+}
+
+fn build_embed(payload: &DiscordEmbedPayload) -> CreateEmbed {
+    let mut embed = CreateEmbed::new()
+        .description(payload.description.clone())
+        .colour(payload.color);
+    if let Some(title) = &payload.title {
+        embed = embed.title(title.clone());
+    }
+    if let Some(footer) = &payload.footer {
+        embed = embed.footer(CreateEmbedFooter::new(footer.clone()));
+    }
+    embed
+}
+
+fn build_edit_message(
+    chunk: &DiscordOutboundChunk,
+    components: Vec<CreateActionRow>,
+) -> EditMessage {
+    EditMessage::new()
+        .content(chunk.content.clone())
+        .embeds(chunk.embed.iter().map(build_embed).collect())
+        .components(components)
+}
+
+fn build_edit_interaction_response(
+    chunk: &DiscordOutboundChunk,
+    components: Vec<CreateActionRow>,
+) -> EditInteractionResponse {
+    EditInteractionResponse::new()
+        .content(chunk.content.clone())
+        .embeds(chunk.embed.iter().map(build_embed).collect())
+        .components(components)
+}
+
+fn approval_components(request_id: &str) -> Vec<CreateActionRow> {
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(approval_button_custom_id(
+            DiscordApprovalDecision::Approve,
+            request_id,
+        ))
+        .label("Approve")
+        .style(ButtonStyle::Success),
+        CreateButton::new(approval_button_custom_id(
+            DiscordApprovalDecision::Reject,
+            request_id,
+        ))
+        .label("Reject")
+        .style(ButtonStyle::Danger),
+    ])]
+}
+
+/// Builds the native slash command registered for the bridge.
+pub(super) fn build_slash_command(command_name: &str) -> CreateCommand {
+    discord_slash_subcommand_specs().into_iter().fold(
+        CreateCommand::new(command_name).description("Talk to Tau and manage its runs"),
+        |command, spec| {
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                spec.name,
+                spec.description,
+            );
+            if let Some((name, description, required)) = spec.argument {
+                option = option.add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, name, description)
+                        .required(required),
+                );
+            }
+            command.add_option(option)
+        },
+    )
+}
+
+pub(super) fn parse_snowflake(raw: &str) -> Result<u64> {
+    raw.trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|value| *value > 0)
+        .ok_or_else(|| anyhow!("invalid discord snowflake '{}'", raw.trim()))
+}
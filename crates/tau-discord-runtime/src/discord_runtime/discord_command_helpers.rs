@@ -0,0 +1,300 @@
+//! Discord `/tau` command parsing, slash-command definitions, and approval button helpers.
+
+use super::{
+    discord_render_helpers::{
+        normalize_discord_message_text, truncate_for_discord, DISCORD_MESSAGE_CHAR_LIMIT,
+    },
+    DiscordBridgeEvent, DiscordCommand, DISCORD_APPROVAL_CUSTOM_ID_PREFIX,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Native slash subcommand exposed under the bridge's top-level command.
+pub(super) struct DiscordSlashSubcommandSpec {
+    pub(super) name: &'static str,
+    pub(super) description: &'static str,
+    /// `(option name, option description, required)` for the single string argument.
+    pub(super) argument: Option<(&'static str, &'static str, bool)>,
+}
+
+pub(super) fn discord_slash_subcommand_specs() -> Vec<DiscordSlashSubcommandSpec> {
+    vec![
+        DiscordSlashSubcommandSpec {
+            name: "ask",
+            description: "Ask Tau in this channel or thread",
+            argument: Some(("prompt", "What Tau should do", true)),
+        },
+        DiscordSlashSubcommandSpec {
+            name: "help",
+            description: "Show supported Tau commands",
+            argument: None,
+        },
+        DiscordSlashSubcommandSpec {
+            name: "status",
+            description: "Show the run state for this session",
+            argument: None,
+        },
+        DiscordSlashSubcommandSpec {
+            name: "health",
+            description: "Show bridge transport health",
+            argument: None,
+        },
+        DiscordSlashSubcommandSpec {
+            name: "stop",
+            description: "Cancel the active run for this session",
+            argument: None,
+        },
+        DiscordSlashSubcommandSpec {
+            name: "artifacts",
+            description: "List, inspect, or purge session artifacts",
+            argument: Some(("args", "purge | run <run_id> | show <artifact_id>", false)),
+        },
+        DiscordSlashSubcommandSpec {
+            name: "approvals",
+            description: "List or decide pending approval requests",
+            argument: Some(("args", "list | approve <id> | reject <id>", false)),
+        },
+        DiscordSlashSubcommandSpec {
+            name: "canvas",
+            description: "Operate on the session canvas",
+            argument: Some(("args", "create | update | show | export | import ...", true)),
+        },
+    ]
+}
+
+/// Converts a slash invocation into the equivalent text command or prompt.
+pub(super) fn render_slash_invocation_text(
+    command_prefix: &str,
+    subcommand: &str,
+    args: &str,
+) -> String {
+    let subcommand = subcommand.trim();
+    let args = args.trim();
+    if subcommand == "ask" {
+        return args.to_string();
+    }
+    let prefix = command_prefix.trim();
+    let subcommand = if subcommand == "approvals" && args.is_empty() {
+        "approvals list"
+    } else {
+        subcommand
+    };
+    if args.is_empty() {
+        format!("{prefix} {subcommand}")
+    } else {
+        format!("{prefix} {subcommand} {args}")
+    }
+}
+
+pub(super) fn discord_command_usage(command_prefix: &str) -> String {
+    let prefix = command_prefix.trim();
+    [
+        format!("Supported `{prefix}` commands:"),
+        format!("- `{prefix} help`"),
+        format!("- `{prefix} status`"),
+        format!("- `{prefix} health`"),
+        format!("- `{prefix} stop`"),
+        format!("- `{prefix} artifacts [purge|run <run_id>|show <artifact_id>]`"),
+        format!("- `{prefix} approvals [list|approve <id>|reject <id>]`"),
+        format!("- `{prefix} canvas <create|update|show|export|import> ...`"),
+    ]
+    .join("\n")
+}
+
+pub(super) fn rbac_action_for_discord_command(command: Option<&DiscordCommand>) -> String {
+    match command {
+        Some(DiscordCommand::Help) => "command:/tau-help".to_string(),
+        Some(DiscordCommand::Status) => "command:/tau-status".to_string(),
+        Some(DiscordCommand::Health) => "command:/tau-health".to_string(),
+        Some(DiscordCommand::Stop) => "command:/tau-stop".to_string(),
+        Some(DiscordCommand::Artifacts { .. }) => "command:/tau-artifacts".to_string(),
+        Some(DiscordCommand::ArtifactShow { .. }) => "command:/tau-artifacts-show".to_string(),
+        Some(DiscordCommand::Approvals { .. }) => "command:/tau-approvals".to_string(),
+        Some(DiscordCommand::Canvas { .. }) => "command:/tau-canvas".to_string(),
+        Some(DiscordCommand::Invalid { .. }) => "command:/tau-invalid".to_string(),
+        None => "command:/tau-run".to_string(),
+    }
+}
+
+pub(super) fn parse_discord_command(
+    event: &DiscordBridgeEvent,
+    bot_user_id: &str,
+    command_prefix: &str,
+) -> Option<DiscordCommand> {
+    let normalized = normalize_discord_message_text(event, bot_user_id);
+    let trimmed = normalized.trim();
+    let prefix = command_prefix.trim();
+    let mut pieces = trimmed.split_whitespace();
+    let first = pieces.next()?;
+    if prefix.is_empty() || first != prefix {
+        return None;
+    }
+
+    let args = trimmed[first.len()..].trim();
+    if args.is_empty() {
+        return Some(DiscordCommand::Invalid {
+            message: discord_command_usage(prefix),
+        });
+    }
+    let mut parts = args.splitn(2, char::is_whitespace);
+    let command = parts.next().unwrap_or_default();
+    let remainder = parts.next().unwrap_or_default().trim();
+    let no_args = |parsed: DiscordCommand| {
+        if remainder.is_empty() {
+            parsed
+        } else {
+            DiscordCommand::Invalid {
+                message: format!("Usage: {prefix} {command}"),
+            }
+        }
+    };
+    let parsed = match command {
+        "help" => no_args(DiscordCommand::Help),
+        "status" => no_args(DiscordCommand::Status),
+        "health" => no_args(DiscordCommand::Health),
+        "stop" => no_args(DiscordCommand::Stop),
+        "artifacts" => {
+            if remainder.is_empty() {
+                DiscordCommand::Artifacts {
+                    purge: false,
+                    run_id: None,
+                }
+            } else if remainder == "purge" {
+                DiscordCommand::Artifacts {
+                    purge: true,
+                    run_id: None,
+                }
+            } else {
+                let mut artifact_args = remainder.split_whitespace();
+                match (
+                    artifact_args.next(),
+                    artifact_args.next(),
+                    artifact_args.next(),
+                ) {
+                    (Some("run"), Some(run_id), None) => DiscordCommand::Artifacts {
+                        purge: false,
+                        run_id: Some(run_id.to_string()),
+                    },
+                    (Some("show"), Some(artifact_id), None) => DiscordCommand::ArtifactShow {
+                        artifact_id: artifact_id.to_string(),
+                    },
+                    _ => DiscordCommand::Invalid {
+                        message: format!(
+                            "Usage: {prefix} artifacts [purge|run <run_id>|show <artifact_id>]"
+                        ),
+                    },
+                }
+            }
+        }
+        "approvals" => DiscordCommand::Approvals {
+            args: if remainder.is_empty() {
+                "list".to_string()
+            } else {
+                remainder.to_string()
+            },
+        },
+        "canvas" => {
+            if remainder.is_empty() {
+                DiscordCommand::Invalid {
+                    message: format!(
+                        "Usage: {prefix} canvas <create|update|show|export|import> ..."
+                    ),
+                }
+            } else {
+                DiscordCommand::Canvas {
+                    args: remainder.to_string(),
+                }
+            }
+        }
+        _ => DiscordCommand::Invalid {
+            message: format!(
+                "Unknown command `{}`.\n\n{}",
+                command,
+                discord_command_usage(prefix)
+            ),
+        },
+    };
+    Some(parsed)
+}
+
+pub(super) fn render_discord_command_response(
+    event: &DiscordBridgeEvent,
+    command_name: &str,
+    status: &str,
+    message: &str,
+) -> String {
+    let content = if message.trim().is_empty() {
+        "Tau command response."
+    } else {
+        message.trim()
+    };
+    let command = if command_name.trim().is_empty() {
+        "unknown"
+    } else {
+        command_name.trim()
+    };
+    let status_label = if status.trim().is_empty() {
+        "reported"
+    } else {
+        status.trim()
+    };
+    let footer = format!(
+        "\n\n-# Tau command `{}` | status `{}` | event `{}`",
+        command, status_label, event.key
+    );
+    let body_limit = DISCORD_MESSAGE_CHAR_LIMIT.saturating_sub(footer.chars().count());
+    let mut rendered = truncate_for_discord(content, body_limit);
+    rendered.push_str(&footer);
+    truncate_for_discord(&rendered, DISCORD_MESSAGE_CHAR_LIMIT)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum DiscordApprovalDecision {
+    Approve,
+    Reject,
+}
+
+impl DiscordApprovalDecision {
+    pub(super) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+        }
+    }
+
+    pub(super) fn status_label(&self) -> &'static str {
+        match self {
+            Self::Approve => "approved",
+            Self::Reject => "rejected",
+        }
+    }
+}
+
+pub(super) fn approval_button_custom_id(
+    decision: DiscordApprovalDecision,
+    request_id: &str,
+) -> String {
+    format!(
+        "{DISCORD_APPROVAL_CUSTOM_ID_PREFIX}{}:{}",
+        decision.as_str(),
+        request_id.trim()
+    )
+}
+
+pub(super) fn parse_approval_button_custom_id(
+    custom_id: &str,
+) -> Option<(DiscordApprovalDecision, String)> {
+    let remainder = custom_id
+        .trim()
+        .strip_prefix(DISCORD_APPROVAL_CUSTOM_ID_PREFIX)?;
+    let (decision, request_id) = remainder.split_once(':')?;
+    let decision = match decision {
+        "approve" => DiscordApprovalDecision::Approve,
+        "reject" => DiscordApprovalDecision::Reject,
+        _ => return None,
+    };
+    let request_id = request_id.trim();
+    if request_id.is_empty() || request_id.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((decision, request_id.to_string()))
+}
@@ -0,0 +1,308 @@
+//! Serenity event handler that forwards gateway events into the bridge runtime loop.
+//!
+//! The handler stays thin: it acknowledges interactions inside Discord's
+//! three-second window, resolves channel scope, and hands plain inputs to the
+//! single-owner runtime over an unbounded channel.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use serenity::all::{
+    Channel, ChannelId, ChannelType, Command, CommandDataOption, CommandDataOptionValue, Context,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EventHandler, GuildId,
+    Interaction, Message, Ready,
+};
+use serenity::async_trait;
+use tokio::sync::mpsc;
+
+use super::{
+    discord_api_client::build_slash_command, DiscordAttachment, DiscordChannelScope,
+    DiscordComponentInvocation, DiscordGatewayInput, DiscordInboundMessage, DiscordSlashInvocation,
+    DISCORD_APPROVAL_CUSTOM_ID_PREFIX,
+};
+use crate::current_unix_timestamp_ms;
+
+pub(super) struct DiscordGatewayHandler {
+    inputs: mpsc::UnboundedSender<DiscordGatewayInput>,
+    slash_command_name: String,
+    command_guild_ids: Vec<u64>,
+    scope_cache: Mutex<HashMap<u64, (DiscordChannelScope, Option<String>)>>,
+}
+
+impl DiscordGatewayHandler {
+    pub(super) fn new(
+        inputs: mpsc::UnboundedSender<DiscordGatewayInput>,
+        slash_command_name: String,
+        command_guild_ids: Vec<u64>,
+    ) -> Self {
+        Self {
+            inputs,
+            slash_command_name,
+            command_guild_ids,
+            scope_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn forward(&self, input: DiscordGatewayInput) {
+        if self.inputs.send(input).is_err() {
+            eprintln!("discord bridge runtime loop is gone; dropping gateway input");
+        }
+    }
+
+    async fn register_slash_command(&self, ctx: &Context) {
+        let command = build_slash_command(&self.slash_command_name);
+        if self.command_guild_ids.is_empty() {
+            if let Err(error) = Command::set_global_commands(&ctx.http, vec![command]).await {
+                eprintln!("discord bridge failed to register global slash command: {error}");
+            }
+            return;
+        }
+        for guild_id in &self.command_guild_ids {
+            if let Err(error) = GuildId::new(*guild_id)
+                .set_commands(&ctx.http, vec![command.clone()])
+                .await
+            {
+                eprintln!(
+                    "discord bridge failed to register slash command for guild {guild_id}: {error}"
+                );
+            }
+        }
+    }
+
+    /// Resolves the session scope and parent channel for a channel, caching the result.
+    async fn resolve_channel_scope(
+        &self,
+        ctx: &Context,
+        channel_id: ChannelId,
+        guild_id: Option<GuildId>,
+    ) -> (DiscordChannelScope, Option<String>) {
+        if guild_id.is_none() {
+            return (DiscordChannelScope::DirectMessage, None);
+        }
+        if let Some(cached) = self
+            .scope_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(&channel_id.get()).cloned())
+        {
+            return cached;
+        }
+
+        let resolved = match channel_id.to_channel(&ctx.http).await {
+            Ok(Channel::Guild(channel)) => {
+                let parent_kind = match channel.parent_id {
+                    Some(parent_id) if is_thread_kind(channel.kind) => {
+                        match parent_id.to_channel(&ctx.http).await {
+                            Ok(Channel::Guild(parent)) => Some(parent.kind),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                (
+                    scope_for_guild_channel(channel.kind, parent_kind),
+                    channel
+                        .parent_id
+                        .map(|parent_id| parent_id.get().to_string()),
+                )
+            }
+            Ok(Channel::Private(_)) => (DiscordChannelScope::DirectMessage, None),
+            Ok(_) | Err(_) => return (DiscordChannelScope::GuildText, None),
+        };
+        if let Ok(mut cache) = self.scope_cache.lock() {
+            cache.insert(channel_id.get(), resolved.clone());
+        }
+        resolved
+    }
+}
+
+#[async_trait]
+impl EventHandler for DiscordGatewayHandler {
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        self.register_slash_command(&ctx).await;
+        self.forward(DiscordGatewayInput::Ready {
+            bot_user_id: ready.user.id.get().to_string(),
+            bot_user_name: ready.user.name.clone(),
+            guild_count: ready.guilds.len(),
+        });
+    }
+
+    async fn message(&self, ctx: Context, message: Message) {
+        if message.author.bot {
+            return;
+        }
+        let (scope, parent_channel_id) = self
+            .resolve_channel_scope(&ctx, message.channel_id, message.guild_id)
+            .await;
+        let timestamp_unix_ms = u64::try_from(message.timestamp.unix_timestamp())
+            .unwrap_or(0)
+            .saturating_mul(1000);
+        self.forward(DiscordGatewayInput::Message(DiscordInboundMessage {
+            message_id: message.id.get().to_string(),
+            channel_id: message.channel_id.get().to_string(),
+            parent_channel_id,
+            guild_id: message.guild_id.map(|guild_id| guild_id.get().to_string()),
+            scope,
+            author_id: message.author.id.get().to_string(),
+            author_name: message.author.name.clone(),
+            author_is_bot: message.author.bot,
+            member_role_ids: message
+                .member
+                .as_ref()
+                .map(|member| {
+                    member
+                        .roles
+                        .iter()
+                        .map(|role| role.get().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            mentioned_user_ids: message
+                .mentions
+                .iter()
+                .map(|user| user.id.get().to_string())
+                .collect(),
+            content: message.content.clone(),
+            timestamp_unix_ms,
+            attachments: message
+                .attachments
+                .iter()
+                .map(|attachment| DiscordAttachment {
+                    id: attachment.id.get().to_string(),
+                    filename: attachment.filename.clone(),
+                    url: attachment.url.clone(),
+                    content_type: attachment.content_type.clone(),
+                    size: u64::from(attachment.size),
+                })
+                .collect(),
+        }));
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        match interaction {
+            Interaction::Command(command) => {
+                if command.data.name != self.slash_command_name || command.user.bot {
+                    return;
+                }
+                let (subcommand, args) = slash_subcommand_and_args(&command.data.options);
+                if let Err(error) = command
+                    .create_response(
+                        &ctx.http,
+                        CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()),
+                    )
+                    .await
+                {
+                    eprintln!("discord bridge failed to defer slash command: {error}");
+                    return;
+                }
+                let (scope, parent_channel_id) = self
+                    .resolve_channel_scope(&ctx, command.channel_id, command.guild_id)
+                    .await;
+                self.forward(DiscordGatewayInput::SlashCommand(DiscordSlashInvocation {
+                    interaction_id: command.id.get().to_string(),
+                    interaction_token: command.token.clone(),
+                    guild_id: command.guild_id.map(|guild_id| guild_id.get().to_string()),
+                    channel_id: command.channel_id.get().to_string(),
+                    parent_channel_id,
+                    scope,
+                    user_id: command.user.id.get().to_string(),
+                    user_name: command.user.name.clone(),
+                    member_role_ids: command
+                        .member
+                        .as_ref()
+                        .map(|member| {
+                            member
+                                .roles
+                                .iter()
+                                .map(|role| role.get().to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    subcommand,
+                    args,
+                    occurred_unix_ms: current_unix_timestamp_ms(),
+                }));
+            }
+            Interaction::Component(component) => {
+                if !component
+                    .data
+                    .custom_id
+                    .starts_with(DISCORD_APPROVAL_CUSTOM_ID_PREFIX)
+                {
+                    return;
+                }
+                if let Err(error) = component
+                    .create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+                    .await
+                {
+                    eprintln!("discord bridge failed to acknowledge component: {error}");
+                    return;
+                }
+                self.forward(DiscordGatewayInput::Component(DiscordComponentInvocation {
+                    interaction_id: component.id.get().to_string(),
+                    guild_id: component
+                        .guild_id
+                        .map(|guild_id| guild_id.get().to_string()),
+                    channel_id: component.channel_id.get().to_string(),
+                    message_id: component.message.id.get().to_string(),
+                    user_id: component.user.id.get().to_string(),
+                    user_name: component.user.name.clone(),
+                    member_role_ids: component
+                        .member
+                        .as_ref()
+                        .map(|member| {
+                            member
+                                .roles
+                                .iter()
+                                .map(|role| role.get().to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    custom_id: component.data.custom_id.clone(),
+                    occurred_unix_ms: current_unix_timestamp_ms(),
+                }));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_thread_kind(kind: ChannelType) -> bool {
+    matches!(
+        kind,
+        ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread
+    )
+}
+
+/// Classifies a guild channel; threads whose parent is a forum are forum posts.
+pub(super) fn scope_for_guild_channel(
+    kind: ChannelType,
+    parent_kind: Option<ChannelType>,
+) -> DiscordChannelScope {
+    if !is_thread_kind(kind) {
+        return DiscordChannelScope::GuildText;
+    }
+    if parent_kind == Some(ChannelType::Forum) {
+        DiscordChannelScope::ForumPost
+    } else {
+        DiscordChannelScope::Thread
+    }
+}
+
+fn slash_subcommand_and_args(options: &[CommandDataOption]) -> (String, String) {
+    let Some(option) = options.first() else {
+        return ("help".to_string(), String::new());
+    };
+    let args = match &option.value {
+        CommandDataOptionValue::SubCommand(sub_options) => sub_options
+            .iter()
+            .filter_map(|sub_option| match &sub_option.value {
+                CommandDataOptionValue::String(value) => Some(value.trim().to_string()),
+                _ => None,
+            })
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => String::new(),
+    };
+    (option.name.clone(), args)
+}
@@ -0,0 +1,247 @@
+//! Per-guild Discord policy loading and RBAC principal resolution.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{DiscordBridgeEvent, DiscordBridgeEventKind, DiscordChannelScope};
+use crate::{authorize_action_for_principal_with_policy_path, discord_principal, RbacDecision};
+
+pub(super) const DISCORD_GUILD_CONFIG_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) struct DiscordGuildPolicy {
+    #[serde(default = "default_true")]
+    pub(super) enabled: bool,
+    #[serde(default = "default_true")]
+    pub(super) require_mention: bool,
+    #[serde(default)]
+    pub(super) allowed_channel_ids: Vec<String>,
+    #[serde(default)]
+    pub(super) forum_channel_ids: Vec<String>,
+    #[serde(default)]
+    pub(super) role_principals: BTreeMap<String, String>,
+}
+
+impl Default for DiscordGuildPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            require_mention: true,
+            allowed_channel_ids: Vec::new(),
+            forum_channel_ids: Vec::new(),
+            role_principals: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) struct DiscordGuildConfigFile {
+    #[serde(default = "discord_guild_config_schema_version")]
+    pub(super) schema_version: u32,
+    #[serde(default = "default_true")]
+    pub(super) allow_direct_messages: bool,
+    #[serde(default)]
+    pub(super) default_guild: DiscordGuildPolicy,
+    #[serde(default)]
+    pub(super) guilds: BTreeMap<String, DiscordGuildPolicy>,
+}
+
+impl Default for DiscordGuildConfigFile {
+    fn default() -> Self {
+        Self {
+            schema_version: DISCORD_GUILD_CONFIG_SCHEMA_VERSION,
+            allow_direct_messages: true,
+            default_guild: DiscordGuildPolicy::default(),
+            guilds: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn discord_guild_config_schema_version() -> u32 {
+    DISCORD_GUILD_CONFIG_SCHEMA_VERSION
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum DiscordAdmission {
+    Accept,
+    Ignore { reason_code: &'static str },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DiscordPrincipalAuthorization {
+    pub(super) principal: String,
+    pub(super) candidates: Vec<String>,
+    pub(super) decision: RbacDecision,
+}
+
+impl DiscordGuildConfigFile {
+    pub(super) fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let config = serde_json::from_str::<Self>(&raw)
+            .with_context(|| format!("failed to parse discord guild config {}", path.display()))?;
+        if config.schema_version != DISCORD_GUILD_CONFIG_SCHEMA_VERSION {
+            bail!(
+                "unsupported discord guild config schema: expected {}, found {}",
+                DISCORD_GUILD_CONFIG_SCHEMA_VERSION,
+                config.schema_version
+            );
+        }
+        Ok(config)
+    }
+
+    pub(super) fn policy_for_guild(&self, guild_id: Option<&str>) -> &DiscordGuildPolicy {
+        guild_id
+            .and_then(|guild_id| self.guilds.get(guild_id.trim()))
+            .unwrap_or(&self.default_guild)
+    }
+
+    pub(super) fn is_forum_channel(&self, guild_id: Option<&str>, channel_id: &str) -> bool {
+        self.policy_for_guild(guild_id)
+            .forum_channel_ids
+            .iter()
+            .any(|value| value.trim() == channel_id)
+    }
+
+    /// Decides whether a normalized event should start or continue a session.
+    pub(super) fn admit_event(
+        &self,
+        event: &DiscordBridgeEvent,
+        mentioned: bool,
+        session_exists: bool,
+    ) -> DiscordAdmission {
+        if event.scope == DiscordChannelScope::DirectMessage {
+            return if self.allow_direct_messages {
+                DiscordAdmission::Accept
+            } else {
+                DiscordAdmission::Ignore {
+                    reason_code: "direct_messages_disabled",
+                }
+            };
+        }
+
+        let policy = self.policy_for_guild(event.guild_id.as_deref());
+        if !policy.enabled {
+            return DiscordAdmission::Ignore {
+                reason_code: "guild_disabled",
+            };
+        }
+        if !policy.allowed_channel_ids.is_empty() {
+            let allowed = policy.allowed_channel_ids.iter().any(|value| {
+                let value = value.trim();
+                value == event.channel_id || event.parent_channel_id.as_deref() == Some(value)
+            });
+            if !allowed {
+                return DiscordAdmission::Ignore {
+                    reason_code: "channel_not_allowed",
+                };
+            }
+        }
+        if event.kind == DiscordBridgeEventKind::SlashCommand
+            || mentioned
+            || !policy.require_mention
+        {
+            return DiscordAdmission::Accept;
+        }
+        match event.scope {
+            DiscordChannelScope::ForumPost if session_exists || self.forum_admits(event) => {
+                DiscordAdmission::Accept
+            }
+            DiscordChannelScope::Thread if session_exists => DiscordAdmission::Accept,
+            _ => DiscordAdmission::Ignore {
+                reason_code: "mention_required",
+            },
+        }
+    }
+
+    fn forum_admits(&self, event: &DiscordBridgeEvent) -> bool {
+        event
+            .parent_channel_id
+            .as_deref()
+            .is_some_and(|parent| self.is_forum_channel(event.guild_id.as_deref(), parent))
+    }
+
+    /// Resolves RBAC principal candidates for one Discord actor.
+    ///
+    /// The user principal always comes first; guild role mappings follow in
+    /// role-id order so that role bindings can grant access the user principal
+    /// alone would not.
+    pub(super) fn principal_candidates(
+        &self,
+        guild_id: Option<&str>,
+        user_id: &str,
+        member_role_ids: &[String],
+    ) -> Vec<String> {
+        let mut candidates = vec![discord_principal(user_id)];
+        if guild_id.is_none() {
+            return candidates;
+        }
+        let policy = self.policy_for_guild(guild_id);
+        let member_roles = member_role_ids
+            .iter()
+            .map(|role_id| role_id.trim())
+            .collect::<BTreeSet<_>>();
+        for (role_id, principal) in &policy.role_principals {
+            let principal = principal.trim();
+            if principal.is_empty() || !member_roles.contains(role_id.trim()) {
+                continue;
+            }
+            if !candidates.iter().any(|candidate| candidate == principal) {
+                candidates.push(principal.to_string());
+            }
+        }
+        candidates
+    }
+}
+
+/// Authorizes an action against every principal candidate, allowing on the first match.
+pub(super) fn authorize_discord_action(
+    candidates: &[String],
+    action: &str,
+    policy_path: &Path,
+) -> Result<DiscordPrincipalAuthorization> {
+    let mut first_denial = None;
+    for candidate in candidates {
+        let decision =
+            authorize_action_for_principal_with_policy_path(candidate, action, policy_path)?;
+        if decision.is_allowed() {
+            return Ok(DiscordPrincipalAuthorization {
+                principal: candidate.clone(),
+                candidates: candidates.to_vec(),
+                decision,
+            });
+        }
+        if first_denial.is_none() {
+            first_denial = Some((candidate.clone(), decision));
+        }
+    }
+    let (principal, decision) = first_denial.unwrap_or_else(|| {
+        (
+            String::new(),
+            RbacDecision::Deny {
+                reason_code: "deny_principal_missing".to_string(),
+                matched_role: None,
+                matched_pattern: None,
+            },
+        )
+    });
+    Ok(DiscordPrincipalAuthorization {
+        principal,
+        candidates: candidates.to_vec(),
+        decision,
+    })
+}
@@ -0,0 +1,450 @@
+//! Prompt, chunked reply, and embed rendering helpers for Discord bridge runtime flows.
+
+use serde_json::Value;
+use tau_ai::{Message, MessageRole};
+
+use super::{
+    DiscordApprovalPrompt, DiscordBridgeEvent, DiscordChannelScope, PromptRunReport,
+    PromptRunStatus, DISCORD_MAX_APPROVAL_PROMPTS,
+};
+use crate::discord_helpers::truncate_for_error;
+
+pub(super) const DISCORD_MESSAGE_CHAR_LIMIT: usize = 2_000;
+pub(super) const DISCORD_EMBED_DESCRIPTION_LIMIT: usize = 4_096;
+const DISCORD_EMBED_TITLE_LIMIT: usize = 256;
+const DISCORD_EMBED_FOOTER_LIMIT: usize = 2_048;
+const DISCORD_FENCE_RESERVE_CHARS: usize = 32;
+const DISCORD_FENCE_OPENER_MAX_CHARS: usize = 16;
+
+const DISCORD_EMBED_COLOR_COMPLETED: u32 = 0x2ECC71;
+const DISCORD_EMBED_COLOR_CANCELLED: u32 = 0xF1C40F;
+const DISCORD_EMBED_COLOR_TIMED_OUT: u32 = 0xE67E22;
+const DISCORD_EMBED_COLOR_FAILED: u32 = 0xE74C3C;
+const DISCORD_EMBED_COLOR_APPROVAL: u32 = 0x5865F2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DiscordEmbedPayload {
+    pub(super) title: Option<String>,
+    pub(super) description: String,
+    pub(super) color: u32,
+    pub(super) footer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One Discord message worth of output: plain content, an optional embed, or both.
+pub(super) struct DiscordOutboundChunk {
+    pub(super) content: String,
+    pub(super) embed: Option<DiscordEmbedPayload>,
+}
+
+impl DiscordOutboundChunk {
+    pub(super) fn text(content: &str) -> Self {
+        Self {
+            content: truncate_for_discord(content, DISCORD_MESSAGE_CHAR_LIMIT),
+            embed: None,
+        }
+    }
+}
+
+/// Strips direct bot mentions (`<@id>` and legacy `<@!id>`) from message text.
+pub(super) fn normalize_discord_message_text(
+    event: &DiscordBridgeEvent,
+    bot_user_id: &str,
+) -> String {
+    let mut message_text = event.text.trim().to_string();
+    if !bot_user_id.is_empty() {
+        for mention in [format!("<@{bot_user_id}>"), format!("<@!{bot_user_id}>")] {
+            message_text = message_text.replace(&mention, "");
+        }
+    }
+    message_text.trim().to_string()
+}
+
+pub(super) fn render_event_prompt(event: &DiscordBridgeEvent, bot_user_id: &str) -> String {
+    let message_text = normalize_discord_message_text(event, bot_user_id);
+
+    let mut prompt = format!(
+        "You are responding as Tau inside Discord.\nGuild: {}\nSession channel: {}\nSession scope: {}\nUser: <@{}> ({})\nEvent kind: {}\nEvent key: {}",
+        event.guild_id.as_deref().unwrap_or("direct message"),
+        event.channel_id,
+        event.scope.as_str(),
+        event.user_id,
+        event.user_name,
+        event.kind.as_str(),
+        event.key,
+    );
+    if let Some(parent) = event.parent_channel_id.as_deref() {
+        let label = if event.scope == DiscordChannelScope::ForumPost {
+            "Forum channel"
+        } else {
+            "Parent channel"
+        };
+        prompt.push_str(&format!("\n{label}: {parent}"));
+    }
+    prompt.push_str(&format!(
+        "\n\nUser message:\n{}",
+        if message_text.is_empty() {
+            "(empty message)"
+        } else {
+            &message_text
+        }
+    ));
+
+    if !event.attachments.is_empty() {
+        prompt.push_str("\n\nAttachments:\n");
+        for attachment in &event.attachments {
+            prompt.push_str(&format!(
+                "- id={} name={} url={} content_type={} size={}\n",
+                attachment.id,
+                attachment.filename,
+                attachment.url,
+                attachment.content_type.as_deref().unwrap_or("unknown"),
+                attachment.size
+            ));
+        }
+    }
+
+    prompt.push_str("\nProvide a direct, concise response formatted with Discord markdown.");
+    prompt
+}
+
+/// Truncates text to at most `max_chars` characters, including the `...` suffix.
+pub(super) fn truncate_for_discord(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        return value.to_string();
+    }
+    let mut truncated = value
+        .chars()
+        .take(max_chars.saturating_sub(3))
+        .collect::<String>();
+    truncated.push_str("...");
+    truncated
+}
+
+/// Splits text into chunks of at most `limit` characters.
+///
+/// Splits prefer line boundaries. A code fence that is open at a split point is
+/// closed at the end of the chunk and reopened at the start of the next one so
+/// every chunk renders as balanced markdown.
+pub(super) fn chunk_discord_text(text: &str, limit: usize) -> Vec<String> {
+    let limit = limit.max(DISCORD_FENCE_RESERVE_CHARS * 2);
+    let segment_limit = limit - DISCORD_FENCE_RESERVE_CHARS;
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0_usize;
+    let mut open_fence: Option<String> = None;
+
+    for line in text.split('\n') {
+        for segment in split_line_into_segments(line, segment_limit) {
+            let segment_chars = segment.chars().count();
+            let fence_close_reserve = if open_fence.is_some() { 4 } else { 0 };
+            let needed = current_chars + usize::from(!current.is_empty()) + segment_chars;
+            if !current.is_empty() && needed + fence_close_reserve > limit {
+                if open_fence.is_some() {
+                    current.push_str("\n```");
+                }
+                chunks.push(std::mem::take(&mut current));
+                if let Some(fence) = &open_fence {
+                    current = fence.clone();
+                }
+                current_chars = current.chars().count();
+            }
+            if !current.is_empty() {
+                current.push('\n');
+                current_chars += 1;
+            }
+            current.push_str(&segment);
+            current_chars += segment_chars;
+        }
+
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            open_fence = match open_fence {
+                Some(_) => None,
+                None => Some(
+                    trimmed
+                        .chars()
+                        .take(DISCORD_FENCE_OPENER_MAX_CHARS)
+                        .collect(),
+                ),
+            };
+        }
+    }
+
+    if !current.trim().is_empty() || chunks.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+fn split_line_into_segments(line: &str, segment_limit: usize) -> Vec<String> {
+    if line.chars().count() <= segment_limit {
+        return vec![line.to_string()];
+    }
+    let chars = line.chars().collect::<Vec<_>>();
+    chars
+        .chunks(segment_limit)
+        .map(|segment| segment.iter().collect::<String>())
+        .collect()
+}
+
+fn status_color(status: PromptRunStatus) -> u32 {
+    match status {
+        PromptRunStatus::Completed => DISCORD_EMBED_COLOR_COMPLETED,
+        PromptRunStatus::Cancelled => DISCORD_EMBED_COLOR_CANCELLED,
+        PromptRunStatus::TimedOut => DISCORD_EMBED_COLOR_TIMED_OUT,
+    }
+}
+
+/// Renders a run reply as Discord messages, as embeds or as plain chunked content.
+pub(super) fn render_discord_reply_chunks(
+    run: &PromptRunReport,
+    embed_responses: bool,
+) -> Vec<DiscordOutboundChunk> {
+    let reply = run.assistant_reply.trim();
+    let base_reply = if reply.is_empty() {
+        "I couldn't generate a textual response for this Discord event."
+    } else {
+        reply
+    };
+    let usage = &run.usage;
+    let run_line = format!(
+        "Tau run {} | status {} | model {} | tokens {}/{}/{}",
+        run.run_id,
+        prompt_status_label(run.status),
+        run.model,
+        usage.input_tokens,
+        usage.output_tokens,
+        usage.total_tokens
+    );
+    let artifact_line = format!(
+        "artifact {} | sha256 {} | bytes {}",
+        run.artifact.relative_path, run.artifact.checksum_sha256, run.artifact.bytes
+    );
+
+    if embed_responses {
+        let pieces = chunk_discord_text(base_reply, DISCORD_EMBED_DESCRIPTION_LIMIT);
+        let total = pieces.len();
+        return pieces
+            .into_iter()
+            .enumerate()
+            .map(|(index, description)| {
+                let footer = if index + 1 == total {
+                    format!("{run_line}\n{artifact_line}")
+                } else {
+                    format!("part {}/{}", index + 1, total)
+                };
+                DiscordOutboundChunk {
+                    content: String::new(),
+                    embed: Some(DiscordEmbedPayload {
+                        title: (index == 0).then(|| {
+                            truncate_for_discord(
+                                &format!("Tau run {}", run.run_id),
+                                DISCORD_EMBED_TITLE_LIMIT,
+                            )
+                        }),
+                        description,
+                        color: status_color(run.status),
+                        footer: Some(truncate_for_discord(&footer, DISCORD_EMBED_FOOTER_LIMIT)),
+                    }),
+                }
+            })
+            .collect();
+    }
+
+    let metadata = truncate_for_discord(
+        &format!("-# {run_line}\n-# {artifact_line}"),
+        DISCORD_MESSAGE_CHAR_LIMIT,
+    );
+    let mut pieces = chunk_discord_text(base_reply, DISCORD_MESSAGE_CHAR_LIMIT);
+    let last = pieces.len() - 1;
+    let appended_chars = pieces[last].chars().count() + 2 + metadata.chars().count();
+    if appended_chars <= DISCORD_MESSAGE_CHAR_LIMIT {
+        pieces[last].push_str("\n\n");
+        pieces[last].push_str(&metadata);
+    } else {
+        pieces.push(metadata);
+    }
+    pieces
+        .into_iter()
+        .map(|content| DiscordOutboundChunk {
+            content,
+            embed: None,
+        })
+        .collect()
+}
+
+pub(super) fn render_discord_run_error_chunks(
+    event: &DiscordBridgeEvent,
+    run_id: &str,
+    error: &anyhow::Error,
+    embed_responses: bool,
+) -> Vec<DiscordOutboundChunk> {
+    let description = format!(
+        "Tau run {} failed for event {}.\n\nError: {}",
+        run_id,
+        event.key,
+        truncate_for_error(&error.to_string(), 600)
+    );
+    if embed_responses {
+        return vec![DiscordOutboundChunk {
+            content: String::new(),
+            embed: Some(DiscordEmbedPayload {
+                title: Some(truncate_for_discord(
+                    &format!("Tau run {run_id} failed"),
+                    DISCORD_EMBED_TITLE_LIMIT,
+                )),
+                description: truncate_for_discord(&description, DISCORD_EMBED_DESCRIPTION_LIMIT),
+                color: DISCORD_EMBED_COLOR_FAILED,
+                footer: None,
+            }),
+        }];
+    }
+    vec![DiscordOutboundChunk::text(&description)]
+}
+
+/// Renders the message body that accompanies one approval's Approve/Reject buttons.
+pub(super) fn render_discord_approval_prompt(
+    approval: &DiscordApprovalPrompt,
+    embed_responses: bool,
+) -> DiscordOutboundChunk {
+    let description = format!(
+        "Tau paused `{}`: {}\nrule `{}` | request `{}`\nApprove or reject, then ask Tau to retry.",
+        approval.action_kind, approval.summary, approval.rule_id, approval.request_id
+    );
+    if embed_responses {
+        return DiscordOutboundChunk {
+            content: String::new(),
+            embed: Some(DiscordEmbedPayload {
+                title: Some(truncate_for_discord(
+                    &format!("Approval required: {}", approval.request_id),
+                    DISCORD_EMBED_TITLE_LIMIT,
+                )),
+                description: truncate_for_discord(&description, DISCORD_EMBED_DESCRIPTION_LIMIT),
+                color: DISCORD_EMBED_COLOR_APPROVAL,
+                footer: None,
+            }),
+        };
+    }
+    DiscordOutboundChunk::text(&format!(
+        "**Approval required** `{}`\n{}",
+        approval.request_id, description
+    ))
+}
+
+/// Collects approval-gated tool calls from a run so they can be offered as buttons.
+pub(super) fn extract_pending_approvals(messages: &[Message]) -> Vec<DiscordApprovalPrompt> {
+    let mut approvals: Vec<DiscordApprovalPrompt> = Vec::new();
+    for message in messages {
+        if message.role != MessageRole::Tool || !message.is_error {
+            continue;
+        }
+        let Ok(payload) = serde_json::from_str::<Value>(&message.text_content()) else {
+            continue;
+        };
+        if payload.get("policy_rule").and_then(Value::as_str) != Some("approval_gate") {
+            continue;
+        }
+        let Some(request_id) = payload
+            .get("approval_request_id")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            continue;
+        };
+        if approvals
+            .iter()
+            .any(|approval| approval.request_id == request_id)
+        {
+            continue;
+        }
+        let summary = match payload.get("action") {
+            Some(Value::String(action)) => action.clone(),
+            Some(action) => action.to_string(),
+            None => message.tool_name.clone().unwrap_or_default(),
+        };
+        approvals.push(DiscordApprovalPrompt {
+            request_id: request_id.to_string(),
+            rule_id: payload
+                .get("approval_rule_id")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string(),
+            action_kind: payload
+                .get("action_kind")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string(),
+            summary: truncate_for_discord(&summary, 300),
+        });
+        if approvals.len() >= DISCORD_MAX_APPROVAL_PROMPTS {
+            break;
+        }
+    }
+    approvals
+}
+
+pub(super) fn render_discord_artifact_markdown(
+    event: &DiscordBridgeEvent,
+    run_id: &str,
+    status: PromptRunStatus,
+    assistant_reply: &str,
+) -> String {
+    let mut lines = vec![
+        "# Tau Discord Artifact".to_string(),
+        format!(
+            "guild_id: {}",
+            event.guild_id.as_deref().unwrap_or("direct_message")
+        ),
+        format!("channel_id: {}", event.channel_id),
+        format!("scope: {}", event.scope.as_str()),
+        format!("event_key: {}", event.key),
+        format!("event_kind: {}", event.kind.as_str()),
+        format!("run_id: {}", run_id),
+        format!("status: {}", prompt_status_label(status)),
+    ];
+    if event.attachments.is_empty() {
+        lines.push("attachments: none".to_string());
+    } else {
+        lines.push(format!("attachments: {}", event.attachments.len()));
+        for attachment in &event.attachments {
+            lines.push(format!("- {} ({})", attachment.filename, attachment.url));
+        }
+    }
+    lines.push(String::new());
+    lines.push("## Assistant Reply".to_string());
+    lines.push(assistant_reply.trim().to_string());
+    lines.join("\n")
+}
+
+pub(super) fn normalize_artifact_retention_days(days: u64) -> Option<u64> {
+    if days == 0 {
+        None
+    } else {
+        Some(days)
+    }
+}
+
+pub(super) fn collect_assistant_reply(messages: &[Message]) -> String {
+    let content = messages
+        .iter()
+        .filter(|message| message.role == MessageRole::Assistant)
+        .map(Message::text_content)
+        .filter(|text| !text.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    if content.trim().is_empty() {
+        "I couldn't generate a textual response for this event.".to_string()
+    } else {
+        content
+    }
+}
+
+pub(super) fn prompt_status_label(status: PromptRunStatus) -> &'static str {
+    match status {
+        PromptRunStatus::Completed => "completed",
+        PromptRunStatus::Cancelled => "cancelled",
+        PromptRunStatus::TimedOut => "timed_out",
+    }
+}
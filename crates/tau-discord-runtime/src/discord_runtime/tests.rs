@@ -0,0 +1,613 @@
+//! Tests for Discord bridge runtime behavior and regressions.
+
+use std::{collections::VecDeque, path::Path, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::json;
+use serenity::all::ChannelType;
+use tau_ai::{ChatRequest, ChatResponse, ChatUsage, LlmClient, Message, TauAiError};
+use tempfile::tempdir;
+
+use super::{
+    dequeue_coalesced_event_for_run,
+    discord_command_helpers::{
+        approval_button_custom_id, parse_approval_button_custom_id, parse_discord_command,
+        rbac_action_for_discord_command, render_discord_command_response,
+        render_slash_invocation_text, DiscordApprovalDecision,
+    },
+    discord_gateway_handler::scope_for_guild_channel,
+    discord_guild_config::{authorize_discord_action, DiscordAdmission, DiscordGuildConfigFile},
+    discord_render_helpers::{
+        chunk_discord_text, extract_pending_approvals, render_discord_reply_chunks,
+        render_event_prompt, DISCORD_MESSAGE_CHAR_LIMIT,
+    },
+    event_is_stale, normalize_inbound_message, normalize_slash_invocation, run_prompt_for_event,
+    DiscordAttachment, DiscordBridgeEvent, DiscordBridgeEventKind, DiscordBridgeRuntimeConfig,
+    DiscordChannelScope, DiscordCommand, DiscordInboundMessage, DiscordSlashInvocation,
+    PromptRunReport, PromptRunStatus, PromptUsageSummary,
+};
+use crate::{
+    channel_store::{ChannelArtifactRecord, ChannelStore},
+    tools::ToolPolicy,
+    DiscordRuntimeBootstrapConfig, RbacDecision, RenderOptions,
+};
+
+struct StaticReplyClient;
+
+#[async_trait]
+impl LlmClient for StaticReplyClient {
+    async fn complete(&self, _request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        Ok(ChatResponse {
+            message: Message::assistant_text("discord bridge reply"),
+            finish_reason: Some("stop".to_string()),
+            usage: ChatUsage {
+                input_tokens: 11,
+                output_tokens: 7,
+                total_tokens: 18,
+                cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
+            },
+        })
+    }
+}
+
+fn test_config(state_dir: &Path) -> DiscordBridgeRuntimeConfig {
+    DiscordBridgeRuntimeConfig {
+        client: Arc::new(StaticReplyClient),
+        model: "openai/gpt-5.2".to_string(),
+        system_prompt: "You are Tau.".to_string(),
+        max_turns: 4,
+        tool_policy: ToolPolicy::new(vec![state_dir.to_path_buf()]),
+        turn_timeout_ms: 0,
+        render_options: RenderOptions {
+            stream_output: false,
+            stream_delay_ms: 0,
+        },
+        session_lock_wait_ms: 2_000,
+        session_lock_stale_ms: 30_000,
+        state_dir: state_dir.to_path_buf(),
+        bot_token: "discord-test-token".to_string(),
+        bootstrap: DiscordRuntimeBootstrapConfig::default(),
+        command_guild_ids: Vec::new(),
+        guild_config_path: state_dir.join("guilds.json"),
+        embed_responses: true,
+        processed_event_cap: 32,
+        max_event_age_seconds: 3_600,
+        coalescing_window_ms: 0,
+        reconnect_delay: Duration::from_millis(10),
+        artifact_retention_days: 30,
+    }
+}
+
+fn test_inbound_message(scope: DiscordChannelScope, content: &str) -> DiscordInboundMessage {
+    DiscordInboundMessage {
+        message_id: "5001".to_string(),
+        channel_id: "3001".to_string(),
+        parent_channel_id: match scope {
+            DiscordChannelScope::Thread | DiscordChannelScope::ForumPost => {
+                Some("2001".to_string())
+            }
+            _ => None,
+        },
+        guild_id: (scope != DiscordChannelScope::DirectMessage).then(|| "1001".to_string()),
+        scope,
+        author_id: "4001".to_string(),
+        author_name: "alice".to_string(),
+        author_is_bot: false,
+        member_role_ids: vec!["7001".to_string()],
+        mentioned_user_ids: Vec::new(),
+        content: content.to_string(),
+        timestamp_unix_ms: 1_000,
+        attachments: Vec::new(),
+    }
+}
+
+fn test_event(scope: DiscordChannelScope, text: &str) -> DiscordBridgeEvent {
+    normalize_inbound_message(&test_inbound_message(scope, text), "9001")
+        .expect("event")
+        .0
+}
+
+fn test_run_report(reply: &str) -> PromptRunReport {
+    PromptRunReport {
+        run_id: "discord-3001-1".to_string(),
+        model: "openai/gpt-5.2".to_string(),
+        status: PromptRunStatus::Completed,
+        assistant_reply: reply.to_string(),
+        usage: PromptUsageSummary {
+            input_tokens: 3,
+            output_tokens: 2,
+            total_tokens: 5,
+            request_duration_ms: 1,
+            finish_reason: Some("stop".to_string()),
+        },
+        pending_approvals: Vec::new(),
+        artifact: ChannelArtifactRecord {
+            id: "artifact-1".to_string(),
+            run_id: "discord-3001-1".to_string(),
+            artifact_type: "discord-reply".to_string(),
+            visibility: "private".to_string(),
+            relative_path: "artifacts/discord-3001-1/reply.md".to_string(),
+            bytes: 42,
+            checksum_sha256: "abc123".to_string(),
+            created_unix_ms: 1,
+            expires_unix_ms: None,
+        },
+    }
+}
+
+#[test]
+fn unit_normalize_inbound_message_maps_scope_to_event_kind() {
+    let cases = [
+        (
+            DiscordChannelScope::DirectMessage,
+            DiscordBridgeEventKind::DirectMessage,
+        ),
+        (
+            DiscordChannelScope::GuildText,
+            DiscordBridgeEventKind::Mention,
+        ),
+        (
+            DiscordChannelScope::Thread,
+            DiscordBridgeEventKind::ThreadMessage,
+        ),
+        (
+            DiscordChannelScope::ForumPost,
+            DiscordBridgeEventKind::ForumPost,
+        ),
+    ];
+    for (scope, expected_kind) in cases {
+        let mut message = test_inbound_message(scope, "<@9001> hello");
+        message.mentioned_user_ids = vec!["9001".to_string()];
+        let (event, mentioned) = normalize_inbound_message(&message, "9001").expect("event");
+        assert_eq!(event.kind, expected_kind);
+        assert_eq!(event.key, "message:5001");
+        assert_eq!(event.channel_id, "3001");
+        assert!(mentioned);
+    }
+}
+
+#[test]
+fn regression_normalize_inbound_message_ignores_bot_self_and_empty_messages() {
+    let mut bot_message = test_inbound_message(DiscordChannelScope::GuildText, "hi");
+    bot_message.author_is_bot = true;
+    assert!(normalize_inbound_message(&bot_message, "9001").is_none());
+
+    let mut self_message = test_inbound_message(DiscordChannelScope::GuildText, "hi");
+    self_message.author_id = "9001".to_string();
+    assert!(normalize_inbound_message(&self_message, "9001").is_none());
+
+    let empty = test_inbound_message(DiscordChannelScope::GuildText, "   ");
+    assert!(normalize_inbound_message(&empty, "9001").is_none());
+
+    let mut attachment_only = test_inbound_message(DiscordChannelScope::GuildText, "");
+    attachment_only.attachments.push(DiscordAttachment {
+        id: "8001".to_string(),
+        filename: "trace.log".to_string(),
+        url: "https://cdn.discordapp.com/attachments/trace.log".to_string(),
+        content_type: Some("text/plain".to_string()),
+        size: 12,
+    });
+    let (event, mentioned) =
+        normalize_inbound_message(&attachment_only, "9001").expect("attachment event");
+    assert!(!mentioned);
+    let prompt = render_event_prompt(&event, "9001");
+    assert!(prompt.contains("name=trace.log"));
+    assert!(prompt.contains("(empty message)"));
+}
+
+#[test]
+fn functional_parse_discord_command_strips_mentions_and_parses_subcommands() {
+    let status = test_event(DiscordChannelScope::GuildText, "<@!9001> /tau status");
+    assert_eq!(
+        parse_discord_command(&status, "9001", "/tau"),
+        Some(DiscordCommand::Status)
+    );
+
+    let approvals = test_event(DiscordChannelScope::GuildText, "/tau approvals");
+    assert_eq!(
+        parse_discord_command(&approvals, "9001", "/tau"),
+        Some(DiscordCommand::Approvals {
+            args: "list".to_string()
+        })
+    );
+
+    let show = test_event(
+        DiscordChannelScope::Thread,
+        "/tau artifacts show artifact-1",
+    );
+    assert_eq!(
+        parse_discord_command(&show, "9001", "/tau"),
+        Some(DiscordCommand::ArtifactShow {
+            artifact_id: "artifact-1".to_string()
+        })
+    );
+
+    let invalid = test_event(DiscordChannelScope::GuildText, "/tau stop now");
+    assert_eq!(
+        parse_discord_command(&invalid, "9001", "/tau"),
+        Some(DiscordCommand::Invalid {
+            message: "Usage: /tau stop".to_string()
+        })
+    );
+
+    let prompt = test_event(DiscordChannelScope::GuildText, "<@9001> summarize this");
+    assert_eq!(parse_discord_command(&prompt, "9001", "/tau"), None);
+    assert_eq!(
+        rbac_action_for_discord_command(None),
+        "command:/tau-run".to_string()
+    );
+    assert_eq!(
+        rbac_action_for_discord_command(Some(&DiscordCommand::Approvals {
+            args: "list".to_string()
+        })),
+        "command:/tau-approvals".to_string()
+    );
+}
+
+#[test]
+fn unit_normalize_slash_invocation_maps_ask_to_prompt_and_commands_to_text() {
+    assert_eq!(
+        render_slash_invocation_text("/tau", "ask", " explain the failure "),
+        "explain the failure"
+    );
+    assert_eq!(
+        render_slash_invocation_text("/tau", "approvals", ""),
+        "/tau approvals list"
+    );
+    assert_eq!(
+        render_slash_invocation_text("/tau", "artifacts", "run r1"),
+        "/tau artifacts run r1"
+    );
+
+    let invocation = DiscordSlashInvocation {
+        interaction_id: "6001".to_string(),
+        interaction_token: "token-1".to_string(),
+        guild_id: Some("1001".to_string()),
+        channel_id: "3001".to_string(),
+        parent_channel_id: None,
+        scope: DiscordChannelScope::GuildText,
+        user_id: "4001".to_string(),
+        user_name: "alice".to_string(),
+        member_role_ids: Vec::new(),
+        subcommand: "status".to_string(),
+        args: String::new(),
+        occurred_unix_ms: 1_000,
+    };
+    let event = normalize_slash_invocation(&invocation, "/tau");
+    assert_eq!(event.key, "interaction:6001");
+    assert_eq!(event.kind, DiscordBridgeEventKind::SlashCommand);
+    assert_eq!(event.interaction_token.as_deref(), Some("token-1"));
+    assert_eq!(
+        parse_discord_command(&event, "9001", "/tau"),
+        Some(DiscordCommand::Status)
+    );
+    assert!(!event.raw_payload.to_string().contains("token-1"));
+}
+
+#[test]
+fn unit_approval_button_custom_id_round_trips_and_rejects_foreign_ids() {
+    let approve = approval_button_custom_id(DiscordApprovalDecision::Approve, "req-7");
+    assert_eq!(approve, "tau:approval:approve:req-7");
+    assert_eq!(
+        parse_approval_button_custom_id(&approve),
+        Some((DiscordApprovalDecision::Approve, "req-7".to_string()))
+    );
+    assert_eq!(
+        parse_approval_button_custom_id("tau:approval:reject:req-8"),
+        Some((DiscordApprovalDecision::Reject, "req-8".to_string()))
+    );
+    assert_eq!(
+        parse_approval_button_custom_id("tau:approval:maybe:req-9"),
+        None
+    );
+    assert_eq!(
+        parse_approval_button_custom_id("tau:approval:approve:"),
+        None
+    );
+    assert_eq!(parse_approval_button_custom_id("other:approve:req-1"), None);
+}
+
+#[test]
+fn functional_chunk_discord_text_respects_limit_and_balances_code_fences() {
+    let mut text = String::from("Intro line\n```rust\n");
+    for index in 0..400 {
+        text.push_str(&format!("let value_{index} = {index};\n"));
+    }
+    text.push_str("```\nOutro line");
+
+    let chunks = chunk_discord_text(&text, DISCORD_MESSAGE_CHAR_LIMIT);
+    assert!(chunks.len() > 1);
+    for chunk in &chunks {
+        assert!(chunk.chars().count() <= DISCORD_MESSAGE_CHAR_LIMIT);
+        assert_eq!(chunk.matches("```").count() % 2, 0, "unbalanced: {chunk}");
+    }
+    assert!(chunks[1].starts_with("```rust"));
+    assert!(chunks.last().expect("last").ends_with("Outro line"));
+
+    let long_line = "x".repeat(5_000);
+    let chunks = chunk_discord_text(&long_line, DISCORD_MESSAGE_CHAR_LIMIT);
+    assert_eq!(chunks.concat(), long_line);
+    assert!(chunks
+        .iter()
+        .all(|chunk| chunk.chars().count() <= DISCORD_MESSAGE_CHAR_LIMIT));
+}
+
+#[test]
+fn functional_render_discord_reply_chunks_uses_embeds_with_final_footer() {
+    let reply = "paragraph\n".repeat(1_000);
+    let chunks = render_discord_reply_chunks(&test_run_report(&reply), true);
+    assert!(chunks.len() > 1);
+    let first = chunks[0].embed.as_ref().expect("embed");
+    assert_eq!(first.title.as_deref(), Some("Tau run discord-3001-1"));
+    assert_eq!(first.footer.as_deref(), Some("part 1/3"));
+    let last = chunks
+        .last()
+        .and_then(|chunk| chunk.embed.as_ref())
+        .expect("embed");
+    let footer = last.footer.as_deref().expect("footer");
+    assert!(footer.contains("Tau run discord-3001-1 | status completed"));
+    assert!(footer.contains("sha256 abc123"));
+    assert!(chunks
+        .iter()
+        .all(|chunk| chunk.content.is_empty() && chunk.embed.is_some()));
+}
+
+#[test]
+fn unit_render_discord_reply_chunks_plain_mode_appends_metadata_subtext() {
+    let chunks = render_discord_reply_chunks(&test_run_report("short reply"), false);
+    assert_eq!(chunks.len(), 1);
+    assert!(chunks[0].embed.is_none());
+    assert!(chunks[0]
+        .content
+        .starts_with("short reply\n\n-# Tau run discord-3001-1"));
+    assert!(chunks[0]
+        .content
+        .contains("-# artifact artifacts/discord-3001-1/reply.md"));
+
+    let event = test_event(DiscordChannelScope::GuildText, "/tau status");
+    let long_message = "y".repeat(5_000);
+    let response = render_discord_command_response(&event, "status", "reported", &long_message);
+    assert!(response.chars().count() <= DISCORD_MESSAGE_CHAR_LIMIT);
+    assert!(
+        response.ends_with("-# Tau command `status` | status `reported` | event `message:5001`")
+    );
+}
+
+#[test]
+fn functional_extract_pending_approvals_reads_approval_gate_tool_errors() {
+    let gated = json!({
+        "policy_rule": "approval_gate",
+        "approval_request_id": "req-1",
+        "approval_rule_id": "shell-writes",
+        "action_kind": "command",
+        "action": "rm -rf build",
+        "reason_code": "approval_required",
+    })
+    .to_string();
+    let messages = vec![
+        Message::tool_result("call-1", "bash", gated.clone(), true),
+        Message::tool_result("call-2", "bash", gated, true),
+        Message::tool_result("call-3", "bash", "{\"policy_rule\":\"allowlist\"}", true),
+        Message::tool_result(
+            "call-4",
+            "bash",
+            "{\"policy_rule\":\"approval_gate\",\"approval_request_id\":\"req-2\"}",
+            false,
+        ),
+    ];
+    let approvals = extract_pending_approvals(&messages);
+    assert_eq!(approvals.len(), 1);
+    assert_eq!(approvals[0].request_id, "req-1");
+    assert_eq!(approvals[0].rule_id, "shell-writes");
+    assert_eq!(approvals[0].action_kind, "command");
+    assert_eq!(approvals[0].summary, "rm -rf build");
+}
+
+#[test]
+fn functional_guild_config_admission_covers_threads_forums_and_mentions() {
+    let config: DiscordGuildConfigFile = serde_json::from_value(json!({
+        "schema_version": 1,
+        "allow_direct_messages": false,
+        "guilds": {
+            "1001": {
+                "allowed_channel_ids": ["2001", "3001"],
+                "forum_channel_ids": ["2001"],
+            }
+        }
+    }))
+    .expect("guild config");
+
+    let dm = test_event(DiscordChannelScope::DirectMessage, "hello");
+    assert_eq!(
+        config.admit_event(&dm, false, false),
+        DiscordAdmission::Ignore {
+            reason_code: "direct_messages_disabled"
+        }
+    );
+
+    let unmentioned = test_event(DiscordChannelScope::GuildText, "hello");
+    assert_eq!(
+        config.admit_event(&unmentioned, false, false),
+        DiscordAdmission::Ignore {
+            reason_code: "mention_required"
+        }
+    );
+    assert_eq!(
+        config.admit_event(&unmentioned, true, false),
+        DiscordAdmission::Accept
+    );
+
+    let thread = test_event(DiscordChannelScope::Thread, "follow-up");
+    assert_eq!(
+        config.admit_event(&thread, false, false),
+        DiscordAdmission::Ignore {
+            reason_code: "mention_required"
+        }
+    );
+    assert_eq!(
+        config.admit_event(&thread, false, true),
+        DiscordAdmission::Accept
+    );
+
+    let forum_post = test_event(DiscordChannelScope::ForumPost, "new post");
+    assert_eq!(
+        config.admit_event(&forum_post, false, false),
+        DiscordAdmission::Accept
+    );
+
+    let mut elsewhere = test_event(DiscordChannelScope::GuildText, "hello");
+    elsewhere.channel_id = "3999".to_string();
+    assert_eq!(
+        config.admit_event(&elsewhere, true, false),
+        DiscordAdmission::Ignore {
+            reason_code: "channel_not_allowed"
+        }
+    );
+}
+
+#[test]
+fn regression_guild_config_load_defaults_when_missing_and_rejects_unknown_schema() {
+    let temp = tempdir().expect("tempdir");
+    let path = temp.path().join("guilds.json");
+    let loaded = DiscordGuildConfigFile::load(&path).expect("default config");
+    assert_eq!(loaded, DiscordGuildConfigFile::default());
+
+    std::fs::write(&path, "{\"schema_version\": 9}").expect("write config");
+    let error = DiscordGuildConfigFile::load(&path).expect_err("unsupported schema");
+    assert!(error
+        .to_string()
+        .contains("unsupported discord guild config schema"));
+}
+
+#[test]
+fn integration_authorize_discord_action_falls_back_to_role_principal() {
+    let temp = tempdir().expect("tempdir");
+    let policy_path = temp.path().join("rbac.json");
+    std::fs::write(
+        &policy_path,
+        serde_json::to_string_pretty(&json!({
+            "schema_version": 1,
+            "team_mode": true,
+            "bindings": [
+                { "principal": "team:operators", "roles": ["operator"] }
+            ],
+            "roles": {
+                "operator": { "allow": ["command:/tau-*"] }
+            }
+        }))
+        .expect("policy json"),
+    )
+    .expect("write policy");
+    let config: DiscordGuildConfigFile = serde_json::from_value(json!({
+        "guilds": {
+            "1001": { "role_principals": { "7001": "team:operators" } }
+        }
+    }))
+    .expect("guild config");
+
+    let candidates = config.principal_candidates(Some("1001"), "4001", &["7001".to_string()]);
+    assert_eq!(
+        candidates,
+        vec!["discord:4001".to_string(), "team:operators".to_string()]
+    );
+    let allowed =
+        authorize_discord_action(&candidates, "command:/tau-run", &policy_path).expect("authorize");
+    assert!(allowed.decision.is_allowed());
+    assert_eq!(allowed.principal, "team:operators");
+
+    let user_only = config.principal_candidates(Some("1001"), "4001", &[]);
+    let denied =
+        authorize_discord_action(&user_only, "command:/tau-run", &policy_path).expect("authorize");
+    assert!(!denied.decision.is_allowed());
+    assert_eq!(denied.principal, "discord:4001");
+
+    let missing =
+        authorize_discord_action(&[], "command:/tau-run", &policy_path).expect("authorize");
+    assert!(matches!(
+        missing.decision,
+        RbacDecision::Deny { ref reason_code, .. } if reason_code == "deny_principal_missing"
+    ));
+}
+
+#[test]
+fn unit_scope_for_guild_channel_detects_threads_and_forum_posts() {
+    assert_eq!(
+        scope_for_guild_channel(ChannelType::Text, None),
+        DiscordChannelScope::GuildText
+    );
+    assert_eq!(
+        scope_for_guild_channel(ChannelType::PublicThread, Some(ChannelType::Text)),
+        DiscordChannelScope::Thread
+    );
+    assert_eq!(
+        scope_for_guild_channel(ChannelType::PublicThread, Some(ChannelType::Forum)),
+        DiscordChannelScope::ForumPost
+    );
+}
+
+#[test]
+fn unit_dequeue_coalesced_event_merges_messages_but_not_interactions() {
+    let mut first = test_event(DiscordChannelScope::Thread, "first");
+    first.occurred_unix_ms = 1_000;
+    let mut second = test_event(DiscordChannelScope::Thread, "second");
+    second.key = "message:5002".to_string();
+    second.message_id = Some("5002".to_string());
+    second.occurred_unix_ms = 1_200;
+    let mut slash = second.clone();
+    slash.key = "interaction:6001".to_string();
+    slash.interaction_token = Some("token".to_string());
+    slash.occurred_unix_ms = 1_300;
+
+    let mut queue = VecDeque::from(vec![first, second, slash]);
+    assert!(dequeue_coalesced_event_for_run(&mut queue, 1_400, 500).is_none());
+    let merged = dequeue_coalesced_event_for_run(&mut queue, 2_000, 500).expect("merged");
+    assert_eq!(merged.text, "first\nsecond");
+    assert_eq!(merged.message_id.as_deref(), Some("5002"));
+    assert_eq!(queue.len(), 1);
+    let interaction = dequeue_coalesced_event_for_run(&mut queue, 1_301, 500).expect("slash");
+    assert_eq!(interaction.key, "interaction:6001");
+}
+
+#[test]
+fn unit_event_is_stale_honors_zero_as_disabled() {
+    let event = test_event(DiscordChannelScope::GuildText, "hello");
+    assert!(!event_is_stale(&event, 0, 10_000_000));
+    assert!(!event_is_stale(&event, 60, 30_000));
+    assert!(event_is_stale(&event, 60, 70_000));
+}
+
+#[tokio::test]
+async fn functional_run_prompt_for_event_persists_thread_session_and_artifact() {
+    let temp = tempdir().expect("tempdir");
+    let config = test_config(temp.path());
+    let event = test_event(DiscordChannelScope::Thread, "<@9001> summarize the thread");
+    let (_cancel_tx, cancel_rx) = tokio::sync::watch::channel(false);
+
+    let report = run_prompt_for_event(
+        &config,
+        temp.path(),
+        &event,
+        "discord-3001-1",
+        cancel_rx,
+        "9001",
+        "discord:4001",
+    )
+    .await
+    .expect("run prompt");
+    assert_eq!(report.status, PromptRunStatus::Completed);
+    assert_eq!(report.assistant_reply, "discord bridge reply");
+    assert_eq!(report.usage.total_tokens, 18);
+    assert!(report.pending_approvals.is_empty());
+    assert!(report.artifact.expires_unix_ms.is_some());
+
+    let store = ChannelStore::open(&temp.path().join("channel-store"), "discord", "3001")
+        .expect("channel store");
+    assert!(store.session_path().exists());
+    let artifacts = store.load_artifact_records_tolerant().expect("artifacts");
+    assert_eq!(artifacts.records.len(), 1);
+    assert_eq!(artifacts.records[0].artifact_type, "discord-reply");
+}
@@ -576,6 +576,7 @@ fn wasm_runtime_capabilities_from_manifest(
         filesystem_mode,
         network_mode,
         env_allowlist: manifest.wasm.env_allowlist.clone(),
+        host_functions: Vec::new(),
     }
 }
 
@@ -399,6 +399,39 @@ fn regression_execute_extension_process_hook_wasm_fails_closed_on_unsupported_ne
         .contains("reason_code=wasm_capability_network_unsupported"));
 }
 
+#[test]
+fn regression_execute_extension_process_hook_wasm_fails_closed_on_filesystem_without_root() {
+    let temp = tempdir().expect("tempdir");
+    let module_path = temp.path().join("hook.wasm");
+    write_wasm_module(&module_path, r#"{"ok":true}"#);
+
+    let manifest_path = temp.path().join("extension.json");
+    fs::write(
+        &manifest_path,
+        r#"{
+  "schema_version": 1,
+  "id": "wasm-extension",
+  "version": "0.1.0",
+  "runtime": "wasm",
+  "entrypoint": "hook.wasm",
+  "hooks": ["run-start"],
+  "permissions": ["run-commands"],
+  "timeout_ms": 5000,
+  "wasm": {
+    "filesystem_mode": "read-only"
+  }
+}"#,
+    )
+    .expect("write manifest");
+
+    let payload = serde_json::json!({"event":"created"});
+    let error = execute_extension_process_hook(&manifest_path, "run-start", &payload)
+        .expect_err("wasm filesystem capability without a preopen root should fail closed");
+    assert!(error
+        .to_string()
+        .contains("reason_code=wasm_capability_filesystem_unsupported"));
+}
+
 #[test]
 fn regression_execute_extension_process_hook_rejects_undeclared_hook() {
     let temp = tempdir().expect("tempdir");
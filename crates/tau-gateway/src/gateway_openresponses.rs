@@ -42,10 +42,13 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::remote_profile::GatewayOpenResponsesAuthMode;
 
+mod anthropic_compat;
+mod anthropic_compat_runtime;
 mod audit_runtime;
 mod auth_runtime;
 mod auth_session_handler;
 mod channel_telemetry_runtime;
+mod client_tools_runtime;
 mod compat_state_runtime;
 mod config_runtime;
 mod cortex_bulletin_runtime;
@@ -60,7 +63,9 @@ mod events_status;
 mod external_agent_runtime;
 mod jobs_runtime;
 mod learning_runtime;
+mod mcp_http_runtime;
 mod memory_runtime;
+mod metrics_runtime;
 mod mission_api_runtime;
 mod mission_completion_runtime;
 mod mission_supervisor_runtime;
@@ -95,6 +100,11 @@ mod webchat_page;
 mod websocket;
 mod ws_stream_handlers;
 
+#[cfg(test)]
+use anthropic_compat::{
+    count_anthropic_input_tokens, translate_anthropic_messages_request, AnthropicMessagesRequest,
+};
+use anthropic_compat_runtime::{handle_anthropic_count_tokens, handle_anthropic_messages};
 use audit_runtime::{handle_gateway_audit_log, handle_gateway_audit_summary};
 use auth_runtime::{
     authorize_gateway_request, collect_gateway_auth_status_report, enforce_gateway_rate_limit,
@@ -104,6 +114,7 @@ use auth_session_handler::handle_gateway_auth_session;
 use channel_telemetry_runtime::{
     handle_gateway_channel_lifecycle_action, handle_gateway_ui_telemetry,
 };
+use client_tools_runtime::{describe_gateway_client_tool_calls, register_gateway_client_tools};
 use compat_state_runtime::{
     GatewayOpenAiCompatRuntimeState, GatewayOpenAiCompatSurface, GatewayUiTelemetryRuntimeState,
 };
@@ -150,12 +161,18 @@ use learning_runtime::{
 use learning_runtime::{
     build_gateway_learning_insight, gateway_action_history_path, load_gateway_action_history_store,
 };
+use mcp_http_runtime::{
+    handle_gateway_mcp_delete, handle_gateway_mcp_post, handle_gateway_mcp_stream,
+    GatewayMcpRuntimeState,
+};
 use memory_runtime::{
     gateway_memory_store, gateway_memory_store_root, gateway_memory_stores_root,
     handle_api_memories_graph, handle_gateway_memory_entry_delete,
     handle_gateway_memory_entry_read, handle_gateway_memory_entry_write,
     handle_gateway_memory_graph, handle_gateway_memory_read, handle_gateway_memory_write,
+    memory_record_json,
 };
+use metrics_runtime::handle_gateway_metrics;
 use mission_api_runtime::{handle_gateway_mission_detail, handle_gateway_missions_list};
 use mission_completion_runtime::{
     extract_gateway_completion_signal, register_gateway_completion_tool,
@@ -174,7 +191,7 @@ use openai_compat::{translate_chat_completions_request, OpenAiChatCompletionsReq
 use openai_compat_runtime::{
     handle_openai_chat_completions, handle_openai_completions, handle_openai_models,
 };
-use openresponses_entry_handler::handle_openresponses;
+use openresponses_entry_handler::{finish_openresponses_server_span, handle_openresponses};
 use openresponses_execution_handler::execute_openresponses_request;
 use ops_dashboard_shell::{
     handle_ops_dashboard_channel_action, handle_ops_dashboard_chat_new,
@@ -243,7 +260,7 @@ use types::{
     GatewayMemoryGraphNode, GatewayMemoryGraphQuery, GatewayMemoryGraphResponse,
     GatewayMemoryReadQuery, GatewayMemoryUpdateRequest, GatewaySafetyPolicyUpdateRequest,
     GatewaySafetyRulesUpdateRequest, GatewaySafetyTestRequest, OpenResponsesApiError,
-    OpenResponsesExecutionResult, OpenResponsesObservedToolExecution, OpenResponsesOutputItem,
+    OpenResponsesClientToolCall, OpenResponsesExecutionResult, OpenResponsesObservedToolExecution, OpenResponsesOutputItem,
     OpenResponsesOutputTextItem, OpenResponsesPrompt, OpenResponsesRequest, OpenResponsesResponse,
     OpenResponsesUsage, OpenResponsesUsageSummary, SseFrame,
 };
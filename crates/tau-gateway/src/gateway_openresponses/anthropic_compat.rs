@@ -0,0 +1,722 @@
+//! Anthropic Messages API request/response adapters layered onto the OpenResponses runtime.
+
+use std::collections::BTreeMap;
+
+use axum::http::{header::AUTHORIZATION, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tau_ai::{Message, TokenizerFamily, ToolChoice, ToolDefinition};
+
+use super::types::{
+    OpenResponsesApiError, OpenResponsesClientToolCall, OpenResponsesRequest,
+    OpenResponsesResponse, SseFrame,
+};
+
+const ANTHROPIC_API_KEY_HEADER: &str = "x-api-key";
+const ANTHROPIC_MESSAGE_OBJECT: &str = "message";
+
+#[derive(Debug, Deserialize)]
+pub(super) struct AnthropicMessagesRequest {
+    pub(super) model: Option<String>,
+    #[serde(default)]
+    pub(super) messages: Value,
+    #[serde(default)]
+    pub(super) system: Value,
+    #[serde(default)]
+    pub(super) max_tokens: Option<u64>,
+    #[serde(default)]
+    pub(super) stream: bool,
+    #[serde(default)]
+    pub(super) metadata: Value,
+    #[serde(flatten)]
+    pub(super) extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug)]
+pub(super) struct AnthropicRequestTranslation {
+    pub(super) request: OpenResponsesRequest,
+    pub(super) ignored_fields: Vec<String>,
+    pub(super) requested_model: Option<String>,
+    pub(super) stream: bool,
+}
+
+/// Anthropic-shaped error envelope (`{"type":"error","error":{...}}`).
+#[derive(Debug)]
+pub(super) struct AnthropicApiError(OpenResponsesApiError);
+
+impl From<OpenResponsesApiError> for AnthropicApiError {
+    fn from(error: OpenResponsesApiError) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for AnthropicApiError {
+    fn into_response(self) -> Response {
+        let error = self.0;
+        (
+            error.status,
+            Json(build_anthropic_error_payload(
+                error.status,
+                error.code,
+                error.message.as_str(),
+            )),
+        )
+            .into_response()
+    }
+}
+
+/// Accept the Anthropic SDK `x-api-key` header as the gateway bearer token.
+pub(super) fn anthropic_authorization_headers(headers: &HeaderMap) -> HeaderMap {
+    if headers.contains_key(AUTHORIZATION) {
+        return headers.clone();
+    }
+    let mut normalized = headers.clone();
+    if let Some(api_key) = headers
+        .get(ANTHROPIC_API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        if let Ok(value) = HeaderValue::from_str(format!("Bearer {api_key}").as_str()) {
+            normalized.insert(AUTHORIZATION, value);
+        }
+    }
+    normalized
+}
+
+/// Translate Anthropic messages payload into OpenResponses runtime request envelope.
+///
+/// `tool_use` blocks become `function_call` items and `tool_result` blocks become
+/// `function_call_output` items. Client-declared `tools` are offered to the model
+/// alongside the gateway's own tools; calls to them are returned as `tool_use`.
+pub(super) fn translate_anthropic_messages_request(
+    request: AnthropicMessagesRequest,
+) -> Result<AnthropicRequestTranslation, OpenResponsesApiError> {
+    let mut ignored_fields = Vec::new();
+    let mut extra = request.extra;
+    for client_side in ["stop_sequences", "thinking"] {
+        if extra.remove(client_side).is_some() {
+            ignored_fields.push(client_side.to_string());
+        }
+    }
+    let client_tools =
+        translate_anthropic_tools(extra.remove("tools").as_ref(), &mut ignored_fields)?;
+    let client_tool_choice = translate_anthropic_tool_choice(
+        extra.remove("tool_choice").as_ref(),
+        &client_tools,
+        &mut ignored_fields,
+    )?;
+    let max_tokens = parse_anthropic_max_tokens(request.max_tokens)?;
+    let instructions = extract_anthropic_system_text(&request.system)?;
+
+    let messages = match request.messages {
+        Value::Array(messages) => messages,
+        _ => {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_messages",
+                "messages must be an array",
+            ));
+        }
+    };
+    if messages.is_empty() {
+        return Err(OpenResponsesApiError::bad_request(
+            "missing_messages",
+            "messages must include at least one item",
+        ));
+    }
+
+    let mut input_items = Vec::new();
+    for (index, message) in messages.into_iter().enumerate() {
+        let Value::Object(map) = message else {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_messages",
+                format!("messages[{index}] must be an object"),
+            ));
+        };
+        let role = match map.get("role").and_then(Value::as_str).map(str::trim) {
+            Some("user") => "user",
+            Some("assistant") => "assistant",
+            _ => {
+                return Err(OpenResponsesApiError::bad_request(
+                    "invalid_role",
+                    format!("messages[{index}].role must be 'user' or 'assistant'"),
+                ));
+            }
+        };
+        match map.get("content").unwrap_or(&Value::Null) {
+            Value::String(text) => {
+                push_anthropic_text_item(&mut input_items, role, text);
+            }
+            Value::Array(blocks) => {
+                for (block_index, block) in blocks.iter().enumerate() {
+                    translate_anthropic_content_block(
+                        block,
+                        role,
+                        format!("messages[{index}].content[{block_index}]").as_str(),
+                        &mut input_items,
+                        &mut ignored_fields,
+                    )?;
+                }
+            }
+            _ => ignored_fields.push(format!("messages[{index}].content")),
+        }
+    }
+
+    if input_items.is_empty() {
+        return Err(OpenResponsesApiError::bad_request(
+            "missing_messages",
+            "messages did not include any textual content",
+        ));
+    }
+
+    let session_user = request
+        .metadata
+        .get("user_id")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let metadata = session_user
+        .as_ref()
+        .map(|user| json!({ "session_id": user }))
+        .unwrap_or_else(|| json!({}));
+    let requested_model = request.model.clone();
+
+    Ok(AnthropicRequestTranslation {
+        stream: request.stream,
+        requested_model,
+        ignored_fields,
+        request: OpenResponsesRequest {
+            model: request.model,
+            input: Value::Array(input_items),
+            stream: request.stream,
+            max_tokens,
+            instructions,
+            metadata,
+            conversation: session_user,
+            previous_response_id: None,
+            client_tools,
+            client_tool_choice,
+            extra,
+        },
+    })
+}
+
+fn translate_anthropic_content_block(
+    block: &Value,
+    role: &str,
+    path: &str,
+    input_items: &mut Vec<Value>,
+    ignored_fields: &mut Vec<String>,
+) -> Result<(), OpenResponsesApiError> {
+    let block_type = block.get("type").and_then(Value::as_str).unwrap_or("text");
+    match block_type {
+        "text" => {
+            let text = block
+                .get("text")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            push_anthropic_text_item(input_items, role, text);
+        }
+        "tool_use" => {
+            let name = block
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let id = block.get("id").and_then(Value::as_str).unwrap_or("unknown");
+            if name.trim().is_empty() {
+                return Err(OpenResponsesApiError::bad_request(
+                    "invalid_tool_use",
+                    format!("{path} tool_use block requires a name"),
+                ));
+            }
+            let input = block.get("input").cloned().unwrap_or_else(|| json!({}));
+            input_items.push(json!({
+                "type": "function_call",
+                "call_id": id,
+                "name": name.trim(),
+                "arguments": input.to_string(),
+            }));
+        }
+        "tool_result" => {
+            let Some(tool_use_id) = block
+                .get("tool_use_id")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+            else {
+                return Err(OpenResponsesApiError::bad_request(
+                    "invalid_tool_result",
+                    format!("{path} tool_result block requires tool_use_id"),
+                ));
+            };
+            let mut output = extract_anthropic_tool_result_text(block.get("content"));
+            if block.get("is_error").and_then(Value::as_bool) == Some(true) {
+                output = format!("[tool error] {output}");
+            }
+            if output.trim().is_empty() {
+                ignored_fields.push(path.to_string());
+                return Ok(());
+            }
+            input_items.push(json!({
+                "type": "function_call_output",
+                "call_id": tool_use_id,
+                "output": output,
+            }));
+        }
+        _ => ignored_fields.push(path.to_string()),
+    }
+    Ok(())
+}
+
+/// Map Anthropic `tools` onto function definitions; server tools are ignored.
+fn translate_anthropic_tools(
+    tools: Option<&Value>,
+    ignored_fields: &mut Vec<String>,
+) -> Result<Vec<ToolDefinition>, OpenResponsesApiError> {
+    let tools = match tools {
+        None | Some(Value::Null) => return Ok(Vec::new()),
+        Some(Value::Array(tools)) => tools,
+        Some(_) => {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_tools",
+                "tools must be an array",
+            ));
+        }
+    };
+    let mut definitions = Vec::with_capacity(tools.len());
+    for (index, tool) in tools.iter().enumerate() {
+        let tool_type = tool.get("type").and_then(Value::as_str).unwrap_or("custom");
+        if tool_type != "custom" {
+            ignored_fields.push(format!("tools[{index}]"));
+            continue;
+        }
+        let Some(name) = tool
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        else {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_tools",
+                format!("tools[{index}] requires a name"),
+            ));
+        };
+        if definitions
+            .iter()
+            .any(|definition: &ToolDefinition| definition.name == name)
+        {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_tools",
+                format!("tools[{index}] duplicates tool name '{name}'"),
+            ));
+        }
+        let parameters = match tool.get("input_schema") {
+            Some(Value::Object(schema)) => Value::Object(schema.clone()),
+            None | Some(Value::Null) => json!({ "type": "object" }),
+            Some(_) => {
+                return Err(OpenResponsesApiError::bad_request(
+                    "invalid_tools",
+                    format!("tools[{index}].input_schema must be an object"),
+                ));
+            }
+        };
+        definitions.push(ToolDefinition {
+            name: name.to_string(),
+            description: tool
+                .get("description")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            parameters,
+        });
+    }
+    Ok(definitions)
+}
+
+fn translate_anthropic_tool_choice(
+    tool_choice: Option<&Value>,
+    client_tools: &[ToolDefinition],
+    ignored_fields: &mut Vec<String>,
+) -> Result<Option<ToolChoice>, OpenResponsesApiError> {
+    let Some(tool_choice) = tool_choice.filter(|value| !value.is_null()) else {
+        return Ok(None);
+    };
+    if tool_choice.get("disable_parallel_tool_use").is_some() {
+        ignored_fields.push("tool_choice.disable_parallel_tool_use".to_string());
+    }
+    match tool_choice.get("type").and_then(Value::as_str) {
+        Some("auto") => Ok(Some(ToolChoice::Auto)),
+        Some("none") => Ok(Some(ToolChoice::None)),
+        Some("any") => Ok(Some(ToolChoice::Required)),
+        Some("tool") => {
+            let name = tool_choice
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .unwrap_or_default();
+            if !client_tools.iter().any(|tool| tool.name == name) {
+                return Err(OpenResponsesApiError::bad_request(
+                    "invalid_tool_choice",
+                    format!("tool_choice names undeclared tool '{name}'"),
+                ));
+            }
+            Ok(Some(ToolChoice::Tool {
+                name: name.to_string(),
+            }))
+        }
+        _ => Err(OpenResponsesApiError::bad_request(
+            "invalid_tool_choice",
+            "tool_choice.type must be one of: auto, any, tool, none",
+        )),
+    }
+}
+
+fn push_anthropic_text_item(input_items: &mut Vec<Value>, role: &str, text: &str) {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+    input_items.push(json!({
+        "type": "message",
+        "role": role,
+        "content": trimmed,
+    }));
+}
+
+fn extract_anthropic_tool_result_text(content: Option<&Value>) -> String {
+    match content {
+        Some(Value::String(text)) => text.trim().to_string(),
+        Some(Value::Array(blocks)) => blocks
+            .iter()
+            .filter(|block| block.get("type").and_then(Value::as_str) == Some("text"))
+            .filter_map(|block| block.get("text").and_then(Value::as_str))
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+fn extract_anthropic_system_text(system: &Value) -> Result<Option<String>, OpenResponsesApiError> {
+    let text = match system {
+        Value::Null => return Ok(None),
+        Value::String(text) => text.trim().to_string(),
+        Value::Array(blocks) => blocks
+            .iter()
+            .filter_map(|block| block.get("text").and_then(Value::as_str))
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => {
+            return Err(OpenResponsesApiError::bad_request(
+                "invalid_system",
+                "system must be a string or an array of text blocks",
+            ));
+        }
+    };
+    Ok((!text.is_empty()).then_some(text))
+}
+
+fn parse_anthropic_max_tokens(
+    max_tokens: Option<u64>,
+) -> Result<Option<u32>, OpenResponsesApiError> {
+    let Some(max_tokens) = max_tokens else {
+        return Ok(None);
+    };
+    if max_tokens == 0 {
+        return Err(OpenResponsesApiError::bad_request(
+            "invalid_max_tokens",
+            "max_tokens must be greater than zero",
+        ));
+    }
+    let max_tokens = u32::try_from(max_tokens).map_err(|_| {
+        OpenResponsesApiError::bad_request(
+            "invalid_max_tokens",
+            "max_tokens exceeds supported range",
+        )
+    })?;
+    Ok(Some(max_tokens))
+}
+
+/// Input token count for `/v1/messages/count_tokens` using the model's tokenizer.
+pub(super) fn count_anthropic_input_tokens(model: &str, system_prompt: &str, prompt: &str) -> u64 {
+    let tokenizer = TokenizerFamily::for_model(model);
+    let system_tokens = if system_prompt.is_empty() {
+        0
+    } else {
+        tokenizer.count_message_tokens(&Message::system(system_prompt))
+    };
+    u64::from(system_tokens) + u64::from(tokenizer.count_message_tokens(&Message::user(prompt)))
+}
+
+/// Build Anthropic `message` JSON payload from one OpenResponses result.
+pub(super) fn build_anthropic_message_payload(
+    response: &OpenResponsesResponse,
+    client_tool_calls: &[OpenResponsesClientToolCall],
+) -> Value {
+    let mut content = Vec::with_capacity(client_tool_calls.len() + 1);
+    if client_tool_calls.is_empty() || !response.output_text.trim().is_empty() {
+        content.push(json!({
+            "type": "text",
+            "text": response.output_text,
+        }));
+    }
+    content.extend(client_tool_calls.iter().map(anthropic_tool_use_block));
+    json!({
+        "id": anthropic_message_id(response.id.as_str()),
+        "type": ANTHROPIC_MESSAGE_OBJECT,
+        "role": "assistant",
+        "model": response.model,
+        "content": content,
+        "stop_reason": anthropic_stop_reason(response),
+        "stop_sequence": Value::Null,
+        "usage": {
+            "input_tokens": response.usage.input_tokens,
+            "output_tokens": response.usage.output_tokens,
+        }
+    })
+}
+
+/// Translates live gateway stream frames into the Anthropic `message` SSE sequence.
+#[derive(Debug, Default)]
+pub(super) struct AnthropicMessageStreamTranslator {
+    message_started: bool,
+    text_block_open: bool,
+    streamed_text: String,
+    next_block_index: usize,
+}
+
+impl AnthropicMessageStreamTranslator {
+    /// Anthropic events for one gateway frame; frames without an Anthropic
+    /// counterpart (tool progress, reasoning) produce nothing.
+    pub(super) fn translate_frame(&mut self, frame: &SseFrame) -> Vec<(&'static str, Value)> {
+        let SseFrame::Json { event, payload } = frame else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        match *event {
+            "response.created" => {
+                self.start_message(
+                    &mut events,
+                    payload["response"]["id"].as_str().unwrap_or_default(),
+                    payload["response"]["model"].as_str().unwrap_or_default(),
+                );
+            }
+            "response.output_text.delta" => {
+                let delta = payload["delta"].as_str().unwrap_or_default();
+                if !delta.is_empty() {
+                    self.push_text_delta(&mut events, delta);
+                }
+            }
+            _ => {}
+        }
+        events
+    }
+
+    /// Closing events once execution finished: any text not yet streamed, client
+    /// `tool_use` blocks, then `message_delta` and `message_stop`.
+    pub(super) fn finish(
+        &mut self,
+        response: &OpenResponsesResponse,
+        client_tool_calls: &[OpenResponsesClientToolCall],
+    ) -> Vec<(&'static str, Value)> {
+        let mut events = Vec::new();
+        self.start_message(&mut events, response.id.as_str(), response.model.as_str());
+        let remaining = response
+            .output_text
+            .strip_prefix(self.streamed_text.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if !remaining.is_empty() {
+            self.push_text_delta(&mut events, remaining.as_str());
+        } else if client_tool_calls.is_empty()
+            && !self.text_block_open
+            && self.next_block_index == 0
+        {
+            self.open_text_block(&mut events);
+        }
+        self.close_text_block(&mut events);
+        for call in client_tool_calls {
+            events.extend(anthropic_tool_use_stream_events(
+                self.next_block_index,
+                call,
+            ));
+            self.next_block_index += 1;
+        }
+        events.push((
+            "message_delta",
+            json!({
+                "type": "message_delta",
+                "delta": {
+                    "stop_reason": anthropic_stop_reason(response),
+                    "stop_sequence": Value::Null,
+                },
+                "usage": {
+                    "input_tokens": response.usage.input_tokens,
+                    "output_tokens": response.usage.output_tokens,
+                },
+            }),
+        ));
+        events.push(("message_stop", json!({ "type": "message_stop" })));
+        events
+    }
+
+    fn start_message(
+        &mut self,
+        events: &mut Vec<(&'static str, Value)>,
+        response_id: &str,
+        model: &str,
+    ) {
+        if self.message_started {
+            return;
+        }
+        self.message_started = true;
+        events.push((
+            "message_start",
+            json!({
+                "type": "message_start",
+                "message": {
+                    "id": anthropic_message_id(response_id),
+                    "type": ANTHROPIC_MESSAGE_OBJECT,
+                    "role": "assistant",
+                    "model": model,
+                    "content": [],
+                    "stop_reason": Value::Null,
+                    "stop_sequence": Value::Null,
+                    "usage": {
+                        "input_tokens": 0,
+                        "output_tokens": 0,
+                    }
+                }
+            }),
+        ));
+    }
+
+    fn push_text_delta(&mut self, events: &mut Vec<(&'static str, Value)>, delta: &str) {
+        if !self.text_block_open {
+            self.open_text_block(events);
+        }
+        self.streamed_text.push_str(delta);
+        events.push((
+            "content_block_delta",
+            json!({
+                "type": "content_block_delta",
+                "index": self.next_block_index,
+                "delta": { "type": "text_delta", "text": delta },
+            }),
+        ));
+    }
+
+    fn open_text_block(&mut self, events: &mut Vec<(&'static str, Value)>) {
+        self.text_block_open = true;
+        events.push((
+            "content_block_start",
+            json!({
+                "type": "content_block_start",
+                "index": self.next_block_index,
+                "content_block": { "type": "text", "text": "" },
+            }),
+        ));
+    }
+
+    fn close_text_block(&mut self, events: &mut Vec<(&'static str, Value)>) {
+        if !self.text_block_open {
+            return;
+        }
+        self.text_block_open = false;
+        events.push((
+            "content_block_stop",
+            json!({ "type": "content_block_stop", "index": self.next_block_index }),
+        ));
+        self.next_block_index += 1;
+    }
+}
+
+/// `content_block_start`/`input_json_delta`/`content_block_stop` frames for one tool call.
+fn anthropic_tool_use_stream_events(
+    index: usize,
+    call: &OpenResponsesClientToolCall,
+) -> [(&'static str, Value); 3] {
+    [
+        (
+            "content_block_start",
+            json!({
+                "type": "content_block_start",
+                "index": index,
+                "content_block": {
+                    "type": "tool_use",
+                    "id": call.call_id,
+                    "name": call.name,
+                    "input": {},
+                },
+            }),
+        ),
+        (
+            "content_block_delta",
+            json!({
+                "type": "content_block_delta",
+                "index": index,
+                "delta": {
+                    "type": "input_json_delta",
+                    "partial_json": call.arguments.to_string(),
+                },
+            }),
+        ),
+        (
+            "content_block_stop",
+            json!({ "type": "content_block_stop", "index": index }),
+        ),
+    ]
+}
+
+pub(super) fn build_anthropic_error_payload(
+    status: StatusCode,
+    code: &str,
+    message: &str,
+) -> Value {
+    let error_type = match status {
+        StatusCode::UNAUTHORIZED => "authentication_error",
+        StatusCode::FORBIDDEN => "permission_error",
+        StatusCode::NOT_FOUND => "not_found_error",
+        StatusCode::PAYLOAD_TOO_LARGE => "request_too_large",
+        StatusCode::TOO_MANY_REQUESTS => "rate_limit_error",
+        status if status.is_client_error() => "invalid_request_error",
+        _ => "api_error",
+    };
+    json!({
+        "type": "error",
+        "error": {
+            "type": error_type,
+            "code": code,
+            "message": message,
+        }
+    })
+}
+
+fn anthropic_tool_use_block(call: &OpenResponsesClientToolCall) -> Value {
+    json!({
+        "type": "tool_use",
+        "id": call.call_id,
+        "name": call.name,
+        "input": call.arguments,
+    })
+}
+
+fn anthropic_message_id(response_id: &str) -> String {
+    let suffix = response_id.strip_prefix("resp_").unwrap_or(response_id);
+    format!("msg_{suffix}")
+}
+
+fn anthropic_stop_reason(response: &OpenResponsesResponse) -> &'static str {
+    match response.finish_reason.trim() {
+        "length" | "max_tokens" => "max_tokens",
+        "tool_calls" | "tool_use" => "tool_use",
+        "stop_sequence" => "stop_sequence",
+        _ => "end_turn",
+    }
+}
@@ -0,0 +1,214 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use futures_util::StreamExt;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use super::anthropic_compat::{
+    anthropic_authorization_headers, build_anthropic_error_payload,
+    build_anthropic_message_payload, count_anthropic_input_tokens,
+    translate_anthropic_messages_request, AnthropicApiError, AnthropicMessageStreamTranslator,
+    AnthropicMessagesRequest, AnthropicRequestTranslation,
+};
+use super::{
+    authorize_and_enforce_gateway_limits, execute_openresponses_request, parse_gateway_json_body,
+    translate_openresponses_request, validate_gateway_request_body_size,
+    GatewayOpenAiCompatSurface, GatewayOpenResponsesServerState, OpenResponsesRequest, SseFrame,
+};
+
+pub(super) async fn handle_anthropic_messages(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let translated = match preflight_anthropic_request(
+        &state,
+        &headers,
+        &body,
+        GatewayOpenAiCompatSurface::AnthropicMessages,
+        "anthropic_messages",
+    ) {
+        Ok(translated) => translated,
+        Err(error) => return error.into_response(),
+    };
+
+    if translated.stream {
+        return stream_anthropic_messages(state, translated.request, translated.ignored_fields)
+            .await;
+    }
+
+    match execute_openresponses_request(state.clone(), translated.request, None).await {
+        Ok(result) => {
+            let mut ignored_fields = translated.ignored_fields;
+            ignored_fields.extend(result.response.ignored_fields.clone());
+            if !ignored_fields.is_empty() {
+                state.record_openai_compat_reason("anthropic_messages_ignored_fields");
+            }
+            state.record_openai_compat_ignored_fields(&ignored_fields);
+            state.record_openai_compat_reason("anthropic_messages_succeeded");
+            (
+                StatusCode::OK,
+                Json(build_anthropic_message_payload(
+                    &result.response,
+                    &result.client_tool_calls,
+                )),
+            )
+                .into_response()
+        }
+        Err(error) => {
+            state.increment_openai_compat_execution_failures();
+            state.record_openai_compat_reason("anthropic_messages_execution_failed");
+            AnthropicApiError::from(error).into_response()
+        }
+    }
+}
+
+pub(super) async fn handle_anthropic_count_tokens(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let translated = match preflight_anthropic_request(
+        &state,
+        &headers,
+        &body,
+        GatewayOpenAiCompatSurface::AnthropicCountTokens,
+        "anthropic_count_tokens",
+    ) {
+        Ok(translated) => translated,
+        Err(error) => return error.into_response(),
+    };
+
+    let prompt =
+        match translate_openresponses_request(&translated.request, state.config.max_input_chars) {
+            Ok(prompt) => prompt,
+            Err(error) => {
+                state.increment_openai_compat_translation_failures();
+                state.record_openai_compat_reason("anthropic_count_tokens_translation_failed");
+                return AnthropicApiError::from(error).into_response();
+            }
+        };
+    let model = translated
+        .requested_model
+        .as_deref()
+        .unwrap_or(state.config.model.as_str());
+    let input_tokens = count_anthropic_input_tokens(
+        model,
+        state.resolved_system_prompt().as_str(),
+        prompt.prompt.as_str(),
+    );
+    state.record_openai_compat_reason("anthropic_count_tokens_succeeded");
+    (
+        StatusCode::OK,
+        Json(json!({ "input_tokens": input_tokens })),
+    )
+        .into_response()
+}
+
+fn preflight_anthropic_request(
+    state: &Arc<GatewayOpenResponsesServerState>,
+    headers: &HeaderMap,
+    body: &Bytes,
+    surface: GatewayOpenAiCompatSurface,
+    reason_prefix: &str,
+) -> Result<AnthropicRequestTranslation, AnthropicApiError> {
+    authorize_and_enforce_gateway_limits(state, &anthropic_authorization_headers(headers))?;
+    state.record_openai_compat_reason(format!("{reason_prefix}_request_received").as_str());
+
+    if let Err(error) = validate_gateway_request_body_size(state, body) {
+        state.increment_openai_compat_translation_failures();
+        state.record_openai_compat_reason(format!("{reason_prefix}_body_too_large").as_str());
+        return Err(error.into());
+    }
+
+    let request = match parse_gateway_json_body::<AnthropicMessagesRequest>(body) {
+        Ok(request) => request,
+        Err(error) => {
+            state.increment_openai_compat_translation_failures();
+            state.record_openai_compat_reason(format!("{reason_prefix}_malformed_json").as_str());
+            return Err(error.into());
+        }
+    };
+
+    let translated = match translate_anthropic_messages_request(request) {
+        Ok(translated) => translated,
+        Err(error) => {
+            state.increment_openai_compat_translation_failures();
+            state.record_openai_compat_reason(
+                format!("{reason_prefix}_translation_failed").as_str(),
+            );
+            return Err(error.into());
+        }
+    };
+
+    state.record_openai_compat_request(surface, translated.stream);
+    if translated
+        .requested_model
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .is_some()
+    {
+        state.record_openai_compat_reason(
+            format!("{reason_prefix}_model_override_ignored").as_str(),
+        );
+    }
+    state.record_openai_compat_ignored_fields(&translated.ignored_fields);
+    Ok(translated)
+}
+
+async fn stream_anthropic_messages(
+    state: Arc<GatewayOpenResponsesServerState>,
+    request: OpenResponsesRequest,
+    compat_ignored_fields: Vec<String>,
+) -> Response {
+    let (tx, rx) = mpsc::unbounded_channel::<Event>();
+    tokio::spawn(async move {
+        let (frame_tx, mut frame_rx) = mpsc::unbounded_channel::<SseFrame>();
+        let mut translator = AnthropicMessageStreamTranslator::default();
+        let send_events = |events: Vec<(&'static str, serde_json::Value)>| {
+            for (event, payload) in events {
+                let _ = tx.send(Event::default().event(event).data(payload.to_string()));
+            }
+        };
+        let execution = execute_openresponses_request(state.clone(), request, Some(frame_tx));
+        let forward = async {
+            while let Some(frame) = frame_rx.recv().await {
+                send_events(translator.translate_frame(&frame));
+            }
+        };
+        let (result, ()) = tokio::join!(execution, forward);
+        match result {
+            Ok(result) => {
+                let mut ignored_fields = compat_ignored_fields;
+                ignored_fields.extend(result.response.ignored_fields.clone());
+                if !ignored_fields.is_empty() {
+                    state.record_openai_compat_reason("anthropic_messages_stream_ignored_fields");
+                }
+                state.record_openai_compat_ignored_fields(&ignored_fields);
+                send_events(translator.finish(&result.response, &result.client_tool_calls));
+                state.record_openai_compat_reason("anthropic_messages_stream_succeeded");
+            }
+            Err(error) => {
+                state.increment_openai_compat_execution_failures();
+                state.record_openai_compat_reason("anthropic_messages_stream_failed");
+                let payload =
+                    build_anthropic_error_payload(error.status, error.code, error.message.as_str());
+                let _ = tx.send(Event::default().event("error").data(payload.to_string()));
+            }
+        }
+    });
+
+    let stream = UnboundedReceiverStream::new(rx).map(Ok::<Event, Infallible>);
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
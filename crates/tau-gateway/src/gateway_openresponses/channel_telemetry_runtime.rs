@@ -11,6 +11,7 @@ use axum::Json;
 use serde_json::{json, Value};
 use tau_core::current_unix_timestamp_ms;
 use tau_multi_channel::multi_channel_contract::MultiChannelTransport;
+use tau_multi_channel::multi_channel_email::MultiChannelEmailAccountConfig;
 use tau_multi_channel::multi_channel_lifecycle::{
     default_probe_max_attempts, default_probe_retry_delay_ms, default_probe_timeout_ms,
     execute_multi_channel_lifecycle_action, MultiChannelLifecycleAction,
@@ -140,9 +141,11 @@ fn parse_gateway_channel_transport(
         "telegram" => Ok(MultiChannelTransport::Telegram),
         "discord" => Ok(MultiChannelTransport::Discord),
         "whatsapp" => Ok(MultiChannelTransport::Whatsapp),
+        "matrix" => Ok(MultiChannelTransport::Matrix),
+        "email" => Ok(MultiChannelTransport::Email),
         _ => Err(OpenResponsesApiError::bad_request(
             "invalid_channel",
-            "channel must be one of: telegram, discord, whatsapp",
+            "channel must be one of: telegram, discord, whatsapp, matrix, email",
         )),
     }
 }
@@ -190,6 +193,8 @@ pub(super) fn build_gateway_multi_channel_lifecycle_command_config(
         telegram_api_base: "https://api.telegram.org".to_string(),
         discord_api_base: "https://discord.com/api/v10".to_string(),
         whatsapp_api_base: "https://graph.facebook.com/v20.0".to_string(),
+        matrix_homeserver_url: resolve_gateway_channel_env_secret("TAU_MATRIX_HOMESERVER_URL")
+            .unwrap_or_else(|| "https://matrix.org".to_string()),
         credential_store: None,
         credential_store_unreadable: false,
         telegram_bot_token: resolve_gateway_channel_env_secret("TAU_TELEGRAM_BOT_TOKEN"),
@@ -198,6 +203,18 @@ pub(super) fn build_gateway_multi_channel_lifecycle_command_config(
         whatsapp_phone_number_id: resolve_gateway_channel_env_secret(
             "TAU_WHATSAPP_PHONE_NUMBER_ID",
         ),
+        matrix_access_token: resolve_gateway_channel_env_secret("TAU_MATRIX_ACCESS_TOKEN"),
+        email: MultiChannelEmailAccountConfig {
+            address: resolve_gateway_channel_env_secret("TAU_EMAIL_ADDRESS"),
+            username: resolve_gateway_channel_env_secret("TAU_EMAIL_USERNAME"),
+            password: resolve_gateway_channel_env_secret("TAU_EMAIL_PASSWORD"),
+            imap_host: resolve_gateway_channel_env_secret("TAU_EMAIL_IMAP_HOST")
+                .unwrap_or_default(),
+            imap_port: resolve_gateway_channel_env_secret("TAU_EMAIL_IMAP_PORT")
+                .and_then(|value| value.parse::<u16>().ok())
+                .unwrap_or(993),
+            ..MultiChannelEmailAccountConfig::default()
+        },
         probe_online,
         probe_online_timeout_ms,
         probe_online_max_attempts,
@@ -0,0 +1,85 @@
+//! Client-declared tools whose calls are handed back to compatibility callers.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use super::*;
+use tau_agent_core::{AgentTool, ToolExecutionResult};
+use tau_ai::ToolDefinition;
+
+/// Placeholder for a tool the caller executes. Invoking it cancels the running
+/// attempt so the gateway can return the call instead of continuing the turn.
+pub(super) struct GatewayClientTool {
+    definition: ToolDefinition,
+    attempt_cancellation_token: Arc<Mutex<Option<CooperativeCancellationToken>>>,
+}
+
+impl AgentTool for GatewayClientTool {
+    fn definition(&self) -> ToolDefinition {
+        self.definition.clone()
+    }
+
+    fn execute<'life0, 'async_trait>(
+        &'life0 self,
+        _arguments: Value,
+    ) -> Pin<Box<dyn Future<Output = ToolExecutionResult> + Send + 'async_trait>>
+    where
+        Self: 'async_trait,
+        'life0: 'async_trait,
+    {
+        Box::pin(async move {
+            let token = self
+                .attempt_cancellation_token
+                .lock()
+                .ok()
+                .and_then(|guard| guard.clone());
+            if let Some(token) = token {
+                token.cancel();
+            }
+            ToolExecutionResult::ok(json!({ "status": "delegated_to_client" }))
+        })
+    }
+}
+
+/// Registers client tools on the agent, rejecting names the gateway already serves.
+pub(super) fn register_gateway_client_tools(
+    agent: &mut Agent,
+    client_tools: &[ToolDefinition],
+    attempt_cancellation_token: &Arc<Mutex<Option<CooperativeCancellationToken>>>,
+) -> Result<(), OpenResponsesApiError> {
+    for definition in client_tools {
+        if agent.has_tool(definition.name.as_str()) {
+            return Err(OpenResponsesApiError::bad_request(
+                "client_tool_conflict",
+                format!(
+                    "client tool '{}' conflicts with a gateway tool of the same name",
+                    definition.name
+                ),
+            ));
+        }
+        agent.register_tool(GatewayClientTool {
+            definition: definition.clone(),
+            attempt_cancellation_token: Arc::clone(attempt_cancellation_token),
+        });
+    }
+    Ok(())
+}
+
+/// Session transcript text recording the calls handed back to the client, so a
+/// follow-up request carrying their results keeps its context.
+pub(super) fn describe_gateway_client_tool_calls(
+    preamble: &str,
+    calls: &[OpenResponsesClientToolCall],
+) -> String {
+    let mut lines = Vec::with_capacity(calls.len() + 1);
+    if !preamble.trim().is_empty() {
+        lines.push(preamble.trim().to_string());
+    }
+    for call in calls {
+        lines.push(format!(
+            "Function call {} (call_id={}) with arguments: {}",
+            call.name, call.call_id, call.arguments
+        ));
+    }
+    lines.join("\n")
+}
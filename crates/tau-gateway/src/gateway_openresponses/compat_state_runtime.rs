@@ -1,4 +1,4 @@
-//! OpenAI compatibility and UI telemetry runtime-state helpers.
+//! OpenAI/Anthropic compatibility and UI telemetry runtime-state helpers.
 
 use super::*;
 
@@ -24,6 +24,14 @@ impl GatewayOpenResponsesServerState {
                 GatewayOpenAiCompatSurface::Models => {
                     runtime.models_requests = runtime.models_requests.saturating_add(1);
                 }
+                GatewayOpenAiCompatSurface::AnthropicMessages => {
+                    runtime.anthropic_messages_requests =
+                        runtime.anthropic_messages_requests.saturating_add(1);
+                }
+                GatewayOpenAiCompatSurface::AnthropicCountTokens => {
+                    runtime.anthropic_count_tokens_requests =
+                        runtime.anthropic_count_tokens_requests.saturating_add(1);
+                }
             }
         }
     }
@@ -69,6 +77,8 @@ impl GatewayOpenResponsesServerState {
                 chat_completions_requests: runtime.chat_completions_requests,
                 completions_requests: runtime.completions_requests,
                 models_requests: runtime.models_requests,
+                anthropic_messages_requests: runtime.anthropic_messages_requests,
+                anthropic_count_tokens_requests: runtime.anthropic_count_tokens_requests,
                 stream_requests: runtime.stream_requests,
                 translation_failures: runtime.translation_failures,
                 execution_failures: runtime.execution_failures,
@@ -138,6 +148,8 @@ pub(super) enum GatewayOpenAiCompatSurface {
     ChatCompletions,
     Completions,
     Models,
+    AnthropicMessages,
+    AnthropicCountTokens,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -146,6 +158,8 @@ pub(super) struct GatewayOpenAiCompatRuntimeState {
     pub(super) chat_completions_requests: u64,
     pub(super) completions_requests: u64,
     pub(super) models_requests: u64,
+    pub(super) anthropic_messages_requests: u64,
+    pub(super) anthropic_count_tokens_requests: u64,
     pub(super) stream_requests: u64,
     pub(super) translation_failures: u64,
     pub(super) execution_failures: u64,
@@ -169,6 +183,8 @@ pub(super) struct GatewayOpenAiCompatStatusReport {
     chat_completions_requests: u64,
     completions_requests: u64,
     models_requests: u64,
+    anthropic_messages_requests: u64,
+    anthropic_count_tokens_requests: u64,
     stream_requests: u64,
     translation_failures: u64,
     execution_failures: u64,
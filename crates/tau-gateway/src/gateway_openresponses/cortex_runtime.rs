@@ -591,6 +591,8 @@ pub(super) async fn complete_cortex_chat(
         max_tokens: Some(384),
         temperature: Some(0.0),
         prompt_cache: PromptCacheConfig::default(),
+        reasoning: Default::default(),
+        model_tier: None,
     };
 
     match state.config.client.complete(request).await {
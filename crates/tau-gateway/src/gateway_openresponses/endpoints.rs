@@ -4,6 +4,8 @@ pub(super) const OPENRESPONSES_ENDPOINT: &str = "/v1/responses";
 pub(super) const OPENAI_CHAT_COMPLETIONS_ENDPOINT: &str = "/v1/chat/completions";
 pub(super) const OPENAI_COMPLETIONS_ENDPOINT: &str = "/v1/completions";
 pub(super) const OPENAI_MODELS_ENDPOINT: &str = "/v1/models";
+pub(super) const ANTHROPIC_MESSAGES_ENDPOINT: &str = "/v1/messages";
+pub(super) const ANTHROPIC_COUNT_TOKENS_ENDPOINT: &str = "/v1/messages/count_tokens";
 pub(super) const OPS_DASHBOARD_ENDPOINT: &str = "/ops";
 pub(super) const OPS_DASHBOARD_AGENTS_ENDPOINT: &str = "/ops/agents";
 pub(super) const OPS_DASHBOARD_AGENT_DETAIL_ENDPOINT: &str = "/ops/agents/{agent_id}";
@@ -54,6 +56,8 @@ pub(super) const GATEWAY_MEMORY_ENDPOINT: &str = "/gateway/memory/{session_key}"
 pub(super) const GATEWAY_MEMORY_ENTRY_ENDPOINT: &str = "/gateway/memory/{session_key}/{entry_id}";
 pub(super) const GATEWAY_MEMORY_GRAPH_ENDPOINT: &str = "/gateway/memory-graph/{session_key}";
 pub(super) const API_MEMORIES_GRAPH_ENDPOINT: &str = "/api/memories/graph";
+pub(super) const GATEWAY_MCP_ENDPOINT: &str = "/gateway/mcp";
+pub(super) const GATEWAY_METRICS_ENDPOINT: &str = "/metrics";
 pub(super) const GATEWAY_CHANNEL_LIFECYCLE_ENDPOINT: &str = "/gateway/channels/{channel}/lifecycle";
 pub(super) const GATEWAY_CONFIG_ENDPOINT: &str = "/gateway/config";
 pub(super) const GATEWAY_SAFETY_POLICY_ENDPOINT: &str = "/gateway/safety/policy";
@@ -0,0 +1,871 @@
+//! MCP Streamable HTTP endpoint publishing gateway sessions, memory, and skills.
+//!
+//! Remote MCP clients authenticate with the same bearer credentials as the rest
+//! of the gateway (static token or password-session token), open an MCP session
+//! via `initialize`, and browse Tau context as `tau://` resources. Subscribed
+//! resources are watched by the session's SSE stream and surface as
+//! `notifications/resources/updated` frames.
+
+use super::*;
+use tau_memory::runtime::RuntimeMemoryRecord;
+use tau_session::SessionStore;
+
+const GATEWAY_MCP_JSONRPC_VERSION: &str = "2.0";
+const GATEWAY_MCP_PROTOCOL_VERSION: &str = "2025-06-18";
+const GATEWAY_MCP_SUPPORTED_PROTOCOL_VERSIONS: &[&str] =
+    &["2025-06-18", "2025-03-26", "2024-11-05"];
+const GATEWAY_MCP_SESSION_HEADER: &str = "mcp-session-id";
+const GATEWAY_MCP_PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+const GATEWAY_MCP_SESSION_RESOURCE_PREFIX: &str = "tau://sessions/";
+const GATEWAY_MCP_MEMORY_RESOURCE_PREFIX: &str = "tau://memory/";
+const GATEWAY_MCP_SKILL_RESOURCE_PREFIX: &str = "tau://skills/";
+const GATEWAY_MCP_MIME_TYPE_JSON: &str = "application/json";
+const GATEWAY_MCP_MIME_TYPE_MARKDOWN: &str = "text/markdown";
+const GATEWAY_MCP_RESOURCE_PAGE_SIZE: usize = 200;
+const GATEWAY_MCP_MEMORY_RECORDS_PER_SESSION: usize = 500;
+const GATEWAY_MCP_MAX_SESSIONS: usize = 256;
+const GATEWAY_MCP_STREAM_POLL_INTERVAL_MS: u64 = 750;
+const GATEWAY_MCP_ERROR_PARSE: i64 = -32700;
+const GATEWAY_MCP_ERROR_INVALID_REQUEST: i64 = -32600;
+const GATEWAY_MCP_ERROR_METHOD_NOT_FOUND: i64 = -32601;
+const GATEWAY_MCP_ERROR_INVALID_PARAMS: i64 = -32602;
+const GATEWAY_MCP_ERROR_INTERNAL: i64 = -32603;
+const GATEWAY_MCP_ERROR_RESOURCE_NOT_FOUND: i64 = -32002;
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct GatewayMcpRuntimeState {
+    pub(super) sessions: BTreeMap<String, GatewayMcpSessionRecord>,
+    pub(super) total_requests: u64,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct GatewayMcpSessionRecord {
+    pub(super) principal: String,
+    pub(super) protocol_version: String,
+    pub(super) subscriptions: BTreeSet<String>,
+    pub(super) created_unix_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Default)]
+pub(super) struct GatewayMcpStatusReport {
+    active_sessions: usize,
+    active_subscriptions: usize,
+    total_requests: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GatewayMcpResourceUri {
+    Session {
+        session_key: String,
+    },
+    MemoryRecord {
+        session_key: String,
+        memory_id: String,
+    },
+    Skill {
+        name: String,
+    },
+}
+
+impl GatewayMcpResourceUri {
+    fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        if let Some(session_key) = raw.strip_prefix(GATEWAY_MCP_SESSION_RESOURCE_PREFIX) {
+            if session_key.is_empty() || sanitize_session_key(session_key) != session_key {
+                return None;
+            }
+            return Some(Self::Session {
+                session_key: session_key.to_string(),
+            });
+        }
+        if let Some(rest) = raw.strip_prefix(GATEWAY_MCP_MEMORY_RESOURCE_PREFIX) {
+            let (session_key, memory_id) = rest.split_once('/')?;
+            if session_key.is_empty() || sanitize_session_key(session_key) != session_key {
+                return None;
+            }
+            let memory_id = memory_id.trim();
+            if memory_id.is_empty() {
+                return None;
+            }
+            return Some(Self::MemoryRecord {
+                session_key: session_key.to_string(),
+                memory_id: memory_id.to_string(),
+            });
+        }
+        if let Some(name) = raw.strip_prefix(GATEWAY_MCP_SKILL_RESOURCE_PREFIX) {
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            return Some(Self::Skill {
+                name: name.to_string(),
+            });
+        }
+        None
+    }
+
+    fn uri(&self) -> String {
+        match self {
+            Self::Session { session_key } => {
+                format!("{GATEWAY_MCP_SESSION_RESOURCE_PREFIX}{session_key}")
+            }
+            Self::MemoryRecord {
+                session_key,
+                memory_id,
+            } => format!("{GATEWAY_MCP_MEMORY_RESOURCE_PREFIX}{session_key}/{memory_id}"),
+            Self::Skill { name } => format!("{GATEWAY_MCP_SKILL_RESOURCE_PREFIX}{name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GatewayMcpDispatchError {
+    code: i64,
+    message: String,
+}
+
+impl GatewayMcpDispatchError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(GATEWAY_MCP_ERROR_INVALID_PARAMS, message)
+    }
+
+    fn resource_not_found(uri: &str) -> Self {
+        Self::new(
+            GATEWAY_MCP_ERROR_RESOURCE_NOT_FOUND,
+            format!("resource '{uri}' was not found"),
+        )
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self::new(GATEWAY_MCP_ERROR_INTERNAL, message)
+    }
+}
+
+impl GatewayOpenResponsesServerState {
+    fn open_gateway_mcp_session(&self, principal: &str, protocol_version: &str) -> String {
+        let session_id = format!(
+            "tau_mcp_{:016x}{:012x}",
+            self.next_sequence(),
+            current_unix_timestamp_ms()
+        );
+        if let Ok(mut runtime) = self.mcp_runtime.lock() {
+            while runtime.sessions.len() >= GATEWAY_MCP_MAX_SESSIONS {
+                let Some(oldest) = runtime
+                    .sessions
+                    .iter()
+                    .min_by_key(|(_, record)| record.created_unix_ms)
+                    .map(|(id, _)| id.clone())
+                else {
+                    break;
+                };
+                runtime.sessions.remove(&oldest);
+            }
+            runtime.sessions.insert(
+                session_id.clone(),
+                GatewayMcpSessionRecord {
+                    principal: principal.to_string(),
+                    protocol_version: protocol_version.to_string(),
+                    subscriptions: BTreeSet::new(),
+                    created_unix_ms: current_unix_timestamp_ms(),
+                },
+            );
+        }
+        session_id
+    }
+
+    fn close_gateway_mcp_session(&self, session_id: &str) -> bool {
+        self.mcp_runtime
+            .lock()
+            .map(|mut runtime| runtime.sessions.remove(session_id).is_some())
+            .unwrap_or(false)
+    }
+
+    fn gateway_mcp_session_subscriptions(&self, session_id: &str) -> Option<BTreeSet<String>> {
+        let runtime = self.mcp_runtime.lock().ok()?;
+        runtime
+            .sessions
+            .get(session_id)
+            .map(|record| record.subscriptions.clone())
+    }
+
+    fn update_gateway_mcp_subscription(&self, session_id: &str, uri: &str, subscribed: bool) {
+        if let Ok(mut runtime) = self.mcp_runtime.lock() {
+            if let Some(record) = runtime.sessions.get_mut(session_id) {
+                if subscribed {
+                    record.subscriptions.insert(uri.to_string());
+                } else {
+                    record.subscriptions.remove(uri);
+                }
+            }
+        }
+    }
+
+    fn record_gateway_mcp_request(&self) {
+        if let Ok(mut runtime) = self.mcp_runtime.lock() {
+            runtime.total_requests = runtime.total_requests.saturating_add(1);
+        }
+    }
+
+    pub(super) fn collect_gateway_mcp_status_report(&self) -> GatewayMcpStatusReport {
+        if let Ok(runtime) = self.mcp_runtime.lock() {
+            return GatewayMcpStatusReport {
+                active_sessions: runtime.sessions.len(),
+                active_subscriptions: runtime
+                    .sessions
+                    .values()
+                    .map(|record| record.subscriptions.len())
+                    .sum(),
+                total_requests: runtime.total_requests,
+            };
+        }
+        GatewayMcpStatusReport::default()
+    }
+}
+
+pub(super) async fn handle_gateway_mcp_post(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let principal = match authorize_and_enforce_gateway_limits(&state, &headers) {
+        Ok(principal) => principal,
+        Err(error) => return error.into_response(),
+    };
+    if let Err(error) = validate_gateway_request_body_size(&state, &body) {
+        return error.into_response();
+    }
+    if let Err(error) = validate_gateway_mcp_protocol_version_header(&headers) {
+        return error.into_response();
+    }
+    state.record_gateway_mcp_request();
+
+    let message = match serde_json::from_slice::<Value>(&body) {
+        Ok(message) => message,
+        Err(error) => {
+            return gateway_mcp_jsonrpc_error(
+                Value::Null,
+                GATEWAY_MCP_ERROR_PARSE,
+                format!("failed to parse mcp message: {error}"),
+            )
+        }
+    };
+    let Some(object) = message.as_object() else {
+        return gateway_mcp_jsonrpc_error(
+            Value::Null,
+            GATEWAY_MCP_ERROR_INVALID_REQUEST,
+            "mcp message must be a single jsonrpc object",
+        );
+    };
+
+    let method = object
+        .get("method")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    let (Some(id), Some(method)) = (object.get("id").cloned(), method) else {
+        // Notifications and client responses carry no reply in Streamable HTTP.
+        return StatusCode::ACCEPTED.into_response();
+    };
+    if object.get("jsonrpc").and_then(Value::as_str) != Some(GATEWAY_MCP_JSONRPC_VERSION) {
+        return gateway_mcp_jsonrpc_error(
+            id,
+            GATEWAY_MCP_ERROR_INVALID_REQUEST,
+            format!("jsonrpc must be '{GATEWAY_MCP_JSONRPC_VERSION}'"),
+        );
+    }
+    let params = match object.get("params") {
+        Some(Value::Object(params)) => params.clone(),
+        Some(_) => {
+            return gateway_mcp_jsonrpc_error(
+                id,
+                GATEWAY_MCP_ERROR_INVALID_PARAMS,
+                "jsonrpc request params must be an object",
+            )
+        }
+        None => serde_json::Map::new(),
+    };
+
+    if method == "initialize" {
+        let protocol_version = negotiate_gateway_mcp_protocol_version(
+            params.get("protocolVersion").and_then(Value::as_str),
+        );
+        let session_id = state.open_gateway_mcp_session(principal.as_str(), protocol_version);
+        state.record_ui_telemetry_event("mcp", "initialize", "mcp_session_opened");
+        return (
+            StatusCode::OK,
+            [(GATEWAY_MCP_SESSION_HEADER, session_id)],
+            Json(gateway_mcp_result_frame(
+                id,
+                gateway_mcp_initialize_result(protocol_version),
+            )),
+        )
+            .into_response();
+    }
+
+    let session_id = match resolve_gateway_mcp_session(&state, &headers, principal.as_str()) {
+        Ok(session_id) => session_id,
+        Err(error) => return error.into_response(),
+    };
+    match dispatch_gateway_mcp_request(&state, session_id.as_str(), method, &params) {
+        Ok(result) => (StatusCode::OK, Json(gateway_mcp_result_frame(id, result))).into_response(),
+        Err(error) => gateway_mcp_jsonrpc_error(id, error.code, error.message),
+    }
+}
+
+pub(super) async fn handle_gateway_mcp_stream(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+) -> Response {
+    let principal = match authorize_and_enforce_gateway_limits(&state, &headers) {
+        Ok(principal) => principal,
+        Err(error) => return error.into_response(),
+    };
+    let session_id = match resolve_gateway_mcp_session(&state, &headers, principal.as_str()) {
+        Ok(session_id) => session_id,
+        Err(error) => return error.into_response(),
+    };
+
+    let (tx, rx) = mpsc::unbounded_channel::<Event>();
+    tokio::spawn(run_gateway_mcp_stream_loop(state, session_id, tx));
+    let stream = UnboundedReceiverStream::new(rx).map(Ok::<Event, Infallible>);
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+pub(super) async fn handle_gateway_mcp_delete(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+) -> Response {
+    let principal = match authorize_and_enforce_gateway_limits(&state, &headers) {
+        Ok(principal) => principal,
+        Err(error) => return error.into_response(),
+    };
+    let session_id = match resolve_gateway_mcp_session(&state, &headers, principal.as_str()) {
+        Ok(session_id) => session_id,
+        Err(error) => return error.into_response(),
+    };
+    let closed = state.close_gateway_mcp_session(session_id.as_str());
+    state.record_ui_telemetry_event("mcp", "close", "mcp_session_closed");
+    (
+        StatusCode::OK,
+        Json(json!({
+            "session_id": session_id,
+            "closed": closed,
+        })),
+    )
+        .into_response()
+}
+
+fn validate_gateway_mcp_protocol_version_header(
+    headers: &HeaderMap,
+) -> Result<(), OpenResponsesApiError> {
+    let Some(raw) = headers.get(GATEWAY_MCP_PROTOCOL_VERSION_HEADER) else {
+        return Ok(());
+    };
+    let version = raw.to_str().unwrap_or_default().trim();
+    if GATEWAY_MCP_SUPPORTED_PROTOCOL_VERSIONS.contains(&version) {
+        return Ok(());
+    }
+    Err(OpenResponsesApiError::bad_request(
+        "mcp_protocol_version_unsupported",
+        format!(
+            "{GATEWAY_MCP_PROTOCOL_VERSION_HEADER} '{version}' is not supported; expected one of {}",
+            GATEWAY_MCP_SUPPORTED_PROTOCOL_VERSIONS.join(", ")
+        ),
+    ))
+}
+
+fn resolve_gateway_mcp_session(
+    state: &GatewayOpenResponsesServerState,
+    headers: &HeaderMap,
+    principal: &str,
+) -> Result<String, OpenResponsesApiError> {
+    let session_id = headers
+        .get(GATEWAY_MCP_SESSION_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            OpenResponsesApiError::bad_request(
+                "mcp_session_required",
+                format!("{GATEWAY_MCP_SESSION_HEADER} header is required; call initialize first"),
+            )
+        })?;
+    let negotiated_version = state.mcp_runtime.lock().ok().and_then(|runtime| {
+        runtime
+            .sessions
+            .get(session_id)
+            .filter(|record| record.principal == principal)
+            .map(|record| record.protocol_version.clone())
+    });
+    let Some(negotiated_version) = negotiated_version else {
+        return Err(OpenResponsesApiError::not_found(
+            "mcp_session_not_found",
+            format!("mcp session '{session_id}' is unknown or expired; re-initialize"),
+        ));
+    };
+    if let Some(requested) = headers
+        .get(GATEWAY_MCP_PROTOCOL_VERSION_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+    {
+        if requested != negotiated_version {
+            return Err(OpenResponsesApiError::bad_request(
+                "mcp_protocol_version_mismatch",
+                format!(
+                    "{GATEWAY_MCP_PROTOCOL_VERSION_HEADER} '{requested}' does not match negotiated version '{negotiated_version}'"
+                ),
+            ));
+        }
+    }
+    Ok(session_id.to_string())
+}
+
+fn negotiate_gateway_mcp_protocol_version(requested: Option<&str>) -> &'static str {
+    requested
+        .and_then(|requested| {
+            GATEWAY_MCP_SUPPORTED_PROTOCOL_VERSIONS
+                .iter()
+                .copied()
+                .find(|supported| *supported == requested.trim())
+        })
+        .unwrap_or(GATEWAY_MCP_PROTOCOL_VERSION)
+}
+
+fn gateway_mcp_initialize_result(protocol_version: &str) -> Value {
+    json!({
+        "protocolVersion": protocol_version,
+        "serverInfo": {
+            "name": "tau-gateway",
+            "version": env!("CARGO_PKG_VERSION")
+        },
+        "capabilities": {
+            "resources": {
+                "subscribe": true,
+                "listChanged": true
+            }
+        },
+        "instructions": "Tau sessions, memory records, and skills are published as tau:// resources."
+    })
+}
+
+fn dispatch_gateway_mcp_request(
+    state: &GatewayOpenResponsesServerState,
+    session_id: &str,
+    method: &str,
+    params: &serde_json::Map<String, Value>,
+) -> Result<Value, GatewayMcpDispatchError> {
+    match method {
+        "ping" => Ok(json!({})),
+        "resources/list" => handle_gateway_mcp_resources_list(state, params),
+        "resources/templates/list" => Ok(gateway_mcp_resource_templates()),
+        "resources/read" => {
+            let uri = required_gateway_mcp_uri(params)?;
+            handle_gateway_mcp_resources_read(state, &uri)
+        }
+        "resources/subscribe" | "resources/unsubscribe" => {
+            let uri = required_gateway_mcp_uri(params)?;
+            let subscribed = method == "resources/subscribe";
+            state.update_gateway_mcp_subscription(session_id, uri.uri().as_str(), subscribed);
+            Ok(json!({}))
+        }
+        other => Err(GatewayMcpDispatchError::new(
+            GATEWAY_MCP_ERROR_METHOD_NOT_FOUND,
+            format!("unsupported method '{other}'"),
+        )),
+    }
+}
+
+fn required_gateway_mcp_uri(
+    params: &serde_json::Map<String, Value>,
+) -> Result<GatewayMcpResourceUri, GatewayMcpDispatchError> {
+    let raw = params
+        .get("uri")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| GatewayMcpDispatchError::invalid_params("field 'uri' is required"))?;
+    GatewayMcpResourceUri::parse(raw).ok_or_else(|| {
+        GatewayMcpDispatchError::invalid_params(format!(
+            "unsupported resource uri '{raw}'; expected tau://sessions/, tau://memory/, or tau://skills/"
+        ))
+    })
+}
+
+fn handle_gateway_mcp_resources_list(
+    state: &GatewayOpenResponsesServerState,
+    params: &serde_json::Map<String, Value>,
+) -> Result<Value, GatewayMcpDispatchError> {
+    let offset = match params.get("cursor") {
+        Some(Value::String(cursor)) => cursor.trim().parse::<usize>().map_err(|_| {
+            GatewayMcpDispatchError::invalid_params(format!("invalid cursor '{cursor}'"))
+        })?,
+        Some(Value::Null) | None => 0,
+        Some(_) => {
+            return Err(GatewayMcpDispatchError::invalid_params(
+                "field 'cursor' must be a string",
+            ))
+        }
+    };
+    let resources = collect_gateway_mcp_resources(state)?;
+    let total = resources.len();
+    let page = resources
+        .into_iter()
+        .skip(offset)
+        .take(GATEWAY_MCP_RESOURCE_PAGE_SIZE)
+        .collect::<Vec<_>>();
+    let next_offset = offset.saturating_add(page.len());
+    let mut result = json!({ "resources": page });
+    if next_offset < total {
+        result["nextCursor"] = Value::String(next_offset.to_string());
+    }
+    Ok(result)
+}
+
+fn gateway_mcp_resource_templates() -> Value {
+    json!({
+        "resourceTemplates": [
+            {
+                "uriTemplate": format!("{GATEWAY_MCP_SESSION_RESOURCE_PREFIX}{{session_key}}"),
+                "name": "session",
+                "description": "Tau gateway session transcript with lineage ids.",
+                "mimeType": GATEWAY_MCP_MIME_TYPE_JSON
+            },
+            {
+                "uriTemplate": format!("{GATEWAY_MCP_MEMORY_RESOURCE_PREFIX}{{session_key}}/{{memory_id}}"),
+                "name": "memory-record",
+                "description": "Latest revision of a Tau memory record.",
+                "mimeType": GATEWAY_MCP_MIME_TYPE_JSON
+            },
+            {
+                "uriTemplate": format!("{GATEWAY_MCP_SKILL_RESOURCE_PREFIX}{{name}}"),
+                "name": "skill",
+                "description": "Skill prompt available to the gateway agent.",
+                "mimeType": GATEWAY_MCP_MIME_TYPE_MARKDOWN
+            }
+        ]
+    })
+}
+
+fn collect_gateway_mcp_resources(
+    state: &GatewayOpenResponsesServerState,
+) -> Result<Vec<Value>, GatewayMcpDispatchError> {
+    let mut resources = Vec::new();
+
+    let sessions_root = state
+        .config
+        .state_dir
+        .join("openresponses")
+        .join("sessions");
+    if sessions_root.is_dir() {
+        let entries = std::fs::read_dir(&sessions_root).map_err(|error| {
+            GatewayMcpDispatchError::internal(format!(
+                "failed to list sessions directory {}: {error}",
+                sessions_root.display()
+            ))
+        })?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("jsonl") {
+                continue;
+            }
+            let Some(file_stem) = path.file_stem().and_then(|value| value.to_str()) else {
+                continue;
+            };
+            let session_key = sanitize_session_key(file_stem);
+            let bytes = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+            let uri = GatewayMcpResourceUri::Session {
+                session_key: session_key.clone(),
+            };
+            resources.push(json!({
+                "uri": uri.uri(),
+                "name": format!("session/{session_key}"),
+                "title": format!("Session {session_key}"),
+                "mimeType": GATEWAY_MCP_MIME_TYPE_JSON,
+                "size": bytes,
+            }));
+        }
+    }
+
+    let memory_root = gateway_memory_stores_root(&state.config.state_dir);
+    if memory_root.is_dir() {
+        let entries = std::fs::read_dir(&memory_root).map_err(|error| {
+            GatewayMcpDispatchError::internal(format!(
+                "failed to list memory stores {}: {error}",
+                memory_root.display()
+            ))
+        })?;
+        for entry in entries.flatten() {
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let Some(session_key) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if sanitize_session_key(session_key.as_str()) != session_key {
+                continue;
+            }
+            let store = gateway_memory_store(&state.config.state_dir, &session_key);
+            let Ok(records) =
+                store.list_latest_records(None, GATEWAY_MCP_MEMORY_RECORDS_PER_SESSION)
+            else {
+                continue;
+            };
+            for record in records.iter().filter(|record| !record.forgotten) {
+                let uri = GatewayMcpResourceUri::MemoryRecord {
+                    session_key: session_key.clone(),
+                    memory_id: record.entry.memory_id.clone(),
+                };
+                resources.push(json!({
+                    "uri": uri.uri(),
+                    "name": format!("memory/{session_key}/{}", record.entry.memory_id),
+                    "description": record.entry.summary.as_str(),
+                    "mimeType": GATEWAY_MCP_MIME_TYPE_JSON,
+                }));
+            }
+        }
+    }
+
+    for skill in &state.config.available_skills {
+        let uri = GatewayMcpResourceUri::Skill {
+            name: skill.name.clone(),
+        };
+        resources.push(json!({
+            "uri": uri.uri(),
+            "name": format!("skill/{}", skill.name),
+            "description": skill.description.as_str(),
+            "mimeType": GATEWAY_MCP_MIME_TYPE_MARKDOWN,
+            "size": skill.content.len(),
+        }));
+    }
+
+    resources.sort_by(|left, right| {
+        left["uri"]
+            .as_str()
+            .unwrap_or_default()
+            .cmp(right["uri"].as_str().unwrap_or_default())
+    });
+    Ok(resources)
+}
+
+fn handle_gateway_mcp_resources_read(
+    state: &GatewayOpenResponsesServerState,
+    resource: &GatewayMcpResourceUri,
+) -> Result<Value, GatewayMcpDispatchError> {
+    let uri = resource.uri();
+    let (mime_type, text) = match resource {
+        GatewayMcpResourceUri::Session { session_key } => {
+            let session_path = gateway_session_path(&state.config.state_dir, session_key);
+            if !session_path.exists() {
+                return Err(GatewayMcpDispatchError::resource_not_found(uri.as_str()));
+            }
+            let store = SessionStore::load(&session_path).map_err(|error| {
+                GatewayMcpDispatchError::internal(format!(
+                    "failed to load session '{}': {error}",
+                    session_path.display()
+                ))
+            })?;
+            let entries = store
+                .entries()
+                .iter()
+                .map(|entry| {
+                    json!({
+                        "id": entry.id,
+                        "parent_id": entry.parent_id,
+                        "role": entry.message.role,
+                        "text": entry.message.text_content(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let payload = json!({
+                "session_key": session_key,
+                "entry_count": entries.len(),
+                "head_id": store.head_id(),
+                "entries": entries,
+            });
+            (GATEWAY_MCP_MIME_TYPE_JSON, payload.to_string())
+        }
+        GatewayMcpResourceUri::MemoryRecord {
+            session_key,
+            memory_id,
+        } => {
+            let record = find_gateway_mcp_memory_record(state, session_key, memory_id)?
+                .ok_or_else(|| GatewayMcpDispatchError::resource_not_found(uri.as_str()))?;
+            let mut payload = memory_record_json(&record);
+            payload["session_key"] = Value::String(session_key.clone());
+            (GATEWAY_MCP_MIME_TYPE_JSON, payload.to_string())
+        }
+        GatewayMcpResourceUri::Skill { name } => {
+            let skill = state
+                .config
+                .available_skills
+                .iter()
+                .find(|skill| &skill.name == name)
+                .ok_or_else(|| GatewayMcpDispatchError::resource_not_found(uri.as_str()))?;
+            (GATEWAY_MCP_MIME_TYPE_MARKDOWN, skill.content.clone())
+        }
+    };
+    state.record_ui_telemetry_event("mcp", "resource_read", "mcp_resource_read_requested");
+    Ok(json!({
+        "contents": [{
+            "uri": uri,
+            "mimeType": mime_type,
+            "text": text,
+        }]
+    }))
+}
+
+fn find_gateway_mcp_memory_record(
+    state: &GatewayOpenResponsesServerState,
+    session_key: &str,
+    memory_id: &str,
+) -> Result<Option<RuntimeMemoryRecord>, GatewayMcpDispatchError> {
+    // `read_entry` records an access; resource reads and watchers must stay side-effect free.
+    let store = gateway_memory_store(&state.config.state_dir, session_key);
+    let records = store
+        .list_latest_records(None, usize::MAX)
+        .map_err(|error| {
+            GatewayMcpDispatchError::internal(format!(
+                "failed to load memory records for session '{session_key}': {error}"
+            ))
+        })?;
+    Ok(records
+        .into_iter()
+        .find(|record| record.entry.memory_id == memory_id && !record.forgotten))
+}
+
+fn gateway_mcp_resource_fingerprint(state: &GatewayOpenResponsesServerState, uri: &str) -> String {
+    let Some(resource) = GatewayMcpResourceUri::parse(uri) else {
+        return "invalid".to_string();
+    };
+    match resource {
+        GatewayMcpResourceUri::Session { session_key } => {
+            let path = gateway_session_path(&state.config.state_dir, &session_key);
+            match std::fs::metadata(&path) {
+                Ok(metadata) => format!(
+                    "{}:{}",
+                    metadata
+                        .modified()
+                        .ok()
+                        .and_then(system_time_to_unix_ms)
+                        .unwrap_or(0),
+                    metadata.len()
+                ),
+                Err(_) => "missing".to_string(),
+            }
+        }
+        GatewayMcpResourceUri::MemoryRecord {
+            session_key,
+            memory_id,
+        } => match find_gateway_mcp_memory_record(state, &session_key, &memory_id) {
+            Ok(Some(record)) => record.updated_unix_ms.to_string(),
+            Ok(None) => "missing".to_string(),
+            Err(_) => "unavailable".to_string(),
+        },
+        GatewayMcpResourceUri::Skill { name } => state
+            .config
+            .available_skills
+            .iter()
+            .find(|skill| skill.name == name)
+            .map(|skill| skill.content.len().to_string())
+            .unwrap_or_else(|| "missing".to_string()),
+    }
+}
+
+fn collect_gateway_mcp_resource_uris(state: &GatewayOpenResponsesServerState) -> BTreeSet<String> {
+    collect_gateway_mcp_resources(state)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|resource| resource["uri"].as_str().map(str::to_string))
+        .collect()
+}
+
+async fn run_gateway_mcp_stream_loop(
+    state: Arc<GatewayOpenResponsesServerState>,
+    session_id: String,
+    sender: mpsc::UnboundedSender<Event>,
+) {
+    let mut fingerprints = BTreeMap::<String, String>::new();
+    let mut listed_uris = collect_gateway_mcp_resource_uris(&state);
+    loop {
+        let Some(subscriptions) = state.gateway_mcp_session_subscriptions(&session_id) else {
+            return;
+        };
+        fingerprints.retain(|uri, _| subscriptions.contains(uri));
+        for uri in subscriptions {
+            let fingerprint = gateway_mcp_resource_fingerprint(&state, uri.as_str());
+            let previous = fingerprints.insert(uri.clone(), fingerprint.clone());
+            if previous.is_some_and(|previous| previous != fingerprint) {
+                let notification = gateway_mcp_notification_frame(
+                    "notifications/resources/updated",
+                    json!({ "uri": uri }),
+                );
+                if !send_gateway_mcp_stream_event(&state, &sender, notification) {
+                    return;
+                }
+            }
+        }
+
+        let current_uris = collect_gateway_mcp_resource_uris(&state);
+        if current_uris != listed_uris {
+            let notification =
+                gateway_mcp_notification_frame("notifications/resources/list_changed", json!({}));
+            if !send_gateway_mcp_stream_event(&state, &sender, notification) {
+                return;
+            }
+            listed_uris = current_uris;
+        }
+        tokio::time::sleep(Duration::from_millis(GATEWAY_MCP_STREAM_POLL_INTERVAL_MS)).await;
+    }
+}
+
+fn send_gateway_mcp_stream_event(
+    state: &GatewayOpenResponsesServerState,
+    sender: &mpsc::UnboundedSender<Event>,
+    frame: Value,
+) -> bool {
+    let event = Event::default()
+        .id(format!("mcp-{}", state.next_sequence()))
+        .event("message")
+        .data(frame.to_string());
+    sender.send(event).is_ok()
+}
+
+fn gateway_mcp_result_frame(id: Value, result: Value) -> Value {
+    json!({
+        "jsonrpc": GATEWAY_MCP_JSONRPC_VERSION,
+        "id": id,
+        "result": result,
+    })
+}
+
+fn gateway_mcp_notification_frame(method: &str, params: Value) -> Value {
+    json!({
+        "jsonrpc": GATEWAY_MCP_JSONRPC_VERSION,
+        "method": method,
+        "params": params,
+    })
+}
+
+fn gateway_mcp_jsonrpc_error(id: Value, code: i64, message: impl Into<String>) -> Response {
+    (
+        StatusCode::OK,
+        Json(json!({
+            "jsonrpc": GATEWAY_MCP_JSONRPC_VERSION,
+            "id": id,
+            "error": {
+                "code": code,
+                "message": message.into(),
+            }
+        })),
+    )
+        .into_response()
+}
@@ -875,7 +875,7 @@ fn parse_gateway_memory_type(
     })
 }
 
-fn memory_record_json(record: &RuntimeMemoryRecord) -> Value {
+pub(super) fn memory_record_json(record: &RuntimeMemoryRecord) -> Value {
     json!({
         "memory_id": record.entry.memory_id.as_str(),
         "summary": record.entry.summary.as_str(),
@@ -0,0 +1,75 @@
+//! Prometheus/OpenMetrics scrape endpoint backed by the shared Tau registry.
+//!
+//! Provider latency, token, cost, tool-call, async event dispatch and provider
+//! circuit breaker series are recorded by the runtimes as they execute;
+//! gateway-owned gauges (MCP sessions and multi-channel backlog) are refreshed
+//! on every scrape before rendering. The gateway is the only writer of the
+//! multi-channel backlog gauges, which it reads from the persisted runtime
+//! state whether or not the channel runtime runs in the gateway process.
+
+use super::*;
+use tau_core::{global_metrics_registry, OPENMETRICS_CONTENT_TYPE};
+
+pub(super) async fn handle_gateway_metrics(
+    State(state): State<Arc<GatewayOpenResponsesServerState>>,
+    headers: HeaderMap,
+) -> Response {
+    if let Err(error) = authorize_and_enforce_gateway_limits(&state, &headers) {
+        return error.into_response();
+    }
+
+    refresh_gateway_metrics(&state);
+    (
+        StatusCode::OK,
+        [(axum::http::header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)],
+        global_metrics_registry().render_openmetrics(),
+    )
+        .into_response()
+}
+
+fn refresh_gateway_metrics(state: &GatewayOpenResponsesServerState) {
+    let registry = global_metrics_registry();
+    if let Ok(runtime) = state.mcp_runtime.lock() {
+        registry.set_gauge(
+            "tau_gateway_mcp_sessions",
+            "Active MCP Streamable HTTP sessions.",
+            &[],
+            runtime.sessions.len() as f64,
+        );
+        registry.set_gauge(
+            "tau_gateway_mcp_subscriptions",
+            "Active MCP resource subscriptions.",
+            &[],
+            runtime
+                .sessions
+                .values()
+                .map(|record| record.subscriptions.len())
+                .sum::<usize>() as f64,
+        );
+    }
+
+    let multi_channel = collect_gateway_multi_channel_status_report(&state.config.state_dir);
+    if !multi_channel.state_present {
+        return;
+    }
+    registry.set_gauge(
+        "tau_channel_queue_depth",
+        "Inbound multi-channel events waiting after the last cycle.",
+        &[],
+        multi_channel.queue_depth as f64,
+    );
+    registry.set_gauge(
+        "tau_channel_failure_streak",
+        "Consecutive multi-channel cycles with failed events.",
+        &[],
+        multi_channel.failure_streak as f64,
+    );
+    for (transport, processed) in &multi_channel.transport_counts {
+        registry.set_gauge(
+            "tau_channel_processed_events",
+            "Processed multi-channel events recorded in runtime state by transport.",
+            &[("transport", transport.as_str())],
+            *processed as f64,
+        );
+    }
+}
@@ -149,6 +149,8 @@ pub(super) fn translate_chat_completions_request(
             metadata,
             conversation: session_user,
             previous_response_id: None,
+            client_tools: Vec::new(),
+            client_tool_choice: None,
             extra,
         },
     })
@@ -188,6 +190,8 @@ pub(super) fn translate_completions_request(
             metadata,
             conversation: session_user,
             previous_response_id: None,
+            client_tools: Vec::new(),
+            client_tool_choice: None,
             extra,
         },
     })
@@ -1,6 +1,7 @@
 //! OpenResponses HTTP entry handler.
 
 use super::*;
+use tau_core::{with_trace_context, Span, SpanKind, TraceContext, TRACEPARENT_HEADER};
 
 pub(super) async fn handle_openresponses(
     State(state): State<Arc<GatewayOpenResponsesServerState>>,
@@ -39,12 +40,50 @@ pub(super) async fn handle_openresponses(
         }
     };
 
+    let server_span = start_openresponses_server_span(&headers);
     if request.stream {
-        return stream_openresponses(state, request).await;
+        return stream_openresponses(state, request, server_span).await;
     }
 
-    match execute_openresponses_request(state, request, None).await {
+    let trace_context = server_span.context().clone();
+    let result = with_trace_context(
+        trace_context,
+        execute_openresponses_request(state, request, None),
+    )
+    .await;
+    finish_openresponses_server_span(server_span, &result);
+    match result {
         Ok(result) => (StatusCode::OK, Json(result.response)).into_response(),
         Err(error) => error.into_response(),
     }
 }
+
+/// Starts the server span for `/v1/responses`, continuing an inbound `traceparent`.
+fn start_openresponses_server_span(headers: &HeaderMap) -> Span {
+    let remote_parent = headers
+        .get(TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(TraceContext::parse_traceparent);
+    let mut span = Span::start(
+        format!("POST {OPENRESPONSES_ENDPOINT}"),
+        SpanKind::Server,
+        remote_parent.as_ref(),
+    );
+    span.set_attribute("http.request.method", "POST");
+    span.set_attribute("http.route", OPENRESPONSES_ENDPOINT);
+    span
+}
+
+pub(super) fn finish_openresponses_server_span(
+    mut span: Span,
+    result: &Result<OpenResponsesExecutionResult, OpenResponsesApiError>,
+) {
+    match result {
+        Ok(_) => span.set_status_ok(),
+        Err(error) => {
+            span.set_attribute("error.type", error.code);
+            span.set_status_error(error.message.clone());
+        }
+    }
+    span.end();
+}
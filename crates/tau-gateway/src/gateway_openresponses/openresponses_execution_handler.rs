@@ -3,7 +3,7 @@
 use super::*;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use tau_ai::{Message, ToolChoice};
+use tau_ai::{Message, MessageRole, TokenizerFamily, ToolChoice};
 use tau_contract::operator_state::{
     OperatorErrorContext, OperatorToolState, OperatorToolStatus, OperatorTurnEvent,
     OperatorTurnEventKind, OperatorTurnPhase, OperatorTurnState, OperatorTurnStatus,
@@ -58,6 +58,11 @@ pub(super) async fn execute_openresponses_request(
     stream_sender: Option<mpsc::UnboundedSender<SseFrame>>,
 ) -> Result<OpenResponsesExecutionResult, OpenResponsesApiError> {
     let mut translated = translate_openresponses_request(&request, state.config.max_input_chars)?;
+    let client_tool_names = request
+        .client_tools
+        .iter()
+        .map(|tool| tool.name.clone())
+        .collect::<HashSet<_>>();
     if request.model.is_some() {
         translated.ignored_fields.push("model".to_string());
     }
@@ -97,6 +102,7 @@ pub(super) async fn execute_openresponses_request(
             // incorrectly counts system prompt and persisted session history.
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: Some(TokenizerFamily::for_model(&state.config.model)),
             ..AgentConfig::default()
         },
     );
@@ -113,6 +119,13 @@ pub(super) async fn execute_openresponses_request(
     let read_only_saturation_state =
         Arc::new(Mutex::new(GatewayReadOnlySaturationState::default()));
     let attempt_cancellation_token = Arc::new(Mutex::new(None::<CooperativeCancellationToken>));
+    let streamed_tool_call_names = Arc::new(Mutex::new(HashMap::<(usize, usize), String>::new()));
+    let client_tool_calls = Arc::new(Mutex::new(Vec::<OpenResponsesClientToolCall>::new()));
+    register_gateway_client_tools(
+        &mut agent,
+        &request.client_tools,
+        &attempt_cancellation_token,
+    )?;
     let event_response_id = response_id.clone();
     let event_stream_sender = stream_sender.clone();
     agent.subscribe({
@@ -123,6 +136,8 @@ pub(super) async fn execute_openresponses_request(
         let tool_execution_traces = tool_execution_traces.clone();
         let read_only_saturation_state = read_only_saturation_state.clone();
         let attempt_cancellation_token = attempt_cancellation_token.clone();
+        let streamed_tool_call_names = streamed_tool_call_names.clone();
+        let client_tool_calls = client_tool_calls.clone();
         let event_response_id = event_response_id.clone();
         let event_stream_sender = event_stream_sender.clone();
         move |event| match event {
@@ -147,6 +162,16 @@ pub(super) async fn execute_openresponses_request(
                 arguments,
                 ..
             } => {
+                if client_tool_names.contains(tool_name) {
+                    if let Ok(mut guard) = client_tool_calls.lock() {
+                        guard.push(OpenResponsesClientToolCall {
+                            call_id: tool_call_id.clone(),
+                            name: tool_name.clone(),
+                            arguments: arguments.clone(),
+                        });
+                    }
+                    return;
+                }
                 tool_execution_count.fetch_add(1, Ordering::Relaxed);
                 if let Ok(mut guard) = tool_execution_starts.lock() {
                     guard.insert(
@@ -178,6 +203,9 @@ pub(super) async fn execute_openresponses_request(
                 tool_name,
                 result,
             } => {
+                if client_tool_names.contains(tool_name) {
+                    return;
+                }
                 let now_unix_ms = current_unix_timestamp_ms();
                 let pending = tool_execution_starts
                     .lock()
@@ -230,6 +258,94 @@ pub(super) async fn execute_openresponses_request(
                     }
                 }
             }
+            AgentEvent::StreamReasoningDelta { delta, .. } => {
+                if let Some(sender) = &event_stream_sender {
+                    let _ = sender.send(SseFrame::Json {
+                        event: "response.reasoning_text.delta",
+                        payload: json!({
+                            "type": "response.reasoning_text.delta",
+                            "response_id": event_response_id.as_str(),
+                            "delta": delta,
+                        }),
+                    });
+                }
+            }
+            AgentEvent::StreamToolCallStart {
+                turn,
+                index,
+                tool_call_id,
+                tool_name,
+            } => {
+                if let Ok(mut guard) = streamed_tool_call_names.lock() {
+                    guard.insert((*turn, *index), tool_name.clone());
+                }
+                if tool_name != GATEWAY_COMPLETE_TASK_TOOL_NAME {
+                    if let Some(sender) = &event_stream_sender {
+                        let _ = sender.send(SseFrame::Json {
+                            event: "response.output_item.added",
+                            payload: json!({
+                                "type": "response.output_item.added",
+                                "response_id": event_response_id.as_str(),
+                                "output_index": index,
+                                "item": {
+                                    "type": "function_call",
+                                    "call_id": tool_call_id,
+                                    "name": tool_name,
+                                    "arguments": "",
+                                    "status": "in_progress",
+                                },
+                            }),
+                        });
+                    }
+                }
+            }
+            AgentEvent::StreamToolCallArgumentsDelta {
+                turn,
+                index,
+                tool_call_id,
+                delta,
+            } => {
+                let is_completion_tool = streamed_tool_call_names
+                    .lock()
+                    .ok()
+                    .and_then(|guard| guard.get(&(*turn, *index)).cloned())
+                    .is_some_and(|tool_name| tool_name == GATEWAY_COMPLETE_TASK_TOOL_NAME);
+                if !is_completion_tool {
+                    if let Some(sender) = &event_stream_sender {
+                        let _ = sender.send(SseFrame::Json {
+                            event: "response.function_call_arguments.delta",
+                            payload: json!({
+                                "type": "response.function_call_arguments.delta",
+                                "response_id": event_response_id.as_str(),
+                                "output_index": index,
+                                "call_id": tool_call_id,
+                                "delta": delta,
+                            }),
+                        });
+                    }
+                }
+            }
+            AgentEvent::StreamToolCallEnd {
+                index,
+                tool_call_id,
+                tool_name,
+                arguments,
+                ..
+            } if tool_name != GATEWAY_COMPLETE_TASK_TOOL_NAME => {
+                if let Some(sender) = &event_stream_sender {
+                    let _ = sender.send(SseFrame::Json {
+                        event: "response.function_call_arguments.done",
+                        payload: json!({
+                            "type": "response.function_call_arguments.done",
+                            "response_id": event_response_id.as_str(),
+                            "output_index": index,
+                            "call_id": tool_call_id,
+                            "name": tool_name,
+                            "arguments": arguments.to_string(),
+                        }),
+                    });
+                }
+            }
             _ => {}
         }
     });
@@ -287,6 +403,7 @@ pub(super) async fn execute_openresponses_request(
     let mut terminal_success_verifier = None::<GatewayMissionVerifierBundle>;
     let mut terminal_completion_signal = None::<GatewayMissionCompletionSignalRecord>;
     let mut terminal_output_override = None::<String>;
+    let mut pending_client_tool_calls = Vec::<OpenResponsesClientToolCall>::new();
     let mut action_history_store = load_gateway_action_history_store(&state.config.state_dir)?;
     let prompt_result: Result<(), OpenResponsesApiError> = loop {
         refresh_gateway_learning_system_prompt(
@@ -308,7 +425,11 @@ pub(super) async fn execute_openresponses_request(
             &prompt_tokens,
             &agent,
         );
-        agent.set_next_tool_choice(attempt_tool_choice.clone());
+        agent.set_next_tool_choice(
+            attempt_tool_choice
+                .clone()
+                .or_else(|| request.client_tool_choice.clone()),
+        );
         let cancellation_token = CooperativeCancellationToken::new();
         agent.set_cancellation_token(Some(cancellation_token.clone()));
         set_gateway_attempt_cancellation_token(
@@ -454,6 +575,40 @@ pub(super) async fn execute_openresponses_request(
         };
         let attempt_messages = match attempt_result {
             Ok(messages) => messages,
+            Err(tau_agent_core::AgentError::Cancelled)
+                if client_tool_calls
+                    .lock()
+                    .map(|guard| !guard.is_empty())
+                    .unwrap_or(false) =>
+            {
+                // A client tool was called: hand the calls back instead of retrying.
+                pending_client_tool_calls = client_tool_calls
+                    .lock()
+                    .map(|mut guard| std::mem::take(&mut *guard))
+                    .map_err(|_| {
+                        OpenResponsesApiError::internal("client tool call lock is poisoned")
+                    })?;
+                let preamble = agent.messages()[attempt_start_index..]
+                    .iter()
+                    .filter(|message| message.role == MessageRole::Assistant)
+                    .map(Message::text_content)
+                    .filter(|text| !text.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                strip_failed_action_attempt_messages(&mut agent, attempt_start_index);
+                agent.append_message(Message::user(next_prompt.clone()));
+                agent.append_message(Message::assistant_text(
+                    describe_gateway_client_tool_calls(&preamble, &pending_client_tool_calls),
+                ));
+                flush_buffered_gateway_output(
+                    stream_sender.as_ref(),
+                    response_id.as_str(),
+                    buffered_stream_output.as_ref(),
+                )?;
+                terminal_output_override = Some(preamble);
+                save_gateway_mission_state(&mission_path, &mission_state)?;
+                break Ok(());
+            }
             Err(error) => {
                 finalize_pending_gateway_tool_executions(
                     &tool_execution_starts,
@@ -825,11 +980,15 @@ pub(super) async fn execute_openresponses_request(
         .lock()
         .map_err(|_| OpenResponsesApiError::internal("prompt usage lock is poisoned"))?
         .clone();
-    let finish_reason = finish_reason
-        .lock()
-        .map_err(|_| OpenResponsesApiError::internal("prompt finish reason lock is poisoned"))?
-        .clone()
-        .unwrap_or_else(|| "stop".to_string());
+    let finish_reason = if pending_client_tool_calls.is_empty() {
+        finish_reason
+            .lock()
+            .map_err(|_| OpenResponsesApiError::internal("prompt finish reason lock is poisoned"))?
+            .clone()
+            .unwrap_or_else(|| "stop".to_string())
+    } else {
+        "tool_calls".to_string()
+    };
 
     let mut ignored = BTreeSet::new();
     for field in translated.ignored_fields {
@@ -882,6 +1041,7 @@ pub(super) async fn execute_openresponses_request(
         response,
         tool_executions,
         completion_signal: terminal_completion_signal,
+        client_tool_calls: pending_client_tool_calls,
     })
 }
 
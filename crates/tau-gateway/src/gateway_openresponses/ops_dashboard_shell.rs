@@ -708,6 +708,8 @@ fn normalize_ops_channel_marker(channel: &str) -> &'static str {
         "telegram" => "telegram",
         "discord" => "discord",
         "whatsapp" => "whatsapp",
+        "matrix" => "matrix",
+        "email" => "email",
         _ => "none",
     }
 }
@@ -751,6 +753,8 @@ fn parse_ops_channel_transport(raw: &str) -> Option<MultiChannelTransport> {
         "telegram" => Some(MultiChannelTransport::Telegram),
         "discord" => Some(MultiChannelTransport::Discord),
         "whatsapp" => Some(MultiChannelTransport::Whatsapp),
+        "matrix" => Some(MultiChannelTransport::Matrix),
+        "email" => Some(MultiChannelTransport::Email),
         _ => None,
     }
 }
@@ -1834,11 +1838,7 @@ fn collect_tau_ops_dashboard_harness_snapshot(
                 snapshot.benchmark_rows = category_totals
                     .into_iter()
                     .map(|(category, (total_count, pass_count))| {
-                        let pass_rate = if total_count == 0 {
-                            0
-                        } else {
-                            (pass_count * 100) / total_count
-                        };
+                        let pass_rate = (pass_count * 100).checked_div(total_count).unwrap_or(0);
                         TauOpsDashboardHarnessBenchmarkCategoryRow {
                             category,
                             task_count: total_count,
@@ -2080,7 +2080,7 @@ fn collect_harness_durable_mission_rows(state_dir: &Path) -> Vec<TauOpsDashboard
             Some((proposal_key, updated_unix_ms, row))
         })
         .collect::<Vec<_>>();
-    rows.sort_by(|left, right| right.1.cmp(&left.1));
+    rows.sort_by_key(|row| std::cmp::Reverse(row.1));
 
     let mut seen_proposals = BTreeSet::new();
     rows.into_iter()
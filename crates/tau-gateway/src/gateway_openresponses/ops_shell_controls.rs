@@ -462,6 +462,8 @@ impl OpsShellControlsQuery {
             "telegram" => "telegram",
             "discord" => "discord",
             "whatsapp" => "whatsapp",
+            "matrix" => "matrix",
+            "email" => "email",
             _ => "none",
         }
     }
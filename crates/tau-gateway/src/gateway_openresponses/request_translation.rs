@@ -158,6 +158,33 @@ fn extract_openresponses_item(
                 return Ok(());
             }
 
+            if item_type == "function_call" {
+                let name = map
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .unwrap_or_default();
+                if name.is_empty() {
+                    return Err(OpenResponsesApiError::bad_request(
+                        "invalid_function_call",
+                        format!("input[{index}] function_call item requires a name"),
+                    ));
+                }
+                let call_id = map
+                    .get("call_id")
+                    .or_else(|| map.get("id"))
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .unwrap_or("unknown");
+                let arguments = stringify_output(map.get("arguments").unwrap_or(&Value::Null));
+                segments.push(format!(
+                    "Function call {name} (call_id={call_id}) with arguments:\n{arguments}"
+                ));
+                *extracted = extracted.saturating_add(1);
+                return Ok(());
+            }
+
             if item_type == "message" || map.contains_key("role") || map.contains_key("content") {
                 let role = map.get("role").and_then(Value::as_str).unwrap_or("user");
                 let text = extract_message_content_text(map.get("content"));
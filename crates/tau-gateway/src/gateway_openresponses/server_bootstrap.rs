@@ -96,6 +96,11 @@ pub(super) fn build_gateway_openresponses_router(
         )
         .route(OPENAI_COMPLETIONS_ENDPOINT, post(handle_openai_completions))
         .route(OPENAI_MODELS_ENDPOINT, get(handle_openai_models))
+        .route(ANTHROPIC_MESSAGES_ENDPOINT, post(handle_anthropic_messages))
+        .route(
+            ANTHROPIC_COUNT_TOKENS_ENDPOINT,
+            post(handle_anthropic_count_tokens),
+        )
         .route(
             GATEWAY_AUTH_SESSION_ENDPOINT,
             post(handle_gateway_auth_session),
@@ -133,6 +138,13 @@ pub(super) fn build_gateway_openresponses_router(
             get(handle_gateway_memory_graph),
         )
         .route(API_MEMORIES_GRAPH_ENDPOINT, get(handle_api_memories_graph))
+        .route(
+            GATEWAY_MCP_ENDPOINT,
+            post(handle_gateway_mcp_post)
+                .get(handle_gateway_mcp_stream)
+                .delete(handle_gateway_mcp_delete),
+        )
+        .route(GATEWAY_METRICS_ENDPOINT, get(handle_gateway_metrics))
         .route(
             GATEWAY_CHANNEL_LIFECYCLE_ENDPOINT,
             post(handle_gateway_channel_lifecycle_action),
@@ -111,6 +111,7 @@ pub(super) struct GatewayOpenResponsesServerState {
     pub(super) auth_runtime: Arc<Mutex<GatewayAuthRuntimeState>>,
     pub(super) compat_runtime: Arc<Mutex<GatewayOpenAiCompatRuntimeState>>,
     pub(super) ui_telemetry_runtime: Arc<Mutex<GatewayUiTelemetryRuntimeState>>,
+    pub(super) mcp_runtime: Arc<Mutex<GatewayMcpRuntimeState>>,
     pub(super) external_coding_agent_bridge: Arc<ExternalCodingAgentBridge>,
     pub(super) cortex: Arc<Cortex>,
 }
@@ -129,6 +130,7 @@ impl GatewayOpenResponsesServerState {
             auth_runtime: Arc::new(Mutex::new(GatewayAuthRuntimeState::default())),
             compat_runtime: Arc::new(Mutex::new(GatewayOpenAiCompatRuntimeState::default())),
             ui_telemetry_runtime: Arc::new(Mutex::new(GatewayUiTelemetryRuntimeState::default())),
+            mcp_runtime: Arc::new(Mutex::new(GatewayMcpRuntimeState::default())),
             external_coding_agent_bridge,
             cortex,
         }
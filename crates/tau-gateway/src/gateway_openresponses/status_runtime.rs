@@ -98,6 +98,7 @@ pub(super) async fn handle_gateway_status(
             "events": events_report,
             "training": dashboard_snapshot.training,
             "runtime_heartbeat": runtime_heartbeat,
+            "provider_key_pools": tau_ai::provider_key_pool_health_snapshot(),
             "gateway": {
                 "responses_endpoint": OPENRESPONSES_ENDPOINT,
                 "openai_compat": {
@@ -106,6 +107,10 @@ pub(super) async fn handle_gateway_status(
                     "models_endpoint": OPENAI_MODELS_ENDPOINT,
                     "runtime": state.collect_openai_compat_status_report(),
                 },
+                "anthropic_compat": {
+                    "messages_endpoint": ANTHROPIC_MESSAGES_ENDPOINT,
+                    "count_tokens_endpoint": ANTHROPIC_COUNT_TOKENS_ENDPOINT,
+                },
                 "web_ui": web_ui,
                 "dashboard_shell_endpoint": DASHBOARD_SHELL_ENDPOINT,
                 "ops_dashboard_endpoint": OPS_DASHBOARD_ENDPOINT,
@@ -115,6 +120,11 @@ pub(super) async fn handle_gateway_status(
                 "auth_session_endpoint": GATEWAY_AUTH_SESSION_ENDPOINT,
                 "status_endpoint": GATEWAY_STATUS_ENDPOINT,
                 "ws_endpoint": GATEWAY_WS_ENDPOINT,
+                "mcp": {
+                    "endpoint": GATEWAY_MCP_ENDPOINT,
+                    "runtime": state.collect_gateway_mcp_status_report(),
+                },
+                "metrics_endpoint": GATEWAY_METRICS_ENDPOINT,
                 "dashboard": dashboard,
                 "external_coding_agent": external_coding_agent,
                 "state_dir": state.config.state_dir.display().to_string(),
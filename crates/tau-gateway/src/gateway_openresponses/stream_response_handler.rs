@@ -6,10 +6,12 @@ use tau_contract::operator_state::{
     OperatorTurnEventKind, OperatorTurnPhase, OperatorTurnState, OperatorTurnStatus,
     OPERATOR_TURN_STATE_SCHEMA_VERSION,
 };
+use tau_core::{with_trace_context, Span};
 
 pub(super) async fn stream_openresponses(
     state: Arc<GatewayOpenResponsesServerState>,
     request: OpenResponsesRequest,
+    server_span: Span,
 ) -> Response {
     let (tx, rx) = mpsc::unbounded_channel::<SseFrame>();
     tokio::spawn(async move {
@@ -17,12 +19,20 @@ pub(super) async fn stream_openresponses(
             translate_openresponses_request(&request, state.config.max_input_chars)
                 .ok()
                 .map(|prompt| (prompt.session_key, prompt.mission_id));
-        match execute_openresponses_request(state, request, Some(tx.clone())).await {
+        let trace_context = server_span.context().clone();
+        let result = with_trace_context(
+            trace_context,
+            execute_openresponses_request(state, request, Some(tx.clone())),
+        )
+        .await;
+        finish_openresponses_server_span(server_span, &result);
+        match result {
             Ok(result) => {
                 let OpenResponsesExecutionResult {
                     response,
                     tool_executions,
                     completion_signal,
+                    ..
                 } = result;
                 let _ = tx.send(SseFrame::Json {
                     event: "response.output_text.done",
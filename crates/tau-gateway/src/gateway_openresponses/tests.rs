@@ -2,11 +2,15 @@
 mod api_memories_graph;
 mod e2e_harness;
 mod fixtures;
+mod gateway_anthropic_compat_api;
 mod gateway_channel_lifecycle_api;
+mod gateway_mcp_api;
 mod gateway_memory_api;
 mod gateway_memory_entries_api;
 mod gateway_memory_graph_api;
+mod gateway_metrics_api;
 mod gateway_sessions_api;
+mod gateway_trace_propagation_api;
 mod llm_clients;
 mod ops_auth_navigation;
 mod ops_command_center;
@@ -114,6 +118,8 @@ fn unit_translate_openresponses_request_supports_item_input_and_function_call_ou
         metadata: json!({"session_id": "issue-42"}),
         conversation: None,
         previous_response_id: None,
+        client_tools: Vec::new(),
+        client_tool_choice: None,
         extra: BTreeMap::from([("temperature".to_string(), json!(0.0))]),
     };
 
@@ -142,6 +148,8 @@ fn unit_translate_openresponses_request_prefers_explicit_mission_id() {
         }),
         conversation: None,
         previous_response_id: None,
+        client_tools: Vec::new(),
+        client_tool_choice: None,
         extra: BTreeMap::new(),
     };
 
@@ -163,6 +171,8 @@ fn unit_translate_openresponses_request_defaults_mission_id_to_session_key() {
         }),
         conversation: None,
         previous_response_id: None,
+        client_tools: Vec::new(),
+        client_tool_choice: None,
         extra: BTreeMap::new(),
     };
 
@@ -182,6 +192,8 @@ fn unit_translate_openresponses_request_rejects_invalid_input_shape() {
         metadata: json!({}),
         conversation: None,
         previous_response_id: None,
+        client_tools: Vec::new(),
+        client_tool_choice: None,
         extra: BTreeMap::new(),
     };
 
@@ -7193,6 +7205,7 @@ async fn integration_gateway_status_endpoint_returns_service_snapshot() {
         payload["runtime_heartbeat"]["reason_code"],
         Value::String("heartbeat_state_missing".to_string())
     );
+    assert!(payload["provider_key_pools"].is_array());
     assert_eq!(
         payload["runtime_heartbeat"]["run_state"],
         Value::String("unknown".to_string())
@@ -10252,6 +10265,20 @@ async fn regression_openresponses_stream_emits_completed_event_for_non_timeout_t
     );
     assert!(body.contains("\"tool_name\":\"read\""), "body={body}");
     assert!(body.contains("\"timed_out\":false"), "body={body}");
+    let function_call_added = body
+        .find("event: response.output_item.added")
+        .expect("streamed function call item");
+    let function_call_done = body
+        .find("event: response.function_call_arguments.done")
+        .expect("streamed function call arguments");
+    let tool_started = body
+        .find("event: response.tool_execution.started")
+        .expect("tool execution start");
+    assert!(function_call_added < function_call_done && function_call_done < tool_started);
+    assert!(
+        body.contains("\"call_id\":\"call-successful-read\""),
+        "body={body}"
+    );
     assert!(body.contains("event: response.failed"), "body={body}");
 
     handle.abort();
@@ -12700,6 +12727,10 @@ async fn tier_pr_o3_openai_compatibility_matrix() {
             output_tokens: 10,
             total_tokens: 22,
             cached_input_tokens: 0,
+            reasoning_tokens: 0,
+            hedge_input_tokens: 0,
+            hedge_output_tokens: 0,
+            hedge_model: None,
         },
     }]));
     let max_tokens_state = test_state_with_client_and_auth(
@@ -0,0 +1,451 @@
+use super::*;
+use tau_ai::TokenizerFamily;
+
+#[test]
+fn unit_translate_anthropic_messages_request_maps_system_tool_use_and_tool_result() {
+    let request = AnthropicMessagesRequest {
+        model: Some("claude-sonnet-4-5".to_string()),
+        messages: json!([
+            {"role": "user", "content": "List the repo files."},
+            {"role": "assistant", "content": [
+                {"type": "text", "text": "Checking."},
+                {"type": "tool_use", "id": "toolu_01", "name": "bash", "input": {"command": "ls"}}
+            ]},
+            {"role": "user", "content": [
+                {"type": "tool_result", "tool_use_id": "toolu_01", "content": [
+                    {"type": "text", "text": "Cargo.toml\nsrc"}
+                ]},
+                {"type": "image", "source": {"type": "base64", "data": "AAAA"}}
+            ]}
+        ]),
+        system: json!([{"type": "text", "text": "You are terse."}]),
+        max_tokens: Some(256),
+        stream: false,
+        metadata: json!({"user_id": "anthropic-user-7"}),
+        extra: BTreeMap::from([
+            ("tools".to_string(), json!([{"name": "bash"}])),
+            (
+                "tool_choice".to_string(),
+                json!({"type": "tool", "name": "bash"}),
+            ),
+            ("temperature".to_string(), json!(0.1)),
+        ]),
+    };
+
+    let translated =
+        translate_anthropic_messages_request(request).expect("translate anthropic request");
+    assert!(!translated.stream);
+    assert_eq!(translated.request.max_tokens, Some(256));
+    assert_eq!(
+        translated.request.instructions.as_deref(),
+        Some("You are terse.")
+    );
+    assert_eq!(
+        translated.request.metadata["session_id"].as_str(),
+        Some("anthropic-user-7")
+    );
+    assert_eq!(
+        translated.ignored_fields,
+        vec!["messages[2].content[1]".to_string()]
+    );
+    assert_eq!(translated.request.client_tools.len(), 1);
+    assert_eq!(translated.request.client_tools[0].name, "bash");
+    assert_eq!(
+        translated.request.client_tools[0].parameters,
+        json!({"type": "object"})
+    );
+    assert_eq!(
+        translated.request.client_tool_choice,
+        Some(ToolChoice::Tool {
+            name: "bash".to_string()
+        })
+    );
+    let input = translated.request.input.as_array().expect("array input");
+    assert_eq!(input.len(), 4);
+    assert_eq!(input[2]["type"], "function_call");
+    assert_eq!(input[2]["call_id"], "toolu_01");
+    assert_eq!(input[2]["name"], "bash");
+    assert_eq!(input[2]["arguments"], "{\"command\":\"ls\"}");
+    assert_eq!(input[3]["type"], "function_call_output");
+    assert_eq!(input[3]["call_id"], "toolu_01");
+    assert_eq!(input[3]["output"], "Cargo.toml\nsrc");
+
+    let prompt = translate_openresponses_request(&translated.request, 10_000)
+        .expect("translate openresponses prompt");
+    assert!(prompt
+        .prompt
+        .starts_with("System instructions:\nYou are terse."));
+    assert!(prompt
+        .prompt
+        .contains("Function call bash (call_id=toolu_01) with arguments:\n{\"command\":\"ls\"}"));
+    assert!(prompt
+        .prompt
+        .contains("Function output (call_id=toolu_01):\nCargo.toml"));
+}
+
+#[test]
+fn regression_translate_anthropic_messages_request_rejects_unknown_role_and_orphan_tool_result() {
+    let unknown_role = AnthropicMessagesRequest {
+        model: None,
+        messages: json!([{"role": "system", "content": "hi"}]),
+        system: Value::Null,
+        max_tokens: None,
+        stream: false,
+        metadata: Value::Null,
+        extra: BTreeMap::new(),
+    };
+    let error = translate_anthropic_messages_request(unknown_role).expect_err("reject role");
+    assert_eq!(error.code, "invalid_role");
+
+    let orphan_result = AnthropicMessagesRequest {
+        model: None,
+        messages: json!([{"role": "user", "content": [{"type": "tool_result", "content": "ok"}]}]),
+        system: Value::Null,
+        max_tokens: None,
+        stream: false,
+        metadata: Value::Null,
+        extra: BTreeMap::new(),
+    };
+    let error = translate_anthropic_messages_request(orphan_result).expect_err("reject result");
+    assert_eq!(error.code, "invalid_tool_result");
+
+    let undeclared_choice = AnthropicMessagesRequest {
+        model: None,
+        messages: json!([{"role": "user", "content": "hi"}]),
+        system: Value::Null,
+        max_tokens: None,
+        stream: false,
+        metadata: Value::Null,
+        extra: BTreeMap::from([
+            ("tools".to_string(), json!([{"name": "get_weather"}])),
+            (
+                "tool_choice".to_string(),
+                json!({"type": "tool", "name": "bash"}),
+            ),
+        ]),
+    };
+    let error =
+        translate_anthropic_messages_request(undeclared_choice).expect_err("reject tool choice");
+    assert_eq!(error.code, "invalid_tool_choice");
+}
+
+#[tokio::test]
+async fn integration_anthropic_messages_endpoint_returns_client_tool_use_blocks() {
+    let temp = tempdir().expect("tempdir");
+    let scripted = Arc::new(ScriptedGatewayLlmClient::new(vec![ChatResponse {
+        message: Message::assistant_blocks(vec![
+            ContentBlock::Text {
+                text: "Looking that up.".to_string(),
+            },
+            ContentBlock::ToolCall {
+                id: "toolu_weather_1".to_string(),
+                name: "get_weather".to_string(),
+                arguments: json!({"city": "Paris"}),
+            },
+        ]),
+        finish_reason: Some("tool_calls".to_string()),
+        usage: ChatUsage::default(),
+    }]));
+    let state = test_state_with_client_and_auth(
+        temp.path(),
+        10_000,
+        scripted.clone(),
+        Arc::new(NoopGatewayToolRegistrar),
+        GatewayOpenResponsesAuthMode::Token,
+        Some("secret"),
+        None,
+        60,
+        120,
+    );
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .header("x-api-key", "secret")
+        .json(&json!({
+            "max_tokens": 128,
+            "tools": [{
+                "name": "get_weather",
+                "description": "Current weather for a city.",
+                "input_schema": {
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}},
+                    "required": ["city"]
+                }
+            }],
+            "tool_choice": {"type": "any"},
+            "messages": [{"role": "user", "content": "What is the weather in Paris?"}]
+        }))
+        .send()
+        .await
+        .expect("send messages request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = response.json::<Value>().await.expect("parse message");
+    assert_eq!(payload["stop_reason"], "tool_use");
+    assert_eq!(payload["content"][0]["type"], "text");
+    assert_eq!(payload["content"][0]["text"], "Looking that up.");
+    assert_eq!(payload["content"][1]["type"], "tool_use");
+    assert_eq!(payload["content"][1]["id"], "toolu_weather_1");
+    assert_eq!(payload["content"][1]["name"], "get_weather");
+    assert_eq!(payload["content"][1]["input"], json!({"city": "Paris"}));
+
+    let captured = scripted.captured_requests().await;
+    assert_eq!(captured.len(), 1, "client tool call must end the turn");
+    assert!(captured[0]
+        .tools
+        .iter()
+        .any(|tool| tool.name == "get_weather"));
+    assert_eq!(captured[0].tool_choice, Some(ToolChoice::Required));
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn functional_anthropic_messages_endpoint_accepts_x_api_key_and_returns_message() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .header("x-api-key", "secret")
+        .header("anthropic-version", "2023-06-01")
+        .json(&json!({
+            "model": "claude-sonnet-4-5",
+            "max_tokens": 128,
+            "system": "Answer briefly.",
+            "messages": [{"role": "user", "content": "hello anthropic compat"}]
+        }))
+        .send()
+        .await
+        .expect("send messages request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = response.json::<Value>().await.expect("parse message");
+    assert_eq!(payload["type"], "message");
+    assert_eq!(payload["role"], "assistant");
+    assert!(payload["id"]
+        .as_str()
+        .unwrap_or_default()
+        .starts_with("msg_"));
+    assert_eq!(payload["content"][0]["type"], "text");
+    assert!(payload["content"][0]["text"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("messages="));
+    assert_eq!(payload["stop_reason"], "end_turn");
+    assert!(payload["usage"]["input_tokens"].as_u64().is_some());
+    assert!(payload["usage"]["output_tokens"].as_u64().is_some());
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn functional_anthropic_messages_endpoint_streams_anthropic_event_sequence() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .bearer_auth("secret")
+        .json(&json!({
+            "max_tokens": 128,
+            "stream": true,
+            "messages": [{"role": "user", "content": "hello anthropic stream"}]
+        }))
+        .send()
+        .await
+        .expect("send stream request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.text().await.expect("read stream body");
+    let events = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("event: "))
+        .collect::<Vec<_>>();
+    assert_eq!(events.first(), Some(&"message_start"));
+    assert_eq!(events.get(1), Some(&"content_block_start"));
+    assert_eq!(
+        &events[events.len() - 3..],
+        &["content_block_stop", "message_delta", "message_stop"]
+    );
+    let deltas = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str::<Value>(data).ok())
+        .filter(|payload| payload["type"] == "content_block_delta")
+        .collect::<Vec<_>>();
+    assert!(
+        deltas.len() > 1,
+        "model deltas must be forwarded as they arrive, body={body}"
+    );
+    assert_eq!(events.len(), deltas.len() + 5);
+    assert!(deltas
+        .iter()
+        .all(|delta| delta["delta"]["type"] == "text_delta" && delta["index"] == 0));
+    let streamed = deltas
+        .iter()
+        .filter_map(|delta| delta["delta"]["text"].as_str())
+        .collect::<String>();
+    assert!(streamed.contains("messages="), "streamed={streamed}");
+    assert!(body.contains("\"stop_reason\":\"end_turn\""));
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn integration_anthropic_messages_stream_emits_client_tool_use_blocks() {
+    let temp = tempdir().expect("tempdir");
+    let scripted = Arc::new(ScriptedGatewayLlmClient::new(vec![ChatResponse {
+        message: Message::assistant_blocks(vec![ContentBlock::ToolCall {
+            id: "toolu_weather_2".to_string(),
+            name: "get_weather".to_string(),
+            arguments: json!({"city": "Oslo"}),
+        }]),
+        finish_reason: Some("tool_calls".to_string()),
+        usage: ChatUsage::default(),
+    }]));
+    let state = test_state_with_client_and_auth(
+        temp.path(),
+        10_000,
+        scripted,
+        Arc::new(NoopGatewayToolRegistrar),
+        GatewayOpenResponsesAuthMode::Token,
+        Some("secret"),
+        None,
+        60,
+        120,
+    );
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .header("x-api-key", "secret")
+        .json(&json!({
+            "max_tokens": 128,
+            "stream": true,
+            "tools": [{"name": "get_weather", "input_schema": {"type": "object"}}],
+            "messages": [{"role": "user", "content": "What is the weather in Oslo?"}]
+        }))
+        .send()
+        .await
+        .expect("send stream request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.text().await.expect("read stream body");
+    let events = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("event: "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        vec![
+            "message_start",
+            "content_block_start",
+            "content_block_delta",
+            "content_block_stop",
+            "message_delta",
+            "message_stop",
+        ]
+    );
+    assert!(body.contains("\"type\":\"tool_use\""), "body={body}");
+    assert!(body.contains("\"id\":\"toolu_weather_2\""));
+    assert!(body.contains("\"type\":\"input_json_delta\""));
+    assert!(body.contains("\"stop_reason\":\"tool_use\""));
+
+    handle.abort();
+}
+
+#[test]
+fn unit_count_anthropic_input_tokens_uses_requested_model_tokenizer() {
+    let prompt = "fn main() { println!(\"count these tokens\"); }";
+    let claude = count_anthropic_input_tokens("claude-sonnet-4-5", "You are terse.", prompt);
+    let expected = TokenizerFamily::Anthropic
+        .count_message_tokens(&Message::system("You are terse."))
+        + TokenizerFamily::Anthropic.count_message_tokens(&Message::user(prompt));
+    assert_eq!(claude, u64::from(expected));
+
+    let gpt = count_anthropic_input_tokens("gpt-4o", "", prompt);
+    assert_eq!(
+        gpt,
+        u64::from(TokenizerFamily::O200kBase.count_message_tokens(&Message::user(prompt)))
+    );
+}
+
+#[tokio::test]
+async fn integration_anthropic_count_tokens_and_status_report_compat_surface() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+    let client = Client::new();
+
+    let response = client
+        .post(format!("http://{addr}{ANTHROPIC_COUNT_TOKENS_ENDPOINT}"))
+        .header("x-api-key", "secret")
+        .json(&json!({
+            "model": "claude-sonnet-4-5",
+            "messages": [{"role": "user", "content": "count these tokens please"}]
+        }))
+        .send()
+        .await
+        .expect("send count_tokens request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = response.json::<Value>().await.expect("parse count");
+    assert!(payload["input_tokens"].as_u64().unwrap_or_default() > 0);
+
+    let status = client
+        .get(format!("http://{addr}{GATEWAY_STATUS_ENDPOINT}"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .expect("status request")
+        .json::<Value>()
+        .await
+        .expect("parse status");
+    assert_eq!(
+        status["gateway"]["anthropic_compat"]["messages_endpoint"],
+        ANTHROPIC_MESSAGES_ENDPOINT
+    );
+    assert_eq!(
+        status["gateway"]["openai_compat"]["runtime"]["anthropic_count_tokens_requests"],
+        1
+    );
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn regression_anthropic_messages_endpoint_returns_anthropic_error_envelopes() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+    let client = Client::new();
+
+    let unauthorized = client
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .header("x-api-key", "wrong")
+        .json(&json!({"messages": [{"role": "user", "content": "hi"}]}))
+        .send()
+        .await
+        .expect("send unauthorized request");
+    assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+    let payload = unauthorized.json::<Value>().await.expect("parse error");
+    assert_eq!(payload["type"], "error");
+    assert_eq!(payload["error"]["type"], "authentication_error");
+
+    let invalid = client
+        .post(format!("http://{addr}{ANTHROPIC_MESSAGES_ENDPOINT}"))
+        .header("x-api-key", "secret")
+        .json(&json!({"messages": "not-an-array"}))
+        .send()
+        .await
+        .expect("send invalid request");
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    let payload = invalid.json::<Value>().await.expect("parse error");
+    assert_eq!(payload["error"]["type"], "invalid_request_error");
+    assert_eq!(payload["error"]["code"], "invalid_messages");
+
+    handle.abort();
+}
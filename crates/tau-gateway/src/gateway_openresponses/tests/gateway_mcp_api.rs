@@ -0,0 +1,384 @@
+use super::*;
+
+async fn mcp_initialize(client: &Client, addr: SocketAddr, token: &str) -> String {
+    let response = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth(token)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": "init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-06-18",
+                "capabilities": {},
+                "clientInfo": {"name": "ide", "version": "1.0.0"}
+            }
+        }))
+        .send()
+        .await
+        .expect("send mcp initialize");
+    assert_eq!(response.status(), StatusCode::OK);
+    let session_id = response
+        .headers()
+        .get("mcp-session-id")
+        .and_then(|value| value.to_str().ok())
+        .expect("mcp session id header")
+        .to_string();
+    let payload = response.json::<Value>().await.expect("parse initialize");
+    assert_eq!(payload["result"]["protocolVersion"], "2025-06-18");
+    assert_eq!(
+        payload["result"]["capabilities"]["resources"]["subscribe"],
+        Value::Bool(true)
+    );
+    session_id
+}
+
+async fn mcp_request(
+    client: &Client,
+    addr: SocketAddr,
+    token: &str,
+    session_id: &str,
+    method: &str,
+    params: Value,
+) -> Value {
+    let response = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth(token)
+        .header("mcp-session-id", session_id)
+        .header("mcp-protocol-version", "2025-06-18")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": method,
+            "method": method,
+            "params": params
+        }))
+        .send()
+        .await
+        .expect("send mcp request");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json::<Value>().await.expect("parse mcp response")
+}
+
+#[tokio::test]
+async fn integration_gateway_mcp_endpoint_lists_and_reads_session_memory_and_skill_resources() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state_with_skills(
+        temp.path(),
+        "secret",
+        vec![GatewayOpenResponsesSkillPrompt {
+            name: "release-checklist".to_string(),
+            description: "Release steps".to_string(),
+            content: "# Release\n- tag\n- publish".to_string(),
+        }],
+    );
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+    let client = Client::new();
+
+    let append = client
+        .post(format!(
+            "http://{addr}{}",
+            expand_session_template(GATEWAY_SESSION_APPEND_ENDPOINT, "mcp-session")
+        ))
+        .bearer_auth("secret")
+        .json(&json!({
+            "role": "user",
+            "content": "hello from mcp",
+            "policy_gate": SESSION_WRITE_POLICY_GATE
+        }))
+        .send()
+        .await
+        .expect("append session message");
+    assert_eq!(append.status(), StatusCode::OK);
+    let memory = client
+        .put(format!(
+            "http://{addr}{}",
+            expand_memory_entry_template(GATEWAY_MEMORY_ENTRY_ENDPOINT, "mcp-session", "mem-1")
+        ))
+        .bearer_auth("secret")
+        .json(&json!({
+            "summary": "Gateway exposes MCP resources.",
+            "memory_type": "fact",
+            "policy_gate": MEMORY_WRITE_POLICY_GATE
+        }))
+        .send()
+        .await
+        .expect("write memory entry");
+    assert_eq!(memory.status(), StatusCode::CREATED);
+
+    let session_id = mcp_initialize(&client, addr, "secret").await;
+    let initialized = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header("mcp-session-id", session_id.as_str())
+        .json(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+        .send()
+        .await
+        .expect("send initialized notification");
+    assert_eq!(initialized.status(), StatusCode::ACCEPTED);
+
+    let listed = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/list",
+        json!({}),
+    )
+    .await;
+    let uris = listed["result"]["resources"]
+        .as_array()
+        .expect("resources array")
+        .iter()
+        .filter_map(|resource| resource["uri"].as_str())
+        .collect::<Vec<_>>();
+    assert!(uris.contains(&"tau://sessions/mcp-session"));
+    assert!(uris.contains(&"tau://memory/mcp-session/mem-1"));
+    assert!(uris.contains(&"tau://skills/release-checklist"));
+
+    let session_read = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/read",
+        json!({"uri": "tau://sessions/mcp-session"}),
+    )
+    .await;
+    let session_text = session_read["result"]["contents"][0]["text"]
+        .as_str()
+        .expect("session text");
+    assert!(session_text.contains("hello from mcp"));
+
+    let memory_read = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/read",
+        json!({"uri": "tau://memory/mcp-session/mem-1"}),
+    )
+    .await;
+    let memory_payload = serde_json::from_str::<Value>(
+        memory_read["result"]["contents"][0]["text"]
+            .as_str()
+            .expect("memory text"),
+    )
+    .expect("parse memory payload");
+    assert_eq!(memory_payload["memory_id"], "mem-1");
+    assert_eq!(memory_payload["summary"], "Gateway exposes MCP resources.");
+
+    let skill_read = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/read",
+        json!({"uri": "tau://skills/release-checklist"}),
+    )
+    .await;
+    assert_eq!(
+        skill_read["result"]["contents"][0]["mimeType"],
+        "text/markdown"
+    );
+    assert_eq!(
+        skill_read["result"]["contents"][0]["text"],
+        "# Release\n- tag\n- publish"
+    );
+
+    let templates = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/templates/list",
+        json!({}),
+    )
+    .await;
+    assert_eq!(
+        templates["result"]["resourceTemplates"]
+            .as_array()
+            .map(Vec::len),
+        Some(3)
+    );
+
+    let missing = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/read",
+        json!({"uri": "tau://sessions/unknown"}),
+    )
+    .await;
+    assert_eq!(missing["error"]["code"], -32002);
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn integration_gateway_mcp_stream_notifies_subscribers_when_session_changes() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state.clone())
+        .await
+        .expect("spawn server");
+    let client = Client::new();
+    let append_endpoint = format!(
+        "http://{addr}{}",
+        expand_session_template(GATEWAY_SESSION_APPEND_ENDPOINT, "watched")
+    );
+    let append = |content: &'static str| {
+        client
+            .post(append_endpoint.as_str())
+            .bearer_auth("secret")
+            .json(&json!({
+                "role": "user",
+                "content": content,
+                "policy_gate": SESSION_WRITE_POLICY_GATE
+            }))
+            .send()
+    };
+    assert_eq!(
+        append("first").await.expect("first append").status(),
+        StatusCode::OK
+    );
+
+    let session_id = mcp_initialize(&client, addr, "secret").await;
+    let subscribed = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/subscribe",
+        json!({"uri": "tau://sessions/watched"}),
+    )
+    .await;
+    assert_eq!(subscribed["result"], json!({}));
+
+    let stream = client
+        .get(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header("mcp-session-id", session_id.as_str())
+        .send()
+        .await
+        .expect("open mcp stream");
+    assert_eq!(stream.status(), StatusCode::OK);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(
+        append("second").await.expect("second append").status(),
+        StatusCode::OK
+    );
+
+    let mut body = stream.bytes_stream();
+    let mut buffer = String::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while tokio::time::Instant::now() < deadline
+        && !buffer.contains("notifications/resources/updated")
+    {
+        let Ok(Some(Ok(chunk))) =
+            tokio::time::timeout(Duration::from_millis(300), body.next()).await
+        else {
+            continue;
+        };
+        buffer.push_str(String::from_utf8_lossy(&chunk).as_ref());
+    }
+    assert!(buffer.contains("notifications/resources/updated"));
+    assert!(buffer.contains("tau://sessions/watched"));
+
+    let status = client
+        .get(format!("http://{addr}{GATEWAY_STATUS_ENDPOINT}"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .expect("status request")
+        .json::<Value>()
+        .await
+        .expect("parse status");
+    assert_eq!(status["gateway"]["mcp"]["endpoint"], GATEWAY_MCP_ENDPOINT);
+    assert_eq!(
+        status["gateway"]["mcp"]["runtime"]["active_subscriptions"],
+        1
+    );
+
+    let closed = client
+        .delete(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header("mcp-session-id", session_id.as_str())
+        .send()
+        .await
+        .expect("close mcp session");
+    assert_eq!(closed.status(), StatusCode::OK);
+    let after_close = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header("mcp-session-id", session_id.as_str())
+        .json(&json!({"jsonrpc": "2.0", "id": 1, "method": "ping"}))
+        .send()
+        .await
+        .expect("ping after close");
+    assert_eq!(after_close.status(), StatusCode::NOT_FOUND);
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn regression_gateway_mcp_endpoint_rejects_unauthorized_and_sessionless_requests() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+    let client = Client::new();
+    let ping = json!({"jsonrpc": "2.0", "id": 1, "method": "ping"});
+
+    let unauthorized = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .json(&ping)
+        .send()
+        .await
+        .expect("unauthorized request");
+    assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+
+    let sessionless = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .json(&ping)
+        .send()
+        .await
+        .expect("sessionless request");
+    assert_eq!(sessionless.status(), StatusCode::BAD_REQUEST);
+    let sessionless_payload = sessionless.json::<Value>().await.expect("parse payload");
+    assert_eq!(sessionless_payload["error"]["code"], "mcp_session_required");
+
+    let unsupported_version = client
+        .post(format!("http://{addr}{GATEWAY_MCP_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header("mcp-protocol-version", "1999-01-01")
+        .json(&ping)
+        .send()
+        .await
+        .expect("unsupported version request");
+    assert_eq!(unsupported_version.status(), StatusCode::BAD_REQUEST);
+
+    let session_id = mcp_initialize(&client, addr, "secret").await;
+    let invalid_uri = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "resources/subscribe",
+        json!({"uri": "tau://sessions/../etc"}),
+    )
+    .await;
+    assert_eq!(invalid_uri["error"]["code"], -32602);
+    let unknown_method = mcp_request(
+        &client,
+        addr,
+        "secret",
+        &session_id,
+        "tools/call",
+        json!({}),
+    )
+    .await;
+    assert_eq!(unknown_method["error"]["code"], -32601);
+
+    handle.abort();
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+#[tokio::test]
+async fn integration_gateway_metrics_endpoint_exposes_provider_token_and_gateway_series() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+    let client = Client::new();
+
+    let response = client
+        .post(format!("http://{addr}{OPENRESPONSES_ENDPOINT}"))
+        .bearer_auth("secret")
+        .json(&json!({"input": "hello metrics"}))
+        .send()
+        .await
+        .expect("send openresponses request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let scrape = client
+        .get(format!("http://{addr}{GATEWAY_METRICS_ENDPOINT}"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .expect("scrape metrics");
+    assert_eq!(scrape.status(), StatusCode::OK);
+    let content_type = scrape
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    assert!(content_type.starts_with("application/openmetrics-text"));
+    let body = scrape.text().await.expect("metrics body");
+    assert!(body.contains("# TYPE tau_provider_request_duration_seconds histogram\n"));
+    assert!(body.contains(
+        "tau_provider_request_duration_seconds_count{model=\"gpt-5.2\",outcome=\"success\",provider=\"openai\"} "
+    ));
+    assert!(body
+        .contains("tau_llm_tokens_total{kind=\"output\",model=\"gpt-5.2\",provider=\"openai\"} "));
+    assert!(body.contains("tau_llm_cost_usd_total{model=\"gpt-5.2\",provider=\"openai\"} "));
+    assert!(body.contains("# TYPE tau_gateway_mcp_sessions gauge\n"));
+    assert!(body.ends_with("# EOF\n"));
+
+    let status = client
+        .get(format!("http://{addr}{GATEWAY_STATUS_ENDPOINT}"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .expect("status request")
+        .json::<Value>()
+        .await
+        .expect("parse status");
+    assert_eq!(
+        status["gateway"]["metrics_endpoint"],
+        GATEWAY_METRICS_ENDPOINT
+    );
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn regression_gateway_metrics_endpoint_requires_authorization() {
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .get(format!("http://{addr}{GATEWAY_METRICS_ENDPOINT}"))
+        .send()
+        .await
+        .expect("unauthorized scrape");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    handle.abort();
+}
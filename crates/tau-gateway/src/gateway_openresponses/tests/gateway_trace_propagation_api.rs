@@ -0,0 +1,68 @@
+use super::*;
+use tau_core::{install_span_exporter, SpanExporter, SpanKind, SpanRecord};
+
+const REMOTE_TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const REMOTE_PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+#[derive(Default)]
+struct CollectingSpanExporter {
+    spans: std::sync::Mutex<Vec<SpanRecord>>,
+}
+
+impl SpanExporter for CollectingSpanExporter {
+    fn export(&self, span: SpanRecord) {
+        self.spans.lock().expect("spans lock").push(span);
+    }
+}
+
+#[tokio::test]
+async fn integration_openresponses_continues_inbound_traceparent_for_agent_spans() {
+    let exporter = Arc::new(CollectingSpanExporter::default());
+    install_span_exporter(exporter.clone());
+    let temp = tempdir().expect("tempdir");
+    let state = test_state(temp.path(), 10_000, "secret");
+    let (addr, handle) = spawn_test_server(state).await.expect("spawn server");
+
+    let response = Client::new()
+        .post(format!("http://{addr}{OPENRESPONSES_ENDPOINT}"))
+        .bearer_auth("secret")
+        .header(
+            "traceparent",
+            format!("00-{REMOTE_TRACE_ID}-{REMOTE_PARENT_SPAN_ID}-01"),
+        )
+        .json(&json!({"input": "hello traces"}))
+        .send()
+        .await
+        .expect("send openresponses request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let spans = exporter
+        .spans
+        .lock()
+        .expect("spans lock")
+        .iter()
+        .filter(|span| span.trace_id == REMOTE_TRACE_ID)
+        .cloned()
+        .collect::<Vec<_>>();
+    let server_span = spans
+        .iter()
+        .find(|span| span.name == "POST /v1/responses")
+        .expect("server span");
+    assert_eq!(server_span.kind, SpanKind::Server);
+    assert_eq!(
+        server_span.parent_span_id.as_deref(),
+        Some(REMOTE_PARENT_SPAN_ID)
+    );
+    let agent_span = spans
+        .iter()
+        .find(|span| span.name.starts_with("invoke_agent"))
+        .expect("agent span");
+    assert_eq!(
+        agent_span.parent_span_id.as_deref(),
+        Some(server_span.span_id.as_str())
+    );
+    assert!(spans.iter().any(|span| span.name == "agent_turn"
+        && span.parent_span_id.as_deref() == Some(agent_span.span_id.as_str())));
+
+    handle.abort();
+}
@@ -38,6 +38,10 @@ impl LlmClient for MockGatewayLlmClient {
                 output_tokens: 2,
                 total_tokens: message_count as u64 + 2,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -134,6 +134,16 @@ pub(super) fn test_state_with_fixture_mcp_tools(
     )
 }
 
+pub(super) fn test_state_with_skills(
+    root: &Path,
+    token: &str,
+    available_skills: Vec<GatewayOpenResponsesSkillPrompt>,
+) -> Arc<GatewayOpenResponsesServerState> {
+    let mut config = test_state(root, 10_000, token).config.clone();
+    config.available_skills = available_skills;
+    Arc::new(GatewayOpenResponsesServerState::new(config))
+}
+
 pub(super) fn test_state(
     root: &Path,
     max_input_chars: usize,
@@ -8,6 +8,7 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tau_agent_core::{SafetyPolicy, SafetyRuleSet};
+use tau_ai::{ToolChoice, ToolDefinition};
 
 use super::GatewayMissionCompletionSignalRecord;
 
@@ -103,6 +104,12 @@ pub(super) struct OpenResponsesRequest {
     pub(super) conversation: Option<String>,
     #[serde(default, rename = "previous_response_id")]
     pub(super) previous_response_id: Option<String>,
+    /// Function tools declared by a compatibility client. Calls to them are
+    /// returned to the caller instead of being executed by the gateway.
+    #[serde(skip)]
+    pub(super) client_tools: Vec<ToolDefinition>,
+    #[serde(skip)]
+    pub(super) client_tool_choice: Option<ToolChoice>,
     #[serde(flatten)]
     pub(super) extra: BTreeMap<String, Value>,
 }
@@ -409,6 +416,15 @@ pub(super) struct OpenResponsesExecutionResult {
     pub(super) response: OpenResponsesResponse,
     pub(super) tool_executions: Vec<OpenResponsesObservedToolExecution>,
     pub(super) completion_signal: Option<GatewayMissionCompletionSignalRecord>,
+    pub(super) client_tool_calls: Vec<OpenResponsesClientToolCall>,
+}
+
+/// Call to a client-declared tool that the caller must execute.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct OpenResponsesClientToolCall {
+    pub(super) call_id: String,
+    pub(super) name: String,
+    pub(super) arguments: Value,
 }
 
 #[derive(Debug, Clone)]
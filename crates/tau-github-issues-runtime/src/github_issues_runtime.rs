@@ -96,8 +96,12 @@ mod issue_run_task;
 mod issue_session_runtime;
 mod issue_state_store;
 mod prompt_execution;
+mod pull_request_review_helpers;
+mod pull_request_review_runtime;
 
-use github_api_client::{GithubApiClient, GithubCommentCreateResponse};
+use github_api_client::{
+    GithubApiClient, GithubCommentCreateResponse, GithubPullRequest, GithubReviewComment,
+};
 use issue_command_helpers::{
     default_demo_index_binary_path, default_demo_index_repo_root, parse_tau_issue_command,
 };
@@ -119,6 +123,12 @@ use prompt_execution::{
     run_prompt_for_event, DownloadedGithubAttachment, PromptRunReport, PromptUsageSummary,
     RunPromptForEventRequest,
 };
+use pull_request_review_helpers::{
+    collect_review_thread_events, parse_pull_request_review_reply, parse_review_thread_command,
+    pull_request_head_event, pull_request_head_event_key, rbac_action_for_review_thread_command,
+    render_pull_request_review_body, render_pull_request_review_prompt,
+    render_review_thread_prompt, review_thread_command_usage, ReviewThreadCommand,
+};
 
 const GITHUB_STATE_SCHEMA_VERSION: u32 = 1;
 const GITHUB_COMMENT_MAX_CHARS: usize = 65_000;
@@ -161,6 +171,7 @@ pub struct GithubIssuesBridgeRuntimeConfig {
     pub required_issue_numbers: Vec<u64>,
     pub include_issue_body: bool,
     pub include_edited_comments: bool,
+    pub pull_request_review: bool,
     pub processed_event_cap: usize,
     pub retry_max_attempts: usize,
     pub retry_base_delay_ms: u64,
@@ -605,181 +616,16 @@ impl GithubIssuesBridgeRuntime {
                 }
 
                 let action = event_action_from_shared_body(&event.body, parse_tau_issue_command);
-                let policy_channel = format!("github:{}", self.repo.as_slug());
-                let pairing_policy = pairing_policy_for_state_dir(&self.config.state_dir);
-                let pairing_decision = evaluate_pairing_access(
-                    &pairing_policy,
-                    &policy_channel,
-                    &event.author_login,
-                    current_unix_timestamp_ms(),
-                )?;
-                let pairing_status = if matches!(pairing_decision, PairingDecision::Allow { .. }) {
-                    "allow"
-                } else {
-                    "deny"
-                };
-                let pairing_reason_code = pairing_decision.reason_code().to_string();
-                self.inbound_log.append(&json!({
-                    "timestamp_unix_ms": current_unix_timestamp_ms(),
-                    "repo": self.repo.as_slug(),
-                    "event_key": event.key.clone(),
-                    "kind": event.kind.as_str(),
-                    "issue_number": event.issue_number,
-                    "action": format!("{action:?}"),
-                    "pairing": {
-                        "decision": pairing_status,
-                        "reason_code": pairing_reason_code,
-                        "channel": policy_channel,
-                        "actor_id": event.author_login,
-                    },
-                    "payload": event.raw_payload,
-                }))?;
-
-                if let PairingDecision::Deny { reason_code } = pairing_decision {
-                    self.append_channel_log(
-                        &event,
-                        "inbound",
-                        json!({
-                            "kind": event.kind.as_str(),
-                            "author_login": event.author_login,
-                            "body": event.body,
-                            "action": format!("{action:?}"),
-                            "pairing": {
-                                "decision": "deny",
-                                "reason_code": reason_code,
-                                "channel": policy_channel,
-                            },
-                        }),
-                    )?;
-                    self.outbound_log.append(&json!({
-                        "timestamp_unix_ms": current_unix_timestamp_ms(),
-                        "repo": self.repo.as_slug(),
-                        "event_key": event.key.clone(),
-                        "issue_number": event.issue_number,
-                        "command": "authorization",
-                        "status": "denied",
-                        "reason_code": reason_code,
-                        "channel": policy_channel,
-                        "actor_id": event.author_login,
-                    }))?;
-                    if self.state_store.mark_processed(&event.key) {
-                        state_dirty = true;
-                    }
-                    if self.state_store.record_issue_event_outcome(
-                        event.issue_number,
-                        &event.key,
-                        event.kind.as_str(),
-                        &event.author_login,
-                        IssueEventOutcome::Denied,
-                        Some(reason_code.as_str()),
-                    ) {
-                        state_dirty = true;
-                    }
-                    report.processed_events = report.processed_events.saturating_add(1);
-                    eprintln!(
-                        "github bridge event denied: repo={} issue=#{} key={} actor={} channel={} reason_code={}",
-                        self.repo.as_slug(),
-                        event.issue_number,
-                        event.key,
-                        event.author_login,
-                        policy_channel,
-                        reason_code
-                    );
-                    continue;
-                }
-
-                let rbac_principal = github_principal(&event.author_login);
+                let action_label = format!("{action:?}");
                 let rbac_action = rbac_action_for_event(&action);
-                let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
-                match authorize_action_for_principal_with_policy_path(
-                    &rbac_principal,
+                if !self.authorize_bridge_event(
+                    &event,
+                    &action_label,
                     &rbac_action,
-                    rbac_policy_path.as_path(),
-                ) {
-                    Ok(RbacDecision::Allow { .. }) => {}
-                    Ok(RbacDecision::Deny {
-                        reason_code,
-                        matched_role,
-                        matched_pattern,
-                    }) => {
-                        self.append_channel_log(
-                            &event,
-                            "inbound",
-                            json!({
-                                "kind": event.kind.as_str(),
-                                "author_login": event.author_login,
-                                "body": event.body,
-                                "action": format!("{action:?}"),
-                                "rbac": {
-                                    "decision": "deny",
-                                    "reason_code": reason_code,
-                                    "matched_role": matched_role,
-                                    "matched_pattern": matched_pattern,
-                                    "principal": rbac_principal,
-                                    "action": rbac_action,
-                                },
-                            }),
-                        )?;
-                        self.outbound_log.append(&json!({
-                            "timestamp_unix_ms": current_unix_timestamp_ms(),
-                            "repo": self.repo.as_slug(),
-                            "event_key": event.key.clone(),
-                            "issue_number": event.issue_number,
-                            "command": "rbac-authorization",
-                            "status": "denied",
-                            "reason_code": reason_code,
-                            "matched_role": matched_role,
-                            "matched_pattern": matched_pattern,
-                            "principal": rbac_principal,
-                            "action": rbac_action,
-                            "actor_id": event.author_login,
-                        }))?;
-                        if self.state_store.mark_processed(&event.key) {
-                            state_dirty = true;
-                        }
-                        if self.state_store.record_issue_event_outcome(
-                            event.issue_number,
-                            &event.key,
-                            event.kind.as_str(),
-                            &event.author_login,
-                            IssueEventOutcome::Denied,
-                            Some(reason_code.as_str()),
-                        ) {
-                            state_dirty = true;
-                        }
-                        report.processed_events = report.processed_events.saturating_add(1);
-                        continue;
-                    }
-                    Err(error) => {
-                        self.outbound_log.append(&json!({
-                            "timestamp_unix_ms": current_unix_timestamp_ms(),
-                            "repo": self.repo.as_slug(),
-                            "event_key": event.key.clone(),
-                            "issue_number": event.issue_number,
-                            "command": "rbac-authorization",
-                            "status": "error",
-                            "reason_code": "rbac_policy_error",
-                            "principal": rbac_principal,
-                            "action": rbac_action,
-                            "actor_id": event.author_login,
-                            "error": error.to_string(),
-                        }))?;
-                        if self.state_store.mark_processed(&event.key) {
-                            state_dirty = true;
-                        }
-                        if self.state_store.record_issue_event_outcome(
-                            event.issue_number,
-                            &event.key,
-                            event.kind.as_str(),
-                            &event.author_login,
-                            IssueEventOutcome::Failed,
-                            Some("rbac_policy_error"),
-                        ) {
-                            state_dirty = true;
-                        }
-                        report.failed_events = report.failed_events.saturating_add(1);
-                        continue;
-                    }
+                    &mut report,
+                    &mut state_dirty,
+                )? {
+                    continue;
                 }
 
                 let suppress_processed_outcome =
@@ -820,6 +666,11 @@ impl GithubIssuesBridgeRuntime {
             }
         }
 
+        if self.config.pull_request_review {
+            self.poll_pull_requests(&mut report, &mut state_dirty)
+                .await?;
+        }
+
         self.drain_finished_runs(&mut report, &mut state_dirty, false)
             .await?;
 
@@ -840,6 +691,193 @@ impl GithubIssuesBridgeRuntime {
         Ok(report)
     }
 
+    /// Applies pairing and RBAC policy to one inbound event, recording denials as processed.
+    fn authorize_bridge_event(
+        &mut self,
+        event: &GithubBridgeEvent,
+        action_label: &str,
+        rbac_action: &str,
+        report: &mut PollCycleReport,
+        state_dirty: &mut bool,
+    ) -> Result<bool> {
+        let policy_channel = format!("github:{}", self.repo.as_slug());
+        let pairing_policy = pairing_policy_for_state_dir(&self.config.state_dir);
+        let pairing_decision = evaluate_pairing_access(
+            &pairing_policy,
+            &policy_channel,
+            &event.author_login,
+            current_unix_timestamp_ms(),
+        )?;
+        let pairing_status = if matches!(pairing_decision, PairingDecision::Allow { .. }) {
+            "allow"
+        } else {
+            "deny"
+        };
+        let pairing_reason_code = pairing_decision.reason_code().to_string();
+        self.inbound_log.append(&json!({
+            "timestamp_unix_ms": current_unix_timestamp_ms(),
+            "repo": self.repo.as_slug(),
+            "event_key": event.key.clone(),
+            "kind": event.kind.as_str(),
+            "issue_number": event.issue_number,
+            "action": action_label,
+            "pairing": {
+                "decision": pairing_status,
+                "reason_code": pairing_reason_code,
+                "channel": policy_channel,
+                "actor_id": event.author_login,
+            },
+            "payload": event.raw_payload,
+        }))?;
+
+        if let PairingDecision::Deny { reason_code } = pairing_decision {
+            self.append_channel_log(
+                event,
+                "inbound",
+                json!({
+                    "kind": event.kind.as_str(),
+                    "author_login": event.author_login,
+                    "body": event.body,
+                    "action": action_label,
+                    "pairing": {
+                        "decision": "deny",
+                        "reason_code": reason_code,
+                        "channel": policy_channel,
+                    },
+                }),
+            )?;
+            self.outbound_log.append(&json!({
+                "timestamp_unix_ms": current_unix_timestamp_ms(),
+                "repo": self.repo.as_slug(),
+                "event_key": event.key.clone(),
+                "issue_number": event.issue_number,
+                "command": "authorization",
+                "status": "denied",
+                "reason_code": reason_code,
+                "channel": policy_channel,
+                "actor_id": event.author_login,
+            }))?;
+            if self.state_store.mark_processed(&event.key) {
+                *state_dirty = true;
+            }
+            if self.state_store.record_issue_event_outcome(
+                event.issue_number,
+                &event.key,
+                event.kind.as_str(),
+                &event.author_login,
+                IssueEventOutcome::Denied,
+                Some(reason_code.as_str()),
+            ) {
+                *state_dirty = true;
+            }
+            report.processed_events = report.processed_events.saturating_add(1);
+            eprintln!(
+                "github bridge event denied: repo={} issue=#{} key={} actor={} channel={} reason_code={}",
+                self.repo.as_slug(),
+                event.issue_number,
+                event.key,
+                event.author_login,
+                policy_channel,
+                reason_code
+            );
+            return Ok(false);
+        }
+
+        let rbac_principal = github_principal(&event.author_login);
+        let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
+        match authorize_action_for_principal_with_policy_path(
+            &rbac_principal,
+            rbac_action,
+            rbac_policy_path.as_path(),
+        ) {
+            Ok(RbacDecision::Allow { .. }) => {}
+            Ok(RbacDecision::Deny {
+                reason_code,
+                matched_role,
+                matched_pattern,
+            }) => {
+                self.append_channel_log(
+                    event,
+                    "inbound",
+                    json!({
+                        "kind": event.kind.as_str(),
+                        "author_login": event.author_login,
+                        "body": event.body,
+                        "action": action_label,
+                        "rbac": {
+                            "decision": "deny",
+                            "reason_code": reason_code,
+                            "matched_role": matched_role,
+                            "matched_pattern": matched_pattern,
+                            "principal": rbac_principal,
+                            "action": rbac_action,
+                        },
+                    }),
+                )?;
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": current_unix_timestamp_ms(),
+                    "repo": self.repo.as_slug(),
+                    "event_key": event.key.clone(),
+                    "issue_number": event.issue_number,
+                    "command": "rbac-authorization",
+                    "status": "denied",
+                    "reason_code": reason_code,
+                    "matched_role": matched_role,
+                    "matched_pattern": matched_pattern,
+                    "principal": rbac_principal,
+                    "action": rbac_action,
+                    "actor_id": event.author_login,
+                }))?;
+                if self.state_store.mark_processed(&event.key) {
+                    *state_dirty = true;
+                }
+                if self.state_store.record_issue_event_outcome(
+                    event.issue_number,
+                    &event.key,
+                    event.kind.as_str(),
+                    &event.author_login,
+                    IssueEventOutcome::Denied,
+                    Some(reason_code.as_str()),
+                ) {
+                    *state_dirty = true;
+                }
+                report.processed_events = report.processed_events.saturating_add(1);
+                return Ok(false);
+            }
+            Err(error) => {
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": current_unix_timestamp_ms(),
+                    "repo": self.repo.as_slug(),
+                    "event_key": event.key.clone(),
+                    "issue_number": event.issue_number,
+                    "command": "rbac-authorization",
+                    "status": "error",
+                    "reason_code": "rbac_policy_error",
+                    "principal": rbac_principal,
+                    "action": rbac_action,
+                    "actor_id": event.author_login,
+                    "error": error.to_string(),
+                }))?;
+                if self.state_store.mark_processed(&event.key) {
+                    *state_dirty = true;
+                }
+                if self.state_store.record_issue_event_outcome(
+                    event.issue_number,
+                    &event.key,
+                    event.kind.as_str(),
+                    &event.author_login,
+                    IssueEventOutcome::Failed,
+                    Some("rbac_policy_error"),
+                ) {
+                    *state_dirty = true;
+                }
+                report.failed_events = report.failed_events.saturating_add(1);
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
     fn build_transport_health_snapshot(
         &self,
         report: &PollCycleReport,
@@ -4,13 +4,15 @@ use std::time::Duration;
 
 use anyhow::{bail, Context, Result};
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tau_github_issues::github_transport_helpers::{
     is_retryable_github_status, is_retryable_transport_error, parse_retry_after, retry_delay,
     truncate_for_error,
 };
-use tau_github_issues::issue_event_collection::{GithubIssue, GithubIssueComment};
+use tau_github_issues::issue_event_collection::{
+    GithubIssue, GithubIssueComment, GithubIssueLabel, GithubUser,
+};
 
 use super::RepoRef;
 
@@ -20,6 +22,74 @@ pub(super) struct GithubCommentCreateResponse {
     pub(super) html_url: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(super) struct GithubPullRequestRef {
+    pub(super) sha: String,
+    #[serde(rename = "ref", default)]
+    pub(super) ref_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(super) struct GithubPullRequest {
+    pub(super) id: u64,
+    pub(super) number: u64,
+    pub(super) title: String,
+    pub(super) body: Option<String>,
+    pub(super) created_at: String,
+    pub(super) updated_at: String,
+    pub(super) user: GithubUser,
+    #[serde(default)]
+    pub(super) labels: Vec<GithubIssueLabel>,
+    #[serde(default)]
+    pub(super) draft: bool,
+    pub(super) head: GithubPullRequestRef,
+    pub(super) base: GithubPullRequestRef,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(super) struct GithubPullRequestFile {
+    pub(super) filename: String,
+    #[serde(default)]
+    pub(super) status: String,
+    #[serde(default)]
+    pub(super) additions: u64,
+    #[serde(default)]
+    pub(super) deletions: u64,
+    #[serde(default)]
+    pub(super) patch: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(super) struct GithubReviewComment {
+    pub(super) id: u64,
+    pub(super) body: Option<String>,
+    pub(super) created_at: String,
+    pub(super) updated_at: String,
+    pub(super) user: GithubUser,
+    #[serde(default)]
+    pub(super) path: String,
+    #[serde(default)]
+    pub(super) line: Option<u64>,
+    #[serde(default)]
+    pub(super) diff_hunk: Option<String>,
+    #[serde(default)]
+    pub(super) in_reply_to_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Inline review comment anchored to a right-side diff line.
+pub(super) struct GithubReviewCommentDraft {
+    pub(super) path: String,
+    pub(super) line: u64,
+    pub(super) side: &'static str,
+    pub(super) body: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct GithubReviewCreateResponse {
+    pub(super) id: u64,
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct GithubBytesResponse {
     pub(super) bytes: Vec<u8>,
@@ -207,6 +277,159 @@ impl GithubApiClient {
         .await
     }
 
+    pub(super) async fn list_open_pull_requests(&self) -> Result<Vec<GithubPullRequest>> {
+        let mut page = 1_u32;
+        let mut rows = Vec::new();
+        loop {
+            let page_value = page.to_string();
+            let chunk: Vec<GithubPullRequest> = self
+                .request_json("list pull requests", || {
+                    self.http
+                        .get(format!(
+                            "{}/repos/{}/{}/pulls",
+                            self.api_base, self.repo.owner, self.repo.name
+                        ))
+                        .query(&[
+                            ("state", "open"),
+                            ("sort", "updated"),
+                            ("direction", "asc"),
+                            ("per_page", "100"),
+                            ("page", page_value.as_str()),
+                        ])
+                })
+                .await?;
+            let chunk_len = chunk.len();
+            rows.extend(chunk);
+            if chunk_len < 100 {
+                break;
+            }
+            page = page.saturating_add(1);
+        }
+        Ok(rows)
+    }
+
+    pub(super) async fn list_pull_request_files(
+        &self,
+        pull_number: u64,
+    ) -> Result<Vec<GithubPullRequestFile>> {
+        let mut page = 1_u32;
+        let mut rows = Vec::new();
+        loop {
+            let page_value = page.to_string();
+            let chunk: Vec<GithubPullRequestFile> = self
+                .request_json("list pull request files", || {
+                    self.http
+                        .get(format!(
+                            "{}/repos/{}/{}/pulls/{}/files",
+                            self.api_base, self.repo.owner, self.repo.name, pull_number
+                        ))
+                        .query(&[("per_page", "100"), ("page", page_value.as_str())])
+                })
+                .await?;
+            let chunk_len = chunk.len();
+            rows.extend(chunk);
+            if chunk_len < 100 {
+                break;
+            }
+            page = page.saturating_add(1);
+        }
+        Ok(rows)
+    }
+
+    /// Fetches the unified diff for a pull request via the diff media type.
+    pub(super) async fn fetch_pull_request_diff(&self, pull_number: u64) -> Result<String> {
+        let response = self
+            .request_bytes("fetch pull request diff", || {
+                self.http
+                    .get(format!(
+                        "{}/repos/{}/{}/pulls/{}",
+                        self.api_base, self.repo.owner, self.repo.name, pull_number
+                    ))
+                    .header(
+                        reqwest::header::ACCEPT,
+                        reqwest::header::HeaderValue::from_static("application/vnd.github.diff"),
+                    )
+            })
+            .await?;
+        Ok(String::from_utf8_lossy(&response.bytes).into_owned())
+    }
+
+    pub(super) async fn list_pull_request_review_comments(
+        &self,
+        pull_number: u64,
+    ) -> Result<Vec<GithubReviewComment>> {
+        let mut page = 1_u32;
+        let mut rows = Vec::new();
+        loop {
+            let page_value = page.to_string();
+            let chunk: Vec<GithubReviewComment> = self
+                .request_json("list pull request review comments", || {
+                    self.http
+                        .get(format!(
+                            "{}/repos/{}/{}/pulls/{}/comments",
+                            self.api_base, self.repo.owner, self.repo.name, pull_number
+                        ))
+                        .query(&[
+                            ("sort", "created"),
+                            ("direction", "asc"),
+                            ("per_page", "100"),
+                            ("page", page_value.as_str()),
+                        ])
+                })
+                .await?;
+            let chunk_len = chunk.len();
+            rows.extend(chunk);
+            if chunk_len < 100 {
+                break;
+            }
+            page = page.saturating_add(1);
+        }
+        Ok(rows)
+    }
+
+    /// Submits a `COMMENT` review pinned to `commit_id` with optional inline comments.
+    pub(super) async fn create_pull_request_review(
+        &self,
+        pull_number: u64,
+        commit_id: &str,
+        body: &str,
+        comments: &[GithubReviewCommentDraft],
+    ) -> Result<GithubReviewCreateResponse> {
+        let payload = json!({
+            "commit_id": commit_id,
+            "body": body,
+            "event": "COMMENT",
+            "comments": comments,
+        });
+        self.request_json("create pull request review", || {
+            self.http
+                .post(format!(
+                    "{}/repos/{}/{}/pulls/{}/reviews",
+                    self.api_base, self.repo.owner, self.repo.name, pull_number
+                ))
+                .json(&payload)
+        })
+        .await
+    }
+
+    pub(super) async fn create_review_comment_reply(
+        &self,
+        pull_number: u64,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<GithubCommentCreateResponse> {
+        let payload = json!({ "body": body });
+        self.request_json("create review comment reply", || {
+            self.http
+                .post(format!(
+                    "{}/repos/{}/{}/pulls/{}/comments/{}/replies",
+                    self.api_base, self.repo.owner, self.repo.name, pull_number, comment_id
+                ))
+                .json(&payload)
+        })
+        .await
+    }
+
     pub(super) async fn download_url_bytes(&self, url: &str) -> Result<GithubBytesResponse> {
         let request = || self.http.get(url);
         self.request_bytes("download issue attachment", request)
@@ -0,0 +1,391 @@
+//! Pull request review parsing, diff anchoring, and prompt/comment rendering helpers.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Deserialize;
+use serde_json::Value;
+use tau_github_issues::github_issues_helpers::split_at_char_index;
+use tau_github_issues::issue_event_collection::{GithubBridgeEvent, GithubBridgeEventKind};
+
+use super::github_api_client::{
+    GithubPullRequest, GithubPullRequestFile, GithubReviewComment, GithubReviewCommentDraft,
+};
+use super::{
+    parse_tau_issue_command, TauIssueCommand, EVENT_KEY_MARKER_PREFIX, EVENT_KEY_MARKER_SUFFIX,
+    GITHUB_COMMENT_MAX_CHARS,
+};
+
+pub(super) const REVIEW_COMMENTS_FENCE_TAG: &str = "tau-review-comments";
+const PULL_REQUEST_DIFF_PROMPT_MAX_CHARS: usize = 60_000;
+const REVIEW_INLINE_COMMENT_MAX: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// `/tau` commands accepted inside pull request review comment threads.
+pub(super) enum ReviewThreadCommand {
+    Review { focus: Option<String> },
+    Ask { prompt: String },
+    Help,
+    Status,
+    Stop,
+    Unsupported { command: String },
+    Invalid,
+}
+
+impl ReviewThreadCommand {
+    pub(super) fn command_name(&self) -> &'static str {
+        match self {
+            Self::Review { .. } => "review",
+            Self::Ask { .. } => "run",
+            Self::Help => "help",
+            Self::Status => "status",
+            Self::Stop => "stop",
+            Self::Unsupported { .. } => "unsupported",
+            Self::Invalid => "invalid",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Agent review reply split into a summary and inline comment drafts.
+pub(super) struct ParsedPullRequestReview {
+    pub(super) summary: String,
+    pub(super) inline_comments: Vec<GithubReviewCommentDraft>,
+    pub(super) unanchored_comments: Vec<GithubReviewCommentDraft>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewCommentPayload {
+    path: String,
+    line: u64,
+    body: String,
+}
+
+pub(super) fn pull_request_head_event_key(pull_number: u64, head_sha: &str) -> String {
+    format!("pull-request-head:{pull_number}:{}", head_sha.trim())
+}
+
+/// Builds the synthetic event used to review one pull request head commit.
+pub(super) fn pull_request_head_event(pull: &GithubPullRequest) -> GithubBridgeEvent {
+    GithubBridgeEvent {
+        key: pull_request_head_event_key(pull.number, &pull.head.sha),
+        kind: GithubBridgeEventKind::PullRequestHeadUpdated,
+        issue_number: pull.number,
+        issue_title: pull.title.clone(),
+        author_login: pull.user.login.clone(),
+        occurred_at: pull.updated_at.clone(),
+        body: pull.body.clone().unwrap_or_default(),
+        raw_payload: serde_json::to_value(pull).unwrap_or(Value::Null),
+    }
+}
+
+/// Collects `/tau` review-thread comments from non-bot authors in creation order.
+pub(super) fn collect_review_thread_events<'a>(
+    pull: &GithubPullRequest,
+    comments: &'a [GithubReviewComment],
+    bot_login: &str,
+) -> Vec<(GithubBridgeEvent, &'a GithubReviewComment)> {
+    let mut events = comments
+        .iter()
+        .filter(|comment| comment.user.login != bot_login)
+        .filter_map(|comment| {
+            let body = comment.body.as_deref().unwrap_or_default().trim();
+            parse_review_thread_command(body)?;
+            Some((
+                GithubBridgeEvent {
+                    key: format!("pull-review-comment-created:{}", comment.id),
+                    kind: GithubBridgeEventKind::ReviewCommentCreated,
+                    issue_number: pull.number,
+                    issue_title: pull.title.clone(),
+                    author_login: comment.user.login.clone(),
+                    occurred_at: comment.created_at.clone(),
+                    body: body.to_string(),
+                    raw_payload: serde_json::to_value(comment).unwrap_or(Value::Null),
+                },
+                comment,
+            ))
+        })
+        .collect::<Vec<_>>();
+    events.sort_by(|(left, _), (right, _)| {
+        left.occurred_at
+            .cmp(&right.occurred_at)
+            .then(left.key.cmp(&right.key))
+    });
+    events
+}
+
+/// Parses a review-thread comment; returns `None` for comments not addressed to `/tau`.
+pub(super) fn parse_review_thread_command(body: &str) -> Option<ReviewThreadCommand> {
+    let trimmed = body.trim();
+    let remainder = trimmed.strip_prefix("/tau")?;
+    if !remainder.is_empty() && !remainder.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut parts = remainder.trim().splitn(2, char::is_whitespace);
+    let command = parts.next().unwrap_or_default();
+    let args = parts.next().unwrap_or_default().trim();
+    if command == "review" {
+        return Some(ReviewThreadCommand::Review {
+            focus: (!args.is_empty()).then(|| args.to_string()),
+        });
+    }
+    let parsed = match parse_tau_issue_command(trimmed) {
+        Some(TauIssueCommand::Run { prompt }) => ReviewThreadCommand::Ask { prompt },
+        Some(TauIssueCommand::Help) => ReviewThreadCommand::Help,
+        Some(TauIssueCommand::Status) => ReviewThreadCommand::Status,
+        Some(TauIssueCommand::Stop) => ReviewThreadCommand::Stop,
+        Some(TauIssueCommand::Invalid { .. }) | None => ReviewThreadCommand::Invalid,
+        Some(_) => ReviewThreadCommand::Unsupported {
+            command: command.to_string(),
+        },
+    };
+    Some(parsed)
+}
+
+pub(super) fn review_thread_command_usage() -> String {
+    [
+        "Supported `/tau` commands in pull request review threads:",
+        "- `/tau run <prompt>`: answer in this thread using the surrounding diff hunk",
+        "- `/tau review [focus]`: re-review the current head commit",
+        "- `/tau status`",
+        "- `/tau stop`",
+        "- `/tau help`",
+    ]
+    .join("\n")
+}
+
+pub(super) fn rbac_action_for_review_thread_command(command: &ReviewThreadCommand) -> String {
+    match command {
+        ReviewThreadCommand::Review { .. } => "command:/tau-review".to_string(),
+        ReviewThreadCommand::Ask { .. } => "command:/tau-run".to_string(),
+        ReviewThreadCommand::Help => "command:/tau-help".to_string(),
+        ReviewThreadCommand::Status => "command:/tau-status".to_string(),
+        ReviewThreadCommand::Stop => "command:/tau-stop".to_string(),
+        ReviewThreadCommand::Unsupported { .. } | ReviewThreadCommand::Invalid => {
+            "command:/tau-invalid".to_string()
+        }
+    }
+}
+
+/// Returns new-file line numbers that GitHub accepts as right-side review anchors.
+pub(super) fn commentable_lines_from_patch(patch: &str) -> BTreeSet<u64> {
+    let mut lines = BTreeSet::new();
+    let mut next_line: Option<u64> = None;
+    for raw in patch.lines() {
+        if let Some(header) = raw.strip_prefix("@@") {
+            next_line = parse_hunk_new_start(header);
+            continue;
+        }
+        let Some(current) = next_line else {
+            continue;
+        };
+        if raw.starts_with('-') || raw.starts_with('\\') {
+            continue;
+        }
+        lines.insert(current);
+        next_line = Some(current.saturating_add(1));
+    }
+    lines
+}
+
+fn parse_hunk_new_start(header: &str) -> Option<u64> {
+    let token = header
+        .split_whitespace()
+        .find(|token| token.starts_with('+'))?;
+    token
+        .trim_start_matches('+')
+        .split(',')
+        .next()?
+        .parse::<u64>()
+        .ok()
+}
+
+/// Splits the agent reply into summary text and inline comments anchored to the diff.
+pub(super) fn parse_pull_request_review_reply(
+    reply: &str,
+    files: &[GithubPullRequestFile],
+) -> ParsedPullRequestReview {
+    let mut summary_lines = Vec::new();
+    let mut block_lines = Vec::new();
+    let mut in_block = false;
+    let mut found_block = false;
+    for line in reply.lines() {
+        let trimmed = line.trim();
+        if !in_block && !found_block && trimmed == format!("```{REVIEW_COMMENTS_FENCE_TAG}") {
+            in_block = true;
+            found_block = true;
+            continue;
+        }
+        if in_block {
+            if trimmed == "```" {
+                in_block = false;
+            } else {
+                block_lines.push(line);
+            }
+            continue;
+        }
+        summary_lines.push(line);
+    }
+
+    let payloads = if found_block {
+        match serde_json::from_str::<Vec<ReviewCommentPayload>>(&block_lines.join("\n")) {
+            Ok(payloads) => payloads,
+            Err(_) => {
+                return ParsedPullRequestReview {
+                    summary: reply.trim().to_string(),
+                    inline_comments: Vec::new(),
+                    unanchored_comments: Vec::new(),
+                };
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let anchors = files
+        .iter()
+        .filter_map(|file| {
+            file.patch
+                .as_deref()
+                .map(|patch| (file.filename.as_str(), commentable_lines_from_patch(patch)))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut inline_comments = Vec::new();
+    let mut unanchored_comments = Vec::new();
+    for payload in payloads {
+        let path = payload.path.trim().to_string();
+        let body = payload.body.trim().to_string();
+        if path.is_empty() || body.is_empty() {
+            continue;
+        }
+        let draft = GithubReviewCommentDraft {
+            path,
+            line: payload.line,
+            side: "RIGHT",
+            body,
+        };
+        let anchored = anchors
+            .get(draft.path.as_str())
+            .map(|lines| lines.contains(&draft.line))
+            .unwrap_or(false);
+        if anchored && inline_comments.len() < REVIEW_INLINE_COMMENT_MAX {
+            inline_comments.push(draft);
+        } else {
+            unanchored_comments.push(draft);
+        }
+    }
+
+    ParsedPullRequestReview {
+        summary: summary_lines.join("\n").trim().to_string(),
+        inline_comments,
+        unanchored_comments,
+    }
+}
+
+/// Renders the agent prompt for reviewing one pull request head.
+pub(super) fn render_pull_request_review_prompt(
+    pull: &GithubPullRequest,
+    files: &[GithubPullRequestFile],
+    diff: &str,
+    focus: Option<&str>,
+) -> String {
+    let mut lines = vec![format!(
+        "Review pull request #{} `{}` (`{}` <- `{}` at `{}`).",
+        pull.number, pull.title, pull.base.ref_name, pull.head.ref_name, pull.head.sha
+    )];
+    if let Some(focus) = focus.map(str::trim).filter(|focus| !focus.is_empty()) {
+        lines.push(format!("Reviewer focus: {focus}"));
+    }
+    lines.push(String::new());
+    lines.push(format!("Changed files ({}):", files.len()));
+    for file in files {
+        lines.push(format!(
+            "- `{}` ({}, +{}/-{})",
+            file.filename, file.status, file.additions, file.deletions
+        ));
+    }
+    let diff_chars = diff.chars().count();
+    let (diff_excerpt, _) = split_at_char_index(diff, PULL_REQUEST_DIFF_PROMPT_MAX_CHARS);
+    lines.push(String::new());
+    lines.push("Unified diff:".to_string());
+    lines.push("```diff".to_string());
+    lines.push(diff_excerpt.trim_end().to_string());
+    lines.push("```".to_string());
+    if diff_chars > PULL_REQUEST_DIFF_PROMPT_MAX_CHARS {
+        lines.push(format!(
+            "(diff truncated to {PULL_REQUEST_DIFF_PROMPT_MAX_CHARS} of {diff_chars} characters)"
+        ));
+    }
+    lines.push(String::new());
+    lines.push(
+        "Reply with a concise Markdown review summary. For findings tied to a specific changed line, \
+         append one fenced block tagged `tau-review-comments` containing a JSON array of objects with \
+         `path`, `line` (line number in the new file; must be an added or context line of the diff) \
+         and `body`. Omit the block when there are no inline findings."
+            .to_string(),
+    );
+    lines.join("\n")
+}
+
+/// Renders the agent prompt for a `/tau run` request inside a review thread.
+pub(super) fn render_review_thread_prompt(
+    pull: &GithubPullRequest,
+    comment: &GithubReviewComment,
+    prompt: &str,
+) -> String {
+    let mut lines = vec![format!(
+        "Review thread on pull request #{} `{}` (head `{}`).",
+        pull.number, pull.title, pull.head.sha
+    )];
+    match comment.line {
+        Some(line) => lines.push(format!("File: `{}` line {}", comment.path, line)),
+        None => lines.push(format!("File: `{}`", comment.path)),
+    }
+    if let Some(diff_hunk) = comment
+        .diff_hunk
+        .as_deref()
+        .filter(|hunk| !hunk.trim().is_empty())
+    {
+        lines.push("Diff hunk:".to_string());
+        lines.push("```diff".to_string());
+        lines.push(diff_hunk.trim_end().to_string());
+        lines.push("```".to_string());
+    }
+    lines.push(String::new());
+    lines.push(format!("Request from @{}:", comment.user.login));
+    lines.push(prompt.trim().to_string());
+    lines.join("\n")
+}
+
+/// Renders the summary review body with the replay-safe event key footer.
+pub(super) fn render_pull_request_review_body(
+    event_key: &str,
+    run_id: &str,
+    head_sha: &str,
+    review: &ParsedPullRequestReview,
+) -> String {
+    let short_sha = head_sha.chars().take(12).collect::<String>();
+    let footer = format!(
+        "\n\n---\n{EVENT_KEY_MARKER_PREFIX}{event_key}{EVENT_KEY_MARKER_SUFFIX}\n_Tau review run `{run_id}` | head `{short_sha}` | inline comments `{}`_",
+        review.inline_comments.len()
+    );
+    let mut content = if review.summary.trim().is_empty() {
+        "Tau review completed with no summary.".to_string()
+    } else {
+        review.summary.trim().to_string()
+    };
+    if !review.unanchored_comments.is_empty() {
+        content.push_str("\n\nNotes not anchored to a changed line:");
+        for comment in &review.unanchored_comments {
+            content.push_str(&format!(
+                "\n- `{}:{}` {}",
+                comment.path, comment.line, comment.body
+            ));
+        }
+    }
+    let content_limit = GITHUB_COMMENT_MAX_CHARS.saturating_sub(footer.chars().count());
+    if content.chars().count() > content_limit {
+        let (excerpt, _) = split_at_char_index(&content, content_limit.saturating_sub(32));
+        content = format!("{}\n\n_(review truncated)_", excerpt.trim_end());
+    }
+    format!("{content}{footer}")
+}
@@ -0,0 +1,586 @@
+//! Pull request review polling, review-thread commands, and review run tasks.
+
+use super::*;
+
+#[derive(Debug, Clone)]
+/// Work performed by one pull request run.
+pub(super) enum PullRequestRunTarget {
+    Review {
+        focus: Option<String>,
+    },
+    ThreadReply {
+        comment: GithubReviewComment,
+        prompt: String,
+    },
+}
+
+impl PullRequestRunTarget {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Review { .. } => "review",
+            Self::ThreadReply { .. } => "thread_reply",
+        }
+    }
+}
+
+pub(super) struct PullRequestRunTaskParams {
+    pub(super) github_client: GithubApiClient,
+    pub(super) config: GithubIssuesBridgeRuntimeConfig,
+    pub(super) repo: RepoRef,
+    pub(super) repository_state_dir: PathBuf,
+    pub(super) pull: GithubPullRequest,
+    pub(super) event: GithubBridgeEvent,
+    pub(super) target: PullRequestRunTarget,
+    pub(super) run_id: String,
+    pub(super) cancel_rx: watch::Receiver<bool>,
+    pub(super) started_unix_ms: u64,
+}
+
+struct PullRequestRunOutcome {
+    status: String,
+    usage: PromptUsageSummary,
+    posted_comment_id: Option<u64>,
+    posted_count: usize,
+}
+
+/// Review-thread replies must target the thread's top-level comment.
+fn review_thread_root_id(comment: &GithubReviewComment) -> u64 {
+    comment.in_reply_to_id.unwrap_or(comment.id)
+}
+
+impl GithubIssuesBridgeRuntime {
+    /// Polls open pull requests for new head commits and `/tau` review-thread commands.
+    pub(super) async fn poll_pull_requests(
+        &mut self,
+        report: &mut PollCycleReport,
+        state_dirty: &mut bool,
+    ) -> Result<()> {
+        let pulls = self.github_client.list_open_pull_requests().await?;
+        for pull in pulls {
+            if !issue_matches_required_numbers(pull.number, &self.required_issue_numbers) {
+                continue;
+            }
+            if !issue_matches_required_labels(
+                pull.labels.iter().map(|label| label.name.as_str()),
+                &self.required_issue_labels,
+            ) {
+                continue;
+            }
+
+            let review_comments = self
+                .github_client
+                .list_pull_request_review_comments(pull.number)
+                .await?;
+            let known_event_keys = review_comments
+                .iter()
+                .filter(|comment| comment.user.login == self.bot_login)
+                .flat_map(|comment| {
+                    comment
+                        .body
+                        .as_deref()
+                        .map(extract_footer_event_keys)
+                        .unwrap_or_default()
+                })
+                .collect::<HashSet<_>>();
+            for key in &known_event_keys {
+                if self.state_store.mark_processed(key) {
+                    *state_dirty = true;
+                }
+            }
+
+            let thread_events =
+                collect_review_thread_events(&pull, &review_comments, &self.bot_login);
+            report.discovered_events = report.discovered_events.saturating_add(thread_events.len());
+            for (event, comment) in thread_events {
+                if self.state_store.contains(&event.key) || known_event_keys.contains(&event.key) {
+                    report.skipped_duplicate_events =
+                        report.skipped_duplicate_events.saturating_add(1);
+                    if self.state_store.record_issue_duplicate_event(
+                        event.issue_number,
+                        &event.key,
+                        event.kind.as_str(),
+                        &event.author_login,
+                    ) {
+                        *state_dirty = true;
+                    }
+                    continue;
+                }
+                let Some(command) = parse_review_thread_command(&event.body) else {
+                    continue;
+                };
+                let action_label = format!("{command:?}");
+                let rbac_action = rbac_action_for_review_thread_command(&command);
+                if !self.authorize_bridge_event(
+                    &event,
+                    &action_label,
+                    &rbac_action,
+                    report,
+                    state_dirty,
+                )? {
+                    continue;
+                }
+                let outcome = match self
+                    .handle_review_thread_command(
+                        &pull,
+                        comment,
+                        &event,
+                        command,
+                        report,
+                        state_dirty,
+                    )
+                    .await
+                {
+                    Ok(()) => (IssueEventOutcome::Processed, "event_processed"),
+                    Err(error) => {
+                        report.failed_events = report.failed_events.saturating_add(1);
+                        eprintln!(
+                            "github bridge review thread event failed: repo={} pr=#{} key={} error={error}",
+                            self.repo.as_slug(),
+                            event.issue_number,
+                            event.key
+                        );
+                        (IssueEventOutcome::Failed, "event_action_failed")
+                    }
+                };
+                if self.state_store.record_issue_event_outcome(
+                    event.issue_number,
+                    &event.key,
+                    event.kind.as_str(),
+                    &event.author_login,
+                    outcome.0,
+                    Some(outcome.1),
+                ) {
+                    *state_dirty = true;
+                }
+            }
+
+            if pull.draft || pull.user.login == self.bot_login {
+                continue;
+            }
+            let event = pull_request_head_event(&pull);
+            if self.state_store.contains(&event.key) {
+                continue;
+            }
+            // A busy session defers the review; the head key stays unprocessed until the next poll.
+            if self.active_runs.contains_key(&pull.number) {
+                continue;
+            }
+            report.discovered_events = report.discovered_events.saturating_add(1);
+            if !self.authorize_bridge_event(
+                &event,
+                "PullRequestReview",
+                "command:/tau-review",
+                report,
+                state_dirty,
+            )? {
+                continue;
+            }
+            self.append_channel_log(
+                &event,
+                "inbound",
+                json!({
+                    "kind": event.kind.as_str(),
+                    "author_login": event.author_login,
+                    "head_sha": pull.head.sha,
+                    "action": "PullRequestReview",
+                }),
+            )?;
+            self.enqueue_pull_request_run(
+                &pull,
+                &event,
+                PullRequestRunTarget::Review { focus: None },
+                report,
+                state_dirty,
+            )?;
+            if self.state_store.record_issue_event_outcome(
+                event.issue_number,
+                &event.key,
+                event.kind.as_str(),
+                &event.author_login,
+                IssueEventOutcome::Processed,
+                Some("event_processed"),
+            ) {
+                *state_dirty = true;
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_review_thread_command(
+        &mut self,
+        pull: &GithubPullRequest,
+        comment: &GithubReviewComment,
+        event: &GithubBridgeEvent,
+        command: ReviewThreadCommand,
+        report: &mut PollCycleReport,
+        state_dirty: &mut bool,
+    ) -> Result<()> {
+        self.append_channel_log(
+            event,
+            "inbound",
+            json!({
+                "kind": event.kind.as_str(),
+                "author_login": event.author_login,
+                "body": event.body,
+                "path": comment.path,
+                "line": comment.line,
+                "action": format!("{command:?}"),
+            }),
+        )?;
+        let command_name = command.command_name();
+        let (status, message) = match command {
+            ReviewThreadCommand::Review { .. } | ReviewThreadCommand::Ask { .. }
+                if self.active_runs.contains_key(&pull.number) =>
+            {
+                (
+                    "failed",
+                    format!(
+                        "A run is already active for this pull request.\n\n{}\n\nUse `/tau stop` to cancel it first.",
+                        self.render_issue_status(pull.number)
+                    ),
+                )
+            }
+            ReviewThreadCommand::Review { focus } => {
+                let head_key = pull_request_head_event_key(pull.number, &pull.head.sha);
+                if self.state_store.mark_processed(&head_key) {
+                    *state_dirty = true;
+                }
+                return self.enqueue_pull_request_run(
+                    pull,
+                    event,
+                    PullRequestRunTarget::Review { focus },
+                    report,
+                    state_dirty,
+                );
+            }
+            ReviewThreadCommand::Ask { prompt } => {
+                return self.enqueue_pull_request_run(
+                    pull,
+                    event,
+                    PullRequestRunTarget::ThreadReply {
+                        comment: comment.clone(),
+                        prompt,
+                    },
+                    report,
+                    state_dirty,
+                );
+            }
+            ReviewThreadCommand::Help => ("reported", review_thread_command_usage()),
+            ReviewThreadCommand::Status => ("reported", self.render_issue_status(pull.number)),
+            ReviewThreadCommand::Stop => {
+                let message = if let Some(active) = self.active_runs.get(&pull.number) {
+                    if *active.cancel_tx.borrow() {
+                        format!(
+                            "Stop has already been requested for run `{}`.",
+                            active.run_id
+                        )
+                    } else {
+                        let _ = active.cancel_tx.send(true);
+                        format!(
+                            "Cancellation requested for run `{}` (event `{}`).",
+                            active.run_id, active.event_key
+                        )
+                    }
+                } else {
+                    "No active run for this pull request. Current state is idle.".to_string()
+                };
+                ("acknowledged", message)
+            }
+            ReviewThreadCommand::Unsupported { command } => (
+                "failed",
+                format!(
+                    "`/tau {command}` is not available in review threads.\n\n{}",
+                    review_thread_command_usage()
+                ),
+            ),
+            ReviewThreadCommand::Invalid => ("failed", review_thread_command_usage()),
+        };
+
+        let normalized_status = normalize_issue_command_status(status).to_string();
+        let reason_code = issue_command_reason_code(command_name, &normalized_status);
+        let body = render_issue_command_comment(
+            &event.key,
+            command_name,
+            &normalized_status,
+            &reason_code,
+            &message,
+        );
+        let posted = self
+            .github_client
+            .create_review_comment_reply(pull.number, review_thread_root_id(comment), &body)
+            .await?;
+        self.outbound_log.append(&json!({
+            "timestamp_unix_ms": current_unix_timestamp_ms(),
+            "repo": self.repo.as_slug(),
+            "event_key": event.key,
+            "issue_number": pull.number,
+            "command": command_name,
+            "status": normalized_status,
+            "reason_code": reason_code,
+            "review_comment_id": comment.id,
+            "posted_comment_id": posted.id,
+            "posted_comment_url": posted.html_url,
+        }))?;
+        if self.state_store.mark_processed(&event.key) {
+            *state_dirty = true;
+        }
+        report.processed_events = report.processed_events.saturating_add(1);
+        Ok(())
+    }
+
+    fn enqueue_pull_request_run(
+        &mut self,
+        pull: &GithubPullRequest,
+        event: &GithubBridgeEvent,
+        target: PullRequestRunTarget,
+        report: &mut PollCycleReport,
+        state_dirty: &mut bool,
+    ) -> Result<()> {
+        let run_id = format!(
+            "gh-pr-{}-{}-{}",
+            pull.number,
+            current_unix_timestamp_ms(),
+            shared_short_key_hash(&event.key)
+        );
+        let started_unix_ms = current_unix_timestamp_ms();
+        let target_label = target.as_str();
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+        let params = PullRequestRunTaskParams {
+            github_client: self.github_client.clone(),
+            config: self.config.clone(),
+            repo: self.repo.clone(),
+            repository_state_dir: self.repository_state_dir.clone(),
+            pull: pull.clone(),
+            event: event.clone(),
+            target,
+            run_id: run_id.clone(),
+            cancel_rx,
+            started_unix_ms,
+        };
+        let handle = tokio::spawn(execute_pull_request_run_task(params));
+        self.active_runs.insert(
+            pull.number,
+            ActiveIssueRun {
+                run_id: run_id.clone(),
+                event_key: event.key.clone(),
+                started_unix_ms,
+                started: Instant::now(),
+                cancel_tx,
+                handle,
+            },
+        );
+        if self
+            .state_store
+            .record_issue_run_started(pull.number, &run_id)
+        {
+            *state_dirty = true;
+        }
+        if self.state_store.mark_processed(&event.key) {
+            *state_dirty = true;
+        }
+        report.processed_events = report.processed_events.saturating_add(1);
+        self.outbound_log.append(&json!({
+            "timestamp_unix_ms": current_unix_timestamp_ms(),
+            "repo": self.repo.as_slug(),
+            "event_key": event.key,
+            "issue_number": pull.number,
+            "run_id": run_id,
+            "status": "run_started",
+            "target": target_label,
+            "head_sha": pull.head.sha,
+        }))?;
+        Ok(())
+    }
+}
+
+/// Executes one pull request review or review-thread run and posts the results.
+pub(super) async fn execute_pull_request_run_task(
+    params: PullRequestRunTaskParams,
+) -> RunTaskResult {
+    let started = Instant::now();
+    let issue_number = params.pull.number;
+    let event_key = params.event.key.clone();
+    let run_id = params.run_id.clone();
+    let started_unix_ms = params.started_unix_ms;
+    let model = params.config.model.clone();
+    let outcome = run_pull_request_target(&params).await;
+    let (status, usage, posted_comment_id, posted_count, error) = match outcome {
+        Ok(outcome) => (
+            outcome.status,
+            outcome.usage,
+            outcome.posted_comment_id,
+            outcome.posted_count,
+            None,
+        ),
+        Err(error) => {
+            let body = render_shared_issue_run_error_comment(
+                &event_key,
+                &run_id,
+                &error.to_string(),
+                EVENT_KEY_MARKER_PREFIX,
+                EVENT_KEY_MARKER_SUFFIX,
+            );
+            let posted = match &params.target {
+                PullRequestRunTarget::Review { .. } => {
+                    params
+                        .github_client
+                        .create_issue_comment(issue_number, &body)
+                        .await
+                }
+                PullRequestRunTarget::ThreadReply { comment, .. } => {
+                    params
+                        .github_client
+                        .create_review_comment_reply(
+                            issue_number,
+                            review_thread_root_id(comment),
+                            &body,
+                        )
+                        .await
+                }
+            };
+            let posted_comment_id = posted.ok().map(|comment| comment.id);
+            (
+                "failed".to_string(),
+                PromptUsageSummary::default(),
+                posted_comment_id,
+                usize::from(posted_comment_id.is_some()),
+                Some(error.to_string()),
+            )
+        }
+    };
+
+    RunTaskResult {
+        issue_number,
+        event_key,
+        run_id,
+        started_unix_ms,
+        completed_unix_ms: current_unix_timestamp_ms(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        status,
+        posted_comment_id,
+        comment_edit_attempted: false,
+        comment_edit_success: false,
+        comment_append_count: posted_count,
+        model,
+        usage,
+        error,
+    }
+}
+
+async fn run_pull_request_target(
+    params: &PullRequestRunTaskParams,
+) -> Result<PullRequestRunOutcome> {
+    let PullRequestRunTaskParams {
+        github_client,
+        config,
+        repo,
+        repository_state_dir,
+        pull,
+        event,
+        target,
+        run_id,
+        cancel_rx,
+        ..
+    } = params;
+    match target {
+        PullRequestRunTarget::Review { focus } => {
+            let files = github_client.list_pull_request_files(pull.number).await?;
+            let diff = github_client.fetch_pull_request_diff(pull.number).await?;
+            let prompt = render_pull_request_review_prompt(pull, &files, &diff, focus.as_deref());
+            let run = run_prompt_for_event(RunPromptForEventRequest {
+                config,
+                github_client,
+                repo,
+                repository_state_dir,
+                event,
+                prompt: &prompt,
+                run_id,
+                cancel_rx: cancel_rx.clone(),
+            })
+            .await?;
+            if run.status != PromptRunStatus::Completed {
+                return Ok(PullRequestRunOutcome {
+                    status: prompt_status_label(run.status).to_string(),
+                    usage: run.usage,
+                    posted_comment_id: None,
+                    posted_count: 0,
+                });
+            }
+
+            let mut review = parse_pull_request_review_reply(&run.assistant_reply, &files);
+            let body = render_pull_request_review_body(&event.key, run_id, &pull.head.sha, &review);
+            let posted = match github_client
+                .create_pull_request_review(
+                    pull.number,
+                    &pull.head.sha,
+                    &body,
+                    &review.inline_comments,
+                )
+                .await
+            {
+                Ok(posted) => posted,
+                Err(error) if !review.inline_comments.is_empty() => {
+                    // GitHub rejects the whole review when one anchor is stale, so retry without
+                    // inline comments and keep them in the summary body instead.
+                    eprintln!(
+                        "github bridge review inline comments rejected: repo={} pr=#{} run_id={} error={error}",
+                        repo.as_slug(),
+                        pull.number,
+                        run_id
+                    );
+                    let mut inline_comments = std::mem::take(&mut review.inline_comments);
+                    inline_comments.append(&mut review.unanchored_comments);
+                    review.unanchored_comments = inline_comments;
+                    let body = render_pull_request_review_body(
+                        &event.key,
+                        run_id,
+                        &pull.head.sha,
+                        &review,
+                    );
+                    github_client
+                        .create_pull_request_review(pull.number, &pull.head.sha, &body, &[])
+                        .await?
+                }
+                Err(error) => return Err(error),
+            };
+            Ok(PullRequestRunOutcome {
+                status: prompt_status_label(run.status).to_string(),
+                usage: run.usage,
+                posted_comment_id: Some(posted.id),
+                posted_count: 1,
+            })
+        }
+        PullRequestRunTarget::ThreadReply { comment, prompt } => {
+            let prompt = render_review_thread_prompt(pull, comment, prompt);
+            let run = run_prompt_for_event(RunPromptForEventRequest {
+                config,
+                github_client,
+                repo,
+                repository_state_dir,
+                event,
+                prompt: &prompt,
+                run_id,
+                cancel_rx: cancel_rx.clone(),
+            })
+            .await?;
+            let mut outcome = PullRequestRunOutcome {
+                status: prompt_status_label(run.status).to_string(),
+                usage: run.usage.clone(),
+                posted_comment_id: None,
+                posted_count: 0,
+            };
+            for chunk in render_issue_comment_chunks(event, &run) {
+                let posted = github_client
+                    .create_review_comment_reply(
+                        pull.number,
+                        review_thread_root_id(comment),
+                        &chunk,
+                    )
+                    .await?;
+                outcome.posted_comment_id = Some(posted.id);
+                outcome.posted_count = outcome.posted_count.saturating_add(1);
+            }
+            Ok(outcome)
+        }
+    }
+}
@@ -53,6 +53,10 @@ impl LlmClient for StaticReplyClient {
                 output_tokens: 7,
                 total_tokens: 18,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -72,6 +76,10 @@ impl LlmClient for SlowReplyClient {
                 output_tokens: 3,
                 total_tokens: 8,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -111,6 +119,7 @@ fn test_bridge_config_with_client(
         required_issue_numbers: Vec::new(),
         include_issue_body: false,
         include_edited_comments: true,
+        pull_request_review: false,
         processed_event_cap: 32,
         retry_max_attempts: 3,
         retry_base_delay_ms: 5,
@@ -126,6 +135,9 @@ fn test_bridge_config_with_client(
             openai_auth_mode: ProviderAuthMethod::ApiKey,
             anthropic_auth_mode: ProviderAuthMethod::ApiKey,
             google_auth_mode: ProviderAuthMethod::ApiKey,
+            bedrock_auth_mode: ProviderAuthMethod::ApiKey,
+            vertex_auth_mode: ProviderAuthMethod::Adc,
+            vertex_service_account_file: None,
             provider_subscription_strict: true,
             openai_codex_backend: true,
             openai_codex_cli: "codex".to_string(),
@@ -254,3 +266,5 @@ mod polling_and_replay;
 mod chat_and_controls;
 
 mod artifact_workflows;
+
+mod pull_request_review;
@@ -0,0 +1,421 @@
+//! Pull request review mode coverage: diff anchoring, pulls/reviews API, and review threads.
+
+use super::*;
+use crate::github_issues_runtime::github_api_client::{
+    GithubPullRequestFile, GithubReviewCommentDraft,
+};
+use crate::github_issues_runtime::pull_request_review_helpers::{
+    commentable_lines_from_patch, parse_pull_request_review_reply, parse_review_thread_command,
+    ReviewThreadCommand,
+};
+use crate::github_issues_runtime::PollCycleReport;
+
+const REVIEW_PATCH: &str = "@@ -1,3 +1,4 @@\n fn main() {\n-    old();\n+    new();\n+    extra();\n }\n\\ No newline at end of file";
+
+struct ReviewReplyClient;
+
+#[async_trait]
+impl LlmClient for ReviewReplyClient {
+    async fn complete(&self, _request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        Ok(ChatResponse {
+            message: Message::assistant_text(
+                "Looks good overall.\n\n```tau-review-comments\n[{\"path\":\"src/main.rs\",\"line\":3,\"body\":\"Consider naming this call.\"},{\"path\":\"src/main.rs\",\"line\":40,\"body\":\"Outside the diff.\"}]\n```",
+            ),
+            finish_reason: Some("stop".to_string()),
+            usage: ChatUsage {
+                input_tokens: 13,
+                output_tokens: 9,
+                total_tokens: 22,
+                cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
+            },
+        })
+    }
+}
+
+fn review_file(patch: Option<&str>) -> GithubPullRequestFile {
+    GithubPullRequestFile {
+        filename: "src/main.rs".to_string(),
+        status: "modified".to_string(),
+        additions: 2,
+        deletions: 1,
+        patch: patch.map(str::to_string),
+    }
+}
+
+fn pull_request_json(head_sha: &str) -> serde_json::Value {
+    json!([{
+        "id": 500,
+        "number": 5,
+        "title": "Add extra call",
+        "body": "Please review",
+        "created_at": "2026-01-01T00:00:00Z",
+        "updated_at": "2026-01-01T00:00:05Z",
+        "user": {"login": "alice"},
+        "draft": false,
+        "head": {"sha": head_sha, "ref": "feature"},
+        "base": {"sha": "base000", "ref": "main"}
+    }])
+}
+
+fn mock_empty_issue_stream(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/issues");
+        then.status(200).json_body(json!([]));
+    });
+}
+
+async fn drain_pending_runs(runtime: &mut GithubIssuesBridgeRuntime) -> PollCycleReport {
+    let mut report = PollCycleReport::default();
+    let mut state_dirty = false;
+    runtime
+        .drain_finished_runs(&mut report, &mut state_dirty, true)
+        .await
+        .expect("drain runs");
+    report
+}
+
+#[test]
+fn unit_commentable_lines_from_patch_tracks_added_and_context_lines() {
+    let lines = commentable_lines_from_patch(REVIEW_PATCH);
+    assert_eq!(lines.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    let multi_hunk = "@@ -10,2 +12,2 @@ impl Foo {\n-    a();\n+    b();\n     c();";
+    let lines = commentable_lines_from_patch(multi_hunk);
+    assert_eq!(lines.into_iter().collect::<Vec<_>>(), vec![12, 13]);
+}
+
+#[test]
+fn functional_parse_pull_request_review_reply_splits_anchored_and_unanchored_comments() {
+    let reply = "Summary line.\n\n```tau-review-comments\n[{\"path\":\"src/main.rs\",\"line\":2,\"body\":\"inline\"},{\"path\":\"src/main.rs\",\"line\":99,\"body\":\"stale\"},{\"path\":\"src/other.rs\",\"line\":1,\"body\":\"missing file\"}]\n```";
+    let parsed = parse_pull_request_review_reply(reply, &[review_file(Some(REVIEW_PATCH))]);
+    assert_eq!(parsed.summary, "Summary line.");
+    assert_eq!(
+        parsed.inline_comments,
+        vec![GithubReviewCommentDraft {
+            path: "src/main.rs".to_string(),
+            line: 2,
+            side: "RIGHT",
+            body: "inline".to_string(),
+        }]
+    );
+    assert_eq!(parsed.unanchored_comments.len(), 2);
+}
+
+#[test]
+fn regression_parse_pull_request_review_reply_keeps_malformed_block_in_summary() {
+    let reply = "Summary.\n```tau-review-comments\nnot json\n```";
+    let parsed = parse_pull_request_review_reply(reply, &[review_file(Some(REVIEW_PATCH))]);
+    assert_eq!(parsed.summary, reply);
+    assert!(parsed.inline_comments.is_empty());
+    assert!(parsed.unanchored_comments.is_empty());
+}
+
+#[test]
+fn unit_parse_review_thread_command_maps_supported_commands() {
+    assert_eq!(parse_review_thread_command("looks fine"), None);
+    assert_eq!(parse_review_thread_command("/tauish status"), None);
+    assert_eq!(
+        parse_review_thread_command("/tau review security"),
+        Some(ReviewThreadCommand::Review {
+            focus: Some("security".to_string())
+        })
+    );
+    assert_eq!(
+        parse_review_thread_command("/tau run why this change?"),
+        Some(ReviewThreadCommand::Ask {
+            prompt: "why this change?".to_string()
+        })
+    );
+    assert_eq!(
+        parse_review_thread_command("/tau status"),
+        Some(ReviewThreadCommand::Status)
+    );
+    assert_eq!(
+        parse_review_thread_command("/tau artifacts"),
+        Some(ReviewThreadCommand::Unsupported {
+            command: "artifacts".to_string()
+        })
+    );
+    assert_eq!(
+        parse_review_thread_command("/tau"),
+        Some(ReviewThreadCommand::Invalid)
+    );
+}
+
+#[tokio::test]
+async fn integration_github_api_client_fetches_pull_request_files_and_diff() {
+    let server = MockServer::start();
+    let pulls = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/owner/repo/pulls")
+            .query_param("state", "open");
+        then.status(200).json_body(pull_request_json("abc123"));
+    });
+    let files = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls/5/files");
+        then.status(200).json_body(json!([{
+            "filename": "src/main.rs",
+            "status": "modified",
+            "additions": 2,
+            "deletions": 1,
+            "patch": REVIEW_PATCH
+        }]));
+    });
+    let diff = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/owner/repo/pulls/5")
+            .header("accept", "application/vnd.github.diff");
+        then.status(200)
+            .header("content-type", "text/plain")
+            .body("diff --git a/src/main.rs b/src/main.rs\n");
+    });
+
+    let repo = RepoRef::parse("owner/repo").expect("repo parse");
+    let client = GithubApiClient::new(server.base_url(), "token".to_string(), repo, 2_000, 2, 1)
+        .expect("client");
+    let listed = client.list_open_pull_requests().await.expect("pulls");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].head.sha, "abc123");
+    assert_eq!(listed[0].base.ref_name, "main");
+    let listed_files = client.list_pull_request_files(5).await.expect("files");
+    assert_eq!(listed_files[0].patch.as_deref(), Some(REVIEW_PATCH));
+    let fetched_diff = client.fetch_pull_request_diff(5).await.expect("diff");
+    assert!(fetched_diff.starts_with("diff --git"));
+    pulls.assert_calls(1);
+    files.assert_calls(1);
+    diff.assert_calls(1);
+}
+
+#[tokio::test]
+async fn integration_github_api_client_posts_review_with_inline_comments_and_thread_reply() {
+    let server = MockServer::start();
+    let review = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/reviews")
+            .json_body_includes(json!({"commit_id": "abc123", "event": "COMMENT"}).to_string())
+            .body_includes(r#""path":"src/main.rs""#)
+            .body_includes(r#""side":"RIGHT""#);
+        then.status(200).json_body(json!({
+            "id": 77,
+            "html_url": "https://example.test/pull/5#review-77"
+        }));
+    });
+    let reply = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/comments/300/replies")
+            .body_includes("thread answer");
+        then.status(201).json_body(json!({
+            "id": 301,
+            "html_url": "https://example.test/pull/5#discussion_r301"
+        }));
+    });
+
+    let repo = RepoRef::parse("owner/repo").expect("repo parse");
+    let client = GithubApiClient::new(server.base_url(), "token".to_string(), repo, 2_000, 2, 1)
+        .expect("client");
+    let posted = client
+        .create_pull_request_review(
+            5,
+            "abc123",
+            "summary",
+            &[GithubReviewCommentDraft {
+                path: "src/main.rs".to_string(),
+                line: 3,
+                side: "RIGHT",
+                body: "inline".to_string(),
+            }],
+        )
+        .await
+        .expect("review");
+    assert_eq!(posted.id, 77);
+    let replied = client
+        .create_review_comment_reply(5, 300, "thread answer")
+        .await
+        .expect("reply");
+    assert_eq!(replied.id, 301);
+    review.assert_calls(1);
+    reply.assert_calls(1);
+}
+
+#[tokio::test]
+async fn integration_bridge_pull_request_mode_reviews_each_new_head_once() {
+    let server = MockServer::start();
+    mock_empty_issue_stream(&server);
+    let mut pulls = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls");
+        then.status(200).json_body(pull_request_json("abc123"));
+    });
+    let _review_comments = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls/5/comments");
+        then.status(200).json_body(json!([]));
+    });
+    let files = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls/5/files");
+        then.status(200).json_body(json!([{
+            "filename": "src/main.rs",
+            "status": "modified",
+            "additions": 2,
+            "deletions": 1,
+            "patch": REVIEW_PATCH
+        }]));
+    });
+    let diff = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls/5");
+        then.status(200)
+            .body("diff --git a/src/main.rs b/src/main.rs\n");
+    });
+    let first_review = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/reviews")
+            .body_includes("abc123")
+            .body_includes("Consider naming this call.")
+            .body_includes("Outside the diff.")
+            .body_includes("tau-event-key:pull-request-head:5:abc123");
+        then.status(200)
+            .json_body(json!({"id": 77, "html_url": null}));
+    });
+
+    let temp = tempdir().expect("tempdir");
+    let mut config = test_bridge_config_with_client(
+        &server.base_url(),
+        temp.path(),
+        Arc::new(ReviewReplyClient),
+    );
+    config.pull_request_review = true;
+    let mut runtime = GithubIssuesBridgeRuntime::new(config)
+        .await
+        .expect("runtime");
+
+    let first = runtime.poll_once().await.expect("first poll");
+    assert_eq!(first.discovered_events, 1);
+    assert_eq!(first.processed_events, 1);
+    let drained = drain_pending_runs(&mut runtime).await;
+    assert_eq!(drained.failed_events, 0);
+    first_review.assert_calls(1);
+    files.assert_calls(1);
+    diff.assert_calls(1);
+
+    let second = runtime.poll_once().await.expect("second poll");
+    assert_eq!(second.processed_events, 0);
+    drain_pending_runs(&mut runtime).await;
+    first_review.assert_calls(1);
+
+    pulls.delete();
+    pulls = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls");
+        then.status(200).json_body(pull_request_json("def456"));
+    });
+    let second_review = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/reviews")
+            .body_includes("tau-event-key:pull-request-head:5:def456");
+        then.status(200)
+            .json_body(json!({"id": 78, "html_url": null}));
+    });
+    let third = runtime.poll_once().await.expect("third poll");
+    assert_eq!(third.processed_events, 1);
+    drain_pending_runs(&mut runtime).await;
+    second_review.assert_calls(1);
+    pulls.assert_calls(1);
+
+    let state_raw = std::fs::read_to_string(temp.path().join("owner__repo").join("state.json"))
+        .expect("state file");
+    assert!(state_raw.contains("pull-request-head:5:abc123"));
+    assert!(state_raw.contains("pull-request-head:5:def456"));
+}
+
+#[tokio::test]
+async fn integration_bridge_pull_request_mode_answers_review_thread_commands() {
+    let server = MockServer::start();
+    mock_empty_issue_stream(&server);
+    let _pulls = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls");
+        then.status(200).json_body(pull_request_json("abc123"));
+    });
+    let review_comments = server.mock(|when, then| {
+        when.method(GET).path("/repos/owner/repo/pulls/5/comments");
+        then.status(200).json_body(json!([
+            {
+                "id": 300,
+                "body": "Why is this needed?",
+                "created_at": "2026-01-01T00:00:01Z",
+                "updated_at": "2026-01-01T00:00:01Z",
+                "user": {"login": "bob"},
+                "path": "src/main.rs",
+                "line": 3,
+                "diff_hunk": "@@ -1,3 +1,4 @@\n+    extra();"
+            },
+            {
+                "id": 301,
+                "body": "/tau status",
+                "created_at": "2026-01-01T00:00:02Z",
+                "updated_at": "2026-01-01T00:00:02Z",
+                "user": {"login": "alice"},
+                "path": "src/main.rs",
+                "line": 3,
+                "in_reply_to_id": 300
+            },
+            {
+                "id": 302,
+                "body": "/tau run explain this hunk",
+                "created_at": "2026-01-01T00:00:03Z",
+                "updated_at": "2026-01-01T00:00:03Z",
+                "user": {"login": "alice"},
+                "path": "src/main.rs",
+                "line": 3,
+                "in_reply_to_id": 300
+            }
+        ]));
+    });
+    let status_reply = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/comments/300/replies")
+            .body_includes("Tau command `status`")
+            .body_includes("tau-event-key:pull-review-comment-created:301");
+        then.status(201)
+            .json_body(json!({"id": 401, "html_url": null}));
+    });
+    let run_reply = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/owner/repo/pulls/5/comments/300/replies")
+            .body_includes("bridge reply")
+            .body_includes("tau-event-key:pull-review-comment-created:302");
+        then.status(201)
+            .json_body(json!({"id": 402, "html_url": null}));
+    });
+    let reviews = server.mock(|when, then| {
+        when.method(POST).path("/repos/owner/repo/pulls/5/reviews");
+        then.status(200)
+            .json_body(json!({"id": 77, "html_url": null}));
+    });
+
+    let temp = tempdir().expect("tempdir");
+    let mut config = test_bridge_config(&server.base_url(), temp.path());
+    config.pull_request_review = true;
+    let mut runtime = GithubIssuesBridgeRuntime::new(config)
+        .await
+        .expect("runtime");
+    // Pretend the head was already reviewed so only thread commands are exercised.
+    runtime
+        .state_store
+        .mark_processed("pull-request-head:5:abc123");
+
+    let report = runtime.poll_once().await.expect("poll");
+    assert_eq!(report.discovered_events, 2);
+    assert_eq!(report.processed_events, 2);
+    drain_pending_runs(&mut runtime).await;
+    status_reply.assert_calls(1);
+    run_reply.assert_calls(1);
+    reviews.assert_calls(0);
+
+    let second = runtime.poll_once().await.expect("second poll");
+    assert_eq!(second.processed_events, 0);
+    assert_eq!(second.skipped_duplicate_events, 2);
+    review_comments.assert_calls(2);
+    status_reply.assert_calls(1);
+    run_reply.assert_calls(1);
+}
@@ -45,6 +45,8 @@ pub enum GithubBridgeEventKind {
     Opened,
     CommentCreated,
     CommentEdited,
+    PullRequestHeadUpdated,
+    ReviewCommentCreated,
 }
 
 impl GithubBridgeEventKind {
@@ -53,6 +55,8 @@ impl GithubBridgeEventKind {
             Self::Opened => "issue_opened",
             Self::CommentCreated => "issue_comment_created",
             Self::CommentEdited => "issue_comment_edited",
+            Self::PullRequestHeadUpdated => "pull_request_head_updated",
+            Self::ReviewCommentCreated => "pull_request_review_comment_created",
         }
     }
 }
@@ -45,6 +45,12 @@ pub mod multi_channel_contract;
 /// Callers rely on its contract and failure semantics remaining stable.
 /// Update this comment if behavior or integration expectations change.
 pub mod multi_channel_credentials;
+/// Public `mod` `multi_channel_email` in `tau-multi-channel`.
+///
+/// This item is part of the Wave 2 API surface for M23 documentation uplift.
+/// Callers rely on its contract and failure semantics remaining stable.
+/// Update this comment if behavior or integration expectations change.
+pub mod multi_channel_email;
 /// Public `mod` `multi_channel_incident` in `tau-multi-channel`.
 ///
 /// This item is part of the Wave 2 API surface for M23 documentation uplift.
@@ -114,6 +120,7 @@ pub mod multi_channel_send;
 
 pub use multi_channel_contract::*;
 pub use multi_channel_credentials::*;
+pub use multi_channel_email::*;
 pub use multi_channel_incident::*;
 pub use multi_channel_lifecycle::*;
 pub use multi_channel_live_connectors::*;
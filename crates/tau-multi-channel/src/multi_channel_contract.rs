@@ -33,6 +33,8 @@ pub enum MultiChannelTransport {
     Telegram,
     Discord,
     Whatsapp,
+    Matrix,
+    Email,
 }
 
 impl MultiChannelTransport {
@@ -46,6 +48,8 @@ impl MultiChannelTransport {
             Self::Telegram => "telegram",
             Self::Discord => "discord",
             Self::Whatsapp => "whatsapp",
+            Self::Matrix => "matrix",
+            Self::Email => "email",
         }
     }
 }
@@ -213,7 +217,13 @@ fn validate_attachment(attachment: &MultiChannelAttachment, label: &str) -> Resu
         bail!("{label} has attachment with empty attachment_id");
     }
     let url = attachment.url.trim();
-    if !(url.starts_with("https://") || url.starts_with("http://localhost")) {
+    // Email attachments stay in the mailbox and are referenced by IMAP URL
+    // (RFC 5092) or by Message-ID (RFC 2392) instead of a download link.
+    if !(url.starts_with("https://")
+        || url.starts_with("http://localhost")
+        || url.starts_with("imap://")
+        || url.starts_with("mid:"))
+    {
         bail!("{label} has invalid attachment url '{}'", attachment.url);
     }
     if !attachment.content_type.trim().is_empty() && !attachment.content_type.contains('/') {
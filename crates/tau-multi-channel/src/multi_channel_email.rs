@@ -0,0 +1,2060 @@
+//! Email (IMAP/SMTP) transport helpers for the multi-channel runtime.
+//!
+//! Provides the RFC 5322/MIME parsing used by email ingress, a minimal IMAP
+//! client covering the LOGIN/SELECT/UID SEARCH/UID FETCH/IDLE subset needed for
+//! polling, and an SMTP submission client for threaded replies. Attachment
+//! bodies are decoded only to measure them; nothing beyond metadata is kept.
+
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::io::{
+    AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader, ReadBuf,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::TlsConnector;
+
+const MAX_EMAIL_MESSAGE_BYTES: usize = 25 * 1024 * 1024;
+const MAX_PROTOCOL_LINE_BYTES: usize = 64 * 1024;
+const BASE64_LINE_WIDTH: usize = 76;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// Enumerates supported `MultiChannelEmailTlsMode` values.
+pub enum MultiChannelEmailTlsMode {
+    /// Plain TCP. Credentials are only sent this way to loopback servers.
+    None,
+    /// Plain TCP upgraded with STARTTLS before authenticating.
+    Starttls,
+    /// TLS from the first byte (IMAPS 993 / SMTPS 465).
+    Implicit,
+}
+
+impl MultiChannelEmailTlsMode {
+    /// Public `fn` `as_str` in `tau-multi-channel`.
+    ///
+    /// This item is part of the Wave 2 API surface for M23 documentation uplift.
+    /// Callers rely on its contract and failure semantics remaining stable.
+    /// Update this comment if behavior or integration expectations change.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Starttls => "starttls",
+            Self::Implicit => "implicit",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Mailbox account shared by email ingress (IMAP) and replies (SMTP).
+///
+/// `username` defaults to `address` and the same `password` is used for both
+/// protocols, which matches how hosted mailboxes are provisioned.
+pub struct MultiChannelEmailAccountConfig {
+    pub address: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub imap_host: String,
+    pub imap_port: u16,
+    pub imap_tls: MultiChannelEmailTlsMode,
+    pub imap_mailbox: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_tls: MultiChannelEmailTlsMode,
+}
+
+impl Default for MultiChannelEmailAccountConfig {
+    fn default() -> Self {
+        Self {
+            address: None,
+            username: None,
+            password: None,
+            imap_host: String::new(),
+            imap_port: 993,
+            imap_tls: MultiChannelEmailTlsMode::Implicit,
+            imap_mailbox: "INBOX".to_string(),
+            smtp_host: String::new(),
+            smtp_port: 587,
+            smtp_tls: MultiChannelEmailTlsMode::Starttls,
+        }
+    }
+}
+
+impl MultiChannelEmailAccountConfig {
+    pub(crate) fn resolved_address(&self) -> Option<&str> {
+        non_empty(self.address.as_deref())
+    }
+
+    pub(crate) fn resolved_username(&self) -> Option<&str> {
+        non_empty(self.username.as_deref()).or_else(|| self.resolved_address())
+    }
+
+    pub(crate) fn resolved_password(&self) -> Option<&str> {
+        non_empty(self.password.as_deref())
+    }
+
+    pub(crate) fn resolved_mailbox(&self) -> &str {
+        let mailbox = self.imap_mailbox.trim();
+        if mailbox.is_empty() {
+            "INBOX"
+        } else {
+            mailbox
+        }
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ParsedEmailAttachment {
+    pub(crate) part: String,
+    pub(crate) content_id: String,
+    pub(crate) file_name: String,
+    pub(crate) content_type: String,
+    pub(crate) size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ParsedEmailMessage {
+    headers: Vec<(String, String)>,
+    pub(crate) text: String,
+    pub(crate) html: String,
+    pub(crate) attachments: Vec<ParsedEmailAttachment>,
+}
+
+impl ParsedEmailMessage {
+    pub(crate) fn header<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        self.headers_named(name).next()
+    }
+
+    fn headers_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.headers
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct EmailAddress {
+    pub(crate) address: String,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct EmailAuthenticationResults {
+    pub(crate) dkim: String,
+    pub(crate) spf: String,
+    pub(crate) dmarc: String,
+    /// Signing domains (`header.d`) of passing DKIM signatures.
+    pub(crate) dkim_domains: Vec<String>,
+    /// Envelope sender domain (`smtp.mailfrom`) of a passing SPF check.
+    pub(crate) spf_domain: String,
+}
+
+/// Mailbox coordinates of a fetched message, carried into the ingress payload.
+#[derive(Debug, Clone)]
+pub(crate) struct EmailIngressContext<'a> {
+    pub(crate) account: &'a str,
+    pub(crate) imap_host: &'a str,
+    pub(crate) mailbox: &'a str,
+    pub(crate) uid: u64,
+    pub(crate) uid_validity: u64,
+    pub(crate) trusted_authserv_id: Option<&'a str>,
+    pub(crate) received_unix_ms: u64,
+}
+
+/// Parses a raw RFC 5322 message, walking MIME parts for bodies and attachments.
+pub(crate) fn parse_email_message(raw: &[u8]) -> Result<ParsedEmailMessage> {
+    let (header_bytes, body) = split_header_block(raw);
+    let headers = parse_header_block(header_bytes);
+    if headers.is_empty() {
+        bail!("email message has no header block");
+    }
+    let mut message = ParsedEmailMessage {
+        headers: headers.clone(),
+        ..ParsedEmailMessage::default()
+    };
+    collect_mime_entity(&headers, body, "", &mut message);
+    Ok(message)
+}
+
+/// Builds the live ingress payload for a parsed message.
+pub(crate) fn build_email_ingress_payload(
+    message: &ParsedEmailMessage,
+    context: &EmailIngressContext<'_>,
+) -> Value {
+    let from = message
+        .header("From")
+        .and_then(parse_email_address)
+        .unwrap_or_default();
+    let reply_to = message
+        .header("Reply-To")
+        .and_then(parse_email_address)
+        .map(|address| address.address);
+    let authentication =
+        parse_authentication_results(message, context.trusted_authserv_id.map(str::trim));
+    let attachments = message
+        .attachments
+        .iter()
+        .map(|attachment| {
+            json!({
+                "part": attachment.part,
+                "content_id": attachment.content_id,
+                "file_name": attachment.file_name,
+                "content_type": attachment.content_type,
+                "size_bytes": attachment.size_bytes,
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "account": context.account,
+        "imap_host": context.imap_host,
+        "mailbox": context.mailbox,
+        "uid": context.uid,
+        "uid_validity": context.uid_validity,
+        "received_unix_ms": context.received_unix_ms,
+        "message_id": message
+            .header("Message-ID")
+            .and_then(|value| parse_message_ids(value).into_iter().next())
+            .unwrap_or_default(),
+        "in_reply_to": message.header("In-Reply-To").map(parse_message_ids).unwrap_or_default(),
+        "references": message.header("References").map(parse_message_ids).unwrap_or_default(),
+        "from": { "address": from.address, "name": from.name },
+        "reply_to": reply_to,
+        "subject": message.header("Subject").map(decode_encoded_words).unwrap_or_default(),
+        "date": message.header("Date").unwrap_or_default(),
+        "text": message.text,
+        "html_text": if message.html.is_empty() { String::new() } else { html_to_text(&message.html) },
+        "attachments": attachments,
+        "authentication_results": {
+            "dkim": authentication.dkim,
+            "spf": authentication.spf,
+            "dmarc": authentication.dmarc,
+            "dkim_domains": authentication.dkim_domains,
+            "spf_domain": authentication.spf_domain,
+        },
+    })
+}
+
+/// Returns true for auto-generated mail (RFC 3834 `Auto-Submitted`, bulk
+/// `Precedence`) and for mail sent by the agent's own address, so the agent
+/// never answers bounces, vacation replies, or itself.
+pub(crate) fn is_email_loop_candidate(message: &ParsedEmailMessage, own_address: &str) -> bool {
+    let auto_submitted = message
+        .header("Auto-Submitted")
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+    if !auto_submitted.is_empty() && auto_submitted != "no" {
+        return true;
+    }
+    let precedence = message
+        .header("Precedence")
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+    if matches!(precedence.as_str(), "bulk" | "junk" | "list") {
+        return true;
+    }
+    let own_address = own_address.trim();
+    !own_address.is_empty()
+        && message
+            .header("From")
+            .and_then(parse_email_address)
+            .map(|from| from.address.eq_ignore_ascii_case(own_address))
+            .unwrap_or(false)
+}
+
+/// Derives whether the visible `From` sender is authenticated from verdicts.
+///
+/// A DMARC verdict is authoritative because it checks alignment with the
+/// `From` domain. Without one, a passing DKIM signature or SPF check only
+/// counts when its domain aligns with the `From` domain, so a sender cannot
+/// sign with their own domain and forge `From`.
+pub(crate) fn email_sender_authenticated(
+    from_address: &str,
+    authentication: &EmailAuthenticationResults,
+) -> bool {
+    match authentication.dmarc.trim().to_ascii_lowercase().as_str() {
+        "pass" => return true,
+        "fail" => return false,
+        _ => {}
+    }
+    let Some(from_domain) = email_address_domain(from_address) else {
+        return false;
+    };
+    let dkim_aligned = authentication.dkim.trim().eq_ignore_ascii_case("pass")
+        && authentication
+            .dkim_domains
+            .iter()
+            .any(|domain| email_domains_aligned(&from_domain, domain));
+    let spf_aligned = authentication.spf.trim().eq_ignore_ascii_case("pass")
+        && email_domains_aligned(&from_domain, &authentication.spf_domain);
+    dkim_aligned || spf_aligned
+}
+
+/// Relaxed alignment: the authenticated domain equals the `From` domain or
+/// is one of its parent domains.
+fn email_domains_aligned(from_domain: &str, authenticated_domain: &str) -> bool {
+    let authenticated_domain = authenticated_domain
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    if authenticated_domain.is_empty() || !authenticated_domain.contains('.') {
+        return false;
+    }
+    from_domain == authenticated_domain
+        || from_domain
+            .strip_suffix(authenticated_domain.as_str())
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Lowercased domain of an address, or of a bare domain.
+fn email_address_domain(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let domain = raw.rsplit_once('@').map_or(raw, |(_, domain)| domain);
+    let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+    (!domain.is_empty()).then_some(domain)
+}
+
+/// Stable conversation id for a thread, keyed on the thread root Message-ID.
+pub(crate) fn email_thread_conversation_id(root_message_id: &str) -> String {
+    let digest = Sha256::digest(root_message_id.trim().to_ascii_lowercase().as_bytes());
+    format!("email-thread-{}", hex_prefix(&digest, 8))
+}
+
+fn hex_prefix(bytes: &[u8], len: usize) -> String {
+    bytes
+        .iter()
+        .take(len)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Deterministic Message-ID for an outbound reply chunk so retried
+/// submissions of the same chunk carry the same id.
+pub(crate) fn email_reply_message_id(event_id: &str, chunk_index: usize, from: &str) -> String {
+    let digest = Sha256::digest(format!("{}:{chunk_index}", event_id.trim()).as_bytes());
+    let domain = from
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.trim())
+        .filter(|domain| !domain.is_empty())
+        .unwrap_or("localhost");
+    format!("tau-{}@{domain}", hex_prefix(&digest, 12))
+}
+
+/// Drops trailing quoted history and signatures from a plain-text reply.
+pub(crate) fn strip_quoted_email_reply(text: &str) -> String {
+    let lines = text.lines().collect::<Vec<_>>();
+    let mut end = lines.len();
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("-----Original Message-----") || *line == "-- " {
+            end = index;
+            break;
+        }
+    }
+    let mut quote_start = end;
+    while quote_start > 0 {
+        let line = lines[quote_start - 1].trim();
+        if line.starts_with('>') || line.is_empty() {
+            quote_start -= 1;
+        } else {
+            break;
+        }
+    }
+    if quote_start < end
+        && lines[quote_start..end]
+            .iter()
+            .any(|line| line.trim().starts_with('>'))
+    {
+        end = quote_start;
+        if end > 0 && lines[end - 1].trim_end().ends_with("wrote:") {
+            end -= 1;
+        }
+    }
+    lines[..end].join("\n").trim().to_string()
+}
+
+fn split_header_block(raw: &[u8]) -> (&[u8], &[u8]) {
+    let mut offset = 0usize;
+    while offset < raw.len() {
+        let line_end = raw[offset..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map(|index| offset + index + 1)
+            .unwrap_or(raw.len());
+        if trim_line_ending(&raw[offset..line_end]).is_empty() {
+            return (&raw[..offset], &raw[line_end..]);
+        }
+        offset = line_end;
+    }
+    (raw, &[])
+}
+
+fn parse_header_block(raw: &[u8]) -> Vec<(String, String)> {
+    let text = String::from_utf8_lossy(raw);
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim();
+            if !name.is_empty() && !name.contains(' ') {
+                headers.push((name.to_string(), value.trim().to_string()));
+            }
+        }
+    }
+    headers
+}
+
+fn header_value<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+fn collect_mime_entity(
+    headers: &[(String, String)],
+    body: &[u8],
+    part_path: &str,
+    message: &mut ParsedEmailMessage,
+) {
+    let (content_type, content_type_params) = header_value(headers, "Content-Type")
+        .map(parse_header_params)
+        .unwrap_or_else(|| ("text/plain".to_string(), BTreeMap::new()));
+    if content_type.starts_with("multipart/") {
+        let Some(boundary) = content_type_params.get("boundary") else {
+            return;
+        };
+        for (index, part) in split_multipart(body, boundary).into_iter().enumerate() {
+            let child_path = if part_path.is_empty() {
+                (index + 1).to_string()
+            } else {
+                format!("{part_path}.{}", index + 1)
+            };
+            let (part_header_bytes, part_body) = split_header_block(part);
+            let part_headers = parse_header_block(part_header_bytes);
+            collect_mime_entity(&part_headers, part_body, &child_path, message);
+        }
+        return;
+    }
+
+    // A single-part message body is IMAP section 1.
+    let part = if part_path.is_empty() {
+        "1".to_string()
+    } else {
+        part_path.to_string()
+    };
+    let (disposition, disposition_params) = header_value(headers, "Content-Disposition")
+        .map(parse_header_params)
+        .unwrap_or_default();
+    let file_name = disposition_params
+        .get("filename")
+        .or_else(|| content_type_params.get("name"))
+        .map(|value| decode_encoded_words(value))
+        .unwrap_or_default();
+    let decoded = decode_transfer_encoding(
+        body,
+        header_value(headers, "Content-Transfer-Encoding").unwrap_or_default(),
+    );
+    let is_attachment = disposition == "attachment" || !file_name.is_empty();
+    let charset = content_type_params
+        .get("charset")
+        .map(String::as_str)
+        .unwrap_or("utf-8");
+    if !is_attachment && content_type == "text/plain" && message.text.is_empty() {
+        message.text = decode_charset(&decoded, charset).trim().to_string();
+        return;
+    }
+    if !is_attachment && content_type == "text/html" && message.html.is_empty() {
+        message.html = decode_charset(&decoded, charset);
+        return;
+    }
+    if !is_attachment && content_type.starts_with("text/") {
+        return;
+    }
+    message.attachments.push(ParsedEmailAttachment {
+        part,
+        content_id: header_value(headers, "Content-ID")
+            .and_then(|value| parse_message_ids(value).into_iter().next())
+            .unwrap_or_default(),
+        file_name,
+        content_type,
+        size_bytes: u64::try_from(decoded.len()).unwrap_or(u64::MAX),
+    });
+}
+
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    let mut current_start: Option<usize> = None;
+    let mut offset = 0usize;
+    while offset < body.len() {
+        let line_end = body[offset..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map(|index| offset + index + 1)
+            .unwrap_or(body.len());
+        let line = trim_line_ending(&body[offset..line_end]);
+        if let Some(rest) = line.strip_prefix(delimiter.as_bytes()) {
+            let is_close = rest.starts_with(b"--");
+            if is_close || rest.iter().all(u8::is_ascii_whitespace) {
+                if let Some(start) = current_start.take() {
+                    parts.push(trim_line_ending(&body[start..offset]));
+                }
+                if is_close {
+                    return parts;
+                }
+                current_start = Some(line_end);
+            }
+        }
+        offset = line_end;
+    }
+    if let Some(start) = current_start {
+        parts.push(&body[start..]);
+    }
+    parts
+}
+
+fn trim_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// Splits `type/subtype; key=value; ...` into a lowercase value and params.
+fn parse_header_params(raw: &str) -> (String, BTreeMap<String, String>) {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for ch in raw.chars() {
+        if escaped {
+            current.push(ch);
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => segments.push(std::mem::take(&mut current)),
+            _ => current.push(ch),
+        }
+    }
+    segments.push(current);
+
+    let value = segments
+        .first()
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+    let mut params = BTreeMap::new();
+    for segment in segments.iter().skip(1) {
+        let Some((key, param_value)) = segment.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let param_value = param_value.trim();
+        // RFC 2231 extended values: charset'language'percent-encoded.
+        if let Some(key) = key.strip_suffix('*') {
+            let mut pieces = param_value.splitn(3, '\'');
+            let charset = pieces.next().unwrap_or_default();
+            let _language = pieces.next();
+            let encoded = pieces.next().unwrap_or(charset);
+            params.insert(
+                key.to_string(),
+                decode_charset(&percent_decode(encoded), charset),
+            );
+        } else {
+            params.insert(key, param_value.to_string());
+        }
+    }
+    (value, params)
+}
+
+fn percent_decode(raw: &str) -> Vec<u8> {
+    let bytes = raw.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0usize;
+    while index < bytes.len() {
+        if bytes[index] == b'%' && index + 2 < bytes.len() {
+            if let Some(byte) = decode_hex_pair(bytes[index + 1], bytes[index + 2]) {
+                output.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        output.push(bytes[index]);
+        index += 1;
+    }
+    output
+}
+
+fn decode_hex_pair(high: u8, low: u8) -> Option<u8> {
+    let high = (high as char).to_digit(16)?;
+    let low = (low as char).to_digit(16)?;
+    u8::try_from(high * 16 + low).ok()
+}
+
+fn decode_transfer_encoding(body: &[u8], encoding: &str) -> Vec<u8> {
+    match encoding.trim().to_ascii_lowercase().as_str() {
+        "base64" => {
+            let compact = body
+                .iter()
+                .copied()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .collect::<Vec<_>>();
+            BASE64_STANDARD
+                .decode(&compact)
+                .unwrap_or_else(|_| body.to_vec())
+        }
+        "quoted-printable" => decode_quoted_printable(body, false),
+        _ => body.to_vec(),
+    }
+}
+
+fn decode_quoted_printable(body: &[u8], underscore_is_space: bool) -> Vec<u8> {
+    let mut output = Vec::with_capacity(body.len());
+    let mut index = 0usize;
+    while index < body.len() {
+        let byte = body[index];
+        if byte == b'=' {
+            // Soft line break.
+            if body.get(index + 1) == Some(&b'\r') && body.get(index + 2) == Some(&b'\n') {
+                index += 3;
+                continue;
+            }
+            if body.get(index + 1) == Some(&b'\n') {
+                index += 2;
+                continue;
+            }
+            if let (Some(high), Some(low)) = (body.get(index + 1), body.get(index + 2)) {
+                if let Some(decoded) = decode_hex_pair(*high, *low) {
+                    output.push(decoded);
+                    index += 3;
+                    continue;
+                }
+            }
+        }
+        if underscore_is_space && byte == b'_' {
+            output.push(b' ');
+        } else {
+            output.push(byte);
+        }
+        index += 1;
+    }
+    output
+}
+
+fn decode_charset(bytes: &[u8], charset: &str) -> String {
+    let charset = charset
+        .split('*')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    match charset.as_str() {
+        "iso-8859-1" | "latin1" | "iso8859-1" | "windows-1252" | "cp1252" => {
+            bytes.iter().map(|byte| char::from(*byte)).collect()
+        }
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Decodes RFC 2047 encoded-words (`=?charset?B|Q?text?=`) in a header value.
+pub(crate) fn decode_encoded_words(raw: &str) -> String {
+    let mut output = String::new();
+    let mut rest = raw;
+    let mut previous_was_encoded = false;
+    loop {
+        let Some(start) = rest.find("=?") else {
+            output.push_str(rest);
+            break;
+        };
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((decoded, consumed)) => {
+                // Whitespace between adjacent encoded-words is not displayed.
+                if !(previous_was_encoded && before.trim().is_empty()) {
+                    output.push_str(before);
+                }
+                output.push_str(&decoded);
+                rest = &candidate[consumed..];
+                previous_was_encoded = true;
+            }
+            None => {
+                output.push_str(before);
+                output.push_str("=?");
+                rest = &candidate[2..];
+                previous_was_encoded = false;
+            }
+        }
+    }
+    output
+}
+
+fn decode_encoded_word(input: &str) -> Option<(String, usize)> {
+    let body = input.strip_prefix("=?")?;
+    let (charset, after_charset) = body.split_once('?')?;
+    let (encoding, after_encoding) = after_charset.split_once('?')?;
+    let text_end = after_encoding.find("?=")?;
+    let text = &after_encoding[..text_end];
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+    let bytes = match encoding.to_ascii_lowercase().as_str() {
+        "b" => BASE64_STANDARD.decode(text.as_bytes()).ok()?,
+        "q" => decode_quoted_printable(text.as_bytes(), true),
+        _ => return None,
+    };
+    let consumed = 2 + charset.len() + 1 + encoding.len() + 1 + text_end + 2;
+    Some((decode_charset(&bytes, charset), consumed))
+}
+
+/// Parses the first mailbox of an address header into address and name.
+pub(crate) fn parse_email_address(raw: &str) -> Option<EmailAddress> {
+    let raw = raw.trim();
+    let (name, address) = match (raw.rfind('<'), raw.rfind('>')) {
+        (Some(start), Some(end)) if start < end => (
+            raw[..start].trim().trim_matches('"').trim(),
+            raw[start + 1..end].trim(),
+        ),
+        _ => {
+            let address = raw.split([',', ' ', '(']).next().unwrap_or_default().trim();
+            ("", address)
+        }
+    };
+    if address.is_empty() || !address.contains('@') || address.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(EmailAddress {
+        address: address.to_ascii_lowercase(),
+        name: decode_encoded_words(name),
+    })
+}
+
+/// Extracts bracketed Message-IDs (without `<>`) from a header value.
+pub(crate) fn parse_message_ids(raw: &str) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut rest = raw;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let id = rest[start + 1..start + end].trim();
+        if !id.is_empty() {
+            ids.push(id.to_string());
+        }
+        rest = &rest[start + end + 1..];
+    }
+    if ids.is_empty() {
+        let bare = raw.trim();
+        if !bare.is_empty() && !bare.contains(char::is_whitespace) {
+            ids.push(bare.to_string());
+        }
+    }
+    ids
+}
+
+/// Reads DKIM/SPF/DMARC verdicts from `Authentication-Results` (RFC 8601).
+///
+/// Only headers stamped by `trusted_authserv_id` are honoured when one is
+/// configured; otherwise only the top-most header, which is the one added by
+/// the receiving server, is read. Senders can forge headers further down.
+pub(crate) fn parse_authentication_results(
+    message: &ParsedEmailMessage,
+    trusted_authserv_id: Option<&str>,
+) -> EmailAuthenticationResults {
+    let trusted_authserv_id = trusted_authserv_id.filter(|value| !value.is_empty());
+    let mut results = EmailAuthenticationResults::default();
+    for (index, header) in message.headers_named("Authentication-Results").enumerate() {
+        let header = strip_header_comments(header);
+        let mut segments = header.split(';');
+        let authserv_id = segments
+            .next()
+            .and_then(|value| value.split_whitespace().next())
+            .unwrap_or_default();
+        let honoured = match trusted_authserv_id {
+            Some(trusted) => authserv_id.eq_ignore_ascii_case(trusted),
+            None => index == 0,
+        };
+        if !honoured {
+            continue;
+        }
+        for segment in segments {
+            let mut tokens = segment.split_whitespace();
+            let Some(token) = tokens.next() else {
+                continue;
+            };
+            let Some((method, verdict)) = token.split_once('=') else {
+                continue;
+            };
+            let method = method
+                .split('/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            let verdict = verdict.to_ascii_lowercase();
+            let property = |name: &str| {
+                tokens.clone().find_map(|token| {
+                    let (key, value) = token.split_once('=')?;
+                    key.eq_ignore_ascii_case(name)
+                        .then(|| email_address_domain(value))
+                        .flatten()
+                })
+            };
+            if verdict == "pass" {
+                match method.as_str() {
+                    "dkim" => results.dkim_domains.extend(property("header.d")),
+                    "spf" if results.spf_domain.is_empty() => {
+                        results.spf_domain = property("smtp.mailfrom").unwrap_or_default();
+                    }
+                    _ => {}
+                }
+            }
+            let slot = match method.as_str() {
+                "dkim" => &mut results.dkim,
+                "spf" => &mut results.spf,
+                "dmarc" => &mut results.dmarc,
+                _ => continue,
+            };
+            // With several DKIM signatures any passing one authenticates.
+            if slot.is_empty() || verdict == "pass" {
+                *slot = verdict;
+            }
+        }
+    }
+    results
+}
+
+fn strip_header_comments(raw: &str) -> String {
+    let mut output = String::with_capacity(raw.len());
+    let mut depth = 0usize;
+    for ch in raw.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            _ if depth == 0 => output.push(ch),
+            _ => {}
+        }
+    }
+    output
+}
+
+/// Renders an HTML body as readable plain text, dropping quoted history.
+pub(crate) fn html_to_text(html: &str) -> String {
+    const SKIPPED_TAGS: [&str; 4] = ["script", "style", "head", "blockquote"];
+    const BLOCK_TAGS: [&str; 13] = [
+        "br", "p", "div", "li", "tr", "h1", "h2", "h3", "h4", "h5", "h6", "ul", "table",
+    ];
+    let mut output = String::with_capacity(html.len());
+    let mut skipped: Option<(String, usize)> = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map(|end| &comment[end + 3..])
+                .unwrap_or("");
+            continue;
+        }
+        if rest.starts_with('<') {
+            let Some(end) = rest.find('>') else {
+                break;
+            };
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            let closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .chars()
+                .take_while(char::is_ascii_alphanumeric)
+                .collect::<String>()
+                .to_ascii_lowercase();
+            if let Some((skipped_name, depth)) = skipped.as_mut() {
+                if *skipped_name == name {
+                    if closing {
+                        *depth -= 1;
+                        if *depth == 0 {
+                            skipped = None;
+                        }
+                    } else if !tag.ends_with('/') {
+                        *depth += 1;
+                    }
+                }
+                continue;
+            }
+            if !closing && !tag.ends_with('/') && SKIPPED_TAGS.contains(&name.as_str()) {
+                skipped = Some((name, 1));
+                continue;
+            }
+            if BLOCK_TAGS.contains(&name.as_str()) {
+                output.push('\n');
+            }
+            continue;
+        }
+        let ch = rest.chars().next().unwrap_or_default();
+        if ch == '&' {
+            if let Some((decoded, consumed)) = decode_html_entity(rest) {
+                if skipped.is_none() {
+                    output.push(decoded);
+                }
+                rest = &rest[consumed..];
+                continue;
+            }
+        }
+        if skipped.is_none() {
+            output.push(ch);
+        }
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in output.lines() {
+        let collapsed = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if collapsed.is_empty() && lines.last().map(String::is_empty).unwrap_or(true) {
+            continue;
+        }
+        lines.push(collapsed);
+    }
+    lines.join("\n").trim().to_string()
+}
+
+fn decode_html_entity(input: &str) -> Option<(char, usize)> {
+    let end = input.find(';').filter(|end| *end <= 10)?;
+    let entity = &input[1..end];
+    let decoded = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        _ => {
+            let code = if let Some(hex) = entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                entity.strip_prefix('#')?.parse::<u32>().ok()?
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((decoded, end + 1))
+}
+
+enum MailStream {
+    Plain(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+impl AsyncRead for MailStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(stream) => Pin::new(stream).poll_read(cx, buf),
+            Self::Tls(stream) => Pin::new(stream.as_mut()).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for MailStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            Self::Plain(stream) => Pin::new(stream).poll_write(cx, buf),
+            Self::Tls(stream) => Pin::new(stream.as_mut()).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(stream) => Pin::new(stream).poll_flush(cx),
+            Self::Tls(stream) => Pin::new(stream.as_mut()).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(stream) => Pin::new(stream).poll_shutdown(cx),
+            Self::Tls(stream) => Pin::new(stream.as_mut()).poll_shutdown(cx),
+        }
+    }
+}
+
+/// Line-oriented protocol connection shared by the IMAP and SMTP clients.
+struct MailConnection {
+    stream: BufReader<MailStream>,
+    host: String,
+    timeout: Duration,
+    // Partially read line kept across timeouts so IDLE waits stay lossless.
+    pending_line: Vec<u8>,
+}
+
+impl MailConnection {
+    async fn connect(
+        host: &str,
+        port: u16,
+        tls_mode: MultiChannelEmailTlsMode,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let tcp = tokio::time::timeout(timeout, TcpStream::connect((host, port)))
+            .await
+            .map_err(|_| anyhow!("timed out connecting to {host}:{port}"))?
+            .with_context(|| format!("failed to connect to {host}:{port}"))?;
+        let stream = if tls_mode == MultiChannelEmailTlsMode::Implicit {
+            MailStream::Tls(Box::new(tls_handshake(host, tcp, timeout).await?))
+        } else {
+            MailStream::Plain(tcp)
+        };
+        Ok(Self {
+            stream: BufReader::new(stream),
+            host: host.to_string(),
+            timeout,
+            pending_line: Vec::new(),
+        })
+    }
+
+    async fn upgrade_to_tls(self) -> Result<Self> {
+        let MailStream::Plain(tcp) = self.stream.into_inner() else {
+            bail!("connection to {} already uses TLS", self.host);
+        };
+        let tls = tls_handshake(&self.host, tcp, self.timeout).await?;
+        Ok(Self {
+            stream: BufReader::new(MailStream::Tls(Box::new(tls))),
+            host: self.host,
+            timeout: self.timeout,
+            pending_line: Vec::new(),
+        })
+    }
+
+    fn is_secure(&self) -> bool {
+        matches!(self.stream.get_ref(), MailStream::Tls(_)) || is_loopback_host(&self.host)
+    }
+
+    async fn read_line(&mut self) -> Result<String> {
+        let timeout = self.timeout;
+        self.read_line_within(timeout)
+            .await?
+            .ok_or_else(|| anyhow!("timed out waiting for response from {}", self.host))
+    }
+
+    /// Returns `Ok(None)` when no complete line arrived within `wait`.
+    async fn read_line_within(&mut self, wait: Duration) -> Result<Option<String>> {
+        let read = tokio::time::timeout(
+            wait,
+            (&mut self.stream)
+                .take(MAX_PROTOCOL_LINE_BYTES as u64)
+                .read_until(b'\n', &mut self.pending_line),
+        )
+        .await;
+        let Ok(read) = read else {
+            return Ok(None);
+        };
+        let read = read.with_context(|| format!("failed to read from {}", self.host))?;
+        if read == 0 && self.pending_line.is_empty() {
+            bail!("connection closed by {}", self.host);
+        }
+        let line = std::mem::take(&mut self.pending_line);
+        Ok(Some(
+            String::from_utf8_lossy(trim_line_ending(&line)).into_owned(),
+        ))
+    }
+
+    /// Reads an IMAP literal, keeping at most `MAX_EMAIL_MESSAGE_BYTES`.
+    async fn read_literal(&mut self, len: usize) -> Result<Vec<u8>> {
+        let kept = len.min(MAX_EMAIL_MESSAGE_BYTES);
+        let mut bytes = vec![0u8; kept];
+        tokio::time::timeout(self.timeout, self.stream.read_exact(&mut bytes))
+            .await
+            .map_err(|_| anyhow!("timed out reading message from {}", self.host))?
+            .with_context(|| format!("failed to read message from {}", self.host))?;
+        let skipped = (len - kept) as u64;
+        if skipped > 0 {
+            tokio::time::timeout(
+                self.timeout,
+                tokio::io::copy(
+                    &mut (&mut self.stream).take(skipped),
+                    &mut tokio::io::sink(),
+                ),
+            )
+            .await
+            .map_err(|_| anyhow!("timed out reading message from {}", self.host))?
+            .with_context(|| format!("failed to read message from {}", self.host))?;
+        }
+        Ok(bytes)
+    }
+
+    async fn write_all(&mut self, bytes: &[u8]) -> Result<()> {
+        let stream = self.stream.get_mut();
+        tokio::time::timeout(self.timeout, async {
+            stream.write_all(bytes).await?;
+            stream.flush().await
+        })
+        .await
+        .map_err(|_| anyhow!("timed out writing to {}", self.host))?
+        .with_context(|| format!("failed to write to {}", self.host))
+    }
+}
+
+async fn tls_handshake(
+    host: &str,
+    tcp: TcpStream,
+    timeout: Duration,
+) -> Result<TlsStream<TcpStream>> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .context("failed to configure TLS protocol versions")?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    let server_name = rustls::pki_types::ServerName::try_from(host.to_string())
+        .map_err(|error| anyhow!("invalid TLS server name '{host}': {error}"))?;
+    tokio::time::timeout(
+        timeout,
+        TlsConnector::from(Arc::new(config)).connect(server_name, tcp),
+    )
+    .await
+    .map_err(|_| anyhow!("timed out during TLS handshake with {host}"))?
+    .with_context(|| format!("TLS handshake with {host} failed"))
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    let host = host.trim().trim_start_matches('[').trim_end_matches(']');
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .map(|address| address.is_loopback())
+            .unwrap_or(false)
+}
+
+#[derive(Debug, Default)]
+struct ImapResponse {
+    untagged: Vec<String>,
+    literals: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ImapMailboxStatus {
+    pub(crate) uid_validity: u64,
+}
+
+/// IMAP4rev1 session limited to what polling ingress needs.
+pub(crate) struct ImapSession {
+    connection: MailConnection,
+    next_tag: u32,
+    capabilities: Vec<String>,
+}
+
+impl ImapSession {
+    pub(crate) async fn connect(
+        account: &MultiChannelEmailAccountConfig,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let host = account.imap_host.trim();
+        if host.is_empty() {
+            bail!("imap host is not configured");
+        }
+        let mut connection =
+            MailConnection::connect(host, account.imap_port, account.imap_tls, timeout).await?;
+        let greeting = connection.read_line().await?;
+        if !(greeting.starts_with("* OK") || greeting.starts_with("* PREAUTH")) {
+            bail!("unexpected IMAP greeting from {host}: {greeting}");
+        }
+        let mut session = Self {
+            connection,
+            next_tag: 0,
+            capabilities: Vec::new(),
+        };
+        if account.imap_tls == MultiChannelEmailTlsMode::Starttls {
+            session.command("STARTTLS").await?;
+            session.connection = session.connection.upgrade_to_tls().await?;
+        }
+        Ok(session)
+    }
+
+    pub(crate) async fn login(&mut self, username: &str, password: &str) -> Result<()> {
+        if !self.connection.is_secure() {
+            bail!(
+                "refusing to send IMAP credentials to {} over an unencrypted connection",
+                self.connection.host
+            );
+        }
+        self.command(&format!(
+            "LOGIN {} {}",
+            imap_quote(username)?,
+            imap_quote(password)?
+        ))
+        .await?;
+        let response = self.command("CAPABILITY").await?;
+        self.capabilities = response
+            .untagged
+            .iter()
+            .filter_map(|line| line.strip_prefix("* CAPABILITY "))
+            .flat_map(|line| line.split_whitespace())
+            .map(str::to_ascii_uppercase)
+            .collect();
+        Ok(())
+    }
+
+    pub(crate) fn supports_idle(&self) -> bool {
+        self.capabilities.iter().any(|value| value == "IDLE")
+    }
+
+    pub(crate) async fn select(&mut self, mailbox: &str) -> Result<ImapMailboxStatus> {
+        let response = self
+            .command(&format!("SELECT {}", imap_quote(mailbox)?))
+            .await?;
+        let uid_validity = response
+            .untagged
+            .iter()
+            .find_map(|line| {
+                let start = line.find("[UIDVALIDITY ")? + "[UIDVALIDITY ".len();
+                let end = line[start..].find(']')? + start;
+                line[start..end].trim().parse::<u64>().ok()
+            })
+            .ok_or_else(|| anyhow!("SELECT {mailbox} response did not include UIDVALIDITY"))?;
+        Ok(ImapMailboxStatus { uid_validity })
+    }
+
+    /// Returns UIDs greater than or equal to `from_uid` (all UIDs when `None`).
+    pub(crate) async fn search_uids(&mut self, from_uid: Option<u64>) -> Result<Vec<u64>> {
+        let command = match from_uid {
+            Some(from_uid) => format!("UID SEARCH UID {from_uid}:*"),
+            None => "UID SEARCH ALL".to_string(),
+        };
+        let response = self.command(&command).await?;
+        let mut uids = response
+            .untagged
+            .iter()
+            .filter_map(|line| line.strip_prefix("* SEARCH"))
+            .flat_map(|line| line.split_whitespace())
+            .filter_map(|value| value.parse::<u64>().ok())
+            // `n:*` always matches the highest UID, even when it is below n.
+            .filter(|uid| from_uid.map(|from_uid| *uid >= from_uid).unwrap_or(true))
+            .collect::<Vec<_>>();
+        uids.sort_unstable();
+        uids.dedup();
+        Ok(uids)
+    }
+
+    /// Fetches a full message without setting the `\Seen` flag.
+    pub(crate) async fn fetch_message(&mut self, uid: u64) -> Result<Option<Vec<u8>>> {
+        let response = self
+            .command(&format!("UID FETCH {uid} (UID BODY.PEEK[])"))
+            .await?;
+        Ok(response.literals.into_iter().next())
+    }
+
+    /// Waits in IDLE for up to `wait`; returns true when new mail was announced.
+    pub(crate) async fn idle(&mut self, wait: Duration) -> Result<bool> {
+        let tag = self.next_command_tag();
+        self.connection
+            .write_all(format!("{tag} IDLE\r\n").as_bytes())
+            .await?;
+        let continuation = self.connection.read_line().await?;
+        if !continuation.starts_with('+') {
+            bail!("IMAP IDLE was rejected: {continuation}");
+        }
+        let deadline = tokio::time::Instant::now() + wait;
+        let mut announced = false;
+        loop {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            match self.connection.read_line_within(remaining).await? {
+                Some(line) if line.starts_with("* ") && line.ends_with(" EXISTS") => {
+                    announced = true;
+                    break;
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        self.connection.write_all(b"DONE\r\n").await?;
+        self.read_tagged_response(&tag, "IDLE").await?;
+        Ok(announced)
+    }
+
+    pub(crate) async fn logout(mut self) {
+        let _ = self.command("LOGOUT").await;
+    }
+
+    fn next_command_tag(&mut self) -> String {
+        self.next_tag = self.next_tag.saturating_add(1);
+        format!("T{:04}", self.next_tag)
+    }
+
+    async fn command(&mut self, command: &str) -> Result<ImapResponse> {
+        let tag = self.next_command_tag();
+        self.connection
+            .write_all(format!("{tag} {command}\r\n").as_bytes())
+            .await?;
+        // Only the verb is echoed in errors so LOGIN credentials never leak.
+        let verb = command.split_whitespace().next().unwrap_or_default();
+        self.read_tagged_response(&tag, verb).await
+    }
+
+    async fn read_tagged_response(&mut self, tag: &str, verb: &str) -> Result<ImapResponse> {
+        let mut response = ImapResponse::default();
+        let tagged_prefix = format!("{tag} ");
+        loop {
+            let line = self.connection.read_line().await?;
+            if let Some(status) = line.strip_prefix(&tagged_prefix) {
+                if status.starts_with("OK") {
+                    return Ok(response);
+                }
+                bail!("IMAP {verb} failed: {status}");
+            }
+            if let Some(len) = imap_literal_length(&line) {
+                let literal = self.connection.read_literal(len).await?;
+                response.literals.push(literal);
+            }
+            response.untagged.push(line);
+        }
+    }
+}
+
+fn imap_literal_length(line: &str) -> Option<usize> {
+    let open = line.strip_suffix('}')?.rfind('{')?;
+    line[open + 1..line.len() - 1].parse::<usize>().ok()
+}
+
+fn imap_quote(value: &str) -> Result<String> {
+    if value.contains(['\r', '\n']) {
+        bail!("IMAP strings must not contain line breaks");
+    }
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// A threaded reply ready for SMTP submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EmailSubmission {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) subject: String,
+    pub(crate) message_id: String,
+    pub(crate) in_reply_to: Option<String>,
+    pub(crate) references: Vec<String>,
+    pub(crate) text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SmtpDeliveryError {
+    pub(crate) reason_code: &'static str,
+    pub(crate) detail: String,
+    pub(crate) retryable: bool,
+}
+
+impl SmtpDeliveryError {
+    fn transport(error: anyhow::Error) -> Self {
+        Self {
+            reason_code: "delivery_transport_error",
+            detail: format!("{error:#}"),
+            retryable: true,
+        }
+    }
+
+    fn from_reply(stage: &str, code: u16, lines: &[String]) -> Self {
+        let (reason_code, retryable) = match code {
+            530 | 534 | 535 | 538 => ("delivery_email_auth_failed", false),
+            400..=499 => ("delivery_provider_unavailable", true),
+            _ => ("delivery_request_rejected", false),
+        };
+        Self {
+            reason_code,
+            detail: format!("smtp {stage} rejected with {code}: {}", lines.join(" ")),
+            retryable,
+        }
+    }
+}
+
+/// Renders the RFC 5322 message for a submission. The body is base64 encoded
+/// so arbitrary UTF-8 survives 7-bit relays.
+pub(crate) fn render_email_message(submission: &EmailSubmission, date: &str) -> String {
+    let mut message = String::new();
+    let mut header = |name: &str, value: &str| {
+        message.push_str(name);
+        message.push_str(": ");
+        message.push_str(&sanitize_header_value(value));
+        message.push_str("\r\n");
+    };
+    header("From", &submission.from);
+    header("To", &submission.to);
+    header("Subject", &encode_header_text(&submission.subject));
+    header("Date", date);
+    header("Message-ID", &format!("<{}>", submission.message_id));
+    if let Some(in_reply_to) = submission.in_reply_to.as_deref() {
+        header("In-Reply-To", &format!("<{in_reply_to}>"));
+    }
+    if !submission.references.is_empty() {
+        let references = submission
+            .references
+            .iter()
+            .map(|id| format!("<{id}>"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        header("References", &references);
+    }
+    header("Auto-Submitted", "auto-replied");
+    header("MIME-Version", "1.0");
+    header("Content-Type", "text/plain; charset=utf-8");
+    header("Content-Transfer-Encoding", "base64");
+    message.push_str("\r\n");
+    let encoded = BASE64_STANDARD.encode(submission.text.as_bytes());
+    for chunk in encoded.as_bytes().chunks(BASE64_LINE_WIDTH) {
+        message.push_str(&String::from_utf8_lossy(chunk));
+        message.push_str("\r\n");
+    }
+    message
+}
+
+fn sanitize_header_value(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| if ch == '\r' || ch == '\n' { ' ' } else { ch })
+        .collect()
+}
+
+fn encode_header_text(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+    format!("=?UTF-8?B?{}?=", BASE64_STANDARD.encode(value.as_bytes()))
+}
+
+fn validate_envelope_address(label: &str, address: &str) -> Result<(), SmtpDeliveryError> {
+    let valid = address.contains('@')
+        && !address
+            .chars()
+            .any(|ch| ch.is_whitespace() || ch == '<' || ch == '>');
+    if valid {
+        return Ok(());
+    }
+    Err(SmtpDeliveryError {
+        reason_code: "delivery_request_rejected",
+        detail: format!("invalid {label} address '{address}'"),
+        retryable: false,
+    })
+}
+
+/// Submits `submission` over SMTP using the account's credentials.
+pub(crate) async fn send_smtp_message(
+    account: &MultiChannelEmailAccountConfig,
+    submission: &EmailSubmission,
+    timeout: Duration,
+) -> Result<(), SmtpDeliveryError> {
+    validate_envelope_address("sender", &submission.from)?;
+    validate_envelope_address("recipient", &submission.to)?;
+    let host = account.smtp_host.trim();
+    if host.is_empty() {
+        return Err(SmtpDeliveryError {
+            reason_code: "delivery_missing_email_smtp_host",
+            detail: "email outbound requires an SMTP host".to_string(),
+            retryable: false,
+        });
+    }
+    let mut connection =
+        MailConnection::connect(host, account.smtp_port, account.smtp_tls, timeout)
+            .await
+            .map_err(SmtpDeliveryError::transport)?;
+    smtp_expect(&mut connection, "greeting", |code| code == 220).await?;
+    let helo_domain = submission
+        .from
+        .rsplit_once('@')
+        .map(|(_, domain)| domain)
+        .unwrap_or("localhost")
+        .to_string();
+    let mut capabilities = smtp_command(
+        &mut connection,
+        &format!("EHLO {helo_domain}"),
+        "EHLO",
+        |code| code == 250,
+    )
+    .await?;
+    if account.smtp_tls == MultiChannelEmailTlsMode::Starttls {
+        smtp_command(&mut connection, "STARTTLS", "STARTTLS", |code| code == 220).await?;
+        connection = connection
+            .upgrade_to_tls()
+            .await
+            .map_err(SmtpDeliveryError::transport)?;
+        capabilities = smtp_command(
+            &mut connection,
+            &format!("EHLO {helo_domain}"),
+            "EHLO",
+            |code| code == 250,
+        )
+        .await?;
+    }
+
+    if let (Some(username), Some(password)) =
+        (account.resolved_username(), account.resolved_password())
+    {
+        if !connection.is_secure() {
+            return Err(SmtpDeliveryError {
+                reason_code: "delivery_request_rejected",
+                detail: format!(
+                    "refusing to send SMTP credentials to {host} over an unencrypted connection"
+                ),
+                retryable: false,
+            });
+        }
+        smtp_authenticate(&mut connection, &capabilities, username, password).await?;
+    }
+
+    smtp_command(
+        &mut connection,
+        &format!("MAIL FROM:<{}>", submission.from),
+        "MAIL FROM",
+        |code| code == 250,
+    )
+    .await?;
+    smtp_command(
+        &mut connection,
+        &format!("RCPT TO:<{}>", submission.to),
+        "RCPT TO",
+        |code| code == 250 || code == 251,
+    )
+    .await?;
+    smtp_command(&mut connection, "DATA", "DATA", |code| code == 354).await?;
+    let date = chrono::Utc::now().to_rfc2822();
+    let mut payload = String::new();
+    for line in render_email_message(submission, &date).split_inclusive("\r\n") {
+        // Dot-stuffing (RFC 5321 4.5.2).
+        if line.starts_with('.') {
+            payload.push('.');
+        }
+        payload.push_str(line);
+    }
+    payload.push_str(".\r\n");
+    connection
+        .write_all(payload.as_bytes())
+        .await
+        .map_err(SmtpDeliveryError::transport)?;
+    smtp_expect(&mut connection, "DATA", |code| code == 250).await?;
+    let _ = smtp_command(&mut connection, "QUIT", "QUIT", |code| code == 221).await;
+    Ok(())
+}
+
+async fn smtp_authenticate(
+    connection: &mut MailConnection,
+    capabilities: &[String],
+    username: &str,
+    password: &str,
+) -> Result<(), SmtpDeliveryError> {
+    let mechanisms = capabilities
+        .iter()
+        .filter_map(|line| {
+            let upper = line.to_ascii_uppercase();
+            upper
+                .strip_prefix("AUTH ")
+                .or_else(|| upper.strip_prefix("AUTH="))
+                .map(str::to_string)
+        })
+        .flat_map(|line| {
+            line.split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if mechanisms.iter().any(|mechanism| mechanism == "PLAIN") || mechanisms.is_empty() {
+        let token = BASE64_STANDARD.encode(format!("\0{username}\0{password}"));
+        smtp_command(connection, &format!("AUTH PLAIN {token}"), "AUTH", |code| {
+            code == 235
+        })
+        .await?;
+        return Ok(());
+    }
+    smtp_command(connection, "AUTH LOGIN", "AUTH", |code| code == 334).await?;
+    smtp_command(
+        connection,
+        &BASE64_STANDARD.encode(username),
+        "AUTH",
+        |code| code == 334,
+    )
+    .await?;
+    smtp_command(
+        connection,
+        &BASE64_STANDARD.encode(password),
+        "AUTH",
+        |code| code == 235,
+    )
+    .await?;
+    Ok(())
+}
+
+async fn smtp_command(
+    connection: &mut MailConnection,
+    command: &str,
+    stage: &str,
+    accept: impl Fn(u16) -> bool,
+) -> Result<Vec<String>, SmtpDeliveryError> {
+    connection
+        .write_all(format!("{command}\r\n").as_bytes())
+        .await
+        .map_err(SmtpDeliveryError::transport)?;
+    smtp_expect(connection, stage, accept).await
+}
+
+async fn smtp_expect(
+    connection: &mut MailConnection,
+    stage: &str,
+    accept: impl Fn(u16) -> bool,
+) -> Result<Vec<String>, SmtpDeliveryError> {
+    let mut lines = Vec::new();
+    let code = loop {
+        let line = connection
+            .read_line()
+            .await
+            .map_err(SmtpDeliveryError::transport)?;
+        let code = line
+            .get(..3)
+            .and_then(|value| value.parse::<u16>().ok())
+            .ok_or_else(|| SmtpDeliveryError::transport(anyhow!("malformed SMTP reply: {line}")))?;
+        let is_last = line.as_bytes().get(3) != Some(&b'-');
+        lines.push(line.get(4..).unwrap_or_default().to_string());
+        if is_last {
+            break code;
+        }
+    };
+    if accept(code) {
+        return Ok(lines);
+    }
+    Err(SmtpDeliveryError::from_reply(stage, code, &lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_email_ingress_payload, decode_encoded_words, email_sender_authenticated,
+        email_thread_conversation_id, html_to_text, is_email_loop_candidate,
+        parse_authentication_results, parse_email_address, parse_email_message, parse_message_ids,
+        render_email_message, send_smtp_message, strip_quoted_email_reply,
+        EmailAuthenticationResults, EmailIngressContext, EmailSubmission, ImapSession,
+        MultiChannelEmailAccountConfig, MultiChannelEmailTlsMode,
+    };
+    use std::time::Duration;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    const MULTIPART_MESSAGE: &str = concat!(
+        "Authentication-Results: mx.example.org; dkim=pass header.d=example.com;\r\n",
+        " spf=pass (sender permitted) smtp.mailfrom=alice@example.com; dmarc=pass\r\n",
+        "Authentication-Results: forged.example; dkim=fail\r\n",
+        "From: =?UTF-8?Q?Al=C3=AFce?= <Alice@Example.com>\r\n",
+        "To: agent@example.org\r\n",
+        "Subject: =?UTF-8?B?UXVhcnRlcmx5?= =?UTF-8?B?IHJlcG9ydA==?=\r\n",
+        "Message-ID: <reply-2@example.com>\r\n",
+        "In-Reply-To: <reply-1@example.org>\r\n",
+        "References: <root-0@example.com>\r\n <reply-1@example.org>\r\n",
+        "Date: Tue, 14 Oct 2025 09:30:00 +0000\r\n",
+        "MIME-Version: 1.0\r\n",
+        "Content-Type: multipart/mixed; boundary=\"outer\"\r\n",
+        "\r\n",
+        "preamble\r\n",
+        "--outer\r\n",
+        "Content-Type: multipart/alternative; boundary=inner\r\n",
+        "\r\n",
+        "--inner\r\n",
+        "Content-Type: text/plain; charset=utf-8\r\n",
+        "Content-Transfer-Encoding: quoted-printable\r\n",
+        "\r\n",
+        "Numbers look good =E2=9C=85\r\n",
+        "\r\n",
+        "On Mon, Alice wrote:\r\n",
+        "> earlier\r\n",
+        "--inner\r\n",
+        "Content-Type: text/html; charset=utf-8\r\n",
+        "\r\n",
+        "<p>Numbers look <b>good</b></p>\r\n",
+        "--inner--\r\n",
+        "--outer\r\n",
+        "Content-Type: application/pdf; name=\"report.pdf\"\r\n",
+        "Content-Disposition: attachment; filename=\"report.pdf\"\r\n",
+        "Content-Transfer-Encoding: base64\r\n",
+        "Content-ID: <att-1@example.com>\r\n",
+        "\r\n",
+        "JVBERi0xLjQK\r\n",
+        "--outer--\r\n",
+    );
+
+    #[test]
+    fn unit_parse_email_message_walks_nested_multipart_parts() {
+        let message = parse_email_message(MULTIPART_MESSAGE.as_bytes()).expect("parse");
+        assert!(message.text.starts_with("Numbers look good ✅"));
+        assert_eq!(message.html.trim(), "<p>Numbers look <b>good</b></p>");
+        assert_eq!(message.attachments.len(), 1);
+        let attachment = &message.attachments[0];
+        assert_eq!(attachment.part, "2");
+        assert_eq!(attachment.file_name, "report.pdf");
+        assert_eq!(attachment.content_type, "application/pdf");
+        assert_eq!(attachment.content_id, "att-1@example.com");
+        assert_eq!(attachment.size_bytes, 9);
+    }
+
+    #[test]
+    fn unit_header_helpers_decode_addresses_ids_and_encoded_words() {
+        assert_eq!(
+            decode_encoded_words("=?UTF-8?B?UXVhcnRlcmx5?= =?UTF-8?B?IHJlcG9ydA==?="),
+            "Quarterly report"
+        );
+        assert_eq!(
+            decode_encoded_words("=?iso-8859-1?Q?caf=E9_ok?="),
+            "café ok"
+        );
+        let address = parse_email_address("\"Ops Team\" <Ops@Example.com>").expect("address");
+        assert_eq!(address.address, "ops@example.com");
+        assert_eq!(address.name, "Ops Team");
+        assert!(parse_email_address("not an address").is_none());
+        assert_eq!(
+            parse_message_ids("<a@x> (comment) <b@y>"),
+            vec!["a@x".to_string(), "b@y".to_string()]
+        );
+    }
+
+    #[test]
+    fn functional_authentication_results_only_trust_receiving_server() {
+        let message = parse_email_message(MULTIPART_MESSAGE.as_bytes()).expect("parse");
+        let top_most = parse_authentication_results(&message, None);
+        assert_eq!(top_most.dkim, "pass");
+        assert_eq!(top_most.spf, "pass");
+        assert_eq!(top_most.dmarc, "pass");
+
+        let forged_only = parse_authentication_results(&message, Some("forged.example"));
+        assert_eq!(forged_only.dkim, "fail");
+        assert!(forged_only.spf.is_empty());
+
+        assert_eq!(top_most.dkim_domains, vec!["example.com".to_string()]);
+        assert_eq!(top_most.spf_domain, "example.com");
+        assert!(email_sender_authenticated("alice@example.com", &top_most));
+
+        let verdicts = |dkim: &str, spf: &str, dmarc: &str| EmailAuthenticationResults {
+            dkim: dkim.to_string(),
+            spf: spf.to_string(),
+            dmarc: dmarc.to_string(),
+            dkim_domains: vec!["example.com".to_string()],
+            spf_domain: "example.com".to_string(),
+        };
+        assert!(email_sender_authenticated(
+            "alice@example.com",
+            &verdicts("pass", "", "")
+        ));
+        assert!(email_sender_authenticated(
+            "alice@mail.example.com",
+            &verdicts("", "pass", "none")
+        ));
+        assert!(!email_sender_authenticated(
+            "alice@example.com",
+            &verdicts("pass", "pass", "fail")
+        ));
+        assert!(!email_sender_authenticated(
+            "alice@example.com",
+            &verdicts("fail", "softfail", "")
+        ));
+    }
+
+    #[test]
+    fn regression_email_sender_authentication_rejects_forged_from_with_unaligned_domains() {
+        let message = parse_email_message(
+            concat!(
+                "Authentication-Results: mx.example.org; dkim=pass header.d=attacker.test;\r\n",
+                " spf=pass smtp.mailfrom=bounce@attacker.test; dmarc=none\r\n",
+                "From: CEO <ceo@example.com>\r\n",
+                "Subject: urgent\r\n",
+                "\r\n",
+                "wire the funds\r\n",
+            )
+            .as_bytes(),
+        )
+        .expect("parse");
+        let authentication = parse_authentication_results(&message, None);
+        assert_eq!(authentication.dkim, "pass");
+        assert_eq!(authentication.spf, "pass");
+        assert!(!email_sender_authenticated(
+            "ceo@example.com",
+            &authentication
+        ));
+        assert!(!email_sender_authenticated(
+            "ceo@example.com.attacker.test",
+            &EmailAuthenticationResults {
+                dkim: "pass".to_string(),
+                dkim_domains: vec!["example.com".to_string()],
+                ..EmailAuthenticationResults::default()
+            }
+        ));
+        assert!(email_sender_authenticated(
+            "someone@attacker.test",
+            &authentication
+        ));
+    }
+
+    #[test]
+    fn functional_ingress_payload_carries_thread_and_attachment_metadata() {
+        let message = parse_email_message(MULTIPART_MESSAGE.as_bytes()).expect("parse");
+        let payload = build_email_ingress_payload(
+            &message,
+            &EmailIngressContext {
+                account: "agent@example.org",
+                imap_host: "imap.example.org",
+                mailbox: "INBOX",
+                uid: 42,
+                uid_validity: 7,
+                trusted_authserv_id: Some("mx.example.org"),
+                received_unix_ms: 1_760_000_000_000,
+            },
+        );
+        assert_eq!(payload["message_id"], "reply-2@example.com");
+        assert_eq!(payload["references"][0], "root-0@example.com");
+        assert_eq!(payload["in_reply_to"][0], "reply-1@example.org");
+        assert_eq!(payload["from"]["address"], "alice@example.com");
+        assert_eq!(payload["from"]["name"], "Alïce");
+        assert_eq!(payload["subject"], "Quarterly report");
+        assert_eq!(payload["html_text"], "Numbers look good");
+        assert_eq!(payload["attachments"][0]["part"], "2");
+        assert_eq!(payload["authentication_results"]["dmarc"], "pass");
+    }
+
+    #[test]
+    fn unit_strip_quoted_reply_and_html_to_text() {
+        assert_eq!(
+            strip_quoted_email_reply("Sounds good.\n\nOn Mon, Bob wrote:\n> hi\n>\n> there\n"),
+            "Sounds good."
+        );
+        assert_eq!(
+            strip_quoted_email_reply("Inline > arrow stays\nThanks\n-- \nBob"),
+            "Inline > arrow stays\nThanks"
+        );
+        assert_eq!(
+            html_to_text(
+                "<html><head><style>p{}</style></head><body><p>Hi&nbsp;there &amp; welcome</p>\
+                 <div>Line&#33;</div><blockquote><p>old</p></blockquote></body></html>"
+            ),
+            "Hi there & welcome\n\nLine!"
+        );
+    }
+
+    #[test]
+    fn regression_loop_candidates_and_thread_ids_are_stable() {
+        let auto_reply =
+            parse_email_message(b"From: a@example.com\r\nAuto-Submitted: auto-replied\r\n\r\nbody")
+                .expect("parse");
+        assert!(is_email_loop_candidate(&auto_reply, "agent@example.org"));
+        let own =
+            parse_email_message(b"From: Agent <AGENT@example.org>\r\n\r\nbody").expect("parse");
+        assert!(is_email_loop_candidate(&own, "agent@example.org"));
+        let human = parse_email_message(b"From: a@example.com\r\n\r\nbody").expect("parse");
+        assert!(!is_email_loop_candidate(&human, "agent@example.org"));
+
+        assert_eq!(
+            email_thread_conversation_id("Root@Example.com"),
+            email_thread_conversation_id("root@example.com")
+        );
+        assert!(email_thread_conversation_id("root@example.com").starts_with("email-thread-"));
+    }
+
+    #[test]
+    fn unit_render_email_message_sets_threading_headers_and_strips_injection() {
+        let rendered = render_email_message(
+            &EmailSubmission {
+                from: "agent@example.org".to_string(),
+                to: "alice@example.com".to_string(),
+                subject: "Re: report\r\nBcc: victim@example.com".to_string(),
+                message_id: "tau-1@example.org".to_string(),
+                in_reply_to: Some("reply-2@example.com".to_string()),
+                references: vec![
+                    "root-0@example.com".to_string(),
+                    "reply-2@example.com".to_string(),
+                ],
+                text: "hello".to_string(),
+            },
+            "Tue, 14 Oct 2025 09:31:00 +0000",
+        );
+        assert!(rendered.contains("Subject: Re: report  Bcc: victim@example.com\r\n"));
+        assert!(!rendered.contains("\r\nBcc:"));
+        assert!(rendered.contains("In-Reply-To: <reply-2@example.com>\r\n"));
+        assert!(rendered.contains("References: <root-0@example.com> <reply-2@example.com>\r\n"));
+        assert!(rendered.contains("Auto-Submitted: auto-replied\r\n"));
+        assert!(rendered.ends_with("\r\naGVsbG8=\r\n"));
+    }
+
+    #[tokio::test]
+    async fn integration_smtp_submission_authenticates_and_sends_dot_stuffed_data() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.expect("accept");
+            let (reader, mut writer) = socket.into_split();
+            let mut reader = BufReader::new(reader);
+            let mut transcript = Vec::new();
+            writer
+                .write_all(b"220 relay ready\r\n")
+                .await
+                .expect("greet");
+            let mut in_data = false;
+            let mut data = String::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.expect("read") == 0 {
+                    break;
+                }
+                if in_data {
+                    if line == ".\r\n" {
+                        in_data = false;
+                        writer.write_all(b"250 queued\r\n").await.expect("write");
+                    } else {
+                        data.push_str(&line);
+                    }
+                    continue;
+                }
+                transcript.push(line.trim_end().to_string());
+                let reply: &[u8] = if line.starts_with("EHLO") {
+                    b"250-relay\r\n250 AUTH PLAIN LOGIN\r\n"
+                } else if line.starts_with("AUTH PLAIN") {
+                    b"235 ok\r\n"
+                } else if line.starts_with("DATA") {
+                    in_data = true;
+                    b"354 go\r\n"
+                } else if line.starts_with("QUIT") {
+                    writer.write_all(b"221 bye\r\n").await.expect("write");
+                    break;
+                } else {
+                    b"250 ok\r\n"
+                };
+                writer.write_all(reply).await.expect("write");
+            }
+            (transcript, data)
+        });
+
+        let account = MultiChannelEmailAccountConfig {
+            address: Some("agent@example.org".to_string()),
+            password: Some("secret".to_string()),
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: port,
+            smtp_tls: MultiChannelEmailTlsMode::None,
+            ..MultiChannelEmailAccountConfig::default()
+        };
+        send_smtp_message(
+            &account,
+            &EmailSubmission {
+                from: "agent@example.org".to_string(),
+                to: "alice@example.com".to_string(),
+                subject: "Re: report".to_string(),
+                message_id: "tau-1@example.org".to_string(),
+                in_reply_to: Some("reply-2@example.com".to_string()),
+                references: vec!["reply-2@example.com".to_string()],
+                text: "hello".to_string(),
+            },
+            Duration::from_secs(5),
+        )
+        .await
+        .expect("smtp send");
+
+        let (transcript, data) = server.await.expect("server");
+        assert_eq!(transcript[0], "EHLO example.org");
+        assert!(transcript[1].starts_with("AUTH PLAIN "));
+        assert_eq!(transcript[2], "MAIL FROM:<agent@example.org>");
+        assert_eq!(transcript[3], "RCPT TO:<alice@example.com>");
+        assert!(data.contains("In-Reply-To: <reply-2@example.com>\r\n"));
+    }
+
+    #[tokio::test]
+    async fn regression_smtp_rejection_maps_to_non_retryable_reason_code() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            socket.write_all(b"220 ready\r\n").await.expect("greet");
+            let mut buffer = [0u8; 512];
+            let _ = socket.read(&mut buffer).await;
+            socket.write_all(b"250 relay\r\n").await.expect("ehlo");
+            let _ = socket.read(&mut buffer).await;
+            socket
+                .write_all(b"550 sender rejected\r\n")
+                .await
+                .expect("reject");
+        });
+        let account = MultiChannelEmailAccountConfig {
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: port,
+            smtp_tls: MultiChannelEmailTlsMode::None,
+            ..MultiChannelEmailAccountConfig::default()
+        };
+        let error = send_smtp_message(
+            &account,
+            &EmailSubmission {
+                from: "agent@example.org".to_string(),
+                to: "alice@example.com".to_string(),
+                subject: "Re: hi".to_string(),
+                message_id: "tau-2@example.org".to_string(),
+                in_reply_to: None,
+                references: Vec::new(),
+                text: "hello".to_string(),
+            },
+            Duration::from_secs(5),
+        )
+        .await
+        .expect_err("550 should fail");
+        assert_eq!(error.reason_code, "delivery_request_rejected");
+        assert!(!error.retryable);
+        assert!(error.detail.contains("550"));
+    }
+
+    #[tokio::test]
+    async fn regression_imap_login_refuses_cleartext_to_remote_hosts() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            socket.write_all(b"* OK ready\r\n").await.expect("greet");
+            let mut buffer = [0u8; 64];
+            let _ = socket.read(&mut buffer).await;
+        });
+        let account = MultiChannelEmailAccountConfig {
+            imap_host: "127.0.0.1".to_string(),
+            imap_port: port,
+            imap_tls: MultiChannelEmailTlsMode::None,
+            ..MultiChannelEmailAccountConfig::default()
+        };
+        let mut session = ImapSession::connect(&account, Duration::from_secs(5))
+            .await
+            .expect("connect");
+        // Loopback is allowed; simulate a remote host name on the same socket.
+        session.connection.host = "imap.example.org".to_string();
+        let error = session
+            .login("agent@example.org", "secret")
+            .await
+            .expect_err("cleartext login should be refused");
+        assert!(error.to_string().contains("unencrypted"));
+    }
+}
@@ -19,6 +19,7 @@ use crate::multi_channel_contract::MultiChannelTransport;
 use crate::multi_channel_credentials::{
     resolve_secret, MultiChannelCredentialStoreSnapshot, ResolvedSecret,
 };
+use crate::multi_channel_email::{ImapSession, MultiChannelEmailAccountConfig};
 use tau_core::{current_unix_timestamp_ms, write_text_atomic};
 
 /// Public `const` `MULTI_CHANNEL_LIFECYCLE_STATE_FILE_NAME` in `tau-multi-channel`.
@@ -33,6 +34,8 @@ const TELEGRAM_TOKEN_INTEGRATION_ID: &str = "telegram-bot-token";
 const DISCORD_TOKEN_INTEGRATION_ID: &str = "discord-bot-token";
 const WHATSAPP_TOKEN_INTEGRATION_ID: &str = "whatsapp-access-token";
 const WHATSAPP_PHONE_NUMBER_ID_INTEGRATION_ID: &str = "whatsapp-phone-number-id";
+const MATRIX_TOKEN_INTEGRATION_ID: &str = "matrix-access-token";
+const EMAIL_PASSWORD_INTEGRATION_ID: &str = "email-password";
 const ONLINE_PROBE_TIMEOUT_MS: u64 = 3_000;
 const ONLINE_PROBE_MAX_ATTEMPTS: usize = 2;
 const ONLINE_PROBE_RETRY_DELAY_MS: u64 = 150;
@@ -65,12 +68,15 @@ pub struct MultiChannelLifecycleCommandConfig {
     pub telegram_api_base: String,
     pub discord_api_base: String,
     pub whatsapp_api_base: String,
+    pub matrix_homeserver_url: String,
     pub credential_store: Option<MultiChannelCredentialStoreSnapshot>,
     pub credential_store_unreadable: bool,
     pub telegram_bot_token: Option<String>,
     pub discord_bot_token: Option<String>,
     pub whatsapp_access_token: Option<String>,
     pub whatsapp_phone_number_id: Option<String>,
+    pub matrix_access_token: Option<String>,
+    pub email: MultiChannelEmailAccountConfig,
     pub probe_online: bool,
     pub probe_online_timeout_ms: u64,
     pub probe_online_max_attempts: usize,
@@ -442,6 +448,45 @@ fn probe_channel_readiness(
 
             (token.source, phone_id.source, token.value, phone_id.value)
         }
+        MultiChannelTransport::Matrix => {
+            let token = resolve_lifecycle_secret(
+                config,
+                config.matrix_access_token.as_deref(),
+                MATRIX_TOKEN_INTEGRATION_ID,
+            );
+            if token.credential_store_unreadable {
+                reason_codes.push("credential_store_unreadable".to_string());
+            }
+            if token.value.is_none() {
+                reason_codes.push("missing_matrix_access_token".to_string());
+            }
+            (token.source, "not_required".to_string(), token.value, None)
+        }
+        MultiChannelTransport::Email => {
+            let password = resolve_lifecycle_secret(
+                config,
+                config.email.password.as_deref(),
+                EMAIL_PASSWORD_INTEGRATION_ID,
+            );
+            if password.credential_store_unreadable {
+                reason_codes.push("credential_store_unreadable".to_string());
+            }
+            if password.value.is_none() {
+                reason_codes.push("missing_email_password".to_string());
+            }
+            if config.email.resolved_address().is_none() {
+                reason_codes.push("missing_email_address".to_string());
+            }
+            if config.email.imap_host.trim().is_empty() {
+                reason_codes.push("missing_email_imap_host".to_string());
+            }
+            (
+                password.source,
+                "not_required".to_string(),
+                password.value,
+                None,
+            )
+        }
     };
 
     if require_ingress_file {
@@ -469,6 +514,11 @@ fn probe_channel_readiness(
             MultiChannelTransport::Whatsapp => {
                 token_value.as_deref().is_some() && phone_number_value.as_deref().is_some()
             }
+            MultiChannelTransport::Email => {
+                token_value.as_deref().is_some()
+                    && config.email.resolved_username().is_some()
+                    && !config.email.imap_host.trim().is_empty()
+            }
             _ => token_value.as_deref().is_some(),
         };
         if !credentials_ready {
@@ -553,6 +603,8 @@ fn run_online_probe_with_retry(
                 };
                 probe_whatsapp_online(&client, config, token, phone_number_id)
             }
+            MultiChannelTransport::Matrix => probe_matrix_online(&client, config, token),
+            MultiChannelTransport::Email => probe_email_online(config, token),
         };
         match probe {
             Ok(()) => {
@@ -686,6 +738,83 @@ fn probe_whatsapp_online(
     Err(classify_whatsapp_status(status, &body_json))
 }
 
+fn probe_matrix_online(
+    client: &Client,
+    config: &MultiChannelLifecycleCommandConfig,
+    token: &str,
+) -> Result<(), OnlineProbeError> {
+    let endpoint = format!(
+        "{}/_matrix/client/v3/account/whoami",
+        config.matrix_homeserver_url.trim_end_matches('/')
+    );
+    let response = client
+        .get(&endpoint)
+        .header("Authorization", format!("Bearer {token}"))
+        .send()
+        .map_err(|error| {
+            classify_transport_error(
+                &error,
+                "probe_online_matrix_timeout",
+                "probe_online_matrix_transport_error",
+            )
+        })?;
+    let status = response.status();
+    let body_raw = response.text().unwrap_or_default();
+    if status.is_success() {
+        let payload = serde_json::from_str::<Value>(&body_raw).unwrap_or(Value::Null);
+        if payload.get("user_id").and_then(Value::as_str).is_some() {
+            return Ok(());
+        }
+        return Err(OnlineProbeError {
+            reason_code: "probe_online_matrix_invalid_response",
+            retryable: false,
+        });
+    }
+    Err(classify_matrix_status(status))
+}
+
+fn probe_email_online(
+    config: &MultiChannelLifecycleCommandConfig,
+    password: &str,
+) -> Result<(), OnlineProbeError> {
+    let username = config.email.resolved_username().unwrap_or_default();
+    let timeout = Duration::from_millis(config.probe_online_timeout_ms);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|_| OnlineProbeError {
+            reason_code: "probe_online_email_runtime_unavailable",
+            retryable: false,
+        })?;
+    runtime.block_on(async {
+        let connect = tokio::time::timeout(timeout, ImapSession::connect(&config.email, timeout));
+        let mut session = match connect.await {
+            Ok(Ok(session)) => session,
+            Ok(Err(_)) => {
+                return Err(OnlineProbeError {
+                    reason_code: "probe_online_email_transport_error",
+                    retryable: true,
+                })
+            }
+            Err(_) => {
+                return Err(OnlineProbeError {
+                    reason_code: "probe_online_email_timeout",
+                    retryable: true,
+                })
+            }
+        };
+        // IMAP LOGIN exercises the same credentials used for ingress polling.
+        if session.login(username, password).await.is_err() {
+            return Err(OnlineProbeError {
+                reason_code: "probe_online_email_auth_failed",
+                retryable: false,
+            });
+        }
+        session.logout().await;
+        Ok(())
+    })
+}
+
 fn classify_transport_error(
     error: &reqwest::Error,
     timeout_reason_code: &'static str,
@@ -802,6 +931,31 @@ fn classify_whatsapp_status(status: StatusCode, payload: &Value) -> OnlineProbeE
     }
 }
 
+fn classify_matrix_status(status: StatusCode) -> OnlineProbeError {
+    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+        return OnlineProbeError {
+            reason_code: "probe_online_matrix_auth_failed",
+            retryable: false,
+        };
+    }
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return OnlineProbeError {
+            reason_code: "probe_online_matrix_rate_limited",
+            retryable: true,
+        };
+    }
+    if status.is_server_error() {
+        return OnlineProbeError {
+            reason_code: "probe_online_matrix_provider_unavailable",
+            retryable: true,
+        };
+    }
+    OnlineProbeError {
+        reason_code: "probe_online_matrix_request_rejected",
+        retryable: false,
+    }
+}
+
 fn append_unique_reason_codes(target: &mut Vec<String>, additions: &[String]) {
     for reason in additions {
         if !target.iter().any(|existing| existing == reason) {
@@ -845,6 +999,18 @@ fn remediation_hint_for_reason_code(
         "missing_whatsapp_phone_number_id" => Some(
             "Set TAU_WHATSAPP_PHONE_NUMBER_ID or configure credential-store integration whatsapp-phone-number-id.",
         ),
+        "missing_matrix_access_token" => Some(
+            "Set TAU_MATRIX_ACCESS_TOKEN or configure credential-store integration matrix-access-token.",
+        ),
+        "missing_email_password" => Some(
+            "Set TAU_EMAIL_PASSWORD or configure credential-store integration email-password.",
+        ),
+        "missing_email_address" => {
+            Some("Set --multi-channel-email-address to the mailbox the agent answers from.")
+        }
+        "missing_email_imap_host" => {
+            Some("Set --multi-channel-email-imap-host to the IMAP server for the agent mailbox.")
+        }
         "credential_store_unreadable" => Some(
             "Verify credential-store path/encryption key or pass credentials directly via CLI/env.",
         ),
@@ -858,6 +1024,12 @@ fn remediation_hint_for_reason_code(
             MultiChannelTransport::Whatsapp => {
                 "Run --multi-channel-channel-login whatsapp to initialize ingress state."
             }
+            MultiChannelTransport::Matrix => {
+                "Run --multi-channel-channel-login matrix to initialize ingress state."
+            }
+            MultiChannelTransport::Email => {
+                "Run --multi-channel-channel-login email to initialize ingress state."
+            }
         }),
         "ingress_not_file" => Some(
             "Ensure the ingress path is a writable .ndjson file and rerun login/probe.",
@@ -917,6 +1089,30 @@ fn remediation_hint_for_reason_code(
         "probe_online_whatsapp_request_rejected" | "probe_online_whatsapp_invalid_response" => {
             Some("Verify WhatsApp API base URL, token scope, and phone id configuration.")
         }
+        "probe_online_matrix_auth_failed" => {
+            Some("Rotate the Matrix access token and confirm the bot account is not deactivated.")
+        }
+        "probe_online_matrix_rate_limited" => {
+            Some("Matrix homeserver rate limited the probe; retry after the backoff window.")
+        }
+        "probe_online_matrix_provider_unavailable" => {
+            Some("Matrix homeserver is unavailable; retry probe later.")
+        }
+        "probe_online_matrix_transport_error" | "probe_online_matrix_timeout" => {
+            Some("Check network reachability to the Matrix homeserver.")
+        }
+        "probe_online_matrix_request_rejected" | "probe_online_matrix_invalid_response" => {
+            Some("Verify the Matrix homeserver URL and access token, then retry probe.")
+        }
+        "probe_online_email_auth_failed" => {
+            Some("Verify the email username and password (or app password) for IMAP login.")
+        }
+        "probe_online_email_transport_error" | "probe_online_email_timeout" => {
+            Some("Check IMAP host, port and TLS mode, and network reachability to the mail server.")
+        }
+        "probe_online_email_runtime_unavailable" => {
+            Some("Check local runtime environment and retry online probe.")
+        }
         _ => None,
     }
 }
@@ -994,6 +1190,7 @@ mod tests {
     use crate::multi_channel_credentials::{
         MultiChannelCredentialRecord, MultiChannelCredentialStoreSnapshot,
     };
+    use crate::multi_channel_email::MultiChannelEmailAccountConfig;
     use std::collections::BTreeMap;
     use std::path::Path;
     use tempfile::tempdir;
@@ -1005,12 +1202,15 @@ mod tests {
             telegram_api_base: "https://api.telegram.org".to_string(),
             discord_api_base: "https://discord.com/api/v10".to_string(),
             whatsapp_api_base: "https://graph.facebook.com/v20.0".to_string(),
+            matrix_homeserver_url: "https://matrix.org".to_string(),
             credential_store: None,
             credential_store_unreadable: false,
             telegram_bot_token: None,
             discord_bot_token: None,
             whatsapp_access_token: None,
             whatsapp_phone_number_id: None,
+            matrix_access_token: None,
+            email: MultiChannelEmailAccountConfig::default(),
             probe_online: false,
             probe_online_timeout_ms: 500,
             probe_online_max_attempts: 2,
@@ -1032,6 +1232,35 @@ mod tests {
         assert!(report.reason_codes.contains(&"ingress_missing".to_string()));
     }
 
+    #[test]
+    fn unit_probe_channel_readiness_reports_missing_email_account_settings() {
+        let temp = tempdir().expect("tempdir");
+        let mut config = test_config(temp.path());
+        let report = probe_channel_readiness(&config, MultiChannelTransport::Email, false, false);
+        assert_eq!(report.readiness_status, "fail");
+        for reason in [
+            "missing_email_password",
+            "missing_email_address",
+            "missing_email_imap_host",
+        ] {
+            assert!(
+                report.reason_codes.contains(&reason.to_string()),
+                "{reason}"
+            );
+        }
+        assert!(report
+            .remediation_hints
+            .iter()
+            .any(|hint| hint.contains("TAU_EMAIL_PASSWORD")));
+
+        config.email.address = Some("agent@example.org".to_string());
+        config.email.password = Some("secret".to_string());
+        config.email.imap_host = "imap.example.org".to_string();
+        let report = probe_channel_readiness(&config, MultiChannelTransport::Email, false, false);
+        assert_eq!(report.readiness_status, "pass");
+        assert_eq!(report.token_source, "cli_or_env");
+    }
+
     #[test]
     fn unit_probe_channel_readiness_online_mode_requires_credentials() {
         let temp = tempdir().expect("tempdir");
@@ -1081,6 +1310,37 @@ mod tests {
             .contains(&"probe_online_ready".to_string()));
     }
 
+    #[test]
+    fn functional_probe_action_online_matrix_uses_whoami_with_bearer_token() {
+        let temp = tempdir().expect("tempdir");
+        let server = MockServer::start();
+        let whoami = server.mock(|when, then| {
+            when.method(GET)
+                .path("/_matrix/client/v3/account/whoami")
+                .header("authorization", "Bearer matrix-secret");
+            then.status(200)
+                .json_body(json!({"user_id": "@tau:example.org"}));
+        });
+
+        let mut config = test_config(temp.path());
+        config.probe_online = true;
+        config.matrix_access_token = Some("matrix-secret".to_string());
+        config.matrix_homeserver_url = server.base_url();
+        std::fs::create_dir_all(&config.ingress_dir).expect("mkdir ingress");
+        std::fs::write(config.ingress_dir.join("matrix.ndjson"), "").expect("write ingress");
+
+        let report = execute_multi_channel_lifecycle_action(
+            &config,
+            MultiChannelLifecycleAction::Probe,
+            MultiChannelTransport::Matrix,
+        )
+        .expect("online probe");
+        whoami.assert_calls(1);
+        assert_eq!(report.readiness_status, "pass");
+        assert_eq!(report.online_probe_status, "pass");
+        assert_eq!(report.phone_number_source, "not_required");
+    }
+
     #[test]
     fn integration_probe_action_online_persists_state_with_online_diagnostics() {
         let temp = tempdir().expect("tempdir");
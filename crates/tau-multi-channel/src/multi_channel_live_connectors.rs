@@ -4,7 +4,7 @@
 //! and emit structured diagnostics for delivery failures. This boundary isolates
 //! transport-specific HTTP concerns from core runtime orchestration.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -25,16 +25,29 @@ use sha2::Sha256;
 use tokio::net::TcpListener;
 use tokio::sync::Mutex;
 
-use crate::multi_channel_contract::{event_contract_key, MultiChannelTransport};
+use crate::multi_channel_contract::{
+    event_contract_key, MultiChannelEventKind, MultiChannelInboundEvent, MultiChannelTransport,
+    MULTI_CHANNEL_CONTRACT_SCHEMA_VERSION,
+};
+use crate::multi_channel_email::{
+    build_email_ingress_payload, is_email_loop_candidate, parse_email_message, EmailIngressContext,
+    ImapSession, MultiChannelEmailAccountConfig,
+};
 use crate::multi_channel_live_ingress::{
     build_multi_channel_live_envelope_from_raw_payload, default_multi_channel_live_provider_label,
     parse_multi_channel_live_inbound_envelope_value, MultiChannelLiveInboundEnvelope,
 };
+use crate::multi_channel_outbound::percent_encode_path_segment;
+use crate::multi_channel_policy::{
+    evaluate_multi_channel_channel_policy, load_multi_channel_policy_file,
+    MultiChannelConversationKind, MultiChannelDmPolicy, MultiChannelGroupPolicy,
+};
 use tau_core::current_unix_timestamp_ms;
 
 const LIVE_CONNECTORS_SCHEMA_VERSION: u32 = 1;
 const MAX_POLL_BATCH_SIZE: usize = 50;
 const DISCORD_FIRST_POLL_BACKFILL_LIMIT: usize = 100;
+const EMAIL_IMAP_COMMAND_TIMEOUT_MS: u64 = 30_000;
 const CONNECTOR_BREAKER_STATE_CLOSED: &str = "closed";
 const CONNECTOR_BREAKER_STATE_OPEN: &str = "open";
 const CONNECTOR_BREAKER_STATE_HALF_OPEN: &str = "half_open";
@@ -178,6 +191,14 @@ pub struct MultiChannelLiveConnectorStateFile {
     #[serde(default)]
     pub discord_last_message_ids: BTreeMap<String, String>,
     #[serde(default)]
+    pub matrix_next_batch: Option<String>,
+    #[serde(default)]
+    pub matrix_direct_room_ids: BTreeSet<String>,
+    #[serde(default)]
+    pub email_uid_validity: Option<u64>,
+    #[serde(default)]
+    pub email_last_uid: Option<u64>,
+    #[serde(default)]
     pub channels: BTreeMap<String, MultiChannelLiveConnectorChannelState>,
 }
 
@@ -188,6 +209,10 @@ impl Default for MultiChannelLiveConnectorStateFile {
             processed_event_keys: Vec::new(),
             telegram_next_update_offset: None,
             discord_last_message_ids: BTreeMap::new(),
+            matrix_next_batch: None,
+            matrix_direct_room_ids: BTreeSet::new(),
+            email_uid_validity: None,
+            email_last_uid: None,
             channels: BTreeMap::new(),
         }
     }
@@ -236,6 +261,16 @@ pub struct MultiChannelLiveConnectorsConfig {
     pub whatsapp_mode: MultiChannelLiveConnectorMode,
     pub whatsapp_webhook_verify_token: Option<String>,
     pub whatsapp_webhook_app_secret: Option<String>,
+    pub matrix_mode: MultiChannelLiveConnectorMode,
+    pub matrix_homeserver_url: String,
+    pub matrix_access_token: Option<String>,
+    pub matrix_user_id: Option<String>,
+    pub matrix_auto_join_invites: bool,
+    pub email_mode: MultiChannelLiveConnectorMode,
+    pub email: MultiChannelEmailAccountConfig,
+    pub email_idle_timeout_ms: u64,
+    pub email_trusted_authserv_id: Option<String>,
+    pub channel_policy_path: PathBuf,
 }
 
 #[derive(Debug, Clone)]
@@ -390,6 +425,12 @@ async fn run_multi_channel_live_connectors_poll_cycle(
     if config.discord_mode.is_polling() {
         let _ = poll_discord_messages(config, client, state, &mut summary).await;
     }
+    if config.matrix_mode.is_polling() {
+        let _ = poll_matrix_sync(config, client, state, &mut summary).await;
+    }
+    if config.email_mode.is_polling() {
+        let _ = poll_email_imap(config, state, &mut summary).await;
+    }
     update_channel_liveness(state);
     summary
 }
@@ -874,6 +915,552 @@ async fn poll_discord_messages(
     Ok(())
 }
 
+async fn poll_matrix_sync(
+    config: &MultiChannelLiveConnectorsConfig,
+    client: &Client,
+    state: &mut MultiChannelLiveConnectorStateFile,
+    summary: &mut MultiChannelLiveConnectorCycleSummary,
+) -> Result<()> {
+    if !begin_channel_poll(config, state, "matrix") {
+        return Ok(());
+    }
+    let token = config
+        .matrix_access_token
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| anyhow!("missing matrix access token for polling mode"))?;
+    let homeserver = config.matrix_homeserver_url.trim().trim_end_matches('/');
+    if homeserver.is_empty() {
+        bail!("matrix homeserver url cannot be empty");
+    }
+    let auth_header = format!("Bearer {token}");
+
+    let bot_user_id =
+        match resolve_matrix_bot_user_id(config, client, summary, homeserver, &auth_header).await {
+            Ok(user_id) => user_id,
+            Err(error) => {
+                record_channel_error(
+                    config,
+                    state,
+                    "matrix",
+                    error.code,
+                    error.message,
+                    error.retryable,
+                );
+                return Ok(());
+            }
+        };
+
+    let url = format!("{homeserver}/_matrix/client/v3/sync");
+    let filter = json!({"room": {"timeline": {"limit": MAX_POLL_BATCH_SIZE}}}).to_string();
+    let since = state.matrix_next_batch.clone();
+    let response = request_json_with_retry(
+        config.retry_max_attempts,
+        config.retry_base_delay_ms,
+        summary,
+        "matrix",
+        || {
+            let request = client
+                .get(url.as_str())
+                .query(&[("timeout", "0"), ("filter", filter.as_str())])
+                .header("authorization", auth_header.as_str());
+            match since.as_deref() {
+                Some(since) => request.query(&[("since", since)]),
+                None => request,
+            }
+        },
+    )
+    .await;
+    let response = match response {
+        Ok(response) => response,
+        Err(error) => {
+            record_channel_error(
+                config,
+                state,
+                "matrix",
+                error.code,
+                error.message,
+                error.retryable,
+            );
+            return Ok(());
+        }
+    };
+    let next_batch = response
+        .get("next_batch")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("matrix sync response missing next_batch"))?;
+
+    let account_events = response
+        .pointer("/account_data/events")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for event in &account_events {
+        if event.get("type").and_then(Value::as_str) != Some("m.direct") {
+            continue;
+        }
+        let Some(direct) = event.get("content").and_then(Value::as_object) else {
+            continue;
+        };
+        for room_ids in direct.values().filter_map(Value::as_array) {
+            state.matrix_direct_room_ids.extend(
+                room_ids
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string),
+            );
+        }
+    }
+    if let Some(left_rooms) = response.pointer("/rooms/leave").and_then(Value::as_object) {
+        for room_id in left_rooms.keys() {
+            state.matrix_direct_room_ids.remove(room_id);
+        }
+    }
+
+    if let Some(invited_rooms) = response.pointer("/rooms/invite").and_then(Value::as_object) {
+        for (room_id, invite) in invited_rooms {
+            handle_matrix_invite(
+                config,
+                client,
+                state,
+                summary,
+                homeserver,
+                &auth_header,
+                &bot_user_id,
+                room_id,
+                invite,
+            )
+            .await;
+        }
+    }
+
+    if let Some(joined_rooms) = response.pointer("/rooms/join").and_then(Value::as_object) {
+        for (room_id, room) in joined_rooms {
+            let events = room
+                .pointer("/timeline/events")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            let room_kind = if state.matrix_direct_room_ids.contains(room_id) {
+                "direct"
+            } else {
+                "group"
+            };
+            for event in &events {
+                if !is_ingestible_matrix_event(event, &bot_user_id) {
+                    continue;
+                }
+                let payload = json!({
+                    "room_id": room_id,
+                    "room_kind": room_kind,
+                    "homeserver_url": homeserver,
+                    "bot_user_id": bot_user_id,
+                    "event": event,
+                });
+                let raw = serde_json::to_string(&payload).context("encode matrix event payload")?;
+                match ingest_raw_payload(
+                    config,
+                    state,
+                    MultiChannelTransport::Matrix,
+                    default_multi_channel_live_provider_label(MultiChannelTransport::Matrix),
+                    raw.as_str(),
+                ) {
+                    Ok((_, duplicate)) => {
+                        if duplicate {
+                            summary.duplicate_events = summary.duplicate_events.saturating_add(1);
+                        } else {
+                            summary.ingested_events = summary.ingested_events.saturating_add(1);
+                        }
+                    }
+                    Err(error) => {
+                        record_channel_error(
+                            config,
+                            state,
+                            "matrix",
+                            error.code,
+                            error.message,
+                            false,
+                        );
+                        summary.parse_failures = summary.parse_failures.saturating_add(1);
+                    }
+                }
+            }
+        }
+    }
+
+    state.matrix_next_batch = Some(next_batch);
+    record_channel_success(config, state, "matrix");
+    Ok(())
+}
+
+async fn resolve_matrix_bot_user_id(
+    config: &MultiChannelLiveConnectorsConfig,
+    client: &Client,
+    summary: &mut MultiChannelLiveConnectorCycleSummary,
+    homeserver: &str,
+    auth_header: &str,
+) -> Result<String, ConnectorError> {
+    if let Some(user_id) = config
+        .matrix_user_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        return Ok(user_id.to_string());
+    }
+    let url = format!("{homeserver}/_matrix/client/v3/account/whoami");
+    let response = request_json_with_retry(
+        config.retry_max_attempts,
+        config.retry_base_delay_ms,
+        summary,
+        "matrix",
+        || {
+            client
+                .get(url.as_str())
+                .header("authorization", auth_header)
+        },
+    )
+    .await?;
+    response
+        .get("user_id")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            ConnectorError::new(
+                MultiChannelLiveConnectorErrorCode::ParseFailed,
+                "matrix whoami response missing user_id",
+                false,
+            )
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_matrix_invite(
+    config: &MultiChannelLiveConnectorsConfig,
+    client: &Client,
+    state: &mut MultiChannelLiveConnectorStateFile,
+    summary: &mut MultiChannelLiveConnectorCycleSummary,
+    homeserver: &str,
+    auth_header: &str,
+    bot_user_id: &str,
+    room_id: &str,
+    invite: &Value,
+) {
+    if !config.matrix_auto_join_invites {
+        return;
+    }
+    let membership = invite
+        .pointer("/invite_state/events")
+        .and_then(Value::as_array)
+        .and_then(|events| {
+            events.iter().find(|event| {
+                event.get("type").and_then(Value::as_str) == Some("m.room.member")
+                    && event.get("state_key").and_then(Value::as_str) == Some(bot_user_id)
+            })
+        });
+    let is_direct = membership
+        .and_then(|event| event.pointer("/content/is_direct"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let inviter = membership
+        .and_then(|event| event.get("sender"))
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+
+    let accept = match load_multi_channel_policy_file(&config.channel_policy_path) {
+        Ok(policy_file) => {
+            let mut metadata = BTreeMap::new();
+            metadata.insert(
+                "conversation_mode".to_string(),
+                Value::String(if is_direct { "dm" } else { "group" }.to_string()),
+            );
+            let invite_event = MultiChannelInboundEvent {
+                schema_version: MULTI_CHANNEL_CONTRACT_SCHEMA_VERSION,
+                transport: MultiChannelTransport::Matrix,
+                event_kind: MultiChannelEventKind::System,
+                event_id: format!("invite:{room_id}"),
+                conversation_id: room_id.to_string(),
+                thread_id: String::new(),
+                actor_id: inviter,
+                actor_display: String::new(),
+                timestamp_ms: current_unix_timestamp_ms(),
+                text: String::new(),
+                attachments: Vec::new(),
+                metadata,
+            };
+            // Mention and allowlist gates apply to messages, not to joining.
+            let evaluation = evaluate_multi_channel_channel_policy(&policy_file, &invite_event);
+            match evaluation.conversation_kind {
+                MultiChannelConversationKind::Dm => {
+                    evaluation.policy.dm_policy != MultiChannelDmPolicy::Deny
+                }
+                MultiChannelConversationKind::Group => {
+                    evaluation.policy.group_policy != MultiChannelGroupPolicy::Deny
+                }
+            }
+        }
+        Err(error) => {
+            record_channel_error(
+                config,
+                state,
+                "matrix",
+                MultiChannelLiveConnectorErrorCode::MissingConfig,
+                format!("matrix invite for {room_id} skipped: {error:#}"),
+                false,
+            );
+            return;
+        }
+    };
+
+    let action = if accept { "join" } else { "leave" };
+    let url = format!(
+        "{homeserver}/_matrix/client/v3/rooms/{}/{action}",
+        percent_encode_path_segment(room_id)
+    );
+    let response = request_json_with_retry(
+        config.retry_max_attempts,
+        config.retry_base_delay_ms,
+        summary,
+        "matrix",
+        || {
+            client
+                .post(url.as_str())
+                .header("authorization", auth_header)
+                .json(&json!({}))
+        },
+    )
+    .await;
+    match response {
+        Ok(_) => {
+            if accept && is_direct {
+                state.matrix_direct_room_ids.insert(room_id.to_string());
+            }
+        }
+        Err(error) => {
+            record_channel_error(
+                config,
+                state,
+                "matrix",
+                error.code,
+                format!(
+                    "matrix invite {action} for {room_id} failed: {}",
+                    error.message
+                ),
+                error.retryable,
+            );
+        }
+    }
+}
+
+fn is_ingestible_matrix_event(event: &Value, bot_user_id: &str) -> bool {
+    if event.get("type").and_then(Value::as_str) != Some("m.room.message") {
+        return false;
+    }
+    if event.get("sender").and_then(Value::as_str) == Some(bot_user_id) {
+        return false;
+    }
+    // Redacted events keep their type but lose their content.
+    event
+        .get("content")
+        .and_then(Value::as_object)
+        .map(|content| content.contains_key("body") || content.contains_key("m.new_content"))
+        .unwrap_or(false)
+}
+
+async fn poll_email_imap(
+    config: &MultiChannelLiveConnectorsConfig,
+    state: &mut MultiChannelLiveConnectorStateFile,
+    summary: &mut MultiChannelLiveConnectorCycleSummary,
+) -> Result<()> {
+    if !begin_channel_poll(config, state, "email") {
+        return Ok(());
+    }
+    let account = &config.email;
+    let username = account
+        .resolved_username()
+        .ok_or_else(|| anyhow!("missing email address for polling mode"))?;
+    let password = account
+        .resolved_password()
+        .ok_or_else(|| anyhow!("missing email password for polling mode"))?;
+    if account.imap_host.trim().is_empty() {
+        bail!("email imap host cannot be empty");
+    }
+    let timeout = Duration::from_millis(EMAIL_IMAP_COMMAND_TIMEOUT_MS);
+
+    let max_attempts = config.retry_max_attempts.max(1);
+    let mut attempt = 0usize;
+    let mut session = loop {
+        attempt = attempt.saturating_add(1);
+        match ImapSession::connect(account, timeout).await {
+            Ok(session) => break session,
+            Err(_) if attempt < max_attempts => {
+                summary.retry_attempts = summary.retry_attempts.saturating_add(1);
+                sleep_retry_backoff(config.retry_base_delay_ms, attempt).await;
+            }
+            Err(error) => {
+                summary.provider_failures = summary.provider_failures.saturating_add(1);
+                record_channel_error(
+                    config,
+                    state,
+                    "email",
+                    MultiChannelLiveConnectorErrorCode::TransportError,
+                    format!("email transport error: {error:#}"),
+                    true,
+                );
+                return Ok(());
+            }
+        }
+    };
+    if let Err(error) = session.login(username, password).await {
+        summary.auth_failures = summary.auth_failures.saturating_add(1);
+        record_channel_error(
+            config,
+            state,
+            "email",
+            MultiChannelLiveConnectorErrorCode::AuthFailed,
+            format!("email login failed: {error:#}"),
+            false,
+        );
+        return Ok(());
+    }
+
+    let result = sync_email_mailbox(config, state, summary, &mut session).await;
+    session.logout().await;
+    match result {
+        Ok(()) => record_channel_success(config, state, "email"),
+        Err(error) => {
+            summary.provider_failures = summary.provider_failures.saturating_add(1);
+            record_channel_error(
+                config,
+                state,
+                "email",
+                error.code,
+                error.message,
+                error.retryable,
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn sync_email_mailbox(
+    config: &MultiChannelLiveConnectorsConfig,
+    state: &mut MultiChannelLiveConnectorStateFile,
+    summary: &mut MultiChannelLiveConnectorCycleSummary,
+    session: &mut ImapSession,
+) -> Result<(), ConnectorError> {
+    let provider_error = |error: anyhow::Error| {
+        ConnectorError::new(
+            MultiChannelLiveConnectorErrorCode::ProviderUnavailable,
+            format!("email provider error: {error:#}"),
+            true,
+        )
+    };
+    let account = &config.email;
+    let mailbox = account.resolved_mailbox();
+    let status = session.select(mailbox).await.map_err(provider_error)?;
+
+    // The first sync, or a UIDVALIDITY reset, only records the newest UID so
+    // that existing mail is never answered retroactively.
+    if state.email_uid_validity != Some(status.uid_validity) || state.email_last_uid.is_none() {
+        let uids = session.search_uids(None).await.map_err(provider_error)?;
+        state.email_uid_validity = Some(status.uid_validity);
+        state.email_last_uid = Some(uids.last().copied().unwrap_or(0));
+        return Ok(());
+    }
+
+    let last_uid = state.email_last_uid.unwrap_or(0);
+    let mut uids = session
+        .search_uids(Some(last_uid.saturating_add(1)))
+        .await
+        .map_err(provider_error)?;
+    if uids.is_empty() && config.email_idle_timeout_ms > 0 && session.supports_idle() {
+        let announced = session
+            .idle(Duration::from_millis(config.email_idle_timeout_ms))
+            .await
+            .map_err(provider_error)?;
+        if announced {
+            uids = session
+                .search_uids(Some(last_uid.saturating_add(1)))
+                .await
+                .map_err(provider_error)?;
+        }
+    }
+    uids.truncate(MAX_POLL_BATCH_SIZE);
+
+    let own_address = account.resolved_address().unwrap_or_default();
+    let received_unix_ms = current_unix_timestamp_ms();
+    for uid in uids {
+        let raw_message = session.fetch_message(uid).await.map_err(provider_error)?;
+        state.email_last_uid = Some(uid);
+        let Some(raw_message) = raw_message else {
+            continue;
+        };
+        let message = match parse_email_message(&raw_message) {
+            Ok(message) => message,
+            Err(error) => {
+                record_channel_error(
+                    config,
+                    state,
+                    "email",
+                    MultiChannelLiveConnectorErrorCode::ParseFailed,
+                    format!("failed to parse email uid {uid}: {error:#}"),
+                    false,
+                );
+                summary.parse_failures = summary.parse_failures.saturating_add(1);
+                continue;
+            }
+        };
+        if is_email_loop_candidate(&message, own_address) {
+            continue;
+        }
+        let payload = build_email_ingress_payload(
+            &message,
+            &EmailIngressContext {
+                account: own_address,
+                imap_host: account.imap_host.trim(),
+                mailbox,
+                uid,
+                uid_validity: status.uid_validity,
+                trusted_authserv_id: config.email_trusted_authserv_id.as_deref(),
+                received_unix_ms,
+            },
+        );
+        let raw = serde_json::to_string(&payload).map_err(|error| {
+            ConnectorError::new(
+                MultiChannelLiveConnectorErrorCode::ParseFailed,
+                format!("failed to encode email payload: {error}"),
+                false,
+            )
+        })?;
+        match ingest_raw_payload(
+            config,
+            state,
+            MultiChannelTransport::Email,
+            default_multi_channel_live_provider_label(MultiChannelTransport::Email),
+            raw.as_str(),
+        ) {
+            Ok((_, duplicate)) => {
+                if duplicate {
+                    summary.duplicate_events = summary.duplicate_events.saturating_add(1);
+                } else {
+                    summary.ingested_events = summary.ingested_events.saturating_add(1);
+                }
+            }
+            Err(error) => {
+                record_channel_error(config, state, "email", error.code, error.message, false);
+                summary.parse_failures = summary.parse_failures.saturating_add(1);
+            }
+        }
+    }
+    Ok(())
+}
+
 async fn request_json_with_retry<F>(
     retry_max_attempts: usize,
     retry_base_delay_ms: u64,
@@ -966,6 +1553,8 @@ fn initialize_channel_modes(
         ("telegram", config.telegram_mode),
         ("discord", config.discord_mode),
         ("whatsapp", config.whatsapp_mode),
+        ("matrix", config.matrix_mode),
+        ("email", config.email_mode),
     ] {
         let entry = state.channels.entry(channel.to_string()).or_default();
         entry.mode = mode.as_str().to_string();
@@ -1238,6 +1827,8 @@ fn transport_file_name(transport: MultiChannelTransport) -> &'static str {
         MultiChannelTransport::Telegram => "telegram.ndjson",
         MultiChannelTransport::Discord => "discord.ndjson",
         MultiChannelTransport::Whatsapp => "whatsapp.ndjson",
+        MultiChannelTransport::Matrix => "matrix.ndjson",
+        MultiChannelTransport::Email => "email.ndjson",
     }
 }
 
@@ -1370,6 +1961,16 @@ mod tests {
             whatsapp_mode: MultiChannelLiveConnectorMode::Disabled,
             whatsapp_webhook_verify_token: None,
             whatsapp_webhook_app_secret: None,
+            matrix_mode: MultiChannelLiveConnectorMode::Disabled,
+            matrix_homeserver_url: "https://matrix.org".to_string(),
+            matrix_access_token: None,
+            matrix_user_id: None,
+            matrix_auto_join_invites: false,
+            email_mode: MultiChannelLiveConnectorMode::Disabled,
+            email: MultiChannelEmailAccountConfig::default(),
+            email_idle_timeout_ms: 0,
+            email_trusted_authserv_id: None,
+            channel_policy_path: temp.join("security").join("channel-policy.json"),
         }
     }
 
@@ -1788,6 +2389,323 @@ mod tests {
         second_run_mock.assert_calls(1);
     }
 
+    #[tokio::test]
+    async fn integration_matrix_sync_joins_allowed_invites_and_ingests_room_messages() {
+        let temp = tempdir().expect("tempdir");
+        let server = MockServer::start();
+        let whoami_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/_matrix/client/v3/account/whoami")
+                .header("authorization", "Bearer matrix-token");
+            then.status(200)
+                .body(json!({"user_id":"@tau:example.org"}).to_string());
+        });
+        let first_sync_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/_matrix/client/v3/sync")
+                .query_param("timeout", "0")
+                .query_param_missing("since")
+                .header("authorization", "Bearer matrix-token");
+            then.status(200).body(
+                json!({
+                    "next_batch": "s1",
+                    "account_data": {"events": [{
+                        "type": "m.direct",
+                        "content": {"@bob:example.org": ["!direct:example.org"]}
+                    }]},
+                    "rooms": {
+                        "invite": {
+                            "!dm-invite:example.org": {"invite_state": {"events": [{
+                                "type": "m.room.member",
+                                "state_key": "@tau:example.org",
+                                "sender": "@carol:example.org",
+                                "content": {"membership": "invite", "is_direct": true}
+                            }]}},
+                            "!group-invite:example.org": {"invite_state": {"events": [{
+                                "type": "m.room.member",
+                                "state_key": "@tau:example.org",
+                                "sender": "@mallory:example.org",
+                                "content": {"membership": "invite"}
+                            }]}}
+                        },
+                        "join": {
+                            "!ops:example.org": {"timeline": {"events": [
+                                {
+                                    "type": "m.room.member",
+                                    "event_id": "$member-1",
+                                    "sender": "@alice:example.org",
+                                    "origin_server_ts": 1760300000000u64,
+                                    "content": {"membership": "join"}
+                                },
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$ops-1",
+                                    "sender": "@alice:example.org",
+                                    "origin_server_ts": 1760300000100u64,
+                                    "content": {"msgtype": "m.text", "body": "hello ops"}
+                                },
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$ops-2",
+                                    "sender": "@tau:example.org",
+                                    "origin_server_ts": 1760300000200u64,
+                                    "content": {"msgtype": "m.text", "body": "bot echo"}
+                                },
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$ops-3",
+                                    "sender": "@alice:example.org",
+                                    "origin_server_ts": 1760300000300u64,
+                                    "content": {}
+                                }
+                            ]}},
+                            "!direct:example.org": {"timeline": {"events": [{
+                                "type": "m.room.message",
+                                "event_id": "$direct-1",
+                                "sender": "@bob:example.org",
+                                "origin_server_ts": 1760300000400u64,
+                                "content": {"msgtype": "m.text", "body": "hello dm"}
+                            }]}}
+                        }
+                    }
+                })
+                .to_string(),
+            );
+        });
+        let second_sync_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/_matrix/client/v3/sync")
+                .query_param("since", "s1");
+            then.status(200)
+                .body(json!({"next_batch": "s2"}).to_string());
+        });
+        let join_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/_matrix/client/v3/rooms/%21dm-invite%3Aexample.org/join")
+                .header("authorization", "Bearer matrix-token");
+            then.status(200)
+                .body(json!({"room_id":"!dm-invite:example.org"}).to_string());
+        });
+        let leave_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/_matrix/client/v3/rooms/%21group-invite%3Aexample.org/leave")
+                .header("authorization", "Bearer matrix-token");
+            then.status(200).body("{}");
+        });
+
+        let mut config = build_connector_config(temp.path());
+        config.matrix_mode = MultiChannelLiveConnectorMode::Polling;
+        config.matrix_homeserver_url = server.base_url();
+        config.matrix_access_token = Some("matrix-token".to_string());
+        config.matrix_auto_join_invites = true;
+        std::fs::create_dir_all(config.channel_policy_path.parent().expect("policy parent"))
+            .expect("create policy dir");
+        std::fs::write(
+            &config.channel_policy_path,
+            json!({
+                "schema_version": 1,
+                "channels": {"matrix:*": {"groupPolicy": "deny"}}
+            })
+            .to_string(),
+        )
+        .expect("write policy");
+
+        run_multi_channel_live_connectors_runner(config.clone())
+            .await
+            .expect("first sync should succeed");
+        run_multi_channel_live_connectors_runner(config.clone())
+            .await
+            .expect("second sync should succeed");
+
+        let matrix_lines = read_ndjson(&config.ingress_dir.join("matrix.ndjson"));
+        assert_eq!(matrix_lines.len(), 2);
+        assert_eq!(matrix_lines[0]["transport"].as_str(), Some("matrix"));
+        let room_kinds = matrix_lines
+            .iter()
+            .map(|line| {
+                (
+                    line["payload"]["room_id"].as_str().unwrap_or_default(),
+                    line["payload"]["room_kind"].as_str().unwrap_or_default(),
+                )
+            })
+            .collect::<BTreeSet<_>>();
+        assert!(room_kinds.contains(&("!ops:example.org", "group")));
+        assert!(room_kinds.contains(&("!direct:example.org", "direct")));
+
+        let state = load_multi_channel_live_connectors_state(&config.state_path)
+            .expect("load connector state");
+        assert_eq!(state.matrix_next_batch.as_deref(), Some("s2"));
+        assert!(state
+            .matrix_direct_room_ids
+            .contains("!dm-invite:example.org"));
+        assert_eq!(
+            state
+                .channels
+                .get("matrix")
+                .map(|entry| entry.consecutive_failures),
+            Some(0)
+        );
+
+        whoami_mock.assert_calls(2);
+        first_sync_mock.assert_calls(1);
+        second_sync_mock.assert_calls(1);
+        join_mock.assert_calls(1);
+        leave_mock.assert_calls(1);
+    }
+
+    async fn spawn_imap_stand_in(
+        messages: Arc<std::sync::Mutex<Vec<(u64, String)>>>,
+    ) -> std::net::SocketAddr {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind imap");
+        let addr = listener.local_addr().expect("imap addr");
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let messages = messages.clone();
+                tokio::spawn(async move {
+                    let (reader, mut writer) = socket.into_split();
+                    let mut reader = BufReader::new(reader);
+                    writer.write_all(b"* OK stand-in ready\r\n").await.ok();
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).await.unwrap_or(0) > 0 {
+                        let (tag, command) = line.trim_end().split_once(' ').unwrap_or_default();
+                        let snapshot = messages.lock().expect("messages").clone();
+                        let max_uid = snapshot.iter().map(|(uid, _)| *uid).max().unwrap_or(0);
+                        let untagged = if command.starts_with("CAPABILITY") {
+                            "* CAPABILITY IMAP4rev1 IDLE\r\n".to_string()
+                        } else if command.starts_with("SELECT") {
+                            format!("* {} EXISTS\r\n* OK [UIDVALIDITY 7] ok\r\n", snapshot.len())
+                        } else if command == "UID SEARCH ALL" {
+                            let uids = snapshot.iter().map(|(uid, _)| format!(" {uid}"));
+                            format!("* SEARCH{}\r\n", uids.collect::<String>())
+                        } else if let Some(range) = command.strip_prefix("UID SEARCH UID ") {
+                            let from = range.trim_end_matches(":*").parse::<u64>().expect("uid");
+                            let mut uids = snapshot
+                                .iter()
+                                .map(|(uid, _)| *uid)
+                                .filter(|uid| *uid >= from)
+                                .collect::<Vec<_>>();
+                            if uids.is_empty() && max_uid > 0 {
+                                // RFC 3501: `n:*` still matches the highest UID.
+                                uids.push(max_uid);
+                            }
+                            let uids = uids.iter().map(|uid| format!(" {uid}"));
+                            format!("* SEARCH{}\r\n", uids.collect::<String>())
+                        } else if let Some(rest) = command.strip_prefix("UID FETCH ") {
+                            let uid = rest.split(' ').next().unwrap_or_default();
+                            let uid = uid.parse::<u64>().expect("fetch uid");
+                            let raw = snapshot
+                                .iter()
+                                .find(|(candidate, _)| *candidate == uid)
+                                .map(|(_, raw)| raw.clone())
+                                .unwrap_or_default();
+                            format!(
+                                "* 1 FETCH (UID {uid} BODY[] {{{}}}\r\n{raw})\r\n",
+                                raw.len()
+                            )
+                        } else if command.starts_with("LOGOUT") {
+                            "* BYE\r\n".to_string()
+                        } else {
+                            String::new()
+                        };
+                        let response = format!("{untagged}{tag} OK done\r\n");
+                        if writer.write_all(response.as_bytes()).await.is_err() {
+                            break;
+                        }
+                        line.clear();
+                    }
+                });
+            }
+        });
+        addr
+    }
+
+    fn email_raw(message_id: &str, from: &str, extra_headers: &str, body: &str) -> String {
+        format!(
+            "From: {from}\r\nTo: agent@example.org\r\nSubject: Status\r\nMessage-ID: <{message_id}>\r\nDate: Sun, 12 Oct 2025 20:13:20 +0000\r\n{extra_headers}\r\n{body}\r\n"
+        )
+    }
+
+    #[tokio::test]
+    async fn integration_email_imap_poll_baselines_then_ingests_new_mail_without_loops() {
+        let temp = tempdir().expect("tempdir");
+        let messages = Arc::new(std::sync::Mutex::new(vec![
+            (
+                1,
+                email_raw("old-1@example.com", "alice@example.com", "", "old"),
+            ),
+            (
+                2,
+                email_raw("old-2@example.com", "alice@example.com", "", "old"),
+            ),
+        ]));
+        let addr = spawn_imap_stand_in(messages.clone()).await;
+
+        let mut config = build_connector_config(temp.path());
+        config.email_mode = MultiChannelLiveConnectorMode::Polling;
+        config.email = MultiChannelEmailAccountConfig {
+            address: Some("agent@example.org".to_string()),
+            password: Some("secret".to_string()),
+            imap_host: addr.ip().to_string(),
+            imap_port: addr.port(),
+            imap_tls: crate::multi_channel_email::MultiChannelEmailTlsMode::None,
+            ..MultiChannelEmailAccountConfig::default()
+        };
+
+        run_multi_channel_live_connectors_runner(config.clone())
+            .await
+            .expect("baseline sync should succeed");
+        let state = load_multi_channel_live_connectors_state(&config.state_path)
+            .expect("load connector state");
+        assert_eq!(state.email_uid_validity, Some(7));
+        assert_eq!(state.email_last_uid, Some(2));
+        assert!(!config.ingress_dir.join("email.ndjson").exists());
+
+        messages.lock().expect("messages").extend([
+            (
+                3,
+                email_raw("new-3@example.com", "Alice <alice@example.com>", "", "hi"),
+            ),
+            (
+                4,
+                email_raw(
+                    "auto-4@example.com",
+                    "alice@example.com",
+                    "Auto-Submitted: auto-replied\r\n",
+                    "out of office",
+                ),
+            ),
+            (
+                5,
+                email_raw("self-5@example.org", "agent@example.org", "", "echo"),
+            ),
+        ]);
+        run_multi_channel_live_connectors_runner(config.clone())
+            .await
+            .expect("incremental sync should succeed");
+        run_multi_channel_live_connectors_runner(config.clone())
+            .await
+            .expect("idle sync should succeed");
+
+        let email_lines = read_ndjson(&config.ingress_dir.join("email.ndjson"));
+        assert_eq!(email_lines.len(), 1);
+        assert_eq!(email_lines[0]["transport"].as_str(), Some("email"));
+        assert_eq!(email_lines[0]["provider"].as_str(), Some("imap"));
+        assert_eq!(
+            email_lines[0]["payload"]["message_id"].as_str(),
+            Some("new-3@example.com")
+        );
+        assert_eq!(email_lines[0]["payload"]["uid"].as_u64(), Some(3));
+
+        let state = load_multi_channel_live_connectors_state(&config.state_path)
+            .expect("load connector state");
+        assert_eq!(state.email_last_uid, Some(5));
+        let channel = state.channels.get("email").expect("email channel state");
+        assert_eq!(channel.events_ingested, 1);
+        assert_eq!(channel.consecutive_failures, 0);
+    }
+
     #[tokio::test]
     async fn integration_whatsapp_webhook_ingests_signed_cloud_payload() {
         let temp = tempdir().expect("tempdir");
@@ -21,6 +21,7 @@ use crate::multi_channel_contract::{
     validate_multi_channel_inbound_event, MultiChannelAttachment, MultiChannelEventKind,
     MultiChannelInboundEvent, MultiChannelTransport, MULTI_CHANNEL_CONTRACT_SCHEMA_VERSION,
 };
+use crate::multi_channel_email::{email_thread_conversation_id, strip_quoted_email_reply};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -121,6 +122,8 @@ pub fn default_multi_channel_live_provider_label(transport: MultiChannelTranspor
         MultiChannelTransport::Telegram => "telegram-bot-api",
         MultiChannelTransport::Discord => "discord-gateway",
         MultiChannelTransport::Whatsapp => "whatsapp-cloud-api",
+        MultiChannelTransport::Matrix => "matrix-client-server-api",
+        MultiChannelTransport::Email => "imap",
     }
 }
 
@@ -268,6 +271,8 @@ pub fn parse_multi_channel_live_inbound_envelope_value(
         MultiChannelTransport::Telegram => parse_telegram_event(envelope)?,
         MultiChannelTransport::Discord => parse_discord_event(envelope)?,
         MultiChannelTransport::Whatsapp => parse_whatsapp_event(envelope)?,
+        MultiChannelTransport::Matrix => parse_matrix_event(envelope)?,
+        MultiChannelTransport::Email => parse_email_event(envelope)?,
     };
 
     if event.text.trim().is_empty() && event.attachments.is_empty() {
@@ -319,10 +324,12 @@ fn parse_transport(
         "telegram" => Ok(MultiChannelTransport::Telegram),
         "discord" => Ok(MultiChannelTransport::Discord),
         "whatsapp" => Ok(MultiChannelTransport::Whatsapp),
+        "matrix" => Ok(MultiChannelTransport::Matrix),
+        "email" => Ok(MultiChannelTransport::Email),
         unsupported => Err(parse_error(
             MultiChannelLiveIngressReasonCode::UnsupportedTransport,
             format!(
-                "unsupported transport '{}' (expected telegram, discord, or whatsapp)",
+                "unsupported transport '{}' (expected telegram, discord, whatsapp, matrix, or email)",
                 unsupported
             ),
         )),
@@ -570,6 +577,496 @@ fn parse_whatsapp_event(
     })
 }
 
+fn parse_matrix_event(
+    envelope: &MultiChannelLiveInboundEnvelope,
+) -> Result<MultiChannelInboundEvent, MultiChannelLiveIngressParseError> {
+    let payload = as_object(
+        &envelope.payload,
+        MultiChannelLiveIngressReasonCode::MissingPayload,
+        "payload must be a JSON object",
+    )?;
+    // Connectors wrap each timeline event with its room context; a bare room
+    // event that carries its own `room_id` is accepted as well.
+    let event = optional_object_field(payload, "event").unwrap_or(payload);
+    let event_type = optional_string_field(event, "type").unwrap_or_default();
+    if event_type != "m.room.message" {
+        return Err(parse_error(
+            MultiChannelLiveIngressReasonCode::InvalidFieldType,
+            format!("unsupported matrix event type '{event_type}' (expected m.room.message)"),
+        ));
+    }
+    let room_id = optional_string_field(payload, "room_id")
+        .or_else(|| optional_string_field(event, "room_id"))
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            parse_error(
+                MultiChannelLiveIngressReasonCode::MissingField,
+                "payload.room_id is required",
+            )
+        })?;
+    let content = object_field(
+        event,
+        "content",
+        MultiChannelLiveIngressReasonCode::MissingField,
+        "payload.event.content",
+    )?;
+    let sender = required_string_field(
+        event,
+        "sender",
+        MultiChannelLiveIngressReasonCode::MissingField,
+        "payload.event.sender",
+    )?;
+    let timestamp_ms = required_u64_field(
+        event,
+        "origin_server_ts",
+        MultiChannelLiveIngressReasonCode::InvalidTimestamp,
+        "payload.event.origin_server_ts",
+    )?;
+
+    let relates_to = optional_object_field(content, "m.relates_to");
+    let rel_type = relates_to
+        .and_then(|relation| optional_string_field(relation, "rel_type"))
+        .unwrap_or_default();
+    let relation_event_id = relates_to
+        .and_then(|relation| optional_string_field(relation, "event_id"))
+        .unwrap_or_default();
+    let in_reply_to = relates_to
+        .and_then(|relation| optional_object_field(relation, "m.in_reply_to"))
+        .and_then(|reply| optional_string_field(reply, "event_id"));
+    let is_edit = rel_type == "m.replace";
+    // Edits carry the replacement in `m.new_content`; the outer body is a
+    // "* edited" fallback for clients without edit support.
+    let message_content = if is_edit {
+        optional_object_field(content, "m.new_content").unwrap_or(content)
+    } else {
+        content
+    };
+    let msgtype = optional_string_field(message_content, "msgtype").unwrap_or_default();
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert(
+        "ingress_provider".to_string(),
+        Value::String(envelope.provider.trim().to_string()),
+    );
+    metadata.insert("matrix_msgtype".to_string(), Value::String(msgtype.clone()));
+    let conversation_mode = match optional_string_field(payload, "room_kind").as_deref() {
+        Some("direct") | Some("dm") => "dm",
+        _ => "group",
+    };
+    metadata.insert(
+        "conversation_mode".to_string(),
+        Value::String(conversation_mode.to_string()),
+    );
+    if let Some(reply_event_id) = in_reply_to.as_ref() {
+        metadata.insert(
+            "matrix_in_reply_to_event_id".to_string(),
+            Value::String(reply_event_id.clone()),
+        );
+    }
+    if is_edit && !relation_event_id.is_empty() {
+        metadata.insert(
+            "matrix_replaces_event_id".to_string(),
+            Value::String(relation_event_id.clone()),
+        );
+    }
+
+    let (text, attachments) = match msgtype.as_str() {
+        "m.image" | "m.file" | "m.audio" | "m.video" => {
+            let homeserver_url = optional_string_field(payload, "homeserver_url")
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| {
+                    parse_error(
+                        MultiChannelLiveIngressReasonCode::MissingField,
+                        "payload.homeserver_url is required to resolve matrix media",
+                    )
+                })?;
+            let attachment = parse_matrix_media_attachment(message_content, &homeserver_url)?;
+            // `body` is the file name unless a separate `filename` is present,
+            // in which case `body` is a caption.
+            let caption = optional_string_field(message_content, "filename")
+                .and_then(|_| optional_string_field(message_content, "body"))
+                .unwrap_or_default();
+            (caption, vec![attachment])
+        }
+        _ => {
+            let body = optional_string_field(message_content, "body").unwrap_or_default();
+            let body = if in_reply_to.is_some() && rel_type != "m.thread" {
+                strip_matrix_reply_fallback(&body)
+            } else {
+                body
+            };
+            (body, Vec::new())
+        }
+    };
+
+    let bot_user_id = optional_string_field(payload, "bot_user_id").unwrap_or_default();
+    if !bot_user_id.is_empty() {
+        let mentioned = optional_object_field(content, "m.mentions")
+            .and_then(|mentions| mentions.get("user_ids"))
+            .and_then(Value::as_array)
+            .map(|user_ids| {
+                user_ids
+                    .iter()
+                    .any(|user_id| user_id.as_str() == Some(bot_user_id.as_str()))
+            })
+            .unwrap_or(false)
+            || text.contains(bot_user_id.as_str());
+        metadata.insert("mentions_bot".to_string(), Value::Bool(mentioned));
+    }
+
+    Ok(MultiChannelInboundEvent {
+        schema_version: MULTI_CHANNEL_CONTRACT_SCHEMA_VERSION,
+        transport: MultiChannelTransport::Matrix,
+        event_kind: if is_edit {
+            MultiChannelEventKind::Edit
+        } else {
+            detect_event_kind(Some(text.as_str()))
+        },
+        event_id: required_string_field(
+            event,
+            "event_id",
+            MultiChannelLiveIngressReasonCode::MissingField,
+            "payload.event.event_id",
+        )?,
+        conversation_id: room_id,
+        thread_id: if rel_type == "m.thread" {
+            relation_event_id
+        } else {
+            String::new()
+        },
+        actor_display: optional_string_field(payload, "sender_display_name")
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| matrix_user_localpart(&sender).to_string()),
+        actor_id: sender,
+        timestamp_ms,
+        text,
+        attachments,
+        metadata,
+    })
+}
+
+fn parse_matrix_media_attachment(
+    content: &Map<String, Value>,
+    homeserver_url: &str,
+) -> Result<MultiChannelAttachment, MultiChannelLiveIngressParseError> {
+    let mxc_uri = required_string_field(
+        content,
+        "url",
+        MultiChannelLiveIngressReasonCode::MissingField,
+        "payload.event.content.url",
+    )?;
+    let (server_name, media_id) = mxc_uri
+        .strip_prefix("mxc://")
+        .and_then(|rest| rest.split_once('/'))
+        .filter(|(server_name, media_id)| !server_name.is_empty() && !media_id.is_empty())
+        .ok_or_else(|| {
+            parse_error(
+                MultiChannelLiveIngressReasonCode::InvalidFieldType,
+                format!("payload.event.content.url '{mxc_uri}' is not a valid mxc:// uri"),
+            )
+        })?;
+    let info = optional_object_field(content, "info");
+    Ok(MultiChannelAttachment {
+        attachment_id: mxc_uri.clone(),
+        url: matrix_media_download_url(homeserver_url, server_name, media_id),
+        content_type: info
+            .and_then(|info| optional_string_field(info, "mimetype"))
+            .unwrap_or_default(),
+        file_name: optional_string_field(content, "filename")
+            .or_else(|| optional_string_field(content, "body"))
+            .unwrap_or_default(),
+        size_bytes: info
+            .and_then(|info| optional_u64_value(info.get("size")))
+            .unwrap_or(0),
+    })
+}
+
+/// Resolves an `mxc://<server>/<media-id>` reference to the homeserver's
+/// content repository download endpoint.
+fn matrix_media_download_url(homeserver_url: &str, server_name: &str, media_id: &str) -> String {
+    format!(
+        "{}/_matrix/client/v1/media/download/{}/{}",
+        homeserver_url.trim().trim_end_matches('/'),
+        server_name,
+        media_id
+    )
+}
+
+fn strip_matrix_reply_fallback(body: &str) -> String {
+    if !body.starts_with("> ") {
+        return body.to_string();
+    }
+    let mut lines = body.lines().skip_while(|line| line.starts_with("> "));
+    let mut remainder = Vec::new();
+    if let Some(first) = lines.next() {
+        if !first.trim().is_empty() {
+            remainder.push(first);
+        }
+    }
+    remainder.extend(lines);
+    remainder.join("\n").trim().to_string()
+}
+
+fn matrix_user_localpart(user_id: &str) -> &str {
+    user_id
+        .trim_start_matches('@')
+        .split(':')
+        .next()
+        .unwrap_or(user_id)
+}
+
+fn parse_email_event(
+    envelope: &MultiChannelLiveInboundEnvelope,
+) -> Result<MultiChannelInboundEvent, MultiChannelLiveIngressParseError> {
+    let payload = as_object(
+        &envelope.payload,
+        MultiChannelLiveIngressReasonCode::MissingPayload,
+        "payload must be a JSON object",
+    )?;
+    let from = object_field(
+        payload,
+        "from",
+        MultiChannelLiveIngressReasonCode::MissingField,
+        "payload.from",
+    )?;
+    let from_address = required_string_field(
+        from,
+        "address",
+        MultiChannelLiveIngressReasonCode::MissingField,
+        "payload.from.address",
+    )?
+    .to_ascii_lowercase();
+    let account = optional_string_field(payload, "account").unwrap_or_default();
+    let mailbox = optional_string_field(payload, "mailbox").unwrap_or_default();
+    let imap_host = optional_string_field(payload, "imap_host").unwrap_or_default();
+    let uid = optional_u64_value(payload.get("uid")).unwrap_or(0);
+    let uid_validity = optional_u64_value(payload.get("uid_validity")).unwrap_or(0);
+    let message_id = optional_string_field(payload, "message_id").unwrap_or_default();
+    let event_id = if !message_id.is_empty() {
+        message_id.clone()
+    } else if uid > 0 {
+        // Messages without a Message-ID are still unique per mailbox UID.
+        format!("imap:{account}:{mailbox}:{uid_validity}:{uid}")
+    } else {
+        return Err(parse_error(
+            MultiChannelLiveIngressReasonCode::MissingField,
+            "payload.message_id or payload.uid is required",
+        ));
+    };
+    let timestamp_ms = optional_string_field(payload, "date")
+        .and_then(|raw| parse_rfc2822_to_unix_ms(&raw))
+        .or_else(|| optional_u64_value(payload.get("received_unix_ms")))
+        .filter(|value| *value > 0)
+        .ok_or_else(|| {
+            parse_error(
+                MultiChannelLiveIngressReasonCode::InvalidTimestamp,
+                "payload.date or payload.received_unix_ms is required",
+            )
+        })?;
+
+    let in_reply_to = string_array_field(payload, "in_reply_to")?;
+    let references = string_array_field(payload, "references")?;
+    // Threads are keyed on their root message so every reply shares a session.
+    let thread_root_id = references
+        .first()
+        .or_else(|| in_reply_to.first())
+        .cloned()
+        .unwrap_or_else(|| event_id.clone());
+    let starts_thread = references.is_empty() && in_reply_to.is_empty();
+
+    let subject = optional_string_field(payload, "subject").unwrap_or_default();
+    let body = optional_string_field(payload, "text")
+        .filter(|value| !value.is_empty())
+        .or_else(|| optional_string_field(payload, "html_text"))
+        .unwrap_or_default();
+    let body = strip_quoted_email_reply(&body);
+    let event_kind = detect_event_kind(Some(body.as_str()));
+    // The subject only carries new information on the first message of a thread.
+    let text =
+        if starts_thread && event_kind == MultiChannelEventKind::Message && !subject.is_empty() {
+            if body.is_empty() {
+                subject.clone()
+            } else {
+                format!("{subject}\n\n{body}")
+            }
+        } else {
+            body
+        };
+
+    let mut attachments = Vec::new();
+    if let Some(rows) = payload.get("attachments") {
+        let rows = rows.as_array().ok_or_else(|| {
+            parse_error(
+                MultiChannelLiveIngressReasonCode::InvalidFieldType,
+                "payload.attachments must be an array",
+            )
+        })?;
+        for (index, row) in rows.iter().enumerate() {
+            let row = as_object(
+                row,
+                MultiChannelLiveIngressReasonCode::InvalidFieldType,
+                &format!("payload.attachments[{index}] must be an object"),
+            )?;
+            let part = required_string_field(
+                row,
+                "part",
+                MultiChannelLiveIngressReasonCode::MissingField,
+                &format!("payload.attachments[{index}].part"),
+            )?;
+            let content_id = optional_string_field(row, "content_id").unwrap_or_default();
+            attachments.push(MultiChannelAttachment {
+                attachment_id: format!("{event_id}/{part}"),
+                url: email_attachment_url(
+                    &imap_host,
+                    &mailbox,
+                    uid_validity,
+                    uid,
+                    &part,
+                    &message_id,
+                    &content_id,
+                ),
+                content_type: optional_string_field(row, "content_type").unwrap_or_default(),
+                file_name: optional_string_field(row, "file_name").unwrap_or_default(),
+                size_bytes: optional_u64_value(row.get("size_bytes")).unwrap_or(0),
+            });
+        }
+    }
+
+    let reply_to = optional_string_field(payload, "reply_to")
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_ascii_lowercase());
+    let authentication = optional_object_field(payload, "authentication_results");
+    let verdict = |method: &str| {
+        authentication
+            .and_then(|results| optional_string_field(results, method))
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "none".to_string())
+    };
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert(
+        "ingress_provider".to_string(),
+        Value::String(envelope.provider.trim().to_string()),
+    );
+    metadata.insert(
+        "conversation_mode".to_string(),
+        Value::String("dm".to_string()),
+    );
+    metadata.insert("email_subject".to_string(), Value::String(subject));
+    metadata.insert(
+        "email_message_id".to_string(),
+        Value::String(message_id.clone()),
+    );
+    metadata.insert(
+        "email_in_reply_to".to_string(),
+        Value::String(in_reply_to.first().cloned().unwrap_or_default()),
+    );
+    metadata.insert(
+        "email_references".to_string(),
+        Value::Array(references.into_iter().map(Value::String).collect()),
+    );
+    metadata.insert(
+        "email_thread_root_id".to_string(),
+        Value::String(thread_root_id.clone()),
+    );
+    metadata.insert("email_account".to_string(), Value::String(account));
+    metadata.insert("email_mailbox".to_string(), Value::String(mailbox));
+    if let Some(reply_to) = reply_to {
+        metadata.insert("email_reply_to".to_string(), Value::String(reply_to));
+    }
+    for method in ["dkim", "spf", "dmarc"] {
+        metadata.insert(
+            format!("email_{method}_result"),
+            Value::String(verdict(method)),
+        );
+    }
+    let dkim_domains = authentication
+        .and_then(|results| results.get("dkim_domains"))
+        .and_then(Value::as_array)
+        .map(|domains| {
+            domains
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|domain| Value::String(domain.trim().to_ascii_lowercase()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    metadata.insert("email_dkim_domains".to_string(), Value::Array(dkim_domains));
+    metadata.insert(
+        "email_spf_domain".to_string(),
+        Value::String(
+            authentication
+                .and_then(|results| optional_string_field(results, "spf_domain"))
+                .unwrap_or_default()
+                .to_ascii_lowercase(),
+        ),
+    );
+
+    Ok(MultiChannelInboundEvent {
+        schema_version: MULTI_CHANNEL_CONTRACT_SCHEMA_VERSION,
+        transport: MultiChannelTransport::Email,
+        event_kind,
+        event_id,
+        conversation_id: email_thread_conversation_id(&thread_root_id),
+        thread_id: String::new(),
+        actor_display: optional_string_field(from, "name")
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| from_address.clone()),
+        actor_id: from_address,
+        timestamp_ms,
+        text,
+        attachments,
+        metadata,
+    })
+}
+
+/// Attachments are referenced in place: an RFC 5092 IMAP URL when the mailbox
+/// location is known, otherwise an RFC 2392 `mid:` URL.
+fn email_attachment_url(
+    imap_host: &str,
+    mailbox: &str,
+    uid_validity: u64,
+    uid: u64,
+    part: &str,
+    message_id: &str,
+    content_id: &str,
+) -> String {
+    if !imap_host.is_empty() && !mailbox.is_empty() && uid > 0 {
+        return format!(
+            "imap://{imap_host}/{mailbox};UIDVALIDITY={uid_validity}/;UID={uid}/;SECTION={part}"
+        );
+    }
+    let reference = if content_id.is_empty() {
+        part
+    } else {
+        content_id
+    };
+    format!("mid:{message_id}/{reference}")
+}
+
+fn string_array_field(
+    object: &Map<String, Value>,
+    key: &str,
+) -> Result<Vec<String>, MultiChannelLiveIngressParseError> {
+    let Some(value) = object.get(key) else {
+        return Ok(Vec::new());
+    };
+    if value.is_null() {
+        return Ok(Vec::new());
+    }
+    let rows = value.as_array().ok_or_else(|| {
+        parse_error(
+            MultiChannelLiveIngressReasonCode::InvalidFieldType,
+            format!("payload.{key} must be an array"),
+        )
+    })?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| optional_string_value(Some(row)))
+        .filter(|row| !row.is_empty())
+        .collect())
+}
+
 fn parse_attachments(
     raw_value: Option<&Value>,
 ) -> Result<Vec<MultiChannelAttachment>, MultiChannelLiveIngressParseError> {
@@ -784,6 +1281,17 @@ fn parse_rfc3339_to_unix_ms(raw: &str) -> Option<u64> {
     u64::try_from(parsed.timestamp_millis()).ok()
 }
 
+fn parse_rfc2822_to_unix_ms(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    // Drop a trailing zone comment such as "+0000 (UTC)".
+    let raw = match raw.rfind(" (") {
+        Some(index) if raw.ends_with(')') => raw[..index].trim_end(),
+        _ => raw,
+    };
+    let parsed = DateTime::parse_from_rfc2822(raw).ok()?;
+    u64::try_from(parsed.timestamp_millis()).ok()
+}
+
 fn parse_error(
     code: MultiChannelLiveIngressReasonCode,
     message: impl Into<String>,
@@ -801,7 +1309,7 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use crate::multi_channel_contract::MultiChannelTransport;
+    use crate::multi_channel_contract::{MultiChannelEventKind, MultiChannelTransport};
 
     use super::{
         build_multi_channel_live_envelope_from_raw_payload,
@@ -914,6 +1422,205 @@ mod tests {
         assert_eq!(event.timestamp_ms, 1_760_300_000_000);
     }
 
+    #[test]
+    fn unit_parse_matrix_envelope_maps_thread_reply_and_mention() {
+        let event = parse_multi_channel_live_inbound_envelope(&fixture_raw("matrix-valid.json"))
+            .expect("matrix fixture should parse");
+        assert_eq!(event.transport, MultiChannelTransport::Matrix);
+        assert_eq!(event.event_id, "$evt-reply-1");
+        assert_eq!(event.conversation_id, "!ops:example.org");
+        assert_eq!(event.thread_id, "$thread-root-1");
+        assert_eq!(event.actor_id, "@alice:example.org");
+        assert_eq!(event.actor_display, "alice");
+        assert_eq!(event.timestamp_ms, 1_760_300_000_000);
+        assert_eq!(event.metadata["conversation_mode"], "group");
+        assert_eq!(event.metadata["mentions_bot"], true);
+        assert_eq!(
+            event.metadata["matrix_in_reply_to_event_id"],
+            "$evt-previous-1"
+        );
+    }
+
+    #[test]
+    fn functional_parse_matrix_media_message_resolves_mxc_to_download_url() {
+        let raw = serde_json::json!({
+            "schema_version": 1,
+            "transport": "matrix",
+            "provider": "matrix-client-server-api",
+            "payload": {
+                "room_id": "!dm:example.org",
+                "room_kind": "direct",
+                "homeserver_url": "https://matrix.example.org/",
+                "event": {
+                    "type": "m.room.message",
+                    "event_id": "$img-1",
+                    "sender": "@bob:example.org",
+                    "origin_server_ts": 1760300000500u64,
+                    "content": {
+                        "msgtype": "m.image",
+                        "body": "what is this?",
+                        "filename": "diagram.png",
+                        "url": "mxc://media.example.org/AbCdEf123",
+                        "info": { "mimetype": "image/png", "size": 2048 }
+                    }
+                }
+            }
+        });
+
+        let event = parse_multi_channel_live_inbound_envelope(&raw.to_string())
+            .expect("matrix media payload should parse");
+        assert_eq!(event.text, "what is this?");
+        assert_eq!(event.metadata["conversation_mode"], "dm");
+        assert_eq!(event.attachments.len(), 1);
+        let attachment = &event.attachments[0];
+        assert_eq!(
+            attachment.attachment_id,
+            "mxc://media.example.org/AbCdEf123"
+        );
+        assert_eq!(
+            attachment.url,
+            "https://matrix.example.org/_matrix/client/v1/media/download/media.example.org/AbCdEf123"
+        );
+        assert_eq!(attachment.content_type, "image/png");
+        assert_eq!(attachment.file_name, "diagram.png");
+        assert_eq!(attachment.size_bytes, 2048);
+    }
+
+    #[test]
+    fn regression_parse_matrix_edit_and_reply_use_clean_message_text() {
+        let edit = serde_json::json!({
+            "schema_version": 1,
+            "transport": "matrix",
+            "provider": "matrix-client-server-api",
+            "payload": {
+                "room_id": "!ops:example.org",
+                "event": {
+                    "type": "m.room.message",
+                    "event_id": "$edit-1",
+                    "sender": "@alice:example.org",
+                    "origin_server_ts": 1760300001000u64,
+                    "content": {
+                        "msgtype": "m.text",
+                        "body": "* fixed typo",
+                        "m.new_content": { "msgtype": "m.text", "body": "fixed typo" },
+                        "m.relates_to": { "rel_type": "m.replace", "event_id": "$orig-1" }
+                    }
+                }
+            }
+        });
+        let event = parse_multi_channel_live_inbound_envelope(&edit.to_string())
+            .expect("matrix edit should parse");
+        assert_eq!(event.event_kind, MultiChannelEventKind::Edit);
+        assert_eq!(event.text, "fixed typo");
+        assert_eq!(event.metadata["matrix_replaces_event_id"], "$orig-1");
+
+        let reply = serde_json::json!({
+            "schema_version": 1,
+            "transport": "matrix",
+            "provider": "matrix-client-server-api",
+            "payload": {
+                "room_id": "!ops:example.org",
+                "event": {
+                    "type": "m.room.message",
+                    "event_id": "$reply-1",
+                    "sender": "@alice:example.org",
+                    "origin_server_ts": 1760300002000u64,
+                    "content": {
+                        "msgtype": "m.text",
+                        "body": "> <@bob:example.org> earlier message\n\nsounds good",
+                        "m.relates_to": { "m.in_reply_to": { "event_id": "$orig-2" } }
+                    }
+                }
+            }
+        });
+        let event = parse_multi_channel_live_inbound_envelope(&reply.to_string())
+            .expect("matrix reply should parse");
+        assert_eq!(event.text, "sounds good");
+        assert!(event.thread_id.is_empty());
+
+        let mut state_event = reply.clone();
+        state_event["payload"]["event"]["type"] = serde_json::json!("m.room.member");
+        let error = parse_multi_channel_live_inbound_envelope(&state_event.to_string())
+            .expect_err("non-message matrix events should be rejected");
+        assert_eq!(
+            error.code,
+            MultiChannelLiveIngressReasonCode::InvalidFieldType
+        );
+    }
+
+    #[test]
+    fn unit_parse_email_envelope_maps_thread_root_and_strips_quoted_history() {
+        let event = parse_multi_channel_live_inbound_envelope(&fixture_raw("email-valid.json"))
+            .expect("email fixture should parse");
+        assert_eq!(event.transport, MultiChannelTransport::Email);
+        assert_eq!(event.event_id, "reply-2@example.com");
+        assert_eq!(
+            event.conversation_id,
+            crate::multi_channel_email::email_thread_conversation_id("root-0@example.com")
+        );
+        assert_eq!(event.actor_id, "alice@example.com");
+        assert_eq!(event.actor_display, "Alice Example");
+        assert_eq!(event.text, "hello from email");
+        assert_eq!(event.timestamp_ms, 1_760_300_000_000);
+        assert_eq!(event.metadata["conversation_mode"], "dm");
+        assert_eq!(event.metadata["email_in_reply_to"], "tau-1@example.org");
+        assert_eq!(event.metadata["email_dmarc_result"], "pass");
+        assert_eq!(event.metadata["email_dkim_domains"][0], "example.com");
+        assert_eq!(event.metadata["email_spf_domain"], "example.com");
+        assert_eq!(event.attachments.len(), 1);
+        assert_eq!(
+            event.attachments[0].url,
+            "imap://imap.example.org/INBOX;UIDVALIDITY=7/;UID=42/;SECTION=2"
+        );
+    }
+
+    #[test]
+    fn functional_parse_email_thread_start_includes_subject_and_shares_session() {
+        let start = parse_multi_channel_live_inbound_envelope(
+            &serde_json::json!({
+                "schema_version": 1,
+                "transport": "email",
+                "provider": "imap",
+                "payload": {
+                    "message_id": "root-0@example.com",
+                    "from": { "address": "Alice@Example.com" },
+                    "subject": "Quarterly report",
+                    "date": "Sun, 12 Oct 2025 20:13:20 +0000 (UTC)",
+                    "text": "Can you summarize it?"
+                }
+            })
+            .to_string(),
+        )
+        .expect("thread start should parse");
+        assert_eq!(start.actor_id, "alice@example.com");
+        assert_eq!(start.actor_display, "alice@example.com");
+        assert_eq!(start.text, "Quarterly report\n\nCan you summarize it?");
+        assert_eq!(start.timestamp_ms, 1_760_300_000_000);
+
+        let reply = parse_multi_channel_live_inbound_envelope(&fixture_raw("email-valid.json"))
+            .expect("reply should parse");
+        assert_eq!(start.conversation_id, reply.conversation_id);
+
+        let command = parse_multi_channel_live_inbound_envelope(
+            &serde_json::json!({
+                "schema_version": 1,
+                "transport": "email",
+                "payload": {
+                    "uid": 9,
+                    "from": { "address": "alice@example.com" },
+                    "subject": "status",
+                    "received_unix_ms": 1_760_300_000_000u64,
+                    "html_text": "/status"
+                }
+            })
+            .to_string(),
+        )
+        .expect("html-only command should parse");
+        assert_eq!(command.event_kind, MultiChannelEventKind::Command);
+        assert_eq!(command.text, "/status");
+        assert_eq!(command.event_id, "imap:::0:9");
+    }
+
     #[test]
     fn functional_live_ingress_fixtures_cover_all_supported_transports() {
         let parsed = [
@@ -923,15 +1630,21 @@ mod tests {
                 .expect("discord fixture should parse"),
             load_multi_channel_live_inbound_envelope_fixture(&fixture_path("whatsapp-valid.json"))
                 .expect("whatsapp fixture should parse"),
+            load_multi_channel_live_inbound_envelope_fixture(&fixture_path("matrix-valid.json"))
+                .expect("matrix fixture should parse"),
+            load_multi_channel_live_inbound_envelope_fixture(&fixture_path("email-valid.json"))
+                .expect("email fixture should parse"),
         ];
         let transports = parsed
             .iter()
             .map(|event| event.transport)
             .collect::<HashSet<_>>();
-        assert_eq!(transports.len(), 3);
+        assert_eq!(transports.len(), 5);
         assert!(transports.contains(&MultiChannelTransport::Telegram));
         assert!(transports.contains(&MultiChannelTransport::Discord));
         assert!(transports.contains(&MultiChannelTransport::Whatsapp));
+        assert!(transports.contains(&MultiChannelTransport::Matrix));
+        assert!(transports.contains(&MultiChannelTransport::Email));
     }
 
     #[test]
@@ -13,10 +13,17 @@ use serde_json::{json, Value};
 use tau_runtime::{SsrfGuard, SsrfProtectionConfig, SsrfViolation};
 
 use crate::multi_channel_contract::{MultiChannelInboundEvent, MultiChannelTransport};
+use crate::multi_channel_email::{
+    email_reply_message_id, send_smtp_message, EmailSubmission, MultiChannelEmailAccountConfig,
+};
 
 const TELEGRAM_SAFE_MAX_CHARS: usize = 4096;
 const DISCORD_SAFE_MAX_CHARS: usize = 2000;
 const WHATSAPP_SAFE_MAX_CHARS: usize = 1024;
+const MATRIX_SAFE_MAX_CHARS: usize = 16_000;
+// Email bodies are not length-limited like chat messages; this only bounds a
+// single reply so runaway output is split into a few numbered messages.
+const EMAIL_SAFE_MAX_CHARS: usize = 100_000;
 const REACTION_REASON_UNSUPPORTED_TRANSPORT: &str = "reaction_unsupported_transport";
 const REACTION_REASON_INVALID_MESSAGE_ID: &str = "reaction_invalid_message_id";
 const REACTION_REASON_MISSING_EMOJI: &str = "reaction_missing_emoji";
@@ -62,10 +69,13 @@ pub struct MultiChannelOutboundConfig {
     pub telegram_api_base: String,
     pub discord_api_base: String,
     pub whatsapp_api_base: String,
+    pub matrix_homeserver_url: String,
     pub telegram_bot_token: Option<String>,
     pub discord_bot_token: Option<String>,
     pub whatsapp_access_token: Option<String>,
     pub whatsapp_phone_number_id: Option<String>,
+    pub matrix_access_token: Option<String>,
+    pub email: MultiChannelEmailAccountConfig,
 }
 
 impl Default for MultiChannelOutboundConfig {
@@ -81,10 +91,13 @@ impl Default for MultiChannelOutboundConfig {
             telegram_api_base: "https://api.telegram.org".to_string(),
             discord_api_base: "https://discord.com/api/v10".to_string(),
             whatsapp_api_base: "https://graph.facebook.com/v20.0".to_string(),
+            matrix_homeserver_url: "https://matrix.org".to_string(),
             telegram_bot_token: None,
             discord_bot_token: None,
             whatsapp_access_token: None,
             whatsapp_phone_number_id: None,
+            matrix_access_token: None,
+            email: MultiChannelEmailAccountConfig::default(),
         }
     }
 }
@@ -586,7 +599,12 @@ impl MultiChannelOutboundDispatcher {
         }
 
         let safe_max_chars = self.safe_max_chars(event.transport);
-        let chunk_max = self.config.max_chars.min(safe_max_chars).max(1);
+        // `max_chars` sizes chat bubbles; email replies are sent whole.
+        let chunk_max = if event.transport == MultiChannelTransport::Email {
+            safe_max_chars
+        } else {
+            self.config.max_chars.min(safe_max_chars).max(1)
+        };
         let chunks = chunk_text(trimmed, chunk_max);
         let chunk_count = chunks.len();
         if chunk_count == 0 {
@@ -739,16 +757,47 @@ impl MultiChannelOutboundDispatcher {
                     chunk_count: 1,
                 })
             }
-            MultiChannelTransport::Whatsapp => Err(MultiChannelOutboundDeliveryError {
-                reason_code: REACTION_REASON_UNSUPPORTED_TRANSPORT.to_string(),
-                detail: "reaction delivery is not supported for whatsapp transport".to_string(),
-                retryable: false,
-                chunk_index: 1,
-                chunk_count: 1,
-                endpoint: "".to_string(),
-                request_body: None,
-                http_status: None,
-            }),
+            MultiChannelTransport::Matrix => {
+                let token = self.resolve_matrix_access_token(1, 1)?;
+                let endpoint = format!(
+                    "{}/rooms/{}/send/m.reaction/{}",
+                    self.matrix_client_api_base(),
+                    percent_encode_path_segment(event.conversation_id.trim()),
+                    percent_encode_path_segment(&format!(
+                        "tau-reaction-{normalized_message_id}-{normalized_emoji}"
+                    ))
+                );
+                Ok(MultiChannelOutboundRequest {
+                    method: Method::PUT,
+                    transport: event.transport,
+                    endpoint,
+                    headers: vec![("Authorization".to_string(), format!("Bearer {}", token))],
+                    body: json!({
+                        "m.relates_to": {
+                            "rel_type": "m.annotation",
+                            "event_id": normalized_message_id,
+                            "key": normalized_emoji,
+                        }
+                    }),
+                    chunk_index: 1,
+                    chunk_count: 1,
+                })
+            }
+            MultiChannelTransport::Whatsapp | MultiChannelTransport::Email => {
+                Err(MultiChannelOutboundDeliveryError {
+                    reason_code: REACTION_REASON_UNSUPPORTED_TRANSPORT.to_string(),
+                    detail: format!(
+                        "reaction delivery is not supported for {} transport",
+                        event.transport.as_str()
+                    ),
+                    retryable: false,
+                    chunk_index: 1,
+                    chunk_count: 1,
+                    endpoint: "".to_string(),
+                    request_body: None,
+                    http_status: None,
+                })
+            }
         }
     }
 
@@ -898,9 +947,14 @@ impl MultiChannelOutboundDispatcher {
                     chunk_count: 1,
                 })
             }
-            MultiChannelTransport::Whatsapp => Err(MultiChannelOutboundDeliveryError {
+            MultiChannelTransport::Whatsapp
+            | MultiChannelTransport::Matrix
+            | MultiChannelTransport::Email => Err(MultiChannelOutboundDeliveryError {
                 reason_code: FILE_REASON_UNSUPPORTED_TRANSPORT.to_string(),
-                detail: "file delivery is not supported for whatsapp transport".to_string(),
+                detail: format!(
+                    "file delivery is not supported for {} transport",
+                    event.transport.as_str()
+                ),
                 retryable: false,
                 chunk_index: 1,
                 chunk_count: 1,
@@ -974,21 +1028,22 @@ impl MultiChannelOutboundDispatcher {
                     chunk_count: 1,
                 })
             }
-            MultiChannelTransport::Telegram | MultiChannelTransport::Whatsapp => {
-                Err(MultiChannelOutboundDeliveryError {
-                    reason_code: THREAD_REASON_UNSUPPORTED_TRANSPORT.to_string(),
-                    detail: format!(
-                        "thread delivery is not supported for {} transport",
-                        event.transport.as_str()
-                    ),
-                    retryable: false,
-                    chunk_index: 1,
-                    chunk_count: 1,
-                    endpoint: "".to_string(),
-                    request_body: None,
-                    http_status: None,
-                })
-            }
+            MultiChannelTransport::Telegram
+            | MultiChannelTransport::Whatsapp
+            | MultiChannelTransport::Matrix
+            | MultiChannelTransport::Email => Err(MultiChannelOutboundDeliveryError {
+                reason_code: THREAD_REASON_UNSUPPORTED_TRANSPORT.to_string(),
+                detail: format!(
+                    "thread delivery is not supported for {} transport",
+                    event.transport.as_str()
+                ),
+                retryable: false,
+                chunk_index: 1,
+                chunk_count: 1,
+                endpoint: "".to_string(),
+                request_body: None,
+                http_status: None,
+            }),
         }
     }
 
@@ -1037,21 +1092,22 @@ impl MultiChannelOutboundDispatcher {
                     chunk_count: 1,
                 })
             }
-            MultiChannelTransport::Telegram | MultiChannelTransport::Whatsapp => {
-                Err(MultiChannelOutboundDeliveryError {
-                    reason_code: TYPING_REASON_UNSUPPORTED_TRANSPORT.to_string(),
-                    detail: format!(
-                        "typing indicator dispatch is not supported for {} transport",
-                        event.transport.as_str()
-                    ),
-                    retryable: false,
-                    chunk_index: 1,
-                    chunk_count: 1,
-                    endpoint: "".to_string(),
-                    request_body: None,
-                    http_status: None,
-                })
-            }
+            MultiChannelTransport::Telegram
+            | MultiChannelTransport::Whatsapp
+            | MultiChannelTransport::Matrix
+            | MultiChannelTransport::Email => Err(MultiChannelOutboundDeliveryError {
+                reason_code: TYPING_REASON_UNSUPPORTED_TRANSPORT.to_string(),
+                detail: format!(
+                    "typing indicator dispatch is not supported for {} transport",
+                    event.transport.as_str()
+                ),
+                retryable: false,
+                chunk_index: 1,
+                chunk_count: 1,
+                endpoint: "".to_string(),
+                request_body: None,
+                http_status: None,
+            }),
         }
     }
 
@@ -1060,9 +1116,51 @@ impl MultiChannelOutboundDispatcher {
             MultiChannelTransport::Telegram => TELEGRAM_SAFE_MAX_CHARS,
             MultiChannelTransport::Discord => DISCORD_SAFE_MAX_CHARS,
             MultiChannelTransport::Whatsapp => WHATSAPP_SAFE_MAX_CHARS,
+            MultiChannelTransport::Matrix => MATRIX_SAFE_MAX_CHARS,
+            MultiChannelTransport::Email => EMAIL_SAFE_MAX_CHARS,
         }
     }
 
+    fn matrix_client_api_base(&self) -> String {
+        format!(
+            "{}/_matrix/client/v3",
+            self.config
+                .matrix_homeserver_url
+                .trim()
+                .trim_end_matches('/')
+        )
+    }
+
+    fn resolve_matrix_access_token(
+        &self,
+        chunk_index: usize,
+        chunk_count: usize,
+    ) -> Result<String, MultiChannelOutboundDeliveryError> {
+        self.config
+            .matrix_access_token
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                if self.config.mode == MultiChannelOutboundMode::DryRun {
+                    Some("dry-run-matrix-token".to_string())
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| MultiChannelOutboundDeliveryError {
+                reason_code: "delivery_missing_matrix_access_token".to_string(),
+                detail: "Matrix outbound requires TAU_MATRIX_ACCESS_TOKEN or credential-store integration id matrix-access-token".to_string(),
+                retryable: false,
+                chunk_index,
+                chunk_count,
+                endpoint: "".to_string(),
+                request_body: None,
+                http_status: None,
+            })
+    }
+
     fn build_request_for_chunk(
         &self,
         event: &MultiChannelInboundEvent,
@@ -1262,13 +1360,249 @@ impl MultiChannelOutboundDispatcher {
                     chunk_count,
                 })
             }
+            MultiChannelTransport::Matrix => {
+                let token = self.resolve_matrix_access_token(chunk_index, chunk_count)?;
+                // A deterministic transaction id lets the homeserver dedupe
+                // retried sends of the same chunk.
+                let txn_id = format!("tau-{}-{chunk_index}", event.event_id.trim());
+                let endpoint = format!(
+                    "{}/rooms/{}/send/m.room.message/{}",
+                    self.matrix_client_api_base(),
+                    percent_encode_path_segment(event.conversation_id.trim()),
+                    percent_encode_path_segment(&txn_id)
+                );
+                let mut body = json!({
+                    "msgtype": "m.text",
+                    "body": chunk,
+                });
+                let reply_to = event.event_id.trim();
+                let thread_root = event.thread_id.trim();
+                let relation = if !thread_root.is_empty() {
+                    Some(json!({
+                        "rel_type": "m.thread",
+                        "event_id": thread_root,
+                        "is_falling_back": true,
+                        "m.in_reply_to": { "event_id": reply_to },
+                    }))
+                } else if reply_to.starts_with('$') {
+                    Some(json!({ "m.in_reply_to": { "event_id": reply_to } }))
+                } else {
+                    None
+                };
+                if let (Some(relation), Value::Object(map)) = (relation, &mut body) {
+                    map.insert("m.relates_to".to_string(), relation);
+                }
+                Ok(MultiChannelOutboundRequest {
+                    method: Method::PUT,
+                    transport: event.transport,
+                    endpoint,
+                    headers: vec![("Authorization".to_string(), format!("Bearer {}", token))],
+                    body,
+                    chunk_index,
+                    chunk_count,
+                })
+            }
+            MultiChannelTransport::Email => {
+                self.build_email_request(event, chunk, chunk_index, chunk_count)
+            }
+        }
+    }
+
+    fn build_email_request(
+        &self,
+        event: &MultiChannelInboundEvent,
+        chunk: String,
+        chunk_index: usize,
+        chunk_count: usize,
+    ) -> Result<MultiChannelOutboundRequest, MultiChannelOutboundDeliveryError> {
+        let is_dry_run = self.config.mode == MultiChannelOutboundMode::DryRun;
+        let missing = |reason_code: &str, detail: &str| MultiChannelOutboundDeliveryError {
+            reason_code: reason_code.to_string(),
+            detail: detail.to_string(),
+            retryable: false,
+            chunk_index,
+            chunk_count,
+            endpoint: "".to_string(),
+            request_body: None,
+            http_status: None,
+        };
+        let metadata_str = |key: &str| {
+            event
+                .metadata
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .unwrap_or_default()
+        };
+        let recipient = Some(metadata_str("email_reply_to"))
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| event.actor_id.trim());
+        if !recipient.contains('@') {
+            return Err(missing(
+                "delivery_missing_email_recipient",
+                "email outbound requires a sender address (Reply-To or From) on the inbound event",
+            ));
+        }
+        let from = self
+            .config
+            .email
+            .resolved_address()
+            .map(str::to_string)
+            .or_else(|| is_dry_run.then(|| "dry-run-agent@example.invalid".to_string()))
+            .ok_or_else(|| {
+                missing(
+                    "delivery_missing_email_address",
+                    "email outbound requires --multi-channel-email-address",
+                )
+            })?;
+        let smtp_host = Some(self.config.email.smtp_host.trim())
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .or_else(|| is_dry_run.then(|| "dry-run-smtp.invalid".to_string()))
+            .ok_or_else(|| {
+                missing(
+                    "delivery_missing_email_smtp_host",
+                    "email outbound requires --multi-channel-email-smtp-host",
+                )
+            })?;
+
+        let original_subject = metadata_str("email_subject");
+        let original_subject = if original_subject.is_empty() {
+            "(no subject)"
+        } else {
+            original_subject
+        };
+        // Threading headers point at the message being answered so mail
+        // clients keep the reply in the same conversation.
+        let in_reply_to = Some(metadata_str("email_message_id"))
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+        let already_reply = original_subject
+            .get(..3)
+            .map(|prefix| prefix.eq_ignore_ascii_case("re:"))
+            .unwrap_or(false);
+        let mut subject = if in_reply_to.is_none() || already_reply {
+            original_subject.to_string()
+        } else {
+            format!("Re: {original_subject}")
+        };
+        if chunk_count > 1 {
+            subject.push_str(&format!(" [{chunk_index}/{chunk_count}]"));
+        }
+        let mut references = event
+            .metadata
+            .get("email_references")
+            .and_then(Value::as_array)
+            .map(|rows| {
+                rows.iter()
+                    .filter_map(Value::as_str)
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if let Some(in_reply_to) = in_reply_to.as_ref() {
+            if !references.contains(in_reply_to) {
+                references.push(in_reply_to.clone());
+            }
         }
+        let message_id = email_reply_message_id(&event.event_id, chunk_index, &from);
+        Ok(MultiChannelOutboundRequest {
+            method: Method::POST,
+            transport: event.transport,
+            endpoint: format!("smtp://{smtp_host}:{}", self.config.email.smtp_port),
+            headers: Vec::new(),
+            body: json!({
+                "from": from,
+                "to": recipient.to_ascii_lowercase(),
+                "subject": subject,
+                "message_id": message_id,
+                "in_reply_to": in_reply_to,
+                "references": references,
+                "text": chunk,
+            }),
+            chunk_index,
+            chunk_count,
+        })
+    }
+
+    async fn send_email_request(
+        &self,
+        request: &MultiChannelOutboundRequest,
+    ) -> Result<MultiChannelOutboundDeliveryReceipt, MultiChannelOutboundDeliveryError> {
+        let body_str = |key: &str| {
+            request
+                .body
+                .get(key)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+        let submission = EmailSubmission {
+            from: body_str("from"),
+            to: body_str("to"),
+            subject: body_str("subject"),
+            message_id: body_str("message_id"),
+            in_reply_to: request
+                .body
+                .get("in_reply_to")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            references: request
+                .body
+                .get("references")
+                .and_then(Value::as_array)
+                .map(|rows| {
+                    rows.iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            text: body_str("text"),
+        };
+        // The SMTP relay is operator-configured rather than derived from
+        // inbound data, so it is not routed through the HTTP SSRF guard.
+        send_smtp_message(
+            &self.config.email,
+            &submission,
+            Duration::from_millis(self.config.http_timeout_ms),
+        )
+        .await
+        .map_err(|error| MultiChannelOutboundDeliveryError {
+            reason_code: error.reason_code.to_string(),
+            detail: truncate_detail(&error.detail),
+            retryable: error.retryable,
+            chunk_index: request.chunk_index,
+            chunk_count: request.chunk_count,
+            endpoint: request.endpoint.clone(),
+            request_body: Some(compact_request_body(&request.body)),
+            http_status: None,
+        })?;
+        Ok(MultiChannelOutboundDeliveryReceipt {
+            transport: request.transport.as_str().to_string(),
+            mode: self.config.mode.as_str().to_string(),
+            status: "sent".to_string(),
+            chunk_index: request.chunk_index,
+            chunk_count: request.chunk_count,
+            endpoint: request.endpoint.clone(),
+            request_body: request.body.clone(),
+            reason_code: None,
+            detail: None,
+            retryable: false,
+            http_status: None,
+            provider_message_id: extract_provider_message_id(request.transport, &request.body),
+        })
     }
 
     async fn send_request(
         &self,
         request: &MultiChannelOutboundRequest,
     ) -> Result<MultiChannelOutboundDeliveryReceipt, MultiChannelOutboundDeliveryError> {
+        if request.transport == MultiChannelTransport::Email {
+            return self.send_email_request(request).await;
+        }
         let client = self
             .client
             .as_ref()
@@ -1469,10 +1803,18 @@ fn extract_provider_message_id(
             .and_then(|value| value.get("id"))
             .and_then(Value::as_str)
             .map(|value| value.to_string()),
+        MultiChannelTransport::Matrix => payload
+            .get("event_id")
+            .and_then(Value::as_str)
+            .map(|value| value.to_string()),
+        MultiChannelTransport::Email => payload
+            .get("message_id")
+            .and_then(Value::as_str)
+            .map(|value| value.to_string()),
     }
 }
 
-fn percent_encode_path_segment(value: &str) -> String {
+pub(crate) fn percent_encode_path_segment(value: &str) -> String {
     const HEX: &[u8; 16] = b"0123456789ABCDEF";
     let mut encoded = String::with_capacity(value.len());
     for byte in value.as_bytes() {
@@ -1554,6 +1896,7 @@ mod tests {
     use crate::multi_channel_contract::{
         MultiChannelEventKind, MultiChannelInboundEvent, MultiChannelTransport,
     };
+    use crate::multi_channel_email::{MultiChannelEmailAccountConfig, MultiChannelEmailTlsMode};
     use std::collections::BTreeMap;
 
     fn sample_event(transport: MultiChannelTransport) -> MultiChannelInboundEvent {
@@ -2026,6 +2369,173 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn functional_dry_run_shapes_matrix_threaded_reply_payload() {
+        let dispatcher = MultiChannelOutboundDispatcher::new(MultiChannelOutboundConfig {
+            mode: MultiChannelOutboundMode::DryRun,
+            max_chars: 100,
+            matrix_homeserver_url: "https://matrix.example.org/".to_string(),
+            ..MultiChannelOutboundConfig::default()
+        })
+        .expect("dispatcher");
+        let mut event = sample_event(MultiChannelTransport::Matrix);
+        event.event_id = "$evt-1".to_string();
+        event.conversation_id = "!ops:example.org".to_string();
+        event.thread_id = "$root-1".to_string();
+        let result = dispatcher
+            .deliver(&event, "hello matrix")
+            .await
+            .expect("dry-run should succeed");
+        assert_eq!(result.chunk_count, 1);
+        let receipt = &result.receipts[0];
+        assert_eq!(
+            receipt.endpoint,
+            "https://matrix.example.org/_matrix/client/v3/rooms/%21ops%3Aexample.org/send/m.room.message/tau-%24evt-1-1"
+        );
+        assert_eq!(receipt.request_body["msgtype"], "m.text");
+        assert_eq!(receipt.request_body["body"], "hello matrix");
+        assert_eq!(
+            receipt.request_body["m.relates_to"],
+            json!({
+                "rel_type": "m.thread",
+                "event_id": "$root-1",
+                "is_falling_back": true,
+                "m.in_reply_to": { "event_id": "$evt-1" }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn integration_provider_mode_puts_matrix_room_message_with_bearer_token() {
+        let server = MockServer::start();
+        let sent = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/_matrix/client/v3/rooms/%21dm%3Aexample.org/send/m.room.message/tau-%24evt-9-1")
+                .header("authorization", "Bearer matrix-token")
+                .json_body(json!({
+                    "msgtype": "m.text",
+                    "body": "hello back",
+                    "m.relates_to": { "m.in_reply_to": { "event_id": "$evt-9" } }
+                }));
+            then.status(200).json_body(json!({"event_id": "$sent-1"}));
+        });
+
+        let dispatcher = MultiChannelOutboundDispatcher::new(MultiChannelOutboundConfig {
+            mode: MultiChannelOutboundMode::Provider,
+            max_chars: 100,
+            matrix_homeserver_url: server.base_url(),
+            matrix_access_token: Some("matrix-token".to_string()),
+            ssrf_allow_http: true,
+            ssrf_allow_private_network: true,
+            ..MultiChannelOutboundConfig::default()
+        })
+        .expect("dispatcher");
+        let mut event = sample_event(MultiChannelTransport::Matrix);
+        event.event_id = "$evt-9".to_string();
+        event.conversation_id = "!dm:example.org".to_string();
+        let result = dispatcher
+            .deliver(&event, "hello back")
+            .await
+            .expect("provider send should succeed");
+        sent.assert_calls(1);
+        assert_eq!(result.receipts[0].status, "sent");
+        assert_eq!(
+            result.receipts[0].provider_message_id.as_deref(),
+            Some("$sent-1")
+        );
+    }
+
+    fn sample_email_event() -> MultiChannelInboundEvent {
+        let mut event = sample_event(MultiChannelTransport::Email);
+        event.event_id = "reply-2@example.com".to_string();
+        event.conversation_id = "email-thread-0123456789abcdef".to_string();
+        event.actor_id = "alice@example.com".to_string();
+        for (key, value) in [
+            ("email_subject", json!("Re: Quarterly report")),
+            ("email_message_id", json!("reply-2@example.com")),
+            ("email_references", json!(["root-0@example.com"])),
+        ] {
+            event.metadata.insert(key.to_string(), value);
+        }
+        event
+    }
+
+    #[tokio::test]
+    async fn functional_dry_run_shapes_threaded_email_reply_without_chat_chunking() {
+        let dispatcher = MultiChannelOutboundDispatcher::new(MultiChannelOutboundConfig {
+            mode: MultiChannelOutboundMode::DryRun,
+            max_chars: 5,
+            email: MultiChannelEmailAccountConfig {
+                address: Some("agent@example.org".to_string()),
+                smtp_host: "smtp.example.org".to_string(),
+                ..MultiChannelEmailAccountConfig::default()
+            },
+            ..MultiChannelOutboundConfig::default()
+        })
+        .expect("dispatcher");
+        let mut event = sample_email_event();
+        event.metadata.insert(
+            "email_reply_to".to_string(),
+            Value::String("team@example.com".to_string()),
+        );
+        let result = dispatcher
+            .deliver(&event, "a reply longer than five characters")
+            .await
+            .expect("dry-run should succeed");
+        assert_eq!(result.chunk_count, 1);
+        let receipt = &result.receipts[0];
+        assert_eq!(receipt.endpoint, "smtp://smtp.example.org:587");
+        assert_eq!(receipt.request_body["to"], "team@example.com");
+        assert_eq!(receipt.request_body["from"], "agent@example.org");
+        assert_eq!(receipt.request_body["subject"], "Re: Quarterly report");
+        assert_eq!(receipt.request_body["in_reply_to"], "reply-2@example.com");
+        assert_eq!(
+            receipt.request_body["references"],
+            json!(["root-0@example.com", "reply-2@example.com"])
+        );
+        assert!(receipt.request_body["message_id"]
+            .as_str()
+            .expect("message id")
+            .ends_with("@example.org"));
+        assert_eq!(
+            receipt.request_body["text"],
+            "a reply longer than five characters"
+        );
+    }
+
+    #[tokio::test]
+    async fn regression_provider_email_requires_account_address_and_smtp_host() {
+        let dispatcher = MultiChannelOutboundDispatcher::new(MultiChannelOutboundConfig {
+            mode: MultiChannelOutboundMode::Provider,
+            max_chars: 100,
+            ..MultiChannelOutboundConfig::default()
+        })
+        .expect("dispatcher");
+        let error = dispatcher
+            .deliver(&sample_email_event(), "hello")
+            .await
+            .expect_err("missing address should fail");
+        assert_eq!(error.reason_code, "delivery_missing_email_address");
+
+        let dispatcher = MultiChannelOutboundDispatcher::new(MultiChannelOutboundConfig {
+            mode: MultiChannelOutboundMode::Provider,
+            max_chars: 100,
+            email: MultiChannelEmailAccountConfig {
+                address: Some("agent@example.org".to_string()),
+                smtp_tls: MultiChannelEmailTlsMode::None,
+                ..MultiChannelEmailAccountConfig::default()
+            },
+            ..MultiChannelOutboundConfig::default()
+        })
+        .expect("dispatcher");
+        let error = dispatcher
+            .deliver(&sample_email_event(), "hello")
+            .await
+            .expect_err("missing smtp host should fail");
+        assert_eq!(error.reason_code, "delivery_missing_email_smtp_host");
+        assert!(!error.retryable);
+    }
+
     #[tokio::test]
     async fn spec_2766_c03_integration_provider_mode_posts_discord_typing_indicator() {
         let server = MockServer::start();
@@ -11,7 +11,10 @@ use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::multi_channel_contract::{MultiChannelEventKind, MultiChannelInboundEvent};
+use crate::multi_channel_contract::{
+    MultiChannelEventKind, MultiChannelInboundEvent, MultiChannelTransport,
+};
+use crate::multi_channel_email::{email_sender_authenticated, EmailAuthenticationResults};
 
 /// Public `const` `MULTI_CHANNEL_POLICY_SCHEMA_VERSION` in `tau-multi-channel`.
 ///
@@ -168,6 +171,10 @@ pub struct MultiChannelChannelPolicy {
     pub group_policy: MultiChannelGroupPolicy,
     #[serde(default, rename = "requireMention")]
     pub require_mention: bool,
+    /// Deny email whose sender failed DMARC/DKIM/SPF checks. Transports that
+    /// authenticate senders themselves are unaffected.
+    #[serde(default, rename = "requireSenderAuthentication")]
+    pub require_sender_authentication: bool,
 }
 
 impl MultiChannelChannelPolicy {
@@ -266,6 +273,7 @@ pub struct MultiChannelPolicyEvaluation {
     pub policy: MultiChannelChannelPolicy,
     pub conversation_kind: MultiChannelConversationKind,
     pub mention_present: bool,
+    pub sender_authenticated: Option<bool>,
     pub decision: MultiChannelPolicyDecision,
 }
 
@@ -364,6 +372,7 @@ pub fn evaluate_multi_channel_channel_policy(
 
     let conversation_kind = detect_conversation_kind(event);
     let mention_present = detect_mention_present(event);
+    let sender_authenticated = detect_sender_authenticated(event);
 
     let decision = match conversation_kind {
         MultiChannelConversationKind::Dm => {
@@ -389,6 +398,16 @@ pub fn evaluate_multi_channel_channel_policy(
             }
         }
     };
+    let decision = if matches!(decision, MultiChannelPolicyDecision::Allow { .. })
+        && policy.require_sender_authentication
+        && sender_authenticated == Some(false)
+    {
+        MultiChannelPolicyDecision::Deny {
+            reason_code: "deny_channel_policy_sender_unauthenticated".to_string(),
+        }
+    } else {
+        decision
+    };
 
     MultiChannelPolicyEvaluation {
         policy_channel,
@@ -396,6 +415,7 @@ pub fn evaluate_multi_channel_channel_policy(
         policy,
         conversation_kind,
         mention_present,
+        sender_authenticated,
         decision,
     }
 }
@@ -511,6 +531,44 @@ fn detect_mention_present(event: &MultiChannelInboundEvent) -> bool {
     text.contains("@tau") || text.contains("<@") || text.contains("/tau")
 }
 
+/// Returns `None` for transports whose platform already authenticates the
+/// sender; only email carries forgeable sender identities.
+fn detect_sender_authenticated(event: &MultiChannelInboundEvent) -> Option<bool> {
+    if event.transport != MultiChannelTransport::Email {
+        return None;
+    }
+    let verdict = |key: &str| {
+        event
+            .metadata
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let dkim_domains = event
+        .metadata
+        .get("email_dkim_domains")
+        .and_then(Value::as_array)
+        .map(|domains| {
+            domains
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(email_sender_authenticated(
+        &event.actor_id,
+        &EmailAuthenticationResults {
+            dkim: verdict("email_dkim_result"),
+            spf: verdict("email_spf_result"),
+            dmarc: verdict("email_dmarc_result"),
+            dkim_domains,
+            spf_domain: verdict("email_spf_domain"),
+        },
+    ))
+}
+
 fn metadata_bool(event: &MultiChannelInboundEvent, key: &str) -> bool {
     event
         .metadata
@@ -531,7 +589,7 @@ fn metadata_string_matches(event: &MultiChannelInboundEvent, key: &str, accepted
 mod tests {
     use std::collections::BTreeMap;
 
-    use serde_json::Value;
+    use serde_json::{json, Value};
     use tempfile::tempdir;
 
     use super::{
@@ -626,6 +684,87 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn functional_evaluate_policy_denies_unauthenticated_email_senders_when_required() {
+        let policy = MultiChannelPolicyFile {
+            channels: BTreeMap::from([(
+                "email:*".to_string(),
+                MultiChannelChannelPolicy {
+                    require_sender_authentication: true,
+                    ..MultiChannelChannelPolicy::default()
+                },
+            )]),
+            ..MultiChannelPolicyFile::default()
+        };
+        let mut event = sample_event(MultiChannelTransport::Email, "email-thread-1", "hello");
+        event.actor_id = "alice@example.com".to_string();
+        event.metadata.insert(
+            "conversation_mode".to_string(),
+            Value::String("dm".to_string()),
+        );
+        event.metadata.insert(
+            "email_dkim_result".to_string(),
+            Value::String("pass".to_string()),
+        );
+        event
+            .metadata
+            .insert("email_dkim_domains".to_string(), json!(["example.com"]));
+        event.metadata.insert(
+            "email_dmarc_result".to_string(),
+            Value::String("fail".to_string()),
+        );
+        let denied = evaluate_multi_channel_channel_policy(&policy, &event);
+        assert_eq!(denied.matched_policy_key, "email:*");
+        assert_eq!(denied.sender_authenticated, Some(false));
+        assert_eq!(
+            denied.decision,
+            MultiChannelPolicyDecision::Deny {
+                reason_code: "deny_channel_policy_sender_unauthenticated".to_string(),
+            }
+        );
+
+        event.metadata.insert(
+            "email_dmarc_result".to_string(),
+            Value::String("none".to_string()),
+        );
+        let allowed = evaluate_multi_channel_channel_policy(&policy, &event);
+        assert_eq!(allowed.sender_authenticated, Some(true));
+        assert!(matches!(
+            allowed.decision,
+            MultiChannelPolicyDecision::Allow { .. }
+        ));
+
+        let mut forged = event.clone();
+        forged
+            .metadata
+            .insert("email_dkim_domains".to_string(), json!(["attacker.test"]));
+        let forged_evaluation = evaluate_multi_channel_channel_policy(&policy, &forged);
+        assert_eq!(forged_evaluation.sender_authenticated, Some(false));
+        assert_eq!(
+            forged_evaluation.decision,
+            MultiChannelPolicyDecision::Deny {
+                reason_code: "deny_channel_policy_sender_unauthenticated".to_string(),
+            }
+        );
+
+        let chat = sample_event(MultiChannelTransport::Telegram, "chat-1", "@tau hi");
+        let chat_evaluation = evaluate_multi_channel_channel_policy(
+            &MultiChannelPolicyFile {
+                default_policy: MultiChannelChannelPolicy {
+                    require_sender_authentication: true,
+                    ..MultiChannelChannelPolicy::default()
+                },
+                ..MultiChannelPolicyFile::default()
+            },
+            &chat,
+        );
+        assert_eq!(chat_evaluation.sender_authenticated, None);
+        assert!(matches!(
+            chat_evaluation.decision,
+            MultiChannelPolicyDecision::Allow { .. }
+        ));
+    }
+
     #[test]
     fn integration_load_policy_for_state_dir_uses_tau_security_root_for_multi_channel() {
         let temp = tempdir().expect("tempdir");
@@ -271,6 +271,7 @@ pub fn resolve_multi_channel_account_id(event: &MultiChannelInboundEvent) -> Str
         "discord_application_id",
         "whatsapp_business_account_id",
         "whatsapp_phone_number_id",
+        "email_account",
     ] {
         if let Some(value) = event.metadata.get(key).and_then(Value::as_str) {
             if let Some(normalized) = normalize_optional_text(Some(value)) {
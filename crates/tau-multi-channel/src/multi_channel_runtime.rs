@@ -41,7 +41,7 @@ use tau_access::{
     evaluate_signed_envelope_access, signed_envelope_policy_for_state_dir, SignedEnvelopeContext,
     SignedEnvelopeDecision,
 };
-use tau_core::{current_unix_timestamp_ms, write_text_atomic};
+use tau_core::{current_unix_timestamp_ms, global_metrics_registry, write_text_atomic};
 use tau_orchestrator::multi_agent_router::{load_multi_agent_route_table, MultiAgentRouteTable};
 use tau_runtime::{ChannelContextEntry, ChannelLogEntry, ChannelStore, TransportHealthSnapshot};
 
@@ -61,10 +61,12 @@ use routing::{
 const MULTI_CHANNEL_RUNTIME_STATE_SCHEMA_VERSION: u32 = 1;
 const MULTI_CHANNEL_RUNTIME_EVENTS_LOG_FILE: &str = "runtime-events.jsonl";
 const MULTI_CHANNEL_ROUTE_TRACES_LOG_FILE: &str = "route-traces.jsonl";
-const MULTI_CHANNEL_LIVE_INGRESS_SOURCES: [(&str, &str); 3] = [
+const MULTI_CHANNEL_LIVE_INGRESS_SOURCES: [(&str, &str); 5] = [
     ("telegram", "telegram.ndjson"),
     ("discord", "discord.ndjson"),
     ("whatsapp", "whatsapp.ndjson"),
+    ("matrix", "matrix.ndjson"),
+    ("email", "email.ndjson"),
 ];
 const PAIRING_REASON_ALLOW_PERMISSIVE_MODE: &str = "allow_permissive_mode";
 const PAIRING_REASON_DENY_POLICY_EVALUATION_ERROR: &str = "deny_policy_evaluation_error";
@@ -787,6 +789,7 @@ impl MultiChannelRuntime {
         );
         let classification = health.classify();
         let reason_codes = cycle_reason_codes(&summary);
+        record_multi_channel_cycle_metrics(&summary);
         self.state.health = health.clone();
         self.state.telemetry_policy = MultiChannelRuntimeTelemetryPolicyState {
             typing_presence_enabled: self.config.telemetry.typing_presence_enabled,
@@ -1327,6 +1330,8 @@ impl MultiChannelRuntime {
             "group_policy": access_decision.channel_policy.policy.group_policy.as_str(),
             "require_mention": access_decision.channel_policy.policy.require_mention,
             "mention_present": access_decision.channel_policy.mention_present,
+            "require_sender_authentication": access_decision.channel_policy.policy.require_sender_authentication,
+            "sender_authenticated": access_decision.channel_policy.sender_authenticated,
         });
         let mut inbound_payload =
             serde_json::to_value(event).context("serialize inbound event payload")?;
@@ -1943,6 +1948,26 @@ impl MultiChannelRuntime {
     }
 }
 
+/// Records per-cycle event counters. Backlog gauges (`tau_channel_queue_depth`,
+/// `tau_channel_failure_streak`) are owned by the gateway scrape, which reads
+/// them from the persisted runtime state.
+fn record_multi_channel_cycle_metrics(summary: &MultiChannelRuntimeSummary) {
+    let registry = global_metrics_registry();
+    for (outcome, count) in [
+        ("completed", summary.completed_events),
+        ("failed", summary.failed_events),
+        ("duplicate", summary.duplicate_skips),
+        ("retried", summary.retry_attempts),
+    ] {
+        registry.increment_counter(
+            "tau_channel_events",
+            "Multi-channel inbound events processed by outcome.",
+            &[("outcome", outcome)],
+            count as f64,
+        );
+    }
+}
+
 fn approvals_success_reason_code(action: &MultiChannelTauApprovalsAction) -> &'static str {
     match action {
         MultiChannelTauApprovalsAction::List => COMMAND_REASON_APPROVALS_LIST_REPORTED,
@@ -2027,6 +2052,8 @@ fn channel_typing_signal(transport: MultiChannelTransport) -> &'static str {
         MultiChannelTransport::Telegram => "telegram:typing",
         MultiChannelTransport::Discord => "discord:typing",
         MultiChannelTransport::Whatsapp => "whatsapp:typing",
+        MultiChannelTransport::Matrix => "matrix:typing",
+        MultiChannelTransport::Email => "email:typing",
     }
 }
 
@@ -2038,6 +2065,10 @@ fn channel_presence_signal(transport: MultiChannelTransport, active: bool) -> &'
         (MultiChannelTransport::Discord, false) => "discord:idle",
         (MultiChannelTransport::Whatsapp, true) => "whatsapp:available",
         (MultiChannelTransport::Whatsapp, false) => "whatsapp:idle",
+        (MultiChannelTransport::Matrix, true) => "matrix:online",
+        (MultiChannelTransport::Matrix, false) => "matrix:unavailable",
+        (MultiChannelTransport::Email, true) => "email:online",
+        (MultiChannelTransport::Email, false) => "email:idle",
     }
 }
 
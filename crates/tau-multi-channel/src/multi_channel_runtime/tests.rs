@@ -30,6 +30,7 @@ use crate::multi_channel_contract::{
     load_multi_channel_contract_fixture, parse_multi_channel_contract_fixture,
     MultiChannelAttachment, MultiChannelEventKind, MultiChannelInboundEvent, MultiChannelTransport,
 };
+use crate::multi_channel_email::MultiChannelEmailTlsMode;
 use crate::multi_channel_outbound::{MultiChannelOutboundConfig, MultiChannelOutboundMode};
 use tau_runtime::{ChannelStore, TransportHealthState};
 
@@ -3242,6 +3243,17 @@ async fn integration_runner_provider_outbound_posts_per_transport_adapter() {
                 config.outbound.whatsapp_access_token = Some("whatsapp-token".to_string());
                 config.outbound.whatsapp_phone_number_id = Some("15551234567".to_string());
             }
+            MultiChannelTransport::Matrix => {
+                config.outbound.matrix_homeserver_url = server.base_url();
+                config.outbound.matrix_access_token = Some("matrix-token".to_string());
+            }
+            MultiChannelTransport::Email => {
+                config.outbound.email.address = Some("tau@example.test".to_string());
+                config.outbound.email.password = Some("email-password".to_string());
+                config.outbound.email.smtp_host = server.host();
+                config.outbound.email.smtp_port = server.port();
+                config.outbound.email.smtp_tls = MultiChannelEmailTlsMode::None;
+            }
         }
         let mut runtime = MultiChannelRuntime::new(config.clone()).expect("runtime");
         let event = sample_event(
@@ -17,6 +17,7 @@ use crate::multi_channel_contract::{
 use crate::multi_channel_credentials::{
     resolve_secret, MultiChannelCredentialStoreSnapshot, ResolvedSecret,
 };
+use crate::multi_channel_email::{parse_email_address, MultiChannelEmailAccountConfig};
 use crate::multi_channel_outbound::{
     MultiChannelOutboundConfig, MultiChannelOutboundDeliveryError,
     MultiChannelOutboundDeliveryReceipt, MultiChannelOutboundDeliveryResult,
@@ -32,6 +33,9 @@ const TELEGRAM_TOKEN_INTEGRATION_ID: &str = "telegram-bot-token";
 const DISCORD_TOKEN_INTEGRATION_ID: &str = "discord-bot-token";
 const WHATSAPP_TOKEN_INTEGRATION_ID: &str = "whatsapp-access-token";
 const WHATSAPP_PHONE_NUMBER_ID_INTEGRATION_ID: &str = "whatsapp-phone-number-id";
+const MATRIX_TOKEN_INTEGRATION_ID: &str = "matrix-access-token";
+const EMAIL_PASSWORD_INTEGRATION_ID: &str = "email-password";
+const EMAIL_SEND_DEFAULT_SUBJECT: &str = "Message from Tau";
 
 #[derive(Debug, Clone)]
 /// Public struct `MultiChannelSendCommandConfig` used across Tau components.
@@ -50,12 +54,15 @@ pub struct MultiChannelSendCommandConfig {
     pub telegram_api_base: String,
     pub discord_api_base: String,
     pub whatsapp_api_base: String,
+    pub matrix_homeserver_url: String,
     pub credential_store: Option<MultiChannelCredentialStoreSnapshot>,
     pub credential_store_unreadable: bool,
     pub telegram_bot_token: Option<String>,
     pub discord_bot_token: Option<String>,
     pub whatsapp_access_token: Option<String>,
     pub whatsapp_phone_number_id: Option<String>,
+    pub matrix_access_token: Option<String>,
+    pub email: MultiChannelEmailAccountConfig,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -271,6 +278,16 @@ fn build_multi_channel_send_outbound_config(
         config.whatsapp_phone_number_id.as_deref(),
         WHATSAPP_PHONE_NUMBER_ID_INTEGRATION_ID,
     );
+    let matrix_token = resolve_send_secret(
+        config,
+        config.matrix_access_token.as_deref(),
+        MATRIX_TOKEN_INTEGRATION_ID,
+    );
+    let email_password = resolve_send_secret(
+        config,
+        config.email.password.as_deref(),
+        EMAIL_PASSWORD_INTEGRATION_ID,
+    );
     MultiChannelOutboundConfig {
         mode: config.outbound_mode,
         max_chars: config.outbound_max_chars.max(1),
@@ -282,10 +299,16 @@ fn build_multi_channel_send_outbound_config(
         telegram_api_base: config.telegram_api_base.trim().to_string(),
         discord_api_base: config.discord_api_base.trim().to_string(),
         whatsapp_api_base: config.whatsapp_api_base.trim().to_string(),
+        matrix_homeserver_url: config.matrix_homeserver_url.trim().to_string(),
         telegram_bot_token: telegram_token.value,
         discord_bot_token: discord_token.value,
         whatsapp_access_token: whatsapp_token.value,
         whatsapp_phone_number_id: whatsapp_phone_number_id.value,
+        matrix_access_token: matrix_token.value,
+        email: MultiChannelEmailAccountConfig {
+            password: email_password.value,
+            ..config.email.clone()
+        },
     }
 }
 
@@ -393,6 +416,45 @@ fn parse_multi_channel_send_target(
                 metadata,
             })
         }
+        MultiChannelTransport::Matrix => {
+            let target = trimmed.strip_prefix("room:").unwrap_or(trimmed).trim();
+            if !is_matrix_room_id(target) {
+                bail!(
+                    "invalid matrix target '{}': expected room id (for example !abc123:example.org)",
+                    raw_target
+                );
+            }
+            Ok(ParsedSendTarget {
+                normalized: target.to_string(),
+                conversation_id: target.to_string(),
+                channel_store_id: target.to_string(),
+                actor_id: "operator:cli".to_string(),
+                metadata: BTreeMap::new(),
+            })
+        }
+        MultiChannelTransport::Email => {
+            let target = trimmed.strip_prefix("mailto:").unwrap_or(trimmed).trim();
+            let Some(recipient) = parse_email_address(target) else {
+                bail!(
+                    "invalid email target '{}': expected an address (for example ops@example.com)",
+                    raw_target
+                );
+            };
+            let address = recipient.address;
+            let mut metadata = BTreeMap::new();
+            metadata.insert("email_reply_to".to_string(), Value::String(address.clone()));
+            metadata.insert(
+                "email_subject".to_string(),
+                Value::String(EMAIL_SEND_DEFAULT_SUBJECT.to_string()),
+            );
+            Ok(ParsedSendTarget {
+                normalized: address.clone(),
+                conversation_id: format!("email:{address}"),
+                channel_store_id: format!("email:{address}"),
+                actor_id: address,
+                metadata,
+            })
+        }
     }
 }
 
@@ -497,6 +559,16 @@ fn is_e164_like(value: &str) -> bool {
     value[1..].chars().all(|ch| ch.is_ascii_digit())
 }
 
+fn is_matrix_room_id(value: &str) -> bool {
+    let Some((local, server)) = value
+        .strip_prefix('!')
+        .and_then(|rest| rest.split_once(':'))
+    else {
+        return false;
+    };
+    !local.is_empty() && !server.is_empty() && !value.chars().any(char::is_whitespace)
+}
+
 /// Public `fn` `render_multi_channel_send_report` in `tau-multi-channel`.
 ///
 /// This item is part of the Wave 2 API surface for M23 documentation uplift.
@@ -536,6 +608,40 @@ mod tests {
         assert!(err.to_string().contains("cannot be empty"));
     }
 
+    #[test]
+    fn unit_parse_multi_channel_send_target_accepts_matrix_room_ids_only() {
+        let target = parse_multi_channel_send_target(
+            MultiChannelTransport::Matrix,
+            "room:!ops123:example.org",
+        )
+        .expect("matrix room id should parse");
+        assert_eq!(target.conversation_id, "!ops123:example.org");
+
+        let err =
+            parse_multi_channel_send_target(MultiChannelTransport::Matrix, "#ops:example.org")
+                .expect_err("room alias should fail");
+        assert!(err.to_string().contains("expected room id"));
+    }
+
+    #[test]
+    fn unit_parse_multi_channel_send_target_normalizes_email_addresses() {
+        let target = parse_multi_channel_send_target(
+            MultiChannelTransport::Email,
+            "mailto:Ops <Ops@Example.com>",
+        )
+        .expect("email address should parse");
+        assert_eq!(target.normalized, "ops@example.com");
+        assert_eq!(target.conversation_id, "email:ops@example.com");
+        assert_eq!(
+            target.metadata.get("email_reply_to"),
+            Some(&Value::String("ops@example.com".to_string()))
+        );
+
+        let err = parse_multi_channel_send_target(MultiChannelTransport::Email, "ops-team")
+            .expect_err("bare name should fail");
+        assert!(err.to_string().contains("invalid email target"));
+    }
+
     #[test]
     fn unit_resolve_multi_channel_send_text_prefers_inline_text() {
         let text = resolve_multi_channel_send_text(Some("hello"), None).expect("text");
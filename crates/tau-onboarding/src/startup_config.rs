@@ -27,6 +27,9 @@ pub fn build_auth_command_config(cli: &Cli) -> AuthCommandConfig {
         openai_auth_mode: cli.openai_auth_mode.into(),
         anthropic_auth_mode: cli.anthropic_auth_mode.into(),
         google_auth_mode: cli.google_auth_mode.into(),
+        bedrock_auth_mode: cli.bedrock_auth_mode.into(),
+        vertex_auth_mode: cli.vertex_auth_mode.into(),
+        vertex_service_account_file: cli.vertex_service_account_file.clone(),
         provider_subscription_strict: cli.provider_subscription_strict,
         openai_codex_backend: cli.openai_codex_backend,
         openai_codex_cli: cli.openai_codex_cli.clone(),
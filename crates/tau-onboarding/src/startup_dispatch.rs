@@ -157,12 +157,14 @@ where
     FResolveModelCatalog:
         for<'a> FnOnce(&'a Cli) -> Pin<Box<dyn Future<Output = Result<TModelCatalog>> + 'a>>,
     FValidateModelCatalog: FnOnce(&TModelCatalog, &TModelRef, &TFallbackModelRefs) -> Result<()>,
-    FBuildClientWithFallbacks: FnOnce(&Cli, &TModelRef, &TFallbackModelRefs) -> Result<TClient>,
+    FBuildClientWithFallbacks:
+        FnOnce(&Cli, &TModelRef, &TFallbackModelRefs, &TModelCatalog) -> Result<TClient>,
 {
     let (model_ref, fallback_model_refs) = resolve_models(cli)?;
     let model_catalog = resolve_model_catalog(cli).await?;
     validate_model_catalog(&model_catalog, &model_ref, &fallback_model_refs)?;
-    let client = build_client_with_fallbacks(cli, &model_ref, &fallback_model_refs)?;
+    let client =
+        build_client_with_fallbacks(cli, &model_ref, &fallback_model_refs, &model_catalog)?;
     Ok(StartupModelRuntimeResolution {
         model_ref,
         fallback_model_refs,
@@ -178,8 +180,8 @@ type ResolveModelCatalogCallback<'a, TModelCatalog> = Box<
 >;
 type ValidateModelCatalogCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog> =
     Box<dyn FnOnce(&TModelCatalog, &TModelRef, &TFallbackModelRefs) -> Result<()> + 'a>;
-type BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TClient> =
-    Box<dyn FnOnce(&Cli, &TModelRef, &TFallbackModelRefs) -> Result<TClient> + 'a>;
+type BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog, TClient> =
+    Box<dyn FnOnce(&Cli, &TModelRef, &TFallbackModelRefs, &TModelCatalog) -> Result<TClient> + 'a>;
 type RunSkillsBootstrapCallback<'a, TSkillsBootstrap> = Box<
     dyn for<'b> FnOnce(&'b Cli) -> Pin<Box<dyn Future<Output = Result<TSkillsBootstrap>> + 'b>>
         + 'a,
@@ -240,7 +242,7 @@ pub struct ResolveStartupRuntimeFromCliRequest<
     pub validate_model_catalog:
         ValidateModelCatalogCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog>,
     pub build_client_with_fallbacks:
-        BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TClient>,
+        BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog, TClient>,
     pub run_skills_bootstrap: RunSkillsBootstrapCallback<'a, TSkillsBootstrap>,
     pub execute_package_activate_on_startup:
         ExecutePackageActivateOnStartupCallback<'a, TPackageActivation>,
@@ -322,7 +324,7 @@ pub struct ExecuteStartupRuntimeFromCliWithModesRequest<
     pub validate_model_catalog:
         ValidateModelCatalogCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog>,
     pub build_client_with_fallbacks:
-        BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TClient>,
+        BuildClientWithFallbacksCallback<'a, TModelRef, TFallbackModelRefs, TModelCatalog, TClient>,
     pub run_skills_bootstrap: RunSkillsBootstrapCallback<'a, TSkillsBootstrap>,
     pub execute_package_activate_on_startup:
         ExecutePackageActivateOnStartupCallback<'a, TPackageActivation>,
@@ -764,7 +766,7 @@ mod tests {
                 assert_eq!(fallback.len(), 2);
                 Ok(())
             },
-            |_cli, model, _fallback| Ok(format!("client:{model}")),
+            |_cli, model, _fallback, catalog| Ok(format!("client:{model}@{catalog}")),
         )
         .await
         .expect("runtime");
@@ -775,7 +777,7 @@ mod tests {
             vec!["fallback-a".to_string(), "fallback-b".to_string()]
         );
         assert_eq!(model_catalog, "catalog-v1");
-        assert_eq!(client, "client:primary-model");
+        assert_eq!(client, "client:primary-model@catalog-v1");
     }
 
     #[tokio::test]
@@ -793,7 +795,7 @@ mod tests {
             },
             |_cli| Box::pin(async { Ok("catalog".to_string()) }),
             |_catalog, _model, _fallback| Ok(()),
-            |_cli, model, fallback| {
+            |_cli, model, fallback, _catalog| {
                 build_calls.fetch_add(1, Ordering::Relaxed);
                 Ok(format!("client:{model}+{}", fallback.len()))
             },
@@ -817,7 +819,7 @@ mod tests {
                 stage.store(1, Ordering::Relaxed);
                 Ok(())
             },
-            |_cli, _model, _fallback| {
+            |_cli, _model, _fallback, _catalog| {
                 assert_eq!(stage.load(Ordering::Relaxed), 1);
                 stage.store(2, Ordering::Relaxed);
                 Ok("client".to_string())
@@ -837,7 +839,7 @@ mod tests {
             |_cli| Ok(("primary".to_string(), vec!["fallback".to_string()])),
             |_cli| Box::pin(async { Ok("catalog".to_string()) }),
             |_catalog, _model, _fallback| Err(anyhow!("catalog validation failed")),
-            |_cli, _model, _fallback| -> Result<String> {
+            |_cli, _model, _fallback, _catalog| -> Result<String> {
                 panic!("client builder should not run after validation error");
             },
         )
@@ -870,7 +872,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, model, _fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, model, _fallback, _catalog| {
                 Ok(format!("client:{model}"))
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -920,7 +922,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, model, fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, model, fallback, _catalog| {
                 Ok(format!("client:{model}+{}", fallback.len()))
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -972,7 +974,7 @@ mod tests {
                 },
             ),
             build_client_with_fallbacks: Box::new(
-                |_cli, _model: &String, _fallback: &Vec<String>| {
+                |_cli, _model: &String, _fallback: &Vec<String>, _catalog| {
                     client_builder_calls.fetch_add(1, Ordering::Relaxed);
                     Ok("client".to_string())
                 },
@@ -1011,7 +1013,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, _model, _fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, _model, _fallback, _catalog| {
                 Ok("client".to_string())
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -1049,7 +1051,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, model, _fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, model, _fallback, _catalog| {
                 Ok(format!("client:{model}"))
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -1332,7 +1334,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, model, _fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, model, _fallback, _catalog| {
                 Ok(format!("client:{model}"))
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -1401,7 +1403,7 @@ mod tests {
             }),
             resolve_model_catalog: Box::new(|_cli| Box::pin(async { Ok("catalog".to_string()) })),
             validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-            build_client_with_fallbacks: Box::new(|_cli, _model, _fallback| {
+            build_client_with_fallbacks: Box::new(|_cli, _model, _fallback, _catalog| {
                 Ok("client".to_string())
             }),
             run_skills_bootstrap: Box::new(|_cli| {
@@ -1459,7 +1461,7 @@ mod tests {
                     Box::pin(async { Ok("catalog".to_string()) })
                 }),
                 validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-                build_client_with_fallbacks: Box::new(|_cli, _model, _fallback| {
+                build_client_with_fallbacks: Box::new(|_cli, _model, _fallback, _catalog| {
                     Ok("client".to_string())
                 }),
                 run_skills_bootstrap: Box::new(|_cli| {
@@ -1527,7 +1529,7 @@ mod tests {
                     Box::pin(async { Ok("catalog".to_string()) })
                 }),
                 validate_model_catalog: Box::new(|_catalog, _model, _fallback| Ok(())),
-                build_client_with_fallbacks: Box::new(|_cli, _model, _fallback| {
+                build_client_with_fallbacks: Box::new(|_cli, _model, _fallback, _catalog| {
                     Ok("client".to_string())
                 }),
                 run_skills_bootstrap: Box::new(|_cli| {
@@ -4,15 +4,16 @@
 //! inputs and runs the local execution loop. It is the concrete implementation
 //! behind local dispatch mode and preserves startup report metadata boundaries.
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
 use serde_json::Value;
-use tau_agent_core::{Agent, AgentConfig, AgentEvent, SafetyMode, SafetyPolicy};
-use tau_ai::{LlmClient, ModelRef};
-use tau_cli::{Cli, CliOrchestratorMode};
+use tau_agent_core::{Agent, AgentConfig, AgentEvent, ModelCostRates, SafetyMode, SafetyPolicy};
+use tau_ai::{LlmClient, ModelRef, ReasoningConfig, ReasoningEffort, TokenizerFamily};
+use tau_cli::{Cli, CliOrchestratorMode, CliReasoningEffort};
 use tau_core::current_unix_timestamp_ms;
 use tau_diagnostics::{build_doctor_command_config, DoctorCommandConfig};
 use tau_provider::AuthCommandConfig;
@@ -27,10 +28,12 @@ const EXTENSION_TOOL_HOOK_PAYLOAD_SCHEMA_VERSION: u32 = 1;
 pub struct LocalRuntimeAgentSettings {
     pub max_turns: usize,
     pub max_tokens: Option<u32>,
+    pub reasoning: ReasoningConfig,
     pub max_parallel_tool_calls: usize,
     pub max_context_messages: Option<usize>,
     pub max_estimated_input_tokens: Option<u32>,
     pub max_estimated_total_tokens: Option<u32>,
+    pub tokenizer: Option<TokenizerFamily>,
     pub context_compaction_warn_threshold_percent: u8,
     pub context_compaction_aggressive_threshold_percent: u8,
     pub context_compaction_emergency_threshold_percent: u8,
@@ -45,6 +48,7 @@ pub struct LocalRuntimeAgentSettings {
     pub model_input_cost_per_million: Option<f64>,
     pub model_cached_input_cost_per_million: Option<f64>,
     pub model_output_cost_per_million: Option<f64>,
+    pub route_cost_rates: HashMap<String, ModelCostRates>,
     pub cost_budget_usd: Option<f64>,
     pub cost_alert_thresholds_percent: Vec<u8>,
     pub prompt_sanitizer_enabled: bool,
@@ -75,6 +79,18 @@ pub fn derive_preflight_token_limits(
     (max_estimated_input_tokens, max_estimated_total_tokens)
 }
 
+/// Resolve the extended thinking/reasoning request settings from CLI flags.
+pub fn resolve_local_runtime_reasoning_config(cli: &Cli) -> ReasoningConfig {
+    ReasoningConfig {
+        budget_tokens: cli.agent_reasoning_budget_tokens,
+        effort: cli.agent_reasoning_effort.map(|effort| match effort {
+            CliReasoningEffort::Low => ReasoningEffort::Low,
+            CliReasoningEffort::Medium => ReasoningEffort::Medium,
+            CliReasoningEffort::High => ReasoningEffort::High,
+        }),
+    }
+}
+
 pub(crate) fn build_local_runtime_agent_config(
     model_ref: &ModelRef,
     system_prompt: &str,
@@ -85,10 +101,12 @@ pub(crate) fn build_local_runtime_agent_config(
         system_prompt: system_prompt.to_string(),
         max_turns: settings.max_turns,
         max_tokens: settings.max_tokens,
+        reasoning: settings.reasoning,
         max_parallel_tool_calls: settings.max_parallel_tool_calls,
         max_context_messages: settings.max_context_messages,
         max_estimated_input_tokens: settings.max_estimated_input_tokens,
         max_estimated_total_tokens: settings.max_estimated_total_tokens,
+        tokenizer: settings.tokenizer,
         context_compaction_warn_threshold_percent: settings
             .context_compaction_warn_threshold_percent,
         context_compaction_aggressive_threshold_percent: settings
@@ -108,6 +126,7 @@ pub(crate) fn build_local_runtime_agent_config(
         model_input_cost_per_million: settings.model_input_cost_per_million,
         model_cached_input_cost_per_million: settings.model_cached_input_cost_per_million,
         model_output_cost_per_million: settings.model_output_cost_per_million,
+        route_cost_rates: settings.route_cost_rates.clone(),
         cost_budget_usd: settings.cost_budget_usd,
         cost_alert_thresholds_percent: settings.cost_alert_thresholds_percent.clone(),
         ..AgentConfig::default()
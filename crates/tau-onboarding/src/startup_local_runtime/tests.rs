@@ -27,7 +27,7 @@ use super::{
 use async_trait::async_trait;
 use clap::Parser;
 use serde_json::Value;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::{
@@ -38,8 +38,8 @@ use tau_agent_core::{
     Agent, AgentConfig, AgentError, AgentEvent, AgentTool, SafetyMode, ToolExecutionResult,
 };
 use tau_ai::{
-    ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, Message, ModelRef, TauAiError,
-    ToolDefinition,
+    ChatRequest, ChatResponse, ChatUsage, ContentBlock, LlmClient, Message, ModelRef,
+    ReasoningConfig, TauAiError, TokenizerFamily, ToolDefinition,
 };
 use tau_cli::{Cli, CliOrchestratorMode};
 use tau_tools::tools::ToolPolicy;
@@ -157,6 +157,7 @@ async fn functional_build_local_runtime_agent_enforces_preflight_token_limits()
             max_context_messages: Some(256),
             max_estimated_input_tokens: Some(1),
             max_estimated_total_tokens: Some(5),
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -171,6 +172,7 @@ async fn functional_build_local_runtime_agent_enforces_preflight_token_limits()
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -179,6 +181,7 @@ async fn functional_build_local_runtime_agent_enforces_preflight_token_limits()
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -207,6 +210,7 @@ fn unit_build_local_runtime_agent_preserves_system_prompt_message() {
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -221,6 +225,7 @@ fn unit_build_local_runtime_agent_preserves_system_prompt_message() {
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -229,6 +234,7 @@ fn unit_build_local_runtime_agent_preserves_system_prompt_message() {
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -253,6 +259,7 @@ fn unit_build_local_runtime_agent_applies_prompt_sanitizer_settings() {
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -267,6 +274,7 @@ fn unit_build_local_runtime_agent_applies_prompt_sanitizer_settings() {
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -275,6 +283,7 @@ fn unit_build_local_runtime_agent_applies_prompt_sanitizer_settings() {
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Block,
             secret_leak_redaction_token: "[SECRET-MASK]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -301,6 +310,10 @@ async fn functional_build_local_runtime_agent_applies_cost_budget_and_pricing_se
                 output_tokens: 0,
                 total_tokens: 100_000,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         }])),
     });
@@ -315,6 +328,7 @@ async fn functional_build_local_runtime_agent_applies_cost_budget_and_pricing_se
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -329,6 +343,7 @@ async fn functional_build_local_runtime_agent_applies_cost_budget_and_pricing_se
             model_input_cost_per_million: Some(10.0),
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: Some(0.0),
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: Some(1.0),
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -337,6 +352,7 @@ async fn functional_build_local_runtime_agent_applies_cost_budget_and_pricing_se
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -370,6 +386,7 @@ async fn functional_build_local_runtime_agent_registers_builtin_tools_with_model
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -384,6 +401,7 @@ async fn functional_build_local_runtime_agent_registers_builtin_tools_with_model
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -392,6 +410,7 @@ async fn functional_build_local_runtime_agent_registers_builtin_tools_with_model
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -432,6 +451,7 @@ async fn spec_2561_c02_build_local_runtime_agent_applies_custom_compaction_polic
             max_context_messages: Some(256),
             max_estimated_input_tokens: Some(8_000),
             max_estimated_total_tokens: Some(10_000),
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 1,
             context_compaction_aggressive_threshold_percent: 2,
             context_compaction_emergency_threshold_percent: 100,
@@ -446,6 +466,7 @@ async fn spec_2561_c02_build_local_runtime_agent_applies_custom_compaction_polic
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -454,6 +475,7 @@ async fn spec_2561_c02_build_local_runtime_agent_applies_custom_compaction_polic
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -505,6 +527,7 @@ fn spec_2561_c02_build_local_runtime_agent_config_maps_all_compaction_policy_fie
         max_context_messages: Some(123),
         max_estimated_input_tokens: Some(8_000),
         max_estimated_total_tokens: Some(10_000),
+        tokenizer: Some(TokenizerFamily::O200kBase),
         context_compaction_warn_threshold_percent: 11,
         context_compaction_aggressive_threshold_percent: 22,
         context_compaction_emergency_threshold_percent: 33,
@@ -519,6 +542,7 @@ fn spec_2561_c02_build_local_runtime_agent_config_maps_all_compaction_policy_fie
         model_input_cost_per_million: Some(1.1),
         model_cached_input_cost_per_million: Some(0.22),
         model_output_cost_per_million: Some(3.3),
+        route_cost_rates: HashMap::new(),
         cost_budget_usd: Some(12.34),
         cost_alert_thresholds_percent: vec![33, 66, 99],
         prompt_sanitizer_enabled: true,
@@ -527,6 +551,7 @@ fn spec_2561_c02_build_local_runtime_agent_config_maps_all_compaction_policy_fie
         secret_leak_detector_enabled: true,
         secret_leak_detector_mode: SafetyMode::Warn,
         secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+        reasoning: ReasoningConfig::default(),
     };
 
     let config = build_local_runtime_agent_config(&model_ref, "system prompt", &settings);
@@ -539,6 +564,7 @@ fn spec_2561_c02_build_local_runtime_agent_config_maps_all_compaction_policy_fie
     assert_eq!(config.max_context_messages, Some(123));
     assert_eq!(config.max_estimated_input_tokens, Some(8_000));
     assert_eq!(config.max_estimated_total_tokens, Some(10_000));
+    assert_eq!(config.tokenizer, Some(TokenizerFamily::O200kBase));
     assert_eq!(config.context_compaction_warn_threshold_percent, 11);
     assert_eq!(config.context_compaction_aggressive_threshold_percent, 22);
     assert_eq!(config.context_compaction_emergency_threshold_percent, 33);
@@ -574,6 +600,7 @@ async fn integration_build_local_runtime_agent_respects_max_turns_limit() {
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -588,6 +615,7 @@ async fn integration_build_local_runtime_agent_respects_max_turns_limit() {
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -596,6 +624,7 @@ async fn integration_build_local_runtime_agent_respects_max_turns_limit() {
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -623,6 +652,7 @@ fn regression_build_local_runtime_agent_skips_empty_system_prompt_message() {
             max_context_messages: Some(256),
             max_estimated_input_tokens: None,
             max_estimated_total_tokens: None,
+            tokenizer: None,
             context_compaction_warn_threshold_percent: 80,
             context_compaction_aggressive_threshold_percent: 85,
             context_compaction_emergency_threshold_percent: 95,
@@ -637,6 +667,7 @@ fn regression_build_local_runtime_agent_skips_empty_system_prompt_message() {
             model_input_cost_per_million: None,
             model_cached_input_cost_per_million: None,
             model_output_cost_per_million: None,
+            route_cost_rates: HashMap::new(),
             cost_budget_usd: None,
             cost_alert_thresholds_percent: vec![80, 100],
             prompt_sanitizer_enabled: true,
@@ -645,6 +676,7 @@ fn regression_build_local_runtime_agent_skips_empty_system_prompt_message() {
             secret_leak_detector_enabled: true,
             secret_leak_detector_mode: SafetyMode::Warn,
             secret_leak_redaction_token: "[TAU-SECRET-REDACTED]".to_string(),
+            reasoning: ReasoningConfig::default(),
         },
         ToolPolicy::new(vec![std::env::temp_dir()]),
     );
@@ -23,7 +23,7 @@ use tau_browser_automation::browser_automation_live::{
 };
 use tau_cli::validation::{
     validate_browser_automation_live_runner_cli, validate_deployment_contract_runner_cli,
-    validate_events_runner_cli, validate_gateway_contract_runner_cli,
+    validate_discord_bridge_cli, validate_events_runner_cli, validate_gateway_contract_runner_cli,
     validate_gateway_openresponses_server_cli, validate_github_issues_bridge_cli,
     validate_multi_agent_contract_runner_cli, validate_multi_channel_contract_runner_cli,
     validate_multi_channel_live_connectors_runner_cli, validate_multi_channel_live_runner_cli,
@@ -40,7 +40,8 @@ use tau_gateway::{
     GatewayOpenResponsesSkillPrompt, GatewayRuntimeConfig, GatewayToolRegistrarFn,
 };
 use tau_multi_channel::{
-    MultiChannelCommandHandlers, MultiChannelLiveConnectorsConfig, MultiChannelLiveRuntimeConfig,
+    channel_policy_path_for_state_dir, MultiChannelCommandHandlers, MultiChannelEmailAccountConfig,
+    MultiChannelLiveConnectorsConfig, MultiChannelLiveRuntimeConfig,
     MultiChannelMediaUnderstandingConfig, MultiChannelOutboundConfig, MultiChannelPairingEvaluator,
     MultiChannelRuntimeConfig, MultiChannelTelemetryConfig,
 };
@@ -72,6 +73,7 @@ pub enum BridgeTransportMode {
     None,
     GithubIssuesBridge,
     SlackBridge,
+    DiscordBridge,
     EventsRunner,
 }
 
@@ -94,6 +96,7 @@ pub enum TransportRuntimeMode {
     GatewayOpenResponsesServer,
     GithubIssuesBridge,
     SlackBridge,
+    DiscordBridge,
     EventsRunner,
     MultiChannelContractRunner,
     MultiChannelLiveRunner,
@@ -112,6 +115,7 @@ pub trait TransportRuntimeExecutor {
     async fn run_gateway_openresponses_server(&self) -> Result<()>;
     async fn run_github_issues_bridge(&self) -> Result<()>;
     async fn run_slack_bridge(&self) -> Result<()>;
+    async fn run_discord_bridge(&self) -> Result<()>;
     async fn run_events_runner(&self) -> Result<()>;
     async fn run_multi_channel_contract_runner(&self) -> Result<()>;
     async fn run_multi_channel_live_runner(&self) -> Result<()>;
@@ -144,6 +148,10 @@ where
             executor.run_slack_bridge().await?;
             Ok(true)
         }
+        TransportRuntimeMode::DiscordBridge => {
+            executor.run_discord_bridge().await?;
+            Ok(true)
+        }
         TransportRuntimeMode::EventsRunner => {
             executor.run_events_runner().await?;
             Ok(true)
@@ -200,6 +208,7 @@ pub fn validate_transport_mode_cli(cli: &Cli) -> Result<()> {
     validate_removed_contract_runner_flags_cli(cli)?;
     validate_github_issues_bridge_cli(cli)?;
     validate_slack_bridge_cli(cli)?;
+    validate_discord_bridge_cli(cli)?;
     validate_events_runner_cli(cli)?;
     validate_multi_channel_contract_runner_cli(cli)?;
     validate_multi_channel_live_runner_cli(cli)?;
@@ -349,6 +358,8 @@ pub fn resolve_bridge_transport_mode(cli: &Cli) -> BridgeTransportMode {
         BridgeTransportMode::GithubIssuesBridge
     } else if cli.slack_bridge {
         BridgeTransportMode::SlackBridge
+    } else if cli.discord_bridge {
+        BridgeTransportMode::DiscordBridge
     } else if cli.events_runner {
         BridgeTransportMode::EventsRunner
     } else {
@@ -384,6 +395,7 @@ pub fn resolve_transport_runtime_mode(cli: &Cli) -> TransportRuntimeMode {
             return TransportRuntimeMode::GithubIssuesBridge;
         }
         BridgeTransportMode::SlackBridge => return TransportRuntimeMode::SlackBridge,
+        BridgeTransportMode::DiscordBridge => return TransportRuntimeMode::DiscordBridge,
         BridgeTransportMode::EventsRunner => return TransportRuntimeMode::EventsRunner,
         BridgeTransportMode::None => {}
     }
@@ -675,6 +687,23 @@ pub struct SlackBridgeCliConfig {
     pub artifact_retention_days: u64,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Discord gateway bridge settings resolved from CLI flags and credentials.
+pub struct DiscordBridgeCliConfig {
+    pub state_dir: PathBuf,
+    pub bot_token: String,
+    pub application_id: u64,
+    pub command_prefix: String,
+    pub command_guild_ids: Vec<u64>,
+    pub guild_config_path: PathBuf,
+    pub embed_responses: bool,
+    pub processed_event_cap: usize,
+    pub max_event_age_seconds: u64,
+    pub coalescing_window_ms: u64,
+    pub reconnect_delay_ms: u64,
+    pub artifact_retention_days: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Public struct `GithubIssuesBridgeCliConfig` used across Tau components.
 pub struct GithubIssuesBridgeCliConfig {
@@ -689,6 +718,7 @@ pub struct GithubIssuesBridgeCliConfig {
     pub required_issue_numbers: Vec<u64>,
     pub include_issue_body: bool,
     pub include_edited_comments: bool,
+    pub pull_request_review: bool,
     pub processed_event_cap: usize,
     pub retry_max_attempts: usize,
     pub retry_base_delay_ms: u64,
@@ -1068,6 +1098,83 @@ where
     .await
 }
 
+pub fn build_discord_bridge_cli_config(cli: &Cli, bot_token: String) -> DiscordBridgeCliConfig {
+    DiscordBridgeCliConfig {
+        state_dir: cli.discord_state_dir.clone(),
+        bot_token,
+        application_id: cli.discord_application_id.unwrap_or(0),
+        command_prefix: cli.discord_command_prefix.trim().to_string(),
+        command_guild_ids: cli.discord_command_guild_ids.clone(),
+        guild_config_path: cli
+            .discord_guild_config
+            .clone()
+            .unwrap_or_else(|| cli.discord_state_dir.join("guilds.json")),
+        embed_responses: cli.discord_embed_responses,
+        processed_event_cap: cli.discord_processed_event_cap.max(1),
+        max_event_age_seconds: cli.discord_max_event_age_seconds,
+        coalescing_window_ms: cli.discord_coalescing_window_ms,
+        reconnect_delay_ms: cli.discord_reconnect_delay_ms.max(1),
+        artifact_retention_days: cli.discord_artifact_retention_days,
+    }
+}
+
+pub fn resolve_discord_bridge_token_from_cli<FResolveSecret>(
+    cli: &Cli,
+    mut resolve_secret: FResolveSecret,
+) -> Result<String>
+where
+    FResolveSecret: FnMut(Option<&str>, Option<&str>, &str) -> Result<Option<String>>,
+{
+    resolve_secret(
+        cli.discord_bot_token.as_deref(),
+        cli.discord_bot_token_id.as_deref(),
+        "--discord-bot-token-id",
+    )?
+    .ok_or_else(|| {
+        anyhow!(
+            "--discord-bot-token (or --discord-bot-token-id) is required when --discord-bridge is set"
+        )
+    })
+}
+
+pub async fn run_discord_bridge_if_requested<FResolveToken, FRunBridge, Fut>(
+    cli: &Cli,
+    resolve_token: FResolveToken,
+    run_bridge: FRunBridge,
+) -> Result<bool>
+where
+    FResolveToken: FnOnce() -> Result<String>,
+    FRunBridge: FnOnce(DiscordBridgeCliConfig) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    if !cli.discord_bridge {
+        return Ok(false);
+    }
+    let bot_token = resolve_token()?;
+    let config = build_discord_bridge_cli_config(cli, bot_token);
+    run_bridge(config).await?;
+    Ok(true)
+}
+
+pub async fn run_discord_bridge_with_runtime_defaults_if_requested<FResolveToken, FRunBridge, Fut>(
+    cli: &Cli,
+    model_ref: &ModelRef,
+    system_prompt: &str,
+    resolve_token: FResolveToken,
+    run_bridge: FRunBridge,
+) -> Result<bool>
+where
+    FResolveToken: FnOnce() -> Result<String>,
+    FRunBridge: FnOnce(DiscordBridgeCliConfig, TransportRuntimeDefaults) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let runtime_defaults = build_transport_runtime_defaults(cli, model_ref, system_prompt);
+    run_discord_bridge_if_requested(cli, resolve_token, move |config| {
+        run_bridge(config, runtime_defaults)
+    })
+    .await
+}
+
 pub fn build_github_issues_bridge_cli_config(
     cli: &Cli,
     repo_slug: String,
@@ -1089,6 +1196,7 @@ pub fn build_github_issues_bridge_cli_config(
         required_issue_numbers: cli.github_issue_number.clone(),
         include_issue_body: cli.github_include_issue_body,
         include_edited_comments: cli.github_include_edited_comments,
+        pull_request_review: cli.github_pull_request_review,
         processed_event_cap: cli.github_processed_event_cap.max(1),
         retry_max_attempts: cli.github_retry_max_attempts.max(1),
         retry_base_delay_ms: cli.github_retry_base_delay_ms.max(1),
@@ -1302,6 +1410,41 @@ pub fn build_multi_channel_live_connectors_config(cli: &Cli) -> MultiChannelLive
         whatsapp_webhook_app_secret: resolve_non_empty_cli_value(
             cli.multi_channel_whatsapp_webhook_app_secret.as_deref(),
         ),
+        matrix_mode: cli.multi_channel_matrix_ingress_mode.into(),
+        matrix_homeserver_url: cli.multi_channel_matrix_homeserver_url.trim().to_string(),
+        matrix_access_token: resolve_multi_channel_outbound_secret(
+            cli,
+            cli.multi_channel_matrix_access_token.as_deref(),
+            "matrix-access-token",
+        ),
+        matrix_user_id: resolve_non_empty_cli_value(cli.multi_channel_matrix_user_id.as_deref()),
+        matrix_auto_join_invites: cli.multi_channel_matrix_auto_join_invites,
+        email_mode: cli.multi_channel_email_ingress_mode.into(),
+        email: build_multi_channel_email_account_config(cli),
+        email_idle_timeout_ms: cli.multi_channel_email_idle_timeout_ms,
+        email_trusted_authserv_id: resolve_non_empty_cli_value(
+            cli.multi_channel_email_trusted_authserv_id.as_deref(),
+        ),
+        channel_policy_path: channel_policy_path_for_state_dir(&cli.multi_channel_state_dir),
+    }
+}
+
+pub fn build_multi_channel_email_account_config(cli: &Cli) -> MultiChannelEmailAccountConfig {
+    MultiChannelEmailAccountConfig {
+        address: resolve_non_empty_cli_value(cli.multi_channel_email_address.as_deref()),
+        username: resolve_non_empty_cli_value(cli.multi_channel_email_username.as_deref()),
+        password: resolve_multi_channel_outbound_secret(
+            cli,
+            cli.multi_channel_email_password.as_deref(),
+            "email-password",
+        ),
+        imap_host: cli.multi_channel_email_imap_host.trim().to_string(),
+        imap_port: cli.multi_channel_email_imap_port,
+        imap_tls: cli.multi_channel_email_imap_tls.into(),
+        imap_mailbox: cli.multi_channel_email_imap_mailbox.trim().to_string(),
+        smtp_host: cli.multi_channel_email_smtp_host.trim().to_string(),
+        smtp_port: cli.multi_channel_email_smtp_port,
+        smtp_tls: cli.multi_channel_email_smtp_tls.into(),
     }
 }
 
@@ -1372,6 +1515,13 @@ pub fn build_multi_channel_outbound_config(cli: &Cli) -> MultiChannelOutboundCon
             cli.multi_channel_whatsapp_phone_number_id.as_deref(),
             "whatsapp-phone-number-id",
         ),
+        matrix_homeserver_url: cli.multi_channel_matrix_homeserver_url.trim().to_string(),
+        matrix_access_token: resolve_multi_channel_outbound_secret(
+            cli,
+            cli.multi_channel_matrix_access_token.as_deref(),
+            "matrix-access-token",
+        ),
+        email: build_multi_channel_email_account_config(cli),
     }
 }
 
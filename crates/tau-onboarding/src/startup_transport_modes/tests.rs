@@ -2,20 +2,21 @@
 
 use super::{
     build_browser_automation_live_runner_config, build_deployment_contract_runner_config,
-    build_events_runner_cli_config, build_gateway_contract_runner_config,
-    build_gateway_openresponses_server_config, build_github_issues_bridge_cli_config,
-    build_multi_agent_contract_runner_config, build_multi_channel_contract_runner_config,
-    build_multi_channel_live_connectors_config, build_multi_channel_live_runner_config,
-    build_multi_channel_media_config, build_multi_channel_outbound_config,
-    build_multi_channel_runtime_dependencies, build_multi_channel_telemetry_config,
-    build_runtime_heartbeat_scheduler_config, build_slack_bridge_cli_config,
-    build_transport_doctor_config, build_transport_runtime_defaults,
+    build_discord_bridge_cli_config, build_events_runner_cli_config,
+    build_gateway_contract_runner_config, build_gateway_openresponses_server_config,
+    build_github_issues_bridge_cli_config, build_multi_agent_contract_runner_config,
+    build_multi_channel_contract_runner_config, build_multi_channel_live_connectors_config,
+    build_multi_channel_live_runner_config, build_multi_channel_media_config,
+    build_multi_channel_outbound_config, build_multi_channel_runtime_dependencies,
+    build_multi_channel_telemetry_config, build_runtime_heartbeat_scheduler_config,
+    build_slack_bridge_cli_config, build_transport_doctor_config, build_transport_runtime_defaults,
     build_voice_contract_runner_config, build_voice_live_runner_config,
     execute_transport_runtime_mode, map_gateway_openresponses_auth_mode,
     resolve_bridge_transport_mode, resolve_contract_transport_mode,
-    resolve_gateway_openresponses_auth, resolve_github_issues_bridge_repo_and_token_from_cli,
-    resolve_multi_channel_outbound_secret, resolve_multi_channel_transport_mode,
-    resolve_slack_bridge_tokens_from_cli, resolve_transport_runtime_mode,
+    resolve_discord_bridge_token_from_cli, resolve_gateway_openresponses_auth,
+    resolve_github_issues_bridge_repo_and_token_from_cli, resolve_multi_channel_outbound_secret,
+    resolve_multi_channel_transport_mode, resolve_slack_bridge_tokens_from_cli,
+    resolve_transport_runtime_mode, run_discord_bridge_if_requested,
     run_events_runner_if_requested, run_events_runner_with_runtime_defaults_if_requested,
     run_github_issues_bridge_if_requested,
     run_github_issues_bridge_with_runtime_defaults_if_requested,
@@ -23,8 +24,9 @@ use super::{
     run_multi_channel_live_runner_with_runtime_dependencies_if_requested,
     run_slack_bridge_if_requested, run_slack_bridge_with_runtime_defaults_if_requested,
     run_transport_mode_if_requested, validate_transport_mode_cli, BridgeTransportMode,
-    ContractTransportMode, EventsRunnerCliConfig, MultiChannelTransportMode, SlackBridgeCliConfig,
-    TransportRuntimeDefaults, TransportRuntimeExecutor, TransportRuntimeMode,
+    ContractTransportMode, DiscordBridgeCliConfig, EventsRunnerCliConfig,
+    MultiChannelTransportMode, SlackBridgeCliConfig, TransportRuntimeDefaults,
+    TransportRuntimeExecutor, TransportRuntimeMode,
 };
 use async_trait::async_trait;
 use clap::Parser;
@@ -137,6 +139,10 @@ impl TransportRuntimeExecutor for RecordingTransportRuntimeExecutor {
         self.record(TransportRuntimeMode::SlackBridge, "slack")
     }
 
+    async fn run_discord_bridge(&self) -> anyhow::Result<()> {
+        self.record(TransportRuntimeMode::DiscordBridge, "discord")
+    }
+
     async fn run_events_runner(&self) -> anyhow::Result<()> {
         self.record(TransportRuntimeMode::EventsRunner, "events")
     }
@@ -210,6 +216,7 @@ fn write_integration_secret(
             encryption: CredentialStoreEncryptionMode::None,
             providers: BTreeMap::new(),
             integrations: BTreeMap::new(),
+            provider_key_pools: BTreeMap::new(),
         });
     store.integrations.insert(
         integration_id.to_string(),
@@ -459,6 +466,21 @@ fn integration_resolve_bridge_transport_mode_selects_slack_bridge() {
     );
 }
 
+#[test]
+fn integration_resolve_bridge_transport_mode_selects_discord_bridge() {
+    let mut cli = parse_cli_with_stack();
+    cli.discord_bridge = true;
+    cli.events_runner = true;
+    assert_eq!(
+        resolve_bridge_transport_mode(&cli),
+        BridgeTransportMode::DiscordBridge
+    );
+    assert_eq!(
+        resolve_transport_runtime_mode(&cli),
+        TransportRuntimeMode::DiscordBridge
+    );
+}
+
 #[test]
 fn regression_resolve_bridge_transport_mode_selects_events_runner() {
     let mut cli = parse_cli_with_stack();
@@ -993,6 +1015,26 @@ fn regression_build_slack_bridge_cli_config_enforces_minimums() {
     assert_eq!(config.retry_base_delay_ms, 1);
 }
 
+#[test]
+fn regression_build_discord_bridge_cli_config_enforces_minimums_and_guild_config_default() {
+    let mut cli = parse_cli_with_stack();
+    cli.discord_processed_event_cap = 0;
+    cli.discord_reconnect_delay_ms = 0;
+    cli.discord_application_id = Some(42);
+    cli.discord_command_prefix = "  /tau  ".to_string();
+
+    let config = build_discord_bridge_cli_config(&cli, "bot-token".to_string());
+    assert_eq!(config.bot_token, "bot-token");
+    assert_eq!(config.application_id, 42);
+    assert_eq!(config.command_prefix, "/tau");
+    assert_eq!(config.processed_event_cap, 1);
+    assert_eq!(config.reconnect_delay_ms, 1);
+    assert_eq!(
+        config.guild_config_path,
+        cli.discord_state_dir.join("guilds.json")
+    );
+}
+
 #[test]
 fn regression_build_github_issues_bridge_cli_config_enforces_minimums() {
     let mut cli = parse_cli_with_stack();
@@ -1883,6 +1925,7 @@ fn integration_build_github_issues_bridge_cli_config_preserves_runtime_fields()
     cli.github_issue_number = vec![7, 42];
     cli.github_include_issue_body = true;
     cli.github_include_edited_comments = true;
+    cli.github_pull_request_review = true;
     cli.github_processed_event_cap = 321;
     cli.github_retry_max_attempts = 9;
     cli.github_retry_base_delay_ms = 700;
@@ -1907,6 +1950,7 @@ fn integration_build_github_issues_bridge_cli_config_preserves_runtime_fields()
     assert_eq!(config.required_issue_numbers, vec![7, 42]);
     assert!(config.include_issue_body);
     assert!(config.include_edited_comments);
+    assert!(config.pull_request_review);
     assert_eq!(config.processed_event_cap, 321);
     assert_eq!(config.retry_max_attempts, 9);
     assert_eq!(config.retry_base_delay_ms, 700);
@@ -2010,12 +2054,33 @@ fn integration_build_multi_channel_live_connectors_config_preserves_runtime_fiel
     cli.multi_channel_telegram_webhook_secret = Some(" tg-secret ".to_string());
     cli.multi_channel_whatsapp_webhook_verify_token = Some(" wa-verify-secret ".to_string());
     cli.multi_channel_whatsapp_webhook_app_secret = Some(" wa-app-secret ".to_string());
+    cli.multi_channel_matrix_ingress_mode = tau_cli::CliMultiChannelLiveConnectorMode::Polling;
+    cli.multi_channel_matrix_homeserver_url = " https://matrix.example ".to_string();
+    cli.multi_channel_matrix_user_id = Some(" @tau:example.org ".to_string());
+    cli.multi_channel_matrix_auto_join_invites = true;
+    cli.multi_channel_email_ingress_mode = tau_cli::CliMultiChannelLiveConnectorMode::Polling;
+    cli.multi_channel_email_address = Some(" agent@example.org ".to_string());
+    cli.multi_channel_email_imap_host = " imap.example.org ".to_string();
+    cli.multi_channel_email_idle_timeout_ms = 25_000;
+    cli.multi_channel_email_trusted_authserv_id = Some(" mx.example.org ".to_string());
     write_integration_secret(
         &cli.credential_store,
         "discord-bot-token",
         Some("discord-store"),
         false,
     );
+    write_integration_secret(
+        &cli.credential_store,
+        "matrix-access-token",
+        Some("matrix-store"),
+        false,
+    );
+    write_integration_secret(
+        &cli.credential_store,
+        "email-password",
+        Some("email-store"),
+        false,
+    );
 
     let config = build_multi_channel_live_connectors_config(&cli);
     assert_eq!(
@@ -2049,6 +2114,26 @@ fn integration_build_multi_channel_live_connectors_config_preserves_runtime_fiel
         config.whatsapp_webhook_app_secret.as_deref(),
         Some("wa-app-secret")
     );
+    assert_eq!(config.matrix_mode, MultiChannelLiveConnectorMode::Polling);
+    assert_eq!(config.matrix_homeserver_url, "https://matrix.example");
+    assert_eq!(config.matrix_access_token.as_deref(), Some("matrix-store"));
+    assert_eq!(config.matrix_user_id.as_deref(), Some("@tau:example.org"));
+    assert!(config.matrix_auto_join_invites);
+    assert_eq!(config.email_mode, MultiChannelLiveConnectorMode::Polling);
+    assert_eq!(config.email.address.as_deref(), Some("agent@example.org"));
+    assert_eq!(config.email.password.as_deref(), Some("email-store"));
+    assert_eq!(config.email.imap_host, "imap.example.org");
+    assert_eq!(config.email.imap_port, 993);
+    assert_eq!(config.email.imap_mailbox, "INBOX");
+    assert_eq!(config.email_idle_timeout_ms, 25_000);
+    assert_eq!(
+        config.email_trusted_authserv_id.as_deref(),
+        Some("mx.example.org")
+    );
+    assert_eq!(
+        config.channel_policy_path,
+        tau_multi_channel::channel_policy_path_for_state_dir(&cli.multi_channel_state_dir)
+    );
 }
 
 #[test]
@@ -2277,6 +2362,57 @@ fn regression_resolve_slack_bridge_tokens_from_cli_fails_closed_when_bot_token_m
     );
 }
 
+#[test]
+fn regression_resolve_discord_bridge_token_from_cli_fails_closed_when_missing() {
+    let cli = parse_cli_with_stack();
+
+    let error = resolve_discord_bridge_token_from_cli(&cli, |direct, _secret_id, _flag| {
+        Ok(direct.map(str::to_string))
+    })
+    .expect_err("missing bot token should fail");
+
+    assert!(
+        error.to_string().contains("--discord-bot-token"),
+        "unexpected error: {error}"
+    );
+}
+
+#[tokio::test]
+async fn functional_run_discord_bridge_if_requested_builds_config_with_resolved_token() {
+    let mut cli = parse_cli_with_stack();
+    cli.discord_bridge = true;
+    cli.discord_application_id = Some(99);
+    cli.discord_command_guild_ids = vec![7, 8];
+    cli.discord_embed_responses = false;
+    let captured = Arc::new(Mutex::new(None::<DiscordBridgeCliConfig>));
+    let captured_sink = Arc::clone(&captured);
+
+    let ran = run_discord_bridge_if_requested(
+        &cli,
+        || Ok("bot-token".to_string()),
+        move |config| {
+            let sink = Arc::clone(&captured_sink);
+            async move {
+                *sink.lock().expect("capture lock") = Some(config);
+                Ok(())
+            }
+        },
+    )
+    .await
+    .expect("discord helper succeeds");
+
+    assert!(ran);
+    let config = captured
+        .lock()
+        .expect("capture lock")
+        .clone()
+        .expect("captured config");
+    assert_eq!(config.bot_token, "bot-token");
+    assert_eq!(config.application_id, 99);
+    assert_eq!(config.command_guild_ids, vec![7, 8]);
+    assert!(!config.embed_responses);
+}
+
 #[tokio::test]
 async fn unit_run_github_issues_bridge_if_requested_returns_false_when_disabled() {
     let cli = parse_cli_with_stack();
@@ -117,10 +117,10 @@ pub const COMMAND_SPECS: &[CommandSpec] = &[
     },
     CommandSpec {
         name: "/session-merge",
-        usage: "/session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>]",
+        usage: "/session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>] [--git]",
         description: "Merge one branch head into another using explicit strategy",
         details:
-            "Defaults target-id to the active head when omitted. append replays source-only entries, squash writes one summary entry, fast-forward requires target ancestry.",
+            "Defaults target-id to the active head when omitted. append replays source-only entries, squash writes one summary entry, fast-forward requires target ancestry. --git also merges the source branch's git worktree branch into the target checkout.",
         example: "/session-merge 42 24 --strategy squash",
     },
     CommandSpec {
@@ -287,10 +287,10 @@ pub const COMMAND_SPECS: &[CommandSpec] = &[
     },
     CommandSpec {
         name: "/auth",
-        usage: "/auth <login|reauth|status|logout|matrix|rotate-key> ...",
+        usage: "/auth <login|reauth|status|logout|matrix|rotate-key|pool> ...",
         description: "Manage provider authentication state and credential-store sessions",
         details:
-            "Supports login/reauth/status/logout flows, credential-store key rotation, and provider-mode matrix diagnostics with optional --json output.",
+            "Supports login/reauth/status/logout flows, credential-store key rotation, pooled provider API keys, and provider-mode matrix diagnostics with optional --json output.",
         example: "/auth rotate-key --new-key next-store-key --json",
     },
     CommandSpec {
@@ -325,6 +325,13 @@ pub const COMMAND_SPECS: &[CommandSpec] = &[
             "Supports set/status/rotate/revoke flows for integration secret ids with optional --json output.",
         example: "/integration-auth status github-token --json",
     },
+    CommandSpec {
+        name: "/mcp",
+        usage: "/mcp [status|resources [server]|read <server> <uri>|prompts [server]|prompt <server> <name> [key=value ...]]",
+        description: "Inspect live MCP client sessions, resources, and prompts",
+        details: "Requires --mcp-client. 'prompt' renders a server prompt template and appends its messages to the conversation.",
+        example: "/mcp prompt docs summarize topic=release",
+    },
     CommandSpec {
         name: "/pair",
         usage: "/pair <add|remove|status> ...",
@@ -558,6 +565,7 @@ pub const COMMAND_NAMES: &[&str] = &[
     "/rbac",
     "/approvals",
     "/integration-auth",
+    "/mcp",
     "/pair",
     "/unpair",
     "/profile",
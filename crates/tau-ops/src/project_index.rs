@@ -14,10 +14,12 @@ use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use tau_cli::Cli;
+use tau_code_intel::{extract_file_symbols, refresh_code_index, CodeLanguage};
 use tau_core::{current_unix_timestamp_ms, write_text_atomic};
 
-const PROJECT_INDEX_SCHEMA_VERSION: u32 = 1;
+const PROJECT_INDEX_SCHEMA_VERSION: u32 = 2;
 const PROJECT_INDEX_FILE_NAME: &str = "project-index.json";
+const CODE_INDEX_DIR_NAME: &str = "code-index";
 const MAX_INDEX_FILE_BYTES: usize = 2 * 1024 * 1024;
 const MAX_TOKENS_PER_FILE: usize = 512;
 const MAX_SYMBOLS_PER_FILE: usize = 128;
@@ -56,6 +58,12 @@ struct ProjectIndexBuildReport {
     skipped_non_utf8: usize,
     skipped_large: usize,
     recovered_from_corrupt_state: bool,
+    code_index_dir: String,
+    code_index_files: usize,
+    code_index_files_parsed: usize,
+    code_index_definitions: usize,
+    code_index_references: usize,
+    code_index_imports: usize,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -162,6 +170,10 @@ fn project_index_file_path(state_dir: &Path) -> PathBuf {
     state_dir.join(PROJECT_INDEX_FILE_NAME)
 }
 
+fn code_index_dir_path(state_dir: &Path) -> PathBuf {
+    state_dir.join(CODE_INDEX_DIR_NAME)
+}
+
 fn should_skip_directory(name: &str) -> bool {
     matches!(
         name,
@@ -357,6 +369,23 @@ fn extract_symbols(text: &str) -> Vec<String> {
     symbols.into_iter().collect()
 }
 
+fn extract_symbols_for_path(path: &Path, text: &str) -> Vec<String> {
+    let Some(language) = CodeLanguage::from_path(path) else {
+        return extract_symbols(text);
+    };
+    let Ok(file_symbols) = extract_file_symbols(language, text) else {
+        return extract_symbols(text);
+    };
+    file_symbols
+        .definitions
+        .into_iter()
+        .map(|definition| definition.name)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .take(MAX_SYMBOLS_PER_FILE)
+        .collect()
+}
+
 fn parse_identifier(raw: &str) -> &str {
     let trimmed = raw.trim_start();
     let mut end = 0usize;
@@ -457,7 +486,7 @@ fn build_project_index(root: &Path, state_dir: &Path) -> Result<ProjectIndexBuil
         files_updated = files_updated.saturating_add(1);
         indexed_paths.insert(relative_path.clone());
         let tokens = extract_tokens(&text);
-        let symbols = extract_symbols(&text);
+        let symbols = extract_symbols_for_path(&file_path, &text);
         indexed_entries.push(ProjectIndexEntry {
             path: relative_path,
             sha256: sha,
@@ -487,6 +516,7 @@ fn build_project_index(root: &Path, state_dir: &Path) -> Result<ProjectIndexBuil
             .context("failed to serialize project index state payload")?,
     )
     .with_context(|| format!("failed writing '{}'", index_path.display()))?;
+    let code_index = refresh_code_index(root, &code_index_dir_path(state_dir))?.report;
 
     Ok(ProjectIndexBuildReport {
         root: root.display().to_string(),
@@ -501,6 +531,12 @@ fn build_project_index(root: &Path, state_dir: &Path) -> Result<ProjectIndexBuil
         skipped_non_utf8,
         skipped_large,
         recovered_from_corrupt_state,
+        code_index_dir: code_index.index_dir,
+        code_index_files: code_index.files_indexed,
+        code_index_files_parsed: code_index.files_parsed,
+        code_index_definitions: code_index.definitions,
+        code_index_references: code_index.references,
+        code_index_imports: code_index.imports,
     })
 }
 
@@ -640,7 +676,7 @@ fn score_project_index_entry(
 
 fn render_project_index_build_report(report: &ProjectIndexBuildReport) -> String {
     format!(
-        "project index build: root={} index_path={} schema_version={} generated_unix_ms={} discovered={} indexed={} reused={} updated={} removed={} skipped_non_utf8={} skipped_large={} recovered_from_corrupt_state={}",
+        "project index build: root={} index_path={} schema_version={} generated_unix_ms={} discovered={} indexed={} reused={} updated={} removed={} skipped_non_utf8={} skipped_large={} recovered_from_corrupt_state={} code_index_dir={} code_index_files={} code_index_parsed={} code_index_definitions={} code_index_references={} code_index_imports={}",
         report.root,
         report.index_path,
         report.schema_version,
@@ -652,7 +688,13 @@ fn render_project_index_build_report(report: &ProjectIndexBuildReport) -> String
         report.files_removed,
         report.skipped_non_utf8,
         report.skipped_large,
-        report.recovered_from_corrupt_state
+        report.recovered_from_corrupt_state,
+        report.code_index_dir,
+        report.code_index_files,
+        report.code_index_files_parsed,
+        report.code_index_definitions,
+        report.code_index_references,
+        report.code_index_imports
     )
 }
 
@@ -735,6 +777,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unit_extract_symbols_for_path_uses_syntax_tree_for_supported_languages() {
+        let rust =
+            "pub(crate) fn scoped_helper() {}\nimpl Runner {\n    async fn run(&self) {}\n}\n";
+        assert_eq!(
+            extract_symbols_for_path(Path::new("src/lib.rs"), rust),
+            vec!["run".to_string(), "scoped_helper".to_string()]
+        );
+
+        let python = "class Loader:\n    def load(self):\n        pass\n";
+        assert_eq!(
+            extract_symbols_for_path(Path::new("tools/loader.py"), python),
+            vec!["Loader".to_string(), "load".to_string()]
+        );
+
+        let markdown = "fn looks_like_code\n";
+        assert_eq!(
+            extract_symbols_for_path(Path::new("README.md"), markdown),
+            vec!["looks_like_code".to_string()]
+        );
+    }
+
     #[test]
     fn functional_build_project_index_writes_state_and_counts_files() {
         let temp = tempdir().expect("tempdir");
@@ -757,6 +821,11 @@ mod tests {
             project_index_file_path(&state_dir).exists(),
             "index file should exist after build"
         );
+        assert_eq!(report.code_index_files, 1);
+        assert_eq!(report.code_index_definitions, 1);
+        assert!(code_index_dir_path(&state_dir)
+            .join("manifest.json")
+            .exists());
 
         let inspect = inspect_project_index(&root, &project_index_file_path(&state_dir))
             .expect("inspect should succeed");
@@ -1,11 +1,20 @@
 //! Plan execution engine with parallel step scheduling.
 //!
 //! Executes structured plans by running ready steps, handling failures, and
-//! optionally revising the plan on step failure.
+//! optionally revising the plan on step failure. Progress can be checkpointed
+//! to disk so an interrupted run resumes from the last completed step.
+
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tau_agent_core::{Agent, AgentEvent};
+use tau_core::atomic_io::write_text_atomic;
+use tau_core::time_utils::current_unix_timestamp_ms;
+
+use crate::plan::{PlanCondition, PlanStep, PlanStepStatus, StructuredPlan};
 
-use crate::plan::{PlanStepStatus, StructuredPlan};
+const PLAN_EXECUTION_CHECKPOINT_SCHEMA_VERSION: u32 = 1;
 
 /// Report generated after plan execution completes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +27,39 @@ pub struct PlanExecutionReport {
     pub skipped_steps: usize,
     pub is_complete: bool,
     pub is_deadlocked: bool,
+    #[serde(default)]
+    pub revisions: usize,
+}
+
+/// Configuration for [`execute_plan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanExecutorConfig {
+    /// Upper bound on steps dispatched concurrently from one ready group.
+    pub max_parallel_steps: usize,
+    /// Number of LLM-driven revisions allowed after step failures.
+    pub max_revisions: usize,
+    /// When set, plan progress is persisted here after every state change and
+    /// a matching checkpoint is resumed on the next run.
+    pub checkpoint_path: Option<PathBuf>,
+}
+
+impl Default for PlanExecutorConfig {
+    fn default() -> Self {
+        Self {
+            max_parallel_steps: 4,
+            max_revisions: 2,
+            checkpoint_path: None,
+        }
+    }
+}
+
+/// Persisted plan progress used to resume an interrupted execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanExecutionCheckpoint {
+    pub schema_version: u32,
+    pub plan: StructuredPlan,
+    pub revisions: usize,
+    pub updated_unix_ms: u64,
 }
 
 /// Errors that can occur during plan execution.
@@ -27,6 +69,8 @@ pub enum PlanExecutionError {
     Deadlock { remaining_step_ids: Vec<String> },
     /// Plan validation failed before execution.
     ValidationFailed { errors: Vec<String> },
+    /// Plan progress could not be loaded from or persisted to the checkpoint.
+    Checkpoint { path: String, reason: String },
 }
 
 impl std::fmt::Display for PlanExecutionError {
@@ -40,6 +84,9 @@ impl std::fmt::Display for PlanExecutionError {
             PlanExecutionError::ValidationFailed { errors } => {
                 write!(f, "plan validation failed: {:?}", errors)
             }
+            PlanExecutionError::Checkpoint { path, reason } => {
+                write!(f, "plan checkpoint '{}' failed: {}", path, reason)
+            }
         }
     }
 }
@@ -75,6 +122,7 @@ pub fn build_execution_report(plan: &StructuredPlan) -> PlanExecutionReport {
         skipped_steps: skipped,
         is_complete,
         is_deadlocked,
+        revisions: 0,
     }
 }
 
@@ -102,10 +150,476 @@ pub fn check_deadlock(plan: &StructuredPlan) -> Option<PlanExecutionError> {
     None
 }
 
+/// Load a plan checkpoint, returning `None` when the file does not exist.
+pub fn load_plan_checkpoint(
+    path: &Path,
+) -> Result<Option<PlanExecutionCheckpoint>, PlanExecutionError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let raw = std::fs::read_to_string(path).map_err(|error| checkpoint_error(path, error))?;
+    let checkpoint = serde_json::from_str::<PlanExecutionCheckpoint>(&raw)
+        .map_err(|error| checkpoint_error(path, error))?;
+    if checkpoint.schema_version != PLAN_EXECUTION_CHECKPOINT_SCHEMA_VERSION {
+        return Err(checkpoint_error(
+            path,
+            format!(
+                "unsupported schema_version {} (expected {})",
+                checkpoint.schema_version, PLAN_EXECUTION_CHECKPOINT_SCHEMA_VERSION
+            ),
+        ));
+    }
+    Ok(Some(checkpoint))
+}
+
+/// Atomically persist plan progress so a crashed run can resume.
+pub fn save_plan_checkpoint(
+    path: &Path,
+    plan: &StructuredPlan,
+    revisions: usize,
+) -> Result<(), PlanExecutionError> {
+    let checkpoint = PlanExecutionCheckpoint {
+        schema_version: PLAN_EXECUTION_CHECKPOINT_SCHEMA_VERSION,
+        plan: plan.clone(),
+        revisions,
+        updated_unix_ms: current_unix_timestamp_ms(),
+    };
+    let mut payload =
+        serde_json::to_string_pretty(&checkpoint).map_err(|error| checkpoint_error(path, error))?;
+    payload.push('\n');
+    write_text_atomic(path, &payload).map_err(|error| checkpoint_error(path, error))
+}
+
+/// Execute a structured plan to completion using forked copies of `agent`.
+///
+/// Each wave of ready steps is dispatched concurrently through
+/// [`Agent::run_parallel_prompts`]. Steps whose [`PlanCondition`] can no longer
+/// hold are skipped, and when execution stalls on failed steps the agent is
+/// asked to revise the remaining work. Step-level [`AgentEvent`]s are published
+/// to the agent's subscribers.
+pub async fn execute_plan(
+    agent: &Agent,
+    plan: StructuredPlan,
+    config: &PlanExecutorConfig,
+) -> Result<PlanExecutionReport, PlanExecutionError> {
+    let available_tools = agent.registered_tool_names();
+    validate_plan(&plan, &available_tools)?;
+
+    let (mut plan, mut revisions) =
+        resume_plan(plan, config.checkpoint_path.as_deref(), &available_tools)?;
+    let persist = |plan: &StructuredPlan, revisions: usize| match &config.checkpoint_path {
+        Some(path) => save_plan_checkpoint(path, plan, revisions),
+        None => Ok(()),
+    };
+    persist(&plan, revisions)?;
+
+    loop {
+        if skip_unreachable_steps(agent, &mut plan) {
+            persist(&plan, revisions)?;
+        }
+        if plan.is_complete() {
+            break;
+        }
+
+        let wave = plan
+            .parallelizable_groups()
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        if wave.is_empty() {
+            if !plan.has_failures() {
+                return Err(
+                    check_deadlock(&plan).unwrap_or(PlanExecutionError::Deadlock {
+                        remaining_step_ids: Vec::new(),
+                    }),
+                );
+            }
+            if revisions >= config.max_revisions {
+                break;
+            }
+            match revise_plan(agent, &plan, &available_tools).await {
+                Ok((revised, reason)) => {
+                    revisions += 1;
+                    plan = revised;
+                    agent.publish_event(AgentEvent::PlanRevised {
+                        plan_id: plan.id.clone(),
+                        revision: revisions,
+                        reason,
+                    });
+                    persist(&plan, revisions)?;
+                    continue;
+                }
+                Err(_) => break,
+            }
+        }
+
+        for step in &wave {
+            plan.update_step_status(&step.id, PlanStepStatus::InProgress);
+            agent.publish_event(AgentEvent::PlanStepStarted {
+                plan_id: plan.id.clone(),
+                step_id: step.id.clone(),
+            });
+        }
+        persist(&plan, revisions)?;
+
+        let prompts = wave
+            .iter()
+            .map(|step| build_step_prompt(&plan, step))
+            .collect::<Vec<_>>();
+        let results = agent
+            .run_parallel_prompts(prompts, config.max_parallel_steps)
+            .await;
+        for (step, result) in wave.iter().zip(results) {
+            match result {
+                Ok(new_messages) => {
+                    plan.update_step_status(&step.id, PlanStepStatus::Completed);
+                    agent.publish_event(AgentEvent::PlanStepCompleted {
+                        plan_id: plan.id.clone(),
+                        step_id: step.id.clone(),
+                        new_messages: new_messages.len(),
+                    });
+                }
+                Err(error) => {
+                    let reason = error.to_string();
+                    plan.update_step_status(
+                        &step.id,
+                        PlanStepStatus::Failed {
+                            reason: reason.clone(),
+                        },
+                    );
+                    agent.publish_event(AgentEvent::PlanStepFailed {
+                        plan_id: plan.id.clone(),
+                        step_id: step.id.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+        persist(&plan, revisions)?;
+    }
+
+    let mut report = build_execution_report(&plan);
+    report.revisions = revisions;
+    Ok(report)
+}
+
+fn validate_plan(
+    plan: &StructuredPlan,
+    available_tools: &[String],
+) -> Result<(), PlanExecutionError> {
+    plan.validate(available_tools)
+        .map_err(|errors| PlanExecutionError::ValidationFailed {
+            errors: errors.iter().map(ToString::to_string).collect(),
+        })
+}
+
+/// Swaps in the checkpointed progress for `plan` when one exists.
+///
+/// The checkpoint file is validated like a fresh plan, so an edited or stale
+/// checkpoint cannot bring back unknown tools, dangling dependencies or cycles.
+fn resume_plan(
+    plan: StructuredPlan,
+    checkpoint_path: Option<&Path>,
+    available_tools: &[String],
+) -> Result<(StructuredPlan, usize), PlanExecutionError> {
+    let Some(path) = checkpoint_path else {
+        return Ok((plan, 0));
+    };
+    let Some(checkpoint) = load_plan_checkpoint(path)? else {
+        return Ok((plan, 0));
+    };
+    if checkpoint.plan.id != plan.id {
+        return Ok((plan, 0));
+    }
+    validate_plan(&checkpoint.plan, available_tools)?;
+
+    // Steps that were in flight when the previous run stopped are re-run.
+    let mut resumed = checkpoint.plan;
+    for step in &mut resumed.steps {
+        if step.status == PlanStepStatus::InProgress {
+            step.status = PlanStepStatus::Pending;
+        }
+    }
+    Ok((resumed, checkpoint.revisions))
+}
+
+/// Marks pending steps whose condition references a settled step that did not
+/// reach the required outcome as skipped. Returns true when any step changed.
+fn skip_unreachable_steps(agent: &Agent, plan: &mut StructuredPlan) -> bool {
+    let unreachable = plan
+        .steps
+        .iter()
+        .filter(|step| step.status == PlanStepStatus::Pending)
+        .filter_map(|step| {
+            let target_id = match step.condition.as_ref()? {
+                PlanCondition::IfSucceeded(target_id) | PlanCondition::IfFailed(target_id) => {
+                    target_id
+                }
+                PlanCondition::Always => return None,
+            };
+            let target = plan.steps.iter().find(|s| s.id == *target_id)?;
+            let settled = !matches!(
+                target.status,
+                PlanStepStatus::Pending | PlanStepStatus::InProgress
+            );
+            (settled && !step.condition_satisfied(plan)).then(|| {
+                (
+                    step.id.clone(),
+                    format!("condition on step '{}' not met", target_id),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (step_id, reason) in &unreachable {
+        plan.update_step_status(
+            step_id,
+            PlanStepStatus::Skipped {
+                reason: reason.clone(),
+            },
+        );
+        agent.publish_event(AgentEvent::PlanStepSkipped {
+            plan_id: plan.id.clone(),
+            step_id: step_id.clone(),
+            reason: reason.clone(),
+        });
+    }
+    !unreachable.is_empty()
+}
+
+fn build_step_prompt(plan: &StructuredPlan, step: &PlanStep) -> String {
+    let mut prompt = format!(
+        "Plan goal: {}\nExecute plan step '{}': {}",
+        plan.goal, step.id, step.description
+    );
+    let completed = plan
+        .steps
+        .iter()
+        .filter(|s| s.status == PlanStepStatus::Completed)
+        .map(|s| s.id.as_str())
+        .collect::<Vec<_>>();
+    if !completed.is_empty() {
+        prompt.push_str(&format!("\nCompleted steps: {}", completed.join(", ")));
+    }
+    if !step.tools_required.is_empty() {
+        prompt.push_str(&format!(
+            "\nTools required: {}",
+            step.tools_required.join(", ")
+        ));
+    }
+    prompt
+}
+
+#[derive(Debug, Deserialize)]
+struct RevisedPlanStep {
+    id: String,
+    description: String,
+    #[serde(default)]
+    depends_on: Vec<String>,
+    #[serde(default)]
+    tools_required: Vec<String>,
+    #[serde(default = "default_revised_step_turns")]
+    estimated_turns: usize,
+}
+
+fn default_revised_step_turns() -> usize {
+    1
+}
+
+fn plan_revision_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["steps"],
+        "properties": {
+            "reason": { "type": "string" },
+            "steps": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id", "description"],
+                    "properties": {
+                        "id": { "type": "string" },
+                        "description": { "type": "string" },
+                        "depends_on": { "type": "array", "items": { "type": "string" } },
+                        "tools_required": { "type": "array", "items": { "type": "string" } },
+                        "estimated_turns": { "type": "integer", "minimum": 0 }
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn build_revision_prompt(plan: &StructuredPlan) -> String {
+    let mut prompt = format!(
+        "Revise the plan for goal: {}\nReplace the failed and pending steps with new steps that \
+         finish the goal. Completed steps may be referenced in depends_on.\n",
+        plan.goal
+    );
+    for step in &plan.steps {
+        let status = match &step.status {
+            PlanStepStatus::Pending | PlanStepStatus::InProgress => "pending".to_string(),
+            PlanStepStatus::Completed => "completed".to_string(),
+            PlanStepStatus::Failed { reason } => format!("failed: {}", reason),
+            PlanStepStatus::Skipped { reason } => format!("skipped: {}", reason),
+        };
+        prompt.push_str(&format!(
+            "- {} [{}] {}\n",
+            step.id, status, step.description
+        ));
+    }
+    prompt.push_str(
+        "Respond with JSON {\"reason\": string, \"steps\": [{\"id\", \"description\", \
+         \"depends_on\", \"tools_required\", \"estimated_turns\"}]}.",
+    );
+    prompt
+}
+
+/// Asks a fork of `agent` for replacement steps and merges them with the
+/// settled (completed or skipped) part of `plan`.
+async fn revise_plan(
+    agent: &Agent,
+    plan: &StructuredPlan,
+    available_tools: &[String],
+) -> Result<(StructuredPlan, String), String> {
+    let mut reviser = agent.fork();
+    let response = reviser
+        .prompt_json(build_revision_prompt(plan), &plan_revision_schema())
+        .await
+        .map_err(|error| error.to_string())?;
+    let reason = response
+        .get("reason")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|reason| !reason.is_empty())
+        .unwrap_or("step failure")
+        .to_string();
+    let revised_steps = serde_json::from_value::<Vec<RevisedPlanStep>>(
+        response.get("steps").cloned().unwrap_or(Value::Null),
+    )
+    .map_err(|error| error.to_string())?;
+    if revised_steps.is_empty() {
+        return Err("revision returned no steps".to_string());
+    }
+
+    let mut revised = plan.clone();
+    revised.steps.retain(|step| {
+        matches!(
+            step.status,
+            PlanStepStatus::Completed | PlanStepStatus::Skipped { .. }
+        )
+    });
+    revised
+        .steps
+        .extend(revised_steps.into_iter().map(|step| PlanStep {
+            id: step.id,
+            description: step.description,
+            depends_on: step.depends_on,
+            tools_required: step.tools_required,
+            estimated_turns: step.estimated_turns,
+            status: PlanStepStatus::Pending,
+            condition: None,
+        }));
+    revised
+        .validate(available_tools)
+        .map_err(|errors| format!("{:?}", errors))?;
+    Ok((revised, reason))
+}
+
+fn checkpoint_error(path: &Path, error: impl std::fmt::Display) -> PlanExecutionError {
+    PlanExecutionError::Checkpoint {
+        path: path.display().to_string(),
+        reason: error.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::plan::{PlanStep, PlanStepStatus, StructuredPlan};
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use tau_agent_core::AgentConfig;
+    use tau_ai::{
+        ChatRequest, ChatResponse, ChatUsage, LlmClient, Message, MessageRole, TauAiError,
+    };
+
+    /// Answers step prompts with "done", fails prompts containing `FAIL`, and
+    /// returns `revision` for plan revision prompts.
+    struct ScriptedPlanClient {
+        revision: Value,
+        prompts: Mutex<Vec<String>>,
+    }
+
+    impl ScriptedPlanClient {
+        fn new(revision: Value) -> Arc<Self> {
+            Arc::new(Self {
+                revision,
+                prompts: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn prompts(&self) -> Vec<String> {
+            self.prompts.lock().expect("prompts lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for ScriptedPlanClient {
+        async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            let prompt = request
+                .messages
+                .iter()
+                .rev()
+                .find(|message| message.role == MessageRole::User)
+                .map(Message::text_content)
+                .unwrap_or_default();
+            self.prompts
+                .lock()
+                .expect("prompts lock")
+                .push(prompt.clone());
+            let text = if prompt.starts_with("Revise the plan") {
+                self.revision.to_string()
+            } else if prompt.contains("FAIL") {
+                return Err(TauAiError::InvalidResponse("step exploded".to_string()));
+            } else {
+                "done".to_string()
+            };
+            Ok(ChatResponse {
+                message: Message::assistant_text(text),
+                finish_reason: Some("stop".to_string()),
+                usage: ChatUsage::default(),
+            })
+        }
+    }
+
+    fn recording_agent(client: Arc<ScriptedPlanClient>) -> (Agent, Arc<Mutex<Vec<String>>>) {
+        let mut agent = Agent::new(client, AgentConfig::default());
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        agent.subscribe(move |event| {
+            let label = match event {
+                AgentEvent::PlanStepStarted { step_id, .. } => format!("started:{step_id}"),
+                AgentEvent::PlanStepCompleted { step_id, .. } => format!("completed:{step_id}"),
+                AgentEvent::PlanStepFailed { step_id, .. } => format!("failed:{step_id}"),
+                AgentEvent::PlanStepSkipped { step_id, .. } => format!("skipped:{step_id}"),
+                AgentEvent::PlanRevised { revision, .. } => format!("revised:{revision}"),
+                _ => return,
+            };
+            sink.lock().expect("events lock").push(label);
+        });
+        (agent, events)
+    }
+
+    fn pending_plan(steps: Vec<PlanStep>) -> StructuredPlan {
+        StructuredPlan {
+            id: "plan-exec".to_string(),
+            goal: "ship the feature".to_string(),
+            steps,
+            created_at_ms: 0,
+        }
+    }
 
     fn make_step(id: &str, deps: &[&str], status: PlanStepStatus) -> PlanStep {
         PlanStep {
@@ -178,4 +692,183 @@ mod tests {
         let deadlock = check_deadlock(&plan);
         assert!(deadlock.is_some());
     }
+
+    #[tokio::test]
+    async fn functional_execute_plan_runs_dependency_waves_and_emits_step_events() {
+        let client = ScriptedPlanClient::new(Value::Null);
+        let (agent, events) = recording_agent(Arc::clone(&client));
+        let plan = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Pending),
+            make_step("b", &["a"], PlanStepStatus::Pending),
+            make_step("c", &["a"], PlanStepStatus::Pending),
+        ]);
+
+        let report = execute_plan(&agent, plan, &PlanExecutorConfig::default())
+            .await
+            .expect("execute plan");
+
+        assert!(report.is_complete);
+        assert_eq!(report.completed_steps, 3);
+        assert_eq!(report.revisions, 0);
+        let events = events.lock().expect("events lock").clone();
+        assert_eq!(&events[..2], &["started:a", "completed:a"]);
+        assert_eq!(events.len(), 6);
+        assert!(client
+            .prompts()
+            .iter()
+            .any(|prompt| prompt.contains("Execute plan step 'b'")
+                && prompt.contains("Completed steps: a")));
+    }
+
+    #[tokio::test]
+    async fn functional_execute_plan_revises_failed_step_and_completes() {
+        let client = ScriptedPlanClient::new(json!({
+            "reason": "retry with a smaller change",
+            "steps": [
+                { "id": "b-retry", "description": "apply smaller change", "depends_on": ["a"] },
+                { "id": "c", "description": "verify", "depends_on": ["b-retry"] }
+            ]
+        }));
+        let (agent, events) = recording_agent(client);
+        let mut failing = make_step("b", &["a"], PlanStepStatus::Pending);
+        failing.description = "FAIL on purpose".to_string();
+        let plan = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Pending),
+            failing,
+            make_step("c", &["b"], PlanStepStatus::Pending),
+        ]);
+
+        let report = execute_plan(&agent, plan, &PlanExecutorConfig::default())
+            .await
+            .expect("execute plan");
+
+        assert!(report.is_complete);
+        assert_eq!(report.revisions, 1);
+        assert_eq!(report.completed_steps, 3);
+        let events = events.lock().expect("events lock").clone();
+        assert!(events.contains(&"failed:b".to_string()));
+        assert!(events.contains(&"revised:1".to_string()));
+        assert!(events.contains(&"completed:b-retry".to_string()));
+    }
+
+    #[tokio::test]
+    async fn functional_execute_plan_skips_steps_whose_condition_cannot_hold() {
+        let client = ScriptedPlanClient::new(Value::Null);
+        let (agent, events) = recording_agent(client);
+        let mut rollback = make_step("rollback", &[], PlanStepStatus::Pending);
+        rollback.condition = Some(PlanCondition::IfFailed("deploy".to_string()));
+        let plan = pending_plan(vec![
+            make_step("deploy", &[], PlanStepStatus::Pending),
+            rollback,
+        ]);
+
+        let report = execute_plan(&agent, plan, &PlanExecutorConfig::default())
+            .await
+            .expect("execute plan");
+
+        assert!(report.is_complete);
+        assert_eq!(report.skipped_steps, 1);
+        assert!(events
+            .lock()
+            .expect("events lock")
+            .contains(&"skipped:rollback".to_string()));
+    }
+
+    #[tokio::test]
+    async fn regression_execute_plan_stops_when_revisions_are_exhausted() {
+        let client = ScriptedPlanClient::new(Value::Null);
+        let (agent, _events) = recording_agent(Arc::clone(&client));
+        let mut plan = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Pending),
+            make_step("b", &["a"], PlanStepStatus::Pending),
+        ]);
+        plan.steps[0].description = "FAIL always".to_string();
+        let config = PlanExecutorConfig {
+            max_revisions: 0,
+            ..PlanExecutorConfig::default()
+        };
+
+        let report = execute_plan(&agent, plan, &config)
+            .await
+            .expect("execute plan");
+
+        assert!(!report.is_complete);
+        assert!(report.is_deadlocked);
+        assert_eq!(report.failed_steps, 1);
+        assert!(client
+            .prompts()
+            .iter()
+            .all(|prompt| !prompt.contains("Execute plan step 'b'")));
+    }
+
+    #[tokio::test]
+    async fn integration_execute_plan_resumes_from_checkpoint_after_crash() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let checkpoint_path = temp.path().join("plans/plan-exec.json");
+        let crashed = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Completed),
+            make_step("b", &["a"], PlanStepStatus::InProgress),
+        ]);
+        save_plan_checkpoint(&checkpoint_path, &crashed, 0).expect("save checkpoint");
+
+        let client = ScriptedPlanClient::new(Value::Null);
+        let (agent, _events) = recording_agent(Arc::clone(&client));
+        let fresh = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Pending),
+            make_step("b", &["a"], PlanStepStatus::Pending),
+        ]);
+        let config = PlanExecutorConfig {
+            checkpoint_path: Some(checkpoint_path.clone()),
+            ..PlanExecutorConfig::default()
+        };
+
+        let report = execute_plan(&agent, fresh, &config)
+            .await
+            .expect("execute plan");
+
+        assert!(report.is_complete);
+        let prompts = client.prompts();
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("Execute plan step 'b'"));
+        let persisted = load_plan_checkpoint(&checkpoint_path)
+            .expect("load checkpoint")
+            .expect("checkpoint exists");
+        assert!(persisted.plan.is_complete());
+    }
+
+    #[tokio::test]
+    async fn regression_execute_plan_rejects_invalid_checkpoint_on_resume() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let checkpoint_path = temp.path().join("plans/plan-exec.json");
+        let mut tampered = make_step("b", &["a"], PlanStepStatus::Pending);
+        tampered.tools_required = vec!["rm_rf".to_string()];
+        let crashed = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Completed),
+            tampered,
+            make_step("c", &["ghost"], PlanStepStatus::Pending),
+        ]);
+        save_plan_checkpoint(&checkpoint_path, &crashed, 0).expect("save checkpoint");
+
+        let client = ScriptedPlanClient::new(Value::Null);
+        let (agent, _events) = recording_agent(Arc::clone(&client));
+        let fresh = pending_plan(vec![
+            make_step("a", &[], PlanStepStatus::Pending),
+            make_step("b", &["a"], PlanStepStatus::Pending),
+        ]);
+        let config = PlanExecutorConfig {
+            checkpoint_path: Some(checkpoint_path),
+            ..PlanExecutorConfig::default()
+        };
+
+        let error = execute_plan(&agent, fresh, &config)
+            .await
+            .expect_err("invalid checkpoint must be rejected");
+
+        let PlanExecutionError::ValidationFailed { errors } = error else {
+            panic!("expected validation failure, got {error}");
+        };
+        assert!(errors.iter().any(|error| error.contains("rm_rf")));
+        assert!(errors.iter().any(|error| error.contains("ghost")));
+        assert!(client.prompts().is_empty());
+    }
 }
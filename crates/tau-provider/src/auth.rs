@@ -8,6 +8,7 @@ use anyhow::{Context, Result};
 use tau_ai::Provider;
 use tau_cli::Cli;
 
+use crate::credentials::aws_credential_secret_from_env;
 use crate::types::{AuthCommandConfig, ProviderAuthMethod};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -87,12 +88,84 @@ const GOOGLE_AUTH_CAPABILITIES: &[ProviderAuthCapability] = &[
     },
 ];
 
+const LOCAL_AUTH_CAPABILITIES: &[ProviderAuthCapability] = &[
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::ApiKey,
+        supported: true,
+        reason: "supported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::OauthToken,
+        supported: false,
+        reason: "unsupported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::Adc,
+        supported: false,
+        reason: "unsupported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::SessionToken,
+        supported: false,
+        reason: "unsupported",
+    },
+];
+
+const BEDROCK_AUTH_CAPABILITIES: &[ProviderAuthCapability] = &[
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::ApiKey,
+        supported: true,
+        reason: "supported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::OauthToken,
+        supported: false,
+        reason: "unsupported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::Adc,
+        supported: false,
+        reason: "unsupported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::SessionToken,
+        supported: true,
+        reason: "supported",
+    },
+];
+
+const VERTEX_AUTH_CAPABILITIES: &[ProviderAuthCapability] = &[
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::ApiKey,
+        supported: false,
+        reason: "unsupported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::OauthToken,
+        supported: true,
+        reason: "supported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::Adc,
+        supported: true,
+        reason: "supported",
+    },
+    ProviderAuthCapability {
+        method: ProviderAuthMethod::SessionToken,
+        supported: false,
+        reason: "unsupported",
+    },
+];
+
 fn provider_auth_capabilities(provider: Provider) -> &'static [ProviderAuthCapability] {
     match provider {
         Provider::OpenAi => OPENAI_AUTH_CAPABILITIES,
         Provider::OpenRouter => OPENAI_AUTH_CAPABILITIES,
         Provider::Anthropic => ANTHROPIC_AUTH_CAPABILITIES,
         Provider::Google => GOOGLE_AUTH_CAPABILITIES,
+        Provider::Bedrock => BEDROCK_AUTH_CAPABILITIES,
+        Provider::Vertex => VERTEX_AUTH_CAPABILITIES,
+        Provider::Local => LOCAL_AUTH_CAPABILITIES,
     }
 }
 
@@ -140,6 +213,9 @@ pub fn configured_provider_auth_method(cli: &Cli, provider: Provider) -> Provide
         Provider::OpenRouter => cli.openai_auth_mode.into(),
         Provider::Anthropic => cli.anthropic_auth_mode.into(),
         Provider::Google => cli.google_auth_mode.into(),
+        Provider::Bedrock => cli.bedrock_auth_mode.into(),
+        Provider::Vertex => cli.vertex_auth_mode.into(),
+        Provider::Local => ProviderAuthMethod::ApiKey,
     }
 }
 
@@ -157,6 +233,9 @@ pub fn configured_provider_auth_method_from_config(
         Provider::OpenRouter => config.openai_auth_mode,
         Provider::Anthropic => config.anthropic_auth_mode,
         Provider::Google => config.google_auth_mode,
+        Provider::Bedrock => config.bedrock_auth_mode,
+        Provider::Vertex => config.vertex_auth_mode,
+        Provider::Local => ProviderAuthMethod::ApiKey,
     }
 }
 
@@ -171,6 +250,9 @@ pub fn provider_auth_mode_flag(provider: Provider) -> &'static str {
         Provider::OpenRouter => "--openai-auth-mode",
         Provider::Anthropic => "--anthropic-auth-mode",
         Provider::Google => "--google-auth-mode",
+        Provider::Bedrock => "--bedrock-auth-mode",
+        Provider::Vertex => "--vertex-auth-mode",
+        Provider::Local => "--local-api-key",
     }
 }
 
@@ -193,6 +275,15 @@ pub fn missing_provider_api_key_message(provider: Provider) -> &'static str {
         Provider::Google => {
             "missing Google API key. Set GEMINI_API_KEY, GOOGLE_API_KEY, TAU_API_KEY, --google-api-key, or --api-key"
         }
+        Provider::Bedrock => {
+            "missing AWS Bedrock credentials. Set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY, or run /auth login bedrock"
+        }
+        Provider::Vertex => {
+            "Vertex AI does not accept API keys. Set --vertex-auth-mode adc with --vertex-service-account-file or GOOGLE_APPLICATION_CREDENTIALS, or --vertex-auth-mode oauth-token with GOOGLE_ACCESS_TOKEN"
+        }
+        Provider::Local => {
+            "missing local inference server API key. Set TAU_LOCAL_API_KEY or --local-api-key"
+        }
     }
 }
 
@@ -262,6 +353,12 @@ pub fn provider_api_key_candidates_with_inputs(
             ("GOOGLE_API_KEY", std::env::var("GOOGLE_API_KEY").ok()),
             ("TAU_API_KEY", std::env::var("TAU_API_KEY").ok()),
         ],
+        Provider::Bedrock => vec![(
+            "AWS_ACCESS_KEY_ID+AWS_SECRET_ACCESS_KEY",
+            aws_credential_secret_from_env(false),
+        )],
+        Provider::Vertex => Vec::new(),
+        Provider::Local => vec![("TAU_LOCAL_API_KEY", std::env::var("TAU_LOCAL_API_KEY").ok())],
     }
 }
 
@@ -362,6 +459,21 @@ pub fn provider_login_access_token_candidates(
                 std::env::var("GOOGLE_ACCESS_TOKEN").ok(),
             ),
         ],
+        Provider::Bedrock => vec![(
+            "AWS_ACCESS_KEY_ID+AWS_SECRET_ACCESS_KEY+AWS_SESSION_TOKEN",
+            aws_credential_secret_from_env(true),
+        )],
+        Provider::Vertex => vec![
+            (
+                "TAU_AUTH_ACCESS_TOKEN",
+                std::env::var("TAU_AUTH_ACCESS_TOKEN").ok(),
+            ),
+            (
+                "GOOGLE_ACCESS_TOKEN",
+                std::env::var("GOOGLE_ACCESS_TOKEN").ok(),
+            ),
+        ],
+        Provider::Local => Vec::new(),
     }
 }
 
@@ -414,6 +526,8 @@ pub fn provider_login_refresh_token_candidates(
                 std::env::var("GOOGLE_REFRESH_TOKEN").ok(),
             ),
         ],
+        Provider::Bedrock | Provider::Vertex => Vec::new(),
+        Provider::Local => Vec::new(),
     }
 }
 
@@ -466,6 +580,27 @@ pub fn provider_login_expires_candidates(
                 std::env::var("GOOGLE_AUTH_EXPIRES_UNIX").ok(),
             ),
         ],
+        Provider::Bedrock => vec![
+            (
+                "TAU_AUTH_EXPIRES_UNIX",
+                std::env::var("TAU_AUTH_EXPIRES_UNIX").ok(),
+            ),
+            (
+                "AWS_SESSION_EXPIRES_UNIX",
+                std::env::var("AWS_SESSION_EXPIRES_UNIX").ok(),
+            ),
+        ],
+        Provider::Vertex => vec![
+            (
+                "TAU_AUTH_EXPIRES_UNIX",
+                std::env::var("TAU_AUTH_EXPIRES_UNIX").ok(),
+            ),
+            (
+                "GOOGLE_AUTH_EXPIRES_UNIX",
+                std::env::var("GOOGLE_AUTH_EXPIRES_UNIX").ok(),
+            ),
+        ],
+        Provider::Local => Vec::new(),
     }
 }
 
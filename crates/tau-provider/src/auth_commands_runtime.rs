@@ -13,11 +13,16 @@ use crate::*;
 
 mod anthropic_backend;
 mod google_backend;
+mod key_pool_commands;
 mod openai_backend;
 mod shared_runtime_core;
 
 use anthropic_backend::execute_anthropic_login_backend_ready;
 use google_backend::execute_google_login_backend_ready;
+use key_pool_commands::{auth_key_pool_rows, format_auth_key_pool_row, parse_auth_pool_command};
+pub use key_pool_commands::{
+    execute_auth_pool_add_command, execute_auth_pool_remove_command, AuthKeyPoolRow,
+};
 use openai_backend::execute_openai_login_backend_ready;
 use shared_runtime_core::{
     build_auth_login_launch_spec, collect_non_empty_secrets, redact_known_secrets,
@@ -28,7 +33,7 @@ use shared_runtime_core::{
 /// This item is part of the Wave 2 API surface for M23 documentation uplift.
 /// Callers rely on its contract and failure semantics remaining stable.
 /// Update this comment if behavior or integration expectations change.
-pub const AUTH_USAGE: &str = "usage: /auth <login|reauth|status|logout|matrix|rotate-key> ...";
+pub const AUTH_USAGE: &str = "usage: /auth <login|reauth|status|logout|matrix|rotate-key|pool> ...";
 /// Public `const` `AUTH_LOGIN_USAGE` in `tau-provider`.
 ///
 /// This item is part of the Wave 2 API surface for M23 documentation uplift.
@@ -70,6 +75,9 @@ pub const AUTH_MATRIX_USAGE: &str =
 /// Update this comment if behavior or integration expectations change.
 pub const AUTH_ROTATE_KEY_USAGE: &str =
     "usage: /auth rotate-key --new-key <key> [--old-key <key>] [--json]";
+/// Usage for `/auth pool`, which manages a provider's pooled API keys.
+pub const AUTH_POOL_USAGE: &str =
+    "usage: /auth pool <add|remove> <provider> --label <label> [--key <key>] [--json]";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Enumerates supported `AuthMatrixAvailabilityFilter` values.
@@ -211,6 +219,17 @@ pub enum AuthCommand {
         new_key: String,
         json_output: bool,
     },
+    PoolAdd {
+        provider: Provider,
+        label: String,
+        api_key: String,
+        json_output: bool,
+    },
+    PoolRemove {
+        provider: Provider,
+        label: String,
+        json_output: bool,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -297,8 +316,10 @@ pub fn parse_auth_provider(token: &str) -> Result<Provider> {
         "openrouter" => Ok(Provider::OpenRouter),
         "anthropic" => Ok(Provider::Anthropic),
         "google" => Ok(Provider::Google),
+        "bedrock" | "aws-bedrock" | "amazon-bedrock" => Ok(Provider::Bedrock),
+        "vertex" | "vertex-ai" | "vertexai" | "google-vertex" => Ok(Provider::Vertex),
         other => bail!(
-            "unknown provider '{}'; supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google",
+            "unknown provider '{}'; supported providers: openai, openrouter, deepseek (alias), groq (alias), xai (alias), mistral (alias), azure/azure-openai (alias), anthropic, google, bedrock, vertex",
             other
         ),
     }
@@ -712,6 +733,7 @@ pub fn parse_auth_command(command_args: &str) -> Result<AuthCommand> {
                 json_output,
             })
         }
+        "pool" => parse_auth_pool_command(&tokens[1..]),
         other => bail!("unknown subcommand '{}'; {AUTH_USAGE}", other),
     }
 }
@@ -1474,6 +1496,9 @@ fn provider_auth_recovery_order(provider: Provider) -> Vec<ProviderAuthMethod> {
             ProviderAuthMethod::Adc,
             ProviderAuthMethod::ApiKey,
         ],
+        Provider::Bedrock => vec![ProviderAuthMethod::SessionToken, ProviderAuthMethod::ApiKey],
+        Provider::Vertex => vec![ProviderAuthMethod::Adc, ProviderAuthMethod::OauthToken],
+        Provider::Local => vec![ProviderAuthMethod::ApiKey],
     }
 }
 
@@ -1507,6 +1532,21 @@ fn provider_reauth_prerequisites(provider: Provider, method: ProviderAuthMethod)
             "google session-token mode is unsupported; prefer oauth-token, adc, or api-key"
                 .to_string()
         }
+        (Provider::Bedrock, ProviderAuthMethod::SessionToken) => "export AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY and AWS_SESSION_TOKEN (for example from aws sts get-session-token); run /auth login bedrock --mode session-token".to_string(),
+        (Provider::Bedrock, ProviderAuthMethod::OauthToken | ProviderAuthMethod::Adc) => {
+            "bedrock supports api-key (static IAM keys) and session-token auth".to_string()
+        }
+        (Provider::Vertex, ProviderAuthMethod::Adc) => {
+            "set --vertex-service-account-file or GOOGLE_APPLICATION_CREDENTIALS to a service account key file"
+                .to_string()
+        }
+        (Provider::Vertex, ProviderAuthMethod::OauthToken) => "export GOOGLE_ACCESS_TOKEN (for example from gcloud auth print-access-token); run /auth login vertex --mode oauth-token".to_string(),
+        (Provider::Vertex, ProviderAuthMethod::ApiKey | ProviderAuthMethod::SessionToken) => {
+            "vertex ai supports adc (service account) and oauth-token auth".to_string()
+        }
+        (Provider::Local, ProviderAuthMethod::OauthToken | ProviderAuthMethod::SessionToken) => {
+            "local inference servers only support api-key auth".to_string()
+        }
         (_, ProviderAuthMethod::ApiKey) => missing_provider_api_key_message(provider).to_string(),
         (_, ProviderAuthMethod::Adc) => {
             "adc flow requires cloud credential bootstrap in the active environment".to_string()
@@ -1629,6 +1669,7 @@ fn auth_next_action(snapshot: &ProviderAuthSnapshot) -> String {
             }
             Provider::Anthropic => "set --anthropic-claude-backend=true".to_string(),
             Provider::Google => "set --google-gemini-backend=true".to_string(),
+            Provider::Bedrock | Provider::Vertex | Provider::Local => "none".to_string(),
         },
         "backend_unavailable" => match snapshot.provider {
             Provider::OpenAi | Provider::OpenRouter => {
@@ -1641,8 +1682,16 @@ fn auth_next_action(snapshot: &ProviderAuthSnapshot) -> String {
             Provider::Google => {
                 "install gemini or set --google-gemini-cli to an available executable".to_string()
             }
+            Provider::Bedrock | Provider::Vertex | Provider::Local => "none".to_string(),
         },
-        "unsupported_mode" => format!("set {} api-key", provider_auth_mode_flag(snapshot.provider)),
+        "unsupported_mode" => format!(
+            "set {} {}",
+            provider_auth_mode_flag(snapshot.provider),
+            provider_supported_auth_modes(snapshot.provider)
+                .first()
+                .map(|method| method.as_str())
+                .unwrap_or("api-key")
+        ),
         "missing_credential"
         | "missing_access_token"
         | "invalid_env_expires"
@@ -1907,6 +1956,7 @@ pub fn execute_auth_status_command(
     let state_counts = auth_state_counts(&rows);
     let source_kind_counts = auth_source_kind_counts(&rows);
     let revoked_counts = auth_revoked_counts(&rows);
+    let key_pools = auth_key_pool_rows(config, &selected_providers);
 
     if json_output {
         return serde_json::json!({
@@ -1941,6 +1991,7 @@ pub fn execute_auth_status_command(
             "revoked_counts_total": revoked_counts_total,
             "revoked_counts": revoked_counts,
             "entries": rows,
+            "key_pools": key_pools,
         })
         .to_string();
     }
@@ -2016,6 +2067,7 @@ pub fn execute_auth_status_command(
             row.next_action
         ));
     }
+    lines.extend(key_pools.iter().map(format_auth_key_pool_row));
     lines.join("\n")
 }
 
@@ -2029,6 +2081,9 @@ fn auth_config_with_provider_mode(
         Provider::OpenAi | Provider::OpenRouter => overridden.openai_auth_mode = mode,
         Provider::Anthropic => overridden.anthropic_auth_mode = mode,
         Provider::Google => overridden.google_auth_mode = mode,
+        Provider::Bedrock => overridden.bedrock_auth_mode = mode,
+        Provider::Vertex => overridden.vertex_auth_mode = mode,
+        Provider::Local => {}
     }
     overridden
 }
@@ -2517,6 +2572,17 @@ pub fn execute_auth_command(config: &AuthCommandConfig, command_args: &str) -> S
             new_key,
             json_output,
         } => execute_auth_rotate_key_command(config, old_key, new_key, json_output),
+        AuthCommand::PoolAdd {
+            provider,
+            label,
+            api_key,
+            json_output,
+        } => execute_auth_pool_add_command(config, provider, label, api_key, json_output),
+        AuthCommand::PoolRemove {
+            provider,
+            label,
+            json_output,
+        } => execute_auth_pool_remove_command(config, provider, label, json_output),
     }
 }
 
@@ -2538,6 +2604,9 @@ mod tests {
             openai_auth_mode: ProviderAuthMethod::ApiKey,
             anthropic_auth_mode: ProviderAuthMethod::ApiKey,
             google_auth_mode: ProviderAuthMethod::ApiKey,
+            bedrock_auth_mode: ProviderAuthMethod::ApiKey,
+            vertex_auth_mode: ProviderAuthMethod::Adc,
+            vertex_service_account_file: None,
             provider_subscription_strict: false,
             openai_codex_backend: true,
             openai_codex_cli: "codex".to_string(),
@@ -2626,4 +2695,98 @@ mod tests {
             Some("ready")
         );
     }
+
+    #[test]
+    fn functional_auth_pool_commands_persist_keys_and_surface_in_status() {
+        let temp_dir = tempfile::tempdir().expect("tempdir");
+        let config = test_auth_config(&temp_dir);
+
+        let added =
+            execute_auth_command(&config, "pool add openai --label team-b --key sk-b --json");
+        let parsed: Value = serde_json::from_str(&added).expect("valid pool add json");
+        assert_eq!(
+            parsed.get("command").and_then(Value::as_str),
+            Some("auth.pool_add")
+        );
+        assert_eq!(parsed.get("status").and_then(Value::as_str), Some("added"));
+        assert!(!added.contains("sk-b"));
+        let replaced = execute_auth_command(&config, "pool add openai --label team-b --key sk-b2");
+        assert!(replaced.contains("status=replaced pool_keys=1"));
+
+        let raw_store = std::fs::read_to_string(&config.credential_store).expect("read store");
+        assert!(!raw_store.contains("sk-b2"));
+        let store = load_credential_store(
+            &config.credential_store,
+            CredentialStoreEncryptionMode::Keyed,
+            config.credential_store_key.as_deref(),
+        )
+        .expect("load store");
+        assert_eq!(
+            store.provider_key_pools["openai"][0].api_key.as_deref(),
+            Some("sk-b2")
+        );
+
+        let status = execute_auth_status_command(
+            &config,
+            Some(Provider::OpenAi),
+            parse_status_filters("status openai"),
+            true,
+        );
+        let parsed: Value = serde_json::from_str(&status).expect("valid status json");
+        let pool = &parsed["key_pools"][0];
+        assert_eq!(pool["provider"].as_str(), Some("openai"));
+        assert_eq!(pool["configured_keys"].as_u64(), Some(1));
+        assert_eq!(pool["labels"][0].as_str(), Some("team-b"));
+        let text = execute_auth_command(&config, "status openai");
+        assert!(text.contains("auth key pool: provider=openai configured_keys=1"));
+
+        let removed = execute_auth_command(&config, "pool remove openai --label team-b");
+        assert!(removed.contains("status=removed pool_keys=0"));
+        let missing = execute_auth_command(&config, "pool remove openai --label team-b");
+        assert!(missing.contains("status=not_found"));
+    }
+
+    #[test]
+    fn regression_parse_auth_pool_command_rejects_missing_flags() {
+        for (args, expected) in [
+            ("pool", "usage: /auth pool"),
+            ("pool list openai", "unknown pool action 'list'"),
+            ("pool add openai --label team-b", "missing --key flag"),
+            ("pool add openai --key sk-b", "missing --label flag"),
+            (
+                "pool remove openai --label a --key sk",
+                "--key is only valid for pool add",
+            ),
+            (
+                "pool add openai --label a --label b --key sk",
+                "duplicate --label flag",
+            ),
+        ] {
+            let error = parse_auth_command(args).expect_err(args);
+            assert!(error.to_string().contains(expected), "{args}: {error}");
+        }
+    }
+
+    fn parse_status_filters(args: &str) -> AuthQueryFilters {
+        let AuthCommand::Status {
+            mode,
+            mode_support,
+            availability,
+            state,
+            source_kind,
+            revoked,
+            ..
+        } = parse_auth_command(args).expect("parse status")
+        else {
+            panic!("expected status command");
+        };
+        AuthQueryFilters {
+            mode,
+            mode_support,
+            availability,
+            state,
+            source_kind,
+            revoked,
+        }
+    }
 }
@@ -0,0 +1,311 @@
+//! `/auth pool` commands and key pool health rows for `/auth status`.
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use tau_ai::{provider_key_pool_health_snapshot, Provider, ProviderKeyHealth};
+use tau_core::current_unix_timestamp;
+
+use crate::{
+    load_credential_store, save_credential_store, AuthCommand, AuthCommandConfig,
+    ProviderPooledKeyRecord, AUTH_POOL_USAGE,
+};
+
+use super::parse_auth_provider;
+
+pub(super) fn parse_auth_pool_command(tokens: &[&str]) -> Result<AuthCommand> {
+    let (Some(action), Some(provider)) = (tokens.first(), tokens.get(1)) else {
+        bail!("{AUTH_POOL_USAGE}");
+    };
+    if !matches!(*action, "add" | "remove") {
+        bail!("unknown pool action '{}'; {AUTH_POOL_USAGE}", action);
+    }
+    let provider = parse_auth_provider(provider)?;
+    let mut label: Option<String> = None;
+    let mut api_key: Option<String> = None;
+    let mut json_output = false;
+    let mut index = 2usize;
+    while index < tokens.len() {
+        match tokens[index] {
+            "--json" => {
+                json_output = true;
+                index += 1;
+            }
+            flag @ ("--label" | "--key") => {
+                let slot = if flag == "--label" {
+                    &mut label
+                } else {
+                    &mut api_key
+                };
+                if slot.is_some() {
+                    bail!("duplicate {flag} flag; {AUTH_POOL_USAGE}");
+                }
+                let Some(value) = tokens
+                    .get(index + 1)
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                else {
+                    bail!("missing value after {flag}; {AUTH_POOL_USAGE}");
+                };
+                *slot = Some(value.to_string());
+                index += 2;
+            }
+            other => bail!("unexpected argument '{}'; {AUTH_POOL_USAGE}", other),
+        }
+    }
+    let Some(label) = label else {
+        bail!("missing --label flag; {AUTH_POOL_USAGE}");
+    };
+    if *action == "remove" {
+        if api_key.is_some() {
+            bail!("--key is only valid for pool add; {AUTH_POOL_USAGE}");
+        }
+        return Ok(AuthCommand::PoolRemove {
+            provider,
+            label,
+            json_output,
+        });
+    }
+    let Some(api_key) = api_key else {
+        bail!("missing --key flag; {AUTH_POOL_USAGE}");
+    };
+    Ok(AuthCommand::PoolAdd {
+        provider,
+        label,
+        api_key,
+        json_output,
+    })
+}
+
+fn render_auth_pool_result(
+    command: &str,
+    provider: Provider,
+    label: &str,
+    status: &str,
+    keys: usize,
+    json_output: bool,
+) -> String {
+    if json_output {
+        return serde_json::json!({
+            "command": format!("auth.pool_{command}"),
+            "provider": provider.as_str(),
+            "label": label,
+            "status": status,
+            "pool_keys": keys,
+        })
+        .to_string();
+    }
+    format!(
+        "auth pool {command}: provider={} label={} status={} pool_keys={}",
+        provider.as_str(),
+        label,
+        status,
+        keys
+    )
+}
+
+fn render_auth_pool_error(
+    command: &str,
+    provider: Provider,
+    reason: String,
+    json_output: bool,
+) -> String {
+    if json_output {
+        return serde_json::json!({
+            "command": format!("auth.pool_{command}"),
+            "provider": provider.as_str(),
+            "status": "error",
+            "reason": reason,
+        })
+        .to_string();
+    }
+    format!(
+        "auth pool {command} error: provider={} error={reason}",
+        provider.as_str()
+    )
+}
+
+/// Adds or replaces a labelled API key in a provider's credential pool.
+pub fn execute_auth_pool_add_command(
+    config: &AuthCommandConfig,
+    provider: Provider,
+    label: String,
+    api_key: String,
+    json_output: bool,
+) -> String {
+    let mut store = match load_credential_store(
+        &config.credential_store,
+        config.credential_store_encryption,
+        config.credential_store_key.as_deref(),
+    ) {
+        Ok(store) => store,
+        Err(error) => {
+            return render_auth_pool_error("add", provider, error.to_string(), json_output)
+        }
+    };
+    let pool = store
+        .provider_key_pools
+        .entry(provider.as_str().to_string())
+        .or_default();
+    let record = ProviderPooledKeyRecord {
+        label: label.clone(),
+        api_key: Some(api_key),
+        revoked: false,
+        added_unix: Some(current_unix_timestamp()),
+    };
+    let status = match pool.iter_mut().find(|entry| entry.label == label) {
+        Some(entry) => {
+            *entry = record;
+            "replaced"
+        }
+        None => {
+            pool.push(record);
+            "added"
+        }
+    };
+    let keys = pool.len();
+    if let Err(error) = save_credential_store(
+        &config.credential_store,
+        &store,
+        config.credential_store_key.as_deref(),
+    ) {
+        return render_auth_pool_error("add", provider, error.to_string(), json_output);
+    }
+    render_auth_pool_result("add", provider, &label, status, keys, json_output)
+}
+
+/// Removes a labelled API key from a provider's credential pool.
+pub fn execute_auth_pool_remove_command(
+    config: &AuthCommandConfig,
+    provider: Provider,
+    label: String,
+    json_output: bool,
+) -> String {
+    let mut store = match load_credential_store(
+        &config.credential_store,
+        config.credential_store_encryption,
+        config.credential_store_key.as_deref(),
+    ) {
+        Ok(store) => store,
+        Err(error) => {
+            return render_auth_pool_error("remove", provider, error.to_string(), json_output)
+        }
+    };
+    let pool = store
+        .provider_key_pools
+        .entry(provider.as_str().to_string())
+        .or_default();
+    let before = pool.len();
+    pool.retain(|entry| entry.label != label);
+    let keys = pool.len();
+    let status = if keys < before {
+        "removed"
+    } else {
+        "not_found"
+    };
+    if keys == 0 {
+        store.provider_key_pools.remove(provider.as_str());
+    }
+    if status == "removed" {
+        if let Err(error) = save_credential_store(
+            &config.credential_store,
+            &store,
+            config.credential_store_key.as_deref(),
+        ) {
+            return render_auth_pool_error("remove", provider, error.to_string(), json_output);
+        }
+    }
+    render_auth_pool_result("remove", provider, &label, status, keys, json_output)
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+/// Configured and live key pool state for one provider in `/auth status`.
+pub struct AuthKeyPoolRow {
+    pub provider: String,
+    pub configured_keys: usize,
+    pub revoked_keys: usize,
+    pub labels: Vec<String>,
+    pub live: bool,
+    pub healthy_keys: usize,
+    pub quarantined_keys: usize,
+    pub keys: Vec<ProviderKeyHealth>,
+}
+
+/// Merges credential-store pool entries with health from live pool clients.
+pub(super) fn auth_key_pool_rows(
+    config: &AuthCommandConfig,
+    providers: &[Provider],
+) -> Vec<AuthKeyPoolRow> {
+    let store = if config.credential_store.exists() {
+        load_credential_store(
+            &config.credential_store,
+            config.credential_store_encryption,
+            config.credential_store_key.as_deref(),
+        )
+        .ok()
+    } else {
+        None
+    };
+    let live_pools = provider_key_pool_health_snapshot();
+    providers
+        .iter()
+        .filter_map(|provider| {
+            let configured = store
+                .as_ref()
+                .and_then(|store| store.provider_key_pools.get(provider.as_str()));
+            let live = live_pools
+                .iter()
+                .rev()
+                .find(|pool| pool.provider == provider.as_str());
+            if configured.is_none() && live.is_none() {
+                return None;
+            }
+            let configured = configured.map(Vec::as_slice).unwrap_or_default();
+            Some(AuthKeyPoolRow {
+                provider: provider.as_str().to_string(),
+                configured_keys: configured.len(),
+                revoked_keys: configured.iter().filter(|entry| entry.revoked).count(),
+                labels: configured.iter().map(|entry| entry.label.clone()).collect(),
+                live: live.is_some(),
+                healthy_keys: live.map(|pool| pool.healthy_keys).unwrap_or_default(),
+                quarantined_keys: live.map(|pool| pool.quarantined_keys).unwrap_or_default(),
+                keys: live.map(|pool| pool.keys.clone()).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+pub(super) fn format_auth_key_pool_row(row: &AuthKeyPoolRow) -> String {
+    let keys = if row.keys.is_empty() {
+        "none".to_string()
+    } else {
+        row.keys
+            .iter()
+            .map(|key| {
+                format!(
+                    "{}:{}:remaining_requests={}",
+                    key.label,
+                    key.quarantine_reason.as_deref().unwrap_or(&key.state),
+                    key.remaining_requests
+                        .map(|value| value.to_string())
+                        .unwrap_or_else(|| "unknown".to_string())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    format!(
+        "auth key pool: provider={} configured_keys={} revoked_keys={} labels={} live={} healthy_keys={} quarantined_keys={} keys={}",
+        row.provider,
+        row.configured_keys,
+        row.revoked_keys,
+        if row.labels.is_empty() {
+            "none".to_string()
+        } else {
+            row.labels.join(",")
+        },
+        row.live,
+        row.healthy_keys,
+        row.quarantined_keys,
+        keys
+    )
+}
@@ -14,14 +14,16 @@ use std::{
 use anyhow::{anyhow, bail, Result};
 use async_trait::async_trait;
 use tau_ai::{
-    AnthropicClient, AnthropicConfig, GoogleClient, GoogleConfig, LlmClient, OpenAiAuthScheme,
-    OpenAiClient, OpenAiConfig, Provider,
+    default_bedrock_api_base, default_vertex_api_base, AnthropicClient, AnthropicConfig,
+    BedrockClient, BedrockConfig, GoogleClient, GoogleConfig, LlmClient, LocalClient, LocalConfig,
+    OpenAiAuthScheme, OpenAiClient, OpenAiConfig, Provider, VertexClient, VertexConfig,
+    VertexCredentials, VertexServiceAccountKey,
 };
 use tau_cli::Cli;
 
 use crate::auth::{
     configured_provider_auth_method, missing_provider_api_key_message, provider_auth_capability,
-    provider_auth_mode_flag,
+    provider_auth_mode_flag, provider_supported_auth_modes,
 };
 use crate::claude_cli_client::{ClaudeCliClient, ClaudeCliConfig};
 use crate::codex_appserver_client::{CodexAppServerClient, CodexAppServerConfig};
@@ -29,9 +31,11 @@ use crate::codex_appserver_lifecycle::CodexAppServerProcess;
 use crate::codex_cli_client::{CodexCliClient, CodexCliConfig};
 use crate::credential_store::{load_credential_store, resolve_credential_store_encryption_mode};
 use crate::credentials::{
-    CliProviderCredentialResolver, ProviderAuthCredential, ProviderCredentialResolver,
+    parse_aws_credential_secret, CliProviderCredentialResolver, ProviderAuthCredential,
+    ProviderCredentialResolver,
 };
 use crate::gemini_cli_client::{GeminiCliClient, GeminiCliConfig};
+use crate::key_pool::build_key_pooled_client;
 use crate::types::ProviderAuthMethod;
 
 const DEFAULT_OPENAI_API_BASE: &str = "https://api.openai.com/v1";
@@ -215,6 +219,15 @@ impl LlmClient for ProviderRateLimitedClient {
         self.limiter.acquire(self.provider).await?;
         self.inner.complete_with_stream(request, on_delta).await
     }
+
+    async fn complete_with_events(
+        &self,
+        request: tau_ai::ChatRequest,
+        on_event: Option<tau_ai::StreamEventHandler>,
+    ) -> Result<tau_ai::ChatResponse, tau_ai::TauAiError> {
+        self.limiter.acquire(self.provider).await?;
+        self.inner.complete_with_events(request, on_event).await
+    }
 }
 
 fn maybe_wrap_provider_rate_limited_client(
@@ -298,6 +311,7 @@ fn should_use_subscription_backend_fallback(
         Provider::OpenAi | Provider::OpenRouter => cli.openai_codex_backend,
         Provider::Anthropic => cli.anthropic_claude_backend,
         Provider::Google => cli.google_gemini_backend,
+        Provider::Bedrock | Provider::Vertex | Provider::Local => false,
     }
 }
 
@@ -515,34 +529,42 @@ fn build_openai_http_client(
     let api_key = resolved_secret_for_provider(resolved, provider)?;
     let api_base = match provider {
         Provider::OpenRouter => resolve_openrouter_api_base(&cli.api_base),
-        Provider::OpenAi | Provider::Anthropic | Provider::Google => cli.api_base.clone(),
+        Provider::OpenAi
+        | Provider::Anthropic
+        | Provider::Google
+        | Provider::Bedrock
+        | Provider::Vertex
+        | Provider::Local => cli.api_base.clone(),
     };
     let azure_mode = provider == Provider::OpenAi && is_azure_openai_endpoint(&api_base);
-    let client = OpenAiClient::new(OpenAiConfig {
-        api_base,
-        api_key,
-        organization: None,
-        request_timeout_ms: cli.request_timeout_ms.max(1),
-        max_retries: cli.provider_max_retries,
-        retry_budget_ms: cli.provider_retry_budget_ms,
-        retry_jitter: cli.provider_retry_jitter,
-        auth_scheme: if azure_mode {
-            OpenAiAuthScheme::ApiKeyHeader
-        } else {
-            OpenAiAuthScheme::Bearer
-        },
-        api_version: if azure_mode {
-            Some(cli.azure_openai_api_version.clone())
-        } else {
-            None
-        },
+    let client = build_key_pooled_client(cli, provider, resolved, api_key, |api_key| {
+        let client = OpenAiClient::new(OpenAiConfig {
+            api_base: api_base.clone(),
+            api_key,
+            organization: None,
+            request_timeout_ms: cli.request_timeout_ms.max(1),
+            max_retries: cli.provider_max_retries,
+            retry_budget_ms: cli.provider_retry_budget_ms,
+            retry_jitter: cli.provider_retry_jitter,
+            auth_scheme: if azure_mode {
+                OpenAiAuthScheme::ApiKeyHeader
+            } else {
+                OpenAiAuthScheme::Bearer
+            },
+            api_version: if azure_mode {
+                Some(cli.azure_openai_api_version.clone())
+            } else {
+                None
+            },
+        })?;
+        Ok(Arc::new(client) as Arc<dyn LlmClient>)
     })?;
     let auth_source = resolved.source.as_deref().unwrap_or("none");
     log_provider_auth_resolution(provider, resolved, auth_source);
     Ok(maybe_wrap_provider_rate_limited_client(
         rate_limit_config,
         provider,
-        Arc::new(client),
+        client,
     ))
 }
 
@@ -552,20 +574,23 @@ fn build_anthropic_http_client(
 ) -> Result<Arc<dyn LlmClient>> {
     let rate_limit_config = ProviderOutboundRateLimitConfig::from_cli(cli);
     let api_key = resolved_secret_for_provider(resolved, Provider::Anthropic)?;
-    let client = AnthropicClient::new(AnthropicConfig {
-        api_base: cli.anthropic_api_base.clone(),
-        api_key,
-        request_timeout_ms: cli.request_timeout_ms.max(1),
-        max_retries: cli.provider_max_retries,
-        retry_budget_ms: cli.provider_retry_budget_ms,
-        retry_jitter: cli.provider_retry_jitter,
+    let client = build_key_pooled_client(cli, Provider::Anthropic, resolved, api_key, |api_key| {
+        let client = AnthropicClient::new(AnthropicConfig {
+            api_base: cli.anthropic_api_base.clone(),
+            api_key,
+            request_timeout_ms: cli.request_timeout_ms.max(1),
+            max_retries: cli.provider_max_retries,
+            retry_budget_ms: cli.provider_retry_budget_ms,
+            retry_jitter: cli.provider_retry_jitter,
+        })?;
+        Ok(Arc::new(client) as Arc<dyn LlmClient>)
     })?;
     let auth_source = resolved.source.as_deref().unwrap_or("none");
     log_provider_auth_resolution(Provider::Anthropic, resolved, auth_source);
     Ok(maybe_wrap_provider_rate_limited_client(
         rate_limit_config,
         Provider::Anthropic,
-        Arc::new(client),
+        client,
     ))
 }
 
@@ -575,19 +600,148 @@ fn build_google_http_client(
 ) -> Result<Arc<dyn LlmClient>> {
     let rate_limit_config = ProviderOutboundRateLimitConfig::from_cli(cli);
     let api_key = resolved_secret_for_provider(resolved, Provider::Google)?;
-    let client = GoogleClient::new(GoogleConfig {
-        api_base: cli.google_api_base.clone(),
-        api_key,
+    let client = build_key_pooled_client(cli, Provider::Google, resolved, api_key, |api_key| {
+        let client = GoogleClient::new(GoogleConfig {
+            api_base: cli.google_api_base.clone(),
+            api_key,
+            request_timeout_ms: cli.request_timeout_ms.max(1),
+            max_retries: cli.provider_max_retries,
+            retry_budget_ms: cli.provider_retry_budget_ms,
+            retry_jitter: cli.provider_retry_jitter,
+        })?;
+        Ok(Arc::new(client) as Arc<dyn LlmClient>)
+    })?;
+    let auth_source = resolved.source.as_deref().unwrap_or("none");
+    log_provider_auth_resolution(Provider::Google, resolved, auth_source);
+    Ok(maybe_wrap_provider_rate_limited_client(
+        rate_limit_config,
+        Provider::Google,
+        client,
+    ))
+}
+
+fn build_bedrock_http_client(
+    cli: &Cli,
+    resolved: &ProviderAuthCredential,
+) -> Result<Arc<dyn LlmClient>> {
+    let rate_limit_config = ProviderOutboundRateLimitConfig::from_cli(cli);
+    let credentials =
+        parse_aws_credential_secret(&resolved_secret_for_provider(resolved, Provider::Bedrock)?)?;
+    let region = cli.bedrock_region.trim().to_string();
+    let api_base = cli
+        .bedrock_api_base
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| default_bedrock_api_base(&region));
+    let client = BedrockClient::new(BedrockConfig {
+        api_base,
+        region,
+        credentials,
         request_timeout_ms: cli.request_timeout_ms.max(1),
         max_retries: cli.provider_max_retries,
         retry_budget_ms: cli.provider_retry_budget_ms,
         retry_jitter: cli.provider_retry_jitter,
     })?;
     let auth_source = resolved.source.as_deref().unwrap_or("none");
-    log_provider_auth_resolution(Provider::Google, resolved, auth_source);
+    log_provider_auth_resolution(Provider::Bedrock, resolved, auth_source);
     Ok(maybe_wrap_provider_rate_limited_client(
         rate_limit_config,
-        Provider::Google,
+        Provider::Bedrock,
+        Arc::new(client),
+    ))
+}
+
+fn load_vertex_service_account_key(cli: &Cli) -> Result<VertexServiceAccountKey> {
+    let path = cli.vertex_service_account_file.as_ref().ok_or_else(|| {
+        anyhow!(
+            "vertex adc auth requires a service account key; set --vertex-service-account-file or GOOGLE_APPLICATION_CREDENTIALS"
+        )
+    })?;
+    let raw = std::fs::read_to_string(path).map_err(|error| {
+        anyhow!(
+            "failed to read vertex service account key {}: {error}",
+            path.display()
+        )
+    })?;
+    Ok(VertexServiceAccountKey::from_json(&raw)?)
+}
+
+fn build_vertex_http_client(
+    cli: &Cli,
+    resolved: &ProviderAuthCredential,
+) -> Result<Arc<dyn LlmClient>> {
+    let rate_limit_config = ProviderOutboundRateLimitConfig::from_cli(cli);
+    let (credentials, key_project) = match resolved.method {
+        ProviderAuthMethod::Adc => {
+            let key = load_vertex_service_account_key(cli)?;
+            let project = key.project_id.clone();
+            (VertexCredentials::ServiceAccount(key), project)
+        }
+        _ => (
+            VertexCredentials::AccessToken(resolved_secret_for_provider(
+                resolved,
+                Provider::Vertex,
+            )?),
+            None,
+        ),
+    };
+    let project_id = cli
+        .vertex_project
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+        .or(key_project)
+        .or_else(|| non_empty_env("GOOGLE_CLOUD_PROJECT"))
+        .ok_or_else(|| {
+            anyhow!(
+                "vertex provider requires a project; set --vertex-project or GOOGLE_CLOUD_PROJECT"
+            )
+        })?;
+    let location = cli.vertex_location.trim().to_string();
+    let api_base = cli
+        .vertex_api_base
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| default_vertex_api_base(&location));
+    let client = VertexClient::new(VertexConfig {
+        api_base,
+        project_id,
+        location,
+        credentials,
+        request_timeout_ms: cli.request_timeout_ms.max(1),
+        max_retries: cli.provider_max_retries,
+        retry_budget_ms: cli.provider_retry_budget_ms,
+        retry_jitter: cli.provider_retry_jitter,
+    })?;
+    let auth_source = match resolved.method {
+        ProviderAuthMethod::Adc => "service_account",
+        _ => resolved.source.as_deref().unwrap_or("none"),
+    };
+    log_provider_auth_resolution(Provider::Vertex, resolved, auth_source);
+    Ok(maybe_wrap_provider_rate_limited_client(
+        rate_limit_config,
+        Provider::Vertex,
+        Arc::new(client),
+    ))
+}
+
+fn build_local_http_client(cli: &Cli) -> Result<Arc<dyn LlmClient>> {
+    let rate_limit_config = ProviderOutboundRateLimitConfig::from_cli(cli);
+    let client = LocalClient::new(LocalConfig {
+        api_base: cli.local_api_base.clone(),
+        api_key: cli.local_api_key.clone(),
+        request_timeout_ms: cli.request_timeout_ms.max(1),
+        max_retries: cli.provider_max_retries,
+        retry_budget_ms: cli.provider_retry_budget_ms,
+        retry_jitter: cli.provider_retry_jitter,
+    })?;
+    tracing::debug!(
+        provider = Provider::Local.as_str(),
+        api_base = cli.local_api_base.as_str(),
+        "local inference client configured"
+    );
+    Ok(maybe_wrap_provider_rate_limited_client(
+        rate_limit_config,
+        Provider::Local,
         Arc::new(client),
     ))
 }
@@ -602,11 +756,15 @@ pub fn build_provider_client(cli: &Cli, provider: Provider) -> Result<Arc<dyn Ll
     let capability = provider_auth_capability(provider, auth_mode);
     if !capability.supported {
         bail!(
-            "unsupported auth mode '{}' for provider '{}': {} (set {} api-key)",
+            "unsupported auth mode '{}' for provider '{}': {} (set {} {})",
             auth_mode.as_str(),
             provider.as_str(),
             capability.reason,
             provider_auth_mode_flag(provider),
+            provider_supported_auth_modes(provider)
+                .first()
+                .map(|method| method.as_str())
+                .unwrap_or("api-key"),
         );
     }
 
@@ -760,6 +918,17 @@ pub fn build_provider_client(cli: &Cli, provider: Provider) -> Result<Arc<dyn Ll
             };
             build_google_http_client(cli, &resolved)
         }
+        Provider::Bedrock => {
+            let resolver = CliProviderCredentialResolver { cli };
+            let resolved = resolver.resolve(provider, auth_mode)?;
+            build_bedrock_http_client(cli, &resolved)
+        }
+        Provider::Vertex => {
+            let resolver = CliProviderCredentialResolver { cli };
+            let resolved = resolver.resolve(provider, auth_mode)?;
+            build_vertex_http_client(cli, &resolved)
+        }
+        Provider::Local => build_local_http_client(cli),
     }
 }
 
@@ -810,6 +979,8 @@ mod tests {
             max_tokens: Some(16),
             temperature: Some(0.0),
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         }
     }
 
@@ -1085,6 +1256,8 @@ mod tests {
                 max_tokens: Some(16),
                 temperature: Some(0.0),
                 prompt_cache: Default::default(),
+                reasoning: Default::default(),
+                model_tier: None,
             })
             .await
             .expect("live direct Responses request should complete");
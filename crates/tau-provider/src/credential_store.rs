@@ -48,6 +48,8 @@ struct CredentialStoreFile {
     providers: BTreeMap<String, StoredProviderCredential>,
     #[serde(default)]
     integrations: BTreeMap<String, StoredIntegrationCredential>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    provider_key_pools: BTreeMap<String, Vec<StoredPooledProviderKey>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -66,6 +68,15 @@ struct StoredIntegrationCredential {
     updated_unix: Option<u64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct StoredPooledProviderKey {
+    label: String,
+    api_key: Option<String>,
+    revoked: bool,
+    #[serde(default)]
+    added_unix: Option<u64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Public struct `ProviderCredentialStoreRecord` used across Tau components.
 pub struct ProviderCredentialStoreRecord {
@@ -84,12 +95,23 @@ pub struct IntegrationCredentialStoreRecord {
     pub updated_unix: Option<u64>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One labelled API key in a provider's credential pool.
+pub struct ProviderPooledKeyRecord {
+    pub label: String,
+    pub api_key: Option<String>,
+    pub revoked: bool,
+    pub added_unix: Option<u64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Public struct `CredentialStoreData` used across Tau components.
 pub struct CredentialStoreData {
     pub encryption: CredentialStoreEncryptionMode,
     pub providers: BTreeMap<String, ProviderCredentialStoreRecord>,
     pub integrations: BTreeMap<String, IntegrationCredentialStoreRecord>,
+    /// Additional API keys per provider, rotated by the key pool client.
+    pub provider_key_pools: BTreeMap<String, Vec<ProviderPooledKeyRecord>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -577,6 +599,7 @@ pub fn load_credential_store(
             encryption: default_mode,
             providers: BTreeMap::new(),
             integrations: BTreeMap::new(),
+            provider_key_pools: BTreeMap::new(),
         });
     }
 
@@ -668,10 +691,43 @@ pub fn load_credential_store(
         );
     }
 
+    let mut provider_key_pools = BTreeMap::new();
+    for (provider, keys) in parsed.provider_key_pools {
+        let mut records = Vec::with_capacity(keys.len());
+        for record in keys {
+            let api_key = record
+                .api_key
+                .map(|value| {
+                    if parsed.encryption == CredentialStoreEncryptionMode::Keyed
+                        && !value.starts_with(CREDENTIAL_STORE_ENCRYPTED_V3_PREFIX)
+                    {
+                        migration_required = true;
+                    }
+                    decrypt_credential_store_secret(&value, parsed.encryption, key).with_context(
+                        || {
+                            format!(
+                                "credential store pool key '{}/{}' is invalid or corrupted",
+                                provider, record.label
+                            )
+                        },
+                    )
+                })
+                .transpose()?;
+            records.push(ProviderPooledKeyRecord {
+                label: record.label,
+                api_key,
+                revoked: record.revoked,
+                added_unix: record.added_unix,
+            });
+        }
+        provider_key_pools.insert(provider, records);
+    }
+
     let store = CredentialStoreData {
         encryption: parsed.encryption,
         providers,
         integrations,
+        provider_key_pools,
     };
     if migration_required {
         save_credential_store(path, &store, key).with_context(|| {
@@ -753,11 +809,40 @@ pub fn save_credential_store(
         );
     }
 
+    let mut provider_key_pools = BTreeMap::new();
+    for (provider, records) in &store.provider_key_pools {
+        let mut keys = Vec::with_capacity(records.len());
+        for record in records {
+            let api_key = record
+                .api_key
+                .as_deref()
+                .map(|value| {
+                    encrypt_credential_store_secret(value, store.encryption, key).with_context(
+                        || {
+                            format!(
+                                "failed to encode credential store pool key '{}/{}'",
+                                provider, record.label
+                            )
+                        },
+                    )
+                })
+                .transpose()?;
+            keys.push(StoredPooledProviderKey {
+                label: record.label.clone(),
+                api_key,
+                revoked: record.revoked,
+                added_unix: record.added_unix,
+            });
+        }
+        provider_key_pools.insert(provider.clone(), keys);
+    }
+
     let payload = CredentialStoreFile {
         schema_version: CREDENTIAL_STORE_SCHEMA_VERSION,
         encryption: store.encryption,
         providers,
         integrations,
+        provider_key_pools,
     };
     let mut encoded =
         serde_json::to_string_pretty(&payload).context("failed to encode credential store")?;
@@ -935,6 +1020,7 @@ mod tests {
             encryption: CredentialStoreEncryptionMode::Keyed,
             providers,
             integrations: BTreeMap::<String, IntegrationCredentialStoreRecord>::new(),
+            provider_key_pools: BTreeMap::new(),
         };
         save_credential_store(&path, &store, Some("credential-store-passphrase-v3"))
             .expect("save store");
@@ -5,7 +5,7 @@
 //! surfaced with provider-specific messages and fail-closed semantics.
 
 use anyhow::{anyhow, Context, Result};
-use tau_ai::Provider;
+use tau_ai::{AwsCredentials, Provider, VertexServiceAccountKey};
 use tau_cli::Cli;
 use tau_core::current_unix_timestamp;
 
@@ -161,6 +161,49 @@ pub fn resolve_non_empty_secret_with_source(
     })
 }
 
+/// Returns AWS credentials from the standard environment variables encoded as a
+/// single provider secret (`access_key_id:secret_access_key[:session_token]`).
+///
+/// The combined form lets Bedrock credentials flow through the same api-key and
+/// session-token resolution paths and credential store entries as other providers.
+pub fn aws_credential_secret_from_env(include_session_token: bool) -> Option<String> {
+    let non_empty = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let access_key_id = non_empty("AWS_ACCESS_KEY_ID")?;
+    let secret_access_key = non_empty("AWS_SECRET_ACCESS_KEY")?;
+    if !include_session_token {
+        return Some(format!("{access_key_id}:{secret_access_key}"));
+    }
+    let session_token = non_empty("AWS_SESSION_TOKEN")?;
+    Some(format!(
+        "{access_key_id}:{secret_access_key}:{session_token}"
+    ))
+}
+
+/// Parses a secret produced by [`aws_credential_secret_from_env`] into AWS credentials.
+pub fn parse_aws_credential_secret(secret: &str) -> Result<AwsCredentials> {
+    let mut parts = secret.trim().splitn(3, ':').map(str::trim);
+    let access_key_id = parts.next().filter(|value| !value.is_empty());
+    let secret_access_key = parts.next().filter(|value| !value.is_empty());
+    let (Some(access_key_id), Some(secret_access_key)) = (access_key_id, secret_access_key) else {
+        return Err(anyhow!(
+            "invalid AWS credential: expected access_key_id:secret_access_key[:session_token]"
+        ));
+    };
+    Ok(AwsCredentials {
+        access_key_id: access_key_id.to_string(),
+        secret_access_key: secret_access_key.to_string(),
+        session_token: parts
+            .next()
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+    })
+}
+
 fn resolve_env_backed_provider_credential(
     provider: Provider,
     method: ProviderAuthMethod,
@@ -279,6 +322,44 @@ fn google_gemini_backend_snapshot(
     }
 }
 
+fn vertex_service_account_snapshot(config: &AuthCommandConfig) -> ProviderAuthSnapshot {
+    let snapshot =
+        |available: bool, state: &str, source: String, reason: String| ProviderAuthSnapshot {
+            provider: Provider::Vertex,
+            method: ProviderAuthMethod::Adc,
+            mode_supported: true,
+            available,
+            state: state.to_string(),
+            source,
+            reason,
+            expires_unix: None,
+            revoked: false,
+            refreshable: available,
+            secret: None,
+        };
+    let Some(path) = config.vertex_service_account_file.as_ref() else {
+        return snapshot(
+            false,
+            "missing_service_account",
+            "none".to_string(),
+            "set --vertex-service-account-file or GOOGLE_APPLICATION_CREDENTIALS".to_string(),
+        );
+    };
+    let source = path.display().to_string();
+    match std::fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|raw| VertexServiceAccountKey::from_json(&raw).map_err(anyhow::Error::from))
+    {
+        Ok(_) => snapshot(
+            true,
+            "ready",
+            source,
+            "vertex_service_account_available".to_string(),
+        ),
+        Err(error) => snapshot(false, "invalid_service_account", source, error.to_string()),
+    }
+}
+
 fn openai_codex_backend_snapshot(
     config: &AuthCommandConfig,
     mode: ProviderAuthMethod,
@@ -437,6 +518,10 @@ pub fn provider_auth_snapshot_for_status(
         return google_gemini_backend_snapshot(config, mode);
     }
 
+    if provider == Provider::Vertex && mode == ProviderAuthMethod::Adc {
+        return vertex_service_account_snapshot(config);
+    }
+
     if provider == Provider::Anthropic
         && matches!(
             mode,
@@ -898,6 +983,9 @@ mod tests {
             openai_auth_mode: ProviderAuthMethod::ApiKey,
             anthropic_auth_mode: ProviderAuthMethod::ApiKey,
             google_auth_mode: ProviderAuthMethod::ApiKey,
+            bedrock_auth_mode: ProviderAuthMethod::ApiKey,
+            vertex_auth_mode: ProviderAuthMethod::Adc,
+            vertex_service_account_file: None,
             provider_subscription_strict: false,
             openai_codex_backend: true,
             openai_codex_cli: "codex".to_string(),
@@ -927,6 +1015,7 @@ mod tests {
             encryption: CredentialStoreEncryptionMode::Keyed,
             providers,
             integrations: BTreeMap::new(),
+            provider_key_pools: BTreeMap::new(),
         };
 
         let snapshot =
@@ -956,6 +1045,7 @@ mod tests {
             encryption: CredentialStoreEncryptionMode::Keyed,
             providers,
             integrations: BTreeMap::new(),
+            provider_key_pools: BTreeMap::new(),
         };
 
         let snapshot =
@@ -966,4 +1056,33 @@ mod tests {
         assert_eq!(snapshot.source, "credential_store");
         assert_eq!(snapshot.secret.as_deref(), Some("store-api-key"));
     }
+
+    #[test]
+    fn unit_parse_aws_credential_secret_accepts_optional_session_token() {
+        let credentials =
+            parse_aws_credential_secret("AKIDEXAMPLE:secret-key:session-token").expect("parse");
+        assert_eq!(credentials.access_key_id, "AKIDEXAMPLE");
+        assert_eq!(credentials.secret_access_key, "secret-key");
+        assert_eq!(credentials.session_token.as_deref(), Some("session-token"));
+
+        let credentials = parse_aws_credential_secret("AKIDEXAMPLE:secret-key").expect("parse");
+        assert_eq!(credentials.session_token, None);
+
+        let error = parse_aws_credential_secret("AKIDEXAMPLE").expect_err("missing secret");
+        assert!(error
+            .to_string()
+            .contains("access_key_id:secret_access_key"));
+    }
+
+    #[test]
+    fn unit_provider_auth_snapshot_for_status_vertex_adc_without_service_account_is_missing() {
+        let mut config = test_auth_config();
+        config.vertex_auth_mode = ProviderAuthMethod::Adc;
+        config.vertex_service_account_file = None;
+
+        let snapshot = provider_auth_snapshot_for_status(&config, Provider::Vertex, None, None);
+
+        assert!(!snapshot.available);
+        assert_eq!(snapshot.state, "missing_service_account");
+    }
 }
@@ -4,20 +4,30 @@
 //! clients, preserving error context and attempt ordering for diagnostics. It is
 //! used when startup/runtime enables provider fallback routing.
 
-use std::sync::{Arc, Mutex};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use tau_ai::{
-    ChatRequest, ChatResponse, LlmClient, ModelRef, Provider, StreamDeltaHandler, TauAiError,
+    stream_event_handler_from_deltas, ChatRequest, ChatResponse, LlmClient, ModelRef, Provider,
+    StreamDeltaHandler, StreamEvent, StreamEventHandler, TauAiError, TokenizerFamily,
 };
 use tau_cli::Cli;
-use tau_core::current_unix_timestamp_ms;
+use tau_core::{current_unix_timestamp_ms, global_metrics_registry};
+use tokio::sync::watch;
 
 use crate::client::build_provider_client;
+use crate::model_router::ModelRoutingFeatures;
 
 type FallbackEventSink = Arc<dyn Fn(serde_json::Value) + Send + Sync>;
 type ClockFn = Arc<dyn Fn() -> u64 + Send + Sync>;
+type RouteAttemptFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<ChatResponse, TauAiError>> + Send + 'a>>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Public struct `CircuitBreakerConfig` used across Tau components.
@@ -43,6 +53,111 @@ struct RouteCircuitState {
     open_until_unix_ms: Option<u64>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Settings for hedging the first available route with a duplicate request.
+pub struct HedgingConfig {
+    /// First-token latency percentile (0-100) after which the hedge is sent.
+    pub percentile: f64,
+    /// Hedge delay used until a route has `min_samples` latency samples.
+    pub initial_delay_ms: u64,
+    /// Lower bound for the adaptive hedge delay.
+    pub min_delay_ms: u64,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for HedgingConfig {
+    fn default() -> Self {
+        Self {
+            percentile: 95.0,
+            initial_delay_ms: 2_000,
+            min_delay_ms: 250,
+            min_samples: 20,
+            max_samples: 200,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct RouteHedgeState {
+    first_token_latencies_ms: VecDeque<u64>,
+    hedges_launched: u64,
+    primary_wins: u64,
+    hedge_wins: u64,
+    hedged_for_others: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Hedge outcome statistics for one route.
+pub struct RouteHedgeStats {
+    pub model: String,
+    pub latency_samples: usize,
+    pub hedge_delay_ms: u64,
+    /// Hedges sent while this route was the primary attempt.
+    pub hedges_launched: u64,
+    /// Hedged requests this route won as the primary attempt.
+    pub primary_wins: u64,
+    /// Hedged requests the duplicate won while this route was primary.
+    pub hedge_wins: u64,
+    /// Duplicate requests this route served for another primary route.
+    pub hedged_for_others: u64,
+}
+
+/// First-event timing for one attempt inside a hedged race.
+struct HedgeAttemptProbe {
+    started: Instant,
+    first_event_ms: OnceLock<u64>,
+    tokenizer: TokenizerFamily,
+    streamed_output_tokens: AtomicU64,
+}
+
+impl HedgeAttemptProbe {
+    fn new(model: &str) -> Arc<Self> {
+        Arc::new(Self {
+            started: Instant::now(),
+            first_event_ms: OnceLock::new(),
+            tokenizer: TokenizerFamily::for_model(model),
+            streamed_output_tokens: AtomicU64::new(0),
+        })
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+
+    fn mark_first_event(&self) {
+        let _ = self.first_event_ms.set(self.elapsed_ms());
+    }
+
+    fn first_event_ms(&self) -> Option<u64> {
+        self.first_event_ms.get().copied()
+    }
+
+    /// Counts the output an attempt streamed, whether or not it owns the stream.
+    fn record_output(&self, event: &StreamEvent) {
+        let tokens = match event {
+            StreamEvent::TextDelta { text } | StreamEvent::ReasoningDelta { text } => {
+                self.tokenizer.count_text_tokens(text)
+            }
+            StreamEvent::ToolCallArgumentsDelta { delta, .. } => {
+                self.tokenizer.count_text_tokens(delta)
+            }
+            _ => 0,
+        };
+        self.streamed_output_tokens
+            .fetch_add(u64::from(tokens), Ordering::Relaxed);
+    }
+
+    fn streamed_output_tokens(&self) -> u64 {
+        self.streamed_output_tokens.load(Ordering::Relaxed)
+    }
+}
+
+enum HedgeRaceEvent {
+    Finished(usize, Result<ChatResponse, TauAiError>),
+    StreamClaimed(usize),
+}
+
 #[derive(Clone)]
 /// Public struct `ClientRoute` used across Tau components.
 pub struct ClientRoute {
@@ -55,6 +170,31 @@ impl ClientRoute {
     fn model_ref(&self) -> String {
         format!("{}/{}", self.provider, self.model)
     }
+
+    fn record_circuit_open_metric(&self, open: bool) {
+        global_metrics_registry().set_gauge(
+            "tau_provider_circuit_open",
+            "Whether a provider route circuit breaker is open (1) or closed (0).",
+            &[
+                ("provider", self.provider.as_str()),
+                ("model", self.model.as_str()),
+            ],
+            if open { 1.0 } else { 0.0 },
+        );
+    }
+
+    fn record_circuit_event_metric(&self, event: &str) {
+        global_metrics_registry().increment_counter(
+            "tau_provider_circuit_events",
+            "Provider route circuit breaker transitions and skipped attempts.",
+            &[
+                ("provider", self.provider.as_str()),
+                ("model", self.model.as_str()),
+                ("event", event),
+            ],
+            1.0,
+        );
+    }
 }
 
 /// Public struct `FallbackRoutingClient` used across Tau components.
@@ -63,6 +203,8 @@ pub struct FallbackRoutingClient {
     event_sink: Option<FallbackEventSink>,
     circuit_breaker: CircuitBreakerConfig,
     route_circuit_state: Mutex<Vec<RouteCircuitState>>,
+    hedging: Option<HedgingConfig>,
+    route_hedge_state: Mutex<Vec<RouteHedgeState>>,
     clock: ClockFn,
 }
 
@@ -102,13 +244,45 @@ impl FallbackRoutingClient {
     ) -> Self {
         Self {
             route_circuit_state: Mutex::new(vec![RouteCircuitState::default(); routes.len()]),
+            route_hedge_state: Mutex::new(vec![RouteHedgeState::default(); routes.len()]),
             routes,
             event_sink,
             circuit_breaker,
+            hedging: None,
             clock,
         }
     }
 
+    /// Enables hedged requests: when the first available route has not produced
+    /// a first token within its latency percentile, the next available route
+    /// receives a duplicate and the first to finish (or, for streaming callers,
+    /// to start streaming) wins while the other is cancelled.
+    pub fn with_hedging(mut self, hedging: HedgingConfig) -> Self {
+        self.hedging = Some(hedging);
+        self
+    }
+
+    /// Returns per-route hedge latency and outcome statistics.
+    pub fn hedge_stats(&self) -> Vec<RouteHedgeStats> {
+        let hedging = self.hedging.unwrap_or_default();
+        self.routes
+            .iter()
+            .enumerate()
+            .map(|(index, route)| {
+                let state = lock_or_recover_mutex(&self.route_hedge_state)[index].clone();
+                RouteHedgeStats {
+                    model: route.model_ref(),
+                    latency_samples: state.first_token_latencies_ms.len(),
+                    hedge_delay_ms: self.hedge_delay_ms(index, hedging),
+                    hedges_launched: state.hedges_launched,
+                    primary_wins: state.primary_wins,
+                    hedge_wins: state.hedge_wins,
+                    hedged_for_others: state.hedged_for_others,
+                }
+            })
+            .collect()
+    }
+
     fn emit_fallback_event(
         &self,
         from: &ClientRoute,
@@ -173,6 +347,11 @@ impl FallbackRoutingClient {
         }
         route_state.open_until_unix_ms = None;
         route_state.consecutive_failures = 0;
+        drop(state);
+        if let Some(route) = self.routes.get(route_index) {
+            route.record_circuit_open_metric(false);
+            route.record_circuit_event_metric("closed");
+        }
         None
     }
 
@@ -181,8 +360,15 @@ impl FallbackRoutingClient {
         let Some(route_state) = state.get_mut(route_index) else {
             return;
         };
-        route_state.open_until_unix_ms = None;
+        let was_open = route_state.open_until_unix_ms.take().is_some();
         route_state.consecutive_failures = 0;
+        drop(state);
+        if was_open {
+            if let Some(route) = self.routes.get(route_index) {
+                route.record_circuit_open_metric(false);
+                route.record_circuit_event_metric("closed");
+            }
+        }
     }
 
     fn record_retryable_route_failure(&self, route_index: usize, now_unix_ms: u64) -> Option<u64> {
@@ -202,10 +388,353 @@ impl FallbackRoutingClient {
         Some(open_until)
     }
 
+    fn note_retryable_route_failure(&self, route_index: usize, now_unix_ms: u64) {
+        if let Some(open_until) = self.record_retryable_route_failure(route_index, now_unix_ms) {
+            let route = &self.routes[route_index];
+            route.record_circuit_open_metric(true);
+            route.record_circuit_event_metric("opened");
+            self.emit_circuit_opened_event(route, route_index, open_until);
+        }
+    }
+
+    fn route_attempt(
+        &self,
+        request: &ChatRequest,
+        route_index: usize,
+        on_event: Option<StreamEventHandler>,
+    ) -> RouteAttemptFuture<'_> {
+        let route = &self.routes[route_index];
+        let mut routed_request = request.clone();
+        routed_request.model = route.model.clone();
+        if let Some(event_handler) = on_event {
+            route
+                .client
+                .complete_with_events(routed_request, Some(event_handler))
+        } else {
+            route.client.complete(routed_request)
+        }
+    }
+
+    /// Returns the route that would receive a hedge for `route_index`, if hedging applies.
+    fn hedge_partner(
+        &self,
+        route_index: usize,
+        now_unix_ms: u64,
+    ) -> Option<(usize, HedgingConfig)> {
+        let hedging = self.hedging?;
+        let hedge_index = (route_index + 1..self.routes.len())
+            .find(|candidate| self.route_open_until(*candidate, now_unix_ms).is_none())?;
+        Some((hedge_index, hedging))
+    }
+
+    fn hedge_delay_ms(&self, route_index: usize, hedging: HedgingConfig) -> u64 {
+        let state = lock_or_recover_mutex(&self.route_hedge_state);
+        let Some(route_state) = state.get(route_index) else {
+            return hedging.initial_delay_ms.max(hedging.min_delay_ms);
+        };
+        let samples = &route_state.first_token_latencies_ms;
+        if samples.is_empty() || samples.len() < hedging.min_samples {
+            return hedging.initial_delay_ms.max(hedging.min_delay_ms);
+        }
+        let mut percentile = hedging.percentile.clamp(0.0, 100.0);
+        // Hedges that rarely win only add spend, so back off toward the tail.
+        if route_state.hedges_launched >= hedging.min_samples as u64
+            && route_state.hedge_wins.saturating_mul(10) < route_state.hedges_launched
+        {
+            percentile = (percentile + 100.0) / 2.0;
+        }
+        let mut sorted = samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let rank = ((percentile / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+        sorted[rank.min(sorted.len() - 1)].max(hedging.min_delay_ms)
+    }
+
+    fn record_first_token_latency(
+        &self,
+        route_index: usize,
+        latency_ms: u64,
+        hedging: HedgingConfig,
+    ) {
+        let mut state = lock_or_recover_mutex(&self.route_hedge_state);
+        let Some(route_state) = state.get_mut(route_index) else {
+            return;
+        };
+        route_state.first_token_latencies_ms.push_back(latency_ms);
+        while route_state.first_token_latencies_ms.len() > hedging.max_samples.max(1) {
+            route_state.first_token_latencies_ms.pop_front();
+        }
+    }
+
+    fn record_hedge_outcome(&self, primary_index: usize, hedge_index: usize, hedge_won: bool) {
+        let mut state = lock_or_recover_mutex(&self.route_hedge_state);
+        if let Some(primary_state) = state.get_mut(primary_index) {
+            if hedge_won {
+                primary_state.hedge_wins = primary_state.hedge_wins.saturating_add(1);
+            } else {
+                primary_state.primary_wins = primary_state.primary_wins.saturating_add(1);
+            }
+        }
+        if let Some(hedge_state) = state.get_mut(hedge_index) {
+            hedge_state.hedged_for_others = hedge_state.hedged_for_others.saturating_add(1);
+        }
+    }
+
+    fn emit_hedge_launched_event(&self, primary_index: usize, hedge_index: usize, delay_ms: u64) {
+        {
+            let mut state = lock_or_recover_mutex(&self.route_hedge_state);
+            if let Some(primary_state) = state.get_mut(primary_index) {
+                primary_state.hedges_launched = primary_state.hedges_launched.saturating_add(1);
+            }
+        }
+        let Some(sink) = &self.event_sink else {
+            return;
+        };
+        sink(serde_json::json!({
+            "type": "provider_hedge_launched",
+            "model": self.routes[primary_index].model_ref(),
+            "hedge_model": self.routes[hedge_index].model_ref(),
+            "route_index": primary_index,
+            "hedge_route_index": hedge_index,
+            "hedge_delay_ms": delay_ms,
+        }));
+    }
+
+    fn emit_hedge_outcome_event(
+        &self,
+        primary_index: usize,
+        hedge_index: usize,
+        winner_index: Option<usize>,
+        hedge_usage: (u64, u64),
+        elapsed_ms: u64,
+    ) {
+        let Some(sink) = &self.event_sink else {
+            return;
+        };
+        let outcome = match winner_index {
+            Some(index) if index == primary_index => "primary_won",
+            Some(_) => "hedge_won",
+            None => "both_failed",
+        };
+        sink(serde_json::json!({
+            "type": "provider_hedge_outcome",
+            "model": self.routes[primary_index].model_ref(),
+            "hedge_model": self.routes[hedge_index].model_ref(),
+            "outcome": outcome,
+            "winner_model": winner_index.map(|index| self.routes[index].model_ref()),
+            "hedge_input_tokens": hedge_usage.0,
+            "hedge_output_tokens": hedge_usage.1,
+            "elapsed_ms": elapsed_ms,
+        }));
+    }
+
+    /// Starts one attempt of a hedged race. Streaming events claim the shared
+    /// stream for the first attempt that emits one; events from the other
+    /// attempt are dropped so callers never see interleaved output.
+    fn hedge_attempt(
+        &self,
+        request: &ChatRequest,
+        route_index: usize,
+        on_event: Option<StreamEventHandler>,
+        stream_owner: &Arc<watch::Sender<Option<usize>>>,
+        probe: &Arc<HedgeAttemptProbe>,
+    ) -> RouteAttemptFuture<'_> {
+        let Some(on_event) = on_event else {
+            return self.route_attempt(request, route_index, None);
+        };
+        let stream_owner = stream_owner.clone();
+        let probe = probe.clone();
+        let gated_handler: StreamEventHandler = Arc::new(move |event| {
+            probe.mark_first_event();
+            probe.record_output(&event);
+            stream_owner.send_if_modified(|owner| {
+                if owner.is_none() {
+                    *owner = Some(route_index);
+                    true
+                } else {
+                    false
+                }
+            });
+            if *stream_owner.borrow() == Some(route_index) {
+                on_event(event);
+            }
+        });
+        self.route_attempt(request, route_index, Some(gated_handler))
+    }
+
+    /// Runs `primary_index` and, once its hedge delay passes without a first
+    /// token, a duplicate on `hedge_index`. Returns the result and whether the
+    /// hedge was sent; circuit state is recorded here except for a primary
+    /// failure that is returned to the caller.
+    async fn complete_hedged(
+        &self,
+        request: &ChatRequest,
+        on_event: Option<StreamEventHandler>,
+        primary_index: usize,
+        hedge_index: usize,
+        hedging: HedgingConfig,
+    ) -> (Result<ChatResponse, TauAiError>, bool) {
+        let (stream_owner, mut stream_owner_rx) = watch::channel(None);
+        let stream_owner = Arc::new(stream_owner);
+        let primary_probe = HedgeAttemptProbe::new(&self.routes[primary_index].model);
+        let mut primary = self.hedge_attempt(
+            request,
+            primary_index,
+            on_event.clone(),
+            &stream_owner,
+            &primary_probe,
+        );
+        let delay_ms = self.hedge_delay_ms(primary_index, hedging);
+
+        let launch_hedge = tokio::select! {
+            result = &mut primary => {
+                let latency_ms = primary_probe
+                    .first_event_ms()
+                    .unwrap_or_else(|| primary_probe.elapsed_ms());
+                self.record_first_token_latency(primary_index, latency_ms, hedging);
+                if result.is_ok() {
+                    self.record_route_success(primary_index);
+                }
+                return (result, false);
+            }
+            _ = stream_owner_rx.wait_for(Option::is_some) => false,
+            _ = tokio::time::sleep(Duration::from_millis(delay_ms)) => true,
+        };
+        if !launch_hedge {
+            if let Some(latency_ms) = primary_probe.first_event_ms() {
+                self.record_first_token_latency(primary_index, latency_ms, hedging);
+            }
+            let result = primary.await;
+            if result.is_ok() {
+                self.record_route_success(primary_index);
+            }
+            return (result, false);
+        }
+
+        self.emit_hedge_launched_event(primary_index, hedge_index, delay_ms);
+        let hedge_probe = HedgeAttemptProbe::new(&self.routes[hedge_index].model);
+        let mut hedge =
+            self.hedge_attempt(request, hedge_index, on_event, &stream_owner, &hedge_probe);
+        let race_event = tokio::select! {
+            result = &mut primary => HedgeRaceEvent::Finished(primary_index, result),
+            result = &mut hedge => HedgeRaceEvent::Finished(hedge_index, result),
+            owner = stream_owner_rx.wait_for(Option::is_some) => {
+                let owner = owner.ok().and_then(|owner| *owner).unwrap_or(primary_index);
+                HedgeRaceEvent::StreamClaimed(owner)
+            }
+        };
+        let (primary_result, hedge_result) = match race_event {
+            HedgeRaceEvent::StreamClaimed(owner) if owner == hedge_index => {
+                (None, Some((&mut hedge).await))
+            }
+            HedgeRaceEvent::StreamClaimed(_) => (Some((&mut primary).await), None),
+            HedgeRaceEvent::Finished(index, result) if index == primary_index => {
+                if result.is_ok() {
+                    (Some(result), None)
+                } else {
+                    (Some(result), Some((&mut hedge).await))
+                }
+            }
+            HedgeRaceEvent::Finished(_, result) => {
+                if result.is_ok() {
+                    (None, Some(result))
+                } else {
+                    (Some((&mut primary).await), Some(result))
+                }
+            }
+        };
+        // Dropping the unfinished attempt cancels its in-flight provider call.
+        drop(primary);
+        drop(hedge);
+
+        self.record_first_token_latency(
+            primary_index,
+            primary_probe
+                .first_event_ms()
+                .unwrap_or_else(|| primary_probe.elapsed_ms()),
+            hedging,
+        );
+        if let Some(latency_ms) = hedge_probe
+            .first_event_ms()
+            .or_else(|| matches!(hedge_result, Some(Ok(_))).then(|| hedge_probe.elapsed_ms()))
+        {
+            self.record_first_token_latency(hedge_index, latency_ms, hedging);
+        }
+
+        let now_unix_ms = (self.clock)();
+        if let Some(Err(error)) = &hedge_result {
+            if is_retryable_provider_error(error) {
+                self.note_retryable_route_failure(hedge_index, now_unix_ms);
+            }
+        }
+        let cancelled_duplicate = primary_result.is_none() || hedge_result.is_none();
+        let (winner_index, result) = match (primary_result, hedge_result) {
+            (Some(Ok(response)), _) => (Some(primary_index), Ok(response)),
+            (primary_result, Some(Ok(response))) => {
+                if let Some(Err(error)) = &primary_result {
+                    if is_retryable_provider_error(error) {
+                        self.note_retryable_route_failure(primary_index, now_unix_ms);
+                    }
+                }
+                (Some(hedge_index), Ok(response))
+            }
+            (Some(Err(error)), _) | (None, Some(Err(error))) => (None, Err(error)),
+            (None, None) => (
+                None,
+                Err(TauAiError::InvalidResponse(
+                    "hedged provider request finished without a result".to_string(),
+                )),
+            ),
+        };
+
+        let elapsed_ms = primary_probe.elapsed_ms();
+        let Some(winner_index) = winner_index else {
+            self.emit_hedge_outcome_event(primary_index, hedge_index, None, (0, 0), elapsed_ms);
+            return (result, true);
+        };
+        self.record_route_success(winner_index);
+        self.record_hedge_outcome(primary_index, hedge_index, winner_index == hedge_index);
+        let mut response = match result {
+            Ok(response) => response,
+            Err(error) => return (Err(error), true),
+        };
+        let (hedge_input_tokens, hedge_output_tokens) = if cancelled_duplicate {
+            let (cancelled_index, cancelled_probe) = if winner_index == primary_index {
+                (hedge_index, &hedge_probe)
+            } else {
+                (primary_index, &primary_probe)
+            };
+            let input_tokens = if response.usage.input_tokens > 0 {
+                response.usage.input_tokens
+            } else {
+                u64::from(ModelRoutingFeatures::from_request(request).estimated_input_tokens)
+            };
+            response.usage.hedge_model = Some(self.routes[cancelled_index].model_ref());
+            (input_tokens, cancelled_probe.streamed_output_tokens())
+        } else {
+            (0, 0)
+        };
+        response.usage.hedge_input_tokens = response
+            .usage
+            .hedge_input_tokens
+            .saturating_add(hedge_input_tokens);
+        response.usage.hedge_output_tokens = response
+            .usage
+            .hedge_output_tokens
+            .saturating_add(hedge_output_tokens);
+        self.emit_hedge_outcome_event(
+            primary_index,
+            hedge_index,
+            Some(winner_index),
+            (hedge_input_tokens, hedge_output_tokens),
+            elapsed_ms,
+        );
+        (Ok(response), true)
+    }
+
     async fn complete_inner(
         &self,
         request: ChatRequest,
-        on_delta: Option<StreamDeltaHandler>,
+        on_event: Option<StreamEventHandler>,
     ) -> Result<ChatResponse, TauAiError> {
         if self.routes.is_empty() {
             return Err(TauAiError::InvalidResponse(
@@ -214,24 +743,38 @@ impl FallbackRoutingClient {
         }
 
         let mut attempted_route = false;
+        let mut hedged_route = None;
         for (index, route) in self.routes.iter().enumerate() {
+            if hedged_route == Some(index) {
+                continue;
+            }
             let now_unix_ms = (self.clock)();
             if let Some(open_until) = self.route_open_until(index, now_unix_ms) {
+                route.record_circuit_event_metric("skipped");
                 self.emit_circuit_skip_event(route, index, open_until);
                 continue;
             }
+            let hedge_partner = if attempted_route {
+                None
+            } else {
+                self.hedge_partner(index, now_unix_ms)
+            };
             attempted_route = true;
 
-            let mut routed_request = request.clone();
-            routed_request.model = route.model.clone();
-
-            let response = if let Some(stream_handler) = on_delta.clone() {
-                route
-                    .client
-                    .complete_with_stream(routed_request, Some(stream_handler))
-                    .await
+            let response = if let Some((hedge_index, hedging)) = hedge_partner {
+                let (response, hedge_launched) = self
+                    .complete_hedged(&request, on_event.clone(), index, hedge_index, hedging)
+                    .await;
+                if hedge_launched {
+                    hedged_route = Some(hedge_index);
+                }
+                // Hedged races record route success themselves.
+                if response.is_ok() {
+                    return response;
+                }
+                response
             } else {
-                route.client.complete(routed_request).await
+                self.route_attempt(&request, index, on_event.clone()).await
             };
 
             match response {
@@ -241,13 +784,16 @@ impl FallbackRoutingClient {
                 }
                 Err(error) => {
                     if is_retryable_provider_error(&error) {
-                        if let Some(open_until) =
-                            self.record_retryable_route_failure(index, now_unix_ms)
-                        {
-                            self.emit_circuit_opened_event(route, index, open_until);
-                        }
-                        if let Some(next_route) = self.routes.get(index + 1) {
-                            self.emit_fallback_event(route, next_route, &error, index + 1);
+                        self.note_retryable_route_failure(index, now_unix_ms);
+                        let next_index = (index + 1..self.routes.len())
+                            .find(|next_index| Some(*next_index) != hedged_route);
+                        if let Some(next_index) = next_index {
+                            self.emit_fallback_event(
+                                route,
+                                &self.routes[next_index],
+                                &error,
+                                next_index,
+                            );
                             continue;
                         }
                     }
@@ -280,7 +826,16 @@ impl LlmClient for FallbackRoutingClient {
         request: ChatRequest,
         on_delta: Option<StreamDeltaHandler>,
     ) -> Result<ChatResponse, TauAiError> {
-        self.complete_inner(request, on_delta).await
+        self.complete_inner(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, on_event).await
     }
 }
 
@@ -403,7 +958,15 @@ pub fn build_client_with_fallbacks(
     } else {
         None
     };
-    Ok(Arc::new(FallbackRoutingClient::new(routes, event_sink)))
+    let client = FallbackRoutingClient::new(routes, event_sink);
+    if !cli.provider_hedging {
+        return Ok(Arc::new(client));
+    }
+    Ok(Arc::new(client.with_hedging(HedgingConfig {
+        percentile: cli.provider_hedge_percentile.clamp(0.0, 100.0),
+        initial_delay_ms: cli.provider_hedge_initial_delay_ms,
+        ..HedgingConfig::default()
+    })))
 }
 
 #[cfg(test)]
@@ -518,6 +1081,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         }
     }
 
@@ -777,6 +1342,65 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn functional_typed_stream_events_are_forwarded_through_fallback_route() {
+        let primary = MockLlmClient::new(
+            Vec::new(),
+            vec![MockStreamResponse {
+                deltas: Vec::new(),
+                result: Err(TauAiError::HttpStatus {
+                    status: 503,
+                    body: "service unavailable".to_string(),
+                }),
+            }],
+        );
+        let secondary = MockLlmClient::new(
+            Vec::new(),
+            vec![MockStreamResponse {
+                deltas: vec!["Hi".to_string()],
+                result: Ok(assistant_text_response("Hi")),
+            }],
+        );
+        let router = FallbackRoutingClient::new(
+            vec![
+                ClientRoute {
+                    provider: Provider::OpenAi,
+                    model: "gpt-5.2".to_string(),
+                    client: Arc::new(primary),
+                },
+                ClientRoute {
+                    provider: Provider::Google,
+                    model: "gemini-2.5-pro".to_string(),
+                    client: Arc::new(secondary),
+                },
+            ],
+            None,
+        );
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let event_sink = events.clone();
+        let sink: StreamEventHandler = Arc::new(move |event| {
+            event_sink.lock().expect("event lock").push(event);
+        });
+
+        router
+            .complete_with_events(test_request(), Some(sink))
+            .await
+            .expect("streaming fallback should succeed");
+
+        let events = events.lock().expect("event lock");
+        assert_eq!(
+            events.first(),
+            Some(&tau_ai::StreamEvent::TextDelta {
+                text: "Hi".to_string(),
+            })
+        );
+        assert!(matches!(
+            events.last(),
+            Some(tau_ai::StreamEvent::Stop { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn functional_circuit_breaker_opens_and_skips_temporarily_unhealthy_route() {
         let primary = MockLlmClient::new(
@@ -864,6 +1488,32 @@ mod tests {
                 .any(|event| event["type"] == "provider_circuit_skip"),
             "open circuit should skip primary route"
         );
+        let registry = global_metrics_registry();
+        let circuit_labels = |event| {
+            [
+                ("provider", "openai"),
+                ("model", "gpt-5.2"),
+                ("event", event),
+            ]
+        };
+        assert!(
+            registry
+                .value("tau_provider_circuit_events", &circuit_labels("opened"))
+                .unwrap_or_default()
+                >= 1.0
+        );
+        assert!(
+            registry
+                .value("tau_provider_circuit_events", &circuit_labels("skipped"))
+                .unwrap_or_default()
+                >= 1.0
+        );
+        assert!(registry
+            .value(
+                "tau_provider_circuit_open",
+                &[("provider", "openai"), ("model", "gpt-5.2")]
+            )
+            .is_some());
     }
 
     #[tokio::test]
@@ -1120,4 +1770,313 @@ mod tests {
         assert_eq!(primary.observed_models().len(), 1);
         assert_eq!(secondary.observed_models().len(), 1);
     }
+
+    struct DelayedLlmClient {
+        delay: Duration,
+        text: &'static str,
+        error_status: Option<u16>,
+        input_tokens: u64,
+        calls: AtomicU64,
+        completed: AtomicU64,
+    }
+
+    impl DelayedLlmClient {
+        fn new(
+            delay_ms: u64,
+            text: &'static str,
+            error_status: Option<u16>,
+            input_tokens: u64,
+        ) -> Arc<Self> {
+            Arc::new(Self {
+                delay: Duration::from_millis(delay_ms),
+                text,
+                error_status,
+                input_tokens,
+                calls: AtomicU64::new(0),
+                completed: AtomicU64::new(0),
+            })
+        }
+
+        fn ok(delay_ms: u64, text: &'static str, input_tokens: u64) -> Arc<Self> {
+            Self::new(delay_ms, text, None, input_tokens)
+        }
+
+        fn failing(delay_ms: u64, status: u16) -> Arc<Self> {
+            Self::new(delay_ms, "", Some(status), 0)
+        }
+
+        fn calls(&self) -> u64 {
+            self.calls.load(Ordering::SeqCst)
+        }
+
+        fn completed(&self) -> u64 {
+            self.completed.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for DelayedLlmClient {
+        async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            self.complete_with_events(request, None).await
+        }
+
+        async fn complete_with_events(
+            &self,
+            _request: ChatRequest,
+            on_event: Option<StreamEventHandler>,
+        ) -> Result<ChatResponse, TauAiError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.completed.fetch_add(1, Ordering::SeqCst);
+            if let Some(status) = self.error_status {
+                return Err(TauAiError::HttpStatus {
+                    status,
+                    body: "unavailable".to_string(),
+                });
+            }
+            if let Some(handler) = on_event {
+                handler(tau_ai::StreamEvent::TextDelta {
+                    text: self.text.to_string(),
+                });
+            }
+            let mut response = assistant_text_response(self.text);
+            response.usage.input_tokens = self.input_tokens;
+            response.usage.total_tokens = self.input_tokens;
+            Ok(response)
+        }
+    }
+
+    /// Streams `text` from a background reader task after `delay`, so the delta
+    /// still arrives once the request future has lost the race.
+    struct BackgroundStreamLlmClient {
+        delay: Duration,
+        complete_after: Duration,
+        text: &'static str,
+    }
+
+    impl BackgroundStreamLlmClient {
+        fn new(delay_ms: u64, complete_after_ms: u64, text: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                delay: Duration::from_millis(delay_ms),
+                complete_after: Duration::from_millis(complete_after_ms),
+                text,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for BackgroundStreamLlmClient {
+        async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            self.complete_with_events(request, None).await
+        }
+
+        async fn complete_with_events(
+            &self,
+            _request: ChatRequest,
+            on_event: Option<StreamEventHandler>,
+        ) -> Result<ChatResponse, TauAiError> {
+            if let Some(handler) = on_event {
+                let (delay, text) = (self.delay, self.text);
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    handler(tau_ai::StreamEvent::TextDelta {
+                        text: text.to_string(),
+                    });
+                });
+            }
+            tokio::time::sleep(self.complete_after).await;
+            Ok(assistant_text_response(self.text))
+        }
+    }
+
+    fn delayed_routes(clients: &[&Arc<DelayedLlmClient>]) -> Vec<ClientRoute> {
+        clients
+            .iter()
+            .enumerate()
+            .map(|(index, client)| ClientRoute {
+                provider: Provider::OpenAi,
+                model: format!("route-{index}"),
+                client: (*client).clone(),
+            })
+            .collect()
+    }
+
+    fn test_hedging(initial_delay_ms: u64) -> HedgingConfig {
+        HedgingConfig {
+            initial_delay_ms,
+            min_delay_ms: 0,
+            ..HedgingConfig::default()
+        }
+    }
+
+    #[test]
+    fn unit_hedge_delay_tracks_latency_percentile_and_backs_off_when_hedges_lose() {
+        let primary = DelayedLlmClient::ok(0, "primary", 0);
+        let secondary = DelayedLlmClient::ok(0, "secondary", 0);
+        let hedging = test_hedging(1_500);
+        let router = FallbackRoutingClient::new(delayed_routes(&[&primary, &secondary]), None)
+            .with_hedging(hedging);
+
+        assert_eq!(router.hedge_delay_ms(0, hedging), 1_500);
+        for latency_ms in 1..=100 {
+            router.record_first_token_latency(0, latency_ms, hedging);
+        }
+        assert_eq!(router.hedge_delay_ms(0, hedging), 95);
+
+        {
+            let mut state = lock_or_recover_mutex(&router.route_hedge_state);
+            state[0].hedges_launched = 20;
+            state[0].hedge_wins = 1;
+        }
+        assert_eq!(router.hedge_delay_ms(0, hedging), 98);
+
+        for latency_ms in 0..hedging.max_samples as u64 {
+            router.record_first_token_latency(0, 500 + latency_ms, hedging);
+        }
+        let stats = router.hedge_stats();
+        assert_eq!(stats[0].latency_samples, hedging.max_samples);
+        assert!(stats[0].hedge_delay_ms >= 500);
+        assert_eq!(stats[1].latency_samples, 0);
+    }
+
+    #[tokio::test]
+    async fn functional_hedged_request_uses_faster_route_and_accounts_cancelled_duplicate() {
+        let primary = DelayedLlmClient::ok(2_000, "slow", 120);
+        let secondary = DelayedLlmClient::ok(10, "fast", 80);
+        let events = Arc::new(Mutex::new(Vec::<Value>::new()));
+        let sink_events = events.clone();
+        let router = FallbackRoutingClient::new(
+            delayed_routes(&[&primary, &secondary]),
+            Some(Arc::new(move |event| {
+                sink_events.lock().expect("events lock").push(event);
+            })),
+        )
+        .with_hedging(test_hedging(20));
+
+        let started = Instant::now();
+        let response = router.complete(test_request()).await.expect("hedged");
+
+        assert!(started.elapsed() < Duration::from_millis(1_000));
+        assert_eq!(response.message.text_content(), "fast");
+        assert_eq!(response.usage.input_tokens, 80);
+        assert_eq!(response.usage.hedge_input_tokens, 80);
+        assert_eq!(response.usage.hedge_output_tokens, 0);
+        assert_eq!(
+            response.usage.hedge_model.as_deref(),
+            Some("openai/route-0")
+        );
+        assert_eq!((primary.calls(), primary.completed()), (1, 0));
+        assert_eq!(secondary.completed(), 1);
+
+        let events = events.lock().expect("events lock");
+        assert_eq!(events[0]["type"], "provider_hedge_launched");
+        assert_eq!(events[0]["hedge_model"], "openai/route-1");
+        assert_eq!(events[1]["type"], "provider_hedge_outcome");
+        assert_eq!(events[1]["outcome"], "hedge_won");
+        assert_eq!(events[1]["hedge_input_tokens"], 80);
+
+        let stats = router.hedge_stats();
+        assert_eq!(stats[0].hedges_launched, 1);
+        assert_eq!(stats[0].hedge_wins, 1);
+        assert_eq!(stats[0].latency_samples, 1);
+        assert_eq!(stats[1].hedged_for_others, 1);
+        assert_eq!(stats[1].latency_samples, 1);
+    }
+
+    #[tokio::test]
+    async fn functional_streaming_hedge_forwards_only_the_winning_stream() {
+        let primary = DelayedLlmClient::ok(2_000, "slow", 10);
+        let secondary = DelayedLlmClient::ok(10, "fast", 10);
+        let router = FallbackRoutingClient::new(delayed_routes(&[&primary, &secondary]), None)
+            .with_hedging(test_hedging(20));
+
+        let deltas = Arc::new(Mutex::new(Vec::new()));
+        let sink_deltas = deltas.clone();
+        let sink: StreamEventHandler = Arc::new(move |event| {
+            if let tau_ai::StreamEvent::TextDelta { text } = event {
+                sink_deltas.lock().expect("delta lock").push(text);
+            }
+        });
+        let response = router
+            .complete_with_events(test_request(), Some(sink))
+            .await
+            .expect("hedged stream");
+
+        assert_eq!(response.message.text_content(), "fast");
+        assert_eq!(
+            *deltas.lock().expect("delta lock"),
+            vec!["fast".to_string()]
+        );
+        assert_eq!(primary.completed(), 0);
+    }
+
+    #[tokio::test]
+    async fn functional_streaming_hedge_prices_output_streamed_by_cancelled_route() {
+        let primary = BackgroundStreamLlmClient::new(80, 2_000, "slow partial answer");
+        let secondary = BackgroundStreamLlmClient::new(10, 150, "fast");
+        let routes = [primary, secondary]
+            .into_iter()
+            .enumerate()
+            .map(|(index, client)| ClientRoute {
+                provider: Provider::OpenAi,
+                model: format!("route-{index}"),
+                client,
+            })
+            .collect();
+        let router = FallbackRoutingClient::new(routes, None).with_hedging(test_hedging(20));
+
+        let sink: StreamEventHandler = Arc::new(|_event| {});
+        let response = router
+            .complete_with_events(test_request(), Some(sink))
+            .await
+            .expect("hedged stream");
+
+        assert_eq!(response.message.text_content(), "fast");
+        assert_eq!(
+            response.usage.hedge_model.as_deref(),
+            Some("openai/route-0")
+        );
+        assert_eq!(
+            response.usage.hedge_output_tokens,
+            u64::from(
+                TokenizerFamily::for_model("route-0").count_text_tokens("slow partial answer")
+            )
+        );
+        assert!(response.usage.hedge_output_tokens > 0);
+    }
+
+    #[tokio::test]
+    async fn regression_fast_primary_does_not_send_hedge() {
+        let primary = DelayedLlmClient::ok(0, "primary", 50);
+        let secondary = DelayedLlmClient::ok(0, "secondary", 50);
+        let router = FallbackRoutingClient::new(delayed_routes(&[&primary, &secondary]), None)
+            .with_hedging(test_hedging(500));
+
+        let response = router.complete(test_request()).await.expect("primary");
+
+        assert_eq!(response.message.text_content(), "primary");
+        assert_eq!(response.usage.hedge_input_tokens, 0);
+        assert_eq!(response.usage.hedge_model, None);
+        assert_eq!(secondary.calls(), 0);
+        assert_eq!(router.hedge_stats()[0].hedges_launched, 0);
+        assert_eq!(router.hedge_stats()[0].latency_samples, 1);
+    }
+
+    #[tokio::test]
+    async fn integration_failed_hedged_race_falls_through_to_remaining_routes() {
+        let primary = DelayedLlmClient::failing(100, 503);
+        let secondary = DelayedLlmClient::failing(5, 503);
+        let tertiary = DelayedLlmClient::ok(0, "tertiary", 30);
+        let router =
+            FallbackRoutingClient::new(delayed_routes(&[&primary, &secondary, &tertiary]), None)
+                .with_hedging(test_hedging(20));
+
+        let response = router.complete(test_request()).await.expect("fallback");
+
+        assert_eq!(response.message.text_content(), "tertiary");
+        assert_eq!(response.usage.hedge_input_tokens, 0);
+        assert_eq!(primary.calls(), 1);
+        assert_eq!(secondary.calls(), 1);
+        assert_eq!(tertiary.calls(), 1);
+    }
 }
@@ -358,6 +358,8 @@ fn render_gemini_prompt(request: &ChatRequest) -> String {
                 ContentBlock::Audio { source } => {
                     lines.push(format!("[tau-audio:{}]", media_source_descriptor(source)))
                 }
+                // Provider reasoning is not meaningful to a separate CLI session.
+                ContentBlock::Thinking { .. } => {}
             }
         }
     }
@@ -431,6 +433,8 @@ mod tests {
             max_tokens: None,
             temperature: None,
             prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
         }
     }
 
@@ -0,0 +1,635 @@
+//! Multi-key credential pooling with per-key rate-limit tracking.
+//!
+//! `ProviderKeyPoolClient` holds one provider client per API key and sends each
+//! request to the least-loaded healthy key, ranked by in-flight requests and the
+//! remaining request/token quota reported in rate-limit response headers. Keys
+//! answering 401/403 are quarantined until restart, keys answering 429 until
+//! their `Retry-After` (or a default cooldown) elapses; the request then moves
+//! on to the next healthy key.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tau_ai::{
+    register_provider_key_pool, stream_event_handler_from_deltas, with_rate_limit_observer,
+    ChatRequest, ChatResponse, LlmClient, Provider, ProviderKeyHealth, ProviderKeyPoolHealth,
+    ProviderKeyPoolHealthSource, RateLimitObserver, RateLimitSnapshot, StreamDeltaHandler,
+    StreamEventHandler, TauAiError,
+};
+use tau_cli::Cli;
+use tau_core::current_unix_timestamp_ms;
+
+use crate::credential_store::{load_credential_store, resolve_credential_store_encryption_mode};
+use crate::credentials::ProviderAuthCredential;
+use crate::types::ProviderAuthMethod;
+
+type ClockFn = Arc<dyn Fn() -> u64 + Send + Sync>;
+
+/// Label used for the key resolved through the regular provider auth flags.
+pub const PRIMARY_POOL_KEY_LABEL: &str = "primary";
+const DEFAULT_RATE_LIMIT_QUARANTINE_MS: u64 = 60_000;
+const FULL_HEADROOM_PERMILLE: u64 = 1_000;
+
+#[derive(Clone)]
+/// One API key's client inside a provider key pool.
+pub struct PooledProviderKey {
+    pub label: String,
+    pub client: Arc<dyn LlmClient>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KeyQuarantine {
+    reason: &'static str,
+    until_unix_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PooledKeyState {
+    in_flight: u64,
+    requests_total: u64,
+    failures_total: u64,
+    last_status: Option<u16>,
+    last_retry_after_ms: Option<u64>,
+    limit_requests: Option<u64>,
+    remaining_requests: Option<u64>,
+    limit_tokens: Option<u64>,
+    remaining_tokens: Option<u64>,
+    quarantine: Option<KeyQuarantine>,
+}
+
+impl PooledKeyState {
+    fn is_quarantined(&mut self, now_unix_ms: u64) -> bool {
+        match self.quarantine {
+            Some(KeyQuarantine {
+                until_unix_ms: Some(until),
+                ..
+            }) if now_unix_ms >= until => {
+                self.quarantine = None;
+                false
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    fn headroom_permille(&self) -> u64 {
+        [
+            (self.remaining_requests, self.limit_requests),
+            (self.remaining_tokens, self.limit_tokens),
+        ]
+        .into_iter()
+        .map(|(remaining, limit)| match (remaining, limit) {
+            (Some(remaining), Some(limit)) if limit > 0 => {
+                remaining.min(limit).saturating_mul(FULL_HEADROOM_PERMILLE) / limit
+            }
+            (Some(0), _) => 0,
+            _ => FULL_HEADROOM_PERMILLE,
+        })
+        .min()
+        .unwrap_or(FULL_HEADROOM_PERMILLE)
+    }
+}
+
+struct ProviderKeyPoolState {
+    provider: Provider,
+    labels: Vec<String>,
+    keys: Mutex<Vec<PooledKeyState>>,
+    clock: ClockFn,
+}
+
+impl ProviderKeyPoolState {
+    fn acquire(&self, attempted: &[usize]) -> Option<usize> {
+        let now_unix_ms = (self.clock)();
+        let mut keys = lock_or_recover_mutex(&self.keys);
+        let index = keys
+            .iter_mut()
+            .enumerate()
+            .filter(|(index, _)| !attempted.contains(index))
+            .filter_map(|(index, key)| (!key.is_quarantined(now_unix_ms)).then_some((index, key)))
+            .min_by_key(|(index, key)| {
+                (
+                    key.in_flight,
+                    FULL_HEADROOM_PERMILLE - key.headroom_permille(),
+                    key.requests_total,
+                    *index,
+                )
+            })
+            .map(|(index, _)| index)?;
+        let key = &mut keys[index];
+        key.in_flight = key.in_flight.saturating_add(1);
+        key.requests_total = key.requests_total.saturating_add(1);
+        Some(index)
+    }
+
+    fn record_snapshot(&self, index: usize, snapshot: RateLimitSnapshot) {
+        let mut keys = lock_or_recover_mutex(&self.keys);
+        let Some(key) = keys.get_mut(index) else {
+            return;
+        };
+        key.last_status = Some(snapshot.status);
+        key.last_retry_after_ms = snapshot.retry_after_ms;
+        key.limit_requests = snapshot.limit_requests.or(key.limit_requests);
+        key.remaining_requests = snapshot.remaining_requests.or(key.remaining_requests);
+        key.limit_tokens = snapshot.limit_tokens.or(key.limit_tokens);
+        key.remaining_tokens = snapshot.remaining_tokens.or(key.remaining_tokens);
+    }
+
+    /// Releases a key after a call and returns true when the key was quarantined.
+    fn release(&self, index: usize, result: &Result<ChatResponse, TauAiError>) -> bool {
+        let now_unix_ms = (self.clock)();
+        let mut keys = lock_or_recover_mutex(&self.keys);
+        let Some(key) = keys.get_mut(index) else {
+            return false;
+        };
+        key.in_flight = key.in_flight.saturating_sub(1);
+        let Err(error) = result else {
+            return false;
+        };
+        key.failures_total = key.failures_total.saturating_add(1);
+        let TauAiError::HttpStatus { status, .. } = error else {
+            return false;
+        };
+        key.last_status = Some(*status);
+        key.quarantine = match *status {
+            401 | 403 => Some(KeyQuarantine {
+                reason: "auth_failure",
+                until_unix_ms: None,
+            }),
+            429 => Some(KeyQuarantine {
+                reason: "rate_limited",
+                until_unix_ms: Some(
+                    now_unix_ms.saturating_add(
+                        key.last_retry_after_ms
+                            .unwrap_or(DEFAULT_RATE_LIMIT_QUARANTINE_MS),
+                    ),
+                ),
+            }),
+            _ => return false,
+        };
+        tracing::warn!(
+            provider = self.provider.as_str(),
+            key_label = self.labels[index].as_str(),
+            status = *status,
+            "provider key quarantined"
+        );
+        true
+    }
+}
+
+impl ProviderKeyPoolHealthSource for ProviderKeyPoolState {
+    fn key_pool_health(&self) -> ProviderKeyPoolHealth {
+        let now_unix_ms = (self.clock)();
+        let mut keys = lock_or_recover_mutex(&self.keys);
+        let keys = keys
+            .iter_mut()
+            .zip(&self.labels)
+            .map(|(key, label)| {
+                let quarantined = key.is_quarantined(now_unix_ms);
+                ProviderKeyHealth {
+                    label: label.clone(),
+                    state: if quarantined {
+                        "quarantined"
+                    } else {
+                        "healthy"
+                    }
+                    .to_string(),
+                    quarantine_reason: key.quarantine.map(|value| value.reason.to_string()),
+                    quarantined_until_unix_ms: key.quarantine.and_then(|value| value.until_unix_ms),
+                    in_flight: key.in_flight,
+                    requests_total: key.requests_total,
+                    failures_total: key.failures_total,
+                    last_status: key.last_status,
+                    limit_requests: key.limit_requests,
+                    remaining_requests: key.remaining_requests,
+                    limit_tokens: key.limit_tokens,
+                    remaining_tokens: key.remaining_tokens,
+                }
+            })
+            .collect::<Vec<_>>();
+        let healthy_keys = keys.iter().filter(|key| key.state == "healthy").count();
+        ProviderKeyPoolHealth {
+            provider: self.provider.as_str().to_string(),
+            keys_total: keys.len(),
+            healthy_keys,
+            quarantined_keys: keys.len() - healthy_keys,
+            keys,
+        }
+    }
+}
+
+/// `LlmClient` that spreads requests across a provider's pooled API keys.
+pub struct ProviderKeyPoolClient {
+    clients: Vec<Arc<dyn LlmClient>>,
+    state: Arc<ProviderKeyPoolState>,
+}
+
+impl ProviderKeyPoolClient {
+    /// Builds a pool and registers its health for status surfaces.
+    pub fn new(provider: Provider, keys: Vec<PooledProviderKey>) -> Self {
+        Self::new_with_clock(provider, keys, Arc::new(current_unix_timestamp_ms))
+    }
+
+    fn new_with_clock(provider: Provider, keys: Vec<PooledProviderKey>, clock: ClockFn) -> Self {
+        let (labels, clients): (Vec<_>, Vec<_>) =
+            keys.into_iter().map(|key| (key.label, key.client)).unzip();
+        let state = Arc::new(ProviderKeyPoolState {
+            provider,
+            keys: Mutex::new(vec![PooledKeyState::default(); labels.len()]),
+            labels,
+            clock,
+        });
+        let health_source: Arc<dyn ProviderKeyPoolHealthSource> = state.clone();
+        register_provider_key_pool(Arc::downgrade(&health_source));
+        Self { clients, state }
+    }
+
+    /// Returns the pool's current per-key health.
+    pub fn health(&self) -> ProviderKeyPoolHealth {
+        self.state.key_pool_health()
+    }
+
+    async fn complete_inner(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        let mut attempted = Vec::new();
+        let mut last_error = None;
+        while let Some(index) = self.state.acquire(&attempted) {
+            attempted.push(index);
+            let observer_state = self.state.clone();
+            let observer: RateLimitObserver = Arc::new(move |snapshot| {
+                observer_state.record_snapshot(index, snapshot);
+            });
+            let client = &self.clients[index];
+            let result = with_rate_limit_observer(observer, async {
+                if let Some(event_handler) = on_event.clone() {
+                    client
+                        .complete_with_events(request.clone(), Some(event_handler))
+                        .await
+                } else {
+                    client.complete(request.clone()).await
+                }
+            })
+            .await;
+            let quarantined = self.state.release(index, &result);
+            match result {
+                Ok(response) => return Ok(response),
+                Err(error) if quarantined => last_error = Some(error),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| TauAiError::HttpStatus {
+            status: 429,
+            body: format!(
+                "all {} pooled keys for provider '{}' are quarantined",
+                self.clients.len(),
+                self.state.provider.as_str()
+            ),
+        }))
+    }
+}
+
+#[async_trait]
+impl LlmClient for ProviderKeyPoolClient {
+    async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, None).await
+    }
+
+    async fn complete_with_stream(
+        &self,
+        request: ChatRequest,
+        on_delta: Option<StreamDeltaHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, on_event).await
+    }
+}
+
+fn lock_or_recover_mutex<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Returns the non-revoked `(label, api_key)` pairs pooled for `provider`.
+pub fn load_provider_key_pool(cli: &Cli, provider: Provider) -> Vec<(String, String)> {
+    if !cli.credential_store.exists() {
+        return Vec::new();
+    }
+    let store = match load_credential_store(
+        &cli.credential_store,
+        resolve_credential_store_encryption_mode(cli),
+        cli.credential_store_key.as_deref(),
+    ) {
+        Ok(store) => store,
+        Err(error) => {
+            tracing::warn!(
+                provider = provider.as_str(),
+                error = %error,
+                "provider key pool unavailable; credential store failed to load"
+            );
+            return Vec::new();
+        }
+    };
+    store
+        .provider_key_pools
+        .get(provider.as_str())
+        .into_iter()
+        .flatten()
+        .filter(|record| !record.revoked)
+        .filter_map(|record| {
+            let api_key = record.api_key.as_deref()?.trim();
+            (!api_key.is_empty()).then(|| (record.label.clone(), api_key.to_string()))
+        })
+        .collect()
+}
+
+/// Builds the client for an API-key credential, pooling store keys when configured.
+pub(crate) fn build_key_pooled_client<F>(
+    cli: &Cli,
+    provider: Provider,
+    resolved: &ProviderAuthCredential,
+    primary_api_key: String,
+    build_key_client: F,
+) -> Result<Arc<dyn LlmClient>>
+where
+    F: Fn(String) -> Result<Arc<dyn LlmClient>>,
+{
+    if resolved.method != ProviderAuthMethod::ApiKey {
+        return build_key_client(primary_api_key);
+    }
+    let pooled = load_provider_key_pool(cli, provider)
+        .into_iter()
+        .filter(|(_, api_key)| *api_key != primary_api_key)
+        .collect::<Vec<_>>();
+    if pooled.is_empty() {
+        return build_key_client(primary_api_key);
+    }
+
+    let mut keys = vec![PooledProviderKey {
+        label: PRIMARY_POOL_KEY_LABEL.to_string(),
+        client: build_key_client(primary_api_key)?,
+    }];
+    for (label, api_key) in pooled {
+        if keys.iter().any(|key| key.label == label) {
+            continue;
+        }
+        keys.push(PooledProviderKey {
+            label,
+            client: build_key_client(api_key)?,
+        });
+    }
+    tracing::debug!(
+        provider = provider.as_str(),
+        keys = keys.len(),
+        "provider key pool enabled"
+    );
+    Ok(Arc::new(ProviderKeyPoolClient::new(provider, keys)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use tau_ai::{observe_rate_limit_headers, Message};
+
+    type ScriptedResponse = (u16, Vec<(&'static str, &'static str)>);
+
+    struct ScriptedKeyClient {
+        label: &'static str,
+        responses: Mutex<VecDeque<ScriptedResponse>>,
+        observed: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl ScriptedKeyClient {
+        fn pooled(
+            label: &'static str,
+            responses: Vec<ScriptedResponse>,
+            observed: &Arc<Mutex<Vec<&'static str>>>,
+        ) -> PooledProviderKey {
+            PooledProviderKey {
+                label: label.to_string(),
+                client: Arc::new(Self {
+                    label,
+                    responses: Mutex::new(responses.into()),
+                    observed: observed.clone(),
+                }),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for ScriptedKeyClient {
+        async fn complete(&self, _request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            self.observed
+                .lock()
+                .expect("observed lock")
+                .push(self.label);
+            let (status, headers) = self
+                .responses
+                .lock()
+                .expect("responses lock")
+                .pop_front()
+                .unwrap_or((200, Vec::new()));
+            let mut header_map = HeaderMap::new();
+            for (name, value) in headers {
+                header_map.insert(name, HeaderValue::from_static(value));
+            }
+            observe_rate_limit_headers(status, &header_map);
+            if status == 200 {
+                return Ok(ChatResponse {
+                    message: Message::assistant_text(self.label),
+                    finish_reason: Some("stop".to_string()),
+                    usage: Default::default(),
+                });
+            }
+            Err(TauAiError::HttpStatus {
+                status,
+                body: format!("{} failed", self.label),
+            })
+        }
+    }
+
+    fn test_request() -> ChatRequest {
+        ChatRequest {
+            model: "gpt-test".to_string(),
+            messages: vec![Message::user("hello")],
+            tools: Vec::new(),
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        }
+    }
+
+    fn fixed_clock(now: &Arc<AtomicU64>) -> ClockFn {
+        let now = now.clone();
+        Arc::new(move || now.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn unit_headroom_prefers_keys_with_more_remaining_quota() {
+        let mut state = PooledKeyState::default();
+        assert_eq!(state.headroom_permille(), FULL_HEADROOM_PERMILLE);
+        state.limit_requests = Some(100);
+        state.remaining_requests = Some(25);
+        assert_eq!(state.headroom_permille(), 250);
+        state.limit_tokens = Some(1_000);
+        state.remaining_tokens = Some(100);
+        assert_eq!(state.headroom_permille(), 100);
+        state.limit_tokens = None;
+        state.remaining_tokens = Some(0);
+        assert_eq!(state.headroom_permille(), 0);
+    }
+
+    #[tokio::test]
+    async fn functional_pool_routes_to_key_with_most_remaining_quota() {
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let now = Arc::new(AtomicU64::new(1_000));
+        let pool = ProviderKeyPoolClient::new_with_clock(
+            Provider::OpenAi,
+            vec![
+                ScriptedKeyClient::pooled(
+                    "team-a",
+                    vec![(
+                        200,
+                        vec![
+                            ("x-ratelimit-limit-requests", "100"),
+                            ("x-ratelimit-remaining-requests", "5"),
+                        ],
+                    )],
+                    &observed,
+                ),
+                ScriptedKeyClient::pooled(
+                    "team-b",
+                    vec![(
+                        200,
+                        vec![
+                            ("x-ratelimit-limit-requests", "100"),
+                            ("x-ratelimit-remaining-requests", "90"),
+                        ],
+                    )],
+                    &observed,
+                ),
+            ],
+            fixed_clock(&now),
+        );
+
+        for _ in 0..4 {
+            pool.complete(test_request())
+                .await
+                .expect("pooled completion");
+        }
+
+        assert_eq!(
+            *observed.lock().expect("observed lock"),
+            vec!["team-a", "team-b", "team-b", "team-b"]
+        );
+        let health = pool.health();
+        assert_eq!(health.keys_total, 2);
+        assert_eq!(health.healthy_keys, 2);
+        assert_eq!(health.keys[0].remaining_requests, Some(5));
+        assert_eq!(health.keys[0].requests_total, 1);
+        assert_eq!(health.keys[1].remaining_requests, Some(90));
+        assert_eq!(health.keys[1].requests_total, 3);
+        assert_eq!(health.keys[1].last_status, Some(200));
+    }
+
+    #[tokio::test]
+    async fn integration_pool_quarantines_rejected_keys_and_fails_over() {
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let now = Arc::new(AtomicU64::new(10_000));
+        let pool = ProviderKeyPoolClient::new_with_clock(
+            Provider::Anthropic,
+            vec![
+                ScriptedKeyClient::pooled("revoked", vec![(401, Vec::new())], &observed),
+                ScriptedKeyClient::pooled(
+                    "throttled",
+                    vec![(429, vec![("retry-after", "30")])],
+                    &observed,
+                ),
+                ScriptedKeyClient::pooled("spare", Vec::new(), &observed),
+            ],
+            fixed_clock(&now),
+        );
+
+        let response = pool.complete(test_request()).await.expect("failover");
+        assert_eq!(response.message.text_content(), "spare");
+        assert_eq!(
+            *observed.lock().expect("observed lock"),
+            vec!["revoked", "throttled", "spare"]
+        );
+
+        let health = pool.health();
+        assert_eq!(health.provider, "anthropic");
+        assert_eq!(health.healthy_keys, 1);
+        assert_eq!(health.quarantined_keys, 2);
+        assert_eq!(
+            health.keys[0].quarantine_reason.as_deref(),
+            Some("auth_failure")
+        );
+        assert_eq!(health.keys[0].quarantined_until_unix_ms, None);
+        assert_eq!(
+            health.keys[1].quarantine_reason.as_deref(),
+            Some("rate_limited")
+        );
+        assert_eq!(health.keys[1].quarantined_until_unix_ms, Some(40_000));
+        assert!(tau_ai::provider_key_pool_health_snapshot()
+            .iter()
+            .any(|entry| entry.provider == "anthropic" && entry.keys_total == 3));
+
+        now.store(40_000, Ordering::Relaxed);
+        let health = pool.health();
+        assert_eq!(health.keys[0].state, "quarantined");
+        assert_eq!(health.keys[1].state, "healthy");
+        assert_eq!(health.keys[1].quarantine_reason, None);
+    }
+
+    #[tokio::test]
+    async fn regression_pool_returns_retryable_error_when_all_keys_are_quarantined() {
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let now = Arc::new(AtomicU64::new(0));
+        let pool = ProviderKeyPoolClient::new_with_clock(
+            Provider::OpenAi,
+            vec![ScriptedKeyClient::pooled(
+                "only",
+                vec![(429, Vec::new())],
+                &observed,
+            )],
+            fixed_clock(&now),
+        );
+
+        let first = pool.complete(test_request()).await.expect_err("429");
+        assert!(matches!(first, TauAiError::HttpStatus { status: 429, .. }));
+        let second = pool
+            .complete(test_request())
+            .await
+            .expect_err("quarantined");
+        match second {
+            TauAiError::HttpStatus { status, body } => {
+                assert_eq!(status, 429);
+                assert!(body.contains("all 1 pooled keys for provider 'openai' are quarantined"));
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert_eq!(observed.lock().expect("observed lock").len(), 1);
+    }
+}
@@ -1,7 +1,8 @@
 //! Provider auth, credential, and fallback routing infrastructure for Tau.
 //!
 //! Includes provider client construction, credential-store operations,
-//! integration auth commands, and model fallback/circuit-breaker logic.
+//! integration auth commands, model fallback/circuit-breaker logic, and
+//! cost-aware model routing.
 
 mod auth;
 mod auth_commands_runtime;
@@ -16,7 +17,10 @@ mod credentials;
 mod fallback;
 mod gemini_cli_client;
 mod integration_auth;
+mod key_pool;
+mod local_model_discovery;
 mod model_catalog;
+mod model_router;
 mod types;
 
 pub use auth::*;
@@ -31,5 +35,8 @@ pub use credentials::*;
 pub use fallback::*;
 pub use gemini_cli_client::*;
 pub use integration_auth::*;
+pub use key_pool::*;
+pub use local_model_discovery::*;
 pub use model_catalog::*;
+pub use model_router::*;
 pub use types::*;
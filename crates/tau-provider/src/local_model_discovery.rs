@@ -0,0 +1,382 @@
+//! Model discovery for local inference servers (Ollama, llama.cpp, vLLM).
+//!
+//! Discovery probes Ollama's tag listing first and falls back to the
+//! OpenAI-compatible `/models` listing served by llama.cpp and vLLM. Results are
+//! mapped into `local` model-catalog entries carrying context-window metadata so
+//! they can be merged into the startup catalog.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tau_ai::Provider;
+
+use crate::model_catalog::{ModelCatalogEntry, ModelCatalogFile, MODEL_CATALOG_SCHEMA_VERSION};
+
+#[derive(Debug, Deserialize)]
+struct OllamaTagsResponse {
+    #[serde(default)]
+    models: Vec<OllamaTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTag {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OllamaShowResponse {
+    #[serde(default)]
+    model_info: serde_json::Map<String, Value>,
+    capabilities: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiCompatibleModelsResponse {
+    #[serde(default)]
+    data: Vec<OpenAiCompatibleModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiCompatibleModel {
+    id: String,
+    /// vLLM reports the served context length as `max_model_len`.
+    max_model_len: Option<u32>,
+    /// llama.cpp server reports training context under `meta.n_ctx_train`.
+    meta: Option<LlamaCppModelMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlamaCppModelMeta {
+    n_ctx_train: Option<u32>,
+}
+
+/// Discovers models served by the local inference server at `api_base`.
+///
+/// `api_base` is the OpenAI-compatible base URL (for example
+/// `http://127.0.0.1:11434/v1`). Ollama servers are detected through
+/// `/api/tags` and enriched per model via `/api/show`; other servers are read
+/// from `{api_base}/models`.
+pub async fn discover_local_model_catalog(
+    api_base: &str,
+    request_timeout_ms: u64,
+) -> Result<ModelCatalogFile> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(request_timeout_ms.max(1)))
+        .build()
+        .context("failed to build local model discovery HTTP client")?;
+
+    let ollama_error = match discover_ollama_models(&client, api_base).await {
+        Ok(entries) => return Ok(local_model_catalog_file(entries)),
+        Err(error) => error,
+    };
+    match discover_openai_compatible_models(&client, api_base).await {
+        Ok(entries) => Ok(local_model_catalog_file(entries)),
+        Err(error) => bail!(
+            "local model discovery failed for '{}': ollama probe: {:#}; openai-compatible probe: {:#}",
+            api_base,
+            ollama_error,
+            error
+        ),
+    }
+}
+
+fn local_model_catalog_file(mut entries: Vec<ModelCatalogEntry>) -> ModelCatalogFile {
+    entries.sort_by(|left, right| left.model.cmp(&right.model));
+    entries.dedup_by(|left, right| left.model.eq_ignore_ascii_case(&right.model));
+    ModelCatalogFile {
+        schema_version: MODEL_CATALOG_SCHEMA_VERSION,
+        entries,
+    }
+}
+
+fn local_server_root(api_base: &str) -> &str {
+    let trimmed = api_base.trim().trim_end_matches('/');
+    trimmed.strip_suffix("/v1").unwrap_or(trimmed)
+}
+
+async fn discover_ollama_models(
+    client: &reqwest::Client,
+    api_base: &str,
+) -> Result<Vec<ModelCatalogEntry>> {
+    let root = local_server_root(api_base);
+    let tags_url = format!("{root}/api/tags");
+    let response = client
+        .get(&tags_url)
+        .send()
+        .await
+        .with_context(|| format!("failed to fetch '{tags_url}'"))?;
+    if !response.status().is_success() {
+        bail!("'{}' returned status {}", tags_url, response.status());
+    }
+    let tags = response
+        .json::<OllamaTagsResponse>()
+        .await
+        .with_context(|| format!("failed to parse Ollama tags from '{tags_url}'"))?;
+
+    let show_url = format!("{root}/api/show");
+    let mut entries = Vec::new();
+    for tag in tags.models {
+        let name = tag.name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        // `/api/show` only enriches the entry; a failure must not hide the model.
+        let show = fetch_ollama_model_details(client, &show_url, name)
+            .await
+            .unwrap_or_else(|error| {
+                tracing::debug!(
+                    model = name,
+                    error = %format!("{error:#}"),
+                    "ollama model details unavailable"
+                );
+                OllamaShowResponse::default()
+            });
+        entries.push(map_ollama_model_to_catalog_entry(name, &show));
+    }
+    Ok(entries)
+}
+
+async fn fetch_ollama_model_details(
+    client: &reqwest::Client,
+    show_url: &str,
+    model: &str,
+) -> Result<OllamaShowResponse> {
+    let response = client
+        .post(show_url)
+        .json(&json!({ "model": model }))
+        .send()
+        .await
+        .with_context(|| format!("failed to fetch '{show_url}' for '{model}'"))?;
+    if !response.status().is_success() {
+        bail!("'{}' returned status {}", show_url, response.status());
+    }
+    response
+        .json::<OllamaShowResponse>()
+        .await
+        .with_context(|| format!("failed to parse Ollama details for '{model}'"))
+}
+
+fn map_ollama_model_to_catalog_entry(name: &str, show: &OllamaShowResponse) -> ModelCatalogEntry {
+    let context_window_tokens = show
+        .model_info
+        .iter()
+        .find(|(key, _)| key.ends_with(".context_length"))
+        .and_then(|(_, value)| value.as_u64())
+        .and_then(|value| u32::try_from(value).ok())
+        .filter(|value| *value > 0);
+    let capabilities = show.capabilities.as_ref().map(|values| {
+        values
+            .iter()
+            .map(|value| value.trim().to_ascii_lowercase())
+            .collect::<Vec<_>>()
+    });
+    let has_capability = |name: &str| {
+        capabilities
+            .as_ref()
+            .map(|values| values.iter().any(|value| value == name))
+    };
+    // Older Ollama releases omit `capabilities`; textual tool calls are still
+    // promoted for those models, so tool use is assumed.
+    let supports_tools = has_capability("tools").unwrap_or(true);
+    let supports_reasoning = has_capability("thinking").unwrap_or(false);
+
+    local_catalog_entry(
+        name,
+        context_window_tokens,
+        supports_tools,
+        has_capability("vision").unwrap_or(false),
+        supports_reasoning,
+    )
+}
+
+async fn discover_openai_compatible_models(
+    client: &reqwest::Client,
+    api_base: &str,
+) -> Result<Vec<ModelCatalogEntry>> {
+    let models_url = format!("{}/models", api_base.trim().trim_end_matches('/'));
+    let response = client
+        .get(&models_url)
+        .send()
+        .await
+        .with_context(|| format!("failed to fetch '{models_url}'"))?;
+    if !response.status().is_success() {
+        bail!("'{}' returned status {}", models_url, response.status());
+    }
+    let models = response
+        .json::<OpenAiCompatibleModelsResponse>()
+        .await
+        .with_context(|| format!("failed to parse model listing from '{models_url}'"))?;
+
+    Ok(models
+        .data
+        .into_iter()
+        .filter(|model| !model.id.trim().is_empty())
+        .map(|model| {
+            let context_window_tokens = model
+                .max_model_len
+                .or_else(|| model.meta.as_ref().and_then(|meta| meta.n_ctx_train))
+                .filter(|value| *value > 0);
+            local_catalog_entry(model.id.trim(), context_window_tokens, true, false, false)
+        })
+        .collect())
+}
+
+fn local_catalog_entry(
+    model: &str,
+    context_window_tokens: Option<u32>,
+    supports_tools: bool,
+    supports_multimodal: bool,
+    supports_reasoning: bool,
+) -> ModelCatalogEntry {
+    ModelCatalogEntry {
+        provider: Provider::Local.as_str().to_string(),
+        model: model.to_string(),
+        context_window_tokens,
+        supports_tools,
+        supports_multimodal,
+        supports_reasoning,
+        supports_extended_thinking: supports_reasoning,
+        max_output_tokens: None,
+        knowledge_cutoff: None,
+        deprecated: false,
+        cached_input_cost_per_million: Some(0.0),
+        input_cost_per_million: Some(0.0),
+        output_cost_per_million: Some(0.0),
+        tokenizer: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    #[test]
+    fn unit_local_server_root_strips_openai_compatible_suffix() {
+        assert_eq!(
+            local_server_root("http://127.0.0.1:11434/v1/"),
+            "http://127.0.0.1:11434"
+        );
+        assert_eq!(
+            local_server_root("http://127.0.0.1:8080"),
+            "http://127.0.0.1:8080"
+        );
+    }
+
+    #[tokio::test]
+    async fn integration_discovers_ollama_tags_with_context_window_and_capabilities() {
+        let server = MockServer::start();
+        let tags = server.mock(|when, then| {
+            when.method(GET).path("/api/tags");
+            then.status(200).json_body(json!({
+                "models": [
+                    {"name": "qwen2.5-coder:7b", "model": "qwen2.5-coder:7b"},
+                    {"name": "gemma:2b", "model": "gemma:2b"}
+                ]
+            }));
+        });
+        let show_qwen = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/show")
+                .json_body_includes(json!({"model": "qwen2.5-coder:7b"}).to_string());
+            then.status(200).json_body(json!({
+                "model_info": {
+                    "general.architecture": "qwen2",
+                    "qwen2.context_length": 32768
+                },
+                "capabilities": ["completion", "tools"]
+            }));
+        });
+        let show_gemma = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/show")
+                .json_body_includes(json!({"model": "gemma:2b"}).to_string());
+            then.status(200).json_body(json!({
+                "model_info": {"gemma.context_length": 8192},
+                "capabilities": ["completion"]
+            }));
+        });
+
+        let file = discover_local_model_catalog(&format!("{}/v1", server.base_url()), 5_000)
+            .await
+            .expect("ollama discovery should succeed");
+
+        tags.assert_calls(1);
+        show_qwen.assert_calls(1);
+        show_gemma.assert_calls(1);
+        assert_eq!(file.entries.len(), 2);
+        let qwen = file
+            .entries
+            .iter()
+            .find(|entry| entry.model == "qwen2.5-coder:7b")
+            .expect("qwen entry");
+        assert_eq!(qwen.provider, "local");
+        assert_eq!(qwen.context_window_tokens, Some(32_768));
+        assert!(qwen.supports_tools);
+        assert_eq!(qwen.input_cost_per_million, Some(0.0));
+        let gemma = file
+            .entries
+            .iter()
+            .find(|entry| entry.model == "gemma:2b")
+            .expect("gemma entry");
+        assert_eq!(gemma.context_window_tokens, Some(8_192));
+        assert!(!gemma.supports_tools);
+    }
+
+    #[tokio::test]
+    async fn integration_falls_back_to_openai_compatible_model_listing() {
+        let server = MockServer::start();
+        let tags = server.mock(|when, then| {
+            when.method(GET).path("/api/tags");
+            then.status(404);
+        });
+        let models = server.mock(|when, then| {
+            when.method(GET).path("/v1/models");
+            then.status(200).json_body(json!({
+                "object": "list",
+                "data": [
+                    {"id": "meta-llama/Llama-3.1-8B-Instruct", "max_model_len": 131072},
+                    {"id": "model.gguf", "meta": {"n_ctx_train": 4096}}
+                ]
+            }));
+        });
+
+        let file = discover_local_model_catalog(&format!("{}/v1", server.base_url()), 5_000)
+            .await
+            .expect("openai-compatible discovery should succeed");
+
+        tags.assert_calls(1);
+        models.assert_calls(1);
+        let windows = file
+            .entries
+            .iter()
+            .map(|entry| (entry.model.as_str(), entry.context_window_tokens))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            windows,
+            vec![
+                ("meta-llama/Llama-3.1-8B-Instruct", Some(131_072)),
+                ("model.gguf", Some(4_096)),
+            ]
+        );
+        assert!(file.entries.iter().all(|entry| entry.supports_tools));
+    }
+
+    #[tokio::test]
+    async fn regression_discovery_reports_both_probe_failures() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET);
+            then.status(503);
+        });
+
+        let error = discover_local_model_catalog(&format!("{}/v1", server.base_url()), 5_000)
+            .await
+            .expect_err("discovery should fail");
+        let message = format!("{error:#}");
+        assert!(message.contains("ollama probe"), "{message}");
+        assert!(message.contains("openai-compatible probe"), "{message}");
+    }
+}
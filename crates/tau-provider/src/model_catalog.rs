@@ -12,7 +12,7 @@ use std::{
 
 use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use tau_ai::ModelRef;
+use tau_ai::{ModelRef, TokenizerFamily};
 
 use tau_core::write_text_atomic;
 
@@ -56,6 +56,18 @@ pub struct ModelCatalogEntry {
     pub cached_input_cost_per_million: Option<f64>,
     pub input_cost_per_million: Option<f64>,
     pub output_cost_per_million: Option<f64>,
+    /// Tokenizer override; inferred from the model name when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokenizer: Option<TokenizerFamily>,
+}
+
+impl ModelCatalogEntry {
+    /// Tokenizer used for budgets, compaction, and cost estimates for this model.
+    pub fn tokenizer_family(&self) -> TokenizerFamily {
+        self.tokenizer.unwrap_or_else(|| {
+            TokenizerFamily::for_model(&format!("{}/{}", self.provider, self.model))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -284,6 +296,17 @@ impl ModelCatalog {
             cache_age,
         })
     }
+
+    /// Merges `overlay` entries (for example discovered local models) into the
+    /// catalog, replacing entries with matching provider/model keys.
+    pub fn merge_overlay(self, overlay: ModelCatalogFile) -> Result<Self> {
+        let base = ModelCatalogFile {
+            schema_version: MODEL_CATALOG_SCHEMA_VERSION,
+            entries: self.entries,
+        };
+        let merged = merge_model_catalog_files(base, overlay)?;
+        Self::from_file(merged, self.source, self.cache_age)
+    }
 }
 
 /// Public `fn` `default_model_catalog_cache_path` in `tau-provider`.
@@ -670,6 +693,7 @@ pub fn render_model_show(catalog: &ModelCatalog, raw_model: &str) -> Result<Stri
         "output_cost_per_million={}",
         format_cost(entry.output_cost_per_million)
     ));
+    lines.push(format!("tokenizer={}", entry.tokenizer_family()));
     Ok(lines.join("\n"))
 }
 
@@ -874,6 +898,7 @@ fn map_openrouter_model_to_catalog_entry(model: OpenRouterModel) -> Option<Model
         cached_input_cost_per_million,
         input_cost_per_million,
         output_cost_per_million,
+        tokenizer: None,
     })
 }
 
@@ -944,6 +969,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.175000),
                 input_cost_per_million: Some(1.750000),
                 output_cost_per_million: Some(14.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -959,6 +985,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(2.100000),
                 input_cost_per_million: Some(21.000000),
                 output_cost_per_million: Some(168.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -974,6 +1001,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.125000),
                 input_cost_per_million: Some(1.250000),
                 output_cost_per_million: Some(10.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -989,6 +1017,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.125000),
                 input_cost_per_million: Some(1.250000),
                 output_cost_per_million: Some(10.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1004,6 +1033,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.500000),
                 input_cost_per_million: Some(2.000000),
                 output_cost_per_million: Some(8.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1019,6 +1049,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.100000),
                 input_cost_per_million: Some(0.400000),
                 output_cost_per_million: Some(1.600000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1034,6 +1065,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.025000),
                 input_cost_per_million: Some(0.100000),
                 output_cost_per_million: Some(0.400000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1049,6 +1081,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(2.000000),
                 output_cost_per_million: Some(8.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1064,6 +1097,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(1.100000),
                 output_cost_per_million: Some(4.400000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1079,6 +1113,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(2.500000),
                 output_cost_per_million: Some(10.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1094,6 +1129,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.150000),
                 output_cost_per_million: Some(0.600000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1109,6 +1145,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(3.000000),
                 output_cost_per_million: Some(15.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1124,6 +1161,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.200000),
                 output_cost_per_million: Some(0.500000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1139,6 +1177,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.200000),
                 output_cost_per_million: Some(1.500000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1154,6 +1193,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.500000),
                 output_cost_per_million: Some(1.500000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1169,6 +1209,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.400000),
                 output_cost_per_million: Some(2.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1184,6 +1225,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.030000),
                 output_cost_per_million: Some(0.110000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1199,6 +1241,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.150000),
                 output_cost_per_million: Some(0.500000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1214,6 +1257,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.220000),
                 output_cost_per_million: Some(0.850000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1229,6 +1273,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.028000),
                 input_cost_per_million: Some(0.280000),
                 output_cost_per_million: Some(0.420000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "openai".to_string(),
@@ -1244,6 +1289,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: Some(0.028000),
                 input_cost_per_million: Some(0.280000),
                 output_cost_per_million: Some(0.420000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "anthropic".to_string(),
@@ -1259,6 +1305,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(5.000000),
                 output_cost_per_million: Some(25.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "anthropic".to_string(),
@@ -1274,6 +1321,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(3.000000),
                 output_cost_per_million: Some(15.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "anthropic".to_string(),
@@ -1289,6 +1337,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(1.000000),
                 output_cost_per_million: Some(5.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "anthropic".to_string(),
@@ -1304,6 +1353,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(3.000000),
                 output_cost_per_million: Some(15.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "anthropic".to_string(),
@@ -1319,6 +1369,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(5.000000),
                 output_cost_per_million: Some(25.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "google".to_string(),
@@ -1334,6 +1385,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(2.000000),
                 output_cost_per_million: Some(12.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "google".to_string(),
@@ -1349,6 +1401,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.500000),
                 output_cost_per_million: Some(3.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "google".to_string(),
@@ -1364,6 +1417,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(1.250000),
                 output_cost_per_million: Some(10.000000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "google".to_string(),
@@ -1379,6 +1433,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.300000),
                 output_cost_per_million: Some(2.500000),
+                tokenizer: None,
             },
             ModelCatalogEntry {
                 provider: "google".to_string(),
@@ -1394,6 +1449,7 @@ fn built_in_model_catalog_file() -> ModelCatalogFile {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: Some(0.100000),
                 output_cost_per_million: Some(0.400000),
+                tokenizer: None,
             },
         ],
     }
@@ -1424,6 +1480,7 @@ mod tests {
                     cached_input_cost_per_million: None,
                     input_cost_per_million: None,
                     output_cost_per_million: None,
+                    tokenizer: None,
                 },
                 ModelCatalogEntry {
                     provider: "OPENAI".to_string(),
@@ -1439,6 +1496,7 @@ mod tests {
                     cached_input_cost_per_million: None,
                     input_cost_per_million: None,
                     output_cost_per_million: None,
+                    tokenizer: None,
                 },
             ],
         };
@@ -1479,6 +1537,42 @@ mod tests {
         assert!(output.contains("supports_tools=true"));
         assert!(output.contains("supports_multimodal=true"));
         assert!(output.contains("supports_reasoning=true"));
+        assert!(output.contains("tokenizer=o200k_base"));
+    }
+
+    #[test]
+    fn unit_model_catalog_entry_tokenizer_prefers_override_then_model_inference() {
+        let payload = serde_json::json!({
+            "schema_version": MODEL_CATALOG_SCHEMA_VERSION,
+            "entries": [
+                {
+                    "provider": "anthropic",
+                    "model": "claude-sonnet-4-5",
+                    "context_window_tokens": 200000
+                },
+                {
+                    "provider": "local",
+                    "model": "qwen-coder",
+                    "context_window_tokens": 32768,
+                    "tokenizer": "cl100k_base"
+                }
+            ]
+        })
+        .to_string();
+        let file = parse_model_catalog_payload(&payload).expect("parse payload");
+        assert_eq!(file.entries[0].tokenizer, None);
+        assert_eq!(
+            file.entries[0].tokenizer_family(),
+            TokenizerFamily::Anthropic
+        );
+        assert_eq!(
+            file.entries[1].tokenizer_family(),
+            TokenizerFamily::Cl100kBase
+        );
+
+        let encoded = serde_json::to_value(&file).expect("serialize payload");
+        assert!(encoded["entries"][0].get("tokenizer").is_none());
+        assert_eq!(encoded["entries"][1]["tokenizer"], "cl100k_base");
     }
 
     #[test]
@@ -1823,6 +1917,7 @@ mod tests {
                     cached_input_cost_per_million: Some(0.175),
                     input_cost_per_million: Some(1.75),
                     output_cost_per_million: Some(14.0),
+                    tokenizer: None,
                 },
                 ModelCatalogEntry {
                     provider: "openrouter".to_string(),
@@ -1838,6 +1933,7 @@ mod tests {
                     cached_input_cost_per_million: None,
                     input_cost_per_million: Some(0.4),
                     output_cost_per_million: Some(1.6),
+                    tokenizer: None,
                 },
             ],
         };
@@ -1881,6 +1977,7 @@ mod tests {
                 cached_input_cost_per_million: None,
                 input_cost_per_million: None,
                 output_cost_per_million: None,
+                tokenizer: None,
             }],
         };
         write_model_catalog_cache(&cache_path, &cached).expect("write cache");
@@ -0,0 +1,920 @@
+//! Cost- and complexity-aware model routing client.
+//!
+//! `ModelRoutingClient` classifies each request into a [`ModelTier`] from its
+//! features (estimated input tokens, tool count, images, explicit tier hint),
+//! picks the cheapest catalog-capable route for that tier, and tracks
+//! per-session spend against an optional cost budget. Every decision is emitted
+//! as a `model_routing_decision` JSON event.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use tau_ai::{
+    stream_event_handler_from_deltas, ChatRequest, ChatResponse, ChatUsage, ContentBlock,
+    LlmClient, MessageRole, ModelRef, ModelTier, Provider, StreamDeltaHandler, StreamEventHandler,
+    TauAiError, TokenizerFamily,
+};
+use tau_cli::Cli;
+
+use crate::client::build_provider_client;
+use crate::fallback::ClientRoute;
+use crate::model_catalog::{ModelCatalog, ModelCatalogEntry};
+
+type ModelRouterEventSink = Arc<dyn Fn(serde_json::Value) + Send + Sync>;
+
+const DEFAULT_BUDGET_SESSION_KEY: &str = "default";
+const ESTIMATED_TOKENS_PER_IMAGE: u32 = 1_024;
+const DEFAULT_EXPECTED_OUTPUT_TOKENS: u32 = 1_024;
+const TIERS_ASCENDING: [ModelTier; 3] =
+    [ModelTier::Economy, ModelTier::Standard, ModelTier::Frontier];
+
+#[derive(Clone)]
+/// One routable model with its tier and optional catalog metadata.
+pub struct ModelRoute {
+    pub route: ClientRoute,
+    pub tier: ModelTier,
+    pub catalog_entry: Option<ModelCatalogEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Thresholds and budget settings for `ModelRoutingClient`.
+pub struct ModelRouterConfig {
+    /// Requests without tools at or below this estimate go to the economy tier.
+    pub economy_max_input_tokens: u32,
+    /// Requests at or above this estimate go to the frontier tier.
+    pub frontier_min_input_tokens: u32,
+    /// Requests offering at least this many tools go to the frontier tier.
+    pub frontier_min_tool_count: usize,
+    /// Optional per-session spend ceiling in USD.
+    pub session_budget_usd: Option<f64>,
+    /// Fail requests instead of routing to the cheapest model once the budget is spent.
+    pub budget_hard_limit: bool,
+}
+
+impl Default for ModelRouterConfig {
+    fn default() -> Self {
+        Self {
+            economy_max_input_tokens: 4_000,
+            frontier_min_input_tokens: 48_000,
+            frontier_min_tool_count: 16,
+            session_budget_usd: None,
+            budget_hard_limit: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Request features used to classify a chat request into a tier.
+pub struct ModelRoutingFeatures {
+    pub estimated_input_tokens: u32,
+    pub tool_count: usize,
+    pub has_images: bool,
+    pub tool_result_follow_up: bool,
+    pub tier_hint: Option<ModelTier>,
+}
+
+impl ModelRoutingFeatures {
+    pub fn from_request(request: &ChatRequest) -> Self {
+        let (estimated_input_tokens, image_count) =
+            estimate_request_input_tokens(request, TokenizerFamily::for_model(&request.model));
+        Self {
+            estimated_input_tokens,
+            tool_count: request.tools.len(),
+            has_images: image_count > 0,
+            tool_result_follow_up: request
+                .messages
+                .last()
+                .is_some_and(|message| message.role == MessageRole::Tool),
+            tier_hint: request.model_tier,
+        }
+    }
+}
+
+/// Counts request text with `tokenizer` and adds a fixed allowance per image.
+fn estimate_request_input_tokens(request: &ChatRequest, tokenizer: TokenizerFamily) -> (u32, u32) {
+    let mut text_tokens = 0u32;
+    let mut image_count = 0u32;
+    for message in &request.messages {
+        for block in &message.content {
+            let block_tokens = match block {
+                ContentBlock::Text { text } | ContentBlock::Thinking { text, .. } => {
+                    tokenizer.count_text_tokens(text)
+                }
+                ContentBlock::ToolCall { arguments, .. } => {
+                    tokenizer.count_text_tokens(&arguments.to_string())
+                }
+                ContentBlock::Image { .. } => {
+                    image_count = image_count.saturating_add(1);
+                    0
+                }
+                ContentBlock::Audio { .. } => 0,
+            };
+            text_tokens = text_tokens.saturating_add(block_tokens);
+        }
+    }
+    for tool in &request.tools {
+        text_tokens = text_tokens
+            .saturating_add(tokenizer.count_text_tokens(&tool.name))
+            .saturating_add(tokenizer.count_text_tokens(&tool.description))
+            .saturating_add(tokenizer.count_text_tokens(&tool.parameters.to_string()));
+    }
+    (
+        text_tokens.saturating_add(image_count.saturating_mul(ESTIMATED_TOKENS_PER_IMAGE)),
+        image_count,
+    )
+}
+
+/// Classifies request features into a tier and returns the reason code.
+pub fn classify_model_tier(
+    features: &ModelRoutingFeatures,
+    config: &ModelRouterConfig,
+) -> (ModelTier, &'static str) {
+    if let Some(hint) = features.tier_hint {
+        return (hint, "tier_hint");
+    }
+    if features.estimated_input_tokens >= config.frontier_min_input_tokens {
+        return (ModelTier::Frontier, "large_input");
+    }
+    if features.tool_count >= config.frontier_min_tool_count.max(1) {
+        return (ModelTier::Frontier, "tool_heavy");
+    }
+    if features.tool_count == 0 {
+        if features.tool_result_follow_up {
+            return (ModelTier::Economy, "tool_result_summary");
+        }
+        if features.estimated_input_tokens <= config.economy_max_input_tokens {
+            return (ModelTier::Economy, "small_input");
+        }
+    }
+    (ModelTier::Standard, "default")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ModelRoutingDecision {
+    route_index: usize,
+    requested_tier: ModelTier,
+    reason: &'static str,
+    estimated_cost_usd: f64,
+    budget_downgraded: bool,
+}
+
+/// `LlmClient` that picks one model route per request by tier, capability and cost.
+pub struct ModelRoutingClient {
+    routes: Vec<ModelRoute>,
+    config: ModelRouterConfig,
+    event_sink: Option<ModelRouterEventSink>,
+    session_spend_usd: Mutex<HashMap<String, f64>>,
+}
+
+impl ModelRoutingClient {
+    pub fn new(
+        routes: Vec<ModelRoute>,
+        config: ModelRouterConfig,
+        event_sink: Option<ModelRouterEventSink>,
+    ) -> Self {
+        Self {
+            routes,
+            config,
+            event_sink,
+            session_spend_usd: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the recorded spend for one budget session key.
+    pub fn session_spend_usd(&self, session_key: &str) -> f64 {
+        lock_or_recover_mutex(&self.session_spend_usd)
+            .get(session_key)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn record_session_spend(&self, session_key: &str, cost_usd: f64) {
+        let mut spend = lock_or_recover_mutex(&self.session_spend_usd);
+        *spend.entry(session_key.to_string()).or_insert(0.0) += cost_usd.max(0.0);
+    }
+
+    fn select_route(
+        &self,
+        request: &ChatRequest,
+        features: &ModelRoutingFeatures,
+        session_spent_usd: f64,
+    ) -> Result<ModelRoutingDecision, TauAiError> {
+        let eligible = self
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| route_supports_request(route, features, request.max_tokens))
+            .map(|(index, route)| {
+                (
+                    index,
+                    route,
+                    estimate_route_cost_usd(route, features, request),
+                )
+            })
+            .collect::<Vec<_>>();
+        if eligible.is_empty() {
+            return Err(TauAiError::InvalidResponse(format!(
+                "no routed model supports this request (estimated_input_tokens={}, tools={}, images={})",
+                features.estimated_input_tokens, features.tool_count, features.has_images
+            )));
+        }
+
+        let (requested_tier, reason) = classify_model_tier(features, &self.config);
+        let (route_index, _, estimated_cost_usd) = tier_preference(requested_tier)
+            .into_iter()
+            .find_map(|tier| {
+                cheapest(
+                    eligible
+                        .iter()
+                        .filter(|(_, route, _)| route.tier == tier)
+                        .copied(),
+                )
+            })
+            .expect("eligible routes are non-empty");
+        let decision = ModelRoutingDecision {
+            route_index,
+            requested_tier,
+            reason,
+            estimated_cost_usd,
+            budget_downgraded: false,
+        };
+
+        let Some(budget_usd) = self.config.session_budget_usd else {
+            return Ok(decision);
+        };
+        let remaining_usd = budget_usd - session_spent_usd;
+        if estimated_cost_usd <= remaining_usd {
+            return Ok(decision);
+        }
+        let affordable = cheapest(
+            eligible
+                .iter()
+                .filter(|(_, _, cost)| *cost <= remaining_usd)
+                .copied(),
+        );
+        if let Some((route_index, _, estimated_cost_usd)) = affordable {
+            return Ok(ModelRoutingDecision {
+                route_index,
+                reason: "budget_downgrade",
+                estimated_cost_usd,
+                budget_downgraded: true,
+                ..decision
+            });
+        }
+
+        self.emit_budget_exhausted_event(budget_usd, session_spent_usd);
+        if self.config.budget_hard_limit {
+            return Err(TauAiError::InvalidResponse(format!(
+                "model router session budget exhausted (spent ${session_spent_usd:.4} of ${budget_usd:.4})"
+            )));
+        }
+        let (route_index, _, estimated_cost_usd) =
+            cheapest(eligible.iter().copied()).expect("eligible routes are non-empty");
+        Ok(ModelRoutingDecision {
+            route_index,
+            reason: "budget_exhausted",
+            estimated_cost_usd,
+            budget_downgraded: true,
+            ..decision
+        })
+    }
+
+    fn emit_decision_event(
+        &self,
+        decision: &ModelRoutingDecision,
+        features: &ModelRoutingFeatures,
+        session_key: &str,
+        session_spent_usd: f64,
+    ) {
+        let Some(sink) = &self.event_sink else {
+            return;
+        };
+        let route = &self.routes[decision.route_index];
+        sink(serde_json::json!({
+            "type": "model_routing_decision",
+            "model": format!("{}/{}", route.route.provider, route.route.model),
+            "route_index": decision.route_index,
+            "tier": route.tier.as_str(),
+            "requested_tier": decision.requested_tier.as_str(),
+            "reason": decision.reason,
+            "estimated_input_tokens": features.estimated_input_tokens,
+            "tool_count": features.tool_count,
+            "has_images": features.has_images,
+            "estimated_cost_usd": decision.estimated_cost_usd,
+            "budget_downgraded": decision.budget_downgraded,
+            "session": session_key,
+            "session_spent_usd": session_spent_usd,
+            "session_budget_usd": self.config.session_budget_usd,
+        }));
+    }
+
+    fn emit_budget_exhausted_event(&self, budget_usd: f64, session_spent_usd: f64) {
+        let Some(sink) = &self.event_sink else {
+            return;
+        };
+        sink(serde_json::json!({
+            "type": "model_routing_budget_exhausted",
+            "session_budget_usd": budget_usd,
+            "session_spent_usd": session_spent_usd,
+            "hard_limit": self.config.budget_hard_limit,
+        }));
+    }
+
+    async fn complete_inner(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        if self.routes.is_empty() {
+            return Err(TauAiError::InvalidResponse(
+                "no model router routes configured".to_string(),
+            ));
+        }
+
+        let session_key = request
+            .prompt_cache
+            .cache_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .unwrap_or(DEFAULT_BUDGET_SESSION_KEY)
+            .to_string();
+        let session_spent_usd = self.session_spend_usd(&session_key);
+        let features = ModelRoutingFeatures::from_request(&request);
+        let decision = self.select_route(&request, &features, session_spent_usd)?;
+        self.emit_decision_event(&decision, &features, &session_key, session_spent_usd);
+
+        let route = &self.routes[decision.route_index];
+        let mut routed_request = request;
+        routed_request.model = route.route.model.clone();
+        let response = if let Some(event_handler) = on_event {
+            route
+                .route
+                .client
+                .complete_with_events(routed_request, Some(event_handler))
+                .await?
+        } else {
+            route.route.client.complete(routed_request).await?
+        };
+
+        if let Some(entry) = route.catalog_entry.as_ref() {
+            self.record_session_spend(&session_key, usage_cost_usd(entry, &response.usage));
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl LlmClient for ModelRoutingClient {
+    async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, None).await
+    }
+
+    async fn complete_with_stream(
+        &self,
+        request: ChatRequest,
+        on_delta: Option<StreamDeltaHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, on_delta.map(stream_event_handler_from_deltas))
+            .await
+    }
+
+    async fn complete_with_events(
+        &self,
+        request: ChatRequest,
+        on_event: Option<StreamEventHandler>,
+    ) -> Result<ChatResponse, TauAiError> {
+        self.complete_inner(request, on_event).await
+    }
+}
+
+fn tier_preference(requested: ModelTier) -> Vec<ModelTier> {
+    let mut order = vec![requested];
+    order.extend(
+        TIERS_ASCENDING
+            .iter()
+            .copied()
+            .filter(|tier| *tier > requested),
+    );
+    order.extend(
+        TIERS_ASCENDING
+            .iter()
+            .rev()
+            .copied()
+            .filter(|tier| *tier < requested),
+    );
+    order
+}
+
+fn cheapest<'a>(
+    candidates: impl Iterator<Item = (usize, &'a ModelRoute, f64)>,
+) -> Option<(usize, &'a ModelRoute, f64)> {
+    candidates.fold(None, |best, candidate| match best {
+        Some(best) if best.2 <= candidate.2 => Some(best),
+        _ => Some(candidate),
+    })
+}
+
+fn route_supports_request(
+    route: &ModelRoute,
+    features: &ModelRoutingFeatures,
+    max_tokens: Option<u32>,
+) -> bool {
+    let Some(entry) = route.catalog_entry.as_ref() else {
+        return true;
+    };
+    if features.tool_count > 0 && !entry.supports_tools {
+        return false;
+    }
+    if features.has_images && !entry.supports_multimodal {
+        return false;
+    }
+    match entry.context_window_tokens {
+        Some(context_window) => {
+            u64::from(features.estimated_input_tokens) + u64::from(max_tokens.unwrap_or(0))
+                <= u64::from(context_window)
+        }
+        None => true,
+    }
+}
+
+fn estimate_route_cost_usd(
+    route: &ModelRoute,
+    features: &ModelRoutingFeatures,
+    request: &ChatRequest,
+) -> f64 {
+    let Some(entry) = route.catalog_entry.as_ref() else {
+        return 0.0;
+    };
+    let output_tokens = request.max_tokens.unwrap_or(DEFAULT_EXPECTED_OUTPUT_TOKENS);
+    // Price with the route's own tokenizer; feature estimates use the request model.
+    let input_tokens = match entry.tokenizer_family() {
+        TokenizerFamily::Heuristic => features.estimated_input_tokens,
+        tokenizer => estimate_request_input_tokens(request, tokenizer).0,
+    };
+    per_million_cost(entry.input_cost_per_million, u64::from(input_tokens))
+        + per_million_cost(entry.output_cost_per_million, u64::from(output_tokens))
+}
+
+fn usage_cost_usd(entry: &ModelCatalogEntry, usage: &ChatUsage) -> f64 {
+    let cached_input_tokens = usage.cached_input_tokens.min(usage.input_tokens);
+    let uncached_input_tokens = usage.input_tokens - cached_input_tokens;
+    per_million_cost(entry.input_cost_per_million, uncached_input_tokens)
+        + per_million_cost(
+            entry
+                .cached_input_cost_per_million
+                .or(entry.input_cost_per_million),
+            cached_input_tokens,
+        )
+        + per_million_cost(entry.output_cost_per_million, usage.output_tokens)
+}
+
+fn per_million_cost(cost_per_million: Option<f64>, tokens: u64) -> f64 {
+    cost_per_million
+        .map(|cost| cost.max(0.0) * tokens as f64 / 1_000_000.0)
+        .unwrap_or(0.0)
+}
+
+fn lock_or_recover_mutex<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Parses a `--model-route` value in `tier=provider/model` format.
+pub fn parse_model_route_spec(raw: &str) -> Result<(ModelTier, ModelRef)> {
+    let (tier, model) = raw
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid --model-route '{raw}': expected tier=provider/model"))?;
+    let tier = tier
+        .parse::<ModelTier>()
+        .map_err(|error| anyhow!("invalid --model-route '{raw}': {error}"))?;
+    let model = ModelRef::parse(model.trim())
+        .map_err(|error| anyhow!("invalid --model-route '{raw}': {error}"))?;
+    Ok((tier, model))
+}
+
+/// Wraps `primary_client` in a `ModelRoutingClient` when `--model-route` is set.
+///
+/// The primary model joins the route table as a standard-tier route unless a
+/// `--model-route` entry names it explicitly; the primary client keeps its
+/// fallback chain.
+pub fn build_model_router_client_if_configured(
+    cli: &Cli,
+    primary: &ModelRef,
+    primary_client: Arc<dyn LlmClient>,
+    catalog: &ModelCatalog,
+) -> Result<Arc<dyn LlmClient>> {
+    if cli.model_route.is_empty() {
+        return Ok(primary_client);
+    }
+
+    let mut specs = Vec::new();
+    for raw in &cli.model_route {
+        let (tier, model_ref) = parse_model_route_spec(raw)?;
+        if specs.iter().any(|(_, existing): &(ModelTier, ModelRef)| {
+            existing.provider == model_ref.provider && existing.model == model_ref.model
+        }) {
+            continue;
+        }
+        specs.push((tier, model_ref));
+    }
+    if !specs.iter().any(|(_, model_ref)| {
+        model_ref.provider == primary.provider && model_ref.model == primary.model
+    }) {
+        specs.insert(0, (ModelTier::Standard, primary.clone()));
+    }
+
+    let mut provider_clients: Vec<(Provider, Arc<dyn LlmClient>)> = Vec::new();
+    let mut routes = Vec::with_capacity(specs.len());
+    for (tier, model_ref) in specs {
+        let client = if model_ref.provider == primary.provider && model_ref.model == primary.model {
+            primary_client.clone()
+        } else if let Some((_, existing)) = provider_clients
+            .iter()
+            .find(|(provider, _)| *provider == model_ref.provider)
+        {
+            existing.clone()
+        } else {
+            let created = build_provider_client(cli, model_ref.provider).with_context(|| {
+                format!(
+                    "failed to create {} client for routed model '{}'",
+                    model_ref.provider, model_ref.model
+                )
+            })?;
+            provider_clients.push((model_ref.provider, created.clone()));
+            created
+        };
+        routes.push(ModelRoute {
+            catalog_entry: catalog.find_model_ref(&model_ref).cloned(),
+            route: ClientRoute {
+                provider: model_ref.provider,
+                model: model_ref.model,
+                client,
+            },
+            tier,
+        });
+    }
+
+    let config = ModelRouterConfig {
+        session_budget_usd: cli.model_router_session_budget_usd,
+        budget_hard_limit: cli.model_router_budget_hard_limit,
+        ..ModelRouterConfig::default()
+    };
+    let event_sink = if cli.json_events {
+        Some(Arc::new(|event| println!("{event}")) as ModelRouterEventSink)
+    } else {
+        None
+    };
+    Ok(Arc::new(ModelRoutingClient::new(
+        routes, config, event_sink,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use tau_ai::{Message, ToolDefinition};
+
+    #[derive(Clone, Default)]
+    struct MockLlmClient {
+        observed_models: Arc<Mutex<Vec<String>>>,
+        usage: ChatUsage,
+    }
+
+    impl MockLlmClient {
+        fn with_usage(input_tokens: u64, output_tokens: u64) -> Self {
+            Self {
+                observed_models: Arc::new(Mutex::new(Vec::new())),
+                usage: ChatUsage {
+                    input_tokens,
+                    output_tokens,
+                    total_tokens: input_tokens + output_tokens,
+                    ..ChatUsage::default()
+                },
+            }
+        }
+
+        fn observed_models(&self) -> Vec<String> {
+            self.observed_models
+                .lock()
+                .expect("observed models lock")
+                .clone()
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for MockLlmClient {
+        async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            self.observed_models
+                .lock()
+                .expect("observed models lock")
+                .push(request.model);
+            Ok(ChatResponse {
+                message: Message::assistant_text("ok"),
+                finish_reason: Some("stop".to_string()),
+                usage: self.usage.clone(),
+            })
+        }
+    }
+
+    fn catalog_entry(
+        model: &str,
+        input_cost_per_million: f64,
+        output_cost_per_million: f64,
+        context_window_tokens: u32,
+        supports_multimodal: bool,
+    ) -> ModelCatalogEntry {
+        ModelCatalogEntry {
+            provider: "openai".to_string(),
+            model: model.to_string(),
+            context_window_tokens: Some(context_window_tokens),
+            supports_tools: true,
+            supports_multimodal,
+            supports_reasoning: false,
+            supports_extended_thinking: false,
+            max_output_tokens: None,
+            knowledge_cutoff: None,
+            deprecated: false,
+            cached_input_cost_per_million: None,
+            input_cost_per_million: Some(input_cost_per_million),
+            output_cost_per_million: Some(output_cost_per_million),
+            tokenizer: None,
+        }
+    }
+
+    fn route(client: &MockLlmClient, tier: ModelTier, entry: ModelCatalogEntry) -> ModelRoute {
+        ModelRoute {
+            route: ClientRoute {
+                provider: Provider::OpenAi,
+                model: entry.model.clone(),
+                client: Arc::new(client.clone()),
+            },
+            tier,
+            catalog_entry: Some(entry),
+        }
+    }
+
+    fn tiered_routes(client: &MockLlmClient) -> Vec<ModelRoute> {
+        vec![
+            route(
+                client,
+                ModelTier::Standard,
+                catalog_entry("standard-model", 2.0, 8.0, 128_000, true),
+            ),
+            route(
+                client,
+                ModelTier::Economy,
+                catalog_entry("economy-model", 0.1, 0.4, 16_000, false),
+            ),
+            route(
+                client,
+                ModelTier::Frontier,
+                catalog_entry("frontier-model", 10.0, 40.0, 400_000, true),
+            ),
+        ]
+    }
+
+    fn test_request(text: &str) -> ChatRequest {
+        ChatRequest {
+            model: "placeholder-model".to_string(),
+            messages: vec![Message::user(text)],
+            tools: Vec::new(),
+            tool_choice: None,
+            json_mode: false,
+            max_tokens: None,
+            temperature: None,
+            prompt_cache: Default::default(),
+            reasoning: Default::default(),
+            model_tier: None,
+        }
+    }
+
+    fn test_tool(name: &str) -> ToolDefinition {
+        ToolDefinition {
+            name: name.to_string(),
+            description: "test tool".to_string(),
+            parameters: serde_json::json!({"type": "object"}),
+        }
+    }
+
+    fn collecting_sink() -> (ModelRouterEventSink, Arc<Mutex<Vec<Value>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let sink = Arc::new(move |event: Value| {
+            sink_events.lock().expect("events lock").push(event);
+        }) as ModelRouterEventSink;
+        (sink, events)
+    }
+
+    #[test]
+    fn unit_classify_model_tier_uses_hint_size_and_tool_features() {
+        let config = ModelRouterConfig::default();
+        let mut features = ModelRoutingFeatures::from_request(&test_request("summarize this"));
+        assert_eq!(
+            classify_model_tier(&features, &config),
+            (ModelTier::Economy, "small_input")
+        );
+
+        features.tool_count = 3;
+        assert_eq!(
+            classify_model_tier(&features, &config),
+            (ModelTier::Standard, "default")
+        );
+
+        features.tool_count = config.frontier_min_tool_count;
+        assert_eq!(
+            classify_model_tier(&features, &config),
+            (ModelTier::Frontier, "tool_heavy")
+        );
+
+        features.tool_count = 0;
+        features.estimated_input_tokens = config.frontier_min_input_tokens;
+        assert_eq!(
+            classify_model_tier(&features, &config),
+            (ModelTier::Frontier, "large_input")
+        );
+
+        features.tier_hint = Some(ModelTier::Economy);
+        assert_eq!(
+            classify_model_tier(&features, &config),
+            (ModelTier::Economy, "tier_hint")
+        );
+    }
+
+    #[test]
+    fn unit_routing_features_estimate_tokens_images_and_tool_results() {
+        let mut request = test_request(&"x".repeat(400));
+        request.messages.push(Message {
+            role: MessageRole::User,
+            content: vec![ContentBlock::image_url("https://example.com/cat.png")],
+            tool_call_id: None,
+            tool_name: None,
+            is_error: false,
+        });
+        request
+            .messages
+            .push(Message::tool_result("call-1", "read", "done", false));
+
+        let features = ModelRoutingFeatures::from_request(&request);
+        assert_eq!(
+            features.estimated_input_tokens,
+            100 + 1 + ESTIMATED_TOKENS_PER_IMAGE
+        );
+        assert!(features.has_images);
+        assert!(features.tool_result_follow_up);
+        assert_eq!(features.tool_count, 0);
+    }
+
+    #[test]
+    fn unit_parse_model_route_spec_validates_tier_and_model() {
+        let (tier, model) = parse_model_route_spec("economy=openai/gpt-4o-mini").expect("parse");
+        assert_eq!(tier, ModelTier::Economy);
+        assert_eq!(model.provider, Provider::OpenAi);
+        assert_eq!(model.model, "gpt-4o-mini");
+
+        let error = parse_model_route_spec("openai/gpt-4o-mini").expect_err("missing tier");
+        assert!(error.to_string().contains("expected tier=provider/model"));
+        let error = parse_model_route_spec("cheap=openai/gpt-4o-mini").expect_err("bad tier");
+        assert!(error.to_string().contains("unsupported model tier 'cheap'"));
+    }
+
+    #[tokio::test]
+    async fn functional_router_sends_cheap_turns_to_economy_and_emits_decision_event() {
+        let client = MockLlmClient::default();
+        let (sink, events) = collecting_sink();
+        let router = ModelRoutingClient::new(
+            tiered_routes(&client),
+            ModelRouterConfig::default(),
+            Some(sink),
+        );
+
+        router
+            .complete(test_request("summarize the tool output"))
+            .await
+            .expect("economy completion");
+        let mut tool_request = test_request("edit the repository");
+        tool_request.tools = vec![test_tool("read"), test_tool("write")];
+        router
+            .complete(tool_request)
+            .await
+            .expect("standard completion");
+
+        assert_eq!(
+            client.observed_models(),
+            vec!["economy-model".to_string(), "standard-model".to_string()]
+        );
+        let events = events.lock().expect("events lock");
+        assert_eq!(events[0]["type"], "model_routing_decision");
+        assert_eq!(events[0]["model"], "openai/economy-model");
+        assert_eq!(events[0]["tier"], "economy");
+        assert_eq!(events[0]["reason"], "small_input");
+        assert_eq!(events[1]["tier"], "standard");
+        assert_eq!(events[1]["tool_count"], 2);
+    }
+
+    #[tokio::test]
+    async fn functional_router_escalates_when_tier_lacks_capability_or_context() {
+        let client = MockLlmClient::default();
+        let router =
+            ModelRoutingClient::new(tiered_routes(&client), ModelRouterConfig::default(), None);
+
+        let mut image_request = test_request("what is in this picture?");
+        image_request.messages[0]
+            .content
+            .push(ContentBlock::image_url("https://example.com/cat.png"));
+        image_request.model_tier = Some(ModelTier::Economy);
+        router
+            .complete(image_request)
+            .await
+            .expect("image completion");
+
+        let mut long_request = test_request(&"x".repeat(80_000));
+        long_request.model_tier = Some(ModelTier::Economy);
+        router
+            .complete(long_request)
+            .await
+            .expect("long completion");
+
+        assert_eq!(
+            client.observed_models(),
+            vec!["standard-model".to_string(), "standard-model".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn integration_router_downgrades_then_rejects_when_session_budget_is_spent() {
+        let client = MockLlmClient::with_usage(100_000, 10_000);
+        let (sink, events) = collecting_sink();
+        let router = ModelRoutingClient::new(
+            tiered_routes(&client),
+            ModelRouterConfig {
+                session_budget_usd: Some(1.41),
+                budget_hard_limit: true,
+                ..ModelRouterConfig::default()
+            },
+            Some(sink),
+        );
+
+        let mut request = test_request("plan the migration");
+        request.model_tier = Some(ModelTier::Frontier);
+        request.max_tokens = Some(4_000);
+        request.prompt_cache.cache_key = Some("session-a".to_string());
+        router
+            .complete(request.clone())
+            .await
+            .expect("frontier completion");
+        let spent = router.session_spend_usd("session-a");
+        assert!((spent - 1.4).abs() < 1e-9, "spent={spent}");
+
+        router
+            .complete(request.clone())
+            .await
+            .expect("downgraded completion");
+        let error = router
+            .complete(request.clone())
+            .await
+            .expect_err("budget exhausted");
+        assert!(error
+            .to_string()
+            .contains("model router session budget exhausted"));
+        assert_eq!(router.session_spend_usd("other-session"), 0.0);
+
+        assert_eq!(
+            client.observed_models(),
+            vec!["frontier-model".to_string(), "economy-model".to_string()]
+        );
+        let events = events.lock().expect("events lock");
+        assert_eq!(events[1]["reason"], "budget_downgrade");
+        assert_eq!(events[1]["budget_downgraded"], true);
+        assert_eq!(events[1]["requested_tier"], "frontier");
+        assert_eq!(events[2]["type"], "model_routing_budget_exhausted");
+    }
+
+    #[tokio::test]
+    async fn regression_router_soft_budget_routes_to_cheapest_model_when_exhausted() {
+        let client = MockLlmClient::with_usage(1_000_000, 0);
+        let router = ModelRoutingClient::new(
+            tiered_routes(&client),
+            ModelRouterConfig {
+                session_budget_usd: Some(0.5),
+                ..ModelRouterConfig::default()
+            },
+            None,
+        );
+
+        let mut request = test_request("review this design");
+        request.model_tier = Some(ModelTier::Standard);
+        router.complete(request.clone()).await.expect("first");
+        router.complete(request).await.expect("second");
+
+        assert_eq!(
+            client.observed_models(),
+            vec!["standard-model".to_string(), "economy-model".to_string()]
+        );
+    }
+}
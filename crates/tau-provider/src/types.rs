@@ -66,6 +66,9 @@ pub struct AuthCommandConfig {
     pub openai_auth_mode: ProviderAuthMethod,
     pub anthropic_auth_mode: ProviderAuthMethod,
     pub google_auth_mode: ProviderAuthMethod,
+    pub bedrock_auth_mode: ProviderAuthMethod,
+    pub vertex_auth_mode: ProviderAuthMethod,
+    pub vertex_service_account_file: Option<PathBuf>,
     pub provider_subscription_strict: bool,
     pub openai_codex_backend: bool,
     pub openai_codex_cli: String,
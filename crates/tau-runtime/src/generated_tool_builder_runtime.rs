@@ -70,6 +70,7 @@ impl Default for GeneratedToolBuildRequest {
                 filesystem_mode: WasmSandboxFilesystemMode::Deny,
                 network_mode: WasmSandboxNetworkMode::Deny,
                 env_allowlist: Vec::new(),
+                host_functions: Vec::new(),
             },
             provided_wat_source: None,
         }
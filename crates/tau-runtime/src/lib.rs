@@ -10,6 +10,7 @@ pub mod external_coding_agent_bridge_runtime;
 pub mod generated_tool_builder_runtime;
 pub mod heartbeat_runtime;
 pub mod observability_loggers_runtime;
+pub mod otel_trace_export_runtime;
 pub mod rpc_capabilities_runtime;
 pub mod rpc_protocol_runtime;
 pub mod runtime_output_runtime;
@@ -25,6 +26,7 @@ pub use external_coding_agent_bridge_runtime::*;
 pub use generated_tool_builder_runtime::*;
 pub use heartbeat_runtime::*;
 pub use observability_loggers_runtime::*;
+pub use otel_trace_export_runtime::*;
 pub use rpc_capabilities_runtime::*;
 pub use rpc_protocol_runtime::*;
 pub use runtime_output_runtime::*;
@@ -947,6 +947,10 @@ mod tests {
                     output_tokens: 1,
                     total_tokens: 2,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
                 finish_reason: Some("length".to_string()),
             })
@@ -990,6 +994,10 @@ mod tests {
                     output_tokens: 40,
                     total_tokens: 140,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
                 finish_reason: Some("stop".to_string()),
             })
@@ -1130,6 +1138,10 @@ mod tests {
                     output_tokens: 5,
                     total_tokens: 15,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
                 finish_reason: Some("stop".to_string()),
             })
@@ -1201,6 +1213,10 @@ mod tests {
                     output_tokens: 2,
                     total_tokens: 5,
                     cached_input_tokens: 0,
+                    reasoning_tokens: 0,
+                    hedge_input_tokens: 0,
+                    hedge_output_tokens: 0,
+                    hedge_model: None,
                 },
                 finish_reason: Some("stop".to_string()),
             })
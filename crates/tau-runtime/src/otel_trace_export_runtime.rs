@@ -0,0 +1,451 @@
+//! OTLP/HTTP trace export for spans recorded through `tau_core::trace_spans`.
+//!
+//! Finished spans are queued in memory and posted as OTLP JSON
+//! (`ExportTraceServiceRequest`) to a configurable collector on a fixed
+//! interval or whenever a batch fills. Export failures are counted and never
+//! surface to the code that recorded the span.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use tau_core::{
+    install_span_exporter, SpanAttributeValue, SpanExporter, SpanKind, SpanRecord, SpanStatus,
+};
+
+/// Default collector path for OTLP/HTTP trace exports.
+pub const OTLP_TRACES_PATH: &str = "/v1/traces";
+/// Default service name reported in the OTLP resource.
+pub const DEFAULT_OTLP_SERVICE_NAME: &str = "tau";
+/// Default number of spans per export request.
+pub const DEFAULT_OTLP_EXPORT_BATCH_SIZE: usize = 256;
+/// Default interval between background exports.
+pub const DEFAULT_OTLP_EXPORT_INTERVAL_MS: u64 = 2_000;
+const OTLP_EXPORT_TIMEOUT_MS: u64 = 10_000;
+const OTLP_MAX_PENDING_BATCHES: usize = 16;
+const OTLP_INSTRUMENTATION_SCOPE: &str = "tau";
+
+/// Configuration for the OTLP/HTTP span exporter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtlpTraceExporterConfig {
+    pub endpoint: String,
+    pub headers: Vec<(String, String)>,
+    pub service_name: String,
+    pub max_batch_size: usize,
+    pub export_interval_ms: u64,
+}
+
+impl OtlpTraceExporterConfig {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            headers: Vec::new(),
+            service_name: DEFAULT_OTLP_SERVICE_NAME.to_string(),
+            max_batch_size: DEFAULT_OTLP_EXPORT_BATCH_SIZE,
+            export_interval_ms: DEFAULT_OTLP_EXPORT_INTERVAL_MS,
+        }
+    }
+}
+
+/// Export counters reported by [`OtlpHttpSpanExporter::counters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OtlpTraceExportCounters {
+    pub exported_spans: u64,
+    pub dropped_spans: u64,
+    pub failed_exports: u64,
+}
+
+/// Batching span exporter posting OTLP JSON to a collector.
+#[derive(Debug)]
+pub struct OtlpHttpSpanExporter {
+    traces_url: String,
+    headers: Vec<(String, String)>,
+    service_name: String,
+    max_batch_size: usize,
+    export_interval: Duration,
+    client: reqwest::Client,
+    pending: Mutex<Vec<SpanRecord>>,
+    batch_ready: tokio::sync::Notify,
+    exported_spans: AtomicU64,
+    dropped_spans: AtomicU64,
+    failed_exports: AtomicU64,
+}
+
+impl OtlpHttpSpanExporter {
+    pub fn new(config: OtlpTraceExporterConfig) -> Result<Self> {
+        let traces_url = resolve_otlp_traces_url(&config.endpoint)?;
+        for (name, _) in &config.headers {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("invalid OTLP export header name '{name}'"))?;
+        }
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(OTLP_EXPORT_TIMEOUT_MS))
+            .build()
+            .context("failed to build OTLP export HTTP client")?;
+        Ok(Self {
+            traces_url,
+            headers: config.headers,
+            service_name: if config.service_name.trim().is_empty() {
+                DEFAULT_OTLP_SERVICE_NAME.to_string()
+            } else {
+                config.service_name.trim().to_string()
+            },
+            max_batch_size: config.max_batch_size.max(1),
+            export_interval: Duration::from_millis(config.export_interval_ms.max(10)),
+            client,
+            pending: Mutex::new(Vec::new()),
+            batch_ready: tokio::sync::Notify::new(),
+            exported_spans: AtomicU64::new(0),
+            dropped_spans: AtomicU64::new(0),
+            failed_exports: AtomicU64::new(0),
+        })
+    }
+
+    /// Resolved collector URL spans are posted to.
+    pub fn traces_url(&self) -> &str {
+        &self.traces_url
+    }
+
+    pub fn counters(&self) -> OtlpTraceExportCounters {
+        OtlpTraceExportCounters {
+            exported_spans: self.exported_spans.load(Ordering::Relaxed),
+            dropped_spans: self.dropped_spans.load(Ordering::Relaxed),
+            failed_exports: self.failed_exports.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Posts every queued span, returning how many were exported.
+    pub async fn flush(&self) -> Result<usize> {
+        let mut exported = 0usize;
+        loop {
+            let batch = {
+                let mut pending = self.lock_pending();
+                let take = pending.len().min(self.max_batch_size);
+                pending.drain(..take).collect::<Vec<_>>()
+            };
+            if batch.is_empty() {
+                return Ok(exported);
+            }
+            if let Err(error) = self.post_batch(&batch).await {
+                self.failed_exports.fetch_add(1, Ordering::Relaxed);
+                self.dropped_spans
+                    .fetch_add(batch.len() as u64, Ordering::Relaxed);
+                return Err(error);
+            }
+            self.exported_spans
+                .fetch_add(batch.len() as u64, Ordering::Relaxed);
+            exported = exported.saturating_add(batch.len());
+        }
+    }
+
+    /// Spawns the background loop exporting on interval or when a batch fills.
+    pub fn spawn_export_loop(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let exporter = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = exporter.batch_ready.notified() => {}
+                    _ = tokio::time::sleep(exporter.export_interval) => {}
+                }
+                let _ = exporter.flush().await;
+            }
+        })
+    }
+
+    async fn post_batch(&self, spans: &[SpanRecord]) -> Result<()> {
+        let payload = build_otlp_trace_export_payload(&self.service_name, spans);
+        let mut request = self.client.post(&self.traces_url).json(&payload);
+        for (name, value) in &self.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("failed to post OTLP traces to {}", self.traces_url))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "OTLP collector {} rejected trace export with status {}: {}",
+                self.traces_url,
+                status.as_u16(),
+                body
+            );
+        }
+        Ok(())
+    }
+
+    fn lock_pending(&self) -> std::sync::MutexGuard<'_, Vec<SpanRecord>> {
+        match self.pending.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl SpanExporter for OtlpHttpSpanExporter {
+    fn export(&self, span: SpanRecord) {
+        let batch_full = {
+            let mut pending = self.lock_pending();
+            let max_pending = self.max_batch_size.saturating_mul(OTLP_MAX_PENDING_BATCHES);
+            if pending.len() >= max_pending {
+                pending.remove(0);
+                self.dropped_spans.fetch_add(1, Ordering::Relaxed);
+            }
+            pending.push(span);
+            pending.len() >= self.max_batch_size
+        };
+        if batch_full {
+            self.batch_ready.notify_one();
+        }
+    }
+}
+
+/// Builds the exporter, starts its background loop, and installs it process-wide.
+///
+/// Must be called from within a Tokio runtime.
+pub fn install_otlp_trace_exporter(
+    config: OtlpTraceExporterConfig,
+) -> Result<Arc<OtlpHttpSpanExporter>> {
+    let exporter = Arc::new(OtlpHttpSpanExporter::new(config)?);
+    exporter.spawn_export_loop();
+    install_span_exporter(exporter.clone());
+    Ok(exporter)
+}
+
+/// Appends [`OTLP_TRACES_PATH`] to collector base URLs without a path.
+pub fn resolve_otlp_traces_url(endpoint: &str) -> Result<String> {
+    let trimmed = endpoint.trim();
+    let parsed = url::Url::parse(trimmed)
+        .with_context(|| format!("invalid OTLP traces endpoint '{trimmed}'"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        bail!("OTLP traces endpoint '{trimmed}' must use http or https");
+    }
+    if parsed.path().trim_matches('/').is_empty() {
+        return Ok(format!(
+            "{}{OTLP_TRACES_PATH}",
+            trimmed.trim_end_matches('/')
+        ));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Parses `KEY=VALUE` collector header arguments.
+pub fn parse_otlp_export_headers(raw: &[String]) -> Result<Vec<(String, String)>> {
+    raw.iter()
+        .map(|entry| {
+            let Some((name, value)) = entry.split_once('=') else {
+                bail!("OTLP export header '{entry}' must use KEY=VALUE");
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                bail!("OTLP export header '{entry}' has an empty name");
+            }
+            Ok((name.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Renders spans as an OTLP JSON `ExportTraceServiceRequest`.
+pub fn build_otlp_trace_export_payload(service_name: &str, spans: &[SpanRecord]) -> Value {
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [otlp_key_value("service.name", &SpanAttributeValue::from(service_name))]
+            },
+            "scopeSpans": [{
+                "scope": {
+                    "name": OTLP_INSTRUMENTATION_SCOPE,
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "spans": spans.iter().map(otlp_span).collect::<Vec<_>>(),
+            }]
+        }]
+    })
+}
+
+fn otlp_span(span: &SpanRecord) -> Value {
+    let mut payload = json!({
+        "traceId": span.trace_id,
+        "spanId": span.span_id,
+        "name": span.name,
+        "kind": otlp_span_kind(span.kind),
+        "startTimeUnixNano": span.start_unix_nanos.to_string(),
+        "endTimeUnixNano": span.end_unix_nanos.to_string(),
+        "attributes": span
+            .attributes
+            .iter()
+            .map(|(key, value)| otlp_key_value(key, value))
+            .collect::<Vec<_>>(),
+        "status": otlp_status(&span.status),
+    });
+    if let Some(parent_span_id) = span.parent_span_id.as_ref() {
+        payload["parentSpanId"] = json!(parent_span_id);
+    }
+    payload
+}
+
+fn otlp_span_kind(kind: SpanKind) -> u8 {
+    match kind {
+        SpanKind::Internal => 1,
+        SpanKind::Server => 2,
+        SpanKind::Client => 3,
+    }
+}
+
+fn otlp_status(status: &SpanStatus) -> Value {
+    match status {
+        SpanStatus::Unset => json!({ "code": 0 }),
+        SpanStatus::Ok => json!({ "code": 1 }),
+        SpanStatus::Error(message) => json!({ "code": 2, "message": message }),
+    }
+}
+
+fn otlp_key_value(key: &str, value: &SpanAttributeValue) -> Value {
+    json!({ "key": key, "value": otlp_any_value(value) })
+}
+
+fn otlp_any_value(value: &SpanAttributeValue) -> Value {
+    match value {
+        SpanAttributeValue::String(value) => json!({ "stringValue": value }),
+        SpanAttributeValue::Int(value) => json!({ "intValue": value.to_string() }),
+        SpanAttributeValue::Float(value) => json!({ "doubleValue": value }),
+        SpanAttributeValue::Bool(value) => json!({ "boolValue": value }),
+        SpanAttributeValue::StringArray(values) => json!({
+            "arrayValue": {
+                "values": values
+                    .iter()
+                    .map(|value| json!({ "stringValue": value }))
+                    .collect::<Vec<_>>()
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    fn sample_span(name: &str) -> SpanRecord {
+        SpanRecord {
+            trace_id: "6af7651916cd43dd8448eb211c80319c".to_string(),
+            span_id: "e7ad6b7169203331".to_string(),
+            parent_span_id: Some("d7ad6b7169203331".to_string()),
+            name: name.to_string(),
+            kind: SpanKind::Client,
+            start_unix_nanos: 1_000,
+            end_unix_nanos: 2_000,
+            attributes: vec![
+                (
+                    "gen_ai.usage.input_tokens".to_string(),
+                    SpanAttributeValue::Int(12),
+                ),
+                (
+                    "gen_ai.response.finish_reasons".to_string(),
+                    SpanAttributeValue::StringArray(vec!["stop".to_string()]),
+                ),
+            ],
+            status: SpanStatus::Error("provider failed".to_string()),
+        }
+    }
+
+    #[test]
+    fn unit_otlp_payload_encodes_ids_kinds_attributes_and_status() {
+        let payload = build_otlp_trace_export_payload("tau-gateway", &[sample_span("chat gpt")]);
+        let resource = &payload["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0],
+            json!({"key": "service.name", "value": {"stringValue": "tau-gateway"}})
+        );
+        let span = &resource["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["traceId"], "6af7651916cd43dd8448eb211c80319c");
+        assert_eq!(span["parentSpanId"], "d7ad6b7169203331");
+        assert_eq!(span["kind"], 3);
+        assert_eq!(span["startTimeUnixNano"], "1000");
+        assert_eq!(
+            span["attributes"][0],
+            json!({"key": "gen_ai.usage.input_tokens", "value": {"intValue": "12"}})
+        );
+        assert_eq!(
+            span["attributes"][1]["value"]["arrayValue"]["values"][0]["stringValue"],
+            "stop"
+        );
+        assert_eq!(
+            span["status"],
+            json!({"code": 2, "message": "provider failed"})
+        );
+    }
+
+    #[test]
+    fn unit_resolve_otlp_traces_url_and_headers_validate_input() {
+        assert_eq!(
+            resolve_otlp_traces_url("http://collector:4318").expect("base url"),
+            "http://collector:4318/v1/traces"
+        );
+        assert_eq!(
+            resolve_otlp_traces_url("https://collector/custom/traces").expect("full url"),
+            "https://collector/custom/traces"
+        );
+        assert!(resolve_otlp_traces_url("ftp://collector").is_err());
+        assert!(resolve_otlp_traces_url("not a url").is_err());
+
+        assert_eq!(
+            parse_otlp_export_headers(&["authorization=Bearer abc".to_string()])
+                .expect("valid header"),
+            vec![("authorization".to_string(), "Bearer abc".to_string())]
+        );
+        assert!(parse_otlp_export_headers(&["missing-separator".to_string()]).is_err());
+    }
+
+    #[tokio::test]
+    async fn integration_otlp_exporter_posts_batches_to_stand_in_collector() {
+        let server = MockServer::start();
+        let collector = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/traces")
+                .header("x-tenant", "tau-tests")
+                .body_includes("\"name\":\"chat gpt\"")
+                .body_includes("\"traceId\":\"6af7651916cd43dd8448eb211c80319c\"");
+            then.status(200).body("{}");
+        });
+        let mut config = OtlpTraceExporterConfig::new(server.base_url());
+        config.headers = vec![("x-tenant".to_string(), "tau-tests".to_string())];
+        config.max_batch_size = 1;
+        let exporter = OtlpHttpSpanExporter::new(config).expect("exporter");
+
+        exporter.export(sample_span("chat gpt"));
+        exporter.export(sample_span("chat gpt"));
+        assert_eq!(exporter.flush().await.expect("flush"), 2);
+        collector.assert_calls(2);
+        assert_eq!(exporter.counters().exported_spans, 2);
+    }
+
+    #[tokio::test]
+    async fn regression_otlp_exporter_counts_rejected_batches_without_panicking() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/v1/traces");
+            then.status(503).body("unavailable");
+        });
+        let exporter = OtlpHttpSpanExporter::new(OtlpTraceExporterConfig::new(server.base_url()))
+            .expect("exporter");
+        exporter.export(sample_span("chat gpt"));
+
+        let error = exporter
+            .flush()
+            .await
+            .expect_err("collector rejects export");
+        assert!(error.to_string().contains("503"));
+        let counters = exporter.counters();
+        assert_eq!(counters.failed_exports, 1);
+        assert_eq!(counters.dropped_spans, 1);
+        assert_eq!(counters.exported_spans, 0);
+    }
+}
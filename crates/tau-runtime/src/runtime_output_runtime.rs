@@ -187,6 +187,112 @@ pub fn event_to_json(event: &AgentEvent) -> serde_json::Value {
             "matched_rules": matched_rules,
             "reason_codes": reason_codes,
         }),
+        AgentEvent::StreamTextDelta { turn, delta } => serde_json::json!({
+            "type": "stream_text_delta",
+            "turn": turn,
+            "delta": delta,
+        }),
+        AgentEvent::StreamReasoningDelta { turn, delta } => serde_json::json!({
+            "type": "stream_reasoning_delta",
+            "turn": turn,
+            "delta": delta,
+        }),
+        AgentEvent::StreamToolCallStart {
+            turn,
+            index,
+            tool_call_id,
+            tool_name,
+        } => serde_json::json!({
+            "type": "stream_tool_call_start",
+            "turn": turn,
+            "index": index,
+            "tool_call_id": tool_call_id,
+            "tool_name": tool_name,
+        }),
+        AgentEvent::StreamToolCallArgumentsDelta {
+            turn,
+            index,
+            tool_call_id,
+            delta,
+        } => serde_json::json!({
+            "type": "stream_tool_call_arguments_delta",
+            "turn": turn,
+            "index": index,
+            "tool_call_id": tool_call_id,
+            "delta": delta,
+        }),
+        AgentEvent::StreamToolCallEnd {
+            turn,
+            index,
+            tool_call_id,
+            tool_name,
+            arguments,
+        } => serde_json::json!({
+            "type": "stream_tool_call_end",
+            "turn": turn,
+            "index": index,
+            "tool_call_id": tool_call_id,
+            "tool_name": tool_name,
+            "arguments": arguments,
+        }),
+        AgentEvent::StreamUsage { turn, usage } => serde_json::json!({
+            "type": "stream_usage",
+            "turn": turn,
+            "usage": usage,
+        }),
+        AgentEvent::StreamStop {
+            turn,
+            finish_reason,
+        } => serde_json::json!({
+            "type": "stream_stop",
+            "turn": turn,
+            "finish_reason": finish_reason,
+        }),
+        AgentEvent::PlanStepStarted { plan_id, step_id } => serde_json::json!({
+            "type": "plan_step_started",
+            "plan_id": plan_id,
+            "step_id": step_id,
+        }),
+        AgentEvent::PlanStepCompleted {
+            plan_id,
+            step_id,
+            new_messages,
+        } => serde_json::json!({
+            "type": "plan_step_completed",
+            "plan_id": plan_id,
+            "step_id": step_id,
+            "new_messages": new_messages,
+        }),
+        AgentEvent::PlanStepFailed {
+            plan_id,
+            step_id,
+            reason,
+        } => serde_json::json!({
+            "type": "plan_step_failed",
+            "plan_id": plan_id,
+            "step_id": step_id,
+            "reason": reason,
+        }),
+        AgentEvent::PlanStepSkipped {
+            plan_id,
+            step_id,
+            reason,
+        } => serde_json::json!({
+            "type": "plan_step_skipped",
+            "plan_id": plan_id,
+            "step_id": step_id,
+            "reason": reason,
+        }),
+        AgentEvent::PlanRevised {
+            plan_id,
+            revision,
+            reason,
+        } => serde_json::json!({
+            "type": "plan_revised",
+            "plan_id": plan_id,
+            "revision": revision,
+            "reason": reason,
+        }),
         _ => serde_json::json!({ "type": "unknown_event" }),
     }
 }
@@ -242,6 +348,37 @@ mod tests {
         assert_eq!(value["content"]["ok"], true);
     }
 
+    #[test]
+    fn unit_event_to_json_maps_stream_tool_call_events() {
+        let start = event_to_json(&AgentEvent::StreamToolCallStart {
+            turn: 1,
+            index: 0,
+            tool_call_id: "call-1".to_string(),
+            tool_name: "read".to_string(),
+        });
+        assert_eq!(start["type"], "stream_tool_call_start");
+        assert_eq!(start["tool_name"], "read");
+
+        let delta = event_to_json(&AgentEvent::StreamToolCallArgumentsDelta {
+            turn: 1,
+            index: 0,
+            tool_call_id: "call-1".to_string(),
+            delta: "{\"path\":".to_string(),
+        });
+        assert_eq!(delta["type"], "stream_tool_call_arguments_delta");
+        assert_eq!(delta["delta"], "{\"path\":");
+
+        let end = event_to_json(&AgentEvent::StreamToolCallEnd {
+            turn: 1,
+            index: 0,
+            tool_call_id: "call-1".to_string(),
+            tool_name: "read".to_string(),
+            arguments: serde_json::json!({ "path": "README.md" }),
+        });
+        assert_eq!(end["type"], "stream_tool_call_end");
+        assert_eq!(end["arguments"]["path"], "README.md");
+    }
+
     #[test]
     fn unit_event_to_json_maps_replan_triggered_shape() {
         let event = AgentEvent::ReplanTriggered {
@@ -290,6 +427,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unit_event_to_json_maps_plan_step_failed_shape() {
+        let event = AgentEvent::PlanStepFailed {
+            plan_id: "plan-1".to_string(),
+            step_id: "build".to_string(),
+            reason: "compile error".to_string(),
+        };
+        let value = event_to_json(&event);
+        assert_eq!(value["type"], "plan_step_failed");
+        assert_eq!(value["plan_id"], "plan-1");
+        assert_eq!(value["step_id"], "build");
+        assert_eq!(value["reason"], "compile error");
+    }
+
     #[test]
     fn unit_print_assistant_messages_stream_fallback_avoids_blocking_delay() {
         let started = Instant::now();
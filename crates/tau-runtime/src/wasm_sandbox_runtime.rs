@@ -1,23 +1,43 @@
-//! Wasmtime-backed sandbox runtime for extension and generated tool execution.
+//! Wasmtime-backed sandbox runtime for extension, skill, and generated tool
+//! execution.
 //!
 //! Provides a deny-by-default capability contract with memory/fuel/timeout
-//! enforcement and structured reason-code diagnostics.
+//! enforcement and structured reason-code diagnostics. Modules are linked
+//! against WASI preview1 (preopens derived from the filesystem mode) and the
+//! capability-gated `tau_host` import module.
 
 use std::{
     fmt,
     path::PathBuf,
-    sync::mpsc::{self, RecvTimeoutError},
-    time::Duration,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use wasmparser::{Parser, Payload};
 use wasmtime::{Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{preview1::WasiP1Ctx, DirPerms, FilePerms, WasiCtxBuilder};
+
+use crate::ssrf_guard::SsrfProtectionConfig;
+
+mod host_api;
+
+use host_api::{add_host_functions_to_linker, WasmHostState};
 
 const WASM_PAGE_SIZE_BYTES: u64 = 65_536;
 const WASM_MEMORY_EXPORT_NAME: &str = "memory";
 const WASM_ALLOC_EXPORT_NAME: &str = "tau_extension_alloc";
 const WASM_INVOKE_EXPORT_NAME: &str = "tau_extension_invoke";
+const WASM_INITIALIZE_EXPORT_NAME: &str = "_initialize";
+
+/// Guest path where the host filesystem root is preopened for WASI modules.
+pub const WASM_SANDBOX_WASI_PREOPEN_GUEST_PATH: &str = "/workspace";
+/// Import module name for the capability-gated host function set.
+pub const WASM_SANDBOX_HOST_MODULE_NAME: &str = "tau_host";
 
 /// Default fuel budget for wasm sandbox execution.
 pub const WASM_SANDBOX_FUEL_LIMIT_DEFAULT: u64 = 2_000_000;
@@ -49,6 +69,8 @@ pub struct WasmSandboxCapabilityProfile {
     pub filesystem_mode: WasmSandboxFilesystemMode,
     pub network_mode: WasmSandboxNetworkMode,
     pub env_allowlist: Vec<String>,
+    #[serde(default)]
+    pub host_functions: Vec<WasmSandboxHostFunction>,
 }
 
 impl Default for WasmSandboxCapabilityProfile {
@@ -57,10 +79,64 @@ impl Default for WasmSandboxCapabilityProfile {
             filesystem_mode: WasmSandboxFilesystemMode::Deny,
             network_mode: WasmSandboxNetworkMode::Deny,
             env_allowlist: Vec::new(),
+            host_functions: Vec::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// Enumerates `tau_host` functions a capability profile can grant to a guest.
+pub enum WasmSandboxHostFunction {
+    Log,
+    KvGet,
+    KvSet,
+    HttpFetch,
+    MemorySearch,
+}
+
+impl WasmSandboxHostFunction {
+    /// Returns the `tau_host` import name for this host function.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Log => "log",
+            Self::KvGet => "kv_get",
+            Self::KvSet => "kv_set",
+            Self::HttpFetch => "http_fetch",
+            Self::MemorySearch => "memory_search",
+        }
+    }
+}
+
+/// Memory search backend exposed to guests through `tau_host.memory_search`.
+pub trait WasmSandboxMemorySearch: Send + Sync {
+    /// Returns up to `limit` JSON-serializable matches for `query`.
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<Value>, String>;
+}
+
+#[derive(Clone, Default)]
+/// Host-side resources backing filesystem preopens and `tau_host` functions.
+pub struct WasmSandboxHostContext {
+    /// Host directory preopened at [`WASM_SANDBOX_WASI_PREOPEN_GUEST_PATH`].
+    pub filesystem_root: Option<PathBuf>,
+    /// JSON file backing `kv_get`/`kv_set`.
+    pub kv_store_path: Option<PathBuf>,
+    /// SSRF policy applied to every `http_fetch` destination.
+    pub ssrf_protection: SsrfProtectionConfig,
+    /// Backend for `memory_search`.
+    pub memory_search: Option<Arc<dyn WasmSandboxMemorySearch>>,
+}
+
+impl fmt::Debug for WasmSandboxHostContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmSandboxHostContext")
+            .field("filesystem_root", &self.filesystem_root)
+            .field("kv_store_path", &self.kv_store_path)
+            .field("ssrf_protection", &self.ssrf_protection)
+            .field("memory_search", &self.memory_search.is_some())
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 /// Runtime limits applied to a sandboxed wasm invocation.
 pub struct WasmSandboxLimits {
@@ -149,16 +225,26 @@ impl fmt::Display for WasmSandboxError {
 
 impl std::error::Error for WasmSandboxError {}
 
-#[derive(Debug)]
 struct WasmStoreState {
     limits: StoreLimits,
+    wasi: WasiP1Ctx,
+    host: WasmHostState,
 }
 
 /// Executes a wasm module inside a fuel- and memory-bounded wasmtime sandbox.
 pub async fn execute_wasm_sandbox(
     request: WasmSandboxExecutionRequest,
 ) -> Result<WasmSandboxExecutionReport, WasmSandboxError> {
-    let join = tokio::task::spawn_blocking(move || execute_wasm_sandbox_sync(request));
+    execute_wasm_sandbox_with_host(request, WasmSandboxHostContext::default()).await
+}
+
+/// Executes a wasm module with host resources backing preopens and `tau_host` calls.
+pub async fn execute_wasm_sandbox_with_host(
+    request: WasmSandboxExecutionRequest,
+    host: WasmSandboxHostContext,
+) -> Result<WasmSandboxExecutionReport, WasmSandboxError> {
+    let join =
+        tokio::task::spawn_blocking(move || execute_wasm_sandbox_with_host_sync(request, host));
     join.await.map_err(|error| {
         WasmSandboxError::new(
             "wasm_execution_join_error",
@@ -171,7 +257,15 @@ pub async fn execute_wasm_sandbox(
 pub fn execute_wasm_sandbox_sync(
     request: WasmSandboxExecutionRequest,
 ) -> Result<WasmSandboxExecutionReport, WasmSandboxError> {
-    validate_capability_profile(&request.capabilities)?;
+    execute_wasm_sandbox_with_host_sync(request, WasmSandboxHostContext::default())
+}
+
+/// Blocking variant of [`execute_wasm_sandbox_with_host`].
+pub fn execute_wasm_sandbox_with_host_sync(
+    request: WasmSandboxExecutionRequest,
+    host: WasmSandboxHostContext,
+) -> Result<WasmSandboxExecutionReport, WasmSandboxError> {
+    validate_capability_profile(&request.capabilities, &host)?;
     validate_limits(&request.limits)?;
 
     let timeout_ms = request.limits.timeout_ms.max(1);
@@ -179,7 +273,7 @@ pub fn execute_wasm_sandbox_sync(
     std::thread::Builder::new()
         .name("tau-wasm-sandbox".to_string())
         .spawn(move || {
-            let _ = sender.send(execute_wasm_sandbox_blocking(request));
+            let _ = sender.send(execute_wasm_sandbox_blocking(request, host));
         })
         .map_err(|error| {
             WasmSandboxError::new(
@@ -203,34 +297,135 @@ pub fn execute_wasm_sandbox_sync(
 
 fn validate_capability_profile(
     capabilities: &WasmSandboxCapabilityProfile,
+    host: &WasmSandboxHostContext,
 ) -> Result<(), WasmSandboxError> {
     match capabilities.filesystem_mode {
         WasmSandboxFilesystemMode::Deny => {}
         WasmSandboxFilesystemMode::ReadOnly | WasmSandboxFilesystemMode::ReadWrite => {
-            return Err(WasmSandboxError::new(
-                "wasm_capability_filesystem_unsupported",
-                "filesystem capabilities are not available in this wasm sandbox runtime",
-            ));
+            let Some(root) = host.filesystem_root.as_ref() else {
+                return Err(WasmSandboxError::new(
+                    "wasm_capability_filesystem_unsupported",
+                    "filesystem capabilities require a host filesystem root to preopen",
+                ));
+            };
+            if !root.is_dir() {
+                return Err(WasmSandboxError::new(
+                    "wasm_capability_filesystem_root_invalid",
+                    format!(
+                        "wasm filesystem root is not a directory: {}",
+                        root.display()
+                    ),
+                ));
+            }
         }
     }
 
-    if matches!(capabilities.network_mode, WasmSandboxNetworkMode::Allow) {
+    if capabilities
+        .host_functions
+        .contains(&WasmSandboxHostFunction::HttpFetch)
+        && matches!(capabilities.network_mode, WasmSandboxNetworkMode::Deny)
+    {
+        return Err(WasmSandboxError::new(
+            "wasm_capability_network_denied",
+            "host function 'http_fetch' requires network_mode Allow",
+        ));
+    }
+
+    // WASI sockets are never granted; `http_fetch` is the only network path.
+    if matches!(capabilities.network_mode, WasmSandboxNetworkMode::Allow)
+        && !capabilities
+            .host_functions
+            .contains(&WasmSandboxHostFunction::HttpFetch)
+    {
         return Err(WasmSandboxError::new(
             "wasm_capability_network_unsupported",
-            "network capabilities are not available in this wasm sandbox runtime",
+            "network_mode Allow requires the 'http_fetch' host function",
         ));
     }
 
-    if !capabilities.env_allowlist.is_empty() {
+    if let Some(name) = capabilities
+        .env_allowlist
+        .iter()
+        .find(|name| name.trim().is_empty() || name.contains('='))
+    {
         return Err(WasmSandboxError::new(
-            "wasm_capability_env_unsupported",
-            "environment allowlist is not available in this wasm sandbox runtime",
+            "wasm_capability_env_invalid",
+            format!("environment allowlist entry '{name}' is not a valid variable name"),
         ));
     }
 
     Ok(())
 }
 
+fn build_wasi_context(
+    capabilities: &WasmSandboxCapabilityProfile,
+    host: &WasmSandboxHostContext,
+) -> Result<WasiP1Ctx, WasmSandboxError> {
+    let mut builder = WasiCtxBuilder::new();
+    for name in &capabilities.env_allowlist {
+        if let Ok(value) = std::env::var(name) {
+            builder.env(name, value);
+        }
+    }
+    let permissions = match capabilities.filesystem_mode {
+        WasmSandboxFilesystemMode::Deny => None,
+        WasmSandboxFilesystemMode::ReadOnly => Some((DirPerms::READ, FilePerms::READ)),
+        WasmSandboxFilesystemMode::ReadWrite => Some((
+            DirPerms::READ | DirPerms::MUTATE,
+            FilePerms::READ | FilePerms::WRITE,
+        )),
+    };
+    if let (Some((dir_perms, file_perms)), Some(root)) =
+        (permissions, host.filesystem_root.as_ref())
+    {
+        builder
+            .preopened_dir(
+                root,
+                WASM_SANDBOX_WASI_PREOPEN_GUEST_PATH,
+                dir_perms,
+                file_perms,
+            )
+            .map_err(|error| {
+                WasmSandboxError::new(
+                    "wasm_wasi_preopen_failed",
+                    format!(
+                        "failed to preopen wasm filesystem root '{}': {error}",
+                        root.display()
+                    ),
+                )
+            })?;
+    }
+    Ok(builder.build_p1())
+}
+
+fn capability_diagnostic(capabilities: &WasmSandboxCapabilityProfile) -> String {
+    let filesystem = match capabilities.filesystem_mode {
+        WasmSandboxFilesystemMode::Deny => "deny-filesystem",
+        WasmSandboxFilesystemMode::ReadOnly => "read-only-filesystem",
+        WasmSandboxFilesystemMode::ReadWrite => "read-write-filesystem",
+    };
+    let network = match capabilities.network_mode {
+        WasmSandboxNetworkMode::Deny => "deny-network",
+        WasmSandboxNetworkMode::Allow => "allow-network",
+    };
+    let env = if capabilities.env_allowlist.is_empty() {
+        "deny-env".to_string()
+    } else {
+        format!("env={}", capabilities.env_allowlist.join("+"))
+    };
+    let mut diagnostic = format!("capabilities={filesystem},{network},{env}");
+    if !capabilities.host_functions.is_empty() {
+        let host_functions = capabilities
+            .host_functions
+            .iter()
+            .map(|function| function.as_str())
+            .collect::<Vec<_>>()
+            .join("+");
+        diagnostic.push_str(&format!(",host={host_functions}"));
+    }
+    diagnostic
+}
+
 fn validate_limits(limits: &WasmSandboxLimits) -> Result<(), WasmSandboxError> {
     if limits.fuel_limit == 0 {
         return Err(WasmSandboxError::new(
@@ -261,6 +456,7 @@ fn validate_limits(limits: &WasmSandboxLimits) -> Result<(), WasmSandboxError> {
 
 fn execute_wasm_sandbox_blocking(
     request: WasmSandboxExecutionRequest,
+    host: WasmSandboxHostContext,
 ) -> Result<WasmSandboxExecutionReport, WasmSandboxError> {
     if !request.module_path.exists() {
         return Err(WasmSandboxError::new(
@@ -310,12 +506,21 @@ fn execute_wasm_sandbox_blocking(
         )
     })?;
 
+    let wasi = build_wasi_context(&request.capabilities, &host)?;
+    let deadline = Instant::now() + Duration::from_millis(request.limits.timeout_ms.max(1));
     let mut store = Store::new(
         &engine,
         WasmStoreState {
             limits: StoreLimitsBuilder::new()
                 .memory_size(request.limits.memory_limit_bytes as usize)
                 .build(),
+            wasi,
+            host: WasmHostState::new(
+                host,
+                request.capabilities.host_functions.clone(),
+                deadline,
+                request.limits.max_response_bytes,
+            ),
         },
     );
     store.limiter(|state| &mut state.limits);
@@ -326,13 +531,31 @@ fn execute_wasm_sandbox_blocking(
         )
     })?;
 
-    let linker = Linker::<WasmStoreState>::new(&engine);
+    let mut linker = Linker::<WasmStoreState>::new(&engine);
+    wasmtime_wasi::preview1::add_to_linker_sync(&mut linker, |state| &mut state.wasi)
+        .and_then(|_| add_host_functions_to_linker(&mut linker))
+        .map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_linker_init_failed",
+                format!("failed to link wasm host imports: {error}"),
+            )
+        })?;
     let instance = linker.instantiate(&mut store, &module).map_err(|error| {
         WasmSandboxError::new(
             "wasm_instance_init_failed",
             format!("failed to instantiate wasm module: {error}"),
         )
     })?;
+    if let Ok(initialize) =
+        instance.get_typed_func::<(), ()>(&mut store, WASM_INITIALIZE_EXPORT_NAME)
+    {
+        initialize.call(&mut store, ()).map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_initialize_trap",
+                format!("wasm reactor initializer trapped: {error}"),
+            )
+        })?;
+    }
 
     let memory = instance
         .get_memory(&mut store, WASM_MEMORY_EXPORT_NAME)
@@ -457,19 +680,21 @@ fn execute_wasm_sandbox_blocking(
     }
     let remaining_fuel = store.get_fuel().unwrap_or_default();
     let fuel_consumed = request.limits.fuel_limit.saturating_sub(remaining_fuel);
+    let mut diagnostics = vec![
+        format!(
+            "module={} fuel_consumed={} memory_limit_bytes={}",
+            request.module_path.display(),
+            fuel_consumed,
+            request.limits.memory_limit_bytes
+        ),
+        capability_diagnostic(&request.capabilities),
+    ];
+    diagnostics.extend(store.data_mut().host.take_guest_logs());
     Ok(WasmSandboxExecutionReport {
         response_json,
         fuel_consumed,
         reason_codes: vec!["wasm_execution_succeeded".to_string()],
-        diagnostics: vec![
-            format!(
-                "module={} fuel_consumed={} memory_limit_bytes={}",
-                request.module_path.display(),
-                fuel_consumed,
-                request.limits.memory_limit_bytes
-            ),
-            "capabilities=deny-filesystem,deny-network,deny-env".to_string(),
-        ],
+        diagnostics,
         limits: request.limits,
         capabilities: request.capabilities,
     })
@@ -554,10 +779,18 @@ fn validate_memory_range(
 
 #[cfg(test)]
 mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use httpmock::prelude::*;
+    use serde_json::{json, Value};
+
     use super::{
-        execute_wasm_sandbox, WasmSandboxCapabilityProfile, WasmSandboxError,
-        WasmSandboxExecutionRequest, WasmSandboxFilesystemMode, WasmSandboxLimits,
+        execute_wasm_sandbox, execute_wasm_sandbox_with_host, WasmSandboxCapabilityProfile,
+        WasmSandboxError, WasmSandboxExecutionRequest, WasmSandboxFilesystemMode,
+        WasmSandboxHostContext, WasmSandboxHostFunction, WasmSandboxLimits,
+        WasmSandboxMemorySearch, WasmSandboxNetworkMode,
     };
+    use crate::ssrf_guard::SsrfProtectionConfig;
     use tempfile::tempdir;
 
     fn write_wasm(path: &std::path::Path, wat_source: &str) {
@@ -584,11 +817,340 @@ mod tests {
 )"#
     }
 
+    fn host_call_module_wat(function: &str, request_json: &str) -> String {
+        format!(
+            r#"(module
+  (import "tau_host" "{function}" (func $host (param i32 i32) (result i64)))
+  (memory (export "memory") 1)
+  (global $heap (mut i32) (i32.const 4096))
+  (data (i32.const 0) "{data}")
+  (func (export "tau_extension_alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    global.get $heap
+    local.set $ptr
+    global.get $heap
+    local.get $len
+    i32.add
+    global.set $heap
+    local.get $ptr)
+  (func (export "tau_extension_invoke") (param i32 i32) (result i64)
+    (call $host (i32.const 0) (i32.const {len})))
+)"#,
+            data = request_json.replace('\\', "\\\\").replace('"', "\\\""),
+            len = request_json.len(),
+        )
+    }
+
+    fn wasi_preopen_probe_wat() -> &'static str {
+        r#"(module
+  (import "wasi_snapshot_preview1" "fd_prestat_get" (func $prestat (param i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{\"preopen\":true}")
+  (data (i32.const 32) "{\"preopen\":false}")
+  (func (export "tau_extension_alloc") (param i32) (result i32) i32.const 1024)
+  (func (export "tau_extension_invoke") (param i32 i32) (result i64)
+    (if (result i64) (i32.eqz (call $prestat (i32.const 3) (i32.const 512)))
+      (then (i64.const 16))
+      (else (i64.const 0x2000000011))))
+)"#
+    }
+
+    async fn run_module(
+        module_path: &Path,
+        capabilities: WasmSandboxCapabilityProfile,
+        host: WasmSandboxHostContext,
+    ) -> Result<super::WasmSandboxExecutionReport, WasmSandboxError> {
+        execute_wasm_sandbox_with_host(
+            WasmSandboxExecutionRequest {
+                module_path: module_path.to_path_buf(),
+                request_json: "{}".to_string(),
+                limits: WasmSandboxLimits::default(),
+                capabilities,
+            },
+            host,
+        )
+        .await
+    }
+
+    fn host_envelope(report: &super::WasmSandboxExecutionReport) -> Value {
+        serde_json::from_str(&report.response_json).expect("host envelope json")
+    }
+
+    struct StaticMemorySearch;
+
+    impl WasmSandboxMemorySearch for StaticMemorySearch {
+        fn search(&self, query: &str, limit: usize) -> Result<Vec<Value>, String> {
+            Ok((0..limit.min(2))
+                .map(|index| json!({"id": format!("mem-{index}"), "query": query}))
+                .collect())
+        }
+    }
+
     #[test]
     fn unit_wasm_sandbox_capability_profile_default_denies_all() {
         let profile = WasmSandboxCapabilityProfile::default();
         assert_eq!(profile.filesystem_mode, WasmSandboxFilesystemMode::Deny);
         assert!(profile.env_allowlist.is_empty());
+        assert!(profile.host_functions.is_empty());
+    }
+
+    #[test]
+    fn unit_wasm_sandbox_capability_profile_deserializes_without_host_functions() {
+        let profile: WasmSandboxCapabilityProfile = serde_json::from_value(json!({
+            "filesystem_mode": "ReadOnly",
+            "network_mode": "Deny",
+            "env_allowlist": [],
+        }))
+        .expect("legacy capability profile");
+        assert_eq!(profile.filesystem_mode, WasmSandboxFilesystemMode::ReadOnly);
+        assert!(profile.host_functions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn functional_execute_wasm_sandbox_preopens_filesystem_root_for_wasi() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("probe.wasm");
+        write_wasm(&module_path, wasi_preopen_probe_wat());
+        let root = temp.path().join("workspace");
+        std::fs::create_dir_all(&root).expect("create root");
+
+        let report = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile {
+                filesystem_mode: WasmSandboxFilesystemMode::ReadOnly,
+                ..WasmSandboxCapabilityProfile::default()
+            },
+            WasmSandboxHostContext {
+                filesystem_root: Some(root),
+                ..WasmSandboxHostContext::default()
+            },
+        )
+        .await
+        .expect("wasi module should run");
+
+        assert_eq!(report.response_json, "{\"preopen\":true}");
+        assert!(report
+            .diagnostics
+            .iter()
+            .any(|line| line.contains("read-only-filesystem")));
+    }
+
+    #[tokio::test]
+    async fn regression_execute_wasm_sandbox_denied_filesystem_has_no_preopens() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("probe.wasm");
+        write_wasm(&module_path, wasi_preopen_probe_wat());
+
+        let report = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile::default(),
+            WasmSandboxHostContext {
+                filesystem_root: Some(temp.path().to_path_buf()),
+                ..WasmSandboxHostContext::default()
+            },
+        )
+        .await
+        .expect("wasi module should run");
+
+        assert_eq!(report.response_json, "{\"preopen\":false}");
+    }
+
+    #[tokio::test]
+    async fn functional_execute_wasm_sandbox_kv_round_trips_across_invocations() {
+        let temp = tempdir().expect("tempdir");
+        let set_path = temp.path().join("kv-set.wasm");
+        let get_path = temp.path().join("kv-get.wasm");
+        write_wasm(
+            &set_path,
+            &host_call_module_wat("kv_set", r#"{"key":"color","value":"blue"}"#),
+        );
+        write_wasm(
+            &get_path,
+            &host_call_module_wat("kv_get", r#"{"key":"color"}"#),
+        );
+        let host = WasmSandboxHostContext {
+            kv_store_path: Some(temp.path().join("state/kv.json")),
+            ..WasmSandboxHostContext::default()
+        };
+        let capabilities = WasmSandboxCapabilityProfile {
+            host_functions: vec![
+                WasmSandboxHostFunction::KvGet,
+                WasmSandboxHostFunction::KvSet,
+            ],
+            ..WasmSandboxCapabilityProfile::default()
+        };
+
+        let set = run_module(&set_path, capabilities.clone(), host.clone())
+            .await
+            .expect("kv_set module");
+        assert_eq!(host_envelope(&set), json!({"ok": true, "value": null}));
+        let get = run_module(&get_path, capabilities, host)
+            .await
+            .expect("kv_get module");
+        assert_eq!(host_envelope(&get), json!({"ok": true, "value": "blue"}));
+    }
+
+    #[tokio::test]
+    async fn regression_execute_wasm_sandbox_denies_ungranted_host_function() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("kv-get.wasm");
+        write_wasm(
+            &module_path,
+            &host_call_module_wat("kv_get", r#"{"key":"color"}"#),
+        );
+
+        let report = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile::default(),
+            WasmSandboxHostContext {
+                kv_store_path: Some(temp.path().join("kv.json")),
+                ..WasmSandboxHostContext::default()
+            },
+        )
+        .await
+        .expect("denied host call should not trap");
+
+        let envelope = host_envelope(&report);
+        assert_eq!(envelope["ok"], false);
+        assert_eq!(envelope["reason_code"], "wasm_host_capability_denied");
+        assert!(!temp.path().join("kv.json").exists());
+    }
+
+    #[tokio::test]
+    async fn integration_execute_wasm_sandbox_http_fetch_routes_through_ssrf_guard() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/status");
+                then.status(200).body("all good");
+            })
+            .await;
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("fetch.wasm");
+        let request = json!({"url": server.url("/status")}).to_string();
+        write_wasm(&module_path, &host_call_module_wat("http_fetch", &request));
+        let capabilities = WasmSandboxCapabilityProfile {
+            network_mode: WasmSandboxNetworkMode::Allow,
+            host_functions: vec![WasmSandboxHostFunction::HttpFetch],
+            ..WasmSandboxCapabilityProfile::default()
+        };
+
+        let report = run_module(
+            &module_path,
+            capabilities.clone(),
+            WasmSandboxHostContext {
+                ssrf_protection: SsrfProtectionConfig {
+                    enabled: true,
+                    allow_http: true,
+                    allow_private_network: true,
+                },
+                ..WasmSandboxHostContext::default()
+            },
+        )
+        .await
+        .expect("http fetch module");
+        let envelope = host_envelope(&report);
+        assert_eq!(envelope["ok"], true);
+        assert_eq!(envelope["value"]["status"], 200);
+        assert_eq!(envelope["value"]["body"], "all good");
+        mock.assert_async().await;
+
+        let blocked = run_module(
+            &module_path,
+            capabilities,
+            WasmSandboxHostContext::default(),
+        )
+        .await
+        .expect("blocked fetch should not trap");
+        let envelope = host_envelope(&blocked);
+        assert_eq!(envelope["ok"], false);
+        assert_eq!(envelope["reason_code"], "delivery_ssrf_blocked_scheme");
+        assert_eq!(mock.calls_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn regression_execute_wasm_sandbox_rejects_http_fetch_without_network() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("ok.wasm");
+        write_wasm(&module_path, ok_module_wat());
+
+        let error = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile {
+                host_functions: vec![WasmSandboxHostFunction::HttpFetch],
+                ..WasmSandboxCapabilityProfile::default()
+            },
+            WasmSandboxHostContext::default(),
+        )
+        .await
+        .expect_err("http_fetch without network should fail closed");
+
+        assert_eq!(error.reason_code, "wasm_capability_network_denied");
+    }
+
+    #[tokio::test]
+    async fn functional_execute_wasm_sandbox_memory_search_uses_host_backend() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("search.wasm");
+        write_wasm(
+            &module_path,
+            &host_call_module_wat("memory_search", r#"{"query":"deploy notes","limit":5}"#),
+        );
+
+        let report = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile {
+                host_functions: vec![WasmSandboxHostFunction::MemorySearch],
+                ..WasmSandboxCapabilityProfile::default()
+            },
+            WasmSandboxHostContext {
+                memory_search: Some(Arc::new(StaticMemorySearch)),
+                ..WasmSandboxHostContext::default()
+            },
+        )
+        .await
+        .expect("memory search module");
+
+        let envelope = host_envelope(&report);
+        assert_eq!(envelope["ok"], true);
+        assert_eq!(envelope["value"][1]["id"], "mem-1");
+        assert_eq!(envelope["value"][0]["query"], "deploy notes");
+    }
+
+    #[tokio::test]
+    async fn functional_execute_wasm_sandbox_records_guest_logs_in_diagnostics() {
+        let temp = tempdir().expect("tempdir");
+        let module_path = temp.path().join("log.wasm");
+        write_wasm(
+            &module_path,
+            r#"(module
+  (import "tau_host" "log" (func $log (param i32 i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "cache warmed")
+  (data (i32.const 256) "{\"ok\":true}")
+  (func (export "tau_extension_alloc") (param i32) (result i32) i32.const 1024)
+  (func (export "tau_extension_invoke") (param i32 i32) (result i64)
+    (drop (call $log (i32.const 1) (i32.const 0) (i32.const 12)))
+    i64.const 0x1000000000b)
+)"#,
+        );
+
+        let report = run_module(
+            &module_path,
+            WasmSandboxCapabilityProfile {
+                host_functions: vec![WasmSandboxHostFunction::Log],
+                ..WasmSandboxCapabilityProfile::default()
+            },
+            WasmSandboxHostContext::default(),
+        )
+        .await
+        .expect("log module");
+
+        assert_eq!(report.response_json, "{\"ok\":true}");
+        assert!(report
+            .diagnostics
+            .iter()
+            .any(|line| line == "guest_log level=info message=cache warmed"));
     }
 
     #[tokio::test]
@@ -0,0 +1,494 @@
+//! `tau_host` import module linked into every wasm sandbox instance.
+//!
+//! Data-returning host calls take a JSON request buffer and answer with a JSON
+//! envelope written into guest memory through `tau_extension_alloc`:
+//! `{"ok":true,"value":...}` or `{"ok":false,"reason_code":"...","error":"..."}`.
+//! Functions not granted by the capability profile answer with
+//! `wasm_host_capability_denied` instead of trapping, so guests can degrade.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use wasmtime::{Caller, Extern, Linker, Memory};
+
+use super::{
+    WasmSandboxError, WasmSandboxHostContext, WasmSandboxHostFunction, WasmStoreState,
+    WASM_ALLOC_EXPORT_NAME, WASM_MEMORY_EXPORT_NAME, WASM_SANDBOX_HOST_MODULE_NAME,
+};
+use crate::ssrf_guard::SsrfGuard;
+
+const WASM_HOST_REQUEST_MAX_BYTES: usize = 1024 * 1024;
+const WASM_HOST_LOG_MAX_ENTRIES: usize = 64;
+const WASM_HOST_LOG_MAX_CHARS: usize = 512;
+const WASM_HOST_KV_MAX_KEY_BYTES: usize = 256;
+const WASM_HOST_KV_MAX_VALUE_BYTES: usize = 64 * 1024;
+const WASM_HOST_KV_MAX_ENTRIES: usize = 1_024;
+const WASM_HOST_MEMORY_SEARCH_DEFAULT_LIMIT: usize = 5;
+const WASM_HOST_MEMORY_SEARCH_MAX_LIMIT: usize = 50;
+
+type HostCallHandler = fn(&WasmHostState, &[u8]) -> Result<Value, WasmSandboxError>;
+
+/// Per-invocation host state backing `tau_host` calls.
+pub(super) struct WasmHostState {
+    context: WasmSandboxHostContext,
+    host_functions: Vec<WasmSandboxHostFunction>,
+    deadline: Instant,
+    max_response_bytes: usize,
+    guest_logs: Vec<String>,
+    dropped_guest_logs: usize,
+}
+
+impl WasmHostState {
+    pub(super) fn new(
+        context: WasmSandboxHostContext,
+        host_functions: Vec<WasmSandboxHostFunction>,
+        deadline: Instant,
+        max_response_bytes: usize,
+    ) -> Self {
+        Self {
+            context,
+            host_functions,
+            deadline,
+            max_response_bytes,
+            guest_logs: Vec::new(),
+            dropped_guest_logs: 0,
+        }
+    }
+
+    fn allows(&self, function: WasmSandboxHostFunction) -> bool {
+        self.host_functions.contains(&function)
+    }
+
+    fn record_guest_log(&mut self, level: i32, message: &str) {
+        if self.guest_logs.len() >= WASM_HOST_LOG_MAX_ENTRIES {
+            self.dropped_guest_logs = self.dropped_guest_logs.saturating_add(1);
+            return;
+        }
+        let level = match level {
+            0 => "debug",
+            1 => "info",
+            2 => "warn",
+            _ => "error",
+        };
+        let message = message
+            .chars()
+            .take(WASM_HOST_LOG_MAX_CHARS)
+            .collect::<String>();
+        self.guest_logs.push(format!(
+            "guest_log level={level} message={}",
+            message.trim()
+        ));
+    }
+
+    /// Drains guest log lines for inclusion in execution diagnostics.
+    pub(super) fn take_guest_logs(&mut self) -> Vec<String> {
+        let mut logs = std::mem::take(&mut self.guest_logs);
+        if self.dropped_guest_logs > 0 {
+            logs.push(format!(
+                "guest_log_dropped count={}",
+                self.dropped_guest_logs
+            ));
+            self.dropped_guest_logs = 0;
+        }
+        logs
+    }
+}
+
+/// Registers every `tau_host` function; capability checks happen per call.
+pub(super) fn add_host_functions_to_linker(
+    linker: &mut Linker<WasmStoreState>,
+) -> wasmtime::Result<()> {
+    linker.func_wrap(
+        WASM_SANDBOX_HOST_MODULE_NAME,
+        WasmSandboxHostFunction::Log.as_str(),
+        |mut caller: Caller<'_, WasmStoreState>,
+         level: i32,
+         ptr: i32,
+         len: i32|
+         -> wasmtime::Result<i32> {
+            if !caller.data().host.allows(WasmSandboxHostFunction::Log) {
+                return Ok(-1);
+            }
+            let bytes = read_guest_bytes(&mut caller, ptr, len)?;
+            let message = String::from_utf8_lossy(&bytes).into_owned();
+            caller.data_mut().host.record_guest_log(level, &message);
+            Ok(0)
+        },
+    )?;
+    let handlers: [(WasmSandboxHostFunction, HostCallHandler); 4] = [
+        (WasmSandboxHostFunction::KvGet, host_kv_get),
+        (WasmSandboxHostFunction::KvSet, host_kv_set),
+        (WasmSandboxHostFunction::HttpFetch, host_http_fetch),
+        (WasmSandboxHostFunction::MemorySearch, host_memory_search),
+    ];
+    for (function, handler) in handlers {
+        linker.func_wrap(
+            WASM_SANDBOX_HOST_MODULE_NAME,
+            function.as_str(),
+            move |mut caller: Caller<'_, WasmStoreState>,
+                  ptr: i32,
+                  len: i32|
+                  -> wasmtime::Result<i64> {
+                dispatch_host_call(&mut caller, function, handler, ptr, len)
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn dispatch_host_call(
+    caller: &mut Caller<'_, WasmStoreState>,
+    function: WasmSandboxHostFunction,
+    handler: HostCallHandler,
+    ptr: i32,
+    len: i32,
+) -> wasmtime::Result<i64> {
+    let envelope = if caller.data().host.allows(function) {
+        let request = read_guest_bytes(caller, ptr, len)?;
+        match handler(&caller.data().host, &request) {
+            Ok(value) => json!({ "ok": true, "value": value }),
+            Err(error) => host_error_envelope(&error),
+        }
+    } else {
+        host_error_envelope(&WasmSandboxError::new(
+            "wasm_host_capability_denied",
+            format!(
+                "host function '{}' is not granted by the capability profile",
+                function.as_str()
+            ),
+        ))
+    };
+    let mut bytes = serde_json::to_vec(&envelope)?;
+    if bytes.len() > caller.data().host.max_response_bytes {
+        bytes = serde_json::to_vec(&host_error_envelope(&WasmSandboxError::new(
+            "wasm_host_response_too_large",
+            format!(
+                "host response length {} exceeds limit {}",
+                bytes.len(),
+                caller.data().host.max_response_bytes
+            ),
+        )))?;
+    }
+    write_guest_bytes(caller, &bytes)
+}
+
+fn host_error_envelope(error: &WasmSandboxError) -> Value {
+    json!({
+        "ok": false,
+        "reason_code": error.reason_code,
+        "error": error.message,
+    })
+}
+
+fn guest_memory(caller: &mut Caller<'_, WasmStoreState>) -> wasmtime::Result<Memory> {
+    caller
+        .get_export(WASM_MEMORY_EXPORT_NAME)
+        .and_then(Extern::into_memory)
+        .ok_or_else(|| {
+            wasmtime::Error::msg(format!(
+                "wasm module missing required memory export '{WASM_MEMORY_EXPORT_NAME}'"
+            ))
+        })
+}
+
+fn read_guest_bytes(
+    caller: &mut Caller<'_, WasmStoreState>,
+    ptr: i32,
+    len: i32,
+) -> wasmtime::Result<Vec<u8>> {
+    let (Ok(offset), Ok(len)) = (usize::try_from(ptr), usize::try_from(len)) else {
+        return Err(wasmtime::Error::msg(
+            "host call received a negative guest buffer pointer or length",
+        ));
+    };
+    if len > WASM_HOST_REQUEST_MAX_BYTES {
+        return Err(wasmtime::Error::msg(format!(
+            "host call request length {len} exceeds limit {WASM_HOST_REQUEST_MAX_BYTES}"
+        )));
+    }
+    let memory = guest_memory(caller)?;
+    let mut bytes = vec![0u8; len];
+    memory.read(&*caller, offset, &mut bytes)?;
+    Ok(bytes)
+}
+
+fn write_guest_bytes(
+    caller: &mut Caller<'_, WasmStoreState>,
+    bytes: &[u8],
+) -> wasmtime::Result<i64> {
+    let len = i32::try_from(bytes.len())?;
+    let alloc = caller
+        .get_export(WASM_ALLOC_EXPORT_NAME)
+        .and_then(Extern::into_func)
+        .ok_or_else(|| {
+            wasmtime::Error::msg(format!(
+                "wasm module missing required alloc export '{WASM_ALLOC_EXPORT_NAME}'"
+            ))
+        })?
+        .typed::<i32, i32>(&*caller)?;
+    let ptr = alloc.call(&mut *caller, len)?;
+    let offset = u32::try_from(ptr)
+        .map_err(|_| wasmtime::Error::msg("wasm alloc export returned a negative pointer"))?;
+    let memory = guest_memory(caller)?;
+    memory.write(&mut *caller, offset as usize, bytes)?;
+    Ok(((u64::from(offset) << 32) | bytes.len() as u64) as i64)
+}
+
+fn parse_host_request<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, WasmSandboxError> {
+    serde_json::from_slice(bytes).map_err(|error| {
+        WasmSandboxError::new(
+            "wasm_host_request_invalid",
+            format!("host call request is not valid JSON for this function: {error}"),
+        )
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct KvGetRequest {
+    key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KvSetRequest {
+    key: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+fn host_kv_get(host: &WasmHostState, bytes: &[u8]) -> Result<Value, WasmSandboxError> {
+    let request = parse_host_request::<KvGetRequest>(bytes)?;
+    validate_kv_key(&request.key)?;
+    let store = load_kv_store(kv_store_path(host)?)?;
+    Ok(store
+        .get(&request.key)
+        .cloned()
+        .map_or(Value::Null, Value::String))
+}
+
+fn host_kv_set(host: &WasmHostState, bytes: &[u8]) -> Result<Value, WasmSandboxError> {
+    let request = parse_host_request::<KvSetRequest>(bytes)?;
+    validate_kv_key(&request.key)?;
+    let path = kv_store_path(host)?;
+    let mut store = load_kv_store(path)?;
+    match request.value {
+        Some(value) => {
+            if value.len() > WASM_HOST_KV_MAX_VALUE_BYTES {
+                return Err(WasmSandboxError::new(
+                    "wasm_host_kv_value_too_large",
+                    format!(
+                        "kv value length {} exceeds limit {}",
+                        value.len(),
+                        WASM_HOST_KV_MAX_VALUE_BYTES
+                    ),
+                ));
+            }
+            if !store.contains_key(&request.key) && store.len() >= WASM_HOST_KV_MAX_ENTRIES {
+                return Err(WasmSandboxError::new(
+                    "wasm_host_kv_full",
+                    format!("kv store already holds {WASM_HOST_KV_MAX_ENTRIES} entries"),
+                ));
+            }
+            store.insert(request.key, value);
+        }
+        None => {
+            store.remove(&request.key);
+        }
+    }
+    save_kv_store(path, &store)?;
+    Ok(Value::Null)
+}
+
+fn kv_store_path(host: &WasmHostState) -> Result<&Path, WasmSandboxError> {
+    host.context.kv_store_path.as_deref().ok_or_else(|| {
+        WasmSandboxError::new(
+            "wasm_host_kv_unavailable",
+            "no kv store is configured for this wasm sandbox",
+        )
+    })
+}
+
+fn validate_kv_key(key: &str) -> Result<(), WasmSandboxError> {
+    if key.trim().is_empty() || key.len() > WASM_HOST_KV_MAX_KEY_BYTES {
+        return Err(WasmSandboxError::new(
+            "wasm_host_kv_key_invalid",
+            format!("kv key must be non-empty and at most {WASM_HOST_KV_MAX_KEY_BYTES} bytes"),
+        ));
+    }
+    Ok(())
+}
+
+fn load_kv_store(path: &Path) -> Result<BTreeMap<String, String>, WasmSandboxError> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let raw = std::fs::read_to_string(path).map_err(|error| {
+        WasmSandboxError::new(
+            "wasm_host_kv_read_failed",
+            format!("failed to read kv store '{}': {error}", path.display()),
+        )
+    })?;
+    if raw.trim().is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    serde_json::from_str(&raw).map_err(|error| {
+        WasmSandboxError::new(
+            "wasm_host_kv_read_failed",
+            format!("failed to parse kv store '{}': {error}", path.display()),
+        )
+    })
+}
+
+fn save_kv_store(path: &Path, store: &BTreeMap<String, String>) -> Result<(), WasmSandboxError> {
+    let raw = serde_json::to_string_pretty(store).map_err(|error| {
+        WasmSandboxError::new(
+            "wasm_host_kv_write_failed",
+            format!("failed to serialize kv store: {error}"),
+        )
+    })?;
+    tau_core::write_text_atomic(path, &raw).map_err(|error| {
+        WasmSandboxError::new(
+            "wasm_host_kv_write_failed",
+            format!("failed to write kv store '{}': {error}", path.display()),
+        )
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct HttpFetchRequest {
+    #[serde(default = "default_http_method")]
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+fn default_http_method() -> String {
+    "GET".to_string()
+}
+
+fn host_http_fetch(host: &WasmHostState, bytes: &[u8]) -> Result<Value, WasmSandboxError> {
+    let request = parse_host_request::<HttpFetchRequest>(bytes)?;
+    let method = reqwest::Method::from_bytes(request.method.trim().to_ascii_uppercase().as_bytes())
+        .map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_host_http_method_invalid",
+                format!("invalid http method '{}': {error}", request.method),
+            )
+        })?;
+    let remaining = host.deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return Err(WasmSandboxError::new(
+            "wasm_host_http_timeout",
+            "wasm sandbox timeout budget exhausted before http fetch",
+        ));
+    }
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_host_http_runtime_failed",
+                format!("failed to start http fetch runtime: {error}"),
+            )
+        })?;
+    let guard = SsrfGuard::new(host.context.ssrf_protection);
+    let max_response_bytes = host.max_response_bytes;
+    runtime.block_on(async move {
+        let url = guard
+            .parse_and_validate_url(&request.url)
+            .await
+            .map_err(|violation| WasmSandboxError::new(&violation.reason_code, violation.detail))?;
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(remaining.max(Duration::from_millis(1)))
+            .build()
+            .map_err(|error| {
+                WasmSandboxError::new(
+                    "wasm_host_http_client_failed",
+                    format!("failed to build http client: {error}"),
+                )
+            })?;
+        let mut builder = client.request(method, url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let mut response = builder.send().await.map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_host_http_request_failed",
+                format!("http fetch failed: {error}"),
+            )
+        })?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_string(), value.to_string()))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|error| {
+            WasmSandboxError::new(
+                "wasm_host_http_request_failed",
+                format!("failed to read http response body: {error}"),
+            )
+        })? {
+            if body.len() + chunk.len() > max_response_bytes {
+                return Err(WasmSandboxError::new(
+                    "wasm_host_http_response_too_large",
+                    format!("http response body exceeds limit {max_response_bytes}"),
+                ));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(json!({
+            "status": status,
+            "headers": headers,
+            "body": String::from_utf8_lossy(&body),
+        }))
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct MemorySearchRequest {
+    query: String,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+fn host_memory_search(host: &WasmHostState, bytes: &[u8]) -> Result<Value, WasmSandboxError> {
+    let request = parse_host_request::<MemorySearchRequest>(bytes)?;
+    let search = host.context.memory_search.as_ref().ok_or_else(|| {
+        WasmSandboxError::new(
+            "wasm_host_memory_search_unavailable",
+            "no memory search backend is configured for this wasm sandbox",
+        )
+    })?;
+    if request.query.trim().is_empty() {
+        return Err(WasmSandboxError::new(
+            "wasm_host_request_invalid",
+            "memory search query must be non-empty",
+        ));
+    }
+    let limit = request
+        .limit
+        .unwrap_or(WASM_HOST_MEMORY_SEARCH_DEFAULT_LIMIT)
+        .clamp(1, WASM_HOST_MEMORY_SEARCH_MAX_LIMIT);
+    let matches = search
+        .search(request.query.trim(), limit)
+        .map_err(|error| WasmSandboxError::new("wasm_host_memory_search_failed", error))?;
+    Ok(Value::Array(matches.into_iter().take(limit).collect()))
+}
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use tau_ai::Message;
 
 mod session_commands;
+mod session_git_worktrees;
 mod session_graph_commands;
 mod session_integrity;
 mod session_locking;
@@ -192,6 +193,7 @@ pub struct SessionRuntime {
 }
 
 pub use session_commands::*;
+pub use session_git_worktrees::*;
 pub use session_graph_commands::*;
 pub use session_navigation_commands::*;
 pub use session_runtime_commands::*;
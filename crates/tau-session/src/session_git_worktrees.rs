@@ -0,0 +1,324 @@
+//! Git worktrees bound to session branches.
+//!
+//! A session branch that opts into a worktree gets a git branch named
+//! `tau/session-<store-key>-<entry-id>` checked out at
+//! `<repo_root>/.tau/git-worktrees/session-<store-key>-<entry-id>`, so
+//! alternative implementations explored on different session branches never
+//! touch the main checkout. The store key identifies the session file, so
+//! several sessions in one repository never share a branch. Later heads on the
+//! same lineage resolve to the nearest ancestor entry that owns a worktree.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, bail, Context, Result};
+use sha2::{Digest, Sha256};
+
+use crate::SessionStore;
+
+/// Workspace-relative directory that holds session branch worktrees.
+pub const SESSION_GIT_WORKTREES_SUBDIR: &str = ".tau/git-worktrees";
+const SESSION_GIT_BRANCH_PREFIX: &str = "tau/session-";
+const SESSION_GIT_STORE_KEY_HEX_CHARS: usize = 12;
+/// Config overrides that keep repository hooks and fsmonitor programs from
+/// running during worktree and merge operations.
+const SESSION_GIT_HARDENING_CONFIG: [&str; 4] = [
+    "-c",
+    "core.hooksPath=/dev/null",
+    "-c",
+    "core.fsmonitor=false",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Public struct `SessionGitWorktree` used across Tau components.
+pub struct SessionGitWorktree {
+    pub entry_id: u64,
+    pub branch: String,
+    pub path: PathBuf,
+    pub created: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Public struct `SessionGitMergeReport` used across Tau components.
+pub struct SessionGitMergeReport {
+    pub source_branch: String,
+    pub target_branch: String,
+    pub target_path: PathBuf,
+    pub merge_commit: String,
+    pub already_up_to_date: bool,
+}
+
+/// Returns the short identifier of the session file backing `store`.
+///
+/// Derived from a SHA-256 of the canonical session path, so it stays stable
+/// across runs and differs between session files in the same repository.
+pub fn session_git_store_key(store: &SessionStore) -> String {
+    let path = store.path();
+    let canonical = fs::canonicalize(path).unwrap_or_else(|_| {
+        let parent = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let parent = fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+        match path.file_name() {
+            Some(file_name) => parent.join(file_name),
+            None => parent,
+        }
+    });
+    let mut key = format!(
+        "{:x}",
+        Sha256::digest(canonical.to_string_lossy().as_bytes())
+    );
+    key.truncate(SESSION_GIT_STORE_KEY_HEX_CHARS);
+    key
+}
+
+/// Returns the git branch name used for the worktree of session entry `entry_id`.
+pub fn session_git_branch_name(store_key: &str, entry_id: u64) -> String {
+    format!("{SESSION_GIT_BRANCH_PREFIX}{store_key}-{entry_id}")
+}
+
+/// Returns the worktree directory for session entry `entry_id`.
+pub fn session_git_worktree_path(repo_root: &Path, store_key: &str, entry_id: u64) -> PathBuf {
+    repo_root
+        .join(SESSION_GIT_WORKTREES_SUBDIR)
+        .join(format!("session-{store_key}-{entry_id}"))
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(SESSION_GIT_HARDENING_CONFIG)
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .with_context(|| format!("failed to run git in {}", dir.display()))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let detail = if stderr.trim().is_empty() {
+            stdout.trim()
+        } else {
+            stderr.trim()
+        };
+        bail!("git {} failed: {detail}", args.join(" "));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Resolves the main worktree root of the repository containing `start`.
+///
+/// Starting inside a linked worktree still returns the primary checkout, so
+/// session worktrees are never nested inside each other.
+pub fn discover_git_main_worktree_root(start: &Path) -> Result<PathBuf> {
+    let common_dir = run_git(start, &["rev-parse", "--git-common-dir"])
+        .with_context(|| format!("{} is not inside a git repository", start.display()))?;
+    let common_dir = PathBuf::from(common_dir);
+    let common_dir = if common_dir.is_absolute() {
+        common_dir
+    } else {
+        start.join(common_dir)
+    };
+    let common_dir = fs::canonicalize(&common_dir)
+        .with_context(|| format!("failed to resolve git dir {}", common_dir.display()))?;
+    common_dir
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow!("git dir {} has no parent", common_dir.display()))
+}
+
+/// Resolves the main worktree root of the repository that holds the session file.
+pub fn discover_session_git_repo_root(store: &SessionStore) -> Result<PathBuf> {
+    let session_dir = store
+        .path()
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    discover_git_main_worktree_root(session_dir)
+}
+
+fn exclude_session_worktrees(repo_root: &Path) -> Result<()> {
+    let info_dir = PathBuf::from(run_git(
+        repo_root,
+        &["rev-parse", "--path-format=absolute", "--git-path", "info"],
+    )?);
+    let exclude_path = info_dir.join("exclude");
+    let pattern = format!("/{SESSION_GIT_WORKTREES_SUBDIR}/");
+    let existing = fs::read_to_string(&exclude_path).unwrap_or_default();
+    if existing.lines().any(|line| line.trim() == pattern) {
+        return Ok(());
+    }
+    fs::create_dir_all(&info_dir)
+        .with_context(|| format!("failed to create {}", info_dir.display()))?;
+    let mut updated = existing;
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(&pattern);
+    updated.push('\n');
+    fs::write(&exclude_path, updated)
+        .with_context(|| format!("failed to update {}", exclude_path.display()))
+}
+
+/// Creates (or reuses) the worktree for entry `entry_id` of the session in `store`.
+///
+/// New branches start from `base_ref`, defaulting to the main checkout's `HEAD`.
+/// The worktrees directory is added to `.git/info/exclude` so it never shows up
+/// as untracked content in the main checkout.
+pub fn create_session_git_worktree(
+    repo_root: &Path,
+    store: &SessionStore,
+    entry_id: u64,
+    base_ref: Option<&str>,
+) -> Result<SessionGitWorktree> {
+    let store_key = session_git_store_key(store);
+    let branch = session_git_branch_name(&store_key, entry_id);
+    let path = session_git_worktree_path(repo_root, &store_key, entry_id);
+    if path.join(".git").exists() {
+        return Ok(SessionGitWorktree {
+            entry_id,
+            branch,
+            path,
+            created: false,
+        });
+    }
+    exclude_session_worktrees(repo_root)?;
+    let path_arg = path.display().to_string();
+    let branch_ref = format!("refs/heads/{branch}");
+    if run_git(
+        repo_root,
+        &["rev-parse", "--verify", "--quiet", &branch_ref],
+    )
+    .is_ok()
+    {
+        run_git(repo_root, &["worktree", "add", &path_arg, &branch])?;
+    } else {
+        run_git(
+            repo_root,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                &branch,
+                &path_arg,
+                base_ref.unwrap_or("HEAD"),
+            ],
+        )?;
+    }
+    Ok(SessionGitWorktree {
+        entry_id,
+        branch,
+        path,
+        created: true,
+    })
+}
+
+/// Finds the worktree owned by `head_id` or its nearest ancestor, if any.
+pub fn resolve_session_git_worktree(
+    repo_root: &Path,
+    store: &SessionStore,
+    head_id: u64,
+) -> Result<Option<SessionGitWorktree>> {
+    let lineage = store.lineage_entries(Some(head_id))?;
+    let store_key = session_git_store_key(store);
+    Ok(lineage.iter().rev().find_map(|entry| {
+        let path = session_git_worktree_path(repo_root, &store_key, entry.id);
+        path.join(".git").exists().then(|| SessionGitWorktree {
+            entry_id: entry.id,
+            branch: session_git_branch_name(&store_key, entry.id),
+            path,
+            created: false,
+        })
+    }))
+}
+
+/// Checks that the git branch of `source_head` can be merged into the checkout
+/// of `target_head` without touching either checkout.
+///
+/// Returns the source worktree and the target checkout path.
+pub fn check_session_git_merge_ready(
+    repo_root: &Path,
+    store: &SessionStore,
+    source_head: u64,
+    target_head: Option<u64>,
+) -> Result<(SessionGitWorktree, PathBuf)> {
+    let source = resolve_session_git_worktree(repo_root, store, source_head)?
+        .ok_or_else(|| anyhow!("session branch {source_head} has no git worktree"))?;
+    let target = match target_head {
+        Some(target_head) => resolve_session_git_worktree(repo_root, store, target_head)?,
+        None => None,
+    };
+    if target
+        .as_ref()
+        .is_some_and(|target| target.entry_id == source.entry_id)
+    {
+        bail!(
+            "source and target session heads share git branch {}",
+            source.branch
+        );
+    }
+    let pending = run_git(&source.path, &["status", "--porcelain"])?;
+    if !pending.is_empty() {
+        bail!(
+            "git worktree {} has uncommitted changes; commit them before merging",
+            source.path.display()
+        );
+    }
+
+    let target_path = target
+        .map(|target| target.path)
+        .unwrap_or_else(|| repo_root.to_path_buf());
+    Ok((source, target_path))
+}
+
+/// Merges the git branch of session head `source_head` into the checkout of
+/// `target_head` (its worktree, or the main checkout when it has none).
+///
+/// The source worktree must be clean; a conflicting merge is aborted so the
+/// target checkout is left unchanged.
+pub fn merge_session_git_branch(
+    repo_root: &Path,
+    store: &SessionStore,
+    source_head: u64,
+    target_head: Option<u64>,
+) -> Result<SessionGitMergeReport> {
+    let (source, target_path) =
+        check_session_git_merge_ready(repo_root, store, source_head, target_head)?;
+    let target_branch = run_git(&target_path, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+    let already_up_to_date = run_git(
+        &target_path,
+        &["merge-base", "--is-ancestor", &source.branch, "HEAD"],
+    )
+    .is_ok();
+    if !already_up_to_date {
+        let message = format!("Merge session branch {}", source.branch);
+        if let Err(error) = run_git(
+            &target_path,
+            &[
+                "merge",
+                "--no-ff",
+                "--no-edit",
+                "--no-verify",
+                "-m",
+                &message,
+                &source.branch,
+            ],
+        ) {
+            let _ = run_git(&target_path, &["merge", "--abort"]);
+            return Err(error.context(format!(
+                "failed to merge {} into {target_branch}",
+                source.branch
+            )));
+        }
+    }
+    let merge_commit = run_git(&target_path, &["rev-parse", "HEAD"])?;
+    Ok(SessionGitMergeReport {
+        source_branch: source.branch,
+        target_branch,
+        target_path,
+        merge_commit,
+        already_up_to_date,
+    })
+}
@@ -6,10 +6,11 @@ use anyhow::{anyhow, bail, Result};
 use tau_ai::Message;
 
 use crate::{
-    execute_session_diff_command, execute_session_graph_export_command,
-    execute_session_search_command, execute_session_stats_command, format_id_list,
-    format_remap_ids, navigate_session_head, parse_session_diff_args, parse_session_stats_args,
-    redo_session_head, undo_session_head, SessionImportMode, SessionMergeStrategy, SessionRuntime,
+    check_session_git_merge_ready, discover_session_git_repo_root, execute_session_diff_command,
+    execute_session_graph_export_command, execute_session_search_command,
+    execute_session_stats_command, format_id_list, format_remap_ids, merge_session_git_branch,
+    navigate_session_head, parse_session_diff_args, parse_session_stats_args, redo_session_head,
+    undo_session_head, SessionImportMode, SessionMergeStrategy, SessionRuntime,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -49,6 +50,7 @@ struct SessionMergeCommandArgs {
     source_head: u64,
     target_head: Option<u64>,
     strategy: SessionMergeStrategy,
+    git: bool,
 }
 
 fn parse_session_merge_strategy(raw: &str) -> Result<SessionMergeStrategy> {
@@ -70,7 +72,7 @@ fn parse_session_merge_command_args(command_args: &str) -> Result<SessionMergeCo
         .filter(|token| !token.is_empty())
         .collect::<Vec<_>>();
     if tokens.is_empty() {
-        bail!("usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>]");
+        bail!("usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>] [--git]");
     }
 
     let source_head = tokens[0].parse::<u64>().map_err(|_| {
@@ -81,6 +83,7 @@ fn parse_session_merge_command_args(command_args: &str) -> Result<SessionMergeCo
     })?;
     let mut target_head = None;
     let mut strategy = SessionMergeStrategy::Append;
+    let mut git = false;
 
     let mut index = 1usize;
     if let Some(token) = tokens.get(index) {
@@ -107,6 +110,11 @@ fn parse_session_merge_command_args(command_args: &str) -> Result<SessionMergeCo
             index += 1;
             continue;
         }
+        if token == "--git" {
+            git = true;
+            index += 1;
+            continue;
+        }
         bail!("unknown flag '{}'", token);
     }
 
@@ -114,6 +122,7 @@ fn parse_session_merge_command_args(command_args: &str) -> Result<SessionMergeCo
         source_head,
         target_head,
         strategy,
+        git,
     })
 }
 
@@ -398,7 +407,7 @@ pub fn execute_session_merge_command(
         Ok(parsed) => parsed,
         Err(_) => {
             return Ok(SessionRuntimeCommandOutcome::new(
-                "usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>]".to_string(),
+                "usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>] [--git]".to_string(),
                 false,
             ));
         }
@@ -407,15 +416,54 @@ pub fn execute_session_merge_command(
     let target_head = parsed.target_head.or(runtime.active_head).ok_or_else(|| {
         anyhow!("target head is not set; provide explicit target id or set active head")
     })?;
+    let git_repo_root = if parsed.git {
+        let repo_root = discover_session_git_repo_root(&runtime.store)?;
+        check_session_git_merge_ready(
+            &repo_root,
+            &runtime.store,
+            parsed.source_head,
+            Some(target_head),
+        )?;
+        Some(repo_root)
+    } else {
+        None
+    };
     let previous_head = runtime.active_head;
     let report = runtime
         .store
         .merge_branches(parsed.source_head, target_head, parsed.strategy)?;
     navigate_session_head(runtime, Some(report.merged_head))?;
 
+    // The session merge is already persisted, so a git merge that still fails
+    // (e.g. on conflicts) is reported next to it rather than as an error.
+    let git_summary = match git_repo_root.map(|repo_root| {
+        merge_session_git_branch(
+            &repo_root,
+            &runtime.store,
+            parsed.source_head,
+            Some(target_head),
+        )
+    }) {
+        Some(Ok(git)) => format!(
+            " git_source={} git_target={} git_commit={}{}",
+            git.source_branch,
+            git.target_branch,
+            git.merge_commit,
+            if git.already_up_to_date {
+                " git_already_up_to_date=true"
+            } else {
+                ""
+            }
+        ),
+        Some(Err(error)) => format!(
+            " git_merge=failed git_error={}",
+            format!("{error:#}").replace('\n', " ")
+        ),
+        None => String::new(),
+    };
     Ok(SessionRuntimeCommandOutcome::new(
         format!(
-            "session merge complete: source={} target={} strategy={} common_ancestor={} appended_entries={} head={}",
+            "session merge complete: source={} target={} strategy={} common_ancestor={} appended_entries={} head={}{git_summary}",
             report.source_head,
             report.target_head,
             session_merge_strategy_label(report.strategy),
@@ -684,6 +732,7 @@ mod tests {
                 source_head: 42,
                 target_head: Some(24),
                 strategy: SessionMergeStrategy::Squash,
+                git: false,
             }
         );
 
@@ -695,8 +744,14 @@ mod tests {
                 source_head: 42,
                 target_head: None,
                 strategy: SessionMergeStrategy::FastForward,
+                git: false,
             }
         );
+
+        let with_git = parse_session_merge_command_args("42 24 --git --strategy squash")
+            .expect("merge args should parse");
+        assert!(with_git.git);
+        assert_eq!(with_git.strategy, SessionMergeStrategy::Squash);
     }
 
     #[test]
@@ -945,7 +1000,7 @@ mod tests {
             .expect("usage should be returned as non-error outcome");
         assert_eq!(
             usage.message,
-            "usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>]"
+            "usage: /session-merge <source-id> [target-id] [--strategy <append|squash|fast-forward>] [--git]"
         );
         assert!(!usage.reload_active_head);
 
@@ -12,8 +12,11 @@ use std::{
 use tempfile::tempdir;
 
 use super::{
-    acquire_lock, resolve_session_backend, CompactReport, RepairReport, SessionEntry,
-    SessionImportMode, SessionMergeStrategy, SessionRecord, SessionStorageBackend, SessionStore,
+    acquire_lock, create_session_git_worktree, discover_git_main_worktree_root,
+    discover_session_git_repo_root, execute_session_merge_command, merge_session_git_branch,
+    resolve_session_backend, resolve_session_git_worktree, session_git_store_key,
+    session_git_worktree_path, CompactReport, RepairReport, SessionEntry, SessionImportMode,
+    SessionMergeStrategy, SessionRecord, SessionRuntime, SessionStorageBackend, SessionStore,
     SessionUsageSummary, SessionValidationReport, SESSION_BACKEND_ENV, SESSION_POSTGRES_DSN_ENV,
 };
 
@@ -119,6 +122,32 @@ fn appends_and_restores_lineage() {
     assert_eq!(reloaded.entries().len(), 3);
 }
 
+#[test]
+fn functional_thinking_blocks_survive_session_reload_for_replay() {
+    let temp = tempdir().expect("tempdir");
+    let path = temp.path().join("session.jsonl");
+    let assistant = tau_ai::Message::assistant_blocks(vec![
+        tau_ai::ContentBlock::thinking("anthropic", "check the file first", Some("sig-1".into())),
+        tau_ai::ContentBlock::ToolCall {
+            id: "toolu_1".to_string(),
+            name: "read".to_string(),
+            arguments: serde_json::json!({ "path": "README.md" }),
+        },
+    ]);
+
+    let mut store = load_store(&path).expect("load");
+    let head = store
+        .append_messages(None, &[tau_ai::Message::user("read"), assistant.clone()])
+        .expect("append");
+
+    let reloaded = load_store(&path).expect("reload");
+    let lineage = reloaded.lineage_messages(head).expect("lineage");
+    assert_eq!(lineage.len(), 2);
+    assert_eq!(lineage[1], assistant);
+    assert_eq!(lineage[1].thinking_content(), "check the file first");
+    assert_eq!(lineage[1].text_content(), "");
+}
+
 #[test]
 fn supports_branching_from_older_id() {
     let temp = tempdir().expect("tempdir");
@@ -314,6 +343,242 @@ fn regression_merge_branches_fast_forward_rejects_diverged_branches() {
     assert!(error.to_string().contains("cannot fast-forward target"));
 }
 
+fn git_available() -> bool {
+    std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .expect("run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn init_git_repo(root: &std::path::Path) {
+    git(root, &["init", "-q"]);
+    git(root, &["config", "user.name", "Tau Test"]);
+    git(root, &["config", "user.email", "tau@example.com"]);
+    git(root, &["config", "commit.gpgsign", "false"]);
+    fs::write(root.join("lib.rs"), "fn main() {}\n").expect("write lib");
+    git(root, &["add", "lib.rs"]);
+    git(root, &["commit", "-q", "-m", "initial"]);
+}
+
+#[test]
+fn integration_session_git_worktrees_follow_branch_lineage_and_merge_into_main_checkout() {
+    if !git_available() {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_repo(&repo);
+    let mut store = load_store(repo.join("session.jsonl")).expect("load");
+    let root = store
+        .append_messages(None, &[tau_ai::Message::system("sys")])
+        .expect("append")
+        .expect("root");
+    let branch = store
+        .append_messages(Some(root), &[tau_ai::Message::user("try approach a")])
+        .expect("append branch")
+        .expect("branch head");
+
+    let store_key = session_git_store_key(&store);
+    assert_eq!(store_key.len(), 12);
+    let worktree =
+        create_session_git_worktree(&repo, &store, branch, None).expect("create worktree");
+    assert!(worktree.created);
+    assert_eq!(worktree.branch, format!("tau/session-{store_key}-{branch}"));
+    assert_eq!(
+        worktree.path,
+        session_git_worktree_path(&repo, &store_key, branch)
+    );
+    assert!(
+        !create_session_git_worktree(&repo, &store, branch, None)
+            .expect("reuse worktree")
+            .created
+    );
+    assert_eq!(
+        discover_git_main_worktree_root(&worktree.path).expect("main root"),
+        repo
+    );
+    assert_eq!(
+        discover_session_git_repo_root(&store).expect("session repo root"),
+        repo
+    );
+
+    fs::write(worktree.path.join("feature.rs"), "pub fn feature() {}\n").expect("write feature");
+    let dirty = merge_session_git_branch(&repo, &store, branch, Some(root))
+        .expect_err("dirty worktree should not merge");
+    assert!(dirty.to_string().contains("uncommitted changes"));
+    git(&worktree.path, &["add", "feature.rs"]);
+    git(&worktree.path, &["commit", "-q", "-m", "add feature"]);
+
+    let tip = store
+        .append_messages(
+            Some(branch),
+            &[tau_ai::Message::assistant_text("feature added")],
+        )
+        .expect("append tip")
+        .expect("tip");
+    let resolved = resolve_session_git_worktree(&repo, &store, tip)
+        .expect("resolve worktree")
+        .expect("tip inherits branch worktree");
+    assert_eq!(resolved.entry_id, branch);
+    assert!(resolve_session_git_worktree(&repo, &store, root)
+        .expect("resolve root")
+        .is_none());
+
+    let report = merge_session_git_branch(&repo, &store, tip, Some(root)).expect("merge branch");
+    assert_eq!(
+        report.source_branch,
+        format!("tau/session-{store_key}-{branch}")
+    );
+    assert_eq!(report.target_path, repo);
+    assert!(!report.already_up_to_date);
+    assert!(repo.join("feature.rs").exists());
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(&repo)
+        .args(["status", "--porcelain"])
+        .output()
+        .expect("git status");
+    assert!(
+        !String::from_utf8_lossy(&status.stdout).contains(".tau/"),
+        "session worktrees should be excluded from the main checkout"
+    );
+
+    let again = merge_session_git_branch(&repo, &store, tip, None).expect("merge again");
+    assert!(again.already_up_to_date);
+
+    let mut other_store = load_store(repo.join("other-session.jsonl")).expect("load other");
+    let other_root = other_store
+        .append_messages(None, &[tau_ai::Message::system("sys")])
+        .expect("append other")
+        .expect("other root");
+    let other_branch = other_store
+        .append_messages(Some(other_root), &[tau_ai::Message::user("try approach b")])
+        .expect("append other branch")
+        .expect("other branch head");
+    assert_eq!(
+        other_branch, branch,
+        "both sessions reuse the same entry id"
+    );
+    let other_worktree = create_session_git_worktree(&repo, &other_store, other_branch, None)
+        .expect("create other worktree");
+    assert!(other_worktree.created);
+    assert_ne!(other_worktree.branch, worktree.branch);
+    assert_ne!(other_worktree.path, worktree.path);
+}
+
+#[test]
+fn regression_session_merge_command_git_checks_before_merging_and_reports_conflicts() {
+    if !git_available() {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_repo(&repo);
+    let mut store = load_store(repo.join("session.jsonl")).expect("load");
+    let root = store
+        .append_messages(None, &[tau_ai::Message::system("sys")])
+        .expect("append")
+        .expect("root");
+    let branch = store
+        .append_messages(Some(root), &[tau_ai::Message::user("try approach a")])
+        .expect("append branch")
+        .expect("branch head");
+    let target = store
+        .append_messages(Some(root), &[tau_ai::Message::user("stay on main")])
+        .expect("append target")
+        .expect("target head");
+    let worktree =
+        create_session_git_worktree(&repo, &store, branch, None).expect("create worktree");
+    let mut runtime = SessionRuntime {
+        store,
+        active_head: Some(target),
+    };
+
+    fs::write(worktree.path.join("lib.rs"), "fn branch() {}\n").expect("dirty worktree");
+    let dirty = execute_session_merge_command(
+        &format!("{branch} {target} --strategy append --git"),
+        &mut runtime,
+    )
+    .expect_err("dirty source worktree should fail before merging");
+    assert!(dirty.to_string().contains("uncommitted changes"));
+    assert_eq!(runtime.active_head, Some(target));
+    assert_eq!(runtime.store.entries().len(), 3);
+
+    git(&worktree.path, &["commit", "-q", "-am", "branch change"]);
+    fs::write(repo.join("lib.rs"), "fn main_change() {}\n").expect("write main change");
+    git(&repo, &["commit", "-q", "-am", "main change"]);
+    let main_head = std::process::Command::new("git")
+        .arg("-C")
+        .arg(&repo)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .expect("rev-parse")
+        .stdout;
+
+    let outcome = execute_session_merge_command(
+        &format!("{branch} {target} --strategy append --git"),
+        &mut runtime,
+    )
+    .expect("session merge should complete despite git conflict");
+    assert!(outcome.reload_active_head);
+    assert!(outcome.message.contains("appended_entries=1"));
+    assert!(
+        outcome.message.contains("git_merge=failed"),
+        "{}",
+        outcome.message
+    );
+    assert_ne!(runtime.active_head, Some(target));
+    let after_head = std::process::Command::new("git")
+        .arg("-C")
+        .arg(&repo)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .expect("rev-parse")
+        .stdout;
+    assert_eq!(after_head, main_head, "conflicting merge must be aborted");
+    assert_eq!(
+        fs::read_to_string(repo.join("lib.rs")).expect("read lib"),
+        "fn main_change() {}\n"
+    );
+}
+
+#[test]
+fn regression_merge_session_git_branch_requires_source_worktree() {
+    if !git_available() {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_repo(&repo);
+    let mut store = load_store(repo.join("session.jsonl")).expect("load");
+    let root = store
+        .append_messages(None, &[tau_ai::Message::system("sys")])
+        .expect("append")
+        .expect("root");
+
+    let error = merge_session_git_branch(&repo, &store, root, None)
+        .expect_err("merge without worktree should fail");
+    assert!(error.to_string().contains("has no git worktree"));
+    assert!(
+        discover_git_main_worktree_root(&std::env::temp_dir().join("tau-missing-repo")).is_err()
+    );
+}
+
 #[test]
 fn functional_export_lineage_writes_schema_valid_snapshot() {
     let temp = tempdir().expect("tempdir");
@@ -4,12 +4,21 @@
 //! based on the skill's declared `SkillRuntime`.
 
 use std::io::Write;
+use std::path::Path;
 use std::process::{Command, Stdio};
 
 use anyhow::{anyhow, bail, Result};
 use serde_json::Value;
+use tau_runtime::{
+    execute_wasm_sandbox_with_host_sync, WasmSandboxCapabilityProfile, WasmSandboxExecutionRequest,
+    WasmSandboxFilesystemMode, WasmSandboxHostContext, WasmSandboxHostFunction, WasmSandboxLimits,
+    WasmSandboxNetworkMode,
+};
 
-use crate::{Skill, SkillHook, SkillRuntime};
+use crate::{Skill, SkillHook, SkillPermission, SkillRuntime};
+
+/// Kv store file backing `tau_host.kv_get`/`kv_set` for wasm skills.
+const WASM_SKILL_KV_STORE_FILE: &str = ".tau-skill-kv.json";
 
 /// Context passed to lifecycle hook dispatch.
 #[derive(Debug, Clone)]
@@ -63,7 +72,7 @@ pub fn dispatch_skill_tool(skill: &Skill, tool_name: &str, params: Value) -> Res
     let request_json = serde_json::to_string(&request)
         .map_err(|e| anyhow!("failed to serialize tool request: {e}"))?;
 
-    execute_skill_runtime(runtime, entrypoint, &request_json)
+    execute_skill_runtime(skill, runtime, entrypoint, &request_json)
 }
 
 /// Dispatch a lifecycle hook to a skill's runtime.
@@ -107,7 +116,7 @@ pub fn dispatch_skill_hook(skill: &Skill, hook: SkillHook, context: &HookContext
     let request_json = serde_json::to_string(&request)
         .map_err(|e| anyhow!("failed to serialize hook request: {e}"))?;
 
-    let _response = execute_skill_runtime(runtime, entrypoint, &request_json)?;
+    let _response = execute_skill_runtime(skill, runtime, entrypoint, &request_json)?;
     Ok(())
 }
 
@@ -160,7 +169,7 @@ pub fn dispatch_skill_command(
     let request_json = serde_json::to_string(&request)
         .map_err(|e| anyhow!("failed to serialize command request: {e}"))?;
 
-    let response = execute_skill_runtime(runtime, entrypoint, &request_json)?;
+    let response = execute_skill_runtime(skill, runtime, entrypoint, &request_json)?;
 
     // Extract "output" field from response, or return the raw JSON.
     let output = response
@@ -177,25 +186,88 @@ pub fn dispatch_skill_command(
 /// For `Process` runtime: spawns the entrypoint as a process, writes request
 /// JSON to stdin, reads response JSON from stdout.
 ///
-/// For `Wasm` runtime: currently returns an error indicating WASM support is
-/// not yet integrated at the skill level. When integrated, this will delegate
-/// to the existing `tau-runtime` WASM sandbox.
+/// For `Wasm` runtime: runs the module (resolved against the skill directory)
+/// in the `tau-runtime` WASM sandbox with capabilities derived from the
+/// skill's declared permissions.
 fn execute_skill_runtime(
+    skill: &Skill,
     runtime: &SkillRuntime,
     entrypoint: &str,
     request_json: &str,
 ) -> Result<Value> {
     match runtime {
         SkillRuntime::Process => execute_process_runtime(entrypoint, request_json),
-        SkillRuntime::Wasm => {
-            bail!(
-                "WASM skill runtime is not yet supported; \
-                 use process runtime or wait for WASM integration"
-            )
-        }
+        SkillRuntime::Wasm => execute_wasm_runtime(skill, entrypoint, request_json),
     }
 }
 
+/// Execute a WASM skill module inside the sandbox.
+fn execute_wasm_runtime(skill: &Skill, entrypoint: &str, request_json: &str) -> Result<Value> {
+    let entrypoint_path = Path::new(entrypoint);
+    let module_path = if entrypoint_path.is_absolute() {
+        entrypoint_path.to_path_buf()
+    } else {
+        skill.base_dir.join(entrypoint_path)
+    };
+    let (capabilities, host) = wasm_skill_capabilities(skill);
+    let report = execute_wasm_sandbox_with_host_sync(
+        WasmSandboxExecutionRequest {
+            module_path,
+            request_json: request_json.to_string(),
+            limits: WasmSandboxLimits::default(),
+            capabilities,
+        },
+        host,
+    )
+    .map_err(|error| anyhow!("skill '{}' wasm runtime failed: {error}", skill.name))?;
+    serde_json::from_str(&report.response_json)
+        .map_err(|e| anyhow!("skill wasm response is not valid JSON: {e}"))
+}
+
+/// Map skill permissions onto a sandbox capability profile.
+///
+/// `read-files`/`write-files` preopen the skill directory and grant kv access
+/// backed by a file inside it; `network` grants SSRF-guarded `http_fetch`.
+/// Guest logging is always available.
+fn wasm_skill_capabilities(
+    skill: &Skill,
+) -> (WasmSandboxCapabilityProfile, WasmSandboxHostContext) {
+    let granted = |permission: SkillPermission| {
+        skill
+            .permissions
+            .as_ref()
+            .is_some_and(|permissions| permissions.contains(&permission))
+    };
+    let mut capabilities = WasmSandboxCapabilityProfile {
+        host_functions: vec![WasmSandboxHostFunction::Log],
+        ..WasmSandboxCapabilityProfile::default()
+    };
+    let mut host = WasmSandboxHostContext::default();
+    if granted(SkillPermission::WriteFiles) {
+        capabilities.filesystem_mode = WasmSandboxFilesystemMode::ReadWrite;
+        capabilities.host_functions.extend([
+            WasmSandboxHostFunction::KvGet,
+            WasmSandboxHostFunction::KvSet,
+        ]);
+    } else if granted(SkillPermission::ReadFiles) {
+        capabilities.filesystem_mode = WasmSandboxFilesystemMode::ReadOnly;
+        capabilities
+            .host_functions
+            .push(WasmSandboxHostFunction::KvGet);
+    }
+    if capabilities.filesystem_mode != WasmSandboxFilesystemMode::Deny {
+        host.filesystem_root = Some(skill.base_dir.clone());
+        host.kv_store_path = Some(skill.base_dir.join(WASM_SKILL_KV_STORE_FILE));
+    }
+    if granted(SkillPermission::Network) {
+        capabilities.network_mode = WasmSandboxNetworkMode::Allow;
+        capabilities
+            .host_functions
+            .push(WasmSandboxHostFunction::HttpFetch);
+    }
+    (capabilities, host)
+}
+
 /// Execute a process-based skill runtime.
 fn execute_process_runtime(entrypoint: &str, request_json: &str) -> Result<Value> {
     let mut child = Command::new(entrypoint)
@@ -539,20 +611,86 @@ mod tests {
         );
     }
 
-    // ---- Test 6: WASM runtime returns not-yet-supported error ----
+    // ---- Test 6: WASM runtime executes through the sandbox ----
+
+    fn write_echo_wasm_module(path: &std::path::Path) {
+        let bytes = wat::parse_str(
+            r#"(module
+  (import "tau_host" "log" (func $log (param i32 i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{\"greeting\":\"hi\"}")
+  (func (export "tau_extension_alloc") (param i32) (result i32) i32.const 1024)
+  (func (export "tau_extension_invoke") (param i32 i32) (result i64)
+    (drop (call $log (i32.const 1) (i32.const 0) (i32.const 17)))
+    i64.const 17)
+)"#,
+        )
+        .expect("parse wat");
+        std::fs::write(path, bytes).expect("write wasm module");
+    }
 
     #[test]
-    fn test_wasm_runtime_returns_not_supported_error() {
-        let error = execute_skill_runtime(&SkillRuntime::Wasm, "module.wasm", "{}")
-            .expect_err("WASM should not be supported yet");
+    fn test_dispatch_skill_tool_executes_wasm_runtime() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        write_echo_wasm_module(&temp.path().join("skill.wasm"));
+        let mut skill = skill_with_tools(
+            "greeter",
+            vec![SkillToolDefinition {
+                name: "greet".to_string(),
+                description: "Greet a user".to_string(),
+                parameters: json!({"type": "object"}),
+                handler: None,
+            }],
+        );
+        skill.base_dir = temp.path().to_path_buf();
+        skill.runtime = Some(SkillRuntime::Wasm);
+        skill.entrypoint = Some("skill.wasm".to_string());
+
+        let response = dispatch_skill_tool(&skill, "greet", json!({})).expect("wasm tool call");
+        assert_eq!(response, json!({"greeting": "hi"}));
+    }
+
+    #[test]
+    fn test_wasm_runtime_reports_missing_module() {
+        let skill = minimal_skill("missing");
+        let error = execute_skill_runtime(&skill, &SkillRuntime::Wasm, "module.wasm", "{}")
+            .expect_err("missing module should fail");
         assert!(
-            error
-                .to_string()
-                .contains("WASM skill runtime is not yet supported"),
+            error.to_string().contains("wasm_module_missing"),
             "unexpected error: {error}"
         );
     }
 
+    #[test]
+    fn test_wasm_skill_capabilities_follow_permissions() {
+        let mut skill = minimal_skill("scoped");
+        let (capabilities, host) = wasm_skill_capabilities(&skill);
+        assert_eq!(
+            capabilities.filesystem_mode,
+            WasmSandboxFilesystemMode::Deny
+        );
+        assert_eq!(
+            capabilities.host_functions,
+            vec![WasmSandboxHostFunction::Log]
+        );
+        assert!(host.filesystem_root.is_none());
+
+        skill.permissions = Some(vec![SkillPermission::ReadFiles, SkillPermission::Network]);
+        let (capabilities, host) = wasm_skill_capabilities(&skill);
+        assert_eq!(
+            capabilities.filesystem_mode,
+            WasmSandboxFilesystemMode::ReadOnly
+        );
+        assert_eq!(capabilities.network_mode, WasmSandboxNetworkMode::Allow);
+        assert!(capabilities
+            .host_functions
+            .contains(&WasmSandboxHostFunction::HttpFetch));
+        assert!(!capabilities
+            .host_functions
+            .contains(&WasmSandboxHostFunction::KvSet));
+        assert_eq!(host.filesystem_root, Some(skill.base_dir.clone()));
+    }
+
     // ---- Test 7: SkillHook::as_str ----
 
     #[test]
@@ -23,6 +23,9 @@ use tokio::sync::mpsc;
 pub mod slack_runtime;
 
 pub use slack_runtime::{run_slack_bridge, SlackBridgeRuntimeConfig};
+pub use tau_access::approvals::{
+    approval_paths_for_state_dir, execute_approvals_command_with_paths_and_actor,
+};
 pub use tau_access::pairing::{
     evaluate_pairing_access, pairing_policy_for_state_dir, PairingDecision,
 };
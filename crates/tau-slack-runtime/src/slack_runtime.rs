@@ -24,12 +24,13 @@ use crate::slack_helpers::{
 };
 use crate::tools::ToolPolicy;
 use crate::{
-    authorize_action_for_principal_with_policy_path, current_unix_timestamp_ms,
-    evaluate_pairing_access, execute_canvas_command, pairing_policy_for_state_dir,
-    rbac_policy_path_for_state_dir, run_prompt_with_cancellation, slack_principal,
-    write_text_atomic, CanvasCommandConfig, CanvasEventOrigin, CanvasSessionLinkContext,
-    PairingDecision, PromptRunStatus, RbacDecision, RenderOptions, SessionRuntime,
-    TransportHealthSnapshot,
+    approval_paths_for_state_dir, authorize_action_for_principal_with_policy_path,
+    current_unix_timestamp_ms, evaluate_pairing_access,
+    execute_approvals_command_with_paths_and_actor, execute_canvas_command,
+    pairing_policy_for_state_dir, rbac_policy_path_for_state_dir, run_prompt_with_cancellation,
+    slack_principal, write_text_atomic, CanvasCommandConfig, CanvasEventOrigin,
+    CanvasSessionLinkContext, PairingDecision, PromptRunStatus, RbacDecision, RenderOptions,
+    SessionRuntime, TransportHealthSnapshot,
 };
 use tau_session::SessionStore;
 
@@ -68,6 +69,7 @@ pub struct SlackBridgeRuntimeConfig {
 
 mod slack_api_client;
 mod slack_command_helpers;
+mod slack_interactivity;
 mod slack_render_helpers;
 mod slack_state_store;
 
@@ -76,6 +78,12 @@ use slack_command_helpers::{
     parse_slack_command, rbac_action_for_slack_command, render_slack_command_response,
     slack_command_usage,
 };
+use slack_interactivity::{
+    extract_pending_approvals, parse_interactive_payload, render_approval_resume_text,
+    render_slack_approval_blocks, render_slack_approval_fallback_text,
+    render_slack_approval_reason_modal, render_slack_approval_resolved, SlackApprovalContext,
+    SlackApprovalDecision, SlackApprovalPrompt, SlackInteraction,
+};
 use slack_render_helpers::{
     collect_assistant_reply, normalize_artifact_retention_days, prompt_status_label,
     render_event_prompt, render_slack_artifact_markdown, render_slack_response,
@@ -109,6 +117,7 @@ struct SlackFileAttachment {
 enum SlackBridgeEventKind {
     AppMention,
     DirectMessage,
+    ApprovalDecision,
 }
 
 impl SlackBridgeEventKind {
@@ -116,6 +125,7 @@ impl SlackBridgeEventKind {
         match self {
             Self::AppMention => "app_mention",
             Self::DirectMessage => "message.im",
+            Self::ApprovalDecision => "approval_decision",
         }
     }
 }
@@ -171,6 +181,7 @@ struct PromptRunReport {
     usage: PromptUsageSummary,
     downloaded_files: Vec<DownloadedSlackFile>,
     artifact: ChannelArtifactRecord,
+    pending_approvals: Vec<SlackApprovalPrompt>,
 }
 
 #[derive(Debug, Clone)]
@@ -228,6 +239,7 @@ struct RunTaskResult {
     status: String,
     model: String,
     usage: PromptUsageSummary,
+    approval_prompts: usize,
     error: Option<String>,
 }
 
@@ -456,6 +468,9 @@ impl SlackBridgeRuntime {
         envelope: SlackSocketEnvelope,
         report: &mut PollCycleReport,
     ) -> Result<()> {
+        if envelope.envelope_type == "interactive" {
+            return self.handle_interactive_envelope(envelope, report).await;
+        }
         let now_unix_ms = current_unix_timestamp_ms();
         report.discovered_events = report.discovered_events.saturating_add(1);
 
@@ -626,6 +641,231 @@ impl SlackBridgeRuntime {
         Ok(())
     }
 
+    /// Resolves approval buttons and deny-reason modals, then resumes the paused turn.
+    async fn handle_interactive_envelope(
+        &mut self,
+        envelope: SlackSocketEnvelope,
+        report: &mut PollCycleReport,
+    ) -> Result<()> {
+        let now_unix_ms = current_unix_timestamp_ms();
+        report.discovered_events = report.discovered_events.saturating_add(1);
+        let Some(interaction) = parse_interactive_payload(&envelope.payload) else {
+            return Ok(());
+        };
+        let key = interaction.key();
+        if self.state_store.contains(&key) {
+            report.skipped_duplicate_events = report.skipped_duplicate_events.saturating_add(1);
+            return Ok(());
+        }
+        if self.state_store.mark_processed(&key) {
+            self.state_store.save()?;
+        }
+
+        let context = interaction.context().clone();
+        let actor_id = interaction.user_id().to_string();
+        let interaction_kind = match &interaction {
+            SlackInteraction::ApprovalButton { .. } => "block_actions",
+            SlackInteraction::ApprovalReasonSubmitted { .. } => "view_submission",
+        };
+        self.inbound_log.append(&json!({
+            "timestamp_unix_ms": now_unix_ms,
+            "event_key": key,
+            "kind": interaction_kind,
+            "channel": context.channel_id,
+            "request_id": context.request_id,
+            "actor_id": actor_id,
+            "payload": envelope.payload,
+        }))?;
+
+        let rbac_principal = slack_principal(&actor_id);
+        let rbac_action = "command:/tau-approvals".to_string();
+        let rbac_policy_path = rbac_policy_path_for_state_dir(&self.config.state_dir);
+        let denial = match authorize_action_for_principal_with_policy_path(
+            &rbac_principal,
+            &rbac_action,
+            rbac_policy_path.as_path(),
+        ) {
+            Ok(RbacDecision::Allow { .. }) => None,
+            Ok(RbacDecision::Deny { reason_code, .. }) => Some(("denied", reason_code, None)),
+            Err(error) => Some((
+                "error",
+                "rbac_policy_error".to_string(),
+                Some(error.to_string()),
+            )),
+        };
+        if let Some((status, reason_code, error)) = denial {
+            self.outbound_log.append(&json!({
+                "timestamp_unix_ms": now_unix_ms,
+                "event_key": key,
+                "channel": context.channel_id,
+                "command": "approval-interaction",
+                "status": status,
+                "reason_code": reason_code,
+                "principal": rbac_principal,
+                "action": rbac_action,
+                "actor_id": actor_id,
+                "request_id": context.request_id,
+                "error": error,
+            }))?;
+            let _ = self
+                .slack_client
+                .post_message(
+                    &context.channel_id,
+                    &format!(
+                        "<@{}> is not allowed to decide approval `{}` ({}).",
+                        actor_id, context.request_id, reason_code
+                    ),
+                    context.thread_ts.as_deref(),
+                )
+                .await;
+            if error.is_some() {
+                report.failed_events = report.failed_events.saturating_add(1);
+            }
+            return Ok(());
+        }
+
+        let (decision, reason) = match interaction {
+            SlackInteraction::ApprovalButton {
+                decision: SlackApprovalDecision::Deny,
+                trigger_id: Some(trigger_id),
+                ..
+            } => {
+                let opened = self
+                    .slack_client
+                    .open_view(&trigger_id, &render_slack_approval_reason_modal(&context))
+                    .await;
+                let modal_status = if opened.is_ok() { "opened" } else { "failed" };
+                if opened.is_err() {
+                    report.failed_events = report.failed_events.saturating_add(1);
+                }
+                self.outbound_log.append(&json!({
+                    "timestamp_unix_ms": now_unix_ms,
+                    "event_key": key,
+                    "channel": context.channel_id,
+                    "command": "approval-reason-modal",
+                    "status": modal_status,
+                    "principal": rbac_principal,
+                    "request_id": context.request_id,
+                    "error": opened.err().map(|error| error.to_string()),
+                }))?;
+                return Ok(());
+            }
+            SlackInteraction::ApprovalButton { decision, .. } => (decision, None),
+            SlackInteraction::ApprovalReasonSubmitted { reason, .. } => {
+                (SlackApprovalDecision::Deny, reason)
+            }
+        };
+
+        let (policy_path, store_path) = approval_paths_for_state_dir(&self.state_dir);
+        let command_args = match reason.as_deref() {
+            Some(reason) => format!(
+                "{} {} {}",
+                decision.command_verb(),
+                context.request_id,
+                reason
+            ),
+            None => format!("{} {}", decision.command_verb(), context.request_id),
+        };
+        let output = execute_approvals_command_with_paths_and_actor(
+            &command_args,
+            &policy_path,
+            &store_path,
+            Some(rbac_principal.as_str()),
+        );
+        let resolved = !output.trim_start().starts_with("approvals error:")
+            && !output.trim_start().starts_with("usage:");
+        let delivery = if resolved {
+            let (text, blocks) =
+                render_slack_approval_resolved(&context, decision, &actor_id, reason.as_deref());
+            match context.message_ts.as_deref() {
+                Some(message_ts) => self
+                    .slack_client
+                    .update_blocks_message(&context.channel_id, message_ts, &text, &blocks)
+                    .await
+                    .map(|_| ()),
+                None => self
+                    .slack_client
+                    .post_message(&context.channel_id, &text, context.thread_ts.as_deref())
+                    .await
+                    .map(|_| ()),
+            }
+        } else {
+            self.slack_client
+                .post_message(
+                    &context.channel_id,
+                    &truncate_for_slack(&output, 4_000),
+                    context.thread_ts.as_deref(),
+                )
+                .await
+                .map(|_| ())
+        };
+        if delivery.is_err() || !resolved {
+            report.failed_events = report.failed_events.saturating_add(1);
+        }
+
+        let status = if resolved {
+            decision.status_label()
+        } else {
+            "failed"
+        };
+        self.outbound_log.append(&json!({
+            "timestamp_unix_ms": now_unix_ms,
+            "event_key": key,
+            "channel": context.channel_id,
+            "command": "approval-interaction",
+            "status": status,
+            "decision": decision.command_verb(),
+            "principal": rbac_principal,
+            "request_id": context.request_id,
+            "requester_id": context.requester_id,
+            "reason": reason,
+            "error": delivery.err().map(|error| error.to_string()),
+        }))?;
+        ChannelStore::open(
+            &self.state_dir.join("channel-store"),
+            "slack",
+            &context.channel_id,
+        )?
+        .append_log_entry(&ChannelLogEntry {
+            timestamp_unix_ms: now_unix_ms,
+            direction: "inbound".to_string(),
+            event_key: Some(key.clone()),
+            source: "slack".to_string(),
+            payload: json!({
+                "kind": "approval_decision",
+                "request_id": context.request_id,
+                "decision": decision.command_verb(),
+                "status": status,
+                "user_id": actor_id,
+                "principal": rbac_principal,
+                "reason": reason,
+            }),
+        })?;
+        if !resolved {
+            return Ok(());
+        }
+
+        let text = render_approval_resume_text(&context, decision, &actor_id, reason.as_deref());
+        self.channel_queues
+            .entry(context.channel_id.clone())
+            .or_default()
+            .push_back(SlackBridgeEvent {
+                key: format!("{key}:resume"),
+                kind: SlackBridgeEventKind::ApprovalDecision,
+                event_id: key,
+                occurred_unix_ms: now_unix_ms,
+                channel_id: context.channel_id,
+                user_id: context.requester_id,
+                text,
+                ts: context.message_ts.unwrap_or_default(),
+                thread_ts: context.thread_ts,
+                files: Vec::new(),
+                raw_payload: envelope.payload,
+            });
+        report.queued_events = report.queued_events.saturating_add(1);
+        Ok(())
+    }
+
     async fn try_start_queued_runs(&mut self, report: &mut PollCycleReport) -> Result<()> {
         let channels = self.channel_queues.keys().cloned().collect::<Vec<_>>();
 
@@ -1104,6 +1344,7 @@ impl SlackBridgeRuntime {
                             "request_duration_ms": result.usage.request_duration_ms,
                             "finish_reason": result.usage.finish_reason,
                         },
+                        "approval_prompts": result.approval_prompts,
                         "error": result.error,
                     }))?;
                     report.completed_runs = report.completed_runs.saturating_add(1);
@@ -1127,6 +1368,11 @@ fn should_coalesce_events(
     if coalescing_window_ms == 0 {
         return false;
     }
+    if previous.kind == SlackBridgeEventKind::ApprovalDecision
+        || current.kind == SlackBridgeEventKind::ApprovalDecision
+    {
+        return false;
+    }
     if previous.user_id.trim() != current.user_id.trim() {
         return false;
     }
@@ -1240,25 +1486,31 @@ async fn execute_channel_run_task(params: SlackRunTaskParams) -> RunTaskResult {
     let completed_unix_ms = current_unix_timestamp_ms();
     let duration_ms = started.elapsed().as_millis() as u64;
 
-    let (status, usage, body, detail): (String, PromptUsageSummary, String, Option<String>) =
-        match run_result {
-            Ok(run) => {
-                let status = prompt_status_label(run.status).to_string();
-                let rendered = render_slack_response(
-                    &event,
-                    &run,
-                    config.detail_thread_output,
-                    config.detail_thread_threshold_chars,
-                );
-                (status, run.usage.clone(), rendered.0, rendered.1)
-            }
-            Err(error) => (
-                "failed".to_string(),
-                PromptUsageSummary::default(),
-                render_slack_run_error_message(&event, &run_id, &error),
-                None,
-            ),
-        };
+    let (status, usage, body, detail, approvals) = match run_result {
+        Ok(run) => {
+            let status = prompt_status_label(run.status).to_string();
+            let rendered = render_slack_response(
+                &event,
+                &run,
+                config.detail_thread_output,
+                config.detail_thread_threshold_chars,
+            );
+            (
+                status,
+                run.usage.clone(),
+                rendered.0,
+                rendered.1,
+                run.pending_approvals,
+            )
+        }
+        Err(error) => (
+            "failed".to_string(),
+            PromptUsageSummary::default(),
+            render_slack_run_error_message(&event, &run_id, &error),
+            None,
+            Vec::new(),
+        ),
+    };
 
     let update_result = slack_client
         .update_message(&working_message.channel, &working_message.ts, &body)
@@ -1284,6 +1536,8 @@ async fn execute_channel_run_task(params: SlackRunTaskParams) -> RunTaskResult {
             .await;
     }
 
+    let approval_prompts = post_approval_prompts(&slack_client, &event, &approvals).await;
+
     RunTaskResult {
         channel_id: event.channel_id,
         event_key: event.key,
@@ -1294,10 +1548,45 @@ async fn execute_channel_run_task(params: SlackRunTaskParams) -> RunTaskResult {
         status,
         model: config.model,
         usage,
+        approval_prompts,
         error: update_result.err().map(|error| error.to_string()),
     }
 }
 
+/// Posts one interactive Block Kit prompt per pending approval; returns how many were posted.
+async fn post_approval_prompts(
+    slack_client: &SlackApiClient,
+    event: &SlackBridgeEvent,
+    approvals: &[SlackApprovalPrompt],
+) -> usize {
+    let mut posted = 0_usize;
+    for approval in approvals {
+        let context = SlackApprovalContext {
+            request_id: approval.request_id.clone(),
+            requester_id: event.user_id.clone(),
+            channel_id: event.channel_id.clone(),
+            thread_ts: event.reply_thread_ts().map(ToOwned::to_owned),
+            message_ts: None,
+        };
+        match slack_client
+            .post_blocks_message(
+                &event.channel_id,
+                &render_slack_approval_fallback_text(approval),
+                &render_slack_approval_blocks(approval, &context),
+                event.reply_thread_ts(),
+            )
+            .await
+        {
+            Ok(_) => posted = posted.saturating_add(1),
+            Err(error) => eprintln!(
+                "slack bridge failed to post approval prompt: request_id={} error={error}",
+                approval.request_id
+            ),
+        }
+    }
+    posted
+}
+
 async fn run_prompt_for_event(
     config: &SlackBridgeRuntimeConfig,
     state_dir: &Path,
@@ -1395,6 +1684,11 @@ async fn run_prompt_for_event(
     } else {
         None
     };
+    let pending_approvals = if status == PromptRunStatus::Completed {
+        extract_pending_approvals(&agent.messages()[start_index..])
+    } else {
+        Vec::new()
+    };
     let (assistant_reply, send_file_delivery) = if status == PromptRunStatus::Cancelled {
         ("Run cancelled before completion.".to_string(), None)
     } else if status == PromptRunStatus::TimedOut {
@@ -1494,6 +1788,7 @@ async fn run_prompt_for_event(
         usage,
         downloaded_files,
         artifact,
+        pending_approvals,
     })
 }
 
@@ -34,6 +34,12 @@ struct SlackChatMessageResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct SlackViewsOpenResponse {
+    ok: bool,
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct SlackGetUploadUrlExternalResponse {
     ok: bool,
@@ -242,6 +248,119 @@ impl SlackApiClient {
         })
     }
 
+    pub(super) async fn post_blocks_message(
+        &self,
+        channel: &str,
+        text: &str,
+        blocks: &Value,
+        thread_ts: Option<&str>,
+    ) -> Result<SlackPostedMessage> {
+        let mut payload = json!({
+            "channel": channel,
+            "text": text,
+            "blocks": blocks,
+            "unfurl_links": false,
+            "unfurl_media": false,
+        });
+        if let Some(thread_ts) = thread_ts {
+            payload["thread_ts"] = Value::String(thread_ts.to_string());
+        }
+
+        let response: SlackChatMessageResponse = self
+            .request_json(
+                "chat.postMessage",
+                || {
+                    self.http
+                        .post(format!("{}/chat.postMessage", self.api_base))
+                        .bearer_auth(&self.bot_token)
+                        .json(&payload)
+                },
+                true,
+            )
+            .await?;
+        if !response.ok {
+            bail!(
+                "slack chat.postMessage failed: {}",
+                response
+                    .error
+                    .unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+        Ok(SlackPostedMessage {
+            channel: response.channel.unwrap_or_else(|| channel.to_string()),
+            ts: response
+                .ts
+                .ok_or_else(|| anyhow!("slack chat.postMessage response missing ts"))?,
+        })
+    }
+
+    pub(super) async fn update_blocks_message(
+        &self,
+        channel: &str,
+        ts: &str,
+        text: &str,
+        blocks: &Value,
+    ) -> Result<SlackPostedMessage> {
+        let payload = json!({
+            "channel": channel,
+            "ts": ts,
+            "text": text,
+            "blocks": blocks,
+        });
+        let response: SlackChatMessageResponse = self
+            .request_json(
+                "chat.update",
+                || {
+                    self.http
+                        .post(format!("{}/chat.update", self.api_base))
+                        .bearer_auth(&self.bot_token)
+                        .json(&payload)
+                },
+                true,
+            )
+            .await?;
+        if !response.ok {
+            bail!(
+                "slack chat.update failed: {}",
+                response
+                    .error
+                    .unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+        Ok(SlackPostedMessage {
+            channel: response.channel.unwrap_or_else(|| channel.to_string()),
+            ts: response.ts.unwrap_or_else(|| ts.to_string()),
+        })
+    }
+
+    pub(super) async fn open_view(&self, trigger_id: &str, view: &Value) -> Result<()> {
+        let payload = json!({
+            "trigger_id": trigger_id,
+            "view": view,
+        });
+        let response: SlackViewsOpenResponse = self
+            .request_json(
+                "views.open",
+                || {
+                    self.http
+                        .post(format!("{}/views.open", self.api_base))
+                        .bearer_auth(&self.bot_token)
+                        .json(&payload)
+                },
+                true,
+            )
+            .await?;
+        if !response.ok {
+            bail!(
+                "slack views.open failed: {}",
+                response
+                    .error
+                    .unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+        Ok(())
+    }
+
     pub(super) async fn upload_file_v2(
         &self,
         channel: &str,
@@ -0,0 +1,417 @@
+//! Block Kit approval prompts and interactive payload parsing for the Slack bridge.
+//!
+//! Approval-gated tool calls are surfaced as messages with Approve/Deny buttons.
+//! Deny opens a modal that collects an optional reason. Button values and modal
+//! metadata carry a serialized [`SlackApprovalContext`], so decisions survive
+//! bridge restarts without extra runtime state.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tau_ai::{Message, MessageRole};
+
+use crate::slack_helpers::truncate_for_slack;
+
+pub(super) const SLACK_APPROVAL_APPROVE_ACTION_ID: &str = "tau_approval_approve";
+pub(super) const SLACK_APPROVAL_DENY_ACTION_ID: &str = "tau_approval_deny";
+pub(super) const SLACK_APPROVAL_REASON_CALLBACK_ID: &str = "tau_approval_reason";
+const SLACK_APPROVAL_ACTIONS_BLOCK_ID: &str = "tau_approval_actions";
+const SLACK_APPROVAL_REASON_BLOCK_ID: &str = "tau_approval_reason_block";
+const SLACK_APPROVAL_REASON_ACTION_ID: &str = "tau_approval_reason_input";
+const SLACK_APPROVAL_REASON_MAX_CHARS: usize = 500;
+const SLACK_MAX_APPROVAL_PROMPTS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Approval-gated tool call extracted from a finished run.
+pub(super) struct SlackApprovalPrompt {
+    pub(super) request_id: String,
+    pub(super) rule_id: String,
+    pub(super) action_kind: String,
+    pub(super) summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Routing data embedded in approval buttons and the deny-reason modal.
+pub(super) struct SlackApprovalContext {
+    pub(super) request_id: String,
+    pub(super) requester_id: String,
+    pub(super) channel_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) thread_ts: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) message_ts: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SlackApprovalDecision {
+    Approve,
+    Deny,
+}
+
+impl SlackApprovalDecision {
+    /// Subcommand passed to the approvals command executor.
+    pub(super) fn command_verb(&self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Deny => "reject",
+        }
+    }
+
+    pub(super) fn status_label(&self) -> &'static str {
+        match self {
+            Self::Approve => "approved",
+            Self::Deny => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Normalized `block_actions` / `view_submission` payload relevant to the bridge.
+pub(super) enum SlackInteraction {
+    ApprovalButton {
+        decision: SlackApprovalDecision,
+        context: SlackApprovalContext,
+        user_id: String,
+        trigger_id: Option<String>,
+        action_ts: String,
+    },
+    ApprovalReasonSubmitted {
+        context: SlackApprovalContext,
+        user_id: String,
+        view_id: String,
+        reason: Option<String>,
+    },
+}
+
+impl SlackInteraction {
+    /// Stable dedupe key for the processed-event store.
+    pub(super) fn key(&self) -> String {
+        match self {
+            Self::ApprovalButton {
+                context, action_ts, ..
+            } => format!("interactive:{}:{}", context.request_id, action_ts),
+            Self::ApprovalReasonSubmitted {
+                context, view_id, ..
+            } => format!("interactive:{}:{}", context.request_id, view_id),
+        }
+    }
+
+    pub(super) fn context(&self) -> &SlackApprovalContext {
+        match self {
+            Self::ApprovalButton { context, .. }
+            | Self::ApprovalReasonSubmitted { context, .. } => context,
+        }
+    }
+
+    pub(super) fn user_id(&self) -> &str {
+        match self {
+            Self::ApprovalButton { user_id, .. }
+            | Self::ApprovalReasonSubmitted { user_id, .. } => user_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackInteractivePayload {
+    #[serde(rename = "type")]
+    payload_type: String,
+    #[serde(default)]
+    user: Option<SlackInteractiveUser>,
+    #[serde(default)]
+    trigger_id: Option<String>,
+    #[serde(default)]
+    container: Option<SlackInteractiveContainer>,
+    #[serde(default)]
+    actions: Vec<SlackInteractiveAction>,
+    #[serde(default)]
+    view: Option<SlackInteractiveView>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackInteractiveUser {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackInteractiveContainer {
+    #[serde(default)]
+    message_ts: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackInteractiveAction {
+    action_id: String,
+    #[serde(default)]
+    value: Option<String>,
+    #[serde(default)]
+    action_ts: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackInteractiveView {
+    id: String,
+    #[serde(default)]
+    callback_id: Option<String>,
+    #[serde(default)]
+    private_metadata: Option<String>,
+    #[serde(default)]
+    state: Value,
+}
+
+/// Collects approval-gated tool calls from a run so they can be offered as buttons.
+pub(super) fn extract_pending_approvals(messages: &[Message]) -> Vec<SlackApprovalPrompt> {
+    let mut approvals: Vec<SlackApprovalPrompt> = Vec::new();
+    for message in messages {
+        if message.role != MessageRole::Tool || !message.is_error {
+            continue;
+        }
+        let Ok(payload) = serde_json::from_str::<Value>(&message.text_content()) else {
+            continue;
+        };
+        if payload.get("policy_rule").and_then(Value::as_str) != Some("approval_gate") {
+            continue;
+        }
+        if payload.get("reason_code").and_then(Value::as_str) == Some("approval_rejected") {
+            continue;
+        }
+        let Some(request_id) = payload
+            .get("approval_request_id")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            continue;
+        };
+        if approvals
+            .iter()
+            .any(|approval| approval.request_id == request_id)
+        {
+            continue;
+        }
+        let summary = match payload.get("action") {
+            Some(Value::String(action)) => action.clone(),
+            Some(action) => action.to_string(),
+            None => message.tool_name.clone().unwrap_or_default(),
+        };
+        approvals.push(SlackApprovalPrompt {
+            request_id: request_id.to_string(),
+            rule_id: payload
+                .get("approval_rule_id")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string(),
+            action_kind: payload
+                .get("action_kind")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string(),
+            summary: truncate_for_slack(&summary, 600),
+        });
+        if approvals.len() >= SLACK_MAX_APPROVAL_PROMPTS {
+            break;
+        }
+    }
+    approvals
+}
+
+pub(super) fn render_slack_approval_fallback_text(prompt: &SlackApprovalPrompt) -> String {
+    format!(
+        "Approval required for request {} ({})",
+        prompt.request_id, prompt.action_kind
+    )
+}
+
+pub(super) fn render_slack_approval_blocks(
+    prompt: &SlackApprovalPrompt,
+    context: &SlackApprovalContext,
+) -> Value {
+    let value = serde_json::to_string(context).unwrap_or_default();
+    json!([
+        {
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": format!(
+                    "*Approval required* `{}`\nTau paused `{}` requested by <@{}>:\n```{}```\nrule `{}`",
+                    prompt.request_id,
+                    prompt.action_kind,
+                    context.requester_id,
+                    prompt.summary,
+                    prompt.rule_id,
+                ),
+            },
+        },
+        {
+            "type": "actions",
+            "block_id": SLACK_APPROVAL_ACTIONS_BLOCK_ID,
+            "elements": [
+                {
+                    "type": "button",
+                    "action_id": SLACK_APPROVAL_APPROVE_ACTION_ID,
+                    "style": "primary",
+                    "text": { "type": "plain_text", "text": "Approve" },
+                    "value": value,
+                },
+                {
+                    "type": "button",
+                    "action_id": SLACK_APPROVAL_DENY_ACTION_ID,
+                    "style": "danger",
+                    "text": { "type": "plain_text", "text": "Deny" },
+                    "value": value,
+                },
+            ],
+        },
+    ])
+}
+
+/// Replacement content for an approval prompt once a decision is recorded.
+pub(super) fn render_slack_approval_resolved(
+    context: &SlackApprovalContext,
+    decision: SlackApprovalDecision,
+    actor_id: &str,
+    reason: Option<&str>,
+) -> (String, Value) {
+    let mut text = format!(
+        "Approval `{}` {} by <@{}>.",
+        context.request_id,
+        decision.status_label(),
+        actor_id
+    );
+    if let Some(reason) = reason {
+        text.push_str(&format!("\nReason: {reason}"));
+    }
+    let blocks = json!([
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": text },
+        },
+    ]);
+    (text, blocks)
+}
+
+pub(super) fn render_slack_approval_reason_modal(context: &SlackApprovalContext) -> Value {
+    json!({
+        "type": "modal",
+        "callback_id": SLACK_APPROVAL_REASON_CALLBACK_ID,
+        "private_metadata": serde_json::to_string(context).unwrap_or_default(),
+        "title": { "type": "plain_text", "text": "Deny approval" },
+        "submit": { "type": "plain_text", "text": "Deny" },
+        "close": { "type": "plain_text", "text": "Cancel" },
+        "blocks": [
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!("Deny approval request `{}`.", context.request_id),
+                },
+            },
+            {
+                "type": "input",
+                "block_id": SLACK_APPROVAL_REASON_BLOCK_ID,
+                "optional": true,
+                "label": { "type": "plain_text", "text": "Reason" },
+                "element": {
+                    "type": "plain_text_input",
+                    "action_id": SLACK_APPROVAL_REASON_ACTION_ID,
+                    "multiline": true,
+                    "max_length": SLACK_APPROVAL_REASON_MAX_CHARS,
+                },
+            },
+        ],
+    })
+}
+
+/// Prompt text used to resume the paused agent turn after a decision.
+pub(super) fn render_approval_resume_text(
+    context: &SlackApprovalContext,
+    decision: SlackApprovalDecision,
+    actor_id: &str,
+    reason: Option<&str>,
+) -> String {
+    let mut text = format!(
+        "Approval request `{}` was {} by <@{}>.",
+        context.request_id,
+        decision.status_label(),
+        actor_id
+    );
+    if let Some(reason) = reason {
+        text.push_str(&format!(" Reason: {reason}"));
+    }
+    match decision {
+        SlackApprovalDecision::Approve => text.push_str(
+            " Continue the previous request and retry the approved action if it is still needed.",
+        ),
+        SlackApprovalDecision::Deny => text.push_str(
+            " Do not retry the rejected action; continue the previous request without it or explain what is blocked.",
+        ),
+    }
+    text
+}
+
+/// Normalizes a Socket Mode `interactive` payload into a bridge interaction.
+pub(super) fn parse_interactive_payload(payload: &Value) -> Option<SlackInteraction> {
+    let payload = serde_json::from_value::<SlackInteractivePayload>(payload.clone()).ok()?;
+    let user_id = payload
+        .user
+        .map(|user| user.id)
+        .filter(|id| !id.trim().is_empty())?;
+    match payload.payload_type.as_str() {
+        "block_actions" => {
+            let action = payload.actions.into_iter().find(|action| {
+                action.action_id == SLACK_APPROVAL_APPROVE_ACTION_ID
+                    || action.action_id == SLACK_APPROVAL_DENY_ACTION_ID
+            })?;
+            let decision = if action.action_id == SLACK_APPROVAL_APPROVE_ACTION_ID {
+                SlackApprovalDecision::Approve
+            } else {
+                SlackApprovalDecision::Deny
+            };
+            let mut context = parse_approval_context(action.value.as_deref()?)?;
+            context.message_ts = payload
+                .container
+                .and_then(|container| container.message_ts)
+                .or(context.message_ts);
+            Some(SlackInteraction::ApprovalButton {
+                decision,
+                context,
+                user_id,
+                trigger_id: payload.trigger_id.filter(|id| !id.trim().is_empty()),
+                action_ts: action.action_ts.unwrap_or_default(),
+            })
+        }
+        "view_submission" => {
+            let view = payload.view?;
+            if view.callback_id.as_deref() != Some(SLACK_APPROVAL_REASON_CALLBACK_ID) {
+                return None;
+            }
+            let context = parse_approval_context(view.private_metadata.as_deref()?)?;
+            let reason = view
+                .state
+                .pointer(&format!(
+                    "/values/{SLACK_APPROVAL_REASON_BLOCK_ID}/{SLACK_APPROVAL_REASON_ACTION_ID}/value"
+                ))
+                .and_then(Value::as_str)
+                .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|value| !value.is_empty())
+                .map(|value| truncate_for_slack(&value, SLACK_APPROVAL_REASON_MAX_CHARS));
+            Some(SlackInteraction::ApprovalReasonSubmitted {
+                context,
+                user_id,
+                view_id: view.id,
+                reason,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn parse_approval_context(raw: &str) -> Option<SlackApprovalContext> {
+    let context = serde_json::from_str::<SlackApprovalContext>(raw).ok()?;
+    let request_id = context.request_id.trim();
+    if request_id.is_empty()
+        || request_id.contains(char::is_whitespace)
+        || context.channel_id.trim().is_empty()
+    {
+        return None;
+    }
+    Some(context)
+}
@@ -15,6 +15,10 @@ use tempfile::tempdir;
 use tokio::time::sleep;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
 
+use super::slack_interactivity::{
+    extract_pending_approvals, parse_interactive_payload, render_slack_approval_blocks,
+    SlackApprovalContext, SlackApprovalDecision, SlackApprovalPrompt, SlackInteraction,
+};
 use super::{
     dequeue_coalesced_event_for_run, dispatch_send_file_directive, event_is_stale,
     extract_send_file_response_directive, normalize_artifact_retention_days,
@@ -46,6 +50,10 @@ impl LlmClient for StaticReplyClient {
                 output_tokens: 8,
                 total_tokens: 21,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -65,6 +73,10 @@ impl LlmClient for SlowReplyClient {
                 output_tokens: 3,
                 total_tokens: 8,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -93,6 +105,10 @@ impl LlmClient for SendFileDirectiveClient {
                 output_tokens: 4,
                 total_tokens: 11,
                 cached_input_tokens: 0,
+                reasoning_tokens: 0,
+                hedge_input_tokens: 0,
+                hedge_output_tokens: 0,
+                hedge_model: None,
             },
         })
     }
@@ -952,6 +968,7 @@ fn functional_render_slack_response_thread_splits_long_output() {
             created_unix_ms: 1,
             expires_unix_ms: Some(2),
         },
+        pending_approvals: vec![],
     };
     let (summary, detail) = render_slack_response(&event, &run, true, 10);
     assert!(summary.contains("full response posted in this thread"));
@@ -1829,3 +1846,326 @@ async fn regression_duplicate_command_events_remain_idempotent_with_markers() {
     );
     assert!(channel_log.contains("\"response_marker\":\"<!-- tau-slack-event:EvDup:C1:55.1 -->\""));
 }
+
+fn approval_context() -> SlackApprovalContext {
+    SlackApprovalContext {
+        request_id: "req-1".to_string(),
+        requester_id: "U1".to_string(),
+        channel_id: "C1".to_string(),
+        thread_ts: Some("10.0".to_string()),
+        message_ts: None,
+    }
+}
+
+fn write_pending_approval_request(state_dir: &Path) {
+    let approvals_dir = state_dir.join("approvals");
+    std::fs::create_dir_all(&approvals_dir).expect("approvals dir");
+    let now_ms = current_unix_timestamp_ms();
+    std::fs::write(
+        approvals_dir.join("requests.json"),
+        serde_json::to_string_pretty(&json!({
+            "schema_version": 1,
+            "next_request_id": 2,
+            "requests": [{
+                "id": "req-1",
+                "rule_id": "bash-rm",
+                "action_kind": "tool:bash",
+                "action_summary": "bash command='rm -rf build' cwd=default",
+                "fingerprint": "fp-1",
+                "status": "pending",
+                "created_at_ms": now_ms,
+                "expires_at_ms": now_ms + 600_000,
+                "decision_at_ms": null,
+                "decision_reason": null,
+                "decision_actor": null,
+                "consumed_at_ms": null
+            }]
+        }))
+        .expect("serialize approvals store"),
+    )
+    .expect("write approvals store");
+}
+
+fn approval_button_envelope(envelope_id: &str, action_id: &str) -> SlackSocketEnvelope {
+    SlackSocketEnvelope {
+        envelope_id: envelope_id.to_string(),
+        envelope_type: "interactive".to_string(),
+        payload: json!({
+            "type": "block_actions",
+            "user": {"id": "U2"},
+            "trigger_id": "trigger-1",
+            "container": {"type": "message", "message_ts": "20.0", "channel_id": "C1"},
+            "actions": [{
+                "action_id": action_id,
+                "value": serde_json::to_string(&approval_context()).expect("context json"),
+                "action_ts": format!("{envelope_id}.1"),
+            }]
+        }),
+    }
+}
+
+#[test]
+fn unit_extract_pending_approvals_collects_gated_tool_errors_once() {
+    let gated = json!({
+        "policy_rule": "approval_gate",
+        "action_kind": "tool:bash",
+        "action": {"kind": "tool_bash", "command": "rm -rf build"},
+        "approval_request_id": "req-1",
+        "approval_rule_id": "bash-rm",
+        "reason_code": "approval_required",
+    })
+    .to_string();
+    let rejected = json!({
+        "policy_rule": "approval_gate",
+        "approval_request_id": "req-2",
+        "reason_code": "approval_rejected",
+    })
+    .to_string();
+    let messages = vec![
+        Message::tool_result("call-1", "bash", &gated, true),
+        Message::tool_result("call-2", "bash", &gated, true),
+        Message::tool_result("call-3", "bash", &rejected, true),
+        Message::tool_result("call-4", "bash", &gated, false),
+    ];
+
+    let approvals = extract_pending_approvals(&messages);
+    assert_eq!(approvals.len(), 1);
+    assert_eq!(approvals[0].request_id, "req-1");
+    assert_eq!(approvals[0].rule_id, "bash-rm");
+    assert_eq!(approvals[0].action_kind, "tool:bash");
+    assert!(approvals[0].summary.contains("rm -rf build"));
+}
+
+#[test]
+fn unit_parse_interactive_payload_maps_buttons_and_reason_modal() {
+    let prompt = SlackApprovalPrompt {
+        request_id: "req-1".to_string(),
+        rule_id: "bash-rm".to_string(),
+        action_kind: "tool:bash".to_string(),
+        summary: "rm -rf build".to_string(),
+    };
+    let blocks = render_slack_approval_blocks(&prompt, &approval_context());
+    let elements = blocks[1]["elements"].as_array().expect("button elements");
+    assert_eq!(elements.len(), 2);
+    assert_eq!(elements[0]["action_id"], "tau_approval_approve");
+    assert_eq!(elements[1]["action_id"], "tau_approval_deny");
+
+    let approve = parse_interactive_payload(
+        &approval_button_envelope("env-approve", "tau_approval_approve").payload,
+    )
+    .expect("approve interaction");
+    let SlackInteraction::ApprovalButton {
+        decision,
+        context,
+        user_id,
+        trigger_id,
+        ..
+    } = approve
+    else {
+        panic!("expected approval button");
+    };
+    assert_eq!(decision, SlackApprovalDecision::Approve);
+    assert_eq!(context.message_ts.as_deref(), Some("20.0"));
+    assert_eq!(context.requester_id, "U1");
+    assert_eq!(user_id, "U2");
+    assert_eq!(trigger_id.as_deref(), Some("trigger-1"));
+
+    let mut submitted_context = approval_context();
+    submitted_context.message_ts = Some("20.0".to_string());
+    let submission = parse_interactive_payload(&json!({
+        "type": "view_submission",
+        "user": {"id": "U2"},
+        "view": {
+            "id": "V1",
+            "callback_id": "tau_approval_reason",
+            "private_metadata": serde_json::to_string(&submitted_context).expect("context json"),
+            "state": {"values": {"tau_approval_reason_block": {
+                "tau_approval_reason_input": {"type": "plain_text_input", "value": "  not on\n prod  "}
+            }}}
+        }
+    }))
+    .expect("reason submission");
+    assert_eq!(
+        submission,
+        SlackInteraction::ApprovalReasonSubmitted {
+            context: submitted_context,
+            user_id: "U2".to_string(),
+            view_id: "V1".to_string(),
+            reason: Some("not on prod".to_string()),
+        }
+    );
+
+    assert!(parse_interactive_payload(
+        &approval_button_envelope("env-other", "some_other_action").payload
+    )
+    .is_none());
+}
+
+#[tokio::test]
+async fn integration_approval_button_resolves_request_and_queues_resume_run() {
+    let server = MockServer::start();
+    let update = server.mock(|when, then| {
+        when.method(POST)
+            .path("/chat.update")
+            .body_includes("\"ts\":\"20.0\"")
+            .body_includes("approved by <@U2>");
+        then.status(200)
+            .json_body(json!({"ok": true, "channel": "C1", "ts": "20.0"}));
+    });
+
+    let temp = tempdir().expect("tempdir");
+    write_pending_approval_request(temp.path());
+    let config = test_config(&server.base_url(), temp.path());
+    let mut runtime = SlackBridgeRuntime::new(config).await.expect("runtime");
+
+    let mut report = PollCycleReport::default();
+    runtime
+        .handle_envelope(
+            approval_button_envelope("env-approve", "tau_approval_approve"),
+            &mut report,
+        )
+        .await
+        .expect("handle approval");
+    runtime
+        .handle_envelope(
+            approval_button_envelope("env-approve", "tau_approval_approve"),
+            &mut report,
+        )
+        .await
+        .expect("handle duplicate approval");
+
+    update.assert_calls(1);
+    assert_eq!(report.queued_events, 1);
+    assert_eq!(report.skipped_duplicate_events, 1);
+    let queued = runtime
+        .channel_queues
+        .get("C1")
+        .and_then(|queue| queue.front())
+        .expect("resume event queued");
+    assert_eq!(queued.kind, SlackBridgeEventKind::ApprovalDecision);
+    assert_eq!(queued.user_id, "U1");
+    assert_eq!(queued.thread_ts.as_deref(), Some("10.0"));
+    assert!(queued.text.contains("req-1"));
+
+    let store = std::fs::read_to_string(temp.path().join("approvals/requests.json"))
+        .expect("read approvals store");
+    assert!(store.contains("\"status\": \"approved\""));
+    assert!(store.contains("\"decision_actor\": \"slack:u2\""));
+}
+
+#[tokio::test]
+async fn integration_deny_button_opens_reason_modal_and_submission_rejects() {
+    let server = MockServer::start();
+    let open_view = server.mock(|when, then| {
+        when.method(POST)
+            .path("/views.open")
+            .body_includes("\"trigger_id\":\"trigger-1\"")
+            .body_includes("tau_approval_reason");
+        then.status(200).json_body(json!({"ok": true}));
+    });
+    let update = server.mock(|when, then| {
+        when.method(POST)
+            .path("/chat.update")
+            .body_includes("rejected by <@U2>")
+            .body_includes("not on prod");
+        then.status(200)
+            .json_body(json!({"ok": true, "channel": "C1", "ts": "20.0"}));
+    });
+
+    let temp = tempdir().expect("tempdir");
+    write_pending_approval_request(temp.path());
+    let config = test_config(&server.base_url(), temp.path());
+    let mut runtime = SlackBridgeRuntime::new(config).await.expect("runtime");
+
+    let mut report = PollCycleReport::default();
+    runtime
+        .handle_envelope(
+            approval_button_envelope("env-deny", "tau_approval_deny"),
+            &mut report,
+        )
+        .await
+        .expect("handle deny click");
+    open_view.assert_calls(1);
+    assert_eq!(report.queued_events, 0);
+
+    let mut context = approval_context();
+    context.message_ts = Some("20.0".to_string());
+    runtime
+        .handle_envelope(
+            SlackSocketEnvelope {
+                envelope_id: "env-submit".to_string(),
+                envelope_type: "interactive".to_string(),
+                payload: json!({
+                    "type": "view_submission",
+                    "user": {"id": "U2"},
+                    "view": {
+                        "id": "V1",
+                        "callback_id": "tau_approval_reason",
+                        "private_metadata": serde_json::to_string(&context).expect("context json"),
+                        "state": {"values": {"tau_approval_reason_block": {
+                            "tau_approval_reason_input": {"value": "not on prod"}
+                        }}}
+                    }
+                }),
+            },
+            &mut report,
+        )
+        .await
+        .expect("handle reason submission");
+
+    update.assert_calls(1);
+    assert_eq!(report.queued_events, 1);
+    let store = std::fs::read_to_string(temp.path().join("approvals/requests.json"))
+        .expect("read approvals store");
+    assert!(store.contains("\"status\": \"rejected\""));
+    assert!(store.contains("not on prod"));
+}
+
+#[tokio::test]
+async fn regression_approval_button_denied_for_unbound_principal_in_rbac_team_mode() {
+    let server = MockServer::start();
+    let notice = server.mock(|when, then| {
+        when.method(POST)
+            .path("/chat.postMessage")
+            .body_includes("is not allowed to decide approval");
+        then.status(200)
+            .json_body(json!({"ok": true, "channel": "C1", "ts": "21.0"}));
+    });
+
+    let temp = tempdir().expect("tempdir");
+    write_pending_approval_request(temp.path());
+    let security_dir = temp.path().join("security");
+    std::fs::create_dir_all(&security_dir).expect("security dir");
+    std::fs::write(
+        security_dir.join("rbac.json"),
+        r#"{
+  "schema_version": 1,
+  "team_mode": true,
+  "bindings": [],
+  "roles": {}
+}
+"#,
+    )
+    .expect("write rbac policy");
+    let config = test_config(&server.base_url(), temp.path());
+    let mut runtime = SlackBridgeRuntime::new(config).await.expect("runtime");
+
+    let mut report = PollCycleReport::default();
+    runtime
+        .handle_envelope(
+            approval_button_envelope("env-approve", "tau_approval_approve"),
+            &mut report,
+        )
+        .await
+        .expect("handle approval");
+
+    notice.assert_calls(1);
+    assert_eq!(report.queued_events, 0);
+    let store = std::fs::read_to_string(temp.path().join("approvals/requests.json"))
+        .expect("read approvals store");
+    assert!(store.contains("\"status\": \"pending\""));
+    let outbound = std::fs::read_to_string(temp.path().join("outbound-events.jsonl"))
+        .expect("read outbound log");
+    assert!(outbound.contains("\"command\":\"approval-interaction\""));
+    assert!(outbound.contains("\"reason_code\":\"deny_unbound_principal\""));
+}
@@ -1,9 +1,9 @@
 use anyhow::Result;
-use tau_ai::ModelRef;
+use tau_ai::{ModelRef, Provider};
 use tau_cli::Cli;
 use tau_provider::{
-    ensure_model_supports_tools, load_model_catalog_with_cache, ModelCatalog,
-    ModelCatalogLoadOptions,
+    discover_local_model_catalog, ensure_model_supports_tools, load_model_catalog_with_cache,
+    ModelCatalog, ModelCatalogLoadOptions,
 };
 
 /// Resolve startup model catalog using CLI cache/refresh settings.
@@ -15,7 +15,22 @@ pub async fn resolve_startup_model_catalog(cli: &Cli) -> Result<ModelCatalog> {
         stale_after_hours: cli.model_catalog_stale_after_hours,
         request_timeout_ms: cli.request_timeout_ms,
     };
-    let catalog = load_model_catalog_with_cache(&options).await?;
+    let mut catalog = load_model_catalog_with_cache(&options).await?;
+    if !cli.model_catalog_offline && cli_selects_local_provider(cli) {
+        match discover_local_model_catalog(&cli.local_api_base, cli.request_timeout_ms).await {
+            Ok(discovered) => {
+                println!(
+                    "model catalog: discovered {} local models from {}",
+                    discovered.entries.len(),
+                    cli.local_api_base
+                );
+                catalog = catalog.merge_overlay(discovered)?;
+            }
+            Err(error) => {
+                println!("model catalog warning: {error:#}");
+            }
+        }
+    }
     println!(
         "model catalog: {}",
         catalog.diagnostics_line(cli.model_catalog_stale_after_hours)
@@ -23,6 +38,13 @@ pub async fn resolve_startup_model_catalog(cli: &Cli) -> Result<ModelCatalog> {
     Ok(catalog)
 }
 
+fn cli_selects_local_provider(cli: &Cli) -> bool {
+    std::iter::once(&cli.model)
+        .chain(cli.fallback_model.iter())
+        .filter_map(|raw| ModelRef::parse(raw).ok())
+        .any(|model_ref| model_ref.provider == Provider::Local)
+}
+
 /// Validate startup primary and fallback models support required tool calling.
 pub fn validate_startup_model_catalog(
     catalog: &ModelCatalog,
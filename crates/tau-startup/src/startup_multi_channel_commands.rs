@@ -41,6 +41,7 @@ pub fn execute_multi_channel_send_command(cli: &Cli) -> Result<()> {
         telegram_api_base: cli.multi_channel_telegram_api_base.trim().to_string(),
         discord_api_base: cli.multi_channel_discord_api_base.trim().to_string(),
         whatsapp_api_base: cli.multi_channel_whatsapp_api_base.trim().to_string(),
+        matrix_homeserver_url: cli.multi_channel_matrix_homeserver_url.trim().to_string(),
         credential_store,
         credential_store_unreadable,
         telegram_bot_token: resolve_non_empty_cli_value(
@@ -55,6 +56,10 @@ pub fn execute_multi_channel_send_command(cli: &Cli) -> Result<()> {
         whatsapp_phone_number_id: resolve_non_empty_cli_value(
             cli.multi_channel_whatsapp_phone_number_id.as_deref(),
         ),
+        matrix_access_token: resolve_non_empty_cli_value(
+            cli.multi_channel_matrix_access_token.as_deref(),
+        ),
+        email: build_multi_channel_command_email_config(cli),
     };
 
     let report = tau_multi_channel::execute_multi_channel_send_action(&config)?;
@@ -73,6 +78,25 @@ pub fn execute_multi_channel_send_command(cli: &Cli) -> Result<()> {
     Ok(())
 }
 
+/// Build the email account for send/lifecycle commands; the password falls back to the
+/// credential store inside `tau-multi-channel`.
+fn build_multi_channel_command_email_config(
+    cli: &Cli,
+) -> tau_multi_channel::MultiChannelEmailAccountConfig {
+    tau_multi_channel::MultiChannelEmailAccountConfig {
+        address: resolve_non_empty_cli_value(cli.multi_channel_email_address.as_deref()),
+        username: resolve_non_empty_cli_value(cli.multi_channel_email_username.as_deref()),
+        password: resolve_non_empty_cli_value(cli.multi_channel_email_password.as_deref()),
+        imap_host: cli.multi_channel_email_imap_host.trim().to_string(),
+        imap_port: cli.multi_channel_email_imap_port,
+        imap_tls: cli.multi_channel_email_imap_tls.into(),
+        imap_mailbox: cli.multi_channel_email_imap_mailbox.trim().to_string(),
+        smtp_host: cli.multi_channel_email_smtp_host.trim().to_string(),
+        smtp_port: cli.multi_channel_email_smtp_port,
+        smtp_tls: cli.multi_channel_email_smtp_tls.into(),
+    }
+}
+
 /// Execute multi-channel channel lifecycle command for login/logout/status/probe.
 pub fn execute_multi_channel_channel_lifecycle_command(cli: &Cli) -> Result<()> {
     let (action, transport, json_output) = if let Some(transport) = cli.multi_channel_channel_status
@@ -112,6 +136,7 @@ pub fn execute_multi_channel_channel_lifecycle_command(cli: &Cli) -> Result<()>
         telegram_api_base: cli.multi_channel_telegram_api_base.trim().to_string(),
         discord_api_base: cli.multi_channel_discord_api_base.trim().to_string(),
         whatsapp_api_base: cli.multi_channel_whatsapp_api_base.trim().to_string(),
+        matrix_homeserver_url: cli.multi_channel_matrix_homeserver_url.trim().to_string(),
         credential_store,
         credential_store_unreadable,
         telegram_bot_token: resolve_non_empty_cli_value(
@@ -126,6 +151,10 @@ pub fn execute_multi_channel_channel_lifecycle_command(cli: &Cli) -> Result<()>
         whatsapp_phone_number_id: resolve_non_empty_cli_value(
             cli.multi_channel_whatsapp_phone_number_id.as_deref(),
         ),
+        matrix_access_token: resolve_non_empty_cli_value(
+            cli.multi_channel_matrix_access_token.as_deref(),
+        ),
+        email: build_multi_channel_command_email_config(cli),
         probe_online: cli.multi_channel_channel_probe_online,
         probe_online_timeout_ms: tau_multi_channel::default_probe_timeout_ms(),
         probe_online_max_attempts: tau_multi_channel::default_probe_max_attempts(),
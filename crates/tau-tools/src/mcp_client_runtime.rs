@@ -6,9 +6,7 @@
 
 use std::{
     collections::{BTreeMap, BTreeSet},
-    io::{BufRead, BufReader, Write},
     path::{Path, PathBuf},
-    process::{Command, Stdio},
     sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -28,15 +26,26 @@ use tau_provider::{
     CredentialStoreEncryptionMode, IntegrationCredentialStoreRecord,
 };
 
+mod session;
+mod session_manager;
+
+pub use session::McpClientSamplingBackend;
+use session::McpClientSession;
+use session_manager::catalog_tools_for_session;
+pub use session_manager::{
+    execute_mcp_client_command, McpClientSessionManager, MCP_CLIENT_COMMAND_USAGE,
+};
+
 const MCP_JSONRPC_VERSION: &str = "2.0";
 const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
 const MCP_CLIENT_CONFIG_SCHEMA_VERSION: u32 = 1;
 const MCP_CLIENT_INIT_REQUEST_ID: &str = "tau-client-init";
-const MCP_CLIENT_TOOLS_LIST_REQUEST_ID: &str = "tau-client-tools-list";
-const MCP_CLIENT_TOOLS_CALL_REQUEST_ID: &str = "tau-client-tools-call";
 const MCP_CLIENT_TOOL_PREFIX: &str = "mcp.";
 const MCP_CLIENT_OAUTH_INTEGRATION_PREFIX: &str = "mcp.oauth.";
 const DEFAULT_HTTP_TIMEOUT_MS: u64 = 30_000;
+const DEFAULT_STDIO_TIMEOUT_MS: u64 = 120_000;
+const DEFAULT_SAMPLING_MAX_TOKENS: u32 = 1_024;
+const DEFAULT_SAMPLING_MAX_REQUESTS: usize = 16;
 const DEFAULT_OAUTH_REFRESH_SKEW_SECONDS: u64 = 60;
 const OAUTH_CODE_CHALLENGE_METHOD_S256: &str = "S256";
 
@@ -52,6 +61,18 @@ fn default_http_timeout_ms() -> u64 {
     DEFAULT_HTTP_TIMEOUT_MS
 }
 
+fn default_stdio_timeout_ms() -> u64 {
+    DEFAULT_STDIO_TIMEOUT_MS
+}
+
+fn default_sampling_max_tokens() -> u32 {
+    DEFAULT_SAMPLING_MAX_TOKENS
+}
+
+fn default_sampling_max_requests() -> usize {
+    DEFAULT_SAMPLING_MAX_REQUESTS
+}
+
 fn default_oauth_refresh_skew_seconds() -> u64 {
     DEFAULT_OAUTH_REFRESH_SKEW_SECONDS
 }
@@ -80,6 +101,29 @@ struct McpClientServerConfig {
     tool_prefix: Option<String>,
     #[serde(default = "default_sse_probe")]
     sse_probe: bool,
+    #[serde(default)]
+    sampling: McpClientSamplingPolicy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+/// Per-server policy for servicing `sampling/createMessage` requests.
+struct McpClientSamplingPolicy {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default = "default_sampling_max_tokens")]
+    max_tokens: u32,
+    #[serde(default = "default_sampling_max_requests")]
+    max_requests: usize,
+}
+
+impl Default for McpClientSamplingPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_tokens: DEFAULT_SAMPLING_MAX_TOKENS,
+            max_requests: DEFAULT_SAMPLING_MAX_REQUESTS,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -105,6 +149,8 @@ struct McpClientStdioTransportConfig {
     env: BTreeMap<String, String>,
     #[serde(default)]
     cwd: Option<PathBuf>,
+    #[serde(default = "default_stdio_timeout_ms")]
+    timeout_ms: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -159,11 +205,13 @@ struct McpClientServerRuntime {
     auth: Option<McpClientAuthConfig>,
     tool_prefix: String,
     sse_probe: bool,
+    sampling: McpClientSamplingPolicy,
 }
 
 #[derive(Debug, Clone)]
 struct McpClientDiscoveredTool {
     server: Arc<McpClientServerRuntime>,
+    session: Arc<McpClientSession>,
     local_tool_name: String,
     remote_tool_name: String,
     description: String,
@@ -174,6 +222,7 @@ struct McpClientDiscoveredTool {
 struct McpClientDiscoveryOutcome {
     config_path: PathBuf,
     servers: Vec<Arc<McpClientServerRuntime>>,
+    sessions: Vec<Arc<McpClientSession>>,
     tools: Vec<McpClientDiscoveredTool>,
     diagnostics: Vec<McpClientDiagnostic>,
 }
@@ -250,12 +299,17 @@ struct McpOauthTokenRecord {
     updated_unix: Option<u64>,
 }
 
+/// Result of starting persistent MCP client sessions for a local runtime.
+pub struct McpClientSessionStartup {
+    pub report: McpClientRegistrationReport,
+    pub sessions: Option<McpClientSessionManager>,
+}
+
 #[derive(Debug, Clone)]
 struct McpClientProxyTool {
     definition: ToolDefinition,
     remote_tool_name: String,
-    server: Arc<McpClientServerRuntime>,
-    context: McpClientRuntimeContext,
+    session: Arc<McpClientSession>,
 }
 
 #[async_trait]
@@ -266,12 +320,17 @@ impl AgentTool for McpClientProxyTool {
 
     async fn execute(&self, arguments: Value) -> ToolExecutionResult {
         let remote_tool_name = self.remote_tool_name.clone();
-        let server = self.server.clone();
-        let context = self.context.clone();
-        let call_server = server.clone();
+        let server = self.session.server().clone();
+        let session = self.session.clone();
         let call_remote_tool_name = remote_tool_name.clone();
         let join = tokio::task::spawn_blocking(move || {
-            execute_remote_tool_call(&call_server, &context, &call_remote_tool_name, arguments)
+            session.request(
+                "tools/call",
+                json!({
+                    "name": call_remote_tool_name,
+                    "arguments": arguments,
+                }),
+            )
         })
         .await;
 
@@ -311,75 +370,140 @@ pub fn execute_mcp_client_inspect_command(cli: &Cli) -> Result<()> {
     Ok(())
 }
 
+/// Discovers MCP client tools and registers them on `agent`; sessions stay
+/// alive only as long as the registered proxy tools.
 pub fn register_mcp_client_tools(
     agent: &mut Agent,
     cli: &Cli,
 ) -> Result<McpClientRegistrationReport> {
+    start_mcp_client_sessions(agent, cli, None).map(|startup| startup.report)
+}
+
+/// Connects persistent sessions to every configured MCP server, registers
+/// their tools plus resource/prompt catalog tools on `agent`, and returns the
+/// session manager used for live tool refresh and the `/mcp` command.
+pub fn start_mcp_client_sessions(
+    agent: &mut Agent,
+    cli: &Cli,
+    sampling: Option<McpClientSamplingBackend>,
+) -> Result<McpClientSessionStartup> {
     if !cli.mcp_client {
-        return Ok(McpClientRegistrationReport {
-            config_path: String::new(),
-            server_count: 0,
-            discovered_tool_count: 0,
-            registered_tool_count: 0,
-            diagnostics: vec![McpClientDiagnostic {
-                server: "global".to_string(),
-                phase: "registration".to_string(),
-                status: "info".to_string(),
-                reason_code: "mcp_client_disabled".to_string(),
-                detail: "mcp client mode is disabled".to_string(),
-            }],
+        return Ok(McpClientSessionStartup {
+            report: McpClientRegistrationReport {
+                config_path: String::new(),
+                server_count: 0,
+                discovered_tool_count: 0,
+                registered_tool_count: 0,
+                diagnostics: vec![McpClientDiagnostic {
+                    server: "global".to_string(),
+                    phase: "registration".to_string(),
+                    status: "info".to_string(),
+                    reason_code: "mcp_client_disabled".to_string(),
+                    detail: "mcp client mode is disabled".to_string(),
+                }],
+            },
+            sessions: None,
         });
     }
 
     let context = McpClientRuntimeContext::from_cli(cli);
-    let outcome = discover_mcp_client_tools(cli, &context)?;
+    let outcome = discover_mcp_client_tools_with_sampling(cli, &context, sampling.as_ref())?;
     let discovered_tool_count = outcome.tools.len();
     let mut diagnostics = outcome.diagnostics;
     let mut registered_tool_count = 0usize;
+    let mut registered_tools = BTreeMap::<String, BTreeSet<String>>::new();
     for tool in outcome.tools {
-        if agent.has_tool(&tool.local_tool_name) {
+        let server_name = tool.server.name.clone();
+        let local_tool_name = tool.local_tool_name.clone();
+        if register_discovered_tool(agent, tool, &mut diagnostics) {
+            registered_tool_count = registered_tool_count.saturating_add(1);
+            registered_tools
+                .entry(server_name)
+                .or_default()
+                .insert(local_tool_name);
+        }
+    }
+    for session in &outcome.sessions {
+        for tool in catalog_tools_for_session(session) {
+            let name = tool.definition().name;
+            if agent.has_tool(&name) {
+                diagnostics.push(McpClientDiagnostic {
+                    server: session.server().name.clone(),
+                    phase: "registration".to_string(),
+                    status: "warn".to_string(),
+                    reason_code: "mcp_client_tool_name_conflict".to_string(),
+                    detail: format!(
+                        "tool '{}' was skipped because a tool with the same name is already registered",
+                        name
+                    ),
+                });
+                continue;
+            }
+            agent.register_tool(tool);
+            registered_tool_count = registered_tool_count.saturating_add(1);
             diagnostics.push(McpClientDiagnostic {
-                server: tool.server.name.clone(),
+                server: session.server().name.clone(),
                 phase: "registration".to_string(),
-                status: "warn".to_string(),
-                reason_code: "mcp_client_tool_name_conflict".to_string(),
-                detail: format!(
-                    "tool '{}' was skipped because a tool with the same name is already registered",
-                    tool.local_tool_name
-                ),
+                status: "ok".to_string(),
+                reason_code: "mcp_client_catalog_tool_registered".to_string(),
+                detail: format!("registered '{}'", name),
             });
-            continue;
         }
-        agent.register_tool(McpClientProxyTool {
-            definition: ToolDefinition {
-                name: tool.local_tool_name.clone(),
-                description: tool.description.clone(),
-                parameters: tool.input_schema.clone(),
-            },
-            remote_tool_name: tool.remote_tool_name.clone(),
-            server: tool.server.clone(),
-            context: context.clone(),
-        });
-        registered_tool_count = registered_tool_count.saturating_add(1);
+    }
+
+    Ok(McpClientSessionStartup {
+        report: McpClientRegistrationReport {
+            config_path: outcome.config_path.display().to_string(),
+            server_count: outcome.servers.len(),
+            discovered_tool_count,
+            registered_tool_count,
+            diagnostics,
+        },
+        sessions: Some(McpClientSessionManager::new(
+            outcome.sessions,
+            registered_tools,
+        )),
+    })
+}
+
+fn register_discovered_tool(
+    agent: &mut Agent,
+    tool: McpClientDiscoveredTool,
+    diagnostics: &mut Vec<McpClientDiagnostic>,
+) -> bool {
+    if agent.has_tool(&tool.local_tool_name) {
         diagnostics.push(McpClientDiagnostic {
             server: tool.server.name.clone(),
             phase: "registration".to_string(),
-            status: "ok".to_string(),
-            reason_code: "mcp_client_tool_registered".to_string(),
+            status: "warn".to_string(),
+            reason_code: "mcp_client_tool_name_conflict".to_string(),
             detail: format!(
-                "registered '{}' mapped to '{}'",
-                tool.local_tool_name, tool.remote_tool_name
+                "tool '{}' was skipped because a tool with the same name is already registered",
+                tool.local_tool_name
             ),
         });
+        return false;
     }
-
-    Ok(McpClientRegistrationReport {
-        config_path: outcome.config_path.display().to_string(),
-        server_count: outcome.servers.len(),
-        discovered_tool_count,
-        registered_tool_count,
-        diagnostics,
-    })
+    diagnostics.push(McpClientDiagnostic {
+        server: tool.server.name.clone(),
+        phase: "registration".to_string(),
+        status: "ok".to_string(),
+        reason_code: "mcp_client_tool_registered".to_string(),
+        detail: format!(
+            "registered '{}' mapped to '{}'",
+            tool.local_tool_name, tool.remote_tool_name
+        ),
+    });
+    agent.register_tool(McpClientProxyTool {
+        definition: ToolDefinition {
+            name: tool.local_tool_name,
+            description: tool.description,
+            parameters: tool.input_schema,
+        },
+        remote_tool_name: tool.remote_tool_name,
+        session: tool.session,
+    });
+    true
 }
 
 pub fn render_mcp_client_inspect_report(report: &McpClientInspectReport) -> String {
@@ -435,6 +559,14 @@ fn build_mcp_client_inspect_report(cli: &Cli) -> Result<McpClientInspectReport>
 fn discover_mcp_client_tools(
     cli: &Cli,
     context: &McpClientRuntimeContext,
+) -> Result<McpClientDiscoveryOutcome> {
+    discover_mcp_client_tools_with_sampling(cli, context, None)
+}
+
+fn discover_mcp_client_tools_with_sampling(
+    cli: &Cli,
+    context: &McpClientRuntimeContext,
+    sampling: Option<&McpClientSamplingBackend>,
 ) -> Result<McpClientDiscoveryOutcome> {
     let config_path = cli.mcp_external_server_config.clone().ok_or_else(|| {
         anyhow!(
@@ -444,11 +576,17 @@ fn discover_mcp_client_tools(
     let servers = load_mcp_client_servers(&config_path)?;
     let server_refs = servers.into_iter().map(Arc::new).collect::<Vec<_>>();
     let mut diagnostics = Vec::new();
+    let mut sessions = Vec::new();
     let mut tools = Vec::new();
 
     for server in &server_refs {
-        match discover_tools_for_server(server, context) {
-            Ok(server_tools) => {
+        let discovered = McpClientSession::connect(server, context, sampling)
+            .map(Arc::new)
+            .and_then(|session| {
+                discover_tools_for_server(&session).map(|server_tools| (session, server_tools))
+            });
+        match discovered {
+            Ok((session, server_tools)) => {
                 diagnostics.push(McpClientDiagnostic {
                     server: server.name.clone(),
                     phase: "discovery".to_string(),
@@ -456,6 +594,7 @@ fn discover_mcp_client_tools(
                     reason_code: "mcp_client_server_discovered".to_string(),
                     detail: format!("discovered {} tool(s)", server_tools.len()),
                 });
+                sessions.push(session);
                 tools.extend(server_tools);
             }
             Err(error) => diagnostics.push(McpClientDiagnostic {
@@ -471,6 +610,7 @@ fn discover_mcp_client_tools(
     Ok(McpClientDiscoveryOutcome {
         config_path,
         servers: server_refs,
+        sessions,
         tools,
         diagnostics,
     })
@@ -528,6 +668,7 @@ fn load_mcp_client_servers(path: &Path) -> Result<Vec<McpClientServerRuntime>> {
             auth: server.auth,
             tool_prefix,
             sse_probe: server.sse_probe,
+            sampling: server.sampling,
         });
     }
 
@@ -609,6 +750,12 @@ fn validate_stdio_transport(
             server_name
         );
     }
+    if transport.timeout_ms == 0 {
+        bail!(
+            "mcp client server '{}' stdio timeout must be greater than 0",
+            server_name
+        );
+    }
     Ok(())
 }
 
@@ -632,44 +779,14 @@ fn validate_http_sse_transport(
 }
 
 fn discover_tools_for_server(
-    server: &Arc<McpClientServerRuntime>,
-    context: &McpClientRuntimeContext,
+    session: &Arc<McpClientSession>,
 ) -> Result<Vec<McpClientDiscoveredTool>> {
-    let responses = call_mcp_server(
-        server,
-        context,
-        &[
-            jsonrpc_request_frame(
-                MCP_CLIENT_INIT_REQUEST_ID,
-                "initialize",
-                json!({
-                    "protocolVersion": MCP_PROTOCOL_VERSION,
-                    "capabilities": {"tools": {"listChanged": true}},
-                    "clientInfo": {
-                        "name": "tau-rs",
-                        "version": env!("CARGO_PKG_VERSION")
-                    }
-                }),
-            ),
-            jsonrpc_request_frame(MCP_CLIENT_TOOLS_LIST_REQUEST_ID, "tools/list", json!({})),
-        ],
-        true,
-    )?;
-    let tools_result =
-        jsonrpc_result_for_id(&responses, MCP_CLIENT_TOOLS_LIST_REQUEST_ID, &server.name)?;
-    let tools_array = tools_result
-        .get("tools")
-        .and_then(Value::as_array)
-        .ok_or_else(|| {
-            anyhow!(
-                "mcp client server '{}' returned invalid tools/list payload",
-                server.name
-            )
-        })?;
+    let server = session.server();
+    let tools_array = session.request_paginated("tools/list", "tools")?;
 
     let mut discovered = Vec::new();
     let mut seen_names = BTreeSet::new();
-    for tool in tools_array {
+    for tool in &tools_array {
         let object = tool.as_object().ok_or_else(|| {
             anyhow!(
                 "mcp client server '{}' returned non-object tool descriptor",
@@ -713,6 +830,7 @@ fn discover_tools_for_server(
             .unwrap_or_else(|| json!({"type":"object","properties":{}}));
         discovered.push(McpClientDiscoveredTool {
             server: server.clone(),
+            session: session.clone(),
             local_tool_name,
             remote_tool_name,
             description,
@@ -722,192 +840,6 @@ fn discover_tools_for_server(
     Ok(discovered)
 }
 
-fn execute_remote_tool_call(
-    server: &Arc<McpClientServerRuntime>,
-    context: &McpClientRuntimeContext,
-    remote_tool_name: &str,
-    arguments: Value,
-) -> Result<Value> {
-    let responses = call_mcp_server(
-        server,
-        context,
-        &[
-            jsonrpc_request_frame(
-                MCP_CLIENT_INIT_REQUEST_ID,
-                "initialize",
-                json!({
-                    "protocolVersion": MCP_PROTOCOL_VERSION,
-                    "capabilities": {"tools": {"listChanged": true}},
-                    "clientInfo": {
-                        "name": "tau-rs",
-                        "version": env!("CARGO_PKG_VERSION")
-                    }
-                }),
-            ),
-            jsonrpc_request_frame(
-                MCP_CLIENT_TOOLS_CALL_REQUEST_ID,
-                "tools/call",
-                json!({
-                    "name": remote_tool_name,
-                    "arguments": arguments,
-                }),
-            ),
-        ],
-        false,
-    )?;
-    jsonrpc_result_for_id(&responses, MCP_CLIENT_TOOLS_CALL_REQUEST_ID, &server.name)
-}
-
-fn call_mcp_server(
-    server: &Arc<McpClientServerRuntime>,
-    context: &McpClientRuntimeContext,
-    requests: &[Value],
-    probe_sse: bool,
-) -> Result<Vec<Value>> {
-    match &server.transport {
-        McpClientTransportRuntime::Stdio(transport) => {
-            call_stdio_mcp_server(server, transport, requests)
-        }
-        McpClientTransportRuntime::HttpSse(transport) => {
-            let bearer_token = resolve_server_bearer_token(server, context)?;
-            if probe_sse && server.sse_probe {
-                probe_http_sse_endpoint(transport, bearer_token.as_deref())?;
-            }
-            call_http_mcp_server(transport, bearer_token.as_deref(), requests)
-        }
-    }
-}
-
-fn call_stdio_mcp_server(
-    server: &Arc<McpClientServerRuntime>,
-    transport: &McpClientStdioTransportConfig,
-    requests: &[Value],
-) -> Result<Vec<Value>> {
-    let mut command = Command::new(&transport.command);
-    command.args(&transport.args);
-    command
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-    if let Some(cwd) = transport.cwd.as_ref() {
-        command.current_dir(cwd);
-    }
-    for (key, value) in &transport.env {
-        command.env(key, value);
-    }
-    let mut child = command.spawn().with_context(|| {
-        format!(
-            "failed to spawn mcp client server '{}' command '{}'",
-            server.name, transport.command
-        )
-    })?;
-
-    {
-        let stdin = child.stdin.as_mut().ok_or_else(|| {
-            anyhow!(
-                "failed to open stdin for mcp client server '{}'",
-                server.name
-            )
-        })?;
-        for request in requests {
-            let line =
-                serde_json::to_string(request).context("failed to encode mcp client request")?;
-            stdin.write_all(line.as_bytes()).with_context(|| {
-                format!("failed to write mcp client request to '{}'", server.name)
-            })?;
-            stdin.write_all(b"\n").with_context(|| {
-                format!(
-                    "failed to write mcp client request terminator to '{}'",
-                    server.name
-                )
-            })?;
-        }
-    }
-
-    let stdout = child.stdout.take().ok_or_else(|| {
-        anyhow!(
-            "failed to open stdout for mcp client server '{}'",
-            server.name
-        )
-    })?;
-    let mut reader = BufReader::new(stdout);
-    let mut responses = Vec::with_capacity(requests.len());
-    for _ in requests {
-        let mut line = String::new();
-        let bytes = reader.read_line(&mut line).with_context(|| {
-            format!(
-                "failed to read response from mcp client server '{}'",
-                server.name
-            )
-        })?;
-        if bytes == 0 {
-            break;
-        }
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-        let value = serde_json::from_str::<Value>(trimmed).with_context(|| {
-            format!(
-                "mcp client server '{}' returned invalid JSON '{}'",
-                server.name, trimmed
-            )
-        })?;
-        responses.push(value);
-    }
-    let status = child
-        .wait()
-        .with_context(|| format!("failed to wait for mcp client server '{}'", server.name))?;
-    if !status.success() {
-        bail!(
-            "mcp client server '{}' exited with status {}",
-            server.name,
-            status
-        );
-    }
-    Ok(responses)
-}
-
-fn call_http_mcp_server(
-    transport: &McpClientHttpSseTransportConfig,
-    bearer_token: Option<&str>,
-    requests: &[Value],
-) -> Result<Vec<Value>> {
-    let client = Client::builder()
-        .timeout(Duration::from_millis(transport.timeout_ms))
-        .build()
-        .context("failed to build mcp client http transport")?;
-    let mut responses = Vec::with_capacity(requests.len());
-
-    for request in requests {
-        let mut outbound = client.post(&transport.endpoint).json(request);
-        for (key, value) in &transport.headers {
-            outbound = outbound.header(key, value);
-        }
-        if let Some(token) = bearer_token {
-            outbound = outbound.bearer_auth(token);
-        }
-        let response = outbound.send().context("mcp client http request failed")?;
-        let status = response.status();
-        if !status.is_success() {
-            let body = response
-                .text()
-                .unwrap_or_else(|_| "<unreadable response body>".to_string());
-            bail!(
-                "mcp client http request failed with status {} body {}",
-                status,
-                body
-            );
-        }
-        let payload = response
-            .json::<Value>()
-            .context("failed to decode mcp client http json response")?;
-        responses.push(payload);
-    }
-
-    Ok(responses)
-}
-
 fn probe_http_sse_endpoint(
     transport: &McpClientHttpSseTransportConfig,
     bearer_token: Option<&str>,
@@ -1370,6 +1302,8 @@ fn classify_mcp_client_error(error: &anyhow::Error) -> &'static str {
         return "mcp_client_jsonrpc_error";
     }
     if message.contains("invalid tools/list payload")
+        || message.contains("invalid resources/list payload")
+        || message.contains("invalid prompts/list payload")
         || message.contains("tool descriptor")
         || message.contains("duplicate local tool")
     {
@@ -1379,9 +1313,13 @@ fn classify_mcp_client_error(error: &anyhow::Error) -> &'static str {
         || message.contains("open stdin")
         || message.contains("open stdout")
         || message.contains("exited with status")
+        || message.contains("session closed")
     {
         return "mcp_client_stdio_transport_failed";
     }
+    if message.contains("timed out after") {
+        return "mcp_client_request_timeout";
+    }
     "mcp_client_runtime_error"
 }
 
@@ -1540,8 +1478,7 @@ done
                 parameters: outcome.tools[0].input_schema.clone(),
             },
             remote_tool_name: outcome.tools[0].remote_tool_name.clone(),
-            server: outcome.tools[0].server.clone(),
-            context,
+            session: outcome.tools[0].session.clone(),
         };
         let result = tool.execute(json!({"value":"hello"})).await;
         assert!(!result.is_error);
@@ -1576,7 +1513,7 @@ done
                 .body_includes("\"method\":\"tools/list\"");
             then.status(200).json_body(json!({
                 "jsonrpc": "2.0",
-                "id": "tau-client-tools-list",
+                "id": "tau-client-1",
                 "result": {
                     "tools": [
                         {
@@ -1716,7 +1653,7 @@ done
                 .body_includes("\"method\":\"tools/list\"");
             then.status(200).json_body(json!({
                 "jsonrpc": "2.0",
-                "id": "tau-client-tools-list",
+                "id": "tau-client-1",
                 "result": {"tools":[{"name":"status","description":"status","inputSchema":{"type":"object"}}]}
             }));
         });
@@ -1874,8 +1811,7 @@ done
                 parameters: outcome.tools[0].input_schema.clone(),
             },
             remote_tool_name: outcome.tools[0].remote_tool_name.clone(),
-            server: outcome.tools[0].server.clone(),
-            context,
+            session: outcome.tools[0].session.clone(),
         };
         let result = tool.execute(json!({})).await;
         assert!(result.is_error);
@@ -1920,4 +1856,364 @@ done
         assert_eq!(report.registered_tool_count, 1);
         assert!(agent.has_tool("mcp.local_stdio.echo"));
     }
+
+    struct SamplingClient;
+
+    #[async_trait]
+    impl LlmClient for SamplingClient {
+        async fn complete(&self, request: ChatRequest) -> Result<ChatResponse, TauAiError> {
+            assert_eq!(request.max_tokens, Some(64));
+            Ok(ChatResponse {
+                message: tau_ai::Message::assistant_text("sampled reply"),
+                finish_reason: Some("stop".to_string()),
+                usage: tau_ai::ChatUsage::default(),
+            })
+        }
+    }
+
+    fn write_executable_script(path: &Path, body: &str) {
+        std::fs::write(path, body).expect("write script");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(path).expect("metadata").permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(path, perms).expect("chmod");
+        }
+    }
+
+    fn mcp_client_cli(temp: &Path, config_path: &Path) -> Cli {
+        let mut cli = parse_cli_with_stack(
+            [
+                "tau-rs",
+                "--mcp-client",
+                "--mcp-external-server-config",
+                config_path.to_string_lossy().as_ref(),
+            ]
+            .as_slice(),
+        );
+        cli.credential_store = temp.join("credentials.json");
+        cli
+    }
+
+    fn proxy_tool_for(tool: &McpClientDiscoveredTool) -> McpClientProxyTool {
+        McpClientProxyTool {
+            definition: ToolDefinition {
+                name: tool.local_tool_name.clone(),
+                description: tool.description.clone(),
+                parameters: tool.input_schema.clone(),
+            },
+            remote_tool_name: tool.remote_tool_name.clone(),
+            session: tool.session.clone(),
+        }
+    }
+
+    const CATALOG_SERVER_SCRIPT: &str = r#"#!/bin/sh
+set -eu
+while IFS= read -r line; do
+  method=$(printf '%s' "$line" | sed -n 's/.*"method":"\([^"]*\)".*/\1/p')
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$method" in
+    initialize)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"protocolVersion":"2024-11-05","capabilities":{"tools":{},"resources":{},"prompts":{}}}}\n' "$id" ;;
+    tools/list)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    resources/list)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"resources":[{"uri":"file:///notes.md","name":"notes","mimeType":"text/markdown"}]}}\n' "$id" ;;
+    resources/read)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"contents":[{"uri":"file:///notes.md","mimeType":"text/markdown","text":"release notes"}]}}\n' "$id" ;;
+    prompts/list)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"prompts":[{"name":"summarize","description":"summarize notes","arguments":[{"name":"topic","required":true}]}]}}\n' "$id" ;;
+    prompts/get)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"messages":[{"role":"user","content":{"type":"text","text":"summarize the release"}}]}}\n' "$id" ;;
+  esac
+done
+"#;
+
+    const SAMPLING_SERVER_SCRIPT: &str = r#"#!/bin/sh
+set -eu
+while IFS= read -r line; do
+  method=$(printf '%s' "$line" | sed -n 's/.*"method":"\([^"]*\)".*/\1/p')
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$method" in
+    initialize)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"protocolVersion":"2024-11-05","capabilities":{"tools":{}}}}\n' "$id" ;;
+    tools/list)
+      printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[{"name":"ask","inputSchema":{"type":"object"}}]}}\n' "$id" ;;
+    tools/call)
+      printf '{"jsonrpc":"2.0","id":"srv-sample-1","method":"sampling/createMessage","params":{"messages":[{"role":"user","content":{"type":"text","text":"hello?"}}],"maxTokens":64}}\n'
+      IFS= read -r reply
+      case "$reply" in
+        *'"error"'*)
+          printf '{"jsonrpc":"2.0","id":"%s","result":{"isError":true,"structuredContent":{"reason_code":"sampling_rejected"}}}\n' "$id" ;;
+        *)
+          text=$(printf '%s' "$reply" | sed -n 's/.*"text":"\([^"]*\)".*/\1/p')
+          printf '{"jsonrpc":"2.0","id":"%s","result":{"isError":false,"structuredContent":{"sampled":"%s"}}}\n' "$id" "$text" ;;
+      esac ;;
+  esac
+done
+"#;
+
+    #[tokio::test]
+    async fn functional_mcp_client_stdio_session_reuses_one_process_across_calls() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-counted.sh");
+        let counter_path = temp.path().join("spawn-count.log");
+        write_executable_script(
+            &script_path,
+            &format!(
+                "#!/bin/sh\necho started >> '{}'\nexec '{}'\n",
+                counter_path.display(),
+                temp.path().join("mock-mcp-inner.sh").display()
+            ),
+        );
+        write_stdio_mock_script(&temp.path().join("mock-mcp-inner.sh"));
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{"name": "local_stdio", "command": script_path.display().to_string()}]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+
+        let context = McpClientRuntimeContext::from_cli(&cli);
+        let outcome = discover_mcp_client_tools(&cli, &context).expect("discover tools");
+        let tool = proxy_tool_for(&outcome.tools[0]);
+        for value in ["first", "second", "third"] {
+            let result = tool.execute(json!({ "value": value })).await;
+            assert!(!result.is_error, "unexpected error: {}", result.content);
+        }
+
+        let spawn_log = std::fs::read_to_string(&counter_path).expect("read spawn log");
+        assert_eq!(spawn_log.lines().count(), 1);
+    }
+
+    #[test]
+    fn integration_mcp_client_tools_list_changed_reregisters_agent_tools() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-list-changed.sh");
+        let state_path = temp.path().join("listed.flag");
+        let ids_path = temp.path().join("tools-list-ids.log");
+        write_executable_script(
+            &script_path,
+            &format!(
+                r#"#!/bin/sh
+set -eu
+while IFS= read -r line; do
+  method=$(printf '%s' "$line" | sed -n 's/.*"method":"\([^"]*\)".*/\1/p')
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  if [ "$method" = "initialize" ]; then
+    printf '{{"jsonrpc":"2.0","id":"%s","result":{{"protocolVersion":"2024-11-05","capabilities":{{"tools":{{"listChanged":true}}}}}}}}\n' "$id"
+  elif [ "$method" = "tools/list" ]; then
+    printf '%s\n' "$id" >> '{ids}'
+    if [ -f '{state}' ]; then
+      printf '{{"jsonrpc":"2.0","id":"%s","result":{{"tools":[{{"name":"extra"}}]}}}}\n' "$id"
+    else
+      : > '{state}'
+      printf '{{"jsonrpc":"2.0","id":"%s","result":{{"tools":[{{"name":"echo"}}]}}}}\n' "$id"
+      printf '{{"jsonrpc":"2.0","method":"notifications/tools/list_changed"}}\n'
+    fi
+  fi
+done
+"#,
+                state = state_path.display(),
+                ids = ids_path.display()
+            ),
+        );
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{"name": "live", "command": script_path.display().to_string()}]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+
+        let mut agent = Agent::new(Arc::new(NoopClient), tau_agent_core::AgentConfig::default());
+        let startup = start_mcp_client_sessions(&mut agent, &cli, None).expect("start sessions");
+        let sessions = startup.sessions.expect("session manager");
+        assert!(agent.has_tool("mcp.live.echo"));
+
+        let mut diagnostics = Vec::new();
+        for _ in 0..100 {
+            diagnostics = sessions.apply_pending_tool_changes(&mut agent);
+            if !diagnostics.is_empty() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(diagnostics
+            .iter()
+            .any(|entry| entry.reason_code == "mcp_client_tools_refreshed"));
+        assert!(agent.has_tool("mcp.live.extra"));
+        assert!(!agent.has_tool("mcp.live.echo"));
+
+        let ids = std::fs::read_to_string(&ids_path).expect("read tools/list ids");
+        let ids = ids.lines().collect::<Vec<_>>();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn functional_mcp_client_resource_and_prompt_catalog_tools_roundtrip() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-catalog.sh");
+        write_executable_script(&script_path, CATALOG_SERVER_SCRIPT);
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{"name": "docs", "command": script_path.display().to_string()}]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+
+        let context = McpClientRuntimeContext::from_cli(&cli);
+        let outcome = discover_mcp_client_tools(&cli, &context).expect("discover tools");
+        let catalog = catalog_tools_for_session(&outcome.sessions[0]);
+        let names = catalog
+            .iter()
+            .map(|tool| tool.definition().name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "mcp.docs.resources.list",
+                "mcp.docs.resources.read",
+                "mcp.docs.prompts.list",
+                "mcp.docs.prompts.get"
+            ]
+        );
+
+        let listed = catalog[0].execute(json!({})).await;
+        assert_eq!(listed.content["resources"][0]["uri"], "file:///notes.md");
+        let read = catalog[1].execute(json!({"uri": "file:///notes.md"})).await;
+        assert!(!read.is_error);
+        assert_eq!(read.content["contents"][0]["text"], "release notes");
+        let missing_uri = catalog[1].execute(json!({})).await;
+        assert!(missing_uri.is_error);
+        let prompt = catalog[3]
+            .execute(json!({"name": "summarize", "arguments": {"topic": "release"}}))
+            .await;
+        assert_eq!(
+            prompt.content["messages"][0]["content"]["text"],
+            "summarize the release"
+        );
+    }
+
+    #[test]
+    fn integration_mcp_client_command_lists_resources_and_loads_prompt_messages() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-catalog.sh");
+        write_executable_script(&script_path, CATALOG_SERVER_SCRIPT);
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{"name": "docs", "command": script_path.display().to_string()}]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+        let mut agent = Agent::new(Arc::new(NoopClient), tau_agent_core::AgentConfig::default());
+        let startup = start_mcp_client_sessions(&mut agent, &cli, None).expect("start sessions");
+        let sessions = startup.sessions.expect("session manager");
+        assert!(agent.has_tool("mcp.docs.prompts.get"));
+
+        let status = execute_mcp_client_command("", Some(&sessions), &mut agent);
+        assert!(status.contains("name=docs transport=stdio tools=0 resources=true prompts=true"));
+        let resources = execute_mcp_client_command("resources", Some(&sessions), &mut agent);
+        assert!(resources.contains("uri=file:///notes.md name=notes"));
+        let read =
+            execute_mcp_client_command("read docs file:///notes.md", Some(&sessions), &mut agent);
+        assert!(read.ends_with("release notes"));
+        let prompts = execute_mcp_client_command("prompts docs", Some(&sessions), &mut agent);
+        assert!(prompts.contains("name=summarize arguments=topic"));
+
+        let before = agent.messages().len();
+        let loaded = execute_mcp_client_command(
+            "prompt docs summarize topic=release",
+            Some(&sessions),
+            &mut agent,
+        );
+        assert_eq!(
+            loaded,
+            "mcp prompt loaded: server=docs name=summarize messages=1"
+        );
+        assert_eq!(agent.messages().len(), before + 1);
+        assert_eq!(
+            agent.messages().last().expect("message").text_content(),
+            "summarize the release"
+        );
+
+        let unknown = execute_mcp_client_command("read other x", Some(&sessions), &mut agent);
+        assert!(unknown.contains("unknown mcp client server 'other'"));
+        assert_eq!(
+            execute_mcp_client_command("bogus", Some(&sessions), &mut agent),
+            MCP_CLIENT_COMMAND_USAGE
+        );
+        assert!(execute_mcp_client_command("status", None, &mut agent).contains("disabled"));
+    }
+
+    #[tokio::test]
+    async fn functional_mcp_client_services_sampling_requests_through_llm_client() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-sampling.sh");
+        write_executable_script(&script_path, SAMPLING_SERVER_SCRIPT);
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{
+                    "name": "sampler",
+                    "command": script_path.display().to_string(),
+                    "sampling": {"enabled": true, "max_tokens": 256}
+                }]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+        let backend = McpClientSamplingBackend {
+            client: Arc::new(SamplingClient),
+            model: "test-model".to_string(),
+        };
+
+        let context = McpClientRuntimeContext::from_cli(&cli);
+        let outcome = discover_mcp_client_tools_with_sampling(&cli, &context, Some(&backend))
+            .expect("discover tools");
+        let result = proxy_tool_for(&outcome.tools[0]).execute(json!({})).await;
+        assert!(!result.is_error, "unexpected error: {}", result.content);
+        assert_eq!(result.content["sampled"], "sampled reply");
+    }
+
+    #[tokio::test]
+    async fn regression_mcp_client_rejects_sampling_when_policy_disabled() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("mock-mcp-sampling.sh");
+        write_executable_script(&script_path, SAMPLING_SERVER_SCRIPT);
+        let config_path = temp.path().join("mcp-client.json");
+        write_client_config(
+            &config_path,
+            json!({
+                "schema_version": 1,
+                "servers": [{"name": "sampler", "command": script_path.display().to_string()}]
+            }),
+        );
+        let cli = mcp_client_cli(temp.path(), &config_path);
+        let backend = McpClientSamplingBackend {
+            client: Arc::new(SamplingClient),
+            model: "test-model".to_string(),
+        };
+
+        let context = McpClientRuntimeContext::from_cli(&cli);
+        let outcome = discover_mcp_client_tools_with_sampling(&cli, &context, Some(&backend))
+            .expect("discover tools");
+        let result = proxy_tool_for(&outcome.tools[0]).execute(json!({})).await;
+        assert!(result.is_error);
+        assert_eq!(result.content["reason_code"], "sampling_rejected");
+    }
 }
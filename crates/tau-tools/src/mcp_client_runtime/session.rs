@@ -0,0 +1,729 @@
+//! Long-lived MCP client sessions.
+//!
+//! A session owns one initialized connection per configured server: stdio
+//! servers keep a single child process whose stdout is demultiplexed by a
+//! reader thread, while http servers reuse the negotiated `mcp-session-id`.
+//! Server-initiated requests (`ping`, `sampling/createMessage`) and
+//! `notifications/tools/list_changed` are handled on the session side.
+
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::blocking::Client;
+use serde_json::{json, Value};
+use tau_ai::{ChatRequest, LlmClient, Message};
+
+use super::{
+    jsonrpc_request_frame, jsonrpc_result_for_id, probe_http_sse_endpoint,
+    resolve_server_bearer_token, McpClientHttpSseTransportConfig, McpClientRuntimeContext,
+    McpClientSamplingPolicy, McpClientServerRuntime, McpClientStdioTransportConfig,
+    McpClientTransportRuntime, MCP_CLIENT_INIT_REQUEST_ID, MCP_JSONRPC_VERSION,
+    MCP_PROTOCOL_VERSION,
+};
+
+const MCP_SESSION_ID_HEADER: &str = "mcp-session-id";
+const MCP_LIST_MAX_PAGES: usize = 64;
+const JSONRPC_METHOD_NOT_FOUND: i64 = -32601;
+const JSONRPC_INVALID_PARAMS: i64 = -32602;
+const MCP_SAMPLING_REJECTED: i64 = -1;
+
+type PendingResponses = Arc<Mutex<BTreeMap<String, mpsc::Sender<Value>>>>;
+
+#[derive(Clone)]
+/// LLM client and model used to service server-initiated `sampling/createMessage` requests.
+pub struct McpClientSamplingBackend {
+    pub client: Arc<dyn LlmClient>,
+    pub model: String,
+}
+
+/// One initialized MCP connection shared by every proxy tool of a server.
+pub(super) struct McpClientSession {
+    server: Arc<McpClientServerRuntime>,
+    context: McpClientRuntimeContext,
+    transport: McpClientSessionTransport,
+    capabilities: Value,
+    next_request_id: AtomicU64,
+    tools_changed: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for McpClientSession {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("McpClientSession")
+            .field("server", &self.server.name)
+            .field("capabilities", &self.capabilities)
+            .finish_non_exhaustive()
+    }
+}
+
+enum McpClientSessionTransport {
+    Stdio(McpStdioSession),
+    Http(McpHttpSession),
+}
+
+struct McpStdioSession {
+    child: Mutex<Child>,
+    stdin: Arc<Mutex<ChildStdin>>,
+    pending: PendingResponses,
+    closed: Arc<AtomicBool>,
+    timeout: Duration,
+}
+
+struct McpHttpSession {
+    transport: McpClientHttpSseTransportConfig,
+    session_id: Mutex<Option<String>>,
+}
+
+/// Policy-gated bridge from server sampling requests to the configured LLM client.
+struct McpClientSamplingService {
+    server_name: String,
+    policy: McpClientSamplingPolicy,
+    backend: Option<McpClientSamplingBackend>,
+    runtime: Option<tokio::runtime::Handle>,
+    served: AtomicUsize,
+}
+
+impl McpClientSession {
+    /// Starts the transport, performs the `initialize` handshake and sends
+    /// `notifications/initialized`.
+    pub(super) fn connect(
+        server: &Arc<McpClientServerRuntime>,
+        context: &McpClientRuntimeContext,
+        sampling: Option<&McpClientSamplingBackend>,
+    ) -> Result<Self> {
+        let sampling_service = Arc::new(McpClientSamplingService {
+            server_name: server.name.clone(),
+            policy: server.sampling.clone(),
+            backend: sampling.cloned(),
+            runtime: tokio::runtime::Handle::try_current().ok(),
+            served: AtomicUsize::new(0),
+        });
+        let tools_changed = Arc::new(AtomicBool::new(false));
+        let transport = match &server.transport {
+            McpClientTransportRuntime::Stdio(transport) => {
+                McpClientSessionTransport::Stdio(McpStdioSession::spawn(
+                    server,
+                    transport,
+                    tools_changed.clone(),
+                    sampling_service.clone(),
+                )?)
+            }
+            McpClientTransportRuntime::HttpSse(transport) => {
+                if server.sse_probe {
+                    let bearer_token = resolve_server_bearer_token(server, context)?;
+                    probe_http_sse_endpoint(transport, bearer_token.as_deref())?;
+                }
+                McpClientSessionTransport::Http(McpHttpSession {
+                    transport: transport.clone(),
+                    session_id: Mutex::new(None),
+                })
+            }
+        };
+
+        let mut session = Self {
+            server: server.clone(),
+            context: context.clone(),
+            transport,
+            capabilities: Value::Null,
+            next_request_id: AtomicU64::new(1),
+            tools_changed,
+        };
+        let mut capabilities = json!({"tools": {"listChanged": true}});
+        if sampling_service.is_available() {
+            capabilities["sampling"] = json!({});
+        }
+        let initialized = session.request_with_id(
+            MCP_CLIENT_INIT_REQUEST_ID,
+            "initialize",
+            json!({
+                "protocolVersion": MCP_PROTOCOL_VERSION,
+                "capabilities": capabilities,
+                "clientInfo": {
+                    "name": "tau-rs",
+                    "version": env!("CARGO_PKG_VERSION")
+                }
+            }),
+        )?;
+        session.capabilities = initialized
+            .get("capabilities")
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+        session.notify("notifications/initialized", json!({}))?;
+        Ok(session)
+    }
+
+    pub(super) fn server(&self) -> &Arc<McpClientServerRuntime> {
+        &self.server
+    }
+
+    /// Returns true when the server advertised `capability` during initialize.
+    pub(super) fn supports(&self, capability: &str) -> bool {
+        self.capabilities
+            .get(capability)
+            .is_some_and(|value| !value.is_null())
+    }
+
+    /// Returns and clears the pending `tools/list_changed` flag.
+    pub(super) fn take_tools_changed(&self) -> bool {
+        self.tools_changed.swap(false, Ordering::SeqCst)
+    }
+
+    pub(super) fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = format!(
+            "tau-client-{}",
+            self.next_request_id.fetch_add(1, Ordering::SeqCst)
+        );
+        self.request_with_id(&id, method, params)
+    }
+
+    /// Collects every page of a cursor-paginated list method (`tools/list`,
+    /// `resources/list`, `prompts/list`).
+    pub(super) fn request_paginated(&self, method: &str, key: &str) -> Result<Vec<Value>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MCP_LIST_MAX_PAGES {
+            let params = match cursor.as_deref() {
+                Some(cursor) => json!({ "cursor": cursor }),
+                None => json!({}),
+            };
+            let result = self.request(method, params)?;
+            let page = result.get(key).and_then(Value::as_array).ok_or_else(|| {
+                anyhow!(
+                    "mcp client server '{}' returned invalid {} payload",
+                    self.server.name,
+                    method
+                )
+            })?;
+            items.extend(page.iter().cloned());
+            cursor = result
+                .get("nextCursor")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(ToString::to_string);
+            if cursor.is_none() {
+                return Ok(items);
+            }
+        }
+        bail!(
+            "mcp client server '{}' exceeded {} pages for {}",
+            self.server.name,
+            MCP_LIST_MAX_PAGES,
+            method
+        )
+    }
+
+    fn request_with_id(&self, id: &str, method: &str, params: Value) -> Result<Value> {
+        let frame = jsonrpc_request_frame(id, method, params);
+        match &self.transport {
+            McpClientSessionTransport::Stdio(stdio) => {
+                let response = stdio.request(&self.server.name, id, method, &frame)?;
+                jsonrpc_result_for_id(&[response], id, &self.server.name)
+            }
+            McpClientSessionTransport::Http(http) => {
+                let bearer_token = resolve_server_bearer_token(&self.server, &self.context)?;
+                let responses = http.post(&frame, bearer_token.as_deref(), &self.tools_changed)?;
+                jsonrpc_result_for_id(&responses, id, &self.server.name)
+            }
+        }
+    }
+
+    fn notify(&self, method: &str, params: Value) -> Result<()> {
+        let frame = json!({
+            "jsonrpc": MCP_JSONRPC_VERSION,
+            "method": method,
+            "params": params,
+        });
+        match &self.transport {
+            McpClientSessionTransport::Stdio(stdio) => {
+                write_frame(&stdio.stdin, &self.server.name, &frame)
+            }
+            McpClientSessionTransport::Http(http) => {
+                // Notifications are acknowledged with 202 and no body; servers
+                // that reject them still accept subsequent requests.
+                let bearer_token = resolve_server_bearer_token(&self.server, &self.context)?;
+                let _ = http.post(&frame, bearer_token.as_deref(), &self.tools_changed);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl McpStdioSession {
+    fn spawn(
+        server: &Arc<McpClientServerRuntime>,
+        transport: &McpClientStdioTransportConfig,
+        tools_changed: Arc<AtomicBool>,
+        sampling: Arc<McpClientSamplingService>,
+    ) -> Result<Self> {
+        let mut command = Command::new(&transport.command);
+        command.args(&transport.args);
+        command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        if let Some(cwd) = transport.cwd.as_ref() {
+            command.current_dir(cwd);
+        }
+        for (key, value) in &transport.env {
+            command.env(key, value);
+        }
+        let mut child = command.spawn().with_context(|| {
+            format!(
+                "failed to spawn mcp client server '{}' command '{}'",
+                server.name, transport.command
+            )
+        })?;
+        let stdin = child.stdin.take().ok_or_else(|| {
+            anyhow!(
+                "failed to open stdin for mcp client server '{}'",
+                server.name
+            )
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| {
+            anyhow!(
+                "failed to open stdout for mcp client server '{}'",
+                server.name
+            )
+        })?;
+
+        let stdin = Arc::new(Mutex::new(stdin));
+        let pending: PendingResponses = Arc::new(Mutex::new(BTreeMap::new()));
+        let closed = Arc::new(AtomicBool::new(false));
+        let reader = McpStdioReader {
+            server_name: server.name.clone(),
+            stdin: stdin.clone(),
+            pending: pending.clone(),
+            closed: closed.clone(),
+            tools_changed,
+            sampling,
+        };
+        std::thread::Builder::new()
+            .name(format!("mcp-client-{}", server.name))
+            .spawn(move || reader.run(stdout))
+            .with_context(|| {
+                format!(
+                    "failed to spawn reader thread for mcp client server '{}'",
+                    server.name
+                )
+            })?;
+
+        Ok(Self {
+            child: Mutex::new(child),
+            stdin,
+            pending,
+            closed,
+            timeout: Duration::from_millis(transport.timeout_ms),
+        })
+    }
+
+    fn request(&self, server_name: &str, id: &str, method: &str, frame: &Value) -> Result<Value> {
+        if self.closed.load(Ordering::SeqCst) {
+            bail!("mcp client server '{}' session closed", server_name);
+        }
+        let (sender, receiver) = mpsc::channel();
+        lock(&self.pending).insert(id.to_string(), sender);
+        if let Err(error) = write_frame(&self.stdin, server_name, frame) {
+            lock(&self.pending).remove(id);
+            return Err(error);
+        }
+        match receiver.recv_timeout(self.timeout) {
+            Ok(response) => Ok(response),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                lock(&self.pending).remove(id);
+                bail!(
+                    "mcp client server '{}' timed out after {}ms waiting for {}",
+                    server_name,
+                    self.timeout.as_millis(),
+                    method
+                )
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                bail!(
+                    "mcp client server '{}' session closed before responding to {}",
+                    server_name,
+                    method
+                )
+            }
+        }
+    }
+}
+
+impl Drop for McpStdioSession {
+    fn drop(&mut self) {
+        let mut child = lock(&self.child);
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+struct McpStdioReader {
+    server_name: String,
+    stdin: Arc<Mutex<ChildStdin>>,
+    pending: PendingResponses,
+    closed: Arc<AtomicBool>,
+    tools_changed: Arc<AtomicBool>,
+    sampling: Arc<McpClientSamplingService>,
+}
+
+impl McpStdioReader {
+    fn run(self, stdout: std::process::ChildStdout) {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let Ok(message) = serde_json::from_str::<Value>(trimmed) else {
+                continue;
+            };
+            self.dispatch(message);
+        }
+        self.closed.store(true, Ordering::SeqCst);
+        lock(&self.pending).clear();
+    }
+
+    fn dispatch(&self, message: Value) {
+        let method = message.get("method").and_then(Value::as_str);
+        let id = message.get("id").cloned().filter(|id| !id.is_null());
+        match (method, id) {
+            (Some(method), Some(id)) => {
+                // Server requests may call back into the LLM, so they run off
+                // the reader thread to keep responses flowing.
+                let method = method.to_string();
+                let params = message.get("params").cloned().unwrap_or(Value::Null);
+                let stdin = self.stdin.clone();
+                let sampling = self.sampling.clone();
+                let server_name = self.server_name.clone();
+                std::thread::spawn(move || {
+                    let response = match handle_server_request(&sampling, &method, params) {
+                        Ok(result) => json!({
+                            "jsonrpc": MCP_JSONRPC_VERSION,
+                            "id": id,
+                            "result": result,
+                        }),
+                        Err((code, message)) => json!({
+                            "jsonrpc": MCP_JSONRPC_VERSION,
+                            "id": id,
+                            "error": {"code": code, "message": message},
+                        }),
+                    };
+                    let _ = write_frame(&stdin, &server_name, &response);
+                });
+            }
+            (Some(method), None) => mark_notification(method, &self.tools_changed),
+            (None, Some(id)) => {
+                let key = match &id {
+                    Value::String(value) => value.clone(),
+                    other => other.to_string(),
+                };
+                if let Some(sender) = lock(&self.pending).remove(&key) {
+                    let _ = sender.send(message);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+impl McpHttpSession {
+    fn post(
+        &self,
+        frame: &Value,
+        bearer_token: Option<&str>,
+        tools_changed: &AtomicBool,
+    ) -> Result<Vec<Value>> {
+        let client = Client::builder()
+            .timeout(Duration::from_millis(self.transport.timeout_ms))
+            .build()
+            .context("failed to build mcp client http transport")?;
+        let mut outbound = client
+            .post(&self.transport.endpoint)
+            .header("accept", "application/json, text/event-stream")
+            .json(frame);
+        for (key, value) in &self.transport.headers {
+            outbound = outbound.header(key, value);
+        }
+        if let Some(session_id) = lock(&self.session_id).clone() {
+            outbound = outbound.header(MCP_SESSION_ID_HEADER, session_id);
+        }
+        if let Some(token) = bearer_token {
+            outbound = outbound.bearer_auth(token);
+        }
+        let response = outbound.send().context("mcp client http request failed")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response
+                .text()
+                .unwrap_or_else(|_| "<unreadable response body>".to_string());
+            bail!(
+                "mcp client http request failed with status {} body {}",
+                status,
+                body
+            );
+        }
+        if let Some(session_id) = response
+            .headers()
+            .get(MCP_SESSION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            *lock(&self.session_id) = Some(session_id.to_string());
+        }
+        let is_event_stream = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.to_ascii_lowercase().contains("text/event-stream"));
+        let body = response
+            .text()
+            .context("failed to read mcp client http response body")?;
+        if body.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let payloads = if is_event_stream {
+            body.lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(str::trim)
+                .filter(|data| !data.is_empty())
+                .map(|data| {
+                    serde_json::from_str::<Value>(data)
+                        .context("failed to decode mcp client http event-stream data")
+                })
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            vec![serde_json::from_str::<Value>(&body)
+                .context("failed to decode mcp client http json response")?]
+        };
+        let mut messages = Vec::new();
+        for payload in payloads {
+            match payload {
+                Value::Array(batch) => messages.extend(batch),
+                other => messages.push(other),
+            }
+        }
+        for message in &messages {
+            if message.get("id").is_none() {
+                if let Some(method) = message.get("method").and_then(Value::as_str) {
+                    mark_notification(method, tools_changed);
+                }
+            }
+        }
+        Ok(messages)
+    }
+}
+
+impl McpClientSamplingService {
+    fn is_available(&self) -> bool {
+        self.policy.enabled && self.backend.is_some()
+    }
+
+    fn create_message(&self, params: Value) -> Result<Value, (i64, String)> {
+        let Some(backend) = self.backend.as_ref().filter(|_| self.policy.enabled) else {
+            return Err((
+                MCP_SAMPLING_REJECTED,
+                format!(
+                    "sampling is disabled for mcp client server '{}'",
+                    self.server_name
+                ),
+            ));
+        };
+        if self.served.fetch_add(1, Ordering::SeqCst) >= self.policy.max_requests {
+            return Err((
+                MCP_SAMPLING_REJECTED,
+                format!(
+                    "sampling request budget of {} exhausted for mcp client server '{}'",
+                    self.policy.max_requests, self.server_name
+                ),
+            ));
+        }
+        let request = sampling_chat_request(&params, &backend.model, self.policy.max_tokens)
+            .map_err(|reason| (JSONRPC_INVALID_PARAMS, reason))?;
+
+        let client = backend.client.clone();
+        let completion = match self.runtime.as_ref() {
+            Some(handle) => handle.block_on(client.complete(request)),
+            None => tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|error| (MCP_SAMPLING_REJECTED, error.to_string()))?
+                .block_on(client.complete(request)),
+        };
+        let response = completion.map_err(|error| {
+            (
+                MCP_SAMPLING_REJECTED,
+                format!("sampling completion failed: {error}"),
+            )
+        })?;
+        let stop_reason = match response.finish_reason.as_deref() {
+            Some("length") | Some("max_tokens") => "maxTokens",
+            Some("stop_sequence") => "stopSequence",
+            _ => "endTurn",
+        };
+        Ok(json!({
+            "role": "assistant",
+            "content": {"type": "text", "text": response.message.text_content()},
+            "model": backend.model,
+            "stopReason": stop_reason,
+        }))
+    }
+}
+
+fn handle_server_request(
+    sampling: &McpClientSamplingService,
+    method: &str,
+    params: Value,
+) -> Result<Value, (i64, String)> {
+    match method {
+        "ping" => Ok(json!({})),
+        "sampling/createMessage" => sampling.create_message(params),
+        other => Err((
+            JSONRPC_METHOD_NOT_FOUND,
+            format!("method '{other}' is not supported by tau mcp client"),
+        )),
+    }
+}
+
+/// Maps MCP `sampling/createMessage` params onto a text-only chat request.
+fn sampling_chat_request(
+    params: &Value,
+    model: &str,
+    policy_max_tokens: u32,
+) -> Result<ChatRequest, String> {
+    let entries = params
+        .get("messages")
+        .and_then(Value::as_array)
+        .filter(|messages| !messages.is_empty())
+        .ok_or_else(|| "sampling request requires a non-empty messages array".to_string())?;
+
+    let mut messages = Vec::with_capacity(entries.len().saturating_add(1));
+    if let Some(system_prompt) = params
+        .get("systemPrompt")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        messages.push(Message::system(system_prompt));
+    }
+    for entry in entries {
+        let text = entry
+            .get("content")
+            .and_then(|content| {
+                if content.get("type").and_then(Value::as_str) == Some("text") {
+                    content.get("text").and_then(Value::as_str)
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| "sampling request supports only text content".to_string())?;
+        match entry.get("role").and_then(Value::as_str) {
+            Some("user") => messages.push(Message::user(text)),
+            Some("assistant") => messages.push(Message::assistant_text(text)),
+            other => {
+                return Err(format!(
+                    "sampling request has unsupported role '{}'",
+                    other.unwrap_or_default()
+                ))
+            }
+        }
+    }
+
+    let max_tokens = params
+        .get("maxTokens")
+        .and_then(Value::as_u64)
+        .and_then(|value| u32::try_from(value).ok())
+        .map_or(policy_max_tokens, |value| value.min(policy_max_tokens));
+    Ok(ChatRequest {
+        model: model.to_string(),
+        messages,
+        tools: Vec::new(),
+        tool_choice: None,
+        json_mode: false,
+        max_tokens: Some(max_tokens),
+        temperature: params
+            .get("temperature")
+            .and_then(Value::as_f64)
+            .map(|value| value as f32),
+        prompt_cache: Default::default(),
+        reasoning: Default::default(),
+        model_tier: None,
+    })
+}
+
+fn mark_notification(method: &str, tools_changed: &AtomicBool) {
+    if method == "notifications/tools/list_changed" {
+        tools_changed.store(true, Ordering::SeqCst);
+    }
+}
+
+fn write_frame(stdin: &Mutex<ChildStdin>, server_name: &str, frame: &Value) -> Result<()> {
+    let mut line = serde_json::to_string(frame).context("failed to encode mcp client request")?;
+    line.push('\n');
+    let mut stdin = lock(stdin);
+    stdin
+        .write_all(line.as_bytes())
+        .and_then(|()| stdin.flush())
+        .with_context(|| format!("failed to write mcp client request to '{}'", server_name))
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::sampling_chat_request;
+
+    #[test]
+    fn unit_sampling_chat_request_clamps_max_tokens_and_maps_roles() {
+        let request = sampling_chat_request(
+            &json!({
+                "systemPrompt": "be brief",
+                "maxTokens": 9000,
+                "messages": [
+                    {"role": "user", "content": {"type": "text", "text": "hi"}},
+                    {"role": "assistant", "content": {"type": "text", "text": "hello"}}
+                ]
+            }),
+            "gpt-test",
+            512,
+        )
+        .expect("request");
+
+        assert_eq!(request.model, "gpt-test");
+        assert_eq!(request.max_tokens, Some(512));
+        assert_eq!(request.messages.len(), 3);
+        assert_eq!(request.messages[2].text_content(), "hello");
+    }
+
+    #[test]
+    fn regression_sampling_chat_request_rejects_non_text_content() {
+        let error = sampling_chat_request(
+            &json!({
+                "messages": [
+                    {"role": "user", "content": {"type": "image", "data": "AAAA", "mimeType": "image/png"}}
+                ]
+            }),
+            "gpt-test",
+            512,
+        )
+        .expect_err("image content must be rejected");
+        assert!(error.contains("only text content"));
+    }
+}
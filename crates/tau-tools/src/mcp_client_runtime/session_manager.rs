@@ -0,0 +1,524 @@
+//! Agent-facing surfaces for persistent MCP client sessions.
+//!
+//! Owns live tool re-registration after `notifications/tools/list_changed`,
+//! the resource/prompt catalog tools, and the `/mcp` slash command.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use tau_agent_core::{Agent, AgentTool, ToolExecutionResult};
+use tau_ai::{Message, ToolDefinition};
+
+use super::{
+    classify_mcp_client_error, discover_tools_for_server, register_discovered_tool,
+    session::McpClientSession, McpClientDiagnostic, McpClientTransportRuntime,
+};
+
+pub const MCP_CLIENT_COMMAND_USAGE: &str =
+    "usage: /mcp [status|resources [server]|read <server> <uri>|prompts [server]|prompt <server> <name> [key=value ...]]";
+
+/// Persistent MCP client sessions kept alive for the lifetime of a local runtime.
+pub struct McpClientSessionManager {
+    sessions: Vec<Arc<McpClientSession>>,
+    registered_tools: Mutex<BTreeMap<String, BTreeSet<String>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum McpClientCatalogKind {
+    ResourcesList,
+    ResourcesRead,
+    PromptsList,
+    PromptsGet,
+}
+
+/// Agent tool exposing one MCP resource or prompt catalog method.
+pub(super) struct McpClientCatalogTool {
+    definition: ToolDefinition,
+    kind: McpClientCatalogKind,
+    session: Arc<McpClientSession>,
+}
+
+impl McpClientSessionManager {
+    pub(super) fn new(
+        sessions: Vec<Arc<McpClientSession>>,
+        registered_tools: BTreeMap<String, BTreeSet<String>>,
+    ) -> Self {
+        Self {
+            sessions,
+            registered_tools: Mutex::new(registered_tools),
+        }
+    }
+
+    pub fn server_names(&self) -> Vec<String> {
+        self.sessions
+            .iter()
+            .map(|session| session.server().name.clone())
+            .collect()
+    }
+
+    /// Re-registers the tools of every server that sent
+    /// `notifications/tools/list_changed` since the previous call.
+    pub fn apply_pending_tool_changes(&self, agent: &mut Agent) -> Vec<McpClientDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for session in &self.sessions {
+            if !session.take_tools_changed() {
+                continue;
+            }
+            let server_name = session.server().name.clone();
+            let tools = match run_session_blocking(|| discover_tools_for_server(session)) {
+                Ok(tools) => tools,
+                Err(error) => {
+                    diagnostics.push(McpClientDiagnostic {
+                        server: server_name,
+                        phase: "refresh".to_string(),
+                        status: "error".to_string(),
+                        reason_code: classify_mcp_client_error(&error).to_string(),
+                        detail: error.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let mut registered_tools = self
+                .registered_tools
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let previous = registered_tools.remove(&server_name).unwrap_or_default();
+            for tool_name in &previous {
+                agent.unregister_tool(tool_name);
+            }
+            let mut current = BTreeSet::new();
+            let mut registration_diagnostics = Vec::new();
+            for tool in tools {
+                let local_tool_name = tool.local_tool_name.clone();
+                if register_discovered_tool(agent, tool, &mut registration_diagnostics) {
+                    current.insert(local_tool_name);
+                }
+            }
+            diagnostics.extend(
+                registration_diagnostics
+                    .into_iter()
+                    .filter(|diagnostic| diagnostic.status != "ok"),
+            );
+            diagnostics.push(McpClientDiagnostic {
+                server: server_name.clone(),
+                phase: "refresh".to_string(),
+                status: "ok".to_string(),
+                reason_code: "mcp_client_tools_refreshed".to_string(),
+                detail: format!(
+                    "re-registered {} tool(s) after tools/list_changed (previously {})",
+                    current.len(),
+                    previous.len()
+                ),
+            });
+            registered_tools.insert(server_name, current);
+        }
+        diagnostics
+    }
+
+    pub fn list_resources(&self, server: Option<&str>) -> Result<Vec<(String, Value)>> {
+        self.list_catalog(server, "resources", "resources/list")
+    }
+
+    pub fn read_resource(&self, server: &str, uri: &str) -> Result<Value> {
+        let session = self.session(server)?;
+        run_session_blocking(|| session.request("resources/read", json!({ "uri": uri })))
+    }
+
+    pub fn list_prompts(&self, server: Option<&str>) -> Result<Vec<(String, Value)>> {
+        self.list_catalog(server, "prompts", "prompts/list")
+    }
+
+    pub fn get_prompt(
+        &self,
+        server: &str,
+        name: &str,
+        arguments: BTreeMap<String, String>,
+    ) -> Result<Value> {
+        let session = self.session(server)?;
+        run_session_blocking(|| {
+            session.request(
+                "prompts/get",
+                json!({ "name": name, "arguments": arguments }),
+            )
+        })
+    }
+
+    fn list_catalog(
+        &self,
+        server: Option<&str>,
+        capability: &str,
+        method: &str,
+    ) -> Result<Vec<(String, Value)>> {
+        let sessions = match server {
+            Some(server) => vec![self.session(server)?],
+            None => self
+                .sessions
+                .iter()
+                .filter(|session| session.supports(capability))
+                .collect(),
+        };
+        let mut entries = Vec::new();
+        for session in sessions {
+            let items = run_session_blocking(|| session.request_paginated(method, capability))?;
+            entries.extend(
+                items
+                    .into_iter()
+                    .map(|item| (session.server().name.clone(), item)),
+            );
+        }
+        Ok(entries)
+    }
+
+    fn session(&self, server: &str) -> Result<&Arc<McpClientSession>> {
+        self.sessions
+            .iter()
+            .find(|session| session.server().name == server)
+            .ok_or_else(|| anyhow!("unknown mcp client server '{}'", server))
+    }
+}
+
+/// Builds the resource/prompt catalog tools for the capabilities a server advertised.
+pub(super) fn catalog_tools_for_session(
+    session: &Arc<McpClientSession>,
+) -> Vec<McpClientCatalogTool> {
+    let prefix = session.server().tool_prefix.clone();
+    let server_name = session.server().name.clone();
+    let mut kinds = Vec::new();
+    if session.supports("resources") {
+        kinds.push(McpClientCatalogKind::ResourcesList);
+        kinds.push(McpClientCatalogKind::ResourcesRead);
+    }
+    if session.supports("prompts") {
+        kinds.push(McpClientCatalogKind::PromptsList);
+        kinds.push(McpClientCatalogKind::PromptsGet);
+    }
+    kinds
+        .into_iter()
+        .map(|kind| {
+            let (suffix, description, parameters) = match kind {
+                McpClientCatalogKind::ResourcesList => (
+                    "resources.list",
+                    format!("List resources exposed by MCP server '{server_name}'"),
+                    json!({"type": "object", "properties": {}}),
+                ),
+                McpClientCatalogKind::ResourcesRead => (
+                    "resources.read",
+                    format!("Read a resource by URI from MCP server '{server_name}'"),
+                    json!({
+                        "type": "object",
+                        "properties": {"uri": {"type": "string"}},
+                        "required": ["uri"]
+                    }),
+                ),
+                McpClientCatalogKind::PromptsList => (
+                    "prompts.list",
+                    format!("List prompt templates exposed by MCP server '{server_name}'"),
+                    json!({"type": "object", "properties": {}}),
+                ),
+                McpClientCatalogKind::PromptsGet => (
+                    "prompts.get",
+                    format!("Render a prompt template from MCP server '{server_name}'"),
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string"},
+                            "arguments": {
+                                "type": "object",
+                                "additionalProperties": {"type": "string"}
+                            }
+                        },
+                        "required": ["name"]
+                    }),
+                ),
+            };
+            McpClientCatalogTool {
+                definition: ToolDefinition {
+                    name: format!("{prefix}{suffix}"),
+                    description,
+                    parameters,
+                },
+                kind,
+                session: session.clone(),
+            }
+        })
+        .collect()
+}
+
+#[async_trait]
+impl AgentTool for McpClientCatalogTool {
+    fn definition(&self) -> ToolDefinition {
+        self.definition.clone()
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let kind = self.kind;
+        let session = self.session.clone();
+        let server = session.server().name.clone();
+        let join =
+            tokio::task::spawn_blocking(move || execute_catalog_call(&session, kind, arguments))
+                .await;
+        match join {
+            Ok(Ok(result)) => ToolExecutionResult::ok(result),
+            Ok(Err(error)) => ToolExecutionResult::error(json!({
+                "reason_code": classify_mcp_client_error(&error),
+                "message": error.to_string(),
+                "server": server,
+                "tool_name": self.definition.name,
+            })),
+            Err(error) => ToolExecutionResult::error(json!({
+                "reason_code": "mcp_client_runtime_join_failed",
+                "message": error.to_string(),
+                "server": server,
+                "tool_name": self.definition.name,
+            })),
+        }
+    }
+}
+
+fn execute_catalog_call(
+    session: &McpClientSession,
+    kind: McpClientCatalogKind,
+    arguments: Value,
+) -> Result<Value> {
+    match kind {
+        McpClientCatalogKind::ResourcesList => Ok(json!({
+            "resources": session.request_paginated("resources/list", "resources")?,
+        })),
+        McpClientCatalogKind::ResourcesRead => {
+            let uri = required_argument(&arguments, "uri")?;
+            session.request("resources/read", json!({ "uri": uri }))
+        }
+        McpClientCatalogKind::PromptsList => Ok(json!({
+            "prompts": session.request_paginated("prompts/list", "prompts")?,
+        })),
+        McpClientCatalogKind::PromptsGet => {
+            let name = required_argument(&arguments, "name")?;
+            let prompt_arguments = arguments
+                .get("arguments")
+                .cloned()
+                .unwrap_or_else(|| Value::Object(Map::new()));
+            if !prompt_arguments.is_object() {
+                bail!("mcp client prompts.get arguments must be an object");
+            }
+            session.request(
+                "prompts/get",
+                json!({ "name": name, "arguments": prompt_arguments }),
+            )
+        }
+    }
+}
+
+fn required_argument<'a>(arguments: &'a Value, key: &str) -> Result<&'a str> {
+    arguments
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| anyhow!("mcp client catalog tool requires non-empty '{}'", key))
+}
+
+/// Executes the `/mcp` slash command against the live sessions.
+pub fn execute_mcp_client_command(
+    command_args: &str,
+    sessions: Option<&McpClientSessionManager>,
+    agent: &mut Agent,
+) -> String {
+    let Some(sessions) = sessions else {
+        return "mcp client is disabled; start with --mcp-client".to_string();
+    };
+    let tokens = command_args.split_whitespace().collect::<Vec<_>>();
+    let outcome = match tokens.as_slice() {
+        [] | ["status"] => Ok(render_mcp_client_status(sessions)),
+        ["resources"] => render_resources(sessions, None),
+        ["resources", server] => render_resources(sessions, Some(server)),
+        ["read", server, uri] => sessions
+            .read_resource(server, uri)
+            .map(|result| render_resource_contents(server, uri, &result)),
+        ["prompts"] => render_prompts(sessions, None),
+        ["prompts", server] => render_prompts(sessions, Some(server)),
+        ["prompt", server, name, rest @ ..] => {
+            load_prompt_into_agent(sessions, agent, server, name, rest)
+        }
+        _ => return MCP_CLIENT_COMMAND_USAGE.to_string(),
+    };
+    outcome.unwrap_or_else(|error| {
+        format!(
+            "mcp command error: reason_code={} error={error}",
+            classify_mcp_client_error(&error)
+        )
+    })
+}
+
+fn render_mcp_client_status(sessions: &McpClientSessionManager) -> String {
+    let registered_tools = sessions
+        .registered_tools
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut lines = vec![format!("mcp client: servers={}", sessions.sessions.len())];
+    for session in &sessions.sessions {
+        let server = session.server();
+        let transport = match server.transport {
+            McpClientTransportRuntime::Stdio(_) => "stdio",
+            McpClientTransportRuntime::HttpSse(_) => "http-sse",
+        };
+        lines.push(format!(
+            "mcp server: name={} transport={} tools={} resources={} prompts={} sampling={}",
+            server.name,
+            transport,
+            registered_tools.get(&server.name).map_or(0, BTreeSet::len),
+            session.supports("resources"),
+            session.supports("prompts"),
+            server.sampling.enabled
+        ));
+    }
+    lines.join("\n")
+}
+
+fn render_resources(sessions: &McpClientSessionManager, server: Option<&str>) -> Result<String> {
+    let resources = sessions.list_resources(server)?;
+    let mut lines = vec![format!("mcp resources: count={}", resources.len())];
+    for (server, resource) in resources {
+        lines.push(format!(
+            "mcp resource: server={} uri={} name={} mime_type={}",
+            server,
+            text_field(&resource, "uri"),
+            text_field(&resource, "name"),
+            text_field(&resource, "mimeType")
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn render_prompts(sessions: &McpClientSessionManager, server: Option<&str>) -> Result<String> {
+    let prompts = sessions.list_prompts(server)?;
+    let mut lines = vec![format!("mcp prompts: count={}", prompts.len())];
+    for (server, prompt) in prompts {
+        let arguments = prompt
+            .get("arguments")
+            .and_then(Value::as_array)
+            .map(|arguments| {
+                arguments
+                    .iter()
+                    .filter_map(|argument| argument.get("name").and_then(Value::as_str))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .unwrap_or_default();
+        lines.push(format!(
+            "mcp prompt: server={} name={} arguments={} description={}",
+            server,
+            text_field(&prompt, "name"),
+            if arguments.is_empty() {
+                "-"
+            } else {
+                &arguments
+            },
+            text_field(&prompt, "description")
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn render_resource_contents(server: &str, uri: &str, result: &Value) -> String {
+    let contents = result
+        .get("contents")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let mut lines = vec![format!(
+        "mcp resource read: server={} uri={} contents={}",
+        server,
+        uri,
+        contents.len()
+    )];
+    for content in &contents {
+        match content.get("text").and_then(Value::as_str) {
+            Some(text) => lines.push(text.to_string()),
+            None => lines.push(format!(
+                "<binary resource uri={} mime_type={}>",
+                text_field(content, "uri"),
+                text_field(content, "mimeType")
+            )),
+        }
+    }
+    lines.join("\n")
+}
+
+fn load_prompt_into_agent(
+    sessions: &McpClientSessionManager,
+    agent: &mut Agent,
+    server: &str,
+    name: &str,
+    raw_arguments: &[&str],
+) -> Result<String> {
+    let mut arguments = BTreeMap::new();
+    for raw in raw_arguments {
+        let Some((key, value)) = raw.split_once('=') else {
+            bail!("prompt argument '{}' must use key=value", raw);
+        };
+        arguments.insert(key.trim().to_string(), value.to_string());
+    }
+    let result = sessions.get_prompt(server, name, arguments)?;
+    let messages = result
+        .get("messages")
+        .and_then(Value::as_array)
+        .ok_or_else(|| {
+            anyhow!(
+                "mcp client server '{}' returned invalid prompts/get payload",
+                server
+            )
+        })?;
+    let mut loaded = 0usize;
+    for message in messages {
+        let text = prompt_message_text(message.get("content").unwrap_or(&Value::Null));
+        match message.get("role").and_then(Value::as_str) {
+            Some("assistant") => agent.append_message(Message::assistant_text(text)),
+            _ => agent.append_message(Message::user(text)),
+        }
+        loaded = loaded.saturating_add(1);
+    }
+    Ok(format!(
+        "mcp prompt loaded: server={} name={} messages={}",
+        server, name, loaded
+    ))
+}
+
+fn prompt_message_text(content: &Value) -> String {
+    match content.get("type").and_then(Value::as_str) {
+        Some("text") => text_field(content, "text").to_string(),
+        Some("resource") => {
+            let resource = content.get("resource").unwrap_or(&Value::Null);
+            match resource.get("text").and_then(Value::as_str) {
+                Some(text) => text.to_string(),
+                None => format!("<resource uri={}>", text_field(resource, "uri")),
+            }
+        }
+        Some(other) => format!("<{other} content omitted>"),
+        None => content.to_string(),
+    }
+}
+
+fn text_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or("-")
+}
+
+/// Runs a blocking session call without stalling a multi-threaded tokio worker.
+fn run_session_blocking<T>(call: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle)
+            if matches!(
+                handle.runtime_flavor(),
+                tokio::runtime::RuntimeFlavor::MultiThread
+            ) =>
+        {
+            tokio::task::block_in_place(call)
+        }
+        _ => call(),
+    }
+}
@@ -27,7 +27,9 @@ use crate::tools::{
 };
 
 const MCP_JSONRPC_VERSION: &str = "2.0";
-const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+const MCP_PROTOCOL_VERSION: &str = "2025-06-18";
+const MCP_SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+const MCP_NOTIFICATION_METHOD_PREFIX: &str = "notifications/";
 const MCP_EXTERNAL_SERVER_SCHEMA_VERSION: u32 = 1;
 const MCP_ERROR_PARSE: i64 = -32700;
 const MCP_ERROR_INVALID_REQUEST: i64 = -32600;
@@ -624,6 +626,9 @@ where
             }
         };
         processed_frames = processed_frames.saturating_add(1);
+        if is_jsonrpc_notification(&frame) {
+            continue;
+        }
 
         let response = match parse_jsonrpc_request(&frame) {
             Ok(request) => match dispatch_jsonrpc_request(&request, state) {
@@ -647,6 +652,17 @@ where
     })
 }
 
+fn is_jsonrpc_notification(value: &Value) -> bool {
+    let Some(object) = value.as_object() else {
+        return false;
+    };
+    !object.contains_key("id")
+        && object
+            .get("method")
+            .and_then(Value::as_str)
+            .is_some_and(|method| method.starts_with(MCP_NOTIFICATION_METHOD_PREFIX))
+}
+
 fn parse_jsonrpc_request(value: &Value) -> Result<McpJsonRpcRequest, McpDispatchError> {
     let Some(object) = value.as_object() else {
         return Err(McpDispatchError::new(
@@ -708,7 +724,8 @@ fn dispatch_jsonrpc_request(
     state: &McpServerState,
 ) -> Result<Value, McpDispatchError> {
     match request.method.as_str() {
-        "initialize" => Ok(handle_initialize(state)),
+        "initialize" => Ok(handle_initialize(state, &request.params)),
+        "ping" => Ok(json!({})),
         "tools/list" => Ok(handle_tools_list(state)),
         "tools/call" => handle_tools_call(state, &request.params).map_err(|error| {
             McpDispatchError::new(
@@ -725,14 +742,27 @@ fn dispatch_jsonrpc_request(
     }
 }
 
-fn handle_initialize(state: &McpServerState) -> Value {
+fn negotiate_mcp_protocol_version(requested: Option<&str>) -> &'static str {
+    requested
+        .and_then(|requested| {
+            MCP_SUPPORTED_PROTOCOL_VERSIONS
+                .iter()
+                .copied()
+                .find(|supported| *supported == requested.trim())
+        })
+        .unwrap_or(MCP_PROTOCOL_VERSION)
+}
+
+fn handle_initialize(state: &McpServerState, params: &serde_json::Map<String, Value>) -> Value {
+    let protocol_version =
+        negotiate_mcp_protocol_version(params.get("protocolVersion").and_then(Value::as_str));
     let context_providers = state
         .context_providers
         .iter()
         .map(|value| Value::String(value.clone()))
         .collect::<Vec<_>>();
     json!({
-        "protocolVersion": MCP_PROTOCOL_VERSION,
+        "protocolVersion": protocol_version,
         "serverInfo": {
             "name": "tau-coding-agent",
             "version": env!("CARGO_PKG_VERSION")
@@ -1518,6 +1548,7 @@ mod tests {
         resolve_mcp_context_providers, serve_mcp_jsonrpc_reader, McpExternalServerConfig,
         McpServerState, MCP_CONTEXT_PROVIDER_CHANNEL_STORE, MCP_CONTEXT_PROVIDER_SESSION,
         MCP_ERROR_INVALID_REQUEST, MCP_ERROR_METHOD_NOT_FOUND, MCP_JSONRPC_VERSION,
+        MCP_PROTOCOL_VERSION,
     };
     use crate::tools::ToolPolicy;
     use serde::Deserialize;
@@ -1671,7 +1702,10 @@ mod tests {
         assert_eq!(responses.len(), 2);
         assert_eq!(responses[0]["jsonrpc"], MCP_JSONRPC_VERSION);
         assert_eq!(responses[0]["id"], "req-init");
-        assert_eq!(responses[0]["result"]["protocolVersion"], "2024-11-05");
+        assert_eq!(
+            responses[0]["result"]["protocolVersion"],
+            MCP_PROTOCOL_VERSION
+        );
         let tools = responses[1]["result"]["tools"]
             .as_array()
             .expect("tools array");
@@ -1854,6 +1888,49 @@ mod tests {
         assert_eq!(responses[1]["error"]["code"], MCP_ERROR_METHOD_NOT_FOUND);
     }
 
+    #[test]
+    fn functional_mcp_server_negotiates_protocol_version_and_accepts_notifications() {
+        let state = test_state();
+        let request_frames = vec![
+            jsonrpc_request_frame(
+                Value::String("req-legacy".to_string()),
+                "initialize",
+                serde_json::json!({"protocolVersion": "2024-11-05"}),
+            ),
+            serde_json::json!({
+                "jsonrpc": MCP_JSONRPC_VERSION,
+                "method": "notifications/initialized"
+            }),
+            jsonrpc_request_frame(
+                Value::String("req-future".to_string()),
+                "initialize",
+                serde_json::json!({"protocolVersion": "2099-01-01"}),
+            ),
+            jsonrpc_request_frame(
+                Value::String("req-ping".to_string()),
+                "ping",
+                serde_json::json!({}),
+            ),
+        ];
+        let raw = encode_frames(&request_frames);
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(raw));
+        let mut writer = Vec::new();
+        let report = serve_mcp_jsonrpc_reader(&mut reader, &mut writer, &state)
+            .expect("serve should succeed");
+        assert_eq!(report.processed_frames, 4);
+        assert_eq!(report.error_count, 0);
+
+        let responses = decode_frames(&writer);
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["result"]["protocolVersion"], "2024-11-05");
+        assert_eq!(
+            responses[1]["result"]["protocolVersion"],
+            MCP_PROTOCOL_VERSION
+        );
+        assert_eq!(responses[2]["id"], "req-ping");
+        assert_eq!(responses[2]["result"], serde_json::json!({}));
+    }
+
     #[test]
     fn regression_context_provider_guard_rejects_disabled_provider() {
         let mut state = test_state();
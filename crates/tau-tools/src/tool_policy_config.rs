@@ -12,9 +12,9 @@ use tau_cli::cli_types::{
 };
 
 use crate::tools::{
-    os_sandbox_docker_network_name, os_sandbox_policy_mode_name, tool_policy_preset_name,
-    tool_rate_limit_behavior_name, BashCommandProfile, OsSandboxDockerNetwork, OsSandboxMode,
-    OsSandboxPolicyMode, ToolPolicy,
+    apply_lsp_server_overrides, os_sandbox_docker_network_name, os_sandbox_policy_mode_name,
+    tool_policy_preset_name, tool_rate_limit_behavior_name, BashCommandProfile,
+    OsSandboxDockerNetwork, OsSandboxMode, OsSandboxPolicyMode, ToolPolicy,
 };
 use tau_memory::runtime::MemoryType;
 
@@ -99,6 +99,15 @@ pub fn build_tool_policy(cli: &Cli) -> Result<ToolPolicy> {
     policy.os_sandbox_docker_read_only_rootfs = cli.os_sandbox_docker_read_only_rootfs;
     policy.os_sandbox_docker_env_allowlist =
         parse_docker_env_allowlist(&cli.os_sandbox_docker_env)?;
+    policy.os_sandbox_native_allow_network = cli.os_sandbox_native_allow_network;
+    policy.os_sandbox_native_memory_mb = cli.os_sandbox_native_memory_mb.max(64);
+    policy.os_sandbox_native_max_processes = cli.os_sandbox_native_max_processes;
+    policy.lsp_enabled = cli.lsp_enabled;
+    apply_lsp_server_overrides(&mut policy.lsp_servers, &cli.lsp_server)
+        .map_err(|error| anyhow!("invalid --lsp-server: {error}"))?;
+    policy.lsp_request_timeout_ms = cli.lsp_request_timeout_ms.max(1);
+    policy.lsp_post_edit_diagnostics = cli.lsp_post_edit_diagnostics;
+    policy.git_session_worktrees = cli.git_session_worktrees;
     if cli.http_timeout_ms != 20_000 {
         policy.http_timeout_ms = cli.http_timeout_ms.max(1);
     }
@@ -182,6 +191,8 @@ pub fn build_tool_policy(cli: &Cli) -> Result<ToolPolicy> {
     } else {
         Some(cwd.join(cli.session.as_path()))
     };
+    policy.file_checkpoint_dir = cwd.join(policy.file_checkpoint_dir.as_path());
+    policy.code_index_dir = cwd.join(policy.code_index_dir.as_path());
     if let Some(provider) = parse_optional_env_string(MEMORY_EMBEDDING_PROVIDER_ENV) {
         policy.memory_embedding_provider = Some(provider);
     }
@@ -476,6 +487,14 @@ pub fn tool_policy_to_json(policy: &ToolPolicy) -> serde_json::Value {
         "jobs_state_dir".to_string(),
         serde_json::json!(policy.jobs_state_dir.display().to_string()),
     );
+    payload.insert(
+        "file_checkpoint_dir".to_string(),
+        serde_json::json!(policy.file_checkpoint_dir.display().to_string()),
+    );
+    payload.insert(
+        "code_index_dir".to_string(),
+        serde_json::json!(policy.code_index_dir.display().to_string()),
+    );
     payload.insert(
         "jobs_list_default_limit".to_string(),
         serde_json::json!(policy.jobs_list_default_limit),
@@ -581,6 +600,46 @@ pub fn tool_policy_to_json(policy: &ToolPolicy) -> serde_json::Value {
         "os_sandbox_docker_env_allowlist".to_string(),
         serde_json::json!(policy.os_sandbox_docker_env_allowlist.clone()),
     );
+    payload.insert(
+        "os_sandbox_native_allow_network".to_string(),
+        serde_json::json!(policy.os_sandbox_native_allow_network),
+    );
+    payload.insert(
+        "os_sandbox_native_memory_mb".to_string(),
+        serde_json::json!(policy.os_sandbox_native_memory_mb),
+    );
+    payload.insert(
+        "os_sandbox_native_max_processes".to_string(),
+        serde_json::json!(policy.os_sandbox_native_max_processes),
+    );
+    payload.insert(
+        "lsp_enabled".to_string(),
+        serde_json::json!(policy.lsp_enabled),
+    );
+    payload.insert(
+        "lsp_servers".to_string(),
+        serde_json::json!(policy
+            .lsp_servers
+            .iter()
+            .map(|server| serde_json::json!({
+                "language": server.language,
+                "extensions": server.extensions,
+                "command": server.command,
+            }))
+            .collect::<Vec<_>>()),
+    );
+    payload.insert(
+        "lsp_request_timeout_ms".to_string(),
+        serde_json::json!(policy.lsp_request_timeout_ms),
+    );
+    payload.insert(
+        "lsp_post_edit_diagnostics".to_string(),
+        serde_json::json!(policy.lsp_post_edit_diagnostics),
+    );
+    payload.insert(
+        "git_session_worktrees".to_string(),
+        serde_json::json!(policy.git_session_worktrees),
+    );
     payload.insert(
         "http_timeout_ms".to_string(),
         serde_json::json!(policy.http_timeout_ms),
@@ -785,6 +844,7 @@ fn map_cli_os_sandbox_mode(value: CliOsSandboxMode) -> OsSandboxMode {
         CliOsSandboxMode::Off => OsSandboxMode::Off,
         CliOsSandboxMode::Auto => OsSandboxMode::Auto,
         CliOsSandboxMode::Force => OsSandboxMode::Force,
+        CliOsSandboxMode::Native => OsSandboxMode::Native,
     }
 }
 
@@ -964,6 +1024,14 @@ mod tests {
             .as_str()
             .map(|value| value.ends_with(".tau/channel-store"))
             .unwrap_or(false));
+        assert!(payload["file_checkpoint_dir"]
+            .as_str()
+            .map(|value| value.ends_with(".tau/file-checkpoints"))
+            .unwrap_or(false));
+        assert!(payload["code_index_dir"]
+            .as_str()
+            .map(|value| value.ends_with(".tau/code-index"))
+            .unwrap_or(false));
         assert_eq!(payload["os_sandbox_policy_mode"], "best-effort");
         assert_eq!(payload["os_sandbox_docker_enabled"], false);
         assert_eq!(payload["os_sandbox_docker_image"], "debian:stable-slim");
@@ -1308,6 +1376,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn functional_build_tool_policy_applies_native_sandbox_settings() {
+        let _guard = env_lock().lock().expect("env lock");
+        let vars = ["TAU_MEMORY_DEFAULT_IMPORTANCE_IDENTITY"];
+        let _snapshot = EnvSnapshot::capture(&vars);
+        for name in vars {
+            std::env::remove_var(name);
+        }
+
+        let cli = parse_cli_with_stack_args(vec![
+            "tau-rs",
+            "--os-sandbox-mode",
+            "native",
+            "--os-sandbox-native-allow-network=true",
+            "--os-sandbox-native-memory-mb",
+            "8",
+            "--os-sandbox-native-max-processes",
+            "256",
+        ]);
+        let policy = build_tool_policy(&cli).expect("build tool policy");
+        assert_eq!(policy.os_sandbox_mode, crate::tools::OsSandboxMode::Native);
+        assert!(policy.os_sandbox_native_allow_network);
+        assert_eq!(policy.os_sandbox_native_memory_mb, 64);
+        assert_eq!(policy.os_sandbox_native_max_processes, 256);
+
+        let payload = tool_policy_to_json(&policy);
+        assert_eq!(payload["os_sandbox_mode"], "native");
+        assert_eq!(payload["os_sandbox_native_allow_network"], true);
+        assert_eq!(payload["os_sandbox_native_memory_mb"], 64);
+        assert_eq!(payload["os_sandbox_native_max_processes"], 256);
+    }
+
+    #[test]
+    fn functional_build_tool_policy_applies_lsp_settings() {
+        let _guard = env_lock().lock().expect("env lock");
+        let vars = ["TAU_MEMORY_DEFAULT_IMPORTANCE_IDENTITY"];
+        let _snapshot = EnvSnapshot::capture(&vars);
+        for name in vars {
+            std::env::remove_var(name);
+        }
+
+        let defaults = build_tool_policy(&parse_cli_with_stack_args(vec!["tau-rs"]))
+            .expect("build default tool policy");
+        assert!(defaults.lsp_enabled);
+        assert!(!defaults.lsp_post_edit_diagnostics);
+        assert_eq!(defaults.lsp_request_timeout_ms, 10_000);
+        assert_eq!(defaults.lsp_servers[0].command, vec!["rust-analyzer"]);
+
+        let cli = parse_cli_with_stack_args(vec![
+            "tau-rs",
+            "--lsp-server",
+            "rust=ra-multiplex --stdio,go=",
+            "--lsp-request-timeout-ms",
+            "2500",
+            "--lsp-post-edit-diagnostics=true",
+        ]);
+        let policy = build_tool_policy(&cli).expect("build tool policy");
+        assert!(policy.lsp_post_edit_diagnostics);
+        assert_eq!(policy.lsp_request_timeout_ms, 2_500);
+        assert!(policy
+            .lsp_servers
+            .iter()
+            .all(|server| server.language != "go"));
+
+        let payload = tool_policy_to_json(&policy);
+        assert_eq!(payload["lsp_enabled"], true);
+        assert_eq!(payload["lsp_request_timeout_ms"], 2_500);
+        assert_eq!(payload["lsp_post_edit_diagnostics"], true);
+        assert_eq!(payload["lsp_servers"][1]["language"], "rust");
+        assert_eq!(
+            payload["lsp_servers"][1]["command"],
+            serde_json::json!(["ra-multiplex", "--stdio"])
+        );
+
+        let invalid = parse_cli_with_stack_args(vec!["tau-rs", "--lsp-server", "cobol=cobol-ls"]);
+        let error = build_tool_policy(&invalid).expect_err("unsupported language should fail");
+        assert!(error.to_string().contains("unsupported LSP language"));
+    }
+
+    #[test]
+    fn functional_build_tool_policy_applies_git_session_worktrees() {
+        let _guard = env_lock().lock().expect("env lock");
+        let vars = [
+            "TAU_MEMORY_DEFAULT_IMPORTANCE_IDENTITY",
+            "TAU_GIT_SESSION_WORKTREES",
+        ];
+        let _snapshot = EnvSnapshot::capture(&vars);
+        for name in vars {
+            std::env::remove_var(name);
+        }
+
+        let defaults = build_tool_policy(&parse_cli_with_stack_args(vec!["tau-rs"]))
+            .expect("build default tool policy");
+        assert!(!defaults.git_session_worktrees);
+
+        let cli = parse_cli_with_stack_args(vec!["tau-rs", "--git-session-worktrees"]);
+        let policy = build_tool_policy(&cli).expect("build tool policy");
+        assert!(policy.git_session_worktrees);
+        assert_eq!(tool_policy_to_json(&policy)["git_session_worktrees"], true);
+    }
+
     #[test]
     fn functional_build_tool_policy_applies_tool_builder_settings() {
         let _guard = env_lock().lock().expect("env lock");
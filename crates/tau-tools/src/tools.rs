@@ -38,8 +38,9 @@ use tau_memory::runtime::{
     MemoryScopeFilter, MemorySearchOptions, MemoryType, MemoryTypeImportanceProfile,
 };
 use tau_session::{
-    redo_session_head, resolve_session_navigation_head, session_message_preview, undo_session_head,
-    SessionRuntime, SessionStore,
+    create_session_git_worktree, discover_session_git_repo_root, redo_session_head,
+    resolve_session_navigation_head, session_message_preview, undo_session_head, SessionRuntime,
+    SessionStore,
 };
 
 const BALANCED_COMMAND_ALLOWLIST: &[&str] = &[
@@ -98,12 +99,18 @@ const DOCKER_SANDBOX_DEFAULT_MEMORY_MB: u64 = 256;
 const DOCKER_SANDBOX_DEFAULT_CPUS: f32 = 1.0;
 const DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT: u64 = 256;
 const DOCKER_SANDBOX_TMPFS_SIZE_MB: u64 = 64;
+const NATIVE_SANDBOX_DEFAULT_MEMORY_MB: u64 = 2_048;
+/// `RLIMIT_NPROC` is per user, so no default limit is applied to the native sandbox.
+const NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES: u64 = 0;
+const LSP_REQUEST_TIMEOUT_MS_DEFAULT: u64 = 10_000;
 const SANDBOX_REQUIRED_UNAVAILABLE_ERROR: &str =
     "OS sandbox policy mode 'required' is enabled but command would run without a sandbox launcher";
 const SANDBOX_FORCE_UNAVAILABLE_ERROR: &str =
     "OS sandbox mode 'force' is enabled but no sandbox launcher is configured or available";
 const SANDBOX_DOCKER_UNAVAILABLE_ERROR: &str =
     "OS sandbox Docker backend is enabled but Docker CLI is unavailable";
+const SANDBOX_NATIVE_UNAVAILABLE_ERROR: &str =
+    "OS sandbox mode 'native' requires Linux with Landlock support and a seccomp-capable architecture";
 static MEMORY_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 static BACKGROUND_JOB_RUNTIME_REGISTRY: OnceLock<
     Mutex<HashMap<PathBuf, Arc<BackgroundJobRuntime>>>,
@@ -123,6 +130,7 @@ const BUILTIN_AGENT_TOOL_NAMES: &[&str] = &[
     "read",
     "write",
     "edit",
+    "apply_patch",
     "memory_write",
     "memory_read",
     "memory_delete",
@@ -145,27 +153,64 @@ const BUILTIN_AGENT_TOOL_NAMES: &[&str] = &[
     "skip",
     "grep",
     "glob",
+    "find_definition",
+    "find_references",
+    "outline_file",
+    "symbol_search",
+    "lsp_diagnostics",
+    "lsp_hover",
+    "lsp_definition",
+    "lsp_rename",
+    "git_status",
+    "git_diff",
+    "git_commit",
+    "git_log",
+    "git_blame",
     "ls",
     "http",
     "tool_builder",
     "bash",
 ];
 
+mod apply_patch_tool;
 mod bash_tool;
+mod code_intel_tools;
+mod file_checkpoints;
+mod git_tools;
 mod jobs_tools;
+mod lsp_client;
+mod lsp_tools;
 mod memory_tools;
+mod native_sandbox;
 mod registry_core;
 mod runtime_helpers;
 mod session_tools;
 
+pub use apply_patch_tool::ApplyPatchTool;
 pub use bash_tool::BashTool;
 use bash_tool::{
     evaluate_tool_approval_gate, evaluate_tool_rate_limit_gate, evaluate_tool_rbac_gate,
 };
+pub use code_intel_tools::{
+    FindDefinitionTool, FindReferencesTool, OutlineFileTool, SymbolSearchTool,
+};
+use file_checkpoints::{
+    apply_file_checkpoint, execute_file_checkpoint_history, file_checkpoint_error_result,
+    optional_history_scope, FileCheckpointDirection, HistoryScope,
+};
+pub use git_tools::{GitBlameTool, GitCommitTool, GitDiffTool, GitLogTool, GitStatusTool};
 pub use jobs_tools::{JobsCancelTool, JobsCreateTool, JobsListTool, JobsStatusTool};
+pub use lsp_client::{
+    apply_lsp_server_overrides, default_lsp_servers, parse_lsp_server_spec, LspServerConfig,
+};
+use lsp_client::{lsp_sync_after_edit, lsp_sync_after_file_changes};
+pub use lsp_tools::{LspDefinitionTool, LspDiagnosticsTool, LspHoverTool, LspRenameTool};
 pub use memory_tools::{
     MemoryDeleteTool, MemoryReadTool, MemorySearchTool, MemoryTreeTool, MemoryWriteTool,
 };
+use native_sandbox::{
+    native_sandbox_landlock_abi, native_sandbox_network_filter_supported, NativeSandboxConfig,
+};
 use registry_core::BashSandboxSpec;
 pub use registry_core::{
     builtin_agent_tool_names, register_builtin_tools, register_extension_tools,
@@ -413,6 +458,7 @@ impl AgentTool for ToolBuilderTool {
                 filesystem_mode,
                 network_mode,
                 env_allowlist,
+                host_functions: Vec::new(),
             },
             provided_wat_source,
         };
@@ -686,16 +732,21 @@ impl AgentTool for WriteTool {
             }
         }
 
-        match tokio::fs::write(&resolved, content.as_bytes()).await {
-            Ok(()) => ToolExecutionResult::ok(json!({
-                "path": resolved.display().to_string(),
-                "bytes_written": content.len(),
-            })),
-            Err(error) => ToolExecutionResult::error(json!({
+        if let Err(error) = tokio::fs::write(&resolved, content.as_bytes()).await {
+            return ToolExecutionResult::error(json!({
                 "path": resolved.display().to_string(),
                 "error": error.to_string(),
-            })),
+            }));
+        }
+
+        let mut payload = json!({
+            "path": resolved.display().to_string(),
+            "bytes_written": content.len(),
+        });
+        if let Some(diagnostics) = lsp_sync_after_edit(&self.policy, &resolved, &content).await {
+            payload["lsp_diagnostics"] = diagnostics;
         }
+        ToolExecutionResult::ok(payload)
     }
 }
 
@@ -853,10 +904,14 @@ impl AgentTool for EditTool {
         }
 
         let replacements = if replace_all { occurrences } else { 1 };
-        ToolExecutionResult::ok(json!({
+        let mut payload = json!({
             "path": resolved.display().to_string(),
             "replacements": replacements,
-        }))
+        });
+        if let Some(diagnostics) = lsp_sync_after_edit(&self.policy, &resolved, &updated).await {
+            payload["lsp_diagnostics"] = diagnostics;
+        }
+        ToolExecutionResult::ok(payload)
     }
 }
 
@@ -895,6 +950,10 @@ impl AgentTool for BranchTool {
                     "parent_id": {
                         "type": "integer",
                         "description": "Optional parent entry id. Defaults to session head."
+                    },
+                    "git_worktree": {
+                        "type": "boolean",
+                        "description": "Create a dedicated git worktree for the new branch. Defaults to the git session worktrees policy."
                     }
                 },
                 "required": ["path", "prompt"],
@@ -916,6 +975,10 @@ impl AgentTool for BranchTool {
             Ok(parent_id) => parent_id,
             Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
         };
+        let git_worktree = arguments
+            .get("git_worktree")
+            .and_then(Value::as_bool)
+            .unwrap_or(self.policy.git_session_worktrees);
         if prompt.trim().is_empty() {
             return ToolExecutionResult::error(json!({
                 "tool": "branch",
@@ -1001,6 +1064,29 @@ impl AgentTool for BranchTool {
             }
         };
         let after_entries = store.entries().len();
+        let git_worktree = if git_worktree {
+            let worktree = discover_session_git_repo_root(&store).and_then(|repo_root| {
+                create_session_git_worktree(&repo_root, &store, branch_head_id, None)
+            });
+            match worktree {
+                Ok(worktree) => Some(json!({
+                    "path": worktree.path.display().to_string(),
+                    "branch": worktree.branch,
+                    "created": worktree.created,
+                })),
+                Err(error) => {
+                    return ToolExecutionResult::error(json!({
+                        "tool": "branch",
+                        "path": resolved.display().to_string(),
+                        "reason_code": "session_branch_git_worktree_error",
+                        "branch_head_id": branch_head_id,
+                        "error": format!("{error:#}"),
+                    }))
+                }
+            }
+        } else {
+            None
+        };
 
         ToolExecutionResult::ok(json!({
             "tool": "branch",
@@ -1014,6 +1100,7 @@ impl AgentTool for BranchTool {
             "after_entries": after_entries,
             "appended_entries": after_entries.saturating_sub(before_entries),
             "prompt_preview": session_message_preview(&Message::user(prompt)),
+            "git_worktree": git_worktree,
         }))
     }
 }
@@ -1035,27 +1122,50 @@ impl AgentTool for UndoTool {
         ToolDefinition {
             name: "undo".to_string(),
             description:
-                "Move a session's active navigation head backward using persisted undo history"
+                "Move a session's active navigation head backward using persisted undo history and restore the latest file checkpoint"
                     .to_string(),
             parameters: json!({
                 "type": "object",
                 "properties": {
                     "path": {
                         "type": "string",
-                        "description": "Path to target session JSONL file"
+                        "description": "Path to target session JSONL file; required when scope is 'session'"
+                    },
+                    "scope": {
+                        "type": "string",
+                        "enum": ["all", "session", "files"],
+                        "default": "all",
+                        "description": "History to move: session navigation, file checkpoints, or both"
                     }
                 },
-                "required": ["path"],
                 "additionalProperties": false
             }),
         }
     }
 
     async fn execute(&self, arguments: Value) -> ToolExecutionResult {
-        let path = match required_string(&arguments, "path") {
-            Ok(path) => path,
+        let scope = match optional_history_scope(&arguments) {
+            Ok(scope) => scope,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let path = match optional_string(&arguments, "path") {
+            Ok(path) if scope.includes_session() => path,
+            Ok(_) => None,
             Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
         };
+        let Some(path) = path else {
+            if scope == HistoryScope::Session {
+                return ToolExecutionResult::error(json!({
+                    "error": "missing required string argument 'path'",
+                }));
+            }
+            return execute_file_checkpoint_history(
+                &self.policy,
+                "undo",
+                FileCheckpointDirection::Undo,
+            )
+            .await;
+        };
 
         let resolved = match resolve_and_validate_path(&path, &self.policy, PathMode::Write) {
             Ok(path) => path,
@@ -1099,6 +1209,15 @@ impl AgentTool for UndoTool {
                 }))
             }
         };
+        let file_checkpoint = if scope.includes_files() {
+            match apply_file_checkpoint(&self.policy, "undo", FileCheckpointDirection::Undo) {
+                Ok(Some(transition)) => Some(transition.synced_payload(&self.policy).await),
+                Ok(None) => None,
+                Err(error) => return file_checkpoint_error_result("undo", Some(&resolved), error),
+            }
+        } else {
+            None
+        };
         let mut runtime = SessionRuntime { store, active_head };
         let transition = match undo_session_head(&mut runtime) {
             Ok(transition) => transition,
@@ -1108,11 +1227,12 @@ impl AgentTool for UndoTool {
                     "path": resolved.display().to_string(),
                     "reason_code": "session_navigation_state_error",
                     "error": format!("failed to execute undo: {error}"),
+                    "file_checkpoint": file_checkpoint,
                 }))
             }
         };
 
-        if !transition.changed {
+        if !transition.changed && file_checkpoint.is_none() {
             return ToolExecutionResult::error(json!({
                 "tool": "undo",
                 "path": resolved.display().to_string(),
@@ -1123,19 +1243,26 @@ impl AgentTool for UndoTool {
                 "undo_depth": transition.undo_depth,
                 "redo_depth": transition.redo_depth,
                 "skipped_invalid_targets": transition.skipped_invalid_targets,
+                "file_checkpoint": Value::Null,
             }));
         }
 
+        let reason_code = if transition.changed {
+            "session_undo_applied"
+        } else {
+            "file_checkpoint_undo_applied"
+        };
         ToolExecutionResult::ok(json!({
             "tool": "undo",
             "path": resolved.display().to_string(),
-            "reason_code": "session_undo_applied",
+            "reason_code": reason_code,
             "summary": "undo complete",
             "previous_head_id": transition.previous_head,
             "active_head_id": transition.active_head,
             "undo_depth": transition.undo_depth,
             "redo_depth": transition.redo_depth,
             "skipped_invalid_targets": transition.skipped_invalid_targets,
+            "file_checkpoint": file_checkpoint,
         }))
     }
 }
@@ -1157,27 +1284,50 @@ impl AgentTool for RedoTool {
         ToolDefinition {
             name: "redo".to_string(),
             description:
-                "Move a session's active navigation head forward using persisted redo history"
+                "Move a session's active navigation head forward using persisted redo history and reapply the latest file checkpoint"
                     .to_string(),
             parameters: json!({
                 "type": "object",
                 "properties": {
                     "path": {
                         "type": "string",
-                        "description": "Path to target session JSONL file"
+                        "description": "Path to target session JSONL file; required when scope is 'session'"
+                    },
+                    "scope": {
+                        "type": "string",
+                        "enum": ["all", "session", "files"],
+                        "default": "all",
+                        "description": "History to move: session navigation, file checkpoints, or both"
                     }
                 },
-                "required": ["path"],
                 "additionalProperties": false
             }),
         }
     }
 
     async fn execute(&self, arguments: Value) -> ToolExecutionResult {
-        let path = match required_string(&arguments, "path") {
-            Ok(path) => path,
+        let scope = match optional_history_scope(&arguments) {
+            Ok(scope) => scope,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let path = match optional_string(&arguments, "path") {
+            Ok(path) if scope.includes_session() => path,
+            Ok(_) => None,
             Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
         };
+        let Some(path) = path else {
+            if scope == HistoryScope::Session {
+                return ToolExecutionResult::error(json!({
+                    "error": "missing required string argument 'path'",
+                }));
+            }
+            return execute_file_checkpoint_history(
+                &self.policy,
+                "redo",
+                FileCheckpointDirection::Redo,
+            )
+            .await;
+        };
 
         let resolved = match resolve_and_validate_path(&path, &self.policy, PathMode::Write) {
             Ok(path) => path,
@@ -1221,6 +1371,15 @@ impl AgentTool for RedoTool {
                 }))
             }
         };
+        let file_checkpoint = if scope.includes_files() {
+            match apply_file_checkpoint(&self.policy, "redo", FileCheckpointDirection::Redo) {
+                Ok(Some(transition)) => Some(transition.synced_payload(&self.policy).await),
+                Ok(None) => None,
+                Err(error) => return file_checkpoint_error_result("redo", Some(&resolved), error),
+            }
+        } else {
+            None
+        };
         let mut runtime = SessionRuntime { store, active_head };
         let transition = match redo_session_head(&mut runtime) {
             Ok(transition) => transition,
@@ -1230,11 +1389,12 @@ impl AgentTool for RedoTool {
                     "path": resolved.display().to_string(),
                     "reason_code": "session_navigation_state_error",
                     "error": format!("failed to execute redo: {error}"),
+                    "file_checkpoint": file_checkpoint,
                 }))
             }
         };
 
-        if !transition.changed {
+        if !transition.changed && file_checkpoint.is_none() {
             return ToolExecutionResult::error(json!({
                 "tool": "redo",
                 "path": resolved.display().to_string(),
@@ -1245,19 +1405,26 @@ impl AgentTool for RedoTool {
                 "undo_depth": transition.undo_depth,
                 "redo_depth": transition.redo_depth,
                 "skipped_invalid_targets": transition.skipped_invalid_targets,
+                "file_checkpoint": Value::Null,
             }));
         }
 
+        let reason_code = if transition.changed {
+            "session_redo_applied"
+        } else {
+            "file_checkpoint_redo_applied"
+        };
         ToolExecutionResult::ok(json!({
             "tool": "redo",
             "path": resolved.display().to_string(),
-            "reason_code": "session_redo_applied",
+            "reason_code": reason_code,
             "summary": "redo complete",
             "previous_head_id": transition.previous_head,
             "active_head_id": transition.active_head,
             "undo_depth": transition.undo_depth,
             "redo_depth": transition.redo_depth,
             "skipped_invalid_targets": transition.skipped_invalid_targets,
+            "file_checkpoint": file_checkpoint,
         }))
     }
 }
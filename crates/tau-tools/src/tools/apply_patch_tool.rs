@@ -0,0 +1,871 @@
+use super::file_checkpoints::{
+    read_optional_text, record_file_checkpoint, write_optional_text, FileCheckpointEntry,
+};
+use super::*;
+
+const APPLY_PATCH_MAX_FILES: usize = 64;
+const APPLY_PATCH_MAX_FUZZ: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PatchHunk {
+    pub(super) old_start: Option<usize>,
+    pub(super) old_lines: Vec<String>,
+    pub(super) new_lines: Vec<String>,
+    pub(super) old_missing_final_newline: bool,
+    pub(super) new_missing_final_newline: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum PatchFileAction {
+    Create {
+        content: String,
+    },
+    Delete,
+    Update {
+        hunks: Vec<PatchHunk>,
+        move_to: Option<String>,
+    },
+}
+
+impl PatchFileAction {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Create { .. } => "create",
+            Self::Delete => "delete",
+            Self::Update {
+                move_to: Some(_), ..
+            } => "rename",
+            Self::Update { move_to: None, .. } => "update",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FilePatch {
+    pub(super) path: String,
+    pub(super) action: PatchFileAction,
+}
+
+#[derive(Debug, Default)]
+struct GitFileHeader {
+    old_path: Option<String>,
+    new_path: Option<String>,
+    rename_from: Option<String>,
+    rename_to: Option<String>,
+    new_file: bool,
+    deleted_file: bool,
+}
+
+fn strip_diff_path_prefix(raw: &str, prefix: &str) -> Option<String> {
+    let path = raw.split('\t').next().unwrap_or(raw).trim();
+    if path.is_empty() || path == "/dev/null" {
+        return None;
+    }
+    let path = path.trim_matches('"');
+    Some(path.strip_prefix(prefix).unwrap_or(path).to_string())
+}
+
+fn parse_git_header_paths(rest: &str) -> (Option<String>, Option<String>) {
+    match rest.split_once(" b/") {
+        Some((old, new)) => (
+            strip_diff_path_prefix(old, "a/"),
+            strip_diff_path_prefix(new, ""),
+        ),
+        None => (None, None),
+    }
+}
+
+fn parse_hunk_old_start(header: &str) -> Result<usize, String> {
+    let ranges = header
+        .strip_prefix("@@")
+        .and_then(|rest| rest.split("@@").next())
+        .ok_or_else(|| format!("malformed hunk header '{header}'"))?;
+    let old_range = ranges
+        .split_whitespace()
+        .find_map(|range| range.strip_prefix('-'))
+        .ok_or_else(|| format!("hunk header '{header}' is missing the old range"))?;
+    let start = old_range.split(',').next().unwrap_or(old_range);
+    start
+        .parse::<usize>()
+        .map_err(|_| format!("hunk header '{header}' has an invalid old start line"))
+}
+
+fn is_file_header_at(lines: &[&str], index: usize) -> bool {
+    lines[index].starts_with("--- ")
+        && lines
+            .get(index + 1)
+            .is_some_and(|next| next.starts_with("+++ "))
+}
+
+fn parse_unified_hunk(lines: &[&str], index: &mut usize) -> Result<PatchHunk, String> {
+    let header = lines[*index];
+    let old_start = parse_hunk_old_start(header)?;
+    *index += 1;
+
+    let mut hunk = PatchHunk {
+        old_start: Some(old_start),
+        old_lines: Vec::new(),
+        new_lines: Vec::new(),
+        old_missing_final_newline: false,
+        new_missing_final_newline: false,
+    };
+    let mut last_marker = ' ';
+    let mut trailing_blank_lines = 0usize;
+    while *index < lines.len() {
+        let line = lines[*index];
+        if line.starts_with("@@")
+            || line.starts_with("diff --git ")
+            || is_file_header_at(lines, *index)
+        {
+            break;
+        }
+        if line.is_empty() {
+            // Some editors strip the single-space prefix from blank context lines.
+            hunk.old_lines.push(String::new());
+            hunk.new_lines.push(String::new());
+            trailing_blank_lines += 1;
+            *index += 1;
+            continue;
+        }
+        let marker = line.chars().next().unwrap_or(' ');
+        let body = &line[marker.len_utf8()..];
+        match marker {
+            ' ' => {
+                hunk.old_lines.push(body.to_string());
+                hunk.new_lines.push(body.to_string());
+            }
+            '-' => hunk.old_lines.push(body.to_string()),
+            '+' => hunk.new_lines.push(body.to_string()),
+            '\\' => {
+                if matches!(last_marker, ' ' | '-') {
+                    hunk.old_missing_final_newline = true;
+                }
+                if matches!(last_marker, ' ' | '+') {
+                    hunk.new_missing_final_newline = true;
+                }
+                *index += 1;
+                continue;
+            }
+            _ => break,
+        }
+        last_marker = marker;
+        trailing_blank_lines = 0;
+        *index += 1;
+    }
+    for _ in 0..trailing_blank_lines {
+        hunk.old_lines.pop();
+        hunk.new_lines.pop();
+    }
+
+    if hunk.old_lines.is_empty() && hunk.new_lines.is_empty() {
+        return Err(format!("hunk '{header}' has no content lines"));
+    }
+    Ok(hunk)
+}
+
+fn git_header_matches(
+    header: &GitFileHeader,
+    old_path: Option<&str>,
+    new_path: Option<&str>,
+) -> bool {
+    let side_matches = |header_path: Option<&str>, path: Option<&str>| match (header_path, path) {
+        (Some(header_path), Some(path)) => header_path == path,
+        _ => true,
+    };
+    side_matches(header.old_path.as_deref(), old_path)
+        && side_matches(header.new_path.as_deref(), new_path)
+}
+
+fn finish_git_header_without_hunks(header: GitFileHeader) -> Option<FilePatch> {
+    if let (Some(from), Some(to)) = (header.rename_from, header.rename_to) {
+        return Some(FilePatch {
+            path: from,
+            action: PatchFileAction::Update {
+                hunks: Vec::new(),
+                move_to: Some(to),
+            },
+        });
+    }
+    if header.new_file {
+        return header.new_path.map(|path| FilePatch {
+            path,
+            action: PatchFileAction::Create {
+                content: String::new(),
+            },
+        });
+    }
+    if header.deleted_file {
+        return header.old_path.map(|path| FilePatch {
+            path,
+            action: PatchFileAction::Delete,
+        });
+    }
+    None
+}
+
+/// Parses a unified diff (plain or `git diff` flavored) into per-file patches.
+///
+/// Hunk line counts are not trusted; hunks extend until the next hunk or file header so
+/// hand-written or model-generated diffs with miscounted ranges still parse.
+pub(super) fn parse_unified_diff(patch: &str) -> Result<Vec<FilePatch>, String> {
+    let lines = patch.lines().collect::<Vec<_>>();
+    let mut patches = Vec::new();
+    let mut git_header: Option<GitFileHeader> = None;
+    let mut index = 0usize;
+
+    while index < lines.len() {
+        let line = lines[index];
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            if let Some(patch) = git_header.take().and_then(finish_git_header_without_hunks) {
+                patches.push(patch);
+            }
+            let (old_path, new_path) = parse_git_header_paths(rest);
+            git_header = Some(GitFileHeader {
+                old_path,
+                new_path,
+                ..GitFileHeader::default()
+            });
+            index += 1;
+            continue;
+        }
+        if let Some(header) = git_header.as_mut() {
+            if let Some(path) = line.strip_prefix("rename from ") {
+                header.rename_from = strip_diff_path_prefix(path, "");
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                header.rename_to = strip_diff_path_prefix(path, "");
+            } else if line.starts_with("new file mode") {
+                header.new_file = true;
+            } else if line.starts_with("deleted file mode") {
+                header.deleted_file = true;
+            }
+        }
+        if is_file_header_at(&lines, index) {
+            let old_path = strip_diff_path_prefix(&lines[index][4..], "a/");
+            let new_path = strip_diff_path_prefix(&lines[index + 1][4..], "b/");
+            let header = match git_header.take() {
+                Some(header)
+                    if git_header_matches(&header, old_path.as_deref(), new_path.as_deref()) =>
+                {
+                    header
+                }
+                Some(header) => {
+                    patches.extend(finish_git_header_without_hunks(header));
+                    GitFileHeader::default()
+                }
+                None => GitFileHeader::default(),
+            };
+            index += 2;
+
+            let mut hunks = Vec::new();
+            while index < lines.len() && lines[index].starts_with("@@") {
+                hunks.push(parse_unified_hunk(&lines, &mut index)?);
+            }
+
+            let patch = match (old_path, new_path) {
+                (None, None) => {
+                    return Err("file header has /dev/null on both sides".to_string());
+                }
+                (None, Some(path)) => {
+                    if hunks.iter().any(|hunk| !hunk.old_lines.is_empty()) {
+                        return Err(format!(
+                            "new file '{path}' must not contain removed or context lines"
+                        ));
+                    }
+                    let missing_final_newline = hunks
+                        .last()
+                        .is_some_and(|hunk| hunk.new_missing_final_newline);
+                    let mut content = hunks
+                        .into_iter()
+                        .flat_map(|hunk| hunk.new_lines)
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    if !content.is_empty() && !missing_final_newline {
+                        content.push('\n');
+                    }
+                    FilePatch {
+                        path,
+                        action: PatchFileAction::Create { content },
+                    }
+                }
+                (Some(path), None) => FilePatch {
+                    path,
+                    action: PatchFileAction::Delete,
+                },
+                (Some(old_path), Some(new_path)) => {
+                    let source = header.rename_from.unwrap_or(old_path);
+                    let destination = header.rename_to.unwrap_or(new_path);
+                    if hunks.is_empty() && source == destination {
+                        return Err(format!("patch for '{source}' contains no hunks"));
+                    }
+                    let move_to = (source != destination).then_some(destination);
+                    FilePatch {
+                        path: source,
+                        action: PatchFileAction::Update { hunks, move_to },
+                    }
+                }
+            };
+            patches.push(patch);
+            continue;
+        }
+        if line.starts_with("@@") {
+            return Err(format!(
+                "hunk header '{line}' appears before any '---'/'+++' file header"
+            ));
+        }
+        index += 1;
+    }
+    if let Some(patch) = git_header.take().and_then(finish_git_header_without_hunks) {
+        patches.push(patch);
+    }
+
+    if patches.is_empty() {
+        return Err("patch contains no file changes".to_string());
+    }
+    Ok(patches)
+}
+
+fn structured_text_lines(value: &str) -> Vec<String> {
+    value.lines().map(str::to_string).collect()
+}
+
+/// Parses the structured `files` form of the `apply_patch` arguments.
+pub(super) fn parse_structured_patch(files: &Value) -> Result<Vec<FilePatch>, String> {
+    let files = files
+        .as_array()
+        .ok_or_else(|| "'files' must be an array".to_string())?;
+    if files.is_empty() {
+        return Err("'files' must contain at least one entry".to_string());
+    }
+
+    let mut patches = Vec::with_capacity(files.len());
+    for (index, file) in files.iter().enumerate() {
+        let field = |key: &str| -> Result<String, String> {
+            file.get(key)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| format!("files[{index}] is missing string field '{key}'"))
+        };
+        let path = field("path")?;
+        let action = file
+            .get("action")
+            .and_then(Value::as_str)
+            .unwrap_or("update");
+        let hunks = match file.get("hunks") {
+            None | Some(Value::Null) => Vec::new(),
+            Some(Value::Array(hunks)) => hunks
+                .iter()
+                .enumerate()
+                .map(|(hunk_index, hunk)| {
+                    let text = |key: &str| {
+                        hunk.get(key).and_then(Value::as_str).ok_or_else(|| {
+                            format!("files[{index}].hunks[{hunk_index}] is missing string field '{key}'")
+                        })
+                    };
+                    Ok(PatchHunk {
+                        old_start: None,
+                        old_lines: structured_text_lines(text("old")?),
+                        new_lines: structured_text_lines(text("new")?),
+                        old_missing_final_newline: false,
+                        new_missing_final_newline: false,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?,
+            Some(_) => return Err(format!("files[{index}].hunks must be an array")),
+        };
+
+        let action = match action {
+            "create" => PatchFileAction::Create {
+                content: field("content")?,
+            },
+            "delete" => PatchFileAction::Delete,
+            "update" => {
+                if hunks.is_empty() {
+                    return Err(format!("files[{index}] update requires at least one hunk"));
+                }
+                PatchFileAction::Update {
+                    hunks,
+                    move_to: None,
+                }
+            }
+            "rename" => PatchFileAction::Update {
+                hunks,
+                move_to: Some(field("new_path")?),
+            },
+            other => {
+                return Err(format!(
+                    "files[{index}] has unsupported action '{other}', expected one of: create, update, delete, rename"
+                ))
+            }
+        };
+        patches.push(FilePatch { path, action });
+    }
+    Ok(patches)
+}
+
+fn parse_apply_patch_arguments(arguments: &Value) -> Result<Vec<FilePatch>, String> {
+    let patch = match arguments.get("patch") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(patch)) => Some(patch.as_str()),
+        Some(_) => return Err("optional argument 'patch' must be a string".to_string()),
+    };
+    match (patch, arguments.get("files")) {
+        (Some(patch), None) => parse_unified_diff(patch),
+        (None, Some(files)) => parse_structured_patch(files),
+        (Some(_), Some(_)) => Err("provide either 'patch' or 'files', not both".to_string()),
+        (None, None) => Err("missing required argument 'patch' or 'files'".to_string()),
+    }
+}
+
+fn lines_match(left: &str, right: &str, fuzz: u8) -> bool {
+    match fuzz {
+        0 => left == right,
+        1 => left.trim_end() == right.trim_end(),
+        _ => left.split_whitespace().eq(right.split_whitespace()),
+    }
+}
+
+/// Finds where `needle` applies at or after `cursor`, preferring exact matches and, within a
+/// fuzz level, the candidate closest to the hunk's line hint.
+fn find_hunk_position(
+    lines: &[String],
+    needle: &[String],
+    cursor: usize,
+    hint: Option<usize>,
+) -> Option<(usize, u8)> {
+    if needle.len() > lines.len() {
+        return None;
+    }
+    let last_start = lines.len() - needle.len();
+    for fuzz in 0..=APPLY_PATCH_MAX_FUZZ {
+        let best = (cursor..=last_start)
+            .filter(|start| {
+                needle
+                    .iter()
+                    .zip(&lines[*start..*start + needle.len()])
+                    .all(|(expected, actual)| lines_match(expected, actual, fuzz))
+            })
+            .min_by_key(|start| hint.map_or(0, |hint| start.abs_diff(hint)));
+        if let Some(start) = best {
+            return Some((start, fuzz));
+        }
+    }
+    None
+}
+
+/// Applies hunks in order to `source`, preserving its line-ending style.
+///
+/// Returns the patched text and the highest fuzz level any hunk needed.
+pub(super) fn apply_patch_hunks(source: &str, hunks: &[PatchHunk]) -> Result<(String, u8), String> {
+    let crlf = source.contains("\r\n");
+    let normalized = if crlf {
+        source.replace("\r\n", "\n")
+    } else {
+        source.to_string()
+    };
+    let mut trailing_newline = normalized.is_empty() || normalized.ends_with('\n');
+    let mut lines = normalized.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut cursor = 0usize;
+    let mut offset = 0isize;
+    let mut max_fuzz = 0u8;
+
+    for (index, hunk) in hunks.iter().enumerate() {
+        let hint = hunk
+            .old_start
+            .map(|start| (start.saturating_sub(1) as isize + offset).max(0) as usize);
+        let (start, fuzz) = if hunk.old_lines.is_empty() {
+            let position = match hunk.old_start {
+                Some(start) => ((start as isize + offset).max(0) as usize).max(cursor),
+                None => lines.len(),
+            };
+            (position.min(lines.len()), 0)
+        } else {
+            find_hunk_position(&lines, &hunk.old_lines, cursor, hint).ok_or_else(|| {
+                format!(
+                    "hunk {} did not match: context not found{}",
+                    index + 1,
+                    hunk.old_start
+                        .map(|start| format!(" near line {start}"))
+                        .unwrap_or_default()
+                )
+            })?
+        };
+        let end = start + hunk.old_lines.len();
+        lines.splice(start..end, hunk.new_lines.iter().cloned());
+        cursor = start + hunk.new_lines.len();
+        offset += hunk.new_lines.len() as isize - hunk.old_lines.len() as isize;
+        max_fuzz = max_fuzz.max(fuzz);
+
+        if cursor == lines.len() {
+            if hunk.new_missing_final_newline {
+                trailing_newline = false;
+            } else if hunk.old_missing_final_newline {
+                trailing_newline = true;
+            }
+        }
+    }
+
+    let mut patched = lines.join("\n");
+    if trailing_newline && !lines.is_empty() {
+        patched.push('\n');
+    }
+    if crlf {
+        patched = patched.replace('\n', "\r\n");
+    }
+    Ok((patched, max_fuzz))
+}
+
+#[derive(Debug)]
+struct PlannedFileChange {
+    path: PathBuf,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+fn apply_patch_error(reason_code: &str, error: impl Into<String>) -> ToolExecutionResult {
+    ToolExecutionResult::error(json!({
+        "tool": "apply_patch",
+        "reason_code": reason_code,
+        "error": error.into(),
+    }))
+}
+
+fn rollback_planned_changes(changes: &[PlannedFileChange]) -> Vec<String> {
+    changes
+        .iter()
+        .rev()
+        .filter_map(|change| write_optional_text(&change.path, change.before.as_deref()).err())
+        .collect()
+}
+
+/// Public struct `ApplyPatchTool` used across Tau components.
+pub struct ApplyPatchTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl ApplyPatchTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+
+    fn resolve_patch_path(
+        &self,
+        path: &str,
+        mode: PathMode,
+    ) -> Result<PathBuf, ToolExecutionResult> {
+        let resolved = resolve_and_validate_path(path, &self.policy, mode).map_err(|error| {
+            ToolExecutionResult::error(json!({
+                "tool": "apply_patch",
+                "path": path,
+                "reason_code": "apply_patch_path_denied",
+                "error": error,
+            }))
+        })?;
+        validate_file_target(&resolved, mode, self.policy.enforce_regular_files).map_err(
+            |error| {
+                ToolExecutionResult::error(json!({
+                    "tool": "apply_patch",
+                    "path": resolved.display().to_string(),
+                    "reason_code": "apply_patch_path_denied",
+                    "error": error,
+                }))
+            },
+        )?;
+        if let Some(protected_path_result) =
+            evaluate_protected_path_gate(&self.policy, "apply_patch", &resolved)
+        {
+            return Err(protected_path_result);
+        }
+        Ok(resolved)
+    }
+
+    fn plan_file_patch(
+        &self,
+        patch: &FilePatch,
+        changes: &mut Vec<PlannedFileChange>,
+    ) -> Result<Value, ToolExecutionResult> {
+        let mode = match patch.action {
+            PatchFileAction::Create { .. } => PathMode::Write,
+            _ => PathMode::Edit,
+        };
+        let source = self.resolve_patch_path(&patch.path, mode)?;
+        let read_source = |path: &Path| {
+            read_optional_text(path)
+                .map_err(|error| apply_patch_error("apply_patch_read_failed", error))
+        };
+        let source_display = source.display().to_string();
+
+        match &patch.action {
+            PatchFileAction::Create { content } => {
+                if source.exists() {
+                    return Err(ToolExecutionResult::error(json!({
+                        "tool": "apply_patch",
+                        "path": source_display,
+                        "reason_code": "apply_patch_target_exists",
+                        "error": "cannot create a file that already exists",
+                    })));
+                }
+                changes.push(PlannedFileChange {
+                    path: source,
+                    before: None,
+                    after: Some(content.clone()),
+                });
+                Ok(json!({
+                    "path": source_display,
+                    "action": patch.action.kind(),
+                    "bytes": content.len(),
+                }))
+            }
+            PatchFileAction::Delete => {
+                let before = read_source(&source)?;
+                changes.push(PlannedFileChange {
+                    path: source,
+                    before,
+                    after: None,
+                });
+                Ok(json!({
+                    "path": source_display,
+                    "action": patch.action.kind(),
+                }))
+            }
+            PatchFileAction::Update { hunks, move_to } => {
+                let Some(before) = read_source(&source)? else {
+                    return Err(ToolExecutionResult::error(json!({
+                        "tool": "apply_patch",
+                        "path": source_display,
+                        "reason_code": "apply_patch_read_failed",
+                        "error": "file does not exist",
+                    })));
+                };
+                let (after, fuzz) = apply_patch_hunks(&before, hunks).map_err(|error| {
+                    ToolExecutionResult::error(json!({
+                        "tool": "apply_patch",
+                        "path": source_display,
+                        "reason_code": "apply_patch_hunk_failed",
+                        "error": error,
+                    }))
+                })?;
+                let destination = match move_to {
+                    Some(destination) => {
+                        let resolved = self.resolve_patch_path(destination, PathMode::Write)?;
+                        if resolved.exists() {
+                            return Err(ToolExecutionResult::error(json!({
+                                "tool": "apply_patch",
+                                "path": resolved.display().to_string(),
+                                "reason_code": "apply_patch_target_exists",
+                                "error": "rename destination already exists",
+                            })));
+                        }
+                        Some(resolved)
+                    }
+                    None => None,
+                };
+                let bytes = after.len();
+                let new_path = destination.as_ref().map(|path| path.display().to_string());
+                match destination {
+                    Some(destination) => {
+                        changes.push(PlannedFileChange {
+                            path: source,
+                            before: Some(before),
+                            after: None,
+                        });
+                        changes.push(PlannedFileChange {
+                            path: destination,
+                            before: None,
+                            after: Some(after),
+                        });
+                    }
+                    None => changes.push(PlannedFileChange {
+                        path: source,
+                        before: Some(before),
+                        after: Some(after),
+                    }),
+                }
+                Ok(json!({
+                    "path": source_display,
+                    "new_path": new_path,
+                    "action": patch.action.kind(),
+                    "hunks": hunks.len(),
+                    "fuzz": fuzz,
+                    "bytes": bytes,
+                }))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AgentTool for ApplyPatchTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "apply_patch".to_string(),
+            description: "Atomically apply a multi-file patch (unified diff or structured hunks) with create, delete, and rename support; the change is checkpointed so `undo` restores the files".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "patch": {
+                        "type": "string",
+                        "description": "Unified diff text; /dev/null headers create or delete files and git rename headers move them"
+                    },
+                    "files": {
+                        "type": "array",
+                        "description": "Structured alternative to 'patch'",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "path": { "type": "string" },
+                                "action": {
+                                    "type": "string",
+                                    "enum": ["create", "update", "delete", "rename"],
+                                    "default": "update"
+                                },
+                                "new_path": { "type": "string" },
+                                "content": { "type": "string" },
+                                "hunks": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "object",
+                                        "properties": {
+                                            "old": { "type": "string" },
+                                            "new": { "type": "string" }
+                                        },
+                                        "required": ["old", "new"],
+                                        "additionalProperties": false
+                                    }
+                                }
+                            },
+                            "required": ["path"],
+                            "additionalProperties": false
+                        }
+                    }
+                },
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let patches = match parse_apply_patch_arguments(&arguments) {
+            Ok(patches) => patches,
+            Err(error) => return apply_patch_error("apply_patch_invalid", error),
+        };
+        if patches.len() > APPLY_PATCH_MAX_FILES {
+            return apply_patch_error(
+                "apply_patch_invalid",
+                format!(
+                    "patch touches {} files, limit is {APPLY_PATCH_MAX_FILES}",
+                    patches.len()
+                ),
+            );
+        }
+
+        let mut changes = Vec::new();
+        let mut files = Vec::with_capacity(patches.len());
+        for patch in &patches {
+            match self.plan_file_patch(patch, &mut changes) {
+                Ok(file) => files.push(file),
+                Err(result) => return result,
+            }
+        }
+
+        let mut seen = BTreeSet::new();
+        for change in &changes {
+            if !seen.insert(normalize_policy_path(&change.path)) {
+                return ToolExecutionResult::error(json!({
+                    "tool": "apply_patch",
+                    "path": change.path.display().to_string(),
+                    "reason_code": "apply_patch_duplicate_path",
+                    "error": "a path may only be touched once per patch",
+                }));
+            }
+            let bytes = change.after.as_ref().map_or(0, String::len);
+            if bytes > self.policy.max_file_write_bytes {
+                return ToolExecutionResult::error(json!({
+                    "tool": "apply_patch",
+                    "path": change.path.display().to_string(),
+                    "reason_code": "apply_patch_too_large",
+                    "error": format!(
+                        "patched content is too large ({bytes} bytes), limit is {} bytes",
+                        self.policy.max_file_write_bytes
+                    ),
+                }));
+            }
+        }
+
+        if let Some(rbac_result) = evaluate_tool_rbac_gate(
+            self.policy.rbac_principal.as_deref(),
+            "apply_patch",
+            self.policy.rbac_policy_path.as_deref(),
+            json!({ "files": files }),
+        ) {
+            return rbac_result;
+        }
+
+        for change in &changes {
+            if let Some(approval_result) = evaluate_tool_approval_gate(ApprovalAction::ToolWrite {
+                path: change.path.display().to_string(),
+                content_bytes: change.after.as_ref().map_or(0, String::len),
+            }) {
+                return approval_result;
+            }
+        }
+
+        if let Some(rate_limit_result) =
+            evaluate_tool_rate_limit_gate(&self.policy, "apply_patch", json!({ "files": files }))
+        {
+            return rate_limit_result;
+        }
+
+        for (index, change) in changes.iter().enumerate() {
+            if let Err(error) = write_optional_text(&change.path, change.after.as_deref()) {
+                let rollback_errors = rollback_planned_changes(&changes[..=index]);
+                return ToolExecutionResult::error(json!({
+                    "tool": "apply_patch",
+                    "path": change.path.display().to_string(),
+                    "reason_code": "apply_patch_write_failed",
+                    "error": error,
+                    "rolled_back": rollback_errors.is_empty(),
+                    "rollback_errors": rollback_errors,
+                }));
+            }
+        }
+
+        let checkpoint_entries = changes
+            .iter()
+            .map(|change| FileCheckpointEntry {
+                path: change.path.display().to_string(),
+                before: change.before.clone(),
+                after: change.after.clone(),
+            })
+            .collect::<Vec<_>>();
+        let checkpoint_id =
+            match record_file_checkpoint(&self.policy, "apply_patch", checkpoint_entries) {
+                Ok(checkpoint_id) => checkpoint_id,
+                Err(error) => {
+                    let rollback_errors = rollback_planned_changes(&changes);
+                    return ToolExecutionResult::error(json!({
+                        "tool": "apply_patch",
+                        "reason_code": error.reason_code,
+                        "error": error.message,
+                        "rolled_back": rollback_errors.is_empty(),
+                        "rollback_errors": rollback_errors,
+                    }));
+                }
+            };
+
+        let mut payload = json!({
+            "tool": "apply_patch",
+            "reason_code": "apply_patch_applied",
+            "checkpoint_id": checkpoint_id,
+            "files_changed": files.len(),
+            "files": files,
+        });
+        let synced_files = changes
+            .iter()
+            .map(|change| (change.path.clone(), change.after.clone()))
+            .collect::<Vec<_>>();
+        if let Some(diagnostics) = lsp_sync_after_file_changes(&self.policy, &synced_files).await {
+            payload["lsp_diagnostics"] = diagnostics;
+        }
+        ToolExecutionResult::ok(payload)
+    }
+}
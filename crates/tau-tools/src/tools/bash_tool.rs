@@ -19,11 +19,12 @@ use tau_extensions::evaluate_extension_policy_override;
 use tokio::{process::Command, time::timeout};
 
 use super::{
-    bash_profile_name, is_command_allowed, leading_executable, os_sandbox_mode_name,
-    os_sandbox_policy_mode_name, redact_secrets, required_string, resolve_and_validate_path,
-    resolve_sandbox_spec, truncate_bytes, validate_directory_target, PathMode, ToolPolicy,
-    ToolRateLimitExceededBehavior, SANDBOX_DOCKER_UNAVAILABLE_ERROR,
-    SANDBOX_FORCE_UNAVAILABLE_ERROR, SANDBOX_REQUIRED_UNAVAILABLE_ERROR,
+    bash_profile_name, is_command_allowed, leading_executable,
+    native_sandbox::prepare_native_sandbox, os_sandbox_mode_name, os_sandbox_policy_mode_name,
+    redact_secrets, required_string, resolve_and_validate_path, resolve_sandbox_spec,
+    truncate_bytes, validate_directory_target, PathMode, ToolPolicy, ToolRateLimitExceededBehavior,
+    SANDBOX_DOCKER_UNAVAILABLE_ERROR, SANDBOX_FORCE_UNAVAILABLE_ERROR,
+    SANDBOX_NATIVE_UNAVAILABLE_ERROR, SANDBOX_REQUIRED_UNAVAILABLE_ERROR,
 };
 
 const SAFE_BASH_ENV_VARS: &[&str] = &[
@@ -126,6 +127,7 @@ fn sandbox_reason_code(error: &str) -> &'static str {
         SANDBOX_REQUIRED_UNAVAILABLE_ERROR => "sandbox_policy_required",
         SANDBOX_FORCE_UNAVAILABLE_ERROR => "sandbox_force_unavailable",
         SANDBOX_DOCKER_UNAVAILABLE_ERROR => "sandbox_docker_unavailable",
+        SANDBOX_NATIVE_UNAVAILABLE_ERROR => "sandbox_native_unavailable",
         _ => "sandbox_unavailable",
     }
 }
@@ -156,6 +158,12 @@ pub(super) fn evaluate_tool_rate_limit_gate(
             "tool execution deferred by rate limit for principal",
         ),
     };
+    tau_core::global_metrics_registry().increment_counter(
+        "tau_tool_rate_limit_throttles",
+        "Tool calls throttled by the per-principal rate limit.",
+        &[("tool", tool_name), ("decision", decision_label)],
+        1.0,
+    );
 
     Some(ToolExecutionResult::error(json!({
         "policy_rule": "rate_limit",
@@ -698,6 +706,9 @@ impl AgentTool for BashTool {
                 "sandbox_backend".to_string(),
                 json!(sandbox_spec.backend.clone()),
             );
+            if let Some(native) = &sandbox_spec.native {
+                payload.insert("sandbox_native".to_string(), native.metadata());
+            }
             payload.insert(
                 "sandbox_policy_mode".to_string(),
                 json!(os_sandbox_policy_mode_name(
@@ -740,6 +751,33 @@ impl AgentTool for BashTool {
         if let Some(cwd) = &cwd {
             command_builder.current_dir(cwd);
         }
+        let _native_sandbox_guard = match sandbox_spec.native.as_ref() {
+            Some(native) => match prepare_native_sandbox(&mut command_builder, native) {
+                Ok(guard) => Some(guard),
+                Err(error) => {
+                    let mut payload = serde_json::Map::new();
+                    payload.insert("command".to_string(), json!(command));
+                    payload.insert(
+                        "cwd".to_string(),
+                        json!(cwd.as_ref().map(|value| value.display().to_string())),
+                    );
+                    payload.insert("policy_rule".to_string(), json!("os_sandbox_mode"));
+                    payload.insert(
+                        "reason_code".to_string(),
+                        json!("sandbox_native_setup_failed"),
+                    );
+                    payload.insert(
+                        "sandbox_mode".to_string(),
+                        json!(os_sandbox_mode_name(self.policy.os_sandbox_mode)),
+                    );
+                    payload.insert("sandbox_backend".to_string(), json!("native"));
+                    payload.insert("error".to_string(), json!(error));
+                    attach_policy_trace(&mut payload, trace_enabled, &trace, "deny");
+                    return ToolExecutionResult::error(Value::Object(payload));
+                }
+            },
+            None => None,
+        };
 
         let timeout_duration = Duration::from_millis(self.policy.bash_timeout_ms.max(1));
         let output = match timeout(timeout_duration, command_builder.output()).await {
@@ -789,6 +827,9 @@ impl AgentTool for BashTool {
             "sandbox_mode".to_string(),
             json!(os_sandbox_mode_name(self.policy.os_sandbox_mode)),
         );
+        if let Some(native) = &sandbox_spec.native {
+            payload.insert("sandbox_native".to_string(), native.metadata());
+        }
         payload.insert("sandbox_backend".to_string(), json!(sandbox_spec.backend));
         payload.insert(
             "sandbox_policy_mode".to_string(),
@@ -839,4 +880,12 @@ mod tests {
             "sandbox_docker_unavailable"
         );
     }
+
+    #[test]
+    fn regression_sandbox_reason_code_maps_native_unavailable_constant() {
+        assert_eq!(
+            sandbox_reason_code(SANDBOX_NATIVE_UNAVAILABLE_ERROR),
+            "sandbox_native_unavailable"
+        );
+    }
 }
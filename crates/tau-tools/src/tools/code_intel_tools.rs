@@ -0,0 +1,480 @@
+//! Code-intelligence navigation tools backed by the tree-sitter code index.
+
+use tau_code_intel::{
+    extract_file_symbols, refresh_code_index, CodeIndex, CodeIndexRefreshReport, CodeLanguage,
+    SymbolKind,
+};
+
+use super::*;
+
+const CODE_INTEL_DEFAULT_MAX_RESULTS: usize = 50;
+const CODE_INTEL_MAX_RESULTS: usize = 500;
+const SYMBOL_SEARCH_DEFAULT_MAX_RESULTS: usize = 20;
+const CODE_INTEL_SUPPORTED_LANGUAGES: &str = "rust, typescript, tsx, python, go";
+
+struct CodeIntelScope {
+    root: PathBuf,
+    prefix: Option<String>,
+    index: CodeIndex,
+    report: CodeIndexRefreshReport,
+}
+
+impl CodeIntelScope {
+    fn contains(&self, path: &str) -> bool {
+        match self.prefix.as_deref() {
+            None => true,
+            Some(prefix) => {
+                path == prefix
+                    || path
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            }
+        }
+    }
+
+    fn index_summary(&self) -> Value {
+        json!({
+            "root": self.root.display().to_string(),
+            "index_dir": self.report.index_dir,
+            "files_indexed": self.report.files_indexed,
+            "files_parsed": self.report.files_parsed,
+            "files_reused": self.report.files_reused,
+        })
+    }
+}
+
+fn code_intel_project_root(policy: &ToolPolicy) -> Result<PathBuf, String> {
+    let root = match policy.allowed_roots.first() {
+        Some(root) => root.clone(),
+        None => {
+            std::env::current_dir().map_err(|error| format!("failed to resolve cwd: {error}"))?
+        }
+    };
+    canonicalize_best_effort(&root).map_err(|error| {
+        format!(
+            "failed to canonicalize project root '{}': {error}",
+            root.display()
+        )
+    })
+}
+
+fn code_intel_index_dir(policy: &ToolPolicy, root: &Path) -> PathBuf {
+    if policy.code_index_dir.is_absolute() {
+        policy.code_index_dir.clone()
+    } else {
+        root.join(policy.code_index_dir.as_path())
+    }
+}
+
+fn relative_to_root(root: &Path, path: &Path) -> Result<String, String> {
+    let canonical = canonicalize_best_effort(path)
+        .map_err(|error| format!("failed to canonicalize path '{}': {error}", path.display()))?;
+    let relative = canonical.strip_prefix(root).map_err(|_| {
+        format!(
+            "path '{}' is outside the indexed project root '{}'",
+            canonical.display(),
+            root.display()
+        )
+    })?;
+    Ok(relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Refreshes the code index for the policy's project root and resolves the
+/// optional `path` argument into a root-relative scope prefix.
+fn load_code_intel_scope(policy: &ToolPolicy, arguments: &Value) -> Result<CodeIntelScope, Value> {
+    let root = code_intel_project_root(policy).map_err(|error| json!({ "error": error }))?;
+    let prefix = match arguments.get("path").and_then(Value::as_str) {
+        None => None,
+        Some(path) => {
+            let resolved = resolve_and_validate_path(path, policy, PathMode::Read)
+                .map_err(|error| json!({ "path": path, "error": error }))?;
+            let relative = relative_to_root(&root, &resolved)
+                .map_err(|error| json!({ "path": path, "error": error }))?;
+            (!relative.is_empty()).then_some(relative)
+        }
+    };
+    let refresh =
+        refresh_code_index(&root, &code_intel_index_dir(policy, &root)).map_err(|error| {
+            json!({
+                "root": root.display().to_string(),
+                "error": format!("failed to refresh code index: {error:#}"),
+            })
+        })?;
+    Ok(CodeIntelScope {
+        root,
+        prefix,
+        index: refresh.index,
+        report: refresh.report,
+    })
+}
+
+fn optional_symbol_kind(arguments: &Value) -> Result<Option<SymbolKind>, String> {
+    let Some(value) = arguments.get("kind") else {
+        return Ok(None);
+    };
+    let raw = value
+        .as_str()
+        .ok_or_else(|| "optional argument 'kind' must be a string".to_string())?;
+    SymbolKind::parse(raw)
+        .map(Some)
+        .ok_or_else(|| format!("unsupported symbol kind '{raw}'"))
+}
+
+fn symbol_kind_schema() -> Value {
+    json!({
+        "type": "string",
+        "enum": [
+            "function", "method", "struct", "enum", "trait", "interface",
+            "class", "type", "constant", "variable", "module", "macro"
+        ],
+        "description": "Restrict matches to one definition kind"
+    })
+}
+
+/// Looks up where a symbol is defined using the tree-sitter code index.
+pub struct FindDefinitionTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl FindDefinitionTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for FindDefinitionTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "find_definition".to_string(),
+            description: "Find where a function, type, method, constant or module is defined in Rust, TypeScript, Python or Go sources. Accepts plain names or qualified names such as 'Parser::parse' or 'Widget.render'. Returns file paths, line ranges and signatures.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "symbol": { "type": "string", "description": "Symbol name, optionally qualified by its enclosing type" },
+                    "kind": symbol_kind_schema(),
+                    "path": { "type": "string", "description": "Optional file or directory to restrict the search to" },
+                    "max_results": { "type": "integer", "description": "Maximum number of definitions to return (default 50)" }
+                },
+                "required": ["symbol"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let symbol = match required_string(&arguments, "symbol") {
+            Ok(symbol) => symbol,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let kind = match optional_symbol_kind(&arguments) {
+            Ok(kind) => kind,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let max_results = match optional_usize(
+            &arguments,
+            "max_results",
+            CODE_INTEL_DEFAULT_MAX_RESULTS,
+            CODE_INTEL_MAX_RESULTS,
+        ) {
+            Ok(max_results) => max_results,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let scope = match load_code_intel_scope(&self.policy, &arguments) {
+            Ok(scope) => scope,
+            Err(payload) => return ToolExecutionResult::error(payload),
+        };
+
+        let matches = scope
+            .index
+            .find_definitions(&symbol, kind)
+            .into_iter()
+            .filter(|definition| scope.contains(&definition.path))
+            .collect::<Vec<_>>();
+        let total_matches = matches.len();
+        let definitions = matches.into_iter().take(max_results).collect::<Vec<_>>();
+
+        ToolExecutionResult::ok(json!({
+            "symbol": symbol,
+            "kind": kind.map(SymbolKind::as_str),
+            "path": scope.prefix.as_deref(),
+            "definitions": definitions,
+            "total_matches": total_matches,
+            "truncated": total_matches > max_results,
+            "index": scope.index_summary(),
+        }))
+    }
+}
+
+/// Lists identifier occurrences of a symbol using the tree-sitter code index.
+pub struct FindReferencesTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl FindReferencesTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for FindReferencesTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "find_references".to_string(),
+            description: "Find usage sites of a symbol across Rust, TypeScript, Python and Go sources. Matches identifiers in the syntax tree, so comments and string literals are ignored. Returns file paths, positions and the source line for each reference.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "symbol": { "type": "string", "description": "Symbol name; for qualified names only the last segment is matched" },
+                    "path": { "type": "string", "description": "Optional file or directory to restrict the search to" },
+                    "max_results": { "type": "integer", "description": "Maximum number of references to return (default 50)" }
+                },
+                "required": ["symbol"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let symbol = match required_string(&arguments, "symbol") {
+            Ok(symbol) => symbol,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let max_results = match optional_usize(
+            &arguments,
+            "max_results",
+            CODE_INTEL_DEFAULT_MAX_RESULTS,
+            CODE_INTEL_MAX_RESULTS,
+        ) {
+            Ok(max_results) => max_results,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let scope = match load_code_intel_scope(&self.policy, &arguments) {
+            Ok(scope) => scope,
+            Err(payload) => return ToolExecutionResult::error(payload),
+        };
+
+        let matches = scope
+            .index
+            .find_references(&symbol)
+            .into_iter()
+            .filter(|reference| scope.contains(&reference.path))
+            .collect::<Vec<_>>();
+        let total_matches = matches.len();
+        let mut file_lines: HashMap<String, Vec<String>> = HashMap::new();
+        let references = matches
+            .into_iter()
+            .take(max_results)
+            .map(|reference| {
+                let lines = file_lines.entry(reference.path.clone()).or_insert_with(|| {
+                    std::fs::read_to_string(scope.root.join(&reference.path))
+                        .map(|content| content.lines().map(str::to_string).collect())
+                        .unwrap_or_default()
+                });
+                let content = lines
+                    .get((reference.line as usize).saturating_sub(1))
+                    .map(|line| line.trim())
+                    .unwrap_or_default();
+                json!({
+                    "path": reference.path,
+                    "line": reference.line,
+                    "column": reference.column,
+                    "content": content,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        ToolExecutionResult::ok(json!({
+            "symbol": symbol,
+            "path": scope.prefix.as_deref(),
+            "references": references,
+            "total_matches": total_matches,
+            "truncated": total_matches > max_results,
+            "index": scope.index_summary(),
+        }))
+    }
+}
+
+/// Summarizes the definitions and imports of one source file.
+pub struct OutlineFileTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl OutlineFileTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for OutlineFileTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "outline_file".to_string(),
+            description: "Outline a Rust, TypeScript, Python or Go source file: lists its imports and every definition with kind, enclosing container, line range and signature. Use this before reading a large file to locate the relevant section.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Source file to outline" }
+                },
+                "required": ["path"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let path = match required_string(&arguments, "path") {
+            Ok(path) => path,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let resolved = match resolve_and_validate_path(&path, &self.policy, PathMode::Read) {
+            Ok(path) => path,
+            Err(error) => {
+                return ToolExecutionResult::error(json!({ "path": path, "error": error }))
+            }
+        };
+        if !resolved.is_file() {
+            return ToolExecutionResult::error(json!({
+                "path": resolved.display().to_string(),
+                "error": "path is not a file",
+            }));
+        }
+        let Some(language) = CodeLanguage::from_path(&resolved) else {
+            return ToolExecutionResult::error(json!({
+                "path": resolved.display().to_string(),
+                "error": format!(
+                    "unsupported source language; supported languages: {CODE_INTEL_SUPPORTED_LANGUAGES}"
+                ),
+            }));
+        };
+        let content = match std::fs::read(&resolved) {
+            Ok(content) => content,
+            Err(error) => {
+                return ToolExecutionResult::error(json!({
+                    "path": resolved.display().to_string(),
+                    "error": format!("failed to read file: {error}"),
+                }))
+            }
+        };
+        if content.len() > self.policy.max_file_read_bytes {
+            return ToolExecutionResult::error(json!({
+                "path": resolved.display().to_string(),
+                "error": format!(
+                    "file is too large ({} bytes), limit is {} bytes",
+                    content.len(),
+                    self.policy.max_file_read_bytes
+                ),
+            }));
+        }
+        let Ok(text) = String::from_utf8(content) else {
+            return ToolExecutionResult::error(json!({
+                "path": resolved.display().to_string(),
+                "error": "file is not valid UTF-8",
+            }));
+        };
+        let symbols = match extract_file_symbols(language, &text) {
+            Ok(symbols) => symbols,
+            Err(error) => {
+                return ToolExecutionResult::error(json!({
+                    "path": resolved.display().to_string(),
+                    "error": format!("failed to parse file: {error}"),
+                }))
+            }
+        };
+
+        ToolExecutionResult::ok(json!({
+            "path": resolved.display().to_string(),
+            "language": language.as_str(),
+            "definitions": symbols.definitions,
+            "imports": symbols.imports,
+            "total_definitions": symbols.definitions.len(),
+            "truncated": symbols.truncated,
+        }))
+    }
+}
+
+/// Fuzzy-searches definition names across the tree-sitter code index.
+pub struct SymbolSearchTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl SymbolSearchTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for SymbolSearchTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "symbol_search".to_string(),
+            description: "Search definition names across Rust, TypeScript, Python and Go sources when the exact name is unknown. Ranks exact, prefix, substring and subsequence matches (case-insensitive) and returns file paths, kinds and signatures.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Partial symbol name to search for" },
+                    "kind": symbol_kind_schema(),
+                    "path": { "type": "string", "description": "Optional file or directory to restrict the search to" },
+                    "max_results": { "type": "integer", "description": "Maximum number of symbols to return (default 20)" }
+                },
+                "required": ["query"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let query = match required_string(&arguments, "query") {
+            Ok(query) => query,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        if query.trim().is_empty() {
+            return ToolExecutionResult::error(json!({
+                "error": "query must not be empty",
+            }));
+        }
+        let kind = match optional_symbol_kind(&arguments) {
+            Ok(kind) => kind,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let max_results = match optional_usize(
+            &arguments,
+            "max_results",
+            SYMBOL_SEARCH_DEFAULT_MAX_RESULTS,
+            CODE_INTEL_MAX_RESULTS,
+        ) {
+            Ok(max_results) => max_results,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let scope = match load_code_intel_scope(&self.policy, &arguments) {
+            Ok(scope) => scope,
+            Err(payload) => return ToolExecutionResult::error(payload),
+        };
+
+        let matches = scope
+            .index
+            .search_symbols(&query, kind, usize::MAX)
+            .into_iter()
+            .filter(|hit| scope.contains(&hit.path))
+            .collect::<Vec<_>>();
+        let total_matches = matches.len();
+        let symbols = matches.into_iter().take(max_results).collect::<Vec<_>>();
+
+        ToolExecutionResult::ok(json!({
+            "query": query,
+            "kind": kind.map(SymbolKind::as_str),
+            "path": scope.prefix.as_deref(),
+            "symbols": symbols,
+            "total_matches": total_matches,
+            "truncated": total_matches > max_results,
+            "index": scope.index_summary(),
+        }))
+    }
+}
@@ -0,0 +1,431 @@
+use super::*;
+
+use serde::Deserialize;
+
+const FILE_CHECKPOINT_SCHEMA_VERSION: u32 = 1;
+const FILE_CHECKPOINT_STATE_FILE_NAME: &str = "state.json";
+const FILE_CHECKPOINT_MAX_UNDO_DEPTH: usize = 64;
+static FILE_CHECKPOINT_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+/// Before/after content for one file touched by a checkpointed tool call.
+///
+/// `None` means the file did not exist on that side of the change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct FileCheckpointEntry {
+    pub(super) path: String,
+    pub(super) before: Option<String>,
+    pub(super) after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileCheckpointRecord {
+    schema_version: u32,
+    checkpoint_id: String,
+    tool: String,
+    created_unix_ms: u64,
+    files: Vec<FileCheckpointEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct FileCheckpointState {
+    schema_version: u32,
+    #[serde(default)]
+    undo_stack: Vec<String>,
+    #[serde(default)]
+    redo_stack: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FileCheckpointDirection {
+    Undo,
+    Redo,
+}
+
+impl FileCheckpointDirection {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Undo => "undo",
+            Self::Redo => "redo",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct FileCheckpointTransition {
+    pub(super) checkpoint_id: String,
+    pub(super) tool: String,
+    /// Each restored file with the content it now has (`None` when deleted).
+    pub(super) restored_files: Vec<(PathBuf, Option<String>)>,
+    pub(super) undo_depth: usize,
+    pub(super) redo_depth: usize,
+}
+
+impl FileCheckpointTransition {
+    pub(super) fn payload(&self) -> Value {
+        json!({
+            "checkpoint_id": self.checkpoint_id,
+            "tool": self.tool,
+            "restored_paths": self
+                .restored_files
+                .iter()
+                .map(|(path, _)| path.display().to_string())
+                .collect::<Vec<_>>(),
+            "undo_depth": self.undo_depth,
+            "redo_depth": self.redo_depth,
+        })
+    }
+
+    /// Syncs the restored files to running language servers and returns the payload,
+    /// with an `lsp_diagnostics` list when post-edit diagnostics are enabled.
+    pub(super) async fn synced_payload(&self, policy: &ToolPolicy) -> Value {
+        let mut payload = self.payload();
+        if let Some(diagnostics) = lsp_sync_after_file_changes(policy, &self.restored_files).await {
+            payload["lsp_diagnostics"] = diagnostics;
+        }
+        payload
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct FileCheckpointError {
+    pub(super) reason_code: &'static str,
+    pub(super) message: String,
+}
+
+impl FileCheckpointError {
+    fn new(reason_code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            reason_code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Which history an `undo`/`redo` call moves: session navigation, files, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum HistoryScope {
+    Session,
+    Files,
+    All,
+}
+
+impl HistoryScope {
+    pub(super) fn includes_session(self) -> bool {
+        matches!(self, Self::Session | Self::All)
+    }
+
+    pub(super) fn includes_files(self) -> bool {
+        matches!(self, Self::Files | Self::All)
+    }
+}
+
+pub(super) fn optional_history_scope(arguments: &Value) -> Result<HistoryScope, String> {
+    match optional_string(arguments, "scope")?.as_deref() {
+        None | Some("all") => Ok(HistoryScope::All),
+        Some("session") => Ok(HistoryScope::Session),
+        Some("files") => Ok(HistoryScope::Files),
+        Some(other) => Err(format!(
+            "unsupported scope '{other}', expected one of: session, files, all"
+        )),
+    }
+}
+
+fn file_checkpoint_root(policy: &ToolPolicy) -> PathBuf {
+    normalize_policy_path(policy.file_checkpoint_dir.as_path())
+}
+
+fn load_file_checkpoint_state(root: &Path) -> Result<FileCheckpointState, FileCheckpointError> {
+    let path = root.join(FILE_CHECKPOINT_STATE_FILE_NAME);
+    if !path.exists() {
+        return Ok(FileCheckpointState {
+            schema_version: FILE_CHECKPOINT_SCHEMA_VERSION,
+            ..FileCheckpointState::default()
+        });
+    }
+    let raw = std::fs::read_to_string(&path).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!("failed to read {}: {error}", path.display()),
+        )
+    })?;
+    let state = serde_json::from_str::<FileCheckpointState>(&raw).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!("failed to parse {}: {error}", path.display()),
+        )
+    })?;
+    if state.schema_version != FILE_CHECKPOINT_SCHEMA_VERSION {
+        return Err(FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!(
+                "unsupported file checkpoint schema_version {} in {}",
+                state.schema_version,
+                path.display()
+            ),
+        ));
+    }
+    Ok(state)
+}
+
+fn save_file_checkpoint_state(
+    root: &Path,
+    state: &FileCheckpointState,
+) -> Result<(), FileCheckpointError> {
+    let path = root.join(FILE_CHECKPOINT_STATE_FILE_NAME);
+    let encoded = serde_json::to_string_pretty(state).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!("failed to encode file checkpoint state: {error}"),
+        )
+    })?;
+    tau_core::write_text_atomic(&path, &encoded).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!("failed to write {}: {error}", path.display()),
+        )
+    })
+}
+
+fn file_checkpoint_record_path(root: &Path, checkpoint_id: &str) -> PathBuf {
+    root.join(format!("{checkpoint_id}.json"))
+}
+
+fn load_file_checkpoint_record(
+    root: &Path,
+    checkpoint_id: &str,
+) -> Result<FileCheckpointRecord, FileCheckpointError> {
+    let path = file_checkpoint_record_path(root, checkpoint_id);
+    let raw = std::fs::read_to_string(&path).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_missing",
+            format!("failed to read {}: {error}", path.display()),
+        )
+    })?;
+    serde_json::from_str::<FileCheckpointRecord>(&raw).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_state_error",
+            format!("failed to parse {}: {error}", path.display()),
+        )
+    })
+}
+
+fn remove_file_checkpoint_records(root: &Path, checkpoint_ids: &[String]) {
+    for checkpoint_id in checkpoint_ids {
+        let _ = std::fs::remove_file(file_checkpoint_record_path(root, checkpoint_id));
+    }
+}
+
+/// Persists a checkpoint for one mutating tool call and pushes it onto the undo stack.
+///
+/// Recording a new checkpoint discards any redo history, mirroring session navigation.
+pub(super) fn record_file_checkpoint(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    files: Vec<FileCheckpointEntry>,
+) -> Result<String, FileCheckpointError> {
+    let root = file_checkpoint_root(policy);
+    let mut state = load_file_checkpoint_state(&root)?;
+    let created_unix_ms = current_unix_timestamp_ms();
+    let counter = FILE_CHECKPOINT_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let checkpoint_id = format!(
+        "checkpoint-{created_unix_ms}-{}-{counter}",
+        std::process::id()
+    );
+    let record = FileCheckpointRecord {
+        schema_version: FILE_CHECKPOINT_SCHEMA_VERSION,
+        checkpoint_id: checkpoint_id.clone(),
+        tool: tool_name.to_string(),
+        created_unix_ms,
+        files,
+    };
+    let encoded = serde_json::to_string_pretty(&record).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_record_failed",
+            format!("failed to encode file checkpoint: {error}"),
+        )
+    })?;
+    let record_path = file_checkpoint_record_path(&root, &checkpoint_id);
+    tau_core::write_text_atomic(&record_path, &encoded).map_err(|error| {
+        FileCheckpointError::new(
+            "file_checkpoint_record_failed",
+            format!("failed to write {}: {error}", record_path.display()),
+        )
+    })?;
+
+    let discarded_redo = std::mem::take(&mut state.redo_stack);
+    state.undo_stack.push(checkpoint_id.clone());
+    let overflow = state
+        .undo_stack
+        .len()
+        .saturating_sub(FILE_CHECKPOINT_MAX_UNDO_DEPTH);
+    let evicted = state.undo_stack.drain(..overflow).collect::<Vec<_>>();
+    if let Err(error) = save_file_checkpoint_state(&root, &state) {
+        let _ = std::fs::remove_file(&record_path);
+        return Err(FileCheckpointError::new(
+            "file_checkpoint_record_failed",
+            error.message,
+        ));
+    }
+    remove_file_checkpoint_records(&root, &discarded_redo);
+    remove_file_checkpoint_records(&root, &evicted);
+    Ok(checkpoint_id)
+}
+
+pub(super) fn read_optional_text(path: &Path) -> Result<Option<String>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(format!("failed to read '{}': {error}", path.display())),
+    }
+}
+
+/// Writes `content` atomically, or removes the file when `content` is `None`.
+///
+/// Permissions of an existing file are carried over to the replacement.
+pub(super) fn write_optional_text(path: &Path, content: Option<&str>) -> Result<(), String> {
+    match content {
+        Some(content) => {
+            let permissions = std::fs::metadata(path)
+                .ok()
+                .map(|metadata| metadata.permissions());
+            tau_core::write_text_atomic(path, content)
+                .map_err(|error| format!("failed to write '{}': {error}", path.display()))?;
+            if let Some(permissions) = permissions {
+                std::fs::set_permissions(path, permissions).map_err(|error| {
+                    format!(
+                        "failed to restore permissions on '{}': {error}",
+                        path.display()
+                    )
+                })?;
+            }
+            Ok(())
+        }
+        None => match std::fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(format!("failed to remove '{}': {error}", path.display())),
+        },
+    }
+}
+
+/// Restores (undo) or reapplies (redo) the most recent file checkpoint.
+///
+/// Returns `Ok(None)` when the relevant stack is empty. Every file is checked against the
+/// state the checkpoint expects before anything is written, so files edited outside the
+/// checkpointed tool call are never silently overwritten.
+pub(super) fn apply_file_checkpoint(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    direction: FileCheckpointDirection,
+) -> Result<Option<FileCheckpointTransition>, FileCheckpointError> {
+    let root = file_checkpoint_root(policy);
+    let mut state = load_file_checkpoint_state(&root)?;
+    let checkpoint_id = match direction {
+        FileCheckpointDirection::Undo => state.undo_stack.last().cloned(),
+        FileCheckpointDirection::Redo => state.redo_stack.last().cloned(),
+    };
+    let Some(checkpoint_id) = checkpoint_id else {
+        return Ok(None);
+    };
+    let record = load_file_checkpoint_record(&root, &checkpoint_id)?;
+
+    let mut planned = Vec::with_capacity(record.files.len());
+    for entry in &record.files {
+        let resolved = resolve_and_validate_path(&entry.path, policy, PathMode::Write)
+            .map_err(|error| FileCheckpointError::new("file_checkpoint_path_denied", error))?;
+        validate_file_target(&resolved, PathMode::Write, policy.enforce_regular_files)
+            .map_err(|error| FileCheckpointError::new("file_checkpoint_path_denied", error))?;
+        if evaluate_protected_path_gate(policy, tool_name, &resolved).is_some() {
+            return Err(FileCheckpointError::new(
+                "protected_path_denied",
+                format!("path '{}' is protected by tool policy", resolved.display()),
+            ));
+        }
+        let (expected, target) = match direction {
+            FileCheckpointDirection::Undo => (entry.after.as_deref(), entry.before.as_deref()),
+            FileCheckpointDirection::Redo => (entry.before.as_deref(), entry.after.as_deref()),
+        };
+        let current = read_optional_text(&resolved)
+            .map_err(|error| FileCheckpointError::new("file_checkpoint_io_error", error))?;
+        if current.as_deref() != expected {
+            return Err(FileCheckpointError::new(
+                "file_checkpoint_conflict",
+                format!(
+                    "cannot {} checkpoint '{}': '{}' changed after the checkpoint was recorded",
+                    direction.as_str(),
+                    checkpoint_id,
+                    resolved.display()
+                ),
+            ));
+        }
+        planned.push((resolved, expected, target));
+    }
+
+    for (index, (path, _, target)) in planned.iter().enumerate() {
+        if let Err(error) = write_optional_text(path, *target) {
+            for (rollback_path, expected, _) in planned.iter().take(index + 1) {
+                let _ = write_optional_text(rollback_path, *expected);
+            }
+            return Err(FileCheckpointError::new("file_checkpoint_io_error", error));
+        }
+    }
+
+    match direction {
+        FileCheckpointDirection::Undo => {
+            state.undo_stack.pop();
+            state.redo_stack.push(checkpoint_id.clone());
+        }
+        FileCheckpointDirection::Redo => {
+            state.redo_stack.pop();
+            state.undo_stack.push(checkpoint_id.clone());
+        }
+    }
+    save_file_checkpoint_state(&root, &state)?;
+
+    Ok(Some(FileCheckpointTransition {
+        checkpoint_id,
+        tool: record.tool,
+        restored_files: planned
+            .iter()
+            .map(|(path, _, target)| (path.clone(), target.map(str::to_string)))
+            .collect(),
+        undo_depth: state.undo_stack.len(),
+        redo_depth: state.redo_stack.len(),
+    }))
+}
+
+pub(super) fn file_checkpoint_error_result(
+    tool_name: &str,
+    path: Option<&Path>,
+    error: FileCheckpointError,
+) -> ToolExecutionResult {
+    ToolExecutionResult::error(json!({
+        "tool": tool_name,
+        "path": path.map(|path| path.display().to_string()),
+        "reason_code": error.reason_code,
+        "error": error.message,
+    }))
+}
+
+/// Runs a files-only `undo`/`redo`, used when no session path is supplied.
+pub(super) async fn execute_file_checkpoint_history(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    direction: FileCheckpointDirection,
+) -> ToolExecutionResult {
+    let label = direction.as_str();
+    match apply_file_checkpoint(policy, tool_name, direction) {
+        Ok(Some(transition)) => ToolExecutionResult::ok(json!({
+            "tool": tool_name,
+            "reason_code": format!("file_checkpoint_{label}_applied"),
+            "summary": format!("{label} complete"),
+            "file_checkpoint": transition.synced_payload(policy).await,
+        })),
+        Ok(None) => ToolExecutionResult::error(json!({
+            "tool": tool_name,
+            "reason_code": format!("file_checkpoint_{label}_empty_stack"),
+            "summary": format!("{label} unavailable: no file checkpoint to apply"),
+        })),
+        Err(error) => file_checkpoint_error_result(tool_name, None, error),
+    }
+}
@@ -0,0 +1,1027 @@
+//! Git workspace tools: status, diff, staged commit, log and blame.
+//!
+//! Every tool runs `git -C <cwd>` against a directory inside the allowed roots
+//! (the first allowed root by default) with the bash timeout, and reports
+//! structured results instead of raw porcelain text. Repository hooks and
+//! fsmonitor commands are disabled so no repository-controlled program runs
+//! outside the bash sandbox.
+
+use super::*;
+
+const GIT_LOG_DEFAULT_MAX_COUNT: usize = 20;
+const GIT_LOG_MAX_COUNT: usize = 200;
+const GIT_BLAME_MAX_LINES: usize = 2_000;
+const GIT_MAX_PATHSPECS: usize = 256;
+const GIT_MAX_PATHSPEC_CHARS: usize = 4_096;
+const GIT_COMMIT_MAX_MESSAGE_CHARS: usize = 10_000;
+const GIT_COMMIT_MESSAGE_MAX_LISTED_FILES: usize = 20;
+const GIT_LOG_FIELD_SEPARATOR: char = '\u{1f}';
+const GIT_LOG_RECORD_SEPARATOR: char = '\u{1e}';
+/// Config overrides applied to every invocation so repository-controlled hooks
+/// and fsmonitor programs never execute.
+const GIT_HARDENING_CONFIG: [&str; 4] = [
+    "-c",
+    "core.hooksPath=/dev/null",
+    "-c",
+    "core.fsmonitor=false",
+];
+
+#[derive(Debug)]
+struct GitCommandOutput {
+    stdout: String,
+    stderr: String,
+    success: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct GitStagedChange {
+    path: String,
+    status: char,
+    additions: Option<u64>,
+    deletions: Option<u64>,
+}
+
+fn git_tool_error(
+    tool_name: &str,
+    reason_code: &str,
+    error: impl Into<String>,
+) -> ToolExecutionResult {
+    ToolExecutionResult::error(json!({
+        "tool": tool_name,
+        "reason_code": reason_code,
+        "error": error.into(),
+    }))
+}
+
+/// Resolves the `cwd` argument (or the first allowed root) to a directory the
+/// policy allows git to run in.
+fn resolve_git_cwd(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    arguments: &Value,
+) -> Result<PathBuf, ToolExecutionResult> {
+    let cwd = optional_string(arguments, "cwd")
+        .map_err(|error| ToolExecutionResult::error(json!({ "error": error })))?;
+    let cwd = match cwd {
+        Some(cwd) => cwd,
+        None => match policy.allowed_roots.first() {
+            Some(root) => root.display().to_string(),
+            None => ".".to_string(),
+        },
+    };
+    let resolved = resolve_and_validate_path(&cwd, policy, PathMode::Directory)
+        .map_err(|error| git_tool_error(tool_name, "git_cwd_invalid", format!("{cwd}: {error}")))?;
+    validate_directory_target(&resolved, policy.enforce_regular_files)
+        .map_err(|error| git_tool_error(tool_name, "git_cwd_invalid", error))?;
+    Ok(resolved)
+}
+
+/// Resolves the `paths` argument to absolute pathspecs inside the allowed roots.
+fn resolve_git_pathspecs(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    arguments: &Value,
+) -> Result<Vec<String>, ToolExecutionResult> {
+    let paths = optional_string_array(
+        arguments,
+        "paths",
+        GIT_MAX_PATHSPECS,
+        GIT_MAX_PATHSPEC_CHARS,
+    )
+    .map_err(|error| ToolExecutionResult::error(json!({ "error": error })))?;
+    paths
+        .iter()
+        .map(|path| {
+            resolve_and_validate_path(path, policy, PathMode::Write)
+                .map(|resolved| resolved.display().to_string())
+                .map_err(|error| {
+                    git_tool_error(tool_name, "git_path_invalid", format!("{path}: {error}"))
+                })
+        })
+        .collect()
+}
+
+/// Reads an optional revision argument, rejecting values git would parse as options.
+fn optional_git_revision(
+    arguments: &Value,
+    key: &str,
+    tool_name: &str,
+) -> Result<Option<String>, ToolExecutionResult> {
+    let revision = optional_string(arguments, key)
+        .map_err(|error| ToolExecutionResult::error(json!({ "error": error })))?;
+    if let Some(revision) = revision.as_deref() {
+        if revision.starts_with('-') || revision.chars().any(char::is_whitespace) {
+            return Err(git_tool_error(
+                tool_name,
+                "git_revision_invalid",
+                format!("'{key}' must be a revision, not '{revision}'"),
+            ));
+        }
+    }
+    Ok(revision)
+}
+
+fn evaluate_git_tool_gates(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    payload: Value,
+) -> Option<ToolExecutionResult> {
+    if let Some(rbac_result) = evaluate_tool_rbac_gate(
+        policy.rbac_principal.as_deref(),
+        tool_name,
+        policy.rbac_policy_path.as_deref(),
+        payload.clone(),
+    ) {
+        return Some(rbac_result);
+    }
+    evaluate_tool_rate_limit_gate(policy, tool_name, payload)
+}
+
+async fn run_git_command(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    cwd: &Path,
+    args: &[String],
+) -> Result<GitCommandOutput, ToolExecutionResult> {
+    let mut command = tokio::process::Command::new("git");
+    command
+        .args(GIT_HARDENING_CONFIG)
+        .arg("-C")
+        .arg(cwd)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env("GIT_OPTIONAL_LOCKS", "0")
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true);
+    let timeout = Duration::from_millis(policy.bash_timeout_ms.max(1));
+    let output = match tokio::time::timeout(timeout, command.output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(error)) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Err(git_tool_error(
+                tool_name,
+                "git_unavailable",
+                "git executable was not found on PATH",
+            ))
+        }
+        Ok(Err(error)) => {
+            return Err(git_tool_error(
+                tool_name,
+                "git_spawn_failed",
+                format!("failed to run git: {error}"),
+            ))
+        }
+        Err(_) => {
+            return Err(git_tool_error(
+                tool_name,
+                "git_timeout",
+                format!("git timed out after {} ms", policy.bash_timeout_ms),
+            ))
+        }
+    };
+    Ok(GitCommandOutput {
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        success: output.status.success(),
+    })
+}
+
+/// Runs git and maps a non-zero exit status to a `git_command_failed` error.
+async fn run_git_checked(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    cwd: &Path,
+    args: &[String],
+) -> Result<String, ToolExecutionResult> {
+    let output = run_git_command(policy, tool_name, cwd, args).await?;
+    if output.success {
+        return Ok(output.stdout);
+    }
+    let detail = if output.stderr.trim().is_empty() {
+        output.stdout.trim().to_string()
+    } else {
+        output.stderr.trim().to_string()
+    };
+    Err(ToolExecutionResult::error(json!({
+        "tool": tool_name,
+        "reason_code": "git_command_failed",
+        "cwd": cwd.display().to_string(),
+        "command": format!("git {}", args.join(" ")),
+        "error": redact_secrets(&truncate_bytes(&detail, policy.max_command_output_bytes)),
+    })))
+}
+
+fn git_args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| (*arg).to_string()).collect()
+}
+
+fn push_pathspecs(args: &mut Vec<String>, pathspecs: &[String]) {
+    args.push("--".to_string());
+    args.extend(pathspecs.iter().cloned());
+}
+
+/// Parses `git status --porcelain=v1 --branch -z` output.
+pub(super) fn parse_git_status_porcelain(output: &str) -> Value {
+    let mut branch = Value::Null;
+    let mut upstream = Value::Null;
+    let mut ahead = 0_u64;
+    let mut behind = 0_u64;
+    let mut entries = Vec::new();
+    let (mut staged, mut unstaged, mut untracked, mut conflicted) = (0_usize, 0, 0, 0);
+
+    let mut records = output.split('\0').filter(|record| !record.is_empty());
+    while let Some(record) = records.next() {
+        if let Some(header) = record.strip_prefix("## ") {
+            let (names, tracking) = match header.split_once(" [") {
+                Some((names, tracking)) => (names, tracking.trim_end_matches(']')),
+                None => (header, ""),
+            };
+            let names = names
+                .strip_prefix("No commits yet on ")
+                .or_else(|| names.strip_prefix("Initial commit on "))
+                .unwrap_or(names);
+            match names.split_once("...") {
+                Some((local, remote)) => {
+                    branch = json!(local);
+                    upstream = json!(remote);
+                }
+                None if names == "HEAD (no branch)" => {}
+                None => branch = json!(names),
+            }
+            for part in tracking.split(", ") {
+                if let Some(count) = part.strip_prefix("ahead ") {
+                    ahead = count.parse().unwrap_or(0);
+                } else if let Some(count) = part.strip_prefix("behind ") {
+                    behind = count.parse().unwrap_or(0);
+                }
+            }
+            continue;
+        }
+        if record.len() < 4 {
+            continue;
+        }
+        let mut codes = record.chars();
+        let index_status = codes.next().unwrap_or(' ');
+        let worktree_status = codes.next().unwrap_or(' ');
+        let path = &record[3..];
+        let original_path = if matches!(index_status, 'R' | 'C') {
+            records.next()
+        } else {
+            None
+        };
+        let is_conflict = matches!(
+            (index_status, worktree_status),
+            ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D')
+        );
+        if index_status == '?' {
+            untracked += 1;
+        } else if is_conflict {
+            conflicted += 1;
+        } else {
+            if index_status != ' ' {
+                staged += 1;
+            }
+            if worktree_status != ' ' {
+                unstaged += 1;
+            }
+        }
+        entries.push(json!({
+            "path": path,
+            "original_path": original_path,
+            "index_status": index_status.to_string(),
+            "worktree_status": worktree_status.to_string(),
+        }));
+    }
+
+    json!({
+        "branch": branch,
+        "upstream": upstream,
+        "ahead": ahead,
+        "behind": behind,
+        "clean": entries.is_empty(),
+        "staged_count": staged,
+        "unstaged_count": unstaged,
+        "untracked_count": untracked,
+        "conflicted_count": conflicted,
+        "entries": entries,
+    })
+}
+
+/// Parses `git diff --numstat` output; binary files report `null` counts.
+fn parse_git_numstat(output: &str) -> Vec<(String, Option<u64>, Option<u64>)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let additions = fields.next()?;
+            let deletions = fields.next()?;
+            let path = fields.next()?;
+            Some((
+                path.to_string(),
+                additions.parse().ok(),
+                deletions.parse().ok(),
+            ))
+        })
+        .collect()
+}
+
+pub(super) fn parse_git_staged_changes(name_status: &str, numstat: &str) -> Vec<GitStagedChange> {
+    let counts = parse_git_numstat(numstat)
+        .into_iter()
+        .map(|(path, additions, deletions)| (path, (additions, deletions)))
+        .collect::<HashMap<_, _>>();
+    name_status
+        .lines()
+        .filter_map(|line| {
+            let (status, path) = line.split_once('\t')?;
+            let (additions, deletions) = counts.get(path).copied().unwrap_or((None, None));
+            Some(GitStagedChange {
+                path: path.to_string(),
+                status: status.chars().next().unwrap_or('M'),
+                additions,
+                deletions,
+            })
+        })
+        .collect()
+}
+
+fn git_change_verb(status: char) -> &'static str {
+    match status {
+        'A' => "Add",
+        'D' => "Delete",
+        _ => "Update",
+    }
+}
+
+/// Builds a commit message from the staged changes: a one-line subject plus a
+/// body listing each file with its line counts.
+pub(super) fn generate_git_commit_message(changes: &[GitStagedChange]) -> String {
+    let first_status = changes.first().map(|change| change.status).unwrap_or('M');
+    let verb = if changes.iter().all(|change| change.status == first_status) {
+        git_change_verb(first_status)
+    } else {
+        "Update"
+    };
+    let subject = match changes {
+        [change] => format!("{} {}", git_change_verb(change.status), change.path),
+        _ => format!("{verb} {} files", changes.len()),
+    };
+
+    let mut body = Vec::new();
+    for change in changes.iter().take(GIT_COMMIT_MESSAGE_MAX_LISTED_FILES) {
+        let counts = match (change.additions, change.deletions) {
+            (Some(additions), Some(deletions)) => format!(" (+{additions} -{deletions})"),
+            _ => " (binary)".to_string(),
+        };
+        body.push(format!(
+            "- {} {}{counts}",
+            git_change_verb(change.status).to_ascii_lowercase(),
+            change.path
+        ));
+    }
+    if changes.len() > GIT_COMMIT_MESSAGE_MAX_LISTED_FILES {
+        body.push(format!(
+            "- and {} more files",
+            changes.len() - GIT_COMMIT_MESSAGE_MAX_LISTED_FILES
+        ));
+    }
+    format!("{subject}\n\n{}", body.join("\n"))
+}
+
+fn parse_git_log(output: &str) -> Vec<Value> {
+    output
+        .split(GIT_LOG_RECORD_SEPARATOR)
+        .map(|record| record.trim_start_matches('\n'))
+        .filter(|record| !record.is_empty())
+        .filter_map(|record| {
+            let fields = record.split(GIT_LOG_FIELD_SEPARATOR).collect::<Vec<_>>();
+            let [commit, short, author, email, date, subject] = fields.as_slice() else {
+                return None;
+            };
+            Some(json!({
+                "commit": commit,
+                "short_commit": short,
+                "author": author,
+                "author_email": email,
+                "date": date,
+                "subject": subject,
+            }))
+        })
+        .collect()
+}
+
+/// Parses `git blame --porcelain` output into one record per line.
+fn parse_git_blame_porcelain(output: &str) -> Vec<Value> {
+    let mut commits: HashMap<String, (String, String, String)> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current: Option<(String, u64)> = None;
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let Some((commit, line_number)) = current.take() else {
+                continue;
+            };
+            let (author, author_time, summary) = commits.get(&commit).cloned().unwrap_or_default();
+            lines.push(json!({
+                "line": line_number,
+                "commit": commit,
+                "author": author,
+                "author_time": author_time.parse::<i64>().ok(),
+                "summary": summary,
+                "content": content,
+            }));
+            continue;
+        }
+        match &current {
+            None => {
+                let mut fields = line.split(' ');
+                let commit = fields.next().unwrap_or_default();
+                let line_number = fields.nth(1).and_then(|value| value.parse().ok());
+                if let Some(line_number) = line_number.filter(|_| commit.len() >= 40) {
+                    commits.entry(commit.to_string()).or_default();
+                    current = Some((commit.to_string(), line_number));
+                }
+            }
+            Some((commit, _)) => {
+                let Some(metadata) = commits.get_mut(commit) else {
+                    continue;
+                };
+                if let Some(author) = line.strip_prefix("author ") {
+                    metadata.0 = author.to_string();
+                } else if let Some(author_time) = line.strip_prefix("author-time ") {
+                    metadata.1 = author_time.to_string();
+                } else if let Some(summary) = line.strip_prefix("summary ") {
+                    metadata.2 = summary.to_string();
+                }
+            }
+        }
+    }
+    lines
+}
+
+/// Reports branch, upstream tracking and changed files for a git working tree.
+pub struct GitStatusTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl GitStatusTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for GitStatusTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "git_status".to_string(),
+            description: "Show the current branch, upstream ahead/behind counts and staged, unstaged and untracked files of a git working tree".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "cwd": { "type": "string", "description": "Directory inside the repository (defaults to the workspace root)" },
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Optional paths to limit the status to" }
+                },
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let cwd = match resolve_git_cwd(&self.policy, "git_status", &arguments) {
+            Ok(cwd) => cwd,
+            Err(error) => return error,
+        };
+        let pathspecs = match resolve_git_pathspecs(&self.policy, "git_status", &arguments) {
+            Ok(pathspecs) => pathspecs,
+            Err(error) => return error,
+        };
+        if let Some(gate_result) = evaluate_git_tool_gates(
+            &self.policy,
+            "git_status",
+            json!({ "cwd": cwd.display().to_string(), "paths": pathspecs }),
+        ) {
+            return gate_result;
+        }
+
+        let mut args = git_args(&["status", "--porcelain=v1", "--branch", "-z"]);
+        push_pathspecs(&mut args, &pathspecs);
+        let output = match run_git_checked(&self.policy, "git_status", &cwd, &args).await {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let mut payload = parse_git_status_porcelain(&output);
+        if let Some(object) = payload.as_object_mut() {
+            object.insert("tool".to_string(), json!("git_status"));
+            object.insert("cwd".to_string(), json!(cwd.display().to_string()));
+        }
+        ToolExecutionResult::ok(payload)
+    }
+}
+
+/// Shows unstaged, staged or revision diffs with per-file line counts.
+pub struct GitDiffTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl GitDiffTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for GitDiffTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "git_diff".to_string(),
+            description: "Show a unified diff with per-file addition/deletion counts. Defaults to unstaged changes; set staged=true for the index or base to compare against a revision".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "cwd": { "type": "string", "description": "Directory inside the repository (defaults to the workspace root)" },
+                    "staged": { "type": "boolean", "default": false, "description": "Diff the index against HEAD instead of the working tree against the index" },
+                    "base": { "type": "string", "description": "Optional revision to compare against (e.g. main, HEAD~3)" },
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Optional paths to limit the diff to" }
+                },
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let staged = arguments
+            .get("staged")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let base = match optional_git_revision(&arguments, "base", "git_diff") {
+            Ok(base) => base,
+            Err(error) => return error,
+        };
+        let cwd = match resolve_git_cwd(&self.policy, "git_diff", &arguments) {
+            Ok(cwd) => cwd,
+            Err(error) => return error,
+        };
+        let pathspecs = match resolve_git_pathspecs(&self.policy, "git_diff", &arguments) {
+            Ok(pathspecs) => pathspecs,
+            Err(error) => return error,
+        };
+        if let Some(gate_result) = evaluate_git_tool_gates(
+            &self.policy,
+            "git_diff",
+            json!({
+                "cwd": cwd.display().to_string(),
+                "staged": staged,
+                "base": base,
+                "paths": pathspecs,
+            }),
+        ) {
+            return gate_result;
+        }
+
+        let mut diff_args = git_args(&["diff", "--no-color", "--no-ext-diff"]);
+        if staged {
+            diff_args.push("--cached".to_string());
+        }
+        if let Some(base) = &base {
+            diff_args.push(base.clone());
+        }
+        let mut numstat_args = diff_args.clone();
+        numstat_args.insert(1, "--numstat".to_string());
+        push_pathspecs(&mut numstat_args, &pathspecs);
+        push_pathspecs(&mut diff_args, &pathspecs);
+
+        let numstat = match run_git_checked(&self.policy, "git_diff", &cwd, &numstat_args).await {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let diff = match run_git_checked(&self.policy, "git_diff", &cwd, &diff_args).await {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let files = parse_git_numstat(&numstat);
+        let additions = files.iter().filter_map(|(_, added, _)| *added).sum::<u64>();
+        let deletions = files
+            .iter()
+            .filter_map(|(_, _, deleted)| *deleted)
+            .sum::<u64>();
+        let files = files
+            .into_iter()
+            .map(|(path, added, deleted)| {
+                json!({
+                    "path": path,
+                    "additions": added,
+                    "deletions": deleted,
+                    "binary": added.is_none(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let diff = redact_secrets(&diff);
+        ToolExecutionResult::ok(json!({
+            "tool": "git_diff",
+            "cwd": cwd.display().to_string(),
+            "staged": staged,
+            "base": base,
+            "file_count": files.len(),
+            "additions": additions,
+            "deletions": deletions,
+            "files": files,
+            "truncated": diff.len() > self.policy.max_command_output_bytes,
+            "diff": truncate_bytes(&diff, self.policy.max_command_output_bytes),
+        }))
+    }
+}
+
+/// Commits staged changes, optionally staging paths first and generating the
+/// message from the staged diff when none is given.
+pub struct GitCommitTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl GitCommitTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for GitCommitTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "git_commit".to_string(),
+            description: "Commit staged changes. Optionally stage paths first; when message is omitted a message is generated from the staged files and line counts".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "cwd": { "type": "string", "description": "Directory inside the repository (defaults to the workspace root)" },
+                    "message": {
+                        "type": "string",
+                        "description": format!(
+                            "Commit message (max {GIT_COMMIT_MAX_MESSAGE_CHARS} characters). Generated from the staged changes when omitted."
+                        )
+                    },
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Paths to stage (including deletions) before committing" }
+                },
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let message = match optional_string(&arguments, "message") {
+            Ok(message) => message,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        if message
+            .as_ref()
+            .is_some_and(|message| message.chars().count() > GIT_COMMIT_MAX_MESSAGE_CHARS)
+        {
+            return git_tool_error(
+                "git_commit",
+                "git_commit_message_too_large",
+                format!("message exceeds max length of {GIT_COMMIT_MAX_MESSAGE_CHARS} characters"),
+            );
+        }
+        let cwd = match resolve_git_cwd(&self.policy, "git_commit", &arguments) {
+            Ok(cwd) => cwd,
+            Err(error) => return error,
+        };
+        let pathspecs = match resolve_git_pathspecs(&self.policy, "git_commit", &arguments) {
+            Ok(pathspecs) => pathspecs,
+            Err(error) => return error,
+        };
+
+        let mut approval_command = String::new();
+        if !pathspecs.is_empty() {
+            approval_command.push_str(&format!("git add -A -- {} && ", pathspecs.join(" ")));
+        }
+        approval_command.push_str("git commit");
+        if let Some(approval_result) = evaluate_tool_approval_gate(ApprovalAction::ToolBash {
+            command: approval_command,
+            cwd: Some(cwd.display().to_string()),
+        }) {
+            return approval_result;
+        }
+        if let Some(gate_result) = evaluate_git_tool_gates(
+            &self.policy,
+            "git_commit",
+            json!({
+                "cwd": cwd.display().to_string(),
+                "paths": pathspecs,
+                "message_provided": message.is_some(),
+            }),
+        ) {
+            return gate_result;
+        }
+
+        if !pathspecs.is_empty() {
+            let mut add_args = git_args(&["add", "-A"]);
+            push_pathspecs(&mut add_args, &pathspecs);
+            if let Err(error) = run_git_checked(&self.policy, "git_commit", &cwd, &add_args).await {
+                return error;
+            }
+        }
+
+        let name_status = match run_git_checked(
+            &self.policy,
+            "git_commit",
+            &cwd,
+            &git_args(&["diff", "--cached", "--no-renames", "--name-status"]),
+        )
+        .await
+        {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let numstat = match run_git_checked(
+            &self.policy,
+            "git_commit",
+            &cwd,
+            &git_args(&["diff", "--cached", "--no-renames", "--numstat"]),
+        )
+        .await
+        {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let changes = parse_git_staged_changes(&name_status, &numstat);
+        if changes.is_empty() {
+            return git_tool_error(
+                "git_commit",
+                "git_nothing_staged",
+                "no staged changes to commit; stage files or pass paths",
+            );
+        }
+
+        let generated = message.is_none();
+        let message = message.unwrap_or_else(|| generate_git_commit_message(&changes));
+        let commit_args = vec![
+            "commit".to_string(),
+            "--quiet".to_string(),
+            "--no-verify".to_string(),
+            "-m".to_string(),
+            message.clone(),
+        ];
+        if let Err(error) = run_git_checked(&self.policy, "git_commit", &cwd, &commit_args).await {
+            return error;
+        }
+        let commit = match run_git_checked(
+            &self.policy,
+            "git_commit",
+            &cwd,
+            &git_args(&["rev-parse", "HEAD"]),
+        )
+        .await
+        {
+            Ok(output) => output.trim().to_string(),
+            Err(error) => return error,
+        };
+        let branch = run_git_checked(
+            &self.policy,
+            "git_commit",
+            &cwd,
+            &git_args(&["rev-parse", "--abbrev-ref", "HEAD"]),
+        )
+        .await
+        .map(|output| output.trim().to_string())
+        .ok();
+
+        ToolExecutionResult::ok(json!({
+            "tool": "git_commit",
+            "cwd": cwd.display().to_string(),
+            "reason_code": "git_commit_created",
+            "commit": commit,
+            "branch": branch,
+            "message": message,
+            "message_generated": generated,
+            "file_count": changes.len(),
+            "files": changes
+                .iter()
+                .map(|change| json!({
+                    "path": change.path,
+                    "status": change.status.to_string(),
+                    "additions": change.additions,
+                    "deletions": change.deletions,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+/// Lists recent commits for a revision and optional paths.
+pub struct GitLogTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl GitLogTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for GitLogTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "git_log".to_string(),
+            description: "List recent commits (hash, author, date, subject), optionally for a revision or limited to paths".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "cwd": { "type": "string", "description": "Directory inside the repository (defaults to the workspace root)" },
+                    "revision": { "type": "string", "description": "Revision or range to list (defaults to HEAD)" },
+                    "max_count": {
+                        "type": "integer",
+                        "description": format!(
+                            "Maximum commits to return (default {GIT_LOG_DEFAULT_MAX_COUNT}, max {GIT_LOG_MAX_COUNT})"
+                        )
+                    },
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Optional paths to limit history to" }
+                },
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let max_count = match optional_usize(
+            &arguments,
+            "max_count",
+            GIT_LOG_DEFAULT_MAX_COUNT,
+            GIT_LOG_MAX_COUNT,
+        ) {
+            Ok(max_count) => max_count,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let revision = match optional_git_revision(&arguments, "revision", "git_log") {
+            Ok(revision) => revision,
+            Err(error) => return error,
+        };
+        let cwd = match resolve_git_cwd(&self.policy, "git_log", &arguments) {
+            Ok(cwd) => cwd,
+            Err(error) => return error,
+        };
+        let pathspecs = match resolve_git_pathspecs(&self.policy, "git_log", &arguments) {
+            Ok(pathspecs) => pathspecs,
+            Err(error) => return error,
+        };
+        if let Some(gate_result) = evaluate_git_tool_gates(
+            &self.policy,
+            "git_log",
+            json!({
+                "cwd": cwd.display().to_string(),
+                "revision": revision,
+                "max_count": max_count,
+                "paths": pathspecs,
+            }),
+        ) {
+            return gate_result;
+        }
+
+        let mut args = vec![
+            "log".to_string(),
+            "--no-color".to_string(),
+            format!("--max-count={max_count}"),
+            "--format=%H%x1f%h%x1f%an%x1f%ae%x1f%aI%x1f%s%x1e".to_string(),
+        ];
+        if let Some(revision) = &revision {
+            args.push(revision.clone());
+        }
+        push_pathspecs(&mut args, &pathspecs);
+        let output = match run_git_checked(&self.policy, "git_log", &cwd, &args).await {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let commits = parse_git_log(&output);
+        ToolExecutionResult::ok(json!({
+            "tool": "git_log",
+            "cwd": cwd.display().to_string(),
+            "revision": revision,
+            "returned": commits.len(),
+            "commits": commits,
+        }))
+    }
+}
+
+/// Attributes each line of a file to the commit that last changed it.
+pub struct GitBlameTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl GitBlameTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for GitBlameTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "git_blame".to_string(),
+            description: format!(
+                "Show the commit, author and date that last changed each line of a file (1-based line range, at most {GIT_BLAME_MAX_LINES} lines)"
+            ),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "File to blame" },
+                    "start_line": { "type": "integer", "description": "First line to blame (1-based)" },
+                    "end_line": { "type": "integer", "description": "Last line to blame (inclusive)" },
+                    "revision": { "type": "string", "description": "Optional revision to blame at (defaults to the working tree)" }
+                },
+                "required": ["path"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let path = match required_string(&arguments, "path") {
+            Ok(path) => path,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let start_line = match optional_positive_u64(&arguments, "start_line") {
+            Ok(start_line) => start_line,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let end_line = match optional_positive_u64(&arguments, "end_line") {
+            Ok(end_line) => end_line,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        if let (Some(start_line), Some(end_line)) = (start_line, end_line) {
+            if end_line < start_line {
+                return git_tool_error(
+                    "git_blame",
+                    "git_blame_range_invalid",
+                    format!("end_line {end_line} is before start_line {start_line}"),
+                );
+            }
+        }
+        let revision = match optional_git_revision(&arguments, "revision", "git_blame") {
+            Ok(revision) => revision,
+            Err(error) => return error,
+        };
+        let resolved = match resolve_and_validate_path(&path, &self.policy, PathMode::Read) {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                return ToolExecutionResult::error(json!({ "path": path, "error": error }))
+            }
+        };
+        if let Err(error) =
+            validate_file_target(&resolved, PathMode::Read, self.policy.enforce_regular_files)
+        {
+            return ToolExecutionResult::error(json!({
+                "path": resolved.display().to_string(),
+                "error": error,
+            }));
+        }
+        let Some(cwd) = resolved.parent().map(Path::to_path_buf) else {
+            return git_tool_error(
+                "git_blame",
+                "git_path_invalid",
+                format!("path '{}' has no parent directory", resolved.display()),
+            );
+        };
+        if let Some(gate_result) = evaluate_git_tool_gates(
+            &self.policy,
+            "git_blame",
+            json!({
+                "path": resolved.display().to_string(),
+                "start_line": start_line,
+                "end_line": end_line,
+                "revision": revision,
+            }),
+        ) {
+            return gate_result;
+        }
+
+        let start = start_line.unwrap_or(1);
+        let end = end_line
+            .unwrap_or(u64::MAX)
+            .min(start.saturating_add(GIT_BLAME_MAX_LINES as u64 - 1));
+        let mut args = vec![
+            "blame".to_string(),
+            "--porcelain".to_string(),
+            format!("-L{start},{end}"),
+        ];
+        if let Some(revision) = &revision {
+            args.push(revision.clone());
+        }
+        args.push("--".to_string());
+        args.push(resolved.display().to_string());
+        let output = match run_git_checked(&self.policy, "git_blame", &cwd, &args).await {
+            Ok(output) => output,
+            Err(error) => return error,
+        };
+        let lines = parse_git_blame_porcelain(&output);
+        ToolExecutionResult::ok(json!({
+            "tool": "git_blame",
+            "path": resolved.display().to_string(),
+            "revision": revision,
+            "start_line": start,
+            "returned": lines.len(),
+            "truncated": lines.len() == GIT_BLAME_MAX_LINES
+                && end_line.is_none_or(|end_line| end_line > end),
+            "lines": lines,
+        }))
+    }
+}
@@ -0,0 +1,1109 @@
+//! Language Server Protocol client sessions shared by the `lsp_*` tools.
+//!
+//! One server process is kept alive per (workspace root, language) and spoken to
+//! over stdio with `Content-Length` framed JSON-RPC. Documents are synced with
+//! full-text `didOpen`/`didChange` notifications, and pushed diagnostics are
+//! cached per URI with a generation counter so callers can wait for the first
+//! publish that follows their own edit.
+
+use std::{
+    process::Stdio,
+    sync::{atomic::AtomicBool, MutexGuard, Weak},
+};
+
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    process::{Child, ChildStdin, Command},
+    sync::{oneshot, Notify},
+};
+
+use super::*;
+
+const LSP_MAX_MESSAGE_BYTES: usize = 64 * 1024 * 1024;
+const LSP_POST_EDIT_DIAGNOSTICS_WAIT_MS: u64 = 1_500;
+const LSP_POST_EDIT_MAX_DIAGNOSTICS: usize = 20;
+const LSP_KNOWN_LANGUAGES: &[(&str, &[&str])] = &[
+    ("rust", &["rs"]),
+    ("python", &["py", "pyi"]),
+    ("go", &["go"]),
+    (
+        "typescript",
+        &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"],
+    ),
+];
+
+type LspSessionKey = (PathBuf, String);
+
+static LSP_SESSION_REGISTRY: OnceLock<Mutex<HashMap<LspSessionKey, Arc<LspSession>>>> =
+    OnceLock::new();
+
+/// Launch configuration for the language server handling one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LspServerConfig {
+    pub language: String,
+    pub extensions: Vec<String>,
+    pub command: Vec<String>,
+}
+
+/// Returns the built-in server commands (rust-analyzer, pyright, gopls).
+pub fn default_lsp_servers() -> Vec<LspServerConfig> {
+    [
+        ("rust", vec!["rust-analyzer"]),
+        ("python", vec!["pyright-langserver", "--stdio"]),
+        ("go", vec!["gopls"]),
+    ]
+    .into_iter()
+    .filter_map(|(language, command)| {
+        Some(LspServerConfig {
+            language: language.to_string(),
+            extensions: lsp_language_extensions(language)?,
+            command: command.into_iter().map(str::to_string).collect(),
+        })
+    })
+    .collect()
+}
+
+fn lsp_language_extensions(language: &str) -> Option<Vec<String>> {
+    LSP_KNOWN_LANGUAGES
+        .iter()
+        .find(|(name, _)| *name == language)
+        .map(|(_, extensions)| extensions.iter().map(|ext| ext.to_string()).collect())
+}
+
+/// Parses a `language=command args...` override; an empty command disables the language.
+pub fn parse_lsp_server_spec(raw: &str) -> Result<LspServerConfig, String> {
+    let (language, command) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("invalid LSP server '{raw}': expected language=command"))?;
+    let language = language.trim().to_ascii_lowercase();
+    let extensions = lsp_language_extensions(&language).ok_or_else(|| {
+        format!("unsupported LSP language '{language}' (expected rust, python, go or typescript)")
+    })?;
+    let command = shell_words::split(command.trim())
+        .map_err(|error| format!("invalid LSP server command for '{language}': {error}"))?;
+    Ok(LspServerConfig {
+        language,
+        extensions,
+        command,
+    })
+}
+
+/// Applies `language=command` overrides on top of `servers`, replacing per language.
+pub fn apply_lsp_server_overrides(
+    servers: &mut Vec<LspServerConfig>,
+    specs: &[String],
+) -> Result<(), String> {
+    for spec in specs {
+        let config = parse_lsp_server_spec(spec)?;
+        servers.retain(|existing| existing.language != config.language);
+        if !config.command.is_empty() {
+            servers.push(config);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct LspError {
+    pub(super) reason_code: &'static str,
+    pub(super) message: String,
+}
+
+impl LspError {
+    pub(super) fn new(reason_code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            reason_code,
+            message: message.into(),
+        }
+    }
+}
+
+fn lock_unpoisoned<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+pub(super) fn lsp_server_for_path<'a>(
+    policy: &'a ToolPolicy,
+    path: &Path,
+) -> Option<&'a LspServerConfig> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    policy
+        .lsp_servers
+        .iter()
+        .find(|server| !server.command.is_empty() && server.extensions.contains(&extension))
+}
+
+fn lsp_language_id(config: &LspServerConfig, path: &Path) -> String {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let language_id = match (config.language.as_str(), extension.as_str()) {
+        ("typescript", "tsx") => "typescriptreact",
+        ("typescript", "js" | "mjs" | "cjs") => "javascript",
+        ("typescript", "jsx") => "javascriptreact",
+        (language, _) => language,
+    };
+    language_id.to_string()
+}
+
+/// Uses the allowed root containing `path` as the workspace root, else its parent.
+pub(super) fn lsp_workspace_root(policy: &ToolPolicy, path: &Path) -> PathBuf {
+    let document = lsp_document_path(path);
+    for root in &policy.allowed_roots {
+        if let Ok(root) = canonicalize_best_effort(root) {
+            if document.starts_with(&root) {
+                return root;
+            }
+        }
+    }
+    document
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| document.clone())
+}
+
+fn lsp_document_path(path: &Path) -> PathBuf {
+    canonicalize_best_effort(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn lsp_document_uri(path: &Path) -> String {
+    path_to_file_uri(&lsp_document_path(path))
+}
+
+pub(super) fn path_to_file_uri(path: &Path) -> String {
+    let raw = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from("file://");
+    if !raw.starts_with('/') {
+        uri.push('/');
+    }
+    for byte in raw.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                uri.push(char::from(byte))
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
+
+pub(super) fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let encoded = encoded.strip_prefix("localhost").unwrap_or(encoded);
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut input = encoded.bytes();
+    while let Some(byte) = input.next() {
+        if byte == b'%' {
+            let high = char::from(input.next()?).to_digit(16)?;
+            let low = char::from(input.next()?).to_digit(16)?;
+            bytes.push(u8::try_from(high * 16 + low).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    let mut decoded = String::from_utf8(bytes).ok()?;
+    if cfg!(windows) && decoded.starts_with('/') && decoded.get(2..3) == Some(":") {
+        decoded.remove(0);
+    }
+    Some(PathBuf::from(decoded))
+}
+
+fn normalize_lsp_uri(uri: &str) -> String {
+    file_uri_to_path(uri)
+        .map(|path| path_to_file_uri(&path))
+        .unwrap_or_else(|| uri.to_string())
+}
+
+pub(super) fn encode_lsp_message(message: &Value) -> Vec<u8> {
+    let body = message.to_string();
+    let mut framed = format!("Content-Length: {}\r\n\r\n", body.len()).into_bytes();
+    framed.extend_from_slice(body.as_bytes());
+    framed
+}
+
+/// Reads one framed message, returning `Ok(None)` on a clean end of stream.
+pub(super) async fn read_lsp_message<R>(reader: &mut R) -> Result<Option<Value>, String>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut content_length = None;
+    let mut saw_header = false;
+    loop {
+        let mut line = String::new();
+        let read = reader
+            .read_line(&mut line)
+            .await
+            .map_err(|error| format!("failed to read LSP header: {error}"))?;
+        if read == 0 {
+            if saw_header {
+                return Err("language server closed the stream inside a message header".into());
+            }
+            return Ok(None);
+        }
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed.is_empty() {
+            if saw_header {
+                break;
+            }
+            continue;
+        }
+        saw_header = true;
+        if let Some((name, value)) = trimmed.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|error| format!("invalid LSP Content-Length '{value}': {error}"))?;
+                content_length = Some(length);
+            }
+        }
+    }
+    let length = content_length.ok_or("LSP message is missing a Content-Length header")?;
+    if length > LSP_MAX_MESSAGE_BYTES {
+        return Err(format!(
+            "LSP message of {length} bytes exceeds limit of {LSP_MAX_MESSAGE_BYTES} bytes"
+        ));
+    }
+    let mut body = vec![0u8; length];
+    reader
+        .read_exact(&mut body)
+        .await
+        .map_err(|error| format!("failed to read LSP message body: {error}"))?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|error| format!("invalid LSP message body: {error}"))
+}
+
+/// Converts a 1-based line and 1-based character column into an LSP position.
+pub(super) fn lsp_position_from_line_column(
+    text: &str,
+    line: u64,
+    column: u64,
+) -> Result<Value, String> {
+    if line == 0 || column == 0 {
+        return Err("line and column are 1-based and must be greater than 0".to_string());
+    }
+    let line_text = text
+        .split('\n')
+        .nth(usize::try_from(line - 1).unwrap_or(usize::MAX))
+        .ok_or_else(|| format!("line {line} is past the end of the file"))?;
+    let line_text = line_text.strip_suffix('\r').unwrap_or(line_text);
+    let prefix_chars = usize::try_from(column - 1).unwrap_or(usize::MAX);
+    if prefix_chars > line_text.chars().count() {
+        return Err(format!("column {column} is past the end of line {line}"));
+    }
+    let character = line_text
+        .chars()
+        .take(prefix_chars)
+        .map(char::len_utf16)
+        .sum::<usize>();
+    Ok(json!({ "line": line - 1, "character": character }))
+}
+
+/// Maps an LSP position (0-based line, UTF-16 character) to a byte offset.
+///
+/// Characters past the end of the line clamp to the line end, as the protocol requires.
+pub(super) fn lsp_position_to_offset(text: &str, line: u64, character: u64) -> Option<usize> {
+    let mut line_start = 0usize;
+    for _ in 0..line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |index| line_start + index);
+    let content_end = if text[line_start..line_end].ends_with('\r') {
+        line_end - 1
+    } else {
+        line_end
+    };
+    let mut units = 0u64;
+    for (index, character_value) in text[line_start..content_end].char_indices() {
+        if units >= character {
+            return Some(line_start + index);
+        }
+        units += character_value.len_utf16() as u64;
+    }
+    Some(content_end)
+}
+
+/// Maps an LSP position to a 1-based (line, character column) pair for tool output.
+pub(super) fn lsp_position_to_line_column(text: Option<&str>, position: &Value) -> (u64, u64) {
+    let line = position.get("line").and_then(Value::as_u64).unwrap_or(0);
+    let character = position
+        .get("character")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+    let Some(text) = text else {
+        return (line + 1, character + 1);
+    };
+    let Some(offset) = lsp_position_to_offset(text, line, character) else {
+        return (line + 1, character + 1);
+    };
+    let line_prefix = text[..offset].rsplit('\n').next().unwrap_or_default();
+    (line + 1, line_prefix.chars().count() as u64 + 1)
+}
+
+fn lsp_range_offset(text: &str, position: Option<&Value>) -> Result<usize, String> {
+    let position = position.ok_or("text edit range is missing a position")?;
+    let line = position
+        .get("line")
+        .and_then(Value::as_u64)
+        .ok_or("text edit position is missing 'line'")?;
+    let character = position
+        .get("character")
+        .and_then(Value::as_u64)
+        .ok_or("text edit position is missing 'character'")?;
+    lsp_position_to_offset(text, line, character)
+        .ok_or_else(|| format!("text edit position {line}:{character} is outside the document"))
+}
+
+/// Applies LSP `TextEdit`s to `text`; overlapping edits are rejected.
+pub(super) fn apply_lsp_text_edits(text: &str, edits: &[Value]) -> Result<String, String> {
+    let mut resolved = Vec::with_capacity(edits.len());
+    for edit in edits {
+        let range = edit.get("range").ok_or("text edit is missing 'range'")?;
+        let start = lsp_range_offset(text, range.get("start"))?;
+        let end = lsp_range_offset(text, range.get("end"))?;
+        if end < start {
+            return Err("text edit range ends before it starts".to_string());
+        }
+        let new_text = edit
+            .get("newText")
+            .and_then(Value::as_str)
+            .ok_or("text edit is missing 'newText'")?;
+        resolved.push((start, end, new_text));
+    }
+    resolved.sort_by_key(|(start, end, _)| (*start, *end));
+    if resolved.windows(2).any(|pair| pair[1].0 < pair[0].1) {
+        return Err("text edits overlap".to_string());
+    }
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0usize;
+    for (start, end, new_text) in resolved {
+        output.push_str(&text[cursor..start]);
+        output.push_str(new_text);
+        cursor = end;
+    }
+    output.push_str(&text[cursor..]);
+    Ok(output)
+}
+
+/// Groups a `WorkspaceEdit` into per-file text edits.
+///
+/// Resource operations (create/rename/delete) are rejected so every change stays a
+/// policy-checked content edit of an existing file.
+pub(super) fn lsp_workspace_edit_files(edit: &Value) -> Result<Vec<(PathBuf, Vec<Value>)>, String> {
+    let mut files: BTreeMap<PathBuf, Vec<Value>> = BTreeMap::new();
+    let uri_path = |uri: &str| {
+        file_uri_to_path(uri).ok_or_else(|| format!("unsupported document URI '{uri}'"))
+    };
+    if let Some(document_changes) = edit.get("documentChanges").and_then(Value::as_array) {
+        for change in document_changes {
+            if let Some(kind) = change.get("kind").and_then(Value::as_str) {
+                return Err(format!(
+                    "workspace edit resource operation '{kind}' is not supported"
+                ));
+            }
+            let uri = change
+                .pointer("/textDocument/uri")
+                .and_then(Value::as_str)
+                .ok_or("document change is missing textDocument.uri")?;
+            let edits = change
+                .get("edits")
+                .and_then(Value::as_array)
+                .ok_or("document change is missing edits")?;
+            files
+                .entry(uri_path(uri)?)
+                .or_default()
+                .extend(edits.iter().cloned());
+        }
+    } else if let Some(changes) = edit.get("changes").and_then(Value::as_object) {
+        for (uri, edits) in changes {
+            let edits = edits
+                .as_array()
+                .ok_or("workspace edit changes must be arrays of text edits")?;
+            files
+                .entry(uri_path(uri)?)
+                .or_default()
+                .extend(edits.iter().cloned());
+        }
+    }
+    files.retain(|_, edits| !edits.is_empty());
+    Ok(files.into_iter().collect())
+}
+
+/// Flattens `Hover.contents` (MarkupContent, MarkedString or arrays) into text.
+pub(super) fn lsp_hover_text(contents: &Value) -> String {
+    match contents {
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(lsp_hover_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        Value::Object(object) => {
+            let Some(value) = object.get("value").and_then(Value::as_str) else {
+                return String::new();
+            };
+            match object.get("language").and_then(Value::as_str) {
+                Some(language) => format!("```{language}\n{value}\n```"),
+                None => value.to_string(),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// Normalizes `Location | Location[] | LocationLink[]` into (uri, range) pairs.
+pub(super) fn lsp_locations(result: &Value) -> Vec<(String, Value)> {
+    let items = match result {
+        Value::Array(items) => items.as_slice(),
+        Value::Null => &[],
+        other => std::slice::from_ref(other),
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let uri = item
+                .get("uri")
+                .or_else(|| item.get("targetUri"))?
+                .as_str()?;
+            let range = item
+                .get("range")
+                .or_else(|| item.get("targetSelectionRange"))
+                .or_else(|| item.get("targetRange"))?;
+            Some((uri.to_string(), range.clone()))
+        })
+        .collect()
+}
+
+fn lsp_severity_name(diagnostic: &Value) -> &'static str {
+    match diagnostic.get("severity").and_then(Value::as_u64) {
+        Some(2) => "warning",
+        Some(3) => "information",
+        Some(4) => "hint",
+        _ => "error",
+    }
+}
+
+/// Summarizes published diagnostics with 1-based positions and severity counts.
+pub(super) fn lsp_diagnostics_payload(
+    text: Option<&str>,
+    diagnostics: &[Value],
+    max_results: usize,
+) -> Value {
+    let count = |severity: &str| {
+        diagnostics
+            .iter()
+            .filter(|diagnostic| lsp_severity_name(diagnostic) == severity)
+            .count()
+    };
+    let entries = diagnostics
+        .iter()
+        .take(max_results)
+        .map(|diagnostic| {
+            let null = Value::Null;
+            let start = diagnostic.pointer("/range/start").unwrap_or(&null);
+            let end = diagnostic.pointer("/range/end").unwrap_or(&null);
+            let (line, column) = lsp_position_to_line_column(text, start);
+            let (end_line, end_column) = lsp_position_to_line_column(text, end);
+            json!({
+                "severity": lsp_severity_name(diagnostic),
+                "line": line,
+                "column": column,
+                "end_line": end_line,
+                "end_column": end_column,
+                "message": diagnostic.get("message").and_then(Value::as_str).unwrap_or_default(),
+                "source": diagnostic.get("source"),
+                "code": diagnostic.get("code"),
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "total": diagnostics.len(),
+        "error_count": count("error"),
+        "warning_count": count("warning"),
+        "diagnostics": entries,
+        "truncated": diagnostics.len() > max_results,
+    })
+}
+
+/// Default reply for requests the server sends to the client.
+fn lsp_server_request_reply(method: &str, params: Option<&Value>) -> Value {
+    match method {
+        "workspace/configuration" => {
+            let items = params
+                .and_then(|params| params.get("items"))
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            Value::Array(vec![Value::Null; items])
+        }
+        "workspace/applyEdit" => json!({
+            "applied": false,
+            "failureReason": "workspace edits are applied through the lsp_rename tool",
+        }),
+        _ => Value::Null,
+    }
+}
+
+#[derive(Debug, Default)]
+struct LspPublishedDiagnostics {
+    generation: u64,
+    diagnostics: Vec<Value>,
+}
+
+#[derive(Debug)]
+struct LspOpenDocument {
+    version: i64,
+    text: String,
+}
+
+/// One running language server bound to a workspace root.
+pub(super) struct LspSession {
+    language: String,
+    root: PathBuf,
+    command: Vec<String>,
+    stdin: tokio::sync::Mutex<ChildStdin>,
+    _child: Mutex<Child>,
+    next_request_id: AtomicU64,
+    pending: Mutex<HashMap<u64, oneshot::Sender<Result<Value, String>>>>,
+    diagnostics: Mutex<HashMap<String, LspPublishedDiagnostics>>,
+    diagnostics_generation: AtomicU64,
+    diagnostics_updated: Notify,
+    documents: Mutex<HashMap<String, LspOpenDocument>>,
+    alive: AtomicBool,
+}
+
+impl LspSession {
+    async fn start(
+        config: &LspServerConfig,
+        root: &Path,
+        timeout_ms: u64,
+    ) -> Result<Arc<Self>, LspError> {
+        let (program, args) = config.command.split_first().ok_or_else(|| {
+            LspError::new(
+                "lsp_server_unavailable",
+                format!(
+                    "no language server command configured for {}",
+                    config.language
+                ),
+            )
+        })?;
+        let mut child = Command::new(program)
+            .args(args)
+            .current_dir(root)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|error| {
+                LspError::new(
+                    "lsp_server_unavailable",
+                    format!(
+                        "failed to start language server '{program}' for {}: {error}",
+                        config.language
+                    ),
+                )
+            })?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(LspError::new(
+                "lsp_server_unavailable",
+                "language server stdio pipes are unavailable",
+            ));
+        };
+        let session = Arc::new(Self {
+            language: config.language.clone(),
+            root: root.to_path_buf(),
+            command: config.command.clone(),
+            stdin: tokio::sync::Mutex::new(stdin),
+            _child: Mutex::new(child),
+            next_request_id: AtomicU64::new(1),
+            pending: Mutex::new(HashMap::new()),
+            diagnostics: Mutex::new(HashMap::new()),
+            diagnostics_generation: AtomicU64::new(0),
+            diagnostics_updated: Notify::new(),
+            documents: Mutex::new(HashMap::new()),
+            alive: AtomicBool::new(true),
+        });
+        tokio::spawn(run_lsp_reader(
+            BufReader::new(stdout),
+            Arc::downgrade(&session),
+        ));
+        session.initialize(timeout_ms).await?;
+        Ok(session)
+    }
+
+    async fn initialize(&self, timeout_ms: u64) -> Result<(), LspError> {
+        let root_uri = path_to_file_uri(&self.root);
+        let root_name = self
+            .root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.root.display().to_string());
+        self.request(
+            "initialize",
+            json!({
+                "processId": std::process::id(),
+                "clientInfo": { "name": "tau" },
+                "rootUri": root_uri,
+                "rootPath": self.root.display().to_string(),
+                "workspaceFolders": [{ "uri": root_uri, "name": root_name }],
+                "capabilities": {
+                    "textDocument": {
+                        "synchronization": { "didSave": true },
+                        "publishDiagnostics": { "relatedInformation": false },
+                        "hover": { "contentFormat": ["markdown", "plaintext"] },
+                        "definition": { "linkSupport": true },
+                        "rename": { "prepareSupport": false }
+                    },
+                    "workspace": {
+                        "configuration": true,
+                        "workspaceFolders": true,
+                        "workspaceEdit": { "documentChanges": true }
+                    }
+                }
+            }),
+            timeout_ms,
+        )
+        .await?;
+        self.notify("initialized", json!({})).await
+    }
+
+    pub(super) fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub(super) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub(super) fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    async fn write_message(&self, message: &Value) -> Result<(), String> {
+        let framed = encode_lsp_message(message);
+        let mut stdin = self.stdin.lock().await;
+        stdin
+            .write_all(&framed)
+            .await
+            .map_err(|error| format!("failed to write to language server: {error}"))?;
+        stdin
+            .flush()
+            .await
+            .map_err(|error| format!("failed to flush language server stdin: {error}"))
+    }
+
+    pub(super) async fn notify(&self, method: &str, params: Value) -> Result<(), LspError> {
+        self.write_message(&json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+            .await
+            .map_err(|error| LspError::new("lsp_server_exited", error))
+    }
+
+    pub(super) async fn request(
+        &self,
+        method: &str,
+        params: Value,
+        timeout_ms: u64,
+    ) -> Result<Value, LspError> {
+        if !self.is_alive() {
+            return Err(LspError::new(
+                "lsp_server_exited",
+                format!("{} language server is no longer running", self.language),
+            ));
+        }
+        let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+        lock_unpoisoned(&self.pending).insert(id, sender);
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        if let Err(error) = self.write_message(&message).await {
+            lock_unpoisoned(&self.pending).remove(&id);
+            return Err(LspError::new("lsp_server_exited", error));
+        }
+        match tokio::time::timeout(Duration::from_millis(timeout_ms.max(1)), receiver).await {
+            Ok(Ok(Ok(result))) => Ok(result),
+            Ok(Ok(Err(error))) => Err(LspError::new(
+                "lsp_request_failed",
+                format!("{method} failed: {error}"),
+            )),
+            Ok(Err(_)) => Err(LspError::new(
+                "lsp_server_exited",
+                format!("{} language server exited before responding", self.language),
+            )),
+            Err(_) => {
+                lock_unpoisoned(&self.pending).remove(&id);
+                let _ = self.notify("$/cancelRequest", json!({ "id": id })).await;
+                Err(LspError::new(
+                    "lsp_request_timeout",
+                    format!("{method} timed out after {timeout_ms}ms"),
+                ))
+            }
+        }
+    }
+
+    async fn handle_message(&self, message: Value) {
+        let method = message.get("method").and_then(Value::as_str);
+        match (method, message.get("id")) {
+            (Some(method), Some(id)) => {
+                let reply = json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "result": lsp_server_request_reply(method, message.get("params")),
+                });
+                let _ = self.write_message(&reply).await;
+            }
+            (Some("textDocument/publishDiagnostics"), None) => {
+                self.record_diagnostics(message.get("params"));
+            }
+            (None, Some(id)) => {
+                let Some(sender) = id
+                    .as_u64()
+                    .and_then(|id| lock_unpoisoned(&self.pending).remove(&id))
+                else {
+                    return;
+                };
+                let outcome = match message.get("error") {
+                    Some(error) => Err(format!(
+                        "{} (code {})",
+                        error
+                            .get("message")
+                            .and_then(Value::as_str)
+                            .unwrap_or("unknown error"),
+                        error.get("code").cloned().unwrap_or(Value::Null)
+                    )),
+                    None => Ok(message.get("result").cloned().unwrap_or(Value::Null)),
+                };
+                let _ = sender.send(outcome);
+            }
+            _ => {}
+        }
+    }
+
+    fn record_diagnostics(&self, params: Option<&Value>) {
+        let Some(uri) = params
+            .and_then(|params| params.get("uri"))
+            .and_then(Value::as_str)
+        else {
+            return;
+        };
+        let diagnostics = params
+            .and_then(|params| params.get("diagnostics"))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let generation = self.diagnostics_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        lock_unpoisoned(&self.diagnostics).insert(
+            normalize_lsp_uri(uri),
+            LspPublishedDiagnostics {
+                generation,
+                diagnostics,
+            },
+        );
+        self.diagnostics_updated.notify_waiters();
+    }
+
+    fn mark_exited(&self) {
+        self.alive.store(false, Ordering::SeqCst);
+        let pending = std::mem::take(&mut *lock_unpoisoned(&self.pending));
+        for (_, sender) in pending {
+            let _ = sender.send(Err("language server exited".to_string()));
+        }
+        self.diagnostics_updated.notify_waiters();
+    }
+
+    pub(super) fn diagnostics_generation(&self) -> u64 {
+        self.diagnostics_generation.load(Ordering::SeqCst)
+    }
+
+    /// Opens or updates `path` on the server; returns `true` when a notification was sent.
+    pub(super) async fn sync_document(
+        &self,
+        config: &LspServerConfig,
+        path: &Path,
+        text: &str,
+    ) -> Result<bool, LspError> {
+        let uri = lsp_document_uri(path);
+        let notification = {
+            let mut documents = lock_unpoisoned(&self.documents);
+            match documents.get_mut(&uri) {
+                Some(document) if document.text == text => None,
+                Some(document) => {
+                    document.version += 1;
+                    document.text = text.to_string();
+                    Some((
+                        "textDocument/didChange",
+                        json!({
+                            "textDocument": { "uri": uri, "version": document.version },
+                            "contentChanges": [{ "text": text }],
+                        }),
+                    ))
+                }
+                None => {
+                    documents.insert(
+                        uri.clone(),
+                        LspOpenDocument {
+                            version: 1,
+                            text: text.to_string(),
+                        },
+                    );
+                    Some((
+                        "textDocument/didOpen",
+                        json!({
+                            "textDocument": {
+                                "uri": uri,
+                                "languageId": lsp_language_id(config, path),
+                                "version": 1,
+                                "text": text,
+                            }
+                        }),
+                    ))
+                }
+            }
+        };
+        let Some((method, params)) = notification else {
+            return Ok(false);
+        };
+        self.notify(method, params).await?;
+        Ok(true)
+    }
+
+    /// Closes `path` on the server if it was opened; returns `true` when a notification was sent.
+    pub(super) async fn close_document(&self, path: &Path) -> Result<bool, LspError> {
+        let uri = lsp_document_uri(path);
+        if lock_unpoisoned(&self.documents).remove(&uri).is_none() {
+            return Ok(false);
+        }
+        lock_unpoisoned(&self.diagnostics).remove(&uri);
+        self.notify(
+            "textDocument/didClose",
+            json!({ "textDocument": { "uri": uri } }),
+        )
+        .await?;
+        Ok(true)
+    }
+
+    pub(super) async fn did_save(&self, path: &Path) -> Result<(), LspError> {
+        self.notify(
+            "textDocument/didSave",
+            json!({ "textDocument": { "uri": lsp_document_uri(path) } }),
+        )
+        .await
+    }
+
+    pub(super) fn cached_diagnostics(&self, path: &Path) -> Option<Vec<Value>> {
+        lock_unpoisoned(&self.diagnostics)
+            .get(&lsp_document_uri(path))
+            .map(|published| published.diagnostics.clone())
+    }
+
+    /// Waits for diagnostics published after `after_generation`.
+    ///
+    /// Returns the latest known diagnostics and whether a fresh publish arrived in time.
+    pub(super) async fn wait_for_diagnostics(
+        &self,
+        path: &Path,
+        after_generation: u64,
+        wait_ms: u64,
+    ) -> (Vec<Value>, bool) {
+        let uri = lsp_document_uri(path);
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(wait_ms);
+        loop {
+            let mut notified = std::pin::pin!(self.diagnostics_updated.notified());
+            notified.as_mut().enable();
+            let latest = {
+                let diagnostics = lock_unpoisoned(&self.diagnostics);
+                diagnostics
+                    .get(&uri)
+                    .map(|published| (published.generation, published.diagnostics.clone()))
+            };
+            match latest {
+                Some((generation, diagnostics)) if generation > after_generation => {
+                    return (diagnostics, true);
+                }
+                latest if !self.is_alive() => {
+                    return (
+                        latest
+                            .map(|(_, diagnostics)| diagnostics)
+                            .unwrap_or_default(),
+                        false,
+                    );
+                }
+                latest => {
+                    if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                        return (
+                            latest
+                                .map(|(_, diagnostics)| diagnostics)
+                                .unwrap_or_default(),
+                            false,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn document_uri(&self, path: &Path) -> String {
+        lsp_document_uri(path)
+    }
+}
+
+async fn run_lsp_reader<R>(mut reader: R, session: Weak<LspSession>)
+where
+    R: AsyncBufRead + Unpin,
+{
+    loop {
+        let message = read_lsp_message(&mut reader).await;
+        let Some(session) = session.upgrade() else {
+            return;
+        };
+        match message {
+            Ok(Some(message)) => session.handle_message(message).await,
+            Ok(None) | Err(_) => {
+                session.mark_exited();
+                return;
+            }
+        }
+    }
+}
+
+fn lsp_session_registry() -> &'static Mutex<HashMap<LspSessionKey, Arc<LspSession>>> {
+    LSP_SESSION_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn existing_lsp_session(key: &LspSessionKey, config: &LspServerConfig) -> Option<Arc<LspSession>> {
+    let mut registry = lock_unpoisoned(lsp_session_registry());
+    let reusable = registry
+        .get(key)
+        .filter(|session| session.is_alive() && session.command == config.command)
+        .cloned();
+    if reusable.is_none() {
+        registry.remove(key);
+    }
+    reusable
+}
+
+/// Returns the live session for `path`'s language and workspace root, starting one if needed.
+pub(super) async fn lsp_session_for_path(
+    policy: &ToolPolicy,
+    path: &Path,
+) -> Result<(Arc<LspSession>, LspServerConfig), LspError> {
+    let config = lsp_server_for_path(policy, path).cloned().ok_or_else(|| {
+        LspError::new(
+            "lsp_language_unsupported",
+            format!("no language server is configured for '{}'", path.display()),
+        )
+    })?;
+    let key = (lsp_workspace_root(policy, path), config.language.clone());
+    if let Some(session) = existing_lsp_session(&key, &config) {
+        return Ok((session, config));
+    }
+    let session = LspSession::start(&config, &key.0, policy.lsp_request_timeout_ms).await?;
+    let mut registry = lock_unpoisoned(lsp_session_registry());
+    if let Some(existing) = registry
+        .get(&key)
+        .filter(|existing| existing.is_alive() && existing.command == config.command)
+    {
+        return Ok((existing.clone(), config));
+    }
+    registry.insert(key, session.clone());
+    Ok((session, config))
+}
+
+/// Syncs a file written by `write`, `edit`, `apply_patch` or `undo`/`redo` to its
+/// language server.
+///
+/// Only already-running servers are updated unless `lsp_post_edit_diagnostics` is
+/// enabled, in which case a server is started if needed and fresh diagnostics for
+/// the file are returned for the tool result.
+pub(super) async fn lsp_sync_after_edit(
+    policy: &ToolPolicy,
+    path: &Path,
+    text: &str,
+) -> Option<Value> {
+    if !policy.lsp_enabled {
+        return None;
+    }
+    let config = lsp_server_for_path(policy, path)?.clone();
+    let session = if policy.lsp_post_edit_diagnostics {
+        match lsp_session_for_path(policy, path).await {
+            Ok((session, _)) => session,
+            Err(error) => {
+                return Some(json!({
+                    "language": config.language,
+                    "reason_code": error.reason_code,
+                    "error": error.message,
+                }))
+            }
+        }
+    } else {
+        let key = (lsp_workspace_root(policy, path), config.language.clone());
+        existing_lsp_session(&key, &config)?
+    };
+
+    let generation = session.diagnostics_generation();
+    let synced = match session.sync_document(&config, path, text).await {
+        Ok(_) => session.did_save(path).await,
+        Err(error) => Err(error),
+    };
+    if !policy.lsp_post_edit_diagnostics {
+        return None;
+    }
+    if let Err(error) = synced {
+        return Some(json!({
+            "language": config.language,
+            "reason_code": error.reason_code,
+            "error": error.message,
+        }));
+    }
+    let wait_ms = LSP_POST_EDIT_DIAGNOSTICS_WAIT_MS.min(policy.lsp_request_timeout_ms);
+    let (diagnostics, settled) = session
+        .wait_for_diagnostics(path, generation, wait_ms)
+        .await;
+    let mut payload =
+        lsp_diagnostics_payload(Some(text), &diagnostics, LSP_POST_EDIT_MAX_DIAGNOSTICS);
+    payload["language"] = json!(config.language);
+    payload["settled"] = json!(settled);
+    Some(payload)
+}
+
+/// Syncs every file rewritten by `apply_patch` or a file checkpoint `undo`/`redo`.
+///
+/// `None` content means the file was deleted; it is closed on any running server.
+/// With `lsp_post_edit_diagnostics` enabled, returns one diagnostics summary per
+/// synced file, tagged with its `path`.
+pub(super) async fn lsp_sync_after_file_changes(
+    policy: &ToolPolicy,
+    files: &[(PathBuf, Option<String>)],
+) -> Option<Value> {
+    if !policy.lsp_enabled {
+        return None;
+    }
+    let mut summaries = Vec::new();
+    for (path, text) in files {
+        match text {
+            Some(text) => {
+                if let Some(mut summary) = lsp_sync_after_edit(policy, path, text).await {
+                    summary["path"] = json!(path.display().to_string());
+                    summaries.push(summary);
+                }
+            }
+            None => {
+                let Some(config) = lsp_server_for_path(policy, path) else {
+                    continue;
+                };
+                let key = (lsp_workspace_root(policy, path), config.language.clone());
+                if let Some(session) = existing_lsp_session(&key, config) {
+                    let _ = session.close_document(path).await;
+                }
+            }
+        }
+    }
+    (!summaries.is_empty()).then_some(Value::Array(summaries))
+}
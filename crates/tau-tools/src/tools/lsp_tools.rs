@@ -0,0 +1,788 @@
+//! Language-server backed tools: diagnostics, hover, go-to-definition and rename.
+
+use super::file_checkpoints::{
+    read_optional_text, record_file_checkpoint, write_optional_text, FileCheckpointEntry,
+};
+use super::lsp_client::{
+    apply_lsp_text_edits, file_uri_to_path, lsp_diagnostics_payload, lsp_hover_text, lsp_locations,
+    lsp_position_from_line_column, lsp_position_to_line_column, lsp_server_for_path,
+    lsp_session_for_path, lsp_workspace_edit_files, LspSession,
+};
+use super::*;
+
+const LSP_DIAGNOSTICS_DEFAULT_WAIT_MS: u64 = 3_000;
+const LSP_DIAGNOSTICS_DEFAULT_MAX_RESULTS: usize = 100;
+const LSP_DIAGNOSTICS_MAX_RESULTS: usize = 1_000;
+const LSP_DEFINITION_MAX_RESULTS: usize = 50;
+const LSP_RENAME_MAX_FILES: usize = 256;
+
+/// A document synced to its language server and ready for a request.
+struct LspDocument {
+    path: PathBuf,
+    text: String,
+    session: Arc<LspSession>,
+    changed: bool,
+    generation_before_sync: u64,
+}
+
+impl LspDocument {
+    fn text_document(&self) -> Value {
+        json!({ "uri": self.session.document_uri(&self.path) })
+    }
+
+    fn summary(&self) -> Value {
+        json!({
+            "path": self.path.display().to_string(),
+            "language": self.session.language(),
+            "workspace_root": self.session.root().display().to_string(),
+        })
+    }
+}
+
+fn lsp_tool_error(
+    tool_name: &str,
+    path: Option<&str>,
+    reason_code: &str,
+    error: impl Into<String>,
+) -> ToolExecutionResult {
+    ToolExecutionResult::error(json!({
+        "tool": tool_name,
+        "path": path,
+        "reason_code": reason_code,
+        "error": error.into(),
+    }))
+}
+
+fn required_position(arguments: &Value, key: &str) -> Result<u64, String> {
+    match optional_positive_u64(arguments, key) {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => Err(format!("missing required integer argument '{key}'")),
+        Err(error) => Err(error),
+    }
+}
+
+/// Validates the `path` argument, runs the policy gates and syncs the file to
+/// its language server, starting the server on first use.
+async fn prepare_lsp_document(
+    policy: &ToolPolicy,
+    tool_name: &str,
+    arguments: &Value,
+    gate_fields: Value,
+) -> Result<LspDocument, ToolExecutionResult> {
+    if !policy.lsp_enabled {
+        return Err(lsp_tool_error(
+            tool_name,
+            None,
+            "lsp_disabled",
+            format!("{tool_name} is disabled by policy"),
+        ));
+    }
+    let path = required_string(arguments, "path")
+        .map_err(|error| ToolExecutionResult::error(json!({ "error": error })))?;
+    let resolved = resolve_and_validate_path(&path, policy, PathMode::Read)
+        .map_err(|error| ToolExecutionResult::error(json!({ "path": path, "error": error })))?;
+    let display = resolved.display().to_string();
+    validate_file_target(&resolved, PathMode::Read, policy.enforce_regular_files)
+        .map_err(|error| ToolExecutionResult::error(json!({ "path": display, "error": error })))?;
+    if lsp_server_for_path(policy, &resolved).is_none() {
+        return Err(lsp_tool_error(
+            tool_name,
+            Some(&display),
+            "lsp_language_unsupported",
+            format!("no language server is configured for '{display}'"),
+        ));
+    }
+
+    let mut gate_payload = json!({ "path": display });
+    if let (Some(payload), Value::Object(fields)) = (gate_payload.as_object_mut(), gate_fields) {
+        payload.extend(fields);
+    }
+    if let Some(rbac_result) = evaluate_tool_rbac_gate(
+        policy.rbac_principal.as_deref(),
+        tool_name,
+        policy.rbac_policy_path.as_deref(),
+        gate_payload.clone(),
+    ) {
+        return Err(rbac_result);
+    }
+    if let Some(rate_limit_result) = evaluate_tool_rate_limit_gate(policy, tool_name, gate_payload)
+    {
+        return Err(rate_limit_result);
+    }
+
+    let metadata = tokio::fs::metadata(&resolved).await.map_err(|error| {
+        ToolExecutionResult::error(json!({ "path": display, "error": error.to_string() }))
+    })?;
+    if metadata.len() as usize > policy.max_file_read_bytes {
+        return Err(ToolExecutionResult::error(json!({
+            "path": display,
+            "error": format!(
+                "file is too large ({} bytes), limit is {} bytes",
+                metadata.len(),
+                policy.max_file_read_bytes
+            ),
+        })));
+    }
+    let text = tokio::fs::read_to_string(&resolved)
+        .await
+        .map_err(|error| {
+            ToolExecutionResult::error(json!({ "path": display, "error": error.to_string() }))
+        })?;
+
+    let (session, config) = lsp_session_for_path(policy, &resolved)
+        .await
+        .map_err(|error| {
+            lsp_tool_error(tool_name, Some(&display), error.reason_code, error.message)
+        })?;
+    let generation_before_sync = session.diagnostics_generation();
+    let changed = session
+        .sync_document(&config, &resolved, &text)
+        .await
+        .map_err(|error| {
+            lsp_tool_error(tool_name, Some(&display), error.reason_code, error.message)
+        })?;
+    Ok(LspDocument {
+        path: resolved,
+        text,
+        session,
+        changed,
+        generation_before_sync,
+    })
+}
+
+/// Reports compiler and linter diagnostics published by the file's language server.
+pub struct LspDiagnosticsTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl LspDiagnosticsTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for LspDiagnosticsTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "lsp_diagnostics".to_string(),
+            description: "Report errors and warnings for a source file from its language server (rust-analyzer, pyright, gopls). Positions are 1-based.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Source file to check" },
+                    "wait_ms": { "type": "integer", "description": "How long to wait for fresh diagnostics (default 3000, capped by the LSP request timeout)" },
+                    "max_results": { "type": "integer", "description": "Maximum number of diagnostics to return (default 100)" }
+                },
+                "required": ["path"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let wait_ms = match optional_u64(&arguments, "wait_ms") {
+            Ok(wait_ms) => wait_ms
+                .unwrap_or(LSP_DIAGNOSTICS_DEFAULT_WAIT_MS)
+                .min(self.policy.lsp_request_timeout_ms),
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let max_results = match optional_usize(
+            &arguments,
+            "max_results",
+            LSP_DIAGNOSTICS_DEFAULT_MAX_RESULTS,
+            LSP_DIAGNOSTICS_MAX_RESULTS,
+        ) {
+            Ok(max_results) => max_results,
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let document = match prepare_lsp_document(
+            &self.policy,
+            "lsp_diagnostics",
+            &arguments,
+            json!({}),
+        )
+        .await
+        {
+            Ok(document) => document,
+            Err(result) => return result,
+        };
+
+        let cached = (!document.changed)
+            .then(|| document.session.cached_diagnostics(&document.path))
+            .flatten();
+        let (diagnostics, settled) = match cached {
+            Some(diagnostics) => (diagnostics, true),
+            None => {
+                document
+                    .session
+                    .wait_for_diagnostics(&document.path, document.generation_before_sync, wait_ms)
+                    .await
+            }
+        };
+
+        let mut payload = lsp_diagnostics_payload(Some(&document.text), &diagnostics, max_results);
+        if let (Some(payload), Value::Object(summary)) =
+            (payload.as_object_mut(), document.summary())
+        {
+            payload.extend(summary);
+        }
+        payload["tool"] = json!("lsp_diagnostics");
+        payload["settled"] = json!(settled);
+        ToolExecutionResult::ok(payload)
+    }
+}
+
+/// Shows type information and documentation for the symbol at a position.
+pub struct LspHoverTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl LspHoverTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for LspHoverTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "lsp_hover".to_string(),
+            description: "Show the type signature and documentation of the symbol at a 1-based line and column, as reported by the file's language server.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Source file containing the symbol" },
+                    "line": { "type": "integer", "description": "1-based line number" },
+                    "column": { "type": "integer", "description": "1-based character column" }
+                },
+                "required": ["path", "line", "column"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let (line, column) = match (
+            required_position(&arguments, "line"),
+            required_position(&arguments, "column"),
+        ) {
+            (Ok(line), Ok(column)) => (line, column),
+            (Err(error), _) | (_, Err(error)) => {
+                return ToolExecutionResult::error(json!({ "error": error }))
+            }
+        };
+        let document = match prepare_lsp_document(
+            &self.policy,
+            "lsp_hover",
+            &arguments,
+            json!({ "line": line, "column": column }),
+        )
+        .await
+        {
+            Ok(document) => document,
+            Err(result) => return result,
+        };
+        let display = document.path.display().to_string();
+        let position = match lsp_position_from_line_column(&document.text, line, column) {
+            Ok(position) => position,
+            Err(error) => {
+                return lsp_tool_error("lsp_hover", Some(&display), "lsp_position_invalid", error)
+            }
+        };
+
+        let hover = match document
+            .session
+            .request(
+                "textDocument/hover",
+                json!({ "textDocument": document.text_document(), "position": position }),
+                self.policy.lsp_request_timeout_ms,
+            )
+            .await
+        {
+            Ok(hover) => hover,
+            Err(error) => {
+                return lsp_tool_error(
+                    "lsp_hover",
+                    Some(&display),
+                    error.reason_code,
+                    error.message,
+                )
+            }
+        };
+
+        let contents = hover
+            .get("contents")
+            .map(lsp_hover_text)
+            .unwrap_or_default();
+        let range = hover.get("range").map(|range| {
+            let null = Value::Null;
+            let (start_line, start_column) = lsp_position_to_line_column(
+                Some(&document.text),
+                range.get("start").unwrap_or(&null),
+            );
+            let (end_line, end_column) = lsp_position_to_line_column(
+                Some(&document.text),
+                range.get("end").unwrap_or(&null),
+            );
+            json!({
+                "line": start_line,
+                "column": start_column,
+                "end_line": end_line,
+                "end_column": end_column,
+            })
+        });
+        let mut payload = document.summary();
+        payload["tool"] = json!("lsp_hover");
+        payload["line"] = json!(line);
+        payload["column"] = json!(column);
+        payload["found"] = json!(!contents.is_empty());
+        payload["contents"] = json!(contents);
+        payload["range"] = json!(range);
+        ToolExecutionResult::ok(payload)
+    }
+}
+
+/// Resolves the definition of the symbol at a position via the language server.
+pub struct LspDefinitionTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl LspDefinitionTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait]
+impl AgentTool for LspDefinitionTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "lsp_definition".to_string(),
+            description: "Jump to the definition of the symbol at a 1-based line and column using the file's language server. Resolves through imports, traits and generics; locations outside the allowed roots are omitted.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Source file containing the symbol" },
+                    "line": { "type": "integer", "description": "1-based line number" },
+                    "column": { "type": "integer", "description": "1-based character column" }
+                },
+                "required": ["path", "line", "column"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let (line, column) = match (
+            required_position(&arguments, "line"),
+            required_position(&arguments, "column"),
+        ) {
+            (Ok(line), Ok(column)) => (line, column),
+            (Err(error), _) | (_, Err(error)) => {
+                return ToolExecutionResult::error(json!({ "error": error }))
+            }
+        };
+        let document = match prepare_lsp_document(
+            &self.policy,
+            "lsp_definition",
+            &arguments,
+            json!({ "line": line, "column": column }),
+        )
+        .await
+        {
+            Ok(document) => document,
+            Err(result) => return result,
+        };
+        let display = document.path.display().to_string();
+        let position = match lsp_position_from_line_column(&document.text, line, column) {
+            Ok(position) => position,
+            Err(error) => {
+                return lsp_tool_error(
+                    "lsp_definition",
+                    Some(&display),
+                    "lsp_position_invalid",
+                    error,
+                )
+            }
+        };
+
+        let result = match document
+            .session
+            .request(
+                "textDocument/definition",
+                json!({ "textDocument": document.text_document(), "position": position }),
+                self.policy.lsp_request_timeout_ms,
+            )
+            .await
+        {
+            Ok(result) => result,
+            Err(error) => {
+                return lsp_tool_error(
+                    "lsp_definition",
+                    Some(&display),
+                    error.reason_code,
+                    error.message,
+                )
+            }
+        };
+
+        let document_path =
+            canonicalize_best_effort(&document.path).unwrap_or_else(|_| document.path.clone());
+        let mut definitions = Vec::new();
+        let mut filtered = 0usize;
+        for (uri, range) in lsp_locations(&result) {
+            let Some(target) = file_uri_to_path(&uri) else {
+                filtered += 1;
+                continue;
+            };
+            let target = canonicalize_best_effort(&target).unwrap_or(target);
+            if !matches!(is_path_allowed(&target, &self.policy), Ok(true)) {
+                filtered += 1;
+                continue;
+            }
+            if definitions.len() >= LSP_DEFINITION_MAX_RESULTS {
+                continue;
+            }
+            let target_text = if target == document_path {
+                Some(document.text.clone())
+            } else {
+                read_optional_text(&target).ok().flatten()
+            };
+            let null = Value::Null;
+            let (start_line, start_column) = lsp_position_to_line_column(
+                target_text.as_deref(),
+                range.get("start").unwrap_or(&null),
+            );
+            let (end_line, _) = lsp_position_to_line_column(
+                target_text.as_deref(),
+                range.get("end").unwrap_or(&null),
+            );
+            let content = target_text.as_deref().and_then(|text| {
+                text.lines()
+                    .nth(usize::try_from(start_line - 1).ok()?)
+                    .map(|line| line.trim_end().to_string())
+            });
+            definitions.push(json!({
+                "path": target.display().to_string(),
+                "line": start_line,
+                "column": start_column,
+                "end_line": end_line,
+                "content": content,
+            }));
+        }
+
+        let mut payload = document.summary();
+        payload["tool"] = json!("lsp_definition");
+        payload["line"] = json!(line);
+        payload["column"] = json!(column);
+        payload["definitions"] = json!(definitions);
+        payload["filtered_outside_allowed_roots"] = json!(filtered);
+        ToolExecutionResult::ok(payload)
+    }
+}
+
+#[derive(Debug)]
+struct PlannedRenameChange {
+    path: PathBuf,
+    before: String,
+    after: String,
+    edits: usize,
+}
+
+fn rollback_rename_changes(changes: &[PlannedRenameChange]) -> Vec<String> {
+    changes
+        .iter()
+        .rev()
+        .filter_map(|change| write_optional_text(&change.path, Some(&change.before)).err())
+        .collect()
+}
+
+/// Renames a symbol across the workspace using the language server's rename edits.
+pub struct LspRenameTool {
+    policy: Arc<ToolPolicy>,
+}
+
+impl LspRenameTool {
+    pub fn new(policy: Arc<ToolPolicy>) -> Self {
+        Self { policy }
+    }
+
+    fn plan_rename_change(
+        &self,
+        path: PathBuf,
+        edits: &[Value],
+    ) -> Result<PlannedRenameChange, ToolExecutionResult> {
+        let path = canonicalize_best_effort(&path).unwrap_or(path);
+        let display = path.display().to_string();
+        let denied = |error: String| {
+            lsp_tool_error(
+                "lsp_rename",
+                Some(&display),
+                "lsp_rename_path_denied",
+                error,
+            )
+        };
+        if !matches!(is_path_allowed(&path, &self.policy), Ok(true)) {
+            return Err(denied(
+                "rename edit targets a path outside the allowed roots".to_string(),
+            ));
+        }
+        let resolved =
+            resolve_and_validate_path(&display, &self.policy, PathMode::Edit).map_err(&denied)?;
+        validate_file_target(&resolved, PathMode::Edit, self.policy.enforce_regular_files)
+            .map_err(&denied)?;
+        if let Some(protected_path_result) =
+            evaluate_protected_path_gate(&self.policy, "lsp_rename", &resolved)
+        {
+            return Err(protected_path_result);
+        }
+        let before = match read_optional_text(&resolved) {
+            Ok(Some(before)) => before,
+            Ok(None) => return Err(denied("rename edit targets a missing file".to_string())),
+            Err(error) => {
+                return Err(lsp_tool_error(
+                    "lsp_rename",
+                    Some(&display),
+                    "lsp_rename_edit_failed",
+                    error,
+                ))
+            }
+        };
+        let after = apply_lsp_text_edits(&before, edits).map_err(|error| {
+            lsp_tool_error(
+                "lsp_rename",
+                Some(&display),
+                "lsp_rename_edit_failed",
+                error,
+            )
+        })?;
+        if after.len() > self.policy.max_file_write_bytes {
+            return Err(lsp_tool_error(
+                "lsp_rename",
+                Some(&display),
+                "lsp_rename_too_large",
+                format!(
+                    "renamed content is too large ({} bytes), limit is {} bytes",
+                    after.len(),
+                    self.policy.max_file_write_bytes
+                ),
+            ));
+        }
+        Ok(PlannedRenameChange {
+            path: resolved,
+            before,
+            after,
+            edits: edits.len(),
+        })
+    }
+}
+
+#[async_trait]
+impl AgentTool for LspRenameTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "lsp_rename".to_string(),
+            description: "Rename the symbol at a 1-based line and column across the workspace using the language server's semantic rename. Every touched file is policy-checked, written atomically and recorded as one undoable file checkpoint. Use dry_run to preview the affected files.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Source file containing the symbol" },
+                    "line": { "type": "integer", "description": "1-based line number" },
+                    "column": { "type": "integer", "description": "1-based character column" },
+                    "new_name": { "type": "string", "description": "New identifier for the symbol" },
+                    "dry_run": { "type": "boolean", "default": false, "description": "Report planned edits without writing files" }
+                },
+                "required": ["path", "line", "column", "new_name"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> ToolExecutionResult {
+        let (line, column) = match (
+            required_position(&arguments, "line"),
+            required_position(&arguments, "column"),
+        ) {
+            (Ok(line), Ok(column)) => (line, column),
+            (Err(error), _) | (_, Err(error)) => {
+                return ToolExecutionResult::error(json!({ "error": error }))
+            }
+        };
+        let new_name = match required_string(&arguments, "new_name") {
+            Ok(new_name) if !new_name.trim().is_empty() => new_name,
+            Ok(_) => {
+                return ToolExecutionResult::error(json!({
+                    "error": "'new_name' must not be empty",
+                }))
+            }
+            Err(error) => return ToolExecutionResult::error(json!({ "error": error })),
+        };
+        let dry_run = arguments
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let document = match prepare_lsp_document(
+            &self.policy,
+            "lsp_rename",
+            &arguments,
+            json!({ "line": line, "column": column, "new_name": new_name, "dry_run": dry_run }),
+        )
+        .await
+        {
+            Ok(document) => document,
+            Err(result) => return result,
+        };
+        let display = document.path.display().to_string();
+        let position = match lsp_position_from_line_column(&document.text, line, column) {
+            Ok(position) => position,
+            Err(error) => {
+                return lsp_tool_error("lsp_rename", Some(&display), "lsp_position_invalid", error)
+            }
+        };
+
+        let workspace_edit = match document
+            .session
+            .request(
+                "textDocument/rename",
+                json!({
+                    "textDocument": document.text_document(),
+                    "position": position,
+                    "newName": new_name,
+                }),
+                self.policy.lsp_request_timeout_ms,
+            )
+            .await
+        {
+            Ok(workspace_edit) => workspace_edit,
+            Err(error) => {
+                return lsp_tool_error(
+                    "lsp_rename",
+                    Some(&display),
+                    error.reason_code,
+                    error.message,
+                )
+            }
+        };
+        let file_edits = match lsp_workspace_edit_files(&workspace_edit) {
+            Ok(file_edits) if file_edits.is_empty() => {
+                return lsp_tool_error(
+                    "lsp_rename",
+                    Some(&display),
+                    "lsp_rename_no_edits",
+                    "language server returned no edits for this position",
+                )
+            }
+            Ok(file_edits) if file_edits.len() > LSP_RENAME_MAX_FILES => {
+                return lsp_tool_error(
+                    "lsp_rename",
+                    Some(&display),
+                    "lsp_rename_invalid",
+                    format!(
+                        "rename touches {} files, limit is {LSP_RENAME_MAX_FILES}",
+                        file_edits.len()
+                    ),
+                )
+            }
+            Ok(file_edits) => file_edits,
+            Err(error) => {
+                return lsp_tool_error("lsp_rename", Some(&display), "lsp_rename_invalid", error)
+            }
+        };
+
+        let mut changes = Vec::with_capacity(file_edits.len());
+        for (path, edits) in file_edits {
+            match self.plan_rename_change(path, &edits) {
+                Ok(change) => changes.push(change),
+                Err(result) => return result,
+            }
+        }
+        let files = changes
+            .iter()
+            .map(|change| json!({ "path": change.path.display().to_string(), "edits": change.edits }))
+            .collect::<Vec<_>>();
+        if dry_run {
+            return ToolExecutionResult::ok(json!({
+                "tool": "lsp_rename",
+                "reason_code": "lsp_rename_planned",
+                "new_name": new_name,
+                "dry_run": true,
+                "files_changed": files.len(),
+                "files": files,
+            }));
+        }
+
+        for change in &changes {
+            if let Some(approval_result) = evaluate_tool_approval_gate(ApprovalAction::ToolWrite {
+                path: change.path.display().to_string(),
+                content_bytes: change.after.len(),
+            }) {
+                return approval_result;
+            }
+        }
+
+        for (index, change) in changes.iter().enumerate() {
+            if let Err(error) = write_optional_text(&change.path, Some(&change.after)) {
+                let rollback_errors = rollback_rename_changes(&changes[..=index]);
+                return ToolExecutionResult::error(json!({
+                    "tool": "lsp_rename",
+                    "path": change.path.display().to_string(),
+                    "reason_code": "lsp_rename_write_failed",
+                    "error": error,
+                    "rolled_back": rollback_errors.is_empty(),
+                    "rollback_errors": rollback_errors,
+                }));
+            }
+        }
+
+        let checkpoint_entries = changes
+            .iter()
+            .map(|change| FileCheckpointEntry {
+                path: change.path.display().to_string(),
+                before: Some(change.before.clone()),
+                after: Some(change.after.clone()),
+            })
+            .collect::<Vec<_>>();
+        let checkpoint_id =
+            match record_file_checkpoint(&self.policy, "lsp_rename", checkpoint_entries) {
+                Ok(checkpoint_id) => checkpoint_id,
+                Err(error) => {
+                    let rollback_errors = rollback_rename_changes(&changes);
+                    return ToolExecutionResult::error(json!({
+                        "tool": "lsp_rename",
+                        "reason_code": error.reason_code,
+                        "error": error.message,
+                        "rolled_back": rollback_errors.is_empty(),
+                        "rollback_errors": rollback_errors,
+                    }));
+                }
+            };
+
+        for change in &changes {
+            let Some(config) = lsp_server_for_path(&self.policy, &change.path) else {
+                continue;
+            };
+            if config.language != document.session.language() {
+                continue;
+            }
+            if document
+                .session
+                .sync_document(config, &change.path, &change.after)
+                .await
+                .is_ok()
+            {
+                let _ = document.session.did_save(&change.path).await;
+            }
+        }
+
+        ToolExecutionResult::ok(json!({
+            "tool": "lsp_rename",
+            "reason_code": "lsp_rename_applied",
+            "new_name": new_name,
+            "checkpoint_id": checkpoint_id,
+            "files_changed": files.len(),
+            "files": files,
+        }))
+    }
+}
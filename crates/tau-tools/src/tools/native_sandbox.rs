@@ -0,0 +1,429 @@
+//! In-process Linux sandbox backend for the bash tool.
+//!
+//! The `native` OS sandbox mode avoids external launchers: the parent prepares
+//! a Landlock ruleset derived from policy roots plus a seccomp-bpf network
+//! filter, and the forked child applies rlimits, Landlock and seccomp right
+//! before `exec`.
+
+use super::*;
+
+#[cfg(target_os = "linux")]
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+#[cfg(target_os = "linux")]
+use std::os::unix::fs::OpenOptionsExt;
+
+const NATIVE_SANDBOX_READ_WRITE_SYSTEM_PATHS: &[&str] = &["/tmp", "/dev"];
+const NATIVE_SANDBOX_READ_ONLY_SYSTEM_PATHS: &[&str] = &[
+    "/usr", "/bin", "/sbin", "/lib", "/lib32", "/lib64", "/etc", "/opt", "/proc", "/sys",
+];
+
+#[cfg(target_os = "linux")]
+const LANDLOCK_CREATE_RULESET_VERSION: libc::c_uint = 1 << 0;
+#[cfg(target_os = "linux")]
+const LANDLOCK_RULE_PATH_BENEATH: libc::c_int = 1;
+#[cfg(target_os = "linux")]
+const LANDLOCK_ACCESS_FS_EXECUTE: u64 = 1 << 0;
+#[cfg(target_os = "linux")]
+const LANDLOCK_ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+#[cfg(target_os = "linux")]
+const LANDLOCK_ACCESS_FS_READ_FILE: u64 = 1 << 2;
+#[cfg(target_os = "linux")]
+const LANDLOCK_ACCESS_FS_READ_DIR: u64 = 1 << 3;
+#[cfg(target_os = "linux")]
+const LANDLOCK_ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+
+/// Resolved settings for one natively sandboxed bash invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct NativeSandboxConfig {
+    pub(super) landlock_abi: u32,
+    pub(super) read_write_paths: Vec<PathBuf>,
+    pub(super) read_only_paths: Vec<PathBuf>,
+    pub(super) allow_network: bool,
+    pub(super) cpu_seconds: u64,
+    pub(super) memory_bytes: u64,
+    pub(super) max_processes: u64,
+}
+
+impl NativeSandboxConfig {
+    /// Derives Landlock paths and rlimits from the tool policy.
+    ///
+    /// Read-write access covers the policy allowed roots (or only `cwd` when no
+    /// roots are configured), `cwd`, `/tmp` and `/dev`; common system prefixes
+    /// stay readable and executable. A zero memory or process limit disables
+    /// that rlimit.
+    pub(super) fn from_policy(policy: &ToolPolicy, cwd: &Path, landlock_abi: u32) -> Self {
+        let mut read_write_paths = Vec::new();
+        for root in policy
+            .allowed_roots
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(std::iter::once(cwd))
+        {
+            push_unique_path(&mut read_write_paths, root);
+        }
+        for path in NATIVE_SANDBOX_READ_WRITE_SYSTEM_PATHS {
+            push_unique_path(&mut read_write_paths, Path::new(path));
+        }
+        let mut read_only_paths = Vec::new();
+        for path in NATIVE_SANDBOX_READ_ONLY_SYSTEM_PATHS {
+            push_unique_path(&mut read_only_paths, Path::new(path));
+        }
+
+        Self {
+            landlock_abi,
+            read_write_paths,
+            read_only_paths,
+            allow_network: policy.os_sandbox_native_allow_network,
+            cpu_seconds: policy.bash_timeout_ms.div_ceil(1_000).max(1),
+            memory_bytes: policy
+                .os_sandbox_native_memory_mb
+                .saturating_mul(1024 * 1024),
+            max_processes: policy.os_sandbox_native_max_processes,
+        }
+    }
+
+    /// Returns the metadata object reported as `sandbox_native` by the bash tool.
+    pub(super) fn metadata(&self) -> Value {
+        let render_paths = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+        };
+        json!({
+            "landlock_abi": self.landlock_abi,
+            "read_write_paths": render_paths(&self.read_write_paths),
+            "read_only_paths": render_paths(&self.read_only_paths),
+            "network": if self.allow_network { "allowed" } else { "denied" },
+            "seccomp_network_filter": !self.allow_network,
+            "rlimits": {
+                "cpu_seconds": self.cpu_seconds,
+                "memory_bytes": self.memory_bytes,
+                "max_processes": self.max_processes,
+            },
+        })
+    }
+}
+
+fn push_unique_path(paths: &mut Vec<PathBuf>, path: &Path) {
+    let path = canonicalize_best_effort(path).unwrap_or_else(|_| path.to_path_buf());
+    if !paths.contains(&path) {
+        paths.push(path);
+    }
+}
+
+/// Keeps parent-side sandbox resources alive until the child has been spawned.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(super) struct NativeSandboxGuard {
+    #[cfg(target_os = "linux")]
+    _ruleset: OwnedFd,
+}
+
+/// Returns the Landlock ABI version supported by the running kernel.
+#[cfg(target_os = "linux")]
+pub(super) fn native_sandbox_landlock_abi() -> Option<u32> {
+    // SAFETY: a null attribute pointer with the VERSION flag only queries the ABI.
+    let abi = unsafe {
+        libc::syscall(
+            libc::SYS_landlock_create_ruleset,
+            std::ptr::null::<LandlockRulesetAttr>(),
+            0usize,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        )
+    };
+    u32::try_from(abi).ok().filter(|abi| *abi > 0)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(super) fn native_sandbox_landlock_abi() -> Option<u32> {
+    None
+}
+
+/// Returns true when a seccomp network filter can be built for this architecture.
+pub(super) fn native_sandbox_network_filter_supported() -> bool {
+    cfg!(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))
+}
+
+/// Builds the seccomp-bpf program that denies non-`AF_UNIX` sockets and io_uring.
+///
+/// Syscalls from a foreign audit architecture kill the process so the filter
+/// cannot be bypassed through compat syscall tables. Returns `None` on
+/// architectures without a known audit arch value.
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub(super) fn native_sandbox_network_filter() -> Option<Vec<libc::sock_filter>> {
+    Some(seccomp_filter::network_filter())
+}
+
+#[cfg(all(
+    target_os = "linux",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+pub(super) fn native_sandbox_network_filter() -> Option<Vec<libc::sock_filter>> {
+    None
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod seccomp_filter {
+    const BPF_LD_W_ABS: u16 = 0x20;
+    const BPF_JMP_JEQ_K: u16 = 0x15;
+    #[cfg(target_arch = "x86_64")]
+    const BPF_JMP_JGE_K: u16 = 0x35;
+    const BPF_RET_K: u16 = 0x06;
+    const SECCOMP_DATA_NR_OFFSET: u32 = 0;
+    const SECCOMP_DATA_ARCH_OFFSET: u32 = 4;
+    const SECCOMP_DATA_ARG0_OFFSET: u32 = 16;
+    const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+    const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+    const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xc000_003e;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xc000_00b7;
+    #[cfg(target_arch = "x86_64")]
+    const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+    pub(super) fn network_filter() -> Vec<libc::sock_filter> {
+        let deny = SECCOMP_RET_ERRNO | libc::EACCES as u32;
+        let mut program = vec![
+            stmt(BPF_LD_W_ABS, SECCOMP_DATA_ARCH_OFFSET),
+            jump(BPF_JMP_JEQ_K, AUDIT_ARCH, 1, 0),
+            stmt(BPF_RET_K, SECCOMP_RET_KILL_PROCESS),
+            stmt(BPF_LD_W_ABS, SECCOMP_DATA_NR_OFFSET),
+        ];
+        #[cfg(target_arch = "x86_64")]
+        program.extend([
+            jump(BPF_JMP_JGE_K, X32_SYSCALL_BIT, 0, 1),
+            stmt(BPF_RET_K, deny),
+        ]);
+        program.extend([
+            jump(BPF_JMP_JEQ_K, libc::SYS_socket as u32, 0, 4),
+            stmt(BPF_LD_W_ABS, SECCOMP_DATA_ARG0_OFFSET),
+            jump(BPF_JMP_JEQ_K, libc::AF_UNIX as u32, 0, 1),
+            stmt(BPF_RET_K, SECCOMP_RET_ALLOW),
+            stmt(BPF_RET_K, deny),
+        ]);
+        for syscall in [
+            libc::SYS_io_uring_setup,
+            libc::SYS_io_uring_enter,
+            libc::SYS_io_uring_register,
+        ] {
+            program.extend([
+                jump(BPF_JMP_JEQ_K, syscall as u32, 0, 1),
+                stmt(BPF_RET_K, deny),
+            ]);
+        }
+        program.push(stmt(BPF_RET_K, SECCOMP_RET_ALLOW));
+        program
+    }
+
+    fn stmt(code: u16, k: u32) -> libc::sock_filter {
+        libc::sock_filter {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    fn jump(code: u16, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+        libc::sock_filter { code, jt, jf, k }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct LandlockRulesetAttr {
+    handled_access_fs: u64,
+}
+
+#[cfg(target_os = "linux")]
+#[repr(C, packed)]
+struct LandlockPathBeneathAttr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+#[cfg(target_os = "linux")]
+fn landlock_handled_access_fs(abi: u32) -> u64 {
+    match abi {
+        0 => 0,
+        1 => (1 << 13) - 1,
+        2 => (1 << 14) - 1,
+        _ => (1 << 15) - 1,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn create_landlock_ruleset(config: &NativeSandboxConfig) -> Result<OwnedFd, String> {
+    let handled_access_fs = landlock_handled_access_fs(config.landlock_abi);
+    let attr = LandlockRulesetAttr { handled_access_fs };
+    // SAFETY: `attr` outlives the call and its size is passed alongside it.
+    let fd = unsafe {
+        libc::syscall(
+            libc::SYS_landlock_create_ruleset,
+            &attr as *const LandlockRulesetAttr,
+            std::mem::size_of::<LandlockRulesetAttr>(),
+            0 as libc::c_uint,
+        )
+    };
+    if fd < 0 {
+        return Err(format!(
+            "failed to create landlock ruleset: {}",
+            std::io::Error::last_os_error()
+        ));
+    }
+    // SAFETY: the kernel returned a fresh close-on-exec descriptor we now own.
+    let ruleset = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+    let read_only_access =
+        LANDLOCK_ACCESS_FS_EXECUTE | LANDLOCK_ACCESS_FS_READ_FILE | LANDLOCK_ACCESS_FS_READ_DIR;
+    for (paths, access) in [
+        (&config.read_write_paths, handled_access_fs),
+        (&config.read_only_paths, read_only_access),
+    ] {
+        for path in paths {
+            add_landlock_path_rule(&ruleset, path, access & handled_access_fs)?;
+        }
+    }
+    Ok(ruleset)
+}
+
+#[cfg(target_os = "linux")]
+fn add_landlock_path_rule(ruleset: &OwnedFd, path: &Path, access: u64) -> Result<(), String> {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return Ok(());
+    };
+    let allowed_access = if metadata.is_dir() {
+        access
+    } else {
+        access
+            & (LANDLOCK_ACCESS_FS_EXECUTE
+                | LANDLOCK_ACCESS_FS_WRITE_FILE
+                | LANDLOCK_ACCESS_FS_READ_FILE
+                | LANDLOCK_ACCESS_FS_TRUNCATE)
+    };
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_PATH | libc::O_CLOEXEC)
+        .open(path)
+        .map_err(|error| format!("failed to open '{}': {error}", path.display()))?;
+    let rule = LandlockPathBeneathAttr {
+        allowed_access,
+        parent_fd: file.as_raw_fd(),
+    };
+    // SAFETY: both descriptors are open and `rule` outlives the call.
+    let status = unsafe {
+        libc::syscall(
+            libc::SYS_landlock_add_rule,
+            ruleset.as_raw_fd(),
+            LANDLOCK_RULE_PATH_BENEATH,
+            &rule as *const LandlockPathBeneathAttr,
+            0 as libc::c_uint,
+        )
+    };
+    if status != 0 {
+        return Err(format!(
+            "failed to add landlock rule for '{}': {}",
+            path.display(),
+            std::io::Error::last_os_error()
+        ));
+    }
+    Ok(())
+}
+
+/// Arms `command` so the spawned child applies rlimits, Landlock and seccomp before `exec`.
+///
+/// All allocation happens here in the parent; the `pre_exec` hook only issues
+/// raw syscalls so it stays async-signal-safe after `fork`.
+#[cfg(target_os = "linux")]
+pub(super) fn prepare_native_sandbox(
+    command: &mut tokio::process::Command,
+    config: &NativeSandboxConfig,
+) -> Result<NativeSandboxGuard, String> {
+    let network_filter = if config.allow_network {
+        None
+    } else {
+        Some(
+            native_sandbox_network_filter()
+                .ok_or_else(|| SANDBOX_NATIVE_UNAVAILABLE_ERROR.to_string())?,
+        )
+    };
+    let ruleset = create_landlock_ruleset(config)?;
+    let ruleset_fd = ruleset.as_raw_fd();
+    let rlimits = [
+        (libc::RLIMIT_CPU, config.cpu_seconds),
+        (libc::RLIMIT_AS, config.memory_bytes),
+        (libc::RLIMIT_NPROC, config.max_processes),
+    ];
+
+    // SAFETY: the hook runs between fork and exec and only performs raw
+    // syscalls on data captured by value; it does not allocate or lock.
+    unsafe {
+        command.pre_exec(move || {
+            for (resource, limit) in rlimits {
+                if limit == 0 {
+                    continue;
+                }
+                let value = libc::rlimit {
+                    rlim_cur: limit as libc::rlim_t,
+                    rlim_max: limit as libc::rlim_t,
+                };
+                if libc::setrlimit(resource, &value) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            if libc::prctl(
+                libc::PR_SET_NO_NEW_PRIVS,
+                1 as libc::c_ulong,
+                0 as libc::c_ulong,
+                0 as libc::c_ulong,
+                0 as libc::c_ulong,
+            ) != 0
+            {
+                return Err(std::io::Error::last_os_error());
+            }
+            if libc::syscall(
+                libc::SYS_landlock_restrict_self,
+                ruleset_fd,
+                0 as libc::c_uint,
+            ) != 0
+            {
+                return Err(std::io::Error::last_os_error());
+            }
+            if let Some(filter) = &network_filter {
+                let program = libc::sock_fprog {
+                    len: filter.len() as libc::c_ushort,
+                    filter: filter.as_ptr() as *mut libc::sock_filter,
+                };
+                if libc::prctl(
+                    libc::PR_SET_SECCOMP,
+                    libc::SECCOMP_MODE_FILTER as libc::c_ulong,
+                    &program as *const libc::sock_fprog,
+                ) != 0
+                {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    Ok(NativeSandboxGuard { _ruleset: ruleset })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(super) fn prepare_native_sandbox(
+    _command: &mut tokio::process::Command,
+    _config: &NativeSandboxConfig,
+) -> Result<NativeSandboxGuard, String> {
+    Err(SANDBOX_NATIVE_UNAVAILABLE_ERROR.to_string())
+}
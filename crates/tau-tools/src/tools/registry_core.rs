@@ -29,6 +29,7 @@ pub enum OsSandboxMode {
     Off,
     Auto,
     Force,
+    Native,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -59,6 +60,7 @@ pub(super) struct BashSandboxSpec {
     pub(super) args: Vec<String>,
     pub(super) sandboxed: bool,
     pub(super) backend: String,
+    pub(super) native: Option<NativeSandboxConfig>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -205,6 +207,8 @@ pub struct ToolPolicy {
     pub jobs_max_timeout_ms: u64,
     pub jobs_channel_store_root: PathBuf,
     pub jobs_default_session_path: Option<PathBuf>,
+    pub file_checkpoint_dir: PathBuf,
+    pub code_index_dir: PathBuf,
     pub max_file_read_bytes: usize,
     pub max_file_write_bytes: usize,
     pub max_command_output_bytes: usize,
@@ -224,6 +228,14 @@ pub struct ToolPolicy {
     pub os_sandbox_docker_pids_limit: u64,
     pub os_sandbox_docker_read_only_rootfs: bool,
     pub os_sandbox_docker_env_allowlist: Vec<String>,
+    pub os_sandbox_native_allow_network: bool,
+    pub os_sandbox_native_memory_mb: u64,
+    pub os_sandbox_native_max_processes: u64,
+    pub lsp_enabled: bool,
+    pub lsp_servers: Vec<LspServerConfig>,
+    pub lsp_request_timeout_ms: u64,
+    pub lsp_post_edit_diagnostics: bool,
+    pub git_session_worktrees: bool,
     pub http_timeout_ms: u64,
     pub http_max_response_bytes: usize,
     pub http_max_redirects: usize,
@@ -304,6 +316,8 @@ impl ToolPolicy {
             jobs_max_timeout_ms: JOBS_MAX_TIMEOUT_MS,
             jobs_channel_store_root: PathBuf::from(".tau/channel-store"),
             jobs_default_session_path: Some(PathBuf::from(".tau/sessions/default.sqlite")),
+            file_checkpoint_dir: PathBuf::from(".tau/file-checkpoints"),
+            code_index_dir: PathBuf::from(".tau/code-index"),
             max_file_read_bytes: 1_000_000,
             max_file_write_bytes: 1_000_000,
             max_command_output_bytes: 16_000,
@@ -326,6 +340,14 @@ impl ToolPolicy {
             os_sandbox_docker_pids_limit: DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT,
             os_sandbox_docker_read_only_rootfs: true,
             os_sandbox_docker_env_allowlist: Vec::new(),
+            os_sandbox_native_allow_network: false,
+            os_sandbox_native_memory_mb: NATIVE_SANDBOX_DEFAULT_MEMORY_MB,
+            os_sandbox_native_max_processes: NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES,
+            lsp_enabled: true,
+            lsp_servers: default_lsp_servers(),
+            lsp_request_timeout_ms: LSP_REQUEST_TIMEOUT_MS_DEFAULT,
+            lsp_post_edit_diagnostics: false,
+            git_session_worktrees: false,
             http_timeout_ms: TOOL_HTTP_TIMEOUT_MS_BALANCED,
             http_max_response_bytes: TOOL_HTTP_MAX_RESPONSE_BYTES_BALANCED,
             http_max_redirects: TOOL_HTTP_MAX_REDIRECTS_BALANCED,
@@ -401,6 +423,9 @@ impl ToolPolicy {
                 self.os_sandbox_docker_pids_limit = DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT;
                 self.os_sandbox_docker_read_only_rootfs = true;
                 self.os_sandbox_docker_env_allowlist.clear();
+                self.os_sandbox_native_allow_network = false;
+                self.os_sandbox_native_memory_mb = NATIVE_SANDBOX_DEFAULT_MEMORY_MB;
+                self.os_sandbox_native_max_processes = NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES;
                 self.http_timeout_ms = TOOL_HTTP_TIMEOUT_MS_PERMISSIVE;
                 self.http_max_response_bytes = TOOL_HTTP_MAX_RESPONSE_BYTES_PERMISSIVE;
                 self.http_max_redirects = TOOL_HTTP_MAX_REDIRECTS_PERMISSIVE;
@@ -430,6 +455,9 @@ impl ToolPolicy {
                 self.os_sandbox_docker_pids_limit = DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT;
                 self.os_sandbox_docker_read_only_rootfs = true;
                 self.os_sandbox_docker_env_allowlist.clear();
+                self.os_sandbox_native_allow_network = false;
+                self.os_sandbox_native_memory_mb = NATIVE_SANDBOX_DEFAULT_MEMORY_MB;
+                self.os_sandbox_native_max_processes = NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES;
                 self.http_timeout_ms = TOOL_HTTP_TIMEOUT_MS_BALANCED;
                 self.http_max_response_bytes = TOOL_HTTP_MAX_RESPONSE_BYTES_BALANCED;
                 self.http_max_redirects = TOOL_HTTP_MAX_REDIRECTS_BALANCED;
@@ -459,6 +487,9 @@ impl ToolPolicy {
                 self.os_sandbox_docker_pids_limit = DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT;
                 self.os_sandbox_docker_read_only_rootfs = true;
                 self.os_sandbox_docker_env_allowlist.clear();
+                self.os_sandbox_native_allow_network = false;
+                self.os_sandbox_native_memory_mb = NATIVE_SANDBOX_DEFAULT_MEMORY_MB;
+                self.os_sandbox_native_max_processes = NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES;
                 self.http_timeout_ms = TOOL_HTTP_TIMEOUT_MS_STRICT;
                 self.http_max_response_bytes = TOOL_HTTP_MAX_RESPONSE_BYTES_STRICT;
                 self.http_max_redirects = TOOL_HTTP_MAX_REDIRECTS_STRICT;
@@ -488,6 +519,9 @@ impl ToolPolicy {
                 self.os_sandbox_docker_pids_limit = DOCKER_SANDBOX_DEFAULT_PIDS_LIMIT;
                 self.os_sandbox_docker_read_only_rootfs = true;
                 self.os_sandbox_docker_env_allowlist.clear();
+                self.os_sandbox_native_allow_network = false;
+                self.os_sandbox_native_memory_mb = NATIVE_SANDBOX_DEFAULT_MEMORY_MB;
+                self.os_sandbox_native_max_processes = NATIVE_SANDBOX_DEFAULT_MAX_PROCESSES;
                 self.http_timeout_ms = TOOL_HTTP_TIMEOUT_MS_HARDENED;
                 self.http_max_response_bytes = TOOL_HTTP_MAX_RESPONSE_BYTES_HARDENED;
                 self.http_max_redirects = TOOL_HTTP_MAX_REDIRECTS_HARDENED;
@@ -592,6 +626,7 @@ pub fn register_builtin_tools(agent: &mut Agent, policy: ToolPolicy) {
     agent.register_tool(ReadTool::new(policy.clone()));
     agent.register_tool(WriteTool::new(policy.clone()));
     agent.register_tool(EditTool::new(policy.clone()));
+    agent.register_tool(ApplyPatchTool::new(policy.clone()));
     agent.register_tool(MemoryWriteTool::new(policy.clone()));
     agent.register_tool(MemoryReadTool::new(policy.clone()));
     agent.register_tool(MemoryDeleteTool::new(policy.clone()));
@@ -614,6 +649,19 @@ pub fn register_builtin_tools(agent: &mut Agent, policy: ToolPolicy) {
     agent.register_tool(SkipTool::new(policy.clone()));
     agent.register_tool(GrepTool::new(policy.clone()));
     agent.register_tool(GlobTool::new(policy.clone()));
+    agent.register_tool(FindDefinitionTool::new(policy.clone()));
+    agent.register_tool(FindReferencesTool::new(policy.clone()));
+    agent.register_tool(OutlineFileTool::new(policy.clone()));
+    agent.register_tool(SymbolSearchTool::new(policy.clone()));
+    agent.register_tool(LspDiagnosticsTool::new(policy.clone()));
+    agent.register_tool(LspHoverTool::new(policy.clone()));
+    agent.register_tool(LspDefinitionTool::new(policy.clone()));
+    agent.register_tool(LspRenameTool::new(policy.clone()));
+    agent.register_tool(GitStatusTool::new(policy.clone()));
+    agent.register_tool(GitDiffTool::new(policy.clone()));
+    agent.register_tool(GitCommitTool::new(policy.clone()));
+    agent.register_tool(GitLogTool::new(policy.clone()));
+    agent.register_tool(GitBlameTool::new(policy.clone()));
     agent.register_tool(ListDirectoryTool::new(policy.clone()));
     agent.register_tool(HttpTool::new(policy.clone()));
     if policy.tool_builder_enabled {
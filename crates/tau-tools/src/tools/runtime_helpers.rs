@@ -599,6 +599,7 @@ pub(super) fn resolve_sandbox_spec(
                 args: vec!["-lc".to_string(), command.to_string()],
                 sandboxed: false,
                 backend: "none".to_string(),
+                native: None,
             },
             OsSandboxMode::Auto => {
                 if let Some(spec) = auto_sandbox_spec(policy, shell, command, cwd) {
@@ -609,6 +610,7 @@ pub(super) fn resolve_sandbox_spec(
                         args: vec!["-lc".to_string(), command.to_string()],
                         sandboxed: false,
                         backend: "none".to_string(),
+                        native: None,
                     }
                 }
             }
@@ -622,6 +624,23 @@ pub(super) fn resolve_sandbox_spec(
                     return Err(SANDBOX_FORCE_UNAVAILABLE_ERROR.to_string());
                 }
             }
+            OsSandboxMode::Native => {
+                let Some(landlock_abi) = native_sandbox_landlock_abi() else {
+                    return Err(SANDBOX_NATIVE_UNAVAILABLE_ERROR.to_string());
+                };
+                if !policy.os_sandbox_native_allow_network
+                    && !native_sandbox_network_filter_supported()
+                {
+                    return Err(SANDBOX_NATIVE_UNAVAILABLE_ERROR.to_string());
+                }
+                BashSandboxSpec {
+                    program: shell.to_string(),
+                    args: vec!["-lc".to_string(), command.to_string()],
+                    sandboxed: true,
+                    backend: "native".to_string(),
+                    native: Some(NativeSandboxConfig::from_policy(policy, cwd, landlock_abi)),
+                }
+            }
         }
     };
 
@@ -682,6 +701,7 @@ pub(super) fn build_spec_from_command_template(
         args,
         sandboxed: true,
         backend: "template".to_string(),
+        native: None,
     })
 }
 
@@ -732,6 +752,7 @@ pub(super) fn auto_sandbox_spec(
                 args,
                 sandboxed: true,
                 backend: "bwrap".to_string(),
+                native: None,
             });
         }
     }
@@ -808,6 +829,7 @@ pub(super) fn build_docker_sandbox_spec(
         args,
         sandboxed: true,
         backend: "docker".to_string(),
+        native: None,
     }
 }
 
@@ -867,6 +889,7 @@ pub(super) fn os_sandbox_mode_name(mode: OsSandboxMode) -> &'static str {
         OsSandboxMode::Off => "off",
         OsSandboxMode::Auto => "auto",
         OsSandboxMode::Force => "force",
+        OsSandboxMode::Native => "native",
     }
 }
 
@@ -14,19 +14,39 @@ use async_trait::async_trait;
 use proptest::prelude::*;
 use tempfile::tempdir;
 
+use super::apply_patch_tool::{
+    apply_patch_hunks, parse_structured_patch, parse_unified_diff, FilePatch, PatchFileAction,
+    PatchHunk,
+};
+use super::git_tools::{
+    generate_git_commit_message, parse_git_staged_changes, parse_git_status_porcelain,
+};
+use super::lsp_client::{
+    apply_lsp_text_edits, encode_lsp_message, file_uri_to_path, lsp_position_from_line_column,
+    lsp_position_to_offset, path_to_file_uri, read_lsp_message,
+};
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+use super::native_sandbox::native_sandbox_network_filter;
 use super::{
-    bash_profile_name, build_docker_sandbox_spec, build_spec_from_command_template,
-    builtin_agent_tool_names, canonicalize_best_effort, command_available,
-    evaluate_tool_approval_gate, evaluate_tool_rate_limit_gate, evaluate_tool_rbac_gate,
-    is_command_allowed, is_session_candidate_path, leading_executable,
+    apply_lsp_server_overrides, bash_profile_name, build_docker_sandbox_spec,
+    build_spec_from_command_template, builtin_agent_tool_names, canonicalize_best_effort,
+    command_available, default_lsp_servers, evaluate_tool_approval_gate,
+    evaluate_tool_rate_limit_gate, evaluate_tool_rbac_gate, is_command_allowed,
+    is_session_candidate_path, leading_executable, native_sandbox_landlock_abi,
     os_sandbox_docker_network_name, os_sandbox_mode_name, os_sandbox_policy_mode_name,
-    redact_secrets, register_builtin_tools, resolve_sandbox_spec, truncate_bytes, AgentTool,
-    BashCommandProfile, BashTool, BranchTool, EditTool, HttpTool, JobsCancelTool, JobsCreateTool,
-    JobsListTool, JobsStatusTool, MemoryDeleteTool, MemoryReadTool, MemorySearchTool,
-    MemoryTreeTool, MemoryTypeImportanceProfile, MemoryWriteTool, OsSandboxDockerNetwork,
-    OsSandboxMode, OsSandboxPolicyMode, ReactTool, RedoTool, SendFileTool, SessionsHistoryTool,
-    SessionsListTool, SessionsSearchTool, SessionsSendTool, SessionsStatsTool, SkipTool,
-    ToolBuilderTool, ToolExecutionResult, ToolPolicy, ToolPolicyPreset,
+    parse_lsp_server_spec, redact_secrets, register_builtin_tools, resolve_sandbox_spec,
+    truncate_bytes, AgentTool, ApplyPatchTool, BashCommandProfile, BashTool, BranchTool, EditTool,
+    FindDefinitionTool, FindReferencesTool, GitBlameTool, GitCommitTool, GitDiffTool, GitLogTool,
+    GitStatusTool, HttpTool, JobsCancelTool, JobsCreateTool, JobsListTool, JobsStatusTool,
+    LspDefinitionTool, LspDiagnosticsTool, LspHoverTool, LspRenameTool, LspServerConfig,
+    MemoryDeleteTool, MemoryReadTool, MemorySearchTool, MemoryTreeTool,
+    MemoryTypeImportanceProfile, MemoryWriteTool, NativeSandboxConfig, OsSandboxDockerNetwork,
+    OsSandboxMode, OsSandboxPolicyMode, OutlineFileTool, ReactTool, RedoTool, SendFileTool,
+    SessionsHistoryTool, SessionsListTool, SessionsSearchTool, SessionsSendTool, SessionsStatsTool,
+    SkipTool, SymbolSearchTool, ToolBuilderTool, ToolExecutionResult, ToolPolicy, ToolPolicyPreset,
     ToolRateLimitExceededBehavior, UndoTool, WriteTool,
 };
 use tau_access::ApprovalAction;
@@ -35,8 +55,8 @@ use tau_ai::Message;
 #[allow(deprecated)]
 use tau_extensions::{discover_extension_runtime_registrations, execute_extension_registered_tool};
 use tau_session::{
-    navigate_session_head, session_message_preview, session_message_role, SessionRuntime,
-    SessionStore,
+    navigate_session_head, session_git_store_key, session_message_preview, session_message_role,
+    SessionRuntime, SessionStore,
 };
 
 fn test_policy(path: &Path) -> Arc<ToolPolicy> {
@@ -239,6 +259,7 @@ fn unit_builtin_agent_tool_name_registry_includes_session_tools() {
     assert!(names.contains(&"read"));
     assert!(names.contains(&"write"));
     assert!(names.contains(&"edit"));
+    assert!(names.contains(&"apply_patch"));
     assert!(names.contains(&"memory_write"));
     assert!(names.contains(&"memory_read"));
     assert!(names.contains(&"memory_delete"));
@@ -1718,6 +1739,945 @@ async fn regression_undo_tool_reports_empty_navigation_history() {
         .contains("undo unavailable"));
 }
 
+fn test_policy_with_file_checkpoints(path: &Path) -> Arc<ToolPolicy> {
+    let mut policy = ToolPolicy::new(vec![path.to_path_buf()]);
+    policy.file_checkpoint_dir = path.join(".tau/file-checkpoints");
+    Arc::new(policy)
+}
+
+#[test]
+fn unit_parse_unified_diff_maps_create_delete_and_rename_headers() {
+    let patches = parse_unified_diff(
+        "diff --git a/src/old.rs b/src/new.rs\n\
+         similarity index 90%\n\
+         rename from src/old.rs\n\
+         rename to src/new.rs\n\
+         --- a/src/old.rs\n\
+         +++ b/src/new.rs\n\
+         @@ -1,2 +1,2 @@\n\
+         \x20fn main() {\n\
+         -    old();\n\
+         +    new();\n\
+         --- /dev/null\n\
+         +++ b/notes.txt\n\
+         @@ -0,0 +1,2 @@\n\
+         +first\n\
+         +second\n\
+         --- a/stale.txt\n\
+         +++ /dev/null\n\
+         @@ -1 +0,0 @@\n\
+         -stale\n",
+    )
+    .expect("parse diff");
+
+    assert_eq!(patches.len(), 3);
+    assert_eq!(patches[0].path, "src/old.rs");
+    match &patches[0].action {
+        PatchFileAction::Update { hunks, move_to } => {
+            assert_eq!(move_to.as_deref(), Some("src/new.rs"));
+            assert_eq!(hunks.len(), 1);
+            assert_eq!(hunks[0].old_start, Some(1));
+            assert_eq!(hunks[0].old_lines, vec!["fn main() {", "    old();"]);
+            assert_eq!(hunks[0].new_lines, vec!["fn main() {", "    new();"]);
+        }
+        other => panic!("expected rename update, got {other:?}"),
+    }
+    assert_eq!(
+        patches[1],
+        FilePatch {
+            path: "notes.txt".to_string(),
+            action: PatchFileAction::Create {
+                content: "first\nsecond\n".to_string(),
+            },
+        }
+    );
+    assert_eq!(
+        patches[2],
+        FilePatch {
+            path: "stale.txt".to_string(),
+            action: PatchFileAction::Delete,
+        }
+    );
+}
+
+#[test]
+fn regression_parse_unified_diff_rejects_hunk_without_file_header() {
+    let error = parse_unified_diff("@@ -1 +1 @@\n-a\n+b\n").expect_err("orphan hunk");
+    assert!(error.contains("before any '---'/'+++' file header"));
+}
+
+#[test]
+fn unit_apply_patch_hunks_falls_back_to_whitespace_fuzz_and_preserves_crlf() {
+    let patches = parse_structured_patch(&serde_json::json!([{
+        "path": "lib.rs",
+        "hunks": [{ "old": "let value = 1;", "new": "let value = 2;" }]
+    }]))
+    .expect("structured patch");
+    let PatchFileAction::Update { hunks, .. } = &patches[0].action else {
+        panic!("expected update");
+    };
+
+    let (patched, fuzz) =
+        apply_patch_hunks("fn f() {\r\n    let  value = 1;   \r\n}\r\n", hunks).expect("apply");
+    assert_eq!(patched, "fn f() {\r\nlet value = 2;\r\n}\r\n");
+    assert_eq!(fuzz, 2);
+}
+
+#[test]
+fn unit_apply_patch_hunks_prefers_match_nearest_line_hint() {
+    let hunk = PatchHunk {
+        old_start: Some(5),
+        old_lines: vec!["x".to_string()],
+        new_lines: vec!["y".to_string()],
+        old_missing_final_newline: false,
+        new_missing_final_newline: false,
+    };
+    let (patched, fuzz) = apply_patch_hunks("x\na\nb\nc\nx\nd\n", &[hunk]).expect("apply");
+    assert_eq!(patched, "x\na\nb\nc\ny\nd\n");
+    assert_eq!(fuzz, 0);
+}
+
+#[tokio::test]
+async fn functional_apply_patch_tool_applies_multi_file_unified_diff() {
+    let temp = tempdir().expect("tempdir");
+    fs::write(temp.path().join("main.rs"), "fn main() {\n    old();\n}\n").expect("seed main");
+    fs::write(temp.path().join("legacy.rs"), "pub fn legacy() {}\n").expect("seed legacy");
+    fs::write(temp.path().join("stale.txt"), "stale\n").expect("seed stale");
+    let patch = format!(
+        "--- a/{root}/main.rs\n+++ b/{root}/main.rs\n@@ -1,3 +1,3 @@\n fn main() {{\n-    old();\n+    new();\n }}\n\
+         diff --git a/{root}/legacy.rs b/{root}/modern.rs\nrename from {root}/legacy.rs\nrename to {root}/modern.rs\n\
+         --- /dev/null\n+++ b/{root}/added.txt\n@@ -0,0 +1 @@\n+added\n\
+         --- a/{root}/stale.txt\n+++ /dev/null\n@@ -1 +0,0 @@\n-stale\n",
+        root = temp.path().display()
+    );
+
+    let tool = ApplyPatchTool::new(test_policy_with_file_checkpoints(temp.path()));
+    let result = tool.execute(serde_json::json!({ "patch": patch })).await;
+    assert!(!result.is_error, "apply_patch failed: {}", result.content);
+    assert_eq!(result.content["reason_code"], "apply_patch_applied");
+    assert_eq!(result.content["files_changed"], 4);
+    assert!(result.content["checkpoint_id"]
+        .as_str()
+        .is_some_and(|id| id.starts_with("checkpoint-")));
+
+    assert_eq!(
+        fs::read_to_string(temp.path().join("main.rs")).expect("read main"),
+        "fn main() {\n    new();\n}\n"
+    );
+    assert!(!temp.path().join("legacy.rs").exists());
+    assert_eq!(
+        fs::read_to_string(temp.path().join("modern.rs")).expect("read modern"),
+        "pub fn legacy() {}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(temp.path().join("added.txt")).expect("read added"),
+        "added\n"
+    );
+    assert!(!temp.path().join("stale.txt").exists());
+}
+
+#[tokio::test]
+async fn regression_apply_patch_tool_writes_nothing_when_any_hunk_fails() {
+    let temp = tempdir().expect("tempdir");
+    let first = temp.path().join("first.txt");
+    let second = temp.path().join("second.txt");
+    fs::write(&first, "alpha\n").expect("seed first");
+    fs::write(&second, "beta\n").expect("seed second");
+
+    let tool = ApplyPatchTool::new(test_policy_with_file_checkpoints(temp.path()));
+    let result = tool
+        .execute(serde_json::json!({
+            "files": [
+                { "path": first, "hunks": [{ "old": "alpha", "new": "ALPHA" }] },
+                { "path": second, "hunks": [{ "old": "gamma", "new": "GAMMA" }] },
+                { "action": "create", "path": temp.path().join("third.txt"), "content": "new\n" }
+            ]
+        }))
+        .await;
+    assert!(result.is_error);
+    assert_eq!(result.content["reason_code"], "apply_patch_hunk_failed");
+    assert_eq!(fs::read_to_string(&first).expect("read first"), "alpha\n");
+    assert_eq!(fs::read_to_string(&second).expect("read second"), "beta\n");
+    assert!(!temp.path().join("third.txt").exists());
+    assert!(!temp
+        .path()
+        .join(".tau/file-checkpoints/state.json")
+        .exists());
+}
+
+#[tokio::test]
+async fn regression_apply_patch_tool_rejects_paths_outside_allowed_roots() {
+    let root = tempdir().expect("root");
+    let outside = tempdir().expect("outside");
+    let inside = root.path().join("inside.txt");
+    let escaped = outside.path().join("escaped.txt");
+    fs::write(&inside, "inside\n").expect("seed inside");
+    fs::write(&escaped, "escaped\n").expect("seed escaped");
+
+    let tool = ApplyPatchTool::new(test_policy_with_file_checkpoints(root.path()));
+    let result = tool
+        .execute(serde_json::json!({
+            "files": [
+                { "path": inside, "hunks": [{ "old": "inside", "new": "changed" }] },
+                { "path": escaped, "hunks": [{ "old": "escaped", "new": "changed" }] }
+            ]
+        }))
+        .await;
+    assert!(result.is_error);
+    assert_eq!(result.content["reason_code"], "apply_patch_path_denied");
+    assert!(result.content.to_string().contains("outside allowed roots"));
+    assert_eq!(
+        fs::read_to_string(&inside).expect("read inside"),
+        "inside\n"
+    );
+}
+
+#[tokio::test]
+async fn integration_undo_and_redo_tools_restore_apply_patch_file_checkpoint() {
+    let temp = tempdir().expect("tempdir");
+    let target = temp.path().join("config.toml");
+    fs::write(&target, "mode = \"old\"\n").expect("seed target");
+    let policy = test_policy_with_file_checkpoints(temp.path());
+
+    let apply = ApplyPatchTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "files": [
+                { "path": target, "hunks": [{ "old": "mode = \"old\"", "new": "mode = \"new\"" }] },
+                { "action": "create", "path": temp.path().join("extra.toml"), "content": "x = 1\n" }
+            ]
+        }))
+        .await;
+    assert!(!apply.is_error, "apply_patch failed: {}", apply.content);
+
+    let undo = UndoTool::new(policy.clone())
+        .execute(serde_json::json!({ "scope": "files" }))
+        .await;
+    assert!(!undo.is_error, "undo failed: {}", undo.content);
+    assert_eq!(undo.content["reason_code"], "file_checkpoint_undo_applied");
+    assert_eq!(
+        undo.content["file_checkpoint"]["checkpoint_id"],
+        apply.content["checkpoint_id"]
+    );
+    assert_eq!(
+        fs::read_to_string(&target).expect("read target"),
+        "mode = \"old\"\n"
+    );
+    assert!(!temp.path().join("extra.toml").exists());
+
+    let redo = RedoTool::new(policy.clone())
+        .execute(serde_json::json!({}))
+        .await;
+    assert!(!redo.is_error, "redo failed: {}", redo.content);
+    assert_eq!(redo.content["reason_code"], "file_checkpoint_redo_applied");
+    assert_eq!(
+        fs::read_to_string(&target).expect("read target"),
+        "mode = \"new\"\n"
+    );
+    assert_eq!(
+        fs::read_to_string(temp.path().join("extra.toml")).expect("read extra"),
+        "x = 1\n"
+    );
+
+    let empty = RedoTool::new(policy)
+        .execute(serde_json::json!({ "scope": "files" }))
+        .await;
+    assert!(empty.is_error);
+    assert_eq!(
+        empty.content["reason_code"],
+        "file_checkpoint_redo_empty_stack"
+    );
+}
+
+#[tokio::test]
+async fn regression_undo_tool_refuses_file_checkpoint_when_file_changed_afterwards() {
+    let temp = tempdir().expect("tempdir");
+    let target = temp.path().join("notes.md");
+    fs::write(&target, "draft\n").expect("seed target");
+    let policy = test_policy_with_file_checkpoints(temp.path());
+
+    let apply = ApplyPatchTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "files": [{ "path": target, "hunks": [{ "old": "draft", "new": "final" }] }]
+        }))
+        .await;
+    assert!(!apply.is_error, "apply_patch failed: {}", apply.content);
+    fs::write(&target, "edited by hand\n").expect("manual edit");
+
+    let undo = UndoTool::new(policy)
+        .execute(serde_json::json!({ "scope": "files" }))
+        .await;
+    assert!(undo.is_error);
+    assert_eq!(undo.content["reason_code"], "file_checkpoint_conflict");
+    assert_eq!(
+        fs::read_to_string(&target).expect("read target"),
+        "edited by hand\n"
+    );
+}
+
+#[tokio::test]
+async fn functional_undo_tool_restores_files_alongside_session_navigation() {
+    let temp = tempdir().expect("tempdir");
+    let session_path = temp.path().join(".tau/sessions/default.sqlite");
+    let mut store = SessionStore::load(&session_path).expect("load session");
+    store
+        .append_messages(None, &[Message::user("seed")])
+        .expect("append seed");
+    let target = temp.path().join("lib.rs");
+    fs::write(&target, "pub const A: u8 = 1;\n").expect("seed target");
+    let policy = test_policy_with_file_checkpoints(temp.path());
+
+    let apply = ApplyPatchTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "files": [{
+                "path": target,
+                "hunks": [{ "old": "pub const A: u8 = 1;", "new": "pub const A: u8 = 2;" }]
+            }]
+        }))
+        .await;
+    assert!(!apply.is_error, "apply_patch failed: {}", apply.content);
+
+    let undo = UndoTool::new(policy)
+        .execute(serde_json::json!({ "path": session_path }))
+        .await;
+    assert!(!undo.is_error, "undo failed: {}", undo.content);
+    assert_eq!(undo.content["reason_code"], "file_checkpoint_undo_applied");
+    assert!(undo.content["file_checkpoint"].is_object());
+    assert_eq!(
+        fs::read_to_string(&target).expect("read target"),
+        "pub const A: u8 = 1;\n"
+    );
+}
+
+fn write_code_intel_fixture(root: &Path) {
+    fs::create_dir_all(root.join("src")).expect("create src");
+    fs::create_dir_all(root.join("web")).expect("create web");
+    fs::write(
+        root.join("src/lib.rs"),
+        "pub struct Parser;\n\nimpl Parser {\n    pub fn parse(&self) -> usize {\n        parse_config()\n    }\n}\n\npub fn parse_config() -> usize {\n    1\n}\n",
+    )
+    .expect("write rust fixture");
+    fs::write(
+        root.join("web/app.ts"),
+        "import { render } from \"./render\";\n\nexport class Widget {\n  parse(): void {\n    render();\n  }\n}\n",
+    )
+    .expect("write typescript fixture");
+}
+
+#[test]
+fn unit_builtin_agent_tool_name_registry_includes_code_intel_tools() {
+    let names = builtin_agent_tool_names();
+    assert!(names.contains(&"find_definition"));
+    assert!(names.contains(&"find_references"));
+    assert!(names.contains(&"outline_file"));
+    assert!(names.contains(&"symbol_search"));
+}
+
+#[tokio::test]
+async fn functional_code_intel_tools_navigate_definitions_references_and_outline() {
+    let temp = tempdir().expect("tempdir");
+    write_code_intel_fixture(temp.path());
+    let policy = test_policy(temp.path());
+
+    let definition = FindDefinitionTool::new(policy.clone())
+        .execute(serde_json::json!({ "symbol": "Parser::parse" }))
+        .await;
+    assert!(!definition.is_error, "{}", definition.content);
+    assert_eq!(definition.content["total_matches"], 1);
+    assert_eq!(definition.content["definitions"][0]["path"], "src/lib.rs");
+    assert_eq!(definition.content["definitions"][0]["kind"], "method");
+    assert_eq!(definition.content["definitions"][0]["container"], "Parser");
+    assert_eq!(definition.content["definitions"][0]["line"], 4);
+
+    let all_parse = FindDefinitionTool::new(policy.clone())
+        .execute(serde_json::json!({ "symbol": "parse", "kind": "method" }))
+        .await;
+    assert_eq!(all_parse.content["total_matches"], 2);
+
+    let references = FindReferencesTool::new(policy.clone())
+        .execute(serde_json::json!({ "symbol": "parse_config" }))
+        .await;
+    assert!(!references.is_error, "{}", references.content);
+    assert_eq!(references.content["total_matches"], 1);
+    assert_eq!(references.content["references"][0]["line"], 5);
+    assert_eq!(
+        references.content["references"][0]["content"],
+        "parse_config()"
+    );
+
+    let outline = OutlineFileTool::new(policy.clone())
+        .execute(serde_json::json!({ "path": temp.path().join("web/app.ts") }))
+        .await;
+    assert!(!outline.is_error, "{}", outline.content);
+    assert_eq!(outline.content["language"], "typescript");
+    assert_eq!(outline.content["imports"][0]["path"], "./render");
+    let outline_names = outline.content["definitions"]
+        .as_array()
+        .expect("definitions")
+        .iter()
+        .map(|definition| definition["name"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(outline_names, vec!["Widget", "parse"]);
+
+    let search = SymbolSearchTool::new(policy)
+        .execute(serde_json::json!({ "query": "pars", "path": temp.path().join("src") }))
+        .await;
+    assert!(!search.is_error, "{}", search.content);
+    assert_eq!(search.content["path"], "src");
+    let search_names = search.content["symbols"]
+        .as_array()
+        .expect("symbols")
+        .iter()
+        .map(|symbol| symbol["name"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(search_names, vec!["parse", "parse_config", "Parser"]);
+}
+
+#[tokio::test]
+async fn integration_code_intel_tools_reuse_index_and_pick_up_edits() {
+    let temp = tempdir().expect("tempdir");
+    write_code_intel_fixture(temp.path());
+    let policy = test_policy(temp.path());
+
+    let first = SymbolSearchTool::new(policy.clone())
+        .execute(serde_json::json!({ "query": "render_widget" }))
+        .await;
+    assert_eq!(first.content["total_matches"], 0);
+    assert_eq!(first.content["index"]["files_parsed"], 2);
+    assert!(temp.path().join(".tau/code-index/manifest.json").exists());
+
+    fs::write(
+        temp.path().join("web/render.ts"),
+        "export function renderWidget(): void {}\n",
+    )
+    .expect("write new module");
+    let second = SymbolSearchTool::new(policy)
+        .execute(serde_json::json!({ "query": "renderwidget" }))
+        .await;
+    assert_eq!(second.content["index"]["files_parsed"], 1);
+    assert_eq!(second.content["index"]["files_reused"], 2);
+    assert_eq!(second.content["symbols"][0]["name"], "renderWidget");
+    assert_eq!(second.content["symbols"][0]["path"], "web/render.ts");
+}
+
+#[tokio::test]
+async fn regression_code_intel_tools_reject_invalid_scope_kind_and_language() {
+    let temp = tempdir().expect("tempdir");
+    let outside = tempdir().expect("outside tempdir");
+    write_code_intel_fixture(temp.path());
+    fs::write(temp.path().join("README.md"), "# fixture\n").expect("write readme");
+    let policy = test_policy(temp.path());
+
+    let outside_scope = FindDefinitionTool::new(policy.clone())
+        .execute(serde_json::json!({ "symbol": "parse", "path": outside.path() }))
+        .await;
+    assert!(outside_scope.is_error);
+    assert!(outside_scope.content["error"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("outside allowed roots"));
+
+    let invalid_kind = SymbolSearchTool::new(policy.clone())
+        .execute(serde_json::json!({ "query": "parse", "kind": "widget" }))
+        .await;
+    assert!(invalid_kind.is_error);
+    assert_eq!(
+        invalid_kind.content["error"],
+        "unsupported symbol kind 'widget'"
+    );
+
+    let unsupported = OutlineFileTool::new(policy)
+        .execute(serde_json::json!({ "path": temp.path().join("README.md") }))
+        .await;
+    assert!(unsupported.is_error);
+    assert!(unsupported.content["error"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("unsupported source language"));
+}
+
+const FAKE_LSP_SERVER_SCRIPT: &str = r##"
+import json, os, re, sys
+from urllib.parse import quote, unquote
+
+root = None
+docs = {}
+
+def send(message):
+    body = json.dumps(message).encode()
+    sys.stdout.buffer.write(b"Content-Length: %d\r\n\r\n" % len(body) + body)
+    sys.stdout.buffer.flush()
+
+def read():
+    length = None
+    while True:
+        line = sys.stdin.buffer.readline()
+        if not line:
+            return None
+        line = line.strip()
+        if not line:
+            if length is None:
+                continue
+            break
+        name, _, value = line.decode().partition(":")
+        if name.lower() == "content-length":
+            length = int(value)
+    return json.loads(sys.stdin.buffer.read(length))
+
+def to_path(uri):
+    return unquote(uri[len("file://"):])
+
+def to_uri(path):
+    return "file://" + quote(path)
+
+def publish(uri, text):
+    diagnostics = [
+        {"range": {"start": {"line": n, "character": 0}, "end": {"line": n, "character": len(line)}},
+         "severity": 1, "source": "fake", "message": "found ERROR marker"}
+        for n, line in enumerate(text.split("\n")) if "ERROR" in line
+    ]
+    send({"jsonrpc": "2.0", "method": "textDocument/publishDiagnostics",
+          "params": {"uri": uri, "diagnostics": diagnostics}})
+
+def word_at(text, position):
+    line = text.split("\n")[position["line"]]
+    for match in re.finditer(r"\w+", line):
+        if match.start() <= position["character"] <= match.end():
+            return match.group(0)
+    return None
+
+while True:
+    message = read()
+    if message is None:
+        break
+    method = message.get("method")
+    params = message.get("params") or {}
+    if "id" not in message:
+        if method == "textDocument/didOpen":
+            document = params["textDocument"]
+            docs[document["uri"]] = document["text"]
+            publish(document["uri"], document["text"])
+        elif method == "textDocument/didChange":
+            uri = params["textDocument"]["uri"]
+            docs[uri] = params["contentChanges"][-1]["text"]
+            publish(uri, docs[uri])
+        elif method == "exit":
+            break
+        continue
+    result = None
+    if method == "initialize":
+        root = to_path(params["rootUri"])
+        result = {"capabilities": {"textDocumentSync": 1, "hoverProvider": True,
+                                   "definitionProvider": True, "renameProvider": True}}
+    elif method == "textDocument/hover":
+        uri = params["textDocument"]["uri"]
+        word = word_at(docs[uri], params["position"])
+        result = {"contents": {"kind": "markdown", "value": "```rust\nfn %s()\n```" % word}} if word else None
+    elif method == "textDocument/definition":
+        uri = params["textDocument"]["uri"]
+        result = [
+            {"uri": uri, "range": {"start": {"line": 0, "character": 3}, "end": {"line": 0, "character": 8}}},
+            {"uri": "file:///outside-allowed-root/lib.rs",
+             "range": {"start": {"line": 0, "character": 0}, "end": {"line": 0, "character": 1}}},
+        ]
+    elif method == "textDocument/rename":
+        uri = params["textDocument"]["uri"]
+        word = word_at(docs[uri], params["position"])
+        changes = {}
+        for name in sorted(os.listdir(root)):
+            if not name.endswith(".rs"):
+                continue
+            path = os.path.join(root, name)
+            text = docs.get(to_uri(path))
+            if text is None:
+                text = open(path).read()
+            edits = []
+            for n, line in enumerate(text.split("\n")):
+                for match in re.finditer(r"\b%s\b" % re.escape(word), line):
+                    edits.append({"range": {"start": {"line": n, "character": match.start()},
+                                            "end": {"line": n, "character": match.end()}},
+                                  "newText": params["newName"]})
+            if edits:
+                changes[to_uri(path)] = edits
+        result = {"changes": changes}
+    elif method == "shutdown":
+        result = None
+    else:
+        send({"jsonrpc": "2.0", "id": message["id"],
+              "error": {"code": -32601, "message": "method not found"}})
+        continue
+    send({"jsonrpc": "2.0", "id": message["id"], "result": result})
+"##;
+
+const LSP_FIXTURE_LIB: &str = "fn greet() {}\nfn main() {\n    greet(); // ERROR\n}\n";
+
+fn test_policy_with_fake_lsp(root: &Path) -> Arc<ToolPolicy> {
+    let script = root.join(".tau/fake_lsp.py");
+    fs::create_dir_all(script.parent().expect("script parent")).expect("create script dir");
+    fs::write(&script, FAKE_LSP_SERVER_SCRIPT).expect("write fake lsp server");
+    fs::write(root.join("lib.rs"), LSP_FIXTURE_LIB).expect("write lib fixture");
+    fs::write(root.join("other.rs"), "use crate::greet;\n").expect("write other fixture");
+    let mut policy = ToolPolicy::new(vec![root.to_path_buf()]);
+    policy.file_checkpoint_dir = root.join(".tau/file-checkpoints");
+    policy.lsp_request_timeout_ms = 5_000;
+    policy.lsp_servers = vec![LspServerConfig {
+        language: "rust".to_string(),
+        extensions: vec!["rs".to_string()],
+        command: vec!["python3".to_string(), script.display().to_string()],
+    }];
+    Arc::new(policy)
+}
+
+#[tokio::test]
+async fn unit_lsp_message_framing_round_trips_through_reader() {
+    let first = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": { "name": "ünïcode" } });
+    let second = serde_json::json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} });
+    let mut stream = b"\r\n".to_vec();
+    stream.extend(encode_lsp_message(&first));
+    stream.extend(
+        String::from_utf8(encode_lsp_message(&second))
+            .expect("utf8 frame")
+            .replace("Content-Length", "content-length")
+            .into_bytes(),
+    );
+    let mut reader = tokio::io::BufReader::new(stream.as_slice());
+
+    assert_eq!(read_lsp_message(&mut reader).await, Ok(Some(first)));
+    assert_eq!(read_lsp_message(&mut reader).await, Ok(Some(second)));
+    assert_eq!(read_lsp_message(&mut reader).await, Ok(None));
+
+    let mut missing_length = tokio::io::BufReader::new(&b"X-Other: 1\r\n\r\n{}"[..]);
+    assert!(read_lsp_message(&mut missing_length)
+        .await
+        .expect_err("missing length")
+        .contains("Content-Length"));
+}
+
+#[test]
+fn unit_lsp_positions_use_utf16_columns_and_apply_text_edits() {
+    let text = "let é = \"😀x\";\r\nsecond\n";
+    assert_eq!(
+        lsp_position_from_line_column(text, 1, 11).expect("position"),
+        serde_json::json!({ "line": 0, "character": 11 })
+    );
+    assert!(lsp_position_from_line_column(text, 1, 40).is_err());
+    assert!(lsp_position_from_line_column(text, 9, 1).is_err());
+    assert_eq!(lsp_position_to_offset(text, 0, 11), Some(14));
+    assert_eq!(lsp_position_to_offset(text, 0, 99), Some(17));
+    assert_eq!(lsp_position_to_offset(text, 1, 0), Some(19));
+
+    let edit = |line: u64, start: u64, end: u64, new_text: &str| {
+        serde_json::json!({
+            "range": {
+                "start": { "line": line, "character": start },
+                "end": { "line": line, "character": end }
+            },
+            "newText": new_text
+        })
+    };
+    assert_eq!(
+        apply_lsp_text_edits(text, &[edit(1, 0, 6, "2nd"), edit(0, 11, 12, "y")])
+            .expect("apply edits"),
+        "let é = \"😀y\";\r\n2nd\n"
+    );
+    assert_eq!(
+        apply_lsp_text_edits(text, &[edit(0, 0, 5, "a"), edit(0, 3, 7, "b")]),
+        Err("text edits overlap".to_string())
+    );
+}
+
+#[test]
+fn unit_lsp_server_specs_override_defaults_and_uris_round_trip() {
+    let defaults = default_lsp_servers();
+    assert_eq!(
+        defaults
+            .iter()
+            .map(|server| server.language.as_str())
+            .collect::<Vec<_>>(),
+        vec!["rust", "python", "go"]
+    );
+
+    let spec =
+        parse_lsp_server_spec("typescript=typescript-language-server --stdio").expect("parse spec");
+    assert!(spec.extensions.contains(&"tsx".to_string()));
+    assert_eq!(spec.command, vec!["typescript-language-server", "--stdio"]);
+    assert!(parse_lsp_server_spec("cobol=cobol-ls").is_err());
+    assert!(parse_lsp_server_spec("rust-analyzer").is_err());
+
+    let mut servers = defaults;
+    apply_lsp_server_overrides(
+        &mut servers,
+        &[
+            "rust=ra-multiplex --stdio".to_string(),
+            "go=".to_string(),
+            "typescript=tsls".to_string(),
+        ],
+    )
+    .expect("apply overrides");
+    let commands = servers
+        .iter()
+        .map(|server| (server.language.as_str(), server.command.join(" ")))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        commands,
+        vec![
+            ("python", "pyright-langserver --stdio".to_string()),
+            ("rust", "ra-multiplex --stdio".to_string()),
+            ("typescript", "tsls".to_string()),
+        ]
+    );
+
+    let path = Path::new("/work/my crate/src/ünï.rs");
+    let uri = path_to_file_uri(path);
+    assert_eq!(uri, "file:///work/my%20crate/src/%C3%BCn%C3%AF.rs");
+    assert_eq!(file_uri_to_path(&uri), Some(path.to_path_buf()));
+    assert_eq!(file_uri_to_path("https://example.com/x.rs"), None);
+}
+
+#[test]
+fn unit_builtin_agent_tool_name_registry_includes_lsp_tools() {
+    let names = builtin_agent_tool_names();
+    for name in [
+        "lsp_diagnostics",
+        "lsp_hover",
+        "lsp_definition",
+        "lsp_rename",
+    ] {
+        assert!(names.contains(&name), "missing {name}");
+    }
+}
+
+#[tokio::test]
+async fn regression_lsp_tools_report_disabled_unsupported_and_unavailable_servers() {
+    let temp = tempdir().expect("tempdir");
+    fs::write(temp.path().join("lib.rs"), "fn main() {}\n").expect("write rust file");
+    fs::write(temp.path().join("notes.txt"), "notes\n").expect("write text file");
+
+    let mut disabled = ToolPolicy::new(vec![temp.path().to_path_buf()]);
+    disabled.lsp_enabled = false;
+    let result = LspDiagnosticsTool::new(Arc::new(disabled))
+        .execute(serde_json::json!({ "path": temp.path().join("lib.rs") }))
+        .await;
+    assert!(result.is_error);
+    assert_eq!(result.content["reason_code"], "lsp_disabled");
+
+    let unsupported = LspHoverTool::new(test_policy(temp.path()))
+        .execute(serde_json::json!({
+            "path": temp.path().join("notes.txt"),
+            "line": 1,
+            "column": 1
+        }))
+        .await;
+    assert!(unsupported.is_error);
+    assert_eq!(
+        unsupported.content["reason_code"],
+        "lsp_language_unsupported"
+    );
+
+    let mut missing = ToolPolicy::new(vec![temp.path().to_path_buf()]);
+    missing.lsp_servers = vec![
+        parse_lsp_server_spec("rust=tau-missing-language-server-binary")
+            .expect("parse missing server"),
+    ];
+    let unavailable = LspDefinitionTool::new(Arc::new(missing))
+        .execute(serde_json::json!({
+            "path": temp.path().join("lib.rs"),
+            "line": 1,
+            "column": 4
+        }))
+        .await;
+    assert!(unavailable.is_error);
+    assert_eq!(unavailable.content["reason_code"], "lsp_server_unavailable");
+
+    let outside = LspDiagnosticsTool::new(test_policy(temp.path()))
+        .execute(serde_json::json!({ "path": "/etc/hostname.rs" }))
+        .await;
+    assert!(outside.is_error);
+}
+
+#[tokio::test]
+async fn functional_lsp_tools_report_diagnostics_hover_and_definition() {
+    if !command_available("python3") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let policy = test_policy_with_fake_lsp(temp.path());
+    let lib = temp.path().join("lib.rs");
+
+    let diagnostics = LspDiagnosticsTool::new(policy.clone())
+        .execute(serde_json::json!({ "path": lib }))
+        .await;
+    assert!(
+        !diagnostics.is_error,
+        "diagnostics failed: {}",
+        diagnostics.content
+    );
+    assert_eq!(diagnostics.content["language"], "rust");
+    assert_eq!(diagnostics.content["settled"], true);
+    assert_eq!(diagnostics.content["error_count"], 1);
+    assert_eq!(diagnostics.content["diagnostics"][0]["line"], 3);
+    assert_eq!(diagnostics.content["diagnostics"][0]["column"], 1);
+    assert_eq!(
+        diagnostics.content["diagnostics"][0]["message"],
+        "found ERROR marker"
+    );
+
+    let hover = LspHoverTool::new(policy.clone())
+        .execute(serde_json::json!({ "path": lib, "line": 3, "column": 6 }))
+        .await;
+    assert!(!hover.is_error, "hover failed: {}", hover.content);
+    assert_eq!(hover.content["found"], true);
+    assert!(hover.content["contents"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("fn greet()"));
+
+    let definition = LspDefinitionTool::new(policy)
+        .execute(serde_json::json!({ "path": lib, "line": 3, "column": 6 }))
+        .await;
+    assert!(
+        !definition.is_error,
+        "definition failed: {}",
+        definition.content
+    );
+    let definitions = definition.content["definitions"]
+        .as_array()
+        .expect("definitions array");
+    assert_eq!(definitions.len(), 1);
+    assert!(definitions[0]["path"]
+        .as_str()
+        .unwrap_or_default()
+        .ends_with("lib.rs"));
+    assert_eq!(definitions[0]["line"], 1);
+    assert_eq!(definitions[0]["column"], 4);
+    assert_eq!(definitions[0]["content"], "fn greet() {}");
+    assert_eq!(definition.content["filtered_outside_allowed_roots"], 1);
+}
+
+#[tokio::test]
+async fn integration_lsp_rename_applies_workspace_edit_as_undoable_checkpoint() {
+    if !command_available("python3") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let policy = test_policy_with_fake_lsp(temp.path());
+    let lib = temp.path().join("lib.rs");
+    let other = temp.path().join("other.rs");
+
+    let preview = LspRenameTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "path": lib,
+            "line": 1,
+            "column": 4,
+            "new_name": "welcome",
+            "dry_run": true
+        }))
+        .await;
+    assert!(!preview.is_error, "dry run failed: {}", preview.content);
+    assert_eq!(preview.content["reason_code"], "lsp_rename_planned");
+    assert_eq!(preview.content["files_changed"], 2);
+    assert_eq!(fs::read_to_string(&lib).expect("read lib"), LSP_FIXTURE_LIB);
+
+    let rename = LspRenameTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "path": lib,
+            "line": 1,
+            "column": 4,
+            "new_name": "welcome"
+        }))
+        .await;
+    assert!(!rename.is_error, "rename failed: {}", rename.content);
+    assert_eq!(rename.content["reason_code"], "lsp_rename_applied");
+    assert_eq!(
+        fs::read_to_string(&lib).expect("read lib"),
+        "fn welcome() {}\nfn main() {\n    welcome(); // ERROR\n}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&other).expect("read other"),
+        "use crate::welcome;\n"
+    );
+
+    let undo = UndoTool::new(policy)
+        .execute(serde_json::json!({ "scope": "files" }))
+        .await;
+    assert!(!undo.is_error, "undo failed: {}", undo.content);
+    assert_eq!(
+        undo.content["file_checkpoint"]["checkpoint_id"],
+        rename.content["checkpoint_id"]
+    );
+    assert_eq!(fs::read_to_string(&lib).expect("read lib"), LSP_FIXTURE_LIB);
+    assert_eq!(
+        fs::read_to_string(&other).expect("read other"),
+        "use crate::greet;\n"
+    );
+}
+
+#[tokio::test]
+async fn functional_edit_tool_appends_post_edit_lsp_diagnostics() {
+    if !command_available("python3") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let mut policy = (*test_policy_with_fake_lsp(temp.path())).clone();
+    policy.lsp_post_edit_diagnostics = true;
+    let policy = Arc::new(policy);
+    let lib = temp.path().join("lib.rs");
+
+    let edit = EditTool::new(policy.clone())
+        .execute(serde_json::json!({ "path": lib, "find": " // ERROR", "replace": "" }))
+        .await;
+    assert!(!edit.is_error, "edit failed: {}", edit.content);
+    assert_eq!(edit.content["lsp_diagnostics"]["settled"], true);
+    assert_eq!(edit.content["lsp_diagnostics"]["error_count"], 0);
+
+    let write = WriteTool::new(policy)
+        .execute(serde_json::json!({ "path": lib, "content": "fn main() {} // ERROR\n" }))
+        .await;
+    assert!(!write.is_error, "write failed: {}", write.content);
+    assert_eq!(write.content["lsp_diagnostics"]["error_count"], 1);
+    assert_eq!(
+        write.content["lsp_diagnostics"]["diagnostics"][0]["line"],
+        1
+    );
+}
+
+#[tokio::test]
+async fn regression_apply_patch_and_undo_sync_lsp_documents() {
+    if !command_available("python3") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let mut policy = (*test_policy_with_fake_lsp(temp.path())).clone();
+    policy.lsp_post_edit_diagnostics = true;
+    let policy = Arc::new(policy);
+    let lib = temp.path().join("lib.rs");
+
+    let patch = ApplyPatchTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "files": [{ "path": lib, "hunks": [{ "old": "    greet(); // ERROR", "new": "    greet();" }] }]
+        }))
+        .await;
+    assert!(!patch.is_error, "apply_patch failed: {}", patch.content);
+    let diagnostics = patch.content["lsp_diagnostics"]
+        .as_array()
+        .expect("apply_patch diagnostics");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["path"], lib.display().to_string());
+    assert_eq!(diagnostics[0]["settled"], true);
+    assert_eq!(diagnostics[0]["error_count"], 0);
+
+    let undo = UndoTool::new(policy)
+        .execute(serde_json::json!({ "scope": "files" }))
+        .await;
+    assert!(!undo.is_error, "undo failed: {}", undo.content);
+    assert_eq!(fs::read_to_string(&lib).expect("read lib"), LSP_FIXTURE_LIB);
+    let diagnostics = undo.content["file_checkpoint"]["lsp_diagnostics"]
+        .as_array()
+        .expect("undo diagnostics");
+    assert_eq!(diagnostics[0]["settled"], true);
+    assert_eq!(diagnostics[0]["error_count"], 1);
+    assert_eq!(diagnostics[0]["diagnostics"][0]["line"], 3);
+}
+
 #[tokio::test]
 async fn unit_memory_write_tool_rejects_empty_summary() {
     let temp = tempdir().expect("tempdir");
@@ -3995,6 +4955,159 @@ fn regression_resolve_sandbox_spec_required_denies_unsandboxed_fallback() {
     assert!(error.contains("policy mode 'required'"));
 }
 
+#[test]
+fn functional_resolve_sandbox_spec_native_runs_shell_in_process_when_landlock_available() {
+    let temp = tempdir().expect("tempdir");
+    let mut policy = ToolPolicy::new(vec![temp.path().to_path_buf()]);
+    policy.os_sandbox_mode = OsSandboxMode::Native;
+    policy.os_sandbox_policy_mode = OsSandboxPolicyMode::Required;
+
+    let result = resolve_sandbox_spec(&policy, "sh", "printf 'ok'", temp.path());
+    let Some(landlock_abi) = native_sandbox_landlock_abi() else {
+        let error = result.expect_err("native mode must fail closed without landlock");
+        assert!(error.contains("mode 'native'"));
+        return;
+    };
+
+    let spec = result.expect("native sandbox spec");
+    assert_eq!(spec.program, "sh");
+    assert_eq!(
+        spec.args,
+        vec!["-lc".to_string(), "printf 'ok'".to_string()]
+    );
+    assert!(spec.sandboxed);
+    assert_eq!(spec.backend, "native");
+    let native = spec.native.expect("native config");
+    assert_eq!(native.landlock_abi, landlock_abi);
+    assert!(!native.allow_network);
+}
+
+#[test]
+fn unit_native_sandbox_config_derives_paths_and_rlimits_from_policy() {
+    let temp = tempdir().expect("tempdir");
+    let root = canonicalize_best_effort(temp.path()).expect("canonical root");
+    let workdir = root.join("work");
+    fs::create_dir_all(&workdir).expect("create workdir");
+    let mut policy = ToolPolicy::new(vec![temp.path().to_path_buf()]);
+    policy.bash_timeout_ms = 2_500;
+    policy.os_sandbox_native_memory_mb = 128;
+    policy.os_sandbox_native_max_processes = 64;
+
+    let config = NativeSandboxConfig::from_policy(&policy, &workdir, 3);
+    assert_eq!(config.read_write_paths[0], root);
+    assert!(config.read_write_paths.contains(&workdir));
+    assert!(config
+        .read_only_paths
+        .iter()
+        .any(|path| path.ends_with("usr")));
+    assert_eq!(config.cpu_seconds, 3);
+    assert_eq!(config.memory_bytes, 128 * 1024 * 1024);
+    assert_eq!(config.max_processes, 64);
+
+    let metadata = config.metadata();
+    assert_eq!(metadata["landlock_abi"], 3);
+    assert_eq!(metadata["network"], "denied");
+    assert_eq!(metadata["seccomp_network_filter"], true);
+    assert_eq!(metadata["rlimits"]["cpu_seconds"], 3);
+    assert_eq!(metadata["rlimits"]["max_processes"], 64);
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+fn run_seccomp_program(program: &[libc::sock_filter], arch: u32, nr: u32, arg0: u32) -> u32 {
+    let mut accumulator = 0_u32;
+    let mut pc = 0_usize;
+    loop {
+        let instruction = &program[pc];
+        match instruction.code {
+            0x20 => {
+                accumulator = match instruction.k {
+                    0 => nr,
+                    4 => arch,
+                    16 => arg0,
+                    offset => panic!("unexpected seccomp_data offset {offset}"),
+                };
+                pc += 1;
+            }
+            0x15 | 0x35 => {
+                let taken = if instruction.code == 0x15 {
+                    accumulator == instruction.k
+                } else {
+                    accumulator >= instruction.k
+                };
+                let skip = if taken {
+                    instruction.jt
+                } else {
+                    instruction.jf
+                };
+                pc += 1 + usize::from(skip);
+            }
+            0x06 => return instruction.k,
+            code => panic!("unexpected bpf opcode {code:#x}"),
+        }
+    }
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+#[test]
+fn unit_native_sandbox_network_filter_denies_inet_sockets_and_foreign_arches() {
+    #[cfg(target_arch = "x86_64")]
+    let arch = 0xc000_003e_u32;
+    #[cfg(target_arch = "aarch64")]
+    let arch = 0xc000_00b7_u32;
+    let allow = 0x7fff_0000_u32;
+    let deny = 0x0005_0000_u32 | libc::EACCES as u32;
+    let program = native_sandbox_network_filter().expect("filter for supported arch");
+
+    let verdict = |nr: libc::c_long, arg0: libc::c_int| {
+        run_seccomp_program(&program, arch, nr as u32, arg0 as u32)
+    };
+    assert_eq!(verdict(libc::SYS_read, 0), allow);
+    assert_eq!(verdict(libc::SYS_socket, libc::AF_UNIX), allow);
+    assert_eq!(verdict(libc::SYS_socket, libc::AF_INET), deny);
+    assert_eq!(verdict(libc::SYS_socket, libc::AF_INET6), deny);
+    assert_eq!(verdict(libc::SYS_io_uring_setup, 0), deny);
+    assert_eq!(
+        run_seccomp_program(&program, 0x4000_0003, libc::SYS_read as u32, 0),
+        0x8000_0000
+    );
+}
+
+#[tokio::test]
+async fn integration_bash_tool_native_sandbox_reports_metadata_or_fails_closed() {
+    let temp = tempdir().expect("tempdir");
+    let mut policy = ToolPolicy::new(vec![temp.path().to_path_buf()]);
+    policy.os_sandbox_mode = OsSandboxMode::Native;
+    let tool = BashTool::new(Arc::new(policy));
+
+    let result = tool
+        .execute(serde_json::json!({
+            "command": "printf 'native-ok'",
+            "cwd": temp.path().display().to_string(),
+        }))
+        .await;
+
+    if native_sandbox_landlock_abi().is_none() {
+        assert!(result.is_error);
+        assert_eq!(
+            result.content["reason_code"],
+            serde_json::json!("sandbox_native_unavailable")
+        );
+        return;
+    }
+
+    assert!(!result.is_error, "unexpected error: {}", result.content);
+    assert_eq!(result.content["sandboxed"], true);
+    assert_eq!(result.content["sandbox_backend"], "native");
+    assert_eq!(result.content["stdout"], "native-ok");
+    assert_eq!(result.content["sandbox_native"]["network"], "denied");
+}
+
 #[test]
 fn truncate_bytes_keeps_valid_utf8_boundaries() {
     let value = "hello🙂world";
@@ -4383,6 +5496,7 @@ fn regression_os_sandbox_mode_name_is_stable() {
     assert_eq!(os_sandbox_mode_name(OsSandboxMode::Off), "off");
     assert_eq!(os_sandbox_mode_name(OsSandboxMode::Auto), "auto");
     assert_eq!(os_sandbox_mode_name(OsSandboxMode::Force), "force");
+    assert_eq!(os_sandbox_mode_name(OsSandboxMode::Native), "native");
 }
 
 #[test]
@@ -4412,3 +5526,277 @@ fn regression_os_sandbox_docker_network_name_is_stable() {
         "host"
     );
 }
+
+fn git_in(dir: &Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .expect("run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn init_git_test_repo(root: &Path) {
+    git_in(root, &["init", "-q", "-b", "main"]);
+    git_in(root, &["config", "user.name", "Tau Test"]);
+    git_in(root, &["config", "user.email", "tau@example.com"]);
+    git_in(root, &["config", "commit.gpgsign", "false"]);
+    fs::write(root.join("lib.rs"), "fn main() {}\n").expect("write lib");
+    git_in(root, &["add", "lib.rs"]);
+    git_in(root, &["commit", "-q", "-m", "initial"]);
+}
+
+#[test]
+fn unit_builtin_agent_tool_name_registry_includes_git_tools() {
+    let names = builtin_agent_tool_names();
+    for name in [
+        "git_status",
+        "git_diff",
+        "git_commit",
+        "git_log",
+        "git_blame",
+    ] {
+        assert!(names.contains(&name), "missing {name}");
+    }
+}
+
+#[test]
+fn unit_parse_git_status_porcelain_reports_tracking_renames_and_counts() {
+    let status = parse_git_status_porcelain(
+        "## feature...origin/feature [ahead 2, behind 1]\0RM g.rs\0f.rs\0A  new.rs\0?? scratch.txt\0UU both.rs\0",
+    );
+    assert_eq!(status["branch"], "feature");
+    assert_eq!(status["upstream"], "origin/feature");
+    assert_eq!(status["ahead"], 2);
+    assert_eq!(status["behind"], 1);
+    assert_eq!(status["clean"], false);
+    assert_eq!(status["entries"][0]["path"], "g.rs");
+    assert_eq!(status["entries"][0]["original_path"], "f.rs");
+    assert_eq!(status["entries"][1]["path"], "new.rs");
+    assert_eq!(status["staged_count"], 2);
+    assert_eq!(status["unstaged_count"], 1);
+    assert_eq!(status["untracked_count"], 1);
+    assert_eq!(status["conflicted_count"], 1);
+
+    let fresh = parse_git_status_porcelain("## No commits yet on main\0");
+    assert_eq!(fresh["branch"], "main");
+    assert!(fresh["upstream"].is_null());
+    assert_eq!(fresh["clean"], true);
+}
+
+#[test]
+fn unit_generate_git_commit_message_summarizes_staged_changes() {
+    let single = parse_git_staged_changes("A\tsrc/new.rs\n", "3\t0\tsrc/new.rs\n");
+    assert_eq!(
+        generate_git_commit_message(&single),
+        "Add src/new.rs\n\n- add src/new.rs (+3 -0)"
+    );
+
+    let mixed = parse_git_staged_changes(
+        "M\tlib.rs\nD\told.rs\nA\tlogo.png\n",
+        "2\t1\tlib.rs\n0\t4\told.rs\n-\t-\tlogo.png\n",
+    );
+    assert_eq!(
+        generate_git_commit_message(&mixed),
+        "Update 3 files\n\n- update lib.rs (+2 -1)\n- delete old.rs (+0 -4)\n- add logo.png (binary)"
+    );
+
+    let deletions = parse_git_staged_changes("D\ta.rs\nD\tb.rs\n", "0\t1\ta.rs\n0\t2\tb.rs\n");
+    assert!(generate_git_commit_message(&deletions).starts_with("Delete 2 files\n"));
+}
+
+#[tokio::test]
+async fn integration_git_tools_report_status_diff_commit_log_and_blame() {
+    if !command_available("git") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_test_repo(&repo);
+    let policy = test_policy(&repo);
+    fs::write(repo.join("lib.rs"), "fn main() {}\nfn added() {}\n").expect("modify lib");
+    fs::write(repo.join("new.rs"), "pub fn new_fn() {}\n").expect("write new file");
+
+    let status = GitStatusTool::new(policy.clone())
+        .execute(serde_json::json!({}))
+        .await;
+    assert!(!status.is_error, "git_status error: {}", status.content);
+    assert_eq!(status.content["branch"], "main");
+    assert_eq!(status.content["unstaged_count"], 1);
+    assert_eq!(status.content["untracked_count"], 1);
+
+    let diff = GitDiffTool::new(policy.clone())
+        .execute(serde_json::json!({ "paths": [repo.join("lib.rs")] }))
+        .await;
+    assert!(!diff.is_error, "git_diff error: {}", diff.content);
+    assert_eq!(diff.content["file_count"], 1);
+    assert_eq!(diff.content["files"][0]["path"], "lib.rs");
+    assert_eq!(diff.content["additions"], 1);
+    assert!(diff.content["diff"]
+        .as_str()
+        .expect("diff text")
+        .contains("+fn added() {}"));
+
+    let commit = GitCommitTool::new(policy.clone())
+        .execute(serde_json::json!({
+            "paths": [repo.join("lib.rs"), repo.join("new.rs")],
+        }))
+        .await;
+    assert!(!commit.is_error, "git_commit error: {}", commit.content);
+    assert_eq!(commit.content["reason_code"], "git_commit_created");
+    assert_eq!(commit.content["message_generated"], true);
+    assert_eq!(commit.content["branch"], "main");
+    let message = commit.content["message"].as_str().expect("message");
+    assert!(message.starts_with("Update 2 files\n\n"), "{message}");
+    assert!(message.contains("- add new.rs (+1 -0)"), "{message}");
+
+    let clean = GitStatusTool::new(policy.clone())
+        .execute(serde_json::json!({}))
+        .await;
+    assert_eq!(clean.content["clean"], true);
+
+    let log = GitLogTool::new(policy.clone())
+        .execute(serde_json::json!({ "max_count": 5 }))
+        .await;
+    assert!(!log.is_error, "git_log error: {}", log.content);
+    assert_eq!(log.content["returned"], 2);
+    assert_eq!(log.content["commits"][0]["subject"], "Update 2 files");
+    assert_eq!(
+        log.content["commits"][0]["commit"],
+        commit.content["commit"]
+    );
+    assert_eq!(log.content["commits"][1]["subject"], "initial");
+
+    let blame = GitBlameTool::new(policy.clone())
+        .execute(serde_json::json!({ "path": repo.join("lib.rs"), "start_line": 2 }))
+        .await;
+    assert!(!blame.is_error, "git_blame error: {}", blame.content);
+    assert_eq!(blame.content["returned"], 1);
+    assert_eq!(blame.content["lines"][0]["line"], 2);
+    assert_eq!(blame.content["lines"][0]["author"], "Tau Test");
+    assert_eq!(blame.content["lines"][0]["summary"], "Update 2 files");
+    assert_eq!(blame.content["lines"][0]["content"], "fn added() {}");
+}
+
+#[tokio::test]
+async fn regression_git_tools_reject_option_revisions_outside_paths_and_empty_commits() {
+    if !command_available("git") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_test_repo(&repo);
+    let policy = test_policy(&repo);
+
+    let option_revision = GitLogTool::new(policy.clone())
+        .execute(serde_json::json!({ "revision": "--output=/tmp/pwned" }))
+        .await;
+    assert!(option_revision.is_error);
+    assert_eq!(
+        option_revision.content["reason_code"],
+        "git_revision_invalid"
+    );
+
+    let outside = GitDiffTool::new(policy.clone())
+        .execute(serde_json::json!({ "paths": ["/etc/passwd"] }))
+        .await;
+    assert!(outside.is_error);
+    assert_eq!(outside.content["reason_code"], "git_path_invalid");
+
+    let empty = GitCommitTool::new(policy.clone())
+        .execute(serde_json::json!({ "message": "nothing" }))
+        .await;
+    assert!(empty.is_error);
+    assert_eq!(empty.content["reason_code"], "git_nothing_staged");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let marker = repo.join("hook-ran");
+        let hook = repo.join(".git/hooks/pre-commit");
+        fs::write(
+            &hook,
+            format!("#!/bin/sh\ntouch '{}'\nexit 1\n", marker.display()),
+        )
+        .expect("write hook");
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).expect("chmod hook");
+        fs::write(repo.join("lib.rs"), "fn main() {}\nfn hooked() {}\n").expect("modify lib");
+        let hooked = GitCommitTool::new(policy.clone())
+            .execute(serde_json::json!({ "paths": [repo.join("lib.rs")], "message": "skip hooks" }))
+            .await;
+        assert!(!hooked.is_error, "git_commit error: {}", hooked.content);
+        assert!(!marker.exists(), "repository hooks must not run");
+    }
+
+    let not_repo = tempdir().expect("tempdir");
+    let status = GitStatusTool::new(test_policy(not_repo.path()))
+        .execute(serde_json::json!({}))
+        .await;
+    assert!(status.is_error);
+    assert_eq!(status.content["reason_code"], "git_command_failed");
+}
+
+#[tokio::test]
+async fn integration_branch_tool_creates_git_worktree_for_session_branch() {
+    if !command_available("git") {
+        return;
+    }
+    let temp = tempdir().expect("tempdir");
+    let repo = fs::canonicalize(temp.path()).expect("canonical repo");
+    init_git_test_repo(&repo);
+    let session_path = repo.join(".tau/sessions/default.sqlite");
+    let mut store = SessionStore::load(&session_path).expect("load session");
+    store
+        .append_messages(None, &[Message::user("seed".to_string())])
+        .expect("append seed");
+    drop(store);
+
+    let tool = BranchTool::new(test_policy(&repo));
+    let plain = tool
+        .execute(serde_json::json!({ "path": session_path, "prompt": "no worktree" }))
+        .await;
+    assert!(!plain.is_error, "branch tool error: {}", plain.content);
+    assert!(plain.content["git_worktree"].is_null());
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": session_path,
+            "prompt": "Try the streaming approach",
+            "git_worktree": true,
+        }))
+        .await;
+    assert!(!result.is_error, "branch tool error: {}", result.content);
+    let branch_head_id = result.content["branch_head_id"]
+        .as_u64()
+        .expect("branch head id");
+    let store_key = session_git_store_key(&SessionStore::load(&session_path).expect("reload"));
+    let worktree_path = repo
+        .join(".tau/git-worktrees")
+        .join(format!("session-{store_key}-{branch_head_id}"));
+    assert_eq!(
+        result.content["git_worktree"]["path"],
+        worktree_path.display().to_string()
+    );
+    assert_eq!(
+        result.content["git_worktree"]["branch"],
+        format!("tau/session-{store_key}-{branch_head_id}")
+    );
+    assert_eq!(result.content["git_worktree"]["created"], true);
+    assert!(worktree_path.join("lib.rs").exists());
+
+    let status = GitStatusTool::new(test_policy(&repo))
+        .execute(serde_json::json!({ "cwd": worktree_path }))
+        .await;
+    assert!(!status.is_error, "git_status error: {}", status.content);
+    assert_eq!(
+        status.content["branch"],
+        format!("tau/session-{store_key}-{branch_head_id}")
+    );
+}
@@ -322,20 +322,46 @@ impl App {
                 }
                 false
             }
-            GatewayTurnEvent::ToolStarted {
+            GatewayTurnEvent::ToolCallForming {
                 tool_call_id,
                 tool_name,
-                detail,
             } => {
-                self.status.agent_state = AgentStateDisplay::ToolExec;
                 self.push_tool_event_with_id(
                     Some(tool_call_id),
                     tool_name,
                     ToolStatus::Running,
-                    detail,
+                    String::new(),
                 );
                 false
             }
+            GatewayTurnEvent::ToolCallArgumentsDelta {
+                tool_call_id,
+                delta,
+            } => {
+                self.tools
+                    .append_running_detail_by_id(&tool_call_id, &delta);
+                false
+            }
+            GatewayTurnEvent::ToolStarted {
+                tool_call_id,
+                tool_name,
+                detail,
+            } => {
+                self.status.agent_state = AgentStateDisplay::ToolExec;
+                // A call announced while the model was still streaming it keeps its entry.
+                if !self
+                    .tools
+                    .update_running_by_id(&tool_call_id, detail.clone())
+                {
+                    self.push_tool_event_with_id(
+                        Some(tool_call_id),
+                        tool_name,
+                        ToolStatus::Running,
+                        detail,
+                    );
+                }
+                false
+            }
             GatewayTurnEvent::ToolCompleted {
                 tool_call_id,
                 tool_name,
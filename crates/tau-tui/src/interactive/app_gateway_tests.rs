@@ -437,6 +437,59 @@ data: [DONE]
     );
 }
 
+#[test]
+fn functional_streamed_function_call_frames_show_forming_tool_call_before_execution() {
+    let (bind, _) = spawn_streaming_gateway_server(vec![
+        r#"event: response.output_item.added
+data: {"type":"response.output_item.added","output_index":0,"item":{"type":"function_call","call_id":"call-1","name":"read_file","arguments":"","status":"in_progress"}}
+
+event: response.function_call_arguments.delta
+data: {"type":"response.function_call_arguments.delta","output_index":0,"call_id":"call-1","delta":"{\"path\":"}
+
+event: response.function_call_arguments.delta
+data: {"type":"response.function_call_arguments.delta","output_index":0,"call_id":"call-1","delta":"\"Cargo.toml\"}"}
+
+"#,
+        r#"event: response.tool_execution.started
+data: {"type":"response.tool_execution.started","tool_call_id":"call-1","tool_name":"read_file","arguments":{"path":"Cargo.toml"}}
+
+event: response.tool_execution.completed
+data: {"type":"response.tool_execution.completed","tool_call_id":"call-1","tool_name":"read_file","success":true,"timed_out":false,"latency_ms":7}
+
+event: response.completed
+data: {"type":"response.completed","response":{"output_text":"read complete","usage":{"total_tokens":9}}}
+
+data: [DONE]
+
+"#,
+    ]);
+    let mut app = build_app(bind);
+    set_input(&mut app, "inspect file");
+
+    app_commands::submit_input(&mut app);
+    wait_until(
+        &mut app,
+        "forming tool call with streamed arguments",
+        |app| {
+            app.tools.latest_running().is_some_and(|entry| {
+                entry.tool_call_id.as_deref() == Some("call-1")
+                    && entry.detail == r#"{"path":"Cargo.toml"}"#
+            })
+        },
+    );
+    wait_for_turn(&mut app);
+
+    let entries = app
+        .tools
+        .entries()
+        .iter()
+        .filter(|entry| entry.tool_call_id.as_deref() == Some("call-1"))
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].status, super::tools::ToolStatus::Success);
+    assert_eq!(entries[0].detail, "latency_ms=7");
+}
+
 #[test]
 fn spec_3671_tool_completion_reconciles_by_tool_call_id() {
     let (bind, _) = spawn_streaming_gateway_server(vec![
@@ -54,6 +54,14 @@ pub enum GatewayTurnEvent {
     },
     TextDelta(String),
     OperatorStateSnapshot(OperatorTurnState),
+    ToolCallForming {
+        tool_call_id: String,
+        tool_name: String,
+    },
+    ToolCallArgumentsDelta {
+        tool_call_id: String,
+        delta: String,
+    },
     ToolStarted {
         tool_call_id: String,
         tool_name: String,
@@ -402,6 +410,43 @@ fn process_sse_frame(
             }
             let _ = sender.send(GatewayTurnEvent::OperatorStateSnapshot(state));
         }
+        "response.output_item.added" => {
+            let Some(item) = value
+                .get("item")
+                .filter(|item| item.get("type").and_then(Value::as_str) == Some("function_call"))
+            else {
+                return Ok(None);
+            };
+            let tool_call_id = item
+                .get("call_id")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let tool_name = item
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or("tool")
+                .to_string();
+            let _ = sender.send(GatewayTurnEvent::ToolCallForming {
+                tool_call_id,
+                tool_name,
+            });
+        }
+        "response.function_call_arguments.delta" => {
+            let tool_call_id = value
+                .get("call_id")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            if let Some(delta) = value.get("delta").and_then(Value::as_str) {
+                if !delta.is_empty() {
+                    let _ = sender.send(GatewayTurnEvent::ToolCallArgumentsDelta {
+                        tool_call_id,
+                        delta: delta.to_string(),
+                    });
+                }
+            }
+        }
         "response.tool_execution.started" => {
             let tool_call_id = value
                 .get("tool_call_id")
@@ -122,6 +122,34 @@ impl ToolPanel {
         true
     }
 
+    /// Appends streamed argument text to the running entry for `tool_call_id`.
+    pub fn append_running_detail_by_id(&mut self, tool_call_id: &str, delta: &str) -> bool {
+        let Some(entry) = self.running_entry_by_id(tool_call_id) else {
+            return false;
+        };
+        entry.detail.push_str(delta);
+        true
+    }
+
+    /// Replaces the detail of the running entry for `tool_call_id`.
+    pub fn update_running_by_id(&mut self, tool_call_id: &str, detail: String) -> bool {
+        let Some(entry) = self.running_entry_by_id(tool_call_id) else {
+            return false;
+        };
+        entry.detail = detail;
+        true
+    }
+
+    fn running_entry_by_id(&mut self, tool_call_id: &str) -> Option<&mut ToolEntry> {
+        if tool_call_id.trim().is_empty() {
+            return None;
+        }
+        self.entries.iter_mut().rev().find(|entry| {
+            entry.tool_call_id.as_deref() == Some(tool_call_id)
+                && entry.status == ToolStatus::Running
+        })
+    }
+
     pub fn entries(&self) -> &[ToolEntry] {
         &self.entries
     }
@@ -0,0 +1,371 @@
+//! Guest SDK for Tau wasm skills.
+//!
+//! Skills compile to `wasm32-wasip1` as a `cdylib` and register a handler with
+//! [`export_skill!`], which provides the `tau_extension_alloc` and
+//! `tau_extension_invoke` exports the sandbox runtime calls. Host helpers wrap
+//! the `tau_host` import module; each returns `wasm_host_capability_denied`
+//! unless the skill's capability profile grants that function. WASI filesystem
+//! access is preopened at [`PREOPEN_GUEST_PATH`] when the profile allows it.
+//!
+//! ```ignore
+//! fn handle(request: serde_json::Value) -> Result<serde_json::Value, String> {
+//!     tau_wasm_skill_sdk::log(tau_wasm_skill_sdk::LogLevel::Info, "handling request");
+//!     let cached = tau_wasm_skill_sdk::kv_get("last-query").map_err(|e| e.to_string())?;
+//!     Ok(serde_json::json!({ "request": request, "cached": cached }))
+//! }
+//!
+//! tau_wasm_skill_sdk::export_skill!(handle);
+//! ```
+
+use std::{cell::RefCell, collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Import module name the sandbox runtime links host functions under.
+pub const HOST_MODULE_NAME: &str = "tau_host";
+/// Guest path where the sandbox preopens the skill's filesystem root.
+pub const PREOPEN_GUEST_PATH: &str = "/workspace";
+
+#[cfg(target_arch = "wasm32")]
+mod imports {
+    #[link(wasm_import_module = "tau_host")]
+    extern "C" {
+        pub fn log(level: i32, ptr: i32, len: i32) -> i32;
+        pub fn kv_get(ptr: i32, len: i32) -> i64;
+        pub fn kv_set(ptr: i32, len: i32) -> i64;
+        pub fn http_fetch(ptr: i32, len: i32) -> i64;
+        pub fn memory_search(ptr: i32, len: i32) -> i64;
+    }
+}
+
+thread_local! {
+    // Buffers handed to the host, keyed by their guest address.
+    static ALLOCATIONS: RefCell<BTreeMap<i32, Vec<u8>>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned by a `tau_host` call.
+pub struct HostError {
+    pub reason_code: String,
+    pub message: String,
+}
+
+impl HostError {
+    fn new(reason_code: &str, message: impl Into<String>) -> Self {
+        Self {
+            reason_code: reason_code.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for HostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.reason_code)
+    }
+}
+
+impl std::error::Error for HostError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Severity attached to guest log lines in sandbox diagnostics.
+pub enum LogLevel {
+    Debug = 0,
+    Info = 1,
+    Warn = 2,
+    Error = 3,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Outbound request for [`http_fetch`]; the host applies its SSRF policy.
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Builds a `GET` request for `url`.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: "GET".to_string(),
+            url: url.into(),
+            headers: BTreeMap::new(),
+            body: None,
+        }
+    }
+
+    /// Builds a `POST` request for `url` with a text body.
+    pub fn post(url: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            method: "POST".to_string(),
+            url: url.into(),
+            headers: BTreeMap::new(),
+            body: Some(body.into()),
+        }
+    }
+
+    /// Adds a request header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+/// Response returned by [`http_fetch`]; redirects are not followed.
+pub struct HttpResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HostCall {
+    KvGet,
+    KvSet,
+    HttpFetch,
+    MemorySearch,
+}
+
+/// Appends a line to the sandbox diagnostics. Returns `false` when denied.
+pub fn log(level: LogLevel, message: &str) -> bool {
+    raw_log(level as i32, message.as_bytes())
+}
+
+/// Reads a value from the skill's kv store.
+pub fn kv_get(key: &str) -> Result<Option<String>, HostError> {
+    let value = call_host(HostCall::KvGet, &json!({ "key": key }))?;
+    serde_json::from_value(value).map_err(invalid_response)
+}
+
+/// Writes a value to the skill's kv store.
+pub fn kv_set(key: &str, value: &str) -> Result<(), HostError> {
+    call_host(HostCall::KvSet, &json!({ "key": key, "value": value })).map(|_| ())
+}
+
+/// Removes a key from the skill's kv store.
+pub fn kv_delete(key: &str) -> Result<(), HostError> {
+    call_host(HostCall::KvSet, &json!({ "key": key, "value": null })).map(|_| ())
+}
+
+/// Performs an HTTP request through the host's SSRF-guarded client.
+pub fn http_fetch(request: &HttpRequest) -> Result<HttpResponse, HostError> {
+    let request = serde_json::to_value(request).map_err(invalid_response)?;
+    let value = call_host(HostCall::HttpFetch, &request)?;
+    serde_json::from_value(value).map_err(invalid_response)
+}
+
+/// Searches agent memory; the host caps `limit`.
+pub fn memory_search(query: &str, limit: usize) -> Result<Vec<Value>, HostError> {
+    let value = call_host(
+        HostCall::MemorySearch,
+        &json!({ "query": query, "limit": limit }),
+    )?;
+    serde_json::from_value(value).map_err(invalid_response)
+}
+
+fn invalid_response(error: serde_json::Error) -> HostError {
+    HostError::new(
+        "tau_sdk_response_invalid",
+        format!("unexpected host payload: {error}"),
+    )
+}
+
+fn call_host(call: HostCall, request: &Value) -> Result<Value, HostError> {
+    let request = serde_json::to_vec(request).map_err(invalid_response)?;
+    let response = raw_call(call, &request)?;
+    decode_host_envelope(&response)
+}
+
+fn decode_host_envelope(bytes: &[u8]) -> Result<Value, HostError> {
+    let envelope: Value = serde_json::from_slice(bytes).map_err(invalid_response)?;
+    if envelope["ok"].as_bool() == Some(true) {
+        return Ok(envelope.get("value").cloned().unwrap_or(Value::Null));
+    }
+    Err(HostError::new(
+        envelope["reason_code"]
+            .as_str()
+            .unwrap_or("tau_sdk_response_invalid"),
+        envelope["error"].as_str().unwrap_or("host call failed"),
+    ))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn raw_log(level: i32, message: &[u8]) -> bool {
+    let ptr = message.as_ptr() as usize as i32;
+    // SAFETY: the host only reads `message.len()` bytes at `ptr` during the call.
+    let status = unsafe { imports::log(level, ptr, message.len() as i32) };
+    status == 0
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn raw_log(_level: i32, _message: &[u8]) -> bool {
+    false
+}
+
+#[cfg(target_arch = "wasm32")]
+fn raw_call(call: HostCall, request: &[u8]) -> Result<Vec<u8>, HostError> {
+    let ptr = request.as_ptr() as usize as i32;
+    let len = request.len() as i32;
+    // SAFETY: the host reads the request buffer during the call and writes its
+    // response into a buffer obtained from `tau_extension_alloc`.
+    let packed = unsafe {
+        match call {
+            HostCall::KvGet => imports::kv_get(ptr, len),
+            HostCall::KvSet => imports::kv_set(ptr, len),
+            HostCall::HttpFetch => imports::http_fetch(ptr, len),
+            HostCall::MemorySearch => imports::memory_search(ptr, len),
+        }
+    } as u64;
+    let response_ptr = (packed >> 32) as i32;
+    let response_len = (packed & 0xFFFF_FFFF) as usize;
+    let mut response = take_allocation(response_ptr).ok_or_else(|| {
+        HostError::new(
+            "tau_sdk_response_invalid",
+            "host response was not written to an SDK allocation",
+        )
+    })?;
+    response.truncate(response_len);
+    Ok(response)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn raw_call(call: HostCall, _request: &[u8]) -> Result<Vec<u8>, HostError> {
+    Err(HostError::new(
+        "tau_sdk_host_unavailable",
+        format!("tau_host call {call:?} is only available inside the wasm sandbox"),
+    ))
+}
+
+fn take_allocation(ptr: i32) -> Option<Vec<u8>> {
+    ALLOCATIONS.with(|allocations| allocations.borrow_mut().remove(&ptr))
+}
+
+/// Reserves a host-writable buffer. Backs the `tau_extension_alloc` export.
+#[doc(hidden)]
+pub fn __alloc(len: i32) -> i32 {
+    let mut buffer = vec![0u8; usize::try_from(len).unwrap_or_default()];
+    let ptr = buffer.as_mut_ptr() as usize as i32;
+    ALLOCATIONS.with(|allocations| allocations.borrow_mut().insert(ptr, buffer));
+    ptr
+}
+
+/// Runs `handler` for a host request. Backs the `tau_extension_invoke` export.
+#[doc(hidden)]
+pub fn __invoke(ptr: i32, len: i32, handler: fn(Value) -> Result<Value, String>) -> i64 {
+    let mut request = take_allocation(ptr).unwrap_or_default();
+    request.truncate(usize::try_from(len).unwrap_or_default());
+    let mut response = render_skill_response(&request, handler);
+    let response_len = response.len() as u64;
+    let response_ptr = response.as_mut_ptr() as usize as u32;
+    // Keep the buffer alive until the host has copied it out.
+    ALLOCATIONS.with(|allocations| {
+        allocations
+            .borrow_mut()
+            .insert(response_ptr as i32, response)
+    });
+    ((u64::from(response_ptr) << 32) | response_len) as i64
+}
+
+fn render_skill_response(request: &[u8], handler: fn(Value) -> Result<Value, String>) -> Vec<u8> {
+    let outcome = serde_json::from_slice::<Value>(request)
+        .map_err(|error| format!("skill request is not valid JSON: {error}"))
+        .and_then(handler);
+    let response = match outcome {
+        Ok(content) => json!({ "content": content, "is_error": false }),
+        Err(error) => json!({ "content": { "error": error }, "is_error": true }),
+    };
+    serde_json::to_vec(&response).unwrap_or_else(|_| b"{\"is_error\":true}".to_vec())
+}
+
+/// Exports the sandbox ABI for a `fn(serde_json::Value) -> Result<serde_json::Value, String>`.
+#[macro_export]
+macro_rules! export_skill {
+    ($handler:path) => {
+        #[no_mangle]
+        pub extern "C" fn tau_extension_alloc(len: i32) -> i32 {
+            $crate::__alloc(len)
+        }
+
+        #[no_mangle]
+        pub extern "C" fn tau_extension_invoke(ptr: i32, len: i32) -> i64 {
+            $crate::__invoke(ptr, len, $handler)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        __alloc, decode_host_envelope, kv_get, render_skill_response, take_allocation, HttpRequest,
+    };
+    use serde_json::{json, Value};
+
+    fn echo(request: Value) -> Result<Value, String> {
+        match request.get("fail") {
+            Some(_) => Err("requested failure".to_string()),
+            None => Ok(json!({ "echo": request })),
+        }
+    }
+
+    #[test]
+    fn unit_decode_host_envelope_maps_success_and_errors() {
+        let value = decode_host_envelope(br#"{"ok":true,"value":"blue"}"#).expect("ok envelope");
+        assert_eq!(value, json!("blue"));
+
+        let error = decode_host_envelope(
+            br#"{"ok":false,"reason_code":"wasm_host_capability_denied","error":"denied"}"#,
+        )
+        .expect_err("error envelope");
+        assert_eq!(error.reason_code, "wasm_host_capability_denied");
+        assert_eq!(error.message, "denied");
+    }
+
+    #[test]
+    fn unit_http_request_serializes_host_fetch_shape() {
+        let request = HttpRequest::post("https://example.com/hook", "{}")
+            .header("content-type", "application/json");
+        assert_eq!(
+            serde_json::to_value(&request).expect("serialize"),
+            json!({
+                "method": "POST",
+                "url": "https://example.com/hook",
+                "headers": { "content-type": "application/json" },
+                "body": "{}",
+            })
+        );
+    }
+
+    #[test]
+    fn functional_render_skill_response_wraps_handler_outcome() {
+        let ok: Value =
+            serde_json::from_slice(&render_skill_response(br#"{"q":1}"#, echo)).expect("json");
+        assert_eq!(
+            ok,
+            json!({ "content": { "echo": { "q": 1 } }, "is_error": false })
+        );
+
+        let failed: Value =
+            serde_json::from_slice(&render_skill_response(br#"{"fail":true}"#, echo))
+                .expect("json");
+        assert_eq!(failed["is_error"], true);
+        assert_eq!(failed["content"]["error"], "requested failure");
+    }
+
+    #[test]
+    fn regression_alloc_buffers_are_reclaimed_once() {
+        let ptr = __alloc(8);
+        assert_eq!(take_allocation(ptr).map(|buffer| buffer.len()), Some(8));
+        assert!(take_allocation(ptr).is_none());
+    }
+
+    #[test]
+    fn regression_host_calls_fail_outside_wasm_sandbox() {
+        let error = kv_get("color").expect_err("native builds have no tau_host");
+        assert_eq!(error.reason_code, "tau_sdk_host_unavailable");
+    }
+}